@@ -0,0 +1,81 @@
+use backend_comparison::persistence::save;
+use burn::tensor::{backend::Backend, Distribution, Shape, Tensor};
+use burn_common::{
+    benchmark::{run_benchmark, Benchmark},
+    sync_type::SyncType,
+};
+use derive_new::new;
+
+/// Chains many element-wise operations on a tiny tensor, so each launch does almost no work and
+/// the time is spent on the cost of launching and submitting the kernels.
+///
+/// Each operation is followed by a reshape, which the element-wise fusion doesn't fuse, so the
+/// fusion backends launch a kernel per operation too instead of fusing the whole chain.
+#[derive(new)]
+struct ChainedOpsBenchmark<B: Backend, const D: usize> {
+    shape: Shape<D>,
+    num_ops: usize,
+    device: B::Device,
+}
+
+impl<B: Backend, const D: usize> Benchmark for ChainedOpsBenchmark<B, D> {
+    type Args = Tensor<B, D>;
+
+    fn name(&self) -> String {
+        "chained-ops".into()
+    }
+
+    fn shapes(&self) -> Vec<Vec<usize>> {
+        vec![self.shape.dims.into()]
+    }
+
+    fn execute(&self, args: Self::Args) {
+        let mut tensor = args;
+
+        let shape = self.shape.clone();
+        let mut reversed_shape = self.shape.clone();
+        reversed_shape.dims.reverse();
+
+        // Alternate the operations so that they can't be simplified into a single one.
+        for i in 0..self.num_ops {
+            tensor = match i % 2 {
+                0 => tensor.add_scalar(1.0).reshape(reversed_shape.clone()),
+                _ => tensor.mul_scalar(0.5).reshape(shape.clone()),
+            };
+        }
+    }
+
+    fn prepare(&self) -> Self::Args {
+        Tensor::random(self.shape.clone(), Distribution::Default, &self.device)
+    }
+
+    fn sync(&self) {
+        B::sync(&self.device, SyncType::Wait)
+    }
+}
+
+#[allow(dead_code)]
+fn bench<B: Backend>(
+    device: &B::Device,
+    feature_name: &str,
+    url: Option<&str>,
+    token: Option<&str>,
+) {
+    const D: usize = 2;
+    let shape: Shape<D> = [4, 8].into();
+
+    let benchmark = ChainedOpsBenchmark::<B, D>::new(shape, 1000, device.clone());
+
+    save::<B>(
+        vec![run_benchmark(benchmark)],
+        device,
+        feature_name,
+        url,
+        token,
+    )
+    .unwrap();
+}
+
+fn main() {
+    backend_comparison::bench_on_backend!();
+}
@@ -0,0 +1,147 @@
+use backend_comparison::persistence::save;
+use burn::tensor::{
+    backend::Backend,
+    module::conv2d,
+    ops::{conv::conv1d_fft, ConvOptions},
+    Distribution, Shape, Tensor,
+};
+use burn_common::{
+    benchmark::{run_benchmark, Benchmark},
+    sync_type::SyncType,
+};
+
+/// How the 1D convolution is computed.
+#[derive(Clone, Copy)]
+enum Conv1dPath {
+    /// The direct convolution, as a 2D convolution.
+    Direct,
+    /// The convolution in the frequency domain.
+    Fft,
+}
+
+pub struct Conv1dBenchmark<B: Backend> {
+    input_shape: Shape<3>,
+    weight_shape: Shape<3>,
+    bias_shape: Shape<1>,
+    options: ConvOptions<1>,
+    path: Conv1dPath,
+    device: B::Device,
+}
+
+impl<B: Backend> Benchmark for Conv1dBenchmark<B> {
+    type Args = (Tensor<B, 3>, Tensor<B, 3>, Tensor<B, 1>);
+
+    fn name(&self) -> String {
+        // The span of the kernel decides which path is faster.
+        let span = self.weight_shape.dims[2] * self.options.dilation[0];
+
+        match self.path {
+            Conv1dPath::Direct => format!("conv1d-direct-span-{span}"),
+            Conv1dPath::Fft => format!("conv1d-fft-span-{span}"),
+        }
+    }
+
+    fn shapes(&self) -> Vec<Vec<usize>> {
+        vec![
+            self.input_shape.dims.into(),
+            self.weight_shape.dims.into(),
+            self.bias_shape.dims.into(),
+        ]
+    }
+
+    fn execute(&self, (x, w, b): Self::Args) {
+        match self.path {
+            Conv1dPath::Direct => {
+                let options = &self.options;
+                conv2d(
+                    x.unsqueeze_dim::<4>(3),
+                    w.unsqueeze_dim::<4>(3),
+                    Some(b),
+                    ConvOptions::new(
+                        [options.stride[0], 1],
+                        [options.padding[0], 0],
+                        [options.dilation[0], 1],
+                        options.groups,
+                    ),
+                );
+            }
+            Conv1dPath::Fft => {
+                conv1d_fft::<B>(
+                    x.into_primitive().tensor(),
+                    w.into_primitive().tensor(),
+                    Some(b.into_primitive().tensor()),
+                    self.options.clone(),
+                );
+            }
+        }
+    }
+
+    fn prepare(&self) -> Self::Args {
+        (
+            Tensor::random(
+                self.input_shape.clone(),
+                Distribution::Default,
+                &self.device,
+            ),
+            Tensor::random(
+                self.weight_shape.clone(),
+                Distribution::Default,
+                &self.device,
+            ),
+            Tensor::random(self.bias_shape.clone(), Distribution::Default, &self.device),
+        )
+    }
+
+    fn sync(&self) {
+        B::sync(&self.device, SyncType::Wait)
+    }
+}
+
+#[allow(dead_code)]
+fn bench<B: Backend>(
+    device: &B::Device,
+    feature_name: &str,
+    url: Option<&str>,
+    token: Option<&str>,
+) {
+    // Shapes
+    let batch_size = 4;
+    let channels_in = 32;
+    let channels_out = 32;
+    let length_in = 16384;
+
+    // Sweep the span of the kernel, with and without dilation, to show where the frequency domain
+    // becomes faster, which gives the threshold to set for the automatic selection.
+    let kernels = [
+        (16, 1),
+        (64, 1),
+        (256, 1),
+        (1024, 1),
+        (16, 16),
+        (16, 64),
+        (16, 256),
+    ];
+
+    let benchmark = |kernel_size: usize, dilation: usize, path| Conv1dBenchmark::<B> {
+        input_shape: [batch_size, channels_in, length_in].into(),
+        weight_shape: [channels_out, channels_in, kernel_size].into(),
+        bias_shape: [channels_out].into(),
+        options: ConvOptions::new([1], [0], [dilation], 1),
+        path,
+        device: device.clone(),
+    };
+
+    let results = kernels
+        .iter()
+        .flat_map(|&(kernel_size, dilation)| {
+            [Conv1dPath::Direct, Conv1dPath::Fft]
+                .map(|path| run_benchmark(benchmark(kernel_size, dilation, path)))
+        })
+        .collect();
+
+    save::<B>(results, device, feature_name, url, token).unwrap();
+}
+
+fn main() {
+    backend_comparison::bench_on_backend!();
+}
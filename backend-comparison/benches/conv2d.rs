@@ -1,6 +1,6 @@
 use backend_comparison::persistence::save;
 use burn::tensor::{
-    backend::Backend, module::conv2d, ops::ConvOptions, Distribution, Shape, Tensor,
+    backend::Backend, module::conv2d, ops::ConvOptions, Distribution, MemoryFormat, Shape, Tensor,
 };
 use burn_common::{
     benchmark::{run_benchmark, Benchmark},
@@ -12,6 +12,7 @@ pub struct Conv2dBenchmark<B: Backend> {
     weight_shape: Shape<4>,
     bias_shape: Shape<1>,
     options: ConvOptions<2>,
+    format: MemoryFormat,
     device: B::Device,
 }
 
@@ -19,7 +20,10 @@ impl<B: Backend> Benchmark for Conv2dBenchmark<B> {
     type Args = (Tensor<B, 4>, Tensor<B, 4>, Tensor<B, 1>);
 
     fn name(&self) -> String {
-        "conv2d".into()
+        match self.format {
+            MemoryFormat::Contiguous => "conv2d".into(),
+            MemoryFormat::ChannelsLast => "conv2d-channels-last".into(),
+        }
     }
 
     fn shapes(&self) -> Vec<Vec<usize>> {
@@ -40,12 +44,14 @@ impl<B: Backend> Benchmark for Conv2dBenchmark<B> {
                 self.input_shape.clone(),
                 Distribution::Default,
                 &self.device,
-            ),
+            )
+            .to_memory_format(self.format),
             Tensor::random(
                 self.weight_shape.clone(),
                 Distribution::Default,
                 &self.device,
-            ),
+            )
+            .to_memory_format(self.format),
             Tensor::random(self.bias_shape.clone(), Distribution::Default, &self.device),
         )
     }
@@ -77,7 +83,7 @@ fn bench<B: Backend>(
     let dilations = [1, 1];
     let groups = 1;
     let options = ConvOptions::new(strides, padding, dilations, groups);
-    let benchmark = Conv2dBenchmark::<B> {
+    let benchmark = |format| Conv2dBenchmark::<B> {
         input_shape: [batch_size, channels_in, height_in, width_in].into(),
         weight_shape: [
             channels_in,
@@ -87,12 +93,16 @@ fn bench<B: Backend>(
         ]
         .into(),
         bias_shape: [channels_out].into(),
-        options,
+        options: options.clone(),
+        format,
         device: device.clone(),
     };
 
     save::<B>(
-        vec![run_benchmark(benchmark)],
+        vec![
+            run_benchmark(benchmark(MemoryFormat::Contiguous)),
+            run_benchmark(benchmark(MemoryFormat::ChannelsLast)),
+        ],
         device,
         feature_name,
         url,
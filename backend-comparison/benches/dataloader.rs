@@ -0,0 +1,104 @@
+use backend_comparison::persistence::save;
+use burn::data::dataloader::{batcher::Batcher, DataLoaderBuilder};
+use burn::data::dataset::InMemDataset;
+use burn::tensor::{backend::Backend, Tensor, TensorData};
+use burn_common::benchmark::{run_benchmark, Benchmark};
+use burn_common::sync_type::SyncType;
+use derive_new::new;
+use std::time::Duration;
+
+/// Simulates an expensive preprocessing of the items, e.g. decoding and augmenting images.
+const BATCHING_TIME: Duration = Duration::from_millis(2);
+/// Simulates an expensive training step on each batch.
+const COMPUTE_TIME: Duration = Duration::from_millis(2);
+
+#[derive(Clone)]
+struct SlowBatcher {
+    item_size: usize,
+}
+
+impl<B: Backend> Batcher<B, usize, Tensor<B, 2>> for SlowBatcher {
+    fn batch(&self, items: Vec<usize>, device: &B::Device) -> Tensor<B, 2> {
+        std::thread::sleep(BATCHING_TIME);
+
+        let values = items
+            .iter()
+            .flat_map(|item| (0..self.item_size).map(move |i| (item + i) as f32))
+            .collect::<Vec<_>>();
+
+        Tensor::from_data(
+            TensorData::new(values, [items.len(), self.item_size]),
+            device,
+        )
+    }
+}
+
+/// Benchmark how well the batching work overlaps with a slow computation on each batch.
+#[derive(new)]
+struct DataLoaderBenchmark<B: Backend> {
+    num_items: usize,
+    batch_size: usize,
+    item_size: usize,
+    prefetch: usize,
+    device: B::Device,
+}
+
+impl<B: Backend> Benchmark for DataLoaderBenchmark<B> {
+    type Args = ();
+
+    fn name(&self) -> String {
+        match self.prefetch {
+            0 => "dataloader".into(),
+            _ => "dataloader_prefetch".into(),
+        }
+    }
+
+    fn options(&self) -> Option<String> {
+        Some(format!("prefetch: {}", self.prefetch))
+    }
+
+    fn shapes(&self) -> Vec<Vec<usize>> {
+        vec![vec![self.num_items, self.item_size]]
+    }
+
+    fn execute(&self, _args: Self::Args) {
+        let dataloader = DataLoaderBuilder::<B, _, _>::new(SlowBatcher {
+            item_size: self.item_size,
+        })
+        .batch_size(self.batch_size)
+        .device(self.device.clone())
+        .prefetch(self.prefetch)
+        .build(InMemDataset::new((0..self.num_items).collect()));
+
+        for batch in dataloader.iter() {
+            let _output = batch.exp().sum();
+            std::thread::sleep(COMPUTE_TIME);
+        }
+    }
+
+    fn prepare(&self) -> Self::Args {}
+
+    fn sync(&self) {
+        B::sync(&self.device, SyncType::Wait)
+    }
+}
+
+#[allow(dead_code)]
+fn bench<B: Backend>(
+    device: &B::Device,
+    feature_name: &str,
+    url: Option<&str>,
+    token: Option<&str>,
+) {
+    let benchmarks = [0, 2]
+        .into_iter()
+        .map(|prefetch| DataLoaderBenchmark::<B>::new(1024, 32, 256, prefetch, device.clone()))
+        .map(run_benchmark)
+        .collect();
+
+    save::<B>(benchmarks, device, feature_name, url, token).unwrap();
+}
+
+fn main() {
+    backend_comparison::bench_on_backend!();
+}
@@ -10,6 +10,9 @@ use derive_new::new;
 struct MatmulBenchmark<B: Backend, const D: usize> {
     shape_lhs: Shape<D>,
     shape_rhs: Shape<D>,
+    /// Whether the lhs and the rhs are transposed views of row major tensors, like the inputs of
+    /// the matmuls of the backward pass of a linear layer.
+    transposed: [bool; 2],
     device: B::Device,
 }
 
@@ -17,7 +20,12 @@ impl<B: Backend, const D: usize> Benchmark for MatmulBenchmark<B, D> {
     type Args = (Tensor<B, D>, Tensor<B, D>);
 
     fn name(&self) -> String {
-        "matmul".into()
+        match self.transposed {
+            [false, false] => "matmul".into(),
+            [true, false] => "matmul-transposed-lhs".into(),
+            [false, true] => "matmul-transposed-rhs".into(),
+            [true, true] => "matmul-transposed".into(),
+        }
     }
 
     fn shapes(&self) -> Vec<Vec<usize>> {
@@ -33,8 +41,18 @@ impl<B: Backend, const D: usize> Benchmark for MatmulBenchmark<B, D> {
     }
 
     fn prepare(&self) -> Self::Args {
-        let lhs = Tensor::random(self.shape_lhs.clone(), Distribution::Default, &self.device);
-        let rhs = Tensor::random(self.shape_rhs.clone(), Distribution::Default, &self.device);
+        let random = |shape: &Shape<D>, transposed: bool| {
+            if !transposed {
+                return Tensor::random(shape.clone(), Distribution::Default, &self.device);
+            }
+
+            let mut dims = shape.dims;
+            dims.swap(D - 2, D - 1);
+            Tensor::random(dims, Distribution::Default, &self.device).swap_dims(D - 2, D - 1)
+        };
+
+        let lhs = random(&self.shape_lhs, self.transposed[0]);
+        let rhs = random(&self.shape_rhs, self.transposed[1]);
 
         (lhs, rhs)
     }
@@ -44,6 +62,113 @@ impl<B: Backend, const D: usize> Benchmark for MatmulBenchmark<B, D> {
     }
 }
 
+/// The tiling 2D kernel of the JIT backends with and without double buffering, which prefetches
+/// the next blocks of the inputs while the current ones are multiplied.
+#[cfg(feature = "burn-jit")]
+#[derive(new)]
+struct Tiling2dBenchmark<R: burn_jit::JitRuntime, const D: usize> {
+    shape_lhs: Shape<D>,
+    shape_rhs: Shape<D>,
+    double_buffering: bool,
+    device: R::Device,
+}
+
+#[cfg(feature = "burn-jit")]
+impl<R: burn_jit::JitRuntime, const D: usize> Benchmark for Tiling2dBenchmark<R, D> {
+    type Args = (
+        burn_jit::tensor::JitTensor<R, f32, D>,
+        burn_jit::tensor::JitTensor<R, f32, D>,
+    );
+
+    fn name(&self) -> String {
+        match self.double_buffering {
+            true => "matmul-tiling2d-double-buffered".into(),
+            false => "matmul-tiling2d".into(),
+        }
+    }
+
+    fn shapes(&self) -> Vec<Vec<usize>> {
+        vec![self.shape_lhs.dims.into(), self.shape_rhs.dims.into()]
+    }
+
+    fn num_samples(&self) -> usize {
+        10
+    }
+
+    fn execute(&self, (lhs, rhs): Self::Args) {
+        use burn_jit::kernel::matmul::{matmul, MatmulStrategy, Tiling2dConfig};
+
+        let config = Tiling2dConfig {
+            double_buffering: self.double_buffering,
+            ..Default::default()
+        };
+        matmul(lhs, rhs, MatmulStrategy::Tiling2d(config));
+    }
+
+    fn prepare(&self) -> Self::Args {
+        let random = |shape: &Shape<D>| {
+            Tensor::<burn_jit::JitBackend<R, f32, i32>, D>::random(
+                shape.clone(),
+                Distribution::Default,
+                &self.device,
+            )
+            .into_primitive()
+            .tensor()
+        };
+
+        (random(&self.shape_lhs), random(&self.shape_rhs))
+    }
+
+    fn sync(&self) {
+        burn_jit::JitBackend::<R, f32, i32>::sync(&self.device, SyncType::Wait)
+    }
+}
+
+/// Compare the tiling 2D kernel with and without double buffering, from a small inner dimension
+/// where the prefetch has little to hide to a large one spanning many blocks.
+#[cfg(feature = "burn-jit")]
+fn bench_double_buffering<R: burn_jit::JitRuntime>(
+    device: &R::Device,
+    feature_name: &str,
+    url: Option<&str>,
+    token: Option<&str>,
+) {
+    const D: usize = 3;
+    let shapes: [([usize; D], [usize; D]); 3] = [
+        ([1, 1024, 64], [1, 64, 1024]),
+        ([1, 1024, 1024], [1, 1024, 1024]),
+        ([1, 256, 8192], [1, 8192, 256]),
+    ];
+
+    let results = shapes
+        .into_iter()
+        .flat_map(|(shape_lhs, shape_rhs)| {
+            [false, true].map(|double_buffering| {
+                let result = run_benchmark(Tiling2dBenchmark::<R, D>::new(
+                    Shape::new(shape_lhs),
+                    Shape::new(shape_rhs),
+                    double_buffering,
+                    device.clone(),
+                ));
+                report_tiling_2d_path(shape_lhs, shape_rhs);
+
+                result
+            })
+        })
+        .collect();
+
+    save::<burn_jit::JitBackend<R, f32, i32>>(results, device, feature_name, url, token).unwrap();
+}
+
+/// Report the specialization of the tiling 2D kernel launched by the last benchmark, the aligned
+/// one skipping every bound check, as recorded by the launch itself.
+#[cfg(feature = "burn-jit")]
+fn report_tiling_2d_path<const D: usize>(shape_lhs: [usize; D], shape_rhs: [usize; D]) {
+    let path = burn_jit::perf::take_last_tiling_2d_path();
+
+    println!("Tiling 2D matmul of {shape_lhs:?} by {shape_rhs:?}: {path:?} path launched");
+}
+
 #[allow(dead_code)]
 fn bench<B: Backend>(
     device: &B::Device,
@@ -52,25 +177,42 @@ fn bench<B: Backend>(
     token: Option<&str>,
 ) {
     const D: usize = 3;
-    let batch_size = 8;
-    let m = 2048;
-    let k = 2048;
-    let n = 2048;
-    let shape_lhs = [batch_size, m, k].into();
-    let shape_rhs = [batch_size, k, n].into();
-
-    let benchmark = MatmulBenchmark::<B, D>::new(shape_lhs, shape_rhs, device.clone());
-
-    save::<B>(
-        vec![run_benchmark(benchmark)],
-        device,
-        feature_name,
-        url,
-        token,
-    )
-    .unwrap();
+    // The last two are the matmuls of the backward pass of a linear layer of 1024 by 4096 on 2048
+    // items: the gradient of the input multiplies by the transposed weights, the one of the
+    // weights multiplies the transposed input.
+    let shapes: [([usize; D], [usize; D], [bool; 2]); 4] = [
+        ([8, 2048, 2048], [8, 2048, 2048], [false, false]),
+        ([1, 1024, 1024], [1, 1024, 1024], [false, false]),
+        ([1, 2048, 4096], [1, 4096, 1024], [false, true]),
+        ([1, 1024, 2048], [1, 2048, 4096], [true, false]),
+    ];
+
+    let results = shapes
+        .into_iter()
+        .map(|(shape_lhs, shape_rhs, transposed)| {
+            run_benchmark(MatmulBenchmark::<B, D>::new(
+                Shape::new(shape_lhs),
+                Shape::new(shape_rhs),
+                transposed,
+                device.clone(),
+            ))
+        })
+        .collect();
+
+    save::<B>(results, device, feature_name, url, token).unwrap();
 }
 
 fn main() {
     backend_comparison::bench_on_backend!();
+
+    #[cfg(feature = "wgpu")]
+    {
+        use burn::backend::wgpu::{WgpuDevice, WgpuRuntime};
+
+        let args: Vec<String> = std::env::args().collect();
+        let url = backend_comparison::get_sharing_url(&args);
+        let token = backend_comparison::get_sharing_token(&args);
+
+        bench_double_buffering::<WgpuRuntime>(&WgpuDevice::default(), "wgpu", url, token);
+    }
 }
@@ -32,6 +32,7 @@ impl<B: Backend> Benchmark for MaxPool2dBenchmark<B> {
             self.stride,
             self.padding,
             self.dilation,
+            false,
         );
     }
 
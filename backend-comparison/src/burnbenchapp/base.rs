@@ -88,10 +88,14 @@ enum BenchmarkValues {
     All,
     #[strum(to_string = "binary")]
     Binary,
+    #[strum(to_string = "chained-ops")]
+    ChainedOps,
     #[strum(to_string = "custom-gelu")]
     CustomGelu,
     #[strum(to_string = "data")]
     Data,
+    #[strum(to_string = "dataloader")]
+    DataLoader,
     #[strum(to_string = "matmul")]
     Matmul,
     #[strum(to_string = "unary")]
@@ -106,6 +110,8 @@ enum BenchmarkValues {
     ConvTranspose2d,
     #[strum(to_string = "conv-transpose3d")]
     ConvTranspose3d,
+    #[strum(to_string = "conv1d")]
+    Conv1d,
     #[strum(to_string = "conv2d")]
     Conv2d,
     #[strum(to_string = "conv3d")]
@@ -5,8 +5,9 @@ use crate::{
     tensor::AutodiffTensor,
     AutodiffBridge,
 };
+use alloc::vec::Vec;
 use burn_common::sync_type::SyncType;
-use burn_tensor::backend::{AutodiffBackend, Backend};
+use burn_tensor::backend::{AutodiffBackend, Backend, DeviceProperties, RngState};
 use core::marker::PhantomData;
 
 /// Enable auto-differentiation on a backend.
@@ -46,9 +47,29 @@ impl<B: Backend, C: CheckpointStrategy> Backend for Autodiff<B, C> {
         B::seed(seed)
     }
 
+    fn seed_device(device: &Self::Device, seed: u64) {
+        B::seed_device(device, seed);
+    }
+
+    fn rng_state() -> Option<RngState> {
+        B::rng_state()
+    }
+
+    fn restore_rng_state(state: RngState) {
+        B::restore_rng_state(state);
+    }
+
     fn sync(device: &B::Device, sync_type: SyncType) {
         B::sync(device, sync_type)
     }
+
+    fn devices() -> Vec<Self::Device> {
+        B::devices()
+    }
+
+    fn device_properties(device: &Self::Device) -> DeviceProperties {
+        B::device_properties(device)
+    }
 }
 
 impl<B: Backend, C: CheckpointStrategy> AutodiffBackend for Autodiff<B, C> {
@@ -61,6 +82,12 @@ impl<B: Backend, C: CheckpointStrategy> AutodiffBackend for Autodiff<B, C> {
         AutodiffClient::backward(&client, tensor)
     }
 
+    fn backward_retained<const D: usize>(tensor: AutodiffTensor<B, D>) -> Gradients {
+        let client = tensor.node.client.clone();
+
+        AutodiffClient::backward_retained(&client, tensor)
+    }
+
     fn grad<const D: usize>(
         tensor: &AutodiffTensor<B, D>,
         grads: &Gradients,
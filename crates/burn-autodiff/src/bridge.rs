@@ -39,6 +39,16 @@ where
             _bridge: PhantomData<Bridge>,
         }
 
+        // The bridge isn't clonable, so the clone isn't derived.
+        impl<B: Backend, Bridge: BackendBridge<B>> Clone for IntoTarget<B, Bridge> {
+            fn clone(&self) -> Self {
+                Self {
+                    _backend: PhantomData,
+                    _bridge: PhantomData,
+                }
+            }
+        }
+
         #[derive(new, Debug, Clone)]
         struct RetroIntoTarget<B: Backend, Bridge: BackendBridge<B>, const D: usize> {
             tensor_id: NodeID,
@@ -65,6 +75,10 @@ where
         {
             type State = ();
 
+            fn clone_backward(&self) -> Option<Self> {
+                Some(self.clone())
+            }
+
             fn backward(
                 self,
                 ops: Ops<Self::State, 1>,
@@ -101,6 +115,15 @@ where
             _bridge: PhantomData<Bridge>,
         }
 
+        impl<B: Backend, Bridge: BackendBridge<B>> Clone for FromTarget<B, Bridge> {
+            fn clone(&self) -> Self {
+                Self {
+                    _backend: PhantomData,
+                    _bridge: PhantomData,
+                }
+            }
+        }
+
         #[derive(new, Debug, Clone)]
         struct RetroFromTarget<B: Backend, Bridge: BackendBridge<B>, const D: usize> {
             tensor_id: NodeID,
@@ -127,6 +150,10 @@ where
         {
             type State = ();
 
+            fn clone_backward(&self) -> Option<Self> {
+                Some(self.clone())
+            }
+
             fn backward(
                 self,
                 ops: Ops<Self::State, 1>,
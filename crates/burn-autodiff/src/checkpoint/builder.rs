@@ -1,14 +1,14 @@
 use crate::{
-    graph::{ComputingProperty, NodeID, NodeSteps},
+    graph::{ComputingProperty, NodeID, StepBoxed},
     tensor::AutodiffTensor,
 };
 use burn_tensor::backend::Backend;
-use std::{any::Any, collections::HashMap, sync::Arc};
+use std::{collections::HashMap, sync::Arc};
 
 use super::{
     base::{Checkpointer, NodeTree},
     retro_forward::{RetroForward, RetroForwards},
-    state::{BackwardStates, State},
+    state::{AnyState, BackwardStates, State},
 };
 
 #[derive(Debug)]
@@ -20,7 +20,7 @@ pub enum CheckpointingAction {
         /// The node
         node_id: NodeID,
         /// The node's output
-        state_content: Box<dyn Any + Send>,
+        state_content: Box<dyn AnyState>,
     },
     /// The node should recompute itself when asked
     Recompute {
@@ -34,6 +34,27 @@ pub enum CheckpointingAction {
 // TODO: Remove that when proper client server.
 unsafe impl Send for CheckpointingAction {}
 
+impl Clone for CheckpointingAction {
+    fn clone(&self) -> Self {
+        match self {
+            CheckpointingAction::Computed {
+                node_id,
+                state_content,
+            } => CheckpointingAction::Computed {
+                node_id: *node_id,
+                state_content: (**state_content).clone_boxed(),
+            },
+            CheckpointingAction::Recompute {
+                node_id,
+                retro_forward,
+            } => CheckpointingAction::Recompute {
+                node_id: *node_id,
+                retro_forward: retro_forward.clone(),
+            },
+        }
+    }
+}
+
 impl CheckpointingAction {
     /// Utilitary function to access the id of the node of the checkpointing action
     pub fn id(&self) -> NodeID {
@@ -50,7 +71,7 @@ impl CheckpointingAction {
     }
 }
 
-#[derive(new, Debug, Default)]
+#[derive(new, Debug, Default, Clone)]
 /// Accumulates checkpoints as checkpointing actions during the forward pass,
 /// and builds a checkpointer right before the backward pass
 pub struct CheckpointerBuilder {
@@ -106,7 +127,12 @@ impl CheckpointerBuilder {
         }
     }
 
-    pub(crate) fn build(self, graph: &NodeSteps) -> Checkpointer {
+    /// Builds the checkpointer, with the steps of the graph that weren't reached by the
+    /// backward pass.
+    pub(crate) fn build<'a>(
+        self,
+        graph: impl IntoIterator<Item = (&'a NodeID, &'a StepBoxed)>,
+    ) -> Checkpointer {
         let node_tree = self.make_tree(graph);
         let mut backward_states_map = HashMap::new();
         let mut retro_forwards_map = HashMap::new();
@@ -245,7 +271,10 @@ impl CheckpointerBuilder {
         }
     }
 
-    fn make_tree(&self, graph: &NodeSteps) -> NodeTree {
+    fn make_tree<'a>(
+        &self,
+        graph: impl IntoIterator<Item = (&'a NodeID, &'a StepBoxed)>,
+    ) -> NodeTree {
         let mut tree = HashMap::default();
         for (id, step) in graph {
             tree.insert(*id, step.parents());
@@ -285,7 +314,7 @@ impl CheckpointerBuilder {
         &self,
         backward_states_map: &mut HashMap<NodeID, State>,
         node_id: NodeID,
-        state_content: Box<dyn Any + Send>,
+        state_content: Box<dyn AnyState>,
         n_required: usize,
     ) {
         backward_states_map.insert(
@@ -2,8 +2,40 @@ use std::{any::Any, collections::HashMap};
 
 use crate::graph::NodeID;
 
-/// In order to accept arbitrary node output in the same hashmap, we need to upcast them to any.
-pub(crate) type StateContent = Box<dyn Any + Send>;
+/// A node output upcasted to any, in order to accept arbitrary node outputs in the same hashmap.
+///
+/// The output can be cloned, so that the checkpoints of a retained graph survive its backward
+/// passes.
+pub trait AnyState: Any + Send {
+    /// Clones the output.
+    fn clone_boxed(&self) -> Box<dyn AnyState>;
+    /// Upcasts a reference to the output.
+    fn as_any(&self) -> &dyn Any;
+    /// Upcasts the output.
+    fn into_any(self: Box<Self>) -> Box<dyn Any + Send>;
+}
+
+impl<T: Clone + Send + 'static> AnyState for T {
+    fn clone_boxed(&self) -> Box<dyn AnyState> {
+        Box::new(self.clone())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any + Send> {
+        self
+    }
+}
+
+impl core::fmt::Debug for dyn AnyState {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("AnyState").finish_non_exhaustive()
+    }
+}
+
+pub(crate) type StateContent = Box<dyn AnyState>;
 
 #[derive(Debug)]
 /// The state contained at one node. Encapsulates the node output if precomputed,
@@ -91,8 +123,8 @@ impl BackwardStates {
                 },
             };
 
-            let downcasted = new_stored_state
-                .to_state_content()
+            let downcasted = (**new_stored_state.to_state_content())
+                .as_any()
                 .downcast_ref::<T>()
                 .unwrap()
                 .clone();
@@ -101,7 +133,11 @@ impl BackwardStates {
 
             downcasted
         } else {
-            let downcasted = state.into_state_content().downcast::<T>().unwrap();
+            let downcasted = state
+                .into_state_content()
+                .into_any()
+                .downcast::<T>()
+                .unwrap();
             *downcasted
         }
     }
@@ -72,6 +72,48 @@ impl Gradients {
             .map(|tensor| tensor.into_primitive().tensor())
     }
 
+    /// Register the matrix multiplication of `lhs` and `rhs` as a grad tensor in the container.
+    ///
+    /// If the tensor already exists, the product is accumulated into it by the backend, without a
+    /// separate addition. The product must then have the shape of the existing tensor.
+    pub fn register_matmul<B: Backend, const D: usize>(
+        &mut self,
+        node_id: NodeID,
+        lhs: TensorPrimitive<B, D>,
+        rhs: TensorPrimitive<B, D>,
+    ) {
+        let value = match self.container.remove::<B, D>(&node_id.value) {
+            Some(tensor_old) => {
+                B::float_matmul_accumulate(tensor_old.into_primitive().tensor(), lhs, rhs)
+            }
+            None => B::float_matmul(lhs, rhs),
+        };
+
+        self.container.register::<B, D>(
+            node_id.value,
+            Tensor::from_primitive(burn_tensor::TensorPrimitive::Float(value)),
+        );
+    }
+
+    /// Replace the grad tensor of the node by the one returned by the hook, if any.
+    ///
+    /// The hook isn't called when no gradient was registered for the node.
+    pub(crate) fn apply_hook<B: Backend, const D: usize>(
+        &mut self,
+        node_id: NodeID,
+        hook: impl FnOnce(&TensorPrimitive<B, D>) -> Option<TensorPrimitive<B, D>>,
+    ) {
+        if let Some(tensor) = self.container.remove::<B, D>(&node_id.value) {
+            let grad = tensor.into_primitive().tensor();
+            let grad = hook(&grad).unwrap_or(grad);
+
+            self.container.register::<B, D>(
+                node_id.value,
+                Tensor::from_primitive(burn_tensor::TensorPrimitive::Float(grad)),
+            );
+        }
+    }
+
     /// Register a grad tensor in the container.
     ///
     /// If the tensor already exists, add both tensors together before saving the result.
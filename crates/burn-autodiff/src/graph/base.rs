@@ -1,11 +1,17 @@
 use super::NodeID;
 use crate::{checkpoint::base::Checkpointer, grads::Gradients};
-use std::collections::HashMap;
 
 /// Backward step for reverse mode autodiff.
 pub trait Step: Send + std::fmt::Debug {
     /// Executes the step and consumes it.
     fn step(self: Box<Self>, grads: &mut Gradients, checkpointer: &mut Checkpointer);
+    /// Clones the step, so that it can be executed again by a later backward pass.
+    ///
+    /// Steps returning `None`, the default, can't be reached by a backward pass retaining the
+    /// graph.
+    fn clone_boxed(&self) -> Option<StepBoxed> {
+        None
+    }
     /// Depth of the operation relative to the first node added to a graph.
     fn depth(&self) -> usize;
     /// The node associated to the step.
@@ -15,4 +21,3 @@ pub trait Step: Send + std::fmt::Debug {
 }
 
 pub type StepBoxed = Box<dyn Step>;
-pub type NodeSteps = HashMap<NodeID, StepBoxed>;
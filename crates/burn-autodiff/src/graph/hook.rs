@@ -0,0 +1,62 @@
+use std::{
+    marker::PhantomData,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+
+use burn_tensor::backend::Backend;
+
+use super::NodeID;
+use crate::grads::Gradients;
+
+/// A callback observing or rewriting the gradient of a node during the backward pass.
+pub trait GradHook: Send {
+    /// Calls the hook with the gradient of its node, once all the contributions to it are
+    /// accumulated.
+    fn apply(&mut self, grads: &mut Gradients);
+}
+
+pub type GradHookBoxed = Box<dyn GradHook>;
+
+/// A handle to a gradient hook registered on a tensor.
+#[derive(Debug, Clone)]
+pub struct GradHookHandle {
+    active: Arc<AtomicBool>,
+}
+
+impl GradHookHandle {
+    /// Removes the hook, which won't be called by the backward passes that follow.
+    pub fn remove(&self) {
+        self.active.store(false, Ordering::Relaxed);
+    }
+}
+
+#[derive(new)]
+pub(crate) struct FloatGradHook<B, const D: usize, F> {
+    node: NodeID,
+    func: F,
+    active: Arc<AtomicBool>,
+    _backend: PhantomData<B>,
+}
+
+impl<B, const D: usize, F> FloatGradHook<B, D, F> {
+    pub(crate) fn handle(&self) -> GradHookHandle {
+        GradHookHandle {
+            active: self.active.clone(),
+        }
+    }
+}
+
+impl<B, const D: usize, F> GradHook for FloatGradHook<B, D, F>
+where
+    B: Backend,
+    F: FnMut(&B::FloatTensorPrimitive<D>) -> Option<B::FloatTensorPrimitive<D>> + Send,
+{
+    fn apply(&mut self, grads: &mut Gradients) {
+        if self.active.load(Ordering::Relaxed) {
+            grads.apply_hook::<B, D>(self.node, &mut self.func);
+        }
+    }
+}
@@ -17,8 +17,24 @@ impl BreadthFirstSearch {
         root_id: NodeID,
         root_step: I,
         steps: &mut HashMap<NodeID, I>,
+        callback: F,
+    ) where
+        F: FnMut(NodeID, I),
+        I: TraversalItem,
+    {
+        self.traverse_with(root_id, root_step, |id| steps.remove(&id), callback)
+    }
+
+    /// Traverse the graph of backward steps from a root node, getting the step of each node
+    /// reached for the first time with `take`.
+    pub fn traverse_with<T, F, I>(
+        &self,
+        root_id: NodeID,
+        root_step: I,
+        mut take: T,
         mut callback: F,
     ) where
+        T: FnMut(NodeID) -> Option<I>,
         F: FnMut(NodeID, I),
         I: TraversalItem,
     {
@@ -31,7 +47,11 @@ impl BreadthFirstSearch {
         callback(root_id, root_step);
 
         while let Some(id) = parents.pop() {
-            let step = match steps.remove(&id) {
+            if visited.contains(&id) {
+                continue;
+            }
+
+            let step = match take(id) {
                 Some(step) => step,
                 None => continue,
             };
@@ -39,10 +59,6 @@ impl BreadthFirstSearch {
             let step_node = step.id();
             let step_parents = step.parents();
 
-            if visited.contains(&step_node) {
-                continue;
-            }
-
             visited.insert(step_node);
 
             for id in step_parents.iter() {
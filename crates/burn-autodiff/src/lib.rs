@@ -21,7 +21,7 @@ pub mod ops;
 
 pub(crate) mod graph;
 // Exported for backend extension
-pub use graph::NodeID;
+pub use graph::{GradHookHandle, NodeID};
 pub(crate) mod tensor;
 pub(crate) mod utils;
 
@@ -17,7 +17,7 @@ use burn_tensor::{
 
 impl<B: Backend, C: CheckpointStrategy> ActivationOps<Autodiff<B, C>> for Autodiff<B, C> {
     fn gelu<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct Gelu<const D: usize>;
 
         retro_unary!(RetroGelu, B::gelu);
@@ -25,6 +25,10 @@ impl<B: Backend, C: CheckpointStrategy> ActivationOps<Autodiff<B, C>> for Autodi
         impl<const D: usize, B: Backend> Backward<B, D, 1> for Gelu<D> {
             type State = NodeID;
 
+            fn clone_backward(&self) -> Option<Self> {
+                Some(self.clone())
+            }
+
             fn backward(
                 self,
                 ops: Ops<Self::State, 1>,
@@ -55,7 +59,7 @@ impl<B: Backend, C: CheckpointStrategy> ActivationOps<Autodiff<B, C>> for Autodi
     }
 
     fn relu<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct Relu;
 
         retro_unary!(RetroRelu, B::relu);
@@ -63,6 +67,10 @@ impl<B: Backend, C: CheckpointStrategy> ActivationOps<Autodiff<B, C>> for Autodi
         impl<B: Backend, const D: usize> Backward<B, D, 1> for Relu {
             type State = NodeID;
 
+            fn clone_backward(&self) -> Option<Self> {
+                Some(self.clone())
+            }
+
             fn backward(
                 self,
                 ops: Ops<Self::State, 1>,
@@ -92,7 +100,7 @@ impl<B: Backend, C: CheckpointStrategy> ActivationOps<Autodiff<B, C>> for Autodi
     }
 
     fn sigmoid<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct Sigmoid;
 
         retro_unary!(RetroSigmoid, B::sigmoid);
@@ -100,6 +108,10 @@ impl<B: Backend, C: CheckpointStrategy> ActivationOps<Autodiff<B, C>> for Autodi
         impl<B: Backend, const D: usize> Backward<B, D, 1> for Sigmoid {
             type State = NodeID;
 
+            fn clone_backward(&self) -> Option<Self> {
+                Some(self.clone())
+            }
+
             fn backward(
                 self,
                 ops: Ops<Self::State, 1>,
@@ -130,7 +142,7 @@ impl<B: Backend, C: CheckpointStrategy> ActivationOps<Autodiff<B, C>> for Autodi
     }
 
     fn log_sigmoid<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct LogSigmoid<const D: usize>;
 
         retro_unary!(RetroLogSigmoid, B::log_sigmoid);
@@ -138,6 +150,10 @@ impl<B: Backend, C: CheckpointStrategy> ActivationOps<Autodiff<B, C>> for Autodi
         impl<const D: usize, B: Backend> Backward<B, D, 1> for LogSigmoid<D> {
             type State = NodeID;
 
+            fn clone_backward(&self) -> Option<Self> {
+                Some(self.clone())
+            }
+
             fn backward(
                 self,
                 ops: Ops<Self::State, 1>,
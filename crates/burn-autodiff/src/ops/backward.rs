@@ -22,6 +22,15 @@ where
     /// Associated type to compute the backward pass.
     type State: Clone + Send + std::fmt::Debug + 'static;
 
+    /// Clones the operation, so that it can be executed again by a backward pass retaining the
+    /// graph.
+    ///
+    /// Operations returning `None`, the default, can't be reached by
+    /// [backward_retained](burn_tensor::backend::AutodiffBackend::backward_retained).
+    fn clone_backward(&self) -> Option<Self> {
+        None
+    }
+
     /// The backward pass.
     fn backward(
         self,
@@ -7,7 +7,7 @@ use crate::{
         strategy::CheckpointStrategy,
     },
     grads::Gradients,
-    graph::{ComputingProperty, NodeID, NodeRef, Requirement, Step},
+    graph::{ComputingProperty, NodeID, NodeRef, Requirement, Step, StepBoxed},
     tensor::AutodiffTensor,
 };
 use burn_tensor::{backend::Backend, Shape};
@@ -230,7 +230,7 @@ pub enum OpsKind<BO, B, S, C, const D: usize, const N: usize> {
 }
 
 /// Operation containing its parent nodes, its own node and the backward step state.
-#[derive(new, Debug)]
+#[derive(new, Debug, Clone)]
 pub struct Ops<S, const N: usize> {
     /// Parents nodes.
     pub parents: [Option<NodeRef>; N],
@@ -263,6 +263,12 @@ where
         self.backward.backward(self.ops, grads, checkpointer);
     }
 
+    fn clone_boxed(&self) -> Option<StepBoxed> {
+        let backward = self.backward.clone_backward()?;
+
+        Some(Box::new(Self::new(self.ops.clone(), backward)))
+    }
+
     fn node(&self) -> NodeID {
         self.ops.node.id
     }
@@ -276,7 +282,7 @@ where
     }
 }
 
-#[derive(new, Debug)]
+#[derive(new, Debug, Clone)]
 struct UntrackedOpsStep<const N: usize> {
     ops: Ops<(), N>,
 }
@@ -286,6 +292,10 @@ impl<const N: usize> Step for UntrackedOpsStep<N> {
         // Nothing to do
     }
 
+    fn clone_boxed(&self) -> Option<StepBoxed> {
+        Some(Box::new(self.clone()))
+    }
+
     fn node(&self) -> NodeID {
         self.ops.node.id
     }
@@ -75,6 +75,18 @@ impl<B: Backend, C: CheckpointStrategy> BoolTensorOps<Self> for Autodiff<B, C> {
         B::bool_not(tensor)
     }
 
+    fn bool_and<const D: usize>(lhs: BoolTensor<B, D>, rhs: BoolTensor<B, D>) -> BoolTensor<B, D> {
+        B::bool_and(lhs, rhs)
+    }
+
+    fn bool_or<const D: usize>(lhs: BoolTensor<B, D>, rhs: BoolTensor<B, D>) -> BoolTensor<B, D> {
+        B::bool_or(lhs, rhs)
+    }
+
+    fn bool_xor<const D: usize>(lhs: BoolTensor<B, D>, rhs: BoolTensor<B, D>) -> BoolTensor<B, D> {
+        B::bool_xor(lhs, rhs)
+    }
+
     fn bool_into_float<const D: usize>(
         tensor: BoolTensor<B, D>,
     ) -> <Autodiff<B> as Backend>::FloatTensorPrimitive<D> {
@@ -121,6 +133,13 @@ impl<B: Backend, C: CheckpointStrategy> BoolTensorOps<Self> for Autodiff<B, C> {
         B::bool_argwhere(tensor).await
     }
 
+    fn bool_argwhere_padded<const D: usize>(
+        tensor: BoolTensor<B, D>,
+        max_count: usize,
+    ) -> (IntTensor<B, 2>, IntTensor<B, 1>) {
+        B::bool_argwhere_padded(tensor, max_count)
+    }
+
     async fn bool_nonzero<const D: usize>(tensor: BoolTensor<B, D>) -> Vec<IntTensor<B, 1>> {
         B::bool_nonzero(tensor).await
     }
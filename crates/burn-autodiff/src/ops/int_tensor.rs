@@ -2,8 +2,8 @@ use crate::{checkpoint::strategy::CheckpointStrategy, tensor::AutodiffTensor, Au
 
 use burn_tensor::{
     backend::Backend,
-    ops::{BoolTensor, IntTensor, IntTensorOps},
-    Device, Distribution, Shape, TensorData,
+    ops::{BoolTensor, IntElem, IntTensor, IntTensorOps},
+    Device, Distribution, PadMode, Shape, TensorData,
 };
 
 impl<B: Backend, C: CheckpointStrategy> IntTensorOps<Self> for Autodiff<B, C> {
@@ -111,6 +111,10 @@ impl<B: Backend, C: CheckpointStrategy> IntTensorOps<Self> for Autodiff<B, C> {
         B::int_mul_scalar(lhs, rhs)
     }
 
+    fn int_matmul<const D: usize>(lhs: IntTensor<B, D>, rhs: IntTensor<B, D>) -> IntTensor<B, D> {
+        B::int_matmul(lhs, rhs)
+    }
+
     fn int_div<const D: usize>(lhs: IntTensor<B, D>, rhs: IntTensor<B, D>) -> IntTensor<B, D> {
         B::int_div(lhs, rhs)
     }
@@ -119,6 +123,13 @@ impl<B: Backend, C: CheckpointStrategy> IntTensorOps<Self> for Autodiff<B, C> {
         B::int_div_scalar(lhs, rhs)
     }
 
+    fn int_remainder<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntTensor<B, D>,
+    ) -> IntTensor<B, D> {
+        B::int_remainder(lhs, rhs)
+    }
+
     fn int_remainder_scalar<const D: usize>(
         lhs: IntTensor<B, D>,
         rhs: B::IntElem,
@@ -126,6 +137,14 @@ impl<B: Backend, C: CheckpointStrategy> IntTensorOps<Self> for Autodiff<B, C> {
         B::int_remainder_scalar(lhs, rhs)
     }
 
+    fn int_powi<const D: usize>(lhs: IntTensor<B, D>, rhs: IntTensor<B, D>) -> IntTensor<B, D> {
+        B::int_powi(lhs, rhs)
+    }
+
+    fn int_powi_scalar<const D: usize>(lhs: IntTensor<B, D>, rhs: B::IntElem) -> IntTensor<B, D> {
+        B::int_powi_scalar(lhs, rhs)
+    }
+
     fn int_neg<const D: usize>(tensor: IntTensor<B, D>) -> IntTensor<B, D> {
         B::int_neg(tensor)
     }
@@ -248,6 +267,16 @@ impl<B: Backend, C: CheckpointStrategy> IntTensorOps<Self> for Autodiff<B, C> {
         B::int_select_assign(tensor, dim, indices, value)
     }
 
+    fn int_index_put<const D: usize>(
+        tensor: IntTensor<B, D>,
+        dim: usize,
+        indices: IntTensor<B, 1>,
+        values: IntTensor<B, D>,
+        accumulate: bool,
+    ) -> IntTensor<B, D> {
+        B::int_index_put(tensor, dim, indices, values, accumulate)
+    }
+
     fn int_mask_where<const D: usize>(
         tensor: IntTensor<B, D>,
         mask: BoolTensor<B, D>,
@@ -399,4 +428,86 @@ impl<B: Backend, C: CheckpointStrategy> IntTensorOps<Self> for Autodiff<B, C> {
     ) -> IntTensor<Self, D> {
         B::int_argsort(tensor, dim, descending)
     }
+
+    fn int_pad<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        padding: [(isize, isize); D],
+        mode: PadMode<IntElem<Self>>,
+    ) -> IntTensor<Self, D> {
+        B::int_pad(tensor, padding, mode)
+    }
+
+    fn int_bitwise_and<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntTensor<B, D>,
+    ) -> IntTensor<B, D> {
+        B::int_bitwise_and(lhs, rhs)
+    }
+
+    fn int_bitwise_and_scalar<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: B::IntElem,
+    ) -> IntTensor<B, D> {
+        B::int_bitwise_and_scalar(lhs, rhs)
+    }
+
+    fn int_bitwise_or<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntTensor<B, D>,
+    ) -> IntTensor<B, D> {
+        B::int_bitwise_or(lhs, rhs)
+    }
+
+    fn int_bitwise_or_scalar<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: B::IntElem,
+    ) -> IntTensor<B, D> {
+        B::int_bitwise_or_scalar(lhs, rhs)
+    }
+
+    fn int_bitwise_xor<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntTensor<B, D>,
+    ) -> IntTensor<B, D> {
+        B::int_bitwise_xor(lhs, rhs)
+    }
+
+    fn int_bitwise_xor_scalar<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: B::IntElem,
+    ) -> IntTensor<B, D> {
+        B::int_bitwise_xor_scalar(lhs, rhs)
+    }
+
+    fn int_bitwise_left_shift<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntTensor<B, D>,
+    ) -> IntTensor<B, D> {
+        B::int_bitwise_left_shift(lhs, rhs)
+    }
+
+    fn int_bitwise_left_shift_scalar<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: B::IntElem,
+    ) -> IntTensor<B, D> {
+        B::int_bitwise_left_shift_scalar(lhs, rhs)
+    }
+
+    fn int_bitwise_right_shift<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntTensor<B, D>,
+    ) -> IntTensor<B, D> {
+        B::int_bitwise_right_shift(lhs, rhs)
+    }
+
+    fn int_bitwise_right_shift_scalar<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: B::IntElem,
+    ) -> IntTensor<B, D> {
+        B::int_bitwise_right_shift_scalar(lhs, rhs)
+    }
+
+    fn int_bitwise_not<const D: usize>(tensor: IntTensor<B, D>) -> IntTensor<B, D> {
+        B::int_bitwise_not(tensor)
+    }
 }
@@ -4,10 +4,12 @@ mod base;
 mod bool_tensor;
 mod int_tensor;
 mod module;
+mod optim;
 mod qtensor;
 mod tensor;
 
 pub(crate) mod maxmin;
+pub(crate) mod scan;
 pub(crate) mod sort;
 
 pub use backward::*;
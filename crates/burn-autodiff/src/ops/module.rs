@@ -13,12 +13,16 @@ use super::OpsKind;
 
 impl<B: Backend, C: CheckpointStrategy> ModuleOps<Autodiff<B, C>> for Autodiff<B, C> {
     fn embedding(weights: AutodiffTensor<B, 2>, indices: IntTensor<B, 2>) -> AutodiffTensor<B, 3> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct Embedding;
 
         impl<B: Backend> Backward<B, 3, 1> for Embedding {
             type State = (B::FloatTensorPrimitive<2>, IntTensor<B, 2>);
 
+            fn clone_backward(&self) -> Option<Self> {
+                Some(self.clone())
+            }
+
             fn backward(
                 self,
                 ops: Ops<Self::State, 1>,
@@ -54,20 +58,62 @@ impl<B: Backend, C: CheckpointStrategy> ModuleOps<Autodiff<B, C>> for Autodiff<B
         panic!("Can't differentiate embedding backward.");
     }
 
+    fn dropout<const D: usize>(x: AutodiffTensor<B, D>, prob: f64) -> AutodiffTensor<B, D> {
+        #[derive(Debug, Clone)]
+        struct Dropout;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Dropout {
+            type State = B::FloatTensorPrimitive<D>;
+
+            fn clone_backward(&self) -> Option<Self> {
+                Some(self.clone())
+            }
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                let mask = ops.state;
+
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    B::float_mul(grad, mask)
+                });
+            }
+        }
+
+        match Dropout.prepare::<C>([x.node]).compute_bound().stateful() {
+            OpsKind::Tracked(prep) => {
+                // The scaled mask is drawn by the backend, then kept to scale the gradient.
+                let shape = B::float_shape(&x.primitive);
+                let device = B::float_device(&x.primitive);
+                let mask = B::dropout(B::float_ones(shape, &device), prob);
+
+                prep.finish(mask.clone(), B::float_mul(x.primitive, mask))
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::dropout(x.primitive, prob)),
+        }
+    }
+
     fn conv1d(
         x: AutodiffTensor<B, 3>,
         weight: AutodiffTensor<B, 3>,
         bias: Option<AutodiffTensor<B, 1>>,
         options: ConvOptions<1>,
     ) -> AutodiffTensor<B, 3> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct Conv1DWithBias;
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct Conv1DNoBias;
 
         impl<B: Backend> Backward<B, 3, 3> for Conv1DWithBias {
             type State = (NodeID, NodeID, NodeID, ConvOptions<1>);
 
+            fn clone_backward(&self) -> Option<Self> {
+                Some(self.clone())
+            }
+
             fn backward(
                 self,
                 ops: Ops<Self::State, 3>,
@@ -99,6 +145,10 @@ impl<B: Backend, C: CheckpointStrategy> ModuleOps<Autodiff<B, C>> for Autodiff<B
         impl<B: Backend> Backward<B, 3, 2> for Conv1DNoBias {
             type State = (NodeID, NodeID, ConvOptions<1>);
 
+            fn clone_backward(&self) -> Option<Self> {
+                Some(self.clone())
+            }
+
             fn backward(
                 self,
                 ops: Ops<Self::State, 2>,
@@ -170,14 +220,18 @@ impl<B: Backend, C: CheckpointStrategy> ModuleOps<Autodiff<B, C>> for Autodiff<B
         bias: Option<AutodiffTensor<B, 1>>,
         options: ConvTransposeOptions<1>,
     ) -> AutodiffTensor<B, 3> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct ConvTranspose1DWithBias;
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct ConvTranspose1DNoBias;
 
         impl<B: Backend> Backward<B, 3, 3> for ConvTranspose1DWithBias {
             type State = (NodeID, NodeID, NodeID, ConvTransposeOptions<1>);
 
+            fn clone_backward(&self) -> Option<Self> {
+                Some(self.clone())
+            }
+
             fn backward(
                 self,
                 ops: Ops<Self::State, 3>,
@@ -209,6 +263,10 @@ impl<B: Backend, C: CheckpointStrategy> ModuleOps<Autodiff<B, C>> for Autodiff<B
         impl<B: Backend> Backward<B, 3, 2> for ConvTranspose1DNoBias {
             type State = (NodeID, NodeID, ConvTransposeOptions<1>);
 
+            fn clone_backward(&self) -> Option<Self> {
+                Some(self.clone())
+            }
+
             fn backward(
                 self,
                 ops: Ops<Self::State, 2>,
@@ -289,14 +347,18 @@ impl<B: Backend, C: CheckpointStrategy> ModuleOps<Autodiff<B, C>> for Autodiff<B
         bias: Option<AutodiffTensor<B, 1>>,
         options: ConvOptions<2>,
     ) -> AutodiffTensor<B, 4> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct Conv2DWithBias;
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct Conv2DNoBias;
 
         impl<B: Backend> Backward<B, 4, 3> for Conv2DWithBias {
             type State = (NodeID, NodeID, NodeID, ConvOptions<2>);
 
+            fn clone_backward(&self) -> Option<Self> {
+                Some(self.clone())
+            }
+
             fn backward(
                 self,
                 ops: Ops<Self::State, 3>,
@@ -328,6 +390,10 @@ impl<B: Backend, C: CheckpointStrategy> ModuleOps<Autodiff<B, C>> for Autodiff<B
         impl<B: Backend> Backward<B, 4, 2> for Conv2DNoBias {
             type State = (NodeID, NodeID, ConvOptions<2>);
 
+            fn clone_backward(&self) -> Option<Self> {
+                Some(self.clone())
+            }
+
             fn backward(
                 self,
                 ops: Ops<Self::State, 2>,
@@ -401,14 +467,18 @@ impl<B: Backend, C: CheckpointStrategy> ModuleOps<Autodiff<B, C>> for Autodiff<B
         bias: Option<AutodiffTensor<B, 1>>,
         options: ConvTransposeOptions<2>,
     ) -> AutodiffTensor<B, 4> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct ConvTranspose2DWithBias;
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct ConvTranspose2DNoBias;
 
         impl<B: Backend> Backward<B, 4, 3> for ConvTranspose2DWithBias {
             type State = (NodeID, NodeID, NodeID, ConvTransposeOptions<2>);
 
+            fn clone_backward(&self) -> Option<Self> {
+                Some(self.clone())
+            }
+
             fn backward(
                 self,
                 ops: Ops<Self::State, 3>,
@@ -440,6 +510,10 @@ impl<B: Backend, C: CheckpointStrategy> ModuleOps<Autodiff<B, C>> for Autodiff<B
         impl<B: Backend> Backward<B, 4, 2> for ConvTranspose2DNoBias {
             type State = (NodeID, NodeID, ConvTransposeOptions<2>);
 
+            fn clone_backward(&self) -> Option<Self> {
+                Some(self.clone())
+            }
+
             fn backward(
                 self,
                 ops: Ops<Self::State, 2>,
@@ -522,14 +596,18 @@ impl<B: Backend, C: CheckpointStrategy> ModuleOps<Autodiff<B, C>> for Autodiff<B
         bias: Option<AutodiffTensor<B, 1>>,
         options: ConvOptions<3>,
     ) -> AutodiffTensor<B, 5> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct Conv3DWithBias;
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct Conv3DNoBias;
 
         impl<B: Backend> Backward<B, 5, 3> for Conv3DWithBias {
             type State = (NodeID, NodeID, NodeID, ConvOptions<3>);
 
+            fn clone_backward(&self) -> Option<Self> {
+                Some(self.clone())
+            }
+
             fn backward(
                 self,
                 ops: Ops<Self::State, 3>,
@@ -561,6 +639,10 @@ impl<B: Backend, C: CheckpointStrategy> ModuleOps<Autodiff<B, C>> for Autodiff<B
         impl<B: Backend> Backward<B, 5, 2> for Conv3DNoBias {
             type State = (NodeID, NodeID, ConvOptions<3>);
 
+            fn clone_backward(&self) -> Option<Self> {
+                Some(self.clone())
+            }
+
             fn backward(
                 self,
                 ops: Ops<Self::State, 2>,
@@ -634,14 +716,18 @@ impl<B: Backend, C: CheckpointStrategy> ModuleOps<Autodiff<B, C>> for Autodiff<B
         bias: Option<AutodiffTensor<B, 1>>,
         options: ConvTransposeOptions<3>,
     ) -> AutodiffTensor<B, 5> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct ConvTranspose3DWithBias;
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct ConvTranspose3DNoBias;
 
         impl<B: Backend> Backward<B, 5, 3> for ConvTranspose3DWithBias {
             type State = (NodeID, NodeID, NodeID, ConvTransposeOptions<3>);
 
+            fn clone_backward(&self) -> Option<Self> {
+                Some(self.clone())
+            }
+
             fn backward(
                 self,
                 ops: Ops<Self::State, 3>,
@@ -673,6 +759,10 @@ impl<B: Backend, C: CheckpointStrategy> ModuleOps<Autodiff<B, C>> for Autodiff<B
         impl<B: Backend> Backward<B, 5, 2> for ConvTranspose3DNoBias {
             type State = (NodeID, NodeID, ConvTransposeOptions<3>);
 
+            fn clone_backward(&self) -> Option<Self> {
+                Some(self.clone())
+            }
+
             fn backward(
                 self,
                 ops: Ops<Self::State, 2>,
@@ -770,12 +860,17 @@ impl<B: Backend, C: CheckpointStrategy> ModuleOps<Autodiff<B, C>> for Autodiff<B
         stride: usize,
         padding: usize,
         count_include_pad: bool,
+        ceil_mode: bool,
     ) -> AutodiffTensor<B, 3> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct AvgPool1D;
 
         impl<B: Backend> Backward<B, 3, 1> for AvgPool1D {
-            type State = (NodeID, usize, usize, usize, bool);
+            type State = (NodeID, usize, usize, usize, bool, bool);
+
+            fn clone_backward(&self) -> Option<Self> {
+                Some(self.clone())
+            }
 
             fn backward(
                 self,
@@ -785,7 +880,8 @@ impl<B: Backend, C: CheckpointStrategy> ModuleOps<Autodiff<B, C>> for Autodiff<B
             ) {
                 let [node_parent] = ops.parents;
                 let grad = grads.consume::<B, 3>(&ops.node);
-                let (x_state, kernel_size, stride, padding, count_include_pad) = ops.state;
+                let (x_state, kernel_size, stride, padding, count_include_pad, ceil_mode) =
+                    ops.state;
                 let x = checkpointer.retrieve_node_output(x_state);
 
                 if let Some(node) = node_parent {
@@ -796,6 +892,7 @@ impl<B: Backend, C: CheckpointStrategy> ModuleOps<Autodiff<B, C>> for Autodiff<B
                         stride,
                         padding,
                         count_include_pad,
+                        ceil_mode,
                     );
                     grads.register::<B, 3>(node.id, grad);
                 }
@@ -810,13 +907,21 @@ impl<B: Backend, C: CheckpointStrategy> ModuleOps<Autodiff<B, C>> for Autodiff<B
             OpsKind::Tracked(mut prep) => {
                 let x_state = prep.checkpoint(&x);
                 prep.finish(
-                    (x_state, kernel_size, stride, padding, count_include_pad),
+                    (
+                        x_state,
+                        kernel_size,
+                        stride,
+                        padding,
+                        count_include_pad,
+                        ceil_mode,
+                    ),
                     B::avg_pool1d(
                         x.primitive.clone(),
                         kernel_size,
                         stride,
                         padding,
                         count_include_pad,
+                        ceil_mode,
                     ),
                 )
             }
@@ -826,6 +931,7 @@ impl<B: Backend, C: CheckpointStrategy> ModuleOps<Autodiff<B, C>> for Autodiff<B
                 stride,
                 padding,
                 count_include_pad,
+                ceil_mode,
             )),
         }
     }
@@ -836,12 +942,17 @@ impl<B: Backend, C: CheckpointStrategy> ModuleOps<Autodiff<B, C>> for Autodiff<B
         stride: [usize; 2],
         padding: [usize; 2],
         count_include_pad: bool,
+        ceil_mode: bool,
     ) -> AutodiffTensor<B, 4> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct AvgPool2D;
 
         impl<B: Backend> Backward<B, 4, 1> for AvgPool2D {
-            type State = (NodeID, [usize; 2], [usize; 2], [usize; 2], bool);
+            type State = (NodeID, [usize; 2], [usize; 2], [usize; 2], bool, bool);
+
+            fn clone_backward(&self) -> Option<Self> {
+                Some(self.clone())
+            }
 
             fn backward(
                 self,
@@ -851,7 +962,8 @@ impl<B: Backend, C: CheckpointStrategy> ModuleOps<Autodiff<B, C>> for Autodiff<B
             ) {
                 let [node_parent] = ops.parents;
                 let grad = grads.consume::<B, 4>(&ops.node);
-                let (x_state, kernel_size, stride, padding, count_include_pad) = ops.state;
+                let (x_state, kernel_size, stride, padding, count_include_pad, ceil_mode) =
+                    ops.state;
                 let x = checkpointer.retrieve_node_output(x_state);
 
                 if let Some(node) = node_parent {
@@ -862,6 +974,7 @@ impl<B: Backend, C: CheckpointStrategy> ModuleOps<Autodiff<B, C>> for Autodiff<B
                         stride,
                         padding,
                         count_include_pad,
+                        ceil_mode,
                     );
                     grads.register::<B, 4>(node.id, grad);
                 }
@@ -876,13 +989,21 @@ impl<B: Backend, C: CheckpointStrategy> ModuleOps<Autodiff<B, C>> for Autodiff<B
             OpsKind::Tracked(mut prep) => {
                 let x_state = prep.checkpoint(&x);
                 prep.finish(
-                    (x_state, kernel_size, stride, padding, count_include_pad),
+                    (
+                        x_state,
+                        kernel_size,
+                        stride,
+                        padding,
+                        count_include_pad,
+                        ceil_mode,
+                    ),
                     B::avg_pool2d(
                         x.primitive.clone(),
                         kernel_size,
                         stride,
                         padding,
                         count_include_pad,
+                        ceil_mode,
                     ),
                 )
             }
@@ -892,6 +1013,7 @@ impl<B: Backend, C: CheckpointStrategy> ModuleOps<Autodiff<B, C>> for Autodiff<B
                 stride,
                 padding,
                 count_include_pad,
+                ceil_mode,
             )),
         }
     }
@@ -903,6 +1025,7 @@ impl<B: Backend, C: CheckpointStrategy> ModuleOps<Autodiff<B, C>> for Autodiff<B
         _stride: [usize; 2],
         _padding: [usize; 2],
         _count_include_pad: bool,
+        _ceil_mode: bool,
     ) -> AutodiffTensor<B, 4> {
         panic!("Can't differentiate avg pool 2d backward.");
     }
@@ -913,6 +1036,7 @@ impl<B: Backend, C: CheckpointStrategy> ModuleOps<Autodiff<B, C>> for Autodiff<B
         stride: usize,
         padding: usize,
         dilation: usize,
+        ceil_mode: bool,
     ) -> AutodiffTensor<B, 3> {
         match MaxPool1D
             .prepare::<C>([x.node.clone()])
@@ -921,8 +1045,14 @@ impl<B: Backend, C: CheckpointStrategy> ModuleOps<Autodiff<B, C>> for Autodiff<B
         {
             OpsKind::Tracked(mut prep) => {
                 let x_state = prep.checkpoint(&x);
-                let output =
-                    B::max_pool1d_with_indices(x.primitive, kernel_size, stride, padding, dilation);
+                let output = B::max_pool1d_with_indices(
+                    x.primitive,
+                    kernel_size,
+                    stride,
+                    padding,
+                    dilation,
+                    ceil_mode,
+                );
                 prep.finish(
                     (
                         x_state,
@@ -931,6 +1061,7 @@ impl<B: Backend, C: CheckpointStrategy> ModuleOps<Autodiff<B, C>> for Autodiff<B
                         stride,
                         padding,
                         dilation,
+                        ceil_mode,
                     ),
                     output.output,
                 )
@@ -941,6 +1072,7 @@ impl<B: Backend, C: CheckpointStrategy> ModuleOps<Autodiff<B, C>> for Autodiff<B
                 stride,
                 padding,
                 dilation,
+                ceil_mode,
             )),
         }
     }
@@ -951,6 +1083,7 @@ impl<B: Backend, C: CheckpointStrategy> ModuleOps<Autodiff<B, C>> for Autodiff<B
         stride: usize,
         padding: usize,
         dilation: usize,
+        ceil_mode: bool,
     ) -> MaxPool1dWithIndices<Self> {
         match MaxPool1D
             .prepare::<C>([x.node.clone()])
@@ -959,8 +1092,14 @@ impl<B: Backend, C: CheckpointStrategy> ModuleOps<Autodiff<B, C>> for Autodiff<B
         {
             OpsKind::Tracked(mut prep) => {
                 let x_state = prep.checkpoint(&x);
-                let output =
-                    B::max_pool1d_with_indices(x.primitive, kernel_size, stride, padding, dilation);
+                let output = B::max_pool1d_with_indices(
+                    x.primitive,
+                    kernel_size,
+                    stride,
+                    padding,
+                    dilation,
+                    ceil_mode,
+                );
 
                 let output_tensor = prep.finish(
                     (
@@ -970,6 +1109,7 @@ impl<B: Backend, C: CheckpointStrategy> ModuleOps<Autodiff<B, C>> for Autodiff<B
                         stride,
                         padding,
                         dilation,
+                        ceil_mode,
                     ),
                     output.output,
                 );
@@ -977,8 +1117,14 @@ impl<B: Backend, C: CheckpointStrategy> ModuleOps<Autodiff<B, C>> for Autodiff<B
                 MaxPool1dWithIndices::new(output_tensor, output.indices)
             }
             OpsKind::UnTracked(prep) => {
-                let output =
-                    B::max_pool1d_with_indices(x.primitive, kernel_size, stride, padding, dilation);
+                let output = B::max_pool1d_with_indices(
+                    x.primitive,
+                    kernel_size,
+                    stride,
+                    padding,
+                    dilation,
+                    ceil_mode,
+                );
                 let output_tensor = prep.finish(output.output);
 
                 MaxPool1dWithIndices::new(output_tensor, output.indices)
@@ -992,6 +1138,7 @@ impl<B: Backend, C: CheckpointStrategy> ModuleOps<Autodiff<B, C>> for Autodiff<B
         stride: usize,
         padding: usize,
         dilation: usize,
+        ceil_mode: bool,
         output_grad: AutodiffTensor<B, 3>,
         indices: IntTensor<B, 3>,
     ) -> MaxPool1dBackward<Self> {
@@ -1001,6 +1148,7 @@ impl<B: Backend, C: CheckpointStrategy> ModuleOps<Autodiff<B, C>> for Autodiff<B
             stride,
             padding,
             dilation,
+            ceil_mode,
             output_grad.primitive,
             indices,
         );
@@ -1013,6 +1161,7 @@ impl<B: Backend, C: CheckpointStrategy> ModuleOps<Autodiff<B, C>> for Autodiff<B
         stride: [usize; 2],
         padding: [usize; 2],
         dilation: [usize; 2],
+        ceil_mode: bool,
     ) -> AutodiffTensor<B, 4> {
         match MaxPool2D
             .prepare::<C>([x.node.clone()])
@@ -1021,8 +1170,14 @@ impl<B: Backend, C: CheckpointStrategy> ModuleOps<Autodiff<B, C>> for Autodiff<B
         {
             OpsKind::Tracked(mut prep) => {
                 let x_state = prep.checkpoint(&x);
-                let output =
-                    B::max_pool2d_with_indices(x.primitive, kernel_size, stride, padding, dilation);
+                let output = B::max_pool2d_with_indices(
+                    x.primitive,
+                    kernel_size,
+                    stride,
+                    padding,
+                    dilation,
+                    ceil_mode,
+                );
                 prep.finish(
                     (
                         x_state,
@@ -1031,6 +1186,7 @@ impl<B: Backend, C: CheckpointStrategy> ModuleOps<Autodiff<B, C>> for Autodiff<B
                         stride,
                         padding,
                         dilation,
+                        ceil_mode,
                     ),
                     output.output,
                 )
@@ -1041,6 +1197,7 @@ impl<B: Backend, C: CheckpointStrategy> ModuleOps<Autodiff<B, C>> for Autodiff<B
                 stride,
                 padding,
                 dilation,
+                ceil_mode,
             )),
         }
     }
@@ -1051,6 +1208,7 @@ impl<B: Backend, C: CheckpointStrategy> ModuleOps<Autodiff<B, C>> for Autodiff<B
         stride: [usize; 2],
         padding: [usize; 2],
         dilation: [usize; 2],
+        ceil_mode: bool,
     ) -> MaxPool2dWithIndices<Self> {
         match MaxPool2D
             .prepare::<C>([x.node.clone()])
@@ -1060,8 +1218,14 @@ impl<B: Backend, C: CheckpointStrategy> ModuleOps<Autodiff<B, C>> for Autodiff<B
             OpsKind::Tracked(mut prep) => {
                 let x_state = prep.checkpoint(&x);
 
-                let output =
-                    B::max_pool2d_with_indices(x.primitive, kernel_size, stride, padding, dilation);
+                let output = B::max_pool2d_with_indices(
+                    x.primitive,
+                    kernel_size,
+                    stride,
+                    padding,
+                    dilation,
+                    ceil_mode,
+                );
 
                 let output_tensor = prep.finish(
                     (
@@ -1071,6 +1235,7 @@ impl<B: Backend, C: CheckpointStrategy> ModuleOps<Autodiff<B, C>> for Autodiff<B
                         stride,
                         padding,
                         dilation,
+                        ceil_mode,
                     ),
                     output.output,
                 );
@@ -1078,8 +1243,14 @@ impl<B: Backend, C: CheckpointStrategy> ModuleOps<Autodiff<B, C>> for Autodiff<B
                 MaxPool2dWithIndices::new(output_tensor, output.indices)
             }
             OpsKind::UnTracked(prep) => {
-                let output =
-                    B::max_pool2d_with_indices(x.primitive, kernel_size, stride, padding, dilation);
+                let output = B::max_pool2d_with_indices(
+                    x.primitive,
+                    kernel_size,
+                    stride,
+                    padding,
+                    dilation,
+                    ceil_mode,
+                );
                 let output_tensor = prep.finish(output.output);
 
                 MaxPool2dWithIndices::new(output_tensor, output.indices)
@@ -1093,18 +1264,23 @@ impl<B: Backend, C: CheckpointStrategy> ModuleOps<Autodiff<B, C>> for Autodiff<B
         _stride: [usize; 2],
         _padding: [usize; 2],
         _dilation: [usize; 2],
+        _ceil_mode: bool,
         _output_grad: AutodiffTensor<B, 4>,
         _indices: IntTensor<B, 4>,
     ) -> MaxPool2dBackward<Self> {
         panic!("Can't differentiate max pool2d with indices backward.");
     }
     fn adaptive_avg_pool1d(x: AutodiffTensor<B, 3>, output_size: usize) -> AutodiffTensor<B, 3> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct AdaptiveAvgPool1D;
 
         impl<B: Backend> Backward<B, 3, 1> for AdaptiveAvgPool1D {
             type State = NodeID;
 
+            fn clone_backward(&self) -> Option<Self> {
+                Some(self.clone())
+            }
+
             fn backward(
                 self,
                 ops: Ops<Self::State, 1>,
@@ -1141,12 +1317,16 @@ impl<B: Backend, C: CheckpointStrategy> ModuleOps<Autodiff<B, C>> for Autodiff<B
         x: AutodiffTensor<B, 4>,
         output_size: [usize; 2],
     ) -> AutodiffTensor<B, 4> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct AdaptiveAvgPool2D;
 
         impl<B: Backend> Backward<B, 4, 1> for AdaptiveAvgPool2D {
             type State = NodeID;
 
+            fn clone_backward(&self) -> Option<Self> {
+                Some(self.clone())
+            }
+
             fn backward(
                 self,
                 ops: Ops<Self::State, 1>,
@@ -1191,11 +1371,15 @@ impl<B: Backend, C: CheckpointStrategy> ModuleOps<Autodiff<B, C>> for Autodiff<B
         output_size: [usize; 2],
         options: InterpolateOptions,
     ) -> AutodiffTensor<B, 4> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct Interpolate;
         impl<B: Backend> Backward<B, 4, 1> for Interpolate {
             type State = (NodeID, [usize; 2], InterpolateOptions);
 
+            fn clone_backward(&self) -> Option<Self> {
+                Some(self.clone())
+            }
+
             fn backward(
                 self,
                 ops: Ops<Self::State, 1>,
@@ -1241,11 +1425,15 @@ impl<B: Backend, C: CheckpointStrategy> ModuleOps<Autodiff<B, C>> for Autodiff<B
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 struct MaxPool1D;
 
 impl<B: Backend> Backward<B, 3, 1> for MaxPool1D {
-    type State = (NodeID, IntTensor<B, 3>, usize, usize, usize, usize);
+    type State = (NodeID, IntTensor<B, 3>, usize, usize, usize, usize, bool);
+
+    fn clone_backward(&self) -> Option<Self> {
+        Some(self.clone())
+    }
 
     fn backward(
         self,
@@ -1255,7 +1443,7 @@ impl<B: Backend> Backward<B, 3, 1> for MaxPool1D {
     ) {
         let [node_parent] = ops.parents;
         let grad = grads.consume::<B, 3>(&ops.node);
-        let (x_state, indices, kernel_size, stride, padding, dilation) = ops.state;
+        let (x_state, indices, kernel_size, stride, padding, dilation, ceil_mode) = ops.state;
         let x = checkpointer.retrieve_node_output(x_state);
 
         if let Some(node) = node_parent {
@@ -1265,6 +1453,7 @@ impl<B: Backend> Backward<B, 3, 1> for MaxPool1D {
                 stride,
                 padding,
                 dilation,
+                ceil_mode,
                 grad,
                 indices,
             );
@@ -1274,7 +1463,7 @@ impl<B: Backend> Backward<B, 3, 1> for MaxPool1D {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 struct MaxPool2D;
 
 impl<B: Backend> Backward<B, 4, 1> for MaxPool2D {
@@ -1285,8 +1474,13 @@ impl<B: Backend> Backward<B, 4, 1> for MaxPool2D {
         [usize; 2],
         [usize; 2],
         [usize; 2],
+        bool,
     );
 
+    fn clone_backward(&self) -> Option<Self> {
+        Some(self.clone())
+    }
+
     fn backward(
         self,
         ops: Ops<Self::State, 1>,
@@ -1295,7 +1489,7 @@ impl<B: Backend> Backward<B, 4, 1> for MaxPool2D {
     ) {
         let [node_parent] = ops.parents;
         let grad = grads.consume::<B, 4>(&ops.node);
-        let (x_state, indices, kernel_size, stride, padding, dilation) = ops.state;
+        let (x_state, indices, kernel_size, stride, padding, dilation, ceil_mode) = ops.state;
         let x = checkpointer.retrieve_node_output(x_state);
 
         if let Some(node) = node_parent {
@@ -1305,6 +1499,7 @@ impl<B: Backend> Backward<B, 4, 1> for MaxPool2D {
                 stride,
                 padding,
                 dilation,
+                ceil_mode,
                 grad,
                 indices,
             );
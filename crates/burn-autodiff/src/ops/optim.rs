@@ -0,0 +1,5 @@
+use burn_tensor::{backend::Backend, ops::OptimizerOps};
+
+use crate::{checkpoint::strategy::CheckpointStrategy, Autodiff};
+
+impl<B: Backend, C: CheckpointStrategy> OptimizerOps<Self> for Autodiff<B, C> {}
@@ -0,0 +1,84 @@
+use super::{Backward, Ops};
+use crate::{checkpoint::base::Checkpointer, grads::Gradients};
+use burn_tensor::backend::Backend;
+
+#[derive(Debug, Clone)]
+pub(crate) struct AssociativeScan;
+
+impl<B: Backend, const D: usize> Backward<B, D, 2> for AssociativeScan {
+    /// The coefficients, the output and the dimension of the recurrence.
+    type State = (
+        B::FloatTensorPrimitive<D>,
+        B::FloatTensorPrimitive<D>,
+        usize,
+    );
+
+    fn clone_backward(&self) -> Option<Self> {
+        Some(self.clone())
+    }
+
+    fn backward(
+        self,
+        ops: Ops<Self::State, 2>,
+        grads: &mut Gradients,
+        _checkpointer: &mut Checkpointer,
+    ) {
+        let [node_a, node_b] = ops.parents;
+        let grad = grads.consume::<B, D>(&ops.node);
+        let (a, output, dim) = ops.state;
+
+        // The gradient of `y[t]` follows the reverse recurrence
+        // `g[t] = grad[t] + a[t + 1] * g[t + 1]`, which is a scan of the reversed dimension.
+        let a_next = shift::<B, D>(a, dim, true);
+        let grad = B::float_flip(
+            B::float_associative_scan(
+                B::float_flip(a_next, &[dim]),
+                B::float_flip(grad, &[dim]),
+                dim,
+            ),
+            &[dim],
+        );
+
+        if let Some(node) = node_a {
+            let output_previous = shift::<B, D>(output, dim, false);
+            grads.register::<B, D>(node.id, B::float_mul(grad.clone(), output_previous));
+        }
+
+        if let Some(node) = node_b {
+            grads.register::<B, D>(node.id, grad);
+        }
+    }
+}
+
+/// Shift the tensor by one position along the dimension, backward when `next` so that the
+/// element `t` is the element `t + 1`, and forward otherwise, filling the vacant position with
+/// zero.
+fn shift<B: Backend, const D: usize>(
+    tensor: B::FloatTensorPrimitive<D>,
+    dim: usize,
+    next: bool,
+) -> B::FloatTensorPrimitive<D> {
+    let mut shape = B::float_shape(&tensor);
+    let device = B::float_device(&tensor);
+    let length = shape.dims[dim];
+
+    if length <= 1 {
+        return B::float_zeros(shape, &device);
+    }
+
+    let mut ranges = shape.dims.map(|dim| 0..dim);
+    ranges[dim] = match next {
+        true => 1..length,
+        false => 0..length - 1,
+    };
+    let tensor = B::float_slice::<D, D>(tensor, ranges);
+
+    shape.dims[dim] = 1;
+    let zeros = B::float_zeros(shape, &device);
+
+    let tensors = match next {
+        true => vec![tensor, zeros],
+        false => vec![zeros, tensor],
+    };
+    B::float_cat(tensors, dim)
+}
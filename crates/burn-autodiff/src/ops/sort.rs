@@ -2,12 +2,16 @@ use super::{unary, Backward, Ops};
 use crate::{checkpoint::base::Checkpointer, grads::Gradients};
 use burn_tensor::{backend::Backend, Shape};
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub(crate) struct SortDim;
 
 impl<B: Backend, const D: usize> Backward<B, D, 1> for SortDim {
     type State = (B::IntTensorPrimitive<D>, Shape<D>);
 
+    fn clone_backward(&self) -> Option<Self> {
+        Some(self.clone())
+    }
+
     fn backward(
         self,
         ops: Ops<Self::State, 1>,
@@ -6,7 +6,7 @@ use crate::{
         state::BackwardStates, strategy::CheckpointStrategy,
     },
     grads::Gradients,
-    graph::{ComputingProperty, NodeID, NodeRef, Requirement, Step},
+    graph::{ComputingProperty, NodeID, NodeRef, Requirement, Step, StepBoxed},
     ops::{binary, broadcast_shape, unary, Backward, Ops, OpsKind},
     retro_binary, retro_unary, retro_unary_scalar,
     tensor::AutodiffTensor,
@@ -17,7 +17,8 @@ use crate::{
 use burn_tensor::{
     backend::Backend,
     ops::{BoolTensor, FloatElem, FloatTensor, FloatTensorOps, IntTensor},
-    Device, ElementConversion, Shape, Tensor, TensorData, TensorPrimitive,
+    pad, Device, ElementConversion, Float, MemoryFormat, PadMode, Shape, Tensor, TensorData,
+    TensorPrimitive,
 };
 
 use super::maxmin::MaxMinDim;
@@ -62,12 +63,16 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         tensor: FloatTensor<Self, D>,
         device: &Device<Self>,
     ) -> FloatTensor<Self, D> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct ToDevice;
 
         impl<B: Backend, const D: usize> Backward<B, D, 1> for ToDevice {
             type State = B::Device;
 
+            fn clone_backward(&self) -> Option<Self> {
+                Some(self.clone())
+            }
+
             fn backward(
                 self,
                 ops: Ops<Self::State, 1>,
@@ -101,7 +106,7 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         lhs: FloatTensor<Self, D>,
         rhs: FloatTensor<Self, D>,
     ) -> FloatTensor<Self, D> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct Add;
 
         retro_binary!(RetroAdd, B::float_add);
@@ -109,6 +114,10 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         impl<B: Backend, const D: usize> Backward<B, D, 2> for Add {
             type State = (Shape<D>, Shape<D>);
 
+            fn clone_backward(&self) -> Option<Self> {
+                Some(self.clone())
+            }
+
             fn backward(
                 self,
                 ops: Ops<Self::State, 2>,
@@ -149,7 +158,7 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         lhs: FloatTensor<Self, D>,
         rhs: FloatElem<B>,
     ) -> FloatTensor<Self, D> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct AddScalar;
 
         retro_unary_scalar!(RetroAddScalar, B::float_add_scalar);
@@ -157,6 +166,10 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         impl<B: Backend, const D: usize> Backward<B, D, 1> for AddScalar {
             type State = ();
 
+            fn clone_backward(&self) -> Option<Self> {
+                Some(self.clone())
+            }
+
             fn backward(
                 self,
                 ops: Ops<Self::State, 1>,
@@ -179,7 +192,7 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         lhs: FloatTensor<Self, D>,
         rhs: FloatTensor<Self, D>,
     ) -> FloatTensor<Self, D> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct Sub;
 
         retro_binary!(RetroSub, B::float_sub);
@@ -187,6 +200,10 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         impl<B: Backend, const D: usize> Backward<B, D, 2> for Sub {
             type State = (Shape<D>, Shape<D>);
 
+            fn clone_backward(&self) -> Option<Self> {
+                Some(self.clone())
+            }
+
             fn backward(
                 self,
                 ops: Ops<Self::State, 2>,
@@ -227,7 +244,7 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         lhs: FloatTensor<Self, D>,
         rhs: FloatElem<B>,
     ) -> FloatTensor<Self, D> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct SubScalar;
 
         retro_unary_scalar!(RetroSubScalar, B::float_sub_scalar);
@@ -235,6 +252,10 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         impl<B: Backend, const D: usize> Backward<B, D, 1> for SubScalar {
             type State = ();
 
+            fn clone_backward(&self) -> Option<Self> {
+                Some(self.clone())
+            }
+
             fn backward(
                 self,
                 ops: Ops<Self::State, 1>,
@@ -257,7 +278,7 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         lhs: FloatTensor<Self, D>,
         rhs: FloatTensor<Self, D>,
     ) -> FloatTensor<Self, D> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct Mul;
 
         retro_binary!(RetroMul, B::float_mul);
@@ -265,6 +286,10 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         impl<B: Backend, const D: usize> Backward<B, D, 2> for Mul {
             type State = (Option<NodeID>, Option<NodeID>, BinaryOpsBroadcast<D>);
 
+            fn clone_backward(&self) -> Option<Self> {
+                Some(self.clone())
+            }
+
             fn backward(
                 self,
                 ops: Ops<Self::State, 2>,
@@ -319,7 +344,7 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         lhs: FloatTensor<Self, D>,
         rhs: FloatElem<B>,
     ) -> FloatTensor<Self, D> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct MulScalar;
 
         retro_unary_scalar!(RetroMulScalar, B::float_mul_scalar);
@@ -327,6 +352,10 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         impl<B: Backend, const D: usize> Backward<B, D, 1> for MulScalar {
             type State = FloatElem<B>;
 
+            fn clone_backward(&self) -> Option<Self> {
+                Some(self.clone())
+            }
+
             fn backward(
                 self,
                 ops: Ops<Self::State, 1>,
@@ -355,7 +384,7 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         lhs: FloatTensor<Self, D>,
         rhs: FloatTensor<Self, D>,
     ) -> FloatTensor<Self, D> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct Div;
 
         retro_binary!(RetroDiv, B::float_div);
@@ -363,6 +392,10 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         impl<B: Backend, const D: usize> Backward<B, D, 2> for Div {
             type State = (Option<NodeID>, Option<NodeID>, BinaryOpsBroadcast<D>);
 
+            fn clone_backward(&self) -> Option<Self> {
+                Some(self.clone())
+            }
+
             fn backward(
                 self,
                 ops: Ops<Self::State, 2>,
@@ -425,7 +458,7 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         lhs: FloatTensor<Self, D>,
         rhs: FloatElem<B>,
     ) -> FloatTensor<Self, D> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct DivScalar;
 
         retro_unary_scalar!(RetroDivScalar, B::float_div_scalar);
@@ -433,6 +466,10 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         impl<B: Backend, const D: usize> Backward<B, D, 1> for DivScalar {
             type State = FloatElem<B>;
 
+            fn clone_backward(&self) -> Option<Self> {
+                Some(self.clone())
+            }
+
             fn backward(
                 self,
                 ops: Ops<Self::State, 1>,
@@ -462,7 +499,7 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         lhs: FloatTensor<Self, D>,
         rhs: FloatElem<B>,
     ) -> FloatTensor<Self, D> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct RemainderScalar;
 
         retro_unary_scalar!(RetroRemainderScalar, B::float_remainder_scalar);
@@ -470,6 +507,10 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         impl<B: Backend, const D: usize> Backward<B, D, 1> for RemainderScalar {
             type State = ();
 
+            fn clone_backward(&self) -> Option<Self> {
+                Some(self.clone())
+            }
+
             fn backward(
                 self,
                 ops: Ops<Self::State, 1>,
@@ -492,11 +533,20 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         lhs: FloatTensor<Self, D>,
         rhs: FloatTensor<Self, D>,
     ) -> FloatTensor<Self, D> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct Matmul;
 
         impl<B: Backend, const D: usize> Backward<B, D, 2> for Matmul {
-            type State = (Option<NodeID>, Option<NodeID>, BinaryOpsBroadcast<D>);
+            type State = (
+                Option<NodeID>,
+                Option<NodeID>,
+                BinaryOpsBroadcast<D>,
+                [bool; 2],
+            );
+
+            fn clone_backward(&self) -> Option<Self> {
+                Some(self.clone())
+            }
 
             fn backward(
                 self,
@@ -504,33 +554,59 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
                 grads: &mut Gradients,
                 checkpointer: &mut Checkpointer,
             ) {
-                let (lhs, rhs, broadcast) = ops.state;
+                let (lhs, rhs, broadcast, [lhs_reduced, rhs_reduced]) = ops.state;
                 let lhs = lhs.map(|lhs| checkpointer.retrieve_node_output(lhs));
                 let rhs = rhs.map(|rhs| checkpointer.retrieve_node_output(rhs));
 
-                binary::<B, D, D, D, _, _>(
-                    ops.parents,
-                    ops.node,
-                    grads,
-                    |grad| {
-                        let rhs = B::float_transpose(rhs.unwrap());
-                        let grad = B::float_matmul(grad, rhs);
-
-                        broadcast.backward_lhs::<B>(grad)
-                    },
-                    |grad| {
-                        let lhs = B::float_transpose(lhs.unwrap());
-                        let grad = B::float_matmul(lhs, grad);
+                let [grad_4lhs, grad_4rhs] =
+                    duplicate(&ops.parents, Some(grads.consume::<B, D>(&ops.node)));
+                let [node_lhs, node_rhs] = ops.parents;
+
+                // The gradients whose batches don't have to be summed are accumulated by the
+                // matmuls into the previous gradients of the parents, like the weights of linear
+                // layers used more than once.
+                if let Some(node) = node_lhs {
+                    let rhs = B::float_transpose(rhs.unwrap());
+                    let grad = grad_4lhs.unwrap();
+
+                    match lhs_reduced {
+                        true => grads.register::<B, D>(
+                            node.id,
+                            broadcast.backward_lhs::<B>(B::float_matmul(grad, rhs)),
+                        ),
+                        false => grads.register_matmul::<B, D>(node.id, grad, rhs),
+                    }
+                }
 
-                        broadcast.backward_rhs::<B>(grad)
-                    },
-                );
+                if let Some(node) = node_rhs {
+                    let lhs = lhs.unwrap();
+                    let grad = grad_4rhs.unwrap();
+
+                    match rhs_reduced {
+                        // A rhs shared by every batch, like the weights of a linear layer applied
+                        // to a sequence, has the product of the flattened batches as gradient.
+                        true if broadcast.rhs_shared() => grads.register_matmul::<B, D>(
+                            node.id,
+                            B::float_transpose(flatten_batches::<B, D>(lhs)),
+                            flatten_batches::<B, D>(grad),
+                        ),
+                        true => grads.register::<B, D>(
+                            node.id,
+                            broadcast
+                                .backward_rhs::<B>(B::float_matmul(B::float_transpose(lhs), grad)),
+                        ),
+                        false => {
+                            grads.register_matmul::<B, D>(node.id, B::float_transpose(lhs), grad)
+                        }
+                    }
+                }
             }
         }
 
         let lhs_tracked = lhs.is_tracked();
         let rhs_tracked = rhs.is_tracked();
         let broadcast = BinaryOpsBroadcast::new::<B>(&lhs.primitive, &rhs.primitive);
+        let reduced = batches_reduced::<B, D>(&lhs.primitive, &rhs.primitive);
 
         match Matmul
             .prepare::<C>([lhs.node.clone(), rhs.node.clone()])
@@ -541,7 +617,7 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
                 let lhs_state = rhs_tracked.then(|| prep.checkpoint(&lhs));
                 let rhs_state = lhs_tracked.then(|| prep.checkpoint(&rhs));
                 prep.finish(
-                    (lhs_state, rhs_state, broadcast),
+                    (lhs_state, rhs_state, broadcast, reduced),
                     B::float_matmul(lhs.primitive, rhs.primitive),
                 )
             }
@@ -549,8 +625,106 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         }
     }
 
+    fn float_spmm(
+        rows: IntTensor<B, 1>,
+        cols: IntTensor<B, 1>,
+        values: FloatTensor<Self, 1>,
+        dense: FloatTensor<Self, 2>,
+        num_rows: usize,
+    ) -> FloatTensor<Self, 2> {
+        #[derive(Debug, Clone)]
+        struct Spmm;
+
+        impl<B: Backend> Backward<B, 2, 2> for Spmm {
+            type State = (
+                IntTensor<B, 1>,
+                IntTensor<B, 1>,
+                Option<NodeID>,
+                Option<NodeID>,
+                usize,
+            );
+
+            fn clone_backward(&self) -> Option<Self> {
+                Some(self.clone())
+            }
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 2>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let [node_values, node_dense] = ops.parents;
+                let grad = grads.consume::<B, 2>(&ops.node);
+                let (rows, cols, values_state, dense_state, num_rows_dense) = ops.state;
+
+                if let Some(node) = node_dense {
+                    // The gradient of the dense matrix is the transposed sparse matrix multiplied
+                    // by the gradient of the output.
+                    let values = checkpointer.retrieve_node_output(values_state.unwrap());
+                    let grad = B::float_spmm(
+                        cols.clone(),
+                        rows.clone(),
+                        values,
+                        grad.clone(),
+                        num_rows_dense,
+                    );
+
+                    grads.register::<B, 2>(node.id, grad);
+                }
+
+                if let Some(node) = node_values {
+                    // The gradient of the values is the product of the gradient of the output and
+                    // the transposed dense matrix, only sampled at the coordinates of the values.
+                    let dense = checkpointer.retrieve_node_output(dense_state.unwrap());
+                    let products = B::float_mul(
+                        B::float_select(grad, 0, rows),
+                        B::float_select(dense, 0, cols),
+                    );
+                    let [num_entries, _] = B::float_shape(&products).dims;
+                    let grad =
+                        B::float_reshape(B::float_sum_dim(products, 1), Shape::new([num_entries]));
+
+                    grads.register::<B, 1>(node.id, grad);
+                }
+            }
+        }
+
+        let values_tracked = values.is_tracked();
+        let dense_tracked = dense.is_tracked();
+        let num_rows_dense = B::float_shape(&dense.primitive).dims[0];
+
+        match Spmm
+            .prepare::<C>([values.node.clone(), dense.node.clone()])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let values_state = dense_tracked.then(|| prep.checkpoint(&values));
+                let dense_state = values_tracked.then(|| prep.checkpoint(&dense));
+                prep.finish(
+                    (
+                        rows.clone(),
+                        cols.clone(),
+                        values_state,
+                        dense_state,
+                        num_rows_dense,
+                    ),
+                    B::float_spmm(rows, cols, values.primitive, dense.primitive, num_rows),
+                )
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_spmm(
+                rows,
+                cols,
+                values.primitive,
+                dense.primitive,
+                num_rows,
+            )),
+        }
+    }
+
     fn float_neg<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct Neg;
 
         retro_unary!(RetroNeg, B::float_neg);
@@ -558,6 +732,10 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         impl<B: Backend, const D: usize> Backward<B, D, 1> for Neg {
             type State = ();
 
+            fn clone_backward(&self) -> Option<Self> {
+                Some(self.clone())
+            }
+
             fn backward(
                 self,
                 ops: Ops<Self::State, 1>,
@@ -576,7 +754,7 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
     }
 
     fn float_recip<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct Recip;
 
         retro_unary!(RetroRecip, B::float_recip);
@@ -584,6 +762,10 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         impl<B: Backend, const D: usize> Backward<B, D, 1> for Recip {
             type State = NodeID;
 
+            fn clone_backward(&self) -> Option<Self> {
+                Some(self.clone())
+            }
+
             fn backward(
                 self,
                 ops: Ops<Self::State, 1>,
@@ -620,7 +802,7 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         dim1: usize,
         dim2: usize,
     ) -> FloatTensor<Self, D> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct SwapDim;
 
         #[derive(new, Debug)]
@@ -642,6 +824,10 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         impl<B: Backend, const D: usize> Backward<B, D, 1> for SwapDim {
             type State = (usize, usize);
 
+            fn clone_backward(&self) -> Option<Self> {
+                Some(self.clone())
+            }
+
             fn backward(
                 self,
                 ops: Ops<Self::State, 1>,
@@ -677,7 +863,7 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         tensor: FloatTensor<Self, D>,
         axes: [usize; D],
     ) -> FloatTensor<Self, D> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct PermuteDim;
 
         #[derive(new, Debug)]
@@ -698,6 +884,10 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         impl<B: Backend, const D: usize> Backward<B, D, 1> for PermuteDim {
             type State = [usize; D];
 
+            fn clone_backward(&self) -> Option<Self> {
+                Some(self.clone())
+            }
+
             fn backward(
                 self,
                 ops: Ops<Self::State, 1>,
@@ -733,7 +923,7 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         tensor: FloatTensor<Self, D>,
         axes: &[usize],
     ) -> FloatTensor<Self, D> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct FlipDim;
 
         #[derive(new, Debug)]
@@ -754,6 +944,10 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         impl<B: Backend, const D: usize> Backward<B, D, 1> for FlipDim {
             type State = Vec<usize>;
 
+            fn clone_backward(&self) -> Option<Self> {
+                Some(self.clone())
+            }
+
             fn backward(
                 self,
                 ops: Ops<Self::State, 1>,
@@ -786,7 +980,7 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         tensor: FloatTensor<Self, D1>,
         shape: Shape<D2>,
     ) -> FloatTensor<Self, D2> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct ReshapeDim<const D1: usize>;
 
         #[derive(new, Debug)]
@@ -807,6 +1001,10 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         impl<B: Backend, const D1: usize, const D2: usize> Backward<B, D2, 1> for ReshapeDim<D1> {
             type State = (Shape<D1>, Shape<D2>);
 
+            fn clone_backward(&self) -> Option<Self> {
+                Some(self.clone())
+            }
+
             fn backward(
                 self,
                 ops: Ops<Self::State, 1>,
@@ -853,12 +1051,16 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         tensor: FloatTensor<Self, D>,
         indices: IntTensor<B, D>,
     ) -> FloatTensor<Self, D> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct Gather;
 
         impl<B: Backend, const D: usize> Backward<B, D, 1> for Gather {
             type State = (usize, IntTensor<B, D>, Shape<D>, B::Device);
 
+            fn clone_backward(&self) -> Option<Self> {
+                Some(self.clone())
+            }
+
             fn backward(
                 self,
                 ops: Ops<Self::State, 1>,
@@ -900,12 +1102,16 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         indices: IntTensor<B, D>,
         value: FloatTensor<Self, D>,
     ) -> FloatTensor<Self, D> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct Scatter;
 
         impl<B: Backend, const D: usize> Backward<B, D, 2> for Scatter {
             type State = (usize, IntTensor<B, D>, Shape<D>, Shape<D>, B::Device);
 
+            fn clone_backward(&self) -> Option<Self> {
+                Some(self.clone())
+            }
+
             fn backward(
                 self,
                 ops: Ops<Self::State, 2>,
@@ -960,7 +1166,7 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         dim: usize,
         indices: IntTensor<B, 1>,
     ) -> FloatTensor<Self, D> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct Select;
 
         #[derive(new, Debug)]
@@ -981,6 +1187,10 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         impl<B: Backend, const D: usize> Backward<B, D, 1> for Select {
             type State = (usize, IntTensor<B, 1>, Shape<D>, B::Device);
 
+            fn clone_backward(&self) -> Option<Self> {
+                Some(self.clone())
+            }
+
             fn backward(
                 self,
                 ops: Ops<Self::State, 1>,
@@ -1028,7 +1238,7 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         indices: IntTensor<B, 1>,
         value: FloatTensor<Self, D>,
     ) -> FloatTensor<Self, D> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct IndexSelectDimAssign<const D: usize>;
 
         #[derive(new, Debug)]
@@ -1051,6 +1261,10 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         impl<B: Backend, const D: usize> Backward<B, D, 2> for IndexSelectDimAssign<D> {
             type State = (usize, IntTensor<B, 1>);
 
+            fn clone_backward(&self) -> Option<Self> {
+                Some(self.clone())
+            }
+
             fn backward(
                 self,
                 ops: Ops<Self::State, 2>,
@@ -1094,11 +1308,140 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         }
     }
 
+    fn float_index_put<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+        indices: IntTensor<B, 1>,
+        values: FloatTensor<Self, D>,
+        accumulate: bool,
+    ) -> FloatTensor<Self, D> {
+        #[derive(Debug, Clone)]
+        struct IndexPut<const D: usize>;
+
+        #[derive(new, Debug)]
+        struct RetroIndexPut<B: Backend, const D: usize> {
+            tensor_id: NodeID,
+            dim: usize,
+            indices: IntTensor<B, 1>,
+            values_id: NodeID,
+            accumulate: bool,
+        }
+
+        impl<B: Backend, const D: usize> RetroForward for RetroIndexPut<B, D> {
+            fn forward(&self, states: &mut BackwardStates, out_node: NodeID) {
+                let tensor = states.get_state::<B::FloatTensorPrimitive<D>>(&self.tensor_id);
+                let values = states.get_state::<B::FloatTensorPrimitive<D>>(&self.values_id);
+                let out = B::float_index_put(
+                    tensor,
+                    self.dim,
+                    self.indices.clone(),
+                    values,
+                    self.accumulate,
+                );
+                states.save(out_node, out)
+            }
+        }
+
+        impl<B: Backend, const D: usize> Backward<B, D, 2> for IndexPut<D> {
+            type State = (usize, IntTensor<B, 1>, Shape<D>, usize, bool);
+
+            fn clone_backward(&self) -> Option<Self> {
+                Some(self.clone())
+            }
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 2>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                let (dim, indices, shape_values, size, accumulate) = ops.state;
+                let indices_4lhs = indices.clone();
+                let shape_values_4lhs = shape_values.clone();
+
+                binary::<B, D, D, D, _, _>(
+                    ops.parents,
+                    ops.node,
+                    grads,
+                    |grad| match accumulate {
+                        true => grad,
+                        // The overwritten elements don't contribute to the output.
+                        false => {
+                            let device = B::float_device(&grad);
+                            let zeros = B::float_zeros(shape_values_4lhs, &device);
+                            B::float_index_put(grad, dim, indices_4lhs, zeros, false)
+                        }
+                    },
+                    |grad| {
+                        let grad = B::float_select(grad, dim, indices.clone());
+                        if accumulate {
+                            return grad;
+                        }
+
+                        // Only the last value of a repeated index is written, following the
+                        // order of the forward pass.
+                        let num_indices = shape_values.dims[dim];
+                        let device = B::int_device(&indices);
+                        let positions = B::int_arange(0..num_indices as i64, &device);
+                        let last = B::int_index_put(
+                            B::int_full(Shape::new([size]), (-1i64).elem(), &device),
+                            0,
+                            indices.clone(),
+                            positions.clone(),
+                            false,
+                        );
+                        let written = B::int_equal(B::int_select(last, 0, indices), positions);
+
+                        let mut shape = Shape::new([1; D]);
+                        shape.dims[dim] = num_indices;
+                        let mask = B::float_reshape(B::bool_into_float(written), shape);
+
+                        B::float_mul(grad, mask)
+                    },
+                );
+            }
+        }
+
+        let size = B::float_shape(&tensor.primitive).dims[dim];
+
+        match IndexPut::<D>
+            .prepare::<C>([tensor.node.clone(), values.node.clone()])
+            .memory_bound()
+            .retro_forward(RetroIndexPut::<B, D>::new(
+                tensor.node.id,
+                dim,
+                indices.clone(),
+                values.node.id,
+                accumulate,
+            ))
+            .parents([&tensor, &values])
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => prep.finish(
+                (
+                    dim,
+                    indices.clone(),
+                    B::float_shape(&values.primitive),
+                    size,
+                    accumulate,
+                ),
+                B::float_index_put(tensor.primitive, dim, indices, values.primitive, accumulate),
+            ),
+            OpsKind::UnTracked(prep) => prep.finish(B::float_index_put(
+                tensor.primitive,
+                dim,
+                indices,
+                values.primitive,
+                accumulate,
+            )),
+        }
+    }
+
     fn float_slice<const D1: usize, const D2: usize>(
         tensor: FloatTensor<Self, D1>,
         ranges: [std::ops::Range<usize>; D2],
     ) -> FloatTensor<Self, D1> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct Index<const D2: usize>;
 
         #[derive(new, Debug)]
@@ -1119,6 +1462,10 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         impl<B: Backend, const D1: usize, const D2: usize> Backward<B, D1, 1> for Index<D2> {
             type State = ([std::ops::Range<usize>; D2], Shape<D1>, B::Device);
 
+            fn clone_backward(&self) -> Option<Self> {
+                Some(self.clone())
+            }
+
             fn backward(
                 self,
                 ops: Ops<Self::State, 1>,
@@ -1158,7 +1505,7 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         ranges: [std::ops::Range<usize>; D2],
         value: FloatTensor<Self, D1>,
     ) -> FloatTensor<Self, D1> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct SliceAssign<const D2: usize>;
 
         #[derive(new, Debug)]
@@ -1181,6 +1528,10 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         impl<B: Backend, const D1: usize, const D2: usize> Backward<B, D1, 2> for SliceAssign<D2> {
             type State = ([std::ops::Range<usize>; D2], Shape<D1>, B::Device);
 
+            fn clone_backward(&self) -> Option<Self> {
+                Some(self.clone())
+            }
+
             fn backward(
                 self,
                 ops: Ops<Self::State, 2>,
@@ -1235,12 +1586,16 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         mask: BoolTensor<Self, D>,
         source: FloatTensor<Self, D>,
     ) -> FloatTensor<Self, D> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct MaskWhere;
 
         impl<B: Backend, const D: usize> Backward<B, D, 2> for MaskWhere {
             type State = (BoolTensor<B, D>, Shape<D>, Shape<D>, B::Device);
 
+            fn clone_backward(&self) -> Option<Self> {
+                Some(self.clone())
+            }
+
             fn backward(
                 self,
                 ops: Ops<Self::State, 2>,
@@ -1297,12 +1652,16 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         mask: BoolTensor<B, D>,
         value: FloatElem<B>,
     ) -> FloatTensor<Self, D> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct MaskFill;
 
         impl<B: Backend, const D: usize> Backward<B, D, 1> for MaskFill {
             type State = BoolTensor<B, D>;
 
+            fn clone_backward(&self) -> Option<Self> {
+                Some(self.clone())
+            }
+
             fn backward(
                 self,
                 ops: Ops<Self::State, 1>,
@@ -1428,12 +1787,16 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
     }
 
     fn float_mean<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, 1> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct Mean<const D: usize>;
 
         impl<B: Backend, const D: usize> Backward<B, 1, 1> for Mean<D> {
             type State = Shape<D>;
 
+            fn clone_backward(&self) -> Option<Self> {
+                Some(self.clone())
+            }
+
             fn backward(
                 self,
                 ops: Ops<Self::State, 1>,
@@ -1464,12 +1827,16 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
     }
 
     fn float_sum<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, 1> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct Sum<const D: usize>;
 
         impl<B: Backend, const D: usize> Backward<B, 1, 1> for Sum<D> {
             type State = Shape<D>;
 
+            fn clone_backward(&self) -> Option<Self> {
+                Some(self.clone())
+            }
+
             fn backward(
                 self,
                 ops: Ops<Self::State, 1>,
@@ -1500,12 +1867,16 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         tensor: FloatTensor<Self, D>,
         dim: usize,
     ) -> FloatTensor<Self, D> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct MeanDim;
 
         impl<B: Backend, const D: usize> Backward<B, D, 1> for MeanDim {
             type State = (Shape<D>, usize);
 
+            fn clone_backward(&self) -> Option<Self> {
+                Some(self.clone())
+            }
+
             fn backward(
                 self,
                 ops: Ops<Self::State, 1>,
@@ -1538,16 +1909,116 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         }
     }
 
+    fn float_var_mean<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dims: &[usize],
+        correction: usize,
+    ) -> (FloatTensor<Self, D>, FloatTensor<Self, D>) {
+        #[derive(Debug, Clone)]
+        struct Var;
+
+        #[derive(Debug, Clone)]
+        struct Mean;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Var {
+            type State = (NodeID, B::FloatTensorPrimitive<D>, f64);
+
+            fn clone_backward(&self) -> Option<Self> {
+                Some(self.clone())
+            }
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let (input, mean, divisor) = ops.state;
+                let input: FloatTensor<B, D> = checkpointer.retrieve_node_output(input);
+
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    // d var / d x = 2 (x - mean) / (n - correction)
+                    let diff = B::float_sub(input, mean);
+                    let factor = B::float_div_scalar(grad, (divisor / 2.0).elem());
+
+                    B::float_mul(diff, factor)
+                });
+            }
+        }
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Mean {
+            type State = (Shape<D>, usize);
+
+            fn clone_backward(&self) -> Option<Self> {
+                Some(self.clone())
+            }
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                let (shape, num_elems) = ops.state;
+
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    // d mean / d x = 1 / n, broadcasted over the reduced dimensions.
+                    let ones = B::float_ones(shape, &B::float_device(&grad));
+                    let grad = B::float_div_scalar(grad, (num_elems as f64).elem());
+
+                    B::float_mul(ones, grad)
+                });
+            }
+        }
+
+        let shape = B::float_shape(&tensor.primitive);
+        let num_elems: usize = dims.iter().map(|dim| shape.dims[*dim]).product();
+        let divisor = num_elems.saturating_sub(correction) as f64;
+
+        // Both outputs come from a single call to the backend, the variance being differentiated
+        // with the mean of the forward pass.
+        let var = Var
+            .prepare::<C>([tensor.node.clone()])
+            .compute_bound()
+            .stateful();
+        let mean = Mean
+            .prepare::<C>([tensor.node.clone()])
+            .compute_bound()
+            .stateful();
+
+        match (var, mean) {
+            (OpsKind::Tracked(mut var_prep), OpsKind::Tracked(mean_prep)) => {
+                let state = var_prep.checkpoint(&tensor);
+                let (var, mean) = B::float_var_mean(tensor.primitive, dims, correction);
+
+                (
+                    var_prep.finish((state, mean.clone(), divisor), var),
+                    mean_prep.finish((shape, num_elems), mean),
+                )
+            }
+            (OpsKind::UnTracked(var_prep), OpsKind::UnTracked(mean_prep)) => {
+                let (var, mean) = B::float_var_mean(tensor.primitive, dims, correction);
+
+                (var_prep.finish(var), mean_prep.finish(mean))
+            }
+            _ => unreachable!("The variance and the mean have the same parent"),
+        }
+    }
+
     fn float_sum_dim<const D: usize>(
         tensor: FloatTensor<Self, D>,
         dim: usize,
     ) -> FloatTensor<Self, D> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct SumDim;
 
         impl<B: Backend, const D: usize> Backward<B, D, 1> for SumDim {
             type State = (Shape<D>, usize);
 
+            fn clone_backward(&self) -> Option<Self> {
+                Some(self.clone())
+            }
+
             fn backward(
                 self,
                 ops: Ops<Self::State, 1>,
@@ -1586,8 +2057,51 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         B::float_argmin(tensor.primitive, dim)
     }
 
+    fn float_to_memory_format<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        format: MemoryFormat,
+    ) -> FloatTensor<Self, D> {
+        // Only the layout changes, so the tensor keeps its node in the graph.
+        AutodiffTensor {
+            primitive: B::float_to_memory_format(tensor.primitive, format),
+            ..tensor
+        }
+    }
+
+    fn float_memory_format<const D: usize>(tensor: &FloatTensor<Self, D>) -> MemoryFormat {
+        B::float_memory_format(&tensor.primitive)
+    }
+
+    fn float_multinomial(
+        tensor: FloatTensor<Self, 2>,
+        num_samples: usize,
+        replacement: bool,
+    ) -> IntTensor<B, 2> {
+        B::float_multinomial(tensor.primitive, num_samples, replacement)
+    }
+
+    fn float_associative_scan<const D: usize>(
+        a: FloatTensor<Self, D>,
+        b: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        match super::scan::AssociativeScan
+            .prepare::<C>([a.node.clone(), b.node.clone()])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => {
+                let output = B::float_associative_scan(a.primitive.clone(), b.primitive, dim);
+                prep.finish((a.primitive, output.clone(), dim), output)
+            }
+            OpsKind::UnTracked(prep) => {
+                prep.finish(B::float_associative_scan(a.primitive, b.primitive, dim))
+            }
+        }
+    }
+
     fn float_exp<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct Exp;
 
         retro_unary!(RetroExp, B::float_exp);
@@ -1595,6 +2109,10 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         impl<B: Backend, const D: usize> Backward<B, D, 1> for Exp {
             type State = NodeID;
 
+            fn clone_backward(&self) -> Option<Self> {
+                Some(self.clone())
+            }
+
             fn backward(
                 self,
                 ops: Ops<Self::State, 1>,
@@ -1625,7 +2143,7 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
     }
 
     fn float_log<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct Log;
 
         retro_unary!(RetroLog, B::float_log);
@@ -1633,6 +2151,10 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         impl<B: Backend, const D: usize> Backward<B, D, 1> for Log {
             type State = NodeID;
 
+            fn clone_backward(&self) -> Option<Self> {
+                Some(self.clone())
+            }
+
             fn backward(
                 self,
                 ops: Ops<Self::State, 1>,
@@ -1663,7 +2185,7 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
     }
 
     fn float_log1p<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct Log1P;
 
         retro_unary!(RetroLog1P, B::float_log1p);
@@ -1671,7 +2193,11 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         impl<B: Backend, const D: usize> Backward<B, D, 1> for Log1P {
             type State = NodeID;
 
-            fn backward(
+            fn clone_backward(&self) -> Option<Self> {
+                Some(self.clone())
+            }
+
+            fn backward(
                 self,
                 ops: Ops<Self::State, 1>,
                 grads: &mut Gradients,
@@ -1706,7 +2232,7 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         tensor: FloatTensor<Self, D>,
         value: f32,
     ) -> FloatTensor<Self, D> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct PowfScalar;
 
         #[derive(new, Debug)]
@@ -1727,6 +2253,10 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         impl<B: Backend, const D: usize> Backward<B, D, 1> for PowfScalar {
             type State = (NodeID, f32);
 
+            fn clone_backward(&self) -> Option<Self> {
+                Some(self.clone())
+            }
+
             fn backward(
                 self,
                 ops: Ops<Self::State, 1>,
@@ -1761,7 +2291,7 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
     }
 
     fn float_sqrt<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct Sqrt;
 
         retro_unary!(RetroSqrt, B::float_sqrt);
@@ -1769,6 +2299,10 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         impl<B: Backend, const D: usize> Backward<B, D, 1> for Sqrt {
             type State = NodeID;
 
+            fn clone_backward(&self) -> Option<Self> {
+                Some(self.clone())
+            }
+
             fn backward(
                 self,
                 ops: Ops<Self::State, 1>,
@@ -1800,7 +2334,7 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
     }
 
     fn float_abs<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct Abs;
 
         retro_unary!(RetroAbs, B::float_abs);
@@ -1808,6 +2342,10 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         impl<B: Backend, const D: usize> Backward<B, D, 1> for Abs {
             type State = NodeID;
 
+            fn clone_backward(&self) -> Option<Self> {
+                Some(self.clone())
+            }
+
             fn backward(
                 self,
                 ops: Ops<Self::State, 1>,
@@ -1840,7 +2378,7 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
     }
 
     fn float_cos<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct Cos;
 
         retro_unary!(RetroCos, B::float_cos);
@@ -1848,6 +2386,10 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         impl<B: Backend, const D: usize> Backward<B, D, 1> for Cos {
             type State = NodeID;
 
+            fn clone_backward(&self) -> Option<Self> {
+                Some(self.clone())
+            }
+
             fn backward(
                 self,
                 ops: Ops<Self::State, 1>,
@@ -1879,7 +2421,7 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
     }
 
     fn float_sin<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct Sin;
 
         retro_unary!(RetroSin, B::float_sin);
@@ -1887,6 +2429,10 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         impl<B: Backend, const D: usize> Backward<B, D, 1> for Sin {
             type State = NodeID;
 
+            fn clone_backward(&self) -> Option<Self> {
+                Some(self.clone())
+            }
+
             fn backward(
                 self,
                 ops: Ops<Self::State, 1>,
@@ -1917,7 +2463,7 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
     }
 
     fn float_tanh<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct Tanh;
 
         retro_unary!(RetroTanh, B::float_tanh);
@@ -1925,6 +2471,10 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         impl<B: Backend, const D: usize> Backward<B, D, 1> for Tanh {
             type State = NodeID;
 
+            fn clone_backward(&self) -> Option<Self> {
+                Some(self.clone())
+            }
+
             fn backward(
                 self,
                 ops: Ops<Self::State, 1>,
@@ -1959,7 +2509,7 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
     }
 
     fn float_erf<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct Erf;
 
         retro_unary!(RetroErf, B::float_erf);
@@ -1967,6 +2517,10 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         impl<B: Backend, const D: usize> Backward<B, D, 1> for Erf {
             type State = NodeID;
 
+            fn clone_backward(&self) -> Option<Self> {
+                Some(self.clone())
+            }
+
             fn backward(
                 self,
                 ops: Ops<Self::State, 1>,
@@ -2000,11 +2554,57 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         }
     }
 
+    fn float_erfinv<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        #[derive(Debug, Clone)]
+        struct Erfinv;
+
+        retro_unary!(RetroErfinv, B::float_erfinv);
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Erfinv {
+            type State = NodeID;
+
+            fn clone_backward(&self) -> Option<Self> {
+                Some(self.clone())
+            }
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    let input = checkpointer.retrieve_node_output(ops.state);
+                    let output = B::float_erfinv(input);
+                    let exponent = B::float_mul(output.clone(), output);
+                    let factor = (std::f64::consts::PI.sqrt() / 2.0).elem();
+                    let value = B::float_mul_scalar(B::float_exp(exponent), factor);
+
+                    B::float_mul(grad, value)
+                });
+            }
+        }
+
+        match Erfinv
+            .prepare::<C>([tensor.node.clone()])
+            .memory_bound()
+            .retro_forward(RetroErfinv::<B, D>::new(tensor.node.id))
+            .parents([&tensor])
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let state = prep.checkpoint(&tensor);
+                prep.finish(state, B::float_erfinv(tensor.primitive))
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_erfinv(tensor.primitive)),
+        }
+    }
+
     fn float_cat<const D: usize>(
         tensors: Vec<FloatTensor<Self, D>>,
         dim: usize,
     ) -> FloatTensor<Self, D> {
-        #[derive(new, Debug)]
+        #[derive(new, Debug, Clone)]
         struct CatStep<B: Backend, const D: usize> {
             nodes: Vec<Option<NodeRef>>,
             // The dimension of each tensor along the dim dimension.
@@ -2035,6 +2635,10 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
                     });
             }
 
+            fn clone_boxed(&self) -> Option<StepBoxed> {
+                Some(Box::new(self.clone()))
+            }
+
             fn node(&self) -> NodeID {
                 self.output.id
             }
@@ -2181,7 +2785,7 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         lhs: FloatTensor<Self, D>,
         rhs: FloatTensor<Self, D>,
     ) -> FloatTensor<Self, D> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct PowF;
 
         retro_binary!(RetroPowf, B::float_powf);
@@ -2189,6 +2793,10 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         impl<B: Backend, const D: usize> Backward<B, D, 2> for PowF {
             type State = (NodeID, NodeID, BinaryOpsBroadcast<D>);
 
+            fn clone_backward(&self) -> Option<Self> {
+                Some(self.clone())
+            }
+
             fn backward(
                 self,
                 ops: Ops<Self::State, 2>,
@@ -2260,7 +2868,7 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
     }
 
     fn float_sign<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct Sign;
 
         retro_unary!(RetroSign, B::float_sign);
@@ -2268,6 +2876,10 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         impl<B: Backend, const D: usize> Backward<B, D, 1> for Sign {
             type State = ();
 
+            fn clone_backward(&self) -> Option<Self> {
+                Some(self.clone())
+            }
+
             fn backward(
                 self,
                 ops: Ops<Self::State, 1>,
@@ -2292,7 +2904,7 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         tensor: FloatTensor<Self, D1>,
         shape: Shape<D2>,
     ) -> FloatTensor<Self, D2> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct ExpandDim<const D1: usize, const D2: usize>;
 
         #[derive(new, Debug)]
@@ -2313,6 +2925,10 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         impl<B: Backend, const D1: usize, const D2: usize> Backward<B, D2, 1> for ExpandDim<D1, D2> {
             type State = Shape<D1>;
 
+            fn clone_backward(&self) -> Option<Self> {
+                Some(self.clone())
+            }
+
             fn backward(
                 self,
                 ops: Ops<Self::State, 1>,
@@ -2418,12 +3034,78 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         B::float_argsort(tensor.primitive, dim, descending)
     }
 
+    fn float_pad<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        padding: [(isize, isize); D],
+        mode: PadMode<FloatElem<Self>>,
+    ) -> FloatTensor<Self, D> {
+        #[derive(Debug, Clone)]
+        struct Pad;
+
+        #[derive(new, Debug)]
+        struct RetroPad<B: Backend, const D: usize> {
+            input_id: NodeID,
+            padding: [(isize, isize); D],
+            mode: PadMode<FloatElem<B>>,
+            _backend: PhantomData<B>,
+        }
+
+        impl<B: Backend, const D: usize> RetroForward for RetroPad<B, D> {
+            fn forward(&self, states: &mut BackwardStates, out_node: NodeID) {
+                let input = states.get_state::<B::FloatTensorPrimitive<D>>(&self.input_id);
+                let out = B::float_pad(input, self.padding, self.mode);
+                states.save(out_node, out)
+            }
+        }
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Pad {
+            type State = [(isize, isize); D];
+
+            fn clone_backward(&self) -> Option<Self> {
+                Some(self.clone())
+            }
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                // Cropping the padded elements and padding the cropped ones with zeros.
+                let padding = ops.state.map(|(before, after)| (-before, -after));
+
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    B::float_pad(grad, padding, PadMode::Constant(0.elem()))
+                });
+            }
+        }
+
+        // The reflected and repeated elements accumulate the gradient of multiple outputs, which
+        // is handled by the backward of the indexing operations.
+        if !matches!(mode, PadMode::Constant(_)) {
+            return pad::<Self, D, Float>(TensorPrimitive::Float(tensor), padding, mode).tensor();
+        }
+
+        match Pad
+            .prepare::<C>([tensor.node.clone()])
+            .memory_bound()
+            .retro_forward(RetroPad::<B, D>::new(tensor.node.id, padding, mode))
+            .parents([&tensor])
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => {
+                prep.finish(padding, B::float_pad(tensor.primitive, padding, mode))
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_pad(tensor.primitive, padding, mode)),
+        }
+    }
+
     fn float_repeat_dim<const D: usize>(
         tensor: FloatTensor<Self, D>,
         dim: usize,
         times: usize,
     ) -> FloatTensor<Self, D> {
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         struct Repeat;
 
         #[derive(new, Debug)]
@@ -2445,6 +3127,10 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         impl<B: Backend, const D: usize> Backward<B, D, 1> for Repeat {
             type State = usize;
 
+            fn clone_backward(&self) -> Option<Self> {
+                Some(self.clone())
+            }
+
             fn backward(
                 self,
                 ops: Ops<Self::State, 1>,
@@ -2475,10 +3161,499 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         }
     }
 
+    fn float_fake_quantize<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        scale: FloatTensor<Self, D>,
+        zero_point: FloatTensor<Self, D>,
+        qmin: FloatElem<B>,
+        qmax: FloatElem<B>,
+    ) -> FloatTensor<Self, D> {
+        #[derive(Debug, Clone)]
+        struct FakeQuantize;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 3> for FakeQuantize {
+            type State = (NodeID, NodeID, NodeID, FloatElem<B>, FloatElem<B>);
+
+            fn clone_backward(&self) -> Option<Self> {
+                Some(self.clone())
+            }
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 3>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let [node_tensor, node_scale, node_zero_point] = ops.parents;
+                let grad = grads.consume::<B, D>(&ops.node);
+
+                let (tensor_state, scale_state, zero_point_state, qmin, qmax) = ops.state;
+                let tensor: B::FloatTensorPrimitive<D> =
+                    checkpointer.retrieve_node_output(tensor_state);
+                let scale: B::FloatTensorPrimitive<D> =
+                    checkpointer.retrieve_node_output(scale_state);
+                let zero_point: B::FloatTensorPrimitive<D> =
+                    checkpointer.retrieve_node_output(zero_point_state);
+
+                // The rounding is treated as the identity (straight-through estimator), so the
+                // gradients only depend on whether the value was clamped or not.
+                let value = B::float_add(
+                    B::float_div(tensor.clone(), scale.clone()),
+                    zero_point.clone(),
+                );
+                let in_range = B::bool_and(
+                    B::float_greater_equal_elem(value.clone(), qmin),
+                    B::float_lower_equal_elem(value, qmax),
+                );
+                let in_range = B::bool_into_float(in_range);
+
+                if let Some(node) = node_scale {
+                    // Learned step size (LSQ): `(q - zp) - x / s` inside the range and
+                    // `qmin - zp` or `qmax - zp` outside of it.
+                    let output = B::float_fake_quantize(
+                        tensor.clone(),
+                        scale.clone(),
+                        zero_point.clone(),
+                        qmin,
+                        qmax,
+                    );
+                    let value = B::float_div(
+                        B::float_sub(output, B::float_mul(in_range.clone(), tensor)),
+                        scale.clone(),
+                    );
+                    let grad = B::float_mul(grad.clone(), value);
+
+                    grads.register::<B, D>(
+                        node.id,
+                        broadcast_shape::<B, D>(grad, &B::float_shape(&scale)),
+                    );
+                }
+                if let Some(node) = node_zero_point {
+                    // Learned offset (LSQ+): `-s` where the value is clamped, zero elsewhere.
+                    let out_of_range = B::float_sub_scalar(in_range.clone(), 1.elem());
+                    let grad = B::float_mul(grad.clone(), B::float_mul(out_of_range, scale));
+
+                    grads.register::<B, D>(
+                        node.id,
+                        broadcast_shape::<B, D>(grad, &B::float_shape(&zero_point)),
+                    );
+                }
+                if let Some(node) = node_tensor {
+                    grads.register::<B, D>(node.id, B::float_mul(grad, in_range));
+                }
+            }
+        }
+
+        match FakeQuantize
+            .prepare::<C>([
+                tensor.node.clone(),
+                scale.node.clone(),
+                zero_point.node.clone(),
+            ])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let tensor_state = prep.checkpoint(&tensor);
+                let scale_state = prep.checkpoint(&scale);
+                let zero_point_state = prep.checkpoint(&zero_point);
+                prep.finish(
+                    (tensor_state, scale_state, zero_point_state, qmin, qmax),
+                    B::float_fake_quantize(
+                        tensor.primitive,
+                        scale.primitive,
+                        zero_point.primitive,
+                        qmin,
+                        qmax,
+                    ),
+                )
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_fake_quantize(
+                tensor.primitive,
+                scale.primitive,
+                zero_point.primitive,
+                qmin,
+                qmax,
+            )),
+        }
+    }
+
+    fn float_lerp<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        end: FloatTensor<Self, D>,
+        weight: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        #[derive(Debug, Clone)]
+        struct Lerp;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 3> for Lerp {
+            type State = (NodeID, NodeID, NodeID);
+
+            fn clone_backward(&self) -> Option<Self> {
+                Some(self.clone())
+            }
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 3>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let [node_tensor, node_end, node_weight] = ops.parents;
+                let grad = grads.consume::<B, D>(&ops.node);
+
+                let (tensor_state, end_state, weight_state) = ops.state;
+                let tensor: B::FloatTensorPrimitive<D> =
+                    checkpointer.retrieve_node_output(tensor_state);
+                let end: B::FloatTensorPrimitive<D> = checkpointer.retrieve_node_output(end_state);
+                let weight: B::FloatTensorPrimitive<D> =
+                    checkpointer.retrieve_node_output(weight_state);
+
+                if let Some(node) = node_weight {
+                    let delta = B::float_sub(end.clone(), tensor.clone());
+                    grads.register::<B, D>(
+                        node.id,
+                        broadcast_shape::<B, D>(
+                            B::float_mul(grad.clone(), delta),
+                            &B::float_shape(&weight),
+                        ),
+                    );
+                }
+                if let Some(node) = node_end {
+                    grads.register::<B, D>(
+                        node.id,
+                        broadcast_shape::<B, D>(
+                            B::float_mul(grad.clone(), weight.clone()),
+                            &B::float_shape(&end),
+                        ),
+                    );
+                }
+                if let Some(node) = node_tensor {
+                    // d/dx (x + w * (y - x)) = 1 - w
+                    let grad = B::float_sub(grad.clone(), B::float_mul(grad, weight));
+                    grads.register::<B, D>(
+                        node.id,
+                        broadcast_shape::<B, D>(grad, &B::float_shape(&tensor)),
+                    );
+                }
+            }
+        }
+
+        match Lerp
+            .prepare::<C>([tensor.node.clone(), end.node.clone(), weight.node.clone()])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let tensor_state = prep.checkpoint(&tensor);
+                let end_state = prep.checkpoint(&end);
+                let weight_state = prep.checkpoint(&weight);
+                prep.finish(
+                    (tensor_state, end_state, weight_state),
+                    B::float_lerp(tensor.primitive, end.primitive, weight.primitive),
+                )
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_lerp(
+                tensor.primitive,
+                end.primitive,
+                weight.primitive,
+            )),
+        }
+    }
+
+    fn float_lerp_scalar<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        end: FloatTensor<Self, D>,
+        weight: FloatElem<B>,
+    ) -> FloatTensor<Self, D> {
+        // The interpolation is the linear combination `(1 - weight) * tensor + weight * end`.
+        let alpha = (1.0 - weight.elem::<f64>()).elem();
+        let broadcast = BinaryOpsBroadcast::new::<B>(&tensor.primitive, &end.primitive);
+
+        match Axpby
+            .prepare::<C>([tensor.node.clone(), end.node.clone()])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => prep.finish(
+                (alpha, weight, broadcast),
+                B::float_lerp_scalar(tensor.primitive, end.primitive, weight),
+            ),
+            OpsKind::UnTracked(prep) => prep.finish(B::float_lerp_scalar(
+                tensor.primitive,
+                end.primitive,
+                weight,
+            )),
+        }
+    }
+
+    fn float_addcmul<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        tensor1: FloatTensor<Self, D>,
+        tensor2: FloatTensor<Self, D>,
+        value: FloatElem<B>,
+    ) -> FloatTensor<Self, D> {
+        #[derive(Debug, Clone)]
+        struct Addcmul;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 3> for Addcmul {
+            type State = (Shape<D>, NodeID, NodeID, FloatElem<B>);
+
+            fn clone_backward(&self) -> Option<Self> {
+                Some(self.clone())
+            }
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 3>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let [node_tensor, node_tensor1, node_tensor2] = ops.parents;
+                let grad = grads.consume::<B, D>(&ops.node);
+
+                let (shape, tensor1_state, tensor2_state, value) = ops.state;
+                let tensor1: B::FloatTensorPrimitive<D> =
+                    checkpointer.retrieve_node_output(tensor1_state);
+                let tensor2: B::FloatTensorPrimitive<D> =
+                    checkpointer.retrieve_node_output(tensor2_state);
+                let grad_scaled = B::float_mul_scalar(grad.clone(), value);
+
+                if let Some(node) = node_tensor1 {
+                    grads.register::<B, D>(
+                        node.id,
+                        broadcast_shape::<B, D>(
+                            B::float_mul(grad_scaled.clone(), tensor2.clone()),
+                            &B::float_shape(&tensor1),
+                        ),
+                    );
+                }
+                if let Some(node) = node_tensor2 {
+                    grads.register::<B, D>(
+                        node.id,
+                        broadcast_shape::<B, D>(
+                            B::float_mul(grad_scaled, tensor1),
+                            &B::float_shape(&tensor2),
+                        ),
+                    );
+                }
+                if let Some(node) = node_tensor {
+                    grads.register::<B, D>(node.id, broadcast_shape::<B, D>(grad, &shape));
+                }
+            }
+        }
+
+        match Addcmul
+            .prepare::<C>([
+                tensor.node.clone(),
+                tensor1.node.clone(),
+                tensor2.node.clone(),
+            ])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let shape = B::float_shape(&tensor.primitive);
+                let tensor1_state = prep.checkpoint(&tensor1);
+                let tensor2_state = prep.checkpoint(&tensor2);
+                prep.finish(
+                    (shape, tensor1_state, tensor2_state, value),
+                    B::float_addcmul(
+                        tensor.primitive,
+                        tensor1.primitive,
+                        tensor2.primitive,
+                        value,
+                    ),
+                )
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_addcmul(
+                tensor.primitive,
+                tensor1.primitive,
+                tensor2.primitive,
+                value,
+            )),
+        }
+    }
+
+    fn float_addcdiv<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        tensor1: FloatTensor<Self, D>,
+        tensor2: FloatTensor<Self, D>,
+        value: FloatElem<B>,
+    ) -> FloatTensor<Self, D> {
+        #[derive(Debug, Clone)]
+        struct Addcdiv;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 3> for Addcdiv {
+            type State = (Shape<D>, NodeID, NodeID, FloatElem<B>);
+
+            fn clone_backward(&self) -> Option<Self> {
+                Some(self.clone())
+            }
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 3>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let [node_tensor, node_tensor1, node_tensor2] = ops.parents;
+                let grad = grads.consume::<B, D>(&ops.node);
+
+                let (shape, tensor1_state, tensor2_state, value) = ops.state;
+                let tensor1: B::FloatTensorPrimitive<D> =
+                    checkpointer.retrieve_node_output(tensor1_state);
+                let tensor2: B::FloatTensorPrimitive<D> =
+                    checkpointer.retrieve_node_output(tensor2_state);
+                let grad_scaled =
+                    B::float_div(B::float_mul_scalar(grad.clone(), value), tensor2.clone());
+
+                if let Some(node) = node_tensor2 {
+                    // d/dz (x + v * y / z) = -v * y / z^2
+                    let ratio = B::float_neg(B::float_div(tensor1.clone(), tensor2.clone()));
+                    grads.register::<B, D>(
+                        node.id,
+                        broadcast_shape::<B, D>(
+                            B::float_mul(grad_scaled.clone(), ratio),
+                            &B::float_shape(&tensor2),
+                        ),
+                    );
+                }
+                if let Some(node) = node_tensor1 {
+                    grads.register::<B, D>(
+                        node.id,
+                        broadcast_shape::<B, D>(grad_scaled, &B::float_shape(&tensor1)),
+                    );
+                }
+                if let Some(node) = node_tensor {
+                    grads.register::<B, D>(node.id, broadcast_shape::<B, D>(grad, &shape));
+                }
+            }
+        }
+
+        match Addcdiv
+            .prepare::<C>([
+                tensor.node.clone(),
+                tensor1.node.clone(),
+                tensor2.node.clone(),
+            ])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let shape = B::float_shape(&tensor.primitive);
+                let tensor1_state = prep.checkpoint(&tensor1);
+                let tensor2_state = prep.checkpoint(&tensor2);
+                prep.finish(
+                    (shape, tensor1_state, tensor2_state, value),
+                    B::float_addcdiv(
+                        tensor.primitive,
+                        tensor1.primitive,
+                        tensor2.primitive,
+                        value,
+                    ),
+                )
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::float_addcdiv(
+                tensor.primitive,
+                tensor1.primitive,
+                tensor2.primitive,
+                value,
+            )),
+        }
+    }
+
+    fn float_axpby<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        alpha: FloatElem<B>,
+        other: FloatTensor<Self, D>,
+        beta: FloatElem<B>,
+    ) -> FloatTensor<Self, D> {
+        let broadcast = BinaryOpsBroadcast::new::<B>(&tensor.primitive, &other.primitive);
+
+        match Axpby
+            .prepare::<C>([tensor.node.clone(), other.node.clone()])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => prep.finish(
+                (alpha, beta, broadcast),
+                B::float_axpby(tensor.primitive, alpha, other.primitive, beta),
+            ),
+            OpsKind::UnTracked(prep) => prep.finish(B::float_axpby(
+                tensor.primitive,
+                alpha,
+                other.primitive,
+                beta,
+            )),
+        }
+    }
+
     // TODO: Implement float_prod and float_sum
     // https://github.com/tracel-ai/burn/issues/1458
 }
 
+/// The backward of the linear combination `alpha * lhs + beta * rhs`, shared by the
+/// [axpby](FloatTensorOps::float_axpby) and the [scalar lerp](FloatTensorOps::float_lerp_scalar).
+#[derive(Debug, Clone)]
+struct Axpby;
+
+impl<B: Backend, const D: usize> Backward<B, D, 2> for Axpby {
+    type State = (FloatElem<B>, FloatElem<B>, BinaryOpsBroadcast<D>);
+
+    fn clone_backward(&self) -> Option<Self> {
+        Some(self.clone())
+    }
+
+    fn backward(
+        self,
+        ops: Ops<Self::State, 2>,
+        grads: &mut Gradients,
+        _checkpointer: &mut Checkpointer,
+    ) {
+        let (alpha, beta, broadcast) = ops.state;
+
+        binary::<B, D, D, D, _, _>(
+            ops.parents,
+            ops.node,
+            grads,
+            |grad| broadcast.backward_lhs::<B>(B::float_mul_scalar(grad, alpha)),
+            |grad| broadcast.backward_rhs::<B>(B::float_mul_scalar(grad, beta)),
+        );
+    }
+}
+
+/// Whether the gradients of the lhs and of the rhs of a matmul have to be summed over the batches
+/// of the output, the batch dimensions being broadcasted.
+fn batches_reduced<B: Backend, const D: usize>(
+    lhs: &B::FloatTensorPrimitive<D>,
+    rhs: &B::FloatTensorPrimitive<D>,
+) -> [bool; 2] {
+    let shape_lhs = B::float_shape(lhs);
+    let shape_rhs = B::float_shape(rhs);
+    let reduced = |shape: &Shape<D>, other: &Shape<D>| {
+        (0..D.saturating_sub(2)).any(|i| shape.dims[i] == 1 && other.dims[i] != 1)
+    };
+
+    [
+        reduced(&shape_lhs, &shape_rhs),
+        reduced(&shape_rhs, &shape_lhs),
+    ]
+}
+
+/// Reshape the batches of a tensor into the rows of a single matrix, keeping the batch dimensions
+/// with a size of 1.
+fn flatten_batches<B: Backend, const D: usize>(
+    tensor: B::FloatTensorPrimitive<D>,
+) -> B::FloatTensorPrimitive<D> {
+    let shape = B::float_shape(&tensor);
+    let mut dims = [1; D];
+    dims[D - 2] = shape.dims[..D - 1].iter().product();
+    dims[D - 1] = shape.dims[D - 1];
+
+    B::float_reshape(tensor, Shape::new(dims))
+}
+
 #[derive(Debug, Clone)]
 enum BinaryOpsBroadcast<const D: usize> {
     Broadcasted(Shape<D>, Shape<D>),
@@ -2499,6 +3674,16 @@ impl<const D: usize> BinaryOpsBroadcast<D> {
         Self::None
     }
 
+    /// Whether the rhs has a single batch, broadcasted to every batch of the lhs.
+    fn rhs_shared(&self) -> bool {
+        match self {
+            BinaryOpsBroadcast::Broadcasted(_lhs, rhs) => {
+                rhs.dims[..D.saturating_sub(2)].iter().all(|dim| *dim == 1)
+            }
+            BinaryOpsBroadcast::None => false,
+        }
+    }
+
     fn backward_lhs<B: Backend>(
         &self,
         grad: B::FloatTensorPrimitive<D>,
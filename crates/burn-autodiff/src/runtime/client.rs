@@ -1,8 +1,9 @@
 use crate::{
     checkpoint::builder::CheckpointerBuilder,
     grads::Gradients,
-    graph::StepBoxed,
+    graph::{GradHookBoxed, StepBoxed},
     tensor::{AutodiffTensor, NodeRefCount},
+    NodeID,
 };
 use burn_tensor::backend::Backend;
 
@@ -10,8 +11,16 @@ use burn_tensor::backend::Backend;
 pub trait AutodiffClient: Send + Clone {
     /// Register a new step.
     fn register(&self, node_id: NodeRefCount, step: StepBoxed, actions: CheckpointerBuilder);
+    /// Register a hook on the gradient of a node.
+    fn register_hook(&self, node_id: NodeID, hook: GradHookBoxed);
     /// Call backpropagation from the given tensor.
     fn backward<B: Backend, const D: usize>(&self, tensor: AutodiffTensor<B, D>) -> Gradients;
+    /// Call backpropagation from the given tensor, retaining the graph for the backward passes
+    /// that follow.
+    fn backward_retained<B: Backend, const D: usize>(
+        &self,
+        tensor: AutodiffTensor<B, D>,
+    ) -> Gradients;
 }
 
 /// Client implementation in used.
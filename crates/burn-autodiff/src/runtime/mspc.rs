@@ -2,7 +2,7 @@ use super::{server::AutodiffServer, AutodiffClient};
 use crate::{
     checkpoint::builder::CheckpointerBuilder,
     grads::Gradients,
-    graph::StepBoxed,
+    graph::{GradHookBoxed, StepBoxed},
     tensor::{AutodiffTensor, NodeRefCount},
     NodeID,
 };
@@ -22,9 +22,14 @@ enum Message {
         step: StepBoxed,
         actions: CheckpointerBuilder,
     },
+    RegisterHook {
+        node_id: NodeID,
+        hook: GradHookBoxed,
+    },
     Backward {
         node_id: NodeID,
         grads: Gradients,
+        retained: bool,
         callback: Sender<Gradients>,
     },
 }
@@ -46,12 +51,17 @@ impl ChannelClient {
                         step,
                         actions,
                     } => server.register(node_id, step, actions),
+                    Message::RegisterHook { node_id, hook } => server.register_hook(node_id, hook),
                     Message::Backward {
                         node_id,
                         grads,
+                        retained,
                         callback,
                     } => {
-                        let grads = server.backward(grads, node_id);
+                        let grads = match retained {
+                            true => server.backward_retained(grads, node_id),
+                            false => server.backward(grads, node_id),
+                        };
                         callback.send(grads).unwrap();
                     }
                 }
@@ -60,20 +70,12 @@ impl ChannelClient {
 
         Self { sender }
     }
-}
-
-impl AutodiffClient for ChannelClient {
-    fn register(&self, node_id: NodeRefCount, step: StepBoxed, actions: CheckpointerBuilder) {
-        self.sender
-            .send(Message::Register {
-                node_id,
-                step,
-                actions,
-            })
-            .unwrap()
-    }
 
-    fn backward<B: Backend, const D: usize>(&self, root: AutodiffTensor<B, D>) -> Gradients {
+    fn send_backward<B: Backend, const D: usize>(
+        &self,
+        root: AutodiffTensor<B, D>,
+        retained: bool,
+    ) -> Gradients {
         let node_id = root.node.id;
         let grads = Gradients::new::<B, D>(root.node, root.primitive);
         let (callback, receiver) = std::sync::mpsc::channel();
@@ -82,6 +84,7 @@ impl AutodiffClient for ChannelClient {
             .send(Message::Backward {
                 node_id,
                 grads,
+                retained,
                 callback,
             })
             .unwrap();
@@ -92,3 +95,32 @@ impl AutodiffClient for ChannelClient {
         }
     }
 }
+
+impl AutodiffClient for ChannelClient {
+    fn register(&self, node_id: NodeRefCount, step: StepBoxed, actions: CheckpointerBuilder) {
+        self.sender
+            .send(Message::Register {
+                node_id,
+                step,
+                actions,
+            })
+            .unwrap()
+    }
+
+    fn register_hook(&self, node_id: NodeID, hook: GradHookBoxed) {
+        self.sender
+            .send(Message::RegisterHook { node_id, hook })
+            .unwrap()
+    }
+
+    fn backward<B: Backend, const D: usize>(&self, root: AutodiffTensor<B, D>) -> Gradients {
+        self.send_backward(root, false)
+    }
+
+    fn backward_retained<B: Backend, const D: usize>(
+        &self,
+        root: AutodiffTensor<B, D>,
+    ) -> Gradients {
+        self.send_backward(root, true)
+    }
+}
@@ -2,8 +2,9 @@ use super::{server::AutodiffServer, AutodiffClient};
 use crate::{
     checkpoint::builder::CheckpointerBuilder,
     grads::Gradients,
-    graph::StepBoxed,
+    graph::{GradHookBoxed, StepBoxed},
     tensor::{AutodiffTensor, NodeRefCount},
+    NodeID,
 };
 use burn_tensor::backend::Backend;
 
@@ -31,6 +32,13 @@ impl AutodiffClient for MutexClient {
         server_new.register(node_id, step, actions);
         *server = Some(server_new);
     }
+    fn register_hook(&self, node_id: NodeID, hook: GradHookBoxed) {
+        let mut server = SERVER.lock();
+
+        server
+            .get_or_insert_with(AutodiffServer::default)
+            .register_hook(node_id, hook);
+    }
     fn backward<B: Backend, const D: usize>(&self, root: AutodiffTensor<B, D>) -> Gradients {
         let mut server = SERVER.lock();
         let node_id = root.node.id;
@@ -46,4 +54,16 @@ impl AutodiffClient for MutexClient {
 
         gradients
     }
+    fn backward_retained<B: Backend, const D: usize>(
+        &self,
+        root: AutodiffTensor<B, D>,
+    ) -> Gradients {
+        let mut server = SERVER.lock();
+        let node_id = root.node.id;
+        let grads = Gradients::new::<B, D>(root.node, root.primitive);
+
+        server
+            .get_or_insert_with(AutodiffServer::default)
+            .backward_retained(grads, node_id)
+    }
 }
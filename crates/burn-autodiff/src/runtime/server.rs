@@ -2,16 +2,17 @@ use super::memory_management::GraphMemoryManagement;
 use crate::{
     checkpoint::{base::Checkpointer, builder::CheckpointerBuilder},
     grads::Gradients,
-    graph::{traversal::BreadthFirstSearch, StepBoxed},
+    graph::{traversal::BreadthFirstSearch, GradHookBoxed, StepBoxed},
     tensor::NodeRefCount,
     NodeID,
 };
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 #[derive(Default)]
 pub struct AutodiffServer {
     steps: HashMap<NodeID, StepBoxed>,
     actions_builder: HashMap<NodeID, CheckpointerBuilder>,
+    hooks: HashMap<NodeID, Vec<GradHookBoxed>>,
     memory_management: GraphMemoryManagement,
 }
 
@@ -26,6 +27,10 @@ impl AutodiffServer {
         self.actions_builder.insert(node_id, actions);
     }
 
+    pub fn register_hook(&mut self, node_id: NodeID, hook: GradHookBoxed) {
+        self.hooks.entry(node_id).or_default().push(hook);
+    }
+
     pub fn backward(&mut self, grads: Gradients, node_id: NodeID) -> Gradients {
         let step = self.steps.remove(&node_id).expect(
             "Node should have a step registered, did you forget to call \
@@ -33,33 +38,109 @@ impl AutodiffServer {
         );
         let builder = self.actions_builder.remove(&node_id).unwrap();
 
-        let (tape, builder) = self.build_tape(node_id, step, builder);
+        let (tape, builder, hooks) = Self::build_tape(
+            node_id,
+            step,
+            builder,
+            |id| self.steps.remove(&id),
+            |id| self.actions_builder.remove(&id),
+            &mut self.hooks,
+            |id| self.memory_management.consume_node(id),
+        );
         let checkpointer = builder.build(&self.steps);
 
-        let gradients = Self::execute_steps(tape, grads, checkpointer);
+        let (gradients, _) = Self::execute_steps(tape, grads, checkpointer, hooks);
+
+        self.cleanup();
+
+        gradients
+    }
+
+    /// Call backpropagation from the given node on clones of its steps and checkpointing
+    /// actions, leaving the graph intact for the backward passes that follow.
+    ///
+    /// The nodes aren't consumed, so they are only freed once their tensors are dropped and no
+    /// tensor depending on them remains, like the nodes that no backward pass reached.
+    ///
+    /// Only the steps reached from the node are cloned, and the hooks are registered again once
+    /// called, so that they are called by every backward pass reaching them.
+    pub fn backward_retained(&mut self, grads: Gradients, node_id: NodeID) -> Gradients {
+        let step = self.steps.get(&node_id).expect(
+            "Node should have a step registered, did you forget to call \
+             `Tensor::register_grad` on the tensor where you need gradients?",
+        );
+        let step = Self::clone_step(step);
+        let builder = self.actions_builder.get(&node_id).unwrap().clone();
+
+        let mut traversed = HashSet::new();
+        let (tape, builder, hooks) = Self::build_tape(
+            node_id,
+            step,
+            builder,
+            |id| self.steps.get(&id).map(Self::clone_step),
+            |id| self.actions_builder.get(&id).cloned(),
+            &mut self.hooks,
+            |id| {
+                traversed.insert(id);
+            },
+        );
+        let checkpointer =
+            builder.build(self.steps.iter().filter(|(id, _)| !traversed.contains(*id)));
+
+        let (gradients, hooks) = Self::execute_steps(tape, grads, checkpointer, hooks);
+
+        for (id, node_hooks) in hooks {
+            self.hooks.entry(id).or_default().extend(node_hooks);
+        }
 
-        // Cleanup
+        self.cleanup();
+
+        gradients
+    }
+
+    fn clone_step(step: &StepBoxed) -> StepBoxed {
+        step.clone_boxed().unwrap_or_else(|| {
+            panic!(
+                "The step of the node {:?} can't be cloned to retain the graph, its backward \
+                 operation should implement `Backward::clone_backward`.",
+                step.node()
+            )
+        })
+    }
+
+    fn cleanup(&mut self) {
         self.memory_management
             .free_unavailable_nodes(|node_id: &NodeID| {
                 self.steps.remove(node_id);
                 self.actions_builder.remove(node_id);
+                self.hooks.remove(node_id);
             });
-
-        gradients
     }
 
     fn build_tape(
-        &mut self,
         node: NodeID,
         node_step: StepBoxed,
         mut builder: CheckpointerBuilder,
-    ) -> (Vec<Vec<StepBoxed>>, CheckpointerBuilder) {
+        take_step: impl FnMut(NodeID) -> Option<StepBoxed>,
+        mut take_actions: impl FnMut(NodeID) -> Option<CheckpointerBuilder>,
+        hooks: &mut HashMap<NodeID, Vec<GradHookBoxed>>,
+        mut on_traversed: impl FnMut(NodeID),
+    ) -> (
+        Vec<Vec<StepBoxed>>,
+        CheckpointerBuilder,
+        HashMap<NodeID, Vec<GradHookBoxed>>,
+    ) {
         let mut tape = (0..node_step.depth())
             .map(|_| Vec::with_capacity(1))
             .collect::<Vec<_>>();
+        let mut tape_hooks = HashMap::new();
+
+        BreadthFirstSearch.traverse_with(node, node_step, take_step, |id, step| {
+            on_traversed(id);
 
-        BreadthFirstSearch.traverse(node, node_step, &mut self.steps, |id, step| {
-            self.memory_management.consume_node(id);
+            if let Some(node_hooks) = hooks.remove(&id) {
+                tape_hooks.insert(id, node_hooks);
+            }
 
             let depth = step.depth();
             if depth == 0 {
@@ -70,28 +151,48 @@ impl AutodiffServer {
                 steps.push(step);
             }
 
-            if let Some(node_builder) = self.actions_builder.remove(&id) {
+            if let Some(node_builder) = take_actions(id) {
                 builder.extend(node_builder);
             }
         });
 
-        (tape, builder)
+        (tape, builder, tape_hooks)
     }
 
+    /// Executes the steps of the tape, returning the gradients along with the called hooks.
     fn execute_steps(
         tape: Vec<Vec<StepBoxed>>,
         mut grads: Gradients,
         mut checkpointer: Checkpointer,
-    ) -> Gradients {
+        mut hooks: HashMap<NodeID, Vec<GradHookBoxed>>,
+    ) -> (Gradients, HashMap<NodeID, Vec<GradHookBoxed>>) {
+        let mut called = HashMap::with_capacity(hooks.len());
+
         tape.into_iter().rev().for_each(|steps| {
-            steps
-                .into_iter()
-                .for_each(|step| step.step(&mut grads, &mut checkpointer))
+            steps.into_iter().for_each(|step| {
+                // The children of the node were executed first, so its gradient is complete
+                // before its own step reads it.
+                if let Some(mut node_hooks) = hooks.remove(&step.node()) {
+                    for hook in node_hooks.iter_mut() {
+                        hook.apply(&mut grads);
+                    }
+                    called.insert(step.node(), node_hooks);
+                }
+                step.step(&mut grads, &mut checkpointer)
+            })
         });
 
+        // The gradients of the leaves aren't read by any step.
+        for (id, mut node_hooks) in hooks {
+            for hook in node_hooks.iter_mut() {
+                hook.apply(&mut grads);
+            }
+            called.insert(id, node_hooks);
+        }
+
         #[cfg(feature = "export_tests")]
         // For checkpointing tests
         assert!(checkpointer.is_empty());
-        grads
+        (grads, called)
     }
 }
@@ -1,9 +1,12 @@
-use std::sync::Arc;
+use std::sync::{atomic::AtomicBool, Arc};
 
 use crate::{
     checkpoint::{base::Checkpointer, builder::CheckpointerBuilder},
     grads::Gradients,
-    graph::{ComputingProperty, Node, NodeID, NodeRef, Requirement, Step},
+    graph::{
+        ComputingProperty, FloatGradHook, GradHookHandle, Node, NodeID, NodeRef, Requirement, Step,
+        StepBoxed,
+    },
     runtime::{AutodiffClient, AutodiffClientImpl},
 };
 use burn_tensor::backend::Backend;
@@ -17,7 +20,7 @@ pub struct AutodiffTensor<B: Backend, const D: usize> {
 
 pub type NodeRefCount = Arc<NodeID>;
 
-#[derive(new, Debug)]
+#[derive(new, Debug, Clone)]
 pub(crate) struct RootStep {
     node: NodeRef,
 }
@@ -27,6 +30,10 @@ impl Step for RootStep {
         // Nothing to do
     }
 
+    fn clone_boxed(&self) -> Option<StepBoxed> {
+        Some(Box::new(self.clone()))
+    }
+
     fn node(&self) -> NodeID {
         self.node.id
     }
@@ -151,6 +158,35 @@ impl<B: Backend, const D: usize> AutodiffTensor<B, D> {
         self
     }
 
+    /// Register a hook called with the gradient of the tensor during the backward pass, once all
+    /// the contributions to it are accumulated and before the steps of its parents read it. The
+    /// gradient is replaced by the one returned by the hook, if any.
+    ///
+    /// The hook is called once by every backward pass reaching the tensor, including the ones
+    /// retaining the graph, unless it is [removed](GradHookHandle::remove) before.
+    ///
+    /// # Panics
+    ///
+    /// It panics if the tensor is not tracked.
+    pub fn register_grad_hook<F>(&self, hook: F) -> GradHookHandle
+    where
+        F: FnMut(&B::FloatTensorPrimitive<D>) -> Option<B::FloatTensorPrimitive<D>>
+            + Send
+            + 'static,
+    {
+        assert!(
+            self.is_tracked(),
+            "Can't register a gradient hook on a tensor that isn't tracked"
+        );
+
+        let hook =
+            FloatGradHook::<B, D, F>::new(self.node.id, hook, Arc::new(AtomicBool::new(true)));
+        let handle = hook.handle();
+        self.node.client.register_hook(self.node.id, Box::new(hook));
+
+        handle
+    }
+
     pub fn into_primitive(self) -> B::FloatTensorPrimitive<D> {
         self.primitive
     }
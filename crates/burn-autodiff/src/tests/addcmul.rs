@@ -0,0 +1,75 @@
+#[burn_tensor_testgen::testgen(ad_addcmul)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Distribution, Tensor};
+
+    #[test]
+    fn should_diff_addcmul_like_the_composed_ops() {
+        let device = Default::default();
+        let data_1 = TestAutodiffTensor::<2>::random([3, 4], Distribution::Default, &device);
+        let data_2 = TestAutodiffTensor::<2>::random([3, 4], Distribution::Default, &device);
+        let data_3 = TestAutodiffTensor::<2>::random([3, 1], Distribution::Default, &device);
+
+        let grads = |fused: bool| {
+            let tensor = data_1.clone().detach().require_grad();
+            let tensor1 = data_2.clone().detach().require_grad();
+            let tensor2 = data_3.clone().detach().require_grad();
+
+            let output = match fused {
+                true => tensor
+                    .clone()
+                    .addcmul(tensor1.clone(), tensor2.clone(), 0.5),
+                false => tensor.clone() + tensor1.clone().mul_scalar(0.5) * tensor2.clone(),
+            };
+            let grads = (output.clone() * output).sum().backward();
+
+            [
+                tensor.grad(&grads).unwrap(),
+                tensor1.grad(&grads).unwrap(),
+                tensor2.grad(&grads).unwrap(),
+            ]
+        };
+
+        assert_same_grads(grads(true), grads(false));
+    }
+
+    #[test]
+    fn should_diff_addcdiv_like_the_composed_ops() {
+        let device = Default::default();
+        let data_1 = TestAutodiffTensor::<2>::random([3, 4], Distribution::Default, &device);
+        let data_2 = TestAutodiffTensor::<2>::random([3, 4], Distribution::Default, &device);
+        let data_3 =
+            TestAutodiffTensor::<2>::random([3, 4], Distribution::Uniform(1.0, 2.0), &device);
+
+        let grads = |fused: bool| {
+            let tensor = data_1.clone().detach().require_grad();
+            let tensor1 = data_2.clone().detach().require_grad();
+            let tensor2 = data_3.clone().detach().require_grad();
+
+            let output = match fused {
+                true => tensor
+                    .clone()
+                    .addcdiv(tensor1.clone(), tensor2.clone(), -2.0),
+                false => tensor.clone() + tensor1.clone().mul_scalar(-2.0) / tensor2.clone(),
+            };
+            let grads = (output.clone() * output).sum().backward();
+
+            [
+                tensor.grad(&grads).unwrap(),
+                tensor1.grad(&grads).unwrap(),
+                tensor2.grad(&grads).unwrap(),
+            ]
+        };
+
+        assert_same_grads(grads(true), grads(false));
+    }
+
+    fn assert_same_grads<const N: usize>(
+        actual: [Tensor<TestBackend, 2>; N],
+        expected: [Tensor<TestBackend, 2>; N],
+    ) {
+        for (actual, expected) in actual.into_iter().zip(expected) {
+            actual.to_data().assert_approx_eq(&expected.to_data(), 4);
+        }
+    }
+}
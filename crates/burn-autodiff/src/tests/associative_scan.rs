@@ -0,0 +1,106 @@
+#[burn_tensor_testgen::testgen(ad_associative_scan)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Distribution, Tensor, TensorData};
+
+    /// The recurrence unrolled with differentiable slices along the last dimension.
+    fn unrolled(a: TestAutodiffTensor<2>, b: TestAutodiffTensor<2>) -> TestAutodiffTensor<2> {
+        let [rows, length] = a.dims();
+        let mut state = b.clone().slice([0..rows, 0..1]);
+        let mut outputs = vec![state.clone()];
+
+        for t in 1..length {
+            state =
+                a.clone().slice([0..rows, t..t + 1]) * state + b.clone().slice([0..rows, t..t + 1]);
+            outputs.push(state.clone());
+        }
+
+        Tensor::cat(outputs, 1)
+    }
+
+    #[test]
+    fn should_diff_associative_scan() {
+        let device = Default::default();
+        let a = TestAutodiffTensor::<2>::from_floats(
+            [[0.5, -1.0, 2.0, 0.3, 1.5], [1.0, 0.9, -0.4, 0.7, 1.1]],
+            &device,
+        );
+        let b = TestAutodiffTensor::<2>::from_floats(
+            [[1.0, 2.0, -1.0, 0.5, 3.0], [-2.0, 0.1, 0.4, 1.2, -0.6]],
+            &device,
+        );
+        let weights = TestAutodiffTensor::<2>::from_floats(
+            [[1.0, -2.0, 0.5, 3.0, 1.0], [0.2, 1.0, -1.0, 2.0, 0.5]],
+            &device,
+        );
+
+        let a_1 = a.clone().require_grad();
+        let b_1 = b.clone().require_grad();
+        let grads_1 = (Tensor::associative_scan(a_1.clone(), b_1.clone(), 1) * weights.clone())
+            .sum()
+            .backward();
+
+        let a_2 = a.require_grad();
+        let b_2 = b.require_grad();
+        let grads_2 = (unrolled(a_2.clone(), b_2.clone()) * weights)
+            .sum()
+            .backward();
+
+        a_1.grad(&grads_1)
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&a_2.grad(&grads_2).unwrap().to_data(), 4);
+        b_1.grad(&grads_1)
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&b_2.grad(&grads_2).unwrap().to_data(), 4);
+    }
+
+    #[test]
+    fn should_diff_associative_scan_along_first_dim() {
+        let device = Default::default();
+        let a = TestAutodiffTensor::<2>::random([13, 3], Distribution::Uniform(0.9, 1.1), &device);
+        let b = TestAutodiffTensor::<2>::random([13, 3], Distribution::Uniform(-1.0, 1.0), &device);
+
+        let a_1 = a.clone().require_grad();
+        let b_1 = b.clone().require_grad();
+        let grads_1 = Tensor::associative_scan(a_1.clone(), b_1.clone(), 0)
+            .sum()
+            .backward();
+
+        let a_2 = a.require_grad();
+        let b_2 = b.require_grad();
+        let grads_2 = unrolled(a_2.clone().transpose(), b_2.clone().transpose())
+            .sum()
+            .backward();
+
+        a_1.grad(&grads_1)
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&a_2.grad(&grads_2).unwrap().to_data(), 3);
+        b_1.grad(&grads_1)
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&b_2.grad(&grads_2).unwrap().to_data(), 3);
+    }
+
+    #[test]
+    fn should_diff_associative_scan_of_length_one() {
+        let device = Default::default();
+        let a = TestAutodiffTensor::<2>::from_floats([[2.0], [3.0]], &device).require_grad();
+        let b = TestAutodiffTensor::<2>::from_floats([[1.0], [-1.0]], &device).require_grad();
+
+        let grads = (Tensor::associative_scan(a.clone(), b.clone(), 1) * 2.0)
+            .sum()
+            .backward();
+
+        a.grad(&grads)
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&TensorData::from([[0.0], [0.0]]), 5);
+        b.grad(&grads)
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&TensorData::from([[2.0], [2.0]]), 5);
+    }
+}
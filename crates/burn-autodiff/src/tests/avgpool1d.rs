@@ -14,6 +14,7 @@ mod tests {
             stride: 1,
             length: 6,
             count_include_pad: true,
+            ceil_mode: false,
         };
 
         test.assert_output(TestTensor::from_floats(
@@ -32,6 +33,7 @@ mod tests {
             stride: 2,
             length: 6,
             count_include_pad: true,
+            ceil_mode: false,
         };
 
         test.assert_output(TestTensor::from_floats(
@@ -53,6 +55,7 @@ mod tests {
             stride: 2,
             length: 6,
             count_include_pad: false,
+            ceil_mode: false,
         };
 
         test.assert_output(TestTensor::from_floats(
@@ -64,6 +67,44 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_avg_pool1d_ceil_mode() {
+        let test = AvgPool1dTestCase {
+            batch_size: 1,
+            channels: 1,
+            kernel_size: 3,
+            padding: 1,
+            stride: 2,
+            length: 6,
+            count_include_pad: true,
+            ceil_mode: true,
+        };
+
+        test.assert_output(TestTensor::from_floats(
+            [[[0.3333, 0.6667, 0.3333, 0.6667, 0.3333, 0.8333]]],
+            &Default::default(),
+        ));
+    }
+
+    #[test]
+    fn test_avg_pool1d_ceil_mode_dont_count_pad() {
+        let test = AvgPool1dTestCase {
+            batch_size: 1,
+            channels: 1,
+            kernel_size: 3,
+            padding: 1,
+            stride: 2,
+            length: 6,
+            count_include_pad: false,
+            ceil_mode: true,
+        };
+
+        test.assert_output(TestTensor::from_floats(
+            [[[0.5000, 0.8333, 0.3333, 0.6667, 0.3333, 1.3333]]],
+            &Default::default(),
+        ));
+    }
+
     struct AvgPool1dTestCase {
         batch_size: usize,
         channels: usize,
@@ -72,6 +113,7 @@ mod tests {
         stride: usize,
         length: usize,
         count_include_pad: bool,
+        ceil_mode: bool,
     }
 
     impl AvgPool1dTestCase {
@@ -91,6 +133,7 @@ mod tests {
                 self.stride,
                 self.padding,
                 self.count_include_pad,
+                self.ceil_mode,
             );
             let grads = output.backward();
             let x_grad_actual = x.grad(&grads).unwrap();
@@ -18,6 +18,7 @@ mod tests {
             height: 6,
             width: 6,
             count_include_pad: true,
+            ceil_mode: false,
         };
 
         test.assert_output(TestTensor::from_floats(
@@ -47,6 +48,7 @@ mod tests {
             height: 4,
             width: 6,
             count_include_pad: true,
+            ceil_mode: false,
         };
 
         test.assert_output(TestTensor::from_floats(
@@ -74,6 +76,7 @@ mod tests {
             height: 4,
             width: 6,
             count_include_pad: false,
+            ceil_mode: false,
         };
 
         test.assert_output(TestTensor::from_floats(
@@ -87,6 +90,64 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_avg_pool2d_ceil_mode() {
+        let test = AvgPool2dTestCase {
+            batch_size: 1,
+            channels: 1,
+            kernel_size_1: 3,
+            kernel_size_2: 2,
+            padding_1: 1,
+            padding_2: 0,
+            stride_1: 2,
+            stride_2: 2,
+            height: 5,
+            width: 5,
+            count_include_pad: true,
+            ceil_mode: true,
+        };
+
+        test.assert_output(TestTensor::from_floats(
+            [[[
+                [0.1667, 0.1667, 0.1667, 0.1667, 0.3333],
+                [0.3333, 0.3333, 0.3333, 0.3333, 0.6667],
+                [0.1667, 0.1667, 0.1667, 0.1667, 0.3333],
+                [0.3333, 0.3333, 0.3333, 0.3333, 0.6667],
+                [0.1667, 0.1667, 0.1667, 0.1667, 0.3333],
+            ]]],
+            &Default::default(),
+        ));
+    }
+
+    #[test]
+    fn test_avg_pool2d_ceil_mode_dont_include_pad() {
+        let test = AvgPool2dTestCase {
+            batch_size: 1,
+            channels: 1,
+            kernel_size_1: 3,
+            kernel_size_2: 2,
+            padding_1: 1,
+            padding_2: 0,
+            stride_1: 2,
+            stride_2: 2,
+            height: 5,
+            width: 5,
+            count_include_pad: false,
+            ceil_mode: true,
+        };
+
+        test.assert_output(TestTensor::from_floats(
+            [[[
+                [0.2500, 0.2500, 0.2500, 0.2500, 0.5000],
+                [0.4167, 0.4167, 0.4167, 0.4167, 0.8333],
+                [0.1667, 0.1667, 0.1667, 0.1667, 0.3333],
+                [0.4167, 0.4167, 0.4167, 0.4167, 0.8333],
+                [0.2500, 0.2500, 0.2500, 0.2500, 0.5000],
+            ]]],
+            &Default::default(),
+        ));
+    }
+
     struct AvgPool2dTestCase {
         batch_size: usize,
         channels: usize,
@@ -99,6 +160,7 @@ mod tests {
         height: usize,
         width: usize,
         count_include_pad: bool,
+        ceil_mode: bool,
     }
 
     impl AvgPool2dTestCase {
@@ -118,6 +180,7 @@ mod tests {
                 [self.stride_1, self.stride_2],
                 [self.padding_1, self.padding_2],
                 self.count_include_pad,
+                self.ceil_mode,
             );
             let grads = output.backward();
             let x_grad_actual = x.grad(&grads).unwrap();
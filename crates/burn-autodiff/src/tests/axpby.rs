@@ -0,0 +1,27 @@
+#[burn_tensor_testgen::testgen(ad_axpby)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn should_diff_axpby() {
+        let device = Default::default();
+        let tensor =
+            TestAutodiffTensor::<2>::from_data([[1.0, 2.0], [3.0, 4.0]], &device).require_grad();
+        let other = TestAutodiffTensor::<2>::from_data([[-1.0, 0.5]], &device).require_grad();
+
+        let output = tensor.clone().axpby(0.5, other.clone(), 3.0);
+        let grads = (output * tensor.clone()).sum().backward();
+
+        let grad_tensor = tensor.grad(&grads).unwrap();
+        let grad_other = other.grad(&grads).unwrap();
+
+        // d/dx (0.5 * x + 3 * y) * x = x + 3 * y, and the rows of y are broadcasted.
+        grad_tensor
+            .to_data()
+            .assert_approx_eq(&TensorData::from([[-2.0, 3.5], [0.0, 5.5]]), 5);
+        grad_other
+            .to_data()
+            .assert_approx_eq(&TensorData::from([[12.0, 18.0]]), 5);
+    }
+}
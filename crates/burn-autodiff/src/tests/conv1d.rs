@@ -1,7 +1,15 @@
 #[burn_tensor_testgen::testgen(ad_conv1d)]
 mod tests {
     use super::*;
-    use burn_tensor::{module::conv1d, ops::ConvOptions, Shape};
+    use burn_tensor::{
+        module::{conv1d, conv2d},
+        ops::{conv, ConvOptions},
+        Distribution, Shape,
+    };
+
+    /// The span of the kernel above which the tests compute the convolution in the frequency
+    /// domain, the same in every test since the threshold is global.
+    const FFT_THRESHOLD: usize = 256;
 
     #[test]
     fn test_conv1d_basic() {
@@ -202,6 +210,73 @@ mod tests {
         test.assert_grads(grads);
     }
 
+    #[test]
+    fn test_conv1d_groups_stride_2() {
+        // The stride doesn't evenly divide the padded length, so the weight gradient of each group
+        // is computed with a larger kernel that must be cropped.
+        let test = Conv1dTestCase {
+            batch_size: 1,
+            channels_in: 2,
+            channels_out: 4,
+            kernel_size: 3,
+            padding: 1,
+            stride: 2,
+            dilation: 1,
+            groups: 2,
+            length: 6,
+        };
+        let device = Default::default();
+        let grads = Grads {
+            x: TestTensor::from_floats(
+                [[[5., 10., 5., 10., 5., 7.], [17., 34., 17., 34., 17., 19.]]],
+                &device,
+            ),
+            weight: TestTensor::from_floats(
+                [
+                    [[4., 6., 9.]],
+                    [[4., 6., 9.]],
+                    [[16., 24., 27.]],
+                    [[16., 24., 27.]],
+                ],
+                &device,
+            ),
+            bias: TestTensor::from_floats([3., 3., 3., 3.], &device),
+        };
+        test.assert_grads(grads);
+    }
+
+    #[test]
+    fn test_conv1d_fft_large_dilation() {
+        let test = Conv1dTestCase {
+            batch_size: 2,
+            channels_in: 2,
+            channels_out: 3,
+            kernel_size: 5,
+            padding: 4,
+            stride: 1,
+            dilation: 60,
+            groups: 1,
+            length: 800,
+        };
+        test.assert_grads_match_direct();
+    }
+
+    #[test]
+    fn test_conv1d_fft_large_dilation_stride_groups() {
+        let test = Conv1dTestCase {
+            batch_size: 1,
+            channels_in: 4,
+            channels_out: 2,
+            kernel_size: 9,
+            padding: 2,
+            stride: 3,
+            dilation: 40,
+            groups: 2,
+            length: 500,
+        };
+        test.assert_grads_match_direct();
+    }
+
     struct Conv1dTestCase {
         batch_size: usize,
         channels_in: usize,
@@ -275,5 +350,75 @@ mod tests {
                 .to_data()
                 .assert_approx_eq(&x_grad_actual.to_data(), 3);
         }
+
+        /// Compares the gradients of the convolution in the frequency domain, selected for large
+        /// kernel spans, with the ones of the direct convolution computed as a 2D convolution.
+        fn assert_grads_match_direct(self) {
+            conv::set_conv1d_fft_threshold(Some(FFT_THRESHOLD));
+            assert!(self.kernel_size * self.dilation > FFT_THRESHOLD);
+
+            let device = Default::default();
+            let x = TestAutodiffTensor::<3>::random(
+                [self.batch_size, self.channels_in, self.length],
+                Distribution::Default,
+                &device,
+            )
+            .require_grad();
+            let weight = TestAutodiffTensor::<3>::random(
+                [
+                    self.channels_out,
+                    self.channels_in / self.groups,
+                    self.kernel_size,
+                ],
+                Distribution::Default,
+                &device,
+            )
+            .require_grad();
+            let bias = TestAutodiffTensor::<1>::random(
+                [self.channels_out],
+                Distribution::Default,
+                &device,
+            )
+            .require_grad();
+
+            let output = conv1d(
+                x.clone(),
+                weight.clone(),
+                Some(bias.clone()),
+                ConvOptions::new([self.stride], [self.padding], [self.dilation], self.groups),
+            );
+            let output_direct = conv2d(
+                x.clone().unsqueeze_dim::<4>(3),
+                weight.clone().unsqueeze_dim::<4>(3),
+                Some(bias.clone()),
+                ConvOptions::new(
+                    [self.stride, 1],
+                    [self.padding, 0],
+                    [self.dilation, 1],
+                    self.groups,
+                ),
+            )
+            .squeeze::<3>(3);
+
+            // Weight the outputs so that the output gradient isn't uniform.
+            let scale =
+                TestAutodiffTensor::<3>::random(output.shape(), Distribution::Default, &device);
+            let grads = (output * scale.clone()).sum().backward();
+            let grads_direct = (output_direct * scale).sum().backward();
+
+            for (actual, expected) in [
+                (x.grad(&grads), x.grad(&grads_direct)),
+                (weight.grad(&grads), weight.grad(&grads_direct)),
+            ] {
+                expected
+                    .unwrap()
+                    .to_data()
+                    .assert_approx_eq(&actual.unwrap().to_data(), 2);
+            }
+            bias.grad(&grads_direct)
+                .unwrap()
+                .to_data()
+                .assert_approx_eq(&bias.grad(&grads).unwrap().to_data(), 2);
+        }
     }
 }
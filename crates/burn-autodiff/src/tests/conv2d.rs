@@ -1,7 +1,7 @@
 #[burn_tensor_testgen::testgen(ad_conv2d)]
 mod tests {
     use super::*;
-    use burn_tensor::{module::conv2d, ops::ConvOptions, Shape};
+    use burn_tensor::{module::conv2d, ops::ConvOptions, Distribution, ElementConversion, Shape};
 
     #[test]
     fn test_conv2d_basic() {
@@ -810,6 +810,38 @@ mod tests {
         test.assert_grads(grads);
     }
 
+    #[test]
+    fn test_conv2d_groups_stride_dilation_finite_differences() {
+        for groups in [1, 2, 3] {
+            for stride in [1, 2, 3] {
+                for dilation in [1, 2] {
+                    // An odd and an even height, so that the stride doesn't always evenly divide
+                    // the padded input.
+                    for height in [6, 7] {
+                        let test = Conv2dTestCase {
+                            batch_size: 2,
+                            channels_in: 2 * groups,
+                            channels_out: 3 * groups,
+                            kernel_size_1: 3,
+                            kernel_size_2: 2,
+                            padding_1: 1,
+                            padding_2: 0,
+                            stride_1: stride,
+                            stride_2: 1,
+                            dilation_1: dilation,
+                            dilation_2: dilation,
+                            groups,
+                            height,
+                            width: 5,
+                        };
+                        test.assert_finite_differences();
+                    }
+                }
+            }
+        }
+    }
+
+    #[derive(Debug)]
     struct Conv2dTestCase {
         batch_size: usize,
         channels_in: usize,
@@ -834,6 +866,95 @@ mod tests {
     }
 
     impl Conv2dTestCase {
+        /// Compare the gradients with central finite differences along random directions.
+        ///
+        /// The loss `sum(conv2d(x, weight, bias) * output_grad)` is linear in each of the inputs, so
+        /// the finite differences are exact up to the float precision.
+        fn assert_finite_differences(self) {
+            let shape_x = Shape::new([self.batch_size, self.channels_in, self.height, self.width]);
+            let shape_weight = Shape::new([
+                self.channels_out,
+                self.channels_in / self.groups,
+                self.kernel_size_1,
+                self.kernel_size_2,
+            ]);
+            let options = ConvOptions::new(
+                [self.stride_1, self.stride_2],
+                [self.padding_1, self.padding_2],
+                [self.dilation_1, self.dilation_2],
+                self.groups,
+            );
+            let device = Default::default();
+            let random =
+                |shape: Shape<4>| TestTensor::random(shape, Distribution::Default, &device);
+
+            let x = random(shape_x.clone());
+            let weight = random(shape_weight.clone());
+            let bias = TestTensor::<1>::random([self.channels_out], Distribution::Default, &device);
+            let output_grad = random(
+                conv2d(
+                    x.clone(),
+                    weight.clone(),
+                    Some(bias.clone()),
+                    options.clone(),
+                )
+                .shape(),
+            );
+
+            let loss = |x: TestTensor<4>, weight: TestTensor<4>, bias: TestTensor<1>| {
+                (conv2d(x, weight, Some(bias), options.clone()) * output_grad.clone())
+                    .sum()
+                    .into_scalar()
+                    .elem::<f32>()
+            };
+
+            let x_ad = TestAutodiffTensor::from_inner(x.clone()).require_grad();
+            let weight_ad = TestAutodiffTensor::from_inner(weight.clone()).require_grad();
+            let bias_ad = TestAutodiffTensor::from_inner(bias.clone()).require_grad();
+            let output = conv2d(
+                x_ad.clone(),
+                weight_ad.clone(),
+                Some(bias_ad.clone()),
+                options.clone(),
+            );
+            let grads = (output * TestAutodiffTensor::from_inner(output_grad.clone()))
+                .sum()
+                .backward();
+
+            let x_grad = x_ad.grad(&grads).unwrap();
+            let weight_grad = weight_ad.grad(&grads).unwrap();
+            let bias_grad = bias_ad.grad(&grads).unwrap();
+
+            let assert_close = |name: &str, actual: f32, expected: f32| {
+                let tolerance = 1e-3 * expected.abs().max(1.0);
+                assert!(
+                    (actual - expected).abs() <= tolerance,
+                    "Gradient of {name} {actual} != {expected} for {self:?}"
+                );
+            };
+
+            let direction = random(shape_x);
+            let expected = (loss(x.clone() + direction.clone(), weight.clone(), bias.clone())
+                - loss(x.clone() - direction.clone(), weight.clone(), bias.clone()))
+                / 2.0;
+            let actual = (x_grad * direction).sum().into_scalar().elem::<f32>();
+            assert_close("x", actual, expected);
+
+            let direction = random(shape_weight);
+            let expected = (loss(x.clone(), weight.clone() + direction.clone(), bias.clone())
+                - loss(x.clone(), weight.clone() - direction.clone(), bias.clone()))
+                / 2.0;
+            let actual = (weight_grad * direction).sum().into_scalar().elem::<f32>();
+            assert_close("weight", actual, expected);
+
+            let direction = bias.random_like(Distribution::Default);
+            let expected = (loss(x.clone(), weight.clone(), bias.clone() + direction.clone())
+                - loss(x, weight, bias - direction.clone()))
+                / 2.0;
+            let actual = (bias_grad * direction).sum().into_scalar().elem::<f32>();
+            assert_close("bias", actual, expected);
+        }
+
         fn assert_grads(self, expected_grads: Grads) {
             let shape_x = Shape::new([self.batch_size, self.channels_in, self.height, self.width]);
             let shape_weight = Shape::new([
@@ -0,0 +1,27 @@
+#[burn_tensor_testgen::testgen(ad_dropout)]
+mod tests {
+    use super::*;
+    use burn_tensor::{module::dropout, TensorData};
+
+    #[test]
+    fn should_diff_dropout() {
+        let device = Default::default();
+        let tensor_1 = TestAutodiffTensor::<2>::ones([32, 32], &device).require_grad();
+        let tensor_2 = TestAutodiffTensor::from_data(TensorData::from([[2.0], [3.0]]), &device);
+
+        let tensor_3 = dropout(tensor_1.clone(), 0.5);
+        let tensor_4 = tensor_3.clone().reshape([2, 512]).mul(tensor_2);
+        let grads = tensor_4.backward();
+
+        // The input being ones, the output is the scaled mask, which also scales the gradient.
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let expected = tensor_3
+            .inner()
+            .reshape([2, 512])
+            .mul(TestTensor::from([[2.0], [3.0]]))
+            .reshape([32, 32]);
+        grad_1
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+    }
+}
@@ -0,0 +1,39 @@
+#[burn_tensor_testgen::testgen(ad_erfinv)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn should_diff_erfinv() {
+        let data = TensorData::from([[-0.5, 0.0], [0.3, 0.9]]);
+
+        let device = Default::default();
+        let tensor_1 = TestAutodiffTensor::<2>::from_data(data, &device).require_grad();
+
+        let tensor_2 = tensor_1.clone().erfinv();
+        let grads = tensor_2.sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        // d/dx erfinv(x) = sqrt(pi) / 2 * exp(erfinv(x)^2)
+        let expected = TensorData::from([[1.11258482, 0.88622693], [0.95452036, 3.42804216]]);
+        grad_1.to_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn should_diff_erfinv_close_to_one() {
+        let data = TensorData::from([[-0.999_999_880_790_710_4, 0.999_999_880_790_710_4]]);
+
+        let device = Default::default();
+        let tensor_1 = TestAutodiffTensor::<2>::from_data(data, &device).require_grad();
+
+        let tensor_2 = tensor_1.clone().erfinv();
+        let grads = tensor_2.sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        // The gradient is steep close to one, so the tolerance is relative to its magnitude.
+        let expected = TensorData::from([[1083994.55234026, 1083994.55234026]]);
+        grad_1.to_data().assert_approx_eq_diff(&expected, 50.0);
+    }
+}
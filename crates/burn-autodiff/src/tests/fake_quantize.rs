@@ -0,0 +1,70 @@
+#[burn_tensor_testgen::testgen(ad_fake_quantize)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn should_diff_fake_quantize() {
+        let device = Default::default();
+        let tensor = TestAutodiffTensor::<1>::from_data(
+            TensorData::from([-3.0, -2.5, -0.6, 0.0, 0.25, 0.75, 1.0, 5.0]),
+            &device,
+        )
+        .require_grad();
+        let scale = TestAutodiffTensor::<1>::from_data([0.5], &device).require_grad();
+        let zero_point = TestAutodiffTensor::<1>::from_data([1.0], &device).require_grad();
+
+        let output = tensor
+            .clone()
+            .fake_quantize(scale.clone(), zero_point.clone(), -4, 3, None);
+        let grads = output.sum().backward();
+
+        let grad_tensor = tensor.grad(&grads).unwrap();
+        let grad_scale = scale.grad(&grads).unwrap();
+        let grad_zero_point = zero_point.grad(&grads).unwrap();
+
+        // The bounds of the quantized range (`-2.5` and `1.0`) still propagate the gradient.
+        grad_tensor.to_data().assert_approx_eq(
+            &TensorData::from([0.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 0.0]),
+            5,
+        );
+        grad_scale
+            .to_data()
+            .assert_approx_eq(&TensorData::from([-2.8]), 4);
+        grad_zero_point
+            .to_data()
+            .assert_approx_eq(&TensorData::from([-1.0]), 5);
+    }
+
+    #[test]
+    fn should_diff_fake_quantize_per_channel() {
+        let device = Default::default();
+        let tensor = TestAutodiffTensor::<2>::from_data(
+            TensorData::from([[0.14, -0.26, 0.96], [0.5, -7.0, 3.2]]),
+            &device,
+        )
+        .require_grad();
+        let scale = TestAutodiffTensor::<1>::from_data([0.1, 1.0], &device).require_grad();
+        let zero_point = TestAutodiffTensor::<1>::from_data([0.0, -2.0], &device).require_grad();
+
+        let output =
+            tensor
+                .clone()
+                .fake_quantize(scale.clone(), zero_point.clone(), -8, 7, Some(0));
+        let grads = output.sum().backward();
+
+        let grad_tensor = tensor.grad(&grads).unwrap();
+        let grad_scale = scale.grad(&grads).unwrap();
+        let grad_zero_point = zero_point.grad(&grads).unwrap();
+
+        grad_tensor
+            .to_data()
+            .assert_approx_eq(&TensorData::from([[1.0, 1.0, 0.0], [1.0, 0.0, 1.0]]), 5);
+        grad_scale
+            .to_data()
+            .assert_approx_eq(&TensorData::from([6.2, -6.7]), 3);
+        grad_zero_point
+            .to_data()
+            .assert_approx_eq(&TensorData::from([-0.1, -1.0]), 4);
+    }
+}
@@ -0,0 +1,200 @@
+#[burn_tensor_testgen::testgen(ad_grad_hook)]
+mod tests {
+    use super::*;
+    use burn_autodiff::checkpoint::strategy::BalancedCheckpointing;
+    use burn_tensor::{
+        backend::Backend, ops::FloatTensorOps, ElementConversion, TensorData, TensorPrimitive,
+    };
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    };
+
+    type FloatPrimitive<B, const D: usize> = <B as Backend>::FloatTensorPrimitive<D>;
+
+    fn double<B: Backend, const D: usize>(grad: &FloatPrimitive<B, D>) -> FloatPrimitive<B, D> {
+        B::float_mul_scalar(grad.clone(), 2.0.elem())
+    }
+
+    #[test]
+    fn should_scale_the_grads_of_the_parents_with_a_hook() {
+        let device = Default::default();
+        let data_1 = TensorData::from([[1.0, 7.0], [2.0, 3.0]]);
+        let data_2 = TensorData::from([[4.0, 7.0], [2.0, 3.0]]);
+
+        let grads_of = |hooked: bool| {
+            let tensor_1 = TestAutodiffTensor::from_data(data_1.clone(), &device).require_grad();
+            let tensor_2 = TestAutodiffTensor::from_data(data_2.clone(), &device).require_grad();
+
+            let tensor_3 = tensor_1.clone().matmul(tensor_2.clone());
+            if hooked {
+                tensor_3
+                    .clone()
+                    .into_primitive()
+                    .tensor()
+                    .register_grad_hook(|grad| Some(double::<TestBackend, 2>(grad)));
+            }
+            let tensor_4 = tensor_3.exp().sum();
+
+            let grads = tensor_4.backward();
+            (
+                tensor_1.grad(&grads).unwrap(),
+                tensor_2.grad(&grads).unwrap(),
+            )
+        };
+
+        let (grad_1, grad_2) = grads_of(false);
+        let (grad_1_hooked, grad_2_hooked) = grads_of(true);
+
+        (grad_1 * 2)
+            .into_data()
+            .assert_approx_eq(&grad_1_hooked.into_data(), 3);
+        (grad_2 * 2)
+            .into_data()
+            .assert_approx_eq(&grad_2_hooked.into_data(), 3);
+    }
+
+    #[test]
+    fn should_call_the_hook_once_with_the_accumulated_grad() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::from_data([[1.0, 2.0], [3.0, 4.0]], &device).require_grad();
+        let tensor_2 = tensor_1.clone().mul_scalar(3.0);
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let observed = Arc::new(Mutex::new(None));
+        let (calls_hook, observed_hook) = (calls.clone(), observed.clone());
+        tensor_2
+            .clone()
+            .into_primitive()
+            .tensor()
+            .register_grad_hook(move |grad| {
+                calls_hook.fetch_add(1, Ordering::Relaxed);
+                *observed_hook.lock().unwrap() = Some(grad.clone());
+                None
+            });
+
+        // Both uses of the tensor contribute to its gradient.
+        let tensor_3 = tensor_2.clone().add(tensor_2.mul_scalar(2.0)).sum();
+        let grads = tensor_3.backward();
+
+        assert_eq!(calls.load(Ordering::Relaxed), 1);
+        let observed = observed.lock().unwrap().take().unwrap();
+        burn_tensor::Tensor::<TestBackend, 2>::from_primitive(TensorPrimitive::Float(observed))
+            .into_data()
+            .assert_eq(&TensorData::from([[3.0, 3.0], [3.0, 3.0]]), false);
+        tensor_1
+            .grad(&grads)
+            .unwrap()
+            .into_data()
+            .assert_eq(&TensorData::from([[9.0, 9.0], [9.0, 9.0]]), false);
+    }
+
+    #[test]
+    fn should_call_the_hook_once_per_backward_pass_of_a_retained_graph() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::from_data([[1.0, 2.0], [3.0, 4.0]], &device).require_grad();
+        let tensor_2 = tensor_1.clone().mul_scalar(3.0);
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let calls_hook = calls.clone();
+        tensor_2
+            .clone()
+            .into_primitive()
+            .tensor()
+            .register_grad_hook(move |grad| {
+                calls_hook.fetch_add(1, Ordering::Relaxed);
+                Some(double::<TestBackend, 2>(grad))
+            });
+
+        let tensor_3 = tensor_2.sum();
+        let grads_1 = tensor_3.backward_retained();
+        assert_eq!(calls.load(Ordering::Relaxed), 1);
+        let grads_2 = tensor_3.backward();
+        assert_eq!(calls.load(Ordering::Relaxed), 2);
+
+        for grads in [grads_1, grads_2] {
+            tensor_1
+                .grad(&grads)
+                .unwrap()
+                .into_data()
+                .assert_eq(&TensorData::from([[6.0, 6.0], [6.0, 6.0]]), false);
+        }
+    }
+
+    #[test]
+    fn should_rewrite_the_grad_of_a_leaf() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::from_data([[1.0, 2.0], [3.0, 4.0]], &device).require_grad();
+        tensor_1
+            .clone()
+            .into_primitive()
+            .tensor()
+            .register_grad_hook(|grad| Some(double::<TestBackend, 2>(grad)));
+
+        let grads = tensor_1.clone().mul_scalar(5.0).sum().backward();
+
+        tensor_1
+            .grad(&grads)
+            .unwrap()
+            .into_data()
+            .assert_eq(&TensorData::from([[10.0, 10.0], [10.0, 10.0]]), false);
+    }
+
+    #[test]
+    fn should_not_call_a_removed_hook() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::from_data([[1.0, 2.0], [3.0, 4.0]], &device).require_grad();
+        let tensor_2 = tensor_1.clone().mul_scalar(5.0);
+
+        let handle = tensor_2
+            .clone()
+            .into_primitive()
+            .tensor()
+            .register_grad_hook(|_grad| panic!("The hook was removed"));
+        handle.remove();
+
+        let grads = tensor_2.sum().backward();
+
+        tensor_1
+            .grad(&grads)
+            .unwrap()
+            .into_data()
+            .assert_eq(&TensorData::from([[5.0, 5.0], [5.0, 5.0]]), false);
+    }
+
+    #[test]
+    fn should_call_the_hook_of_a_recomputed_node() {
+        type CheckpointedTensor<const D: usize> =
+            burn_tensor::Tensor<burn_autodiff::Autodiff<TestBackend, BalancedCheckpointing>, D>;
+
+        let device = Default::default();
+        let grads_of = |hooked: bool| {
+            let tensor_1 = CheckpointedTensor::<2>::from_data([[1.0, 2.0], [3.0, 4.0]], &device)
+                .require_grad();
+            let tensor_2 = CheckpointedTensor::<2>::from_data([[4.0, 7.0], [2.0, 3.0]], &device)
+                .require_grad();
+
+            // Memory bound operations, recomputed during the backward pass.
+            let tensor_3 = tensor_1.clone().mul(tensor_2.clone());
+            if hooked {
+                tensor_3
+                    .clone()
+                    .into_primitive()
+                    .tensor()
+                    .register_grad_hook(|grad| Some(double::<TestBackend, 2>(grad)));
+            }
+            let tensor_4 = tensor_3.clone().mul(tensor_3).sum();
+
+            let grads = tensor_4.backward();
+            tensor_1.grad(&grads).unwrap()
+        };
+
+        (grads_of(false) * 2)
+            .into_data()
+            .assert_approx_eq(&grads_of(true).into_data(), 3);
+    }
+}
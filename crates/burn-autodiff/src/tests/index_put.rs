@@ -0,0 +1,64 @@
+#[burn_tensor_testgen::testgen(ad_index_put)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Int, Tensor, TensorData};
+
+    fn grads(accumulate: bool) -> (TensorData, TensorData) {
+        let device = Default::default();
+        let tensor = TestAutodiffTensor::<2>::from_data(
+            TensorData::from([[0.0, 1.0], [2.0, 3.0], [4.0, 5.0], [6.0, 7.0]]),
+            &device,
+        )
+        .require_grad();
+        let values = TestAutodiffTensor::from_data(
+            TensorData::from([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]),
+            &device,
+        )
+        .require_grad();
+        let indices =
+            Tensor::<TestAutodiffBackend, 1, Int>::from_data(TensorData::from([1, 0, 1]), &device);
+        let weights = TestAutodiffTensor::from_data(
+            TensorData::from([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0], [7.0, 8.0]]),
+            &device,
+        );
+
+        let output = tensor
+            .clone()
+            .index_put(0, indices, values.clone(), accumulate);
+        let grads = (output * weights).sum().backward();
+
+        (
+            tensor.grad(&grads).unwrap().into_data(),
+            values.grad(&grads).unwrap().into_data(),
+        )
+    }
+
+    #[test]
+    fn test_index_put_grad() {
+        let (grad_tensor, grad_values) = grads(false);
+
+        grad_tensor.assert_eq(
+            &TensorData::from([[0.0, 0.0], [0.0, 0.0], [5.0, 6.0], [7.0, 8.0]]),
+            false,
+        );
+        // The first value is overwritten by the last one, written at the same index.
+        grad_values.assert_eq(
+            &TensorData::from([[0.0, 0.0], [1.0, 2.0], [3.0, 4.0]]),
+            false,
+        );
+    }
+
+    #[test]
+    fn test_index_put_accumulate_grad() {
+        let (grad_tensor, grad_values) = grads(true);
+
+        grad_tensor.assert_eq(
+            &TensorData::from([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0], [7.0, 8.0]]),
+            false,
+        );
+        grad_values.assert_eq(
+            &TensorData::from([[3.0, 4.0], [1.0, 2.0], [3.0, 4.0]]),
+            false,
+        );
+    }
+}
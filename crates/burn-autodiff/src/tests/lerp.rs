@@ -0,0 +1,64 @@
+#[burn_tensor_testgen::testgen(ad_lerp)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Distribution, Tensor};
+
+    #[test]
+    fn should_diff_lerp_like_the_composed_ops() {
+        let device = Default::default();
+        let data_tensor = TestAutodiffTensor::<2>::random([3, 4], Distribution::Default, &device);
+        let data_end = TestAutodiffTensor::<2>::random([3, 4], Distribution::Default, &device);
+        let data_weight = TestAutodiffTensor::<2>::random([1, 4], Distribution::Default, &device);
+
+        let grads = |fused: bool| {
+            let tensor = data_tensor.clone().detach().require_grad();
+            let end = data_end.clone().detach().require_grad();
+            let weight = data_weight.clone().detach().require_grad();
+
+            let output = match fused {
+                true => tensor.clone().lerp(end.clone(), weight.clone()),
+                false => tensor.clone() + weight.clone() * (end.clone() - tensor.clone()),
+            };
+            let grads = (output.clone() * output).sum().backward();
+
+            [
+                tensor.grad(&grads).unwrap(),
+                end.grad(&grads).unwrap(),
+                weight.grad(&grads).unwrap(),
+            ]
+        };
+
+        assert_same_grads(grads(true), grads(false));
+    }
+
+    #[test]
+    fn should_diff_lerp_scalar_like_the_composed_ops() {
+        let device = Default::default();
+        let data_tensor = TestAutodiffTensor::<2>::random([3, 4], Distribution::Default, &device);
+        let data_end = TestAutodiffTensor::<2>::random([3, 4], Distribution::Default, &device);
+
+        let grads = |fused: bool| {
+            let tensor = data_tensor.clone().detach().require_grad();
+            let end = data_end.clone().detach().require_grad();
+
+            let output = match fused {
+                true => tensor.clone().lerp_scalar(end.clone(), 0.3),
+                false => tensor.clone() + (end.clone() - tensor.clone()).mul_scalar(0.3),
+            };
+            let grads = (output.clone() * output).sum().backward();
+
+            [tensor.grad(&grads).unwrap(), end.grad(&grads).unwrap()]
+        };
+
+        assert_same_grads(grads(true), grads(false));
+    }
+
+    fn assert_same_grads<const N: usize>(
+        actual: [Tensor<TestBackend, 2>; N],
+        expected: [Tensor<TestBackend, 2>; N],
+    ) {
+        for (actual, expected) in actual.into_iter().zip(expected) {
+            actual.to_data().assert_approx_eq(&expected.to_data(), 4);
+        }
+    }
+}
@@ -61,4 +61,28 @@ mod tests {
         let expected = TensorData::from([[15., 18.], [23., 29.]]);
         grad_3.into_data().assert_approx_eq(&expected, 3);
     }
+
+    #[test]
+    fn should_diff_mask_where_broadcasting() {
+        let device = Default::default();
+        let tensor_1 = TestAutodiffTensor::<2>::from_data([[1.0], [2.0]], &device).require_grad();
+        let tensor_2 =
+            TestAutodiffTensor::<2>::from_data([[3.0, f32::NAN]], &device).require_grad();
+        let mask = Tensor::<TestAutodiffBackend, 2, Bool>::from_data([[true, false]], &device);
+
+        let tensor_3 = tensor_1.clone().mask_where(mask, tensor_2.clone());
+        let grads = tensor_3.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_2 = tensor_2.grad(&grads).unwrap();
+
+        assert!(!grad_1.clone().is_nan().any().into_scalar());
+        assert!(!grad_2.clone().is_nan().any().into_scalar());
+        grad_1
+            .into_data()
+            .assert_eq(&TensorData::from([[1.0], [1.0]]), false);
+        grad_2
+            .into_data()
+            .assert_eq(&TensorData::from([[2.0, 0.0]]), false);
+    }
 }
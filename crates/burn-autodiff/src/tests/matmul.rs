@@ -1,7 +1,7 @@
 #[burn_tensor_testgen::testgen(ad_matmul)]
 mod tests {
     use super::*;
-    use burn_tensor::TensorData;
+    use burn_tensor::{Distribution, TensorData};
 
     #[test]
     fn should_diff_matmul() {
@@ -29,6 +29,59 @@ mod tests {
             .assert_eq(&TensorData::from([[18.0, 28.0], [14.0, 23.0]]), false);
     }
 
+    #[test]
+    fn should_accumulate_the_grad_of_a_rhs_used_twice() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::<2>::from_data([[1.0, 7.0], [2.0, 3.0]], &device).require_grad();
+        let tensor_2 = TestAutodiffTensor::from_data([[4.0, 7.0], [2.0, 3.0]], &device);
+        let weight =
+            TestAutodiffTensor::from_data([[1.0, 2.0], [3.0, 4.0]], &device).require_grad();
+
+        let tensor_3 = tensor_1.clone().matmul(weight.clone()) + tensor_2.matmul(weight.clone());
+        let grads = tensor_3.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_weight = weight.grad(&grads).unwrap();
+
+        grad_1
+            .to_data()
+            .assert_eq(&TensorData::from([[3.0, 7.0], [3.0, 7.0]]), false);
+        grad_weight
+            .to_data()
+            .assert_eq(&TensorData::from([[9.0, 9.0], [20.0, 20.0]]), false);
+    }
+
+    #[test]
+    fn should_accumulate_the_grad_of_a_rhs_shared_by_the_batches() {
+        let device = Default::default();
+        let tensor_1 = TestAutodiffTensor::<3>::from_data(
+            [[[1.0, 7.0], [2.0, 3.0]], [[4.0, 7.0], [2.0, 3.0]]],
+            &device,
+        )
+        .require_grad();
+        let tensor_2 = TestAutodiffTensor::<3>::from_data(
+            [[[1.0, 0.0], [0.0, 1.0]], [[1.0, 1.0], [1.0, 1.0]]],
+            &device,
+        );
+        let weight =
+            TestAutodiffTensor::from_data([[[1.0, 2.0], [3.0, 4.0]]], &device).require_grad();
+
+        let tensor_3 = tensor_1.clone().matmul(weight.clone()) + tensor_2.matmul(weight.clone());
+        let grads = tensor_3.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_weight = weight.grad(&grads).unwrap();
+
+        grad_1.to_data().assert_eq(
+            &TensorData::from([[[3.0, 7.0], [3.0, 7.0]], [[3.0, 7.0], [3.0, 7.0]]]),
+            false,
+        );
+        grad_weight
+            .to_data()
+            .assert_eq(&TensorData::from([[[12.0, 12.0], [23.0, 23.0]]]), false);
+    }
+
     #[test]
     fn test_matmul_complex_1() {
         let data_1 = TensorData::from([[1.0, 7.0], [13.0, -3.0]]);
@@ -83,4 +136,61 @@ mod tests {
             .to_data()
             .assert_eq(&TensorData::from([[264., 264.0], [344.0, 344.0]]), false);
     }
+
+    #[test]
+    fn should_diff_matmul_broadcast() {
+        let data_1 = TensorData::from([[[1.0, 7.0], [2.0, 3.0]]]);
+        let data_2 = TensorData::from([[[4.0, 7.0], [2.0, 3.0]], [[2.0, 5.0], [6.0, 3.0]]]);
+
+        let device = Default::default();
+        let tensor_1 = TestAutodiffTensor::<3>::from_data(data_1, &device).require_grad();
+        let tensor_2 = TestAutodiffTensor::from_data(data_2, &device).require_grad();
+
+        let tensor_3 = tensor_1.clone().matmul(tensor_2.clone());
+        let grads = tensor_3.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_2 = tensor_2.grad(&grads).unwrap();
+
+        // The gradient of the broadcast lhs is summed over the batches.
+        grad_1
+            .to_data()
+            .assert_eq(&TensorData::from([[[18.0, 14.0], [18.0, 14.0]]]), false);
+        grad_2.to_data().assert_eq(
+            &TensorData::from([[[3.0, 3.0], [10.0, 10.0]], [[3.0, 3.0], [10.0, 10.0]]]),
+            false,
+        );
+    }
+
+    #[test]
+    fn should_diff_matmul_broadcast_like_repeat() {
+        let device = Default::default();
+        let data_1 = TestAutodiffTensor::<4>::random([2, 1, 3, 4], Distribution::Default, &device)
+            .into_data();
+        let data_2 = TestAutodiffTensor::<4>::random([1, 3, 4, 5], Distribution::Default, &device)
+            .into_data();
+
+        let tensor_1 = TestAutodiffTensor::<4>::from_data(data_1.clone(), &device).require_grad();
+        let tensor_2 = TestAutodiffTensor::<4>::from_data(data_2.clone(), &device).require_grad();
+        let grads = tensor_1.clone().matmul(tensor_2.clone()).backward();
+
+        let tensor_1_repeat = TestAutodiffTensor::<4>::from_data(data_1, &device).require_grad();
+        let tensor_2_repeat = TestAutodiffTensor::<4>::from_data(data_2, &device).require_grad();
+        let grads_repeat = tensor_1_repeat
+            .clone()
+            .repeat_dim(1, 3)
+            .matmul(tensor_2_repeat.clone().repeat_dim(0, 2))
+            .backward();
+
+        tensor_1
+            .grad(&grads)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq(&tensor_1_repeat.grad(&grads_repeat).unwrap().into_data(), 3);
+        tensor_2
+            .grad(&grads)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq(&tensor_2_repeat.grad(&grads_repeat).unwrap().into_data(), 3);
+    }
 }
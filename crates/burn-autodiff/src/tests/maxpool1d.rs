@@ -19,7 +19,7 @@ mod tests {
         let x_grad_expected =
             TestAutodiffTensor::<3>::from_floats([[[1., 1., 0., 0., 0., 1.]]], &device);
 
-        let output = max_pool1d(x.clone(), kernel_size, stride, padding, dilation);
+        let output = max_pool1d(x.clone(), kernel_size, stride, padding, dilation, false);
         let grads = output.backward();
 
         // Asserts
@@ -54,7 +54,7 @@ mod tests {
             &device,
         );
 
-        let output = max_pool1d(x.clone(), kernel_size, stride, padding, dilation);
+        let output = max_pool1d(x.clone(), kernel_size, stride, padding, dilation, false);
         let grads = output.backward();
 
         // Asserts
@@ -89,7 +89,7 @@ mod tests {
             &device,
         );
 
-        let output = max_pool1d(x.clone(), kernel_size, stride, padding, dilation);
+        let output = max_pool1d(x.clone(), kernel_size, stride, padding, dilation, false);
         let grads = output.backward();
 
         // Asserts
@@ -124,7 +124,31 @@ mod tests {
             &device,
         );
 
-        let output = max_pool1d(x.clone(), kernel_size, stride, padding, dilation);
+        let output = max_pool1d(x.clone(), kernel_size, stride, padding, dilation, false);
+        let grads = output.backward();
+
+        // Asserts
+        let x_grad_actual = x.grad(&grads).unwrap();
+        x_grad_expected
+            .to_data()
+            .assert_approx_eq(&x_grad_actual.to_data(), 3);
+    }
+
+    #[test]
+    fn test_max_pool1d_ceil_mode() {
+        let kernel_size = 2;
+        let padding = 0;
+        let stride = 2;
+        let dilation = 1;
+
+        let device = Default::default();
+        let x =
+            TestAutodiffTensor::from_floats([[[0.2479, 0.6386, 0.3166, 0.5742, 0.9154]]], &device)
+                .require_grad();
+        let x_grad_expected =
+            TestAutodiffTensor::<3>::from_floats([[[0., 1., 0., 1., 1.]]], &device);
+
+        let output = max_pool1d(x.clone(), kernel_size, stride, padding, dilation, true);
         let grads = output.backward();
 
         // Asserts
@@ -41,6 +41,7 @@ mod tests {
             [stride_1, stride_2],
             [padding_1, padding_2],
             [dilation_1, dilation_2],
+            false,
         );
         let grads = output.backward();
 
@@ -89,6 +90,7 @@ mod tests {
             [stride_1, stride_2],
             [padding_1, padding_2],
             [dilation_1, dilation_2],
+            false,
         );
         let grads = output.backward();
 
@@ -137,6 +139,7 @@ mod tests {
             [stride_1, stride_2],
             [padding_1, padding_2],
             [dilation_1, dilation_2],
+            false,
         );
         let grads = output.backward();
 
@@ -187,6 +190,58 @@ mod tests {
             [stride_1, stride_2],
             [padding_1, padding_2],
             [dilation_1, dilation_2],
+            false,
+        );
+        let grads = output.backward();
+
+        // Asserts
+        let x_grad_actual = x.grad(&grads).unwrap();
+        x_grad_expected
+            .to_data()
+            .assert_approx_eq(&x_grad_actual.to_data(), 3);
+    }
+
+    #[test]
+    fn test_max_pool2d_ceil_mode() {
+        let kernel_size_1 = 2;
+        let kernel_size_2 = 2;
+        let padding_1 = 0;
+        let padding_2 = 0;
+        let stride_1 = 2;
+        let stride_2 = 2;
+        let dilation_1 = 1;
+        let dilation_2 = 1;
+
+        let device = Default::default();
+        let x = TestAutodiffTensor::from_floats(
+            [[[
+                [0.5388, 0.0676, 0.7122, 0.8316, 0.0653],
+                [0.9154, 0.1536, 0.9089, 0.8016, 0.7518],
+                [0.2073, 0.0501, 0.8811, 0.5604, 0.5075],
+                [0.4384, 0.9963, 0.9698, 0.4988, 0.2609],
+                [0.3391, 0.2230, 0.4610, 0.5365, 0.6880],
+            ]]],
+            &device,
+        )
+        .require_grad();
+        let x_grad_expected = TestAutodiffTensor::<4>::from_floats(
+            [[[
+                [0., 0., 0., 0., 0.],
+                [1., 0., 1., 0., 1.],
+                [0., 0., 0., 0., 1.],
+                [0., 1., 1., 0., 0.],
+                [1., 0., 0., 1., 1.],
+            ]]],
+            &device,
+        );
+
+        let output = max_pool2d(
+            x.clone(),
+            [kernel_size_1, kernel_size_2],
+            [stride_1, stride_2],
+            [padding_1, padding_2],
+            [dilation_1, dilation_2],
+            true,
         );
         let grads = output.backward();
 
@@ -4,9 +4,12 @@ mod abs;
 mod adaptive_avgpool1d;
 mod adaptive_avgpool2d;
 mod add;
+mod addcmul;
 mod aggregation;
+mod associative_scan;
 mod avgpool1d;
 mod avgpool2d;
+mod axpby;
 mod backward;
 mod bridge;
 mod broadcast;
@@ -22,13 +25,19 @@ mod conv_transpose3d;
 mod cos;
 mod cross_entropy;
 mod div;
+mod dropout;
 mod erf;
+mod erfinv;
 mod exp;
 mod expand;
+mod fake_quantize;
 mod flip;
 mod gather_scatter;
 mod gelu;
+mod grad_hook;
 mod gradients;
+mod index_put;
+mod lerp;
 mod log;
 mod log1p;
 mod log_sigmoid;
@@ -43,23 +52,30 @@ mod multithread;
 mod nearest_interpolate;
 mod neg;
 mod nonzero;
+mod pad;
 mod permute;
 mod pow;
 mod recip;
 mod relu;
 mod repeat_dim;
 mod reshape;
+mod retain_graph;
 mod select;
 mod sigmoid;
 mod sign;
 mod sin;
 mod slice;
 mod softmax;
+mod softplus;
 mod sort;
+mod spmm;
 mod sqrt;
 mod sub;
+mod take_along_dim;
 mod tanh;
 mod transpose;
+mod var;
+mod zero_size;
 
 #[macro_export]
 macro_rules! testgen_all {
@@ -70,6 +86,8 @@ macro_rules! testgen_all {
         // Behavior
         burn_autodiff::testgen_ad_broadcast!();
         burn_autodiff::testgen_gradients!();
+        burn_autodiff::testgen_ad_grad_hook!();
+        burn_autodiff::testgen_ad_retain_graph!();
         burn_autodiff::testgen_bridge!();
         burn_autodiff::testgen_checkpoint!();
         burn_autodiff::testgen_memory_management!();
@@ -79,6 +97,7 @@ macro_rules! testgen_all {
         burn_autodiff::testgen_ad_gelu!();
 
         // Modules
+        burn_autodiff::testgen_ad_dropout!();
         burn_autodiff::testgen_ad_conv1d!();
         burn_autodiff::testgen_ad_conv2d!();
         burn_autodiff::testgen_ad_conv3d!();
@@ -98,17 +117,24 @@ macro_rules! testgen_all {
         burn_autodiff::testgen_ad_complex!();
         burn_autodiff::testgen_ad_multithread!();
         burn_autodiff::testgen_ad_add!();
+        burn_autodiff::testgen_ad_addcmul!();
+        burn_autodiff::testgen_ad_axpby!();
         burn_autodiff::testgen_ad_aggregation!();
+        burn_autodiff::testgen_ad_associative_scan!();
         burn_autodiff::testgen_ad_maxmin!();
         burn_autodiff::testgen_ad_cat!();
         burn_autodiff::testgen_ad_cos!();
         burn_autodiff::testgen_ad_cross_entropy_loss!();
         burn_autodiff::testgen_ad_div!();
         burn_autodiff::testgen_ad_erf!();
+        burn_autodiff::testgen_ad_erfinv!();
+        burn_autodiff::testgen_ad_fake_quantize!();
         burn_autodiff::testgen_ad_exp!();
         burn_autodiff::testgen_ad_slice!();
         burn_autodiff::testgen_ad_gather_scatter!();
+        burn_autodiff::testgen_ad_index_put!();
         burn_autodiff::testgen_ad_select!();
+        burn_autodiff::testgen_ad_lerp!();
         burn_autodiff::testgen_ad_log!();
         burn_autodiff::testgen_ad_log1p!();
         burn_autodiff::testgen_ad_mask!();
@@ -120,19 +146,25 @@ macro_rules! testgen_all {
         burn_autodiff::testgen_ad_reshape!();
         burn_autodiff::testgen_ad_sin!();
         burn_autodiff::testgen_ad_softmax!();
+        burn_autodiff::testgen_ad_softplus!();
         burn_autodiff::testgen_ad_sqrt!();
         burn_autodiff::testgen_ad_abs!();
         burn_autodiff::testgen_ad_sub!();
+        burn_autodiff::testgen_ad_take_along_dim!();
         burn_autodiff::testgen_ad_tanh!();
         burn_autodiff::testgen_ad_sigmoid!();
         burn_autodiff::testgen_ad_log_sigmoid!();
         burn_autodiff::testgen_ad_transpose!();
+        burn_autodiff::testgen_ad_var!();
         burn_autodiff::testgen_ad_permute!();
+        burn_autodiff::testgen_ad_pad!();
         burn_autodiff::testgen_ad_flip!();
         burn_autodiff::testgen_ad_nonzero!();
         burn_autodiff::testgen_ad_sign!();
         burn_autodiff::testgen_ad_expand!();
         burn_autodiff::testgen_ad_sort!();
+        burn_autodiff::testgen_ad_spmm!();
         burn_autodiff::testgen_ad_repeat_dim!();
+        burn_autodiff::testgen_ad_zero_size!();
     };
 }
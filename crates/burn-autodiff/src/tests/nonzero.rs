@@ -41,4 +41,24 @@ mod tests {
             .to_data()
             .assert_eq(&TensorData::from([2.0, 3.0]), false);
     }
+
+    #[test]
+    fn should_diff_indexing_with_argwhere_padded() {
+        let device = Default::default();
+        let tensor =
+            TestAutodiffTensor::<1>::from_data([1.0, -2.0, 3.0, -4.0], &device).require_grad();
+
+        // The mask is detached from the tensor, only the selected values receive gradients.
+        let mask = tensor.clone().greater_elem(0.0);
+        let (indices, count) = mask.argwhere_padded(3);
+        let indices = indices.reshape([3]).clamp_min(0);
+        let output = tensor.clone().gather(0, indices);
+        let grads = output.sum().backward();
+
+        let grad = tensor.grad(&grads).unwrap();
+
+        count.into_data().assert_eq(&TensorData::from([2]), false);
+        grad.to_data()
+            .assert_eq(&TensorData::from([2.0, 0.0, 1.0, 0.0]), false);
+    }
 }
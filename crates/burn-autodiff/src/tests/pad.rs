@@ -0,0 +1,51 @@
+#[burn_tensor_testgen::testgen(ad_pad)]
+mod tests {
+    use super::*;
+    use burn_tensor::{PadMode, TensorData};
+
+    #[test]
+    fn should_diff_pad_constant_with_mixed_amounts() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::<2>::from_data([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device)
+                .require_grad();
+        let weights = TestAutodiffTensor::<2>::from_data(
+            [[1.0, 2.0, 3.0, 4.0], [5.0, 6.0, 7.0, 8.0]],
+            &device,
+        );
+
+        let tensor_2 = tensor_1
+            .clone()
+            .pad(&[(1, -1), (-1, 2)], PadMode::Constant(0.0));
+        let tensor_3 = tensor_2.clone().mul(weights);
+        let grads = tensor_3.sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        tensor_2.to_data().assert_eq(
+            &TensorData::from([[0.0, 0.0, 0.0, 0.0], [2.0, 3.0, 0.0, 0.0]]),
+            false,
+        );
+        grad_1
+            .to_data()
+            .assert_eq(&TensorData::from([[0.0, 5.0, 6.0], [0.0, 0.0, 0.0]]), false);
+    }
+
+    #[test]
+    fn should_diff_pad_reflect() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::<2>::from_data([[1.0, 2.0, 3.0]], &device).require_grad();
+        let weights = TestAutodiffTensor::<2>::from_data([[1.0, 2.0, 3.0, 4.0, 5.0]], &device);
+
+        let tensor_2 = tensor_1.clone().pad(&[(0, 0), (2, 0)], PadMode::Reflect);
+        let tensor_3 = tensor_2.mul(weights);
+        let grads = tensor_3.sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        grad_1
+            .to_data()
+            .assert_eq(&TensorData::from([[3.0, 6.0, 6.0]]), false);
+    }
+}
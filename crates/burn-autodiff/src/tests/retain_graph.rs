@@ -0,0 +1,216 @@
+#[burn_tensor_testgen::testgen(ad_retain_graph)]
+mod tests {
+    use super::*;
+    use burn_autodiff::{
+        checkpoint::{
+            base::Checkpointer,
+            strategy::{BalancedCheckpointing, NoCheckpointing},
+        },
+        grads::Gradients,
+        ops::{unary, Backward, Ops, OpsKind},
+    };
+    use burn_tensor::{
+        backend::{AutodiffBackend, Backend},
+        Tensor, TensorData, TensorPrimitive,
+    };
+
+    /// Two losses computed from a shared subgraph, with compute and memory bound operations.
+    fn losses<B: AutodiffBackend>(
+        tensor_1: Tensor<B, 2>,
+        tensor_2: Tensor<B, 2>,
+    ) -> (Tensor<B, 1>, Tensor<B, 1>) {
+        let shared = tensor_1.matmul(tensor_2.clone()).mul(tensor_2).exp();
+
+        let loss_1 = shared.clone().sum();
+        let loss_2 = shared.clone().mul(shared).sin().mean();
+
+        (loss_1, loss_2)
+    }
+
+    /// The grads of the inputs of each loss, retaining the graph with the first backward pass.
+    fn retained_grads<B: AutodiffBackend>(
+        data_1: TensorData,
+        data_2: TensorData,
+    ) -> [Tensor<B::InnerBackend, 2>; 4] {
+        let device = Default::default();
+        let tensor_1 = Tensor::<B, 2>::from_data(data_1, &device).require_grad();
+        let tensor_2 = Tensor::<B, 2>::from_data(data_2, &device).require_grad();
+
+        let (loss_1, loss_2) = losses(tensor_1.clone(), tensor_2.clone());
+
+        let grads_1 = loss_1.backward_retained();
+        let grads_2 = loss_2.backward();
+
+        [
+            tensor_1.grad(&grads_1).unwrap(),
+            tensor_2.grad(&grads_1).unwrap(),
+            tensor_1.grad(&grads_2).unwrap(),
+            tensor_2.grad(&grads_2).unwrap(),
+        ]
+    }
+
+    /// The grads of the inputs of each loss, computing the forward pass again for each of them.
+    fn independent_grads<B: AutodiffBackend>(
+        data_1: TensorData,
+        data_2: TensorData,
+    ) -> [Tensor<B::InnerBackend, 2>; 4] {
+        let device = Default::default();
+        let grads_of = |first: bool| {
+            let tensor_1 = Tensor::<B, 2>::from_data(data_1.clone(), &device).require_grad();
+            let tensor_2 = Tensor::<B, 2>::from_data(data_2.clone(), &device).require_grad();
+
+            let (loss_1, loss_2) = losses(tensor_1.clone(), tensor_2.clone());
+            let grads = match first {
+                true => loss_1.backward(),
+                false => loss_2.backward(),
+            };
+
+            [
+                tensor_1.grad(&grads).unwrap(),
+                tensor_2.grad(&grads).unwrap(),
+            ]
+        };
+
+        let [grad_1_1, grad_1_2] = grads_of(true);
+        let [grad_2_1, grad_2_2] = grads_of(false);
+
+        [grad_1_1, grad_1_2, grad_2_1, grad_2_2]
+    }
+
+    fn assert_grads_eq<B: AutodiffBackend>(
+        actual: [Tensor<B::InnerBackend, 2>; 4],
+        expected: [Tensor<B::InnerBackend, 2>; 4],
+    ) {
+        for (actual, expected) in actual.into_iter().zip(expected) {
+            actual
+                .into_data()
+                .assert_approx_eq(&expected.into_data(), 3);
+        }
+    }
+
+    #[test]
+    fn should_match_independent_forwards_with_a_retained_graph() {
+        let data_1 = TensorData::from([[0.1, 0.7], [0.2, 0.3]]);
+        let data_2 = TensorData::from([[0.4, 0.7], [0.2, 0.3]]);
+
+        assert_grads_eq::<TestAutodiffBackend>(
+            retained_grads::<TestAutodiffBackend>(data_1.clone(), data_2.clone()),
+            independent_grads::<TestAutodiffBackend>(data_1, data_2),
+        );
+    }
+
+    #[test]
+    fn should_keep_the_recomputed_states_of_a_retained_graph() {
+        type CheckpointedBackend = burn_autodiff::Autodiff<TestBackend, BalancedCheckpointing>;
+
+        let data_1 = TensorData::from([[0.1, 0.7], [0.2, 0.3]]);
+        let data_2 = TensorData::from([[0.4, 0.7], [0.2, 0.3]]);
+
+        assert_grads_eq::<CheckpointedBackend>(
+            retained_grads::<CheckpointedBackend>(data_1.clone(), data_2.clone()),
+            independent_grads::<CheckpointedBackend>(data_1, data_2),
+        );
+    }
+
+    #[test]
+    fn should_retain_the_graph_for_repeated_backward_passes_on_the_same_tensor() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::<2>::from_data([[1.0, 7.0], [2.0, 3.0]], &device).require_grad();
+        let tensor_2 =
+            TestAutodiffTensor::from_data([[4.0, 7.0], [2.0, 3.0]], &device).require_grad();
+
+        let tensor_3 = tensor_1.clone().matmul(tensor_2.clone()).sum();
+
+        let grads_1 = tensor_3.backward_retained();
+        let grads_2 = tensor_3.backward_retained();
+        let grads_3 = tensor_3.backward();
+
+        let expected = tensor_1.grad(&grads_1).unwrap().into_data();
+        tensor_1
+            .grad(&grads_2)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq(&expected, 3);
+        tensor_1
+            .grad(&grads_3)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn should_retain_the_graph_through_a_concatenation() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::<2>::from_data([[1.0, 7.0], [2.0, 3.0]], &device).require_grad();
+        let tensor_2 =
+            TestAutodiffTensor::from_data([[4.0, 7.0], [2.0, 3.0]], &device).require_grad();
+
+        let tensor_3 = TestAutodiffTensor::cat(vec![tensor_1.clone(), tensor_2.clone()], 0);
+        let tensor_4 = tensor_3
+            .clone()
+            .matmul(tensor_2.clone())
+            .mul(tensor_3)
+            .sum();
+
+        let grads_1 = tensor_4.backward_retained();
+        let grads_2 = tensor_4.backward();
+
+        let expected_1 = TensorData::from([[71.0, 51.0], [43.0, 36.0]]);
+        let expected_2 = TensorData::from([[120.0, 125.0], [90.0, 152.0]]);
+        for grads in [grads_1, grads_2] {
+            tensor_1
+                .grad(&grads)
+                .unwrap()
+                .into_data()
+                .assert_approx_eq(&expected_1, 3);
+            tensor_2
+                .grad(&grads)
+                .unwrap()
+                .into_data()
+                .assert_approx_eq(&expected_2, 3);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "can't be cloned to retain the graph")]
+    fn should_not_retain_the_graph_of_an_operation_without_clone() {
+        // The operation doesn't implement `clone_backward`, like the ones written before the
+        // graph could be retained.
+        #[derive(Debug)]
+        struct Identity;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Identity {
+            type State = ();
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                _checkpointer: &mut Checkpointer,
+            ) {
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| grad);
+            }
+        }
+
+        let device = Default::default();
+        let tensor = TestAutodiffTensor::<2>::from_data([[1.0, 7.0], [2.0, 3.0]], &device)
+            .require_grad()
+            .into_primitive()
+            .tensor();
+
+        let output = match Identity
+            .prepare::<NoCheckpointing>([tensor.node.clone()])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(prep) => prep.finish((), tensor.primitive),
+            OpsKind::UnTracked(prep) => prep.finish(tensor.primitive),
+        };
+
+        TestAutodiffTensor::<2>::from_primitive(TensorPrimitive::Float(output))
+            .sum()
+            .backward_retained();
+    }
+}
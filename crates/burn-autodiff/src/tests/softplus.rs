@@ -0,0 +1,22 @@
+#[burn_tensor_testgen::testgen(ad_softplus)]
+mod tests {
+    use super::*;
+    use burn_tensor::{activation, TensorData};
+
+    #[test]
+    fn should_diff_softplus_with_extreme_inputs() {
+        let data = TensorData::from([[-100.0, -20.0, 0.0, 20.0, 100.0]]);
+
+        let device = Default::default();
+        let tensor_1 = TestAutodiffTensor::<2>::from_data(data, &device).require_grad();
+
+        let tensor_2 = activation::softplus(tensor_1.clone(), 1.0);
+        let grads = tensor_2.sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        // d/dx softplus(x) = sigmoid(x), which never overflows to `NaN`.
+        let expected = TensorData::from([[0.0, 2.0611536e-9, 0.5, 1.0, 1.0]]);
+        grad_1.to_data().assert_approx_eq(&expected, 4);
+    }
+}
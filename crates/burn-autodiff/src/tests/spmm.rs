@@ -0,0 +1,38 @@
+#[burn_tensor_testgen::testgen(ad_spmm)]
+mod tests {
+    use super::*;
+    use burn_tensor::{sparse::SparseTensor, Int, Tensor, TensorData};
+
+    #[test]
+    fn should_diff_spmm_like_the_dense_matmul() {
+        let device = Default::default();
+        // Unsorted entries with a duplicated coordinate and an empty row.
+        let indices =
+            Tensor::<TestAutodiffBackend, 2, Int>::from_ints([[2, 0, 2, 0], [1, 2, 0, 2]], &device);
+        let values_data = TensorData::from([2.0, -1.0, 4.0, 3.0]);
+        let dense_data = TensorData::from([[1.0, 2.0], [3.0, -1.0], [0.5, 4.0]]);
+
+        let values =
+            TestAutodiffTensor::<1>::from_data(values_data.clone(), &device).require_grad();
+        let dense = TestAutodiffTensor::<2>::from_data(dense_data.clone(), &device).require_grad();
+        let sparse = SparseTensor::new(indices.clone(), values.clone(), [3, 3]);
+        let output = sparse.spmm(dense.clone());
+        let grads = (output.clone() * output).sum().backward();
+
+        let values_expected =
+            TestAutodiffTensor::<1>::from_data(values_data, &device).require_grad();
+        let dense_expected = TestAutodiffTensor::<2>::from_data(dense_data, &device).require_grad();
+        let sparse_expected = SparseTensor::new(indices, values_expected.clone(), [3, 3]);
+        let output_expected = sparse_expected.to_dense().matmul(dense_expected.clone());
+        let grads_expected = (output_expected.clone() * output_expected).sum().backward();
+
+        values.grad(&grads).unwrap().into_data().assert_approx_eq(
+            &values_expected.grad(&grads_expected).unwrap().into_data(),
+            3,
+        );
+        dense.grad(&grads).unwrap().into_data().assert_approx_eq(
+            &dense_expected.grad(&grads_expected).unwrap().into_data(),
+            3,
+        );
+    }
+}
@@ -0,0 +1,65 @@
+#[burn_tensor_testgen::testgen(ad_take_along_dim)]
+mod tests {
+    use super::*;
+    use burn_tensor::{backend::Backend, ElementConversion, Int, Tensor, TensorData};
+
+    #[test]
+    fn take_along_dim_grad_should_match_finite_differences() {
+        let device = Default::default();
+        let data = TensorData::from([
+            [[0.5, -1.0, 2.0], [1.5, 0.25, -0.75]],
+            [[-2.0, 1.0, 0.5], [3.0, -0.5, 1.25]],
+        ]);
+        // Broadcast over the second dimension, with repeated and missing indices.
+        let indices = TensorData::from([[[2, 0, 2, 2]], [[1, 1, 0, 1]]]);
+        let weights = TensorData::from([
+            [[1.0, 2.0, -1.0, 0.5], [0.5, -3.0, 1.5, 1.0]],
+            [[2.0, 1.0, 1.0, -2.0], [-1.0, 0.5, 2.0, 1.5]],
+        ]);
+
+        let tensor = TestAutodiffTensor::<3>::from_data(data.clone(), &device).require_grad();
+        let grads = loss(tensor.clone(), indices.clone(), weights.clone()).backward();
+        let grad = tensor.grad(&grads).unwrap();
+
+        let tensor = TestTensor::<3>::from_data(data, &device);
+        let shape = tensor.shape();
+        let num_elements = shape.num_elements();
+        let epsilon: f32 = 1e-2;
+        let mut grad_expected = Vec::with_capacity(num_elements);
+
+        for i in 0..num_elements {
+            let delta = TestTensor::<1>::zeros([num_elements], &device)
+                .slice_assign([i..i + 1], TestTensor::full([1], epsilon, &device))
+                .reshape(shape.clone());
+            let loss_plus = loss(
+                tensor.clone() + delta.clone(),
+                indices.clone(),
+                weights.clone(),
+            );
+            let loss_minus = loss(tensor.clone() - delta, indices.clone(), weights.clone());
+            let difference =
+                loss_plus.into_scalar().elem::<f32>() - loss_minus.into_scalar().elem::<f32>();
+
+            grad_expected.push(difference / (2.0 * epsilon));
+        }
+
+        grad.to_data()
+            .assert_approx_eq(&TensorData::new(grad_expected, shape), 2);
+    }
+
+    fn loss<B: Backend>(
+        tensor: Tensor<B, 3>,
+        indices: TensorData,
+        weights: TensorData,
+    ) -> Tensor<B, 1> {
+        let device = tensor.device();
+        let indices = Tensor::<B, 3, Int>::from_data(indices, &device);
+        let weights = Tensor::<B, 3>::from_data(weights, &device);
+
+        tensor
+            .take_along_dim(2, indices)
+            .powf_scalar(2.0)
+            .mul(weights)
+            .sum()
+    }
+}
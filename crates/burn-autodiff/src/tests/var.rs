@@ -0,0 +1,42 @@
+#[burn_tensor_testgen::testgen(ad_var)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn should_diff_var_with_correction() {
+        let data = TensorData::from([[1.0, 2.0, 3.0, 4.0]]);
+        let device = Default::default();
+
+        for (correction, expected) in [
+            (0, [[-0.75, -0.25, 0.25, 0.75]]),
+            (1, [[-1.0, -1.0 / 3.0, 1.0 / 3.0, 1.0]]),
+        ] {
+            let tensor_1 = TestAutodiffTensor::<2>::from_data(data.clone(), &device).require_grad();
+            let tensor_2 = tensor_1.clone().var_dims(&[1], correction);
+            let grads = tensor_2.sum().backward();
+
+            let grad_1 = tensor_1.grad(&grads).unwrap();
+
+            // d var / d x = 2 (x - mean) / (n - correction)
+            grad_1
+                .to_data()
+                .assert_approx_eq(&TensorData::from(expected), 4);
+        }
+    }
+
+    #[test]
+    fn should_diff_var_mean_two_dims() {
+        let data = TensorData::from([[1.0, 2.0], [3.0, 4.0]]);
+        let device = Default::default();
+        let tensor_1 = TestAutodiffTensor::<2>::from_data(data, &device).require_grad();
+
+        let (var, mean) = tensor_1.clone().var_mean_dims(&[0, 1], 1);
+        let grads = (var + mean).sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        let expected = TensorData::from([[-0.75, -1.0 / 12.0], [7.0 / 12.0, 1.25]]);
+        grad_1.to_data().assert_approx_eq(&expected, 4);
+    }
+}
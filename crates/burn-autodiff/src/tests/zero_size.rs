@@ -0,0 +1,64 @@
+#[burn_tensor_testgen::testgen(ad_zero_size)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn should_diff_sum_of_zero_sized_tensor() {
+        let device = Default::default();
+        let tensor = TestAutodiffTensor::<2>::zeros([0, 3], &device).require_grad();
+
+        let grads = tensor.clone().exp().sum().backward();
+        let grad = tensor.grad(&grads).unwrap();
+
+        assert_eq!(grad.dims(), [0, 3]);
+    }
+
+    #[test]
+    fn should_diff_matmul_with_zero_inner_dim() {
+        let device = Default::default();
+        let lhs = TestAutodiffTensor::<2>::zeros([2, 0], &device).require_grad();
+        let rhs = TestAutodiffTensor::<2>::zeros([0, 3], &device).require_grad();
+        let tensor = TestAutodiffTensor::<2>::ones([2, 3], &device).require_grad();
+
+        let output = lhs.clone().matmul(rhs.clone()) + tensor.clone();
+        let grads = output.sum().backward();
+
+        assert_eq!(lhs.grad(&grads).unwrap().dims(), [2, 0]);
+        assert_eq!(rhs.grad(&grads).unwrap().dims(), [0, 3]);
+        tensor
+            .grad(&grads)
+            .unwrap()
+            .into_data()
+            .assert_eq(&TensorData::from([[1.0, 1.0, 1.0], [1.0, 1.0, 1.0]]), false);
+    }
+
+    #[test]
+    fn should_diff_cat_with_zero_sized_member() {
+        let device = Default::default();
+        let tensor_1 = TestAutodiffTensor::<2>::from_data([[1.0, 2.0]], &device).require_grad();
+        let tensor_2 = TestAutodiffTensor::<2>::zeros([0, 2], &device).require_grad();
+        let tensor_3 = TestAutodiffTensor::<2>::from_data([[3.0, 4.0]], &device).require_grad();
+
+        let output = TestAutodiffTensor::cat(
+            vec![tensor_1.clone(), tensor_2.clone(), tensor_3.clone()],
+            0,
+        );
+        let grads = (output.clone() * output).sum().backward();
+
+        // The empty tensor doesn't contribute to the output, so it may not have a gradient.
+        if let Some(grad) = tensor_2.grad(&grads) {
+            assert_eq!(grad.dims(), [0, 2]);
+        }
+        tensor_1
+            .grad(&grads)
+            .unwrap()
+            .into_data()
+            .assert_eq(&TensorData::from([[2.0, 4.0]]), false);
+        tensor_3
+            .grad(&grads)
+            .unwrap()
+            .into_data()
+            .assert_eq(&TensorData::from([[6.0, 8.0]]), false);
+    }
+}
@@ -1,7 +1,7 @@
 use std::marker::PhantomData;
 
 use burn_tensor::{
-    backend::{Backend, DeviceId, DeviceOps, SyncType},
+    backend::{Backend, DeviceId, DeviceOps, DeviceProperties, DeviceType, SyncType},
     quantization::{QTensorPrimitive, QuantizationStrategy},
     Device,
 };
@@ -72,6 +72,56 @@ impl DeviceOps for CandleDevice {
             CandleDevice::Metal(index) => DeviceId::new(2, *index as u32),
         }
     }
+
+    fn enumerate() -> Vec<Self> {
+        // Candle can't count the devices, so only the first one of each kind is reported.
+        let mut devices = vec![CandleDevice::Cpu];
+
+        if candle_core::utils::cuda_is_available() {
+            devices.push(CandleDevice::Cuda(0));
+        }
+        if candle_core::utils::metal_is_available() {
+            devices.push(CandleDevice::Metal(0));
+        }
+
+        devices
+    }
+
+    fn properties(&self) -> DeviceProperties {
+        match self {
+            CandleDevice::Cpu => DeviceProperties {
+                name: "CPU".to_string(),
+                backend_kind: "cpu".to_string(),
+                device_type: DeviceType::Cpu,
+                supports_f16: true,
+                supports_f64: true,
+                supports_bf16: true,
+                ..Default::default()
+            },
+            CandleDevice::Cuda(index) => DeviceProperties {
+                name: format!("CUDA device {index}"),
+                vendor: "NVIDIA".to_string(),
+                backend_kind: "cuda".to_string(),
+                device_type: DeviceType::DiscreteGpu,
+                supports_f16: true,
+                supports_f64: true,
+                supports_bf16: true,
+                max_workgroup_size: Some(1024),
+                max_shared_memory_size: Some(48 * 1024),
+                subgroup_support: true,
+                subgroup_size: Some(32),
+                ..Default::default()
+            },
+            CandleDevice::Metal(index) => DeviceProperties {
+                name: format!("Metal device {index}"),
+                vendor: "Apple".to_string(),
+                backend_kind: "metal".to_string(),
+                device_type: DeviceType::IntegratedGpu,
+                supports_f16: true,
+                ..Default::default()
+            },
+        }
+    }
 }
 
 impl Default for CandleDevice {
@@ -58,10 +58,12 @@ mod tests {
 
     // test ops
     burn_tensor::testgen_add!();
+    burn_tensor::testgen_addcmul!();
     // burn_tensor::testgen_aggregation!();
     burn_tensor::testgen_arange!();
     burn_tensor::testgen_arange_step!();
     burn_tensor::testgen_arg!();
+    burn_tensor::testgen_axpby!();
     burn_tensor::testgen_bool!();
     burn_tensor::testgen_cast!();
     burn_tensor::testgen_cat!();
@@ -71,11 +73,14 @@ mod tests {
     burn_tensor::testgen_close!();
     // burn_tensor::testgen_div!();
     burn_tensor::testgen_erf!();
+    burn_tensor::testgen_erfinv!();
+    burn_tensor::testgen_fake_quantize!();
     burn_tensor::testgen_exp!();
     burn_tensor::testgen_flatten!();
     burn_tensor::testgen_full!();
     burn_tensor::testgen_gather_scatter!();
     burn_tensor::testgen_init!();
+    burn_tensor::testgen_lerp!();
     burn_tensor::testgen_log!();
     burn_tensor::testgen_log1p!();
     burn_tensor::testgen_map_comparison!();
@@ -137,6 +142,8 @@ mod tests {
     burn_autodiff::testgen_ad_complex!();
     burn_autodiff::testgen_ad_multithread!();
     burn_autodiff::testgen_ad_add!();
+    burn_autodiff::testgen_ad_addcmul!();
+    burn_autodiff::testgen_ad_axpby!();
     burn_autodiff::testgen_ad_aggregation!();
     burn_autodiff::testgen_ad_maxmin!();
     // burn_autodiff::testgen_ad_cat!();
@@ -144,10 +151,13 @@ mod tests {
     burn_autodiff::testgen_ad_cross_entropy_loss!();
     burn_autodiff::testgen_ad_div!();
     burn_autodiff::testgen_ad_erf!();
+    burn_autodiff::testgen_ad_erfinv!();
+    burn_autodiff::testgen_ad_fake_quantize!();
     burn_autodiff::testgen_ad_exp!();
     burn_autodiff::testgen_ad_slice!();
     burn_autodiff::testgen_ad_gather_scatter!();
     burn_autodiff::testgen_ad_select!();
+    burn_autodiff::testgen_ad_lerp!();
     burn_autodiff::testgen_ad_log!();
     burn_autodiff::testgen_ad_log1p!();
     burn_autodiff::testgen_ad_mask!();
@@ -158,6 +168,7 @@ mod tests {
     burn_autodiff::testgen_ad_reshape!();
     burn_autodiff::testgen_ad_sin!();
     burn_autodiff::testgen_ad_softmax!();
+    burn_autodiff::testgen_ad_softplus!();
     burn_autodiff::testgen_ad_sqrt!();
     burn_autodiff::testgen_ad_abs!();
     burn_autodiff::testgen_ad_sub!();
@@ -150,6 +150,31 @@ pub fn expand<E: CandleElement, const D1: usize, const D2: usize>(
     CandleTensor::new(tensor.tensor.broadcast_as(&shape.dims).unwrap())
 }
 
+pub fn mask_where<E: CandleElement, const D: usize>(
+    tensor: CandleTensor<E, D>,
+    mask: CandleTensor<u8, D>,
+    value: CandleTensor<E, D>,
+) -> CandleTensor<E, D> {
+    // Candle's `where_cond` doesn't broadcast, the operands are expanded to the output shape.
+    let shape = tensor
+        .tensor
+        .shape()
+        .broadcast_shape_binary_op(mask.tensor.shape(), "mask_where")
+        .and_then(|shape| shape.broadcast_shape_binary_op(value.tensor.shape(), "mask_where"))
+        .unwrap();
+
+    CandleTensor::new(
+        mask.tensor
+            .broadcast_as(shape.clone())
+            .unwrap()
+            .where_cond(
+                &value.tensor.broadcast_as(shape.clone()).unwrap(),
+                &tensor.tensor.broadcast_as(shape).unwrap(),
+            )
+            .unwrap(),
+    )
+}
+
 pub fn sign<E: CandleElement, const D: usize>(tensor: CandleTensor<E, D>) -> CandleTensor<E, D> {
     CandleTensor::new(tensor.tensor.sign().unwrap())
 }
@@ -72,11 +72,7 @@ impl<F: FloatCandleElement, I: IntCandleElement> IntTensorOps<Self> for Candle<F
         mask: BoolTensor<Self, D>,
         source: IntTensor<Self, D>,
     ) -> IntTensor<Self, D> {
-        CandleTensor::new(
-            mask.tensor
-                .where_cond(&source.tensor, &tensor.tensor)
-                .unwrap(),
-        )
+        super::base::mask_where(tensor, mask, source)
     }
 
     fn int_mask_fill<const D: usize>(
@@ -99,7 +95,9 @@ impl<F: FloatCandleElement, I: IntCandleElement> IntTensorOps<Self> for Candle<F
         tensor: IntTensor<Self, D>,
         indices: IntTensor<Self, D>,
     ) -> IntTensor<Self, D> {
-        CandleTensor::new(tensor.tensor.gather(&indices.tensor, dim).unwrap())
+        // Candle requires contiguous indices, which broadcast indices aren't.
+        let indices = indices.tensor.contiguous().unwrap();
+        CandleTensor::new(tensor.tensor.gather(&indices, dim).unwrap())
     }
 
     fn int_scatter<const D: usize>(
@@ -108,10 +106,11 @@ impl<F: FloatCandleElement, I: IntCandleElement> IntTensorOps<Self> for Candle<F
         indices: IntTensor<Self, D>,
         value: IntTensor<Self, D>,
     ) -> IntTensor<Self, D> {
+        let indices = indices.tensor.contiguous().unwrap();
         CandleTensor::new(
             tensor
                 .tensor
-                .scatter_add(&indices.tensor, &value.tensor, dim)
+                .scatter_add(&indices, &value.tensor, dim)
                 .unwrap(),
         )
     }
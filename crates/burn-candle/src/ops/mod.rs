@@ -4,5 +4,6 @@ mod bool_tensor;
 mod candle_utils;
 mod int_tensor;
 mod module;
+mod optim;
 mod qtensor;
 mod tensor;
@@ -0,0 +1,8 @@
+use burn_tensor::ops::OptimizerOps;
+
+use crate::{
+    element::{FloatCandleElement, IntCandleElement},
+    Candle,
+};
+
+impl<F: FloatCandleElement, I: IntCandleElement> OptimizerOps<Self> for Candle<F, I> {}
@@ -187,7 +187,9 @@ impl<F: FloatCandleElement, I: IntCandleElement> FloatTensorOps<Self> for Candle
         tensor: FloatTensor<Self, D>,
         indices: IntTensor<Self, D>,
     ) -> FloatTensor<Self, D> {
-        CandleTensor::new(tensor.tensor.gather(&indices.tensor, dim).unwrap())
+        // Candle requires contiguous indices, which broadcast indices aren't.
+        let indices = indices.tensor.contiguous().unwrap();
+        CandleTensor::new(tensor.tensor.gather(&indices, dim).unwrap())
     }
 
     fn float_scatter<const D: usize>(
@@ -196,10 +198,11 @@ impl<F: FloatCandleElement, I: IntCandleElement> FloatTensorOps<Self> for Candle
         indices: IntTensor<Self, D>,
         value: FloatTensor<Self, D>,
     ) -> FloatTensor<Self, D> {
+        let indices = indices.tensor.contiguous().unwrap();
         CandleTensor::new(
             tensor
                 .tensor
-                .scatter_add(&indices.tensor, &value.tensor, dim)
+                .scatter_add(&indices, &value.tensor, dim)
                 .unwrap(),
         )
     }
@@ -246,11 +249,7 @@ impl<F: FloatCandleElement, I: IntCandleElement> FloatTensorOps<Self> for Candle
         mask: BoolTensor<Self, D>,
         value: FloatTensor<Self, D>,
     ) -> FloatTensor<Self, D> {
-        CandleTensor::new(
-            mask.tensor
-                .where_cond(&value.tensor, &tensor.tensor)
-                .unwrap(),
-        )
+        super::base::mask_where(tensor, mask, value)
     }
 
     fn float_mask_fill<const D: usize>(
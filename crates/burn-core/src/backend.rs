@@ -33,3 +33,9 @@ pub use burn_tch as libtorch;
 
 #[cfg(feature = "tch")]
 pub use burn_tch::LibTorch;
+
+#[cfg(feature = "fallback")]
+pub use burn_fallback as fallback;
+
+#[cfg(feature = "fallback")]
+pub use burn_fallback::Fallback;
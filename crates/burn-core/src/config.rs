@@ -1,5 +1,10 @@
-use alloc::{format, string::String, string::ToString};
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
 pub use burn_derive::Config;
+use serde_json::Value;
 
 /// Configuration IO error.
 #[derive(Debug)]
@@ -9,6 +14,15 @@ pub enum ConfigError {
 
     /// File not found.
     FileNotFound(String),
+
+    /// The path doesn't match any field of the configuration.
+    UnknownPath(String),
+
+    /// The value can't be parsed as the type of the field.
+    InvalidValue(String),
+
+    /// The file can't be written.
+    Io(String),
 }
 
 impl core::fmt::Display for ConfigError {
@@ -22,6 +36,15 @@ impl core::fmt::Display for ConfigError {
             Self::FileNotFound(err) => {
                 message += format!("File not found: {err}").as_str();
             }
+            Self::UnknownPath(err) => {
+                message += format!("Unknown path: {err}").as_str();
+            }
+            Self::InvalidValue(err) => {
+                message += format!("Invalid value: {err}").as_str();
+            }
+            Self::Io(err) => {
+                message += format!("IO error: {err}").as_str();
+            }
         };
 
         f.write_str(message.as_str())
@@ -59,9 +82,7 @@ pub trait Config: serde::Serialize + serde::de::DeserializeOwned {
     /// The loaded configuration.
     #[cfg(feature = "std")]
     fn load<P: AsRef<std::path::Path>>(file: P) -> Result<Self, ConfigError> {
-        let content = std::fs::read_to_string(file.as_ref())
-            .map_err(|_| ConfigError::FileNotFound(file.as_ref().to_string_lossy().to_string()))?;
-        config_from_str(&content)
+        config_from_str(&read_file(file)?)
     }
 
     /// Loads the configuration from a binary buffer.
@@ -79,6 +100,188 @@ pub trait Config: serde::Serialize + serde::de::DeserializeOwned {
         })?;
         config_from_str(content)
     }
+
+    /// Saves the configuration to a TOML file.
+    ///
+    /// The fields set to `None` are omitted, since TOML has no null value.
+    ///
+    /// # Arguments
+    ///
+    /// * `file` - File to save the configuration to.
+    ///
+    /// # Returns
+    ///
+    /// The output of the save operation.
+    #[cfg(feature = "config-toml")]
+    fn save_toml<P: AsRef<std::path::Path>>(&self, file: P) -> Result<(), ConfigError> {
+        let content = toml::to_string_pretty(self)
+            .map_err(|err| ConfigError::InvalidFormat(err.to_string()))?;
+
+        write_file(file, content)
+    }
+
+    /// Loads the configuration from a TOML file.
+    ///
+    /// # Arguments
+    ///
+    /// * `file` - File to load the configuration from.
+    ///
+    /// # Returns
+    ///
+    /// The loaded configuration.
+    #[cfg(feature = "config-toml")]
+    fn load_toml<P: AsRef<std::path::Path>>(file: P) -> Result<Self, ConfigError> {
+        toml::from_str(&read_file(file)?).map_err(|err| ConfigError::InvalidFormat(err.to_string()))
+    }
+
+    /// Overrides the fields set in a partial TOML file, see [merge](Config::merge).
+    ///
+    /// # Arguments
+    ///
+    /// * `file` - File containing the fields to override.
+    ///
+    /// # Returns
+    ///
+    /// The merged configuration.
+    #[cfg(feature = "config-toml")]
+    fn merge_toml<P: AsRef<std::path::Path>>(&self, file: P) -> Result<Self, ConfigError> {
+        let partial: toml::Value = toml::from_str(&read_file(file)?)
+            .map_err(|err| ConfigError::InvalidFormat(err.to_string()))?;
+
+        self.merge(&partial)
+    }
+
+    /// Saves the configuration to a YAML file.
+    ///
+    /// # Arguments
+    ///
+    /// * `file` - File to save the configuration to.
+    ///
+    /// # Returns
+    ///
+    /// The output of the save operation.
+    #[cfg(feature = "config-yaml")]
+    fn save_yaml<P: AsRef<std::path::Path>>(&self, file: P) -> Result<(), ConfigError> {
+        let content = serde_yaml_ng::to_string(self)
+            .map_err(|err| ConfigError::InvalidFormat(err.to_string()))?;
+
+        write_file(file, content)
+    }
+
+    /// Loads the configuration from a YAML file.
+    ///
+    /// # Arguments
+    ///
+    /// * `file` - File to load the configuration from.
+    ///
+    /// # Returns
+    ///
+    /// The loaded configuration.
+    #[cfg(feature = "config-yaml")]
+    fn load_yaml<P: AsRef<std::path::Path>>(file: P) -> Result<Self, ConfigError> {
+        serde_yaml_ng::from_str(&read_file(file)?)
+            .map_err(|err| ConfigError::InvalidFormat(err.to_string()))
+    }
+
+    /// Overrides the fields set in a partial YAML file, see [merge](Config::merge).
+    ///
+    /// # Arguments
+    ///
+    /// * `file` - File containing the fields to override.
+    ///
+    /// # Returns
+    ///
+    /// The merged configuration.
+    #[cfg(feature = "config-yaml")]
+    fn merge_yaml<P: AsRef<std::path::Path>>(&self, file: P) -> Result<Self, ConfigError> {
+        let partial: serde_yaml_ng::Value = serde_yaml_ng::from_str(&read_file(file)?)
+            .map_err(|err| ConfigError::InvalidFormat(err.to_string()))?;
+
+        self.merge(&partial)
+    }
+
+    /// Overrides the fields set in a partial representation of the configuration.
+    ///
+    /// The partial representation can be any value serialized like the configuration, with a
+    /// subset of its fields, such as a struct whose fields are all optional. Nested
+    /// configurations are merged recursively, while lists are replaced as a whole. The fields
+    /// without a value are kept, so an optional field can't be reset to `None` with a merge.
+    ///
+    /// # Arguments
+    ///
+    /// * `partial` - The fields to override.
+    ///
+    /// # Returns
+    ///
+    /// The merged configuration, or an error if a field of the partial representation isn't a
+    /// field of the configuration or doesn't have its type.
+    fn merge<P: serde::Serialize>(&self, partial: &P) -> Result<Self, ConfigError> {
+        let mut value = config_to_value(self)?;
+        let partial = serde_json::to_value(partial)
+            .map_err(|err| ConfigError::InvalidFormat(err.to_string()))?;
+
+        merge_value(&mut value, partial, "")?;
+
+        serde_json::from_value(value).map_err(|err| ConfigError::InvalidValue(err.to_string()))
+    }
+
+    /// Overrides the field at the dotted path with the value parsed from a string.
+    ///
+    /// The segments of the path are field names, or indices for the elements of lists, such as
+    /// `model.layers.2.d_model`. The value is parsed as the type of the current value of the
+    /// field: booleans and numbers are parsed as such, and strings are taken as is. Lists, nested
+    /// configurations and fields set to `None` are parsed from JSON, a value that isn't valid
+    /// JSON being taken as a string for the latter.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The dotted path of the field.
+    /// * `value` - The new value of the field.
+    ///
+    /// # Returns
+    ///
+    /// An error if the path doesn't match a field or if the value doesn't have its type, in which
+    /// case the configuration isn't modified.
+    fn set_by_path(&mut self, path: &str, value: &str) -> Result<(), ConfigError> {
+        let mut root = config_to_value(self)?;
+        let field = value_at_path(&mut root, path)?;
+        *field = parse_value(field, path, value)?;
+
+        *self = serde_json::from_value(root).map_err(|err| {
+            ConfigError::InvalidValue(format!("Can't set `{path}` to `{value}`: {err}"))
+        })?;
+
+        Ok(())
+    }
+
+    /// Overrides fields with a list of `path=value` strings, such as the arguments of a command
+    /// line, see [set_by_path](Config::set_by_path).
+    ///
+    /// # Arguments
+    ///
+    /// * `overrides` - The overrides, applied in order.
+    ///
+    /// # Returns
+    ///
+    /// The overridden configuration.
+    fn with_overrides<I, S>(mut self, overrides: I) -> Result<Self, ConfigError>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        for item in overrides {
+            let item = item.as_ref();
+            let (path, value) = item.split_once('=').ok_or_else(|| {
+                ConfigError::InvalidFormat(format!(
+                    "The override `{item}` should be of the form `path=value`."
+                ))
+            })?;
+
+            self.set_by_path(path.trim(), value.trim())?;
+        }
+
+        Ok(self)
+    }
 }
 
 /// Converts a configuration to a JSON string.
@@ -97,3 +300,137 @@ pub fn config_to_json<C: Config>(config: &C) -> String {
 fn config_from_str<C: Config>(content: &str) -> Result<C, ConfigError> {
     serde_json::from_str(content).map_err(|err| ConfigError::InvalidFormat(format!("{err}")))
 }
+
+fn config_to_value<C: Config>(config: &C) -> Result<Value, ConfigError> {
+    serde_json::to_value(config).map_err(|err| ConfigError::InvalidFormat(err.to_string()))
+}
+
+#[cfg(feature = "std")]
+fn read_file<P: AsRef<std::path::Path>>(file: P) -> Result<String, ConfigError> {
+    std::fs::read_to_string(file.as_ref())
+        .map_err(|_| ConfigError::FileNotFound(file.as_ref().to_string_lossy().to_string()))
+}
+
+#[cfg(any(feature = "config-toml", feature = "config-yaml"))]
+fn write_file<P: AsRef<std::path::Path>>(file: P, content: String) -> Result<(), ConfigError> {
+    let path = file.as_ref().to_string_lossy();
+
+    std::fs::write(file.as_ref(), content).map_err(|err| match err.kind() {
+        std::io::ErrorKind::NotFound => ConfigError::FileNotFound(path.to_string()),
+        _ => ConfigError::Io(format!("{path}: {err}")),
+    })
+}
+
+/// Merge the partial value into the value of the configuration at the given path.
+fn merge_value(value: &mut Value, partial: Value, path: &str) -> Result<(), ConfigError> {
+    match (value, partial) {
+        (_, Value::Null) => {}
+        (Value::Object(fields), Value::Object(partial)) => {
+            for (name, partial) in partial {
+                let path = join_path(path, &name);
+
+                if !fields.contains_key(&name) {
+                    return Err(unknown_field(&path, &name, fields.keys()));
+                }
+
+                merge_value(fields.get_mut(&name).unwrap(), partial, &path)?;
+            }
+        }
+        (value, partial) => *value = partial,
+    }
+
+    Ok(())
+}
+
+fn value_at_path<'a>(root: &'a mut Value, path: &str) -> Result<&'a mut Value, ConfigError> {
+    let mut current = root;
+
+    for segment in path.split('.') {
+        current = match current {
+            Value::Object(fields) => {
+                if !fields.contains_key(segment) {
+                    return Err(unknown_field(path, segment, fields.keys()));
+                }
+
+                fields.get_mut(segment).unwrap()
+            }
+            Value::Array(items) => {
+                let num_items = items.len();
+                let index = segment
+                    .parse::<usize>()
+                    .ok()
+                    .filter(|index| *index < num_items);
+
+                match index {
+                    Some(index) => &mut items[index],
+                    None => {
+                        return Err(ConfigError::UnknownPath(format!(
+                            "`{path}`, `{segment}` isn't an index of the {num_items} elements."
+                        )))
+                    }
+                }
+            }
+            _ => {
+                return Err(ConfigError::UnknownPath(format!(
+                    "`{path}`, `{segment}` is past a field without nested fields."
+                )))
+            }
+        };
+    }
+
+    Ok(current)
+}
+
+fn parse_value(current: &Value, path: &str, value: &str) -> Result<Value, ConfigError> {
+    let invalid = |expected: &str| {
+        ConfigError::InvalidValue(format!(
+            "Can't set `{path}` to `{value}`, expected {expected}."
+        ))
+    };
+
+    match current {
+        Value::Bool(_) => value
+            .parse::<bool>()
+            .map(Value::Bool)
+            .map_err(|_| invalid("a boolean")),
+        Value::Number(number) if number.is_f64() => value
+            .parse::<f64>()
+            .ok()
+            .and_then(serde_json::Number::from_f64)
+            .map(Value::Number)
+            .ok_or_else(|| invalid("a number")),
+        // The sign of the integer is checked when deserializing the field.
+        Value::Number(_) => value
+            .parse::<u64>()
+            .map(Value::from)
+            .or_else(|_| value.parse::<i64>().map(Value::from))
+            .map_err(|_| invalid("an integer")),
+        Value::String(_) => Ok(Value::String(value.to_string())),
+        Value::Null => {
+            Ok(serde_json::from_str(value).unwrap_or_else(|_| Value::String(value.to_string())))
+        }
+        Value::Array(_) | Value::Object(_) => {
+            serde_json::from_str(value).map_err(|_| invalid("a JSON value"))
+        }
+    }
+}
+
+fn join_path(path: &str, name: &str) -> String {
+    match path.is_empty() {
+        true => name.to_string(),
+        false => format!("{path}.{name}"),
+    }
+}
+
+fn unknown_field<'a>(
+    path: &str,
+    name: &str,
+    fields: impl Iterator<Item = &'a String>,
+) -> ConfigError {
+    let fields = fields
+        .map(|field| format!("`{field}`"))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    ConfigError::UnknownPath(format!("`{path}`, `{name}` isn't one of {fields}."))
+}
@@ -2,6 +2,7 @@ use super::{
     batcher::DynBatcher, BatchStrategy, DataLoader, DataLoaderIterator, DynDataLoader,
     MultiThreadDataLoader, Progress,
 };
+use crate::tensor::backend::Backend;
 use burn_dataset::{
     transform::{PartialDataset, ShuffledDataset},
     Dataset,
@@ -10,25 +11,27 @@ use rand::{distributions::Standard, prelude::Distribution, rngs::StdRng, Rng, Se
 use std::sync::Arc;
 
 /// A data loader that can be used to iterate over a dataset in batches.
-pub struct BatchDataLoader<I, O> {
+pub struct BatchDataLoader<B: Backend, I, O> {
     strategy: Box<dyn BatchStrategy<I>>,
     dataset: Arc<dyn Dataset<I>>,
-    batcher: Box<dyn DynBatcher<I, O>>,
+    batcher: Box<dyn DynBatcher<B, I, O>>,
+    device: B::Device,
     rng: Option<Arc<spin::Mutex<rand::rngs::StdRng>>>,
 }
 
-impl<I, O> Clone for BatchDataLoader<I, O> {
+impl<B: Backend, I, O> Clone for BatchDataLoader<B, I, O> {
     fn clone(&self) -> Self {
         Self {
             strategy: self.strategy.clone_dyn(),
             dataset: self.dataset.clone(),
             batcher: self.batcher.clone_dyn(),
+            device: self.device.clone(),
             rng: self.rng.clone(),
         }
     }
 }
 
-impl<I, O> BatchDataLoader<I, O> {
+impl<B: Backend, I, O> BatchDataLoader<B, I, O> {
     /// Creates a new batch data loader.
     ///
     /// # Arguments
@@ -36,6 +39,7 @@ impl<I, O> BatchDataLoader<I, O> {
     /// * `strategy` - The batch strategy.
     /// * `dataset` - The dataset.
     /// * `batcher` - The batcher.
+    /// * `device`  - The device on which the batches are created.
     /// * `rng`     - The rng determining if the dataset is shuffled each time a dataloader
     ///               iterator is created.
     ///
@@ -45,27 +49,30 @@ impl<I, O> BatchDataLoader<I, O> {
     pub fn new(
         strategy: Box<dyn BatchStrategy<I>>,
         dataset: Arc<dyn Dataset<I>>,
-        batcher: Box<dyn DynBatcher<I, O>>,
+        batcher: Box<dyn DynBatcher<B, I, O>>,
+        device: B::Device,
         rng: Option<rand::rngs::StdRng>,
     ) -> Self {
         Self {
             strategy,
             dataset,
             batcher,
+            device,
             rng: rng.map(|rng| Arc::new(spin::Mutex::new(rng))),
         }
     }
 }
 
 /// A data loader iterator that can be used to iterate over a data loader.
-struct BatchDataloaderIterator<I, O> {
+struct BatchDataloaderIterator<B: Backend, I, O> {
     current_index: usize,
     strategy: Box<dyn BatchStrategy<I>>,
     dataset: Arc<dyn Dataset<I>>,
-    batcher: Box<dyn DynBatcher<I, O>>,
+    batcher: Box<dyn DynBatcher<B, I, O>>,
+    device: B::Device,
 }
 
-impl<I, O> BatchDataLoader<I, O>
+impl<B: Backend, I, O> BatchDataLoader<B, I, O>
 where
     I: Send + Sync + Clone + 'static,
     O: Send + Clone + 'static,
@@ -77,6 +84,7 @@ where
     /// * `strategy` - The batch strategy.
     /// * `dataset` - The dataset.
     /// * `batcher` - The batcher.
+    /// * `device` - The device on which the batches are created.
     /// * `num_threads` - The number of threads.
     ///
     /// # Returns
@@ -85,7 +93,8 @@ where
     pub fn multi_thread(
         strategy: Box<dyn BatchStrategy<I>>,
         dataset: Arc<dyn Dataset<I>>,
-        batcher: Box<dyn DynBatcher<I, O>>,
+        batcher: Box<dyn DynBatcher<B, I, O>>,
+        device: B::Device,
         num_threads: usize,
         mut rng: Option<rand::rngs::StdRng>,
     ) -> MultiThreadDataLoader<O> {
@@ -101,8 +110,13 @@ where
 
         for (dataset, rng) in datasets.into_iter().zip(rngs) {
             let strategy = strategy.clone_dyn();
-            let dataloader =
-                BatchDataLoader::new(strategy, Arc::new(dataset), batcher.clone_dyn(), rng);
+            let dataloader = BatchDataLoader::new(
+                strategy,
+                Arc::new(dataset),
+                batcher.clone_dyn(),
+                device.clone(),
+                rng,
+            );
             let dataloader: Box<dyn DynDataLoader<_>> = Box::new(dataloader);
             dataloaders.push(dataloader);
         }
@@ -110,7 +124,7 @@ where
     }
 }
 
-impl<I, O> DataLoader<O> for BatchDataLoader<I, O>
+impl<B: Backend, I, O> DataLoader<O> for BatchDataLoader<B, I, O>
 where
     I: Send + Sync + Clone + 'static,
     O: Send + 'static,
@@ -134,6 +148,7 @@ where
             self.strategy.clone_dyn(),
             dataset,
             self.batcher.clone_dyn(),
+            self.device.clone(),
         ))
     }
 
@@ -142,7 +157,7 @@ where
     }
 }
 
-impl<I, O> BatchDataloaderIterator<I, O> {
+impl<B: Backend, I, O> BatchDataloaderIterator<B, I, O> {
     /// Creates a new batch data loader iterator.
     ///
     /// # Arguments
@@ -150,6 +165,7 @@ impl<I, O> BatchDataloaderIterator<I, O> {
     /// * `strategy` - The batch strategy.
     /// * `dataset` - The dataset.
     /// * `batcher` - The batcher.
+    /// * `device` - The device on which the batches are created.
     ///
     /// # Returns
     ///
@@ -157,18 +173,20 @@ impl<I, O> BatchDataloaderIterator<I, O> {
     pub fn new(
         strategy: Box<dyn BatchStrategy<I>>,
         dataset: Arc<dyn Dataset<I>>,
-        batcher: Box<dyn DynBatcher<I, O>>,
+        batcher: Box<dyn DynBatcher<B, I, O>>,
+        device: B::Device,
     ) -> Self {
         BatchDataloaderIterator {
             current_index: 0,
             strategy,
             dataset,
             batcher,
+            device,
         }
     }
 }
 
-impl<I, O> Iterator for BatchDataloaderIterator<I, O> {
+impl<B: Backend, I, O> Iterator for BatchDataloaderIterator<B, I, O> {
     type Item = O;
 
     fn next(&mut self) -> Option<O> {
@@ -177,19 +195,19 @@ impl<I, O> Iterator for BatchDataloaderIterator<I, O> {
             self.strategy.add(item);
 
             if let Some(items) = self.strategy.batch(false) {
-                return Some(self.batcher.batch(items));
+                return Some(self.batcher.batch(items, &self.device));
             }
         }
 
         if let Some(items) = self.strategy.batch(true) {
-            return Some(self.batcher.batch(items));
+            return Some(self.batcher.batch(items, &self.device));
         }
 
         None
     }
 }
 
-impl<I, O> DataLoaderIterator<O> for BatchDataloaderIterator<I, O> {
+impl<B: Backend, I, O> DataLoaderIterator<O> for BatchDataloaderIterator<B, I, O> {
     fn progress(&self) -> Progress {
         Progress::new(self.current_index, self.dataset.len())
     }
@@ -203,15 +221,17 @@ mod tests {
     use crate::data::dataloader::batcher::TestBatcher;
     use crate::data::dataloader::FixBatchStrategy;
     use crate::data::dataset::FakeDataset;
+    use crate::TestBackend;
 
     #[test]
     fn test_batch_dataloader() {
         let batcher = Box::new(TestBatcher::new());
         let dataset = Arc::new(FakeDataset::<String>::new(27));
-        let dataloader = BatchDataLoader::new(
+        let dataloader = BatchDataLoader::<TestBackend, _, _>::new(
             Box::new(FixBatchStrategy::new(5)),
             dataset.clone(),
             batcher,
+            Default::default(),
             None,
         );
 
@@ -235,16 +255,18 @@ mod tests {
     fn test_multi_thread_batch_dataloader() {
         let batcher = Box::new(TestBatcher::new());
         let dataset = Arc::new(FakeDataset::<String>::new(27));
-        let dataloader_single_thread = BatchDataLoader::new(
+        let dataloader_single_thread = BatchDataLoader::<TestBackend, _, _>::new(
             Box::new(FixBatchStrategy::new(5)),
             dataset.clone(),
             batcher.clone_dyn(),
+            Default::default(),
             None,
         );
-        let dataloader_multi_thread = BatchDataLoader::multi_thread(
+        let dataloader_multi_thread = BatchDataLoader::<TestBackend, _, _>::multi_thread(
             Box::new(FixBatchStrategy::new(5)),
             dataset,
             batcher,
+            Default::default(),
             4,
             None,
         );
@@ -1,30 +1,34 @@
+use crate::tensor::backend::Backend;
+
 /// A trait for batching items of type `I` into items of type `O`.
-pub trait Batcher<I, O>: Send {
-    /// Batches the given items.
+pub trait Batcher<B: Backend, I, O>: Send {
+    /// Batches the given items on the given device.
     ///
     /// # Arguments
     ///
     /// * `items` - The items to batch.
+    /// * `device` - The device on which the tensors of the batch are created.
     ///
     /// # Returns
     ///
     /// The batched items.
-    fn batch(&self, items: Vec<I>) -> O;
+    fn batch(&self, items: Vec<I>, device: &B::Device) -> O;
 }
 
 /// A super trait for [batcher](Batcher) that allows it to be cloned dynamically.
 ///
 /// Any batcher that implements [Clone] should also implement this automatically.
-pub trait DynBatcher<I, O>: Send + Batcher<I, O> {
+pub trait DynBatcher<B: Backend, I, O>: Send + Batcher<B, I, O> {
     /// Clone the batcher and returns a new one.
-    fn clone_dyn(&self) -> Box<dyn DynBatcher<I, O>>;
+    fn clone_dyn(&self) -> Box<dyn DynBatcher<B, I, O>>;
 }
 
-impl<B, I, O> DynBatcher<I, O> for B
+impl<T, B, I, O> DynBatcher<B, I, O> for T
 where
-    B: Batcher<I, O> + Clone + 'static,
+    T: Batcher<B, I, O> + Clone + 'static,
+    B: Backend,
 {
-    fn clone_dyn(&self) -> Box<dyn DynBatcher<I, O>> {
+    fn clone_dyn(&self) -> Box<dyn DynBatcher<B, I, O>> {
         Box::new(self.clone())
     }
 }
@@ -34,8 +38,8 @@ where
 pub struct TestBatcher;
 
 #[cfg(test)]
-impl<I> Batcher<I, Vec<I>> for TestBatcher {
-    fn batch(&self, items: Vec<I>) -> Vec<I> {
+impl<B: Backend, I> Batcher<B, I, Vec<I>> for TestBatcher {
+    fn batch(&self, items: Vec<I>, _device: &B::Device) -> Vec<I> {
         items
     }
 }
@@ -1,18 +1,25 @@
-use super::{batcher::DynBatcher, BatchDataLoader, BatchStrategy, DataLoader, FixBatchStrategy};
+use super::{
+    batcher::DynBatcher, BatchDataLoader, BatchStrategy, DataLoader, FixBatchStrategy,
+    PrefetchDataLoader,
+};
+use crate::tensor::backend::Backend;
 use burn_dataset::Dataset;
 use rand::{rngs::StdRng, SeedableRng};
 use std::sync::Arc;
 
 /// A builder for data loaders.
-pub struct DataLoaderBuilder<I, O> {
+pub struct DataLoaderBuilder<B: Backend, I, O> {
     strategy: Option<Box<dyn BatchStrategy<I>>>,
-    batcher: Box<dyn DynBatcher<I, O>>,
+    batcher: Box<dyn DynBatcher<B, I, O>>,
+    device: Option<B::Device>,
     num_threads: Option<usize>,
+    prefetch: Option<usize>,
     shuffle: Option<u64>,
 }
 
-impl<I, O> DataLoaderBuilder<I, O>
+impl<B, I, O> DataLoaderBuilder<B, I, O>
 where
+    B: Backend,
     I: Send + Sync + Clone + std::fmt::Debug + 'static,
     O: Send + Clone + std::fmt::Debug + 'static,
 {
@@ -25,14 +32,16 @@ where
     /// # Returns
     ///
     /// The data loader builder.
-    pub fn new<B>(batcher: B) -> Self
+    pub fn new<T>(batcher: T) -> Self
     where
-        B: DynBatcher<I, O> + 'static,
+        T: DynBatcher<B, I, O> + 'static,
     {
         Self {
             batcher: Box::new(batcher),
             strategy: None,
+            device: None,
             num_threads: None,
+            prefetch: None,
             shuffle: None,
         }
     }
@@ -82,6 +91,42 @@ where
         self
     }
 
+    /// Sets the device on which the batches are created.
+    ///
+    /// The device is given to the [batcher](crate::data::dataloader::batcher::Batcher), so the
+    /// tensors of each batch are created directly on it. When not set, the default device of the
+    /// backend is used.
+    ///
+    /// # Arguments
+    ///
+    /// * `device` - The device.
+    ///
+    /// # Returns
+    ///
+    /// The data loader builder.
+    pub fn device(mut self, device: B::Device) -> Self {
+        self.device = Some(device);
+        self
+    }
+
+    /// Sets the number of batches prepared ahead of consumption.
+    ///
+    /// The batches are created on a dedicated thread, so that the batching of the next items
+    /// overlaps with the computation done on the current batch. At most `num_batches` batches
+    /// are kept ready at any time; a value of zero disables prefetching.
+    ///
+    /// # Arguments
+    ///
+    /// * `num_batches` - The number of batches to prefetch.
+    ///
+    /// # Returns
+    ///
+    /// The data loader builder.
+    pub fn prefetch(mut self, num_batches: usize) -> Self {
+        self.prefetch = Some(num_batches);
+        self
+    }
+
     /// Builds the data loader.
     ///
     /// # Arguments
@@ -102,16 +147,87 @@ where
             Some(strategy) => strategy,
             None => Box::new(FixBatchStrategy::new(1)),
         };
+        let device = self.device.unwrap_or_default();
+        let prefetch = self.prefetch.filter(|num_batches| *num_batches > 0);
+
         if let Some(num_threads) = self.num_threads {
-            return Arc::new(BatchDataLoader::multi_thread(
+            let dataloader = BatchDataLoader::multi_thread(
                 strategy,
                 dataset,
                 self.batcher,
+                device,
                 num_threads,
                 rng,
-            ));
+            );
+
+            return match prefetch {
+                Some(num_batches) => {
+                    Arc::new(PrefetchDataLoader::new(Box::new(dataloader), num_batches))
+                }
+                None => Arc::new(dataloader),
+            };
+        }
+
+        let dataloader = BatchDataLoader::new(strategy, dataset, self.batcher, device, rng);
+
+        match prefetch {
+            Some(num_batches) => {
+                Arc::new(PrefetchDataLoader::new(Box::new(dataloader), num_batches))
+            }
+            None => Arc::new(dataloader),
         }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::dataloader::batcher::Batcher;
+    use crate::data::dataset::FakeDataset;
+    use crate::tensor::Tensor;
+    use crate::TestBackend;
+
+    #[derive(Clone)]
+    struct TensorBatcher;
+
+    impl<B: Backend> Batcher<B, String, Tensor<B, 1>> for TensorBatcher {
+        fn batch(&self, items: Vec<String>, device: &B::Device) -> Tensor<B, 1> {
+            Tensor::zeros([items.len()], device)
+        }
+    }
+
+    #[test]
+    fn test_batches_are_created_on_the_configured_device() {
+        let device = <TestBackend as Backend>::Device::default();
+
+        for prefetch in [0, 2] {
+            let dataloader = DataLoaderBuilder::<TestBackend, _, _>::new(TensorBatcher)
+                .batch_size(5)
+                .device(device.clone())
+                .prefetch(prefetch)
+                .build(FakeDataset::<String>::new(27));
+
+            let mut num_items = 0;
+            for batch in dataloader.iter() {
+                assert_eq!(batch.device(), device);
+                num_items += batch.dims()[0];
+            }
+
+            assert_eq!(num_items, 27);
+        }
+    }
+
+    #[test]
+    fn test_prefetch_with_multiple_workers() {
+        let dataloader = DataLoaderBuilder::<TestBackend, _, _>::new(TensorBatcher)
+            .batch_size(5)
+            .num_workers(3)
+            .prefetch(4)
+            .build(FakeDataset::<String>::new(27));
+
+        let num_items: usize = dataloader.iter().map(|batch| batch.dims()[0]).sum();
 
-        Arc::new(BatchDataLoader::new(strategy, dataset, self.batcher, rng))
+        assert_eq!(num_items, 27);
+        assert_eq!(dataloader.num_items(), 27);
     }
 }
@@ -9,6 +9,18 @@ pub struct MultiThreadDataLoader<O> {
     dataloaders: Vec<Box<dyn DynDataLoader<O>>>,
 }
 
+impl<O> Clone for MultiThreadDataLoader<O> {
+    fn clone(&self) -> Self {
+        Self {
+            dataloaders: self
+                .dataloaders
+                .iter()
+                .map(|dataloader| dataloader.clone_dyn())
+                .collect(),
+        }
+    }
+}
+
 /// A message that can be sent between threads.
 #[derive(Debug)]
 pub enum Message<O> {
@@ -0,0 +1,174 @@
+use super::{DataLoader, DataLoaderIterator, DynDataLoader, Progress};
+use std::sync::mpsc;
+use std::thread;
+
+/// A data loader that prepares the next batches of another data loader on a dedicated thread.
+///
+/// While the current batch is being consumed, up to `num_batches` batches are created ahead,
+/// which overlaps the batching work with the computation done on each batch.
+pub struct PrefetchDataLoader<O> {
+    dataloader: Box<dyn DynDataLoader<O>>,
+    num_batches: usize,
+}
+
+impl<O> Clone for PrefetchDataLoader<O> {
+    fn clone(&self) -> Self {
+        Self {
+            dataloader: self.dataloader.clone_dyn(),
+            num_batches: self.num_batches,
+        }
+    }
+}
+
+struct PrefetchDataloaderIterator<O> {
+    worker: Option<thread::JoinHandle<()>>,
+    receiver: mpsc::Receiver<(O, Progress)>,
+    progress: Progress,
+}
+
+impl<O> PrefetchDataLoader<O> {
+    /// Creates a new prefetch data loader.
+    ///
+    /// # Arguments
+    ///
+    /// * `dataloader` - The data loader creating the batches.
+    /// * `num_batches` - The maximum number of batches created ahead of consumption.
+    ///
+    /// # Returns
+    ///
+    /// The prefetch data loader.
+    pub fn new(dataloader: Box<dyn DynDataLoader<O>>, num_batches: usize) -> Self {
+        Self {
+            dataloader,
+            num_batches,
+        }
+    }
+}
+
+impl<O> DataLoader<O> for PrefetchDataLoader<O>
+where
+    O: Send + 'static,
+{
+    fn iter<'a>(&'a self) -> Box<dyn DataLoaderIterator<O> + 'a> {
+        // The worker holds one batch while it waits for a free slot in the channel, so the
+        // channel itself only needs to buffer the remaining batches.
+        let (sender, receiver) = mpsc::sync_channel(self.num_batches.saturating_sub(1));
+        let dataloader = self.dataloader.clone_dyn();
+        let progress = Progress::new(0, dataloader.num_items());
+
+        let worker = thread::spawn(move || {
+            let mut iterator = dataloader.iter();
+            while let Some(item) = iterator.next() {
+                let progress = iterator.progress();
+
+                // The receiver is gone when the iterator is dropped before the end, in which case
+                // we just need to stop iterating.
+                if sender.send((item, progress)).is_err() {
+                    return;
+                }
+            }
+        });
+
+        Box::new(PrefetchDataloaderIterator {
+            worker: Some(worker),
+            receiver,
+            progress,
+        })
+    }
+
+    fn num_items(&self) -> usize {
+        self.dataloader.num_items()
+    }
+}
+
+impl<O> DataLoaderIterator<O> for PrefetchDataloaderIterator<O> {
+    fn progress(&self) -> Progress {
+        self.progress.clone()
+    }
+}
+
+impl<O> Iterator for PrefetchDataloaderIterator<O> {
+    type Item = O;
+
+    fn next(&mut self) -> Option<O> {
+        match self.receiver.recv() {
+            Ok((item, progress)) => {
+                self.progress = progress;
+                Some(item)
+            }
+            Err(_) => {
+                // The sender is dropped once the worker is done.
+                if let Some(worker) = self.worker.take() {
+                    worker.join().unwrap();
+                }
+                None
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::dataloader::batcher::TestBatcher;
+    use crate::data::dataloader::{BatchDataLoader, FixBatchStrategy};
+    use crate::data::dataset::FakeDataset;
+    use crate::TestBackend;
+    use std::sync::Arc;
+
+    fn dataloader(
+        num_batches: usize,
+    ) -> (
+        BatchDataLoader<TestBackend, String, Vec<String>>,
+        PrefetchDataLoader<Vec<String>>,
+    ) {
+        let dataloader = BatchDataLoader::<TestBackend, _, _>::new(
+            Box::new(FixBatchStrategy::new(5)),
+            Arc::new(FakeDataset::<String>::new(27)),
+            Box::new(TestBatcher::new()),
+            Default::default(),
+            None,
+        );
+        let prefetch = PrefetchDataLoader::new(Box::new(dataloader.clone()), num_batches);
+
+        (dataloader, prefetch)
+    }
+
+    #[test]
+    fn test_prefetch_dataloader_keeps_the_batch_order() {
+        for num_batches in [1, 2, 10] {
+            let (dataloader, prefetch) = dataloader(num_batches);
+
+            let expected: Vec<_> = dataloader.iter().collect();
+            let actual: Vec<_> = prefetch.iter().collect();
+
+            assert_eq!(expected, actual);
+        }
+    }
+
+    #[test]
+    fn test_prefetch_dataloader_progress() {
+        let (_, prefetch) = dataloader(2);
+        let mut iterator = prefetch.iter();
+
+        assert_eq!(iterator.progress().items_processed, 0);
+        assert_eq!(iterator.progress().items_total, 27);
+
+        iterator.next();
+        assert_eq!(iterator.progress().items_processed, 5);
+
+        while iterator.next().is_some() {}
+        assert_eq!(iterator.progress().items_processed, 27);
+    }
+
+    #[test]
+    fn test_prefetch_dataloader_can_be_dropped_early() {
+        let (_, prefetch) = dataloader(1);
+        let mut iterator = prefetch.iter();
+
+        assert!(iterator.next().is_some());
+        drop(iterator);
+
+        assert_eq!(prefetch.iter().count(), 6);
+    }
+}
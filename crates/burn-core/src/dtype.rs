@@ -0,0 +1,229 @@
+//! Runtime selection of the float data type of the backend.
+//!
+//! The float element type of a backend is part of its type, so running a model with the precision
+//! of a checkpoint chosen at runtime needs one instance of the generic code per precision.
+//! [dispatch_float_dtype](crate::dispatch_float_dtype) instantiates an entry point for each
+//! declared backend and runs the one matching a [data type](DType), usually the
+//! [float data type](crate::record::BurnMetadata::float_dtype) of the record to load.
+//!
+//! The instances are then used behind a trait object, like [DynInference], exchanging
+//! [TensorData] whose type doesn't depend on the backend.
+
+use alloc::vec::Vec;
+
+use crate::tensor::{DType, TensorData};
+
+/// Select the backend matching a float [data type](DType) at runtime, running the body with the
+/// backend as the given type alias.
+///
+/// Each arm maps a variant of [DType] to a backend, the body being compiled once per backend.
+/// The macro returns `Ok` with the value of the body, or an [UnsupportedDType] error when the
+/// data type isn't one of the arms. The backends can't depend on the generic parameters of the
+/// enclosing function, since they are declared as type aliases.
+///
+/// # Example
+///
+/// ```rust, ignore
+/// let recorder = NamedMpkFileRecorder::<DoublePrecisionSettings>::new();
+/// let metadata = Recorder::<Wgpu>::load_metadata(&recorder, path.clone())?;
+/// let dtype = metadata.float_dtype().expect("A float type of the precision settings");
+///
+/// let model = burn::dispatch_float_dtype!(dtype, {
+///     F32 => Wgpu<f32, i32>,
+///     F16 => Wgpu<f16, i32>,
+/// }, |B| Box::new(Server::<B>::load(&recorder, path, &device)?) as Box<dyn DynInference>)?;
+/// ```
+#[macro_export]
+macro_rules! dispatch_float_dtype {
+    ($dtype:expr, { $($variant:ident => $backend:ty),+ $(,)? }, |$name:ident| $body:expr) => {
+        match $dtype {
+            $(
+                $crate::tensor::DType::$variant => {
+                    #[allow(dead_code)]
+                    type $name = $backend;
+                    Ok($body)
+                }
+            )+
+            dtype => Err($crate::dtype::UnsupportedDType::new(
+                dtype,
+                &[$($crate::tensor::DType::$variant),+],
+            )),
+        }
+    };
+}
+
+/// The error of [dispatch_float_dtype](crate::dispatch_float_dtype) when no backend is declared
+/// for the data type.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnsupportedDType {
+    /// The data type to dispatch.
+    pub dtype: DType,
+    /// The data types of the declared backends.
+    pub supported: Vec<DType>,
+}
+
+impl UnsupportedDType {
+    /// Create the error for the data type, given the supported ones.
+    pub fn new(dtype: DType, supported: &[DType]) -> Self {
+        Self {
+            dtype,
+            supported: supported.to_vec(),
+        }
+    }
+}
+
+impl core::fmt::Display for UnsupportedDType {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "The float data type {:?} has no backend, the supported ones being {:?}.",
+            self.dtype, self.supported
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for UnsupportedDType {}
+
+/// An inference entry point whose backend is selected at runtime, taking and returning tensors as
+/// [TensorData].
+pub trait DynInference: Send {
+    /// The float data type of the backend running the inference.
+    fn float_dtype(&self) -> DType;
+
+    /// Run the inference on the inputs.
+    fn infer(&self, inputs: Vec<TensorData>) -> Vec<TensorData>;
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate as burn;
+    use crate::module::Module;
+    use crate::nn::{Linear, LinearConfig};
+    use crate::record::{
+        DoublePrecisionSettings, FullPrecisionSettings, HalfPrecisionSettings,
+        NamedMpkBytesRecorder, PrecisionSettings, Recorder,
+    };
+    use crate::tensor::{backend::Backend, Element, Tensor};
+    use crate::TestBackend;
+    use alloc::boxed::Box;
+    use alloc::vec;
+    use burn_ndarray::NdArray;
+
+    #[derive(Module, Debug)]
+    struct Model<B: Backend> {
+        linear: Linear<B>,
+    }
+
+    struct Inference<B: Backend> {
+        model: Model<B>,
+    }
+
+    impl<B: Backend> Inference<B> {
+        fn load(bytes: Vec<u8>) -> Self {
+            let device = Default::default();
+            let model = Model {
+                linear: LinearConfig::new(2, 3).init(&device),
+            };
+            let recorder = NamedMpkBytesRecorder::<DoublePrecisionSettings>::new();
+            let record = Recorder::<B>::load(&recorder, bytes, &device).unwrap();
+
+            Self {
+                model: model.load_record(record),
+            }
+        }
+    }
+
+    impl<B: Backend> DynInference for Inference<B> {
+        fn float_dtype(&self) -> DType {
+            B::FloatElem::dtype()
+        }
+
+        fn infer(&self, inputs: Vec<TensorData>) -> Vec<TensorData> {
+            inputs
+                .into_iter()
+                .map(|input| {
+                    let input = Tensor::<B, 2>::from_data(input, &Default::default());
+                    self.model.linear.forward(input).into_data()
+                })
+                .collect()
+        }
+    }
+
+    /// Record the model with weights exactly represented in every precision.
+    fn record<S: PrecisionSettings>() -> Vec<u8> {
+        let device = Default::default();
+        let mut linear = LinearConfig::new(2, 3).init::<TestBackend>(&device);
+        linear.weight = linear
+            .weight
+            .map(|_| Tensor::from_floats([[0.5, -1.0, 0.25], [2.0, 0.0, -0.75]], &device));
+        linear.bias = linear
+            .bias
+            .map(|bias| bias.map(|_| Tensor::from_floats([1.0, 0.5, -2.0], &device)));
+
+        Recorder::<TestBackend>::record(
+            &NamedMpkBytesRecorder::<S>::new(),
+            Model { linear }.into_record(),
+            (),
+        )
+        .unwrap()
+    }
+
+    fn load(bytes: Vec<u8>) -> Result<Box<dyn DynInference>, UnsupportedDType> {
+        let recorder = NamedMpkBytesRecorder::<FullPrecisionSettings>::new();
+        let metadata = Recorder::<TestBackend>::load_metadata(&recorder, bytes.clone()).unwrap();
+        let dtype = metadata.float_dtype().unwrap();
+
+        crate::dispatch_float_dtype!(dtype, {
+            F32 => NdArray<f32>,
+            F64 => NdArray<f64>,
+        }, |B| Box::new(Inference::<B>::load(bytes)) as Box<dyn DynInference>)
+    }
+
+    #[test]
+    fn should_run_the_precision_of_the_record() {
+        let input = TensorData::from([[1.0f32, 2.0], [-1.0, 0.5]]);
+        let expected = TensorData::from([[5.5f32, -0.5, -3.25], [1.5, 1.5, -2.625]]);
+
+        for (bytes, dtype) in [
+            (record::<FullPrecisionSettings>(), DType::F32),
+            (record::<DoublePrecisionSettings>(), DType::F64),
+        ] {
+            let inference = load(bytes).unwrap();
+            let output = inference.infer(vec![input.clone()]).remove(0);
+
+            assert_eq!(inference.float_dtype(), dtype);
+            assert_eq!(output.dtype, dtype);
+            output.assert_approx_eq(&expected, 6);
+        }
+    }
+
+    #[test]
+    fn should_report_the_float_dtype_of_the_record() {
+        let recorder = NamedMpkBytesRecorder::<FullPrecisionSettings>::new();
+        let dtype = |bytes| {
+            Recorder::<TestBackend>::load_metadata(&recorder, bytes)
+                .unwrap()
+                .float_dtype()
+        };
+
+        assert_eq!(dtype(record::<HalfPrecisionSettings>()), Some(DType::F16));
+        assert_eq!(dtype(record::<FullPrecisionSettings>()), Some(DType::F32));
+        assert_eq!(dtype(record::<DoublePrecisionSettings>()), Some(DType::F64));
+    }
+
+    #[test]
+    fn should_fail_on_a_record_without_backend() {
+        let err = load(record::<HalfPrecisionSettings>()).err().unwrap();
+
+        assert_eq!(
+            err,
+            UnsupportedDType::new(DType::F16, &[DType::F32, DType::F64])
+        );
+        assert_eq!(
+            err.to_string(),
+            "The float data type F16 has no backend, the supported ones being [F32, F64]."
+        );
+    }
+}
@@ -0,0 +1,419 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use super::{apply_repetition_penalty, AutoregressiveCache, AutoregressiveModel, Generator};
+use crate as burn;
+use crate::config::Config;
+use crate::tensor::{activation::log_softmax, backend::Backend, Int, Tensor, TensorData};
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+/// Configuration of the [beam search](Generator::beam_search) of a generator.
+#[derive(Config, Debug)]
+pub struct BeamSearchConfig {
+    /// The number of sequences kept at each step for each prompt.
+    pub beam_width: usize,
+    /// The exponent `alpha` of the length normalization `((5 + length) / 6) ^ alpha` proposed in
+    /// [GNMT](https://arxiv.org/abs/1609.08144), `0.0` meaning no normalization. Default: 0.6
+    #[config(default = 0.6)]
+    pub length_penalty: f64,
+    /// Stop the search of a prompt as soon as `beam_width` hypotheses are completed, instead of
+    /// when no better hypothesis can be found. Default: true
+    #[config(default = true)]
+    pub early_stopping: bool,
+    /// The number of hypotheses returned for each prompt. Default: 1
+    #[config(default = 1)]
+    pub num_hypotheses: usize,
+}
+
+/// A sequence found by the [beam search](Generator::beam_search).
+#[derive(Clone, Debug, PartialEq)]
+pub struct BeamHypothesis {
+    /// The prompt followed by the generated tokens, including the end token if any.
+    pub tokens: Vec<usize>,
+    /// The log probability of the generated tokens, normalized by their length.
+    pub score: f64,
+}
+
+impl BeamSearchConfig {
+    fn length_normalization(&self, length: usize) -> f64 {
+        ((5.0 + length as f64) / 6.0).powf(self.length_penalty)
+    }
+}
+
+/// The best hypotheses found for a prompt.
+struct BeamHypotheses {
+    hypotheses: Vec<BeamHypothesis>,
+    done: bool,
+}
+
+impl BeamHypotheses {
+    fn new() -> Self {
+        Self {
+            hypotheses: Vec::new(),
+            done: false,
+        }
+    }
+
+    fn add(&mut self, tokens: Vec<usize>, log_prob: f64, length: usize, config: &BeamSearchConfig) {
+        self.hypotheses.push(BeamHypothesis {
+            tokens,
+            score: log_prob / config.length_normalization(length),
+        });
+        self.hypotheses
+            .sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap());
+        self.hypotheses.truncate(config.beam_width);
+    }
+
+    /// If no running beam can become better than the kept hypotheses.
+    ///
+    /// Without early stopping, the best running beam is compared at its current length, as a
+    /// longer sequence only has a lower log probability.
+    fn is_done(&self, best_log_prob: f64, length: usize, config: &BeamSearchConfig) -> bool {
+        if self.hypotheses.len() < config.beam_width {
+            return false;
+        }
+
+        if config.early_stopping {
+            return true;
+        }
+
+        let worst = self.hypotheses.last().unwrap().score;
+        best_log_prob / config.length_normalization(length) <= worst
+    }
+
+    fn into_best(self, num_hypotheses: usize) -> Vec<BeamHypothesis> {
+        let mut hypotheses = self.hypotheses;
+        hypotheses.truncate(num_hypotheses);
+        hypotheses
+    }
+}
+
+impl<M> Generator<M> {
+    /// Search the most likely continuations of every prompt, keeping the `beam_width` best
+    /// sequences at each step.
+    ///
+    /// Every prompt is expanded into `beam_width` rows of the batch given to the model, which are
+    /// reordered with [reorder_cache](AutoregressiveCache::reorder_cache) whenever the beams are
+    /// selected. The maximum number of new tokens, the end token and the repetition penalty of the
+    /// generator are used, while its sampling parameters are ignored.
+    ///
+    /// # Shapes
+    ///
+    /// - prompts: `[batch_size, prompt_length]`
+    ///
+    /// # Returns
+    ///
+    /// The best hypotheses of each prompt sorted by decreasing score.
+    pub fn beam_search<B: Backend>(
+        &self,
+        prompts: Tensor<B, 2, Int>,
+        config: &BeamSearchConfig,
+    ) -> Vec<Vec<BeamHypothesis>>
+    where
+        M: AutoregressiveModel<B>,
+        M::Cache: AutoregressiveCache<B>,
+    {
+        let width = config.beam_width;
+        assert!(width > 0, "The beam width must be positive");
+        assert!(
+            config.num_hypotheses > 0 && config.num_hypotheses <= width,
+            "The number of hypotheses must be between 1 and the beam width"
+        );
+
+        let [batch_size, prompt_length] = prompts.dims();
+        let num_beams = batch_size * width;
+        let device = prompts.device();
+        let pad_token = self.config.pad_token;
+
+        let prompt_tokens = to_tokens(prompts.to_data());
+        let mut sequences: Vec<Vec<usize>> = prompt_tokens
+            .chunks(prompt_length)
+            .flat_map(|prompt| vec![prompt.to_vec(); width])
+            .collect();
+        let origins = Tensor::<B, 1, Int>::arange(0..batch_size as i64, &device)
+            .reshape([batch_size, 1])
+            .repeat_dim(1, width)
+            .reshape([num_beams]);
+        let mut tokens = prompts.select(0, origins);
+        let mut cache = self.model.init_cache(num_beams, &device);
+        let mut groups: Vec<BeamHypotheses> =
+            (0..batch_size).map(|_| BeamHypotheses::new()).collect();
+
+        // All the beams of a prompt are the same at first, so only the first one is expanded.
+        let mut log_probs: Vec<f64> = (0..num_beams)
+            .map(|beam| match beam % width {
+                0 => 0.0,
+                _ => f64::NEG_INFINITY,
+            })
+            .collect();
+
+        for step in 0..self.config.max_new_tokens {
+            let length = step + 1;
+            let mut logits = self.model.forward(tokens.clone(), &mut cache);
+            let [_, vocab_size] = logits.dims();
+
+            if self.config.repetition_penalty != 1.0 {
+                logits = apply_repetition_penalty(
+                    logits,
+                    tokens.clone(),
+                    self.config.repetition_penalty,
+                );
+            }
+
+            let beam_log_probs = Tensor::<B, 1>::from_floats(
+                TensorData::new(log_probs.iter().map(|p| *p as f32).collect(), [num_beams]),
+                &device,
+            );
+            let candidates = (log_softmax(logits, 1) + beam_log_probs.reshape([num_beams, 1]))
+                .reshape([batch_size, width * vocab_size]);
+
+            // Twice the beam width is enough to keep `width` beams even if some candidates end.
+            let num_candidates = usize::min(2 * width, width * vocab_size);
+            let (values, indices) = candidates.topk_with_indices(num_candidates, 1);
+            let values: Vec<f64> = values.into_data().iter::<f64>().collect();
+            let indices = to_tokens(indices.into_data());
+
+            let mut origins = Vec::with_capacity(num_beams);
+            let mut next_tokens = Vec::with_capacity(num_beams);
+            let mut next_log_probs = Vec::with_capacity(num_beams);
+
+            for (prompt, group) in groups.iter_mut().enumerate() {
+                if group.done {
+                    origins.extend((0..width).map(|beam| prompt * width + beam));
+                    next_tokens.extend(vec![pad_token; width]);
+                    next_log_probs.extend(vec![0.0; width]);
+                    continue;
+                }
+
+                let mut num_selected = 0;
+                for rank in 0..num_candidates {
+                    let candidate = prompt * num_candidates + rank;
+                    let origin = prompt * width + indices[candidate] / vocab_size;
+                    let token = indices[candidate] % vocab_size;
+                    let log_prob = values[candidate];
+
+                    if Some(token) == self.config.eos_token {
+                        // Only candidates that would have been selected as beams can complete.
+                        if rank < width {
+                            let mut sequence = sequences[origin].clone();
+                            sequence.push(token);
+                            group.add(sequence, log_prob, length, config);
+                        }
+                        continue;
+                    }
+
+                    origins.push(origin);
+                    next_tokens.push(token);
+                    next_log_probs.push(log_prob);
+                    num_selected += 1;
+
+                    if num_selected == width {
+                        break;
+                    }
+                }
+
+                group.done = group.is_done(next_log_probs[prompt * width], length, config);
+            }
+
+            sequences = origins
+                .iter()
+                .zip(next_tokens.iter())
+                .map(|(origin, token)| {
+                    let mut sequence = sequences[*origin].clone();
+                    sequence.push(*token);
+                    sequence
+                })
+                .collect();
+            log_probs = next_log_probs;
+
+            let origins = Tensor::<B, 1, Int>::from_ints(
+                TensorData::new(origins.into_iter().map(|i| i as i64).collect(), [num_beams]),
+                &device,
+            );
+            let next_tokens = Tensor::<B, 2, Int>::from_ints(
+                TensorData::new(
+                    next_tokens.into_iter().map(|t| t as i64).collect(),
+                    [num_beams, 1],
+                ),
+                &device,
+            );
+            tokens = Tensor::cat(vec![tokens.select(0, origins.clone()), next_tokens], 1);
+            cache.reorder_cache(origins);
+
+            if groups.iter().all(|group| group.done) {
+                break;
+            }
+        }
+
+        let mut running = sequences.into_iter().zip(log_probs);
+
+        groups
+            .into_iter()
+            .map(|mut group| {
+                let beams: Vec<_> = running.by_ref().take(width).collect();
+
+                // The beams still running when the maximum length is reached are also candidates.
+                if !group.done {
+                    for (sequence, log_prob) in beams {
+                        let length = sequence.len() - prompt_length;
+                        group.add(sequence, log_prob, length, config);
+                    }
+                }
+
+                group.into_best(config.num_hypotheses)
+            })
+            .collect()
+    }
+}
+
+fn to_tokens(data: TensorData) -> Vec<usize> {
+    data.iter::<i64>().map(|token| token as usize).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generation::GenerationConfig;
+    use crate::TestBackend;
+
+    const EOS: usize = 0;
+
+    /// Predict the next token from the last one with fixed probabilities.
+    ///
+    /// The most likely sequence after 1 is `1 3 0` with a probability of `0.399 * 0.9`, while
+    /// greedy decoding finds `1 2 4 0` with a probability of `0.598 * 0.4 * 0.9`.
+    struct ScriptedModel {
+        transitions: Tensor<TestBackend, 2>,
+    }
+
+    /// Keep the tokens seen so far to check that they follow the reordered sequences.
+    struct ScriptedCache {
+        tokens: Option<Tensor<TestBackend, 2, Int>>,
+    }
+
+    impl ScriptedModel {
+        fn new() -> Self {
+            let probs = [
+                [0.9, 0.025, 0.025, 0.025, 0.025],
+                [0.001, 0.001, 0.598, 0.399, 0.001],
+                [0.3, 0.05, 0.05, 0.2, 0.4],
+                [0.9, 0.025, 0.025, 0.025, 0.025],
+                [0.9, 0.025, 0.025, 0.025, 0.025],
+            ];
+
+            Self {
+                transitions: Tensor::<TestBackend, 2>::from_floats(probs, &Default::default())
+                    .log(),
+            }
+        }
+    }
+
+    impl AutoregressiveCache<TestBackend> for ScriptedCache {
+        fn reorder_cache(&mut self, indices: Tensor<TestBackend, 1, Int>) {
+            self.tokens = self.tokens.take().map(|tokens| tokens.select(0, indices));
+        }
+    }
+
+    impl AutoregressiveModel<TestBackend> for ScriptedModel {
+        type Cache = ScriptedCache;
+
+        fn init_cache(
+            &self,
+            _batch_size: usize,
+            _device: &<TestBackend as Backend>::Device,
+        ) -> ScriptedCache {
+            ScriptedCache { tokens: None }
+        }
+
+        fn forward(
+            &self,
+            tokens: Tensor<TestBackend, 2, Int>,
+            cache: &mut ScriptedCache,
+        ) -> Tensor<TestBackend, 2> {
+            let [batch_size, seq_length] = tokens.dims();
+
+            if let Some(previous) = cache.tokens.take() {
+                previous.into_data().assert_eq(
+                    &tokens
+                        .clone()
+                        .slice([0..batch_size, 0..seq_length - 1])
+                        .into_data(),
+                    true,
+                );
+            }
+            cache.tokens = Some(tokens.clone());
+
+            let last = tokens
+                .slice([0..batch_size, seq_length - 1..seq_length])
+                .reshape([batch_size]);
+
+            self.transitions.clone().select(0, last)
+        }
+    }
+
+    fn generator() -> Generator<ScriptedModel> {
+        GenerationConfig::new(6)
+            .with_eos_token(Some(EOS))
+            .init(ScriptedModel::new())
+    }
+
+    fn prompts(prompts: [[i64; 1]; 2]) -> Tensor<TestBackend, 2, Int> {
+        Tensor::from_ints(prompts, &Default::default())
+    }
+
+    #[test]
+    fn beam_search_should_find_the_most_likely_sequence_missed_by_greedy() {
+        let generator = generator();
+        let config = BeamSearchConfig::new(2)
+            .with_length_penalty(0.0)
+            .with_num_hypotheses(2);
+
+        let greedy = generator.generate(prompts([[1], [2]]));
+        let hypotheses = generator.beam_search(prompts([[1], [2]]), &config);
+
+        greedy
+            .tokens
+            .into_data()
+            .assert_eq(&TensorData::from([[1, 2, 4, 0], [2, 4, 0, 0]]), false);
+        assert_eq!(hypotheses[0][0].tokens, vec![1, 3, 0]);
+        assert_eq!(hypotheses[0][1].tokens, vec![1, 2, 4, 0]);
+        assert!((hypotheses[0][0].score - (0.399f64 * 0.9).ln()).abs() < 1e-4);
+        assert!((hypotheses[0][1].score - (0.598f64 * 0.4 * 0.9).ln()).abs() < 1e-4);
+        assert_eq!(hypotheses[1][0].tokens, vec![2, 4, 0]);
+        assert_eq!(hypotheses[1][1].tokens, vec![2, 0]);
+    }
+
+    #[test]
+    fn beam_search_should_favor_longer_sequences_with_length_penalty() {
+        let config = BeamSearchConfig::new(2)
+            .with_length_penalty(4.0)
+            .with_num_hypotheses(2);
+
+        let hypotheses = generator().beam_search(prompts([[1], [1]]), &config);
+
+        for hypotheses in hypotheses {
+            assert_eq!(hypotheses[0].tokens, vec![1, 2, 4, 0]);
+            assert_eq!(hypotheses[1].tokens, vec![1, 3, 0]);
+            let normalization = (8.0f64 / 6.0).powf(4.0);
+            let expected = (0.598f64 * 0.4 * 0.9).ln() / normalization;
+            assert!((hypotheses[0].score - expected).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn beam_search_should_return_running_beams_at_max_length() {
+        let generator = GenerationConfig::new(1)
+            .with_eos_token(Some(EOS))
+            .init(ScriptedModel::new());
+        let config = BeamSearchConfig::new(2)
+            .with_length_penalty(0.0)
+            .with_num_hypotheses(2);
+
+        let hypotheses = generator.beam_search(prompts([[1], [1]]), &config);
+
+        assert_eq!(hypotheses[0][0].tokens, vec![1, 2]);
+        assert_eq!(hypotheses[0][1].tokens, vec![1, 3]);
+    }
+}
@@ -0,0 +1,370 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use super::{
+    apply_repetition_penalty, apply_temperature, apply_top_k, apply_top_p, greedy, sample,
+    AutoregressiveModel,
+};
+use crate as burn;
+use crate::config::Config;
+use crate::tensor::{backend::Backend, Int, Tensor};
+
+/// Configuration to create a [generator](Generator) using the [init function](GenerationConfig::init).
+#[derive(Config, Debug)]
+pub struct GenerationConfig {
+    /// The maximum number of tokens to generate for each sequence.
+    pub max_new_tokens: usize,
+    /// The token ending a sequence, if any.
+    #[config(default = "None")]
+    pub eos_token: Option<usize>,
+    /// The token appended to the sequences that are already completed. Default: 0
+    #[config(default = 0)]
+    pub pad_token: usize,
+    /// Sample the next tokens instead of always selecting the most likely ones. Default: false
+    #[config(default = false)]
+    pub do_sample: bool,
+    /// The temperature applied on the logits when sampling. Default: 1.0
+    #[config(default = 1.0)]
+    pub temperature: f64,
+    /// Only sample among the `k` most likely tokens, if set.
+    #[config(default = "None")]
+    pub top_k: Option<usize>,
+    /// Only sample among the most likely tokens reaching this cumulative probability, if set.
+    #[config(default = "None")]
+    pub top_p: Option<f64>,
+    /// The penalty applied on the tokens already in a sequence, `1.0` meaning no penalty.
+    /// Default: 1.0
+    #[config(default = 1.0)]
+    pub repetition_penalty: f64,
+    /// The seed of the backend random number generator used when sampling, if any.
+    #[config(default = "None")]
+    pub seed: Option<u64>,
+}
+
+impl GenerationConfig {
+    /// Initialize a new [generator](Generator) driving the given model.
+    pub fn init<M>(&self, model: M) -> Generator<M> {
+        Generator {
+            model,
+            config: self.clone(),
+        }
+    }
+}
+
+/// Generate sequences with an [autoregressive model](AutoregressiveModel).
+///
+/// Should be created with [GenerationConfig].
+pub struct Generator<M> {
+    /// The model predicting the next tokens.
+    pub model: M,
+    pub(crate) config: GenerationConfig,
+}
+
+/// The sequences produced by a [generator](Generator).
+#[derive(Debug)]
+pub struct GenerationOutput<B: Backend> {
+    /// The prompts followed by the generated tokens, of shape `[batch_size, seq_length]`.
+    ///
+    /// Sequences completed before the others are padded with the pad token.
+    pub tokens: Tensor<B, 2, Int>,
+    /// The length of each sequence without padding, including its prompt and end token.
+    pub lengths: Vec<usize>,
+}
+
+impl<M> Generator<M> {
+    /// Generate the continuation of every prompt.
+    ///
+    /// A sequence is completed when it produces the end token or when the maximum number of new
+    /// tokens is reached. The generation stops once every sequence is completed.
+    ///
+    /// # Shapes
+    ///
+    /// - prompts: `[batch_size, prompt_length]`
+    pub fn generate<B: Backend>(&self, prompts: Tensor<B, 2, Int>) -> GenerationOutput<B>
+    where
+        M: AutoregressiveModel<B>,
+    {
+        let [batch_size, prompt_length] = prompts.dims();
+        let device = prompts.device();
+
+        if let Some(seed) = self.config.seed {
+            B::seed(seed);
+        }
+
+        let mut cache = self.model.init_cache(batch_size, &device);
+        let mut tokens = prompts;
+        let mut finished = Tensor::<B, 2, Int>::zeros([batch_size, 1], &device);
+        let mut lengths = Tensor::<B, 2, Int>::full([batch_size, 1], prompt_length as i64, &device);
+
+        for _ in 0..self.config.max_new_tokens {
+            let logits = self.model.forward(tokens.clone(), &mut cache);
+            let next = self
+                .next_tokens(logits, tokens.clone())
+                .mask_fill(finished.clone().bool(), self.config.pad_token as i64);
+
+            lengths = lengths + finished.clone().equal_elem(0).int();
+            if let Some(eos_token) = self.config.eos_token {
+                finished = finished.max_pair(next.clone().equal_elem(eos_token as i64).int());
+            }
+            tokens = Tensor::cat(vec![tokens, next], 1);
+
+            if finished.clone().bool().all().into_scalar() {
+                break;
+            }
+        }
+
+        let lengths = lengths
+            .into_data()
+            .iter::<i64>()
+            .map(|length| length as usize)
+            .collect();
+
+        GenerationOutput { tokens, lengths }
+    }
+
+    fn next_tokens<B: Backend>(
+        &self,
+        mut logits: Tensor<B, 2>,
+        tokens: Tensor<B, 2, Int>,
+    ) -> Tensor<B, 2, Int> {
+        if self.config.repetition_penalty != 1.0 {
+            logits = apply_repetition_penalty(logits, tokens, self.config.repetition_penalty);
+        }
+
+        if !self.config.do_sample {
+            return greedy(logits);
+        }
+
+        logits = apply_temperature(logits, self.config.temperature);
+        if let Some(k) = self.config.top_k {
+            logits = apply_top_k(logits, k);
+        }
+        if let Some(p) = self.config.top_p {
+            logits = apply_top_p(logits, p);
+        }
+
+        sample(logits)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_tensor::TensorData;
+
+    const EOS: usize = 0;
+
+    /// Always predict the next token from the last one with fixed logits.
+    struct ScriptedModel {
+        transitions: Tensor<TestBackend, 2>,
+    }
+
+    impl ScriptedModel {
+        fn new() -> Self {
+            // Greedily: 1 -> 2 -> 3 -> 0 (EOS) and 4 -> 4 -> 4 ...
+            let transitions = [
+                [9.0, 0.0, 0.0, 0.0, 0.0],
+                [0.0, 0.0, 5.0, 1.0, 4.5],
+                [0.0, 0.0, 0.0, 4.0, 3.8],
+                [5.0, 0.0, 4.5, 0.0, 0.0],
+                [0.0, 0.0, 0.0, 3.0, 4.0],
+            ];
+
+            Self {
+                transitions: Tensor::from_floats(transitions, &Default::default()),
+            }
+        }
+    }
+
+    impl AutoregressiveModel<TestBackend> for ScriptedModel {
+        /// The number of decoding steps.
+        type Cache = usize;
+
+        fn init_cache(
+            &self,
+            _batch_size: usize,
+            _device: &<TestBackend as Backend>::Device,
+        ) -> usize {
+            0
+        }
+
+        fn forward(
+            &self,
+            tokens: Tensor<TestBackend, 2, Int>,
+            cache: &mut usize,
+        ) -> Tensor<TestBackend, 2> {
+            let [batch_size, seq_length] = tokens.dims();
+            *cache += 1;
+
+            let last = tokens
+                .slice([0..batch_size, seq_length - 1..seq_length])
+                .reshape([batch_size]);
+
+            self.transitions.clone().select(0, last)
+        }
+    }
+
+    fn prompts(prompts: [[i64; 1]; 2]) -> Tensor<TestBackend, 2, Int> {
+        Tensor::from_ints(prompts, &Default::default())
+    }
+
+    #[test]
+    fn greedy_should_stop_each_sequence_at_eos() {
+        let generator = GenerationConfig::new(6)
+            .with_eos_token(Some(EOS))
+            .with_pad_token(9)
+            .init(ScriptedModel::new());
+
+        let output = generator.generate(prompts([[1], [2]]));
+
+        output
+            .tokens
+            .into_data()
+            .assert_eq(&TensorData::from([[1, 2, 3, 0], [2, 3, 0, 9]]), false);
+        assert_eq!(output.lengths, vec![4, 3]);
+    }
+
+    #[test]
+    fn greedy_should_stop_at_max_new_tokens() {
+        let generator = GenerationConfig::new(3)
+            .with_eos_token(Some(EOS))
+            .init(ScriptedModel::new());
+
+        let output = generator.generate(prompts([[4], [1]]));
+
+        output
+            .tokens
+            .into_data()
+            .assert_eq(&TensorData::from([[4, 4, 4, 4], [1, 2, 3, 0]]), false);
+        assert_eq!(output.lengths, vec![4, 4]);
+    }
+
+    #[test]
+    fn repetition_penalty_should_avoid_repeated_tokens() {
+        let generator = GenerationConfig::new(4)
+            .with_eos_token(Some(EOS))
+            .with_repetition_penalty(2.0)
+            .init(ScriptedModel::new());
+
+        let output = generator.generate(prompts([[4], [1]]));
+
+        // 4 -> 3 since 4 is penalized, then 3 -> 0 is unchanged. 1 -> 2 -> 3 -> 0 stays the same
+        // since the tokens it would repeat are not the most likely ones.
+        output
+            .tokens
+            .into_data()
+            .assert_eq(&TensorData::from([[4, 3, 0, 0], [1, 2, 3, 0]]), false);
+        assert_eq!(output.lengths, vec![3, 4]);
+    }
+
+    #[test]
+    fn sampling_with_top_k_of_one_should_be_greedy() {
+        let generator = GenerationConfig::new(6)
+            .with_eos_token(Some(EOS))
+            .with_do_sample(true)
+            .with_temperature(5.0)
+            .with_top_k(Some(1))
+            .with_seed(Some(42))
+            .init(ScriptedModel::new());
+
+        let output = generator.generate(prompts([[1], [4]]));
+
+        output.tokens.into_data().assert_eq(
+            &TensorData::from([[1, 2, 3, 0, 0, 0, 0], [4, 4, 4, 4, 4, 4, 4]]),
+            false,
+        );
+        assert_eq!(output.lengths, vec![4, 7]);
+    }
+
+    #[test]
+    fn sampling_with_small_top_p_should_be_greedy() {
+        let generator = GenerationConfig::new(6)
+            .with_eos_token(Some(EOS))
+            .with_do_sample(true)
+            .with_top_p(Some(0.01))
+            .with_seed(Some(42))
+            .init(ScriptedModel::new());
+
+        let output = generator.generate(prompts([[1], [2]]));
+
+        output
+            .tokens
+            .into_data()
+            .assert_eq(&TensorData::from([[1, 2, 3, 0], [2, 3, 0, 0]]), false);
+    }
+
+    #[test]
+    fn sampling_with_low_temperature_should_be_greedy() {
+        let generator = GenerationConfig::new(6)
+            .with_eos_token(Some(EOS))
+            .with_do_sample(true)
+            .with_temperature(0.01)
+            .with_seed(Some(42))
+            .init(ScriptedModel::new());
+
+        let output = generator.generate(prompts([[1], [2]]));
+
+        output
+            .tokens
+            .into_data()
+            .assert_eq(&TensorData::from([[1, 2, 3, 0], [2, 3, 0, 0]]), false);
+    }
+
+    #[test]
+    fn sampling_with_top_k_should_only_draw_the_most_likely_tokens() {
+        let generator = GenerationConfig::new(1)
+            .with_do_sample(true)
+            .with_top_k(Some(2))
+            .with_seed(Some(42))
+            .init(ScriptedModel::new());
+        let prompts = Tensor::<TestBackend, 2, Int>::full([64, 1], 1, &Default::default());
+
+        let output = generator.generate(prompts);
+        let tokens = output.tokens.slice([0..64, 1..2]).into_data();
+
+        // The two most likely tokens after 1 are 2 and 4.
+        assert!(tokens.iter::<i64>().all(|token| token == 2 || token == 4));
+    }
+
+    fn sampling_generator(seed: u64) -> Generator<ScriptedModel> {
+        GenerationConfig::new(6)
+            .with_eos_token(Some(EOS))
+            .with_do_sample(true)
+            .with_temperature(1.0)
+            .with_top_k(Some(3))
+            .with_top_p(Some(0.9))
+            .with_seed(Some(seed))
+            .init(ScriptedModel::new())
+    }
+
+    #[test]
+    fn sampling_with_the_same_seed_should_reproduce_the_sequences() {
+        let generator = sampling_generator(0);
+
+        let first = generator.generate(prompts([[1], [4]]));
+        let second = generator.generate(prompts([[1], [4]]));
+
+        first
+            .tokens
+            .into_data()
+            .assert_eq(&second.tokens.into_data(), true);
+        assert_eq!(first.lengths, second.lengths);
+    }
+
+    // The sequences depend on the random number generator of the backend.
+    #[cfg(not(any(feature = "test-tch", feature = "test-wgpu", feature = "test-cuda")))]
+    #[test]
+    fn sampling_should_draw_the_seeded_sequences() {
+        let generator = sampling_generator(0);
+
+        let output = generator.generate(prompts([[1], [4]]));
+
+        // After 1, top-k and top-p keep 2 and 4, after 4 they keep 4 and 3.
+        output.tokens.into_data().assert_eq(
+            &TensorData::from([[1, 4, 4, 4, 4, 4, 3], [4, 4, 4, 4, 3, 0, 0]]),
+            false,
+        );
+        assert_eq!(output.lengths, vec![7, 6]);
+    }
+}
@@ -0,0 +1,9 @@
+mod beam_search;
+mod generator;
+mod model;
+mod sampling;
+
+pub use beam_search::*;
+pub use generator::*;
+pub use model::*;
+pub use sampling::*;
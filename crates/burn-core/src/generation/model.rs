@@ -0,0 +1,41 @@
+use crate::tensor::{backend::Backend, Int, Tensor};
+
+/// A model predicting the next token of sequences, which can be driven by a
+/// [generator](crate::generation::Generator).
+pub trait AutoregressiveModel<B: Backend> {
+    /// The state kept between the decoding steps, such as the keys and values of the attention
+    /// layers.
+    type Cache;
+
+    /// Create an empty cache for the given number of sequences.
+    fn init_cache(&self, batch_size: usize, device: &B::Device) -> Self::Cache;
+
+    /// Compute the logits of the next token of each sequence.
+    ///
+    /// # Arguments
+    ///
+    /// * `tokens` - All the tokens of the sequences so far, of shape `[batch_size, seq_length]`.
+    ///   Only the last token is new since the previous call, so a model can use its cache to
+    ///   avoid processing the whole sequences again.
+    /// * `cache` - The cache of the model for those sequences.
+    ///
+    /// # Returns
+    ///
+    /// The logits of shape `[batch_size, vocab_size]`.
+    fn forward(&self, tokens: Tensor<B, 2, Int>, cache: &mut Self::Cache) -> Tensor<B, 2>;
+}
+
+/// A cache of an [autoregressive model](AutoregressiveModel) that can follow the sequences when
+/// they are reordered, which is required by [beam search](crate::generation::Generator::beam_search).
+pub trait AutoregressiveCache<B: Backend> {
+    /// Reorder the cached sequences along the batch dimension.
+    ///
+    /// After the call, the sequence at position `i` must be the one previously at position
+    /// `indices[i]`. The same index can appear multiple times when a sequence is duplicated, and
+    /// some may be missing when sequences are discarded.
+    ///
+    /// # Shapes
+    ///
+    /// - indices: `[batch_size]`
+    fn reorder_cache(&mut self, indices: Tensor<B, 1, Int>);
+}
@@ -0,0 +1,200 @@
+use alloc::vec;
+
+use crate::tensor::{activation::softmax, backend::Backend, Distribution, Int, Tensor};
+
+/// Select the token with the highest logit of each sequence.
+///
+/// Returns the tokens of shape `[batch_size, 1]`.
+pub fn greedy<B: Backend>(logits: Tensor<B, 2>) -> Tensor<B, 2, Int> {
+    logits.argmax(1)
+}
+
+/// Draw a token of each sequence from the distribution given by the logits.
+///
+/// The draw uses the random number generator of the backend, so it can be made deterministic
+/// with [Backend::seed].
+///
+/// Returns the tokens of shape `[batch_size, 1]`.
+pub fn sample<B: Backend>(logits: Tensor<B, 2>) -> Tensor<B, 2, Int> {
+    let [batch_size, vocab_size] = logits.dims();
+    let cdf = cumsum(softmax(logits, 1));
+
+    // Rescale the draw by the total mass to be robust to rounding errors in the cumulative sum.
+    let total = cdf
+        .clone()
+        .slice([0..batch_size, vocab_size - 1..vocab_size]);
+    let draw = Tensor::random([batch_size, 1], Distribution::Default, &cdf.device()) * total;
+
+    // The sampled token is the first one whose cumulative probability exceeds the draw.
+    cdf.lower_equal(draw.expand([batch_size, vocab_size]))
+        .int()
+        .sum_dim(1)
+        .clamp_max(vocab_size as i64 - 1)
+}
+
+/// Scale the logits by the inverse of the temperature.
+///
+/// A temperature lower than one sharpens the distribution, while a higher one flattens it.
+pub fn apply_temperature<B: Backend>(logits: Tensor<B, 2>, temperature: f64) -> Tensor<B, 2> {
+    assert!(temperature > 0.0, "The temperature must be positive");
+
+    logits.div_scalar(temperature)
+}
+
+/// Only keep the `k` tokens with the highest logits, the others can't be sampled anymore.
+pub fn apply_top_k<B: Backend>(logits: Tensor<B, 2>, k: usize) -> Tensor<B, 2> {
+    let [batch_size, vocab_size] = logits.dims();
+    assert!(k > 0, "At least one token must be kept by top-k filtering");
+
+    if k >= vocab_size {
+        return logits;
+    }
+
+    let threshold = logits.clone().topk(k, 1).slice([0..batch_size, k - 1..k]);
+    let mask = logits
+        .clone()
+        .lower(threshold.expand([batch_size, vocab_size]));
+
+    logits.mask_fill(mask, f32::NEG_INFINITY)
+}
+
+/// Only keep the smallest set of tokens with the highest logits whose cumulative probability is
+/// greater than or equal to `p`, also known as nucleus filtering.
+///
+/// The token with the highest logit is always kept.
+pub fn apply_top_p<B: Backend>(logits: Tensor<B, 2>, p: f64) -> Tensor<B, 2> {
+    assert!(
+        p > 0.0 && p <= 1.0,
+        "The top-p probability must be in (0, 1]"
+    );
+
+    let (sorted, indices) = logits.sort_descending_with_indices(1);
+    let probs = softmax(sorted.clone(), 1);
+
+    // A token is removed when the tokens before it already reach the probability mass.
+    let mask = (cumsum(probs.clone()) - probs).greater_elem(p);
+    let sorted = sorted.mask_fill(mask, f32::NEG_INFINITY);
+
+    sorted.gather(1, indices.argsort(1))
+}
+
+/// Penalize the tokens already present in the sequences, as proposed in
+/// [CTRL](https://arxiv.org/abs/1909.05858).
+///
+/// Positive logits of those tokens are divided by the penalty while negative ones are multiplied
+/// by it, so a penalty greater than one makes repetitions less likely.
+pub fn apply_repetition_penalty<B: Backend>(
+    logits: Tensor<B, 2>,
+    tokens: Tensor<B, 2, Int>,
+    penalty: f64,
+) -> Tensor<B, 2> {
+    assert!(penalty > 0.0, "The repetition penalty must be positive");
+
+    let counts = Tensor::zeros(logits.shape(), &logits.device()).scatter(
+        1,
+        tokens.clone(),
+        Tensor::ones(tokens.shape(), &logits.device()),
+    );
+    let penalized = logits.clone().div_scalar(penalty).mask_where(
+        logits.clone().lower_elem(0.0),
+        logits.clone().mul_scalar(penalty),
+    );
+
+    logits.mask_where(counts.greater_elem(0.0), penalized)
+}
+
+/// Cumulative sum over the last dimension, computed with a logarithmic number of steps.
+fn cumsum<B: Backend>(tensor: Tensor<B, 2>) -> Tensor<B, 2> {
+    let [batch_size, size] = tensor.dims();
+    let device = tensor.device();
+    let mut output = tensor;
+    let mut offset = 1;
+
+    while offset < size {
+        let shifted = Tensor::cat(
+            vec![
+                Tensor::zeros([batch_size, offset], &device),
+                output.clone().slice([0..batch_size, 0..size - offset]),
+            ],
+            1,
+        );
+        output = output + shifted;
+        offset *= 2;
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn cumsum_should_sum_over_the_last_dimension() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 2>::from_floats(
+            [[1.0, 2.0, 3.0, 4.0, 5.0], [0.5, 0.0, -1.0, 2.0, 1.0]],
+            &device,
+        );
+
+        cumsum(tensor).into_data().assert_approx_eq(
+            &TensorData::from([[1.0, 3.0, 6.0, 10.0, 15.0], [0.5, 0.5, -0.5, 1.5, 2.5]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn top_k_should_remove_the_other_tokens() {
+        let device = Default::default();
+        let logits = Tensor::<TestBackend, 2>::from_floats([[1.0, 4.0, 3.0, 2.0]], &device);
+
+        let logits = apply_top_k(logits, 2);
+
+        logits
+            .equal_elem(f32::NEG_INFINITY)
+            .into_data()
+            .assert_eq(&TensorData::from([[true, false, false, true]]), false);
+    }
+
+    #[test]
+    fn top_p_should_keep_the_tokens_reaching_the_probability_mass() {
+        let device = Default::default();
+        let probs = Tensor::<TestBackend, 2>::from_floats([[0.1, 0.5, 0.15, 0.25]], &device);
+
+        let logits = apply_top_p(probs.log(), 0.7);
+
+        logits
+            .equal_elem(f32::NEG_INFINITY)
+            .into_data()
+            .assert_eq(&TensorData::from([[true, false, true, false]]), false);
+    }
+
+    #[test]
+    fn repetition_penalty_should_only_penalize_present_tokens() {
+        let device = Default::default();
+        let logits = Tensor::<TestBackend, 2>::from_floats([[2.0, -2.0, 2.0, -2.0]], &device);
+        let tokens = Tensor::<TestBackend, 2, Int>::from_ints([[0, 1, 1]], &device);
+
+        let logits = apply_repetition_penalty(logits, tokens, 2.0);
+
+        logits
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[1.0, -4.0, 2.0, -2.0]]), 3);
+    }
+
+    #[test]
+    fn sample_should_only_draw_tokens_with_probability() {
+        let device = Default::default();
+        let logits = Tensor::<TestBackend, 2>::from_floats(
+            [[f32::NEG_INFINITY, 0.0, f32::NEG_INFINITY, 0.0]],
+            &device,
+        )
+        .repeat_dim(0, 64);
+
+        let tokens = sample(logits).into_data();
+
+        assert!(tokens.iter::<i64>().all(|token| token == 1 || token == 3));
+    }
+}
@@ -27,6 +27,9 @@ pub mod lr_scheduler;
 /// Gradient clipping module.
 pub mod grad_clipping;
 
+/// Sequence generation module.
+pub mod generation;
+
 /// Module for the neural network module.
 pub mod module;
 
@@ -42,6 +45,18 @@ pub mod tensor;
 /// Backend module.
 pub mod backend;
 
+/// Reproducibility module.
+pub mod reproducibility;
+
+/// Per-sample gradients module.
+pub mod per_sample;
+
+/// Pruning module.
+pub mod prune;
+
+/// Runtime float data type selection module.
+pub mod dtype;
+
 extern crate alloc;
 
 #[cfg(all(
@@ -1,11 +1,11 @@
-use super::{ParamId, Quantizer};
+use super::{MemoryFormatMapper, ParamId, Quantizer};
 use crate::{
     record::Record,
     tensor::backend::{AutodiffBackend, Backend},
 };
 use alloc::vec::Vec;
 pub use burn_derive::Module;
-use burn_tensor::{quantization::Calibration, Bool, Int, Tensor};
+use burn_tensor::{quantization::Calibration, Bool, Int, MemoryFormat, Tensor};
 
 /// Type alias to `Vec<B::Device>` which supports `no_std` environments, but automatically using
 /// the `alloc` crate.
@@ -207,6 +207,12 @@ pub trait Module<B: Backend>: Clone + Send + core::fmt::Debug {
     fn quantize_weights<C: Calibration>(self, quantizer: &mut Quantizer<C>) -> Self {
         self.map(quantizer)
     }
+
+    /// Lay out the parameters of the module in the given [memory format](MemoryFormat),
+    /// such as the channels last layout of the convolution weights.
+    fn to_memory_format(self, format: MemoryFormat) -> Self {
+        self.map(&mut MemoryFormatMapper::new(format))
+    }
 }
 
 /// Module visitor trait.
@@ -217,6 +223,10 @@ pub trait ModuleVisitor<B: Backend> {
     fn visit_int<const D: usize>(&mut self, _id: &ParamId, _tensor: &Tensor<B, D, Int>) {}
     /// Visit a bool tensor in the module.
     fn visit_bool<const D: usize>(&mut self, _id: &ParamId, _tensor: &Tensor<B, D, Bool>) {}
+    /// Called before visiting the field, or the element of a collection, with the given name.
+    fn enter_module(&mut self, _name: &str) {}
+    /// Called after visiting the field, or the element of a collection, with the given name.
+    fn exit_module(&mut self, _name: &str) {}
 }
 
 /// Module mapper trait.
@@ -241,6 +251,10 @@ pub trait ModuleMapper<B: Backend> {
     ) -> Tensor<B, D, Bool> {
         tensor
     }
+    /// Called before mapping the field, or the element of a collection, with the given name.
+    fn enter_module(&mut self, _name: &str) {}
+    /// Called after mapping the field, or the element of a collection, with the given name.
+    fn exit_module(&mut self, _name: &str) {}
 }
 
 /// Module with auto-differentiation backend.
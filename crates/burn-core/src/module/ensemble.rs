@@ -0,0 +1,417 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+use burn_tensor::{backend::Backend, Bool, Int, Tensor};
+
+use super::path_matches;
+use crate::module::{Module, ModuleMapper, ModuleVisitor, ParamId};
+
+/// The default patterns of the buffers, matching the running statistics of the batch norms.
+pub const DEFAULT_BUFFER_PATTERNS: [&str; 2] = ["**.running_mean", "**.running_var"];
+
+/// How the buffers of the records, such as the running statistics of the batch norms, are
+/// combined in a [soup](Ensemble::soup).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BufferCombination {
+    /// Average the buffers like the parameters.
+    #[default]
+    Average,
+    /// Keep the buffers of the first record.
+    TakeFirst,
+}
+
+/// How the outputs of the models are combined by [predict](Ensemble::predict).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PredictionCombination {
+    /// Average the outputs.
+    #[default]
+    Mean,
+    /// Count the models voting for each class, as the argmax of the last dimension of their
+    /// output, divided by the number of models.
+    Vote,
+}
+
+/// Combines the records of models sharing the same architecture, either in the weight space by
+/// averaging their parameters into a single model, or in the prediction space by combining the
+/// outputs of every model.
+///
+/// The tensors of the records are paired in the order they are visited, so the records must come
+/// from the same module structure.
+#[derive(Debug)]
+pub struct Ensemble<B: Backend, M: Module<B>> {
+    model: M,
+    buffers: BufferCombination,
+    buffer_patterns: Vec<String>,
+    _backend: PhantomData<B>,
+}
+
+impl<B: Backend, M: Module<B>> Ensemble<B, M> {
+    /// Create an ensemble loading the records into the given model.
+    pub fn new(model: M) -> Self {
+        Self {
+            model,
+            buffers: BufferCombination::default(),
+            buffer_patterns: DEFAULT_BUFFER_PATTERNS.map(String::from).to_vec(),
+            _backend: PhantomData,
+        }
+    }
+
+    /// Set how the buffers are combined in a soup.
+    pub fn with_buffer_combination(mut self, buffers: BufferCombination) -> Self {
+        self.buffers = buffers;
+        self
+    }
+
+    /// Set the patterns of the paths of the buffers, [DEFAULT_BUFFER_PATTERNS] by default.
+    ///
+    /// The paths are made of the field names separated by dots, where the `*` component of a
+    /// pattern matches any single component and the `**` component any number of components.
+    pub fn with_buffer_patterns<S: ToString>(
+        mut self,
+        patterns: impl IntoIterator<Item = S>,
+    ) -> Self {
+        self.buffer_patterns = patterns.into_iter().map(|p| p.to_string()).collect();
+        self
+    }
+
+    /// Average the float tensors of the records into a single model, a model soup.
+    ///
+    /// The records are streamed, so only the tensors of the running average are kept besides the
+    /// record being loaded. The int and bool tensors are taken from the first record.
+    ///
+    /// # Panics
+    ///
+    /// If there is no record, or if the records don't have the same structure as the model.
+    pub fn soup<I>(self, records: I) -> M
+    where
+        I: IntoIterator<Item = M::Record>,
+    {
+        let mut records = records.into_iter();
+        let first = records
+            .next()
+            .expect("The soup should have at least one record.");
+        let mut soup = self.model.load_record(first);
+
+        for (index, record) in records.enumerate() {
+            let mut average = TensorSnapshot::default();
+            soup.visit(&mut average);
+
+            // Loading the record overwrites the running states in place, so the average is
+            // snapshotted before, then written back by the mapper.
+            let model = soup.load_record(record);
+            let mut mapper = SoupMapper {
+                average,
+                num_records: index + 2,
+                buffers: self.buffers,
+                buffer_patterns: &self.buffer_patterns,
+                names: Vec::new(),
+                indices: [0; 3],
+            };
+            soup = model.map(&mut mapper);
+        }
+
+        soup
+    }
+
+    /// Combine the outputs of the models of the records, loaded one after the other in the model
+    /// of the ensemble.
+    ///
+    /// # Panics
+    ///
+    /// If there is no record.
+    pub fn predict<const D: usize, I, F>(
+        &mut self,
+        records: I,
+        combination: PredictionCombination,
+        mut forward: F,
+    ) -> Tensor<B, D>
+    where
+        I: IntoIterator<Item = M::Record>,
+        F: FnMut(&M) -> Tensor<B, D>,
+    {
+        let mut combined: Option<Tensor<B, D>> = None;
+        let mut num_models = 0;
+
+        for record in records {
+            // Cloning the model only clones the handles of its tensors.
+            self.model = self.model.clone().load_record(record);
+            let output = match combination {
+                PredictionCombination::Mean => forward(&self.model),
+                PredictionCombination::Vote => votes(forward(&self.model)),
+            };
+
+            combined = Some(match combined {
+                Some(combined) => combined + output,
+                None => output,
+            });
+            num_models += 1;
+        }
+
+        combined
+            .expect("The ensemble should have at least one record.")
+            .div_scalar(num_models as f32)
+    }
+
+    /// The model of the ensemble, holding the last record loaded.
+    pub fn model(&self) -> &M {
+        &self.model
+    }
+}
+
+/// One at the argmax of the last dimension, zero elsewhere.
+fn votes<B: Backend, const D: usize>(output: Tensor<B, D>) -> Tensor<B, D> {
+    let device = output.device();
+    let indices = output.clone().argmax(D - 1);
+    let ones = Tensor::ones(indices.shape(), &device);
+
+    output.zeros_like().scatter(D - 1, indices, ones)
+}
+
+/// The flattened tensors of a module, in the order they are visited.
+struct TensorSnapshot<B: Backend> {
+    floats: Vec<Tensor<B, 1>>,
+    ints: Vec<Tensor<B, 1, Int>>,
+    bools: Vec<Tensor<B, 1, Bool>>,
+}
+
+impl<B: Backend> Default for TensorSnapshot<B> {
+    fn default() -> Self {
+        Self {
+            floats: Vec::new(),
+            ints: Vec::new(),
+            bools: Vec::new(),
+        }
+    }
+}
+
+impl<B: Backend> ModuleVisitor<B> for TensorSnapshot<B> {
+    fn visit_float<const D: usize>(&mut self, _id: &ParamId, tensor: &Tensor<B, D>) {
+        let num_elements = tensor.shape().num_elements();
+        self.floats.push(tensor.clone().reshape([num_elements]));
+    }
+
+    fn visit_int<const D: usize>(&mut self, _id: &ParamId, tensor: &Tensor<B, D, Int>) {
+        let num_elements = tensor.shape().num_elements();
+        self.ints.push(tensor.clone().reshape([num_elements]));
+    }
+
+    fn visit_bool<const D: usize>(&mut self, _id: &ParamId, tensor: &Tensor<B, D, Bool>) {
+        let num_elements = tensor.shape().num_elements();
+        self.bools.push(tensor.clone().reshape([num_elements]));
+    }
+}
+
+/// Updates the running average of the soup with the tensors of the record just loaded.
+struct SoupMapper<'a, B: Backend> {
+    average: TensorSnapshot<B>,
+    num_records: usize,
+    buffers: BufferCombination,
+    buffer_patterns: &'a [String],
+    names: Vec<String>,
+    /// The index of the next float, int and bool tensors of the average.
+    indices: [usize; 3],
+}
+
+impl<B: Backend> SoupMapper<'_, B> {
+    fn next<T: Clone>(tensors: &[T], index: &mut usize) -> T {
+        let tensor = tensors
+            .get(*index)
+            .cloned()
+            .expect("The records should have the same structure as the model.");
+        *index += 1;
+        tensor
+    }
+
+    fn is_buffer(&self) -> bool {
+        let path = self.names.join(".");
+
+        self.buffer_patterns
+            .iter()
+            .any(|pattern| path_matches(pattern, &path))
+    }
+}
+
+impl<B: Backend> ModuleMapper<B> for SoupMapper<'_, B> {
+    fn map_float<const D: usize>(&mut self, _id: &ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        let average =
+            Self::next(&self.average.floats, &mut self.indices[0]).reshape(tensor.shape());
+
+        if self.buffers == BufferCombination::TakeFirst && self.is_buffer() {
+            return average;
+        }
+
+        // The running average doesn't change the tensors equal to the average.
+        let delta = tensor
+            .sub(average.clone())
+            .div_scalar(self.num_records as f32);
+        average.add(delta)
+    }
+
+    fn map_int<const D: usize>(
+        &mut self,
+        _id: &ParamId,
+        tensor: Tensor<B, D, Int>,
+    ) -> Tensor<B, D, Int> {
+        Self::next(&self.average.ints, &mut self.indices[1]).reshape(tensor.shape())
+    }
+
+    fn map_bool<const D: usize>(
+        &mut self,
+        _id: &ParamId,
+        tensor: Tensor<B, D, Bool>,
+    ) -> Tensor<B, D, Bool> {
+        Self::next(&self.average.bools, &mut self.indices[2]).reshape(tensor.shape())
+    }
+
+    fn enter_module(&mut self, name: &str) {
+        self.names.push(String::from(name));
+    }
+
+    fn exit_module(&mut self, _name: &str) {
+        self.names.pop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as burn;
+    use crate::module::{Param, RunningState};
+    use crate::nn::{BatchNorm, BatchNormConfig, Linear, LinearConfig};
+    use crate::TestBackend;
+    use burn_tensor::{Distribution, TensorData};
+
+    #[derive(Module, Debug)]
+    struct Model<B: Backend> {
+        linear: Linear<B>,
+        norm: BatchNorm<B, 0>,
+    }
+
+    fn model(device: &<TestBackend as Backend>::Device) -> Model<TestBackend> {
+        Model {
+            linear: LinearConfig::new(4, 2).init(device),
+            norm: BatchNormConfig::new(2).init(device),
+        }
+    }
+
+    fn with_values(
+        mut model: Model<TestBackend>,
+        weight: f32,
+        running_mean: f32,
+    ) -> Model<TestBackend> {
+        let device = model.linear.weight.device();
+        model.linear.weight = Param::from_tensor(Tensor::full([4, 2], weight, &device));
+        model.norm.running_mean = RunningState::new(Tensor::full([2], running_mean, &device));
+        model
+    }
+
+    #[test]
+    fn soup_of_identical_records_should_equal_the_original() {
+        let device = Default::default();
+        let original = with_values(model(&device), 0.3, 0.7);
+
+        let records = (0..3).map(|_| original.clone().into_record());
+        let soup = Ensemble::new(model(&device)).soup(records);
+
+        soup.linear
+            .weight
+            .val()
+            .into_data()
+            .assert_eq(&original.linear.weight.val().into_data(), true);
+        soup.linear
+            .bias
+            .unwrap()
+            .val()
+            .into_data()
+            .assert_eq(&original.linear.bias.unwrap().val().into_data(), true);
+        soup.norm
+            .running_mean
+            .value()
+            .into_data()
+            .assert_eq(&original.norm.running_mean.value().into_data(), true);
+    }
+
+    #[test]
+    fn soup_should_average_the_parameters_and_the_buffers() {
+        let device = Default::default();
+        let records = || {
+            [(1.0, 2.0), (2.0, 4.0), (6.0, 9.0)]
+                .map(|(weight, mean)| with_values(model(&device), weight, mean).into_record())
+        };
+
+        let soup = Ensemble::new(model(&device)).soup(records());
+        let first = Ensemble::new(model(&device))
+            .with_buffer_combination(BufferCombination::TakeFirst)
+            .soup(records());
+
+        soup.linear
+            .weight
+            .val()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[3.0f32; 2]; 4]), 5);
+        soup.norm
+            .running_mean
+            .value()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([5.0f32, 5.0]), 5);
+        first
+            .linear
+            .weight
+            .val()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[3.0f32; 2]; 4]), 5);
+        first
+            .norm
+            .running_mean
+            .value()
+            .into_data()
+            .assert_eq(&TensorData::from([2.0f32, 2.0]), false);
+    }
+
+    #[test]
+    fn predict_mean_should_match_a_manual_loop() {
+        let device = Default::default();
+        let models: Vec<_> = (0..3).map(|_| model(&device)).collect();
+        let input = Tensor::<TestBackend, 2>::random([5, 4], Distribution::Default, &device);
+
+        let expected = models
+            .iter()
+            .map(|model| model.linear.forward(input.clone()))
+            .reduce(|a, b| a + b)
+            .unwrap()
+            .div_scalar(3.0);
+        let records = models.into_iter().map(|model| model.into_record());
+        let output =
+            Ensemble::new(model(&device)).predict(records, PredictionCombination::Mean, |model| {
+                model.linear.forward(input.clone())
+            });
+
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 5);
+    }
+
+    #[test]
+    fn predict_vote_should_count_the_argmax() {
+        let device = Default::default();
+        let input = Tensor::<TestBackend, 2>::from_data([[1.0, 0.0, 0.0, 0.0]], &device);
+        let records = [1.0, 1.0, -1.0].map(|sign| {
+            let mut model = model(&device);
+            let weight =
+                Tensor::from_data([[sign, -sign], [0.0, 0.0], [0.0, 0.0], [0.0, 0.0]], &device);
+            model.linear.weight = Param::from_tensor(weight);
+            model.linear.bias = Some(Param::from_tensor(Tensor::zeros([2], &device)));
+            model.into_record()
+        });
+
+        let output =
+            Ensemble::new(model(&device)).predict(records, PredictionCombination::Vote, |model| {
+                model.linear.forward(input.clone())
+            });
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[2.0f32 / 3.0, 1.0 / 3.0]]), 5);
+    }
+}
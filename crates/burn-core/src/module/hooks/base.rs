@@ -0,0 +1,342 @@
+use super::path::{module_paths, path_matches, ModuleKey};
+use crate::module::Module;
+
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::any::{Any, TypeId};
+use core::sync::atomic::{AtomicBool, Ordering};
+
+#[cfg(target_has_atomic = "ptr")]
+use alloc::sync::Arc;
+
+#[cfg(not(target_has_atomic = "ptr"))]
+use portable_atomic_util::Arc;
+
+use burn_tensor::{backend::Backend, Shape, Tensor};
+use hashbrown::HashMap;
+
+/// The output of a module given to the forward hooks.
+#[derive(Clone, Debug)]
+pub struct OutputView<B: Backend> {
+    /// The flattened output, detached from the autodiff graph.
+    pub values: Tensor<B, 1>,
+    /// The shape of the output.
+    pub dims: Vec<usize>,
+}
+
+impl<B: Backend> OutputView<B> {
+    fn new<const D: usize>(output: &Tensor<B, D>) -> Self {
+        let shape = output.shape();
+        let values = output.clone().detach().reshape([shape.num_elements()]);
+
+        Self {
+            values,
+            dims: shape.dims.to_vec(),
+        }
+    }
+
+    /// Returns the output with its shape.
+    pub fn tensor<const D: usize>(&self) -> Tensor<B, D> {
+        self.values
+            .clone()
+            .reshape(Shape::<D>::from(self.dims.clone()))
+    }
+}
+
+/// The identifier of a hook, used to [remove](ModuleHooks::remove) it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct HookId(u64);
+
+type Callback<B> = Arc<Box<dyn Fn(&str, &OutputView<B>) + Send + Sync>>;
+
+struct Hook {
+    id: HookId,
+    backend: TypeId,
+    paths: HashMap<ModuleKey, String>,
+    /// The [callback](Callback) of the backend.
+    callback: Box<dyn Any + Send + Sync>,
+}
+
+struct Registry {
+    hooks: Vec<Hook>,
+    next_id: u64,
+}
+
+static REGISTRY: spin::Mutex<Registry> = spin::Mutex::new(Registry {
+    hooks: Vec::new(),
+    next_id: 0,
+});
+
+/// If any hook is registered, so that the forward passes don't lock the registry otherwise.
+static ACTIVE: AtomicBool = AtomicBool::new(false);
+
+/// The global registry of the forward hooks, which are called with the output of the modules
+/// matching a path pattern.
+///
+/// The built-in modules with parameters call the hooks at the end of their forward pass, and
+/// custom modules can do the same with [forward_hook]. Since the modules don't know their own
+/// path, a module is identified by its parameters, as found in the module given when registering
+/// the hook. The hooks keep applying after the parameters are updated by an optimizer, but not to
+/// a module initialized again or loaded with other parameter ids.
+///
+/// The paths are made of the field names from the root module, separated by dots, such as
+/// `encoder.layers.0.linear`. In the patterns, `*` matches any single component and `**` any
+/// number of components, so `**.linear` matches all the `linear` fields.
+///
+/// A hook only applies to the backend of the module given when registering it: a hook registered
+/// with a module of an autodiff backend isn't called by the [valid](crate::module::AutodiffModule::valid)
+/// module.
+pub struct ModuleHooks;
+
+impl ModuleHooks {
+    /// Register a callback called with the path and the output of the modules of `module`
+    /// matching the pattern, including `module` itself.
+    pub fn register<B, M, F>(module: &M, pattern: &str, callback: F) -> HookId
+    where
+        B: Backend,
+        M: Module<B>,
+        F: Fn(&str, &OutputView<B>) + Send + Sync + 'static,
+    {
+        let mut paths = module_paths::<B, M>(module);
+        paths.retain(|_, path| path_matches(pattern, path));
+
+        let callback: Callback<B> = Arc::new(Box::new(callback));
+        let mut registry = REGISTRY.lock();
+        let id = HookId(registry.next_id);
+        registry.next_id += 1;
+        registry.hooks.push(Hook {
+            id,
+            backend: TypeId::of::<B>(),
+            paths,
+            callback: Box::new(callback),
+        });
+        ACTIVE.store(true, Ordering::Release);
+
+        id
+    }
+
+    /// Remove a hook, returning if it was registered.
+    pub fn remove(id: HookId) -> bool {
+        let mut registry = REGISTRY.lock();
+        let num_hooks = registry.hooks.len();
+        registry.hooks.retain(|hook| hook.id != id);
+        ACTIVE.store(!registry.hooks.is_empty(), Ordering::Release);
+
+        registry.hooks.len() != num_hooks
+    }
+
+    /// Remove all the hooks.
+    pub fn clear() {
+        let mut registry = REGISTRY.lock();
+        registry.hooks.clear();
+        ACTIVE.store(false, Ordering::Release);
+    }
+
+    /// Returns if no hook is registered.
+    pub fn is_empty() -> bool {
+        !ACTIVE.load(Ordering::Acquire)
+    }
+}
+
+/// Call the forward hooks matching the module with its output, then return the output.
+///
+/// Custom modules can call it at the end of their forward pass so that they can be hooked like
+/// the built-in modules. Only modules with parameters can be hooked, and when no hook is
+/// registered, the cost is a single atomic load.
+///
+/// # Example
+///
+/// ```rust, ignore
+/// pub fn forward(&self, input: Tensor<B, 2>) -> Tensor<B, 2> {
+///     let output = self.linear.forward(input);
+///     forward_hook(self, relu(output))
+/// }
+/// ```
+pub fn forward_hook<B, M, const D: usize>(module: &M, output: Tensor<B, D>) -> Tensor<B, D>
+where
+    B: Backend,
+    M: Module<B>,
+{
+    if ACTIVE.load(Ordering::Relaxed) {
+        call_hooks(module, &output);
+    }
+
+    output
+}
+
+#[cold]
+fn call_hooks<B: Backend, M: Module<B>, const D: usize>(module: &M, output: &Tensor<B, D>) {
+    let Some(key) = ModuleKey::of::<B, M>(module) else {
+        return;
+    };
+
+    // The callbacks are called without holding the lock, so that they can register hooks.
+    let callbacks = REGISTRY
+        .lock()
+        .hooks
+        .iter()
+        .filter(|hook| hook.backend == TypeId::of::<B>())
+        .filter_map(|hook| {
+            let path = hook.paths.get(&key)?;
+            let callback = hook.callback.downcast_ref::<Callback<B>>()?;
+            Some((path.clone(), callback.clone()))
+        })
+        .collect::<Vec<_>>();
+
+    if callbacks.is_empty() {
+        return;
+    }
+
+    let view = OutputView::new(output);
+    for (path, callback) in callbacks {
+        callback(&path, &view);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as burn;
+    use crate::module::ActivationStats;
+    use crate::nn::{LayerNorm, LayerNormConfig, Linear, LinearConfig};
+    use crate::TestBackend;
+    use alloc::vec;
+    use burn_tensor::{Distribution, ElementConversion};
+
+    #[derive(Module, Debug)]
+    struct Block<B: Backend> {
+        linear: Linear<B>,
+        norm: LayerNorm<B>,
+    }
+
+    #[derive(Module, Debug)]
+    struct Model<B: Backend> {
+        blocks: Vec<Block<B>>,
+        head: Linear<B>,
+    }
+
+    impl<B: Backend> Block<B> {
+        fn forward(&self, input: Tensor<B, 2>) -> Tensor<B, 2> {
+            self.norm.forward(self.linear.forward(input))
+        }
+    }
+
+    impl<B: Backend> Model<B> {
+        fn new(device: &B::Device) -> Self {
+            let block = || Block {
+                linear: LinearConfig::new(4, 4).init(device),
+                norm: LayerNormConfig::new(4).init(device),
+            };
+
+            Self {
+                blocks: vec![block(), block()],
+                head: LinearConfig::new(4, 2).init(device),
+            }
+        }
+
+        fn forward(&self, input: Tensor<B, 2>) -> Tensor<B, 2> {
+            let output = self
+                .blocks
+                .iter()
+                .fold(input, |output, block| block.forward(output));
+
+            forward_hook(self, self.head.forward(output))
+        }
+    }
+
+    fn capture(
+        model: &Model<TestBackend>,
+        pattern: &str,
+    ) -> (HookId, Arc<spin::Mutex<Vec<String>>>) {
+        let paths = Arc::new(spin::Mutex::new(Vec::new()));
+        let paths_hook = paths.clone();
+        let id = ModuleHooks::register(model, pattern, move |path, _: &OutputView<TestBackend>| {
+            paths_hook.lock().push(String::from(path));
+        });
+
+        (id, paths)
+    }
+
+    fn input() -> Tensor<TestBackend, 2> {
+        Tensor::random([3, 4], Distribution::Default, &Default::default())
+    }
+
+    #[test]
+    fn should_call_the_hooks_of_all_the_modules() {
+        let model = Model::<TestBackend>::new(&Default::default());
+        let (id, paths) = capture(&model, "**");
+
+        model.forward(input());
+        model.forward(input());
+        ModuleHooks::remove(id);
+
+        let expected = [
+            "blocks.0.linear",
+            "blocks.0.norm",
+            "blocks.1.linear",
+            "blocks.1.norm",
+            "head",
+            "",
+        ];
+        let paths = paths.lock();
+        assert_eq!(paths.len(), 2 * expected.len());
+        assert_eq!(&paths[..expected.len()], expected);
+        assert_eq!(&paths[expected.len()..], expected);
+    }
+
+    #[test]
+    fn should_call_the_hooks_of_the_matching_modules() {
+        let model = Model::<TestBackend>::new(&Default::default());
+        let (id, paths) = capture(&model, "blocks.*.linear");
+
+        model.forward(input());
+        ModuleHooks::remove(id);
+
+        assert_eq!(*paths.lock(), ["blocks.0.linear", "blocks.1.linear"]);
+    }
+
+    #[test]
+    fn should_not_call_the_removed_hooks() {
+        let model = Model::<TestBackend>::new(&Default::default());
+        let (id, paths) = capture(&model, "**");
+
+        assert!(ModuleHooks::remove(id));
+        assert!(!ModuleHooks::remove(id));
+        model.forward(input());
+
+        assert!(paths.lock().is_empty());
+    }
+
+    #[test]
+    fn should_not_call_the_hooks_of_other_models() {
+        let device = Default::default();
+        let model = Model::<TestBackend>::new(&device);
+        let other = Model::<TestBackend>::new(&device);
+        let (id, paths) = capture(&model, "**");
+
+        other.forward(input());
+        ModuleHooks::remove(id);
+
+        assert!(paths.lock().is_empty());
+    }
+
+    #[test]
+    fn should_collect_activation_stats() {
+        let model = Model::<TestBackend>::new(&Default::default());
+        let stats = ActivationStats::register(&model, "head");
+
+        let output = model.forward(input());
+        let output = Tensor::cat(vec![output, model.forward(input())], 0);
+        stats.remove();
+
+        let layers = stats.take();
+        assert_eq!(layers.len(), 1);
+        let head = &layers["head"];
+        assert_eq!(head.num_forwards, 2);
+        assert_eq!(head.num_elements, 2 * 3 * 2);
+        let expected = output.mean().into_scalar().elem::<f64>();
+        assert!((head.mean() - expected).abs() < 1e-5);
+        assert!(stats.layers().is_empty());
+    }
+}
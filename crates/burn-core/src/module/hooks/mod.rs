@@ -0,0 +1,8 @@
+mod base;
+mod path;
+mod stats;
+
+pub use base::*;
+pub use stats::*;
+
+pub(crate) use path::path_matches;
@@ -0,0 +1,186 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use burn_tensor::{backend::Backend, Bool, Int, Tensor};
+use hashbrown::HashMap;
+
+use crate::module::{Module, ModuleVisitor, ParamId};
+
+/// Identifies a module by its first parameter and its number of parameters, since the modules
+/// don't know their own path.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub(crate) struct ModuleKey {
+    first: ParamId,
+    num_params: usize,
+}
+
+impl ModuleKey {
+    /// Returns the key of the module, or `None` if the module doesn't have any parameter.
+    pub(crate) fn of<B: Backend, M: Module<B>>(module: &M) -> Option<Self> {
+        let mut visitor = KeyVisitor::default();
+        module.visit(&mut visitor);
+
+        visitor.first.map(|first| Self {
+            first,
+            num_params: visitor.num_params,
+        })
+    }
+}
+
+#[derive(Default)]
+struct KeyVisitor {
+    first: Option<ParamId>,
+    num_params: usize,
+}
+
+impl KeyVisitor {
+    fn visit(&mut self, id: &ParamId) {
+        self.first.get_or_insert_with(|| id.clone());
+        self.num_params += 1;
+    }
+}
+
+impl<B: Backend> ModuleVisitor<B> for KeyVisitor {
+    fn visit_float<const D: usize>(&mut self, id: &ParamId, _tensor: &Tensor<B, D>) {
+        self.visit(id);
+    }
+
+    fn visit_int<const D: usize>(&mut self, id: &ParamId, _tensor: &Tensor<B, D, Int>) {
+        self.visit(id);
+    }
+
+    fn visit_bool<const D: usize>(&mut self, id: &ParamId, _tensor: &Tensor<B, D, Bool>) {
+        self.visit(id);
+    }
+}
+
+/// Returns the path of each module with parameters, by [key](ModuleKey).
+///
+/// A module having the same parameters as one of its submodules has the same key, so only the
+/// path of the submodule is kept.
+pub(crate) fn module_paths<B: Backend, M: Module<B>>(module: &M) -> HashMap<ModuleKey, String> {
+    let mut visitor = PathVisitor {
+        names: Vec::new(),
+        frames: Vec::from([Frame::default()]),
+        paths: HashMap::new(),
+    };
+    module.visit(&mut visitor);
+
+    let root = visitor.frames.pop().expect("The root frame should remain.");
+    visitor.insert(root);
+    visitor.paths
+}
+
+#[derive(Default)]
+struct Frame {
+    first: Option<ParamId>,
+    num_params: usize,
+    is_param: bool,
+}
+
+struct PathVisitor {
+    names: Vec<String>,
+    frames: Vec<Frame>,
+    paths: HashMap<ModuleKey, String>,
+}
+
+impl PathVisitor {
+    fn visit(&mut self, id: &ParamId) {
+        if let Some(frame) = self.frames.last_mut() {
+            frame.is_param = true;
+        }
+
+        for frame in self.frames.iter_mut() {
+            frame.first.get_or_insert_with(|| id.clone());
+            frame.num_params += 1;
+        }
+    }
+
+    fn insert(&mut self, frame: Frame) {
+        if frame.is_param {
+            return;
+        }
+
+        if let Some(first) = frame.first {
+            let key = ModuleKey {
+                first,
+                num_params: frame.num_params,
+            };
+            self.paths
+                .entry(key)
+                .or_insert_with(|| self.names.join("."));
+        }
+    }
+}
+
+impl<B: Backend> ModuleVisitor<B> for PathVisitor {
+    fn visit_float<const D: usize>(&mut self, id: &ParamId, _tensor: &Tensor<B, D>) {
+        self.visit(id);
+    }
+
+    fn visit_int<const D: usize>(&mut self, id: &ParamId, _tensor: &Tensor<B, D, Int>) {
+        self.visit(id);
+    }
+
+    fn visit_bool<const D: usize>(&mut self, id: &ParamId, _tensor: &Tensor<B, D, Bool>) {
+        self.visit(id);
+    }
+
+    fn enter_module(&mut self, name: &str) {
+        self.names.push(String::from(name));
+        self.frames.push(Frame::default());
+    }
+
+    fn exit_module(&mut self, _name: &str) {
+        if let Some(frame) = self.frames.pop() {
+            self.insert(frame);
+        }
+        self.names.pop();
+    }
+}
+
+/// Returns if the path matches the pattern.
+///
+/// Both are made of components separated by dots, where the `*` component of the pattern matches
+/// any single component and the `**` component matches any number of components.
+pub(crate) fn path_matches(pattern: &str, path: &str) -> bool {
+    fn components(path: &str) -> Vec<&str> {
+        match path.is_empty() {
+            true => Vec::new(),
+            false => path.split('.').collect(),
+        }
+    }
+
+    fn matches(pattern: &[&str], path: &[&str]) -> bool {
+        match pattern.split_first() {
+            None => path.is_empty(),
+            Some((&"**", pattern)) => (0..=path.len()).any(|i| matches(pattern, &path[i..])),
+            Some((component, pattern)) => match path.split_first() {
+                Some((name, path)) => {
+                    (*component == "*" || component == name) && matches(pattern, path)
+                }
+                None => false,
+            },
+        }
+    }
+
+    matches(&components(pattern), &components(path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_match_paths() {
+        assert!(path_matches("encoder.linear", "encoder.linear"));
+        assert!(!path_matches("encoder.linear", "encoder.linear.inner"));
+        assert!(path_matches("encoder.*", "encoder.linear"));
+        assert!(!path_matches("encoder.*", "encoder"));
+        assert!(path_matches("**.linear", "linear"));
+        assert!(path_matches("**.linear", "encoder.layers.0.linear"));
+        assert!(path_matches("**", ""));
+        assert!(path_matches("", ""));
+        assert!(!path_matches("", "linear"));
+    }
+}
@@ -0,0 +1,122 @@
+use super::{HookId, ModuleHooks, OutputView};
+use crate::module::Module;
+
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec;
+
+#[cfg(target_has_atomic = "ptr")]
+use alloc::sync::Arc;
+
+#[cfg(not(target_has_atomic = "ptr"))]
+use portable_atomic_util::Arc;
+
+use burn_tensor::{backend::Backend, Tensor};
+
+/// The statistics of the outputs of a module, aggregated over its forward passes.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LayerActivationStats {
+    /// The number of forward passes.
+    pub num_forwards: usize,
+    /// The number of output elements.
+    pub num_elements: usize,
+    /// The sum of the output elements.
+    pub sum: f64,
+    /// The sum of the squared output elements.
+    pub sum_squares: f64,
+    /// The number of output elements equal to zero.
+    pub num_zeros: usize,
+}
+
+impl LayerActivationStats {
+    /// The mean of the output elements.
+    pub fn mean(&self) -> f64 {
+        self.sum / self.num_elements as f64
+    }
+
+    /// The standard deviation of the output elements.
+    pub fn std(&self) -> f64 {
+        let mean = self.mean();
+        let variance = self.sum_squares / self.num_elements as f64 - mean * mean;
+
+        variance.max(0.0).sqrt()
+    }
+
+    /// The proportion of the output elements equal to zero, between 0 and 1.
+    pub fn zeros_ratio(&self) -> f64 {
+        self.num_zeros as f64 / self.num_elements as f64
+    }
+
+    fn of<B: Backend>(output: &OutputView<B>) -> Self {
+        let values = output.values.clone();
+        let sum = values.clone().sum();
+        let sum_squares = values.clone().powi_scalar(2).sum();
+        let num_zeros = values.clone().equal_elem(0.0).float().sum();
+
+        // Read the three values at once.
+        let data = Tensor::cat(vec![sum, sum_squares, num_zeros], 0).into_data();
+        let mut data = data.iter::<f64>();
+        let mut next = || data.next().unwrap_or(f64::NAN);
+
+        Self {
+            num_forwards: 1,
+            num_elements: values.dims()[0],
+            sum: next(),
+            sum_squares: next(),
+            num_zeros: next() as usize,
+        }
+    }
+
+    fn merge(&mut self, other: Self) {
+        self.num_forwards += other.num_forwards;
+        self.num_elements += other.num_elements;
+        self.sum += other.sum;
+        self.sum_squares += other.sum_squares;
+        self.num_zeros += other.num_zeros;
+    }
+}
+
+/// Collects the [statistics](LayerActivationStats) of the outputs of the modules matching a path
+/// pattern, with [forward hooks](ModuleHooks).
+///
+/// The statistics are aggregated until they are [taken](ActivationStats::take), which can be done
+/// at the end of each epoch to get the statistics of each epoch. Computing the statistics
+/// synchronizes the backend at each forward pass of the matching modules.
+#[derive(Clone)]
+pub struct ActivationStats {
+    layers: Arc<spin::Mutex<BTreeMap<String, LayerActivationStats>>>,
+    hook: HookId,
+}
+
+impl ActivationStats {
+    /// Register the hook collecting the statistics of the modules of `module` matching the
+    /// pattern, as described in [ModuleHooks].
+    pub fn register<B: Backend, M: Module<B>>(module: &M, pattern: &str) -> Self {
+        let layers = Arc::new(spin::Mutex::new(BTreeMap::new()));
+        let layers_hook = layers.clone();
+
+        let hook = ModuleHooks::register(module, pattern, move |path, output: &OutputView<B>| {
+            let stats = LayerActivationStats::of(output);
+            let mut layers = layers_hook.lock();
+            let layer: &mut LayerActivationStats = layers.entry(String::from(path)).or_default();
+            layer.merge(stats);
+        });
+
+        Self { layers, hook }
+    }
+
+    /// Returns the statistics of each module by path.
+    pub fn layers(&self) -> BTreeMap<String, LayerActivationStats> {
+        self.layers.lock().clone()
+    }
+
+    /// Returns the statistics of each module by path, and start aggregating them from scratch.
+    pub fn take(&self) -> BTreeMap<String, LayerActivationStats> {
+        core::mem::take(&mut *self.layers.lock())
+    }
+
+    /// Remove the hook, so that the statistics aren't collected anymore.
+    pub fn remove(&self) {
+        ModuleHooks::remove(self.hook);
+    }
+}
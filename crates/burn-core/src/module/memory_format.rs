@@ -0,0 +1,48 @@
+use burn_tensor::{backend::Backend, MemoryFormat, Tensor};
+
+use crate::module::{ModuleMapper, ParamId};
+
+/// Lays out the parameters of a module in a [memory format](MemoryFormat).
+///
+/// Only the parameters with a channel dimension, which have at least three dimensions such as
+/// the convolution weights, are converted.
+#[derive(new, Debug, Clone, Copy)]
+pub struct MemoryFormatMapper {
+    /// The memory format of the parameters.
+    pub format: MemoryFormat,
+}
+
+impl<B: Backend> ModuleMapper<B> for MemoryFormatMapper {
+    fn map_float<const D: usize>(&mut self, _id: &ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        if D < 3 {
+            return tensor;
+        }
+
+        tensor.to_memory_format(self.format)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::module::Module;
+    use crate::nn::conv::Conv2dConfig;
+    use crate::TestBackend;
+    use burn_tensor::Distribution;
+
+    #[test]
+    fn channels_last_conv_should_match_the_contiguous_conv() {
+        let device = Default::default();
+        let conv = Conv2dConfig::new([3, 8], [3, 3]).init::<TestBackend>(&device);
+        let input = Tensor::<TestBackend, 4>::random([2, 3, 8, 8], Distribution::Default, &device);
+
+        let expected = conv.forward(input.clone());
+        let conv = conv.to_memory_format(MemoryFormat::ChannelsLast);
+        let output = conv.forward(input.to_memory_format(MemoryFormat::ChannelsLast));
+
+        assert_eq!(output.dims(), expected.dims());
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 4);
+    }
+}
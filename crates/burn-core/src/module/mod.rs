@@ -1,9 +1,15 @@
 mod base;
 mod display;
+mod ensemble;
+mod hooks;
+mod memory_format;
 mod param;
 mod quantize;
 
 pub use base::*;
 pub use display::*;
+pub use ensemble::*;
+pub use hooks::*;
+pub use memory_format::*;
 pub use param::*;
 pub use quantize::*;
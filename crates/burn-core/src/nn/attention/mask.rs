@@ -24,6 +24,32 @@ pub fn generate_autoregressive_mask<B: Backend>(
     mask.equal_elem(1_i64.elem::<i64>())
 }
 
+/// Generate a sliding window attention mask.
+///
+/// Each position can only attend the positions at most `window_size` away from it, except for
+/// the global tokens, which attend and are attended by every position. The mask is equivalent to
+/// the [sliding window](crate::nn::attention::MultiHeadAttentionConfig::sliding_window) of the
+/// multihead attention, which doesn't need to materialize it.
+pub fn generate_sliding_window_mask<B: Backend>(
+    batch_size: usize,
+    seq_length: usize,
+    window_size: usize,
+    global_tokens: &[usize],
+    device: &B::Device,
+) -> Tensor<B, 3, Bool> {
+    let mut values = Vec::with_capacity(seq_length * seq_length);
+
+    for i in 0..seq_length {
+        for j in 0..seq_length {
+            let is_global = global_tokens.contains(&i) || global_tokens.contains(&j);
+            values.push(!is_global && i.abs_diff(j) > window_size);
+        }
+    }
+
+    Tensor::<B, 3, Bool>::from_data(TensorData::new(values, [1, seq_length, seq_length]), device)
+        .repeat_dim(0, batch_size)
+}
+
 /// Generate a padding attention mask.
 pub struct GeneratePaddingMask<B: Backend> {
     /// The generated tensor.
@@ -120,6 +146,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_generate_sliding_window_mask() {
+        let device = <TestBackend as Backend>::Device::default();
+
+        let mask = generate_sliding_window_mask::<TestBackend>(1, 5, 1, &[4], &device);
+
+        assert_eq!(
+            mask.into_data(),
+            TensorData::from([[
+                [false, false, true, true, false],
+                [false, false, false, true, false],
+                [true, false, false, false, false],
+                [true, true, false, false, false],
+                [false, false, false, false, false],
+            ]])
+        );
+    }
+
     #[test]
     fn test_generate_padding_mask() {
         let device = <TestBackend as Backend>::Device::default();
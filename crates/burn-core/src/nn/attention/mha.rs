@@ -1,13 +1,18 @@
 use crate as burn;
 
-use crate::module::{Content, DisplaySettings, Module, ModuleDisplay};
+use crate::generation::AutoregressiveCache;
+use crate::module::{
+    forward_hook, Content, DisplaySettings, Ignored, Module, ModuleDisplay, Param,
+};
+use crate::nn::attention::generate_sliding_window_mask;
 use crate::nn::cache::TensorCache;
 use crate::nn::Initializer;
 use crate::{
     config::Config,
     nn,
-    tensor::{activation, backend::Backend, Bool, Tensor},
+    tensor::{activation, backend::Backend, Bool, Int, Tensor, TensorData},
 };
+use alloc::vec::Vec;
 
 #[cfg(not(feature = "std"))]
 use num_traits::Float;
@@ -22,6 +27,9 @@ pub struct MultiHeadAttentionConfig {
     /// The dropout rate. Default: 0.1
     #[config(default = 0.1)]
     pub dropout: f64,
+    /// Where the dropout is applied. Default: on the attention scores, before the softmax.
+    #[config(default = "AttentionDropout::Scores")]
+    pub dropout_placement: AttentionDropout,
     /// The minimum value a float can take. Default: -1.0e4
     /// This is used to mask attention scores before calculating attention weights.
     /// A value too low might result in NaN.
@@ -35,6 +43,29 @@ pub struct MultiHeadAttentionConfig {
     /// Reference: <https://www.evanmiller.org/attention-is-off-by-one.html>
     #[config(default = false)]
     pub quiet_softmax: bool,
+    /// Only attend the keys at most this number of positions away from each query, if set.
+    ///
+    /// The attention is computed block by block over the windows without building the full
+    /// score matrix, so the memory grows linearly with the sequence length. Only self-attention,
+    /// where the queries and keys have the same length, is supported.
+    /// See [generate_sliding_window_mask](crate::nn::attention::generate_sliding_window_mask)
+    /// for the equivalent mask.
+    #[config(default = "None")]
+    pub sliding_window: Option<usize>,
+    /// Learn the scale multiplying the attention scores instead of dividing them by `sqrt(d_k)`.
+    /// Default: false
+    ///
+    /// The logarithm of the scale is learned so that it stays positive, starting from
+    /// `1 / sqrt(d_k)`, like the logit scale of [CLIP](https://arxiv.org/abs/2103.00020).
+    #[config(default = false)]
+    pub learnable_scale: bool,
+    /// Return the attention weights in the [output](MhaOutput::weights). Default: true
+    ///
+    /// The weights hold `n_heads * seq_length_1 * seq_length_2` values for each item of the batch,
+    /// which stay allocated as long as the output is alive. Disabling it lets inference free them
+    /// as soon as the context is computed.
+    #[config(default = true)]
+    pub return_weights: bool,
     /// The type of function used to initialize neural network parameters
     #[config(
         default = "Initializer::KaimingUniform{gain:1.0/num_traits::Float::sqrt(3.0), fan_out_only:false}"
@@ -42,6 +73,17 @@ pub struct MultiHeadAttentionConfig {
     pub initializer: Initializer,
 }
 
+/// Where the dropout of the [multihead attention](MultiHeadAttention) is applied.
+#[derive(Config, Debug, PartialEq)]
+pub enum AttentionDropout {
+    /// On the attention scores, before the softmax.
+    Scores,
+    /// On the attention weights, after the softmax.
+    Weights,
+    /// On the output of the output projection.
+    Output,
+}
+
 /// The multihead attention module as describe in the paper [Attention Is All You Need](https://arxiv.org/abs/1706.03762).
 ///
 /// # Params
@@ -50,6 +92,8 @@ pub struct MultiHeadAttentionConfig {
 /// - key: [Linear](nn::Linear) layer with `d_model` input and output features.
 /// - value: [Linear](nn::Linear) layer with `d_model` input and output features.
 /// - output: [Linear](nn::Linear) layer with `d_model` input and output features.
+/// - log_scale: Logarithm of the scale of the attention scores, when it is
+///   [learned](MultiHeadAttentionConfig::learnable_scale).
 ///
 /// Should be created with [MultiHeadAttentionConfig].
 #[derive(Module, Debug)]
@@ -65,6 +109,10 @@ pub struct MultiHeadAttention<B: Backend> {
     pub output: nn::Linear<B>,
     /// Dropout layer.
     pub dropout: nn::Dropout,
+    /// Where the dropout is applied.
+    pub dropout_placement: Ignored<AttentionDropout>,
+    /// The logarithm of the scale multiplying the attention scores, if learned.
+    pub log_scale: Option<Param<Tensor<B, 1>>>,
     /// Activation function.
     pub activation: nn::Gelu,
     /// The size of each linear layer.
@@ -77,6 +125,10 @@ pub struct MultiHeadAttention<B: Backend> {
     pub min_float: f64,
     /// Use "quiet softmax" instead of regular softmax.
     pub quiet_softmax: bool,
+    /// The number of positions attended on each side of the queries, if set.
+    pub sliding_window: Option<usize>,
+    /// Return the attention weights.
+    pub return_weights: bool,
 }
 
 impl<B: Backend> ModuleDisplay for MultiHeadAttention<B> {
@@ -109,6 +161,7 @@ pub struct MhaInput<B: Backend> {
     value: Tensor<B, 3>,
     mask_pad: Option<Tensor<B, 2, Bool>>,
     mask_attn: Option<Tensor<B, 3, Bool>>,
+    global_tokens: Vec<usize>,
 }
 
 impl MultiHeadAttentionConfig {
@@ -120,17 +173,29 @@ impl MultiHeadAttentionConfig {
                 .init(device)
         };
 
+        let d_k = self.d_model / self.n_heads;
+        let log_scale = self.learnable_scale.then(|| {
+            Initializer::Constant {
+                value: -0.5 * (d_k as f64).ln(),
+            }
+            .init([1], device)
+        });
+
         MultiHeadAttention {
             query: linear(self),
             key: linear(self),
             value: linear(self),
             output: linear(self),
             dropout: nn::DropoutConfig::new(self.dropout).init(),
+            dropout_placement: Ignored(self.dropout_placement.clone()),
+            log_scale,
             activation: nn::Gelu::new(),
             n_heads: self.n_heads,
-            d_k: self.d_model / self.n_heads,
+            d_k,
             min_float: self.min_float,
             quiet_softmax: self.quiet_softmax,
+            sliding_window: self.sliding_window,
+            return_weights: self.return_weights,
             d_model: self.d_model,
         }
     }
@@ -149,6 +214,7 @@ impl<B: Backend> MhaInput<B> {
             value: tensor,
             mask_pad: None,
             mask_attn: None,
+            global_tokens: Vec::new(),
         }
     }
 
@@ -160,6 +226,7 @@ impl<B: Backend> MhaInput<B> {
             value,
             mask_pad: None,
             mask_attn: None,
+            global_tokens: Vec::new(),
         }
     }
 
@@ -174,13 +241,27 @@ impl<B: Backend> MhaInput<B> {
         self.mask_attn = Some(mask_attn);
         self
     }
+
+    /// Register the positions of the global tokens, which attend and are attended by every
+    /// position when a [sliding window](MultiHeadAttentionConfig::sliding_window) is used.
+    pub fn global_tokens(mut self, global_tokens: Vec<usize>) -> Self {
+        self.global_tokens = global_tokens;
+        self
+    }
 }
 
 /// [Multihead attention](MultiHeadAttention) outputs.
 #[derive(Debug, Clone)]
 pub struct MhaOutput<B: Backend> {
     /// The attention weights `[batch_size, n_heads, seq_length_1, seq_length_2]`.
-    pub weights: Tensor<B, 4>,
+    ///
+    /// With a [sliding window](MultiHeadAttentionConfig::sliding_window), only the weights of the
+    /// band are returned, with shape `[batch_size, n_heads, seq_length, 2 * window + 1]`, where
+    /// the weight at `[.., i, k]` is the one of the key `i + k - window`. The weights of the
+    /// keys outside of the sequence, and of the global tokens outside of the window, are omitted.
+    ///
+    /// Only returned when [enabled](MultiHeadAttentionConfig::return_weights).
+    pub weights: Option<Tensor<B, 4>>,
     /// The context tensor `[batch_size, seq_length_1, d_model]`.
     pub context: Tensor<B, 3>,
 }
@@ -203,14 +284,29 @@ impl<B: Backend> MultiHeadAttention<B> {
         let key = self.attention_linear(input.key, &self.key);
         let value = self.attention_linear(input.value, &self.value);
 
-        let attn_scores = self.attn_scores(query, key);
-        let weights = self.attn_weights(attn_scores, input.mask_pad, input.mask_attn);
+        let (context, weights) = match self.sliding_window {
+            Some(window_size) => self.windowed_attention(
+                query,
+                key,
+                value,
+                window_size,
+                &input.global_tokens,
+                input.mask_pad,
+                input.mask_attn,
+            ),
+            None => {
+                let attn_scores = self.attn_scores(query, key);
+                let weights = self.attn_weights(attn_scores, input.mask_pad, input.mask_attn);
+                let context = self.weights_dropout(weights.clone()).matmul(value);
+
+                (context, self.return_weights.then_some(weights))
+            }
+        };
 
-        let context = weights.clone().matmul(value);
         let context = context
             .swap_dims(1, 2)
             .reshape([batch_size, seq_length_1, d_model]);
-        let context = self.output.forward(context);
+        let context = forward_hook(self, self.output_dropout(self.output.forward(context)));
 
         MhaOutput { weights, context }
     }
@@ -236,25 +332,210 @@ impl<B: Backend> MultiHeadAttention<B> {
             .value
             .forward(input.value, |t| self.attention_linear(t, &self.value));
 
+        // The cached keys are recomputed for every query, so the window is applied with a mask.
+        let mut mask_attn = input.mask_attn;
+        if let Some(window_size) = self.sliding_window {
+            let [_, _, seq_length_2, _] = key.dims();
+            let mask_window = generate_sliding_window_mask(
+                batch_size,
+                seq_length_2,
+                window_size,
+                &input.global_tokens,
+                &key.device(),
+            )
+            .slice([
+                0..batch_size,
+                seq_length_2 - seq_length_1..seq_length_2,
+                0..seq_length_2,
+            ]);
+
+            mask_attn = Some(match mask_attn {
+                Some(mask_attn) => (mask_attn.int() + mask_window.int()).greater_elem(0),
+                None => mask_window,
+            });
+        }
+
         let attn_scores = self.attn_scores(query, key);
-        let weights = self.attn_weights(attn_scores, input.mask_pad, input.mask_attn);
+        let weights = self.attn_weights(attn_scores, input.mask_pad, mask_attn);
 
-        let context = weights.clone().matmul(value);
+        let context = self.weights_dropout(weights.clone()).matmul(value);
         let context = context
             .swap_dims(1, 2)
             .reshape([batch_size, seq_length_1, d_model]);
 
         let context = cache.output.forward(context, |t| self.output.forward(t));
+        let context = self.output_dropout(context);
 
-        MhaOutput { weights, context }
+        MhaOutput {
+            weights: self.return_weights.then_some(weights),
+            context,
+        }
+    }
+
+    /// Attention restricted to a sliding window, computed over blocks of queries so that only the
+    /// scores of the keys in their windows are computed.
+    ///
+    /// Returns the context and the band of the attention weights, when they are returned.
+    #[allow(clippy::too_many_arguments)]
+    fn windowed_attention(
+        &self,
+        query: Tensor<B, 4>,
+        key: Tensor<B, 4>,
+        value: Tensor<B, 4>,
+        window_size: usize,
+        global_tokens: &[usize],
+        mask_pad: Option<Tensor<B, 2, Bool>>,
+        mask_attn: Option<Tensor<B, 3, Bool>>,
+    ) -> (Tensor<B, 4>, Option<Tensor<B, 4>>) {
+        let [batch_size, n_heads, seq_length, _] = query.dims();
+        let device = query.device();
+        assert_eq!(
+            key.dims()[2],
+            seq_length,
+            "The sliding window requires queries and keys of the same length"
+        );
+
+        let mut global_tokens = global_tokens.to_vec();
+        global_tokens.sort_unstable();
+        global_tokens.dedup();
+        assert!(
+            global_tokens.iter().all(|token| *token < seq_length),
+            "The global tokens must be in the sequence"
+        );
+
+        let global = &global_tokens;
+        let block_size = window_size.max(1);
+        let band_size = 2 * window_size + 1;
+        let mut contexts = Vec::new();
+        let mut bands = Vec::new();
+
+        for start in (0..seq_length).step_by(block_size) {
+            let end = usize::min(start + block_size, seq_length);
+            let num_queries = end - start;
+            let key_start = start.saturating_sub(window_size);
+            let key_end = usize::min(end + window_size, seq_length);
+
+            // The keys in the window of the block, followed by the global keys outside of it.
+            let keys = (key_start..key_end)
+                .chain(
+                    global_tokens
+                        .iter()
+                        .copied()
+                        .filter(|token| *token < key_start || *token >= key_end),
+                )
+                .collect::<Vec<_>>();
+            let num_keys = keys.len();
+            let indices = int_tensor::<B, 1>(&keys, [num_keys], &device);
+
+            let out_of_window = (start..end)
+                .flat_map(|i| {
+                    keys.iter()
+                        .map(move |j| i.abs_diff(*j) > window_size && !global.contains(j))
+                })
+                .collect::<Vec<_>>();
+            let out_of_window = Tensor::<B, 4, Bool>::from_data(
+                TensorData::new(out_of_window, [1, 1, num_queries, num_keys]),
+                &device,
+            );
+
+            let attn_scores = self
+                .attn_scores(
+                    query.clone().narrow(2, start, num_queries),
+                    key.clone().select(2, indices.clone()),
+                )
+                .mask_fill(
+                    out_of_window.expand([batch_size, n_heads, num_queries, num_keys]),
+                    self.min_float,
+                );
+            let weights = self.attn_weights(
+                attn_scores,
+                mask_pad.clone().map(|mask| mask.select(1, indices.clone())),
+                mask_attn.clone().map(|mask| {
+                    mask.narrow(1, start, num_queries)
+                        .select(2, indices.clone())
+                }),
+            );
+            contexts.push(
+                self.weights_dropout(weights.clone())
+                    .matmul(value.clone().select(2, indices)),
+            );
+
+            if !self.return_weights {
+                continue;
+            }
+
+            // Gather the band of the weights, pointing to an appended zero column outside of it.
+            let band = (start..end)
+                .flat_map(|i| {
+                    (0..band_size).map(move |k| match (i + k).checked_sub(window_size) {
+                        Some(j) if j >= key_start && j < key_end => j - key_start,
+                        _ => num_keys,
+                    })
+                })
+                .collect::<Vec<_>>();
+            let band = int_tensor::<B, 4>(&band, [1, 1, num_queries, band_size], &device).expand([
+                batch_size,
+                n_heads,
+                num_queries,
+                band_size,
+            ]);
+            let weights = Tensor::cat(
+                alloc::vec![
+                    weights,
+                    Tensor::zeros([batch_size, n_heads, num_queries, 1], &device),
+                ],
+                3,
+            );
+            bands.push(weights.gather(3, band));
+        }
+
+        let mut context = Tensor::cat(contexts, 2);
+        let weights = self.return_weights.then(|| Tensor::cat(bands, 2));
+
+        // The global tokens attend every position.
+        if !global_tokens.is_empty() {
+            let indices = int_tensor::<B, 1>(&global_tokens, [global_tokens.len()], &device);
+            let attn_scores = self.attn_scores(query.select(2, indices.clone()), key);
+            let weights_global = self.attn_weights(
+                attn_scores,
+                mask_pad,
+                mask_attn.map(|mask| mask.select(1, indices.clone())),
+            );
+            let context_global = self.weights_dropout(weights_global).matmul(value);
+
+            // Assigning a selection accumulates the values, so the difference is assigned.
+            let previous = context.clone().select(2, indices.clone());
+            context = context.select_assign(2, indices, context_global - previous);
+        }
+
+        (context, weights)
     }
 
     fn attn_scores(&self, query: Tensor<B, 4>, key: Tensor<B, 4>) -> Tensor<B, 4> {
-        let attn_scores = query
-            .matmul(key.transpose())
-            .div_scalar((self.d_k as f32).sqrt());
+        let attn_scores = query.matmul(key.transpose());
+        let attn_scores = match &self.log_scale {
+            Some(log_scale) => attn_scores.mul(log_scale.val().exp().reshape([1, 1, 1, 1])),
+            None => attn_scores.div_scalar((self.d_k as f32).sqrt()),
+        };
 
-        self.dropout.forward(attn_scores)
+        match *self.dropout_placement {
+            AttentionDropout::Scores => self.dropout.forward(attn_scores),
+            _ => attn_scores,
+        }
+    }
+
+    fn weights_dropout(&self, weights: Tensor<B, 4>) -> Tensor<B, 4> {
+        match *self.dropout_placement {
+            AttentionDropout::Weights => self.dropout.forward(weights),
+            _ => weights,
+        }
+    }
+
+    fn output_dropout(&self, context: Tensor<B, 3>) -> Tensor<B, 3> {
+        match *self.dropout_placement {
+            AttentionDropout::Output => self.dropout.forward(context),
+            _ => context,
+        }
     }
 
     fn attn_weights(
@@ -297,6 +578,16 @@ impl<B: Backend> MultiHeadAttention<B> {
     }
 }
 
+fn int_tensor<B: Backend, const D: usize>(
+    values: &[usize],
+    shape: [usize; D],
+    device: &B::Device,
+) -> Tensor<B, D, Int> {
+    let values = values.iter().map(|value| *value as i64).collect::<Vec<_>>();
+
+    Tensor::from_ints(TensorData::new(values, shape), device)
+}
+
 /// Cache for the [Multi Head Attention](MultiHeadAttention) layer.
 ///
 /// To be used during inference when decoding tokens.
@@ -335,7 +626,23 @@ impl<B: Backend> MhaCache<B> {
     }
 }
 
+impl<B: Backend> AutoregressiveCache<B> for MhaCache<B> {
+    fn reorder_cache(&mut self, indices: Tensor<B, 1, Int>) {
+        self.query.reorder(indices.clone());
+        self.key.reorder(indices.clone());
+        self.value.reorder(indices.clone());
+        self.output.reorder(indices);
+    }
+}
+
 impl<B: Backend, const D: usize> MhaLinearCache<B, D> {
+    fn reorder(&mut self, indices: Tensor<B, 1, Int>) {
+        match self {
+            MhaLinearCache::Autoregressive(cache, _) => cache.reorder(indices),
+            MhaLinearCache::Full(cache) => cache.reorder(indices),
+        }
+    }
+
     pub fn forward<F: Fn(Tensor<B, 3>) -> Tensor<B, D>>(
         &mut self,
         tensor: Tensor<B, 3>,
@@ -355,7 +662,7 @@ mod tests {
     use super::*;
     use crate::tensor::Int;
     use crate::tensor::{Distribution, Shape};
-    use crate::{nn::attention::generate_autoregressive_mask, TestBackend};
+    use crate::{nn::attention::generate_autoregressive_mask, TestAutodiffBackend, TestBackend};
     use alloc::vec::Vec;
 
     #[test]
@@ -377,7 +684,7 @@ mod tests {
             "Context should have the correct shape",
         );
         assert_eq!(
-            output.weights.shape(),
+            output.weights.unwrap().shape(),
             Shape::new([batch_size, n_heads, seq_length, seq_length]),
             "Weights should have the correct shape",
         );
@@ -415,7 +722,7 @@ mod tests {
             "Context should have the correct shape",
         );
         assert_eq!(
-            output.weights.shape(),
+            output.weights.unwrap().shape(),
             Shape::new([batch_size, n_heads, seq_length_1, seq_length_2]),
             "Weights should have the correct shape",
         );
@@ -512,6 +819,235 @@ mod tests {
             .assert_approx_eq(&output_2.into_data(), 3);
     }
 
+    #[test]
+    fn test_sliding_window_should_match_full_attention_with_explicit_mask() {
+        let [batch_size, seq_length, d_model, n_heads, window_size] = [2, 11, 8, 2, 2];
+        let device = Default::default();
+        let windowed = MultiHeadAttentionConfig::new(d_model, n_heads)
+            .with_sliding_window(Some(window_size))
+            .init::<TestBackend>(&device);
+        let full = MultiHeadAttention {
+            sliding_window: None,
+            ..windowed.clone()
+        };
+
+        let tensor = Tensor::<TestBackend, 3>::random(
+            [batch_size, seq_length, d_model],
+            Distribution::Default,
+            &device,
+        );
+        let mask_pad = Tensor::<TestBackend, 2, Int>::zeros([batch_size, seq_length], &device)
+            .slice_assign(
+                [1..2, seq_length - 3..seq_length],
+                Tensor::ones([1, 3], &device),
+            )
+            .equal_elem(1);
+
+        for global_tokens in [Vec::new(), alloc::vec![0, 7]] {
+            let mask_attn = generate_sliding_window_mask(
+                batch_size,
+                seq_length,
+                window_size,
+                &global_tokens,
+                &device,
+            );
+            let expected = full.forward(
+                MhaInput::self_attn(tensor.clone())
+                    .mask_pad(mask_pad.clone())
+                    .mask_attn(mask_attn),
+            );
+            let output = windowed.forward(
+                MhaInput::self_attn(tensor.clone())
+                    .mask_pad(mask_pad.clone())
+                    .global_tokens(global_tokens),
+            );
+
+            output
+                .context
+                .into_data()
+                .assert_approx_eq(&expected.context.into_data(), 3);
+
+            // The band of the weights around the 5th query.
+            output
+                .weights
+                .unwrap()
+                .slice([0..batch_size, 0..n_heads, 5..6, 0..5])
+                .into_data()
+                .assert_approx_eq(
+                    &expected
+                        .weights
+                        .unwrap()
+                        .slice([0..batch_size, 0..n_heads, 5..6, 3..8])
+                        .into_data(),
+                    3,
+                );
+        }
+    }
+
+    #[test]
+    fn test_sliding_window_should_have_same_output_as_autoregressive_decoding() {
+        let [batch_size, seq_length, d_model, n_heads] = [2, 7, 12, 2];
+        let device = Default::default();
+        let mha = MultiHeadAttentionConfig::new(d_model, n_heads)
+            .with_sliding_window(Some(2))
+            .init::<TestBackend>(&device);
+
+        let tensor = Tensor::<TestBackend, 3>::random(
+            [batch_size, seq_length, d_model],
+            Distribution::Default,
+            &device,
+        );
+        let mask_attn = generate_autoregressive_mask(batch_size, seq_length, &tensor.device());
+        let output_1 = mha.forward(MhaInput::self_attn(tensor.clone()).mask_attn(mask_attn));
+
+        let mut output_2 = Vec::new();
+        let mut cache = MhaCache::autoregressive();
+        for i in 1..seq_length + 1 {
+            let tensor = tensor.clone().slice([0..batch_size, 0..i, 0..d_model]);
+            let next_tok = mha
+                .forward_cache(MhaInput::self_attn(tensor), &mut cache)
+                .context
+                .slice([0..batch_size, i - 1..i, 0..d_model]);
+            output_2.push(next_tok);
+        }
+        let output_2 = Tensor::cat(output_2, 1);
+
+        output_1
+            .context
+            .into_data()
+            .assert_approx_eq(&output_2.into_data(), 3);
+    }
+
+    #[test]
+    fn test_sliding_window_memory_should_be_linear_in_sequence_length() {
+        let [batch_size, seq_length, d_model, n_heads, window_size] = [1, 16384, 4, 1, 512];
+        let device = Default::default();
+        let mha = MultiHeadAttentionConfig::new(d_model, n_heads)
+            .with_sliding_window(Some(window_size))
+            .init::<TestBackend>(&device);
+        let input = MhaInput::self_attn(Tensor::random(
+            [batch_size, seq_length, d_model],
+            Distribution::Default,
+            &device,
+        ))
+        .global_tokens(alloc::vec![0]);
+
+        let output = mha.forward(input);
+
+        // The full score matrix would have `seq_length * seq_length` elements per head.
+        assert_eq!(
+            output.weights.unwrap().shape(),
+            Shape::new([batch_size, n_heads, seq_length, 2 * window_size + 1])
+        );
+        assert_eq!(
+            output.context.shape(),
+            Shape::new([batch_size, seq_length, d_model])
+        );
+    }
+
+    #[test]
+    fn test_default_config_should_match_scaled_dot_product_attention() {
+        let [batch_size, seq_length, d_model, n_heads] = [2, 5, 8, 2];
+        let d_k = d_model / n_heads;
+        let device = Default::default();
+        let mha = MultiHeadAttentionConfig::new(d_model, n_heads).init::<TestBackend>(&device);
+        let tensor = Tensor::<TestBackend, 3>::random(
+            [batch_size, seq_length, d_model],
+            Distribution::Default,
+            &device,
+        );
+
+        let output = mha.forward(MhaInput::self_attn(tensor.clone()));
+
+        let heads = |linear: &nn::Linear<TestBackend>| {
+            linear
+                .forward(tensor.clone())
+                .reshape([batch_size, seq_length, n_heads, d_k])
+                .swap_dims(1, 2)
+        };
+        let attn_scores = heads(&mha.query)
+            .matmul(heads(&mha.key).transpose())
+            .div_scalar((d_k as f32).sqrt());
+        let weights = activation::softmax(attn_scores, 3);
+        let context = weights
+            .clone()
+            .matmul(heads(&mha.value))
+            .swap_dims(1, 2)
+            .reshape([batch_size, seq_length, d_model]);
+        let context = mha.output.forward(context);
+
+        output
+            .context
+            .into_data()
+            .assert_eq(&context.into_data(), true);
+        output
+            .weights
+            .unwrap()
+            .into_data()
+            .assert_eq(&weights.into_data(), true);
+    }
+
+    #[test]
+    fn test_learnable_scale_should_start_from_fixed_scale_and_receive_gradients() {
+        let [batch_size, seq_length, d_model, n_heads] = [2, 5, 8, 2];
+        let device = Default::default();
+        let mha = MultiHeadAttentionConfig::new(d_model, n_heads)
+            .with_dropout(0.0)
+            .with_learnable_scale(true)
+            .init::<TestAutodiffBackend>(&device);
+        let fixed = MultiHeadAttention {
+            log_scale: None,
+            ..mha.clone()
+        };
+        let tensor = Tensor::<TestAutodiffBackend, 3>::random(
+            [batch_size, seq_length, d_model],
+            Distribution::Default,
+            &device,
+        );
+
+        let output = mha.forward(MhaInput::self_attn(tensor.clone()));
+        let expected = fixed.forward(MhaInput::self_attn(tensor));
+
+        output
+            .context
+            .clone()
+            .into_data()
+            .assert_approx_eq(&expected.context.into_data(), 3);
+
+        let grads = output.context.powf_scalar(2.0).sum().backward();
+        let grad = mha.log_scale.unwrap().val().grad(&grads).unwrap();
+
+        assert_eq!(grad.dims(), [1]);
+        assert_ne!(grad.into_scalar(), 0.0);
+    }
+
+    #[test]
+    fn test_weights_should_only_be_returned_when_enabled() {
+        let [batch_size, seq_length, d_model, n_heads] = [2, 5, 8, 2];
+        let device = Default::default();
+        let mha = MultiHeadAttentionConfig::new(d_model, n_heads)
+            .with_return_weights(false)
+            .init::<TestBackend>(&device);
+        let tensor = Tensor::<TestBackend, 3>::random(
+            [batch_size, seq_length, d_model],
+            Distribution::Default,
+            &device,
+        );
+
+        let output = mha.forward(MhaInput::self_attn(tensor.clone()));
+        let expected = MultiHeadAttention {
+            return_weights: true,
+            ..mha.clone()
+        }
+        .forward(MhaInput::self_attn(tensor));
+
+        assert!(output.weights.is_none());
+        output
+            .context
+            .into_data()
+            .assert_eq(&expected.context.into_data(), true);
+    }
+
     #[test]
     fn display() {
         let config = MultiHeadAttentionConfig::new(2, 4);
@@ -0,0 +1,284 @@
+use super::{Stft, StftConfig};
+use crate as burn;
+use crate::config::Config;
+use crate::module::{Content, DisplaySettings, Module, ModuleDisplay};
+use crate::tensor::backend::Backend;
+use crate::tensor::{Tensor, TensorData};
+use alloc::vec::Vec;
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+/// Configuration to create a [MelSpectrogram](MelSpectrogram) layer using the
+/// [init function](MelSpectrogramConfig::init).
+///
+/// The defaults follow `torchaudio.transforms.MelSpectrogram`.
+#[derive(Config, Debug)]
+pub struct MelSpectrogramConfig {
+    /// The sample rate of the signals.
+    pub sample_rate: usize,
+    /// The size of the Fourier transform. Default: 400
+    #[config(default = 400)]
+    pub n_fft: usize,
+    /// The size of the Hann window applied on each frame. Default: `n_fft`
+    #[config(default = "None")]
+    pub win_length: Option<usize>,
+    /// The number of samples between the beginning of successive frames.
+    /// Default: `win_length / 2`
+    #[config(default = "None")]
+    pub hop_length: Option<usize>,
+    /// The number of mel bands. Default: 128
+    #[config(default = 128)]
+    pub n_mels: usize,
+    /// The lowest frequency of the mel bands, in Hz. Default: 0.0
+    #[config(default = 0.0)]
+    pub f_min: f64,
+    /// The highest frequency of the mel bands, in Hz. Default: `sample_rate / 2`
+    #[config(default = "None")]
+    pub f_max: Option<f64>,
+    /// The exponent applied on the magnitude of the transform before the mel filterbank.
+    /// Default: 2.0
+    #[config(default = 2.0)]
+    pub power: f64,
+    /// Pad the signal on both sides with its reflection, so that the frames are centered.
+    /// Default: true
+    #[config(default = true)]
+    pub center: bool,
+    /// Returns the natural logarithm of the mel spectrogram shifted by this offset, if set.
+    #[config(default = "None")]
+    pub log_offset: Option<f64>,
+}
+
+impl MelSpectrogramConfig {
+    /// Initialize a new [MelSpectrogram](MelSpectrogram) module.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the frequency range is empty or above the Nyquist frequency.
+    pub fn init<B: Backend>(&self, device: &B::Device) -> MelSpectrogram<B> {
+        let nyquist = (self.sample_rate / 2) as f64;
+        let f_max = self.f_max.unwrap_or(nyquist);
+        assert!(
+            self.f_min >= 0.0 && self.f_min < f_max && f_max <= nyquist,
+            "The frequency range must be in [0, sample_rate / 2]"
+        );
+
+        let stft = StftConfig::new(self.n_fft)
+            .with_win_length(self.win_length)
+            .with_hop_length(self.hop_length)
+            .with_center(self.center)
+            .init(device);
+        let n_freqs = self.n_fft / 2 + 1;
+        let filterbank = mel_filterbank(n_freqs, self.n_mels, self.f_min, f_max, nyquist);
+
+        MelSpectrogram {
+            stft,
+            filterbank: Tensor::from_floats(
+                TensorData::new(filterbank, [self.n_mels, n_freqs]),
+                device,
+            ),
+            power: self.power,
+            log_offset: self.log_offset,
+        }
+    }
+}
+
+/// Computes the mel spectrogram of signals, optionally in log scale.
+///
+/// The filterbank uses the HTK mel scale without normalization, as `torchaudio` does by default.
+/// Everything is computed on the device of the signals and is differentiable, so the module can
+/// be trained end-to-end with the model.
+///
+/// Should be created with [MelSpectrogramConfig].
+#[derive(Module, Debug)]
+#[module(custom_display)]
+pub struct MelSpectrogram<B: Backend> {
+    /// The short-time Fourier transform.
+    pub stft: Stft<B>,
+    /// The triangular mel filters, of shape `[n_mels, n_freqs]`.
+    pub filterbank: Tensor<B, 2>,
+    /// The exponent applied on the magnitude of the transform.
+    pub power: f64,
+    /// The offset added before the logarithm, if any.
+    pub log_offset: Option<f64>,
+}
+
+impl<B: Backend> ModuleDisplay for MelSpectrogram<B> {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        let [n_mels, _] = self.filterbank.dims();
+        content
+            .add("n_mels", &n_mels)
+            .add("n_fft", &self.stft.n_fft)
+            .add("hop_length", &self.stft.hop_length)
+            .add("power", &self.power)
+            .optional()
+    }
+}
+
+impl<B: Backend> MelSpectrogram<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, length]`
+    /// - output: `[batch_size, n_mels, n_frames]`
+    pub fn forward(&self, input: Tensor<B, 2>) -> Tensor<B, 3> {
+        let spectrogram = self.stft.spectrogram(input, self.power);
+        let mel = self.filterbank.clone().unsqueeze().matmul(spectrogram);
+
+        match self.log_offset {
+            Some(offset) => mel.add_scalar(offset).log(),
+            None => mel,
+        }
+    }
+}
+
+/// The triangular filters of the HTK mel scale, of shape `[n_mels, n_freqs]`.
+fn mel_filterbank(n_freqs: usize, n_mels: usize, f_min: f64, f_max: f64, nyquist: f64) -> Vec<f32> {
+    let hz_to_mel = |freq: f64| 2595.0 * (1.0 + freq / 700.0).log10();
+    let mel_to_hz = |mel: f64| 700.0 * (10.0f64.powf(mel / 2595.0) - 1.0);
+
+    let mel_min = hz_to_mel(f_min);
+    let mel_max = hz_to_mel(f_max);
+    let points = (0..n_mels + 2)
+        .map(|i| mel_to_hz(mel_min + (mel_max - mel_min) * i as f64 / (n_mels + 1) as f64))
+        .collect::<Vec<_>>();
+
+    let mut filterbank = Vec::with_capacity(n_mels * n_freqs);
+    for mel in 0..n_mels {
+        let (left, center, right) = (points[mel], points[mel + 1], points[mel + 2]);
+
+        for bin in 0..n_freqs {
+            let freq = nyquist * bin as f64 / (n_freqs - 1) as f64;
+            let up = (freq - left) / (center - left);
+            let down = (right - freq) / (right - center);
+
+            filterbank.push(up.min(down).max(0.0) as f32);
+        }
+    }
+
+    filterbank
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{TestAutodiffBackend, TestBackend};
+    use core::f64::consts::PI;
+
+    fn waveform<B: Backend>(length: usize, sample_rate: usize) -> Tensor<B, 2> {
+        let values = (0..length)
+            .map(|n| {
+                let t = n as f64 / sample_rate as f64;
+                let value = 0.5 * (2.0 * PI * 440.0 * t).sin()
+                    + 0.25 * (2.0 * PI * 3000.0 * t + 0.3).sin()
+                    + 0.1 * (2.0 * PI * 5500.0 * t).cos();
+                value as f32
+            })
+            .collect::<Vec<_>>();
+
+        Tensor::from_floats(TensorData::new(values, [1, length]), &Default::default())
+    }
+
+    #[test]
+    fn log_mel_spectrogram_should_match_reference() {
+        let mel = MelSpectrogramConfig::new(16000)
+            .with_n_fft(64)
+            .with_hop_length(Some(16))
+            .with_n_mels(8)
+            .with_log_offset(Some(1e-6))
+            .init::<TestBackend>(&Default::default());
+
+        let output = mel.forward(waveform(128, 16000));
+
+        // Computed in float64 following `torchaudio.transforms.MelSpectrogram(16000, n_fft=64,
+        // hop_length=16, n_mels=8)` with a reflected centered padding, then `log(x + 1e-6)`.
+        let expected = TensorData::from([[
+            [
+                3.7451, 3.8010, 3.4739, 3.4540, 3.4437, 3.4490, 3.4668, 3.7502, 3.6256,
+            ],
+            [
+                2.2591, 3.7763, 4.0882, 4.0926, 4.0948, 4.0937, 4.0897, 3.7576, 2.4302,
+            ],
+            [
+                2.2630, 1.7740, 1.3645, 1.3549, 1.3500, 1.3526, 1.3611, 2.0367, 2.6987,
+            ],
+            [
+                -0.7341, -1.8128, -4.7793, -4.8816, -4.9377, -4.9083, -4.8146, -0.1490, 1.1191,
+            ],
+            [
+                1.5487, 1.1038, 0.8376, 0.8364, 0.8366, 0.8373, 0.8359, 1.3387, 2.0068,
+            ],
+            [
+                2.5663, 2.9394, 3.0469, 3.0470, 3.0470, 3.0469, 3.0471, 3.0279, 2.9800,
+            ],
+            [
+                1.2869, 0.7531, 0.4305, 0.4298, 0.4300, 0.4303, 0.4295, 0.3461, 0.1282,
+            ],
+            [
+                0.7281, 1.0026, 1.0793, 1.0793, 1.0793, 1.0793, 1.0793, 1.0187, 0.8561,
+            ],
+        ]]);
+        output.into_data().assert_approx_eq_diff(&expected, 1e-3);
+    }
+
+    #[test]
+    fn output_shape_should_depend_on_padding_mode() {
+        let device = Default::default();
+        let input = Tensor::<TestBackend, 2>::zeros([3, 1600], &device);
+        let config = MelSpectrogramConfig::new(16000).with_n_mels(40);
+
+        let output = config.init::<TestBackend>(&device).forward(input.clone());
+        assert_eq!(output.dims(), [3, 40, 9]);
+
+        let output = config
+            .with_center(false)
+            .init::<TestBackend>(&device)
+            .forward(input);
+        assert_eq!(output.dims(), [3, 40, 7]);
+    }
+
+    #[test]
+    fn filterbank_should_be_triangular() {
+        let filterbank = mel_filterbank(9, 2, 0.0, 8000.0, 8000.0);
+
+        assert_eq!(filterbank.len(), 18);
+        assert!(filterbank.iter().all(|value| (0.0..=1.0).contains(value)));
+        // The first band starts at 0 Hz and the last one ends at the Nyquist frequency.
+        assert_eq!(filterbank[0], 0.0);
+        assert_eq!(filterbank[17], 0.0);
+    }
+
+    #[test]
+    fn mel_spectrogram_should_be_differentiable() {
+        let mel = MelSpectrogramConfig::new(16000)
+            .with_n_fft(64)
+            .with_n_mels(8)
+            .with_log_offset(Some(1e-6))
+            .init::<TestAutodiffBackend>(&Default::default());
+        let input = waveform::<TestAutodiffBackend>(128, 16000).require_grad();
+
+        let grads = mel.forward(input.clone()).sum().backward();
+        let grad = input.grad(&grads).unwrap();
+
+        assert_eq!(grad.dims(), [1, 128]);
+        assert!(grad.abs().sum().into_scalar() > 0.0);
+    }
+
+    #[test]
+    fn display() {
+        let config = MelSpectrogramConfig::new(16000);
+        let mel = config.init::<TestBackend>(&Default::default());
+
+        assert_eq!(
+            alloc::format!("{}", mel),
+            "MelSpectrogram {n_mels: 128, n_fft: 400, hop_length: 200, power: 2}"
+        );
+    }
+}
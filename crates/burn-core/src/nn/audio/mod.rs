@@ -0,0 +1,5 @@
+mod mel_spectrogram;
+mod stft;
+
+pub use mel_spectrogram::*;
+pub use stft::*;
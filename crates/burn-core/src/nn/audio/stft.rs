@@ -0,0 +1,289 @@
+use crate as burn;
+use crate::config::Config;
+use crate::module::{Content, DisplaySettings, Module, ModuleDisplay};
+use crate::tensor::backend::Backend;
+use crate::tensor::{Int, Tensor, TensorData};
+use alloc::vec::Vec;
+use core::f64::consts::PI;
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+/// Configuration to create a [short-time Fourier transform](Stft) layer using the
+/// [init function](StftConfig::init).
+#[derive(Config, Debug)]
+pub struct StftConfig {
+    /// The size of the Fourier transform.
+    pub n_fft: usize,
+    /// The size of the Hann window applied on each frame, which must not exceed `n_fft`.
+    /// Default: `n_fft`
+    #[config(default = "None")]
+    pub win_length: Option<usize>,
+    /// The number of samples between the beginning of successive frames.
+    /// Default: `win_length / 2`
+    #[config(default = "None")]
+    pub hop_length: Option<usize>,
+    /// Pad the signal on both sides with its reflection, so that frame `t` is centered at
+    /// sample `t * hop_length`. Default: true
+    #[config(default = true)]
+    pub center: bool,
+}
+
+impl StftConfig {
+    /// Initialize a new [short-time Fourier transform](Stft) module.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the window is empty or larger than the Fourier transform, or if the hop length
+    /// is zero.
+    pub fn init<B: Backend>(&self, device: &B::Device) -> Stft<B> {
+        let win_length = self.win_length.unwrap_or(self.n_fft);
+        let hop_length = self.hop_length.unwrap_or(win_length / 2);
+        assert!(
+            win_length > 0 && win_length <= self.n_fft,
+            "The window length must be in [1, n_fft]"
+        );
+        assert!(hop_length > 0, "The hop length must be positive");
+
+        let n_freqs = self.n_fft / 2 + 1;
+        let window = padded_hann_window(win_length, self.n_fft);
+
+        // The window is folded in the real DFT matrices, so that framing, windowing and the
+        // transform are a single matmul once the frames are gathered.
+        let mut real = Vec::with_capacity(self.n_fft * n_freqs);
+        let mut imag = Vec::with_capacity(self.n_fft * n_freqs);
+        for (k, w) in window.iter().enumerate() {
+            for freq in 0..n_freqs {
+                let angle = 2.0 * PI * ((freq * k) % self.n_fft) as f64 / self.n_fft as f64;
+                real.push((w * angle.cos()) as f32);
+                imag.push((-w * angle.sin()) as f32);
+            }
+        }
+
+        Stft {
+            dft_real: Tensor::from_floats(TensorData::new(real, [self.n_fft, n_freqs]), device),
+            dft_imag: Tensor::from_floats(TensorData::new(imag, [self.n_fft, n_freqs]), device),
+            n_fft: self.n_fft,
+            win_length,
+            hop_length,
+            center: self.center,
+        }
+    }
+}
+
+/// Applies the short-time Fourier transform with a Hann window on real signals, keeping the
+/// `n_fft / 2 + 1` non-negative frequencies.
+///
+/// The transform is computed as a matmul with precomputed DFT matrices, so it runs on the
+/// device of the signal and is differentiable.
+///
+/// Should be created with [StftConfig].
+#[derive(Module, Debug)]
+#[module(custom_display)]
+pub struct Stft<B: Backend> {
+    /// The real part of the windowed DFT matrix, of shape `[n_fft, n_freqs]`.
+    pub dft_real: Tensor<B, 2>,
+    /// The imaginary part of the windowed DFT matrix, of shape `[n_fft, n_freqs]`.
+    pub dft_imag: Tensor<B, 2>,
+    /// The size of the Fourier transform.
+    pub n_fft: usize,
+    /// The size of the Hann window.
+    pub win_length: usize,
+    /// The number of samples between the beginning of successive frames.
+    pub hop_length: usize,
+    /// Whether the signal is padded so that the frames are centered.
+    pub center: bool,
+}
+
+impl<B: Backend> ModuleDisplay for Stft<B> {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        content
+            .add("n_fft", &self.n_fft)
+            .add("win_length", &self.win_length)
+            .add("hop_length", &self.hop_length)
+            .add("center", &self.center)
+            .optional()
+    }
+}
+
+impl<B: Backend> Stft<B> {
+    /// Applies the forward pass on the input tensor, returning the real and imaginary parts of
+    /// the transform.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, length]`
+    /// - output: `[batch_size, n_freqs, n_frames]` for both parts
+    pub fn forward(&self, input: Tensor<B, 2>) -> (Tensor<B, 3>, Tensor<B, 3>) {
+        let frames = self.frames(input);
+        let real = frames.clone().matmul(self.dft_real.clone().unsqueeze());
+        let imag = frames.matmul(self.dft_imag.clone().unsqueeze());
+
+        (real.swap_dims(1, 2), imag.swap_dims(1, 2))
+    }
+
+    /// Computes the magnitude of the transform raised to the given power, `1.0` being the
+    /// magnitude and `2.0` the power spectrogram.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, length]`
+    /// - output: `[batch_size, n_freqs, n_frames]`
+    pub fn spectrogram(&self, input: Tensor<B, 2>, power: f64) -> Tensor<B, 3> {
+        let (real, imag) = self.forward(input);
+        let squared = real.powf_scalar(2.0) + imag.powf_scalar(2.0);
+
+        if power == 2.0 {
+            squared
+        } else {
+            squared.powf_scalar(power / 2.0)
+        }
+    }
+
+    /// Returns the number of frames of a signal with the given length.
+    pub fn num_frames(&self, length: usize) -> usize {
+        let length = match self.center {
+            true => length + 2 * (self.n_fft / 2),
+            false => length,
+        };
+        assert!(
+            length >= self.n_fft,
+            "The signal must be at least as long as the Fourier transform"
+        );
+
+        1 + (length - self.n_fft) / self.hop_length
+    }
+
+    /// Gather the frames of the signal, of shape `[batch_size, n_frames, n_fft]`, with a single
+    /// selection so that the padding doesn't need to be materialized first.
+    fn frames(&self, input: Tensor<B, 2>) -> Tensor<B, 3> {
+        let [batch_size, length] = input.dims();
+        let n_frames = self.num_frames(length);
+        let padding = match self.center {
+            true => self.n_fft / 2,
+            false => 0,
+        };
+        assert!(
+            padding < length,
+            "The signal must be longer than half the Fourier transform to be reflected"
+        );
+
+        let indices = (0..n_frames)
+            .flat_map(|frame| (0..self.n_fft).map(move |k| frame * self.hop_length + k))
+            .map(|index| reflect(index as i64 - padding as i64, length as i64))
+            .collect::<Vec<_>>();
+        let indices = Tensor::<B, 1, Int>::from_ints(
+            TensorData::new(indices, [n_frames * self.n_fft]),
+            &input.device(),
+        );
+
+        input
+            .select(1, indices)
+            .reshape([batch_size, n_frames, self.n_fft])
+    }
+}
+
+/// Map an index of the padded signal to the signal, reflecting it on the borders.
+fn reflect(index: i64, length: i64) -> i64 {
+    if index < 0 {
+        -index
+    } else if index >= length {
+        2 * (length - 1) - index
+    } else {
+        index
+    }
+}
+
+/// The periodic Hann window, zero-padded on both sides to the size of the Fourier transform.
+fn padded_hann_window(win_length: usize, n_fft: usize) -> Vec<f64> {
+    let left = (n_fft - win_length) / 2;
+
+    (0..n_fft)
+        .map(|k| match k.checked_sub(left) {
+            Some(n) if n < win_length => {
+                0.5 - 0.5 * (2.0 * PI * n as f64 / win_length as f64).cos()
+            }
+            _ => 0.0,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+
+    #[test]
+    fn centered_frames_should_be_reflected() {
+        let device = Default::default();
+        let stft = StftConfig::new(4)
+            .with_hop_length(Some(2))
+            .init::<TestBackend>(&device);
+        let input = Tensor::<TestBackend, 2>::from_floats([[0.0, 1.0, 2.0, 3.0, 4.0]], &device);
+
+        let frames = stft.frames(input);
+
+        frames.into_data().assert_eq(
+            &TensorData::from([[
+                [2.0, 1.0, 0.0, 1.0],
+                [0.0, 1.0, 2.0, 3.0],
+                [2.0, 3.0, 4.0, 3.0],
+            ]]),
+            false,
+        );
+    }
+
+    #[test]
+    fn output_shape_should_depend_on_padding_mode() {
+        let device = Default::default();
+        let input = Tensor::<TestBackend, 2>::zeros([2, 100], &device);
+        let config = StftConfig::new(16).with_hop_length(Some(4));
+
+        let (real, imag) = config.init::<TestBackend>(&device).forward(input.clone());
+        assert_eq!(real.dims(), [2, 9, 26]);
+        assert_eq!(imag.dims(), [2, 9, 26]);
+
+        let stft = config.with_center(false).init::<TestBackend>(&device);
+        let (real, _) = stft.forward(input);
+        assert_eq!(real.dims(), [2, 9, 22]);
+        assert_eq!(stft.num_frames(100), 22);
+    }
+
+    #[test]
+    fn spectrogram_should_find_the_frequency_of_a_sinusoid() {
+        let device = Default::default();
+        let n_fft = 32;
+        let stft = StftConfig::new(n_fft)
+            .with_center(false)
+            .init::<TestBackend>(&device);
+        // Exactly 4 periods in each frame.
+        let signal = (0..64)
+            .map(|n| (2.0 * PI * 4.0 * n as f64 / n_fft as f64).cos() as f32)
+            .collect::<Vec<_>>();
+        let input =
+            Tensor::<TestBackend, 2>::from_floats(TensorData::new(signal, [1, 64]), &device);
+
+        let peaks = stft.spectrogram(input, 1.0).argmax(1);
+
+        peaks
+            .into_data()
+            .assert_eq(&TensorData::from([[[4, 4, 4]]]), false);
+    }
+
+    #[test]
+    fn display() {
+        let config = StftConfig::new(400);
+        let stft = config.init::<TestBackend>(&Default::default());
+
+        assert_eq!(
+            alloc::format!("{}", stft),
+            "Stft {n_fft: 400, win_length: 400, hop_length: 200, center: true}"
+        );
+    }
+}
@@ -1,5 +1,5 @@
 use crate::tensor::backend::Backend;
-use crate::tensor::Tensor;
+use crate::tensor::{Int, Tensor};
 
 pub(crate) enum CacheState<T> {
     Value(T),
@@ -22,4 +22,12 @@ impl<B: Backend, const D: usize> TensorCache<B, D> {
             state: CacheState::Empty,
         }
     }
+
+    /// Reorder the cached tensor along its batch dimension, see
+    /// [AutoregressiveCache](crate::generation::AutoregressiveCache).
+    pub(crate) fn reorder(&mut self, indices: Tensor<B, 1, Int>) {
+        if let CacheState::Value(tensor) = &self.state {
+            self.state = CacheState::Value(tensor.clone().select(0, indices));
+        }
+    }
 }
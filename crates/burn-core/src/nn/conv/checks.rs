@@ -1,4 +1,11 @@
 pub(crate) fn checks_channels_div_groups(channels_in: usize, channels_out: usize, groups: usize) {
+    if groups == 0 {
+        panic!(
+            "The number of groups must be greater than zero. Got channels_in={channels_in}, \
+             channels_out={channels_out}, groups={groups}"
+        );
+    }
+
     let channels_in_div_by_group = channels_in % groups == 0;
     let channels_out_div_by_group = channels_out % groups == 0;
 
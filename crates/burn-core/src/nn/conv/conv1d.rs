@@ -4,7 +4,7 @@ use crate as burn;
 
 use crate::{
     config::Config,
-    module::{Content, DisplaySettings, Ignored, Module, ModuleDisplay, Param},
+    module::{forward_hook, Content, DisplaySettings, Ignored, Module, ModuleDisplay, Param},
     nn::{conv::checks, Initializer, PaddingConfig1d},
     tensor::{backend::Backend, module::conv1d, ops::ConvOptions, Tensor},
 };
@@ -135,12 +135,14 @@ impl<B: Backend> Conv1d<B> {
             .padding
             .calculate_padding_1d(length, self.kernel_size, self.stride);
 
-        conv1d(
+        let output = conv1d(
             input,
             self.weight.val(),
             self.bias.as_ref().map(|bias| bias.val()),
             ConvOptions::new([self.stride], [padding], [self.dilation], self.groups),
-        )
+        );
+
+        forward_hook(self, output)
     }
 }
 
@@ -3,9 +3,12 @@ use alloc::format;
 use crate as burn;
 
 use crate::config::Config;
-use crate::module::{Content, DisplaySettings, Ignored, Module, ModuleDisplay, Param};
+use crate::module::{
+    forward_hook, Content, DisplaySettings, Ignored, Module, ModuleDisplay, Param,
+};
 use crate::nn::Initializer;
 use crate::nn::PaddingConfig2d;
+use crate::per_sample;
 use crate::tensor::backend::Backend;
 use crate::tensor::module::conv2d;
 use crate::tensor::ops::ConvOptions;
@@ -146,12 +149,17 @@ impl<B: Backend> Conv2d<B> {
         let padding =
             self.padding
                 .calculate_padding_2d(height_in, width_in, &self.kernel_size, &self.stride);
-        conv2d(
-            input,
+        let options = ConvOptions::new(self.stride, padding, self.dilation, self.groups);
+        let output = conv2d(
+            input.clone(),
             self.weight.val(),
             self.bias.as_ref().map(|bias| bias.val()),
-            ConvOptions::new(self.stride, padding, self.dilation, self.groups),
-        )
+            options.clone(),
+        );
+
+        let output = per_sample::conv2d(&self.weight, self.bias.as_ref(), &input, &options, output);
+
+        forward_hook(self, output)
     }
 }
 
@@ -228,6 +236,14 @@ mod tests {
         let _ = config.init::<TestBackend>(&device);
     }
 
+    #[test]
+    #[should_panic = "The number of groups must be greater than zero."]
+    fn zero_groups_is_invalid() {
+        let device = Default::default();
+        let config = Conv2dConfig::new([4, 4], [1, 1]).with_groups(0);
+        let _ = config.init::<TestBackend>(&device);
+    }
+
     #[test]
     fn display() {
         let config = Conv2dConfig::new([5, 1], [5, 5]);
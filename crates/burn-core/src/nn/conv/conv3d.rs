@@ -3,7 +3,9 @@ use alloc::format;
 use crate as burn;
 
 use crate::config::Config;
-use crate::module::{Content, DisplaySettings, Ignored, Module, ModuleDisplay, Param};
+use crate::module::{
+    forward_hook, Content, DisplaySettings, Ignored, Module, ModuleDisplay, Param,
+};
 use crate::nn::Initializer;
 use crate::nn::PaddingConfig3d;
 use crate::tensor::backend::Backend;
@@ -151,12 +153,14 @@ impl<B: Backend> Conv3d<B> {
             &self.kernel_size,
             &self.stride,
         );
-        conv3d(
+        let output = conv3d(
             input,
             self.weight.val(),
             self.bias.as_ref().map(|bias| bias.val()),
             ConvOptions::new(self.stride, padding, self.dilation, self.groups),
-        )
+        );
+
+        forward_hook(self, output)
     }
 }
 
@@ -3,6 +3,7 @@ use alloc::format;
 use crate as burn;
 
 use crate::config::Config;
+use crate::module::forward_hook;
 use crate::module::Content;
 use crate::module::DisplaySettings;
 use crate::module::Module;
@@ -140,7 +141,7 @@ impl<B: Backend> ConvTranspose1d<B> {
     /// - input: `[batch_size, channels_in, length_in]`
     /// - output: `[batch_size, channels_out, length_out]`
     pub fn forward(&self, input: Tensor<B, 3>) -> Tensor<B, 3> {
-        conv_transpose1d(
+        let output = conv_transpose1d(
             input,
             self.weight.val(),
             self.bias.as_ref().map(|bias| bias.val()),
@@ -151,7 +152,9 @@ impl<B: Backend> ConvTranspose1d<B> {
                 [self.dilation],
                 self.groups,
             ),
-        )
+        );
+
+        forward_hook(self, output)
     }
 }
 
@@ -3,6 +3,7 @@ use alloc::format;
 use crate as burn;
 
 use crate::config::Config;
+use crate::module::forward_hook;
 use crate::module::Content;
 use crate::module::DisplaySettings;
 use crate::module::Module;
@@ -141,7 +142,7 @@ impl<B: Backend> ConvTranspose2d<B> {
     /// - input: `[batch_size, channels_in, height_in, width_in]`
     /// - output: `[batch_size, channels_out, height_out, width_out]`
     pub fn forward(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
-        conv_transpose2d(
+        let output = conv_transpose2d(
             input,
             self.weight.val(),
             self.bias.as_ref().map(|bias| bias.val()),
@@ -152,7 +153,9 @@ impl<B: Backend> ConvTranspose2d<B> {
                 self.dilation,
                 self.groups,
             ),
-        )
+        );
+
+        forward_hook(self, output)
     }
 }
 
@@ -3,6 +3,7 @@ use alloc::format;
 use crate as burn;
 
 use crate::config::Config;
+use crate::module::forward_hook;
 use crate::module::Content;
 use crate::module::DisplaySettings;
 use crate::module::Module;
@@ -142,7 +143,7 @@ impl<B: Backend> ConvTranspose3d<B> {
     /// - input: `[batch_size, channels_in, depth_in, height_in, width_in]`
     /// - output: `[batch_size, channels_out, depth_out, height_out, width_out]`
     pub fn forward(&self, input: Tensor<B, 5>) -> Tensor<B, 5> {
-        conv_transpose3d(
+        let output = conv_transpose3d(
             input,
             self.weight.val(),
             self.bias.as_ref().map(|bias| bias.val()),
@@ -153,7 +154,9 @@ impl<B: Backend> ConvTranspose3d<B> {
                 self.dilation,
                 self.groups,
             ),
-        )
+        );
+
+        forward_hook(self, output)
     }
 }
 
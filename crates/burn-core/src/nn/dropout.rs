@@ -3,7 +3,8 @@ use crate as burn;
 use crate::config::Config;
 use crate::module::{Content, DisplaySettings, Module, ModuleDisplay};
 use crate::tensor::backend::Backend;
-use crate::tensor::{Distribution, Tensor};
+use crate::tensor::module::dropout;
+use crate::tensor::Tensor;
 
 /// Configuration to create a [Dropout](Dropout) layer using the [init function](DropoutConfig::init).
 #[derive(Config, Debug)]
@@ -48,11 +49,7 @@ impl Dropout {
             return input;
         }
 
-        let prob_keep = 1.0 - self.prob;
-        let random = input.random_like(Distribution::Bernoulli(prob_keep));
-        let x = input * random;
-
-        x * (1.0 / prob_keep)
+        dropout(input, self.prob)
     }
 }
 
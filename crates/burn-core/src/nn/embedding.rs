@@ -4,7 +4,8 @@ use super::Initializer;
 use crate::config::Config;
 use crate::module::Module;
 use crate::module::Param;
-use crate::module::{Content, DisplaySettings, ModuleDisplay};
+use crate::module::{forward_hook, Content, DisplaySettings, ModuleDisplay};
+use crate::per_sample;
 use crate::tensor::backend::Backend;
 use crate::tensor::Int;
 use crate::tensor::Tensor;
@@ -71,7 +72,11 @@ impl<B: Backend> Embedding<B> {
     /// - input: `[batch_size, seq_length]`
     /// - output: `[batch_size, d_model]`
     pub fn forward(&self, input: Tensor<B, 2, Int>) -> Tensor<B, 3> {
-        embedding(self.weight.val(), input)
+        let output = embedding(self.weight.val(), input.clone());
+
+        let output = per_sample::embedding(&self.weight, &input, output);
+
+        forward_hook(self, output)
     }
 }
 
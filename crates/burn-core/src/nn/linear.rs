@@ -2,7 +2,8 @@ use crate as burn;
 
 use crate::config::Config;
 use crate::module::Param;
-use crate::module::{Content, DisplaySettings, Module, ModuleDisplay};
+use crate::module::{forward_hook, Content, DisplaySettings, Module, ModuleDisplay};
+use crate::per_sample;
 use crate::tensor::{backend::Backend, Tensor};
 
 use super::Initializer;
@@ -75,12 +76,15 @@ impl<B: Backend> Linear<B> {
             return Self::forward::<2>(self, input.unsqueeze()).flatten(0, 1);
         }
 
-        let output = input.matmul(self.weight.val().unsqueeze());
-
-        match &self.bias {
+        let output = input.clone().matmul(self.weight.val().unsqueeze());
+        let output = match &self.bias {
             Some(bias) => output + bias.val().unsqueeze(),
             None => output,
-        }
+        };
+
+        let output = per_sample::linear(&self.weight, self.bias.as_ref(), &input, output);
+
+        forward_hook(self, output)
     }
 }
 
@@ -0,0 +1,424 @@
+use crate as burn;
+
+use super::LoraConfig;
+use crate::module::{Module, ModuleMapper, ModuleVisitor, Param, ParamId};
+use crate::nn::Initializer;
+use crate::tensor::{backend::Backend, Shape, Tensor};
+use alloc::{string::String, vec::Vec};
+
+/// The low-rank update of a weight of a [LoRA module](Lora).
+#[derive(Module, Debug)]
+pub struct LoraAdapter<B: Backend> {
+    /// The `A` matrix, of shape `[d_input, rank]` for a weight of shape `[d_input, d_output]`,
+    /// and of shape `[rank, channels_in * kernel_size_1 * kernel_size_2]` for a convolution
+    /// weight of shape `[channels_out, channels_in, kernel_size_1, kernel_size_2]`.
+    pub a: Param<Tensor<B, 2>>,
+    /// The `B` matrix, initialized with zeros, of shape `[rank, d_output]` for a weight of shape
+    /// `[d_input, d_output]`, and of shape `[channels_out, rank]` for a convolution weight.
+    pub b: Param<Tensor<B, 2>>,
+}
+
+impl<B: Backend> LoraAdapter<B> {
+    /// The update of a weight of the given shape, without scaling.
+    pub fn delta<const D: usize>(&self, shape: Shape<D>) -> Tensor<B, D> {
+        match D {
+            2 => self.a.val().matmul(self.b.val()).reshape(shape),
+            _ => self.b.val().matmul(self.a.val()).reshape(shape),
+        }
+    }
+}
+
+/// The adapters of a [LoRA module](Lora), which can be saved and loaded without the base
+/// module, so that the checkpoints only contain the adapter weights.
+#[derive(Module, Debug)]
+pub struct LoraAdapters<B: Backend> {
+    /// The adapters of the float parameters of the module, in the order they are visited, which
+    /// are `None` for the parameters that aren't adapted.
+    pub params: Vec<Option<LoraAdapter<B>>>,
+    /// The scaling of the updates, `alpha / rank`.
+    pub scaling: f64,
+}
+
+/// A module whose weights are adapted with low-rank updates, created with [apply_lora].
+///
+/// The base module is frozen, and the forward pass runs on the [adapted](Lora::adapted) module,
+/// whose weights are `W + (alpha / rank) * delta`, while the optimizer updates this one, so that
+/// only the adapters are trained. Unlike [LoraLinear](super::LoraLinear) and
+/// [LoraConv2d](super::LoraConv2d), the updates are applied to the weights, so the
+/// [dropout](LoraConfig::dropout) isn't supported.
+///
+/// # Example
+///
+/// ```rust, ignore
+/// let lora = apply_lora(model, &["encoder.*.query", "encoder.*.value"], &LoraConfig::new(8));
+///
+/// let output = lora.adapted().forward(input);
+/// let grads = GradientsParams::from_grads(loss.backward(), &lora);
+/// let lora = optim.step(lr, lora, grads);
+///
+/// // Only save the adapters, and load them with the base model.
+/// lora.adapters.clone().save_file(path, &recorder)?;
+/// let lora = apply_lora(model, &["encoder.*.query", "encoder.*.value"], &LoraConfig::new(8))
+///     .load_adapters(recorder.load(path.into(), &device)?);
+/// ```
+#[derive(Module, Debug)]
+pub struct Lora<B: Backend, M> {
+    /// The frozen module.
+    pub module: M,
+    /// The adapters of the weights of the module.
+    pub adapters: LoraAdapters<B>,
+}
+
+/// Adapt the weights of the layers of a module matching one of the path patterns with low-rank
+/// updates.
+///
+/// The path of a layer is the path of its fields in the module separated with dots, such as
+/// `encoder.layers.0.query`, and a `*` of a pattern matches any part of a path. The weights of
+/// the matching layers are the parameters named `weight` of rank 2, such as the weights of the
+/// [linear](crate::nn::Linear) layers, and of rank 4, such as the weights of the
+/// [2D convolution](crate::nn::conv::Conv2d) layers.
+pub fn apply_lora<B: Backend, M: Module<B>>(
+    module: M,
+    patterns: &[&str],
+    config: &LoraConfig,
+) -> Lora<B, M> {
+    let module = module.no_grad();
+    let mut visitor = Targets::new(patterns, config);
+    module.visit(&mut visitor);
+
+    Lora {
+        module,
+        adapters: LoraAdapters {
+            params: visitor.adapters,
+            scaling: config.scaling(),
+        },
+    }
+}
+
+impl<B: Backend, M: Module<B>> Lora<B, M> {
+    /// The module with the updates added to its weights, used for the forward pass.
+    ///
+    /// The weights of the adapted module aren't leaves of the graph, so the gradients must be
+    /// collected from the LoRA module.
+    pub fn adapted(&self) -> M {
+        self.module
+            .clone()
+            .map(&mut DeltaMapper::new(&self.adapters, false))
+    }
+
+    /// Fold the updates into the weights of the module, such as for inference.
+    pub fn merge(self) -> M {
+        self.module.map(&mut DeltaMapper::new(&self.adapters, true))
+    }
+
+    /// Load the adapters saved from a module adapted with the same patterns.
+    pub fn load_adapters(self, record: <LoraAdapters<B> as Module<B>>::Record) -> Self {
+        Self {
+            module: self.module,
+            adapters: self.adapters.load_record(record),
+        }
+    }
+}
+
+/// Create the adapters of the float parameters of a module matching the patterns.
+struct Targets<'a, B: Backend> {
+    patterns: &'a [&'a str],
+    config: &'a LoraConfig,
+    path: Vec<String>,
+    adapters: Vec<Option<LoraAdapter<B>>>,
+}
+
+impl<'a, B: Backend> Targets<'a, B> {
+    fn new(patterns: &'a [&'a str], config: &'a LoraConfig) -> Self {
+        Self {
+            patterns,
+            config,
+            path: Vec::new(),
+            adapters: Vec::new(),
+        }
+    }
+
+    fn is_target(&self) -> bool {
+        let Some((name, path)) = self.path.split_last() else {
+            return false;
+        };
+        let path = path.join(".");
+
+        name == "weight"
+            && self
+                .patterns
+                .iter()
+                .any(|pattern| matches_pattern(pattern, &path))
+    }
+}
+
+impl<B: Backend> ModuleVisitor<B> for Targets<'_, B> {
+    fn visit_float<const D: usize>(&mut self, _id: &ParamId, tensor: &Tensor<B, D>) {
+        let adapter = match D {
+            2 | 4 if self.is_target() => {
+                let dims = tensor.dims();
+                let device = tensor.device();
+                let rank = self.config.rank;
+
+                // The input dimensions of the weights are the first one of the linear weights,
+                // and the last ones of the convolution weights.
+                let (d_input, d_output) = match D {
+                    2 => (dims[0], dims[1]),
+                    _ => (dims[1..].iter().product(), dims[0]),
+                };
+                let a_shape = match D {
+                    2 => [d_input, rank],
+                    _ => [rank, d_input],
+                };
+                let b_shape = match D {
+                    2 => [rank, d_output],
+                    _ => [d_output, rank],
+                };
+
+                Some(LoraAdapter {
+                    a: self.config.initializer.init_with(
+                        a_shape,
+                        Some(d_input),
+                        Some(rank),
+                        &device,
+                    ),
+                    b: Initializer::Zeros.init(b_shape, &device),
+                })
+            }
+            _ => None,
+        };
+
+        self.adapters.push(adapter);
+    }
+
+    fn enter_module(&mut self, name: &str) {
+        self.path.push(name.into());
+    }
+
+    fn exit_module(&mut self, _name: &str) {
+        self.path.pop();
+    }
+}
+
+/// Add the updates to the float parameters of a module, in the order they are visited.
+struct DeltaMapper<'a, B: Backend> {
+    adapters: &'a LoraAdapters<B>,
+    index: usize,
+    merge: bool,
+}
+
+impl<'a, B: Backend> DeltaMapper<'a, B> {
+    fn new(adapters: &'a LoraAdapters<B>, merge: bool) -> Self {
+        Self {
+            adapters,
+            index: 0,
+            merge,
+        }
+    }
+}
+
+impl<B: Backend> ModuleMapper<B> for DeltaMapper<'_, B> {
+    fn map_float<const D: usize>(&mut self, _id: &ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        let adapter = self.adapters.params.get(self.index);
+        self.index += 1;
+
+        let Some(Some(adapter)) = adapter else {
+            return tensor;
+        };
+        let delta = adapter
+            .delta(tensor.shape())
+            .mul_scalar(self.adapters.scaling);
+
+        match self.merge {
+            true => {
+                let is_require_grad = tensor.is_require_grad();
+                (tensor.detach() + delta.detach()).set_require_grad(is_require_grad)
+            }
+            false => tensor + delta,
+        }
+    }
+}
+
+/// If the path matches the pattern, where a `*` matches any part of the path.
+fn matches_pattern(pattern: &str, path: &str) -> bool {
+    match pattern.split_once('*') {
+        None => pattern == path,
+        Some((prefix, rest)) => path.strip_prefix(prefix).is_some_and(|path| {
+            path.char_indices()
+                .map(|(i, _)| i)
+                .chain([path.len()])
+                .any(|i| matches_pattern(rest, &path[i..]))
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::{
+        conv::{Conv2d, Conv2dConfig},
+        Linear, LinearConfig,
+    };
+    use crate::tensor::Distribution;
+    use crate::TestBackend;
+
+    #[derive(Module, Debug)]
+    struct Block<B: Backend> {
+        query: Linear<B>,
+        value: Linear<B>,
+    }
+
+    #[derive(Module, Debug)]
+    struct Model<B: Backend> {
+        conv: Conv2d<B>,
+        blocks: Vec<Block<B>>,
+        output: Linear<B>,
+    }
+
+    impl<B: Backend> Model<B> {
+        fn new(device: &B::Device) -> Self {
+            let block = || Block {
+                query: LinearConfig::new(8, 8).init(device),
+                value: LinearConfig::new(8, 8).init(device),
+            };
+
+            Self {
+                conv: Conv2dConfig::new([2, 8], [3, 3]).init(device),
+                blocks: vec![block(), block()],
+                output: LinearConfig::new(8, 3).init(device),
+            }
+        }
+
+        fn forward(&self, input: Tensor<B, 4>) -> Tensor<B, 2> {
+            let x = self
+                .conv
+                .forward(input)
+                .mean_dim(3)
+                .mean_dim(2)
+                .flatten::<2>(1, 3);
+            let x = self.blocks.iter().fold(x, |x, block| {
+                block.value.forward(x.clone()) * block.query.forward(x).tanh()
+            });
+
+            self.output.forward(x)
+        }
+    }
+
+    /// Randomize the `B` matrices, which are zero.
+    fn randomize<B: Backend, M: Module<B>>(lora: Lora<B, M>) -> Lora<B, M> {
+        let params = lora
+            .adapters
+            .params
+            .into_iter()
+            .map(|adapter| {
+                adapter.map(|adapter| LoraAdapter {
+                    a: adapter.a,
+                    b: adapter.b.map(|b| b.random_like(Distribution::Default)),
+                })
+            })
+            .collect();
+
+        Lora {
+            module: lora.module,
+            adapters: LoraAdapters {
+                params,
+                scaling: lora.adapters.scaling,
+            },
+        }
+    }
+
+    #[test]
+    fn should_match_the_paths_with_the_patterns() {
+        assert!(matches_pattern("blocks.*.query", "blocks.0.query"));
+        assert!(matches_pattern("*query", "blocks.1.query"));
+        assert!(matches_pattern("*", "conv"));
+        assert!(!matches_pattern("blocks.*.query", "blocks.0.value"));
+        assert!(!matches_pattern("conv", "blocks.0.conv"));
+
+        let model = Model::<TestBackend>::new(&Default::default());
+        let lora = apply_lora(model, &["blocks.*.query", "conv"], &LoraConfig::new(2));
+
+        // The conv weight and bias, then the query and value weights and biases of the blocks,
+        // then the output weight and bias.
+        let adapted = lora
+            .adapters
+            .params
+            .iter()
+            .map(|adapter| adapter.is_some())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            adapted,
+            [true, false, true, false, false, false, true, false, false, false, false, false]
+        );
+    }
+
+    #[test]
+    fn merged_model_should_match_the_adapted_model() {
+        let device = Default::default();
+        let model = Model::<TestBackend>::new(&device);
+        let input = Tensor::<TestBackend, 4>::random([2, 2, 5, 5], Distribution::Default, &device);
+        let expected = model.forward(input.clone());
+
+        let lora = apply_lora(model, &["*"], &LoraConfig::new(2).with_alpha(4.0));
+        lora.adapted()
+            .forward(input.clone())
+            .into_data()
+            .assert_approx_eq(&expected.clone().into_data(), 5);
+
+        let lora = randomize(lora);
+        let adapted = lora.adapted().forward(input.clone());
+        lora.merge()
+            .forward(input)
+            .into_data()
+            .assert_approx_eq(&adapted.into_data(), 4);
+    }
+
+    #[test]
+    fn adapters_should_round_trip_through_records_without_the_base_model() {
+        use crate::record::{BinBytesRecorder, FullPrecisionSettings, Recorder};
+
+        let device = Default::default();
+        let model = Model::<TestBackend>::new(&device);
+        let input = Tensor::<TestBackend, 4>::random([2, 2, 5, 5], Distribution::Default, &device);
+        let patterns = ["blocks.*"];
+        let config = LoraConfig::new(2);
+        let lora = randomize(apply_lora(model.clone(), &patterns, &config));
+        let recorder = BinBytesRecorder::<FullPrecisionSettings>::default();
+
+        let adapters = recorder
+            .record(lora.adapters.clone().into_record(), ())
+            .unwrap();
+        let model_bytes = recorder.record(model.clone().into_record(), ()).unwrap();
+        assert!(adapters.len() < model_bytes.len());
+
+        let loaded = apply_lora(model, &patterns, &config)
+            .load_adapters(recorder.load(adapters, &device).unwrap());
+        loaded
+            .adapted()
+            .forward(input.clone())
+            .into_data()
+            .assert_approx_eq(&lora.adapted().forward(input).into_data(), 5);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn only_the_adapters_should_receive_gradients() {
+        use crate::optim::GradientsParams;
+        use crate::TestAutodiffBackend;
+
+        let device = Default::default();
+        let model = Model::<TestAutodiffBackend>::new(&device);
+        let lora = apply_lora(model, &["blocks.*"], &LoraConfig::new(2));
+        let input =
+            Tensor::<TestAutodiffBackend, 4>::random([2, 2, 5, 5], Distribution::Default, &device);
+
+        let loss = lora.adapted().forward(input).powf_scalar(2.0).mean();
+        let grads = GradientsParams::from_grads(loss.backward(), &lora);
+
+        assert_eq!(grads.len(), 8);
+        for adapter in lora.adapters.params.iter().flatten() {
+            assert!(grads.get::<TestBackend, 2>(&adapter.a.id).is_some());
+            assert!(grads.get::<TestBackend, 2>(&adapter.b.id).is_some());
+        }
+    }
+}
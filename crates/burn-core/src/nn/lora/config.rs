@@ -0,0 +1,89 @@
+use crate as burn;
+
+use super::{LoraConv2d, LoraLinear};
+use crate::config::Config;
+use crate::module::Module;
+use crate::nn::conv::{Conv2d, Conv2dConfig};
+use crate::nn::{DropoutConfig, Initializer, Linear, LinearConfig};
+use crate::tensor::backend::Backend;
+
+/// Configuration to create low-rank adapters, using [init_linear](LoraConfig::init_linear),
+/// [init_conv2d](LoraConfig::init_conv2d) or [apply_lora](super::apply_lora).
+#[derive(Config, Debug)]
+pub struct LoraConfig {
+    /// The rank of the adapters.
+    pub rank: usize,
+    /// The scaling of the adapters, their output being multiplied by `alpha / rank`.
+    #[config(default = 1.0)]
+    pub alpha: f64,
+    /// The probability of the dropout applied to the input of the adapters.
+    #[config(default = 0.0)]
+    pub dropout: f64,
+    /// The initializer of the `A` matrices, the `B` matrices being initialized with zeros so that
+    /// the adapted layers start as the base layers.
+    #[config(
+        default = "Initializer::KaimingUniform{gain:1.0/num_traits::Float::sqrt(3.0), fan_out_only:false}"
+    )]
+    pub initializer: Initializer,
+}
+
+impl LoraConfig {
+    /// Adapt a [linear](Linear) layer, which is frozen.
+    pub fn init_linear<B: Backend>(&self, base: Linear<B>) -> LoraLinear<B> {
+        let [d_input, d_output] = base.weight.dims();
+        let device = base.weight.device();
+
+        LoraLinear {
+            base: base.no_grad(),
+            lora_a: LinearConfig::new(d_input, self.rank)
+                .with_bias(false)
+                .with_initializer(self.initializer.clone())
+                .init(&device),
+            lora_b: LinearConfig::new(self.rank, d_output)
+                .with_bias(false)
+                .with_initializer(Initializer::Zeros)
+                .init(&device),
+            dropout: DropoutConfig::new(self.dropout).init(),
+            scaling: self.scaling(),
+            merged: false,
+        }
+    }
+
+    /// Adapt a [2D convolution](Conv2d) layer, which is frozen.
+    ///
+    /// # Panics
+    ///
+    /// If the convolution has groups.
+    pub fn init_conv2d<B: Backend>(&self, base: Conv2d<B>) -> LoraConv2d<B> {
+        assert_eq!(
+            base.groups, 1,
+            "Low-rank adapters of grouped convolutions aren't supported"
+        );
+
+        let [channels_out, channels_in, _, _] = base.weight.dims();
+        let device = base.weight.device();
+
+        LoraConv2d {
+            lora_a: Conv2dConfig::new([channels_in, self.rank], base.kernel_size)
+                .with_stride(base.stride)
+                .with_dilation(base.dilation)
+                .with_padding(base.padding.0.clone())
+                .with_bias(false)
+                .with_initializer(self.initializer.clone())
+                .init(&device),
+            lora_b: Conv2dConfig::new([self.rank, channels_out], [1, 1])
+                .with_bias(false)
+                .with_initializer(Initializer::Zeros)
+                .init(&device),
+            base: base.no_grad(),
+            dropout: DropoutConfig::new(self.dropout).init(),
+            scaling: self.scaling(),
+            merged: false,
+        }
+    }
+
+    /// The scaling of the output of the adapters.
+    pub fn scaling(&self) -> f64 {
+        self.alpha / self.rank as f64
+    }
+}
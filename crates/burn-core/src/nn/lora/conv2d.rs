@@ -0,0 +1,153 @@
+use crate as burn;
+
+use super::linear::add_delta;
+use crate::module::Module;
+use crate::nn::conv::Conv2d;
+use crate::nn::Dropout;
+use crate::tensor::{backend::Backend, Tensor};
+
+/// A [2D convolution](Conv2d) layer with a low-rank adapter, created with
+/// [LoraConfig](super::LoraConfig::init_conv2d).
+///
+/// The base layer is frozen, only the `A` and `B` convolutions of the adapter being trained:
+/// `A` has the kernel, stride, dilation and padding of the base layer and outputs `rank`
+/// channels, and `B` is a `1x1` convolution to the output channels.
+///
+/// `O = base(I) + (alpha / rank) * B(A(dropout(I)))`
+#[derive(Module, Debug)]
+pub struct LoraConv2d<B: Backend> {
+    /// The frozen layer.
+    pub base: Conv2d<B>,
+    /// The convolution of the input to the rank of the adapter, without bias.
+    pub lora_a: Conv2d<B>,
+    /// The `1x1` convolution of the adapter to the output, without bias, initialized with zeros.
+    pub lora_b: Conv2d<B>,
+    /// The dropout applied to the input of the adapter.
+    pub dropout: Dropout,
+    /// The scaling of the output of the adapter, `alpha / rank`.
+    pub scaling: f64,
+    /// If the adapter is [merged](LoraConv2d::merge) into the weight of the base layer.
+    ///
+    /// It isn't saved with the records, so a merged layer must be loaded into a merged layer.
+    pub merged: bool,
+}
+
+impl<B: Backend> LoraConv2d<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, channels_in, height_in, width_in]`
+    /// - output: `[batch_size, channels_out, height_out, width_out]`
+    pub fn forward(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+        if self.merged {
+            return self.base.forward(input);
+        }
+
+        let adapter = self
+            .lora_b
+            .forward(self.lora_a.forward(self.dropout.forward(input.clone())));
+
+        self.base.forward(input) + adapter.mul_scalar(self.scaling)
+    }
+
+    /// Fold the adapter into the weight of the base layer, so that the forward pass only runs the
+    /// base layer, such as for inference.
+    pub fn merge(self) -> Self {
+        if self.merged {
+            return self;
+        }
+
+        self.update(1.0, true)
+    }
+
+    /// Remove the adapter from the weight of the base layer, after it is [merged](Self::merge).
+    pub fn unmerge(self) -> Self {
+        if !self.merged {
+            return self;
+        }
+
+        self.update(-1.0, false)
+    }
+
+    /// The update of the weight of the base layer, the product of the `B` and `A` kernels.
+    pub fn delta(&self) -> Tensor<B, 4> {
+        let [rank, channels_in, kernel_0, kernel_1] = self.lora_a.weight.dims();
+        let [channels_out, _, _, _] = self.lora_b.weight.dims();
+
+        self.lora_b
+            .weight
+            .val()
+            .reshape([channels_out, rank])
+            .matmul(
+                self.lora_a
+                    .weight
+                    .val()
+                    .reshape([rank, channels_in * kernel_0 * kernel_1]),
+            )
+            .reshape([channels_out, channels_in, kernel_0, kernel_1])
+            .mul_scalar(self.scaling)
+    }
+
+    fn update(self, sign: f64, merged: bool) -> Self {
+        let delta = self.delta().mul_scalar(sign);
+        let base = Conv2d {
+            weight: add_delta(self.base.weight, delta),
+            ..self.base
+        };
+
+        Self {
+            base,
+            merged,
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::{conv::Conv2dConfig, lora::LoraConfig, PaddingConfig2d};
+    use crate::tensor::Distribution;
+    use crate::TestBackend;
+
+    #[test]
+    fn merged_output_should_match_the_adapter_output() {
+        let device = Default::default();
+        let base = Conv2dConfig::new([3, 5], [3, 3])
+            .with_stride([2, 1])
+            .with_dilation([1, 2])
+            .with_padding(PaddingConfig2d::Explicit(1, 2))
+            .init::<TestBackend>(&device);
+        let mut lora = LoraConfig::new(2).with_alpha(3.0).init_conv2d(base);
+        lora.lora_b.weight = lora
+            .lora_b
+            .weight
+            .map(|weight| weight.random_like(Distribution::Default));
+        let input = Tensor::<TestBackend, 4>::random([2, 3, 7, 8], Distribution::Default, &device);
+
+        let expected = lora.forward(input.clone());
+        assert_ne!(
+            (expected.clone() - lora.base.forward(input.clone()))
+                .abs()
+                .sum()
+                .into_scalar(),
+            0.0
+        );
+
+        lora.merge()
+            .forward(input)
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 4);
+    }
+
+    #[test]
+    #[should_panic = "grouped convolutions"]
+    fn should_panic_with_grouped_convolutions() {
+        let base = Conv2dConfig::new([4, 4], [3, 3])
+            .with_groups(2)
+            .init::<TestBackend>(&Default::default());
+
+        LoraConfig::new(2).init_conv2d(base);
+    }
+}
@@ -0,0 +1,195 @@
+use crate as burn;
+
+use crate::module::{Module, Param};
+use crate::nn::{Dropout, Linear};
+use crate::tensor::{backend::Backend, Tensor};
+
+/// A [linear](Linear) layer with a low-rank adapter, created with
+/// [LoraConfig](super::LoraConfig::init_linear).
+///
+/// The base layer is frozen, only the `A` and `B` matrices of the adapter being trained:
+///
+/// `O = base(I) + (alpha / rank) * B(A(dropout(I)))`
+#[derive(Module, Debug)]
+pub struct LoraLinear<B: Backend> {
+    /// The frozen layer.
+    pub base: Linear<B>,
+    /// The projection of the input to the rank of the adapter, without bias.
+    pub lora_a: Linear<B>,
+    /// The projection of the adapter to the output, without bias, initialized with zeros.
+    pub lora_b: Linear<B>,
+    /// The dropout applied to the input of the adapter.
+    pub dropout: Dropout,
+    /// The scaling of the output of the adapter, `alpha / rank`.
+    pub scaling: f64,
+    /// If the adapter is [merged](LoraLinear::merge) into the weight of the base layer.
+    ///
+    /// It isn't saved with the records, so a merged layer must be loaded into a merged layer.
+    pub merged: bool,
+}
+
+impl<B: Backend> LoraLinear<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[..., d_input]`
+    /// - output: `[..., d_output]`
+    pub fn forward<const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        if self.merged {
+            return self.base.forward(input);
+        }
+
+        let adapter = self
+            .lora_b
+            .forward(self.lora_a.forward(self.dropout.forward(input.clone())));
+
+        self.base.forward(input) + adapter.mul_scalar(self.scaling)
+    }
+
+    /// Fold the adapter into the weight of the base layer, so that the forward pass only runs the
+    /// base layer, such as for inference.
+    pub fn merge(self) -> Self {
+        if self.merged {
+            return self;
+        }
+
+        self.update(1.0, true)
+    }
+
+    /// Remove the adapter from the weight of the base layer, after it is [merged](Self::merge).
+    pub fn unmerge(self) -> Self {
+        if !self.merged {
+            return self;
+        }
+
+        self.update(-1.0, false)
+    }
+
+    /// The update of the weight of the base layer.
+    pub fn delta(&self) -> Tensor<B, 2> {
+        self.lora_a
+            .weight
+            .val()
+            .matmul(self.lora_b.weight.val())
+            .mul_scalar(self.scaling)
+    }
+
+    fn update(self, sign: f64, merged: bool) -> Self {
+        let delta = self.delta().mul_scalar(sign);
+        let base = Linear {
+            weight: add_delta(self.base.weight, delta),
+            ..self.base
+        };
+
+        Self {
+            base,
+            merged,
+            ..self
+        }
+    }
+}
+
+/// Add the update to the frozen weight, keeping it a leaf of the graph.
+pub(crate) fn add_delta<B: Backend, const D: usize>(
+    weight: Param<Tensor<B, D>>,
+    delta: Tensor<B, D>,
+) -> Param<Tensor<B, D>> {
+    weight.map(|weight| {
+        let is_require_grad = weight.is_require_grad();
+        (weight.detach() + delta.clone().detach()).set_require_grad(is_require_grad)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::{lora::LoraConfig, LinearConfig};
+    use crate::tensor::Distribution;
+    use crate::TestBackend;
+
+    fn lora_linear(device: &<TestBackend as Backend>::Device) -> LoraLinear<TestBackend> {
+        let base = LinearConfig::new(6, 4).init(device);
+        let mut lora = LoraConfig::new(2).with_alpha(4.0).init_linear(base);
+        // The zero initialization of `B` would make the adapter vanish.
+        lora.lora_b.weight = lora
+            .lora_b
+            .weight
+            .map(|weight| weight.random_like(Distribution::Default));
+
+        lora
+    }
+
+    #[test]
+    fn adapter_should_start_as_the_base_layer() {
+        let device = Default::default();
+        let base = LinearConfig::new(6, 4).init::<TestBackend>(&device);
+        let lora = LoraConfig::new(2).init_linear(base.clone());
+        let input = Tensor::<TestBackend, 3>::random([2, 3, 6], Distribution::Default, &device);
+
+        lora.forward(input.clone())
+            .into_data()
+            .assert_approx_eq(&base.forward(input).into_data(), 5);
+    }
+
+    #[test]
+    fn merged_output_should_match_the_adapter_output() {
+        let device = Default::default();
+        let lora = lora_linear(&device);
+        let weight = lora.base.weight.val();
+        let input = Tensor::<TestBackend, 3>::random([2, 3, 6], Distribution::Default, &device);
+
+        let expected = lora.forward(input.clone());
+        let merged = lora.merge();
+        assert!(merged.merged);
+        merged
+            .forward(input)
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 4);
+
+        merged
+            .unmerge()
+            .base
+            .weight
+            .val()
+            .into_data()
+            .assert_approx_eq(&weight.into_data(), 5);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn only_the_adapter_should_receive_gradients() {
+        use crate::optim::{GradientsParams, Optimizer, SgdConfig};
+        use crate::TestAutodiffBackend;
+
+        let device = Default::default();
+        let base = LinearConfig::new(6, 4).init::<TestAutodiffBackend>(&device);
+        let mut lora = LoraConfig::new(2).with_dropout(0.1).init_linear(base);
+        let weight = lora.base.weight.val().into_data();
+        let mut optim = SgdConfig::new().init();
+
+        for _ in 0..2 {
+            let input =
+                Tensor::<TestAutodiffBackend, 2>::random([8, 6], Distribution::Default, &device);
+            let loss = lora.forward(input).powf_scalar(2.0).mean();
+            let grads = GradientsParams::from_grads(loss.backward(), &lora);
+
+            assert!(grads
+                .get::<TestBackend, 2>(&lora.lora_a.weight.id)
+                .is_some());
+            assert!(grads
+                .get::<TestBackend, 2>(&lora.lora_b.weight.id)
+                .is_some());
+            assert!(grads.get::<TestBackend, 2>(&lora.base.weight.id).is_none());
+            assert!(grads
+                .get::<TestBackend, 1>(&lora.base.bias.as_ref().unwrap().id)
+                .is_none());
+
+            lora = optim.step(1e-1, lora, grads);
+        }
+
+        lora.base.weight.val().into_data().assert_eq(&weight, false);
+        // The zero `B` matrix is trained away from zero.
+        assert_ne!(lora.lora_b.weight.val().abs().sum().into_scalar(), 0.0);
+    }
+}
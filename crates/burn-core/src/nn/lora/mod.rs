@@ -0,0 +1,9 @@
+mod apply;
+mod config;
+mod conv2d;
+mod linear;
+
+pub use apply::*;
+pub use config::*;
+pub use conv2d::*;
+pub use linear::*;
@@ -1,6 +1,9 @@
 /// Attention module
 pub mod attention;
 
+/// Audio module
+pub mod audio;
+
 /// Cache module
 pub mod cache;
 
@@ -19,6 +22,15 @@ pub mod transformer;
 /// Interpolate module
 pub mod interpolate;
 
+/// Low-rank adaptation module
+pub mod lora;
+
+/// Quantization-aware training module
+pub mod qat;
+
+/// Vision module
+pub mod vision;
+
 mod dropout;
 mod embedding;
 mod gelu;
@@ -1,5 +1,5 @@
 use crate as burn;
-use crate::module::{Content, DisplaySettings, ModuleDisplay};
+use crate::module::{forward_hook, Content, DisplaySettings, ModuleDisplay};
 
 use crate::nn::Initializer;
 use crate::{
@@ -13,25 +13,34 @@ use crate::{
 pub struct BatchNormConfig {
     /// The number of features.
     pub num_features: usize,
-    /// A value required for numerical stability. Default: 1e-5
+    /// A value required for numerical stability, added to the variance under the square root.
+    /// Default: 1e-5
     #[config(default = 1e-5)]
     pub epsilon: f64,
-    /// Momentum used to update the metrics. Default: 0.1
+    /// Momentum used to update the metrics, following the PyTorch convention:
+    /// `running = (1 - momentum) * running + momentum * batch`. Default: 0.1
+    ///
+    /// The ONNX convention is the opposite, its momentum being `1 - momentum`.
     #[config(default = 0.1)]
     pub momentum: f64,
 }
 
 /// Applies Batch Normalization over a tensor as described in the paper [Batch Normalization](https://arxiv.org/abs/1502.03167)
 ///
-/// `Y = norm(X) * γ + β`
+/// `Y = (X - mean) / sqrt(var + ε) * γ + β`
 ///
 /// Where:
 /// - `X` is the input tensor
 /// - `Y` is the output tensor
-/// - `norm` is the normalization function
+/// - `mean` and `var` are the statistics of each channel
+/// - `ε` is the epsilon
 /// - `γ` is the learnable weight
 /// - `β` is the learnable bias
 ///
+/// In training, the statistics are the ones of the batch, the variance being biased, and the
+/// running variance is updated from the unbiased variance of the batch like in PyTorch. In
+/// inference, the running statistics are used.
+///
 /// Should be created using [BatchNormConfig].
 #[derive(Module, Debug)]
 #[module(custom_display)]
@@ -96,10 +105,12 @@ impl<const D: usize, B: Backend> BatchNorm<B, D> {
             );
         }
 
-        match B::ad_enabled() {
+        let output = match B::ad_enabled() {
             true => self.forward_train(input),
             false => self.forward_inference(input),
-        }
+        };
+
+        forward_hook(self, output)
     }
 
     fn forward_inference<const DI: usize>(&self, input: Tensor<B, DI>) -> Tensor<B, DI> {
@@ -147,17 +158,17 @@ impl<const D: usize, B: Backend> BatchNorm<B, D> {
         let running_mean = self.running_mean.value_sync().to_device(&device);
         let running_var = self.running_var.value_sync().to_device(&device);
 
-        let running_mean = running_mean.mul_scalar(1.0 - self.momentum).add(
-            mean.clone()
-                .detach()
-                .mul_scalar(self.momentum)
-                .reshape([channels]),
-        );
-        let running_var = running_var.mul_scalar(1.0 - self.momentum).add(
-            var.clone()
-                .detach()
-                .mul_scalar(self.momentum)
-                .reshape([channels]),
+        let running_mean =
+            running_mean.lerp_scalar(mean.clone().detach().reshape([channels]), self.momentum);
+        // The running variance is unbiased, while the batch is normalized with the biased one.
+        let correction = match flatten_size {
+            1 => 1.0,
+            _ => flatten_size as f64 / (flatten_size - 1) as f64,
+        };
+        let running_var = running_var.axpby(
+            1.0 - self.momentum,
+            var.clone().detach().reshape([channels]),
+            self.momentum * correction,
         );
 
         self.running_mean.update(running_mean.detach());
@@ -247,7 +258,7 @@ mod tests_1d {
             [[0.9409, 0.6976], [0.5892, 0.8774], [0.9106, 0.6844]],
             [[0.6012, 0.0782], [-0.0394, 0.9270], [0.6181, 0.5492]],
         ]);
-        output.to_data().assert_approx_eq(&expected, 2);
+        output.to_data().assert_approx_eq(&expected, 4);
     }
 
     fn input_tensor<B: Backend>(device: &B::Device) -> Tensor<B, 3> {
@@ -311,7 +322,7 @@ mod tests_2d {
                 [[0.6250, 0.5561], [0.5013, 0.4323]],
             ],
         ]);
-        output.to_data().assert_approx_eq(&expected, 2);
+        output.to_data().assert_approx_eq(&expected, 4);
     }
 
     #[test]
@@ -343,7 +354,7 @@ mod tests_2d {
         running_var
             .reshape([3])
             .into_data()
-            .assert_approx_eq(&expected, 2);
+            .assert_approx_eq(&expected, 4);
     }
 
     #[test]
@@ -4,7 +4,7 @@ use crate::nn::Initializer;
 use crate::config::Config;
 use crate::module::Module;
 use crate::module::Param;
-use crate::module::{Content, DisplaySettings, ModuleDisplay};
+use crate::module::{forward_hook, Content, DisplaySettings, ModuleDisplay};
 use crate::tensor::backend::Backend;
 use crate::tensor::Tensor;
 
@@ -15,9 +15,14 @@ pub struct GroupNormConfig {
     pub num_groups: usize,
     /// The number of channels expected in the input
     pub num_channels: usize,
-    /// A value required for numerical stability. Default: 1e-5
+    /// A value required for numerical stability, added to the variance under the square root.
+    /// Default: 1e-5
     #[config(default = 1e-5)]
     pub epsilon: f64,
+    /// Add the epsilon to the standard deviation instead of the variance, as burn did before
+    /// 0.15, to keep the outputs of models trained with an older version. Default: `false`
+    #[config(default = false)]
+    pub epsilon_on_std: bool,
     /// A boolean value that when set to `true`, this module has learnable
     /// per-channel affine parameters initialized to ones (for weights)
     /// and zeros (for biases). Default: `true`
@@ -27,11 +32,13 @@ pub struct GroupNormConfig {
 
 /// Applies Group Normalization over a mini-batch of inputs as described in the paper [Group Normalization](https://arxiv.org/abs/1803.08494).
 ///
-/// `Y = groupnorm(X) * γ + β`
+/// `Y = (X - mean) / sqrt(var + ε) * γ + β`
 ///
 /// Where:
 /// - `X` is the input tensor
 /// - `Y` is the output tensor
+/// - `mean` and `var` are the statistics of each group, the variance being biased
+/// - `ε` is the epsilon
 /// - `γ` is the learnable weight
 /// - `β` is the learnable bias
 ///
@@ -49,6 +56,8 @@ pub struct GroupNorm<B: Backend> {
     pub num_channels: usize,
     /// A value required for numerical stability
     pub epsilon: f64,
+    /// Add the epsilon to the standard deviation instead of the variance
+    pub epsilon_on_std: bool,
     /// A boolean value that when set to `true`, this module has learnable
     pub affine: bool,
 }
@@ -65,6 +74,7 @@ impl<B: Backend> ModuleDisplay for GroupNorm<B> {
             .add("num_groups", &self.num_groups)
             .add("num_channels", &self.num_channels)
             .add("epsilon", &self.epsilon)
+            .add("epsilon_on_std", &self.epsilon_on_std)
             .add("affine", &self.affine)
             .optional()
     }
@@ -94,6 +104,7 @@ impl GroupNormConfig {
             gamma,
             beta,
             epsilon: self.epsilon,
+            epsilon_on_std: self.epsilon_on_std,
             affine: self.affine,
         }
     }
@@ -120,24 +131,28 @@ impl<B: Backend> GroupNorm<B> {
         let gamma = self.gamma.as_ref().map(|x| x.val());
         let beta = self.beta.as_ref().map(|x| x.val());
 
-        group_norm(
+        let output = group_norm(
             input,
             gamma,
             beta,
             self.num_groups,
             self.epsilon,
+            self.epsilon_on_std,
             self.affine,
-        )
+        );
+
+        forward_hook(self, output)
     }
 }
 
 /// Applies Group Normalization over a mini-batch of inputs as described in the paper [Group Normalization](https://arxiv.org/abs/1803.08494).
 ///
-/// `Y = groupnorm(X) * γ + β`
+/// `Y = (X - mean) / sqrt(var + ε) * γ + β`
 ///
 /// Where:
 /// - `X` is the input tensor
 /// - `Y` is the output tensor
+/// - `ε` is the epsilon, added to the standard deviation instead when `epsilon_on_std` is set
 /// - `γ` is the learnable weight
 /// - `β` is the learnable bias
 ///
@@ -147,6 +162,7 @@ pub(crate) fn group_norm<B: Backend, const D: usize>(
     beta: Option<Tensor<B, 1>>,
     num_groups: usize,
     epsilon: f64,
+    epsilon_on_std: bool,
     affine: bool,
 ) -> Tensor<B, D> {
     if (beta.is_none() || gamma.is_none()) && affine {
@@ -171,7 +187,12 @@ pub(crate) fn group_norm<B: Backend, const D: usize>(
     let input = input.sub(mean);
 
     let var = input.clone().powf_scalar(2.).sum_dim(2) / hidden_size as f64;
-    let input_normalized = input.div(var.sqrt().add_scalar(epsilon));
+    let std = if epsilon_on_std {
+        var.sqrt().add_scalar(epsilon)
+    } else {
+        var.add_scalar(epsilon).sqrt()
+    };
+    let input_normalized = input.div(std);
 
     if affine {
         let mut affine_shape = [1; D];
@@ -316,6 +337,28 @@ mod tests {
         output.to_data().assert_approx_eq(&expected, 3);
     }
 
+    #[test]
+    fn group_norm_should_add_the_epsilon_to_the_variance_or_the_standard_deviation() {
+        let device = Default::default();
+        let input = Tensor::<TestBackend, 3>::from_floats([[[-1.0, 1.0]]], &device);
+        let config = GroupNormConfig::new(1, 1)
+            .with_epsilon(1.0)
+            .with_affine(false);
+
+        let output = config.init::<TestBackend>(&device).forward(input.clone());
+        let output_on_std = config
+            .with_epsilon_on_std(true)
+            .init::<TestBackend>(&device)
+            .forward(input);
+
+        output
+            .to_data()
+            .assert_approx_eq(&TensorData::from([[[-0.70711, 0.70711]]]), 4);
+        output_on_std
+            .to_data()
+            .assert_approx_eq(&TensorData::from([[[-0.5, 0.5]]]), 4);
+    }
+
     #[test]
     fn display() {
         let config = GroupNormConfig::new(3, 6);
@@ -323,7 +366,7 @@ mod tests {
 
         assert_eq!(
             format!("{}", group_norm),
-            "GroupNorm {num_groups: 3, num_channels: 6, epsilon: 0.00001, affine: true, params: 12}"
+            "GroupNorm {num_groups: 3, num_channels: 6, epsilon: 0.00001, epsilon_on_std: false, affine: true, params: 12}"
         );
     }
 }
@@ -1,7 +1,7 @@
 use crate as burn;
 
 use crate::config::Config;
-use crate::module::{Content, DisplaySettings, ModuleDisplay};
+use crate::module::{forward_hook, Content, DisplaySettings, ModuleDisplay};
 use crate::module::{Module, Param};
 use crate::nn::norm::group_norm;
 use crate::nn::Initializer;
@@ -12,9 +12,14 @@ use crate::tensor::{backend::Backend, Tensor};
 pub struct InstanceNormConfig {
     /// The number of channels expected in the input
     pub num_channels: usize,
-    /// A value required for numerical stability. Default: 1e-5
+    /// A value required for numerical stability, added to the variance under the square root.
+    /// Default: 1e-5
     #[config(default = 1e-5)]
     pub epsilon: f64,
+    /// Add the epsilon to the standard deviation instead of the variance, as burn did before
+    /// 0.15, to keep the outputs of models trained with an older version. Default: `false`
+    #[config(default = false)]
+    pub epsilon_on_std: bool,
     /// A boolean value that when set to `true`, this module has learnable
     /// per-channel affine parameters initialized to ones (for weights)
     /// and zeros (for biases). Default: `true`
@@ -24,6 +29,8 @@ pub struct InstanceNormConfig {
 
 /// Applies Instance Normalization over a tensor as described in the paper [Instance Normalization](https://arxiv.org/abs/1607.08022)
 ///
+/// `Y = (X - mean) / sqrt(var + ε) * γ + β`, the statistics being the ones of each channel.
+///
 /// Should be created using [InstanceNormConfig](InstanceNormConfig).
 #[derive(Module, Debug)]
 #[module(custom_display)]
@@ -36,6 +43,8 @@ pub struct InstanceNorm<B: Backend> {
     pub num_channels: usize,
     /// A value required for numerical stability
     pub epsilon: f64,
+    /// Add the epsilon to the standard deviation instead of the variance
+    pub epsilon_on_std: bool,
     /// A boolean value that when set to `true`, this module has learnable
     pub affine: bool,
 }
@@ -51,6 +60,7 @@ impl<B: Backend> ModuleDisplay for InstanceNorm<B> {
         content
             .add("num_channels", &self.num_channels)
             .add("epsilon", &self.epsilon)
+            .add("epsilon_on_std", &self.epsilon_on_std)
             .add("affine", &self.affine)
             .optional()
     }
@@ -73,6 +83,7 @@ impl InstanceNormConfig {
             beta,
             num_channels: self.num_channels,
             epsilon: self.epsilon,
+            epsilon_on_std: self.epsilon_on_std,
             affine: self.affine,
         }
     }
@@ -94,7 +105,17 @@ impl<B: Backend> InstanceNorm<B> {
         let gamma = self.gamma.as_ref().map(|x| x.val());
         let beta = self.beta.as_ref().map(|x| x.val());
 
-        group_norm(input, gamma, beta, num_groups, self.epsilon, self.affine)
+        let output = group_norm(
+            input,
+            gamma,
+            beta,
+            num_groups,
+            self.epsilon,
+            self.epsilon_on_std,
+            self.affine,
+        );
+
+        forward_hook(self, output)
     }
 }
 
@@ -209,6 +230,29 @@ mod tests {
         output.to_data().assert_approx_eq(&expected, 3);
     }
 
+    #[test]
+    fn instance_norm_should_add_the_epsilon_to_the_variance_or_the_standard_deviation() {
+        let device = Default::default();
+        let input = Tensor::<TestBackend, 3>::from_floats([[[-1.0, 1.0], [2.0, 4.0]]], &device);
+        let config = InstanceNormConfig::new(2)
+            .with_epsilon(1.0)
+            .with_affine(false);
+
+        let output = config.init::<TestBackend>(&device).forward(input.clone());
+        let output_on_std = config
+            .with_epsilon_on_std(true)
+            .init::<TestBackend>(&device)
+            .forward(input);
+
+        output.to_data().assert_approx_eq(
+            &TensorData::from([[[-0.70711, 0.70711], [-0.70711, 0.70711]]]),
+            4,
+        );
+        output_on_std
+            .to_data()
+            .assert_approx_eq(&TensorData::from([[[-0.5, 0.5], [-0.5, 0.5]]]), 4);
+    }
+
     #[test]
     fn display() {
         let config = InstanceNormConfig::new(6);
@@ -216,7 +260,7 @@ mod tests {
 
         assert_eq!(
             format!("{}", instance_norm),
-            "InstanceNorm {num_channels: 6, epsilon: 0.00001, affine: true, params: 12}"
+            "InstanceNorm {num_channels: 6, epsilon: 0.00001, epsilon_on_std: false, affine: true, params: 12}"
         );
     }
 }
@@ -1,11 +1,13 @@
 use crate as burn;
 use crate::config::Config;
+use crate::module::forward_hook;
 use crate::module::Content;
 use crate::module::DisplaySettings;
 use crate::module::Module;
 use crate::module::ModuleDisplay;
 use crate::module::Param;
 use crate::nn::Initializer;
+use crate::per_sample;
 use crate::tensor::backend::Backend;
 use crate::tensor::Tensor;
 
@@ -14,18 +16,21 @@ use crate::tensor::Tensor;
 pub struct LayerNormConfig {
     /// The size of the input features.
     pub d_model: usize,
-    /// A value required for numerical stability. Default: 1e-5
+    /// A value required for numerical stability, added to the variance under the square root.
+    /// Default: 1e-5
     #[config(default = 1e-5)]
     pub epsilon: f64,
 }
 
 /// Applies Layer Normalization over an input tensor as described in the paper [Layer Normalization](https://arxiv.org/abs/1607.06450).
 ///
-/// `Y = norm(X) * γ + β`
+/// `Y = (X - mean) / sqrt(var + ε) * γ + β`
 ///
 /// Where:
 /// - `X` is the input tensor
 /// - `Y` is the output tensor
+/// - `mean` and `var` are the statistics of the last dimension, the variance being biased
+/// - `ε` is the epsilon
 /// - `γ` is the learnable weight
 /// - `β` is the learnable bias
 ///
@@ -69,9 +74,14 @@ impl<B: Backend> LayerNorm<B> {
 
         let input_normalized = input.sub(mean).div(var.add_scalar(self.epsilon).sqrt());
 
-        input_normalized
+        let output = input_normalized
+            .clone()
             .mul(self.gamma.val().unsqueeze())
-            .add(self.beta.val().unsqueeze())
+            .add(self.beta.val().unsqueeze());
+
+        let output = per_sample::layer_norm(&self.gamma, &self.beta, &input_normalized, output);
+
+        forward_hook(self, output)
     }
 }
 
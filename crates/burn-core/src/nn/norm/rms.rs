@@ -3,7 +3,7 @@ use crate as burn;
 use crate::config::Config;
 use crate::module::Module;
 use crate::module::Param;
-use crate::module::{Content, DisplaySettings, ModuleDisplay};
+use crate::module::{forward_hook, Content, DisplaySettings, ModuleDisplay};
 use crate::nn::Initializer;
 use crate::tensor::backend::Backend;
 use crate::tensor::Tensor;
@@ -75,7 +75,9 @@ impl<B: Backend> RmsNorm<B> {
             .mean_dim(D - 1)
             + self.epsilon)
             .sqrt();
-        (x / Tensor::from_full_precision(rms)) * self.gamma.val().unsqueeze()
+        let output = (x / Tensor::from_full_precision(rms)) * self.gamma.val().unsqueeze();
+
+        forward_hook(self, output)
     }
 }
 
@@ -23,6 +23,9 @@ pub struct AvgPool1dConfig {
     /// If the padding is counted in the denominator when computing the average.
     #[config(default = "true")]
     pub count_include_pad: bool,
+    /// If the output size is rounded up, adding a partial window at the end of the input.
+    #[config(default = "false")]
+    pub ceil_mode: bool,
 }
 
 /// Applies a 1D avg pooling over input tensors.
@@ -51,6 +54,8 @@ pub struct AvgPool1d {
     pub padding: Ignored<PaddingConfig1d>,
     /// If the padding is counted in the denominator when computing the average.
     pub count_include_pad: bool,
+    /// If the output size is rounded up, adding a partial window at the end of the input.
+    pub ceil_mode: bool,
 }
 
 impl ModuleDisplay for AvgPool1d {
@@ -66,6 +71,7 @@ impl ModuleDisplay for AvgPool1d {
             .add("stride", &self.stride)
             .add("padding", &self.padding)
             .add("count_include_pad", &self.count_include_pad)
+            .add("ceil_mode", &self.ceil_mode)
             .optional()
     }
 }
@@ -78,6 +84,7 @@ impl AvgPool1dConfig {
             kernel_size: self.kernel_size,
             padding: Ignored(self.padding.clone()),
             count_include_pad: self.count_include_pad,
+            ceil_mode: self.ceil_mode,
         }
     }
 }
@@ -103,6 +110,7 @@ impl AvgPool1d {
             self.stride,
             padding,
             self.count_include_pad,
+            self.ceil_mode,
         )
     }
 }
@@ -118,7 +126,7 @@ mod tests {
 
         assert_eq!(
             alloc::format!("{}", layer),
-            "AvgPool1d {kernel_size: 3, stride: 1, padding: Valid, count_include_pad: true}"
+            "AvgPool1d {kernel_size: 3, stride: 1, padding: Valid, count_include_pad: true, ceil_mode: false}"
         );
     }
 }
@@ -23,6 +23,9 @@ pub struct AvgPool2dConfig {
     /// If the padding is counted in the denominator when computing the average.
     #[config(default = "true")]
     pub count_include_pad: bool,
+    /// If the output size is rounded up, adding a partial window at the end of the input.
+    #[config(default = "false")]
+    pub ceil_mode: bool,
 }
 
 /// Applies a 2D avg pooling over input tensors.
@@ -50,6 +53,8 @@ pub struct AvgPool2d {
     pub padding: Ignored<PaddingConfig2d>,
     /// If the padding is counted in the denominator when computing the average.
     pub count_include_pad: bool,
+    /// If the output size is rounded up, adding a partial window at the end of the input.
+    pub ceil_mode: bool,
 }
 
 impl ModuleDisplay for AvgPool2d {
@@ -65,6 +70,7 @@ impl ModuleDisplay for AvgPool2d {
             .add("stride", &alloc::format!("{:?}", &self.stride))
             .add("padding", &self.padding)
             .add("count_include_pad", &self.count_include_pad)
+            .add("ceil_mode", &self.ceil_mode)
             .optional()
     }
 }
@@ -77,6 +83,7 @@ impl AvgPool2dConfig {
             kernel_size: self.kernel_size,
             padding: Ignored(self.padding.clone()),
             count_include_pad: self.count_include_pad,
+            ceil_mode: self.ceil_mode,
         }
     }
 }
@@ -102,6 +109,7 @@ impl AvgPool2d {
             self.stride,
             padding,
             self.count_include_pad,
+            self.ceil_mode,
         )
     }
 }
@@ -118,7 +126,7 @@ mod tests {
 
         assert_eq!(
             alloc::format!("{}", layer),
-            "AvgPool2d {kernel_size: [3, 3], stride: [1, 1], padding: Valid, count_include_pad: true}"
+            "AvgPool2d {kernel_size: [3, 3], stride: [1, 1], padding: Valid, count_include_pad: true, ceil_mode: false}"
         );
     }
 }
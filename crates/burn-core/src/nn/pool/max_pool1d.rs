@@ -23,6 +23,9 @@ pub struct MaxPool1dConfig {
     /// The dilation.
     #[config(default = "1")]
     pub dilation: usize,
+    /// If the output size is rounded up, adding a partial window at the end of the input.
+    #[config(default = "false")]
+    pub ceil_mode: bool,
 }
 
 /// Applies a 1D max pooling over input tensors.
@@ -39,6 +42,8 @@ pub struct MaxPool1d {
     pub padding: Ignored<PaddingConfig1d>,
     /// The dilation.
     pub dilation: usize,
+    /// If the output size is rounded up, adding a partial window at the end of the input.
+    pub ceil_mode: bool,
 }
 
 impl ModuleDisplay for MaxPool1d {
@@ -54,6 +59,7 @@ impl ModuleDisplay for MaxPool1d {
             .add("stride", &self.stride)
             .add("padding", &self.padding)
             .add("dilation", &self.dilation)
+            .add("ceil_mode", &self.ceil_mode)
             .optional()
     }
 }
@@ -66,6 +72,7 @@ impl MaxPool1dConfig {
             kernel_size: self.kernel_size,
             padding: Ignored(self.padding.clone()),
             dilation: self.dilation,
+            ceil_mode: self.ceil_mode,
         }
     }
 }
@@ -85,7 +92,14 @@ impl MaxPool1d {
             .padding
             .calculate_padding_1d(length, self.kernel_size, self.stride);
 
-        max_pool1d(input, self.kernel_size, self.stride, padding, self.dilation)
+        max_pool1d(
+            input,
+            self.kernel_size,
+            self.stride,
+            padding,
+            self.dilation,
+            self.ceil_mode,
+        )
     }
 }
 
@@ -101,7 +115,7 @@ mod tests {
 
         assert_eq!(
             alloc::format!("{}", layer),
-            "MaxPool1d {kernel_size: 3, stride: 1, padding: Valid, dilation: 1}"
+            "MaxPool1d {kernel_size: 3, stride: 1, padding: Valid, dilation: 1, ceil_mode: false}"
         );
     }
 }
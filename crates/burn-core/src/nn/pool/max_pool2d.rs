@@ -23,6 +23,9 @@ pub struct MaxPool2dConfig {
     /// The dilation.
     #[config(default = "[1, 1]")]
     pub dilation: [usize; 2],
+    /// If the output size is rounded up, adding a partial window at the end of the input.
+    #[config(default = "false")]
+    pub ceil_mode: bool,
 }
 
 /// Applies a 2D max pooling over input tensors.
@@ -39,6 +42,8 @@ pub struct MaxPool2d {
     pub padding: Ignored<PaddingConfig2d>,
     /// The dilation.
     pub dilation: [usize; 2],
+    /// If the output size is rounded up, adding a partial window at the end of the input.
+    pub ceil_mode: bool,
 }
 
 impl ModuleDisplay for MaxPool2d {
@@ -54,6 +59,7 @@ impl ModuleDisplay for MaxPool2d {
             .add("stride", &alloc::format!("{:?}", &self.stride))
             .add("padding", &self.padding)
             .add("dilation", &alloc::format!("{:?}", &self.dilation))
+            .add("ceil_mode", &self.ceil_mode)
             .optional()
     }
 }
@@ -66,6 +72,7 @@ impl MaxPool2dConfig {
             kernel_size: self.kernel_size,
             padding: Ignored(self.padding.clone()),
             dilation: self.dilation,
+            ceil_mode: self.ceil_mode,
         }
     }
 }
@@ -85,7 +92,14 @@ impl MaxPool2d {
             self.padding
                 .calculate_padding_2d(height_in, width_in, &self.kernel_size, &self.stride);
 
-        max_pool2d(input, self.kernel_size, self.stride, padding, self.dilation)
+        max_pool2d(
+            input,
+            self.kernel_size,
+            self.stride,
+            padding,
+            self.dilation,
+            self.ceil_mode,
+        )
     }
 }
 
@@ -101,7 +115,7 @@ mod tests {
 
         assert_eq!(
             alloc::format!("{}", layer),
-            "MaxPool2d {kernel_size: [3, 3], stride: [1, 1], padding: Valid, dilation: [1, 1]}"
+            "MaxPool2d {kernel_size: [3, 3], stride: [1, 1], padding: Valid, dilation: [1, 1], ceil_mode: false}"
         );
     }
 }
@@ -2,9 +2,12 @@ use alloc::vec::Vec;
 
 use crate as burn;
 use crate::config::Config;
-use crate::module::{Content, DisplaySettings, Module, ModuleDisplay};
+use crate::module::{Content, DisplaySettings, Ignored, Module, ModuleDisplay, Param};
+use crate::nn::{interpolate::InterpolateMode, Initializer};
 
 use crate::tensor::backend::Backend;
+use crate::tensor::module::interpolate;
+use crate::tensor::ops::InterpolateOptions;
 use crate::tensor::Tensor;
 use crate::tensor::TensorData;
 
@@ -184,6 +187,409 @@ pub fn generate_sinusoids<B: Backend>(
     Tensor::<B, 2>::from_data(data, device)
 }
 
+/// Returns 2-D sinusoids for positional embedding of image patches, as used in
+/// [Masked Autoencoders Are Scalable Vision Learners](https://arxiv.org/abs/2111.06377).
+///
+/// The first half of each vector encodes the row index and the second half encodes the column
+/// index, each using the 1-D sinusoids of [generate_sinusoids].
+///
+/// # Arguments
+///
+/// * `height` - The number of patches along the height.
+/// * `width` - The number of patches along the width.
+/// * `d_model` - The size of each vector. Must be a multiple of 4.
+/// * `max_timescale` - The maximum time scale to use.
+///
+/// # Returns
+///
+/// A tensor of shape [height * width, d_model] containing the sinusoids in row-major order.
+pub fn generate_sinusoids_2d<B: Backend>(
+    height: usize,
+    width: usize,
+    d_model: usize,
+    max_timescale: usize,
+    device: &B::Device,
+) -> Tensor<B, 2> {
+    assert!(d_model % 4 == 0, "d_model must be a multiple of 4");
+
+    let d_half = d_model / 2;
+    let rows = generate_sinusoids::<B>(height, d_half, max_timescale, device)
+        .unsqueeze_dim::<3>(1)
+        .expand([height, width, d_half]);
+    let cols = generate_sinusoids::<B>(width, d_half, max_timescale, device)
+        .unsqueeze_dim::<3>(0)
+        .expand([height, width, d_half]);
+
+    Tensor::cat(alloc::vec![rows, cols], 2).reshape([height * width, d_model])
+}
+
+/// Configuration to create a [PositionalEncoding2d](PositionalEncoding2d) layer using the [init function](PositionalEncoding2dConfig::init).
+#[derive(Config)]
+pub struct PositionalEncoding2dConfig {
+    /// The number of patches along the height.
+    pub height: usize,
+
+    /// The number of patches along the width.
+    pub width: usize,
+
+    /// The size of each vector.
+    pub d_model: usize,
+
+    /// Max time scale to use.
+    #[config(default = "10_000")]
+    pub max_timescale: usize,
+}
+
+/// Fixed 2-D sin-cos positional encoding layer for a grid of image patches.
+///
+/// The input is expected to be a sequence of flattened patches in row-major order.
+///
+/// Should be created using [PositionalEncoding2dConfig]
+#[derive(Module, Debug)]
+#[module(custom_display)]
+pub struct PositionalEncoding2d<B: Backend> {
+    /// The sinusoids of shape `[1, height * width, d_model]`.
+    pub sinusoids: Tensor<B, 3>,
+    /// The number of patches along the height.
+    pub height: usize,
+    /// The number of patches along the width.
+    pub width: usize,
+    /// Max time scale to use.
+    pub max_timescale: usize,
+}
+
+impl<B: Backend> ModuleDisplay for PositionalEncoding2d<B> {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        let [_, _, d_model] = self.sinusoids.shape().dims;
+        content
+            .add("d_model", &d_model)
+            .add("height", &self.height)
+            .add("width", &self.width)
+            .add("max_timescale", &self.max_timescale)
+            .optional()
+    }
+}
+
+impl PositionalEncoding2dConfig {
+    /// Initialize a new [PositionalEncoding2d](PositionalEncoding2d) module.
+    pub fn init<B: Backend>(&self, device: &B::Device) -> PositionalEncoding2d<B> {
+        let sinusoids = generate_sinusoids_2d::<B>(
+            self.height,
+            self.width,
+            self.d_model,
+            self.max_timescale,
+            device,
+        )
+        .unsqueeze::<3>();
+
+        PositionalEncoding2d {
+            sinusoids,
+            height: self.height,
+            width: self.width,
+            max_timescale: self.max_timescale,
+        }
+    }
+}
+
+impl<B: Backend> PositionalEncoding2d<B> {
+    /// Applies the forward pass on the input tensor by adding the sinusoids to the input.
+    ///
+    /// # Shapes
+    ///
+    /// * input: [batch_size, height * width, d_model]
+    /// * output: [batch_size, height * width, d_model]
+    ///
+    /// # Panics
+    ///
+    /// * Panics if the input sequence length is not equal to `height * width`.
+    /// * Panics if the input d_model is not equal to the d_model of the sinusoids.
+    pub fn forward(&self, input: Tensor<B, 3>) -> Tensor<B, 3> {
+        let [_, seq_length, d_model_input] = input.dims();
+        let [_, num_patches, d_model] = self.sinusoids.dims();
+
+        assert!(
+            num_patches == seq_length,
+            "length({seq_length}) must be equal to height * width({num_patches})",
+        );
+
+        assert!(
+            d_model_input == d_model,
+            "d_model({}) of the input must be equal to d_model of encoding({})",
+            d_model_input,
+            d_model,
+        );
+
+        input.add(self.sinusoids.clone())
+    }
+}
+
+/// Configuration to create a [LearnedPositionalEncoding](LearnedPositionalEncoding) layer using the [init function](LearnedPositionalEncodingConfig::init).
+#[derive(Config)]
+pub struct LearnedPositionalEncodingConfig {
+    /// Maximum sequence size to use.
+    pub max_sequence_size: usize,
+
+    /// The size of each vector.
+    pub d_model: usize,
+
+    /// Interpolation mode used to resample the table when the input is longer than
+    /// `max_sequence_size`.
+    #[config(default = "InterpolateMode::Linear")]
+    pub interpolate_mode: InterpolateMode,
+
+    /// The type of function used to initialize the table.
+    #[config(default = "Initializer::Normal{mean:0.0, std:0.02}")]
+    pub initializer: Initializer,
+}
+
+/// Learned positional encoding layer.
+///
+/// This layer adds a learnable embedding for each position to the input embeddings, as done in
+/// [BERT](https://arxiv.org/abs/1810.04805) and [ViT](https://arxiv.org/abs/2010.11929).
+///
+/// When the input sequence is longer than `max_sequence_size`, the table is resampled to the
+/// input length with the configured interpolation mode. The table can also be resized
+/// permanently with [resize](LearnedPositionalEncoding::resize) or
+/// [resize_2d](LearnedPositionalEncoding::resize_2d), which is useful when fine-tuning a model at
+/// a higher resolution than it was trained on.
+///
+/// Should be created using [LearnedPositionalEncodingConfig]
+#[derive(Module, Debug)]
+#[module(custom_display)]
+pub struct LearnedPositionalEncoding<B: Backend> {
+    /// The learnable table of shape `[max_sequence_size, d_model]`.
+    pub weight: Param<Tensor<B, 2>>,
+    /// Interpolation mode used to resample the table.
+    pub interpolate_mode: Ignored<InterpolateMode>,
+}
+
+impl<B: Backend> ModuleDisplay for LearnedPositionalEncoding<B> {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        let [max_sequence_size, d_model] = self.weight.shape().dims;
+        content
+            .add("d_model", &d_model)
+            .add("max_sequence_size", &max_sequence_size)
+            .optional()
+    }
+}
+
+impl LearnedPositionalEncodingConfig {
+    /// Initialize a new [LearnedPositionalEncoding](LearnedPositionalEncoding) module.
+    pub fn init<B: Backend>(&self, device: &B::Device) -> LearnedPositionalEncoding<B> {
+        let weight = self
+            .initializer
+            .init([self.max_sequence_size, self.d_model], device);
+
+        LearnedPositionalEncoding {
+            weight,
+            interpolate_mode: Ignored(self.interpolate_mode.clone()),
+        }
+    }
+}
+
+impl<B: Backend> LearnedPositionalEncoding<B> {
+    /// Applies the forward pass on the input tensor by adding the learned table to the input.
+    ///
+    /// # Shapes
+    ///
+    /// * input: [batch_size, seq_length, d_model]
+    /// * output: [batch_size, seq_length, d_model]
+    ///
+    /// # Panics
+    ///
+    /// * Panics if the input d_model is not equal to the d_model of the table.
+    pub fn forward(&self, input: Tensor<B, 3>) -> Tensor<B, 3> {
+        let [_, seq_length, d_model_input] = input.dims();
+        let [max_sequence_size, d_model] = self.weight.dims();
+
+        assert!(
+            d_model_input == d_model,
+            "d_model({}) of the input must be equal to d_model of encoding({})",
+            d_model_input,
+            d_model,
+        );
+
+        let table = if seq_length <= max_sequence_size {
+            self.weight.val().slice([0..seq_length, 0..d_model])
+        } else {
+            self.interpolate(seq_length)
+        };
+
+        input.add(table.unsqueeze())
+    }
+
+    /// Returns the table resampled to `length` positions.
+    ///
+    /// # Shapes
+    ///
+    /// * output: [length, d_model]
+    pub fn interpolate(&self, length: usize) -> Tensor<B, 2> {
+        let [max_sequence_size, d_model] = self.weight.dims();
+
+        // Treat the table as a 1-D signal with `d_model` channels: [1, d_model, 1, max_sequence_size].
+        let table = self
+            .weight
+            .val()
+            .transpose()
+            .reshape([1, d_model, 1, max_sequence_size]);
+        let table = interpolate(
+            table,
+            [1, length],
+            InterpolateOptions::new(self.interpolate_mode.0.clone().into()),
+        );
+
+        table.reshape([d_model, length]).transpose()
+    }
+
+    /// Returns the table resampled from a grid of `[height, width]` positions to a grid of
+    /// `output_size` positions.
+    ///
+    /// # Shapes
+    ///
+    /// * output: [output_height * output_width, d_model]
+    ///
+    /// # Panics
+    ///
+    /// * Panics if `height * width` is not equal to the number of positions of the table.
+    pub fn interpolate_2d(&self, grid_size: [usize; 2], output_size: [usize; 2]) -> Tensor<B, 2> {
+        let [max_sequence_size, d_model] = self.weight.dims();
+        let [height, width] = grid_size;
+        let [output_height, output_width] = output_size;
+
+        assert!(
+            height * width == max_sequence_size,
+            "grid size {:?} must match max_sequence_size({})",
+            grid_size,
+            max_sequence_size,
+        );
+
+        // Treat the table as an image with `d_model` channels: [1, d_model, height, width].
+        let table = self
+            .weight
+            .val()
+            .reshape([height, width, d_model])
+            .permute([2, 0, 1])
+            .unsqueeze::<4>();
+        let table = interpolate(
+            table,
+            output_size,
+            InterpolateOptions::new(self.interpolate_mode.0.clone().into()),
+        );
+
+        table
+            .reshape([d_model, output_height * output_width])
+            .transpose()
+    }
+
+    /// Resample the table to `length` positions, keeping it learnable.
+    pub fn resize(self, length: usize) -> Self {
+        let weight = self.interpolate(length);
+        self.with_weight(weight)
+    }
+
+    /// Resample the table from a grid of `grid_size` positions to a grid of `output_size`
+    /// positions, keeping it learnable.
+    pub fn resize_2d(self, grid_size: [usize; 2], output_size: [usize; 2]) -> Self {
+        let weight = self.interpolate_2d(grid_size, output_size);
+        self.with_weight(weight)
+    }
+
+    fn with_weight(self, weight: Tensor<B, 2>) -> Self {
+        let (id, value) = self.weight.consume();
+        let weight = weight.detach().set_require_grad(value.is_require_grad());
+
+        Self {
+            weight: Param::initialized(id, weight),
+            interpolate_mode: self.interpolate_mode,
+        }
+    }
+}
+
+/// The positional encoding to add to the inputs of a transformer.
+#[derive(Config, Debug, PartialEq)]
+pub enum PositionalEncodingType {
+    /// Fixed 1-D sinusoids, see [PositionalEncoding].
+    Sinusoidal {
+        /// Maximum sequence size to use.
+        max_sequence_size: usize,
+    },
+    /// Learned table, see [LearnedPositionalEncoding].
+    Learned {
+        /// Maximum sequence size to use.
+        max_sequence_size: usize,
+    },
+    /// Fixed 2-D sinusoids over a grid of patches, see [PositionalEncoding2d].
+    Sinusoidal2d {
+        /// The number of patches along the height.
+        height: usize,
+        /// The number of patches along the width.
+        width: usize,
+    },
+}
+
+impl PositionalEncodingType {
+    /// Initialize the positional encoding layer for vectors of size `d_model`.
+    pub fn init<B: Backend>(
+        &self,
+        d_model: usize,
+        device: &B::Device,
+    ) -> PositionalEncodingLayer<B> {
+        match self {
+            Self::Sinusoidal { max_sequence_size } => PositionalEncodingLayer::Sinusoidal(
+                PositionalEncodingConfig::new(d_model)
+                    .with_max_sequence_size(*max_sequence_size)
+                    .with_max_timescale(usize::max(10_000, *max_sequence_size))
+                    .init(device),
+            ),
+            Self::Learned { max_sequence_size } => PositionalEncodingLayer::Learned(
+                LearnedPositionalEncodingConfig::new(*max_sequence_size, d_model).init(device),
+            ),
+            Self::Sinusoidal2d { height, width } => PositionalEncodingLayer::Sinusoidal2d(
+                PositionalEncoding2dConfig::new(*height, *width, d_model).init(device),
+            ),
+        }
+    }
+}
+
+/// A positional encoding layer selected with [PositionalEncodingType].
+#[derive(Module, Debug)]
+pub enum PositionalEncodingLayer<B: Backend> {
+    /// Fixed 1-D sinusoids.
+    Sinusoidal(PositionalEncoding<B>),
+    /// Learned table.
+    Learned(LearnedPositionalEncoding<B>),
+    /// Fixed 2-D sinusoids.
+    Sinusoidal2d(PositionalEncoding2d<B>),
+}
+
+impl<B: Backend> PositionalEncodingLayer<B> {
+    /// Applies the forward pass of the selected positional encoding.
+    ///
+    /// # Shapes
+    ///
+    /// * input: [batch_size, seq_length, d_model]
+    /// * output: [batch_size, seq_length, d_model]
+    pub fn forward(&self, input: Tensor<B, 3>) -> Tensor<B, 3> {
+        match self {
+            Self::Sinusoidal(layer) => layer.forward(input),
+            Self::Learned(layer) => layer.forward(input),
+            Self::Sinusoidal2d(layer) => layer.forward(input),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -274,6 +680,184 @@ mod tests {
         let _output = pe.forward(input);
     }
 
+    #[test]
+    fn test_generate_sinusoids_2d() {
+        let device = Default::default();
+        let [height, width, d_model] = [3, 4, 8];
+        let sinusoids =
+            generate_sinusoids_2d::<TestBackend>(height, width, d_model, 10_000, &device);
+        let rows = generate_sinusoids::<TestBackend>(height, d_model / 2, 10_000, &device);
+        let cols = generate_sinusoids::<TestBackend>(width, d_model / 2, 10_000, &device);
+
+        assert_eq!(sinusoids.dims(), [height * width, d_model]);
+
+        let sinusoids = sinusoids.reshape([height, width, d_model]);
+        for h in 0..height {
+            for w in 0..width {
+                let position = sinusoids
+                    .clone()
+                    .slice([h..h + 1, w..w + 1, 0..d_model])
+                    .reshape([1, d_model]);
+                let expected = Tensor::cat(
+                    alloc::vec![
+                        rows.clone().slice([h..h + 1, 0..d_model / 2]),
+                        cols.clone().slice([w..w + 1, 0..d_model / 2]),
+                    ],
+                    1,
+                );
+                position.to_data().assert_approx_eq(&expected.to_data(), 5);
+            }
+        }
+    }
+
+    #[test]
+    fn test_module_2d() {
+        let device = Default::default();
+        let pe = PositionalEncoding2dConfig::new(2, 3, 4).init::<TestBackend>(&device);
+        let input = Tensor::zeros([2, 6, 4], &device);
+
+        let output = pe.forward(input);
+
+        assert_eq!(output.dims(), [2, 6, 4]);
+        output
+            .slice([0..1])
+            .squeeze::<2>(0)
+            .to_data()
+            .assert_approx_eq(
+                &generate_sinusoids_2d::<TestBackend>(2, 3, 4, 10_000, &device).to_data(),
+                5,
+            );
+    }
+
+    #[test]
+    #[should_panic]
+    fn input_length_should_match_grid_2d() {
+        let device = Default::default();
+        let pe = PositionalEncoding2dConfig::new(2, 3, 4).init::<TestBackend>(&device);
+        let input = Tensor::zeros([1, 5, 4], &device);
+        let _output = pe.forward(input);
+    }
+
+    /// A learned table whose values are the position index, for easy verification.
+    fn ramp_table(
+        max_sequence_size: usize,
+        d_model: usize,
+    ) -> LearnedPositionalEncoding<TestBackend> {
+        let device = Default::default();
+        let pe = LearnedPositionalEncodingConfig::new(max_sequence_size, d_model)
+            .init::<TestBackend>(&device);
+        let ramp = Tensor::<TestBackend, 1, crate::tensor::Int>::arange(
+            0..max_sequence_size as i64,
+            &device,
+        )
+        .float()
+        .reshape([max_sequence_size, 1])
+        .repeat_dim(1, d_model);
+
+        pe.with_weight(ramp)
+    }
+
+    #[test]
+    fn test_learned_module() {
+        let device = Default::default();
+        let pe = ramp_table(8, 4);
+        let input = Tensor::<TestBackend, 3>::zeros([2, 5, 4], &device);
+
+        let output = pe.forward(input);
+
+        assert_eq!(output.dims(), [2, 5, 4]);
+        output
+            .slice([0..1, 0..5, 0..1])
+            .reshape([5])
+            .to_data()
+            .assert_approx_eq(&TensorData::from([0.0f32, 1.0, 2.0, 3.0, 4.0]), 5);
+    }
+
+    #[test]
+    fn test_learned_interpolate_beyond_max_sequence_size() {
+        let device = Default::default();
+        let pe = ramp_table(8, 4);
+        let input = Tensor::<TestBackend, 3>::zeros([1, 20, 4], &device);
+
+        let output = pe.forward(input);
+
+        assert_eq!(output.dims(), [1, 20, 4]);
+
+        // Resampling a linear ramp must stay monotonic, with steps no larger than the original.
+        let values = output
+            .slice([0..1, 0..20, 0..1])
+            .reshape([20])
+            .into_data()
+            .to_vec::<f32>()
+            .unwrap();
+        for step in values.windows(2).map(|pair| pair[1] - pair[0]) {
+            assert!(step >= -1e-5, "Interpolated table should be monotonic");
+            assert!(step <= 1.0 + 1e-5, "Interpolated table should be smooth");
+        }
+        assert!(values[0] >= 0.0 && values[19] <= 7.0 + 1e-5);
+    }
+
+    #[test]
+    fn test_learned_resize_2d() {
+        let pe = ramp_table(4 * 4, 6);
+
+        let pe = pe.resize_2d([4, 4], [6, 6]);
+
+        assert_eq!(pe.weight.dims(), [36, 6]);
+        assert!(pe.weight.is_require_grad());
+    }
+
+    #[test]
+    #[should_panic]
+    fn learned_resize_2d_grid_should_match() {
+        let pe = ramp_table(10, 6);
+        let _pe = pe.resize_2d([4, 4], [6, 6]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_learned_record_round_trip() {
+        use crate::record::{BinBytesRecorder, FullPrecisionSettings, Recorder};
+
+        let device = Default::default();
+        let config = LearnedPositionalEncodingConfig::new(10, 4);
+        let pe = config.init::<TestBackend>(&device);
+        let recorder = BinBytesRecorder::<FullPrecisionSettings>::default();
+
+        let bytes =
+            Recorder::<TestBackend>::record(&recorder, pe.clone().into_record(), ()).unwrap();
+        let loaded = config
+            .init::<TestBackend>(&device)
+            .load_record(Recorder::<TestBackend>::load(&recorder, bytes, &device).unwrap());
+
+        loaded
+            .weight
+            .to_data()
+            .assert_approx_eq(&pe.weight.to_data(), 5);
+    }
+
+    #[test]
+    fn test_positional_encoding_type_init() {
+        let device = Default::default();
+        let input = Tensor::<TestBackend, 3>::zeros([1, 6, 4], &device);
+
+        for encoding in [
+            PositionalEncodingType::Sinusoidal {
+                max_sequence_size: 10,
+            },
+            PositionalEncodingType::Learned {
+                max_sequence_size: 10,
+            },
+            PositionalEncodingType::Sinusoidal2d {
+                height: 2,
+                width: 3,
+            },
+        ] {
+            let layer = encoding.init::<TestBackend>(4, &device);
+            assert_eq!(layer.forward(input.clone()).dims(), [1, 6, 4]);
+        }
+    }
+
     #[test]
     fn display() {
         let config = PositionalEncodingConfig::new(4);
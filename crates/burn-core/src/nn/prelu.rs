@@ -1,7 +1,7 @@
 use crate as burn;
 use crate::config::Config;
 use crate::module::Param;
-use crate::module::{Content, DisplaySettings, Module, ModuleDisplay};
+use crate::module::{forward_hook, Content, DisplaySettings, Module, ModuleDisplay};
 use crate::nn::Initializer;
 use crate::tensor::backend::Backend;
 use crate::tensor::Tensor;
@@ -68,7 +68,9 @@ impl<B: Backend> PRelu<B> {
     ///
     /// See also [prelu](crate::tensor::activation::prelu) for more information.
     pub fn forward<const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
-        crate::tensor::activation::prelu(input, self.alpha.val())
+        let output = crate::tensor::activation::prelu(input, self.alpha.val());
+
+        forward_hook(self, output)
     }
 }
 
@@ -0,0 +1,73 @@
+use crate as burn;
+
+use super::{FakeQuantize, FakeQuantizeConfig, QatConv2d, QatLinear};
+use crate::config::Config;
+use crate::nn::conv::Conv2d;
+use crate::nn::Linear;
+use crate::tensor::backend::Backend;
+use crate::tensor::Tensor;
+
+/// Configuration of the quantization-aware training of layers, using
+/// [init_linear](QatConfig::init_linear) or [init_conv2d](QatConfig::init_conv2d).
+#[derive(Config, Debug)]
+pub struct QatConfig {
+    /// The fake quantization of the weights, whose axis is set by the layer when the weights are
+    /// [quantized per channel](QatConfig::per_channel_weights).
+    #[config(default = "FakeQuantizeConfig::new().with_momentum(1.0)")]
+    pub weights: FakeQuantizeConfig,
+    /// The fake quantization of the input activations.
+    #[config(default = "FakeQuantizeConfig::new()")]
+    pub activations: FakeQuantizeConfig,
+    /// If each output channel of the weights is quantized with its own parameters.
+    #[config(default = true)]
+    pub per_channel_weights: bool,
+    /// If the scale of the weights is learned as in [LSQ](https://arxiv.org/abs/1902.08153)
+    /// instead of observed, with a symmetric quantization.
+    #[config(default = false)]
+    pub learnable_scale: bool,
+}
+
+impl QatConfig {
+    /// Wrap a [linear](Linear) layer, whose weights are quantized per output channel.
+    pub fn init_linear<B: Backend>(&self, linear: Linear<B>) -> QatLinear<B> {
+        let device = linear.weight.device();
+        let weight_quant = self.init_weights(&linear.weight.val(), 1);
+
+        QatLinear {
+            linear,
+            weight_quant,
+            input_quant: self.activations.init(1, &device),
+        }
+    }
+
+    /// Wrap a [2D convolution](Conv2d) layer, whose weights are quantized per output channel.
+    pub fn init_conv2d<B: Backend>(&self, conv: Conv2d<B>) -> QatConv2d<B> {
+        let device = conv.weight.device();
+        let weight_quant = self.init_weights(&conv.weight.val(), 0);
+
+        QatConv2d {
+            conv,
+            weight_quant,
+            input_quant: self.activations.init(1, &device),
+        }
+    }
+
+    fn init_weights<B: Backend, const D: usize>(
+        &self,
+        weight: &Tensor<B, D>,
+        axis: usize,
+    ) -> FakeQuantize<B> {
+        let config = match self.per_channel_weights {
+            true => self.weights.clone().with_axis(Some(axis)),
+            false => self.weights.clone().with_axis(None),
+        };
+
+        match self.learnable_scale {
+            true => config.init_learnable(weight),
+            false => {
+                let num_channels = config.axis.map(|axis| weight.dims()[axis]).unwrap_or(1);
+                config.init(num_channels, &weight.device())
+            }
+        }
+    }
+}
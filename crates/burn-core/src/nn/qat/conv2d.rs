@@ -0,0 +1,125 @@
+use crate as burn;
+
+use super::FakeQuantize;
+use crate::module::Module;
+use crate::nn::conv::Conv2d;
+use crate::tensor::backend::Backend;
+use crate::tensor::module::conv2d;
+use crate::tensor::ops::ConvOptions;
+use crate::tensor::Tensor;
+
+/// A [2D convolution](Conv2d) layer trained with quantized weights and inputs, created with
+/// [QatConfig](super::QatConfig::init_conv2d).
+///
+/// `O = conv2d(fake_quantize(I), fake_quantize(W)) + b`
+#[derive(Module, Debug)]
+pub struct QatConv2d<B: Backend> {
+    /// The wrapped layer.
+    pub conv: Conv2d<B>,
+    /// The fake quantization of the weight, per output channel.
+    pub weight_quant: FakeQuantize<B>,
+    /// The fake quantization of the input.
+    pub input_quant: FakeQuantize<B>,
+}
+
+impl<B: Backend> QatConv2d<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, channels_in, height_in, width_in]`
+    /// - output: `[batch_size, channels_out, height_out, width_out]`
+    pub fn forward(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+        let [_batch_size, _channels_in, height_in, width_in] = input.dims();
+        let padding = self.conv.padding.calculate_padding_2d(
+            height_in,
+            width_in,
+            &self.conv.kernel_size,
+            &self.conv.stride,
+        );
+        let options = ConvOptions::new(
+            self.conv.stride,
+            padding,
+            self.conv.dilation,
+            self.conv.groups,
+        );
+
+        conv2d(
+            self.input_quant.forward(input),
+            self.weight_quant.forward(self.conv.weight.val()),
+            self.conv.bias.as_ref().map(|bias| bias.val()),
+            options,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::module::Param;
+    use crate::nn::conv::Conv2dConfig;
+    use crate::nn::qat::QatConfig;
+    use crate::nn::PaddingConfig2d;
+    use crate::tensor::Distribution;
+    use crate::TestAutodiffBackend;
+
+    #[test]
+    fn forward_should_match_the_layer_with_quantized_weights() {
+        let device = Default::default();
+        let conv = Conv2dConfig::new([2, 3], [3, 3])
+            .with_padding(PaddingConfig2d::Explicit(1, 1))
+            .init::<TestAutodiffBackend>(&device);
+        let qat = QatConfig::new().init_conv2d(conv.clone());
+        let input =
+            Tensor::<TestAutodiffBackend, 4>::random([2, 2, 5, 5], Distribution::Default, &device);
+
+        let output = qat.forward(input.clone());
+
+        let weight = qat.weight_quant.forward(conv.weight.val());
+        let input = qat.input_quant.forward(input);
+        let expected = Conv2d {
+            weight: Param::from_tensor(weight),
+            ..conv
+        }
+        .forward(input);
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+
+        // Each output channel has its own range.
+        assert_eq!(qat.weight_quant.running_max.value().dims(), [3]);
+        qat.weight_quant
+            .running_max
+            .value()
+            .into_data()
+            .assert_approx_eq(
+                &qat.conv
+                    .weight
+                    .val()
+                    .max_dim(1)
+                    .max_dim(2)
+                    .max_dim(3)
+                    .reshape([3])
+                    .into_data(),
+                5,
+            );
+    }
+
+    #[test]
+    fn learnable_scale_should_receive_gradients() {
+        let device = Default::default();
+        let conv = Conv2dConfig::new([2, 3], [3, 3]).init::<TestAutodiffBackend>(&device);
+        let qat = QatConfig::new()
+            .with_learnable_scale(true)
+            .init_conv2d(conv);
+        let input =
+            Tensor::<TestAutodiffBackend, 4>::random([2, 2, 5, 5], Distribution::Default, &device);
+
+        let grads = qat.forward(input).sum().backward();
+
+        let scale = qat.weight_quant.scale.as_ref().unwrap();
+        assert_eq!(scale.dims(), [3]);
+        assert!(scale.grad(&grads).is_some());
+        assert!(qat.conv.weight.grad(&grads).is_some());
+    }
+}
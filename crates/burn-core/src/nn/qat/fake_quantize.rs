@@ -0,0 +1,205 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::{Module, Param, RunningState};
+use crate::tensor::backend::Backend;
+use crate::tensor::quantization::{Calibration, MinMaxCalibration, PerChannelMinMaxCalibration};
+use crate::tensor::{CastOptions, Rounding, Tensor};
+
+/// Configuration to create a [fake quantization](FakeQuantize) module, using the
+/// [init function](FakeQuantizeConfig::init) or the [init_learnable function](FakeQuantizeConfig::init_learnable).
+#[derive(Config, Debug)]
+pub struct FakeQuantizeConfig {
+    /// The smallest value of the quantized range.
+    #[config(default = "-128")]
+    pub qmin: i32,
+    /// The largest value of the quantized range.
+    #[config(default = 127)]
+    pub qmax: i32,
+    /// The dimension of the channels quantized with their own parameters, the whole tensor
+    /// sharing the same parameters when `None`.
+    #[config(default = "None")]
+    pub axis: Option<usize>,
+    /// The momentum of the running range, a momentum of `1.0` only keeping the last observed range.
+    #[config(default = 0.1)]
+    pub momentum: f64,
+}
+
+/// Simulates the quantization of its input during training, rounding the values to the
+/// quantized range while keeping them as floats, so that the model learns to be robust to the
+/// quantization.
+///
+/// The parameters come either from the range of the observed values, or from a learned scale of
+/// a symmetric quantization (LSQ).
+///
+/// Should be created with [FakeQuantizeConfig].
+#[derive(Module, Debug)]
+pub struct FakeQuantize<B: Backend> {
+    /// The running minimum of the observed values, one per channel.
+    pub running_min: RunningState<Tensor<B, 1>>,
+    /// The running maximum of the observed values, one per channel.
+    pub running_max: RunningState<Tensor<B, 1>>,
+    /// The learned scale, one per channel, replacing the observed range when present.
+    pub scale: Option<Param<Tensor<B, 1>>>,
+    /// The smallest value of the quantized range.
+    pub qmin: i32,
+    /// The largest value of the quantized range.
+    pub qmax: i32,
+    /// The dimension of the channels.
+    pub axis: Option<usize>,
+    /// The momentum of the running range.
+    pub momentum: f64,
+}
+
+impl FakeQuantizeConfig {
+    /// Initialize a new fake quantization module observing the range of its input, with the given
+    /// number of channels, which must be `1` without [axis](FakeQuantizeConfig::axis).
+    ///
+    /// The range is empty until the first observation, the quantization giving zeros until then.
+    pub fn init<B: Backend>(&self, num_channels: usize, device: &B::Device) -> FakeQuantize<B> {
+        self.check(num_channels);
+
+        FakeQuantize {
+            running_min: RunningState::new(Tensor::full([num_channels], f32::MAX, device)),
+            running_max: RunningState::new(Tensor::full([num_channels], f32::MIN, device)),
+            scale: None,
+            qmin: self.qmin,
+            qmax: self.qmax,
+            axis: self.axis,
+            momentum: self.momentum,
+        }
+    }
+
+    /// Initialize a new fake quantization module with a learned scale and a zero-point of zero,
+    /// the scale being initialized from the values of the tensor which will be quantized as in
+    /// [LSQ](https://arxiv.org/abs/1902.08153), `2 * mean(|x|) / sqrt(qmax)`.
+    pub fn init_learnable<B: Backend, const D: usize>(
+        &self,
+        tensor: &Tensor<B, D>,
+    ) -> FakeQuantize<B> {
+        let num_channels = self.axis.map(|axis| tensor.dims()[axis]).unwrap_or(1);
+        self.check(num_channels);
+
+        let channels = match self.axis {
+            Some(axis) => tensor.clone().swap_dims(0, axis),
+            None => tensor.clone(),
+        };
+        let scale = channels
+            .detach()
+            .reshape([num_channels as i32, -1])
+            .abs()
+            .mean_dim(1)
+            .reshape([num_channels])
+            .mul_scalar(2.0 / (self.qmax as f64).sqrt());
+
+        FakeQuantize {
+            scale: Some(Param::from_tensor(scale)),
+            ..self.init(num_channels, &tensor.device())
+        }
+    }
+
+    fn check(&self, num_channels: usize) {
+        assert!(
+            self.qmin < 0 && self.qmax > 0,
+            "The quantized range [{}, {}] must contain zero",
+            self.qmin,
+            self.qmax
+        );
+        assert!(
+            self.axis.is_some() || num_channels == 1,
+            "A fake quantization without axis has a single channel, got {num_channels}"
+        );
+    }
+}
+
+impl<B: Backend> FakeQuantize<B> {
+    /// Applies the fake quantization on the input tensor, updating the running range first during
+    /// training, when the backend is an [autodiff backend](crate::tensor::backend::AutodiffBackend).
+    ///
+    /// See [fake_quantize](crate::tensor::Tensor::fake_quantize) for more information.
+    pub fn forward<const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        let (scale, zero_point) = match &self.scale {
+            Some(scale) => {
+                let scale = self.learned_scale(scale.val(), &input);
+                let zero_point = scale.zeros_like();
+                (scale, zero_point)
+            }
+            None => {
+                if B::ad_enabled() {
+                    self.observe(&input);
+                }
+                self.q_params(&input.device())
+            }
+        };
+
+        input.fake_quantize(scale, zero_point, self.qmin, self.qmax, self.axis)
+    }
+
+    /// Update the running range with the range of the tensor, such as to calibrate the module
+    /// without training.
+    pub fn observe<const D: usize>(&self, tensor: &Tensor<B, D>) {
+        let tensor = tensor.clone().detach();
+        let range = match self.axis {
+            Some(axis) => PerChannelMinMaxCalibration { axis }.compute_range(&tensor),
+            None => MinMaxCalibration {}.compute_range(&tensor),
+        };
+
+        let device = tensor.device();
+        let running_min = self.running_min.value_sync().to_device(&device);
+        let running_max = self.running_max.value_sync().to_device(&device);
+
+        // The observed range replaces the empty range of a module which was never updated.
+        let is_empty = running_min.clone().greater(running_max.clone());
+        let running_min = running_min
+            .mul_scalar(1.0 - self.momentum)
+            .add(range.min.clone().mul_scalar(self.momentum))
+            .mask_where(is_empty.clone(), range.min);
+        let running_max = running_max
+            .mul_scalar(1.0 - self.momentum)
+            .add(range.max.clone().mul_scalar(self.momentum))
+            .mask_where(is_empty, range.max);
+
+        self.running_min.update(running_min.detach());
+        self.running_max.update(running_max.detach());
+    }
+
+    /// The scale and the zero-point of the affine quantization of the running range.
+    ///
+    /// The range is extended to contain zero, so that zero is exactly representable.
+    pub fn q_params(&self, device: &B::Device) -> (Tensor<B, 1>, Tensor<B, 1>) {
+        let qmin = self.qmin as f64;
+        let qmax = self.qmax as f64;
+
+        let min = self.running_min.value().to_device(device).clamp_max(0.0);
+        let max = self.running_max.value().to_device(device).clamp_min(0.0);
+
+        let scale = max
+            .sub(min.clone())
+            .div_scalar(qmax - qmin)
+            .clamp_min(f32::EPSILON);
+        let zero_point = min
+            .div(scale.clone())
+            .cast_with(CastOptions::new(Rounding::Nearest, false))
+            .float()
+            .neg()
+            .add_scalar(qmin)
+            .clamp(qmin, qmax);
+
+        (scale, zero_point)
+    }
+
+    /// The learned scale, with its gradient scaled by `1 / sqrt(N * qmax)`, where `N` is the number
+    /// of values of each channel, which balances its updates with the ones of the quantized values.
+    fn learned_scale<const D: usize>(
+        &self,
+        scale: Tensor<B, 1>,
+        input: &Tensor<B, D>,
+    ) -> Tensor<B, 1> {
+        let num_values = input.shape().num_elements() / scale.dims()[0];
+        let grad_scale = 1.0 / (num_values as f64 * self.qmax as f64).sqrt();
+        let scale = scale.clamp_min(f32::EPSILON);
+        let scaled = scale.clone().mul_scalar(grad_scale);
+
+        scaled.clone() + (scale - scaled).detach()
+    }
+}
@@ -0,0 +1,162 @@
+use crate as burn;
+
+use super::FakeQuantize;
+use crate::module::Module;
+use crate::nn::Linear;
+use crate::tensor::{backend::Backend, Tensor};
+
+/// A [linear](Linear) layer trained with quantized weights and inputs, created with
+/// [QatConfig](super::QatConfig::init_linear).
+///
+/// `O = fake_quantize(I) * fake_quantize(W) + b`
+#[derive(Module, Debug)]
+pub struct QatLinear<B: Backend> {
+    /// The wrapped layer.
+    pub linear: Linear<B>,
+    /// The fake quantization of the weight, per output channel.
+    pub weight_quant: FakeQuantize<B>,
+    /// The fake quantization of the input.
+    pub input_quant: FakeQuantize<B>,
+}
+
+impl<B: Backend> QatLinear<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[..., d_input]`
+    /// - output: `[..., d_output]`
+    pub fn forward<const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        if D == 1 {
+            // Insert and remove an extra batch dimension for the batch matmul to work.
+            return Self::forward::<2>(self, input.unsqueeze()).flatten(0, 1);
+        }
+
+        let input = self.input_quant.forward(input);
+        let weight = self.weight_quant.forward(self.linear.weight.val());
+
+        let output = input.matmul(weight.unsqueeze());
+        match &self.linear.bias {
+            Some(bias) => output + bias.val().unsqueeze(),
+            None => output,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::module::{AutodiffModule, Param};
+    use crate::nn::qat::{FakeQuantizeConfig, QatConfig};
+    use crate::nn::LinearConfig;
+    use crate::tensor::{backend::Backend, Distribution, TensorData};
+    use crate::TestAutodiffBackend;
+
+    #[test]
+    fn forward_should_quantize_the_weights_per_output_channel() {
+        let device = Default::default();
+        let mut linear = LinearConfig::new(2, 2).init::<TestAutodiffBackend>(&device);
+        linear.weight = Param::from_data([[0.5, -0.3], [0.26, 1.0]], &device);
+        linear.bias = Some(Param::from_data([0.0, 0.5], &device));
+        let config = QatConfig::new().with_weights(
+            FakeQuantizeConfig::new()
+                .with_qmin(-2)
+                .with_qmax(1)
+                .with_momentum(1.0),
+        );
+        let qat = config.init_linear(linear);
+
+        let output = qat.forward(Tensor::<TestAutodiffBackend, 2>::from_floats(
+            [[2.0, -1.0]],
+            &device,
+        ));
+
+        // The first output channel `[0.5, 0.26]` is quantized with a step of `0.5 / 3` and the
+        // second one `[-0.3, 1.0]` with a step of `1.3 / 3`, the weight becoming
+        // `[[0.5, -1.3 / 3], [1 / 3, 2.6 / 3]]`, while the input is exactly representable.
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[2.0 / 3.0, 0.5 - 5.2 / 3.0]]), 3);
+    }
+
+    #[test]
+    fn forward_should_update_the_range_only_during_training() {
+        let device = Default::default();
+        let linear = LinearConfig::new(3, 2).init::<TestAutodiffBackend>(&device);
+        let qat = QatConfig::new().init_linear(linear);
+        let input = Tensor::<TestAutodiffBackend, 2>::from_floats([[-1.0, 0.5, 2.0]], &device);
+
+        qat.forward(input.clone());
+        qat.input_quant
+            .running_max
+            .value()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([2.0]), 5);
+
+        let qat = qat.valid();
+        qat.forward(input.inner().mul_scalar(10.0));
+        qat.input_quant
+            .running_max
+            .value()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([2.0]), 5);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn quantization_aware_training_should_beat_post_training_quantization() {
+        use crate::optim::{AdamConfig, GradientsParams, Optimizer};
+
+        type B = TestAutodiffBackend;
+
+        let device = Default::default();
+        TestAutodiffBackend::seed(0);
+
+        // The target `x_1 + 0.2 * x_2` is the function of the pretrained layer, whose second
+        // weight is rounded to zero by the ternary quantization of the weights.
+        let input = Tensor::<B, 2>::random([64, 2], Distribution::Uniform(1.0, 2.0), &device);
+        let target = input.clone().narrow(1, 0, 1) + input.clone().narrow(1, 1, 1).mul_scalar(0.2);
+        let config = QatConfig::new().with_weights(
+            FakeQuantizeConfig::new()
+                .with_qmin(-1)
+                .with_qmax(1)
+                .with_momentum(1.0),
+        );
+        let pretrained = || {
+            let mut linear = LinearConfig::new(2, 1).init::<B>(&device);
+            linear.weight = Param::from_data([[1.0], [0.2]], &device);
+            linear.bias = Some(Param::from_data([0.0], &device));
+            config.init_linear(linear)
+        };
+        let eval = |qat: QatLinear<B>| {
+            let output = qat.valid().forward(input.clone().inner());
+            let error = output - target.clone().inner();
+            error.powf_scalar(2.0).mean().into_scalar()
+        };
+
+        let num_steps = 100;
+
+        // Post-training quantization: the ranges are calibrated without updating the layer.
+        let ptq = pretrained();
+        for _ in 0..num_steps {
+            ptq.forward(input.clone());
+        }
+
+        let mut qat = pretrained();
+        let mut optim = AdamConfig::new().init();
+        for _ in 0..num_steps {
+            let loss = (qat.forward(input.clone()) - target.clone())
+                .powf_scalar(2.0)
+                .mean();
+            let grads = GradientsParams::from_grads(loss.backward(), &qat);
+            qat = optim.step(1e-2, qat, grads);
+        }
+
+        let error_ptq = eval(ptq);
+        let error_qat = eval(qat);
+        assert!(
+            error_qat < error_ptq,
+            "QAT error {error_qat} should be lower than the PTQ error {error_ptq}"
+        );
+    }
+}
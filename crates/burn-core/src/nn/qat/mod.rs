@@ -0,0 +1,9 @@
+mod config;
+mod conv2d;
+mod fake_quantize;
+mod linear;
+
+pub use config::*;
+pub use conv2d::*;
+pub use fake_quantize::*;
+pub use linear::*;
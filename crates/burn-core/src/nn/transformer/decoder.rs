@@ -2,11 +2,15 @@ use alloc::vec::Vec;
 
 use super::{PositionWiseFeedForward, PositionWiseFeedForwardConfig};
 
+use crate::generation::AutoregressiveCache;
 use crate::module::{Content, DisplaySettings, Module, ModuleDisplay};
 use crate::tensor::Bool;
 use crate::{
     self as burn,
-    nn::{attention::MhaCache, cache::TensorCache, Initializer},
+    nn::{
+        attention::MhaCache, cache::TensorCache, Initializer, PositionalEncodingLayer,
+        PositionalEncodingType,
+    },
 };
 use crate::{
     config::Config,
@@ -14,7 +18,7 @@ use crate::{
         attention::{MhaInput, MultiHeadAttention, MultiHeadAttentionConfig},
         Dropout, DropoutConfig, LayerNorm, LayerNormConfig,
     },
-    tensor::{backend::Backend, Tensor},
+    tensor::{backend::Backend, Int, Tensor},
 };
 
 /// Configuration to create a [Transformer Decoder](TransformerDecoder) layer using the [init function](TransformerDecoderConfig::init).
@@ -47,6 +51,9 @@ pub struct TransformerDecoderConfig {
         default = "Initializer::KaimingUniform{gain:1.0/num_traits::Float::sqrt(3.0), fan_out_only:false}"
     )]
     pub initializer: Initializer,
+    /// The positional encoding added to the target before the first layer. Default: None
+    #[config(default = "None")]
+    pub positional_encoding: Option<PositionalEncodingType>,
 }
 
 /// The transformer decoder module as describe in the paper [Attention Is All You Need](https://arxiv.org/abs/1706.03762).
@@ -82,6 +89,9 @@ pub struct TransformerDecoder<B: Backend> {
 
     /// Use "quiet softmax" instead of regular softmax.
     pub quiet_softmax: bool,
+
+    /// The positional encoding added to the target before the first layer.
+    pub positional_encoding: Option<PositionalEncodingLayer<B>>,
 }
 
 impl<B: Backend> ModuleDisplay for TransformerDecoder<B> {
@@ -120,6 +130,10 @@ impl TransformerDecoderConfig {
             dropout: self.dropout,
             norm_first: self.norm_first,
             quiet_softmax: self.quiet_softmax,
+            positional_encoding: self
+                .positional_encoding
+                .as_ref()
+                .map(|encoding| encoding.init(self.d_model, device)),
         }
     }
 }
@@ -196,6 +210,15 @@ struct TransformerDecoderLayerAutoregressiveCache<B: Backend> {
 }
 
 impl<B: Backend> TransformerDecoderLayerAutoregressiveCache<B> {
+    fn reorder(&mut self, indices: Tensor<B, 1, Int>) {
+        self.cross_attn.reorder_cache(indices.clone());
+        self.self_attn.reorder_cache(indices.clone());
+        self.pwff.reorder(indices.clone());
+        self.norm_1.reorder(indices.clone());
+        self.norm_2.reorder(indices.clone());
+        self.norm_3.reorder(indices);
+    }
+
     fn empty() -> Self {
         Self {
             cross_attn: MhaCache::autoregressive_cross_attention(),
@@ -215,6 +238,14 @@ pub struct TransformerDecoderAutoregressiveCache<B: Backend> {
     layers: Vec<TransformerDecoderLayerAutoregressiveCache<B>>,
 }
 
+impl<B: Backend> AutoregressiveCache<B> for TransformerDecoderAutoregressiveCache<B> {
+    fn reorder_cache(&mut self, indices: Tensor<B, 1, Int>) {
+        self.layers
+            .iter_mut()
+            .for_each(|layer| layer.reorder(indices.clone()));
+    }
+}
+
 impl<B: Backend> TransformerDecoderAutoregressiveCache<B> {
     fn empty(num_layers: usize) -> Self {
         Self {
@@ -425,6 +456,10 @@ impl<B: Backend> TransformerDecoderLayer<B> {
 impl<B: Backend> TransformerDecoder<B> {
     /// Applies the forward pass.
     pub fn forward(&self, mut input: TransformerDecoderInput<B>) -> Tensor<B, 3> {
+        if let Some(positional_encoding) = &self.positional_encoding {
+            input.target = positional_encoding.forward(input.target);
+        }
+
         for layer in self.layers.iter() {
             input = layer.forward(input);
         }
@@ -438,6 +473,10 @@ impl<B: Backend> TransformerDecoder<B> {
         mut input: TransformerDecoderInput<B>,
         cache: &mut TransformerDecoderAutoregressiveCache<B>,
     ) -> Tensor<B, 3> {
+        if let Some(positional_encoding) = &self.positional_encoding {
+            input.target = positional_encoding.forward(input.target);
+        }
+
         for i in 0..self.layers.len() {
             let layer = self.layers.get(i).unwrap();
             let cache = cache.layers.get_mut(i).unwrap();
@@ -2,10 +2,14 @@ use crate::tensor::Bool;
 use alloc::vec::Vec;
 
 use super::{PositionWiseFeedForward, PositionWiseFeedForwardConfig};
+use crate::generation::AutoregressiveCache;
 use crate::module::{Content, DisplaySettings, Module, ModuleDisplay};
 use crate::{
     self as burn,
-    nn::{attention::MhaCache, cache::TensorCache, Initializer},
+    nn::{
+        attention::MhaCache, cache::TensorCache, Initializer, PositionalEncodingLayer,
+        PositionalEncodingType,
+    },
 };
 use crate::{
     config::Config,
@@ -13,7 +17,7 @@ use crate::{
         attention::{MhaInput, MultiHeadAttention, MultiHeadAttentionConfig},
         Dropout, DropoutConfig, LayerNorm, LayerNormConfig,
     },
-    tensor::{backend::Backend, Tensor},
+    tensor::{backend::Backend, Int, Tensor},
 };
 
 /// Configuration to create a [Transformer Encoder](TransformerEncoder) layer using the [init function](TransformerEncoderConfig::init).
@@ -46,6 +50,9 @@ pub struct TransformerEncoderConfig {
         default = "Initializer::KaimingUniform{gain:1.0/num_traits::Float::sqrt(3.0), fan_out_only:false}"
     )]
     pub initializer: Initializer,
+    /// The positional encoding added to the input before the first layer. Default: None
+    #[config(default = "None")]
+    pub positional_encoding: Option<PositionalEncodingType>,
 }
 
 /// The transformer encoder module as describe in the paper [Attention Is All You Need](https://arxiv.org/abs/1706.03762).
@@ -81,6 +88,9 @@ pub struct TransformerEncoder<B: Backend> {
 
     /// Use "quiet softmax" instead of regular softmax.
     pub quiet_softmax: bool,
+
+    /// The positional encoding added to the input before the first layer.
+    pub positional_encoding: Option<PositionalEncodingLayer<B>>,
 }
 
 impl<B: Backend> ModuleDisplay for TransformerEncoder<B> {
@@ -149,6 +159,10 @@ impl TransformerEncoderConfig {
             dropout: self.dropout,
             norm_first: self.norm_first,
             quiet_softmax: self.quiet_softmax,
+            positional_encoding: self
+                .positional_encoding
+                .as_ref()
+                .map(|encoding| encoding.init(self.d_model, device)),
         }
     }
 }
@@ -163,6 +177,10 @@ impl<B: Backend> TransformerEncoder<B> {
     pub fn forward(&self, input: TransformerEncoderInput<B>) -> Tensor<B, 3> {
         let mut x = input.tensor;
 
+        if let Some(positional_encoding) = &self.positional_encoding {
+            x = positional_encoding.forward(x);
+        }
+
         for layer in self.layers.iter() {
             x = layer.forward(x, input.mask_pad.clone(), input.mask_attn.clone());
         }
@@ -182,6 +200,10 @@ impl<B: Backend> TransformerEncoder<B> {
     ) -> Tensor<B, 3> {
         let mut x = input.tensor;
 
+        if let Some(positional_encoding) = &self.positional_encoding {
+            x = positional_encoding.forward(x);
+        }
+
         for i in 0..self.layers.len() {
             let layer = self.layers.get(i).unwrap();
             let cache = cache.layers.get_mut(i).unwrap();
@@ -361,6 +383,13 @@ struct TransformerEncoderLayerAutoregressiveCache<B: Backend> {
 }
 
 impl<B: Backend> TransformerEncoderLayerAutoregressiveCache<B> {
+    fn reorder(&mut self, indices: Tensor<B, 1, Int>) {
+        self.mha.reorder_cache(indices.clone());
+        self.pwff.reorder(indices.clone());
+        self.norm_1.reorder(indices.clone());
+        self.norm_2.reorder(indices);
+    }
+
     fn empty() -> Self {
         Self {
             mha: MhaCache::autoregressive(),
@@ -378,6 +407,14 @@ pub struct TransformerEncoderAutoregressiveCache<B: Backend> {
     layers: Vec<TransformerEncoderLayerAutoregressiveCache<B>>,
 }
 
+impl<B: Backend> AutoregressiveCache<B> for TransformerEncoderAutoregressiveCache<B> {
+    fn reorder_cache(&mut self, indices: Tensor<B, 1, Int>) {
+        self.layers
+            .iter_mut()
+            .for_each(|layer| layer.reorder(indices.clone()));
+    }
+}
+
 impl<B: Backend> TransformerEncoderAutoregressiveCache<B> {
     fn empty(num_layers: usize) -> Self {
         Self {
@@ -444,6 +481,28 @@ mod tests {
             .assert_approx_eq(&output_2.into_data(), 3);
     }
 
+    #[test]
+    fn test_learned_positional_encoding() {
+        let [d_model, d_ff, n_heads, num_layers] = [12, 24, 2, 3];
+        let device = Default::default();
+        let config = TransformerEncoderConfig::new(d_model, d_ff, n_heads, num_layers)
+            .with_positional_encoding(Some(PositionalEncodingType::Learned {
+                max_sequence_size: 4,
+            }));
+        let transformer = config.init::<TestBackend>(&device);
+
+        // Longer than `max_sequence_size` so the table gets interpolated.
+        let tensor =
+            Tensor::<TestBackend, 3>::random([2, 6, d_model], Distribution::Default, &device);
+        let output = transformer.forward(TransformerEncoderInput::new(tensor));
+
+        assert_eq!(output.dims(), [2, 6, d_model]);
+        assert!(matches!(
+            transformer.positional_encoding,
+            Some(PositionalEncodingLayer::Learned(_))
+        ));
+    }
+
     #[test]
     fn display() {
         let config = TransformerEncoderConfig::new(2, 4, 2, 3);
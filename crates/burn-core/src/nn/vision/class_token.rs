@@ -0,0 +1,111 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::{Content, DisplaySettings, Module, ModuleDisplay, Param};
+use crate::nn::Initializer;
+use crate::tensor::backend::Backend;
+use crate::tensor::Tensor;
+
+/// Configuration to create a [ClassToken](ClassToken) parameter using the
+/// [init function](ClassTokenConfig::init).
+#[derive(Config, Debug)]
+pub struct ClassTokenConfig {
+    /// The size of the embedding of the token.
+    pub embed_dim: usize,
+    /// The type of function used to initialize the token.
+    #[config(default = "Initializer::Normal{mean:0.0, std:0.02}")]
+    pub initializer: Initializer,
+}
+
+/// A learned token prepended to the sequence of patch embeddings, its output being used as the
+/// representation of the whole image.
+///
+/// The token is of shape `[1, 1, embed_dim]`, as the `cls_token` of the `timm` vision
+/// transformers. Since those store it as a parameter of the model rather than of a module, their
+/// key has to be mapped to `cls_token.token` when loading them, for instance with
+/// `.with_key_remap("^cls_token$", "cls_token.token")` on the load arguments of the PyTorch
+/// recorder.
+///
+/// Should be created with [ClassTokenConfig].
+#[derive(Module, Debug)]
+#[module(custom_display)]
+pub struct ClassToken<B: Backend> {
+    /// The learned token of shape `[1, 1, embed_dim]`.
+    pub token: Param<Tensor<B, 3>>,
+}
+
+impl ClassTokenConfig {
+    /// Initialize a new [class token](ClassToken).
+    pub fn init<B: Backend>(&self, device: &B::Device) -> ClassToken<B> {
+        let token = self.initializer.init([1, 1, self.embed_dim], device);
+
+        ClassToken { token }
+    }
+}
+
+impl<B: Backend> ClassToken<B> {
+    /// Prepend the token to each sequence of the input.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, seq_length, embed_dim]`
+    /// - output: `[batch_size, seq_length + 1, embed_dim]`
+    pub fn forward(&self, input: Tensor<B, 3>) -> Tensor<B, 3> {
+        let [batch_size, _, embed_dim] = input.dims();
+        let token = self.token.val().expand([batch_size, 1, embed_dim]);
+
+        Tensor::cat(alloc::vec![token, input], 1)
+    }
+}
+
+impl<B: Backend> ModuleDisplay for ClassToken<B> {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        let [_, _, embed_dim] = self.token.shape().dims;
+
+        content.add("embed_dim", &embed_dim).optional()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::TensorData;
+    use crate::TestBackend;
+
+    #[test]
+    fn forward_prepends_token() {
+        let device = Default::default();
+        let class_token = ClassTokenConfig::new(2)
+            .with_initializer(Initializer::Constant { value: 5.0 })
+            .init::<TestBackend>(&device);
+        let input = Tensor::<TestBackend, 3>::ones([3, 4, 2], &device);
+
+        let output = class_token.forward(input);
+
+        assert_eq!(output.dims(), [3, 5, 2]);
+        output
+            .narrow(1, 0, 1)
+            .to_data()
+            .assert_approx_eq(&TensorData::from([[[5.0f32, 5.0]]; 3]), 3);
+        output
+            .narrow(1, 1, 4)
+            .to_data()
+            .assert_approx_eq(&TensorData::ones::<f32, _>([3, 4, 2]), 3);
+    }
+
+    #[test]
+    fn display() {
+        let class_token = ClassTokenConfig::new(8).init::<TestBackend>(&Default::default());
+
+        assert_eq!(
+            alloc::format!("{}", class_token),
+            "ClassToken {embed_dim: 8, params: 8}"
+        );
+    }
+}
@@ -0,0 +1,7 @@
+mod class_token;
+mod patch_embed;
+mod pos_embed;
+
+pub use class_token::*;
+pub use patch_embed::*;
+pub use pos_embed::*;
@@ -0,0 +1,192 @@
+use alloc::format;
+
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::{Content, DisplaySettings, Module, ModuleDisplay};
+use crate::nn::conv::{Conv2d, Conv2dConfig};
+use crate::nn::Initializer;
+use crate::tensor::backend::Backend;
+use crate::tensor::Tensor;
+
+/// Configuration to create a [PatchEmbed](PatchEmbed) layer using the
+/// [init function](PatchEmbedConfig::init).
+#[derive(Config, Debug)]
+pub struct PatchEmbedConfig {
+    /// The size `[height, width]` of the images the module is created for.
+    pub img_size: [usize; 2],
+    /// The size `[height, width]` of the patches.
+    pub patch_size: [usize; 2],
+    /// The number of channels of the images.
+    #[config(default = 3)]
+    pub in_chans: usize,
+    /// The size of the embedding of each patch.
+    #[config(default = 768)]
+    pub embed_dim: usize,
+    /// If a bias is added to the embeddings.
+    #[config(default = true)]
+    pub bias: bool,
+    /// The type of function used to initialize the projection.
+    #[config(
+        default = "Initializer::KaimingUniform{gain:1.0/num_traits::Float::sqrt(3.0),fan_out_only:false}"
+    )]
+    pub initializer: Initializer,
+}
+
+/// Split images into non-overlapping patches and project each of them to an embedding, as the
+/// input of a vision transformer.
+///
+/// The projection is a [Conv2d](Conv2d) with a stride equal to its kernel, so the records are the
+/// same as the `patch_embed` of the `timm` vision transformers: `proj.weight` of shape
+/// `[embed_dim, in_chans, patch_height, patch_width]` and `proj.bias` of shape `[embed_dim]`.
+///
+/// Should be created with [PatchEmbedConfig].
+#[derive(Module, Debug)]
+#[module(custom_display)]
+pub struct PatchEmbed<B: Backend> {
+    /// The projection of the patches.
+    pub proj: Conv2d<B>,
+    /// The size of the images the module is created for.
+    pub img_size: [usize; 2],
+    /// The size of the patches.
+    pub patch_size: [usize; 2],
+}
+
+impl PatchEmbedConfig {
+    /// Initialize a new [patch embedding](PatchEmbed) module.
+    pub fn init<B: Backend>(&self, device: &B::Device) -> PatchEmbed<B> {
+        let proj = Conv2dConfig::new([self.in_chans, self.embed_dim], self.patch_size)
+            .with_stride(self.patch_size)
+            .with_bias(self.bias)
+            .with_initializer(self.initializer.clone())
+            .init(device);
+
+        PatchEmbed {
+            proj,
+            img_size: self.img_size,
+            patch_size: self.patch_size,
+        }
+    }
+}
+
+impl<B: Backend> PatchEmbed<B> {
+    /// The number of patches `[rows, columns]` of an image of the given size, the pixels past the
+    /// last full patch of each dimension being dropped.
+    pub fn grid_size(&self, img_size: [usize; 2]) -> [usize; 2] {
+        [
+            img_size[0] / self.patch_size[0],
+            img_size[1] / self.patch_size[1],
+        ]
+    }
+
+    /// The number of patches of the images the module is created for.
+    pub fn num_patches(&self) -> usize {
+        let [rows, cols] = self.grid_size(self.img_size);
+        rows * cols
+    }
+
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// The patches are flattened in row-major order: the patch at row `i` and column `j` of the
+    /// grid is the token `i * columns + j` of the output, as with `flatten(2).transpose(1, 2)` in
+    /// PyTorch. The input doesn't have to be of the size the module is created for, the number of
+    /// tokens following its [grid size](PatchEmbed::grid_size).
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, in_chans, height, width]`
+    /// - output: `[batch_size, rows * columns, embed_dim]`
+    pub fn forward(&self, input: Tensor<B, 4>) -> Tensor<B, 3> {
+        let output = self.proj.forward(input);
+        let [batch_size, embed_dim, rows, cols] = output.dims();
+
+        output
+            .reshape([batch_size, embed_dim, rows * cols])
+            .swap_dims(1, 2)
+    }
+}
+
+impl<B: Backend> ModuleDisplay for PatchEmbed<B> {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        let [embed_dim, in_chans, _, _] = self.proj.weight.shape().dims;
+
+        content
+            .add("img_size", &format!("{:?}", self.img_size))
+            .add("patch_size", &format!("{:?}", self.patch_size))
+            .add("in_chans", &in_chans)
+            .add("embed_dim", &embed_dim)
+            .optional()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::TensorData;
+    use crate::TestBackend;
+
+    #[test]
+    fn forward_shape_non_square() {
+        let device = Default::default();
+        let embed = PatchEmbedConfig::new([32, 48], [16, 8])
+            .with_embed_dim(10)
+            .init::<TestBackend>(&device);
+
+        let output = embed.forward(Tensor::zeros([2, 3, 32, 48], &device));
+
+        assert_eq!(embed.num_patches(), 12);
+        assert_eq!(output.dims(), [2, 12, 10]);
+    }
+
+    #[test]
+    fn forward_shape_other_size() {
+        let device = Default::default();
+        let embed = PatchEmbedConfig::new([32, 32], [8, 8])
+            .with_embed_dim(4)
+            .init::<TestBackend>(&device);
+
+        let output = embed.forward(Tensor::zeros([1, 3, 20, 41], &device));
+
+        assert_eq!(embed.grid_size([20, 41]), [2, 5]);
+        assert_eq!(output.dims(), [1, 10, 4]);
+    }
+
+    #[test]
+    fn forward_row_major_order() {
+        let device = Default::default();
+        let embed = PatchEmbedConfig::new([2, 3], [1, 1])
+            .with_in_chans(1)
+            .with_embed_dim(1)
+            .with_bias(false)
+            .with_initializer(Initializer::Ones)
+            .init::<TestBackend>(&device);
+        let input = Tensor::<TestBackend, 1>::arange(0..6, &device)
+            .float()
+            .reshape([1, 1, 2, 3]);
+
+        let output = embed.forward(input);
+
+        output.to_data().assert_approx_eq(
+            &TensorData::from([[[0.0f32], [1.0], [2.0], [3.0], [4.0], [5.0]]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn display() {
+        let embed =
+            PatchEmbedConfig::new([224, 224], [16, 16]).init::<TestBackend>(&Default::default());
+
+        assert_eq!(
+            alloc::format!("{}", embed),
+            "PatchEmbed {img_size: [224, 224], patch_size: [16, 16], \
+            in_chans: 3, embed_dim: 768, params: 590592}"
+        );
+    }
+}
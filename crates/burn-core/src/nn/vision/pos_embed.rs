@@ -0,0 +1,183 @@
+use alloc::vec;
+
+use crate::tensor::backend::Backend;
+use crate::tensor::module::interpolate;
+use crate::tensor::ops::{InterpolateMode, InterpolateOptions};
+use crate::tensor::Tensor;
+
+/// Resize a table of position embeddings to a new grid of patches, for instance to fine-tune a
+/// vision transformer on images of another resolution than the one it was pretrained on.
+///
+/// The table is of shape `[1, num_prefix + rows * columns, embed_dim]`, the embeddings of the
+/// patches following the row-major order of the [patch embedding](super::PatchEmbed), as the
+/// `pos_embed` of the `timm` vision transformers. The grid of the table is square, and the
+/// embedding of the class token, when there is one, comes first: it is kept as is, while the
+/// embeddings of the patches are resized with a bilinear interpolation over the 2-D grid.
+///
+/// # Shapes
+///
+/// - table: `[batch_size, num_prefix + size * size, embed_dim]`, `num_prefix` being `0` or `1`
+/// - output: `[batch_size, num_prefix + new_grid[0] * new_grid[1], embed_dim]`
+///
+/// # Panics
+///
+/// If the number of embeddings of the table isn't the one of a square grid, with or without a
+/// class token.
+pub fn interpolate_pos_embed<B: Backend>(
+    table: Tensor<B, 3>,
+    new_grid: [usize; 2],
+) -> Tensor<B, 3> {
+    let [batch_size, num_embeddings, embed_dim] = table.dims();
+    let (num_prefix, size) = match square_root(num_embeddings) {
+        Some(size) => (0, size),
+        None => match num_embeddings.checked_sub(1).and_then(square_root) {
+            Some(size) => (1, size),
+            None => panic!(
+                "The {num_embeddings} position embeddings don't cover a square grid of patches, \
+                with or without a class token"
+            ),
+        },
+    };
+
+    if [size, size] == new_grid {
+        return table;
+    }
+
+    let [rows, cols] = new_grid;
+    let grid = table
+        .clone()
+        .narrow(1, num_prefix, size * size)
+        .reshape([batch_size, size, size, embed_dim])
+        .permute([0, 3, 1, 2]);
+    let grid = interpolate(
+        grid,
+        new_grid,
+        InterpolateOptions::new(InterpolateMode::Bilinear),
+    )
+    .permute([0, 2, 3, 1])
+    .reshape([batch_size, rows * cols, embed_dim]);
+
+    if num_prefix == 0 {
+        return grid;
+    }
+
+    Tensor::cat(vec![table.narrow(1, 0, num_prefix), grid], 1)
+}
+
+/// The integer square root of the value when it is a perfect square.
+fn square_root(value: usize) -> Option<usize> {
+    let root = num_traits::Float::sqrt(value as f64).round() as usize;
+
+    (root * root == value).then_some(root)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::TensorData;
+    use crate::TestBackend;
+
+    #[test]
+    fn resize_non_square_with_class_token() {
+        let device = Default::default();
+        let table = Tensor::<TestBackend, 3>::ones([1, 1 + 4 * 4, 8], &device);
+        let table = Tensor::cat(
+            vec![
+                Tensor::full([1, 1, 8], -1.0, &device),
+                table.narrow(1, 1, 16),
+            ],
+            1,
+        );
+
+        let output = interpolate_pos_embed(table, [3, 6]);
+
+        assert_eq!(output.dims(), [1, 1 + 3 * 6, 8]);
+        output
+            .clone()
+            .narrow(1, 0, 1)
+            .to_data()
+            .assert_approx_eq(&TensorData::from([[[-1.0f32; 8]]]), 3);
+        output
+            .narrow(1, 1, 18)
+            .to_data()
+            .assert_approx_eq(&TensorData::ones::<f32, _>([1, 18, 8]), 3);
+    }
+
+    #[test]
+    fn resize_without_class_token() {
+        let device = Default::default();
+        // The embedding of the patch at row r and column c is [2r + c, 10 * (2r + c)]
+        let table = Tensor::<TestBackend, 3>::from_floats(
+            [[[0.0, 0.0], [1.0, 10.0], [2.0, 20.0], [3.0, 30.0]]],
+            &device,
+        );
+
+        let output = interpolate_pos_embed(table, [3, 2]);
+
+        output.to_data().assert_approx_eq(
+            &TensorData::from([[
+                [0.0f32, 0.0],
+                [1.0, 10.0],
+                [1.0, 10.0],
+                [2.0, 20.0],
+                [2.0, 20.0],
+                [3.0, 30.0],
+            ]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn resize_with_class_token_should_interpolate_the_rows_and_columns() {
+        let device = Default::default();
+        let table = Tensor::<TestBackend, 3>::from_floats(
+            [[
+                [-1.0, -2.0],
+                [0.0, 0.0],
+                [1.0, 10.0],
+                [2.0, 20.0],
+                [3.0, 30.0],
+            ]],
+            &device,
+        );
+
+        let output = interpolate_pos_embed(table, [3, 3]);
+
+        // The corners of the grids are aligned, the class token being kept in front
+        output.to_data().assert_approx_eq(
+            &TensorData::from([[
+                [-1.0f32, -2.0],
+                [0.0, 0.0],
+                [0.5, 5.0],
+                [1.0, 10.0],
+                [1.0, 10.0],
+                [1.5, 15.0],
+                [2.0, 20.0],
+                [2.0, 20.0],
+                [2.5, 25.0],
+                [3.0, 30.0],
+            ]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn same_grid_is_identity() {
+        let device = Default::default();
+        let table = Tensor::<TestBackend, 1>::arange(0..(1 + 2 * 2) * 3, &device)
+            .float()
+            .reshape([1, 5, 3]);
+
+        let output = interpolate_pos_embed(table.clone(), [2, 2]);
+
+        output.to_data().assert_eq(&table.to_data(), true);
+    }
+
+    #[test]
+    #[should_panic(expected = "don't cover a square grid of patches")]
+    fn not_square_grid() {
+        let table = Tensor::<TestBackend, 3>::zeros([1, 7, 4], &Default::default());
+
+        interpolate_pos_embed(table, [2, 2]);
+    }
+}
@@ -65,6 +65,13 @@ impl<B: Backend> SimpleOptimizer<B> for AdaGrad<B> {
         (tensor - grad, Some(state))
     }
 
+    fn without_weight_decay(&self) -> Self {
+        Self {
+            weight_decay: None,
+            ..self.clone()
+        }
+    }
+
     fn to_device<const D: usize>(
         mut state: Self::State<D>,
         device: &<B as Backend>::Device,
@@ -95,6 +102,9 @@ impl AdaGradConfig {
         if let Some(config) = &self.grad_clipping {
             optim = optim.with_grad_clipping(config.init());
         }
+        if let Some(config) = &self.weight_decay {
+            optim = optim.with_weight_decay_exclusion(config.exclude.clone());
+        }
         optim
     }
 }
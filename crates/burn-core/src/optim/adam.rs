@@ -10,7 +10,9 @@ use super::{
 use crate::config::Config;
 use crate::optim::adaptor::OptimizerAdaptor;
 use crate::tensor::{backend::AutodiffBackend, Tensor};
-use burn_tensor::{backend::Backend, ElementConversion};
+use burn_tensor::backend::Backend;
+use burn_tensor::ops::{AdamStepInput, AdamStepOptions};
+use burn_tensor::TensorPrimitive;
 
 /// Adam configuration.
 #[derive(Config)]
@@ -50,25 +52,22 @@ impl<B: Backend> SimpleOptimizer<B> for Adam<B> {
         &self,
         lr: LearningRate,
         tensor: Tensor<B, D>,
-        mut grad: Tensor<B, D>,
+        grad: Tensor<B, D>,
         state: Option<Self::State<D>>,
     ) -> (Tensor<B, D>, Option<Self::State<D>>) {
-        let mut state_momentum = None;
+        let penalty = self.weight_decay.as_ref().map(WeightDecay::penalty);
+        let (tensor, state_momentum) =
+            self.momentum
+                .step(lr, tensor, grad, state.map(|state| state.momentum), penalty);
 
-        if let Some(state) = state {
-            state_momentum = Some(state.momentum);
-        }
+        (tensor, Some(AdamState::new(state_momentum)))
+    }
 
-        if let Some(weight_decay) = &self.weight_decay {
-            grad = weight_decay.transform(grad, tensor.clone());
+    fn without_weight_decay(&self) -> Self {
+        Self {
+            weight_decay: None,
+            ..self.clone()
         }
-
-        let (grad, state_momentum) = self.momentum.transform(grad, state_momentum);
-
-        let state = AdamState::new(state_momentum);
-        let delta = grad.mul_scalar(lr);
-
-        (tensor - delta, Some(state))
     }
 
     fn to_device<const D: usize>(
@@ -102,6 +101,9 @@ impl AdamConfig {
         if let Some(config) = &self.grad_clipping {
             optim = optim.with_grad_clipping(config.init());
         }
+        if let Some(config) = &self.weight_decay {
+            optim = optim.with_weight_decay_exclusion(config.exclude.clone());
+        }
         optim
     }
 }
@@ -122,50 +124,47 @@ struct AdaptiveMomentum {
 }
 
 impl AdaptiveMomentum {
-    pub fn transform<B: Backend, const D: usize>(
+    /// Update the parameters and the moments with the backend
+    /// [Adam step](burn_tensor::ops::OptimizerOps::adam_step).
+    pub fn step<B: Backend, const D: usize>(
         &self,
+        lr: LearningRate,
+        tensor: Tensor<B, D>,
         grad: Tensor<B, D>,
         momentum_state: Option<AdaptiveMomentumState<B, D>>,
+        penalty: Option<f64>,
     ) -> (Tensor<B, D>, AdaptiveMomentumState<B, D>) {
-        let state = if let Some(mut state) = momentum_state {
-            let factor = 1.0 - self.beta_1;
-            state.moment_1 = state
-                .moment_1
-                .mul_scalar(self.beta_1)
-                .add(grad.clone().mul_scalar(factor));
-
-            let factor = 1.0 - self.beta_2;
-            state.moment_2 = state
-                .moment_2
-                .mul_scalar(self.beta_2)
-                .add(grad.powf_scalar(2.0).mul_scalar(factor));
-
-            state.time += 1;
-
-            state
-        } else {
-            let factor = 1.0 - self.beta_1;
-            let moment_1 = grad.clone().mul_scalar(factor);
-
-            let factor = 1.0 - self.beta_2;
-            let moment_2 = grad.powf_scalar(2.0).mul_scalar(factor);
-
-            AdaptiveMomentumState::new(1, moment_1, moment_2)
+        let (moments, time) = match momentum_state {
+            Some(state) => (
+                Some((
+                    state.moment_1.into_primitive().tensor(),
+                    state.moment_2.into_primitive().tensor(),
+                )),
+                state.time + 1,
+            ),
+            None => (None, 1),
         };
 
-        let time = (state.time as i32).elem();
-        let moment_1_corrected = state
-            .moment_1
-            .clone()
-            .div_scalar(1f32 - self.beta_1.powi(time));
-        let moment_2_corrected = state
-            .moment_2
-            .clone()
-            .div_scalar(1f32 - self.beta_2.powi(time));
+        let input = AdamStepInput {
+            tensor: tensor.into_primitive().tensor(),
+            grad: grad.into_primitive().tensor(),
+            moments,
+        };
+        let options = AdamStepOptions {
+            lr,
+            beta_1: self.beta_1,
+            beta_2: self.beta_2,
+            epsilon: self.epsilon,
+            penalty,
+            weight_decay: None,
+        };
+        let output = B::adam_step(input, time, &options);
 
-        let grad = moment_1_corrected.div(moment_2_corrected.sqrt().add_scalar(self.epsilon));
+        let float = |tensor| Tensor::<B, D>::from_primitive(TensorPrimitive::Float(tensor));
+        let state =
+            AdaptiveMomentumState::new(time, float(output.moment_1), float(output.moment_2));
 
-        (grad, state)
+        (float(output.tensor), state)
     }
 }
 
@@ -8,7 +8,9 @@ use super::SimpleOptimizer;
 use crate::config::Config;
 use crate::optim::adaptor::OptimizerAdaptor;
 use crate::tensor::{backend::AutodiffBackend, Tensor};
-use burn_tensor::{backend::Backend, ElementConversion};
+use burn_tensor::backend::Backend;
+use burn_tensor::ops::{AdamStepInput, AdamStepOptions};
+use burn_tensor::TensorPrimitive;
 
 /// AdamW configuration.
 #[derive(Config)]
@@ -25,6 +27,10 @@ pub struct AdamWConfig {
     /// Weight decay config.
     #[config(default = 1e-4)]
     weight_decay: f32,
+    /// Patterns of the paths of the parameters excluded from the weight decay, see
+    /// [WeightDecayConfig::exclude](crate::optim::decay::WeightDecayConfig::exclude).
+    #[config(default = "Vec::new()")]
+    weight_decay_exclude: Vec<String>,
     /// [Gradient Clipping](GradientClippingConfig) config.
     grad_clipping: Option<GradientClippingConfig>,
 }
@@ -58,15 +64,26 @@ impl<B: Backend> SimpleOptimizer<B> for AdamW<B> {
         // State of the optimizer.
         state: Option<Self::State<D>>,
     ) -> (Tensor<B, D>, Option<Self::State<D>>) {
-        let tensor_updated = tensor.clone() - tensor.mul_scalar(lr).mul_scalar(self.weight_decay);
-
-        let (raw_delta, momentum_state) = self.momentum.transform(grad, state.map(|s| s.momentum));
+        let (tensor, momentum_state) = self.momentum.step(
+            lr,
+            tensor,
+            grad,
+            state.map(|s| s.momentum),
+            self.weight_decay,
+        );
 
         let state = AdamWState {
             momentum: momentum_state,
         };
 
-        (tensor_updated - raw_delta.mul_scalar(lr), Some(state))
+        (tensor, Some(state))
+    }
+
+    fn without_weight_decay(&self) -> Self {
+        Self {
+            weight_decay: 0.0,
+            ..self.clone()
+        }
     }
 
     fn to_device<const D: usize>(
@@ -101,6 +118,7 @@ impl AdamWConfig {
         if let Some(config) = &self.grad_clipping {
             optim = optim.with_grad_clipping(config.init());
         }
+        optim = optim.with_weight_decay_exclusion(self.weight_decay_exclude.clone());
         optim
     }
 }
@@ -121,63 +139,48 @@ struct AdaptiveMomentumW {
 }
 
 impl AdaptiveMomentumW {
-    pub fn transform<B: Backend, const D: usize>(
+    /// Update the parameters and the moments with the backend
+    /// [Adam step](burn_tensor::ops::OptimizerOps::adam_step), decaying the parameters
+    /// independently of the gradient.
+    pub fn step<B: Backend, const D: usize>(
         &self,
+        lr: LearningRate,
+        tensor: Tensor<B, D>,
         grad: Tensor<B, D>,
         state: Option<AdaptiveMomentumWState<B, D>>,
+        weight_decay: f32,
     ) -> (Tensor<B, D>, AdaptiveMomentumWState<B, D>) {
-        let state = if let Some(mut state) = state {
-            // Update first moment estimate.
-            let factor = 1.0 - self.beta_1;
-            state.moment_1 = state
-                .moment_1
-                .mul_scalar(self.beta_1)
-                .add(grad.clone().mul_scalar(factor));
-
-            // Update second moment estimate.
-            let factor = 1.0 - self.beta_2;
-            state.moment_2 = state
-                .moment_2
-                .mul_scalar(self.beta_2)
-                .add(grad.powf_scalar(2.0).mul_scalar(factor));
-
-            // Update time.
-            state.time += 1;
-
-            state
-        } else {
-            // Initialize first moment estimate.
-            let factor = 1.0 - self.beta_1;
-            let moment_1 = grad.clone().mul_scalar(factor);
-
-            // Initialize second moment estimate.
-            let factor = 1.0 - self.beta_2;
-            let moment_2 = grad.powf_scalar(2.0).mul_scalar(factor);
-
-            AdaptiveMomentumWState::new(1, moment_1, moment_2)
+        let (moments, time) = match state {
+            Some(state) => (
+                Some((
+                    state.moment_1.into_primitive().tensor(),
+                    state.moment_2.into_primitive().tensor(),
+                )),
+                state.time + 1,
+            ),
+            None => (None, 1),
         };
 
-        let time: i32 = (state.time as i32).elem();
-
-        // Compute bias-corrected first and second moment estimates.
-        let moment_1_corrected = state
-            .moment_1
-            .clone()
-            .div_scalar(1f32 - self.beta_1.powi(time));
-
-        let moment_2_corrected = state
-            .moment_2
-            .clone()
-            .div_scalar(1f32 - self.beta_2.powi(time));
+        let input = AdamStepInput {
+            tensor: tensor.into_primitive().tensor(),
+            grad: grad.into_primitive().tensor(),
+            moments,
+        };
+        let options = AdamStepOptions {
+            lr,
+            beta_1: self.beta_1,
+            beta_2: self.beta_2,
+            epsilon: self.epsilon,
+            penalty: None,
+            weight_decay: Some(weight_decay),
+        };
+        let output = B::adam_step(input, time, &options);
 
-        // Compute update delta. This still needs to be scaled by the learning rate.
-        let update_delta =
-            moment_1_corrected.div(moment_2_corrected.sqrt().add_scalar(self.epsilon));
+        let float = |tensor| Tensor::<B, D>::from_primitive(TensorPrimitive::Float(tensor));
+        let state =
+            AdaptiveMomentumWState::new(time, float(output.moment_1), float(output.moment_2));
 
-        (
-            update_delta,
-            AdaptiveMomentumWState::new(state.time, state.moment_1, state.moment_2),
-        )
+        (float(output.tensor), state)
     }
 }
 
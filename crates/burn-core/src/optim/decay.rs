@@ -2,6 +2,7 @@ use burn_tensor::backend::Backend;
 
 use crate as burn;
 use crate::record::Record;
+use alloc::{string::String, vec::Vec};
 
 use crate::config::Config;
 use crate::tensor::{ElementConversion, Tensor};
@@ -11,6 +12,14 @@ use crate::tensor::{ElementConversion, Tensor};
 pub struct WeightDecayConfig {
     /// L2 penalty.
     pub penalty: f64,
+    /// Patterns of the paths of the parameters excluded from the weight decay. Default: none
+    ///
+    /// The paths are made of the names of the fields, and the indices of the vectors, separated
+    /// by dots, like `layers.0.norm.gamma`. The `*` component of a pattern matches any single
+    /// component and the `**` component matches any number of them, so `**.bias` excludes all
+    /// the biases.
+    #[config(default = "Vec::new()")]
+    pub exclude: Vec<String>,
 }
 
 /// State of [weight decay](WeightDecay).
@@ -33,6 +42,11 @@ impl<B: Backend> WeightDecay<B> {
         }
     }
 
+    /// The L2 penalty.
+    pub fn penalty(&self) -> f64 {
+        self.penalty.elem()
+    }
+
     /// Transforms a gradient.
     ///
     /// # Arguments
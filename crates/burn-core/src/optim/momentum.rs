@@ -61,15 +61,15 @@ impl<B: Backend> Momentum<B> {
         state: Option<MomentumState<B, D>>,
     ) -> (Tensor<B, D>, MomentumState<B, D>) {
         let velocity = if let Some(state) = state {
-            grad.clone()
-                .mul_scalar(1.0 - self.dampening)
-                .add(state.velocity.mul_scalar(self.momentum))
+            state
+                .velocity
+                .axpby(self.momentum, grad.clone(), 1.0 - self.dampening)
         } else {
             grad.clone()
         };
 
         let grad = match self.nesterov {
-            true => velocity.clone().mul_scalar(self.momentum).add(grad),
+            true => velocity.clone().axpby(self.momentum, grad, 1.0),
             false => velocity.clone(),
         };
 
@@ -58,6 +58,10 @@ impl RmsPropConfig {
             optim = optim.with_grad_clipping(config.init());
         }
 
+        if let Some(config) = &self.weight_decay {
+            optim = optim.with_weight_decay_exclusion(config.exclude.clone());
+        }
+
         optim
     }
 }
@@ -125,6 +129,13 @@ impl<B: Backend> SimpleOptimizer<B> for RmsProp<B> {
         (tensor - delta, Some(state))
     }
 
+    fn without_weight_decay(&self) -> Self {
+        Self {
+            weight_decay: None,
+            ..self.clone()
+        }
+    }
+
     fn to_device<const D: usize>(
         mut state: Self::State<D>,
         device: &<B as Backend>::Device,
@@ -155,10 +166,10 @@ impl<B: Backend, const D: usize> SquareAvgState<B, D> {
     fn transform(alpha: f32, grad: Tensor<B, D>, state: Option<Self>) -> (Tensor<B, D>, Self) {
         match state {
             Some(state) => {
-                let square_avg = state
-                    .square_avg
-                    .mul_scalar(alpha)
-                    .add(grad.clone().powf_scalar(2.).mul_scalar(1. - alpha));
+                let square_avg =
+                    state
+                        .square_avg
+                        .axpby(alpha, grad.clone().powf_scalar(2.), 1. - alpha);
                 (grad, Self { square_avg })
             }
             _ => {
@@ -200,19 +211,15 @@ impl<B: Backend, const D: usize> CenteredState<B, D> {
         centered_state: Option<Self>,
     ) -> (Tensor<B, D>, SquareAvgState<B, D>, Self) {
         if centered {
-            let grad_avg_constant = grad.clone().mul_scalar(1. - alpha);
-            let grad_avg = match centered_state {
-                Some(state) => state
-                    .grad_avg
-                    .map_or(grad_avg_constant.clone(), move |grad_avg| {
-                        grad_avg.mul_scalar(alpha).add(grad_avg_constant)
-                    }),
-                _ => grad_avg_constant,
+            let grad_avg = match centered_state.and_then(|state| state.grad_avg) {
+                Some(grad_avg) => grad_avg.axpby(alpha, grad.clone(), 1. - alpha),
+                None => grad.clone().mul_scalar(1. - alpha),
             };
-            let avg = square_avg_state
-                .square_avg
-                .clone()
-                .sub(grad_avg.clone().powf_scalar(2.));
+            let avg = square_avg_state.square_avg.clone().addcmul(
+                grad_avg.clone(),
+                grad_avg.clone(),
+                -1.,
+            );
 
             (
                 grad,
@@ -274,7 +281,7 @@ impl RmsPropMomentum {
 
         if self.momentum > 0. {
             let buf = match momentum_state {
-                Some(state) => state.buf.mul_scalar(self.momentum).add(grad),
+                Some(state) => state.buf.axpby(self.momentum, grad, 1.),
                 _ => grad,
             };
             (
@@ -539,7 +546,7 @@ mod tests {
             alpha: 0.99,
             epsilon: 1e-9,
             centered: false,
-            weight_decay: Some(WeightDecayConfig { penalty: 0.05 }),
+            weight_decay: Some(WeightDecayConfig::new(0.05)),
             momentum: 0.9,
             grad_clipping: None,
         }
@@ -20,6 +20,11 @@ pub struct SgdConfig {
     momentum: Option<MomentumConfig>,
     /// [Gradient Clipping](GradientClippingConfig) config.
     gradient_clipping: Option<GradientClippingConfig>,
+    /// Decays the parameters directly, scaled by the learning rate, instead of adding the
+    /// penalty to the gradient before the momentum, like [AdamW](crate::optim::AdamW) does for
+    /// Adam.
+    #[config(default = false)]
+    decoupled_weight_decay: bool,
 }
 
 /// Optimizer that implements stochastic gradient descent with momentum.
@@ -29,6 +34,7 @@ pub struct SgdConfig {
 pub struct Sgd<B: Backend> {
     momentum: Option<Momentum<B>>,
     weight_decay: Option<WeightDecay<B>>,
+    decoupled_weight_decay: bool,
 }
 
 /// State of [Sgd](Sgd).
@@ -48,10 +54,14 @@ impl SgdConfig {
         let mut optim = OptimizerAdaptor::from(Sgd {
             momentum,
             weight_decay,
+            decoupled_weight_decay: self.decoupled_weight_decay,
         });
         if let Some(config) = &self.gradient_clipping {
             optim = optim.with_grad_clipping(config.init());
         }
+        if let Some(config) = &self.weight_decay {
+            optim = optim.with_weight_decay_exclusion(config.exclude.clone());
+        }
         optim
     }
 }
@@ -73,7 +83,9 @@ impl<B: Backend> SimpleOptimizer<B> for Sgd<B> {
         }
 
         if let Some(weight_decay) = &self.weight_decay {
-            grad = weight_decay.transform(grad, tensor.clone());
+            if !self.decoupled_weight_decay {
+                grad = weight_decay.transform(grad, tensor.clone());
+            }
         }
 
         if let Some(momentum) = &self.momentum {
@@ -85,9 +97,23 @@ impl<B: Backend> SimpleOptimizer<B> for Sgd<B> {
         let state = SgdState::new(state_momemtum);
         let delta = grad.mul_scalar(lr);
 
+        let tensor = match &self.weight_decay {
+            Some(weight_decay) if self.decoupled_weight_decay => {
+                tensor.mul_scalar(1.0 - lr * weight_decay.penalty())
+            }
+            _ => tensor,
+        };
+
         (tensor - delta, Some(state))
     }
 
+    fn without_weight_decay(&self) -> Self {
+        Self {
+            weight_decay: None,
+            ..self.clone()
+        }
+    }
+
     fn to_device<const D: usize>(mut state: Self::State<D>, device: &B::Device) -> Self::State<D> {
         state.momentum = state.momentum.map(|state| state.to_device(device));
         state
@@ -101,7 +127,7 @@ mod tests {
         grad_clipping::GradientClipping,
         nn::{Linear, LinearConfig},
         optim::{GradientsParams, Optimizer},
-        tensor::{Distribution, Shape},
+        tensor::{Distribution, ElementConversion, Shape},
         TestAutodiffBackend, TestBackend,
     };
 
@@ -155,6 +181,73 @@ mod tests {
         assert_eq!(record.len(), state_restored.len());
     }
 
+    #[test]
+    fn excluded_params_should_not_be_decayed() {
+        let device = Default::default();
+        let mut layer = layer::<TestAutodiffBackend>(&device);
+        let mut optim = SgdConfig::new()
+            .with_weight_decay(Some(
+                WeightDecayConfig::new(0.5).with_exclude(vec!["bias".to_string()]),
+            ))
+            .init();
+        let weight = layer.weight.val().into_data();
+        let bias = layer.bias.as_ref().unwrap().val().into_data();
+
+        for _ in 0..2 {
+            // Only the weight decay changes the parameters, since the gradients are zeros.
+            let loss = layer.forward(random_tensor(&device)).mul_scalar(0.0).sum();
+            let grads = GradientsParams::from_grads(loss.backward(), &layer);
+            layer = optim.step(LEARNING_RATE, layer, grads);
+        }
+
+        layer
+            .bias
+            .as_ref()
+            .unwrap()
+            .val()
+            .into_data()
+            .assert_eq(&bias, true);
+        assert_ne!(layer.weight.val().into_data(), weight);
+    }
+
+    #[test]
+    fn decoupled_weight_decay_should_not_go_through_the_momentum() {
+        let device = Default::default();
+        let momentum = MomentumConfig {
+            momentum: 0.9,
+            dampening: 0.0,
+            nesterov: false,
+        };
+        let sgd = |decoupled_weight_decay| Sgd::<TestBackend> {
+            momentum: Some(Momentum::new(&momentum)),
+            weight_decay: Some(WeightDecay::new(&WeightDecayConfig::new(0.1))),
+            decoupled_weight_decay,
+        };
+
+        // Minimizes `x^2 / 2`, whose gradient is `x`.
+        let steps = |optim: Sgd<TestBackend>| {
+            let mut x = Tensor::<TestBackend, 1>::from_floats([1.0], &device);
+            let mut state = None;
+            let mut values = Vec::new();
+
+            for _ in 0..2 {
+                let (x_new, state_new) = optim.step(0.1, x.clone(), x.clone(), state);
+                values.push(x_new.clone().into_scalar().elem::<f32>());
+                x = x_new;
+                state = state_new;
+            }
+            values
+        };
+
+        let coupled = steps(sgd(false));
+        let decoupled = steps(sgd(true));
+
+        assert!((coupled[0] - 0.89).abs() < 1e-5);
+        assert!((coupled[1] - 0.6931).abs() < 1e-5);
+        assert!((decoupled[0] - 0.89).abs() < 1e-5);
+        assert!((decoupled[1] - 0.7021).abs() < 1e-5);
+    }
+
     fn random_tensor<B: Backend>(device: &B::Device) -> Tensor<B, 2> {
         Tensor::<B, 2>::random(Shape::new([2, 20]), Distribution::Default, device)
     }
@@ -166,13 +259,14 @@ mod tests {
     fn sgd_with_all(
     ) -> OptimizerAdaptor<Sgd<TestBackend>, Linear<TestAutodiffBackend>, TestAutodiffBackend> {
         SgdConfig {
-            weight_decay: Some(WeightDecayConfig { penalty: 0.05 }),
+            weight_decay: Some(WeightDecayConfig::new(0.05)),
             momentum: Some(MomentumConfig {
                 momentum: 0.9,
                 dampening: 0.1,
                 nesterov: true,
             }),
             gradient_clipping: None,
+            decoupled_weight_decay: false,
         }
         .init()
     }
@@ -1,10 +1,11 @@
 use super::{record::AdaptorRecord, SimpleOptimizer};
 use crate::{
     grad_clipping::GradientClipping,
-    module::{AutodiffModule, ModuleMapper, ParamId},
+    module::{path_matches, AutodiffModule, ModuleMapper, ParamId},
     optim::{GradientsParams, Optimizer},
     LearningRate,
 };
+use alloc::{string::String, vec::Vec};
 use burn_tensor::{backend::AutodiffBackend, Tensor};
 use core::marker::PhantomData;
 use hashbrown::HashMap;
@@ -22,6 +23,22 @@ where
     records: HashMap<ParamId, AdaptorRecord<O, B>>,
     module: PhantomData<M>,
     grad_clipping: Option<GradientClipping>,
+    weight_decay_exclusion: Option<WeightDecayExclusion<O>>,
+}
+
+/// The parameters excluded from the weight decay, with the optimizer applied to them.
+#[derive(Clone)]
+struct WeightDecayExclusion<O> {
+    patterns: Vec<String>,
+    optim: O,
+}
+
+impl<O> WeightDecayExclusion<O> {
+    fn excludes(&self, path: &str) -> bool {
+        self.patterns
+            .iter()
+            .any(|pattern| path_matches(pattern, path))
+    }
 }
 
 impl<O, B, M> From<O> for OptimizerAdaptor<O, M, B>
@@ -36,6 +53,7 @@ where
             records: HashMap::new(),
             module: PhantomData,
             grad_clipping: None,
+            weight_decay_exclusion: None,
         }
     }
 }
@@ -60,6 +78,30 @@ where
         self
     }
 
+    /// Excludes the parameters whose path matches one of the patterns from the weight decay.
+    ///
+    /// The paths are made of the names of the fields, and the indices of the vectors, separated
+    /// by dots, like `layers.0.norm.gamma`. The `*` component of a pattern matches any single
+    /// component and the `**` component matches any number of them.
+    ///
+    /// # Arguments
+    ///
+    /// * `patterns` - The patterns of the excluded parameters.
+    ///
+    /// # Returns
+    ///
+    /// The optimizer.
+    pub fn with_weight_decay_exclusion(mut self, patterns: Vec<String>) -> Self {
+        self.weight_decay_exclusion = match patterns.is_empty() {
+            true => None,
+            false => Some(WeightDecayExclusion {
+                patterns,
+                optim: self.optim.without_weight_decay(),
+            }),
+        };
+        self
+    }
+
     #[cfg(test)]
     pub(crate) fn has_gradient_clipping(&self) -> bool {
         self.grad_clipping.is_some()
@@ -81,6 +123,8 @@ where
             &mut grads,
             lr,
             self.grad_clipping.as_ref(),
+            self.weight_decay_exclusion.as_ref(),
+            Vec::new(),
         );
         module.map(&mut mapper)
     }
@@ -108,6 +152,8 @@ where
     lr: LearningRate,
     phantom: PhantomData<M>,
     grad_clipping: Option<&'a GradientClipping>,
+    weight_decay_exclusion: Option<&'a WeightDecayExclusion<O>>,
+    path: Vec<String>,
 }
 
 impl<'a, M, B, O> ModuleMapper<B> for SimpleOptimizerMapper<'a, M, B, O>
@@ -130,7 +176,12 @@ where
                 grad
             };
 
-            let (tensor, state) = self.optimizer.step(
+            let optimizer = match self.weight_decay_exclusion {
+                Some(exclusion) if exclusion.excludes(&self.path.join(".")) => &exclusion.optim,
+                _ => self.optimizer,
+            };
+
+            let (tensor, state) = optimizer.step(
                 self.lr,
                 tensor.inner(),
                 clipped_grad,
@@ -153,4 +204,12 @@ where
 
         tensor
     }
+
+    fn enter_module(&mut self, name: &str) {
+        self.path.push(String::from(name));
+    }
+
+    fn exit_module(&mut self, _name: &str) {
+        self.path.pop();
+    }
 }
@@ -25,6 +25,15 @@ where
         state: Option<Self::State<D>>,
     ) -> (Tensor<B, D>, Option<Self::State<D>>);
 
+    /// The optimizer applied to the parameters excluded from the weight decay, see
+    /// [with_weight_decay_exclusion](crate::optim::adaptor::OptimizerAdaptor::with_weight_decay_exclusion).
+    ///
+    /// Optimizers applying a weight decay must return a copy without it, the default returning
+    /// the optimizer unchanged.
+    fn without_weight_decay(&self) -> Self {
+        self.clone()
+    }
+
     /// Change the device of the state.
     ///
     /// This function will be called accordindly to have the state on the same device as the
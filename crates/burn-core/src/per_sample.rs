@@ -0,0 +1,759 @@
+//! Per-sample gradients, computed with a single backward pass.
+//!
+//! The gradient of a sample only depends on the activations and on the output gradients of the
+//! sample, so it can be computed analytically from the ones of the whole batch instead of running
+//! one backward pass per sample. For a [linear](crate::nn::Linear) layer, the gradient of the
+//! weight for the sample `i` is `a[i]ᵀ g[i]`, where `a[i]` is its input and `g[i]` its output
+//! gradient.
+//!
+//! The supported layers record their activations while [per_sample_gradients] runs, and their
+//! output gradients are captured by adding a zero tensor that requires gradients to their
+//! output. The layers don't record anything otherwise.
+
+use crate::module::{Param, ParamId};
+use crate::tensor::{backend::Backend, Int, Tensor};
+
+#[cfg(feature = "std")]
+pub use recording::*;
+
+/// Record the activations of a [linear](crate::nn::Linear) layer, returning its output.
+#[cfg_attr(not(feature = "std"), allow(unused_variables))]
+pub(crate) fn linear<B: Backend, const D: usize>(
+    weight: &Param<Tensor<B, 2>>,
+    bias: Option<&Param<Tensor<B, 1>>>,
+    input: &Tensor<B, D>,
+    output: Tensor<B, D>,
+) -> Tensor<B, D> {
+    #[cfg(feature = "std")]
+    if recording::is_recording::<B>() {
+        let shape = output.shape();
+        let (output, probe) = recording::probe(flatten_positions(output));
+
+        recording::record(recording::Layer::Linear {
+            weight: weight.id.clone(),
+            bias: bias.map(|bias| bias.id.clone()),
+            input: flatten_positions(input.clone()),
+            probe,
+        });
+
+        return output.reshape(shape);
+    }
+
+    output
+}
+
+/// Record the activations of an [embedding](crate::nn::Embedding) layer, returning its output.
+#[cfg_attr(not(feature = "std"), allow(unused_variables))]
+pub(crate) fn embedding<B: Backend>(
+    weight: &Param<Tensor<B, 2>>,
+    indices: &Tensor<B, 2, Int>,
+    output: Tensor<B, 3>,
+) -> Tensor<B, 3> {
+    #[cfg(feature = "std")]
+    if recording::is_recording::<B>() {
+        let (output, probe) = recording::probe(output);
+
+        recording::record(recording::Layer::Embedding {
+            weight: weight.id.clone(),
+            num_embeddings: weight.dims()[0],
+            indices: indices.clone(),
+            probe,
+        });
+
+        return output;
+    }
+
+    output
+}
+
+/// Record the activations of a [layer norm](crate::nn::LayerNorm) layer, returning its output.
+#[cfg_attr(not(feature = "std"), allow(unused_variables))]
+pub(crate) fn layer_norm<B: Backend, const D: usize>(
+    gamma: &Param<Tensor<B, 1>>,
+    beta: &Param<Tensor<B, 1>>,
+    normalized: &Tensor<B, D>,
+    output: Tensor<B, D>,
+) -> Tensor<B, D> {
+    #[cfg(feature = "std")]
+    if recording::is_recording::<B>() {
+        let shape = output.shape();
+        let (output, probe) = recording::probe(flatten_positions(output));
+
+        recording::record(recording::Layer::LayerNorm {
+            gamma: gamma.id.clone(),
+            beta: beta.id.clone(),
+            normalized: flatten_positions(normalized.clone()),
+            probe,
+        });
+
+        return output.reshape(shape);
+    }
+
+    output
+}
+
+/// Record the activations of a [2D convolution](crate::nn::conv::Conv2d) layer, returning its
+/// output.
+#[cfg_attr(not(feature = "std"), allow(unused_variables))]
+pub(crate) fn conv2d<B: Backend>(
+    weight: &Param<Tensor<B, 4>>,
+    bias: Option<&Param<Tensor<B, 1>>>,
+    input: &Tensor<B, 4>,
+    options: &crate::tensor::ops::ConvOptions<2>,
+    output: Tensor<B, 4>,
+) -> Tensor<B, 4> {
+    #[cfg(feature = "std")]
+    if recording::is_recording::<B>() {
+        let (output, probe) = recording::probe(output);
+        let [_, _, kernel_0, kernel_1] = weight.dims();
+
+        recording::record(recording::Layer::Conv2d {
+            weight: weight.id.clone(),
+            bias: bias.map(|bias| bias.id.clone()),
+            input: input.clone(),
+            kernel_size: [kernel_0, kernel_1],
+            options: options.clone(),
+            probe,
+        });
+
+        return output;
+    }
+
+    output
+}
+
+/// Reshape a tensor of shape `[batch_size, ..., features]` to
+/// `[batch_size, positions, features]`, a tensor without batch dimension being a single sample.
+#[cfg(feature = "std")]
+fn flatten_positions<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, 3> {
+    let dims = tensor.dims();
+    let features = dims[D - 1];
+
+    match D {
+        1 => tensor.reshape([1, 1, features]),
+        _ => {
+            let positions = dims[1..D - 1].iter().product::<usize>();
+            tensor.reshape([dims[0], positions, features])
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+mod recording {
+    use super::*;
+    use crate::module::{AutodiffModule, ModuleVisitor};
+    use crate::tensor::{
+        backend::AutodiffBackend, container::TensorContainer, module::unfold4d, ops::ConvOptions,
+        ops::UnfoldOptions,
+    };
+    use alloc::{string::String, vec::Vec};
+    use core::{any::Any, marker::PhantomData};
+    use std::cell::RefCell;
+
+    std::thread_local! {
+        /// The layers recorded by the current call to [per_sample_gradients], stored as a
+        /// `Vec<Layer<B>>` for the backend of the model.
+        static LAYERS: RefCell<Option<Box<dyn Any>>> = const { RefCell::new(None) };
+    }
+
+    /// The activations of a supported layer, with the probe added to its output, whose
+    /// gradient is the output gradient of the layer.
+    pub(super) enum Layer<B: Backend> {
+        Linear {
+            weight: ParamId,
+            bias: Option<ParamId>,
+            input: Tensor<B, 3>,
+            probe: Tensor<B, 3>,
+        },
+        Embedding {
+            weight: ParamId,
+            num_embeddings: usize,
+            indices: Tensor<B, 2, Int>,
+            probe: Tensor<B, 3>,
+        },
+        LayerNorm {
+            gamma: ParamId,
+            beta: ParamId,
+            normalized: Tensor<B, 3>,
+            probe: Tensor<B, 3>,
+        },
+        Conv2d {
+            weight: ParamId,
+            bias: Option<ParamId>,
+            input: Tensor<B, 4>,
+            kernel_size: [usize; 2],
+            options: ConvOptions<2>,
+            probe: Tensor<B, 4>,
+        },
+    }
+
+    pub(super) fn is_recording<B: Backend>() -> bool {
+        LAYERS.with_borrow(|layers| {
+            layers
+                .as_ref()
+                .is_some_and(|layers| layers.is::<Vec<Layer<B>>>())
+        })
+    }
+
+    pub(super) fn record<B: Backend>(layer: Layer<B>) {
+        LAYERS.with_borrow_mut(|layers| {
+            if let Some(layers) = layers
+                .as_mut()
+                .and_then(|layers| layers.downcast_mut::<Vec<Layer<B>>>())
+            {
+                layers.push(layer);
+            }
+        })
+    }
+
+    /// Add a zero tensor that requires gradients to the output, returning the new output and
+    /// the zero tensor.
+    pub(super) fn probe<B: Backend, const D: usize>(
+        output: Tensor<B, D>,
+    ) -> (Tensor<B, D>, Tensor<B, D>) {
+        let probe = output.zeros_like().require_grad();
+        (output + probe.clone(), probe)
+    }
+
+    /// Restores the layers of the enclosing call when dropped, even if the loss function panics.
+    struct RecordingGuard {
+        previous: Option<Box<dyn Any>>,
+    }
+
+    impl RecordingGuard {
+        fn start<B: Backend>() -> Self {
+            let layers: Box<dyn Any> = Box::new(Vec::<Layer<B>>::new());
+            Self {
+                previous: LAYERS.replace(Some(layers)),
+            }
+        }
+
+        fn finish<B: Backend>(self) -> Vec<Layer<B>> {
+            let layers = LAYERS.take();
+            *layers
+                .expect("The recorded layers should be set")
+                .downcast::<Vec<Layer<B>>>()
+                .expect("The recorded layers should be of the backend of the model")
+        }
+    }
+
+    impl Drop for RecordingGuard {
+        fn drop(&mut self) {
+            LAYERS.set(self.previous.take());
+        }
+    }
+
+    /// Error returned when the per-sample gradients can't be computed.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub enum PerSampleError {
+        /// Parameters of the model, identified by their path in the model, belong to layers
+        /// without per-sample gradients support, such as batch norm layers, whose output mixes
+        /// the samples.
+        UnsupportedParameters(Vec<String>),
+
+        /// A supported layer was called with an input whose first dimension isn't the batch
+        /// dimension of the losses.
+        BatchSizeMismatch {
+            /// The number of losses.
+            expected: usize,
+            /// The first dimension of the input of the layer.
+            actual: usize,
+        },
+    }
+
+    impl core::fmt::Display for PerSampleError {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            match self {
+                Self::UnsupportedParameters(paths) => write!(
+                    f,
+                    "Per-sample gradients are only supported for Linear, Embedding, LayerNorm and \
+                     Conv2d layers, but the parameters {paths:?} belong to other layers",
+                ),
+                Self::BatchSizeMismatch { expected, actual } => write!(
+                    f,
+                    "Expected the layers to be called with a batch of {expected} samples, \
+                     got {actual}",
+                ),
+            }
+        }
+    }
+
+    impl std::error::Error for PerSampleError {}
+
+    /// The per-sample gradients of the parameters of a model.
+    ///
+    /// The gradients of a parameter of shape `[d_1, ..., d_n]` have the shape
+    /// `[batch_size, d_1, ..., d_n]`.
+    #[derive(Default)]
+    pub struct PerSampleGradients {
+        container: TensorContainer<ParamId>,
+    }
+
+    impl PerSampleGradients {
+        /// Get the per-sample gradients of the given [parameter id](ParamId), where `D` is the
+        /// rank of the parameter plus one.
+        pub fn get<B: Backend, const D: usize>(&self, id: &ParamId) -> Option<Tensor<B, D>> {
+            self.container.get(id)
+        }
+
+        /// Remove the per-sample gradients of the given [parameter id](ParamId).
+        pub fn remove<B: Backend, const D: usize>(&mut self, id: &ParamId) -> Option<Tensor<B, D>> {
+            self.container.remove(id)
+        }
+
+        /// The number of parameters with per-sample gradients.
+        pub fn len(&self) -> usize {
+            self.container.len()
+        }
+
+        /// If there isn't any per-sample gradients.
+        pub fn is_empty(&self) -> bool {
+            self.len() == 0
+        }
+
+        /// Register the gradients, adding them to the ones already registered, which happens
+        /// when a layer is called more than once.
+        fn accumulate<B: Backend, const D: usize>(&mut self, id: ParamId, grads: Tensor<B, D>) {
+            let grads = match self.container.remove::<B, D>(&id) {
+                Some(previous) => previous + grads,
+                None => grads,
+            };
+            self.container.register(id, grads);
+        }
+    }
+
+    /// Compute the gradients of every sample of a batch with a single backward pass.
+    ///
+    /// # Arguments
+    ///
+    /// * `model` - The model, made of [Linear](crate::nn::Linear),
+    ///   [Embedding](crate::nn::Embedding), [LayerNorm](crate::nn::LayerNorm) and
+    ///   [Conv2d](crate::nn::conv::Conv2d) layers, possibly with modules without parameters, such
+    ///   as activations.
+    /// * `batch_loss_fn` - The function computing the loss of each sample with the model, of
+    ///   shape `[batch_size]`. The first dimension of the inputs of the layers must be the batch.
+    ///
+    /// # Returns
+    ///
+    /// The gradients of the loss of each sample, or an error if parameters that require
+    /// gradients belong to unsupported layers.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_core::module::Module;
+    /// use burn_core::nn::{Linear, LinearConfig};
+    /// use burn_core::per_sample::per_sample_gradients;
+    /// use burn_core::tensor::{backend::AutodiffBackend, Tensor};
+    ///
+    /// fn example<B: AutodiffBackend>(inputs: Tensor<B, 2>, targets: Tensor<B, 2>) {
+    ///     let model: Linear<B> = LinearConfig::new(4, 2).init(&inputs.device());
+    ///     let grads = per_sample_gradients(&model, |model: &Linear<B>| {
+    ///         let error = model.forward(inputs) - targets;
+    ///         error.powf_scalar(2.0).sum_dim(1).squeeze(1)
+    ///     })
+    ///     .unwrap();
+    ///     // Of shape `[batch_size, 4, 2]`.
+    ///     let weight = grads.get::<B::InnerBackend, 3>(&model.weight.id).unwrap();
+    /// }
+    /// ```
+    pub fn per_sample_gradients<B, M>(
+        model: &M,
+        batch_loss_fn: impl FnOnce(&M) -> Tensor<B, 1>,
+    ) -> Result<PerSampleGradients, PerSampleError>
+    where
+        B: AutodiffBackend,
+        M: AutodiffModule<B>,
+    {
+        let guard = RecordingGuard::start::<B>();
+        let losses = batch_loss_fn(model);
+        let layers = guard.finish::<B>();
+
+        let [batch_size] = losses.dims();
+        let grads = losses.sum().backward();
+        let mut per_sample = PerSampleGradients::default();
+
+        for layer in layers {
+            layer.register(&grads, batch_size, &mut per_sample)?;
+        }
+
+        let mut visitor = UnsupportedParams::<B>::new(&per_sample);
+        model.visit(&mut visitor);
+        let paths = visitor.paths;
+
+        match paths.is_empty() {
+            true => Ok(per_sample),
+            false => Err(PerSampleError::UnsupportedParameters(paths)),
+        }
+    }
+
+    impl<B: AutodiffBackend> Layer<B> {
+        fn register(
+            self,
+            grads: &B::Gradients,
+            batch_size: usize,
+            per_sample: &mut PerSampleGradients,
+        ) -> Result<(), PerSampleError> {
+            match self {
+                Layer::Linear {
+                    weight,
+                    bias,
+                    input,
+                    probe,
+                } => {
+                    let output_grad = output_grad(probe, grads, batch_size)?;
+                    let input = input.inner();
+
+                    per_sample
+                        .accumulate(weight, input.swap_dims(1, 2).matmul(output_grad.clone()));
+                    if let Some(bias) = bias {
+                        per_sample.accumulate(bias, output_grad.sum_dim(1).squeeze::<2>(1));
+                    }
+                }
+                Layer::Embedding {
+                    weight,
+                    num_embeddings,
+                    indices,
+                    probe,
+                } => {
+                    let output_grad = output_grad(probe, grads, batch_size)?;
+                    let indices = indices.inner();
+                    let [batch_size, seq_length] = indices.dims();
+                    let shape = [batch_size, seq_length, num_embeddings];
+
+                    // The one-hot encoding of the indices scatters the output gradients.
+                    let one_hot = indices
+                        .unsqueeze_dim::<3>(2)
+                        .expand(shape)
+                        .equal(
+                            Tensor::arange(0..num_embeddings as i64, &output_grad.device())
+                                .reshape([1, 1, num_embeddings])
+                                .expand(shape),
+                        )
+                        .float();
+
+                    per_sample.accumulate(weight, one_hot.swap_dims(1, 2).matmul(output_grad));
+                }
+                Layer::LayerNorm {
+                    gamma,
+                    beta,
+                    normalized,
+                    probe,
+                } => {
+                    let output_grad = output_grad(probe, grads, batch_size)?;
+                    let normalized = normalized.inner();
+
+                    per_sample.accumulate(
+                        gamma,
+                        (normalized * output_grad.clone())
+                            .sum_dim(1)
+                            .squeeze::<2>(1),
+                    );
+                    per_sample.accumulate(beta, output_grad.sum_dim(1).squeeze::<2>(1));
+                }
+                Layer::Conv2d {
+                    weight,
+                    bias,
+                    input,
+                    kernel_size,
+                    options,
+                    probe,
+                } => {
+                    let output_grad = output_grad(probe, grads, batch_size)?;
+                    let [_, channels_in, _, _] = input.dims();
+                    let [_, channels_out, height_out, width_out] = output_grad.dims();
+                    let groups = options.groups;
+                    let positions = height_out * width_out;
+
+                    // The rows of the unfolded input are ordered by channel, then by kernel
+                    // position, so the channels of a group are contiguous.
+                    let unfolded = unfold4d(
+                        input.inner(),
+                        kernel_size,
+                        UnfoldOptions::new(options.stride, options.padding, options.dilation),
+                    )
+                    .reshape([
+                        batch_size,
+                        groups,
+                        channels_in / groups * kernel_size[0] * kernel_size[1],
+                        positions,
+                    ]);
+                    let output_grad =
+                        output_grad.reshape([batch_size, groups, channels_out / groups, positions]);
+
+                    let weight_grad = output_grad
+                        .clone()
+                        .matmul(unfolded.swap_dims(2, 3))
+                        .reshape([
+                            batch_size,
+                            channels_out,
+                            channels_in / groups,
+                            kernel_size[0],
+                            kernel_size[1],
+                        ]);
+
+                    per_sample.accumulate(weight, weight_grad);
+                    if let Some(bias) = bias {
+                        per_sample.accumulate(
+                            bias,
+                            output_grad.sum_dim(3).reshape([batch_size, channels_out]),
+                        );
+                    }
+                }
+            }
+
+            Ok(())
+        }
+    }
+
+    /// The gradient of the output of a layer, which is zero if the output isn't used by the loss.
+    fn output_grad<B: AutodiffBackend, const D: usize>(
+        probe: Tensor<B, D>,
+        grads: &B::Gradients,
+        batch_size: usize,
+    ) -> Result<Tensor<B::InnerBackend, D>, PerSampleError> {
+        let actual = probe.dims()[0];
+        if actual != batch_size {
+            return Err(PerSampleError::BatchSizeMismatch {
+                expected: batch_size,
+                actual,
+            });
+        }
+
+        Ok(probe
+            .grad(grads)
+            .unwrap_or_else(|| probe.inner().zeros_like()))
+    }
+
+    /// Collect the paths of the parameters that require gradients without per-sample gradients.
+    struct UnsupportedParams<'a, B: Backend> {
+        grads: &'a PerSampleGradients,
+        path: Vec<String>,
+        paths: Vec<String>,
+        phantom: PhantomData<B>,
+    }
+
+    impl<'a, B: Backend> UnsupportedParams<'a, B> {
+        fn new(grads: &'a PerSampleGradients) -> Self {
+            Self {
+                grads,
+                path: Vec::new(),
+                paths: Vec::new(),
+                phantom: PhantomData,
+            }
+        }
+    }
+
+    impl<B: AutodiffBackend> ModuleVisitor<B> for UnsupportedParams<'_, B> {
+        fn visit_float<const D: usize>(&mut self, id: &ParamId, tensor: &Tensor<B, D>) {
+            if tensor.is_require_grad() && !self.grads.container.contains(id) {
+                self.paths.push(self.path.join("."));
+            }
+        }
+
+        fn enter_module(&mut self, name: &str) {
+            self.path.push(name.into());
+        }
+
+        fn exit_module(&mut self, _name: &str) {
+            self.path.pop();
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "std")]
+mod tests {
+    use super::*;
+    use crate as burn;
+    use crate::module::Module;
+    use crate::nn::{
+        conv::{Conv2d, Conv2dConfig},
+        BatchNorm, BatchNormConfig, Embedding, EmbeddingConfig, LayerNorm, LayerNormConfig, Linear,
+        LinearConfig, PaddingConfig2d,
+    };
+    use crate::tensor::{backend::AutodiffBackend, Distribution};
+    use crate::TestAutodiffBackend;
+
+    type TB = TestAutodiffBackend;
+
+    #[derive(Module, Debug)]
+    struct SequenceModel<B: Backend> {
+        embedding: Embedding<B>,
+        norm: LayerNorm<B>,
+        linear: Linear<B>,
+    }
+
+    impl<B: Backend> SequenceModel<B> {
+        fn new(device: &B::Device) -> Self {
+            Self {
+                embedding: EmbeddingConfig::new(7, 4).init(device),
+                norm: LayerNormConfig::new(4).init(device),
+                linear: LinearConfig::new(4, 3).init(device),
+            }
+        }
+
+        fn losses(&self, indices: Tensor<B, 2, Int>) -> Tensor<B, 1> {
+            let [batch_size, _] = indices.dims();
+            let x = self.embedding.forward(indices);
+            let x = self.linear.forward(self.norm.forward(x));
+
+            x.powf_scalar(2.0)
+                .sum_dim(2)
+                .sum_dim(1)
+                .reshape([batch_size])
+        }
+    }
+
+    #[derive(Module, Debug)]
+    struct ConvModel<B: Backend> {
+        conv: Conv2d<B>,
+        linear: Linear<B>,
+    }
+
+    impl<B: Backend> ConvModel<B> {
+        fn new(device: &B::Device) -> Self {
+            Self {
+                conv: Conv2dConfig::new([4, 6], [3, 3])
+                    .with_stride([2, 1])
+                    .with_dilation([1, 2])
+                    .with_padding(PaddingConfig2d::Explicit(1, 1))
+                    .with_groups(2)
+                    .init(device),
+                linear: LinearConfig::new(6 * 3 * 4, 2).init(device),
+            }
+        }
+
+        fn losses(&self, input: Tensor<B, 4>) -> Tensor<B, 1> {
+            let x = self.conv.forward(input).flatten::<2>(1, 3);
+            let x = self.linear.forward(x.tanh());
+
+            x.powf_scalar(2.0).sum_dim(1).squeeze(1)
+        }
+    }
+
+    #[derive(Module, Debug)]
+    struct BatchNormModel<B: Backend> {
+        linear: Linear<B>,
+        norm: BatchNorm<B, 0>,
+    }
+
+    /// Assert that the per-sample gradients of a parameter are the gradients of the loss of each
+    /// sample, computed with one backward pass per sample.
+    fn assert_per_sample<const D: usize, const DB: usize>(
+        per_sample: &PerSampleGradients,
+        param: &Param<Tensor<TB, D>>,
+        sample_grads: &[<TB as AutodiffBackend>::Gradients],
+    ) {
+        let grads = per_sample
+            .get::<<TB as AutodiffBackend>::InnerBackend, DB>(&param.id)
+            .unwrap();
+        let mut ranges = grads.shape().dims.map(|dim| 0..dim);
+
+        assert_eq!(grads.dims()[0], sample_grads.len());
+        for (i, sample_grads) in sample_grads.iter().enumerate() {
+            ranges[0] = i..i + 1;
+            let expected = param.grad(sample_grads).unwrap();
+
+            grads
+                .clone()
+                .slice(ranges.clone())
+                .reshape(expected.shape())
+                .into_data()
+                .assert_approx_eq(&expected.into_data(), 4);
+        }
+    }
+
+    #[test]
+    fn should_match_the_gradients_of_each_sample_with_sequences() {
+        let device = Default::default();
+        let model = SequenceModel::<TB>::new(&device);
+        let indices = Tensor::<TB, 2, Int>::from_ints([[0, 3, 3, 6, 1], [2, 2, 5, 0, 4]], &device);
+
+        let per_sample = per_sample_gradients(&model, |model: &SequenceModel<TB>| {
+            model.losses(indices.clone())
+        })
+        .unwrap();
+        let sample_grads = (0..2)
+            .map(|i| {
+                model
+                    .losses(indices.clone().slice([i..i + 1, 0..5]))
+                    .sum()
+                    .backward()
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(per_sample.len(), 5);
+        assert_per_sample::<2, 3>(&per_sample, &model.embedding.weight, &sample_grads);
+        assert_per_sample::<1, 2>(&per_sample, &model.norm.gamma, &sample_grads);
+        assert_per_sample::<1, 2>(&per_sample, &model.norm.beta, &sample_grads);
+        assert_per_sample::<2, 3>(&per_sample, &model.linear.weight, &sample_grads);
+        assert_per_sample::<1, 2>(
+            &per_sample,
+            model.linear.bias.as_ref().unwrap(),
+            &sample_grads,
+        );
+    }
+
+    #[test]
+    fn should_match_the_gradients_of_each_sample_with_grouped_conv2d() {
+        let device = Default::default();
+        let model = ConvModel::<TB>::new(&device);
+        let input = Tensor::<TB, 4>::random([3, 4, 5, 6], Distribution::Default, &device);
+
+        let per_sample =
+            per_sample_gradients(&model, |model: &ConvModel<TB>| model.losses(input.clone()))
+                .unwrap();
+        let sample_grads = (0..3)
+            .map(|i| {
+                model
+                    .losses(input.clone().slice([i..i + 1, 0..4, 0..5, 0..6]))
+                    .sum()
+                    .backward()
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(per_sample.len(), 4);
+        assert_per_sample::<4, 5>(&per_sample, &model.conv.weight, &sample_grads);
+        assert_per_sample::<1, 2>(
+            &per_sample,
+            model.conv.bias.as_ref().unwrap(),
+            &sample_grads,
+        );
+        assert_per_sample::<2, 3>(&per_sample, &model.linear.weight, &sample_grads);
+    }
+
+    #[test]
+    fn should_not_record_outside_of_per_sample_gradients() {
+        let device = Default::default();
+        let model = ConvModel::<TB>::new(&device);
+        let input = Tensor::<TB, 4>::random([2, 4, 5, 6], Distribution::Default, &device);
+
+        per_sample_gradients(&model, |model: &ConvModel<TB>| model.losses(input.clone())).unwrap();
+
+        assert!(!recording::is_recording::<TB>());
+    }
+
+    #[test]
+    fn should_fail_with_the_paths_of_unsupported_parameters() {
+        let device = Default::default();
+        let model = BatchNormModel::<TB> {
+            linear: LinearConfig::new(3, 3).init(&device),
+            norm: BatchNormConfig::new(3).init(&device),
+        };
+        let input = Tensor::<TB, 2>::random([4, 3], Distribution::Default, &device);
+
+        let result = per_sample_gradients(&model, |model: &BatchNormModel<TB>| {
+            let x = model.norm.forward(model.linear.forward(input.clone()));
+            x.sum_dim(1).squeeze(1)
+        });
+
+        assert_eq!(
+            result.err(),
+            Some(PerSampleError::UnsupportedParameters(vec![
+                "norm.gamma".into(),
+                "norm.beta".into()
+            ]))
+        );
+    }
+}
@@ -0,0 +1,310 @@
+use crate as burn;
+
+use super::{Pruned, PrunedParam};
+use crate::config::Config;
+use crate::module::{Module, ModuleVisitor, ParamId};
+use crate::tensor::{backend::Backend, Bool, Tensor};
+use alloc::vec::Vec;
+
+/// Configuration to prune the weights with the smallest magnitudes of a module, using
+/// [apply_pruning].
+#[derive(Config, Debug)]
+pub struct PruningConfig {
+    /// The fraction of the weights, or of the channels with structured pruning, that are pruned.
+    pub sparsity: f64,
+    /// Whether the sparsity is reached over the whole module or by each parameter.
+    #[config(default = "PruningScope::Global")]
+    pub scope: PruningScope,
+    /// Whether single weights or whole channels are pruned.
+    #[config(default = "PruningStructure::Unstructured")]
+    pub structure: PruningStructure,
+    /// The minimum rank of the pruned parameters. The default doesn't prune the biases and the
+    /// normalization parameters.
+    #[config(default = 2)]
+    pub min_rank: usize,
+}
+
+/// Where the sparsity target of [pruning](PruningConfig) is reached.
+#[derive(Config, Debug, PartialEq, Eq)]
+pub enum PruningScope {
+    /// The weights with the smallest magnitudes of the whole module are pruned, so the
+    /// parameters with smaller weights are pruned more than the others.
+    Global,
+    /// Each parameter is pruned to the sparsity target.
+    PerLayer,
+}
+
+/// The weights pruned together by [pruning](PruningConfig).
+#[derive(Config, Debug, PartialEq, Eq)]
+pub enum PruningStructure {
+    /// The weights with the smallest absolute values are pruned.
+    Unstructured,
+    /// The output channels of the 2D convolutions with the smallest mean absolute values are
+    /// pruned, a channel being a slice of the first dimension of a rank 4 weight. The other
+    /// parameters aren't pruned.
+    Channels,
+}
+
+/// Prune the weights with the smallest magnitudes of a module.
+///
+/// The weights aren't modified: the masks are multiplied into them on each forward pass of the
+/// [pruned module](Pruned), so that the pruned weights stay zero during fine-tuning, until the
+/// masks are [made permanent](Pruned::make_permanent).
+///
+/// # Panics
+///
+/// If the sparsity isn't between 0 and 1.
+pub fn apply_pruning<B: Backend, M: Module<B>>(module: M, config: &PruningConfig) -> Pruned<B, M> {
+    assert!(
+        (0.0..=1.0).contains(&config.sparsity),
+        "The sparsity should be between 0 and 1, got {}",
+        config.sparsity
+    );
+
+    let mut visitor = Scores::new(config);
+    module.visit(&mut visitor);
+
+    let masks = match config.scope {
+        PruningScope::Global => global_masks(visitor.scores, config.sparsity),
+        PruningScope::PerLayer => visitor
+            .scores
+            .into_iter()
+            .map(|score| {
+                score.map(|score| {
+                    let [num_units] = score.units.dims();
+                    let mask = keep_mask(score.units, num_pruned(num_units, config.sparsity));
+                    expand(mask, score.unit_size)
+                })
+            })
+            .collect(),
+    };
+
+    Pruned {
+        module,
+        params: masks
+            .into_iter()
+            .map(|mask| mask.map(PrunedParam::new))
+            .collect(),
+    }
+}
+
+/// The units of a parameter, single weights or channels, and their magnitudes.
+struct Score<B: Backend> {
+    units: Tensor<B, 1>,
+    unit_size: usize,
+}
+
+/// Collect the scores of the float parameters of a module, in the order they are visited.
+struct Scores<'a, B: Backend> {
+    config: &'a PruningConfig,
+    scores: Vec<Option<Score<B>>>,
+}
+
+impl<'a, B: Backend> Scores<'a, B> {
+    fn new(config: &'a PruningConfig) -> Self {
+        Self {
+            config,
+            scores: Vec::new(),
+        }
+    }
+}
+
+impl<B: Backend> ModuleVisitor<B> for Scores<'_, B> {
+    fn visit_float<const D: usize>(&mut self, _id: &ParamId, tensor: &Tensor<B, D>) {
+        let num_elements = tensor.shape().num_elements();
+        let magnitudes = tensor.clone().detach().abs();
+
+        let score = match self.config.structure {
+            _ if D < self.config.min_rank => None,
+            PruningStructure::Unstructured => Some(Score {
+                units: magnitudes.reshape([num_elements]),
+                unit_size: 1,
+            }),
+            PruningStructure::Channels if D == 4 => {
+                let channels = tensor.dims()[0];
+                let unit_size = num_elements / channels;
+
+                Some(Score {
+                    units: magnitudes
+                        .reshape([channels, unit_size])
+                        .mean_dim(1)
+                        .reshape([channels]),
+                    unit_size,
+                })
+            }
+            PruningStructure::Channels => None,
+        };
+
+        self.scores.push(score);
+    }
+}
+
+/// The masks reaching the sparsity over all the parameters.
+fn global_masks<B: Backend>(
+    scores: Vec<Option<Score<B>>>,
+    sparsity: f64,
+) -> Vec<Option<Tensor<B, 1, Bool>>> {
+    let units = scores
+        .iter()
+        .flatten()
+        .map(|score| score.units.clone())
+        .collect::<Vec<_>>();
+
+    if units.is_empty() {
+        return scores.into_iter().map(|_| None).collect();
+    }
+
+    let units = Tensor::cat(units, 0);
+    let [num_units] = units.dims();
+    let mask = keep_mask(units, num_pruned(num_units, sparsity));
+    let mut start = 0;
+
+    scores
+        .into_iter()
+        .map(|score| {
+            score.map(|score| {
+                let [num_units] = score.units.dims();
+                let end = start + num_units;
+                let units = mask.clone().slice([start..end]);
+                start = end;
+
+                expand(units, score.unit_size)
+            })
+        })
+        .collect()
+}
+
+fn num_pruned(num_units: usize, sparsity: f64) -> usize {
+    (num_units as f64 * sparsity).round() as usize
+}
+
+/// The mask of the units that are kept, pruning exactly the given number of units with the
+/// smallest scores.
+fn keep_mask<B: Backend>(scores: Tensor<B, 1>, num_pruned: usize) -> Tensor<B, 1, Bool> {
+    let keep = scores.ones_like();
+
+    if num_pruned == 0 {
+        return keep.greater_elem(0.0);
+    }
+
+    let device = scores.device();
+    let pruned = scores.argsort(0).slice([0..num_pruned]);
+
+    keep.scatter(0, pruned, Tensor::full([num_pruned], -1.0, &device))
+        .greater_elem(0.0)
+}
+
+/// Repeat the mask of every unit for each of its weights.
+fn expand<B: Backend>(mask: Tensor<B, 1, Bool>, unit_size: usize) -> Tensor<B, 1, Bool> {
+    let [num_units] = mask.dims();
+
+    mask.unsqueeze_dim::<2>(1)
+        .expand([num_units, unit_size])
+        .reshape([num_units * unit_size])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::{
+        conv::{Conv2d, Conv2dConfig},
+        Linear, LinearConfig,
+    };
+    use crate::TestBackend;
+
+    #[derive(Module, Debug)]
+    struct Model<B: Backend> {
+        conv: Conv2d<B>,
+        linear: Linear<B>,
+    }
+
+    impl<B: Backend> Model<B> {
+        fn new(device: &B::Device) -> Self {
+            Self {
+                conv: Conv2dConfig::new([3, 8], [3, 3]).init(device),
+                linear: LinearConfig::new(16, 8).init(device),
+            }
+        }
+    }
+
+    fn num_zeros<const D: usize>(tensor: Tensor<TestBackend, D>) -> usize {
+        tensor.equal_elem(0.0).int().sum().into_scalar() as usize
+    }
+
+    #[test]
+    fn global_pruning_should_reach_the_sparsity_over_the_module() {
+        let model = Model::<TestBackend>::new(&Default::default());
+
+        let pruned = apply_pruning(model, &PruningConfig::new(0.5));
+        let (model, report) = pruned.make_permanent();
+
+        let num_weights = 8 * 3 * 3 * 3 + 16 * 8;
+        assert_eq!(report.num_weights, num_weights);
+        assert_eq!(report.num_pruned, num_weights / 2);
+        assert_eq!(
+            num_zeros(model.conv.weight.val()) + num_zeros(model.linear.weight.val()),
+            num_weights / 2
+        );
+        assert_eq!(num_zeros(model.linear.bias.unwrap().val()), 0);
+    }
+
+    #[test]
+    fn global_pruning_should_prune_the_smallest_weights() {
+        let device = Default::default();
+        let mut model = Model::<TestBackend>::new(&device);
+        model.linear.weight = model.linear.weight.map(|weight| weight.mul_scalar(1e-3));
+
+        // The linear weights are the 128 smallest of the 344 weights.
+        let config = PruningConfig::new(128.0 / 344.0);
+        let (model, report) = apply_pruning(model, &config).make_permanent();
+
+        assert_eq!(report.num_pruned, 16 * 8);
+        assert_eq!(num_zeros(model.linear.weight.val()), 16 * 8);
+        assert_eq!(num_zeros(model.conv.weight.val()), 0);
+    }
+
+    #[test]
+    fn per_layer_pruning_should_reach_the_sparsity_of_each_parameter() {
+        let model = Model::<TestBackend>::new(&Default::default());
+        let config = PruningConfig::new(0.75).with_scope(PruningScope::PerLayer);
+
+        let (model, report) = apply_pruning(model, &config).make_permanent();
+
+        assert_eq!(report.sparsity(), 0.75);
+        assert_eq!(num_zeros(model.conv.weight.val()), 8 * 3 * 3 * 3 * 3 / 4);
+        assert_eq!(num_zeros(model.linear.weight.val()), 16 * 8 * 3 / 4);
+    }
+
+    #[test]
+    fn channel_pruning_should_prune_whole_output_channels() {
+        let model = Model::<TestBackend>::new(&Default::default());
+        let config = PruningConfig::new(0.25).with_structure(PruningStructure::Channels);
+
+        let (model, report) = apply_pruning(model, &config).make_permanent();
+
+        assert_eq!(report.num_weights, 8 * 3 * 3 * 3);
+        assert_eq!(report.num_pruned, 2 * 3 * 3 * 3);
+
+        let pruned_channels = model
+            .conv
+            .weight
+            .val()
+            .abs()
+            .reshape([8, 27])
+            .sum_dim(1)
+            .equal_elem(0.0)
+            .int()
+            .sum()
+            .into_scalar();
+        assert_eq!(pruned_channels, 2);
+        assert_eq!(num_zeros(model.linear.weight.val()), 0);
+    }
+
+    #[test]
+    #[should_panic = "The sparsity should be between 0 and 1"]
+    fn should_panic_with_an_invalid_sparsity() {
+        let model = Model::<TestBackend>::new(&Default::default());
+
+        apply_pruning(model, &PruningConfig::new(1.5));
+    }
+}
@@ -0,0 +1,149 @@
+use crate::module::Param;
+use crate::nn::conv::Conv2d;
+use crate::tensor::{backend::Backend, Int, Tensor, TensorData};
+use alloc::vec::Vec;
+
+/// Remove the output channels of the convolutions whose weights are all zero, such as the
+/// channels [pruned](super::PruningStructure::Channels) and made permanent, along with the
+/// matching input channels of the following convolution.
+///
+/// The convolutions are the layers of a sequential model, each one being applied to the output
+/// of the previous one, possibly through layers that keep the channels, such as activations. The
+/// output channels of the last convolution are kept, since they are used by the rest of the
+/// model.
+///
+/// The biases of the removed channels are removed with them, so the output of the model is only
+/// the same when they are zero.
+///
+/// # Panics
+///
+/// If a convolution has groups, or if all the output channels of a convolution are removed.
+pub fn shrink_conv2d_channels<B: Backend>(layers: Vec<Conv2d<B>>) -> Vec<Conv2d<B>> {
+    let mut output = Vec::with_capacity(layers.len());
+    let mut previous: Option<Conv2d<B>> = None;
+
+    for layer in layers {
+        previous = match previous.take() {
+            Some(conv) => {
+                let (conv, next) = shrink_pair(conv, layer);
+                output.push(conv);
+                Some(next)
+            }
+            None => Some(layer),
+        };
+    }
+
+    output.extend(previous);
+    output
+}
+
+fn shrink_pair<B: Backend>(conv: Conv2d<B>, next: Conv2d<B>) -> (Conv2d<B>, Conv2d<B>) {
+    assert_eq!(
+        (conv.groups, next.groups),
+        (1, 1),
+        "Shrinking the channels of grouped convolutions isn't supported"
+    );
+
+    let weight = conv.weight.val();
+    let device = weight.device();
+    let [channels, ..] = weight.dims();
+    let channel_size = weight.shape().num_elements() / channels;
+    let kept = weight
+        .abs()
+        .reshape([channels, channel_size])
+        .sum_dim(1)
+        .into_data()
+        .iter::<f32>()
+        .enumerate()
+        .filter(|(_, magnitude)| *magnitude != 0.0)
+        .map(|(channel, _)| channel as i64)
+        .collect::<Vec<_>>();
+
+    assert!(
+        !kept.is_empty(),
+        "All the output channels of the convolution are pruned"
+    );
+
+    if kept.len() == channels {
+        return (conv, next);
+    }
+
+    let num_kept = kept.len();
+    let kept = Tensor::<B, 1, Int>::from_data(TensorData::new(kept, [num_kept]), &device);
+
+    let conv = Conv2d {
+        weight: select(conv.weight, 0, kept.clone()),
+        bias: conv.bias.map(|bias| select(bias, 0, kept.clone())),
+        ..conv
+    };
+    let next = Conv2d {
+        weight: select(next.weight, 1, kept),
+        ..next
+    };
+
+    (conv, next)
+}
+
+/// Keep the given indices of a parameter along a dimension, the result being a leaf again.
+fn select<B: Backend, const D: usize>(
+    param: Param<Tensor<B, D>>,
+    dim: usize,
+    indices: Tensor<B, 1, Int>,
+) -> Param<Tensor<B, D>> {
+    param.map(|tensor| {
+        let is_require_grad = tensor.is_require_grad();
+        tensor
+            .detach()
+            .select(dim, indices.clone())
+            .set_require_grad(is_require_grad)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::module::Module;
+    use crate::nn::conv::Conv2dConfig;
+    use crate::nn::PaddingConfig2d;
+    use crate::prune::{apply_pruning, PruningConfig, PruningScope, PruningStructure};
+    use crate::tensor::{activation::relu, Distribution};
+    use crate::TestBackend;
+
+    fn forward(
+        layers: &[Conv2d<TestBackend>],
+        input: Tensor<TestBackend, 4>,
+    ) -> Tensor<TestBackend, 4> {
+        layers.iter().fold(input, |x, layer| relu(layer.forward(x)))
+    }
+
+    #[test]
+    fn should_remove_the_pruned_channels_and_the_following_input_channels() {
+        let device = Default::default();
+        let layers = [[3, 8], [8, 6], [6, 4]]
+            .map(|channels| {
+                Conv2dConfig::new(channels, [3, 3])
+                    .with_padding(PaddingConfig2d::Same)
+                    .with_bias(false)
+                    .init::<TestBackend>(&device)
+            })
+            .to_vec();
+        let config = PruningConfig::new(0.5)
+            .with_scope(PruningScope::PerLayer)
+            .with_structure(PruningStructure::Channels);
+
+        let (layers, _) = apply_pruning(layers, &config).make_permanent();
+        let input = Tensor::<TestBackend, 4>::random([2, 3, 5, 5], Distribution::Default, &device);
+        let expected = forward(&layers, input.clone());
+
+        let layers = shrink_conv2d_channels(layers);
+
+        let shapes = layers
+            .iter()
+            .map(|layer| layer.weight.dims())
+            .collect::<Vec<_>>();
+        assert_eq!(shapes, [[4, 3, 3, 3], [3, 4, 3, 3], [4, 3, 3, 3]]);
+        forward(&layers, input)
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 4);
+    }
+}
@@ -0,0 +1,7 @@
+mod base;
+mod channels;
+mod param;
+
+pub use base::*;
+pub use channels::*;
+pub use param::*;
@@ -0,0 +1,260 @@
+use crate as burn;
+
+use crate::module::{Module, ModuleMapper, Param, ParamId};
+use crate::tensor::{backend::Backend, Bool, ElementConversion, Tensor};
+use alloc::vec::Vec;
+
+/// The pruning mask of a parameter, multiplied into it on each forward pass of a
+/// [pruned module](Pruned).
+#[derive(Module, Debug)]
+pub struct PrunedParam<B: Backend> {
+    /// The flattened mask of the parameter, where the kept weights are true.
+    pub mask: Param<Tensor<B, 1, Bool>>,
+}
+
+impl<B: Backend> PrunedParam<B> {
+    /// Create the pruning parametrization of the given flattened mask.
+    pub fn new(mask: Tensor<B, 1, Bool>) -> Self {
+        Self {
+            mask: Param::initialized(ParamId::new(), mask),
+        }
+    }
+
+    /// Multiply the mask into the parameter.
+    pub fn apply<const D: usize>(&self, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        let mask = self.mask.val().reshape(tensor.shape());
+        tensor * mask.float()
+    }
+
+    /// The number of pruned weights.
+    pub fn num_pruned(&self) -> usize {
+        let [num_weights] = self.mask.dims();
+        let num_kept = self.mask.val().int().sum().into_scalar().elem::<i64>();
+
+        num_weights - num_kept as usize
+    }
+}
+
+/// A module with [pruning masks](PrunedParam), created with
+/// [apply_pruning](super::apply_pruning).
+///
+/// The forward pass runs on the [masked](Pruned::masked) module, while the optimizer updates
+/// this one, whose masks are kept as they are. Since the gradients flow through the masks, the
+/// pruned weights are zero after each optimizer step.
+///
+/// # Example
+///
+/// ```rust, ignore
+/// let output = pruned.masked().forward(input);
+/// let grads = GradientsParams::from_grads(loss.backward(), &pruned);
+/// let pruned = optim.step(lr, pruned, grads);
+/// ```
+#[derive(Module, Debug)]
+pub struct Pruned<B: Backend, M> {
+    /// The module, with its unpruned weights.
+    pub module: M,
+    /// The masks of the float parameters of the module, in the order they are visited, which
+    /// are `None` for the parameters that aren't pruned.
+    pub params: Vec<Option<PrunedParam<B>>>,
+}
+
+/// The sparsity reached by [pruning](super::PruningConfig) a module.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PruningReport {
+    /// The number of pruned weights.
+    pub num_pruned: usize,
+    /// The number of weights of the pruned parameters.
+    pub num_weights: usize,
+}
+
+impl PruningReport {
+    /// The fraction of the weights of the pruned parameters that are pruned.
+    pub fn sparsity(&self) -> f64 {
+        match self.num_weights {
+            0 => 0.0,
+            num_weights => self.num_pruned as f64 / num_weights as f64,
+        }
+    }
+}
+
+impl core::fmt::Display for PruningReport {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Pruned {} of {} weights ({:.2}% sparsity)",
+            self.num_pruned,
+            self.num_weights,
+            self.sparsity() * 100.0
+        )
+    }
+}
+
+impl<B: Backend, M: Module<B>> Pruned<B, M> {
+    /// The module with the masks multiplied into its parameters, used for the forward pass.
+    ///
+    /// The parameters of the masked module aren't leaves of the graph, so the gradients must be
+    /// collected from the pruned module.
+    pub fn masked(&self) -> M {
+        self.module
+            .clone()
+            .map(&mut MaskMapper::new(&self.params, false))
+    }
+
+    /// The sparsity of the pruned parameters.
+    pub fn report(&self) -> PruningReport {
+        self.params.iter().flatten().fold(
+            PruningReport {
+                num_pruned: 0,
+                num_weights: 0,
+            },
+            |report, param| PruningReport {
+                num_pruned: report.num_pruned + param.num_pruned(),
+                num_weights: report.num_weights + param.mask.dims()[0],
+            },
+        )
+    }
+
+    /// Bake the zeros of the masks into the module, returning it with the sparsity reached.
+    ///
+    /// The parameters of the returned module are leaves again, so the pruned weights may not stay
+    /// zero during further training.
+    pub fn make_permanent(self) -> (M, PruningReport) {
+        let report = self.report();
+        let module = self.module.map(&mut MaskMapper::new(&self.params, true));
+
+        (module, report)
+    }
+}
+
+/// Multiply the masks into the float parameters of a module, in the order they are visited.
+struct MaskMapper<'a, B: Backend> {
+    params: &'a [Option<PrunedParam<B>>],
+    index: usize,
+    permanent: bool,
+}
+
+impl<'a, B: Backend> MaskMapper<'a, B> {
+    fn new(params: &'a [Option<PrunedParam<B>>], permanent: bool) -> Self {
+        Self {
+            params,
+            index: 0,
+            permanent,
+        }
+    }
+}
+
+impl<B: Backend> ModuleMapper<B> for MaskMapper<'_, B> {
+    fn map_float<const D: usize>(&mut self, _id: &ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        let param = self.params.get(self.index);
+        self.index += 1;
+
+        match param {
+            Some(Some(param)) if self.permanent => {
+                let is_require_grad = tensor.is_require_grad();
+                param
+                    .apply(tensor.detach())
+                    .set_require_grad(is_require_grad)
+            }
+            Some(Some(param)) => param.apply(tensor),
+            _ => tensor,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::{Linear, LinearConfig};
+    use crate::prune::{apply_pruning, PruningConfig};
+    use crate::TestBackend;
+
+    #[test]
+    fn masked_module_should_zero_the_pruned_weights() {
+        let device = Default::default();
+        let linear: Linear<TestBackend> = LinearConfig::new(6, 4).init(&device);
+        let weight = linear.weight.val();
+
+        let pruned = apply_pruning(linear, &PruningConfig::new(0.5));
+        let mask = pruned.params[0]
+            .as_ref()
+            .unwrap()
+            .mask
+            .val()
+            .reshape([6, 4]);
+        let masked = pruned.masked();
+
+        masked.weight.val().into_data().assert_eq(
+            &weight.clone().mask_fill(mask.bool_not(), 0.0).into_data(),
+            false,
+        );
+        // The weights of the pruned module are left as they are.
+        pruned
+            .module
+            .weight
+            .val()
+            .into_data()
+            .assert_eq(&weight.into_data(), false);
+        assert!(pruned.params[1].is_none());
+    }
+
+    #[test]
+    fn masks_should_round_trip_through_records() {
+        use crate::record::{BinBytesRecorder, FullPrecisionSettings, Recorder};
+
+        let device = Default::default();
+        let linear: Linear<TestBackend> = LinearConfig::new(6, 4).init(&device);
+        let pruned = apply_pruning(linear.clone(), &PruningConfig::new(0.5));
+        let recorder = BinBytesRecorder::<FullPrecisionSettings>::default();
+
+        let bytes = recorder.record(pruned.clone().into_record(), ()).unwrap();
+        let loaded = apply_pruning(linear, &PruningConfig::new(0.0))
+            .load_record(recorder.load(bytes, &device).unwrap());
+
+        assert_eq!(loaded.report(), pruned.report());
+        loaded.params[0]
+            .as_ref()
+            .unwrap()
+            .mask
+            .val()
+            .into_data()
+            .assert_eq(
+                &pruned.params[0].as_ref().unwrap().mask.val().into_data(),
+                false,
+            );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn masks_should_persist_through_optimizer_steps() {
+        use crate::optim::{AdamConfig, GradientsParams, Optimizer};
+        use crate::tensor::Distribution;
+        use crate::TestAutodiffBackend;
+
+        let device = Default::default();
+        let linear: Linear<TestAutodiffBackend> = LinearConfig::new(6, 4).init(&device);
+        let mut pruned = apply_pruning(linear, &PruningConfig::new(0.5));
+        let mask = pruned.params[0].as_ref().unwrap().mask.val().into_data();
+        let mut optim = AdamConfig::new().init();
+
+        for _ in 0..3 {
+            let input =
+                Tensor::<TestAutodiffBackend, 2>::random([8, 6], Distribution::Default, &device);
+            let loss = pruned.masked().forward(input).powf_scalar(2.0).mean();
+            let grads = GradientsParams::from_grads(loss.backward(), &pruned);
+            pruned = optim.step(1e-2, pruned, grads);
+        }
+
+        let pruned_mask = pruned.params[0].as_ref().unwrap().mask.val();
+        pruned_mask.clone().into_data().assert_eq(&mask, false);
+
+        let (linear, report) = pruned.make_permanent();
+        assert_eq!(report.num_pruned, 12);
+        linear
+            .weight
+            .val()
+            .equal_elem(0.0)
+            .into_data()
+            .assert_eq(&pruned_mask.reshape([6, 4]).bool_not().into_data(), false);
+        assert!(linear.weight.is_require_grad());
+    }
+}
@@ -12,6 +12,12 @@ pub trait FileRecorder<B: Backend>:
 {
     /// File extension of the format used by the recorder.
     fn file_extension() -> &'static str;
+
+    /// The files of the record saved at the given path.
+    fn record_files(&self, mut path: PathBuf) -> Vec<PathBuf> {
+        path.set_extension(Self::file_extension());
+        vec![path]
+    }
 }
 
 /// Default [file recorder](FileRecorder).
@@ -16,6 +16,11 @@ mod file;
 #[cfg(feature = "std")]
 pub use file::*;
 
+#[cfg(feature = "std")]
+mod shard;
+#[cfg(feature = "std")]
+pub use shard::*;
+
 pub use primitive::ParamSerde;
 
 #[cfg(feature = "record-item-custom-serde")]
@@ -3,7 +3,7 @@ use core::marker::PhantomData;
 
 use alloc::format;
 use alloc::string::{String, ToString};
-use burn_tensor::backend::Backend;
+use burn_tensor::{backend::Backend, DType};
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 
 use super::{BinBytesRecorder, FullPrecisionSettings, PrecisionSettings, Record};
@@ -104,6 +104,17 @@ pub trait Recorder<B: Backend>:
         Ok(R::from_item(item.item, device))
     }
 
+    /// Load the [metadata](BurnMetadata) of a recorded item, without its values.
+    ///
+    /// The metadata give the [float data type](BurnMetadata::float_dtype) of the record, which
+    /// can be used to select the backend loading it at runtime with
+    /// [dispatch_float_dtype](crate::dispatch_float_dtype).
+    fn load_metadata(&self, args: Self::LoadArgs) -> Result<BurnMetadata, RecorderError> {
+        let record: BurnRecordNoItem = self.load_item(args)?;
+
+        Ok(record.metadata)
+    }
+
     /// Saves an item.
     ///
     /// This method is used by [record](Recorder::record) to save the item.
@@ -198,6 +209,21 @@ pub struct BurnMetadata {
     pub settings: String,
 }
 
+impl BurnMetadata {
+    /// The data type of the floats of the record, if it is one of the float types supported by
+    /// the [precision settings](PrecisionSettings).
+    pub fn float_dtype(&self) -> Option<DType> {
+        [
+            (type_name::<f64>(), DType::F64),
+            (type_name::<f32>(), DType::F32),
+            (type_name::<half::f16>(), DType::F16),
+            (type_name::<half::bf16>(), DType::BF16),
+        ]
+        .into_iter()
+        .find_map(|(name, dtype)| (name == self.float).then_some(dtype))
+    }
+}
+
 /// Record that can be saved by a [Recorder](Recorder).
 #[derive(Serialize, Deserialize, Debug)]
 pub struct BurnRecord<I, B: Backend> {
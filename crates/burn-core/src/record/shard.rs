@@ -0,0 +1,987 @@
+use super::{FileRecorder, PrecisionSettings, Recorder, RecorderError};
+use burn_tensor::backend::Backend;
+use core::marker::PhantomData;
+use serde::{
+    de::{self, DeserializeOwned, DeserializeSeed, IntoDeserializer, MapAccess, Visitor},
+    ser::{self, Impossible, SerializeStruct},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+use std::io::{BufReader, BufWriter, Write};
+use std::{fs::File, path::Path, path::PathBuf};
+
+/// File recorder splitting the record into multiple files, listed in a JSON manifest.
+///
+/// The record is split by top-level module: each field of the saved module is serialized with the
+/// [named msgpack](rmp_serde) format in its own shard, unless a
+/// [maximum shard size](ShardedFileRecorder::with_max_shard_size) is set, in which case
+/// consecutive fields are grouped in shards up to that size. The manifest lists the fields of each
+/// shard with their hash, so that a missing or corrupted shard is detected when loading. The shards
+/// are read one at a time, so loading never holds more than one shard in memory on top of the
+/// loaded record.
+///
+/// The manifest is saved with the `shards.json` extension, and the shards next to it with the
+/// `shard-<index>-<hash>.mpk` extension, the hash being the start of the hash of their content. A
+/// save never replaces the shards of the previous record with different content, and moves its
+/// manifest in place last, so a save failing or interrupted before that leaves the previous record
+/// loadable. The shards of the previous record are only removed once the new manifest is in place,
+/// but the shards written by an interrupted save are left behind.
+#[derive(Debug, Default, Clone)]
+pub struct ShardedFileRecorder<S: PrecisionSettings> {
+    max_shard_size: Option<usize>,
+    _settings: PhantomData<S>,
+}
+
+impl<S: PrecisionSettings> ShardedFileRecorder<S> {
+    /// Creates a recorder saving each top-level module in its own shard.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Group the top-level modules in shards of at most `max_shard_size` bytes.
+    ///
+    /// A module bigger than the maximum size is saved alone in its shard.
+    pub fn with_max_shard_size(mut self, max_shard_size: usize) -> Self {
+        self.max_shard_size = Some(max_shard_size);
+        self
+    }
+}
+
+/// The manifest of a [sharded record](ShardedFileRecorder).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShardManifest {
+    /// The fields of the record saved in the manifest, such as its metadata.
+    pub header: Vec<(String, serde_json::Value)>,
+    /// If the item of the record is split by field, otherwise it is the single entry of the
+    /// shards.
+    pub split: bool,
+    /// The shards of the item.
+    pub shards: Vec<ShardInfo>,
+}
+
+/// A shard listed in a [manifest](ShardManifest).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShardInfo {
+    /// The file name of the shard, in the directory of the manifest.
+    pub file: String,
+    /// The size of the shard in bytes.
+    pub size: usize,
+    /// The md5 hash of the shard.
+    pub hash: String,
+    /// The fields of the item saved in the shard.
+    pub entries: Vec<ShardEntry>,
+}
+
+/// A field of the item saved in a [shard](ShardInfo).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShardEntry {
+    /// The name of the field.
+    pub name: String,
+    /// The offset of the field in the shard.
+    pub offset: usize,
+    /// The size of the field in bytes.
+    pub size: usize,
+}
+
+impl ShardManifest {
+    /// Read the manifest of a record saved by a [sharded recorder](ShardedFileRecorder), with its
+    /// extension.
+    pub fn read(path: &Path) -> Result<Self, RecorderError> {
+        let file = File::open(path).map_err(|err| match err.kind() {
+            std::io::ErrorKind::NotFound => RecorderError::FileNotFound(err.to_string()),
+            _ => RecorderError::Unknown(err.to_string()),
+        })?;
+
+        serde_json::from_reader(BufReader::new(file))
+            .map_err(|err| RecorderError::DeserializeError(err.to_string()))
+    }
+
+    /// The paths of the shards, in the directory of the manifest at the given path.
+    pub fn shard_paths(&self, path: &Path) -> Vec<PathBuf> {
+        let directory = path.parent().unwrap_or(Path::new(""));
+
+        self.shards
+            .iter()
+            .map(|shard| directory.join(&shard.file))
+            .collect()
+    }
+}
+
+impl<S: PrecisionSettings, B: Backend> FileRecorder<B> for ShardedFileRecorder<S> {
+    fn file_extension() -> &'static str {
+        "shards.json"
+    }
+
+    fn record_files(&self, mut path: PathBuf) -> Vec<PathBuf> {
+        path.set_extension(<Self as FileRecorder<B>>::file_extension());
+
+        let mut files = match ShardManifest::read(&path) {
+            Ok(manifest) => manifest.shard_paths(&path),
+            Err(_) => Vec::new(),
+        };
+        files.push(path);
+        files
+    }
+}
+
+impl<S: PrecisionSettings, B: Backend> Recorder<B> for ShardedFileRecorder<S> {
+    type Settings = S;
+    type RecordArgs = PathBuf;
+    type RecordOutput = ();
+    type LoadArgs = PathBuf;
+
+    fn save_item<I: Serialize>(
+        &self,
+        item: I,
+        file: Self::RecordArgs,
+    ) -> Result<(), RecorderError> {
+        let parts = item.serialize(RecordSerializer)?;
+        let (split, entries) = match parts.item {
+            Some(ItemParts::Split(entries)) => (true, entries),
+            Some(ItemParts::Whole(bytes)) => (false, vec![(String::new(), bytes)]),
+            None => {
+                return Err(RecorderError::Unknown(
+                    "The record doesn't have any item".to_string(),
+                ))
+            }
+        };
+
+        // The files of the previous record are kept until the new manifest is in place.
+        let previous_files = <Self as FileRecorder<B>>::record_files(self, file.clone());
+
+        let mut manifest_path = file.clone();
+        manifest_path.set_extension(<Self as FileRecorder<B>>::file_extension());
+        if let Some(parent) = manifest_path.parent() {
+            std::fs::create_dir_all(parent).ok();
+        }
+
+        let shards = self.write_shards(&file, entries, &previous_files)?;
+        let manifest = ShardManifest {
+            header: parts.header,
+            split,
+            shards,
+        };
+        let paths = manifest.shard_paths(&manifest_path);
+
+        write_manifest(&manifest_path, &manifest)
+            .and_then(|_| {
+                std::fs::rename(temp_path(&manifest_path), &manifest_path)
+                    .map_err(|err| RecorderError::Unknown(err.to_string()))
+            })
+            .map_err(|err| {
+                remove_temp_files(&[manifest_path.clone()]);
+                remove_new_files(&paths, &previous_files);
+                err
+            })?;
+
+        // Remove the shards of the previous record that aren't part of the new one.
+        for path in previous_files {
+            if path != manifest_path && !paths.contains(&path) && path.exists() {
+                std::fs::remove_file(path)
+                    .map_err(|err| RecorderError::Unknown(err.to_string()))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn load_item<I: DeserializeOwned>(&self, mut file: Self::LoadArgs) -> Result<I, RecorderError> {
+        file.set_extension(<Self as FileRecorder<B>>::file_extension());
+        let manifest = ShardManifest::read(&file)?;
+        let directory = file.parent().unwrap_or(Path::new("")).to_path_buf();
+
+        I::deserialize(RecordDeserializer {
+            manifest: &manifest,
+            directory: &directory,
+        })
+        .map_err(RecorderError::from)
+    }
+}
+
+impl<S: PrecisionSettings> ShardedFileRecorder<S> {
+    /// Write the shards of the entries next to the file, each named after its index and the hash
+    /// of its content, so the shards of the previous record with other content are kept.
+    ///
+    /// On failure, the files written that aren't part of the previous record are removed.
+    fn write_shards(
+        &self,
+        file: &Path,
+        entries: Vec<(String, Vec<u8>)>,
+        previous_files: &[PathBuf],
+    ) -> Result<Vec<ShardInfo>, RecorderError> {
+        let mut paths = Vec::new();
+        let mut shards = Vec::new();
+
+        for (index, group) in self.group(entries).into_iter().enumerate() {
+            let mut temp = file.to_path_buf();
+            temp.set_extension(format!("shard-{index}.mpk"));
+
+            let written = write_shard(&temp, group).and_then(|mut shard| {
+                let mut path = file.to_path_buf();
+                path.set_extension(format!("shard-{index}-{}.mpk", &shard.hash[..16]));
+                shard.file = file_name(&path);
+
+                std::fs::rename(temp_path(&temp), &path)
+                    .map_err(|err| RecorderError::Unknown(err.to_string()))?;
+                paths.push(path);
+
+                Ok(shard)
+            });
+
+            match written {
+                Ok(shard) => shards.push(shard),
+                Err(err) => {
+                    remove_temp_files(&[temp]);
+                    remove_new_files(&paths, previous_files);
+                    return Err(err);
+                }
+            }
+        }
+
+        Ok(shards)
+    }
+
+    fn group(&self, entries: Vec<(String, Vec<u8>)>) -> Vec<Vec<(String, Vec<u8>)>> {
+        let Some(max_shard_size) = self.max_shard_size else {
+            return entries.into_iter().map(|entry| vec![entry]).collect();
+        };
+
+        let mut groups: Vec<Vec<(String, Vec<u8>)>> = Vec::new();
+        let mut size = 0;
+        for entry in entries {
+            match groups.last_mut() {
+                Some(group) if size + entry.1.len() <= max_shard_size => {
+                    size += entry.1.len();
+                    group.push(entry);
+                }
+                _ => {
+                    size = entry.1.len();
+                    groups.push(vec![entry]);
+                }
+            }
+        }
+
+        groups
+    }
+}
+
+/// The file a shard or a manifest is written to before being moved to its path.
+fn temp_path(path: &Path) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(".tmp");
+    path.with_file_name(name)
+}
+
+fn remove_temp_files(paths: &[PathBuf]) {
+    for path in paths {
+        std::fs::remove_file(temp_path(path)).ok();
+    }
+}
+
+/// Remove the files written by a failed save, unless the previous record uses them.
+fn remove_new_files(paths: &[PathBuf], previous_files: &[PathBuf]) {
+    for path in paths {
+        if !previous_files.contains(path) {
+            std::fs::remove_file(path).ok();
+        }
+    }
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default()
+}
+
+fn write_manifest(path: &Path, manifest: &ShardManifest) -> Result<(), RecorderError> {
+    let mut writer = File::create(temp_path(path))
+        .map(BufWriter::new)
+        .map_err(|err| RecorderError::Unknown(err.to_string()))?;
+    serde_json::to_writer_pretty(&mut writer, manifest)
+        .map_err(|err| RecorderError::Unknown(err.to_string()))?;
+    writer
+        .flush()
+        .map_err(|err| RecorderError::Unknown(err.to_string()))
+}
+
+/// Write the entries of a shard to its [temporary file](temp_path).
+fn write_shard(path: &Path, entries: Vec<(String, Vec<u8>)>) -> Result<ShardInfo, RecorderError> {
+    let mut writer = File::create(temp_path(path))
+        .map(BufWriter::new)
+        .map_err(|err| RecorderError::Unknown(err.to_string()))?;
+    let mut context = md5::Context::new();
+    let mut infos = Vec::with_capacity(entries.len());
+    let mut offset = 0;
+
+    for (name, bytes) in entries {
+        writer
+            .write_all(&bytes)
+            .map_err(|err| RecorderError::Unknown(err.to_string()))?;
+        context.consume(&bytes);
+        infos.push(ShardEntry {
+            name,
+            offset,
+            size: bytes.len(),
+        });
+        offset += bytes.len();
+    }
+    writer
+        .flush()
+        .map_err(|err| RecorderError::Unknown(err.to_string()))?;
+
+    Ok(ShardInfo {
+        file: file_name(path),
+        size: offset,
+        hash: format!("{:x}", context.compute()),
+        entries: infos,
+    })
+}
+
+#[derive(Debug)]
+enum ShardError {
+    NotStruct,
+    Missing(String),
+    Corrupted(String),
+    Serde(String),
+}
+
+impl core::fmt::Display for ShardError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ShardError::NotStruct => f.write_str("The item isn't a struct"),
+            ShardError::Missing(message) => f.write_str(message),
+            ShardError::Corrupted(message) => f.write_str(message),
+            ShardError::Serde(message) => f.write_str(message),
+        }
+    }
+}
+
+impl std::error::Error for ShardError {}
+
+impl ser::Error for ShardError {
+    fn custom<T: core::fmt::Display>(msg: T) -> Self {
+        ShardError::Serde(msg.to_string())
+    }
+}
+
+impl de::Error for ShardError {
+    fn custom<T: core::fmt::Display>(msg: T) -> Self {
+        ShardError::Serde(msg.to_string())
+    }
+}
+
+impl From<ShardError> for RecorderError {
+    fn from(err: ShardError) -> Self {
+        match err {
+            ShardError::Missing(message) => RecorderError::FileNotFound(message),
+            ShardError::Corrupted(message) => RecorderError::DeserializeError(message),
+            err => RecorderError::Unknown(err.to_string()),
+        }
+    }
+}
+
+/// The record split into the fields saved in the manifest and its item.
+struct RecordParts {
+    header: Vec<(String, serde_json::Value)>,
+    item: Option<ItemParts>,
+}
+
+enum ItemParts {
+    /// The serialized fields of the item.
+    Split(Vec<(String, Vec<u8>)>),
+    /// The serialized item, when it isn't a struct.
+    Whole(Vec<u8>),
+}
+
+/// Serialize the unsupported types as an error, so that only the structs are split.
+macro_rules! unsupported {
+    ($($method:ident($($arg:ty),*) -> $output:ty;)*) => {
+        $(
+            fn $method(self, $(_: $arg),*) -> Result<$output, Self::Error> {
+                Err(ShardError::NotStruct)
+            }
+        )*
+    };
+}
+
+macro_rules! unsupported_serializer {
+    () => {
+        type SerializeSeq = Impossible<Self::Ok, Self::Error>;
+        type SerializeTuple = Impossible<Self::Ok, Self::Error>;
+        type SerializeTupleStruct = Impossible<Self::Ok, Self::Error>;
+        type SerializeTupleVariant = Impossible<Self::Ok, Self::Error>;
+        type SerializeMap = Impossible<Self::Ok, Self::Error>;
+        type SerializeStructVariant = Impossible<Self::Ok, Self::Error>;
+
+        unsupported! {
+            serialize_bool(bool) -> Self::Ok;
+            serialize_i8(i8) -> Self::Ok;
+            serialize_i16(i16) -> Self::Ok;
+            serialize_i32(i32) -> Self::Ok;
+            serialize_i64(i64) -> Self::Ok;
+            serialize_u8(u8) -> Self::Ok;
+            serialize_u16(u16) -> Self::Ok;
+            serialize_u32(u32) -> Self::Ok;
+            serialize_u64(u64) -> Self::Ok;
+            serialize_f32(f32) -> Self::Ok;
+            serialize_f64(f64) -> Self::Ok;
+            serialize_char(char) -> Self::Ok;
+            serialize_str(&str) -> Self::Ok;
+            serialize_bytes(&[u8]) -> Self::Ok;
+            serialize_none() -> Self::Ok;
+            serialize_unit() -> Self::Ok;
+            serialize_unit_struct(&'static str) -> Self::Ok;
+            serialize_unit_variant(&'static str, u32, &'static str) -> Self::Ok;
+            serialize_seq(Option<usize>) -> Self::SerializeSeq;
+            serialize_tuple(usize) -> Self::SerializeTuple;
+            serialize_tuple_struct(&'static str, usize) -> Self::SerializeTupleStruct;
+            serialize_tuple_variant(&'static str, u32, &'static str, usize) -> Self::SerializeTupleVariant;
+            serialize_map(Option<usize>) -> Self::SerializeMap;
+            serialize_struct_variant(&'static str, u32, &'static str, usize) -> Self::SerializeStructVariant;
+        }
+
+        fn serialize_some<T: ?Sized + Serialize>(self, _value: &T) -> Result<Self::Ok, Self::Error> {
+            Err(ShardError::NotStruct)
+        }
+
+        fn serialize_newtype_variant<T: ?Sized + Serialize>(
+            self,
+            _name: &'static str,
+            _variant_index: u32,
+            _variant: &'static str,
+            _value: &T,
+        ) -> Result<Self::Ok, Self::Error> {
+            Err(ShardError::NotStruct)
+        }
+    };
+}
+
+/// Serialize the fields of the record other than its item in the manifest.
+struct RecordSerializer;
+
+struct RecordFields(RecordParts);
+
+impl Serializer for RecordSerializer {
+    type Ok = RecordParts;
+    type Error = ShardError;
+    type SerializeStruct = RecordFields;
+
+    unsupported_serializer!();
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        Ok(RecordFields(RecordParts {
+            header: Vec::with_capacity(len),
+            item: None,
+        }))
+    }
+}
+
+impl SerializeStruct for RecordFields {
+    type Ok = RecordParts;
+    type Error = ShardError;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        if key != "item" {
+            let value = serde_json::to_value(value).map_err(ser::Error::custom)?;
+            self.0.header.push((key.to_string(), value));
+            return Ok(());
+        }
+
+        let item = match value.serialize(ItemSerializer) {
+            Ok(entries) => ItemParts::Split(entries),
+            Err(ShardError::NotStruct) => {
+                ItemParts::Whole(rmp_serde::to_vec_named(value).map_err(ser::Error::custom)?)
+            }
+            Err(err) => return Err(err),
+        };
+        self.0.item = Some(item);
+
+        Ok(())
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(self.0)
+    }
+}
+
+/// Serialize each field of the item separately.
+struct ItemSerializer;
+
+struct ItemFields(Vec<(String, Vec<u8>)>);
+
+impl Serializer for ItemSerializer {
+    type Ok = Vec<(String, Vec<u8>)>;
+    type Error = ShardError;
+    type SerializeStruct = ItemFields;
+
+    unsupported_serializer!();
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        _value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        Err(ShardError::NotStruct)
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        Ok(ItemFields(Vec::with_capacity(len)))
+    }
+}
+
+impl SerializeStruct for ItemFields {
+    type Ok = Vec<(String, Vec<u8>)>;
+    type Error = ShardError;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        let bytes = rmp_serde::to_vec_named(value).map_err(ser::Error::custom)?;
+        self.0.push((key.to_string(), bytes));
+
+        Ok(())
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(self.0)
+    }
+}
+
+/// Deserialize the record from the header of the manifest and the item from the shards.
+struct RecordDeserializer<'a> {
+    manifest: &'a ShardManifest,
+    directory: &'a Path,
+}
+
+impl<'de> Deserializer<'de> for RecordDeserializer<'_> {
+    type Error = ShardError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_map(RecordAccess {
+            manifest: self.manifest,
+            shards: ShardReader::new(self.manifest, self.directory),
+            index: 0,
+        })
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+struct RecordAccess<'a> {
+    manifest: &'a ShardManifest,
+    shards: ShardReader<'a>,
+    /// The index of the next field, where the item is the field after the header.
+    index: usize,
+}
+
+impl<'de> MapAccess<'de> for RecordAccess<'_> {
+    type Error = ShardError;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Self::Error> {
+        let key = match self.manifest.header.get(self.index) {
+            Some((name, _)) => name.as_str(),
+            None if self.index == self.manifest.header.len() => "item",
+            None => return Ok(None),
+        };
+
+        seed.deserialize(key.into_deserializer()).map(Some)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(
+        &mut self,
+        seed: V,
+    ) -> Result<V::Value, Self::Error> {
+        let index = self.index;
+        self.index += 1;
+
+        if let Some((_, value)) = self.manifest.header.get(index) {
+            return seed.deserialize(value.clone()).map_err(de::Error::custom);
+        }
+
+        match self.manifest.split {
+            true => seed.deserialize(ItemDeserializer {
+                shards: &mut self.shards,
+            }),
+            false => self.shards.next_entry(seed),
+        }
+    }
+}
+
+struct ItemDeserializer<'a, 'b> {
+    shards: &'a mut ShardReader<'b>,
+}
+
+impl<'de> Deserializer<'de> for ItemDeserializer<'_, '_> {
+    type Error = ShardError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_map(ItemAccess {
+            shards: self.shards,
+        })
+    }
+
+    // Ignoring the item, such as when only reading the metadata, doesn't read the shards.
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_unit()
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct enum identifier
+    }
+}
+
+struct ItemAccess<'a, 'b> {
+    shards: &'a mut ShardReader<'b>,
+}
+
+impl<'de> MapAccess<'de> for ItemAccess<'_, '_> {
+    type Error = ShardError;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Self::Error> {
+        match self.shards.peek_name() {
+            Some(name) => seed.deserialize(name.into_deserializer()).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(
+        &mut self,
+        seed: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.shards.next_entry(seed)
+    }
+}
+
+/// Read the entries of the shards in order, holding only the current shard in memory.
+struct ShardReader<'a> {
+    manifest: &'a ShardManifest,
+    directory: &'a Path,
+    shard: usize,
+    entry: usize,
+    bytes: Option<Vec<u8>>,
+}
+
+impl<'a> ShardReader<'a> {
+    fn new(manifest: &'a ShardManifest, directory: &'a Path) -> Self {
+        let mut reader = Self {
+            manifest,
+            directory,
+            shard: 0,
+            entry: 0,
+            bytes: None,
+        };
+        reader.skip_empty_shards();
+        reader
+    }
+
+    fn skip_empty_shards(&mut self) {
+        while let Some(shard) = self.manifest.shards.get(self.shard) {
+            if self.entry < shard.entries.len() {
+                break;
+            }
+            self.shard += 1;
+            self.entry = 0;
+            self.bytes = None;
+        }
+    }
+
+    fn peek_name(&self) -> Option<&'a str> {
+        let shard = self.manifest.shards.get(self.shard)?;
+        Some(shard.entries[self.entry].name.as_str())
+    }
+
+    fn next_entry<'de, V: DeserializeSeed<'de>>(
+        &mut self,
+        seed: V,
+    ) -> Result<V::Value, ShardError> {
+        let manifest = self.manifest;
+        let Some(shard) = manifest.shards.get(self.shard) else {
+            return Err(ShardError::Serde("No entry left in the shards".to_string()));
+        };
+        let entry = &shard.entries[self.entry];
+
+        if self.bytes.is_none() {
+            self.bytes = Some(self.read(shard)?);
+        }
+        let bytes = self.bytes.as_deref().unwrap_or_default();
+        let Some(bytes) = bytes.get(entry.offset..entry.offset + entry.size) else {
+            return Err(ShardError::Corrupted(format!(
+                "The entry {} is out of the bounds of the shard {}",
+                entry.name, shard.file
+            )));
+        };
+
+        let mut deserializer = rmp_serde::Deserializer::new(bytes);
+        let value = seed.deserialize(&mut deserializer).map_err(|err| {
+            ShardError::Serde(format!(
+                "Unable to deserialize the entry {} of the shard {}: {err}",
+                entry.name, shard.file
+            ))
+        })?;
+
+        self.entry += 1;
+        self.skip_empty_shards();
+
+        Ok(value)
+    }
+
+    fn read(&self, shard: &ShardInfo) -> Result<Vec<u8>, ShardError> {
+        let path = self.directory.join(&shard.file);
+        let bytes = std::fs::read(&path).map_err(|err| match err.kind() {
+            std::io::ErrorKind::NotFound => ShardError::Missing(format!(
+                "The shard {} listed in the manifest is missing",
+                path.display()
+            )),
+            _ => ShardError::Serde(format!(
+                "Unable to read the shard {}: {err}",
+                path.display()
+            )),
+        })?;
+
+        if bytes.len() != shard.size {
+            return Err(ShardError::Corrupted(format!(
+                "The shard {} has {} bytes instead of {}",
+                path.display(),
+                bytes.len(),
+                shard.size
+            )));
+        }
+
+        let hash = format!("{:x}", md5::compute(&bytes));
+        if hash != shard.hash {
+            return Err(ShardError::Corrupted(format!(
+                "The shard {} is corrupted: its hash is {hash} instead of {}",
+                path.display(),
+                shard.hash
+            )));
+        }
+
+        Ok(bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as burn;
+    use crate::{
+        module::Module,
+        nn::{
+            conv::{Conv2d, Conv2dConfig},
+            Linear, LinearConfig,
+        },
+        record::{BinBytesRecorder, FullPrecisionSettings, HalfPrecisionSettings},
+        TestBackend,
+    };
+
+    #[derive(Module, Debug)]
+    pub struct Model<B: Backend> {
+        conv2d1: Conv2d<B>,
+        linear1: Linear<B>,
+        linear2: Linear<B>,
+    }
+
+    fn create_model(device: &<TestBackend as Backend>::Device) -> Model<TestBackend> {
+        Model {
+            conv2d1: Conv2dConfig::new([1, 8], [3, 3]).init(device),
+            linear1: LinearConfig::new(32, 32).init(device),
+            linear2: LinearConfig::new(32, 8).init(device),
+        }
+    }
+
+    fn file_path(name: &str) -> PathBuf {
+        std::env::temp_dir()
+            .join("burn_test_sharded_recorder")
+            .join(name)
+    }
+
+    fn bytes(model: Model<TestBackend>) -> Vec<u8> {
+        BinBytesRecorder::<FullPrecisionSettings>::default()
+            .record(model.into_record(), ())
+            .unwrap()
+    }
+
+    fn shard_files(path: &Path) -> Vec<PathBuf> {
+        let recorder = ShardedFileRecorder::<FullPrecisionSettings>::new();
+        FileRecorder::<TestBackend>::record_files(&recorder, path.to_path_buf())
+    }
+
+    #[test]
+    fn test_can_save_and_load_shards() {
+        let device = Default::default();
+        let path = file_path("round_trip");
+        let recorder = ShardedFileRecorder::<FullPrecisionSettings>::new();
+        let model_before = create_model(&device);
+
+        recorder
+            .record(model_before.clone().into_record(), path.clone())
+            .unwrap();
+        let manifest = ShardManifest::read(&path.with_extension("shards.json")).unwrap();
+        let model_after = create_model(&device).load_record(recorder.load(path, &device).unwrap());
+
+        assert!(manifest.split);
+        assert_eq!(manifest.shards.len(), 3);
+        assert_eq!(manifest.shards[1].entries[0].name, "linear1");
+        assert_eq!(bytes(model_after), bytes(model_before));
+    }
+
+    #[test]
+    fn test_can_group_shards_by_size() {
+        let device = Default::default();
+        let path = file_path("grouped");
+        let model_before = create_model(&device);
+
+        // The first linear layer has more than 4000 bytes of parameters.
+        for (max_shard_size, num_shards) in [(1, 3), (4000, 3), (usize::MAX, 1)] {
+            let recorder = ShardedFileRecorder::<FullPrecisionSettings>::new()
+                .with_max_shard_size(max_shard_size);
+            recorder
+                .record(model_before.clone().into_record(), path.clone())
+                .unwrap();
+
+            assert_eq!(shard_files(&path).len(), num_shards + 1);
+            // The shards of the previous saves that weren't replaced are removed, like the
+            // temporary files.
+            let mut files = std::fs::read_dir(path.parent().unwrap())
+                .unwrap()
+                .map(|entry| entry.unwrap().path())
+                .filter(|file| {
+                    file.file_name()
+                        .unwrap()
+                        .to_string_lossy()
+                        .starts_with("grouped.")
+                })
+                .collect::<Vec<_>>();
+            let mut expected = shard_files(&path);
+            files.sort();
+            expected.sort();
+            assert_eq!(files, expected);
+            let model_after =
+                create_model(&device).load_record(recorder.load(path.clone(), &device).unwrap());
+            assert_eq!(bytes(model_after), bytes(model_before.clone()));
+        }
+    }
+
+    #[test]
+    fn test_detect_corrupted_shard() {
+        let device = Default::default();
+        let path = file_path("corrupted");
+        let recorder = ShardedFileRecorder::<FullPrecisionSettings>::new();
+        recorder
+            .record(create_model(&device).into_record(), path.clone())
+            .unwrap();
+
+        let shard = shard_files(&path)[1].clone();
+        let mut shard_bytes = std::fs::read(&shard).unwrap();
+        let last = shard_bytes.len() - 1;
+        shard_bytes[last] ^= 0xFF;
+        std::fs::write(&shard, shard_bytes).unwrap();
+
+        let result = recorder.load::<ModelRecord<TestBackend>>(path, &device);
+        assert!(matches!(
+            result,
+            Err(RecorderError::DeserializeError(message)) if message.contains("corrupted")
+        ));
+    }
+
+    #[test]
+    fn test_detect_missing_shard() {
+        let device = Default::default();
+        let path = file_path("missing");
+        let recorder = ShardedFileRecorder::<FullPrecisionSettings>::new();
+        recorder
+            .record(create_model(&device).into_record(), path.clone())
+            .unwrap();
+
+        let shard = shard_files(&path)[2].clone();
+        std::fs::remove_file(&shard).unwrap();
+
+        let result = recorder.load::<ModelRecord<TestBackend>>(path, &device);
+        let name = shard.file_name().unwrap().to_string_lossy().to_string();
+        assert!(matches!(
+            result,
+            Err(RecorderError::FileNotFound(message)) if message.contains(&name)
+        ));
+    }
+
+    #[test]
+    fn test_interrupted_save_keeps_the_previous_record() {
+        let device = Default::default();
+        let path = file_path("interrupted");
+        let recorder = ShardedFileRecorder::<FullPrecisionSettings>::new();
+        let model_before = create_model(&device);
+        recorder
+            .record(model_before.clone().into_record(), path.clone())
+            .unwrap();
+        let previous_files = shard_files(&path);
+
+        // The next save stops after its shards are in place, before its manifest is moved.
+        let entries = ["conv2d1", "linear1", "linear2"]
+            .map(|name| (name.to_string(), vec![0; 64]))
+            .to_vec();
+        recorder
+            .write_shards(&path, entries, &previous_files)
+            .unwrap();
+
+        let model_after = create_model(&device).load_record(recorder.load(path, &device).unwrap());
+        assert_eq!(bytes(model_after), bytes(model_before));
+    }
+
+    #[test]
+    fn test_can_convert_shards_precision() {
+        let device = Default::default();
+        let path = file_path("precision");
+        let model = create_model(&device);
+
+        ShardedFileRecorder::<FullPrecisionSettings>::new()
+            .record(model.clone().into_record(), path.clone())
+            .unwrap();
+        let record: ModelRecord<TestBackend> = ShardedFileRecorder::<FullPrecisionSettings>::new()
+            .load(path.clone(), &device)
+            .unwrap();
+
+        let recorder = ShardedFileRecorder::<HalfPrecisionSettings>::new();
+        recorder.record(record, path.clone()).unwrap();
+        let model_half = create_model(&device).load_record(recorder.load(path, &device).unwrap());
+
+        model_half
+            .linear1
+            .weight
+            .val()
+            .into_data()
+            .assert_approx_eq(&model.linear1.weight.val().into_data(), 2);
+    }
+}
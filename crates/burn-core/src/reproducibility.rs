@@ -0,0 +1,79 @@
+use crate::tensor::backend::Backend;
+
+/// Run the function with the random number generators of the backend seeded, restoring their
+/// previous state afterward.
+///
+/// Every random operation of the function, such as the random initialization of the
+/// parameters or the dropout masks, is reproducible, without affecting the random sequences of
+/// the rest of the program. The state can't be restored on backends that can't save it, which
+/// then stay seeded.
+///
+/// # Example
+///
+/// ```rust
+/// use burn_core::reproducibility::fixed_seed;
+/// use burn_core::tensor::{backend::Backend, Distribution, Tensor};
+///
+/// fn example<B: Backend>() {
+///     let device = Default::default();
+///     let random = || Tensor::<B, 2>::random([2, 3], Distribution::Default, &device);
+///
+///     let first = fixed_seed::<B, _>(42, random);
+///     let second = fixed_seed::<B, _>(42, random);
+///     // `first` and `second` are equal.
+/// }
+/// ```
+pub fn fixed_seed<B: Backend, T>(seed: u64, func: impl FnOnce() -> T) -> T {
+    let state = B::rng_state();
+    B::seed(seed);
+
+    let output = func();
+
+    if let Some(state) = state {
+        B::restore_rng_state(state);
+    }
+
+    output
+}
+
+#[cfg(test)]
+#[cfg(feature = "std")]
+mod tests {
+    use super::*;
+    use crate::nn::DropoutConfig;
+    use crate::tensor::{Distribution, Tensor};
+    use crate::TestAutodiffBackend;
+
+    #[test]
+    fn same_seed_should_reproduce_the_dropout_masks() {
+        let device = Default::default();
+        let dropout = DropoutConfig::new(0.5).init();
+        let input = Tensor::<TestAutodiffBackend, 2>::ones([8, 32], &device);
+        let forward = || dropout.forward(input.clone()).into_data();
+
+        let first = fixed_seed::<TestAutodiffBackend, _>(42, forward);
+        let second = fixed_seed::<TestAutodiffBackend, _>(42, forward);
+
+        first.assert_eq(&second, true);
+    }
+
+    #[test]
+    fn state_should_be_restored_after_the_scope() {
+        let device = Default::default();
+        let random =
+            || Tensor::<TestAutodiffBackend, 1>::random([64], Distribution::Default, &device);
+
+        if TestAutodiffBackend::rng_state().is_none() {
+            return;
+        }
+
+        TestAutodiffBackend::seed(7);
+        let expected = random().into_data();
+
+        TestAutodiffBackend::seed(7);
+        fixed_seed::<TestAutodiffBackend, _>(42, random);
+        let actual = random().into_data();
+
+        actual.assert_eq(&expected, true);
+    }
+}
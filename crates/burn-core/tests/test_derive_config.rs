@@ -1,4 +1,4 @@
-use burn::config::{config_to_json, Config};
+use burn::config::{config_to_json, Config, ConfigError};
 use burn_core as burn;
 
 #[derive(Config, Debug, PartialEq, Eq)]
@@ -24,6 +24,39 @@ pub enum TestEnumConfig {
     Named { first: f32, second: String },
 }
 
+#[derive(Config, Debug, PartialEq)]
+pub struct TestLayerConfig {
+    d_model: usize,
+    #[config(default = 0.1)]
+    dropout: f64,
+    activation: Option<String>,
+}
+
+#[derive(Config, Debug, PartialEq)]
+pub struct TestModelConfig {
+    layers: Vec<TestLayerConfig>,
+    name: String,
+    #[config(default = 3e-4)]
+    lr: f64,
+    #[config(default = true)]
+    bias: bool,
+}
+
+/// The fields of [TestModelConfig] that can be overridden, all of them being optional.
+#[derive(serde::Serialize, Default)]
+struct TestModelPartialConfig {
+    name: Option<String>,
+    lr: Option<f64>,
+    bias: Option<bool>,
+}
+
+fn model_config() -> TestModelConfig {
+    TestModelConfig::new(
+        vec![TestLayerConfig::new(64), TestLayerConfig::new(128)],
+        "encoder".to_string(),
+    )
+}
+
 #[cfg(feature = "std")]
 #[inline(always)]
 fn file_path(file_name: &str) -> std::path::PathBuf {
@@ -111,3 +144,245 @@ fn struct_config_can_load_binary() {
     let config_loaded = TestStructConfig::load_binary(&binary).unwrap();
     assert_eq!(config, config_loaded);
 }
+
+#[test]
+fn merge_should_only_override_the_fields_set() {
+    let config = model_config();
+    let partial = TestModelPartialConfig {
+        lr: Some(1e-3),
+        ..Default::default()
+    };
+
+    let merged = config.merge(&partial).unwrap();
+
+    assert_eq!(merged.lr, 1e-3);
+    assert_eq!(merged.name, config.name);
+    assert_eq!(merged.layers, config.layers);
+    assert!(merged.bias);
+}
+
+#[test]
+fn merge_should_give_precedence_to_the_last_partial() {
+    let first = TestModelPartialConfig {
+        name: Some("first".to_string()),
+        lr: Some(1e-3),
+        ..Default::default()
+    };
+    let second = TestModelPartialConfig {
+        lr: Some(1e-2),
+        bias: Some(false),
+        ..Default::default()
+    };
+
+    let merged = model_config()
+        .merge(&first)
+        .unwrap()
+        .merge(&second)
+        .unwrap();
+
+    assert_eq!(merged.name, "first");
+    assert_eq!(merged.lr, 1e-2);
+    assert!(!merged.bias);
+}
+
+#[test]
+fn merge_should_replace_lists_and_merge_nested_configs() {
+    let partial = serde_json::json!({
+        "layers": [{ "d_model": 32, "dropout": 0.0, "activation": "gelu" }],
+    });
+
+    let merged = model_config().merge(&partial).unwrap();
+
+    assert_eq!(
+        merged.layers,
+        vec![TestLayerConfig::new(32)
+            .with_dropout(0.0)
+            .with_activation(Some("gelu".to_string()))]
+    );
+}
+
+#[test]
+fn merge_should_fail_on_unknown_fields() {
+    let partial = serde_json::json!({ "optimizer": { "lr": 1e-3 } });
+
+    let result = model_config().merge(&partial);
+
+    assert!(matches!(result, Err(ConfigError::UnknownPath(_))));
+}
+
+#[test]
+fn merge_should_fail_on_fields_of_another_type() {
+    let partial = serde_json::json!({ "bias": "yes" });
+
+    let result = model_config().merge(&partial);
+
+    assert!(matches!(result, Err(ConfigError::InvalidValue(_))));
+}
+
+#[test]
+fn set_by_path_should_override_fields_of_configs_in_lists() {
+    let mut config = model_config();
+
+    config.set_by_path("layers.1.d_model", "512").unwrap();
+    config.set_by_path("layers.0.dropout", "0.25").unwrap();
+    config.set_by_path("lr", "1e-3").unwrap();
+
+    assert_eq!(
+        config.layers[0],
+        TestLayerConfig::new(64).with_dropout(0.25)
+    );
+    assert_eq!(config.layers[1], TestLayerConfig::new(512));
+    assert_eq!(config.lr, 1e-3);
+}
+
+#[test]
+fn set_by_path_should_set_optional_fields() {
+    let mut config = model_config();
+
+    config.set_by_path("layers.0.activation", "relu").unwrap();
+
+    assert_eq!(config.layers[0].activation, Some("relu".to_string()));
+}
+
+#[test]
+fn set_by_path_should_fail_on_unknown_paths() {
+    let mut config = model_config();
+
+    for path in [
+        "optimizer.lr",
+        "layers.2.d_model",
+        "layers.first.d_model",
+        "lr.value",
+    ] {
+        let result = config.set_by_path(path, "1");
+
+        assert!(
+            matches!(result, Err(ConfigError::UnknownPath(_))),
+            "The path `{path}` should be unknown."
+        );
+    }
+    assert_eq!(config, model_config());
+}
+
+#[test]
+fn set_by_path_should_fail_on_values_of_another_type() {
+    let mut config = model_config();
+
+    for (path, value) in [
+        ("layers.0.d_model", "large"),
+        ("layers.0.d_model", "-3"),
+        ("layers.0.d_model", "0.5"),
+        ("bias", "1"),
+        ("lr", "fast"),
+    ] {
+        let result = config.set_by_path(path, value);
+
+        assert!(
+            matches!(result, Err(ConfigError::InvalidValue(_))),
+            "The value `{value}` of `{path}` should be invalid."
+        );
+    }
+    assert_eq!(config, model_config());
+}
+
+#[test]
+fn with_overrides_should_apply_the_overrides_in_order() {
+    let config = model_config()
+        .with_overrides(["lr=1e-3", "name = decoder", "lr=3e-3"])
+        .unwrap();
+
+    assert_eq!(config.lr, 3e-3);
+    assert_eq!(config.name, "decoder");
+}
+
+#[test]
+fn with_overrides_should_fail_without_value() {
+    let result = model_config().with_overrides(["lr"]);
+
+    assert!(matches!(result, Err(ConfigError::InvalidFormat(_))));
+}
+
+#[test]
+fn overridden_config_should_round_trip() {
+    let config = model_config()
+        .with_overrides(["layers.1.activation=gelu", "layers.0.dropout=0.3"])
+        .unwrap();
+
+    let loaded = TestModelConfig::load_binary(config_to_json(&config).as_bytes()).unwrap();
+
+    assert_eq!(config, loaded);
+}
+
+#[cfg(feature = "config-toml")]
+#[test]
+fn struct_config_should_round_trip_in_toml() {
+    let config = model_config()
+        .with_overrides(["layers.1.activation=gelu"])
+        .unwrap();
+    let file_path = file_path("test_model_config.toml");
+
+    config.save_toml(&file_path).unwrap();
+
+    let config_loaded = TestModelConfig::load_toml(&file_path).unwrap();
+    assert_eq!(config, config_loaded);
+}
+
+#[cfg(feature = "config-toml")]
+#[test]
+fn struct_config_should_merge_a_partial_toml_file() {
+    let file_path = file_path("test_model_partial_config.toml");
+    std::fs::write(
+        &file_path,
+        "lr = 0.01\n\n[[layers]]\nd_model = 16\ndropout = 0.0\n",
+    )
+    .unwrap();
+
+    let merged = model_config().merge_toml(&file_path).unwrap();
+
+    assert_eq!(merged.lr, 0.01);
+    assert_eq!(
+        merged.layers,
+        vec![TestLayerConfig::new(16).with_dropout(0.0)]
+    );
+    assert_eq!(merged.name, "encoder");
+}
+
+#[cfg(feature = "config-toml")]
+#[test]
+fn struct_config_should_report_why_a_toml_file_cant_be_written() {
+    let directory = tempfile::tempdir().unwrap();
+
+    let result = model_config().save_toml(directory.path().join("missing").join("config.toml"));
+    assert!(matches!(result, Err(ConfigError::FileNotFound(_))));
+
+    // The path is a directory, which exists but can't be written as a file.
+    let result = model_config().save_toml(directory.path());
+    assert!(matches!(result, Err(ConfigError::Io(_))));
+}
+
+#[cfg(feature = "config-yaml")]
+#[test]
+fn struct_config_should_round_trip_in_yaml() {
+    let config = model_config()
+        .with_overrides(["layers.0.activation=relu"])
+        .unwrap();
+    let file_path = file_path("test_model_config.yaml");
+
+    config.save_yaml(&file_path).unwrap();
+
+    let config_loaded = TestModelConfig::load_yaml(&file_path).unwrap();
+    assert_eq!(config, config_loaded);
+}
+
+#[cfg(feature = "config-yaml")]
+#[test]
+fn struct_config_should_merge_a_partial_yaml_file() {
+    let file_path = file_path("test_model_partial_config.yaml");
+    std::fs::write(&file_path, "name: decoder\nbias: false\n").unwrap();
+
+    let merged = model_config().merge_yaml(&file_path).unwrap();
+
+    assert_eq!(merged.name, "decoder");
+    assert!(!merged.bias);
+    assert_eq!(merged.layers, model_config().layers);
+}
@@ -17,4 +17,14 @@ mod tests {
     pub type TestRuntime = cubecl::cuda::CudaRuntime;
 
     burn_jit::testgen_all!();
+
+    // The brain floats need a device of compute capability 8.0.
+    #[cfg(feature = "half-test")]
+    mod jit_f16 {
+        burn_jit::testgen_jit_float!(burn_jit::tests::half::f16);
+    }
+    #[cfg(feature = "half-test")]
+    mod jit_bf16 {
+        burn_jit::testgen_jit_float!(burn_jit::tests::half::bf16);
+    }
 }
@@ -54,6 +54,19 @@ pub trait Dataset<I>: Send + Sync {
     }
 }
 
+/// The dataset writer trait defines a sink appending items to a dataset as they are produced,
+/// without holding all of them in memory.
+pub trait DatasetWriter<I> {
+    /// The error returned when an item can't be written.
+    type Error;
+
+    /// Appends an item to the dataset, returning its index.
+    fn append(&mut self, item: &I) -> Result<usize, Self::Error>;
+
+    /// Writes the items that are still buffered.
+    fn flush(&mut self) -> Result<(), Self::Error>;
+}
+
 impl<D, I> Dataset<I> for Arc<D>
 where
     D: Dataset<I>,
@@ -25,3 +25,6 @@ pub use sqlite::*;
 
 #[cfg(any(feature = "sqlite", feature = "sqlite-bundled"))]
 mod sqlite;
+
+#[cfg(any(feature = "sqlite", feature = "sqlite-bundled"))]
+mod schema;
@@ -0,0 +1,419 @@
+use core::fmt::Display;
+
+use serde::de::{
+    self, value::StrDeserializer, DeserializeOwned, DeserializeSeed, EnumAccess, IntoDeserializer,
+    MapAccess, SeqAccess, VariantAccess, Visitor,
+};
+
+/// The depth after which options, sequences and maps are traced empty, so that recursive types
+/// terminate.
+const MAX_DEPTH: usize = 8;
+
+/// Computes the fingerprint of the schema of an item type.
+///
+/// The schema is traced from the structure the [Deserialize](serde::Deserialize) implementation
+/// of the type expects: struct and field names, enum variants and primitive types. Types that
+/// can't be traced, such as self-describing ones, fall back to their type name.
+pub(crate) fn schema_fingerprint<I: DeserializeOwned>() -> String {
+    let mut tracer = Tracer::default();
+
+    let schema = match I::deserialize(&mut tracer) {
+        Ok(_) => tracer.schema,
+        Err(_) => format!("type {}", core::any::type_name::<I>()),
+    };
+
+    format!("{:016x}", fnv1a(schema.as_bytes()))
+}
+
+/// The 64-bit FNV-1a hash, which is stable across platforms and compiler versions.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ *byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+#[derive(Debug)]
+struct TraceError(String);
+
+impl Display for TraceError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for TraceError {}
+
+impl de::Error for TraceError {
+    fn custom<T: Display>(msg: T) -> Self {
+        Self(msg.to_string())
+    }
+}
+
+/// A deserializer writing the structure requested by the deserialized type, and providing
+/// placeholder values.
+#[derive(Default)]
+struct Tracer {
+    schema: String,
+    depth: usize,
+}
+
+impl Tracer {
+    fn nested<T>(&mut self, trace: impl FnOnce(&mut Self) -> T) -> T {
+        self.depth += 1;
+        let output = trace(self);
+        self.depth -= 1;
+        output
+    }
+
+    fn seq<'a>(&'a mut self, len: usize, fields: Option<&'static [&'static str]>) -> Seq<'a> {
+        Seq {
+            tracer: self,
+            fields,
+            len,
+            index: 0,
+        }
+    }
+}
+
+macro_rules! trace_primitive {
+    ($de:lifetime; $($method:ident => $visit:ident($($value:expr)?)),* $(,)?) => {
+        $(
+            fn $method<V: Visitor<$de>>(self, visitor: V) -> Result<V::Value, TraceError> {
+                self.schema.push_str(stringify!($visit));
+                self.schema.push(';');
+                visitor.$visit($($value)?)
+            }
+        )*
+    };
+}
+
+impl<'de> de::Deserializer<'de> for &mut Tracer {
+    type Error = TraceError;
+
+    trace_primitive!(
+        'de;
+        deserialize_bool => visit_bool(false),
+        deserialize_i8 => visit_i8(0),
+        deserialize_i16 => visit_i16(0),
+        deserialize_i32 => visit_i32(0),
+        deserialize_i64 => visit_i64(0),
+        deserialize_i128 => visit_i128(0),
+        deserialize_u8 => visit_u8(0),
+        deserialize_u16 => visit_u16(0),
+        deserialize_u32 => visit_u32(0),
+        deserialize_u64 => visit_u64(0),
+        deserialize_u128 => visit_u128(0),
+        deserialize_f32 => visit_f32(0.0),
+        deserialize_f64 => visit_f64(0.0),
+        deserialize_char => visit_char('\0'),
+        deserialize_str => visit_str(""),
+        deserialize_string => visit_str(""),
+        deserialize_bytes => visit_bytes(&[]),
+        deserialize_byte_buf => visit_bytes(&[]),
+        deserialize_unit => visit_unit(),
+    );
+
+    fn deserialize_any<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value, TraceError> {
+        Err(TraceError("self-describing types can't be traced".into()))
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, TraceError> {
+        self.schema.push_str("option<");
+        let value = if self.depth < MAX_DEPTH {
+            self.nested(|tracer| visitor.visit_some(tracer))
+        } else {
+            visitor.visit_none()
+        };
+        self.schema.push_str(">;");
+        value
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, TraceError> {
+        self.schema.push_str(name);
+        self.schema.push(';');
+        visitor.visit_unit()
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, TraceError> {
+        self.schema.push_str(name);
+        self.schema.push('(');
+        let value = visitor.visit_newtype_struct(&mut *self);
+        self.schema.push_str(");");
+        value
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, TraceError> {
+        let len = (self.depth < MAX_DEPTH) as usize;
+        self.schema.push_str("seq<");
+        let value = self.nested(|tracer| visitor.visit_seq(tracer.seq(len, None)));
+        self.schema.push_str(">;");
+        value
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(
+        self,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, TraceError> {
+        self.schema.push_str(&format!("tuple{len}("));
+        let value = visitor.visit_seq(self.seq(len, None));
+        self.schema.push_str(");");
+        value
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, TraceError> {
+        self.schema.push_str(name);
+        de::Deserializer::deserialize_tuple(self, len, visitor)
+    }
+
+    fn deserialize_map<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, TraceError> {
+        let len = (self.depth < MAX_DEPTH) as usize;
+        self.schema.push_str("map<");
+        let value = self.nested(|tracer| {
+            visitor.visit_map(Map {
+                tracer,
+                len,
+                index: 0,
+            })
+        });
+        self.schema.push_str(">;");
+        value
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, TraceError> {
+        self.schema.push_str(name);
+        self.schema.push('{');
+        let value = visitor.visit_seq(self.seq(fields.len(), Some(fields)));
+        self.schema.push_str("};");
+        value
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, TraceError> {
+        let variant = variants
+            .first()
+            .copied()
+            .ok_or_else(|| TraceError("enums without variants can't be traced".into()))?;
+
+        // Only the payload of the first variant is traced, which is enough to build a value.
+        self.schema.push_str(name);
+        self.schema.push('[');
+        self.schema.push_str(&variants.join(","));
+        self.schema.push_str("](");
+        let value = visitor.visit_enum(Enum {
+            tracer: &mut *self,
+            variant,
+        });
+        self.schema.push_str(");");
+        value
+    }
+
+    fn deserialize_identifier<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value, TraceError> {
+        Err(TraceError("identifiers can't be traced".into()))
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, TraceError> {
+        visitor.visit_unit()
+    }
+}
+
+/// The elements of a sequence, a tuple or a struct.
+struct Seq<'a> {
+    tracer: &'a mut Tracer,
+    fields: Option<&'static [&'static str]>,
+    len: usize,
+    index: usize,
+}
+
+impl<'de> SeqAccess<'de> for Seq<'_> {
+    type Error = TraceError;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, TraceError> {
+        if self.index == self.len {
+            return Ok(None);
+        }
+
+        if let Some(field) = self.fields.and_then(|fields| fields.get(self.index)) {
+            self.tracer.schema.push_str(field);
+            self.tracer.schema.push(':');
+        }
+        self.index += 1;
+
+        seed.deserialize(&mut *self.tracer).map(Some)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.len - self.index)
+    }
+}
+
+/// The entries of a map.
+struct Map<'a> {
+    tracer: &'a mut Tracer,
+    len: usize,
+    index: usize,
+}
+
+impl<'de> MapAccess<'de> for Map<'_> {
+    type Error = TraceError;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, TraceError> {
+        if self.index == self.len {
+            return Ok(None);
+        }
+        self.index += 1;
+
+        seed.deserialize(&mut *self.tracer).map(Some)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(
+        &mut self,
+        seed: V,
+    ) -> Result<V::Value, TraceError> {
+        self.tracer.schema.push_str("=>");
+        seed.deserialize(&mut *self.tracer)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.len - self.index)
+    }
+}
+
+/// The first variant of an enum.
+struct Enum<'a> {
+    tracer: &'a mut Tracer,
+    variant: &'static str,
+}
+
+impl<'de, 'a> EnumAccess<'de> for Enum<'a> {
+    type Error = TraceError;
+    type Variant = &'a mut Tracer;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(
+        self,
+        seed: V,
+    ) -> Result<(V::Value, Self::Variant), TraceError> {
+        let variant: StrDeserializer<'_, TraceError> = self.variant.into_deserializer();
+
+        Ok((seed.deserialize(variant)?, self.tracer))
+    }
+}
+
+impl<'de> VariantAccess<'de> for &mut Tracer {
+    type Error = TraceError;
+
+    fn unit_variant(self) -> Result<(), TraceError> {
+        Ok(())
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(
+        self,
+        seed: T,
+    ) -> Result<T::Value, TraceError> {
+        seed.deserialize(self)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(
+        self,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, TraceError> {
+        de::Deserializer::deserialize_tuple(self, len, visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, TraceError> {
+        de::Deserializer::deserialize_struct(self, "", fields, visitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use serde::Deserialize;
+
+    use super::*;
+
+    #[allow(dead_code)]
+    #[derive(Deserialize)]
+    struct Item {
+        name: String,
+        values: Vec<f32>,
+    }
+
+    #[allow(dead_code)]
+    #[derive(Deserialize)]
+    struct ItemRenamedField {
+        name: String,
+        features: Vec<f32>,
+    }
+
+    #[allow(dead_code)]
+    #[derive(Deserialize)]
+    struct ItemChangedType {
+        name: String,
+        values: Vec<f64>,
+    }
+
+    #[allow(dead_code)]
+    #[derive(Deserialize)]
+    enum Label {
+        Cat,
+        Dog { breed: String },
+    }
+
+    #[allow(dead_code)]
+    #[derive(Deserialize)]
+    struct Tree {
+        label: Option<Label>,
+        children: Vec<Tree>,
+        attributes: HashMap<String, [u8; 3]>,
+    }
+
+    #[test]
+    fn fingerprint_should_be_deterministic() {
+        assert_eq!(schema_fingerprint::<Item>(), schema_fingerprint::<Item>());
+        assert_eq!(schema_fingerprint::<Tree>(), schema_fingerprint::<Tree>());
+    }
+
+    #[test]
+    fn fingerprint_should_change_with_the_schema() {
+        let fingerprint = schema_fingerprint::<Item>();
+
+        assert_ne!(fingerprint, schema_fingerprint::<ItemRenamedField>());
+        assert_ne!(fingerprint, schema_fingerprint::<ItemChangedType>());
+        assert_ne!(fingerprint, schema_fingerprint::<Tree>());
+    }
+}
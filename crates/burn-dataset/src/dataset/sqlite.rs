@@ -6,7 +6,7 @@ use std::{
     sync::{Arc, RwLock},
 };
 
-use crate::Dataset;
+use crate::{dataset::schema::schema_fingerprint, Dataset, DatasetWriter};
 
 use gix_tempfile::{
     handle::{persist, Writable},
@@ -51,6 +51,21 @@ pub enum SqliteDatasetError {
     #[error("Could not persist the temporary database file: {0}")]
     PersistDbFile(#[from] persist::Error<Writable>),
 
+    /// The split was written with an item type having a different schema than the one read.
+    #[error(
+        "The split `{split}` was written with an item schema of fingerprint {written}, but is read \
+         with an item type of fingerprint {read}. Read the split with the item type it was written \
+         with, or write the dataset again with the new item type."
+    )]
+    SchemaMismatch {
+        /// The split name.
+        split: String,
+        /// The schema fingerprint of the written items.
+        written: String,
+        /// The schema fingerprint of the item type reading the split.
+        read: String,
+    },
+
     /// Any other error.
     #[error("{0}")]
     Other(&'static str),
@@ -62,6 +77,30 @@ impl From<&'static str> for SqliteDatasetError {
     }
 }
 
+/// The table storing the schema fingerprint and the compression of each split written by a
+/// [SqliteDatasetWriter].
+const METADATA_TABLE: &str = "burn_dataset_metadata";
+
+/// The compression of the serialized items written by a [SqliteDatasetWriter].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SqliteCompression {
+    /// The items are stored as [MessagePack](https://msgpack.org/).
+    #[default]
+    None,
+    /// The MessagePack of each item is compressed with [zstd](https://facebook.github.io/zstd/)
+    /// at the given level.
+    Zstd(i32),
+}
+
+impl SqliteCompression {
+    fn name(&self) -> &'static str {
+        match self {
+            SqliteCompression::None => "none",
+            SqliteCompression::Zstd(_) => "zstd",
+        }
+    }
+}
+
 /// This struct represents a dataset where all items are stored in an SQLite database.
 /// Each instance of this struct corresponds to a specific table within the SQLite database,
 /// and allows for interaction with the data stored in the table in a structured and typed manner.
@@ -87,6 +126,10 @@ impl From<&'static str> for SqliteDatasetError {
 ///
 /// Note: The code automatically figures out which of the above two cases is applicable, and uses the appropriate
 /// method to read the data from the table.
+///
+/// Splits written by a [SqliteDatasetWriter] also have a row in the `burn_dataset_metadata` table,
+/// holding the schema fingerprint of the written item type and the compression of the items. The
+/// fingerprint is checked against the one of `I` when the dataset is opened.
 #[derive(Debug)]
 pub struct SqliteDataset<I> {
     db_file: PathBuf,
@@ -96,18 +139,46 @@ pub struct SqliteDataset<I> {
     len: usize,
     select_statement: String,
     row_serialized: bool,
+    compressed: bool,
     phantom: PhantomData<I>,
 }
 
 impl<I> SqliteDataset<I> {
     /// Initializes a `SqliteDataset` from a SQLite database file and a split name.
-    pub fn from_db_file<P: AsRef<Path>>(db_file: P, split: &str) -> Result<Self> {
+    ///
+    /// Returns [SchemaMismatch](SqliteDatasetError::SchemaMismatch) if the split was written with
+    /// an item type having a different schema.
+    pub fn from_db_file<P: AsRef<Path>>(db_file: P, split: &str) -> Result<Self>
+    where
+        I: DeserializeOwned,
+    {
         // Create a connection pool
         let conn_pool = create_conn_pool(&db_file, false)?;
 
         // Determine how the table is stored
         let row_serialized = Self::check_if_row_serialized(&conn_pool, split)?;
 
+        // Check the written schema, datasets not created by the writer have no metadata
+        let compressed = match fetch_metadata(&conn_pool, split)? {
+            Some((written, compression)) => {
+                let read = schema_fingerprint::<I>();
+                if written != read {
+                    return Err(SqliteDatasetError::SchemaMismatch {
+                        split: split.to_string(),
+                        written,
+                        read,
+                    });
+                }
+
+                match compression.as_str() {
+                    "none" => false,
+                    "zstd" => true,
+                    _ => return Err("Unknown compression of the dataset items".into()),
+                }
+            }
+            None => false,
+        };
+
         // Create a select statement and save it
         let select_statement = if row_serialized {
             format!("select item from {split} where row_id = ?")
@@ -126,6 +197,7 @@ impl<I> SqliteDataset<I> {
             len,
             select_statement,
             row_serialized,
+            compressed,
             phantom: PhantomData,
         })
     }
@@ -209,11 +281,15 @@ where
             // Fetch with a single column `item` and deserialize it with MessagePack
             statement
                 .query_row([row_id], |row| {
+                    let blob = row.get_ref(0).unwrap().as_blob().unwrap();
+
                     // Deserialize item (blob) with MessagePack (rmp-serde)
-                    Ok(
-                        rmp_serde::from_slice::<I>(row.get_ref(0).unwrap().as_blob().unwrap())
-                            .unwrap(),
-                    )
+                    if self.compressed {
+                        let decompressed = zstd::decode_all(blob).unwrap();
+                        Ok(rmp_serde::from_slice::<I>(&decompressed).unwrap())
+                    } else {
+                        Ok(rmp_serde::from_slice::<I>(blob).unwrap())
+                    }
                 })
                 .optional() //Converts Error (not found) to None
                 .unwrap()
@@ -263,6 +339,38 @@ fn fetch_columns_and_len(
     Ok((columns, len))
 }
 
+/// Fetch the schema fingerprint and the compression of the split, if it has metadata.
+fn fetch_metadata(
+    conn_pool: &Pool<SqliteConnectionManager>,
+    split: &str,
+) -> Result<Option<(String, String)>> {
+    let connection = conn_pool.get()?;
+
+    let has_metadata = connection
+        .query_row(
+            "select 1 from sqlite_master where type = 'table' and name = ?",
+            [METADATA_TABLE],
+            |_| Ok(()),
+        )
+        .optional()?
+        .is_some();
+
+    if !has_metadata {
+        return Ok(None);
+    }
+
+    let metadata = connection
+        .query_row(
+            format!("select fingerprint, compression from {METADATA_TABLE} where split = ?")
+                .as_str(),
+            [split],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
+        .optional()?;
+
+    Ok(metadata)
+}
+
 /// Helper function to create a connection pool
 fn create_conn_pool<P: AsRef<Path>>(
     db_file: P,
@@ -416,12 +524,16 @@ impl SqliteDatasetStorage {
 /// - Generation of a new dataset
 /// - Storage of preprocessed data or metadata
 /// - Enlargement of a dataset's item count post preprocessing
+///
+/// Large datasets should be written with a [split writer](SqliteDatasetWriter::split_writer),
+/// which commits the items in batches instead of one transaction per item.
 #[derive(Debug)]
 pub struct SqliteDatasetWriter<I> {
     db_file: PathBuf,
     db_file_tmp: Option<Handle<Writable>>,
     splits: Arc<RwLock<HashSet<String>>>,
     overwrite: bool,
+    compression: SqliteCompression,
     conn_pool: Option<Pool<SqliteConnectionManager>>,
     is_completed: Arc<RwLock<bool>>,
     phantom: PhantomData<I>,
@@ -447,6 +559,7 @@ where
             db_file_tmp: None,
             splits: Arc::new(RwLock::new(HashSet::new())),
             overwrite,
+            compression: SqliteCompression::None,
             conn_pool: None,
             is_completed: Arc::new(RwLock::new(false)),
             phantom: PhantomData,
@@ -503,6 +616,15 @@ where
         Ok(self)
     }
 
+    /// Sets the compression of the written items.
+    ///
+    /// The compression of a split is stored in the database when its table is created, so it
+    /// applies to the splits written from now on.
+    pub fn with_compression(mut self, compression: SqliteCompression) -> Self {
+        self.compression = compression;
+        self
+    }
+
     /// Serializes and writes an item to the database. The item is written to the table for the
     /// specified split. If the table does not exist, it is created. If the table exists, the item
     /// is appended to the table. The serialization is done using the [MessagePack](https://msgpack.org/)
@@ -531,18 +653,8 @@ where
             self.create_table(split)?;
         }
 
-        // Get a connection from the pool
-        let conn_pool = self.conn_pool.as_ref().unwrap();
-        let conn = conn_pool.get()?;
-
-        // Serialize the item using MessagePack
-        let serialized_item = rmp_serde::to_vec(item)?;
-
-        // Turn off the synchronous and journal mode for speed up
-        // We are sacrificing durability for speed but it's okay because
-        // we always recreate the dataset if it is not completed.
-        pragma_update_with_error_handling(&conn, "synchronous", "OFF")?;
-        pragma_update_with_error_handling(&conn, "journal_mode", "OFF")?;
+        let conn = self.connection()?;
+        let serialized_item = self.serialize(item)?;
 
         // Insert the serialized item into the database
         let insert_statement = format!("insert into {split} (item) values (?)", split = split);
@@ -554,6 +666,43 @@ where
         Ok(index)
     }
 
+    /// Provides a writer streaming the items of a split to the database.
+    ///
+    /// The items are committed every `batch_size` items, which is much faster than the
+    /// transaction per item of [write](SqliteDatasetWriter::write), while keeping a bounded
+    /// amount of uncommitted items. The last batch is committed when the split writer is flushed
+    /// or dropped.
+    ///
+    /// # Arguments
+    ///
+    /// * `split` - A string slice that defines the data split for writing (e.g., "train", "test").
+    /// * `batch_size` - The number of items written in each transaction.
+    ///
+    /// # Returns
+    ///
+    /// * A `Result` which is `Ok` if the split writer could be created, `Err` otherwise.
+    pub fn split_writer(&self, split: &str, batch_size: usize) -> Result<SqliteSplitWriter<'_, I>> {
+        if *self.is_completed.read().unwrap() {
+            return Err(SqliteDatasetError::Other(
+                "Cannot save to a completed dataset writer",
+            ));
+        }
+
+        if batch_size == 0 {
+            return Err(SqliteDatasetError::Other("The batch size must be non-zero"));
+        }
+
+        self.create_table(split)?;
+
+        Ok(SqliteSplitWriter {
+            writer: self,
+            conn: self.connection()?,
+            insert_statement: format!("insert into {split} (item) values (?)"),
+            batch_size,
+            num_pending: 0,
+        })
+    }
+
     /// Marks the dataset as completed and persists the temporary database file.
     pub fn set_completed(&mut self) -> Result<()> {
         let mut is_completed = self.is_completed.write().unwrap();
@@ -605,11 +754,115 @@ where
 
         connection.execute(create_table_statement.as_str(), [])?;
 
+        // Save the schema fingerprint and the compression of the split
+        connection.execute(
+            format!(
+                "create table if not exists {METADATA_TABLE} (split text primary key not null, \
+                 fingerprint text not null, compression text not null)"
+            )
+            .as_str(),
+            [],
+        )?;
+        connection.execute(
+            format!("insert or replace into {METADATA_TABLE} values (?, ?, ?)").as_str(),
+            [
+                split,
+                schema_fingerprint::<I>().as_str(),
+                self.compression.name(),
+            ],
+        )?;
+
         // Add the split to the splits
         self.splits.write().unwrap().insert(split.to_string());
 
         Ok(())
     }
+
+    /// Gets a connection from the pool, configured for fast writes.
+    fn connection(&self) -> Result<PooledConnection<SqliteConnectionManager>> {
+        let conn_pool = self.conn_pool.as_ref().unwrap();
+        let conn = conn_pool.get()?;
+
+        // Turn off the synchronous and journal mode for speed up
+        // We are sacrificing durability for speed but it's okay because
+        // we always recreate the dataset if it is not completed.
+        pragma_update_with_error_handling(&conn, "synchronous", "OFF")?;
+        pragma_update_with_error_handling(&conn, "journal_mode", "OFF")?;
+
+        Ok(conn)
+    }
+
+    /// Serializes an item using MessagePack, and compresses it if enabled.
+    fn serialize(&self, item: &I) -> Result<Vec<u8>> {
+        let serialized_item = rmp_serde::to_vec(item)?;
+
+        match self.compression {
+            SqliteCompression::None => Ok(serialized_item),
+            SqliteCompression::Zstd(level) => {
+                Ok(zstd::encode_all(serialized_item.as_slice(), level)?)
+            }
+        }
+    }
+}
+
+/// A writer streaming the items of a split to a SQLite dataset, created with
+/// [split_writer](SqliteDatasetWriter::split_writer).
+///
+/// The items are inserted in transactions of `batch_size` items. Split writers of different
+/// splits can be used concurrently, their transactions are then serialized by SQLite.
+pub struct SqliteSplitWriter<'a, I> {
+    writer: &'a SqliteDatasetWriter<I>,
+    conn: PooledConnection<SqliteConnectionManager>,
+    insert_statement: String,
+    batch_size: usize,
+    num_pending: usize,
+}
+
+impl<I> DatasetWriter<I> for SqliteSplitWriter<'_, I>
+where
+    I: Clone + Send + Sync + Serialize + DeserializeOwned,
+{
+    type Error = SqliteDatasetError;
+
+    fn append(&mut self, item: &I) -> Result<usize> {
+        let serialized_item = self.writer.serialize(item)?;
+
+        if self.num_pending == 0 {
+            self.conn.execute_batch("begin")?;
+        }
+
+        self.conn
+            .prepare_cached(self.insert_statement.as_str())?
+            .execute([serialized_item])?;
+        self.num_pending += 1;
+
+        // Get the primary key of the last inserted row and convert to index (row_id-1)
+        let index = (self.conn.last_insert_rowid() - 1) as usize;
+
+        if self.num_pending == self.batch_size {
+            self.flush()?;
+        }
+
+        Ok(index)
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        if self.num_pending > 0 {
+            self.conn.execute_batch("commit")?;
+            self.num_pending = 0;
+        }
+
+        Ok(())
+    }
+}
+
+impl<I> Drop for SqliteSplitWriter<'_, I> {
+    fn drop(&mut self) {
+        if self.num_pending > 0 {
+            // Errors can't be returned here, call `flush` to handle them
+            let _ = self.conn.execute_batch("commit");
+        }
+    }
 }
 
 /// Runs a pragma update and ignores the `ExecuteReturnedResults` error.
@@ -851,4 +1104,87 @@ mod tests {
         assert_eq!(train.len(), record_count as usize / 2);
         assert_eq!(test.len(), record_count as usize / 2);
     }
+
+    fn complex(index: usize) -> Complex {
+        Complex {
+            column_str: format!("item_{index}"),
+            column_bytes: vec![index as u8; 16],
+            column_int: index as i64,
+            column_bool: index % 2 == 0,
+            column_float: index as f64 / 2.0,
+            column_complex: vec![vec![vec![[1, index as u8, 3]]]],
+        }
+    }
+
+    #[rstest]
+    #[case::uncompressed(SqliteCompression::None)]
+    #[case::zstd(SqliteCompression::Zstd(3))]
+    pub fn sqlite_split_writer_append(
+        writer_fixture: (Writer, TempDir),
+        #[case] compression: SqliteCompression,
+    ) {
+        let (writer, _tmp_dir) = writer_fixture;
+        let mut writer = writer.with_compression(compression);
+        let num_items = 250;
+
+        {
+            let mut split_writer = writer.split_writer("train", 64).unwrap();
+            for index in 0..num_items {
+                assert_eq!(split_writer.append(&complex(index)).unwrap(), index);
+            }
+            split_writer.flush().unwrap();
+        }
+
+        writer.set_completed().unwrap();
+
+        let dataset = SqliteDataset::<Complex>::from_db_file(&writer.db_file, "train").unwrap();
+        assert_eq!(dataset.len(), num_items);
+        assert_eq!(dataset.compressed, compression != SqliteCompression::None);
+        for index in [0, 63, 64, 249] {
+            assert_eq!(dataset.get(index), Some(complex(index)));
+        }
+    }
+
+    #[rstest]
+    pub fn sqlite_split_writer_should_commit_on_drop(writer_fixture: (Writer, TempDir)) {
+        let (mut writer, _tmp_dir) = writer_fixture;
+
+        // Not a multiple of the batch size, the last batch is committed when dropped
+        let mut split_writer = writer.split_writer("train", 4).unwrap();
+        for index in 0..10 {
+            split_writer.append(&complex(index)).unwrap();
+        }
+        core::mem::drop(split_writer);
+
+        writer.set_completed().unwrap();
+
+        let dataset = SqliteDataset::<Complex>::from_db_file(&writer.db_file, "train").unwrap();
+        assert_eq!(dataset.len(), 10);
+        assert_eq!(dataset.get(9), Some(complex(9)));
+    }
+
+    #[rstest]
+    pub fn sqlite_split_writer_zero_batch_size(writer_fixture: (Writer, TempDir)) {
+        let (writer, _tmp_dir) = writer_fixture;
+
+        assert!(writer.split_writer("train", 0).is_err());
+    }
+
+    #[rstest]
+    pub fn sqlite_dataset_schema_mismatch(writer_fixture: (Writer, TempDir)) {
+        let (mut writer, _tmp_dir) = writer_fixture;
+        writer.write("train", &complex(0)).unwrap();
+        writer.set_completed().unwrap();
+
+        let result = SqliteDataset::<Sample>::from_db_file(&writer.db_file, "train");
+
+        match result {
+            Err(error @ SqliteDatasetError::SchemaMismatch { .. }) => {
+                let message = error.to_string();
+                assert!(message.contains(&schema_fingerprint::<Complex>()));
+                assert!(message.contains(&schema_fingerprint::<Sample>()));
+            }
+            other => panic!("Expected a schema mismatch, got {other:?}"),
+        }
+    }
 }
@@ -1,6 +1,7 @@
 use std::fs::{self, create_dir_all};
+use std::io::{BufRead, BufReader};
 use std::path::{Path, PathBuf};
-use std::process::Command;
+use std::process::{Command, Stdio};
 
 use crate::{SqliteDataset, SqliteDatasetError, SqliteDatasetStorage};
 
@@ -13,6 +14,8 @@ const PYTHON_SOURCE: &str = include_str!("importer.py");
 const VENV_BIN_PYTHON: &str = "bin/python3";
 #[cfg(target_os = "windows")]
 const VENV_BIN_PYTHON: &str = "Scripts\\python";
+/// Prefix of the lines printed by the python script to report its progress.
+const PROGRESS_PREFIX: &str = "burn-dataset-progress:";
 
 /// Error type for [HuggingfaceDatasetLoader](HuggingfaceDatasetLoader).
 #[derive(Error, Debug)]
@@ -36,6 +39,83 @@ pub enum ImporterError {
     /// venv environment is not initialized.
     #[error("venv environment is not initialized")]
     VenvNotInitialized,
+
+    /// The dataset isn't in the cache and can't be downloaded in offline mode.
+    #[error("dataset not found in the cache `{0}`, which is required in offline mode")]
+    OfflineCacheMissing(PathBuf),
+
+    /// The import script failed.
+    #[error("fail to import the dataset: `{0}`")]
+    ImportFailed(String),
+}
+
+/// The parameters of a dataset import, given to a [HuggingfaceImporter](HuggingfaceImporter).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ImportRequest {
+    /// The name of the dataset.
+    pub name: String,
+    /// The subset of the dataset.
+    pub subset: Option<String>,
+    /// The revision of the dataset, which is a branch name, a tag or a commit hash.
+    pub revision: Option<String>,
+    /// The huggingface token used to download datasets behind authentication.
+    pub token: Option<String>,
+    /// The huggingface cache directory.
+    pub cache_dir: Option<String>,
+    /// Whether or not to trust remote code.
+    pub trust_remote_code: bool,
+    /// The sqlite database file to create.
+    pub db_file: PathBuf,
+    /// The base directory of the burn datasets.
+    pub base_dir: PathBuf,
+}
+
+/// The progress of a dataset import.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ImportProgress {
+    /// The python dependencies are being installed.
+    InstallingDependencies,
+    /// The dataset is being downloaded.
+    Downloading,
+    /// A split of the dataset is being exported to the sqlite database.
+    ExportingSplit {
+        /// The name of the split.
+        split: String,
+        /// The position of the split, starting at zero.
+        index: usize,
+        /// The number of splits.
+        num_splits: usize,
+    },
+    /// The dataset is imported.
+    Done,
+}
+
+/// Download a huggingface dataset and import it in a sqlite database.
+pub trait HuggingfaceImporter {
+    /// Import the dataset described by the request, reporting the progress to the callback.
+    fn import(
+        &self,
+        request: &ImportRequest,
+        progress: &dyn Fn(ImportProgress),
+    ) -> Result<(), ImporterError>;
+}
+
+/// The default [importer](HuggingfaceImporter), which runs the python `datasets` library in a
+/// virtual environment.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct PythonImporter;
+
+impl HuggingfaceImporter for PythonImporter {
+    fn import(
+        &self,
+        request: &ImportRequest,
+        progress: &dyn Fn(ImportProgress),
+    ) -> Result<(), ImporterError> {
+        progress(ImportProgress::InstallingDependencies);
+        let venv_python_path = install_python_deps(&request.base_dir)?;
+
+        import(venv_python_path, request, progress)
+    }
 }
 
 /// Load a dataset from [huggingface datasets](https://huggingface.co/datasets).
@@ -60,10 +140,14 @@ pub enum ImporterError {
 pub struct HuggingfaceDatasetLoader {
     name: String,
     subset: Option<String>,
+    revision: Option<String>,
     base_dir: Option<PathBuf>,
     huggingface_token: Option<String>,
     huggingface_cache_dir: Option<String>,
     trust_remote_code: bool,
+    offline: bool,
+    progress: Option<Box<dyn Fn(ImportProgress)>>,
+    importer: Box<dyn HuggingfaceImporter>,
 }
 
 impl HuggingfaceDatasetLoader {
@@ -72,10 +156,14 @@ impl HuggingfaceDatasetLoader {
         Self {
             name: name.to_string(),
             subset: None,
+            revision: None,
             base_dir: None,
             huggingface_token: None,
             huggingface_cache_dir: None,
             trust_remote_code: false,
+            offline: false,
+            progress: None,
+            importer: Box::new(PythonImporter),
         }
     }
 
@@ -89,6 +177,14 @@ impl HuggingfaceDatasetLoader {
         self
     }
 
+    /// Pin the revision of the dataset, which is a branch name, a tag or a commit hash.
+    ///
+    /// Each revision is stored in its own database file, so different revisions can coexist.
+    pub fn with_revision(mut self, revision: &str) -> Self {
+        self.revision = Some(revision.to_string());
+        self
+    }
+
     /// Specify a base directory to store the dataset.
     ///
     /// If not specified, the dataset will be stored in `~/.cache/burn-dataset`.
@@ -121,6 +217,29 @@ impl HuggingfaceDatasetLoader {
         self
     }
 
+    /// Specify whether or not to work offline.
+    ///
+    /// In offline mode, the dataset is only loaded from the database file, and an error is
+    /// returned if it doesn't exist instead of downloading it.
+    pub fn with_offline(mut self, offline: bool) -> Self {
+        self.offline = offline;
+        self
+    }
+
+    /// Specify a callback receiving the progress of the download and import of the dataset.
+    pub fn with_progress<F: Fn(ImportProgress) + 'static>(mut self, progress: F) -> Self {
+        self.progress = Some(Box::new(progress));
+        self
+    }
+
+    /// Specify the importer used to download the dataset.
+    ///
+    /// If not specified, the [python importer](PythonImporter) is used.
+    pub fn with_importer<I: HuggingfaceImporter + 'static>(mut self, importer: I) -> Self {
+        self.importer = Box::new(importer);
+        self
+    }
+
     /// Load the dataset.
     pub fn dataset<I: DeserializeOwned + Clone>(
         self,
@@ -135,37 +254,50 @@ impl HuggingfaceDatasetLoader {
     ///
     /// If the database file does not exist, it will be downloaded and imported.
     pub fn db_file(self) -> Result<PathBuf, ImporterError> {
-        // determine (and create if needed) the base directory
+        // determine the base directory
         let base_dir = SqliteDatasetStorage::base_dir(self.base_dir);
 
+        //sanitize the name, subset and revision
+        let mut db_file_name = sanitize(self.name.as_str());
+
+        if let Some(subset) = self.subset.as_ref() {
+            db_file_name = format!("{}-{}", db_file_name, sanitize(subset.as_str()));
+        }
+
+        if let Some(revision) = self.revision.as_ref() {
+            db_file_name = format!("{}@{}", db_file_name, sanitize(revision.as_str()));
+        }
+
+        let db_file = base_dir.join(format!("{}.db", db_file_name));
+
+        if Path::new(&db_file).exists() {
+            return Ok(db_file);
+        }
+
+        if self.offline {
+            return Err(ImporterError::OfflineCacheMissing(db_file));
+        }
+
         if !base_dir.exists() {
             create_dir_all(&base_dir).expect("Failed to create base directory");
         }
 
-        //sanitize the name and subset
-        let name = sanitize(self.name.as_str());
-
-        // create the db file path
-        let db_file_name = if let Some(subset) = self.subset.clone() {
-            format!("{}-{}.db", name, sanitize(subset.as_str()))
-        } else {
-            format!("{}.db", name)
+        // import the dataset
+        let request = ImportRequest {
+            name: self.name,
+            subset: self.subset,
+            revision: self.revision,
+            token: self.huggingface_token,
+            cache_dir: self.huggingface_cache_dir,
+            trust_remote_code: self.trust_remote_code,
+            db_file: db_file.clone(),
+            base_dir,
         };
+        let progress = self
+            .progress
+            .unwrap_or_else(|| Box::new(|_: ImportProgress| {}));
 
-        let db_file = base_dir.join(db_file_name);
-
-        // import the dataset if needed
-        if !Path::new(&db_file).exists() {
-            import(
-                self.name,
-                self.subset,
-                db_file.clone(),
-                base_dir,
-                self.huggingface_token,
-                self.huggingface_cache_dir,
-                self.trust_remote_code,
-            )?;
-        }
+        self.importer.import(&request, progress.as_ref())?;
 
         Ok(db_file)
     }
@@ -173,52 +305,93 @@ impl HuggingfaceDatasetLoader {
 
 /// Import a dataset from huggingface. The transformed dataset is stored as sqlite database.
 fn import(
-    name: String,
-    subset: Option<String>,
-    base_file: PathBuf,
-    base_dir: PathBuf,
-    huggingface_token: Option<String>,
-    huggingface_cache_dir: Option<String>,
-    trust_remote_code: bool,
+    venv_python_path: PathBuf,
+    request: &ImportRequest,
+    progress: &dyn Fn(ImportProgress),
 ) -> Result<(), ImporterError> {
-    let venv_python_path = install_python_deps(&base_dir)?;
-
     let mut command = Command::new(venv_python_path);
 
-    command.arg(importer_script_path(&base_dir));
+    command.arg(importer_script_path(&request.base_dir));
 
     command.arg("--name");
-    command.arg(name);
+    command.arg(&request.name);
 
     command.arg("--file");
-    command.arg(base_file);
+    command.arg(&request.db_file);
 
-    if let Some(subset) = subset {
+    if let Some(subset) = &request.subset {
         command.arg("--subset");
         command.arg(subset);
     }
 
-    if let Some(huggingface_token) = huggingface_token {
+    if let Some(revision) = &request.revision {
+        command.arg("--revision");
+        command.arg(revision);
+    }
+
+    if let Some(huggingface_token) = &request.token {
         command.arg("--token");
         command.arg(huggingface_token);
     }
 
-    if let Some(huggingface_cache_dir) = huggingface_cache_dir {
+    if let Some(huggingface_cache_dir) = &request.cache_dir {
         command.arg("--cache_dir");
         command.arg(huggingface_cache_dir);
     }
-    if trust_remote_code {
+    if request.trust_remote_code {
         command.arg("--trust_remote_code");
         command.arg("True");
     }
-    let mut handle = command.spawn().unwrap();
-    handle
+
+    // Read the output of the script to report its progress, forwarding the other lines.
+    command.env("PYTHONUNBUFFERED", "1");
+    let mut handle = command.stdout(Stdio::piped()).spawn().unwrap();
+    let stdout = handle.stdout.take().expect("Stdout should be piped");
+
+    for line in BufReader::new(stdout).lines() {
+        let line = line.map_err(|err| ImporterError::Unknown(format!("{err:?}")))?;
+
+        match line.strip_prefix(PROGRESS_PREFIX) {
+            Some(event) => {
+                if let Some(event) = parse_progress(event.trim()) {
+                    progress(event);
+                }
+            }
+            None => println!("{line}"),
+        }
+    }
+
+    let status = handle
         .wait()
         .map_err(|err| ImporterError::Unknown(format!("{err:?}")))?;
 
+    if !status.success() {
+        return Err(ImporterError::ImportFailed(format!(
+            "the python script exited with {status}"
+        )));
+    }
+
+    progress(ImportProgress::Done);
+
     Ok(())
 }
 
+/// Parse a progress event printed by the python script, as `downloading` or
+/// `exporting <index> <num_splits> <split>`.
+fn parse_progress(event: &str) -> Option<ImportProgress> {
+    let mut parts = event.splitn(4, ' ');
+
+    match parts.next()? {
+        "downloading" => Some(ImportProgress::Downloading),
+        "exporting" => Some(ImportProgress::ExportingSplit {
+            index: parts.next()?.parse().ok()?,
+            num_splits: parts.next()?.parse().ok()?,
+            split: parts.next()?.to_string(),
+        }),
+        _ => None,
+    }
+}
+
 /// check python --version output is `Python 3.x.x`
 fn check_python_version_is_3(python: &str) -> bool {
     let output = Command::new(python).arg("--version").output();
@@ -307,3 +480,167 @@ fn install_python_deps(base_dir: &Path) -> Result<PathBuf, ImporterError> {
 
     Ok(venv_python_path)
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use tempfile::{tempdir, TempDir};
+
+    use super::*;
+
+    /// An importer creating an empty database file and recording the requests.
+    #[derive(Clone, Default)]
+    struct MockImporter {
+        requests: Arc<Mutex<Vec<ImportRequest>>>,
+    }
+
+    impl HuggingfaceImporter for MockImporter {
+        fn import(
+            &self,
+            request: &ImportRequest,
+            progress: &dyn Fn(ImportProgress),
+        ) -> Result<(), ImporterError> {
+            progress(ImportProgress::Downloading);
+            fs::write(&request.db_file, []).unwrap();
+            progress(ImportProgress::Done);
+
+            self.requests.lock().unwrap().push(request.clone());
+            Ok(())
+        }
+    }
+
+    impl MockImporter {
+        fn requests(&self) -> Vec<ImportRequest> {
+            self.requests.lock().unwrap().clone()
+        }
+    }
+
+    fn loader(base_dir: &TempDir, importer: &MockImporter) -> HuggingfaceDatasetLoader {
+        HuggingfaceDatasetLoader::new("user/dataset")
+            .with_base_dir(base_dir.path().to_str().unwrap())
+            .with_importer(importer.clone())
+    }
+
+    #[test]
+    fn should_pass_the_request_parameters_to_the_importer() {
+        let base_dir = tempdir().unwrap();
+        let importer = MockImporter::default();
+
+        let db_file = loader(&base_dir, &importer)
+            .with_subset("subset")
+            .with_revision("a1b2c3")
+            .with_huggingface_token("token")
+            .with_huggingface_cache_dir("cache")
+            .with_trust_remote_code(true)
+            .db_file()
+            .unwrap();
+
+        assert_eq!(
+            db_file,
+            base_dir.path().join("userdataset-subset@a1b2c3.db")
+        );
+        assert_eq!(
+            importer.requests(),
+            vec![ImportRequest {
+                name: "user/dataset".to_string(),
+                subset: Some("subset".to_string()),
+                revision: Some("a1b2c3".to_string()),
+                token: Some("token".to_string()),
+                cache_dir: Some("cache".to_string()),
+                trust_remote_code: true,
+                db_file,
+                base_dir: base_dir.path().to_path_buf(),
+            }]
+        );
+    }
+
+    #[test]
+    fn should_reuse_the_database_file_of_the_same_revision() {
+        let base_dir = tempdir().unwrap();
+        let importer = MockImporter::default();
+
+        let db_file_1 = loader(&base_dir, &importer)
+            .with_revision("v1")
+            .db_file()
+            .unwrap();
+        let db_file_2 = loader(&base_dir, &importer)
+            .with_revision("v2")
+            .db_file()
+            .unwrap();
+        let db_file_1_cached = loader(&base_dir, &importer)
+            .with_revision("v1")
+            .db_file()
+            .unwrap();
+
+        assert_ne!(db_file_1, db_file_2);
+        assert_eq!(db_file_1, db_file_1_cached);
+        assert_eq!(importer.requests().len(), 2);
+    }
+
+    #[test]
+    fn should_fail_without_importing_when_offline_and_not_cached() {
+        let base_dir = tempdir().unwrap();
+        let importer = MockImporter::default();
+
+        let result = loader(&base_dir, &importer).with_offline(true).db_file();
+
+        assert!(matches!(
+            result,
+            Err(ImporterError::OfflineCacheMissing(db_file))
+                if db_file == base_dir.path().join("userdataset.db")
+        ));
+        assert!(importer.requests().is_empty());
+    }
+
+    #[test]
+    fn should_use_the_cache_when_offline() {
+        let base_dir = tempdir().unwrap();
+        let importer = MockImporter::default();
+
+        let db_file = loader(&base_dir, &importer).db_file().unwrap();
+        let db_file_offline = loader(&base_dir, &importer)
+            .with_offline(true)
+            .db_file()
+            .unwrap();
+
+        assert_eq!(db_file, db_file_offline);
+        assert_eq!(importer.requests().len(), 1);
+    }
+
+    #[test]
+    fn should_report_the_progress_of_the_importer() {
+        let base_dir = tempdir().unwrap();
+        let importer = MockImporter::default();
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let events_progress = events.clone();
+
+        loader(&base_dir, &importer)
+            .with_progress(move |event| events_progress.lock().unwrap().push(event))
+            .db_file()
+            .unwrap();
+
+        assert_eq!(
+            *events.lock().unwrap(),
+            vec![ImportProgress::Downloading, ImportProgress::Done]
+        );
+    }
+
+    #[test]
+    fn should_parse_the_progress_of_the_python_script() {
+        assert_eq!(
+            parse_progress("downloading"),
+            Some(ImportProgress::Downloading)
+        );
+        assert_eq!(
+            parse_progress("exporting 1 3 validation"),
+            Some(ImportProgress::ExportingSplit {
+                split: "validation".to_string(),
+                index: 1,
+                num_splits: 3,
+            })
+        );
+        assert_eq!(parse_progress("exporting one"), None);
+        assert_eq!(parse_progress("unknown"), None);
+    }
+}
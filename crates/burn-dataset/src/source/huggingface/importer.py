@@ -9,6 +9,7 @@ from sqlalchemy.types import LargeBinary
 def download_and_export(
     name: str,
     subset: str,
+    revision: str,
     db_file: str,
     token: str,
     cache_dir: str,
@@ -26,6 +27,7 @@ def download_and_export(
     print("Starting huggingface dataset download and export")
     print(f"Dataset Name: {name}")
     print(f"Subset Name: {subset}")
+    print(f"Revision: {revision}")
     print(f"Sqlite database file: {db_file}")
     print(f"Trust remote code: {trust_remote_code}")
     if cache_dir is None:
@@ -33,11 +35,13 @@ def download_and_export(
     print("*" * 80)
 
     # Load the dataset
+    print_progress("downloading")
     dataset_all = load_dataset(
         name,
         subset,
+        revision=revision,
         cache_dir=cache_dir,
-        use_auth_token=token,
+        token=token,
         trust_remote_code=trust_remote_code,
     )
 
@@ -53,7 +57,9 @@ def download_and_export(
     event.listen(Table, "before_create", add_pk_column)
 
     # Export each split in the dataset
-    for key in dataset_all.keys():
+    splits = list(dataset_all.keys())
+    for index, key in enumerate(splits):
+        print_progress(f"exporting {index} {len(splits)} {key}")
         dataset = dataset_all[key]
 
         # Disable decoding for audio and image fields
@@ -81,6 +87,13 @@ def download_and_export(
     print_table_info(engine)
 
 
+def print_progress(event):
+    """
+    Print a progress event, parsed by the rust code to report the progress of the import.
+    """
+    print(f"burn-dataset-progress: {event}", flush=True)
+
+
 def disable_decoding(dataset):
     """
     Disable decoding for audio and image fields. The fields will be saved as raw file bytes.
@@ -163,6 +176,13 @@ def parse_args():
     parser.add_argument(
         "--subset", type=str, help="Subset name", required=False, default=None
     )
+    parser.add_argument(
+        "--revision",
+        type=str,
+        help="Revision of the dataset: a branch name, a tag or a commit hash",
+        required=False,
+        default=None,
+    )
     parser.add_argument(
         "--token",
         type=str,
@@ -190,6 +210,7 @@ def run():
     download_and_export(
         args.name,
         args.subset,
+        args.revision,
         args.file,
         args.token,
         args.cache_dir,
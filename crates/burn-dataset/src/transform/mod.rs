@@ -3,9 +3,11 @@ mod mapper;
 mod partial;
 mod random;
 mod sampler;
+mod windowed;
 
 pub use composed::*;
 pub use mapper::*;
 pub use partial::*;
 pub use random::*;
 pub use sampler::*;
+pub use windowed::*;
@@ -0,0 +1,471 @@
+use crate::{Dataset, InMemDataset};
+use std::{marker::PhantomData, ops::Range, sync::Arc};
+
+/// The options of a [windowed dataset](WindowedDataset).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WindowOptions {
+    /// The number of past values of each item.
+    pub window: usize,
+    /// The number of future values of each item, following the past ones.
+    pub horizon: usize,
+    /// The number of time steps between the starts of consecutive windows.
+    pub stride: usize,
+    /// Whether the items include the statistics of their past values.
+    pub statistics: bool,
+}
+
+impl WindowOptions {
+    /// Windows of `window` past values followed by `horizon` future values, starting at every
+    /// time step.
+    ///
+    /// # Panics
+    ///
+    /// If the window or the horizon is 0.
+    pub fn new(window: usize, horizon: usize) -> Self {
+        assert!(window > 0, "The window should have at least one value.");
+        assert!(horizon > 0, "The horizon should have at least one value.");
+
+        Self {
+            window,
+            horizon,
+            stride: 1,
+            statistics: false,
+        }
+    }
+
+    /// Start the windows every `stride` time steps.
+    ///
+    /// # Panics
+    ///
+    /// If the stride is 0.
+    pub fn with_stride(mut self, stride: usize) -> Self {
+        assert!(stride > 0, "The stride should be at least one time step.");
+        self.stride = stride;
+        self
+    }
+
+    /// Include the [statistics](WindowStatistics) of the past values in the items.
+    pub fn with_statistics(mut self, statistics: bool) -> Self {
+        self.statistics = statistics;
+        self
+    }
+
+    /// The number of time steps of a window and its horizon.
+    pub fn size(&self) -> usize {
+        self.window + self.horizon
+    }
+
+    /// The number of windows fitting in `len` time steps.
+    pub fn num_windows(&self, len: usize) -> usize {
+        match len.checked_sub(self.size()) {
+            Some(remaining) => remaining / self.stride + 1,
+            None => 0,
+        }
+    }
+}
+
+/// The statistics of the past values of a window, to normalize the window with.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WindowStatistics {
+    /// The mean of the past values.
+    pub mean: f64,
+    /// The standard deviation of the past values, without the Bessel's correction.
+    pub std: f64,
+}
+
+/// An item of a [windowed dataset](WindowedDataset).
+#[derive(Clone, Debug, PartialEq)]
+pub struct WindowItem<I> {
+    /// The index of the sequence of the window.
+    pub sequence: usize,
+    /// The time step of the first past value in its sequence.
+    pub start: usize,
+    /// The past values, the inputs of a forecast.
+    pub past: Vec<I>,
+    /// The future values, the targets of a forecast.
+    pub future: Vec<I>,
+    /// The statistics of the past values, when enabled in the [options](WindowOptions).
+    pub statistics: Option<WindowStatistics>,
+}
+
+/// Slice time series into windows of past values with the future values following them, to
+/// train forecasting models.
+///
+/// The items of the dataset are the time steps of one long sequence, or of sequences stored one
+/// after the other. The windows never cross the end of a sequence, and the sequences shorter
+/// than a window and its horizon are skipped with a warning.
+pub struct WindowedDataset<D, I> {
+    dataset: D,
+    options: WindowOptions,
+    /// The time steps of each sequence in the dataset.
+    sequences: Vec<Range<usize>>,
+    segments: Vec<Segment>,
+    len: usize,
+    input: PhantomData<I>,
+}
+
+/// The windows of a span of a sequence.
+struct Segment {
+    sequence: usize,
+    /// The time step of the first window in the dataset.
+    start: usize,
+    /// The index of the first window.
+    first_window: usize,
+}
+
+impl<D, I> WindowedDataset<D, I>
+where
+    D: Dataset<I>,
+{
+    /// Create the windows of one long sequence, the items of the dataset being its time steps.
+    pub fn new(dataset: D, options: WindowOptions) -> Self {
+        let len = dataset.len();
+
+        Self::with_sequences(dataset, &[len], options)
+    }
+
+    /// Create the windows of sequences of the given lengths, stored one after the other in the
+    /// dataset.
+    ///
+    /// # Panics
+    ///
+    /// If the lengths don't sum to the length of the dataset.
+    pub fn with_sequences(dataset: D, lengths: &[usize], options: WindowOptions) -> Self {
+        assert_eq!(
+            lengths.iter().sum::<usize>(),
+            dataset.len(),
+            "The lengths of the sequences should sum to the length of the dataset."
+        );
+
+        let mut sequences = Vec::with_capacity(lengths.len());
+        let mut start = 0;
+
+        for length in lengths {
+            sequences.push(start..start + length);
+            start += length;
+        }
+
+        let spans = sequences.clone().into_iter().enumerate().collect();
+
+        Self::from_spans(dataset, options, sequences, spans)
+    }
+
+    /// Split the time steps of each sequence into a training and a validation dataset, the last
+    /// `valid_ratio` of the time steps being used for validation.
+    ///
+    /// The validation windows start at least `gap` time steps after the end of the training
+    /// windows and their horizons, so that no value is shared between the two datasets.
+    ///
+    /// # Panics
+    ///
+    /// If the ratio isn't between 0 and 1.
+    pub fn split(
+        self,
+        valid_ratio: f64,
+        gap: usize,
+    ) -> (WindowedDataset<Arc<D>, I>, WindowedDataset<Arc<D>, I>) {
+        assert!(
+            (0.0..=1.0).contains(&valid_ratio),
+            "The validation ratio should be between 0 and 1, got {valid_ratio}."
+        );
+
+        let dataset = Arc::new(self.dataset); // cheap cloning.
+        let mut train = Vec::with_capacity(self.sequences.len());
+        let mut valid = Vec::with_capacity(self.sequences.len());
+
+        for (sequence, span) in self.sequences.iter().enumerate() {
+            let len = span.len();
+            let len_train = (len as f64 * (1.0 - valid_ratio)).round() as usize;
+            let start_valid = usize::min(len_train + gap, len);
+
+            train.push((sequence, span.start..span.start + len_train));
+            valid.push((sequence, span.start + start_valid..span.end));
+        }
+
+        (
+            WindowedDataset::from_spans(
+                dataset.clone(),
+                self.options.clone(),
+                self.sequences.clone(),
+                train,
+            ),
+            WindowedDataset::from_spans(dataset, self.options, self.sequences, valid),
+        )
+    }
+
+    /// The options of the windows.
+    pub fn options(&self) -> &WindowOptions {
+        &self.options
+    }
+
+    fn from_spans(
+        dataset: D,
+        options: WindowOptions,
+        sequences: Vec<Range<usize>>,
+        spans: Vec<(usize, Range<usize>)>,
+    ) -> Self {
+        let mut segments = Vec::with_capacity(spans.len());
+        let mut len = 0;
+
+        for (sequence, span) in spans {
+            let num_windows = options.num_windows(span.len());
+
+            if num_windows == 0 {
+                log::warn!(
+                    "Skipping the {} time steps of the sequence {sequence}, fewer than the {} of \
+                     a window and its horizon.",
+                    span.len(),
+                    options.size(),
+                );
+                continue;
+            }
+
+            segments.push(Segment {
+                sequence,
+                start: span.start,
+                first_window: len,
+            });
+            len += num_windows;
+        }
+
+        Self {
+            dataset,
+            options,
+            sequences,
+            segments,
+            len,
+            input: PhantomData,
+        }
+    }
+}
+
+impl<I> WindowedDataset<InMemDataset<I>, I>
+where
+    I: Clone + Send + Sync,
+{
+    /// Create the windows of each sequence of the dataset, the sequences being loaded in memory.
+    pub fn from_sequences<S>(sequences: &S, options: WindowOptions) -> Self
+    where
+        S: Dataset<Vec<I>>,
+    {
+        let mut lengths = Vec::with_capacity(sequences.len());
+        let items = sequences
+            .iter()
+            .flat_map(|sequence| {
+                lengths.push(sequence.len());
+                sequence
+            })
+            .collect();
+
+        Self::with_sequences(InMemDataset::new(items), &lengths, options)
+    }
+}
+
+impl<D, I> Dataset<WindowItem<I>> for WindowedDataset<D, I>
+where
+    D: Dataset<I>,
+    I: Clone + Into<f64> + Send + Sync,
+{
+    fn get(&self, index: usize) -> Option<WindowItem<I>> {
+        if index >= self.len {
+            return None;
+        }
+
+        let segment =
+            &self.segments[self.segments.partition_point(|s| s.first_window <= index) - 1];
+        let start = segment.start + (index - segment.first_window) * self.options.stride;
+
+        let mut past = (start..start + self.options.size())
+            .map(|step| self.dataset.get(step))
+            .collect::<Option<Vec<_>>>()?;
+        let future = past.split_off(self.options.window);
+        let statistics = match self.options.statistics {
+            true => Some(statistics(&past)),
+            false => None,
+        };
+
+        Some(WindowItem {
+            sequence: segment.sequence,
+            start: start - self.sequences[segment.sequence].start,
+            past,
+            future,
+            statistics,
+        })
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+fn statistics<I: Clone + Into<f64>>(values: &[I]) -> WindowStatistics {
+    let values = values.iter().map(|value| value.clone().into());
+    let num_values = values.len() as f64;
+    let mean = values.clone().sum::<f64>() / num_values;
+    let var = values.map(|value| (value - mean).powi(2)).sum::<f64>() / num_values;
+
+    WindowStatistics {
+        mean,
+        std: var.sqrt(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn series(len: i32) -> InMemDataset<i32> {
+        InMemDataset::new((0..len).collect())
+    }
+
+    fn starts<D: Dataset<WindowItem<i32>>>(dataset: &D) -> Vec<(usize, usize)> {
+        dataset
+            .iter()
+            .map(|item| (item.sequence, item.start))
+            .collect()
+    }
+
+    #[rstest]
+    #[case(3, 2, 1, vec![0, 1, 2, 3, 4, 5])]
+    #[case(3, 2, 2, vec![0, 2, 4])]
+    #[case(3, 2, 3, vec![0, 3])]
+    #[case(2, 3, 5, vec![0, 5])]
+    #[case(4, 3, 4, vec![0])]
+    #[case(6, 4, 1, vec![0])]
+    #[case(6, 5, 1, vec![])]
+    fn should_enumerate_the_windows(
+        #[case] window: usize,
+        #[case] horizon: usize,
+        #[case] stride: usize,
+        #[case] expected: Vec<usize>,
+    ) {
+        let options = WindowOptions::new(window, horizon).with_stride(stride);
+        let dataset = WindowedDataset::new(series(10), options);
+
+        assert_eq!(dataset.len(), expected.len());
+        assert!(dataset.get(expected.len()).is_none());
+
+        for (item, start) in dataset.iter().zip(expected) {
+            let start = start as i32;
+            let end = start + window as i32;
+
+            assert_eq!(item.sequence, 0);
+            assert_eq!(item.start, start as usize);
+            assert_eq!(item.past, (start..end).collect::<Vec<_>>());
+            assert_eq!(item.future, (end..end + horizon as i32).collect::<Vec<_>>());
+            assert_eq!(item.statistics, None);
+        }
+    }
+
+    #[test]
+    fn should_enumerate_the_windows_of_each_sequence() {
+        let sequences = InMemDataset::new(vec![
+            (0..10).collect::<Vec<i32>>(),
+            vec![10, 11],
+            (12..18).collect(),
+        ]);
+        let options = WindowOptions::new(2, 2).with_stride(2);
+
+        let dataset = WindowedDataset::from_sequences(&sequences, options);
+
+        // The second sequence is shorter than a window and its horizon.
+        assert_eq!(
+            starts(&dataset),
+            vec![(0, 0), (0, 2), (0, 4), (0, 6), (2, 0), (2, 2)]
+        );
+        let item = dataset.get(5).unwrap();
+        assert_eq!(item.past, vec![14, 15]);
+        assert_eq!(item.future, vec![16, 17]);
+    }
+
+    #[rstest]
+    #[case(1, 0.5, 0, vec![0, 1, 2], vec![5, 6, 7])]
+    #[case(1, 0.5, 1, vec![0, 1, 2], vec![6, 7])]
+    #[case(1, 0.3, 0, vec![0, 1, 2, 3, 4], vec![7])]
+    #[case(1, 0.3, 1, vec![0, 1, 2, 3, 4], vec![])]
+    #[case(2, 0.5, 1, vec![0, 2], vec![6])]
+    #[case(2, 0.2, 3, vec![0, 2, 4], vec![])]
+    #[case(3, 0.4, 0, vec![0, 3], vec![6])]
+    #[case(1, 0.0, 0, vec![0, 1, 2, 3, 4, 5, 6, 7], vec![])]
+    #[case(1, 1.0, 2, vec![], vec![2, 3, 4, 5, 6, 7])]
+    fn should_split_the_windows_in_time(
+        #[case] stride: usize,
+        #[case] valid_ratio: f64,
+        #[case] gap: usize,
+        #[case] expected_train: Vec<usize>,
+        #[case] expected_valid: Vec<usize>,
+    ) {
+        let options = WindowOptions::new(2, 1).with_stride(stride);
+
+        let (train, valid) = WindowedDataset::new(series(10), options).split(valid_ratio, gap);
+
+        let train_starts = starts(&train);
+        let valid_starts = starts(&valid);
+        assert_eq!(
+            train_starts,
+            expected_train
+                .into_iter()
+                .map(|s| (0, s))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            valid_starts,
+            expected_valid
+                .into_iter()
+                .map(|s| (0, s))
+                .collect::<Vec<_>>()
+        );
+
+        // The validation windows start after the gap following the last training target.
+        if let (Some(train), Some(valid)) = (train.iter().last(), valid.get(0)) {
+            assert!(*train.future.last().unwrap() + (gap as i32) < valid.past[0]);
+        }
+    }
+
+    #[test]
+    fn should_split_each_sequence_in_time() {
+        let dataset = WindowedDataset::with_sequences(
+            InMemDataset::new((0..20).collect()),
+            &[10, 4, 6],
+            WindowOptions::new(2, 1),
+        );
+
+        let (train, valid) = dataset.split(0.5, 1);
+
+        // The halves of the second sequence are too short, and so is the validation half of
+        // the third one after the gap.
+        assert_eq!(starts(&train), vec![(0, 0), (0, 1), (0, 2), (2, 0)]);
+        assert_eq!(starts(&valid), vec![(0, 6), (0, 7)]);
+    }
+
+    #[test]
+    fn should_include_the_statistics_of_the_past_values() {
+        let options = WindowOptions::new(4, 1)
+            .with_stride(2)
+            .with_statistics(true);
+        let dataset = WindowedDataset::new(
+            InMemDataset::new(vec![1.0f32, 2.0, 3.0, 4.0, 6.0, 8.0]),
+            options,
+        );
+
+        let items = dataset.iter().collect::<Vec<_>>();
+
+        assert_eq!(items.len(), 1);
+        let statistics = items[0].statistics.unwrap();
+        assert_eq!(statistics.mean, 2.5);
+        assert!((statistics.std - 1.25f64.sqrt()).abs() < 1e-12);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_the_stride_is_zero() {
+        WindowOptions::new(2, 1).with_stride(0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_the_lengths_dont_cover_the_dataset() {
+        WindowedDataset::with_sequences(series(10), &[4, 4], WindowOptions::new(2, 1));
+    }
+}
@@ -0,0 +1,91 @@
+#![cfg(any(feature = "sqlite", feature = "sqlite-bundled"))]
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use burn_dataset::{Dataset, DatasetWriter, SqliteCompression, SqliteDataset, SqliteDatasetWriter};
+use serde::{Deserialize, Serialize};
+
+/// Tracks the bytes allocated by the test, and the peak since the last reset.
+struct CountingAllocator;
+
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            let allocated = ALLOCATED.fetch_add(layout.size(), Ordering::Relaxed) + layout.size();
+            PEAK.fetch_max(allocated, Ordering::Relaxed);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        ALLOCATED.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+struct Item {
+    index: u64,
+    payload: Vec<u8>,
+}
+
+impl Item {
+    fn new(index: usize) -> Self {
+        Self {
+            index: index as u64,
+            payload: vec![index as u8; 128],
+        }
+    }
+}
+
+#[test]
+fn split_writer_should_write_with_bounded_memory() {
+    const NUM_ITEMS: usize = 100_000;
+    // Keeping the items in memory would take more than 12 MiB.
+    const MAX_MEMORY: usize = 4 * 1024 * 1024;
+
+    let tmp_dir = tempfile::tempdir().unwrap();
+    let db_file = tmp_dir.path().join("items.db");
+
+    for compression in [SqliteCompression::None, SqliteCompression::Zstd(1)] {
+        let mut writer = SqliteDatasetWriter::<Item>::new(&db_file, true)
+            .unwrap()
+            .with_compression(compression);
+
+        {
+            let mut split_writer = writer.split_writer("train", 1_000).unwrap();
+
+            let baseline = ALLOCATED.load(Ordering::Relaxed);
+            PEAK.store(baseline, Ordering::Relaxed);
+
+            for index in 0..NUM_ITEMS {
+                split_writer.append(&Item::new(index)).unwrap();
+            }
+            split_writer.flush().unwrap();
+
+            let peak = PEAK.load(Ordering::Relaxed) - baseline;
+            assert!(
+                peak < MAX_MEMORY,
+                "Writing {NUM_ITEMS} items allocated up to {peak} bytes"
+            );
+        }
+
+        writer.set_completed().unwrap();
+
+        let dataset = SqliteDataset::<Item>::from_db_file(&db_file, "train").unwrap();
+        assert_eq!(dataset.len(), NUM_ITEMS);
+        for index in [0, 999, 1_000, 54_321, NUM_ITEMS - 1] {
+            assert_eq!(dataset.get(index), Some(Item::new(index)));
+        }
+    }
+}
@@ -0,0 +1,124 @@
+use core::marker::PhantomData;
+
+use burn_tensor::backend::{Backend, DeviceId, DeviceOps, DeviceProperties, RngState, SyncType};
+
+use crate::{FallbackBridge, FallbackTensor};
+
+/// The device of the [fallback backend](Fallback), made of the device of each backend.
+#[derive(new, Clone, Default, PartialEq, Debug)]
+pub struct FallbackDevice<DP, DS> {
+    /// The device of the primary backend.
+    pub primary: DP,
+    /// The device of the secondary backend, where the unsupported operations are executed.
+    pub secondary: DS,
+}
+
+impl<DP: DeviceOps, DS: DeviceOps> DeviceOps for FallbackDevice<DP, DS> {
+    fn id(&self) -> DeviceId {
+        self.primary.id()
+    }
+
+    /// Return the devices of the primary backend, each paired with the default device of the
+    /// secondary backend.
+    fn enumerate() -> Vec<Self> {
+        DP::enumerate()
+            .into_iter()
+            .map(|primary| Self::new(primary, DS::default()))
+            .collect()
+    }
+
+    fn properties(&self) -> DeviceProperties {
+        self.primary.properties()
+    }
+}
+
+/// Backend decorator executing the operations on the primary backend `P`, and the operations
+/// that it doesn't support on the secondary backend `S`.
+///
+/// The operations are identified by their name, so the support of an operation is found out
+/// once and applies to all its tensor ranks and arguments.
+pub struct Fallback<P, S> {
+    _p: PhantomData<P>,
+    _s: PhantomData<S>,
+}
+
+impl<P, S> Clone for Fallback<P, S> {
+    fn clone(&self) -> Self {
+        Self::default()
+    }
+}
+
+impl<P, S> Default for Fallback<P, S> {
+    fn default() -> Self {
+        Self {
+            _p: PhantomData,
+            _s: PhantomData,
+        }
+    }
+}
+
+impl<P: Backend, S: Backend> core::fmt::Debug for Fallback<P, S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(&Self::name())
+    }
+}
+
+impl<P: Backend, S: Backend> Backend for Fallback<P, S> {
+    type Device = FallbackDevice<P::Device, S::Device>;
+    type FullPrecisionBridge = FallbackBridge<P::FullPrecisionBridge>;
+
+    type FloatTensorPrimitive<const D: usize> = FallbackTensor<
+        P::FloatTensorPrimitive<D>,
+        S::FloatTensorPrimitive<D>,
+        P::Device,
+        S::Device,
+    >;
+    type FloatElem = P::FloatElem;
+
+    type IntTensorPrimitive<const D: usize> =
+        FallbackTensor<P::IntTensorPrimitive<D>, S::IntTensorPrimitive<D>, P::Device, S::Device>;
+    type IntElem = P::IntElem;
+
+    type BoolTensorPrimitive<const D: usize> =
+        FallbackTensor<P::BoolTensorPrimitive<D>, S::BoolTensorPrimitive<D>, P::Device, S::Device>;
+
+    type QuantizedTensorPrimitive<const D: usize> = FallbackTensor<
+        P::QuantizedTensorPrimitive<D>,
+        S::QuantizedTensorPrimitive<D>,
+        P::Device,
+        S::Device,
+    >;
+
+    fn ad_enabled() -> bool {
+        false
+    }
+
+    fn name() -> String {
+        format!("fallback<{}, {}>", P::name(), S::name())
+    }
+
+    fn seed(seed: u64) {
+        P::seed(seed);
+        S::seed(seed);
+    }
+
+    fn seed_device(device: &Self::Device, seed: u64) {
+        P::seed_device(&device.primary, seed);
+        S::seed_device(&device.secondary, seed);
+    }
+
+    /// Returns the state of the primary backend, where the random tensors are created unless
+    /// the primary backend doesn't support it.
+    fn rng_state() -> Option<RngState> {
+        P::rng_state()
+    }
+
+    fn restore_rng_state(state: RngState) {
+        P::restore_rng_state(state);
+    }
+
+    fn sync(device: &Self::Device, sync_type: SyncType) {
+        P::sync(&device.primary, sync_type);
+        S::sync(&device.secondary, sync_type);
+    }
+}
@@ -0,0 +1,43 @@
+use core::marker::PhantomData;
+
+use burn_tensor::backend::{Backend, BackendBridge};
+use burn_tensor::ops::FloatTensor;
+use burn_tensor::Device;
+
+use crate::{Fallback, FallbackDevice, FallbackTensor};
+
+/// Bridge of the [fallback backend](Fallback) to the full precision backend of its primary
+/// backend, using the bridge `BP` of the primary backend.
+///
+/// The full precision operations are executed by the primary backend only.
+#[derive(Debug)]
+pub struct FallbackBridge<BP> {
+    _bp: PhantomData<BP>,
+}
+
+impl<P, S, BP> BackendBridge<Fallback<P, S>> for FallbackBridge<BP>
+where
+    P: Backend,
+    S: Backend,
+    BP: BackendBridge<P>,
+{
+    type Target = BP::Target;
+
+    fn into_target<const D: usize>(
+        tensor: FloatTensor<Fallback<P, S>, D>,
+        device: Option<Device<Self::Target>>,
+    ) -> FloatTensor<Self::Target, D> {
+        BP::into_target(Fallback::<P, S>::float_primary(tensor), device)
+    }
+
+    fn from_target<const D: usize>(
+        tensor: FloatTensor<Self::Target, D>,
+        device: Option<Device<Fallback<P, S>>>,
+    ) -> FloatTensor<Fallback<P, S>, D> {
+        let tensor = BP::from_target(tensor, device.as_ref().map(|d| d.primary.clone()));
+        let device = device
+            .unwrap_or_else(|| FallbackDevice::new(P::float_device(&tensor), S::Device::default()));
+
+        FallbackTensor::primary(tensor, device)
+    }
+}
@@ -0,0 +1,44 @@
+#![warn(missing_docs)]
+
+//! # Burn Fallback
+//!
+//! This library is a part of the Burn project. It provides a backend decorator executing each
+//! operation on a primary backend, and the operations that the primary backend doesn't support
+//! on a secondary backend, transferring the tensors between the backends.
+//!
+//! An operation is unsupported when the primary backend panics while executing it with a message
+//! saying so, such as the ones of `todo!`, `unimplemented!` or "not supported", as the backends
+//! do for the operations they don't implement. The first execution of each operation is
+//! attempted on the primary backend: if it succeeds, the primary backend is trusted with all the
+//! following executions, and if it is unsupported, the operation is always executed on the
+//! secondary backend. Other panics only execute the failing call on the secondary backend, the
+//! next one being attempted on the primary backend again. The panics are still reported by the
+//! panic hook, and the fallbacks are logged and counted in the [report](Fallback::report).
+//!
+//! Since the panics are caught, the fallback isn't possible when the program aborts on panic.
+
+#[macro_use]
+extern crate derive_new;
+
+mod backend;
+mod bridge;
+mod ops;
+mod report;
+mod tensor;
+
+pub use backend::*;
+pub use bridge::*;
+pub use report::*;
+pub use tensor::*;
+
+#[cfg(test)]
+mod tests {
+    type TestBackend = crate::Fallback<burn_ndarray::NdArray<f32>, burn_ndarray::NdArray<f32>>;
+    type TestTensor<const D: usize> = burn_tensor::Tensor<TestBackend, D>;
+    type TestTensorInt<const D: usize> = burn_tensor::Tensor<TestBackend, D, burn_tensor::Int>;
+    type TestTensorBool<const D: usize> = burn_tensor::Tensor<TestBackend, D, burn_tensor::Bool>;
+
+    burn_tensor::testgen_all!();
+
+    mod fallback;
+}
@@ -0,0 +1,84 @@
+use burn_tensor::backend::Backend;
+use burn_tensor::ops::{ActivationOps, FloatElem, FloatTensor};
+
+use crate::Fallback;
+
+impl<P: Backend, S: Backend> ActivationOps<Self> for Fallback<P, S> {
+    fn leaky_relu<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        negative_slope: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        fallback!(
+            leaky_relu(tensor: float, negative_slope: elem) -> tensor,
+            device = tensor.device.clone()
+        )
+    }
+
+    fn relu<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        fallback!(relu(tensor: float) -> tensor, device = tensor.device.clone())
+    }
+
+    fn relu_backward<const D: usize>(
+        output: FloatTensor<Self, D>,
+        grad: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        fallback!(
+            relu_backward(output: float, grad: float) -> tensor,
+            device = output.device.clone()
+        )
+    }
+
+    fn gelu<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        fallback!(gelu(tensor: float) -> tensor, device = tensor.device.clone())
+    }
+
+    fn prelu<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        alpha: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        fallback!(prelu(tensor: float, alpha: float) -> tensor, device = tensor.device.clone())
+    }
+
+    fn gelu_backward<const D: usize>(
+        x: FloatTensor<Self, D>,
+        grad: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        fallback!(gelu_backward(x: float, grad: float) -> tensor, device = x.device.clone())
+    }
+
+    fn sigmoid<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        fallback!(sigmoid(tensor: float) -> tensor, device = tensor.device.clone())
+    }
+
+    fn sigmoid_backward<const D: usize>(
+        output: FloatTensor<Self, D>,
+        grad: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        fallback!(
+            sigmoid_backward(output: float, grad: float) -> tensor,
+            device = output.device.clone()
+        )
+    }
+
+    fn hard_sigmoid<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        alpha: FloatElem<Self>,
+        beta: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        fallback!(
+            hard_sigmoid(tensor: float, alpha: elem, beta: elem) -> tensor,
+            device = tensor.device.clone()
+        )
+    }
+
+    fn log_sigmoid<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        fallback!(log_sigmoid(tensor: float) -> tensor, device = tensor.device.clone())
+    }
+
+    fn log_sigmoid_backward<const D: usize>(
+        x: FloatTensor<Self, D>,
+        grad: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        fallback!(log_sigmoid_backward(x: float, grad: float) -> tensor, device = x.device.clone())
+    }
+}
@@ -0,0 +1,225 @@
+use core::future::Future;
+use core::ops::Range;
+
+use burn_tensor::backend::Backend;
+use burn_tensor::ops::{BoolTensor, BoolTensorOps, Device, FloatTensor, IntTensor};
+use burn_tensor::{Shape, TensorData};
+
+use crate::{Fallback, FallbackPrimitive, FallbackTensor};
+
+impl<P: Backend, S: Backend> BoolTensorOps<Self> for Fallback<P, S> {
+    fn bool_shape<const D: usize>(tensor: &BoolTensor<Self, D>) -> Shape<D> {
+        match &tensor.primitive {
+            FallbackPrimitive::Primary(tensor) => P::bool_shape(tensor),
+            FallbackPrimitive::Secondary(tensor) => S::bool_shape(tensor),
+        }
+    }
+
+    fn bool_device<const D: usize>(tensor: &BoolTensor<Self, D>) -> Device<Self> {
+        tensor.device.clone()
+    }
+
+    fn bool_to_device<const D: usize>(
+        tensor: BoolTensor<Self, D>,
+        device: &Device<Self>,
+    ) -> BoolTensor<Self, D> {
+        let primitive = match tensor.primitive {
+            FallbackPrimitive::Primary(tensor) => {
+                FallbackPrimitive::Primary(P::bool_to_device(tensor, &device.primary))
+            }
+            FallbackPrimitive::Secondary(tensor) => {
+                FallbackPrimitive::Secondary(S::bool_to_device(tensor, &device.secondary))
+            }
+        };
+
+        FallbackTensor::new(primitive, device.clone())
+    }
+
+    fn bool_into_data<const D: usize>(
+        tensor: BoolTensor<Self, D>,
+    ) -> impl Future<Output = TensorData> + Send {
+        async move {
+            match tensor.primitive {
+                FallbackPrimitive::Primary(tensor) => P::bool_into_data(tensor).await,
+                FallbackPrimitive::Secondary(tensor) => S::bool_into_data(tensor).await,
+            }
+        }
+    }
+
+    fn bool_empty<const D: usize>(shape: Shape<D>, device: &Device<Self>) -> BoolTensor<Self, D> {
+        fallback!(bool_empty(shape: value, device: device) -> tensor, device = device.clone())
+    }
+
+    fn bool_from_data<const D: usize>(
+        data: TensorData,
+        device: &Device<Self>,
+    ) -> BoolTensor<Self, D> {
+        fallback!(bool_from_data(data: value, device: device) -> tensor, device = device.clone())
+    }
+
+    fn bool_into_int<const D: usize>(tensor: BoolTensor<Self, D>) -> IntTensor<Self, D> {
+        fallback!(bool_into_int(tensor: bool) -> tensor, device = tensor.device.clone())
+    }
+
+    fn bool_into_float<const D: usize>(tensor: BoolTensor<Self, D>) -> FloatTensor<Self, D> {
+        fallback!(bool_into_float(tensor: bool) -> tensor, device = tensor.device.clone())
+    }
+
+    fn bool_reshape<const D1: usize, const D2: usize>(
+        tensor: BoolTensor<Self, D1>,
+        shape: Shape<D2>,
+    ) -> BoolTensor<Self, D2> {
+        fallback!(
+            bool_reshape(tensor: bool, shape: value) -> tensor,
+            device = tensor.device.clone()
+        )
+    }
+
+    fn bool_slice<const D1: usize, const D2: usize>(
+        tensor: BoolTensor<Self, D1>,
+        ranges: [Range<usize>; D2],
+    ) -> BoolTensor<Self, D1> {
+        fallback!(bool_slice(tensor: bool, ranges: value) -> tensor, device = tensor.device.clone())
+    }
+
+    fn bool_slice_assign<const D1: usize, const D2: usize>(
+        tensor: BoolTensor<Self, D1>,
+        ranges: [Range<usize>; D2],
+        value: BoolTensor<Self, D1>,
+    ) -> BoolTensor<Self, D1> {
+        fallback!(
+            bool_slice_assign(tensor: bool, ranges: value, value: bool) -> tensor,
+            device = tensor.device.clone()
+        )
+    }
+
+    fn bool_repeat_dim<const D: usize>(
+        tensor: BoolTensor<Self, D>,
+        dim: usize,
+        times: usize,
+    ) -> BoolTensor<Self, D> {
+        fallback!(
+            bool_repeat_dim(tensor: bool, dim: value, times: value) -> tensor,
+            device = tensor.device.clone()
+        )
+    }
+
+    fn bool_cat<const D: usize>(
+        tensors: Vec<BoolTensor<Self, D>>,
+        dim: usize,
+    ) -> BoolTensor<Self, D> {
+        fallback!(
+            bool_cat(tensors: bools, dim: value) -> tensor,
+            device = tensors[0].device.clone()
+        )
+    }
+
+    fn bool_equal<const D: usize>(
+        lhs: BoolTensor<Self, D>,
+        rhs: BoolTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        fallback!(bool_equal(lhs: bool, rhs: bool) -> tensor, device = lhs.device.clone())
+    }
+
+    fn bool_not_equal<const D: usize>(
+        lhs: BoolTensor<Self, D>,
+        rhs: BoolTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        fallback!(bool_not_equal(lhs: bool, rhs: bool) -> tensor, device = lhs.device.clone())
+    }
+
+    fn bool_not<const D: usize>(tensor: BoolTensor<Self, D>) -> BoolTensor<Self, D> {
+        fallback!(bool_not(tensor: bool) -> tensor, device = tensor.device.clone())
+    }
+
+    fn bool_and<const D: usize>(
+        lhs: BoolTensor<Self, D>,
+        rhs: BoolTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        fallback!(bool_and(lhs: bool, rhs: bool) -> tensor, device = lhs.device.clone())
+    }
+
+    fn bool_or<const D: usize>(
+        lhs: BoolTensor<Self, D>,
+        rhs: BoolTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        fallback!(bool_or(lhs: bool, rhs: bool) -> tensor, device = lhs.device.clone())
+    }
+
+    fn bool_xor<const D: usize>(
+        lhs: BoolTensor<Self, D>,
+        rhs: BoolTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        fallback!(bool_xor(lhs: bool, rhs: bool) -> tensor, device = lhs.device.clone())
+    }
+
+    fn bool_transpose<const D: usize>(tensor: BoolTensor<Self, D>) -> BoolTensor<Self, D> {
+        fallback!(bool_transpose(tensor: bool) -> tensor, device = tensor.device.clone())
+    }
+
+    fn bool_swap_dims<const D: usize>(
+        tensor: BoolTensor<Self, D>,
+        dim1: usize,
+        dim2: usize,
+    ) -> BoolTensor<Self, D> {
+        fallback!(
+            bool_swap_dims(tensor: bool, dim1: value, dim2: value) -> tensor,
+            device = tensor.device.clone()
+        )
+    }
+
+    fn bool_permute<const D: usize>(
+        tensor: BoolTensor<Self, D>,
+        axes: [usize; D],
+    ) -> BoolTensor<Self, D> {
+        fallback!(bool_permute(tensor: bool, axes: value) -> tensor, device = tensor.device.clone())
+    }
+
+    fn bool_flip<const D: usize>(
+        tensor: BoolTensor<Self, D>,
+        axes: &[usize],
+    ) -> BoolTensor<Self, D> {
+        fallback!(bool_flip(tensor: bool, axes: value) -> tensor, device = tensor.device.clone())
+    }
+
+    fn bool_narrow<const D: usize>(
+        tensor: BoolTensor<Self, D>,
+        dim: usize,
+        start: usize,
+        length: usize,
+    ) -> BoolTensor<Self, D> {
+        fallback!(
+            bool_narrow(tensor: bool, dim: value, start: value, length: value) -> tensor,
+            device = tensor.device.clone()
+        )
+    }
+
+    fn bool_any<const D: usize>(tensor: BoolTensor<Self, D>) -> BoolTensor<Self, 1> {
+        fallback!(bool_any(tensor: bool) -> tensor, device = tensor.device.clone())
+    }
+
+    fn bool_any_dim<const D: usize>(
+        tensor: BoolTensor<Self, D>,
+        dim: usize,
+    ) -> BoolTensor<Self, D> {
+        fallback!(bool_any_dim(tensor: bool, dim: value) -> tensor, device = tensor.device.clone())
+    }
+
+    fn bool_all<const D: usize>(tensor: BoolTensor<Self, D>) -> BoolTensor<Self, 1> {
+        fallback!(bool_all(tensor: bool) -> tensor, device = tensor.device.clone())
+    }
+
+    fn bool_all_dim<const D: usize>(
+        tensor: BoolTensor<Self, D>,
+        dim: usize,
+    ) -> BoolTensor<Self, D> {
+        fallback!(bool_all_dim(tensor: bool, dim: value) -> tensor, device = tensor.device.clone())
+    }
+
+    fn bool_expand<const D1: usize, const D2: usize>(
+        tensor: BoolTensor<Self, D1>,
+        shape: Shape<D2>,
+    ) -> BoolTensor<Self, D2> {
+        fallback!(bool_expand(tensor: bool, shape: value) -> tensor, device = tensor.device.clone())
+    }
+}
@@ -0,0 +1,873 @@
+use core::future::Future;
+use core::ops::Range;
+
+use burn_tensor::backend::Backend;
+use burn_tensor::ops::{
+    BoolTensor, Device, FloatElem, FloatTensor, FloatTensorOps, IntElem, IntTensor,
+};
+use burn_tensor::{Distribution, PadMode, Shape, TensorData};
+
+use crate::{Fallback, FallbackPrimitive, FallbackTensor};
+
+impl<P: Backend, S: Backend> FloatTensorOps<Self> for Fallback<P, S> {
+    fn float_shape<const D: usize>(tensor: &FloatTensor<Self, D>) -> Shape<D> {
+        match &tensor.primitive {
+            FallbackPrimitive::Primary(tensor) => P::float_shape(tensor),
+            FallbackPrimitive::Secondary(tensor) => S::float_shape(tensor),
+        }
+    }
+
+    fn float_device<const D: usize>(tensor: &FloatTensor<Self, D>) -> Device<Self> {
+        tensor.device.clone()
+    }
+
+    fn float_to_device<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        device: &Device<Self>,
+    ) -> FloatTensor<Self, D> {
+        let primitive = match tensor.primitive {
+            FallbackPrimitive::Primary(tensor) => {
+                FallbackPrimitive::Primary(P::float_to_device(tensor, &device.primary))
+            }
+            FallbackPrimitive::Secondary(tensor) => {
+                FallbackPrimitive::Secondary(S::float_to_device(tensor, &device.secondary))
+            }
+        };
+
+        FallbackTensor::new(primitive, device.clone())
+    }
+
+    fn float_into_data<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+    ) -> impl Future<Output = TensorData> + Send {
+        async move {
+            match tensor.primitive {
+                FallbackPrimitive::Primary(tensor) => P::float_into_data(tensor).await,
+                FallbackPrimitive::Secondary(tensor) => {
+                    S::float_into_data(tensor).await.convert::<P::FloatElem>()
+                }
+            }
+        }
+    }
+
+    fn float_from_data<const D: usize>(
+        data: TensorData,
+        device: &Device<Self>,
+    ) -> FloatTensor<Self, D> {
+        fallback!(float_from_data(data: value, device: device) -> tensor, device = device.clone())
+    }
+
+    fn float_random<const D: usize>(
+        shape: Shape<D>,
+        distribution: Distribution,
+        device: &Device<Self>,
+    ) -> FloatTensor<Self, D> {
+        fallback!(
+            float_random(shape: value, distribution: value, device: device) -> tensor,
+            device = device.clone()
+        )
+    }
+
+    fn float_zeros<const D: usize>(shape: Shape<D>, device: &Device<Self>) -> FloatTensor<Self, D> {
+        fallback!(float_zeros(shape: value, device: device) -> tensor, device = device.clone())
+    }
+
+    fn float_ones<const D: usize>(shape: Shape<D>, device: &Device<Self>) -> FloatTensor<Self, D> {
+        fallback!(float_ones(shape: value, device: device) -> tensor, device = device.clone())
+    }
+
+    fn float_full<const D: usize>(
+        shape: Shape<D>,
+        fill_value: FloatElem<Self>,
+        device: &Device<Self>,
+    ) -> FloatTensor<Self, D> {
+        fallback!(
+            float_full(shape: value, fill_value: elem, device: device) -> tensor,
+            device = device.clone()
+        )
+    }
+
+    fn float_into_int<const D: usize>(tensor: FloatTensor<Self, D>) -> IntTensor<Self, D> {
+        fallback!(float_into_int(tensor: float) -> tensor, device = tensor.device.clone())
+    }
+
+    fn float_empty<const D: usize>(shape: Shape<D>, device: &Device<Self>) -> FloatTensor<Self, D> {
+        fallback!(float_empty(shape: value, device: device) -> tensor, device = device.clone())
+    }
+
+    fn float_repeat_dim<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+        times: usize,
+    ) -> FloatTensor<Self, D> {
+        fallback!(
+            float_repeat_dim(tensor: float, dim: value, times: value) -> tensor,
+            device = tensor.device.clone()
+        )
+    }
+
+    fn float_add<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        fallback!(float_add(lhs: float, rhs: float) -> tensor, device = lhs.device.clone())
+    }
+
+    fn float_add_scalar<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        fallback!(float_add_scalar(lhs: float, rhs: elem) -> tensor, device = lhs.device.clone())
+    }
+
+    fn float_clamp_min<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        min: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        fallback!(
+            float_clamp_min(tensor: float, min: elem) -> tensor,
+            device = tensor.device.clone()
+        )
+    }
+
+    fn float_clamp_max<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        max: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        fallback!(
+            float_clamp_max(tensor: float, max: elem) -> tensor,
+            device = tensor.device.clone()
+        )
+    }
+
+    fn float_clamp<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        min: FloatElem<Self>,
+        max: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        fallback!(
+            float_clamp(tensor: float, min: elem, max: elem) -> tensor,
+            device = tensor.device.clone()
+        )
+    }
+
+    fn float_sub<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        fallback!(float_sub(lhs: float, rhs: float) -> tensor, device = lhs.device.clone())
+    }
+
+    fn float_sub_scalar<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        fallback!(float_sub_scalar(lhs: float, rhs: elem) -> tensor, device = lhs.device.clone())
+    }
+
+    fn float_mul<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        fallback!(float_mul(lhs: float, rhs: float) -> tensor, device = lhs.device.clone())
+    }
+
+    fn float_mul_scalar<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        fallback!(float_mul_scalar(lhs: float, rhs: elem) -> tensor, device = lhs.device.clone())
+    }
+
+    fn float_div<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        fallback!(float_div(lhs: float, rhs: float) -> tensor, device = lhs.device.clone())
+    }
+
+    fn float_div_scalar<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        fallback!(float_div_scalar(lhs: float, rhs: elem) -> tensor, device = lhs.device.clone())
+    }
+
+    fn float_remainder_scalar<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        fallback!(
+            float_remainder_scalar(lhs: float, rhs: elem) -> tensor,
+            device = lhs.device.clone()
+        )
+    }
+
+    fn float_matmul<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        fallback!(float_matmul(lhs: float, rhs: float) -> tensor, device = lhs.device.clone())
+    }
+
+    fn float_matmul_accumulate<const D: usize>(
+        out: FloatTensor<Self, D>,
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        fallback!(
+            float_matmul_accumulate(out: float, lhs: float, rhs: float) -> tensor,
+            device = out.device.clone()
+        )
+    }
+
+    fn float_spmm(
+        rows: IntTensor<Self, 1>,
+        cols: IntTensor<Self, 1>,
+        values: FloatTensor<Self, 1>,
+        dense: FloatTensor<Self, 2>,
+        num_rows: usize,
+    ) -> FloatTensor<Self, 2> {
+        fallback!(
+            float_spmm(rows: int, cols: int, values: float, dense: float, num_rows: value)
+                -> tensor,
+            device = dense.device.clone()
+        )
+    }
+
+    fn float_neg<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        fallback!(float_neg(tensor: float) -> tensor, device = tensor.device.clone())
+    }
+
+    fn float_recip<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        fallback!(float_recip(tensor: float) -> tensor, device = tensor.device.clone())
+    }
+
+    fn float_transpose<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        fallback!(float_transpose(tensor: float) -> tensor, device = tensor.device.clone())
+    }
+
+    fn float_swap_dims<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim1: usize,
+        dim2: usize,
+    ) -> FloatTensor<Self, D> {
+        fallback!(
+            float_swap_dims(tensor: float, dim1: value, dim2: value) -> tensor,
+            device = tensor.device.clone()
+        )
+    }
+
+    fn float_permute<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        axes: [usize; D],
+    ) -> FloatTensor<Self, D> {
+        fallback!(
+            float_permute(tensor: float, axes: value) -> tensor,
+            device = tensor.device.clone()
+        )
+    }
+
+    fn float_flip<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        axes: &[usize],
+    ) -> FloatTensor<Self, D> {
+        fallback!(float_flip(tensor: float, axes: value) -> tensor, device = tensor.device.clone())
+    }
+
+    fn float_reshape<const D1: usize, const D2: usize>(
+        tensor: FloatTensor<Self, D1>,
+        shape: Shape<D2>,
+    ) -> FloatTensor<Self, D2> {
+        fallback!(
+            float_reshape(tensor: float, shape: value) -> tensor,
+            device = tensor.device.clone()
+        )
+    }
+
+    fn float_gather<const D: usize>(
+        dim: usize,
+        tensor: FloatTensor<Self, D>,
+        indices: IntTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        fallback!(
+            float_gather(dim: value, tensor: float, indices: int) -> tensor,
+            device = tensor.device.clone()
+        )
+    }
+
+    fn float_scatter<const D: usize>(
+        dim: usize,
+        tensor: FloatTensor<Self, D>,
+        indices: IntTensor<Self, D>,
+        value: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        fallback!(
+            float_scatter(dim: value, tensor: float, indices: int, value: float) -> tensor,
+            device = tensor.device.clone()
+        )
+    }
+
+    fn float_select<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+        indices: IntTensor<Self, 1>,
+    ) -> FloatTensor<Self, D> {
+        fallback!(
+            float_select(tensor: float, dim: value, indices: int) -> tensor,
+            device = tensor.device.clone()
+        )
+    }
+
+    fn float_select_assign<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+        indices: IntTensor<Self, 1>,
+        value: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        fallback!(
+            float_select_assign(tensor: float, dim: value, indices: int, value: float) -> tensor,
+            device = tensor.device.clone()
+        )
+    }
+
+    fn float_index_put<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+        indices: IntTensor<Self, 1>,
+        values: FloatTensor<Self, D>,
+        accumulate: bool,
+    ) -> FloatTensor<Self, D> {
+        fallback!(
+            float_index_put(
+                tensor: float,
+                dim: value,
+                indices: int,
+                values: float,
+                accumulate: value,
+            ) -> tensor,
+            device = tensor.device.clone()
+        )
+    }
+
+    fn float_slice<const D1: usize, const D2: usize>(
+        tensor: FloatTensor<Self, D1>,
+        ranges: [Range<usize>; D2],
+    ) -> FloatTensor<Self, D1> {
+        fallback!(
+            float_slice(tensor: float, ranges: value) -> tensor,
+            device = tensor.device.clone()
+        )
+    }
+
+    fn float_slice_assign<const D1: usize, const D2: usize>(
+        tensor: FloatTensor<Self, D1>,
+        ranges: [Range<usize>; D2],
+        value: FloatTensor<Self, D1>,
+    ) -> FloatTensor<Self, D1> {
+        fallback!(
+            float_slice_assign(tensor: float, ranges: value, value: float) -> tensor,
+            device = tensor.device.clone()
+        )
+    }
+
+    fn float_mask_where<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        mask: BoolTensor<Self, D>,
+        value: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        fallback!(
+            float_mask_where(tensor: float, mask: bool, value: float) -> tensor,
+            device = tensor.device.clone()
+        )
+    }
+
+    fn float_mask_fill<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        mask: BoolTensor<Self, D>,
+        value: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        fallback!(
+            float_mask_fill(tensor: float, mask: bool, value: elem) -> tensor,
+            device = tensor.device.clone()
+        )
+    }
+
+    fn float_equal<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        fallback!(float_equal(lhs: float, rhs: float) -> tensor, device = lhs.device.clone())
+    }
+
+    fn float_not_equal<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        fallback!(float_not_equal(lhs: float, rhs: float) -> tensor, device = lhs.device.clone())
+    }
+
+    fn float_equal_elem<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<Self>,
+    ) -> BoolTensor<Self, D> {
+        fallback!(float_equal_elem(lhs: float, rhs: elem) -> tensor, device = lhs.device.clone())
+    }
+
+    fn float_not_equal_elem<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<Self>,
+    ) -> BoolTensor<Self, D> {
+        fallback!(
+            float_not_equal_elem(lhs: float, rhs: elem) -> tensor,
+            device = lhs.device.clone()
+        )
+    }
+
+    fn float_greater<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        fallback!(float_greater(lhs: float, rhs: float) -> tensor, device = lhs.device.clone())
+    }
+
+    fn float_greater_elem<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<Self>,
+    ) -> BoolTensor<Self, D> {
+        fallback!(float_greater_elem(lhs: float, rhs: elem) -> tensor, device = lhs.device.clone())
+    }
+
+    fn float_greater_equal<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        fallback!(
+            float_greater_equal(lhs: float, rhs: float) -> tensor,
+            device = lhs.device.clone()
+        )
+    }
+
+    fn float_greater_equal_elem<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<Self>,
+    ) -> BoolTensor<Self, D> {
+        fallback!(
+            float_greater_equal_elem(lhs: float, rhs: elem) -> tensor,
+            device = lhs.device.clone()
+        )
+    }
+
+    fn float_lower<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        fallback!(float_lower(lhs: float, rhs: float) -> tensor, device = lhs.device.clone())
+    }
+
+    fn float_lower_elem<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<Self>,
+    ) -> BoolTensor<Self, D> {
+        fallback!(float_lower_elem(lhs: float, rhs: elem) -> tensor, device = lhs.device.clone())
+    }
+
+    fn float_lower_equal<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        fallback!(float_lower_equal(lhs: float, rhs: float) -> tensor, device = lhs.device.clone())
+    }
+
+    fn float_lower_equal_elem<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatElem<Self>,
+    ) -> BoolTensor<Self, D> {
+        fallback!(
+            float_lower_equal_elem(lhs: float, rhs: elem) -> tensor,
+            device = lhs.device.clone()
+        )
+    }
+
+    fn float_sum<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, 1> {
+        fallback!(float_sum(tensor: float) -> tensor, device = tensor.device.clone())
+    }
+
+    fn float_sum_dim<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        fallback!(
+            float_sum_dim(tensor: float, dim: value) -> tensor,
+            device = tensor.device.clone()
+        )
+    }
+
+    fn float_prod<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, 1> {
+        fallback!(float_prod(tensor: float) -> tensor, device = tensor.device.clone())
+    }
+
+    fn float_prod_dim<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        fallback!(
+            float_prod_dim(tensor: float, dim: value) -> tensor,
+            device = tensor.device.clone()
+        )
+    }
+
+    fn float_mean<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, 1> {
+        fallback!(float_mean(tensor: float) -> tensor, device = tensor.device.clone())
+    }
+
+    fn float_mean_dim<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        fallback!(
+            float_mean_dim(tensor: float, dim: value) -> tensor,
+            device = tensor.device.clone()
+        )
+    }
+
+    fn float_exp<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        fallback!(float_exp(tensor: float) -> tensor, device = tensor.device.clone())
+    }
+
+    fn float_log<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        fallback!(float_log(tensor: float) -> tensor, device = tensor.device.clone())
+    }
+
+    fn float_log1p<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        fallback!(float_log1p(tensor: float) -> tensor, device = tensor.device.clone())
+    }
+
+    fn float_powf<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        fallback!(float_powf(lhs: float, rhs: float) -> tensor, device = lhs.device.clone())
+    }
+
+    fn float_powi<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        fallback!(float_powi(lhs: float, rhs: int) -> tensor, device = lhs.device.clone())
+    }
+
+    fn float_powi_scalar<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        fallback!(float_powi_scalar(lhs: float, rhs: elem) -> tensor, device = lhs.device.clone())
+    }
+
+    fn float_powf_scalar<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        value: f32,
+    ) -> FloatTensor<Self, D> {
+        fallback!(
+            float_powf_scalar(tensor: float, value: value) -> tensor,
+            device = tensor.device.clone()
+        )
+    }
+
+    fn float_sqrt<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        fallback!(float_sqrt(tensor: float) -> tensor, device = tensor.device.clone())
+    }
+
+    fn float_abs<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        fallback!(float_abs(tensor: float) -> tensor, device = tensor.device.clone())
+    }
+
+    fn float_cos<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        fallback!(float_cos(tensor: float) -> tensor, device = tensor.device.clone())
+    }
+
+    fn float_sin<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        fallback!(float_sin(tensor: float) -> tensor, device = tensor.device.clone())
+    }
+
+    fn float_tanh<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        fallback!(float_tanh(tensor: float) -> tensor, device = tensor.device.clone())
+    }
+
+    fn float_erf<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        fallback!(float_erf(tensor: float) -> tensor, device = tensor.device.clone())
+    }
+
+    fn float_erfinv<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        fallback!(float_erfinv(tensor: float) -> tensor, device = tensor.device.clone())
+    }
+
+    fn float_cat<const D: usize>(
+        tensors: Vec<FloatTensor<Self, D>>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        fallback!(
+            float_cat(tensors: floats, dim: value) -> tensor,
+            device = tensors[0].device.clone()
+        )
+    }
+
+    fn float_argmax<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> IntTensor<Self, D> {
+        fallback!(float_argmax(tensor: float, dim: value) -> tensor, device = tensor.device.clone())
+    }
+
+    fn float_argmin<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> IntTensor<Self, D> {
+        fallback!(float_argmin(tensor: float, dim: value) -> tensor, device = tensor.device.clone())
+    }
+
+    fn float_max<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, 1> {
+        fallback!(float_max(tensor: float) -> tensor, device = tensor.device.clone())
+    }
+
+    fn float_max_dim<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        fallback!(
+            float_max_dim(tensor: float, dim: value) -> tensor,
+            device = tensor.device.clone()
+        )
+    }
+
+    fn float_max_dim_with_indices<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> (FloatTensor<Self, D>, IntTensor<Self, D>) {
+        fallback!(
+            float_max_dim_with_indices(tensor: float, dim: value) -> pair,
+            device = tensor.device.clone()
+        )
+    }
+
+    fn float_min<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, 1> {
+        fallback!(float_min(tensor: float) -> tensor, device = tensor.device.clone())
+    }
+
+    fn float_min_dim<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        fallback!(
+            float_min_dim(tensor: float, dim: value) -> tensor,
+            device = tensor.device.clone()
+        )
+    }
+
+    fn float_min_dim_with_indices<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> (FloatTensor<Self, D>, IntTensor<Self, D>) {
+        fallback!(
+            float_min_dim_with_indices(tensor: float, dim: value) -> pair,
+            device = tensor.device.clone()
+        )
+    }
+
+    fn float_narrow<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+        start: usize,
+        length: usize,
+    ) -> FloatTensor<Self, D> {
+        fallback!(
+            float_narrow(tensor: float, dim: value, start: value, length: value) -> tensor,
+            device = tensor.device.clone()
+        )
+    }
+
+    fn float_any<const D: usize>(tensor: FloatTensor<Self, D>) -> BoolTensor<Self, 1> {
+        fallback!(float_any(tensor: float) -> tensor, device = tensor.device.clone())
+    }
+
+    fn float_any_dim<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> BoolTensor<Self, D> {
+        fallback!(
+            float_any_dim(tensor: float, dim: value) -> tensor,
+            device = tensor.device.clone()
+        )
+    }
+
+    fn float_all<const D: usize>(tensor: FloatTensor<Self, D>) -> BoolTensor<Self, 1> {
+        fallback!(float_all(tensor: float) -> tensor, device = tensor.device.clone())
+    }
+
+    fn float_all_dim<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> BoolTensor<Self, D> {
+        fallback!(
+            float_all_dim(tensor: float, dim: value) -> tensor,
+            device = tensor.device.clone()
+        )
+    }
+
+    fn float_sign<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        fallback!(float_sign(tensor: float) -> tensor, device = tensor.device.clone())
+    }
+
+    fn float_expand<const D1: usize, const D2: usize>(
+        tensor: FloatTensor<Self, D1>,
+        shape: Shape<D2>,
+    ) -> FloatTensor<Self, D2> {
+        fallback!(
+            float_expand(tensor: float, shape: value) -> tensor,
+            device = tensor.device.clone()
+        )
+    }
+
+    fn float_multinomial(
+        tensor: FloatTensor<Self, 2>,
+        num_samples: usize,
+        replacement: bool,
+    ) -> IntTensor<Self, 2> {
+        fallback!(
+            float_multinomial(tensor: float, num_samples: value, replacement: value) -> tensor,
+            device = tensor.device.clone()
+        )
+    }
+
+    fn float_associative_scan<const D: usize>(
+        a: FloatTensor<Self, D>,
+        b: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        fallback!(
+            float_associative_scan(a: float, b: float, dim: value) -> tensor,
+            device = a.device.clone()
+        )
+    }
+
+    fn float_sort<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+        descending: bool,
+    ) -> FloatTensor<Self, D> {
+        fallback!(
+            float_sort(tensor: float, dim: value, descending: value) -> tensor,
+            device = tensor.device.clone()
+        )
+    }
+
+    fn float_sort_with_indices<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+        descending: bool,
+    ) -> (FloatTensor<Self, D>, IntTensor<Self, D>) {
+        fallback!(
+            float_sort_with_indices(tensor: float, dim: value, descending: value) -> pair,
+            device = tensor.device.clone()
+        )
+    }
+
+    fn float_argsort<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+        descending: bool,
+    ) -> IntTensor<Self, D> {
+        fallback!(
+            float_argsort(tensor: float, dim: value, descending: value) -> tensor,
+            device = tensor.device.clone()
+        )
+    }
+
+    fn float_pad<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        padding: [(isize, isize); D],
+        mode: PadMode<FloatElem<Self>>,
+    ) -> FloatTensor<Self, D> {
+        fallback!(
+            float_pad(tensor: float, padding: value, mode: pad_mode) -> tensor,
+            device = tensor.device.clone()
+        )
+    }
+
+    fn float_fake_quantize<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        scale: FloatTensor<Self, D>,
+        zero_point: FloatTensor<Self, D>,
+        qmin: FloatElem<Self>,
+        qmax: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        fallback!(
+            float_fake_quantize(
+                tensor: float,
+                scale: float,
+                zero_point: float,
+                qmin: elem,
+                qmax: elem,
+            ) -> tensor,
+            device = tensor.device.clone()
+        )
+    }
+
+    fn float_lerp<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        end: FloatTensor<Self, D>,
+        weight: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        fallback!(
+            float_lerp(tensor: float, end: float, weight: float) -> tensor,
+            device = tensor.device.clone()
+        )
+    }
+
+    fn float_lerp_scalar<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        end: FloatTensor<Self, D>,
+        weight: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        fallback!(
+            float_lerp_scalar(tensor: float, end: float, weight: elem) -> tensor,
+            device = tensor.device.clone()
+        )
+    }
+
+    fn float_addcmul<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        tensor1: FloatTensor<Self, D>,
+        tensor2: FloatTensor<Self, D>,
+        value: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        fallback!(
+            float_addcmul(tensor: float, tensor1: float, tensor2: float, value: elem) -> tensor,
+            device = tensor.device.clone()
+        )
+    }
+
+    fn float_addcdiv<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        tensor1: FloatTensor<Self, D>,
+        tensor2: FloatTensor<Self, D>,
+        value: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        fallback!(
+            float_addcdiv(tensor: float, tensor1: float, tensor2: float, value: elem) -> tensor,
+            device = tensor.device.clone()
+        )
+    }
+
+    fn float_axpby<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        alpha: FloatElem<Self>,
+        other: FloatTensor<Self, D>,
+        beta: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        fallback!(
+            float_axpby(tensor: float, alpha: elem, other: float, beta: elem) -> tensor,
+            device = tensor.device.clone()
+        )
+    }
+}
@@ -0,0 +1,725 @@
+use core::future::Future;
+use core::ops::Range;
+
+use burn_tensor::backend::Backend;
+use burn_tensor::ops::{BoolTensor, Device, FloatTensor, IntElem, IntTensor, IntTensorOps};
+use burn_tensor::{Distribution, PadMode, Shape, TensorData};
+
+use crate::{Fallback, FallbackPrimitive, FallbackTensor};
+
+impl<P: Backend, S: Backend> IntTensorOps<Self> for Fallback<P, S> {
+    fn int_shape<const D: usize>(tensor: &IntTensor<Self, D>) -> Shape<D> {
+        match &tensor.primitive {
+            FallbackPrimitive::Primary(tensor) => P::int_shape(tensor),
+            FallbackPrimitive::Secondary(tensor) => S::int_shape(tensor),
+        }
+    }
+
+    fn int_device<const D: usize>(tensor: &IntTensor<Self, D>) -> Device<Self> {
+        tensor.device.clone()
+    }
+
+    fn int_to_device<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        device: &Device<Self>,
+    ) -> IntTensor<Self, D> {
+        let primitive = match tensor.primitive {
+            FallbackPrimitive::Primary(tensor) => {
+                FallbackPrimitive::Primary(P::int_to_device(tensor, &device.primary))
+            }
+            FallbackPrimitive::Secondary(tensor) => {
+                FallbackPrimitive::Secondary(S::int_to_device(tensor, &device.secondary))
+            }
+        };
+
+        FallbackTensor::new(primitive, device.clone())
+    }
+
+    fn int_into_data<const D: usize>(
+        tensor: IntTensor<Self, D>,
+    ) -> impl Future<Output = TensorData> + Send {
+        async move {
+            match tensor.primitive {
+                FallbackPrimitive::Primary(tensor) => P::int_into_data(tensor).await,
+                FallbackPrimitive::Secondary(tensor) => {
+                    S::int_into_data(tensor).await.convert::<P::IntElem>()
+                }
+            }
+        }
+    }
+
+    fn int_empty<const D: usize>(shape: Shape<D>, device: &Device<Self>) -> IntTensor<Self, D> {
+        fallback!(int_empty(shape: value, device: device) -> tensor, device = device.clone())
+    }
+
+    fn int_from_data<const D: usize>(
+        data: TensorData,
+        device: &Device<Self>,
+    ) -> IntTensor<Self, D> {
+        fallback!(int_from_data(data: value, device: device) -> tensor, device = device.clone())
+    }
+
+    fn int_reshape<const D1: usize, const D2: usize>(
+        tensor: IntTensor<Self, D1>,
+        shape: Shape<D2>,
+    ) -> IntTensor<Self, D2> {
+        fallback!(int_reshape(tensor: int, shape: value) -> tensor, device = tensor.device.clone())
+    }
+
+    fn int_slice<const D1: usize, const D2: usize>(
+        tensor: IntTensor<Self, D1>,
+        indices: [Range<usize>; D2],
+    ) -> IntTensor<Self, D1> {
+        fallback!(int_slice(tensor: int, indices: value) -> tensor, device = tensor.device.clone())
+    }
+
+    fn int_slice_assign<const D1: usize, const D2: usize>(
+        tensor: IntTensor<Self, D1>,
+        indices: [Range<usize>; D2],
+        value: IntTensor<Self, D1>,
+    ) -> IntTensor<Self, D1> {
+        fallback!(
+            int_slice_assign(tensor: int, indices: value, value: int) -> tensor,
+            device = tensor.device.clone()
+        )
+    }
+
+    fn int_into_float<const D: usize>(tensor: IntTensor<Self, D>) -> FloatTensor<Self, D> {
+        fallback!(int_into_float(tensor: int) -> tensor, device = tensor.device.clone())
+    }
+
+    fn int_mask_where<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        mask: BoolTensor<Self, D>,
+        source: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        fallback!(
+            int_mask_where(tensor: int, mask: bool, source: int) -> tensor,
+            device = tensor.device.clone()
+        )
+    }
+
+    fn int_mask_fill<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        mask: BoolTensor<Self, D>,
+        value: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        fallback!(
+            int_mask_fill(tensor: int, mask: bool, value: elem) -> tensor,
+            device = tensor.device.clone()
+        )
+    }
+
+    fn int_gather<const D: usize>(
+        dim: usize,
+        tensor: IntTensor<Self, D>,
+        indices: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        fallback!(
+            int_gather(dim: value, tensor: int, indices: int) -> tensor,
+            device = tensor.device.clone()
+        )
+    }
+
+    fn int_scatter<const D: usize>(
+        dim: usize,
+        tensor: IntTensor<Self, D>,
+        indices: IntTensor<Self, D>,
+        value: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        fallback!(
+            int_scatter(dim: value, tensor: int, indices: int, value: int) -> tensor,
+            device = tensor.device.clone()
+        )
+    }
+
+    fn int_select<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim: usize,
+        indices: IntTensor<Self, 1>,
+    ) -> IntTensor<Self, D> {
+        fallback!(
+            int_select(tensor: int, dim: value, indices: int) -> tensor,
+            device = tensor.device.clone()
+        )
+    }
+
+    fn int_select_assign<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim: usize,
+        indices: IntTensor<Self, 1>,
+        value: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        fallback!(
+            int_select_assign(tensor: int, dim: value, indices: int, value: int) -> tensor,
+            device = tensor.device.clone()
+        )
+    }
+
+    fn int_index_put<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim: usize,
+        indices: IntTensor<Self, 1>,
+        values: IntTensor<Self, D>,
+        accumulate: bool,
+    ) -> IntTensor<Self, D> {
+        fallback!(
+            int_index_put(
+                tensor: int,
+                dim: value,
+                indices: int,
+                values: int,
+                accumulate: value,
+            ) -> tensor,
+            device = tensor.device.clone()
+        )
+    }
+
+    fn int_repeat_dim<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim: usize,
+        times: usize,
+    ) -> IntTensor<Self, D> {
+        fallback!(
+            int_repeat_dim(tensor: int, dim: value, times: value) -> tensor,
+            device = tensor.device.clone()
+        )
+    }
+
+    fn int_cat<const D: usize>(tensors: Vec<IntTensor<Self, D>>, dim: usize) -> IntTensor<Self, D> {
+        fallback!(int_cat(tensors: ints, dim: value) -> tensor, device = tensors[0].device.clone())
+    }
+
+    fn int_equal<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        fallback!(int_equal(lhs: int, rhs: int) -> tensor, device = lhs.device.clone())
+    }
+
+    fn int_not_equal<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        fallback!(int_not_equal(lhs: int, rhs: int) -> tensor, device = lhs.device.clone())
+    }
+
+    fn int_equal_elem<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> BoolTensor<Self, D> {
+        fallback!(int_equal_elem(lhs: int, rhs: elem) -> tensor, device = lhs.device.clone())
+    }
+
+    fn int_not_equal_elem<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> BoolTensor<Self, D> {
+        fallback!(int_not_equal_elem(lhs: int, rhs: elem) -> tensor, device = lhs.device.clone())
+    }
+
+    fn int_greater<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        fallback!(int_greater(lhs: int, rhs: int) -> tensor, device = lhs.device.clone())
+    }
+
+    fn int_greater_elem<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> BoolTensor<Self, D> {
+        fallback!(int_greater_elem(lhs: int, rhs: elem) -> tensor, device = lhs.device.clone())
+    }
+
+    fn int_greater_equal<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        fallback!(int_greater_equal(lhs: int, rhs: int) -> tensor, device = lhs.device.clone())
+    }
+
+    fn int_greater_equal_elem<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> BoolTensor<Self, D> {
+        fallback!(
+            int_greater_equal_elem(lhs: int, rhs: elem) -> tensor,
+            device = lhs.device.clone()
+        )
+    }
+
+    fn int_lower<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        fallback!(int_lower(lhs: int, rhs: int) -> tensor, device = lhs.device.clone())
+    }
+
+    fn int_lower_elem<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> BoolTensor<Self, D> {
+        fallback!(int_lower_elem(lhs: int, rhs: elem) -> tensor, device = lhs.device.clone())
+    }
+
+    fn int_lower_equal<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        fallback!(int_lower_equal(lhs: int, rhs: int) -> tensor, device = lhs.device.clone())
+    }
+
+    fn int_lower_equal_elem<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> BoolTensor<Self, D> {
+        fallback!(int_lower_equal_elem(lhs: int, rhs: elem) -> tensor, device = lhs.device.clone())
+    }
+
+    fn int_add<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        fallback!(int_add(lhs: int, rhs: int) -> tensor, device = lhs.device.clone())
+    }
+
+    fn int_add_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        fallback!(int_add_scalar(lhs: int, rhs: elem) -> tensor, device = lhs.device.clone())
+    }
+
+    fn int_powi<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        fallback!(int_powi(lhs: int, rhs: int) -> tensor, device = lhs.device.clone())
+    }
+
+    fn int_powf<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        fallback!(int_powf(lhs: int, rhs: float) -> tensor, device = lhs.device.clone())
+    }
+
+    fn int_powi_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        fallback!(int_powi_scalar(lhs: int, rhs: elem) -> tensor, device = lhs.device.clone())
+    }
+
+    fn int_powf_scalar<const D: usize>(lhs: IntTensor<Self, D>, rhs: f32) -> IntTensor<Self, D> {
+        fallback!(int_powf_scalar(lhs: int, rhs: value) -> tensor, device = lhs.device.clone())
+    }
+
+    fn int_clamp_min<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        min: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        fallback!(int_clamp_min(tensor: int, min: elem) -> tensor, device = tensor.device.clone())
+    }
+
+    fn int_clamp_max<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        max: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        fallback!(int_clamp_max(tensor: int, max: elem) -> tensor, device = tensor.device.clone())
+    }
+
+    fn int_clamp<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        min: IntElem<Self>,
+        max: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        fallback!(
+            int_clamp(tensor: int, min: elem, max: elem) -> tensor,
+            device = tensor.device.clone()
+        )
+    }
+
+    fn int_sub<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        fallback!(int_sub(lhs: int, rhs: int) -> tensor, device = lhs.device.clone())
+    }
+
+    fn int_sub_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        fallback!(int_sub_scalar(lhs: int, rhs: elem) -> tensor, device = lhs.device.clone())
+    }
+
+    fn int_mul<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        fallback!(int_mul(lhs: int, rhs: int) -> tensor, device = lhs.device.clone())
+    }
+
+    fn int_mul_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        fallback!(int_mul_scalar(lhs: int, rhs: elem) -> tensor, device = lhs.device.clone())
+    }
+
+    fn int_matmul<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        fallback!(int_matmul(lhs: int, rhs: int) -> tensor, device = lhs.device.clone())
+    }
+
+    fn int_div<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        fallback!(int_div(lhs: int, rhs: int) -> tensor, device = lhs.device.clone())
+    }
+
+    fn int_div_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        fallback!(int_div_scalar(lhs: int, rhs: elem) -> tensor, device = lhs.device.clone())
+    }
+
+    fn int_remainder<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        fallback!(int_remainder(lhs: int, rhs: int) -> tensor, device = lhs.device.clone())
+    }
+
+    fn int_remainder_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        fallback!(int_remainder_scalar(lhs: int, rhs: elem) -> tensor, device = lhs.device.clone())
+    }
+
+    fn int_neg<const D: usize>(tensor: IntTensor<Self, D>) -> IntTensor<Self, D> {
+        fallback!(int_neg(tensor: int) -> tensor, device = tensor.device.clone())
+    }
+
+    fn int_zeros<const D: usize>(shape: Shape<D>, device: &Device<Self>) -> IntTensor<Self, D> {
+        fallback!(int_zeros(shape: value, device: device) -> tensor, device = device.clone())
+    }
+
+    fn int_ones<const D: usize>(shape: Shape<D>, device: &Device<Self>) -> IntTensor<Self, D> {
+        fallback!(int_ones(shape: value, device: device) -> tensor, device = device.clone())
+    }
+
+    fn int_full<const D: usize>(
+        shape: Shape<D>,
+        fill_value: IntElem<Self>,
+        device: &Device<Self>,
+    ) -> IntTensor<Self, D> {
+        fallback!(
+            int_full(shape: value, fill_value: elem, device: device) -> tensor,
+            device = device.clone()
+        )
+    }
+
+    fn int_sum<const D: usize>(tensor: IntTensor<Self, D>) -> IntTensor<Self, 1> {
+        fallback!(int_sum(tensor: int) -> tensor, device = tensor.device.clone())
+    }
+
+    fn int_sum_dim<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
+        fallback!(int_sum_dim(tensor: int, dim: value) -> tensor, device = tensor.device.clone())
+    }
+
+    fn int_prod<const D: usize>(tensor: IntTensor<Self, D>) -> IntTensor<Self, 1> {
+        fallback!(int_prod(tensor: int) -> tensor, device = tensor.device.clone())
+    }
+
+    fn int_prod_dim<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
+        fallback!(int_prod_dim(tensor: int, dim: value) -> tensor, device = tensor.device.clone())
+    }
+
+    fn int_mean<const D: usize>(tensor: IntTensor<Self, D>) -> IntTensor<Self, 1> {
+        fallback!(int_mean(tensor: int) -> tensor, device = tensor.device.clone())
+    }
+
+    fn int_mean_dim<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
+        fallback!(int_mean_dim(tensor: int, dim: value) -> tensor, device = tensor.device.clone())
+    }
+
+    fn int_argmax<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
+        fallback!(int_argmax(tensor: int, dim: value) -> tensor, device = tensor.device.clone())
+    }
+
+    fn int_argmin<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
+        fallback!(int_argmin(tensor: int, dim: value) -> tensor, device = tensor.device.clone())
+    }
+
+    fn int_max<const D: usize>(tensor: IntTensor<Self, D>) -> IntTensor<Self, 1> {
+        fallback!(int_max(tensor: int) -> tensor, device = tensor.device.clone())
+    }
+
+    fn int_max_dim<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
+        fallback!(int_max_dim(tensor: int, dim: value) -> tensor, device = tensor.device.clone())
+    }
+
+    fn int_max_dim_with_indices<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim: usize,
+    ) -> (IntTensor<Self, D>, IntTensor<Self, D>) {
+        fallback!(
+            int_max_dim_with_indices(tensor: int, dim: value) -> pair,
+            device = tensor.device.clone()
+        )
+    }
+
+    fn int_min<const D: usize>(tensor: IntTensor<Self, D>) -> IntTensor<Self, 1> {
+        fallback!(int_min(tensor: int) -> tensor, device = tensor.device.clone())
+    }
+
+    fn int_min_dim<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
+        fallback!(int_min_dim(tensor: int, dim: value) -> tensor, device = tensor.device.clone())
+    }
+
+    fn int_min_dim_with_indices<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim: usize,
+    ) -> (IntTensor<Self, D>, IntTensor<Self, D>) {
+        fallback!(
+            int_min_dim_with_indices(tensor: int, dim: value) -> pair,
+            device = tensor.device.clone()
+        )
+    }
+
+    fn int_abs<const D: usize>(tensor: IntTensor<Self, D>) -> IntTensor<Self, D> {
+        fallback!(int_abs(tensor: int) -> tensor, device = tensor.device.clone())
+    }
+
+    fn int_transpose<const D: usize>(tensor: IntTensor<Self, D>) -> IntTensor<Self, D> {
+        fallback!(int_transpose(tensor: int) -> tensor, device = tensor.device.clone())
+    }
+
+    fn int_swap_dims<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim1: usize,
+        dim2: usize,
+    ) -> IntTensor<Self, D> {
+        fallback!(
+            int_swap_dims(tensor: int, dim1: value, dim2: value) -> tensor,
+            device = tensor.device.clone()
+        )
+    }
+
+    fn int_permute<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        axes: [usize; D],
+    ) -> IntTensor<Self, D> {
+        fallback!(int_permute(tensor: int, axes: value) -> tensor, device = tensor.device.clone())
+    }
+
+    fn int_flip<const D: usize>(tensor: IntTensor<Self, D>, axes: &[usize]) -> IntTensor<Self, D> {
+        fallback!(int_flip(tensor: int, axes: value) -> tensor, device = tensor.device.clone())
+    }
+
+    fn int_narrow<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim: usize,
+        start: usize,
+        length: usize,
+    ) -> IntTensor<Self, D> {
+        fallback!(
+            int_narrow(tensor: int, dim: value, start: value, length: value) -> tensor,
+            device = tensor.device.clone()
+        )
+    }
+
+    fn int_random<const D: usize>(
+        shape: Shape<D>,
+        distribution: Distribution,
+        device: &Device<Self>,
+    ) -> IntTensor<Self, D> {
+        fallback!(
+            int_random(shape: value, distribution: value, device: device) -> tensor,
+            device = device.clone()
+        )
+    }
+
+    fn int_arange_step(
+        range: Range<i64>,
+        step: usize,
+        device: &Device<Self>,
+    ) -> IntTensor<Self, 1> {
+        fallback!(
+            int_arange_step(range: value, step: value, device: device) -> tensor,
+            device = device.clone()
+        )
+    }
+
+    fn int_arange(range: Range<i64>, device: &Device<Self>) -> IntTensor<Self, 1> {
+        fallback!(int_arange(range: value, device: device) -> tensor, device = device.clone())
+    }
+
+    fn int_any<const D: usize>(tensor: IntTensor<Self, D>) -> BoolTensor<Self, 1> {
+        fallback!(int_any(tensor: int) -> tensor, device = tensor.device.clone())
+    }
+
+    fn int_any_dim<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> BoolTensor<Self, D> {
+        fallback!(int_any_dim(tensor: int, dim: value) -> tensor, device = tensor.device.clone())
+    }
+
+    fn int_all<const D: usize>(tensor: IntTensor<Self, D>) -> BoolTensor<Self, 1> {
+        fallback!(int_all(tensor: int) -> tensor, device = tensor.device.clone())
+    }
+
+    fn int_all_dim<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> BoolTensor<Self, D> {
+        fallback!(int_all_dim(tensor: int, dim: value) -> tensor, device = tensor.device.clone())
+    }
+
+    fn int_sign<const D: usize>(tensor: IntTensor<Self, D>) -> IntTensor<Self, D> {
+        fallback!(int_sign(tensor: int) -> tensor, device = tensor.device.clone())
+    }
+
+    fn int_expand<const D1: usize, const D2: usize>(
+        tensor: IntTensor<Self, D1>,
+        shape: Shape<D2>,
+    ) -> IntTensor<Self, D2> {
+        fallback!(int_expand(tensor: int, shape: value) -> tensor, device = tensor.device.clone())
+    }
+
+    fn int_sort<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim: usize,
+        descending: bool,
+    ) -> IntTensor<Self, D> {
+        fallback!(
+            int_sort(tensor: int, dim: value, descending: value) -> tensor,
+            device = tensor.device.clone()
+        )
+    }
+
+    fn int_sort_with_indices<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim: usize,
+        descending: bool,
+    ) -> (IntTensor<Self, D>, IntTensor<Self, D>) {
+        fallback!(
+            int_sort_with_indices(tensor: int, dim: value, descending: value) -> pair,
+            device = tensor.device.clone()
+        )
+    }
+
+    fn int_argsort<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim: usize,
+        descending: bool,
+    ) -> IntTensor<Self, D> {
+        fallback!(
+            int_argsort(tensor: int, dim: value, descending: value) -> tensor,
+            device = tensor.device.clone()
+        )
+    }
+
+    fn int_pad<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        padding: [(isize, isize); D],
+        mode: PadMode<IntElem<Self>>,
+    ) -> IntTensor<Self, D> {
+        fallback!(
+            int_pad(tensor: int, padding: value, mode: pad_mode) -> tensor,
+            device = tensor.device.clone()
+        )
+    }
+
+    fn int_bitwise_and<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        fallback!(int_bitwise_and(lhs: int, rhs: int) -> tensor, device = lhs.device.clone())
+    }
+
+    fn int_bitwise_and_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        fallback!(
+            int_bitwise_and_scalar(lhs: int, rhs: elem) -> tensor,
+            device = lhs.device.clone()
+        )
+    }
+
+    fn int_bitwise_or<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        fallback!(int_bitwise_or(lhs: int, rhs: int) -> tensor, device = lhs.device.clone())
+    }
+
+    fn int_bitwise_or_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        fallback!(int_bitwise_or_scalar(lhs: int, rhs: elem) -> tensor, device = lhs.device.clone())
+    }
+
+    fn int_bitwise_xor<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        fallback!(int_bitwise_xor(lhs: int, rhs: int) -> tensor, device = lhs.device.clone())
+    }
+
+    fn int_bitwise_xor_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        fallback!(
+            int_bitwise_xor_scalar(lhs: int, rhs: elem) -> tensor,
+            device = lhs.device.clone()
+        )
+    }
+
+    fn int_bitwise_not<const D: usize>(tensor: IntTensor<Self, D>) -> IntTensor<Self, D> {
+        fallback!(int_bitwise_not(tensor: int) -> tensor, device = tensor.device.clone())
+    }
+
+    fn int_bitwise_left_shift<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        fallback!(int_bitwise_left_shift(lhs: int, rhs: int) -> tensor, device = lhs.device.clone())
+    }
+
+    fn int_bitwise_left_shift_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        fallback!(
+            int_bitwise_left_shift_scalar(lhs: int, rhs: elem) -> tensor,
+            device = lhs.device.clone()
+        )
+    }
+
+    fn int_bitwise_right_shift<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        fallback!(
+            int_bitwise_right_shift(lhs: int, rhs: int) -> tensor,
+            device = lhs.device.clone()
+        )
+    }
+
+    fn int_bitwise_right_shift_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        fallback!(
+            int_bitwise_right_shift_scalar(lhs: int, rhs: elem) -> tensor,
+            device = lhs.device.clone()
+        )
+    }
+}
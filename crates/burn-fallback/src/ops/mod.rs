@@ -0,0 +1,203 @@
+/// Execute an operation on the primary backend, or on the secondary backend if the primary
+/// backend doesn't support it.
+///
+/// Each argument is annotated with its kind, telling how it is given to each backend: the
+/// tensors are transferred to the backend executing the operation, the devices are replaced by
+/// the device of that backend, the elements are converted and the other values are copied. The
+/// outputs are kept on the backend that computed them, on the given device.
+macro_rules! fallback {
+    (
+        $op:ident($($arg:ident: $kind:ident),* $(,)?) -> $out:ident,
+        device = $device:expr
+    ) => {{
+        let device: $crate::FallbackDevice<P::Device, S::Device> = $device;
+
+        Self::run(
+            stringify!($op),
+            ($($arg,)*),
+            |($($arg,)*)| {
+                let output = P::$op($(fallback!(@primary $kind, $arg)),*);
+                fallback!(@output $out, primary, output, device)
+            },
+            |($($arg,)*)| {
+                let output = S::$op($(fallback!(@secondary $kind, $arg)),*);
+                fallback!(@output $out, secondary, output, device)
+            },
+        )
+    }};
+
+    (@primary float, $arg:ident) => { Self::float_primary($arg) };
+    (@primary int, $arg:ident) => { Self::int_primary($arg) };
+    (@primary bool, $arg:ident) => { Self::bool_primary($arg) };
+    (@primary quant, $arg:ident) => { Self::q_primary($arg) };
+    (@primary option_float, $arg:ident) => { $arg.map(Self::float_primary) };
+    (@primary floats, $arg:ident) => {
+        $arg.into_iter().map(Self::float_primary).collect()
+    };
+    (@primary ints, $arg:ident) => { $arg.into_iter().map(Self::int_primary).collect() };
+    (@primary bools, $arg:ident) => { $arg.into_iter().map(Self::bool_primary).collect() };
+    (@primary device, $arg:ident) => { &$arg.primary };
+    (@primary $kind:ident, $arg:ident) => { $arg };
+
+    (@secondary float, $arg:ident) => { Self::float_secondary($arg) };
+    (@secondary int, $arg:ident) => { Self::int_secondary($arg) };
+    (@secondary bool, $arg:ident) => { Self::bool_secondary($arg) };
+    (@secondary quant, $arg:ident) => { Self::q_secondary($arg) };
+    (@secondary option_float, $arg:ident) => { $arg.map(Self::float_secondary) };
+    (@secondary floats, $arg:ident) => {
+        $arg.into_iter().map(Self::float_secondary).collect()
+    };
+    (@secondary ints, $arg:ident) => { $arg.into_iter().map(Self::int_secondary).collect() };
+    (@secondary bools, $arg:ident) => {
+        $arg.into_iter().map(Self::bool_secondary).collect()
+    };
+    (@secondary device, $arg:ident) => { &$arg.secondary };
+    (@secondary elem, $arg:ident) => { burn_tensor::ElementConversion::elem($arg) };
+    (@secondary pad_mode, $arg:ident) => { $crate::ops::pad_mode($arg) };
+    (@secondary $kind:ident, $arg:ident) => { $arg };
+
+    (@output tensor, $variant:ident, $output:expr, $device:expr) => {
+        $crate::FallbackTensor::$variant($output, $device.clone())
+    };
+    (@output pair, $variant:ident, $output:expr, $device:expr) => {{
+        let (first, second) = $output;
+        (
+            $crate::FallbackTensor::$variant(first, $device.clone()),
+            $crate::FallbackTensor::$variant(second, $device.clone()),
+        )
+    }};
+}
+
+// The operations that don't compute anything, such as the autodiff flags, and the operations
+// composed of other operations, such as the backward passes of the convolutions, are left to their
+// default implementations.
+mod activation;
+mod bool;
+mod float;
+mod int;
+mod module;
+mod optim;
+mod qtensor;
+
+use burn_tensor::backend::Backend;
+use burn_tensor::ops::{BoolTensor, FloatTensor, IntTensor, QuantizedTensor};
+use burn_tensor::{ElementConversion, PadMode, TensorData};
+
+use crate::{Fallback, FallbackPrimitive};
+
+pub(crate) fn pad_mode<E: ElementConversion, O: ElementConversion>(mode: PadMode<E>) -> PadMode<O> {
+    match mode {
+        PadMode::Constant(value) => PadMode::Constant(value.elem()),
+        PadMode::Reflect => PadMode::Reflect,
+        PadMode::Edge => PadMode::Edge,
+    }
+}
+
+fn read_sync(data: impl core::future::Future<Output = TensorData>) -> TensorData {
+    burn_tensor::try_read_sync(data)
+        .expect("Failed to synchronously read tensor data to transfer it to the other backend.")
+}
+
+impl<P: Backend, S: Backend> Fallback<P, S> {
+    /// Returns the float tensor of the primary backend, transferring it if needed.
+    pub(crate) fn float_primary<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<P, D> {
+        match tensor.primitive {
+            FallbackPrimitive::Primary(tensor) => tensor,
+            FallbackPrimitive::Secondary(primitive) => {
+                Self::record_transfer();
+                let data = read_sync(S::float_into_data(primitive));
+                P::float_from_data(data.convert::<P::FloatElem>(), &tensor.device.primary)
+            }
+        }
+    }
+
+    /// Returns the float tensor of the secondary backend, transferring it if needed.
+    pub(crate) fn float_secondary<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+    ) -> FloatTensor<S, D> {
+        match tensor.primitive {
+            FallbackPrimitive::Secondary(tensor) => tensor,
+            FallbackPrimitive::Primary(primitive) => {
+                Self::record_transfer();
+                let data = read_sync(P::float_into_data(primitive));
+                S::float_from_data(data.convert::<S::FloatElem>(), &tensor.device.secondary)
+            }
+        }
+    }
+
+    /// Returns the int tensor of the primary backend, transferring it if needed.
+    pub(crate) fn int_primary<const D: usize>(tensor: IntTensor<Self, D>) -> IntTensor<P, D> {
+        match tensor.primitive {
+            FallbackPrimitive::Primary(tensor) => tensor,
+            FallbackPrimitive::Secondary(primitive) => {
+                Self::record_transfer();
+                let data = read_sync(S::int_into_data(primitive));
+                P::int_from_data(data.convert::<P::IntElem>(), &tensor.device.primary)
+            }
+        }
+    }
+
+    /// Returns the int tensor of the secondary backend, transferring it if needed.
+    pub(crate) fn int_secondary<const D: usize>(tensor: IntTensor<Self, D>) -> IntTensor<S, D> {
+        match tensor.primitive {
+            FallbackPrimitive::Secondary(tensor) => tensor,
+            FallbackPrimitive::Primary(primitive) => {
+                Self::record_transfer();
+                let data = read_sync(P::int_into_data(primitive));
+                S::int_from_data(data.convert::<S::IntElem>(), &tensor.device.secondary)
+            }
+        }
+    }
+
+    /// Returns the bool tensor of the primary backend, transferring it if needed.
+    pub(crate) fn bool_primary<const D: usize>(tensor: BoolTensor<Self, D>) -> BoolTensor<P, D> {
+        match tensor.primitive {
+            FallbackPrimitive::Primary(tensor) => tensor,
+            FallbackPrimitive::Secondary(primitive) => {
+                Self::record_transfer();
+                let data = read_sync(S::bool_into_data(primitive));
+                P::bool_from_data(data, &tensor.device.primary)
+            }
+        }
+    }
+
+    /// Returns the bool tensor of the secondary backend, transferring it if needed.
+    pub(crate) fn bool_secondary<const D: usize>(tensor: BoolTensor<Self, D>) -> BoolTensor<S, D> {
+        match tensor.primitive {
+            FallbackPrimitive::Secondary(tensor) => tensor,
+            FallbackPrimitive::Primary(primitive) => {
+                Self::record_transfer();
+                let data = read_sync(P::bool_into_data(primitive));
+                S::bool_from_data(data, &tensor.device.secondary)
+            }
+        }
+    }
+
+    /// Returns the quantized tensor of the primary backend, transferring it if needed.
+    pub(crate) fn q_primary<const D: usize>(
+        tensor: QuantizedTensor<Self, D>,
+    ) -> QuantizedTensor<P, D> {
+        match tensor.primitive {
+            FallbackPrimitive::Primary(tensor) => tensor,
+            FallbackPrimitive::Secondary(primitive) => {
+                Self::record_transfer();
+                let data = read_sync(S::q_into_data(primitive));
+                P::q_from_data(data, &tensor.device.primary)
+            }
+        }
+    }
+
+    /// Returns the quantized tensor of the secondary backend, transferring it if needed.
+    pub(crate) fn q_secondary<const D: usize>(
+        tensor: QuantizedTensor<Self, D>,
+    ) -> QuantizedTensor<S, D> {
+        match tensor.primitive {
+            FallbackPrimitive::Secondary(tensor) => tensor,
+            FallbackPrimitive::Primary(primitive) => {
+                Self::record_transfer();
+                let data = read_sync(P::q_into_data(primitive));
+                S::q_from_data(data, &tensor.device.secondary)
+            }
+        }
+    }
+}
@@ -0,0 +1,396 @@
+use burn_tensor::backend::Backend;
+use burn_tensor::ops::{
+    ConvOptions, ConvTransposeOptions, FloatTensor, IntTensor, InterpolateOptions,
+    MaxPool2dBackward, MaxPool2dWithIndices, ModuleOps, UnfoldOptions,
+};
+
+use crate::{Fallback, FallbackTensor};
+
+impl<P: Backend, S: Backend> ModuleOps<Self> for Fallback<P, S> {
+    fn embedding(
+        weights: FloatTensor<Self, 2>,
+        indices: IntTensor<Self, 2>,
+    ) -> FloatTensor<Self, 3> {
+        fallback!(
+            embedding(weights: float, indices: int) -> tensor,
+            device = weights.device.clone()
+        )
+    }
+
+    fn embedding_backward(
+        weights: FloatTensor<Self, 2>,
+        output_grad: FloatTensor<Self, 3>,
+        indices: IntTensor<Self, 2>,
+    ) -> FloatTensor<Self, 2> {
+        fallback!(
+            embedding_backward(weights: float, output_grad: float, indices: int) -> tensor,
+            device = weights.device.clone()
+        )
+    }
+
+    fn conv1d(
+        x: FloatTensor<Self, 3>,
+        weight: FloatTensor<Self, 3>,
+        bias: Option<FloatTensor<Self, 1>>,
+        options: ConvOptions<1>,
+    ) -> FloatTensor<Self, 3> {
+        fallback!(
+            conv1d(x: float, weight: float, bias: option_float, options: value) -> tensor,
+            device = x.device.clone()
+        )
+    }
+
+    fn conv2d(
+        x: FloatTensor<Self, 4>,
+        weight: FloatTensor<Self, 4>,
+        bias: Option<FloatTensor<Self, 1>>,
+        options: ConvOptions<2>,
+    ) -> FloatTensor<Self, 4> {
+        fallback!(
+            conv2d(x: float, weight: float, bias: option_float, options: value) -> tensor,
+            device = x.device.clone()
+        )
+    }
+
+    fn conv3d(
+        x: FloatTensor<Self, 5>,
+        weight: FloatTensor<Self, 5>,
+        bias: Option<FloatTensor<Self, 1>>,
+        options: ConvOptions<3>,
+    ) -> FloatTensor<Self, 5> {
+        fallback!(
+            conv3d(x: float, weight: float, bias: option_float, options: value) -> tensor,
+            device = x.device.clone()
+        )
+    }
+
+    fn conv_transpose1d(
+        x: FloatTensor<Self, 3>,
+        weight: FloatTensor<Self, 3>,
+        bias: Option<FloatTensor<Self, 1>>,
+        options: ConvTransposeOptions<1>,
+    ) -> FloatTensor<Self, 3> {
+        fallback!(
+            conv_transpose1d(x: float, weight: float, bias: option_float, options: value) -> tensor,
+            device = x.device.clone()
+        )
+    }
+
+    fn conv_transpose2d(
+        x: FloatTensor<Self, 4>,
+        weight: FloatTensor<Self, 4>,
+        bias: Option<FloatTensor<Self, 1>>,
+        options: ConvTransposeOptions<2>,
+    ) -> FloatTensor<Self, 4> {
+        fallback!(
+            conv_transpose2d(x: float, weight: float, bias: option_float, options: value) -> tensor,
+            device = x.device.clone()
+        )
+    }
+
+    fn conv_transpose3d(
+        x: FloatTensor<Self, 5>,
+        weight: FloatTensor<Self, 5>,
+        bias: Option<FloatTensor<Self, 1>>,
+        options: ConvTransposeOptions<3>,
+    ) -> FloatTensor<Self, 5> {
+        fallback!(
+            conv_transpose3d(x: float, weight: float, bias: option_float, options: value) -> tensor,
+            device = x.device.clone()
+        )
+    }
+
+    fn unfold4d(
+        x: FloatTensor<Self, 4>,
+        kernel_size: [usize; 2],
+        options: UnfoldOptions,
+    ) -> FloatTensor<Self, 3> {
+        fallback!(
+            unfold4d(x: float, kernel_size: value, options: value) -> tensor,
+            device = x.device.clone()
+        )
+    }
+
+    fn avg_pool1d(
+        x: FloatTensor<Self, 3>,
+        kernel_size: usize,
+        stride: usize,
+        padding: usize,
+        count_include_pad: bool,
+        ceil_mode: bool,
+    ) -> FloatTensor<Self, 3> {
+        fallback!(
+            avg_pool1d(
+                x: float,
+                kernel_size: value,
+                stride: value,
+                padding: value,
+                count_include_pad: value,
+                ceil_mode: value,
+            ) -> tensor,
+            device = x.device.clone()
+        )
+    }
+
+    fn avg_pool1d_backward(
+        x: FloatTensor<Self, 3>,
+        grad: FloatTensor<Self, 3>,
+        kernel_size: usize,
+        stride: usize,
+        padding: usize,
+        count_include_pad: bool,
+        ceil_mode: bool,
+    ) -> FloatTensor<Self, 3> {
+        fallback!(
+            avg_pool1d_backward(
+                x: float,
+                grad: float,
+                kernel_size: value,
+                stride: value,
+                padding: value,
+                count_include_pad: value,
+                ceil_mode: value,
+            ) -> tensor,
+            device = x.device.clone()
+        )
+    }
+
+    fn avg_pool2d(
+        x: FloatTensor<Self, 4>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        count_include_pad: bool,
+        ceil_mode: bool,
+    ) -> FloatTensor<Self, 4> {
+        fallback!(
+            avg_pool2d(
+                x: float,
+                kernel_size: value,
+                stride: value,
+                padding: value,
+                count_include_pad: value,
+                ceil_mode: value,
+            ) -> tensor,
+            device = x.device.clone()
+        )
+    }
+
+    fn avg_pool2d_backward(
+        x: FloatTensor<Self, 4>,
+        grad: FloatTensor<Self, 4>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        count_include_pad: bool,
+        ceil_mode: bool,
+    ) -> FloatTensor<Self, 4> {
+        fallback!(
+            avg_pool2d_backward(
+                x: float,
+                grad: float,
+                kernel_size: value,
+                stride: value,
+                padding: value,
+                count_include_pad: value,
+                ceil_mode: value,
+            ) -> tensor,
+            device = x.device.clone()
+        )
+    }
+
+    fn adaptive_avg_pool2d(
+        x: FloatTensor<Self, 4>,
+        output_size: [usize; 2],
+    ) -> FloatTensor<Self, 4> {
+        fallback!(
+            adaptive_avg_pool2d(x: float, output_size: value) -> tensor,
+            device = x.device.clone()
+        )
+    }
+
+    fn adaptive_avg_pool2d_backward(
+        x: FloatTensor<Self, 4>,
+        grad: FloatTensor<Self, 4>,
+    ) -> FloatTensor<Self, 4> {
+        fallback!(
+            adaptive_avg_pool2d_backward(x: float, grad: float) -> tensor,
+            device = x.device.clone()
+        )
+    }
+
+    fn adaptive_avg_pool1d(x: FloatTensor<Self, 3>, output_size: usize) -> FloatTensor<Self, 3> {
+        fallback!(
+            adaptive_avg_pool1d(x: float, output_size: value) -> tensor,
+            device = x.device.clone()
+        )
+    }
+
+    fn adaptive_avg_pool1d_backward(
+        x: FloatTensor<Self, 3>,
+        grad: FloatTensor<Self, 3>,
+    ) -> FloatTensor<Self, 3> {
+        fallback!(
+            adaptive_avg_pool1d_backward(x: float, grad: float) -> tensor,
+            device = x.device.clone()
+        )
+    }
+
+    fn max_pool1d(
+        x: FloatTensor<Self, 3>,
+        kernel_size: usize,
+        stride: usize,
+        padding: usize,
+        dilation: usize,
+        ceil_mode: bool,
+    ) -> FloatTensor<Self, 3> {
+        fallback!(
+            max_pool1d(
+                x: float,
+                kernel_size: value,
+                stride: value,
+                padding: value,
+                dilation: value,
+                ceil_mode: value,
+            ) -> tensor,
+            device = x.device.clone()
+        )
+    }
+
+    fn max_pool2d(
+        x: FloatTensor<Self, 4>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        dilation: [usize; 2],
+        ceil_mode: bool,
+    ) -> FloatTensor<Self, 4> {
+        fallback!(
+            max_pool2d(
+                x: float,
+                kernel_size: value,
+                stride: value,
+                padding: value,
+                dilation: value,
+                ceil_mode: value,
+            ) -> tensor,
+            device = x.device.clone()
+        )
+    }
+
+    fn interpolate(
+        x: FloatTensor<Self, 4>,
+        output_size: [usize; 2],
+        options: InterpolateOptions,
+    ) -> FloatTensor<Self, 4> {
+        fallback!(
+            interpolate(x: float, output_size: value, options: value) -> tensor,
+            device = x.device.clone()
+        )
+    }
+
+    fn interpolate_backward(
+        x: FloatTensor<Self, 4>,
+        grad: FloatTensor<Self, 4>,
+        output_size: [usize; 2],
+        options: InterpolateOptions,
+    ) -> FloatTensor<Self, 4> {
+        fallback!(
+            interpolate_backward(
+                x: float,
+                grad: float,
+                output_size: value,
+                options: value,
+            ) -> tensor,
+            device = x.device.clone()
+        )
+    }
+
+    fn max_pool2d_with_indices(
+        x: FloatTensor<Self, 4>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        dilation: [usize; 2],
+        ceil_mode: bool,
+    ) -> MaxPool2dWithIndices<Self> {
+        let device = x.device.clone();
+
+        Self::run(
+            "max_pool2d_with_indices",
+            (x,),
+            |(x,)| {
+                let output = P::max_pool2d_with_indices(
+                    Self::float_primary(x),
+                    kernel_size,
+                    stride,
+                    padding,
+                    dilation,
+                    ceil_mode,
+                );
+                MaxPool2dWithIndices::new(
+                    FallbackTensor::primary(output.output, device.clone()),
+                    FallbackTensor::primary(output.indices, device.clone()),
+                )
+            },
+            |(x,)| {
+                let output = S::max_pool2d_with_indices(
+                    Self::float_secondary(x),
+                    kernel_size,
+                    stride,
+                    padding,
+                    dilation,
+                    ceil_mode,
+                );
+                MaxPool2dWithIndices::new(
+                    FallbackTensor::secondary(output.output, device.clone()),
+                    FallbackTensor::secondary(output.indices, device.clone()),
+                )
+            },
+        )
+    }
+
+    fn max_pool2d_with_indices_backward(
+        x: FloatTensor<Self, 4>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        dilation: [usize; 2],
+        ceil_mode: bool,
+        output_grad: FloatTensor<Self, 4>,
+        indices: IntTensor<Self, 4>,
+    ) -> MaxPool2dBackward<Self> {
+        let device = x.device.clone();
+
+        Self::run(
+            "max_pool2d_with_indices_backward",
+            (x, output_grad, indices),
+            |(x, output_grad, indices)| {
+                let output = P::max_pool2d_with_indices_backward(
+                    Self::float_primary(x),
+                    kernel_size,
+                    stride,
+                    padding,
+                    dilation,
+                    ceil_mode,
+                    Self::float_primary(output_grad),
+                    Self::int_primary(indices),
+                );
+                MaxPool2dBackward::new(FallbackTensor::primary(output.x_grad, device.clone()))
+            },
+            |(x, output_grad, indices)| {
+                let output = S::max_pool2d_with_indices_backward(
+                    Self::float_secondary(x),
+                    kernel_size,
+                    stride,
+                    padding,
+                    dilation,
+                    ceil_mode,
+                    Self::float_secondary(output_grad),
+                    Self::int_secondary(indices),
+                );
+                MaxPool2dBackward::new(FallbackTensor::secondary(output.x_grad, device.clone()))
+            },
+        )
+    }
+}
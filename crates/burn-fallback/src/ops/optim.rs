@@ -0,0 +1,7 @@
+use burn_tensor::backend::Backend;
+use burn_tensor::ops::OptimizerOps;
+
+use crate::Fallback;
+
+/// The fused optimizers are composed of the other operations, each falling back on its own.
+impl<P: Backend, S: Backend> OptimizerOps<Self> for Fallback<P, S> {}
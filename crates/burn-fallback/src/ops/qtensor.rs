@@ -0,0 +1,79 @@
+use core::future::Future;
+
+use burn_tensor::backend::Backend;
+use burn_tensor::ops::{Device, FloatTensor, QTensorOps, QuantizedTensor};
+use burn_tensor::quantization::{QuantizationParametersPrimitive, QuantizationScheme};
+use burn_tensor::{Shape, TensorData};
+
+use crate::{Fallback, FallbackPrimitive, FallbackTensor};
+
+impl<P: Backend, S: Backend> QTensorOps<Self> for Fallback<P, S> {
+    fn q_shape<const D: usize>(tensor: &QuantizedTensor<Self, D>) -> Shape<D> {
+        match &tensor.primitive {
+            FallbackPrimitive::Primary(tensor) => P::q_shape(tensor),
+            FallbackPrimitive::Secondary(tensor) => S::q_shape(tensor),
+        }
+    }
+
+    fn q_device<const D: usize>(tensor: &QuantizedTensor<Self, D>) -> Device<Self> {
+        tensor.device.clone()
+    }
+
+    fn q_into_data<const D: usize>(
+        tensor: QuantizedTensor<Self, D>,
+    ) -> impl Future<Output = TensorData> + Send {
+        async move {
+            match tensor.primitive {
+                FallbackPrimitive::Primary(tensor) => P::q_into_data(tensor).await,
+                FallbackPrimitive::Secondary(tensor) => S::q_into_data(tensor).await,
+            }
+        }
+    }
+
+    fn quantize<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        scheme: &QuantizationScheme,
+        qparams: QuantizationParametersPrimitive<Self>,
+    ) -> QuantizedTensor<Self, D> {
+        let device = tensor.device.clone();
+
+        Self::run(
+            "quantize",
+            (tensor, qparams.scale, qparams.offset),
+            |(tensor, scale, offset)| {
+                let qparams = QuantizationParametersPrimitive {
+                    scale: Self::float_primary(scale),
+                    offset: offset.map(Self::int_primary),
+                };
+                let output = P::quantize(Self::float_primary(tensor), scheme, qparams);
+                FallbackTensor::primary(output, device.clone())
+            },
+            |(tensor, scale, offset)| {
+                let qparams = QuantizationParametersPrimitive {
+                    scale: Self::float_secondary(scale),
+                    offset: offset.map(Self::int_secondary),
+                };
+                let output = S::quantize(Self::float_secondary(tensor), scheme, qparams);
+                FallbackTensor::secondary(output, device.clone())
+            },
+        )
+    }
+
+    fn q_from_data<const D: usize>(
+        data: TensorData,
+        device: &Device<Self>,
+    ) -> QuantizedTensor<Self, D> {
+        fallback!(q_from_data(data: value, device: device) -> tensor, device = device.clone())
+    }
+
+    fn dequantize<const D: usize>(tensor: QuantizedTensor<Self, D>) -> FloatTensor<Self, D> {
+        fallback!(dequantize(tensor: quant) -> tensor, device = tensor.device.clone())
+    }
+
+    fn q_reshape<const D1: usize, const D2: usize>(
+        tensor: QuantizedTensor<Self, D1>,
+        shape: Shape<D2>,
+    ) -> QuantizedTensor<Self, D2> {
+        fallback!(q_reshape(tensor: quant, shape: value) -> tensor, device = tensor.device.clone())
+    }
+}
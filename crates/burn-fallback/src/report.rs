@@ -0,0 +1,196 @@
+use std::any::{Any, TypeId};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Mutex;
+
+use burn_tensor::backend::Backend;
+
+use crate::Fallback;
+
+/// The fallbacks executed by a [fallback backend](Fallback) since the start of the program, or
+/// since the last [reset](Fallback::reset_report).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FallbackReport {
+    /// The number of executions of each operation on the secondary backend, by operation name.
+    pub fallbacks: BTreeMap<&'static str, usize>,
+    /// The number of tensors transferred between the backends.
+    pub transfers: usize,
+}
+
+impl FallbackReport {
+    /// The total number of operations executed on the secondary backend.
+    pub fn num_fallbacks(&self) -> usize {
+        self.fallbacks.values().sum()
+    }
+}
+
+impl core::fmt::Display for FallbackReport {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        writeln!(
+            f,
+            "{} fallbacks, {} transfers",
+            self.num_fallbacks(),
+            self.transfers
+        )?;
+
+        for (op, count) in self.fallbacks.iter() {
+            writeln!(f, "  {op}: {count}")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+struct State {
+    /// If the primary backend supports each operation, once it has been attempted.
+    supported: HashMap<&'static str, bool>,
+    report: FallbackReport,
+    #[cfg(test)]
+    crippled: Vec<&'static str>,
+}
+
+/// The state of each pair of backends.
+static STATES: Mutex<BTreeMap<(TypeId, TypeId), State>> = Mutex::new(BTreeMap::new());
+
+fn with_state<P: Backend, S: Backend, O>(func: impl FnOnce(&mut State) -> O) -> O {
+    let mut states = STATES.lock().unwrap_or_else(|err| err.into_inner());
+    let state = states
+        .entry((TypeId::of::<P>(), TypeId::of::<S>()))
+        .or_default();
+
+    func(state)
+}
+
+/// The messages of the panics of the operations that a backend doesn't implement, such as the
+/// ones of `todo!` and `unimplemented!`.
+const UNSUPPORTED_MARKERS: [&str; 6] = [
+    "not yet implemented",
+    "not implemented",
+    "not supported",
+    "unsupported",
+    "does not support",
+    "doesn't support",
+];
+
+/// If the panic payload signals that the operation isn't implemented, rather than a failure
+/// caused by the inputs.
+fn is_unsupported(payload: &(dyn Any + Send)) -> bool {
+    let message = match payload.downcast_ref::<&str>() {
+        Some(message) => message.to_lowercase(),
+        None => match payload.downcast_ref::<String>() {
+            Some(message) => message.to_lowercase(),
+            None => return false,
+        },
+    };
+
+    UNSUPPORTED_MARKERS
+        .iter()
+        .any(|marker| message.contains(marker))
+}
+
+impl<P: Backend, S: Backend> Fallback<P, S> {
+    /// Returns the fallbacks executed by this backend.
+    pub fn report() -> FallbackReport {
+        with_state::<P, S, _>(|state| state.report.clone())
+    }
+
+    /// Clear the [report](Self::report) of this backend.
+    ///
+    /// The operations already known to be unsupported are still executed on the secondary
+    /// backend.
+    pub fn reset_report() {
+        with_state::<P, S, _>(|state| state.report = FallbackReport::default());
+    }
+
+    /// Execute the operation on the primary backend, or on the secondary backend if the primary
+    /// backend doesn't support it.
+    ///
+    /// The inputs are only cloned until the support of the operation is known, so that the
+    /// secondary backend can be given them when the primary backend panics. The operation is only
+    /// marked as unsupported when the panic [says so](is_unsupported), other panics only fall
+    /// back for this execution. If the secondary backend panics too, its panic is propagated.
+    pub(crate) fn run<I: Clone, O>(
+        op: &'static str,
+        inputs: I,
+        primary: impl FnOnce(I) -> O,
+        secondary: impl FnOnce(I) -> O,
+    ) -> O {
+        let supported = with_state::<P, S, _>(|state| state.supported.get(op).copied());
+
+        match supported {
+            Some(true) => primary(inputs),
+            Some(false) => {
+                Self::record_fallback(op);
+                secondary(inputs)
+            }
+            None => {
+                let inputs_secondary = inputs.clone();
+                let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                    #[cfg(test)]
+                    if with_state::<P, S, _>(|state| state.crippled.contains(&op)) {
+                        unimplemented!("Operation {op} is crippled");
+                    }
+
+                    primary(inputs)
+                }));
+
+                match result {
+                    Ok(output) => {
+                        with_state::<P, S, _>(|state| {
+                            state.supported.insert(op, true);
+                        });
+                        output
+                    }
+                    Err(err) => {
+                        let unsupported = is_unsupported(err.as_ref());
+                        // When both backends panic, the inputs are most likely invalid, so the
+                        // support of the operation is still unknown.
+                        let output = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                            secondary(inputs_secondary)
+                        }))
+                        .unwrap_or_else(|err| std::panic::resume_unwind(err));
+
+                        if unsupported {
+                            log::warn!(
+                                "Operation {op} isn't supported by {}, executing it on {} instead",
+                                P::name(),
+                                S::name()
+                            );
+                            with_state::<P, S, _>(|state| {
+                                state.supported.insert(op, false);
+                            });
+                        } else {
+                            // Only these inputs may be the issue, the next execution is still
+                            // attempted on the primary backend.
+                            log::warn!(
+                                "Operation {op} failed on {}, executing this call on {} instead",
+                                P::name(),
+                                S::name()
+                            );
+                        }
+                        Self::record_fallback(op);
+                        output
+                    }
+                }
+            }
+        }
+    }
+
+    pub(crate) fn record_transfer() {
+        with_state::<P, S, _>(|state| state.report.transfers += 1);
+    }
+
+    fn record_fallback(op: &'static str) {
+        with_state::<P, S, _>(|state| *state.report.fallbacks.entry(op).or_default() += 1);
+    }
+
+    /// Make the primary backend panic on the given operations, forgetting the support found out
+    /// for all operations.
+    #[cfg(test)]
+    pub(crate) fn cripple(ops: &[&'static str]) {
+        with_state::<P, S, _>(|state| {
+            state.crippled = ops.to_vec();
+            state.supported.clear();
+        });
+    }
+}
@@ -0,0 +1,57 @@
+use burn_tensor::quantization::{QTensorPrimitive, QuantizationScheme, QuantizationStrategy};
+
+use crate::FallbackDevice;
+
+/// A tensor of the [fallback backend](crate::Fallback), with the device of each backend.
+///
+/// The result of an operation executed by the secondary backend stays on the secondary backend
+/// until it is used by an operation of the primary backend, so that the successive fallbacks
+/// don't transfer the tensors back and forth.
+#[derive(new, Clone, Debug)]
+pub struct FallbackTensor<TP, TS, DP, DS> {
+    /// The tensor of the backend holding it.
+    pub primitive: FallbackPrimitive<TP, TS>,
+    /// The devices of the tensor.
+    pub device: FallbackDevice<DP, DS>,
+}
+
+/// The tensor of either backend of a [fallback tensor](FallbackTensor).
+#[derive(Clone, Debug)]
+pub enum FallbackPrimitive<TP, TS> {
+    /// A tensor of the primary backend.
+    Primary(TP),
+    /// A tensor of the secondary backend.
+    Secondary(TS),
+}
+
+impl<TP, TS, DP, DS> FallbackTensor<TP, TS, DP, DS> {
+    /// Create a tensor of the primary backend.
+    pub fn primary(tensor: TP, device: FallbackDevice<DP, DS>) -> Self {
+        Self::new(FallbackPrimitive::Primary(tensor), device)
+    }
+
+    /// Create a tensor of the secondary backend.
+    pub fn secondary(tensor: TS, device: FallbackDevice<DP, DS>) -> Self {
+        Self::new(FallbackPrimitive::Secondary(tensor), device)
+    }
+}
+
+impl<TP, TS, DP, DS> QTensorPrimitive for FallbackTensor<TP, TS, DP, DS>
+where
+    TP: QTensorPrimitive,
+    TS: QTensorPrimitive,
+{
+    fn scheme(&self) -> &QuantizationScheme {
+        match &self.primitive {
+            FallbackPrimitive::Primary(tensor) => tensor.scheme(),
+            FallbackPrimitive::Secondary(tensor) => tensor.scheme(),
+        }
+    }
+
+    fn strategy(&self) -> QuantizationStrategy {
+        match &self.primitive {
+            FallbackPrimitive::Primary(tensor) => tensor.strategy(),
+            FallbackPrimitive::Secondary(tensor) => tensor.strategy(),
+        }
+    }
+}
@@ -0,0 +1,131 @@
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+
+use burn_ndarray::NdArray;
+use burn_tensor::{Tensor, TensorData};
+
+use crate::Fallback;
+
+/// The primary backend panics on the crippled operations, simulating unimplemented operations.
+type Crippled = Fallback<NdArray<f64>, NdArray<f32>>;
+type Reference = NdArray<f32>;
+
+/// The crippled operations and the report are shared by all the tests.
+static LOCK: Mutex<()> = Mutex::new(());
+
+fn fallbacks(ops: &[(&'static str, usize)]) -> BTreeMap<&'static str, usize> {
+    ops.iter().copied().collect()
+}
+
+#[test]
+fn should_execute_unsupported_ops_on_secondary() {
+    let _lock = LOCK.lock().unwrap_or_else(|err| err.into_inner());
+    Crippled::cripple(&["float_exp", "float_sum_dim"]);
+    Crippled::reset_report();
+    let data = TensorData::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+
+    let tensor = Tensor::<Crippled, 2>::from_data(data.clone(), &Default::default());
+    let output = tensor.clone().exp().sum_dim(1) + tensor;
+
+    let tensor = Tensor::<Reference, 2>::from_data(data, &Default::default());
+    let expected = tensor.clone().exp().sum_dim(1) + tensor;
+    output
+        .into_data()
+        .assert_approx_eq(&expected.into_data(), 3);
+
+    let report = Crippled::report();
+    assert_eq!(
+        report.fallbacks,
+        fallbacks(&[("float_exp", 1), ("float_sum_dim", 1)])
+    );
+    // The input of `exp` to the secondary backend, and the output of `sum_dim` back to the
+    // primary backend for the addition.
+    assert_eq!(report.transfers, 2);
+}
+
+#[test]
+fn should_keep_executing_unsupported_ops_on_secondary() {
+    let _lock = LOCK.lock().unwrap_or_else(|err| err.into_inner());
+    Crippled::cripple(&["float_exp"]);
+    Crippled::reset_report();
+    let data = TensorData::from([0.0, 1.0, 2.0]);
+
+    let tensor = Tensor::<Crippled, 1>::from_data(data.clone(), &Default::default());
+    let output = tensor.exp().exp();
+
+    let expected = Tensor::<Reference, 1>::from_data(data, &Default::default())
+        .exp()
+        .exp();
+    output
+        .into_data()
+        .assert_approx_eq(&expected.into_data(), 3);
+
+    let report = Crippled::report();
+    assert_eq!(report.fallbacks, fallbacks(&[("float_exp", 2)]));
+    assert_eq!(report.transfers, 1);
+}
+
+#[test]
+fn should_transfer_int_and_bool_tensors() {
+    let _lock = LOCK.lock().unwrap_or_else(|err| err.into_inner());
+    Crippled::cripple(&["float_argmax", "bool_not"]);
+    Crippled::reset_report();
+    let data = TensorData::from([[0.0, 5.0, 2.0], [3.0, 1.0, 4.0]]);
+
+    let tensor = Tensor::<Crippled, 2>::from_data(data.clone(), &Default::default());
+    let output = tensor.argmax(1).equal_elem(1).bool_not();
+
+    let tensor = Tensor::<Reference, 2>::from_data(data, &Default::default());
+    let expected = tensor.argmax(1).equal_elem(1).bool_not();
+    output.into_data().assert_eq(&expected.into_data(), true);
+
+    let report = Crippled::report();
+    assert_eq!(
+        report.fallbacks,
+        fallbacks(&[("bool_not", 1), ("float_argmax", 1)])
+    );
+    assert_eq!(report.transfers, 3);
+}
+
+#[test]
+fn should_not_fall_back_on_supported_ops() {
+    let _lock = LOCK.lock().unwrap_or_else(|err| err.into_inner());
+    Crippled::cripple(&[]);
+    Crippled::reset_report();
+
+    let tensor = Tensor::<Crippled, 1>::from_data([1.0, 2.0, 3.0], &Default::default());
+    let output = (tensor.clone() * tensor).sum();
+
+    output
+        .into_data()
+        .assert_eq(&TensorData::from([14.0]), false);
+    assert_eq!(Crippled::report(), Default::default());
+}
+
+#[test]
+fn should_only_fall_back_once_when_the_primary_panics_on_some_inputs() {
+    let _lock = LOCK.lock().unwrap_or_else(|err| err.into_inner());
+    Crippled::cripple(&[]);
+    Crippled::reset_report();
+    let run = |input: i64| {
+        Crippled::run(
+            "checked_op",
+            input,
+            |input| {
+                assert!(input >= 0, "The input can't be negative");
+                ("primary", input)
+            },
+            |input| ("secondary", input),
+        )
+    };
+
+    assert_eq!(run(-1), ("secondary", -1));
+    // The panic doesn't say the operation is unsupported, so the primary backend is tried again.
+    assert_eq!(run(1), ("primary", 1));
+    assert_eq!(run(2), ("primary", 2));
+
+    assert_eq!(
+        Crippled::report().fallbacks,
+        fallbacks(&[("checked_op", 1)])
+    );
+}
@@ -3,7 +3,7 @@ use crate::{
     QFusionTensor,
 };
 use burn_tensor::{
-    backend::{Backend, DeviceOps, SyncType},
+    backend::{Backend, DeviceOps, DeviceProperties, RngState, SyncType},
     ops::FloatTensor,
     repr::{OperationDescription, ReprBackend},
     Device,
@@ -48,6 +48,18 @@ impl<B: FusionBackend> Backend for Fusion<B> {
         B::seed(seed);
     }
 
+    fn seed_device(device: &Self::Device, seed: u64) {
+        B::seed_device(device, seed);
+    }
+
+    fn rng_state() -> Option<RngState> {
+        B::rng_state()
+    }
+
+    fn restore_rng_state(state: RngState) {
+        B::restore_rng_state(state);
+    }
+
     fn sync(device: &Self::Device, sync_type: SyncType) {
         let client = CLIENTS.client::<B::FusionRuntime>(&device.clone());
         client.drain();
@@ -57,6 +69,14 @@ impl<B: FusionBackend> Backend for Fusion<B> {
     fn ad_enabled() -> bool {
         false
     }
+
+    fn devices() -> Vec<Self::Device> {
+        B::devices()
+    }
+
+    fn device_properties(device: &Self::Device) -> DeviceProperties {
+        B::device_properties(device)
+    }
 }
 
 /// The status of a [builder](OptimizationBuilder).
@@ -77,7 +77,8 @@ pub(crate) fn binary_ops_shape(lhs: &[usize], rhs: &[usize]) -> Vec<usize> {
     let mut shape_out = Vec::with_capacity(lhs.len());
 
     for (l, r) in lhs.iter().zip(rhs.iter()) {
-        shape_out.push(usize::max(*l, *r));
+        // A broadcasted dimension takes the other size, even when it is empty.
+        shape_out.push(if *l == 1 { *r } else { *l });
     }
 
     shape_out
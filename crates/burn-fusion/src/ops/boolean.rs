@@ -9,13 +9,14 @@ use crate::{
     Fusion, FusionBackend,
 };
 use burn_tensor::{
-    ops::{BoolTensor, BoolTensorOps},
+    ops::{BoolTensor, BoolTensorOps, IntTensor},
     repr::{
-        BaseOperationDescription, BinaryOperationDescription, BoolOperationDescription,
-        CatOperationDescription, ExpandOperationDescription, FlipOperationDescription,
-        HandleContainer, OperationDescription, PermuteOperationDescription,
-        RepeatDimOperationDescription, ReshapeDescription, SliceAssignOperationDescription,
-        SliceOperationDescription, SwapDimsDescription, UnaryOperationDescription,
+        ArgwherePaddedDescription, BaseOperationDescription, BinaryOperationDescription,
+        BoolOperationDescription, CatOperationDescription, ExpandOperationDescription,
+        FlipOperationDescription, HandleContainer, OperationDescription,
+        PermuteOperationDescription, RepeatDimOperationDescription, ReshapeDescription,
+        ScalarOperationDescription, SliceAssignOperationDescription, SliceOperationDescription,
+        SwapDimsDescription, UnaryOperationDescription,
     },
     Device, Shape,
 };
@@ -615,4 +616,86 @@ impl<B: FusionBackend> BoolTensorOps<Self> for Fusion<B> {
 
         out
     }
+
+    fn bool_argwhere_padded<const D: usize>(
+        tensor: BoolTensor<Self, D>,
+        max_count: usize,
+    ) -> (IntTensor<Self, 2>, IntTensor<Self, 1>) {
+        #[derive(new)]
+        struct ArgwherePaddedOps<B: FusionBackend, const D: usize> {
+            desc: ArgwherePaddedDescription,
+            _b: PhantomData<B>,
+        }
+
+        impl<const D: usize, B: FusionBackend> Operation<B::FusionRuntime> for ArgwherePaddedOps<B, D> {
+            fn execute(self: Box<Self>, handles: &mut HandleContainer<B::Handle>) {
+                let input = handles.get_bool_tensor::<B, D>(&self.desc.input);
+                let (indices, count) = B::bool_argwhere_padded(input, self.desc.max_count);
+
+                handles.register_int_tensor::<B, 2>(&self.desc.out_indices.id, indices);
+                handles.register_int_tensor::<B, 1>(&self.desc.out_count.id, count);
+            }
+        }
+
+        let stream = tensor.stream;
+        let client = tensor.client.clone();
+        // The shapes of the outputs don't depend on the values, so the operation is queued like
+        // the others instead of reading the mask back.
+        let out_indices = client.tensor_uninitialized(vec![max_count, D], B::IntElem::dtype());
+        let out_count = client.tensor_uninitialized(vec![1], B::IntElem::dtype());
+
+        let desc = ArgwherePaddedDescription {
+            input: tensor.into_description(),
+            max_count,
+            out_indices: out_indices.to_description_out(),
+            out_count: out_count.to_description_out(),
+        };
+        client.register(
+            vec![stream],
+            OperationDescription::Bool(BoolOperationDescription::ArgwherePadded(desc.clone())),
+            ArgwherePaddedOps::<B, D>::new(desc),
+        );
+
+        (out_indices, out_count)
+    }
+
+    fn bool_count_nonzero<const D: usize>(
+        tensor: BoolTensor<Self, D>,
+        dim: usize,
+    ) -> IntTensor<Self, D> {
+        #[derive(new)]
+        struct CountNonzeroOps<B: FusionBackend, const D: usize> {
+            desc: ScalarOperationDescription<usize>,
+            _b: PhantomData<B>,
+        }
+
+        impl<const D: usize, B: FusionBackend> Operation<B::FusionRuntime> for CountNonzeroOps<B, D> {
+            fn execute(self: Box<Self>, handles: &mut HandleContainer<B::Handle>) {
+                let input = handles.get_bool_tensor::<B, D>(&self.desc.lhs);
+                let output = B::bool_count_nonzero(input, self.desc.rhs);
+
+                handles.register_int_tensor::<B, D>(&self.desc.out.id, output);
+            }
+        }
+
+        let stream = tensor.stream;
+        let mut shape = tensor.shape.clone();
+        shape[dim] = 1;
+        let out = tensor
+            .client
+            .tensor_uninitialized(shape, B::IntElem::dtype());
+
+        let desc = ScalarOperationDescription {
+            lhs: tensor.into_description(),
+            rhs: dim,
+            out: out.to_description_out(),
+        };
+        out.client.register(
+            vec![stream],
+            OperationDescription::Bool(BoolOperationDescription::CountNonzero(desc.clone())),
+            CountNonzeroOps::<B, D>::new(desc),
+        );
+
+        out
+    }
 }
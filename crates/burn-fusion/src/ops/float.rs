@@ -10,7 +10,7 @@ use crate::{
 use burn_tensor::{
     ops::{BoolTensor, FloatElem, FloatTensor, FloatTensorOps, IntTensor},
     repr::*,
-    DType, Device, Distribution, Element, ElementConversion, Shape, TensorData,
+    DType, Device, Distribution, Element, ElementConversion, PadDim, PadMode, Shape, TensorData,
 };
 use std::{marker::PhantomData, ops::Range};
 
@@ -561,6 +561,53 @@ impl<B: FusionBackend> FloatTensorOps<Self> for Fusion<B> {
         out
     }
 
+    fn float_spmm(
+        rows: IntTensor<Self, 1>,
+        cols: IntTensor<Self, 1>,
+        values: FloatTensor<Self, 1>,
+        dense: FloatTensor<Self, 2>,
+        num_rows: usize,
+    ) -> FloatTensor<Self, 2> {
+        #[derive(new)]
+        struct SpmmOps<B: FusionBackend> {
+            desc: SpmmDescription,
+            _b: PhantomData<B>,
+        }
+
+        impl<B: FusionBackend> Operation<B::FusionRuntime> for SpmmOps<B> {
+            fn execute(self: Box<Self>, handles: &mut HandleContainer<B::Handle>) {
+                let rows = handles.get_int_tensor::<B, 1>(&self.desc.rows);
+                let cols = handles.get_int_tensor::<B, 1>(&self.desc.cols);
+                let values = handles.get_float_tensor::<B, 1>(&self.desc.values);
+                let dense = handles.get_float_tensor::<B, 2>(&self.desc.dense);
+                let output = B::float_spmm(rows, cols, values, dense, self.desc.num_rows);
+
+                handles.register_float_tensor::<B, 2>(&self.desc.out.id, output);
+            }
+        }
+
+        let streams = vec![rows.stream, cols.stream, values.stream, dense.stream];
+        let out = dense
+            .client
+            .tensor_uninitialized(vec![num_rows, dense.shape[1]], B::FloatElem::dtype());
+
+        let desc = SpmmDescription {
+            rows: rows.into_description(),
+            cols: cols.into_description(),
+            values: values.into_description(),
+            dense: dense.into_description(),
+            num_rows,
+            out: out.to_description_out(),
+        };
+        out.client.register(
+            streams,
+            OperationDescription::Float(FloatOperationDescription::Spmm(desc.clone())),
+            SpmmOps::<B>::new(desc),
+        );
+
+        out
+    }
+
     fn float_swap_dims<const D: usize>(
         tensor: FloatTensor<Self, D>,
         dim1: usize,
@@ -953,7 +1000,8 @@ impl<B: FusionBackend> FloatTensorOps<Self> for Fusion<B> {
         let stream_1 = tensor.stream;
         let stream_2 = mask.stream;
         let stream_3 = value.stream;
-        let shape: Vec<usize> = tensor.shape.clone();
+        let shape: Vec<usize> =
+            binary_ops_shape(&binary_ops_shape(&tensor.shape, &mask.shape), &value.shape);
         let out = tensor
             .client
             .tensor_uninitialized(shape, B::FloatElem::dtype());
@@ -1617,6 +1665,27 @@ impl<B: FusionBackend> FloatTensorOps<Self> for Fusion<B> {
         out
     }
 
+    fn float_erfinv<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        unary_float_ops!(ErfinvOps, B::float_erfinv);
+
+        let stream = tensor.stream;
+        let out = tensor
+            .client
+            .tensor_uninitialized(tensor.shape.clone(), B::FloatElem::dtype());
+
+        let desc = UnaryOperationDescription {
+            input: tensor.into_description(),
+            out: out.to_description_out(),
+        };
+        out.client.register(
+            vec![stream],
+            OperationDescription::Float(FloatOperationDescription::Erfinv(desc.clone())),
+            ErfinvOps::<B, D>::new(desc),
+        );
+
+        out
+    }
+
     fn float_cat<const D: usize>(
         tensors: Vec<FloatTensor<Self, D>>,
         dim: usize,
@@ -1696,6 +1765,119 @@ impl<B: FusionBackend> FloatTensorOps<Self> for Fusion<B> {
         out
     }
 
+    fn float_count_nonzero<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> IntTensor<Self, D> {
+        scalar_float2int_ops!(CountNonzeroOps, B::float_count_nonzero, usize);
+
+        let stream = tensor.stream;
+        let mut shape = tensor.shape.clone();
+        shape[dim] = 1;
+        let out = tensor
+            .client
+            .tensor_uninitialized(shape, B::IntElem::dtype());
+
+        let desc = ScalarOperationDescription {
+            lhs: tensor.into_description(),
+            rhs: dim,
+            out: out.to_description_out(),
+        };
+        out.client.register(
+            vec![stream],
+            OperationDescription::NumericFloat(NumericOperationDescription::CountNonzero(
+                desc.clone(),
+            )),
+            CountNonzeroOps::<B, D>::new(desc),
+        );
+
+        out
+    }
+
+    fn float_is_close<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+        rtol: f64,
+        atol: f64,
+    ) -> BoolTensor<Self, D> {
+        #[derive(new)]
+        struct IsCloseOps<B: FusionBackend, const D: usize> {
+            desc: CloseOperationDescription,
+            _b: PhantomData<B>,
+        }
+
+        impl<const D: usize, B: FusionBackend> Operation<B::FusionRuntime> for IsCloseOps<B, D> {
+            fn execute(self: Box<Self>, handles: &mut HandleContainer<B::Handle>) {
+                let lhs = handles.get_float_tensor::<B, D>(&self.desc.lhs);
+                let rhs = handles.get_float_tensor::<B, D>(&self.desc.rhs);
+                let output = B::float_is_close(lhs, rhs, self.desc.rtol, self.desc.atol);
+
+                handles.register_bool_tensor::<B, D>(&self.desc.out.id, output);
+            }
+        }
+
+        let streams = vec![lhs.stream, rhs.stream];
+        let out = lhs
+            .client
+            .tensor_uninitialized(binary_ops_shape(&lhs.shape, &rhs.shape), DType::Bool);
+
+        let desc = CloseOperationDescription {
+            lhs: lhs.into_description(),
+            rhs: rhs.into_description(),
+            rtol,
+            atol,
+            out: out.to_description_out(),
+        };
+        out.client.register(
+            streams,
+            OperationDescription::NumericFloat(NumericOperationDescription::IsClose(desc.clone())),
+            IsCloseOps::<B, D>::new(desc),
+        );
+
+        out
+    }
+
+    fn float_all_close<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+        rtol: f64,
+        atol: f64,
+    ) -> BoolTensor<Self, 1> {
+        #[derive(new)]
+        struct AllCloseOps<B: FusionBackend, const D: usize> {
+            desc: CloseOperationDescription,
+            _b: PhantomData<B>,
+        }
+
+        impl<const D: usize, B: FusionBackend> Operation<B::FusionRuntime> for AllCloseOps<B, D> {
+            fn execute(self: Box<Self>, handles: &mut HandleContainer<B::Handle>) {
+                let lhs = handles.get_float_tensor::<B, D>(&self.desc.lhs);
+                let rhs = handles.get_float_tensor::<B, D>(&self.desc.rhs);
+                let output = B::float_all_close(lhs, rhs, self.desc.rtol, self.desc.atol);
+
+                handles.register_bool_tensor::<B, 1>(&self.desc.out.id, output);
+            }
+        }
+
+        let streams = vec![lhs.stream, rhs.stream];
+        let out = lhs.client.tensor_uninitialized(vec![1], DType::Bool);
+
+        let desc = CloseOperationDescription {
+            lhs: lhs.into_description(),
+            rhs: rhs.into_description(),
+            rtol,
+            atol,
+            out: out.to_description_out(),
+        };
+        out.client.register(
+            streams,
+            OperationDescription::NumericFloat(NumericOperationDescription::AllClose(desc.clone())),
+            AllCloseOps::<B, D>::new(desc),
+        );
+
+        out
+    }
+
     fn float_repeat_dim<const D: usize>(
         tensor: FloatTensor<Self, D>,
         dim: usize,
@@ -2100,4 +2282,59 @@ impl<B: FusionBackend> FloatTensorOps<Self> for Fusion<B> {
 
         out
     }
+
+    fn float_pad<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        padding: [(isize, isize); D],
+        mode: PadMode<FloatElem<Self>>,
+    ) -> FloatTensor<Self, D> {
+        #[derive(new)]
+        struct PadOps<B: FusionBackend, const D: usize> {
+            desc: PadOperationDescription<f32>,
+            _b: PhantomData<B>,
+        }
+
+        impl<const D: usize, B: FusionBackend> Operation<B::FusionRuntime> for PadOps<B, D> {
+            fn execute(self: Box<Self>, handles: &mut HandleContainer<B::Handle>) {
+                let input = handles.get_float_tensor::<B, D>(&self.desc.tensor);
+                let mode = match self.desc.mode {
+                    PadMode::Constant(value) => PadMode::Constant(value.elem()),
+                    PadMode::Reflect => PadMode::Reflect,
+                    PadMode::Edge => PadMode::Edge,
+                };
+                let output = B::float_pad(input, self.desc.padding.try_into().unwrap(), mode);
+
+                handles.register_float_tensor::<B, D>(&self.desc.out.id, output);
+            }
+        }
+
+        let stream = tensor.stream;
+        let shape = tensor
+            .shape
+            .iter()
+            .zip(padding.iter())
+            .map(|(size, padding)| PadDim::new(*padding).padded_size(*size))
+            .collect();
+        let out = tensor
+            .client
+            .tensor_uninitialized(shape, B::FloatElem::dtype());
+
+        let desc = PadOperationDescription {
+            tensor: tensor.into_description(),
+            padding: padding.to_vec(),
+            mode: match mode {
+                PadMode::Constant(value) => PadMode::Constant(value.elem()),
+                PadMode::Reflect => PadMode::Reflect,
+                PadMode::Edge => PadMode::Edge,
+            },
+            out: out.to_description_out(),
+        };
+        out.client.register(
+            vec![stream],
+            OperationDescription::NumericFloat(NumericOperationDescription::Pad(desc.clone())),
+            PadOps::<B, D>::new(desc),
+        );
+
+        out
+    }
 }
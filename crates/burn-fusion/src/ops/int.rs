@@ -10,7 +10,7 @@ use crate::{
 use burn_tensor::{
     ops::{BoolTensor, FloatTensor, IntElem, IntTensor, IntTensorOps},
     repr::{self, *},
-    DType, Device, Distribution, Element, ElementConversion, Shape, TensorData,
+    DType, Device, Distribution, Element, ElementConversion, PadDim, PadMode, Shape, TensorData,
 };
 use core::ops::Range;
 use std::marker::PhantomData;
@@ -242,7 +242,8 @@ impl<B: FusionBackend> IntTensorOps<Self> for Fusion<B> {
         let stream_1 = tensor.stream;
         let stream_2 = mask.stream;
         let stream_3 = value.stream;
-        let shape: Vec<usize> = tensor.shape.clone();
+        let shape: Vec<usize> =
+            binary_ops_shape(&binary_ops_shape(&tensor.shape, &mask.shape), &value.shape);
         let out = tensor
             .client
             .tensor_uninitialized(shape, B::IntElem::dtype());
@@ -1236,6 +1237,119 @@ impl<B: FusionBackend> IntTensorOps<Self> for Fusion<B> {
         out
     }
 
+    fn int_count_nonzero<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim: usize,
+    ) -> IntTensor<Self, D> {
+        scalar_int_ops!(CountNonzeroOps, B::int_count_nonzero, usize, noconvert);
+
+        let stream = tensor.stream;
+        let mut shape = tensor.shape.clone();
+        shape[dim] = 1;
+        let out = tensor
+            .client
+            .tensor_uninitialized(shape, B::IntElem::dtype());
+
+        let desc = ScalarOperationDescription {
+            lhs: tensor.into_description(),
+            rhs: dim,
+            out: out.to_description_out(),
+        };
+        out.client.register(
+            vec![stream],
+            OperationDescription::NumericInt(NumericOperationDescription::CountNonzero(
+                desc.clone(),
+            )),
+            CountNonzeroOps::<B, D>::new(desc),
+        );
+
+        out
+    }
+
+    fn int_is_close<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+        rtol: f64,
+        atol: f64,
+    ) -> BoolTensor<Self, D> {
+        #[derive(new)]
+        struct IsCloseOps<B: FusionBackend, const D: usize> {
+            desc: CloseOperationDescription,
+            _b: PhantomData<B>,
+        }
+
+        impl<const D: usize, B: FusionBackend> Operation<B::FusionRuntime> for IsCloseOps<B, D> {
+            fn execute(self: Box<Self>, handles: &mut HandleContainer<B::Handle>) {
+                let lhs = handles.get_int_tensor::<B, D>(&self.desc.lhs);
+                let rhs = handles.get_int_tensor::<B, D>(&self.desc.rhs);
+                let output = B::int_is_close(lhs, rhs, self.desc.rtol, self.desc.atol);
+
+                handles.register_bool_tensor::<B, D>(&self.desc.out.id, output);
+            }
+        }
+
+        let streams = vec![lhs.stream, rhs.stream];
+        let out = lhs
+            .client
+            .tensor_uninitialized(binary_ops_shape(&lhs.shape, &rhs.shape), DType::Bool);
+
+        let desc = CloseOperationDescription {
+            lhs: lhs.into_description(),
+            rhs: rhs.into_description(),
+            rtol,
+            atol,
+            out: out.to_description_out(),
+        };
+        out.client.register(
+            streams,
+            OperationDescription::NumericInt(NumericOperationDescription::IsClose(desc.clone())),
+            IsCloseOps::<B, D>::new(desc),
+        );
+
+        out
+    }
+
+    fn int_all_close<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+        rtol: f64,
+        atol: f64,
+    ) -> BoolTensor<Self, 1> {
+        #[derive(new)]
+        struct AllCloseOps<B: FusionBackend, const D: usize> {
+            desc: CloseOperationDescription,
+            _b: PhantomData<B>,
+        }
+
+        impl<const D: usize, B: FusionBackend> Operation<B::FusionRuntime> for AllCloseOps<B, D> {
+            fn execute(self: Box<Self>, handles: &mut HandleContainer<B::Handle>) {
+                let lhs = handles.get_int_tensor::<B, D>(&self.desc.lhs);
+                let rhs = handles.get_int_tensor::<B, D>(&self.desc.rhs);
+                let output = B::int_all_close(lhs, rhs, self.desc.rtol, self.desc.atol);
+
+                handles.register_bool_tensor::<B, 1>(&self.desc.out.id, output);
+            }
+        }
+
+        let streams = vec![lhs.stream, rhs.stream];
+        let out = lhs.client.tensor_uninitialized(vec![1], DType::Bool);
+
+        let desc = CloseOperationDescription {
+            lhs: lhs.into_description(),
+            rhs: rhs.into_description(),
+            rtol,
+            atol,
+            out: out.to_description_out(),
+        };
+        out.client.register(
+            streams,
+            OperationDescription::NumericInt(NumericOperationDescription::AllClose(desc.clone())),
+            AllCloseOps::<B, D>::new(desc),
+        );
+
+        out
+    }
+
     fn int_argmax<const D: usize>(tensor: IntTensor<Self, D>, dim: usize) -> IntTensor<Self, D> {
         scalar_int_ops!(ArgMaxOps, B::int_argmax, usize, noconvert);
 
@@ -1797,4 +1911,352 @@ impl<B: FusionBackend> IntTensorOps<Self> for Fusion<B> {
 
         out
     }
+
+    fn int_pad<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        padding: [(isize, isize); D],
+        mode: PadMode<IntElem<Self>>,
+    ) -> IntTensor<Self, D> {
+        #[derive(new)]
+        struct PadOps<B: FusionBackend, const D: usize> {
+            desc: PadOperationDescription<i32>,
+            _b: PhantomData<B>,
+        }
+
+        impl<const D: usize, B: FusionBackend> Operation<B::FusionRuntime> for PadOps<B, D> {
+            fn execute(self: Box<Self>, handles: &mut HandleContainer<B::Handle>) {
+                let input = handles.get_int_tensor::<B, D>(&self.desc.tensor);
+                let mode = match self.desc.mode {
+                    PadMode::Constant(value) => PadMode::Constant(value.elem()),
+                    PadMode::Reflect => PadMode::Reflect,
+                    PadMode::Edge => PadMode::Edge,
+                };
+                let output = B::int_pad(input, self.desc.padding.try_into().unwrap(), mode);
+
+                handles.register_int_tensor::<B, D>(&self.desc.out.id, output);
+            }
+        }
+
+        let stream = tensor.stream;
+        let shape = tensor
+            .shape
+            .iter()
+            .zip(padding.iter())
+            .map(|(size, padding)| PadDim::new(*padding).padded_size(*size))
+            .collect();
+        let out = tensor
+            .client
+            .tensor_uninitialized(shape, B::IntElem::dtype());
+
+        let desc = PadOperationDescription {
+            tensor: tensor.into_description(),
+            padding: padding.to_vec(),
+            mode: match mode {
+                PadMode::Constant(value) => PadMode::Constant(value.elem()),
+                PadMode::Reflect => PadMode::Reflect,
+                PadMode::Edge => PadMode::Edge,
+            },
+            out: out.to_description_out(),
+        };
+        out.client.register(
+            vec![stream],
+            OperationDescription::NumericInt(NumericOperationDescription::Pad(desc.clone())),
+            PadOps::<B, D>::new(desc),
+        );
+
+        out
+    }
+
+    fn int_bitwise_and<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        binary_int_ops!(BitwiseAndOps, B::int_bitwise_and);
+
+        let stream_1 = lhs.stream;
+        let stream_2 = rhs.stream;
+        let out = lhs.client.tensor_uninitialized(
+            binary_ops_shape(&lhs.shape, &rhs.shape),
+            B::IntElem::dtype(),
+        );
+
+        let desc = BinaryOperationDescription {
+            lhs: lhs.into_description(),
+            rhs: rhs.into_description(),
+            out: out.to_description_out(),
+        };
+        out.client.register(
+            vec![stream_1, stream_2],
+            OperationDescription::Int(repr::IntOperationDescription::BitwiseAnd(desc.clone())),
+            BitwiseAndOps::<B, D>::new(desc),
+        );
+
+        out
+    }
+
+    fn int_bitwise_and_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        scalar_int_ops!(BitwiseAndOps, B::int_bitwise_and_scalar);
+
+        let stream = lhs.stream;
+        let out = lhs
+            .client
+            .tensor_uninitialized(lhs.shape.clone(), B::IntElem::dtype());
+
+        let desc = ScalarOperationDescription {
+            lhs: lhs.into_description(),
+            rhs: rhs.elem(),
+            out: out.to_description_out(),
+        };
+        out.client.register(
+            vec![stream],
+            OperationDescription::Int(repr::IntOperationDescription::BitwiseAndScalar(
+                desc.clone(),
+            )),
+            BitwiseAndOps::<B, D>::new(desc),
+        );
+
+        out
+    }
+
+    fn int_bitwise_or<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        binary_int_ops!(BitwiseOrOps, B::int_bitwise_or);
+
+        let stream_1 = lhs.stream;
+        let stream_2 = rhs.stream;
+        let out = lhs.client.tensor_uninitialized(
+            binary_ops_shape(&lhs.shape, &rhs.shape),
+            B::IntElem::dtype(),
+        );
+
+        let desc = BinaryOperationDescription {
+            lhs: lhs.into_description(),
+            rhs: rhs.into_description(),
+            out: out.to_description_out(),
+        };
+        out.client.register(
+            vec![stream_1, stream_2],
+            OperationDescription::Int(repr::IntOperationDescription::BitwiseOr(desc.clone())),
+            BitwiseOrOps::<B, D>::new(desc),
+        );
+
+        out
+    }
+
+    fn int_bitwise_or_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        scalar_int_ops!(BitwiseOrOps, B::int_bitwise_or_scalar);
+
+        let stream = lhs.stream;
+        let out = lhs
+            .client
+            .tensor_uninitialized(lhs.shape.clone(), B::IntElem::dtype());
+
+        let desc = ScalarOperationDescription {
+            lhs: lhs.into_description(),
+            rhs: rhs.elem(),
+            out: out.to_description_out(),
+        };
+        out.client.register(
+            vec![stream],
+            OperationDescription::Int(repr::IntOperationDescription::BitwiseOrScalar(desc.clone())),
+            BitwiseOrOps::<B, D>::new(desc),
+        );
+
+        out
+    }
+
+    fn int_bitwise_xor<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        binary_int_ops!(BitwiseXorOps, B::int_bitwise_xor);
+
+        let stream_1 = lhs.stream;
+        let stream_2 = rhs.stream;
+        let out = lhs.client.tensor_uninitialized(
+            binary_ops_shape(&lhs.shape, &rhs.shape),
+            B::IntElem::dtype(),
+        );
+
+        let desc = BinaryOperationDescription {
+            lhs: lhs.into_description(),
+            rhs: rhs.into_description(),
+            out: out.to_description_out(),
+        };
+        out.client.register(
+            vec![stream_1, stream_2],
+            OperationDescription::Int(repr::IntOperationDescription::BitwiseXor(desc.clone())),
+            BitwiseXorOps::<B, D>::new(desc),
+        );
+
+        out
+    }
+
+    fn int_bitwise_xor_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        scalar_int_ops!(BitwiseXorOps, B::int_bitwise_xor_scalar);
+
+        let stream = lhs.stream;
+        let out = lhs
+            .client
+            .tensor_uninitialized(lhs.shape.clone(), B::IntElem::dtype());
+
+        let desc = ScalarOperationDescription {
+            lhs: lhs.into_description(),
+            rhs: rhs.elem(),
+            out: out.to_description_out(),
+        };
+        out.client.register(
+            vec![stream],
+            OperationDescription::Int(repr::IntOperationDescription::BitwiseXorScalar(
+                desc.clone(),
+            )),
+            BitwiseXorOps::<B, D>::new(desc),
+        );
+
+        out
+    }
+
+    fn int_bitwise_left_shift<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        binary_int_ops!(BitwiseLeftShiftOps, B::int_bitwise_left_shift);
+
+        let stream_1 = lhs.stream;
+        let stream_2 = rhs.stream;
+        let out = lhs.client.tensor_uninitialized(
+            binary_ops_shape(&lhs.shape, &rhs.shape),
+            B::IntElem::dtype(),
+        );
+
+        let desc = BinaryOperationDescription {
+            lhs: lhs.into_description(),
+            rhs: rhs.into_description(),
+            out: out.to_description_out(),
+        };
+        out.client.register(
+            vec![stream_1, stream_2],
+            OperationDescription::Int(repr::IntOperationDescription::BitwiseLeftShift(
+                desc.clone(),
+            )),
+            BitwiseLeftShiftOps::<B, D>::new(desc),
+        );
+
+        out
+    }
+
+    fn int_bitwise_left_shift_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        scalar_int_ops!(BitwiseLeftShiftOps, B::int_bitwise_left_shift_scalar);
+
+        let stream = lhs.stream;
+        let out = lhs
+            .client
+            .tensor_uninitialized(lhs.shape.clone(), B::IntElem::dtype());
+
+        let desc = ScalarOperationDescription {
+            lhs: lhs.into_description(),
+            rhs: rhs.elem(),
+            out: out.to_description_out(),
+        };
+        out.client.register(
+            vec![stream],
+            OperationDescription::Int(repr::IntOperationDescription::BitwiseLeftShiftScalar(
+                desc.clone(),
+            )),
+            BitwiseLeftShiftOps::<B, D>::new(desc),
+        );
+
+        out
+    }
+
+    fn int_bitwise_right_shift<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        binary_int_ops!(BitwiseRightShiftOps, B::int_bitwise_right_shift);
+
+        let stream_1 = lhs.stream;
+        let stream_2 = rhs.stream;
+        let out = lhs.client.tensor_uninitialized(
+            binary_ops_shape(&lhs.shape, &rhs.shape),
+            B::IntElem::dtype(),
+        );
+
+        let desc = BinaryOperationDescription {
+            lhs: lhs.into_description(),
+            rhs: rhs.into_description(),
+            out: out.to_description_out(),
+        };
+        out.client.register(
+            vec![stream_1, stream_2],
+            OperationDescription::Int(repr::IntOperationDescription::BitwiseRightShift(
+                desc.clone(),
+            )),
+            BitwiseRightShiftOps::<B, D>::new(desc),
+        );
+
+        out
+    }
+
+    fn int_bitwise_right_shift_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        scalar_int_ops!(BitwiseRightShiftOps, B::int_bitwise_right_shift_scalar);
+
+        let stream = lhs.stream;
+        let out = lhs
+            .client
+            .tensor_uninitialized(lhs.shape.clone(), B::IntElem::dtype());
+
+        let desc = ScalarOperationDescription {
+            lhs: lhs.into_description(),
+            rhs: rhs.elem(),
+            out: out.to_description_out(),
+        };
+        out.client.register(
+            vec![stream],
+            OperationDescription::Int(repr::IntOperationDescription::BitwiseRightShiftScalar(
+                desc.clone(),
+            )),
+            BitwiseRightShiftOps::<B, D>::new(desc),
+        );
+
+        out
+    }
+
+    fn int_bitwise_not<const D: usize>(tensor: IntTensor<Self, D>) -> IntTensor<Self, D> {
+        unary_int_ops!(BitwiseNotOps, B::int_bitwise_not);
+
+        let stream = tensor.stream;
+        let out = tensor
+            .client
+            .tensor_uninitialized(tensor.shape.clone(), B::IntElem::dtype());
+
+        let desc = UnaryOperationDescription {
+            input: tensor.into_description(),
+            out: out.to_description_out(),
+        };
+        out.client.register(
+            vec![stream],
+            OperationDescription::Int(repr::IntOperationDescription::BitwiseNot(desc.clone())),
+            BitwiseNotOps::<B, D>::new(desc),
+        );
+
+        out
+    }
 }
@@ -4,5 +4,6 @@ mod boolean;
 mod float;
 mod int;
 mod module;
+mod optim;
 mod qtensor;
 mod unary;
@@ -32,6 +32,41 @@ macro_rules! make_ops {
 }
 
 impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
+    fn dropout<const D: usize>(x: FloatTensor<Self, D>, prob: f64) -> FloatTensor<Self, D> {
+        #[derive(new)]
+        struct DropoutOps<B: FusionBackend, const D: usize> {
+            desc: DropoutDescription,
+            _b: PhantomData<B>,
+        }
+
+        impl<const D: usize, B: FusionBackend> Operation<B::FusionRuntime> for DropoutOps<B, D> {
+            fn execute(self: Box<Self>, handles: &mut HandleContainer<B::Handle>) {
+                let x = handles.get_float_tensor::<B, D>(&self.desc.x);
+                let output = B::dropout(x, self.desc.prob);
+
+                handles.register_float_tensor::<B, D>(&self.desc.out.id, output);
+            }
+        }
+
+        let stream = x.stream;
+        let out = x
+            .client
+            .tensor_uninitialized(x.shape.clone(), B::FloatElem::dtype());
+
+        let desc = DropoutDescription {
+            x: x.into_description(),
+            prob,
+            out: out.to_description_out(),
+        };
+        out.client.register(
+            vec![stream],
+            OperationDescription::Module(ModuleOperationDescription::Dropout(desc.clone())),
+            DropoutOps::<B, D>::new(desc),
+        );
+
+        out
+    }
+
     fn conv1d(
         x: FloatTensor<Self, 3>,
         weight: FloatTensor<Self, 3>,
@@ -438,6 +473,7 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
         stride: usize,
         padding: usize,
         count_include_pad: bool,
+        ceil_mode: bool,
     ) -> FloatTensor<Self, 3> {
         make_ops!(
             AvgPool1dOps,
@@ -450,6 +486,7 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
                     args.stride,
                     args.padding,
                     args.count_include_pad,
+                    args.ceil_mode,
                 );
 
                 handles.register_float_tensor::<B, 3>(&args.out.id, output);
@@ -457,7 +494,8 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
         );
 
         let stream = x.stream;
-        let size = calculate_pool_output_size(kernel_size, stride, padding, 1, x.shape[2]);
+        let size =
+            calculate_pool_output_size(kernel_size, stride, padding, 1, ceil_mode, x.shape[2]);
         let shape = vec![x.shape[0], x.shape[1], size];
         let out = x.client.tensor_uninitialized(shape, B::FloatElem::dtype());
 
@@ -467,6 +505,7 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
             stride,
             padding,
             count_include_pad,
+            ceil_mode,
             out: out.to_description_out(),
         };
         out.client.register(
@@ -484,6 +523,7 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
         stride: [usize; 2],
         padding: [usize; 2],
         count_include_pad: bool,
+        ceil_mode: bool,
     ) -> FloatTensor<Self, 4> {
         make_ops!(
             AvgPool2dOps,
@@ -496,16 +536,29 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
                     args.stride,
                     args.padding,
                     args.count_include_pad,
+                    args.ceil_mode,
                 );
 
                 handles.register_float_tensor::<B, 4>(&args.out.id, output);
             }
         );
 
-        let size_0 =
-            calculate_pool_output_size(kernel_size[0], stride[0], padding[0], 1, x.shape[2]);
-        let size_1 =
-            calculate_pool_output_size(kernel_size[1], stride[1], padding[1], 1, x.shape[3]);
+        let size_0 = calculate_pool_output_size(
+            kernel_size[0],
+            stride[0],
+            padding[0],
+            1,
+            ceil_mode,
+            x.shape[2],
+        );
+        let size_1 = calculate_pool_output_size(
+            kernel_size[1],
+            stride[1],
+            padding[1],
+            1,
+            ceil_mode,
+            x.shape[3],
+        );
 
         let stream = x.stream;
         let shape = vec![x.shape[0], x.shape[1], size_0, size_1];
@@ -517,6 +570,7 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
             stride,
             padding,
             count_include_pad,
+            ceil_mode,
             out: out.to_description_out(),
         };
         out.client.register(
@@ -535,6 +589,7 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
         stride: usize,
         padding: usize,
         count_include_pad: bool,
+        ceil_mode: bool,
     ) -> FloatTensor<Self, 3> {
         make_ops!(
             AvgPool1dBackwardOps,
@@ -549,6 +604,7 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
                     args.stride,
                     args.padding,
                     args.count_include_pad,
+                    args.ceil_mode,
                 );
 
                 handles.register_float_tensor::<B, 3>(&args.out.id, output);
@@ -568,6 +624,7 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
             stride,
             padding,
             count_include_pad,
+            ceil_mode,
             out: out.to_description_out(),
         };
         out.client.register(
@@ -588,6 +645,7 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
         stride: [usize; 2],
         padding: [usize; 2],
         count_include_pad: bool,
+        ceil_mode: bool,
     ) -> FloatTensor<Self, 4> {
         make_ops!(
             AvgPool2dBackwardOps,
@@ -602,6 +660,7 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
                     args.stride,
                     args.padding,
                     args.count_include_pad,
+                    args.ceil_mode,
                 );
 
                 handles.register_float_tensor::<B, 4>(&args.out.id, output);
@@ -621,6 +680,7 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
             stride,
             padding,
             count_include_pad,
+            ceil_mode,
             out: out.to_description_out(),
         };
         out.client.register(
@@ -640,6 +700,7 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
         stride: usize,
         padding: usize,
         dilation: usize,
+        ceil_mode: bool,
     ) -> FloatTensor<Self, 3> {
         make_ops!(
             MaxPool1dOps,
@@ -652,13 +713,21 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
                     args.stride,
                     args.padding,
                     args.dilation,
+                    args.ceil_mode,
                 );
 
                 handles.register_float_tensor::<B, 3>(&args.out.id, output);
             }
         );
 
-        let size = calculate_pool_output_size(kernel_size, stride, padding, dilation, x.shape[2]);
+        let size = calculate_pool_output_size(
+            kernel_size,
+            stride,
+            padding,
+            dilation,
+            ceil_mode,
+            x.shape[2],
+        );
 
         let stream = x.stream;
         let shape = vec![x.shape[0], x.shape[1], size];
@@ -670,6 +739,7 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
             stride,
             padding,
             dilation,
+            ceil_mode,
             out: out.to_description_out(),
         };
         out.client.register(
@@ -687,6 +757,7 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
         stride: [usize; 2],
         padding: [usize; 2],
         dilation: [usize; 2],
+        ceil_mode: bool,
     ) -> FloatTensor<Self, 4> {
         make_ops!(
             MaxPool2dOps,
@@ -699,6 +770,7 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
                     args.stride,
                     args.padding,
                     args.dilation,
+                    args.ceil_mode,
                 );
 
                 handles.register_float_tensor::<B, 4>(&args.out.id, output);
@@ -710,6 +782,7 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
             stride[0],
             padding[0],
             dilation[0],
+            ceil_mode,
             x.shape[2],
         );
         let size_1 = calculate_pool_output_size(
@@ -717,6 +790,7 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
             stride[1],
             padding[1],
             dilation[1],
+            ceil_mode,
             x.shape[3],
         );
 
@@ -730,6 +804,7 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
             stride,
             padding,
             dilation,
+            ceil_mode,
             out: out.to_description_out(),
         };
         out.client.register(
@@ -747,6 +822,7 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
         stride: usize,
         padding: usize,
         dilation: usize,
+        ceil_mode: bool,
     ) -> MaxPool1dWithIndices<Self> {
         make_ops!(
             MaxPool1dWithIndicesOps,
@@ -759,6 +835,7 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
                     args.stride,
                     args.padding,
                     args.dilation,
+                    args.ceil_mode,
                 );
 
                 handles.register_float_tensor::<B, 3>(&args.out.id, output.output);
@@ -767,7 +844,14 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
         );
 
         let stream = x.stream;
-        let size = calculate_pool_output_size(kernel_size, stride, padding, dilation, x.shape[2]);
+        let size = calculate_pool_output_size(
+            kernel_size,
+            stride,
+            padding,
+            dilation,
+            ceil_mode,
+            x.shape[2],
+        );
         let shape = vec![x.shape[0], x.shape[1], size];
         let out = x
             .client
@@ -780,6 +864,7 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
             stride,
             padding,
             dilation,
+            ceil_mode,
             out: out.to_description_out(),
             out_indices: out_indices.to_description_out(),
         };
@@ -800,6 +885,7 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
         stride: [usize; 2],
         padding: [usize; 2],
         dilation: [usize; 2],
+        ceil_mode: bool,
     ) -> MaxPool2dWithIndices<Self> {
         make_ops!(
             MaxPool2dWithIndicesOps,
@@ -812,6 +898,7 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
                     args.stride,
                     args.padding,
                     args.dilation,
+                    args.ceil_mode,
                 );
 
                 handles.register_float_tensor::<B, 4>(&args.out.id, output.output);
@@ -824,6 +911,7 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
             stride[0],
             padding[0],
             dilation[0],
+            ceil_mode,
             x.shape[2],
         );
         let size_1 = calculate_pool_output_size(
@@ -831,6 +919,7 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
             stride[1],
             padding[1],
             dilation[1],
+            ceil_mode,
             x.shape[3],
         );
 
@@ -847,6 +936,7 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
             stride,
             padding,
             dilation,
+            ceil_mode,
             out: out.to_description_out(),
             out_indices: out_indices.to_description_out(),
         };
@@ -867,6 +957,7 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
         stride: usize,
         padding: usize,
         dilation: usize,
+        ceil_mode: bool,
         output_grad: FloatTensor<Self, 3>,
         indices: IntTensor<Self, 3>,
     ) -> MaxPool1dBackward<Self> {
@@ -884,6 +975,7 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
                     args.stride,
                     args.padding,
                     args.dilation,
+                    args.ceil_mode,
                     grad,
                     indices,
                 );
@@ -907,6 +999,7 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
             stride,
             padding,
             dilation,
+            ceil_mode,
             out: out.to_description_out(),
         };
         out.client.register(
@@ -926,6 +1019,7 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
         stride: [usize; 2],
         padding: [usize; 2],
         dilation: [usize; 2],
+        ceil_mode: bool,
         output_grad: FloatTensor<Self, 4>,
         indices: IntTensor<Self, 4>,
     ) -> MaxPool2dBackward<Self> {
@@ -943,6 +1037,7 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
                     args.stride,
                     args.padding,
                     args.dilation,
+                    args.ceil_mode,
                     grad,
                     indices,
                 );
@@ -966,6 +1061,7 @@ impl<B: FusionBackend> ModuleOps<Fusion<B>> for Fusion<B> {
             stride,
             padding,
             dilation,
+            ceil_mode,
             out: out.to_description_out(),
         };
         out.client.register(
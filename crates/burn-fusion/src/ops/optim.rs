@@ -0,0 +1,4 @@
+use crate::{Fusion, FusionBackend};
+use burn_tensor::ops::OptimizerOps;
+
+impl<B: FusionBackend> OptimizerOps<Self> for Fusion<B> {}
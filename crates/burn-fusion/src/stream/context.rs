@@ -1,4 +1,4 @@
-use burn_tensor::{repr::*, Element, ElementConversion};
+use burn_tensor::{repr::*, Element, ElementConversion, PadMode};
 use hashbrown::HashMap;
 
 /// The context contains the relative graph tensor mapping so that a relative tensor id can be
@@ -123,6 +123,13 @@ impl RelativeOps for ModuleOperationDescription {
                     out: desc.out.to_relative(converter),
                 })
             }
+            ModuleOperationDescription::Dropout(desc) => {
+                ModuleOperationDescription::Dropout(DropoutDescription {
+                    x: desc.x.to_relative(converter),
+                    prob: desc.prob,
+                    out: desc.out.to_relative(converter),
+                })
+            }
             ModuleOperationDescription::Conv1d(desc) => {
                 ModuleOperationDescription::Conv1d(Conv1dDescription {
                     x: desc.x.to_relative(converter),
@@ -184,6 +191,7 @@ impl RelativeOps for ModuleOperationDescription {
                     stride: desc.stride,
                     padding: desc.padding,
                     count_include_pad: desc.count_include_pad,
+                    ceil_mode: desc.ceil_mode,
                     out: desc.out.to_relative(converter),
                 })
             }
@@ -194,6 +202,7 @@ impl RelativeOps for ModuleOperationDescription {
                     stride: desc.stride,
                     padding: desc.padding,
                     count_include_pad: desc.count_include_pad,
+                    ceil_mode: desc.ceil_mode,
                     out: desc.out.to_relative(converter),
                 })
             }
@@ -205,6 +214,7 @@ impl RelativeOps for ModuleOperationDescription {
                     stride: desc.stride,
                     padding: desc.padding,
                     count_include_pad: desc.count_include_pad,
+                    ceil_mode: desc.ceil_mode,
                     out: desc.out.to_relative(converter),
                 })
             }
@@ -216,6 +226,7 @@ impl RelativeOps for ModuleOperationDescription {
                     stride: desc.stride,
                     padding: desc.padding,
                     count_include_pad: desc.count_include_pad,
+                    ceil_mode: desc.ceil_mode,
                     out: desc.out.to_relative(converter),
                 })
             }
@@ -258,6 +269,7 @@ impl RelativeOps for ModuleOperationDescription {
                     stride: desc.stride,
                     padding: desc.padding,
                     dilation: desc.dilation,
+                    ceil_mode: desc.ceil_mode,
                     out: desc.out.to_relative(converter),
                 })
             }
@@ -268,6 +280,7 @@ impl RelativeOps for ModuleOperationDescription {
                     stride: desc.stride,
                     padding: desc.padding,
                     dilation: desc.dilation,
+                    ceil_mode: desc.ceil_mode,
                     out: desc.out.to_relative(converter),
                     out_indices: desc.out_indices.to_relative(converter),
                 })
@@ -282,6 +295,7 @@ impl RelativeOps for ModuleOperationDescription {
                         stride: desc.stride,
                         padding: desc.padding,
                         dilation: desc.dilation,
+                        ceil_mode: desc.ceil_mode,
                         out: desc.out.to_relative(converter),
                     },
                 )
@@ -293,6 +307,7 @@ impl RelativeOps for ModuleOperationDescription {
                     stride: desc.stride,
                     padding: desc.padding,
                     dilation: desc.dilation,
+                    ceil_mode: desc.ceil_mode,
                     out: desc.out.to_relative(converter),
                 })
             }
@@ -303,6 +318,7 @@ impl RelativeOps for ModuleOperationDescription {
                     stride: desc.stride,
                     padding: desc.padding,
                     dilation: desc.dilation,
+                    ceil_mode: desc.ceil_mode,
                     out: desc.out.to_relative(converter),
                     out_indices: desc.out_indices.to_relative(converter),
                 })
@@ -317,6 +333,7 @@ impl RelativeOps for ModuleOperationDescription {
                         stride: desc.stride,
                         padding: desc.padding,
                         dilation: desc.dilation,
+                        ceil_mode: desc.ceil_mode,
                         out: desc.out.to_relative(converter),
                     },
                 )
@@ -369,6 +386,12 @@ impl RelativeOps for FloatOperationDescription {
                     out: desc.out.to_relative(converter),
                 })
             }
+            FloatOperationDescription::Erfinv(desc) => {
+                FloatOperationDescription::Erfinv(UnaryOperationDescription {
+                    input: desc.input.to_relative(converter),
+                    out: desc.out.to_relative(converter),
+                })
+            }
             FloatOperationDescription::PowfScalar(desc) => {
                 FloatOperationDescription::PowfScalar(ScalarOperationDescription {
                     lhs: desc.lhs.to_relative(converter),
@@ -413,6 +436,16 @@ impl RelativeOps for FloatOperationDescription {
                     out: desc.out.to_relative(converter),
                 })
             }
+            FloatOperationDescription::Spmm(desc) => {
+                FloatOperationDescription::Spmm(SpmmDescription {
+                    rows: desc.rows.to_relative(converter),
+                    cols: desc.cols.to_relative(converter),
+                    values: desc.values.to_relative(converter),
+                    dense: desc.dense.to_relative(converter),
+                    num_rows: desc.num_rows,
+                    out: desc.out.to_relative(converter),
+                })
+            }
             FloatOperationDescription::Random(desc) => {
                 FloatOperationDescription::Random(RandomOperationDescription {
                     out: desc.out.to_relative(converter),
@@ -450,10 +483,39 @@ impl RelativeOps for BoolOperationDescription {
                     out: desc.out.to_relative(converter),
                 })
             }
+            BoolOperationDescription::ArgwherePadded(desc) => {
+                BoolOperationDescription::ArgwherePadded(ArgwherePaddedDescription {
+                    input: desc.input.to_relative(converter),
+                    max_count: desc.max_count,
+                    out_indices: desc.out_indices.to_relative(converter),
+                    out_count: desc.out_count.to_relative(converter),
+                })
+            }
+            BoolOperationDescription::CountNonzero(desc) => {
+                BoolOperationDescription::CountNonzero(ScalarOperationDescription {
+                    lhs: desc.lhs.to_relative(converter),
+                    rhs: desc.rhs,
+                    out: desc.out.to_relative(converter),
+                })
+            }
         }
     }
 }
 
+/// The tolerances are float scalars for the tensors of every kind.
+fn close_to_relative(
+    desc: &CloseOperationDescription,
+    converter: &mut OperationConverter,
+) -> CloseOperationDescription {
+    CloseOperationDescription {
+        lhs: desc.lhs.to_relative(converter),
+        rhs: desc.rhs.to_relative(converter),
+        rtol: converter.relative_float(&desc.rtol),
+        atol: converter.relative_float(&desc.atol),
+        out: desc.out.to_relative(converter),
+    }
+}
+
 impl RelativeOps for IntOperationDescription {
     fn to_relative(&self, converter: &mut OperationConverter) -> Self {
         match self {
@@ -463,10 +525,72 @@ impl RelativeOps for IntOperationDescription {
                     out: desc.out.to_relative(converter),
                 })
             }
+            IntOperationDescription::BitwiseAnd(desc) => {
+                IntOperationDescription::BitwiseAnd(binary_to_relative(desc, converter))
+            }
+            IntOperationDescription::BitwiseAndScalar(desc) => {
+                IntOperationDescription::BitwiseAndScalar(scalar_int_to_relative(desc, converter))
+            }
+            IntOperationDescription::BitwiseOr(desc) => {
+                IntOperationDescription::BitwiseOr(binary_to_relative(desc, converter))
+            }
+            IntOperationDescription::BitwiseOrScalar(desc) => {
+                IntOperationDescription::BitwiseOrScalar(scalar_int_to_relative(desc, converter))
+            }
+            IntOperationDescription::BitwiseXor(desc) => {
+                IntOperationDescription::BitwiseXor(binary_to_relative(desc, converter))
+            }
+            IntOperationDescription::BitwiseXorScalar(desc) => {
+                IntOperationDescription::BitwiseXorScalar(scalar_int_to_relative(desc, converter))
+            }
+            IntOperationDescription::BitwiseNot(desc) => {
+                IntOperationDescription::BitwiseNot(UnaryOperationDescription {
+                    input: desc.input.to_relative(converter),
+                    out: desc.out.to_relative(converter),
+                })
+            }
+            IntOperationDescription::BitwiseLeftShift(desc) => {
+                IntOperationDescription::BitwiseLeftShift(binary_to_relative(desc, converter))
+            }
+            IntOperationDescription::BitwiseLeftShiftScalar(desc) => {
+                IntOperationDescription::BitwiseLeftShiftScalar(scalar_int_to_relative(
+                    desc, converter,
+                ))
+            }
+            IntOperationDescription::BitwiseRightShift(desc) => {
+                IntOperationDescription::BitwiseRightShift(binary_to_relative(desc, converter))
+            }
+            IntOperationDescription::BitwiseRightShiftScalar(desc) => {
+                IntOperationDescription::BitwiseRightShiftScalar(scalar_int_to_relative(
+                    desc, converter,
+                ))
+            }
         }
     }
 }
 
+fn binary_to_relative(
+    desc: &BinaryOperationDescription,
+    converter: &mut OperationConverter,
+) -> BinaryOperationDescription {
+    BinaryOperationDescription {
+        lhs: desc.lhs.to_relative(converter),
+        rhs: desc.rhs.to_relative(converter),
+        out: desc.out.to_relative(converter),
+    }
+}
+
+fn scalar_int_to_relative(
+    desc: &ScalarOperationDescription<i32>,
+    converter: &mut OperationConverter,
+) -> ScalarOperationDescription<i32> {
+    ScalarOperationDescription {
+        lhs: desc.lhs.to_relative(converter),
+        rhs: converter.relative_int(&desc.rhs),
+        out: desc.out.to_relative(converter),
+    }
+}
+
 impl<E: Element> RelativeOpsScalar<E> for NumericOperationDescription<E> {
     fn to_relative<F>(&self, converter: &mut OperationConverter, local_elem: F) -> Self
     where
@@ -781,6 +905,31 @@ impl<E: Element> RelativeOpsScalar<E> for NumericOperationDescription<E> {
                     out: desc.out.to_relative(converter),
                 })
             }
+            NumericOperationDescription::Pad(desc) => {
+                NumericOperationDescription::Pad(PadOperationDescription {
+                    tensor: desc.tensor.to_relative(converter),
+                    padding: desc.padding.clone(),
+                    mode: match &desc.mode {
+                        PadMode::Constant(value) => PadMode::Constant(local_elem(converter, value)),
+                        PadMode::Reflect => PadMode::Reflect,
+                        PadMode::Edge => PadMode::Edge,
+                    },
+                    out: desc.out.to_relative(converter),
+                })
+            }
+            NumericOperationDescription::CountNonzero(desc) => {
+                NumericOperationDescription::CountNonzero(ScalarOperationDescription {
+                    lhs: desc.lhs.to_relative(converter),
+                    rhs: desc.rhs,
+                    out: desc.out.to_relative(converter),
+                })
+            }
+            NumericOperationDescription::IsClose(desc) => {
+                NumericOperationDescription::IsClose(close_to_relative(desc, converter))
+            }
+            NumericOperationDescription::AllClose(desc) => {
+                NumericOperationDescription::AllClose(close_to_relative(desc, converter))
+            }
         }
     }
 }
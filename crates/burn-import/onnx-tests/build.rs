@@ -24,6 +24,7 @@ fn main() {
         .input("tests/conv_transpose2d/conv_transpose2d.onnx")
         .input("tests/conv_transpose3d/conv_transpose3d.onnx")
         .input("tests/cos/cos.onnx")
+        .input("tests/dequantize_linear/dequantize_linear.onnx")
         .input("tests/div/div.onnx")
         .input("tests/dropout/dropout_opset16.onnx")
         .input("tests/dropout/dropout_opset7.onnx")
@@ -73,6 +74,9 @@ fn main() {
         .input("tests/pow/pow.onnx")
         .input("tests/pow/pow_int.onnx")
         .input("tests/prelu/prelu.onnx")
+        .input("tests/qlinear_conv/qlinear_conv.onnx")
+        .input("tests/qlinear_matmul/qlinear_matmul.onnx")
+        .input("tests/quantize_linear/quantize_linear.onnx")
         .input("tests/random_normal/random_normal.onnx")
         .input("tests/random_uniform/random_uniform.onnx")
         .input("tests/range/range.onnx")
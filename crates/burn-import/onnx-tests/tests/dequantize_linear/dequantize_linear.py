@@ -0,0 +1,45 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/dequantize_linear/dequantize_linear.onnx
+
+import numpy as np
+import onnx
+import onnxruntime as ort
+from onnx import helper, TensorProto
+
+
+def main() -> None:
+    # Per-axis dequantization of a uint8 input along the columns
+    node = helper.make_node(
+        "DequantizeLinear",
+        name="/DequantizeLinear",
+        inputs=["x", "x_scale", "x_zero_point"],
+        outputs=["y"],
+        axis=1,
+    )
+
+    graph_def = helper.make_graph(
+        nodes=[node],
+        name="main_graph",
+        inputs=[helper.make_tensor_value_info("x", TensorProto.UINT8, [2, 3])],
+        outputs=[helper.make_tensor_value_info("y", TensorProto.FLOAT, [2, 3])],
+        initializer=[
+            helper.make_tensor("x_scale", TensorProto.FLOAT, [3], [0.5, 0.25, 0.125]),
+            helper.make_tensor("x_zero_point", TensorProto.UINT8, [3], [128, 0, 4]),
+        ],
+    )
+
+    model_def = helper.make_model(
+        graph_def, producer_name="burn-import", opset_imports=[helper.make_opsetid("", 13)]
+    )
+    onnx.checker.check_model(model_def)
+    onnx.save(model_def, "dequantize_linear.onnx")
+
+    test_input = np.array([[130, 0, 4], [120, 8, 255]], dtype=np.uint8)
+    session = ort.InferenceSession("dequantize_linear.onnx")
+    print("Test input data: {}".format(test_input))
+    print("Test output data: {}".format(session.run(None, {"x": test_input})[0]))
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,79 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/qlinear_conv/qlinear_conv.onnx
+
+import numpy as np
+import onnx
+import onnxruntime as ort
+from onnx import helper, TensorProto
+
+
+def main() -> None:
+    # A small quantized CNN in the QOperator format produced by onnxruntime's quantizer:
+    # the float input is quantized, convolved with int8 weights quantized per output channel
+    # and an int32 bias, then dequantized back to float.
+    channels, out_channels = 2, 3
+    weights = [((i * 7) % 11) - 5 for i in range(out_channels * channels * 9)]
+
+    nodes = [
+        helper.make_node(
+            "QuantizeLinear",
+            name="/QuantizeLinear",
+            inputs=["x", "x_scale", "x_zero_point"],
+            outputs=["x_quantized"],
+        ),
+        helper.make_node(
+            "QLinearConv",
+            name="/QLinearConv",
+            inputs=[
+                "x_quantized", "x_scale", "x_zero_point",
+                "w", "w_scale", "w_zero_point",
+                "y_scale", "y_zero_point", "bias",
+            ],
+            outputs=["y_quantized"],
+            kernel_shape=[3, 3],
+            pads=[1, 1, 1, 1],
+        ),
+        helper.make_node(
+            "DequantizeLinear",
+            name="/DequantizeLinear",
+            inputs=["y_quantized", "y_scale", "y_zero_point"],
+            outputs=["y"],
+        ),
+    ]
+
+    graph_def = helper.make_graph(
+        nodes=nodes,
+        name="main_graph",
+        inputs=[helper.make_tensor_value_info("x", TensorProto.FLOAT, [1, channels, 4, 4])],
+        outputs=[helper.make_tensor_value_info("y", TensorProto.FLOAT, [1, out_channels, 4, 4])],
+        initializer=[
+            helper.make_tensor("x_scale", TensorProto.FLOAT, [], [0.125]),
+            helper.make_tensor("x_zero_point", TensorProto.UINT8, [], [128]),
+            helper.make_tensor("w", TensorProto.INT8, [out_channels, channels, 3, 3], weights),
+            helper.make_tensor("w_scale", TensorProto.FLOAT, [out_channels], [0.0625, 0.125, 0.25]),
+            helper.make_tensor("w_zero_point", TensorProto.INT8, [out_channels], [0] * out_channels),
+            helper.make_tensor("y_scale", TensorProto.FLOAT, [], [0.25]),
+            helper.make_tensor("y_zero_point", TensorProto.UINT8, [], [128]),
+            helper.make_tensor("bias", TensorProto.INT32, [out_channels], [16, -32, 64]),
+        ],
+    )
+
+    model_def = helper.make_model(
+        graph_def, producer_name="burn-import", opset_imports=[helper.make_opsetid("", 13)]
+    )
+    onnx.checker.check_model(model_def)
+    onnx.save(model_def, "qlinear_conv.onnx")
+
+    test_input = np.array(
+        [((i * 5) % 13) / 8 - 0.75 for i in range(channels * 16)], dtype=np.float32
+    ).reshape(1, channels, 4, 4)
+    session = ort.InferenceSession("qlinear_conv.onnx")
+    output = session.run(None, {"x": test_input})[0]
+    print("Test input data: {}".format(test_input))
+    print("Test output data: {}".format(output))
+    print("Test output sum: {}".format(output.sum()))
+
+
+if __name__ == "__main__":
+    main()
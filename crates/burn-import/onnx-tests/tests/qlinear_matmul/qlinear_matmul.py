@@ -0,0 +1,50 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/qlinear_matmul/qlinear_matmul.onnx
+
+import numpy as np
+import onnx
+import onnxruntime as ort
+from onnx import helper, TensorProto
+
+
+def main() -> None:
+    # uint8 activations times int8 weights quantized per column
+    b = [[(i * 3 + j * 5) % 9 - 4 for j in range(4)] for i in range(3)]
+    node = helper.make_node(
+        "QLinearMatMul",
+        name="/QLinearMatMul",
+        inputs=["a", "a_scale", "a_zero_point", "b", "b_scale", "b_zero_point", "y_scale", "y_zero_point"],
+        outputs=["y"],
+    )
+
+    graph_def = helper.make_graph(
+        nodes=[node],
+        name="main_graph",
+        inputs=[helper.make_tensor_value_info("a", TensorProto.UINT8, [2, 3])],
+        outputs=[helper.make_tensor_value_info("y", TensorProto.UINT8, [2, 4])],
+        initializer=[
+            helper.make_tensor("a_scale", TensorProto.FLOAT, [], [0.5]),
+            helper.make_tensor("a_zero_point", TensorProto.UINT8, [], [128]),
+            helper.make_tensor("b", TensorProto.INT8, [3, 4], sum(b, [])),
+            helper.make_tensor("b_scale", TensorProto.FLOAT, [4], [0.25, 0.5, 0.125, 1.0]),
+            helper.make_tensor("b_zero_point", TensorProto.INT8, [4], [0, 0, 0, 0]),
+            helper.make_tensor("y_scale", TensorProto.FLOAT, [], [0.25]),
+            helper.make_tensor("y_zero_point", TensorProto.UINT8, [], [100]),
+        ],
+    )
+
+    model_def = helper.make_model(
+        graph_def, producer_name="burn-import", opset_imports=[helper.make_opsetid("", 13)]
+    )
+    onnx.checker.check_model(model_def)
+    onnx.save(model_def, "qlinear_matmul.onnx")
+
+    test_input = np.array([[128, 130, 120], [255, 0, 140]], dtype=np.uint8)
+    session = ort.InferenceSession("qlinear_matmul.onnx")
+    print("Test input data: {}".format(test_input))
+    print("Test output data: {}".format(session.run(None, {"a": test_input})[0]))
+
+
+if __name__ == "__main__":
+    main()
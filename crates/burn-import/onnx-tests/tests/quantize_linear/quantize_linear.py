@@ -0,0 +1,44 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/quantize_linear/quantize_linear.onnx
+
+import numpy as np
+import onnx
+import onnxruntime as ort
+from onnx import helper, TensorProto
+
+
+def main() -> None:
+    # Per-tensor quantization to int8, which rounds half to even and saturates
+    node = helper.make_node(
+        "QuantizeLinear",
+        name="/QuantizeLinear",
+        inputs=["x", "y_scale", "y_zero_point"],
+        outputs=["y"],
+    )
+
+    graph_def = helper.make_graph(
+        nodes=[node],
+        name="main_graph",
+        inputs=[helper.make_tensor_value_info("x", TensorProto.FLOAT, [2, 3])],
+        outputs=[helper.make_tensor_value_info("y", TensorProto.INT8, [2, 3])],
+        initializer=[
+            helper.make_tensor("y_scale", TensorProto.FLOAT, [], [0.5]),
+            helper.make_tensor("y_zero_point", TensorProto.INT8, [], [-2]),
+        ],
+    )
+
+    model_def = helper.make_model(
+        graph_def, producer_name="burn-import", opset_imports=[helper.make_opsetid("", 13)]
+    )
+    onnx.checker.check_model(model_def)
+    onnx.save(model_def, "quantize_linear.onnx")
+
+    test_input = np.array([[0.3, -1.0, 1.25], [100.0, -100.0, 0.75]], dtype=np.float32)
+    session = ort.InferenceSession("quantize_linear.onnx")
+    print("Test input data: {}".format(test_input))
+    print("Test output data: {}".format(session.run(None, {"x": test_input})[0]))
+
+
+if __name__ == "__main__":
+    main()
@@ -33,6 +33,7 @@ include_models!(
     conv_transpose2d,
     conv_transpose3d,
     cos,
+    dequantize_linear,
     div,
     dropout_opset16,
     dropout_opset7,
@@ -82,6 +83,9 @@ include_models!(
     pow,
     pow_int,
     prelu,
+    qlinear_conv,
+    qlinear_matmul,
+    quantize_linear,
     random_normal,
     random_uniform,
     range,
@@ -1228,6 +1232,90 @@ mod tests {
         output.to_data().assert_eq(&expected, true);
     }
 
+    #[test]
+    fn dequantize_linear() {
+        let device = Default::default();
+        let model: dequantize_linear::Model<Backend> = dequantize_linear::Model::new(&device);
+
+        // Run the model with a scale and a zero-point per column
+        let input = Tensor::<Backend, 2, Int>::from_ints([[130, 0, 4], [120, 8, 255]], &device);
+        let output = model.forward(input);
+        let expected = TensorData::from([[1.0f32, 0.0, 0.0], [-4.0, 2.0, 31.375]]);
+
+        output.to_data().assert_eq(&expected, true);
+    }
+
+    #[test]
+    fn quantize_linear() {
+        let device = Default::default();
+        let model: quantize_linear::Model<Backend> = quantize_linear::Model::new(&device);
+
+        // Run the model, the ties are rounded to even and the results saturate to the int8 range
+        let input =
+            Tensor::<Backend, 2>::from_floats([[0.3, -1.0, 1.25], [100.0, -100.0, 0.75]], &device);
+        let output = model.forward(input);
+        let expected = TensorData::from([[-1i64, -4, 0], [127, -128, 0]]);
+
+        output.to_data().assert_eq(&expected, true);
+    }
+
+    #[test]
+    fn qlinear_matmul() {
+        // Initialize the model with the dequantized weights
+        let model: qlinear_matmul::Model<Backend> = qlinear_matmul::Model::default();
+
+        let device = Default::default();
+        let input = Tensor::<Backend, 2, Int>::from_ints([[128, 130, 120], [255, 0, 140]], &device);
+        let output = model.forward(input);
+        let expected = TensorData::from([[91i64, 124, 94, 100], [0, 0, 14, 255]]);
+
+        output.to_data().assert_eq(&expected, true);
+    }
+
+    #[test]
+    fn qlinear_conv() {
+        // Initialize the model with the dequantized weights
+        let model: qlinear_conv::Model<Backend> = qlinear_conv::Model::default();
+
+        let device = Default::default();
+        // The input values are on the quantization grid of the input
+        let input = Tensor::<Backend, 1>::from_floats(
+            [
+                -0.75, -0.125, 0.5, -0.5, 0.125, 0.75, -0.25, 0.375, -0.625, 0.0, 0.625, -0.375,
+                0.25, -0.75, -0.125, 0.5, -0.5, 0.125, 0.75, -0.25, 0.375, -0.625, 0.0, 0.625,
+                -0.375, 0.25, -0.75, -0.125, 0.5, -0.5, 0.125, 0.75,
+            ],
+            &device,
+        )
+        .reshape([1, 2, 4, 4]);
+        let output = model.forward(input);
+
+        // The scales are powers of two, so the float computations are exact and the output
+        // matches the integer computations of the quantized operators
+        let expected = TensorData::from([[
+            [
+                [-0.25f32, 0.25, 0.25, 0.25],
+                [-0.25, -0.25, 0.75, 0.0],
+                [0.25, 0.0, -0.5, 0.25],
+                [0.0, 0.5, 0.25, -0.25],
+            ],
+            [
+                [0.25, 0.0, -0.75, -0.25],
+                [-0.75, -0.25, -0.25, -0.5],
+                [0.5, -1.75, -0.5, 0.5],
+                [-0.5, 0.0, -1.0, -0.25],
+            ],
+            [
+                [0.75, 2.0, 2.25, 2.0],
+                [1.5, 0.25, 3.5, 3.0],
+                [1.0, 2.0, 3.25, 1.0],
+                [2.25, 0.5, 2.0, 1.75],
+            ],
+        ]]);
+
+        output.to_data().assert_eq(&expected, true);
+    }
+
     #[test]
     fn relu() {
         // Initialize the model without weights (because the exported file does not contain them)
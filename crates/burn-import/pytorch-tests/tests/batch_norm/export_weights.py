@@ -34,6 +34,14 @@ def main():
     print("Output: {}", output)
     print("Output Shape: {}", output.shape)
 
+    # Train mode: the batch statistics are used and the running ones are updated
+    model.train()
+    x3 = torch.arange(20, dtype=torch.float32).reshape(1, 5, 2, 2) / 10
+    output = model(x3)
+    print("Train output: {}", output)
+    print("Running mean: {}", model.norm1.running_mean)
+    print("Running var: {}", model.norm1.running_var)
+
     
 
 
@@ -25,8 +25,10 @@ impl<B: Backend> Net<B> {
 #[cfg(test)]
 mod tests {
     type Backend = burn_ndarray::NdArray<f32>;
+    type AutodiffBackend = burn_autodiff::Autodiff<Backend>;
 
     use burn::record::{FullPrecisionSettings, Recorder};
+    use burn::tensor::TensorData;
     use burn_import::pytorch::PyTorchFileRecorder;
 
     use super::*;
@@ -57,4 +59,45 @@ mod tests {
 
         output.to_data().assert_approx_eq(&expected.to_data(), 5);
     }
+
+    #[test]
+    fn batch_norm2d_train() {
+        let device = Default::default();
+        let record = PyTorchFileRecorder::<FullPrecisionSettings>::default()
+            .load("tests/batch_norm/batch_norm2d.pt".into(), &device)
+            .expect("Should decode state successfully");
+
+        let model = Net::<AutodiffBackend>::new(&device).load_record(record);
+
+        let input = Tensor::<AutodiffBackend, 1, burn::tensor::Int>::arange(0..20, &device)
+            .float()
+            .reshape([1, 5, 2, 2])
+            .div_scalar(10.0);
+
+        let output = model.forward(input);
+
+        // The expected values are the train output and running statistics printed by
+        // `export_weights.py`. The batch is normalized with its biased variance.
+        let expected = Tensor::<Backend, 4>::from_data(
+            [[[[-1.3411044, -0.44703482], [0.44703482, 1.3411044]]; 5]],
+            &device,
+        );
+        output
+            .into_data()
+            .assert_approx_eq_diff(&expected.into_data(), 1e-6);
+
+        // The running variance is updated with the unbiased variance of the batch.
+        model
+            .norm1
+            .running_mean
+            .value_sync()
+            .into_data()
+            .assert_approx_eq_diff(&TensorData::from([0.06, 0.1, 0.14, 0.18, 0.22]), 1e-6);
+        model
+            .norm1
+            .running_var
+            .value_sync()
+            .into_data()
+            .assert_approx_eq_diff(&TensorData::from([0.81166667; 5]), 1e-6);
+    }
 }
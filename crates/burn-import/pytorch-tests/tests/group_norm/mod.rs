@@ -30,7 +30,7 @@ mod tests {
 
     use super::*;
 
-    fn group_norm(record: NetRecord<Backend>, precision: usize) {
+    fn group_norm(record: NetRecord<Backend>, tolerance: f64) {
         let device = Default::default();
 
         let model = Net::<Backend>::init(&device).load_record(record);
@@ -63,7 +63,7 @@ mod tests {
 
         output
             .to_data()
-            .assert_approx_eq(&expected.to_data(), precision);
+            .assert_approx_eq_diff(&expected.to_data(), tolerance);
     }
 
     #[test]
@@ -73,7 +73,7 @@ mod tests {
             .load("tests/group_norm/group_norm.pt".into(), &device)
             .expect("Should decode state successfully");
 
-        group_norm(record, 3);
+        group_norm(record, 1e-6);
     }
 
     #[test]
@@ -83,6 +83,6 @@ mod tests {
             .load("tests/group_norm/group_norm.pt".into(), &device)
             .expect("Should decode state successfully");
 
-        group_norm(record, 3);
+        group_norm(record, 1e-3);
     }
 }
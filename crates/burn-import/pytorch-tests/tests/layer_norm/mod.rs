@@ -31,7 +31,7 @@ mod tests {
 
     use super::*;
 
-    fn layer_norm(record: NetRecord<Backend>, precision: usize) {
+    fn layer_norm(record: NetRecord<Backend>, tolerance: f64) {
         let device = Default::default();
 
         let model = Net::<Backend>::init(&device).load_record(record);
@@ -56,7 +56,7 @@ mod tests {
 
         output
             .to_data()
-            .assert_approx_eq(&expected.to_data(), precision);
+            .assert_approx_eq_diff(&expected.to_data(), tolerance);
     }
 
     #[test]
@@ -65,7 +65,7 @@ mod tests {
         let record = PyTorchFileRecorder::<FullPrecisionSettings>::default()
             .load("tests/layer_norm/layer_norm.pt".into(), &device)
             .expect("Should decode state successfully");
-        layer_norm(record, 3);
+        layer_norm(record, 1e-6);
     }
 
     #[test]
@@ -74,6 +74,6 @@ mod tests {
         let record = PyTorchFileRecorder::<HalfPrecisionSettings>::default()
             .load("tests/layer_norm/layer_norm.pt".into(), &device)
             .expect("Should decode state successfully");
-        layer_norm(record, 3);
+        layer_norm(record, 1e-3);
     }
 }
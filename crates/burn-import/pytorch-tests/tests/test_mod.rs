@@ -18,3 +18,4 @@ mod linear;
 mod missing_module_field;
 mod non_contiguous_indexes;
 mod top_level_key;
+mod vit_patch_embed;
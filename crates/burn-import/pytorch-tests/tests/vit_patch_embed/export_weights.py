@@ -0,0 +1,56 @@
+#!/usr/bin/env python3
+
+import torch
+import torch.nn as nn
+
+
+class PatchEmbed(nn.Module):
+    def __init__(self):
+        super(PatchEmbed, self).__init__()
+        self.proj = nn.Conv2d(2, 3, kernel_size=2, stride=2)
+
+    def forward(self, x):
+        x = self.proj(x)
+        return x.flatten(2).transpose(1, 2)
+
+
+class Model(nn.Module):
+    """The embedding of the inputs of a timm vision transformer."""
+
+    def __init__(self):
+        super(Model, self).__init__()
+        self.patch_embed = PatchEmbed()
+        self.cls_token = nn.Parameter(torch.zeros(1, 1, 3))
+        self.pos_embed = nn.Parameter(torch.zeros(1, 7, 3))
+
+    def forward(self, x):
+        x = self.patch_embed(x)
+        x = torch.cat((self.cls_token.expand(x.shape[0], -1, -1), x), dim=1)
+        return x + self.pos_embed
+
+
+def main():
+
+    torch.set_printoptions(precision=8)
+
+    model = Model().to(torch.device("cpu"))
+
+    # Deterministic weights, so the fixture and the expected output don't depend on the seed.
+    with torch.no_grad():
+        model.patch_embed.proj.weight.copy_(torch.arange(24.0).reshape(3, 2, 2, 2) / 24)
+        model.patch_embed.proj.bias.copy_(torch.arange(1.0, 4.0) / 10)
+        model.cls_token.copy_(-torch.arange(1.0, 4.0).reshape(1, 1, 3) / 2)
+        model.pos_embed.copy_(torch.arange(21.0).reshape(1, 7, 3) / 21)
+
+    torch.save(model.state_dict(), "vit_patch_embed.pt")
+
+    input = torch.arange(48.0).reshape(1, 2, 4, 6) / 48
+    print("Input shape: {}", input.shape)
+    print("Input: {}", input)
+    output = model(input)
+    print("Output: {}", output)
+    print("Output Shape: {}", output.shape)
+
+
+if __name__ == '__main__':
+    main()
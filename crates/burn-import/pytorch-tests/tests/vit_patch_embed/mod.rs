@@ -0,0 +1,84 @@
+use burn::{
+    module::{Module, Param},
+    nn::vision::{ClassToken, ClassTokenConfig, PatchEmbed, PatchEmbedConfig},
+    tensor::{backend::Backend, Tensor},
+};
+
+#[derive(Module, Debug)]
+pub struct Net<B: Backend> {
+    patch_embed: PatchEmbed<B>,
+    cls_token: ClassToken<B>,
+    pos_embed: Param<Tensor<B, 3>>,
+}
+
+impl<B: Backend> Net<B> {
+    /// Create a new model.
+    pub fn init(device: &B::Device) -> Self {
+        let patch_embed = PatchEmbedConfig::new([4, 6], [2, 2])
+            .with_in_chans(2)
+            .with_embed_dim(3)
+            .init(device);
+        let cls_token = ClassTokenConfig::new(3).init(device);
+        let pos_embed = Param::from_tensor(Tensor::zeros([1, 7, 3], device));
+
+        Self {
+            patch_embed,
+            cls_token,
+            pos_embed,
+        }
+    }
+
+    /// Forward pass of the model.
+    pub fn forward(&self, x: Tensor<B, 4>) -> Tensor<B, 3> {
+        let x = self.patch_embed.forward(x);
+        let x = self.cls_token.forward(x);
+
+        x + self.pos_embed.val()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    type Backend = burn_ndarray::NdArray<f32>;
+
+    use burn::record::{FullPrecisionSettings, Recorder};
+    use burn_import::pytorch::{LoadArgs, PyTorchFileRecorder};
+
+    use super::*;
+
+    #[test]
+    fn vit_patch_embed() {
+        let device = Default::default();
+        let load_args = LoadArgs::new("tests/vit_patch_embed/vit_patch_embed.pt".into())
+            // timm stores the class token as a parameter of the model, e.g. "cls_token" -> "cls_token.token"
+            .with_key_remap("^cls_token$", "cls_token.token");
+
+        let record = PyTorchFileRecorder::<FullPrecisionSettings>::default()
+            .load(load_args, &device)
+            .expect("Should decode state successfully");
+
+        let model = Net::<Backend>::init(&device).load_record(record);
+
+        let input = Tensor::<Backend, 1>::arange(0..48, &device)
+            .float()
+            .reshape([1, 2, 4, 6])
+            / 48;
+
+        let output = model.forward(input);
+
+        let expected = Tensor::<Backend, 3>::from_data(
+            [[
+                [-0.500000, -0.952381, -1.404762],
+                [0.808829, 1.817560, 2.826290],
+                [1.000298, 2.120139, 3.239980],
+                [1.191766, 2.422718, 3.653671],
+                [1.529067, 3.204464, 4.879861],
+                [1.720536, 3.507044, 5.293552],
+                [1.912004, 3.809623, 5.707242],
+            ]],
+            &device,
+        );
+
+        output.to_data().assert_approx_eq(&expected.to_data(), 5);
+    }
+}
@@ -52,12 +52,14 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for AvgPool1dNode {
         let strides = self.config.stride.to_tokens();
         let padding = self.config.padding.to_tokens();
         let count_include_pad = self.config.count_include_pad;
+        let ceil_mode = self.config.ceil_mode;
 
         let tokens = quote! {
             let #name = AvgPool1dConfig::new(#kernel_size)
                 .with_stride(#strides)
                 .with_padding(#padding)
                 .with_count_include_pad(#count_include_pad)
+                .with_ceil_mode(#ceil_mode)
                 .init();
         };
 
@@ -133,6 +135,7 @@ mod tests {
                         .with_stride(1)
                         .with_padding(PaddingConfig1d::Valid)
                         .with_count_include_pad(true)
+                        .with_ceil_mode(false)
                         .init();
 
                     Self {
@@ -52,12 +52,14 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for AvgPool2dNode {
         let strides = self.config.strides.to_tokens();
         let padding = self.config.padding.to_tokens();
         let count_include_pad = self.config.count_include_pad;
+        let ceil_mode = self.config.ceil_mode;
 
         let tokens = quote! {
             let #name = AvgPool2dConfig::new(#kernel_size)
                 .with_strides(#strides)
                 .with_padding(#padding)
                 .with_count_include_pad(#count_include_pad)
+                .with_ceil_mode(#ceil_mode)
                 .init();
         };
 
@@ -137,6 +139,7 @@ mod tests {
                         .with_strides([1, 1])
                         .with_padding(PaddingConfig2d::Valid)
                         .with_count_include_pad(true)
+                        .with_ceil_mode(false)
                         .init();
 
                     Self {
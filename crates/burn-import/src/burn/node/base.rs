@@ -5,13 +5,15 @@ use super::{
     batch_norm::BatchNormNode, binary::BinaryNode, clip::ClipNode, concat::ConcatNode,
     constant::ConstantNode, constant_of_shape::ConstantOfShapeNode, conv1d::Conv1dNode,
     conv2d::Conv2dNode, conv3d::Conv3dNode, conv_transpose_2d::ConvTranspose2dNode,
-    conv_transpose_3d::ConvTranspose3dNode, dropout::DropoutNode, expand::ExpandNode,
-    gather::GatherNode, gather_elements::GatherElementsNode, global_avg_pool::GlobalAvgPoolNode,
+    conv_transpose_3d::ConvTranspose3dNode, dequantize_linear::DequantizeLinearNode,
+    dropout::DropoutNode, expand::ExpandNode, gather::GatherNode,
+    gather_elements::GatherElementsNode, global_avg_pool::GlobalAvgPoolNode,
     layer_norm::LayerNormNode, linear::LinearNode, mask_where::WhereNode, matmul::MatmulNode,
     max_pool1d::MaxPool1dNode, max_pool2d::MaxPool2dNode, mean::MeanNode, pad::PadNode,
-    prelu::PReluNode, random_normal::RandomNormalNode, random_uniform::RandomUniformNode,
-    range::RangeNode, reshape::ReshapeNode, resize::ResizeNode, slice::SliceNode,
-    squeeze::SqueezeNode, sum::SumNode, tile::TileNode, unary::UnaryNode, unsqueeze::UnsqueezeNode,
+    prelu::PReluNode, quantize_linear::QuantizeLinearNode, random_normal::RandomNormalNode,
+    random_uniform::RandomUniformNode, range::RangeNode, reshape::ReshapeNode, resize::ResizeNode,
+    slice::SliceNode, squeeze::SqueezeNode, sum::SumNode, tile::TileNode, unary::UnaryNode,
+    unsqueeze::UnsqueezeNode,
 };
 use crate::burn::{BurnImports, Scope, Type};
 use burn::backend::NdArray;
@@ -95,6 +97,7 @@ pub enum Node<PS: PrecisionSettings> {
     ConvTranspose2d(ConvTranspose2dNode),
     ConvTranspose3d(ConvTranspose3dNode),
     PRelu(PReluNode),
+    DequantizeLinear(DequantizeLinearNode),
     Dropout(DropoutNode),
     Expand(ExpandNode),
     Gather(GatherNode),
@@ -107,6 +110,7 @@ pub enum Node<PS: PrecisionSettings> {
     MaxPool2d(MaxPool2dNode),
     Mean(MeanNode),
     Pad(PadNode),
+    QuantizeLinear(QuantizeLinearNode),
     Range(RangeNode),
     Reshape(ReshapeNode),
     Resize(ResizeNode),
@@ -143,6 +147,7 @@ macro_rules! match_all {
             Node::ConvTranspose2d(node) => $func(node),
             Node::ConvTranspose3d(node) => $func(node),
             Node::PRelu(node) => $func(node),
+            Node::DequantizeLinear(node) => $func(node),
             Node::Dropout(node) => $func(node),
             Node::Expand(node) => $func(node),
             Node::Gather(node) => $func(node),
@@ -155,6 +160,7 @@ macro_rules! match_all {
             Node::MaxPool2d(node) => $func(node),
             Node::Mean(node) => $func(node),
             Node::Pad(node) => $func(node),
+            Node::QuantizeLinear(node) => $func(node),
             Node::Range(node) => $func(node),
             Node::Reshape(node) => $func(node),
             Node::Resize(node) => $func(node),
@@ -199,6 +205,7 @@ impl<PS: PrecisionSettings> Node<PS> {
             Node::ConvTranspose2d(_) => "conv_transpose2d",
             Node::ConvTranspose3d(_) => "conv_transpose3d",
             Node::PRelu(_) => "prelu",
+            Node::DequantizeLinear(_) => "dequantize_linear",
             Node::Dropout(_) => "dropout",
             Node::Expand(_) => "expand",
             Node::Gather(_) => "gather",
@@ -211,6 +218,7 @@ impl<PS: PrecisionSettings> Node<PS> {
             Node::MaxPool2d(_) => "max_pool2d",
             Node::Mean(_) => "mean",
             Node::Pad(_) => "pad",
+            Node::QuantizeLinear(_) => "quantize_linear",
             Node::Range(_) => "range",
             Node::Reshape(_) => "reshape",
             Node::Resize(_) => "resize",
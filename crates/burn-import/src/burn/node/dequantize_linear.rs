@@ -0,0 +1,178 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{Scope, TensorKind, TensorType, ToTokens, Type};
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Dequantizes an integer tensor with `(input - zero_point) * scale`.
+#[derive(Debug, Clone, new)]
+pub struct DequantizeLinearNode {
+    pub input: TensorType,
+    pub output: TensorType,
+    /// A single scale, or one scale per index of the axis.
+    pub scale: Vec<f32>,
+    /// The zero-points, as many as scales.
+    pub zero_point: Vec<i64>,
+    pub axis: usize,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for DequantizeLinearNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.input.clone())]
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let output = &self.output.name;
+        let input = match self.input.kind {
+            TensorKind::Float => input,
+            _ => quote! { #input.float() },
+        };
+
+        if self.scale.len() == 1 {
+            let scale = self.scale[0];
+            let zero_point = self.zero_point[0] as f32;
+
+            return quote! {
+                let #output = #input.sub_scalar(#zero_point).mul_scalar(#scale);
+            };
+        }
+
+        let zero_point = self
+            .zero_point
+            .iter()
+            .map(|z| *z as f32)
+            .collect::<Vec<_>>();
+        let zero_point = per_axis_tensor(&zero_point, self.input.dim, self.axis);
+        let scale = per_axis_tensor(&self.scale, self.input.dim, self.axis);
+
+        quote! {
+            let #output = (#input - #zero_point) * #scale;
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::DequantizeLinear(self)
+    }
+}
+
+/// A tensor of the given values along the axis, broadcastable to a tensor of the given rank.
+pub(crate) fn per_axis_tensor(values: &[f32], rank: usize, axis: usize) -> TokenStream {
+    let mut shape = vec![1; rank];
+    shape[axis] = values.len();
+    let shape = shape.to_tokens();
+
+    quote! {
+        Tensor::<B, 1>::from_floats([#(#values),*], &*self.device).reshape(#shape)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{graph::BurnGraph, node::test::assert_tokens, TensorType};
+
+    #[test]
+    fn codegen_nodes_per_tensor() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(DequantizeLinearNode::new(
+            TensorType::new_int("tensor1", 4),
+            TensorType::new_float("tensor2", 4),
+            vec![0.5],
+            vec![3],
+            1,
+        ));
+
+        graph.register_input_output(vec!["tensor1".to_string()], vec!["tensor2".to_string()]);
+
+        let expected = quote! {
+            use burn::tensor::Int;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, tensor1: Tensor<B, 4, Int>) -> Tensor<B, 4> {
+                    let tensor2 = tensor1.float().sub_scalar(3f32).mul_scalar(0.5f32);
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn codegen_nodes_per_axis() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(DequantizeLinearNode::new(
+            TensorType::new_int("tensor1", 4),
+            TensorType::new_float("tensor2", 4),
+            vec![0.5, 0.25],
+            vec![0, 1],
+            1,
+        ));
+
+        graph.register_input_output(vec!["tensor1".to_string()], vec!["tensor2".to_string()]);
+
+        let expected = quote! {
+            use burn::tensor::Int;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, tensor1: Tensor<B, 4, Int>) -> Tensor<B, 4> {
+                    let tensor2 = (tensor1.float()
+                        - Tensor::<B, 1>::from_floats([0f32, 1f32], &*self.device)
+                            .reshape([1, 2, 1, 1]))
+                        * Tensor::<B, 1>::from_floats([0.5f32, 0.25f32], &*self.device)
+                            .reshape([1, 2, 1, 1]);
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
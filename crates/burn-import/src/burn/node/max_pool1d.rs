@@ -52,11 +52,13 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for MaxPool1dNode {
         let strides = self.config.stride.to_tokens();
         let padding = self.config.padding.to_tokens();
         let dilation = self.config.dilation.to_tokens();
+        let ceil_mode = self.config.ceil_mode;
         let tokens = quote! {
             let #name = MaxPool1dConfig::new(#kernel_size)
                 .with_stride(#strides)
                 .with_padding(#padding)
                 .with_dilation(#dilation)
+                .with_ceil_mode(#ceil_mode)
                 .init();
         };
 
@@ -136,6 +138,7 @@ mod tests {
                         .with_stride(1)
                         .with_padding(PaddingConfig1d::Valid)
                         .with_dilation(1)
+                        .with_ceil_mode(false)
                         .init();
 
                     Self {
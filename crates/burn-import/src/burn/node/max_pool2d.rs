@@ -52,11 +52,13 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for MaxPool2dNode {
         let strides = self.config.strides.to_tokens();
         let padding = self.config.padding.to_tokens();
         let dilation = self.config.dilation.to_tokens();
+        let ceil_mode = self.config.ceil_mode;
         let tokens = quote! {
             let #name = MaxPool2dConfig::new(#kernel_size)
                 .with_strides(#strides)
                 .with_padding(#padding)
                 .with_dilation(#dilation)
+                .with_ceil_mode(#ceil_mode)
                 .init();
         };
 
@@ -137,6 +139,7 @@ mod tests {
                         .with_strides([1, 1])
                         .with_padding(PaddingConfig2d::Valid)
                         .with_dilation([1, 1])
+                        .with_ceil_mode(false)
                         .init();
 
                     Self {
@@ -14,6 +14,7 @@ pub(crate) mod conv2d;
 pub(crate) mod conv3d;
 pub(crate) mod conv_transpose_2d;
 pub(crate) mod conv_transpose_3d;
+pub(crate) mod dequantize_linear;
 pub(crate) mod dropout;
 pub(crate) mod expand;
 pub(crate) mod gather;
@@ -28,6 +29,7 @@ pub(crate) mod max_pool2d;
 pub(crate) mod mean;
 pub(crate) mod pad;
 pub(crate) mod prelu;
+pub(crate) mod quantize_linear;
 pub(crate) mod random_normal;
 pub(crate) mod random_uniform;
 pub(crate) mod range;
@@ -31,12 +31,25 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for PadNode {
         let input = scope.tensor_use_owned(&self.input, node_position);
         let output = &self.output.name;
 
-        let pads = self.config.pads.iter().map(|p| p.to_tokens());
+        // The config pads are given as (left, right, top, bottom) on the last two dimensions.
+        let pads = &self.config.pads;
+        let padding = (0..self.input.dim - 2).map(|_| quote! { (0, 0) }).chain(
+            [
+                (pads[2] as i64, pads[3] as i64),
+                (pads[0] as i64, pads[1] as i64),
+            ]
+            .into_iter()
+            .map(|(before, after)| {
+                let before = before.to_tokens();
+                let after = after.to_tokens();
+                quote! { (#before, #after) }
+            }),
+        );
         let constant_value_string = format!("{}_f32.elem()", self.config.constant_value);
         let constant_value = TokenStream::from_str(&constant_value_string).unwrap();
 
         quote! {
-            let #output = #input.pad((#(#pads),*), #constant_value);
+            let #output = #input.pad(&[#(#padding),*], PadMode::Constant(#constant_value));
         }
     }
     fn into_node(self) -> Node<PS> {
@@ -45,6 +58,7 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for PadNode {
 
     fn register_imports(&self, imports: &mut crate::burn::BurnImports) {
         imports.register("burn::tensor::ElementConversion");
+        imports.register("burn::tensor::PadMode");
     }
 }
 
@@ -72,6 +86,7 @@ mod tests {
 
         let expected = quote! {
             use burn::tensor::ElementConversion;
+            use burn::tensor::PadMode;
             use burn::{
                 module::Module,
                 tensor::{backend::Backend, Tensor},
@@ -93,7 +108,7 @@ mod tests {
                 }
                 #[allow(clippy::let_and_return, clippy::approx_constant)]
                 pub fn forward(&self, input: Tensor<B, 2>) -> Tensor<B, 2> {
-                    let output = input.pad((1, 2, 3, 4), -1_f32.elem());
+                    let output = input.pad(&[(3, 4), (1, 2)], PadMode::Constant(-1_f32.elem()));
                     output
                 }
             }
@@ -0,0 +1,201 @@
+use super::{dequantize_linear::per_axis_tensor, Node, NodeCodegen};
+use crate::burn::{Scope, TensorType, ToTokens, Type};
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Quantizes a float tensor with `clamp(round(input / scale) + zero_point, qmin, qmax)`.
+///
+/// The values are rounded to the nearest integer, with ties rounded to the nearest even integer.
+#[derive(Debug, Clone, new)]
+pub struct QuantizeLinearNode {
+    pub input: TensorType,
+    pub output: TensorType,
+    /// A single scale, or one scale per index of the axis.
+    pub scale: Vec<f32>,
+    /// The zero-points, as many as scales.
+    pub zero_point: Vec<i64>,
+    pub axis: usize,
+    pub qmin: i32,
+    pub qmax: i32,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for QuantizeLinearNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.input.clone())]
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let output = &self.output.name;
+        let qmin = self.qmin;
+        let qmax = self.qmax;
+        let zero_point = self
+            .zero_point
+            .iter()
+            .map(|z| *z as i32)
+            .collect::<Vec<_>>();
+
+        // The cast rounds half to even, the zero-point being added to the rounded values
+        if self.scale.len() == 1 {
+            let scale = self.scale[0];
+            let zero_point = zero_point[0];
+
+            return quote! {
+                let #output = #input
+                    .div_scalar(#scale)
+                    .cast_with(burn::tensor::CastOptions::default())
+                    .add_scalar(#zero_point)
+                    .clamp(#qmin, #qmax);
+            };
+        }
+
+        let scale = per_axis_tensor(&self.scale, self.input.dim, self.axis);
+        let zero_point = per_axis_int_tensor(&zero_point, self.input.dim, self.axis);
+
+        quote! {
+            let #output = (#input
+                .div(#scale)
+                .cast_with(burn::tensor::CastOptions::default())
+                + #zero_point)
+                .clamp(#qmin, #qmax);
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::QuantizeLinear(self)
+    }
+}
+
+/// An int tensor of the given values along the axis, broadcastable to a tensor of the given rank.
+fn per_axis_int_tensor(values: &[i32], rank: usize, axis: usize) -> TokenStream {
+    let mut shape = vec![1; rank];
+    shape[axis] = values.len();
+    let shape = shape.to_tokens();
+
+    quote! {
+        Tensor::<B, 1, Int>::from_ints([#(#values),*], &*self.device).reshape(#shape)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{graph::BurnGraph, node::test::assert_tokens, TensorType};
+
+    #[test]
+    fn codegen_nodes_per_tensor() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(QuantizeLinearNode::new(
+            TensorType::new_float("tensor1", 4),
+            TensorType::new_int("tensor2", 4),
+            vec![0.5],
+            vec![3],
+            1,
+            0,
+            255,
+        ));
+
+        graph.register_input_output(vec!["tensor1".to_string()], vec!["tensor2".to_string()]);
+
+        let expected = quote! {
+            use burn::tensor::Int;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4, Int> {
+                    let tensor2 = tensor1
+                        .div_scalar(0.5f32)
+                        .cast_with(burn::tensor::CastOptions::default())
+                        .add_scalar(3i32)
+                        .clamp(0i32, 255i32);
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn codegen_nodes_per_axis() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(QuantizeLinearNode::new(
+            TensorType::new_float("tensor1", 2),
+            TensorType::new_int("tensor2", 2),
+            vec![0.5, 0.25],
+            vec![0, -1],
+            1,
+            -128,
+            127,
+        ));
+
+        graph.register_input_output(vec!["tensor1".to_string()], vec!["tensor2".to_string()]);
+
+        let expected = quote! {
+            use burn::tensor::Int;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, tensor1: Tensor<B, 2>) -> Tensor<B, 2, Int> {
+                    let tensor2 = (tensor1
+                        .div(
+                            Tensor::<B, 1>::from_floats([0.5f32, 0.25f32], &*self.device)
+                                .reshape([1, 2]),
+                        )
+                        .cast_with(burn::tensor::CastOptions::default())
+                        + Tensor::<B, 1, Int>::from_ints([0i32, -1i32], &*self.device)
+                            .reshape([1, 2]))
+                        .clamp(-128i32, 127i32);
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
@@ -161,6 +161,7 @@ pub fn max_pool1d_config(curr: &Node) -> MaxPool1dConfig {
     let mut kernel_shape = Vec::new();
     let mut stride = vec![1];
     let mut pads = vec![0, 0];
+    let mut ceil_mode: i64 = 0;
     let mut dilation = vec![1];
 
     for (key, value) in curr.attrs.iter() {
@@ -169,6 +170,7 @@ pub fn max_pool1d_config(curr: &Node) -> MaxPool1dConfig {
             "strides" => stride = value.clone().into_i64s(),
             "pads" => pads = value.clone().into_i64s(),
             "dilations" => dilation = value.clone().into_i64s(),
+            "ceil_mode" => ceil_mode = value.clone().into_i64(),
             _ => {}
         }
     }
@@ -181,6 +183,7 @@ pub fn max_pool1d_config(curr: &Node) -> MaxPool1dConfig {
         .with_stride(stride[0] as usize)
         .with_padding(padding)
         .with_dilation(dilation[0] as usize)
+        .with_ceil_mode(ceil_mode == 1)
 }
 
 /// Create a MaxPool2dConfig from the attributes of the node
@@ -188,6 +191,7 @@ pub fn max_pool2d_config(curr: &Node) -> MaxPool2dConfig {
     let mut kernel_shape = Vec::new();
     let mut strides = vec![1, 1];
     let mut pads = vec![0, 0, 0, 0];
+    let mut ceil_mode: i64 = 0;
     let mut dilations = vec![1, 1];
 
     for (key, value) in curr.attrs.iter() {
@@ -196,6 +200,7 @@ pub fn max_pool2d_config(curr: &Node) -> MaxPool2dConfig {
             "strides" => strides = value.clone().into_i64s(),
             "pads" => pads = value.clone().into_i64s(),
             "dilations" => dilations = value.clone().into_i64s(),
+            "ceil_mode" => ceil_mode = value.clone().into_i64(),
             _ => {}
         }
     }
@@ -206,6 +211,7 @@ pub fn max_pool2d_config(curr: &Node) -> MaxPool2dConfig {
         .with_strides([strides[0] as usize, strides[1] as usize])
         .with_padding(padding)
         .with_dilation([dilations[0] as usize, dilations[1] as usize])
+        .with_ceil_mode(ceil_mode == 1)
 }
 pub fn conv_transpose2d_config(curr: &Node) -> ConvTranspose2dConfig {
     let mut attrs = curr.attrs.clone();
@@ -354,16 +360,13 @@ pub fn avg_pool1d_config(curr: &Node) -> AvgPool1dConfig {
     assert_eq!(kernel_shape.len(), 1);
     assert_eq!(strides.len(), 1);
 
-    if ceil_mode == 1 {
-        panic!("ceil_mode is not supported");
-    }
-
     let padding = padding_config_1d(&pads);
 
     AvgPool1dConfig::new(kernel_shape[0] as usize)
         .with_stride(strides[0] as usize)
         .with_padding(padding)
         .with_count_include_pad(count_include_pad == 1)
+        .with_ceil_mode(ceil_mode == 1)
 }
 /// Create a AvgPool2dConfig from the attributes of the node
 pub fn avg_pool2d_config(curr: &Node) -> AvgPool2dConfig {
@@ -384,16 +387,13 @@ pub fn avg_pool2d_config(curr: &Node) -> AvgPool2dConfig {
         }
     }
 
-    if ceil_mode == 1 {
-        panic!("ceil_mode is not supported");
-    }
-
     let padding = padding_config_2d(&pads);
 
     AvgPool2dConfig::new([kernel_shape[0] as usize, kernel_shape[1] as usize])
         .with_strides([strides[0] as usize, strides[1] as usize])
         .with_padding(padding)
         .with_count_include_pad(count_include_pad == 1)
+        .with_ceil_mode(ceil_mode == 1)
 }
 
 pub fn expand_config(node: &Node) -> ExpandShape {
@@ -723,8 +723,9 @@ pub fn batch_norm_config(node: &Node) -> BatchNormConfig {
 
     let num_features: usize = tensor_type.shape.clone().unwrap()[0];
 
-    let mut epsilon = 0f32;
-    let mut momentum = 0f32;
+    // The defaults of the ONNX specification.
+    let mut epsilon = 1e-5f32;
+    let mut momentum = 0.9f32;
 
     for (key, value) in node.attrs.iter() {
         match key.as_str() {
@@ -734,9 +735,10 @@ pub fn batch_norm_config(node: &Node) -> BatchNormConfig {
         }
     }
 
+    // ONNX weights the running statistics by the momentum, while burn weights the batch ones.
     BatchNormConfig::new(num_features)
         .with_epsilon(epsilon as f64)
-        .with_momentum(momentum as f64)
+        .with_momentum(1.0 - momentum as f64)
 }
 
 /// Create a LayerNormConfig from the attributes of the node
@@ -1693,3 +1695,70 @@ pub fn squeeze_config(curr: &Node) -> Vec<i64> {
 
     axes
 }
+
+/// Create the scale, zero-point and axis of a DequantizeLinear node.
+pub fn dequantize_linear_config(node: &Node) -> (Vec<f32>, Vec<i64>, usize) {
+    quantization_params("DequantizeLinear", node)
+}
+
+/// Create the scale, zero-point, axis and quantized range of a QuantizeLinear node.
+pub fn quantize_linear_config(node: &Node) -> (Vec<f32>, Vec<i64>, usize, i32, i32) {
+    let (scale, zero_point, axis) = quantization_params("QuantizeLinear", node);
+
+    // The quantized range of the zero-point type is set when lowering the quantized operators
+    let qmin = node
+        .attrs
+        .get("qmin")
+        .map(|value| value.clone().into_i32())
+        .unwrap_or(u8::MIN as i32);
+    let qmax = node
+        .attrs
+        .get("qmax")
+        .map(|value| value.clone().into_i32())
+        .unwrap_or(u8::MAX as i32);
+
+    (scale, zero_point, axis, qmin, qmax)
+}
+
+fn quantization_params(name: &str, node: &Node) -> (Vec<f32>, Vec<i64>, usize) {
+    let rank = match &node.inputs.first().unwrap().ty {
+        ArgType::Tensor(tensor) => tensor.dim,
+        ArgType::Scalar(_) => 0,
+        _ => panic!("{name}: only tensor input is valid"),
+    };
+
+    let scale = match node.inputs.get(1).and_then(|input| input.value.clone()) {
+        Some(Data::Float32(scale)) => vec![scale],
+        Some(Data::Float32s(scale)) => scale,
+        _ => panic!("{name}: the scale must be a float32 constant"),
+    };
+    let zero_point = match node.inputs.get(2).and_then(|input| input.value.clone()) {
+        Some(Data::Int32(zero_point)) => vec![zero_point as i64],
+        Some(Data::Int32s(zero_point)) => zero_point.into_iter().map(|z| z as i64).collect(),
+        Some(_) => panic!("{name}: the zero-point must be an 8-bit integer constant"),
+        // The zero-point is 0 by default
+        None => vec![0; scale.len()],
+    };
+    assert_eq!(
+        scale.len(),
+        zero_point.len(),
+        "{name}: the scale and the zero-point must have the same size"
+    );
+
+    let mut axis = node
+        .attrs
+        .get("axis")
+        .map(|value| value.clone().into_i64())
+        .unwrap_or(1);
+    if axis < 0 {
+        axis += rank as i64;
+    }
+    if scale.len() > 1 {
+        assert!(
+            (axis as usize) < rank,
+            "{name}: the axis {axis} is out of bounds for a tensor of rank {rank}"
+        );
+    }
+
+    (scale, zero_point, axis as usize)
+}
@@ -29,6 +29,7 @@ use crate::{
             conv3d::Conv3dNode,
             conv_transpose_2d::ConvTranspose2dNode,
             conv_transpose_3d::ConvTranspose3dNode,
+            dequantize_linear::DequantizeLinearNode,
             dropout::DropoutNode,
             expand::{ExpandNode, ExpandShape},
             gather::GatherNode,
@@ -42,6 +43,7 @@ use crate::{
             max_pool2d::MaxPool2dNode,
             pad::PadNode,
             prelu::PReluNode,
+            quantize_linear::QuantizeLinearNode,
             random_normal::RandomNormalNode,
             random_uniform::RandomUniformNode,
             range::RangeNode,
@@ -63,12 +65,12 @@ use crate::{
 use super::op_configuration::{
     argmax_config, avg_pool1d_config, avg_pool2d_config, batch_norm_config, clip_config,
     concat_config, conv1d_config, conv2d_config, conv3d_config, conv_transpose2d_config,
-    conv_transpose3d_config, dropout_config, expand_config, flatten_config, gather_config,
-    hard_sigmoid_config, layer_norm_config, leaky_relu_config, linear_config, log_softmax_config,
-    max_pool1d_config, max_pool2d_config, pad_config, reduce_max_config, reduce_mean_config,
-    reduce_min_config, reduce_prod_config, reduce_sum_config, reshape_config, resize_config,
-    shape_config, slice_config, softmax_config, squeeze_config, tile_config, transpose_config,
-    unsqueeze_config,
+    conv_transpose3d_config, dequantize_linear_config, dropout_config, expand_config,
+    flatten_config, gather_config, hard_sigmoid_config, layer_norm_config, leaky_relu_config,
+    linear_config, log_softmax_config, max_pool1d_config, max_pool2d_config, pad_config,
+    quantize_linear_config, reduce_max_config, reduce_mean_config, reduce_min_config,
+    reduce_prod_config, reduce_sum_config, reshape_config, resize_config, shape_config,
+    slice_config, softmax_config, squeeze_config, tile_config, transpose_config, unsqueeze_config,
 };
 use onnx_ir::{
     convert_constant_value,
@@ -321,6 +323,10 @@ impl ParsedOnnxGraph {
                 NodeType::Concat => graph.register(Self::concat_conversion(node)),
                 NodeType::Cast => graph.register(Self::cast_conversion(node)),
                 NodeType::Dropout => graph.register(Self::dropout_conversion(node)),
+                NodeType::DequantizeLinear => {
+                    graph.register(Self::dequantize_linear_conversion(node))
+                }
+                NodeType::QuantizeLinear => graph.register(Self::quantize_linear_conversion(node)),
                 NodeType::GlobalAveragePool => {
                     graph.register(Self::global_avg_pool_conversion(node))
                 }
@@ -755,6 +761,22 @@ impl ParsedOnnxGraph {
         WhereNode::new(condition, x, y, output)
     }
 
+    fn dequantize_linear_conversion(node: Node) -> DequantizeLinearNode {
+        let input = TensorType::from(node.inputs.first().unwrap());
+        let output = TensorType::from(node.outputs.first().unwrap());
+        let (scale, zero_point, axis) = dequantize_linear_config(&node);
+
+        DequantizeLinearNode::new(input, output, scale, zero_point, axis)
+    }
+
+    fn quantize_linear_conversion(node: Node) -> QuantizeLinearNode {
+        let input = TensorType::from(node.inputs.first().unwrap());
+        let output = TensorType::from(node.outputs.first().unwrap());
+        let (scale, zero_point, axis, qmin, qmax) = quantize_linear_config(&node);
+
+        QuantizeLinearNode::new(input, output, scale, zero_point, axis, qmin, qmax)
+    }
+
     fn clip_conversion(node: Node) -> ClipNode {
         let input = TensorType::from(node.inputs.first().unwrap());
         let output = TensorType::from(node.outputs.first().unwrap());
@@ -2,12 +2,11 @@ use crate::{
     tensor::{JitTensor, QJitTensor},
     FloatElement, IntElement, JitRuntime, PrecisionBridge,
 };
-use burn_tensor::backend::{Backend, DeviceOps, SyncType};
+use burn_tensor::backend::{Backend, DeviceOps, DeviceProperties, DeviceRngs, RngState, SyncType};
 use cubecl::server::ComputeServer;
-use rand::{rngs::StdRng, SeedableRng};
-use std::{marker::PhantomData, sync::Mutex};
+use std::marker::PhantomData;
 
-pub(crate) static SEED: Mutex<Option<StdRng>> = Mutex::new(None);
+pub(crate) static SEED: DeviceRngs = DeviceRngs::new();
 
 /// Generic tensor backend that can be compiled just-in-time to any shader runtime
 #[derive(new)]
@@ -41,15 +40,29 @@ where
     }
 
     fn seed(seed: u64) {
-        let rng = StdRng::seed_from_u64(seed);
-        let mut seed = SEED.lock().unwrap();
-        *seed = Some(rng);
+        SEED.seed(seed);
+    }
+
+    fn seed_device(device: &Self::Device, seed: u64) {
+        SEED.seed_device(device.id(), seed);
+    }
+
+    fn rng_state() -> Option<RngState> {
+        Some(SEED.state())
+    }
+
+    fn restore_rng_state(state: RngState) {
+        SEED.restore(state);
     }
 
     fn ad_enabled() -> bool {
         false
     }
 
+    fn device_properties(device: &Self::Device) -> DeviceProperties {
+        crate::device_properties::<R>(device)
+    }
+
     fn sync(device: &Self::Device, sync_type: SyncType) {
         let sync = match sync_type {
             SyncType::Flush => cubecl::client::SyncType::Flush,
@@ -0,0 +1,29 @@
+use burn_tensor::backend::{DeviceId, DeviceOps, DeviceProperties};
+use std::sync::Mutex;
+
+use crate::JitRuntime;
+
+/// The properties of the devices, queried once per runtime and device.
+static PROPERTIES: Mutex<Vec<(&'static str, DeviceId, DeviceProperties)>> = Mutex::new(Vec::new());
+
+/// The [properties](DeviceProperties) of the device, queried from the runtime on the first call
+/// only.
+///
+/// Querying the properties can be expensive, wgpu enumerating the adapters of a new instance, so
+/// the kernels checking a capability or a limit before they launch should go through this cache
+/// instead of [DeviceOps::properties].
+pub fn device_properties<R: JitRuntime>(device: &R::Device) -> DeviceProperties {
+    let id = device.id();
+    let mut devices = PROPERTIES.lock().unwrap();
+    if let Some((_, _, properties)) = devices
+        .iter()
+        .find(|(name, device, _)| *name == R::name() && *device == id)
+    {
+        return properties.clone();
+    }
+
+    let properties = device.properties();
+    devices.push((R::name(), id, properties.clone()));
+
+    properties
+}
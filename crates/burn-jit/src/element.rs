@@ -1,5 +1,7 @@
+use crate::{device_properties, JitRuntime};
+use burn_tensor::DType;
 use cubecl::{
-    frontend::{Float, Int, Numeric, UInt, BF16, F16, F32, I32},
+    frontend::{Atomic, AtomicI32, AtomicUInt, Float, Int, Numeric, UInt, BF16, F16, F32, I32},
     CubeElement,
 };
 
@@ -53,3 +55,66 @@ impl FloatElement for half::f16 {
 impl IntElement for i32 {
     type IntPrimitive = I32;
 }
+
+/// The element types with an atomic counterpart in the kernels.
+pub trait AtomicElement: JitElement {
+    /// The atomic cube type of the element, updated in global or shared memory with `add`,
+    /// `max`, `min` and `compare_and_swap`.
+    type Atomic: Atomic<Primitive = Self::Primitive>;
+}
+
+impl AtomicElement for u32 {
+    type Atomic = AtomicUInt;
+}
+
+impl AtomicElement for i32 {
+    type Atomic = AtomicI32;
+}
+
+/// Whether the kernels of the float element type can run on the device, as reported by its
+/// [properties](burn_tensor::backend::DeviceProperties).
+///
+/// The half precision floats need the device to support them, which isn't the case of every
+/// device, like the `bf16` floats on wgpu.
+pub fn is_float_supported<R: JitRuntime, E: FloatElement>(device: &R::Device) -> bool {
+    if !matches!(E::dtype(), DType::F16 | DType::BF16) {
+        return true;
+    }
+
+    let properties = device_properties::<R>(device);
+    match E::dtype() {
+        DType::F16 => properties.supports_f16,
+        _ => properties.supports_bf16,
+    }
+}
+
+/// Whether the kernels using the atomic counterpart of the element type can run on the device.
+///
+/// The 64 bit atomics need the device to support them, which isn't the case of every device.
+pub fn is_atomic_supported<R: JitRuntime, E: AtomicElement>(device: &R::Device) -> bool {
+    core::mem::size_of::<E>() < 8 || device_properties::<R>(device).supports_atomic_64
+}
+
+/// Panics with a capability error when the atomic counterpart of the element type can't
+/// [run](is_atomic_supported) on the device, before a kernel using it is compiled.
+pub fn assert_atomic_supported<R: JitRuntime, E: AtomicElement>(device: &R::Device) {
+    assert!(
+        is_atomic_supported::<R, E>(device),
+        "The atomic type of {:?} isn't supported by the device {:?}, {}.",
+        E::dtype(),
+        device,
+        device_properties::<R>(device).name,
+    );
+}
+
+/// Panics with a capability error when the float element type can't
+/// [run](is_float_supported) on the device, before a kernel using it is compiled.
+pub fn assert_float_supported<R: JitRuntime, E: FloatElement>(device: &R::Device) {
+    assert!(
+        is_float_supported::<R, E>(device),
+        "The float type {:?} isn't supported by the device {:?}, {}.",
+        E::dtype(),
+        device,
+        device_properties::<R>(device).name,
+    );
+}
@@ -0,0 +1,365 @@
+use super::{ScoreScale, ScoreSoftmax, ScoreSoftmaxDescription};
+use crate::{
+    fusion::{
+        matmul::{is_consumed, is_read},
+        JitOptimization,
+    },
+    JitRuntime,
+};
+use burn_fusion::{OptimizationBuilder, OptimizationProperties, OptimizationStatus};
+use burn_tensor::repr::{
+    BinaryOperationDescription, FloatOperationDescription, NumericOperationDescription,
+    OperationDescription, TensorDescription,
+};
+
+/// Fuse a matmul, optionally scaled by a scalar, with the
+/// [softmax](burn_tensor::activation::softmax) over the last dimension of its output, like the
+/// attention probabilities.
+pub(crate) struct ScoreSoftmaxBuilder<R: JitRuntime> {
+    pattern: ScoreSoftmaxPattern,
+    device: R::Device,
+}
+
+/// Match the operations of the default softmax implementation applied on the scores, as they are
+/// registered.
+///
+/// The scale is only known when executing, so it is read from the float scalars of the context
+/// at the position it was registered in, instead of being matched here. The shapes are relative as well, so the rows too wide to be kept in shared
+/// memory are only handled when launching.
+#[derive(Default)]
+struct ScoreSoftmaxPattern {
+    matmul: Option<BinaryOperationDescription>,
+    scale: Option<ScoreScale>,
+    /// The position of the scale in the float scalars registered by the operations.
+    scale_position: usize,
+    /// The number of float scalars registered by the operations, which are pushed to the context
+    /// in the same order.
+    num_scalars: usize,
+    /// The scores, which are the output of the matmul or of its scaling.
+    scores: Option<TensorDescription>,
+    max: Option<TensorDescription>,
+    shifted: Option<TensorDescription>,
+    exp: Option<TensorDescription>,
+    sum: Option<TensorDescription>,
+    num_registered: usize,
+    complete: Option<ScoreSoftmaxDescription>,
+    closed: bool,
+}
+
+impl<R: JitRuntime> OptimizationBuilder<JitOptimization<R>> for ScoreSoftmaxBuilder<R> {
+    fn register(&mut self, operation: &OperationDescription) {
+        self.pattern.register(operation);
+    }
+
+    fn build(&self) -> JitOptimization<R> {
+        let description = self
+            .pattern
+            .complete
+            .clone()
+            .expect("The score softmax should be complete when built.");
+
+        JitOptimization::ScoreSoftmax(ScoreSoftmax::new(description, self.device.clone()))
+    }
+
+    fn len(&self) -> usize {
+        self.pattern.len()
+    }
+
+    fn reset(&mut self) {
+        self.pattern = ScoreSoftmaxPattern::default();
+    }
+
+    fn status(&self) -> OptimizationStatus {
+        match self.pattern.closed {
+            true => OptimizationStatus::Closed,
+            false => OptimizationStatus::Open,
+        }
+    }
+
+    fn properties(&self) -> OptimizationProperties {
+        OptimizationProperties {
+            ready: self.pattern.complete.is_some(),
+            score: self.len() as u64,
+        }
+    }
+}
+
+impl<R: JitRuntime> ScoreSoftmaxBuilder<R> {
+    pub fn new(device: R::Device) -> Self {
+        Self {
+            pattern: ScoreSoftmaxPattern::default(),
+            device,
+        }
+    }
+}
+
+impl ScoreSoftmaxPattern {
+    fn register(&mut self, operation: &OperationDescription) {
+        if self.closed {
+            return;
+        }
+
+        if !self.register_operation(operation) {
+            self.closed = true;
+            return;
+        }
+
+        self.num_registered += 1;
+
+        // Nothing is fused after the softmax.
+        if self.complete.is_some() {
+            self.closed = true;
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.complete
+            .as_ref()
+            .map(|description| description.num_operations)
+            .unwrap_or(0)
+    }
+
+    fn register_operation(&mut self, operation: &OperationDescription) -> bool {
+        use NumericOperationDescription as Numeric;
+
+        let Some(scores) = self.scores.clone() else {
+            return self.register_matmul(operation);
+        };
+        let last_dim = scores.shape.len() - 1;
+
+        match operation {
+            OperationDescription::NumericFloat(Numeric::MulScalar(desc))
+            | OperationDescription::NumericFloat(Numeric::DivScalar(desc))
+                if self.scale.is_none()
+                    && self.max.is_none()
+                    && is_consumed(&desc.lhs, &scores) =>
+            {
+                self.scale = match operation {
+                    OperationDescription::NumericFloat(Numeric::MulScalar(_)) => {
+                        Some(ScoreScale::Mul)
+                    }
+                    _ => Some(ScoreScale::Div),
+                };
+                self.scale_position = self.num_scalars;
+                self.num_scalars += 1;
+                self.scores = Some(desc.out.clone());
+                true
+            }
+            OperationDescription::NumericFloat(Numeric::MaxDim(desc))
+                if self.max.is_none() && is_read(&desc.lhs, &scores) && desc.rhs == last_dim =>
+            {
+                self.max = Some(desc.out.clone());
+                true
+            }
+            OperationDescription::NumericFloat(Numeric::Sub(desc)) => match &self.max {
+                Some(max)
+                    if self.shifted.is_none()
+                        && is_consumed(&desc.lhs, &scores)
+                        && is_consumed(&desc.rhs, max) =>
+                {
+                    self.shifted = Some(desc.out.clone());
+                    true
+                }
+                _ => false,
+            },
+            OperationDescription::Float(FloatOperationDescription::Exp(desc)) => {
+                match &self.shifted {
+                    Some(shifted) if self.exp.is_none() && is_consumed(&desc.input, shifted) => {
+                        self.exp = Some(desc.out.clone());
+                        true
+                    }
+                    _ => false,
+                }
+            }
+            OperationDescription::NumericFloat(Numeric::SumDim(desc)) => match &self.exp {
+                Some(exp)
+                    if self.sum.is_none() && is_read(&desc.lhs, exp) && desc.rhs == last_dim =>
+                {
+                    self.sum = Some(desc.out.clone());
+                    true
+                }
+                _ => false,
+            },
+            OperationDescription::NumericFloat(Numeric::Div(desc)) => {
+                match (&self.exp, &self.sum) {
+                    (Some(exp), Some(sum))
+                        if is_consumed(&desc.lhs, exp) && is_consumed(&desc.rhs, sum) =>
+                    {
+                        self.complete = Some(ScoreSoftmaxDescription {
+                            matmul: self.matmul.clone().expect("Registered after the matmul"),
+                            scale: self.scale,
+                            scale_position: self.scale_position,
+                            out: desc.out.clone(),
+                            num_operations: self.num_registered + 1,
+                        });
+                        true
+                    }
+                    _ => false,
+                }
+            }
+            _ => false,
+        }
+    }
+
+    fn register_matmul(&mut self, operation: &OperationDescription) -> bool {
+        let OperationDescription::Float(FloatOperationDescription::Matmul(desc)) = operation else {
+            return false;
+        };
+
+        if desc.out.shape.len() < 2 {
+            return false;
+        }
+
+        self.matmul = Some(desc.clone());
+        self.scores = Some(desc.out.clone());
+
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn_tensor::{
+        repr::{ScalarOperationDescription, TensorId, TensorStatus, UnaryOperationDescription},
+        DType,
+    };
+
+    const SHAPE: [usize; 3] = [2, 3, 5];
+    const REDUCED: [usize; 3] = [2, 3, 1];
+
+    fn tensor(id: u64, shape: &[usize], status: TensorStatus) -> TensorDescription {
+        TensorDescription {
+            id: TensorId::new(id),
+            shape: shape.to_vec(),
+            status,
+            dtype: DType::F32,
+        }
+    }
+
+    fn read_write(id: u64, shape: &[usize]) -> TensorDescription {
+        tensor(id, shape, TensorStatus::ReadWrite)
+    }
+
+    fn read_only(id: u64, shape: &[usize]) -> TensorDescription {
+        tensor(id, shape, TensorStatus::ReadOnly)
+    }
+
+    fn not_init(id: u64, shape: &[usize]) -> TensorDescription {
+        tensor(id, shape, TensorStatus::NotInit)
+    }
+
+    /// The operations of `softmax(q.matmul(k) / s, 2)`, producing tensor `9`.
+    fn attention(scores_status: TensorStatus) -> Vec<OperationDescription> {
+        use NumericOperationDescription as Numeric;
+
+        vec![
+            OperationDescription::Float(FloatOperationDescription::Matmul(
+                BinaryOperationDescription {
+                    lhs: read_write(0, &[2, 3, 4]),
+                    rhs: read_write(1, &[2, 4, 5]),
+                    out: not_init(2, &SHAPE),
+                },
+            )),
+            OperationDescription::NumericFloat(Numeric::DivScalar(ScalarOperationDescription {
+                lhs: read_write(2, &SHAPE),
+                rhs: 0.0,
+                out: not_init(3, &SHAPE),
+            })),
+            OperationDescription::NumericFloat(Numeric::MaxDim(ScalarOperationDescription {
+                lhs: read_only(3, &SHAPE),
+                rhs: 2,
+                out: not_init(4, &REDUCED),
+            })),
+            OperationDescription::NumericFloat(Numeric::Sub(BinaryOperationDescription {
+                lhs: tensor(3, &SHAPE, scores_status),
+                rhs: read_write(4, &REDUCED),
+                out: not_init(5, &SHAPE),
+            })),
+            OperationDescription::Float(FloatOperationDescription::Exp(
+                UnaryOperationDescription {
+                    input: read_write(5, &SHAPE),
+                    out: not_init(6, &SHAPE),
+                },
+            )),
+            OperationDescription::NumericFloat(Numeric::SumDim(ScalarOperationDescription {
+                lhs: read_only(6, &SHAPE),
+                rhs: 2,
+                out: not_init(7, &REDUCED),
+            })),
+            OperationDescription::NumericFloat(Numeric::Div(BinaryOperationDescription {
+                lhs: read_write(6, &SHAPE),
+                rhs: read_write(7, &REDUCED),
+                out: not_init(9, &SHAPE),
+            })),
+        ]
+    }
+
+    fn register_all(operations: &[OperationDescription]) -> ScoreSoftmaxPattern {
+        let mut pattern = ScoreSoftmaxPattern::default();
+        operations
+            .iter()
+            .for_each(|operation| pattern.register(operation));
+        pattern
+    }
+
+    #[test]
+    fn scaled_attention_scores_should_be_fused_into_a_single_operation() {
+        let pattern = register_all(&attention(TensorStatus::ReadWrite));
+        let description = pattern.complete.as_ref().unwrap();
+
+        assert_eq!(pattern.len(), 7);
+        assert!(pattern.closed);
+        assert_eq!(description.scale, Some(ScoreScale::Div));
+        assert_eq!(description.scale_position, 0);
+        assert_eq!(description.out.id, TensorId::new(9));
+    }
+
+    #[test]
+    fn attention_scores_without_scale_should_be_fused_into_a_single_operation() {
+        let mut operations = attention(TensorStatus::ReadWrite);
+        operations.remove(1);
+        // Without scaling, the softmax is applied on the output of the matmul.
+        for operation in operations.iter_mut() {
+            match operation {
+                OperationDescription::NumericFloat(NumericOperationDescription::MaxDim(desc)) => {
+                    desc.lhs.id = TensorId::new(2);
+                }
+                OperationDescription::NumericFloat(NumericOperationDescription::Sub(desc)) => {
+                    desc.lhs.id = TensorId::new(2);
+                }
+                _ => {}
+            }
+        }
+
+        let pattern = register_all(&operations);
+        let description = pattern.complete.as_ref().unwrap();
+
+        assert_eq!(pattern.len(), 6);
+        assert_eq!(description.scale, None);
+    }
+
+    #[test]
+    fn scores_used_later_should_not_be_fused() {
+        let pattern = register_all(&attention(TensorStatus::ReadOnly));
+
+        assert_eq!(pattern.len(), 0);
+        assert!(pattern.closed);
+        assert!(pattern.complete.is_none());
+    }
+
+    #[test]
+    fn softmax_over_another_dimension_should_not_be_fused() {
+        let mut operations = attention(TensorStatus::ReadWrite);
+        if let OperationDescription::NumericFloat(NumericOperationDescription::MaxDim(desc)) =
+            &mut operations[2]
+        {
+            desc.rhs = 1;
+        }
+
+        let pattern = register_all(&operations);
+
+        assert!(pattern.complete.is_none());
+        assert!(pattern.closed);
+    }
+}
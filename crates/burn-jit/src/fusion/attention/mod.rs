@@ -0,0 +1,5 @@
+mod builder;
+mod optimization;
+
+pub(crate) use builder::*;
+pub(crate) use optimization::*;
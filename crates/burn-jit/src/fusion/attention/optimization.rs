@@ -0,0 +1,121 @@
+use crate::{
+    fusion::{
+        matmul::{handle_ref, input},
+        strides_dyn_rank, JitFusionHandle,
+    },
+    kernel::attention::launch_score_softmax,
+    FloatElement, JitRuntime,
+};
+use burn_fusion::stream::Context;
+use burn_tensor::{
+    repr::{BinaryOperationDescription, TensorDescription},
+    DType,
+};
+use half::{bf16, f16};
+use serde::{Deserialize, Serialize};
+
+/// How the scores are scaled before the softmax.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ScoreScale {
+    /// The scores are multiplied by the scalar.
+    Mul,
+    /// The scores are divided by the scalar.
+    Div,
+}
+
+/// A matmul followed by a softmax over the last dimension of its output, as captured by the
+/// [builder](super::ScoreSoftmaxBuilder).
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ScoreSoftmaxDescription {
+    /// The matmul operation.
+    pub matmul: BinaryOperationDescription,
+    /// The scaling of the scores, if any.
+    pub scale: Option<ScoreScale>,
+    /// The position of the scale in the float scalars of the fused operations, in the order they
+    /// were registered.
+    pub scale_position: usize,
+    /// The output of the softmax.
+    pub out: TensorDescription,
+    /// The number of operations fused.
+    pub num_operations: usize,
+}
+
+#[derive(new)]
+pub struct ScoreSoftmax<R: JitRuntime> {
+    description: ScoreSoftmaxDescription,
+    device: R::Device,
+}
+
+#[derive(new, Serialize, Deserialize)]
+pub struct ScoreSoftmaxState {
+    description: ScoreSoftmaxDescription,
+}
+
+impl<R: JitRuntime> ScoreSoftmax<R> {
+    pub(crate) fn execute(&mut self, context: &mut Context<'_, JitFusionHandle<R>>) {
+        match self.description.out.dtype {
+            DType::F32 => self.execute_typed::<f32>(context),
+            DType::F16 => self.execute_typed::<f16>(context),
+            DType::BF16 => self.execute_typed::<bf16>(context),
+            dtype => panic!("Score softmax doesn't support {dtype:?}"),
+        }
+    }
+
+    fn execute_typed<E: FloatElement>(&self, context: &mut Context<'_, JitFusionHandle<R>>) {
+        let client = R::client(&self.device);
+        let description = &self.description;
+
+        let (lhs, lhs_shape) = input(context, &description.matmul.lhs);
+        let (rhs, rhs_shape) = input(context, &description.matmul.rhs);
+
+        let out_shape = context
+            .tensors
+            .get(&description.out.id)
+            .unwrap()
+            .shape
+            .clone();
+        let num_bytes = out_shape.iter().product::<usize>() * core::mem::size_of::<E>();
+        crate::perf::record_allocation(num_bytes);
+        let out = JitFusionHandle {
+            client: client.clone(),
+            device: self.device.clone(),
+            strides: strides_dyn_rank(&out_shape),
+            handle: client.empty(num_bytes),
+        };
+
+        let scale = match description.scale {
+            Some(ScoreScale::Mul) => context.scalar_floats[description.scale_position],
+            Some(ScoreScale::Div) => 1.0 / context.scalar_floats[description.scale_position],
+            None => 1.0,
+        };
+
+        launch_score_softmax::<R, E>(
+            &client,
+            &self.device,
+            handle_ref(&lhs, &lhs_shape),
+            handle_ref(&rhs, &rhs_shape),
+            handle_ref(&out, &out_shape),
+            scale,
+        );
+
+        let out_id = context.tensors.get(&description.out.id).unwrap().id;
+        context.handles.register_handle(out_id, out);
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.description.num_operations
+    }
+
+    pub(crate) fn from_state(device: &R::Device, state: ScoreSoftmaxState) -> Self {
+        Self {
+            description: state.description,
+            device: device.clone(),
+        }
+    }
+
+    pub(crate) fn to_state(&self) -> ScoreSoftmaxState {
+        ScoreSoftmaxState {
+            description: self.description.clone(),
+        }
+    }
+}
@@ -1,4 +1,7 @@
-use super::{ElementWise, ElementWiseState};
+use super::{
+    ElementWise, ElementWiseState, MatmulEpilogue, MatmulEpilogueBuilder, MatmulEpilogueState,
+    ScoreSoftmax, ScoreSoftmaxBuilder, ScoreSoftmaxState,
+};
 use crate::{
     element::JitElement, fusion::ElementWiseBuilder, kernel, tensor::JitTensor, FloatElement,
     IntElement, JitBackend, JitRuntime,
@@ -17,6 +20,10 @@ use serde::{Deserialize, Serialize};
 pub enum JitOptimization<R: JitRuntime> {
     /// Element wise optimization.
     ElementWise(ElementWise<R>),
+    /// Matmul with a fused epilogue optimization.
+    MatmulEpilogue(MatmulEpilogue<R>),
+    /// Matmul with a fused softmax optimization.
+    ScoreSoftmax(ScoreSoftmax<R>),
 }
 
 /// Fusion optimization state type for JIT.
@@ -26,6 +33,10 @@ pub enum JitOptimization<R: JitRuntime> {
 pub enum JitOptimizationState {
     /// Element wise state.
     ElementWise(ElementWiseState),
+    /// Matmul with a fused epilogue state.
+    MatmulEpilogue(MatmulEpilogueState),
+    /// Matmul with a fused softmax state.
+    ScoreSoftmax(ScoreSoftmaxState),
 }
 
 impl<R> burn_fusion::Optimization<FusionJitRuntime<R>> for JitOptimization<R>
@@ -35,18 +46,24 @@ where
     fn execute(&mut self, context: &mut burn_fusion::stream::Context<'_, JitFusionHandle<R>>) {
         match self {
             Self::ElementWise(op) => op.execute(context),
+            Self::MatmulEpilogue(op) => op.execute(context),
+            Self::ScoreSoftmax(op) => op.execute(context),
         }
     }
 
     fn len(&self) -> usize {
         match self {
             Self::ElementWise(op) => op.len(),
+            Self::MatmulEpilogue(op) => op.len(),
+            Self::ScoreSoftmax(op) => op.len(),
         }
     }
 
     fn to_state(&self) -> JitOptimizationState {
         match self {
             Self::ElementWise(value) => JitOptimizationState::ElementWise(value.to_state()),
+            Self::MatmulEpilogue(value) => JitOptimizationState::MatmulEpilogue(value.to_state()),
+            Self::ScoreSoftmax(value) => JitOptimizationState::ScoreSoftmax(value.to_state()),
         }
     }
 
@@ -55,6 +72,12 @@ where
             JitOptimizationState::ElementWise(state) => {
                 Self::ElementWise(ElementWise::from_state(device, state))
             }
+            JitOptimizationState::MatmulEpilogue(state) => {
+                Self::MatmulEpilogue(MatmulEpilogue::from_state(device, state))
+            }
+            JitOptimizationState::ScoreSoftmax(state) => {
+                Self::ScoreSoftmax(ScoreSoftmax::from_state(device, state))
+            }
         }
     }
 }
@@ -112,7 +135,11 @@ impl<R: JitRuntime> FusionRuntime for FusionJitRuntime<R> {
     fn optimizations(
         device: R::Device,
     ) -> Vec<Box<dyn burn_fusion::OptimizationBuilder<Self::Optimization>>> {
-        vec![Box::new(ElementWiseBuilder::<R>::new(device))]
+        vec![
+            Box::new(ElementWiseBuilder::<R>::new(device.clone())),
+            Box::new(MatmulEpilogueBuilder::<R>::new(device.clone())),
+            Box::new(ScoreSoftmaxBuilder::<R>::new(device)),
+        ]
     }
 }
 
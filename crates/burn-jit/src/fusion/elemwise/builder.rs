@@ -7,9 +7,10 @@ use crate::{
 use burn_fusion::{OptimizationBuilder, OptimizationProperties, OptimizationStatus};
 use burn_tensor::{
     repr::{
-        BaseOperationDescription, BinaryOperationDescription, FloatOperationDescription,
-        NumericOperationDescription, OperationDescription, ScalarOperationDescription,
-        TensorDescription, UnaryOperationDescription,
+        BaseOperationDescription, BinaryOperationDescription, BoolOperationDescription,
+        FloatOperationDescription, IntOperationDescription, NumericOperationDescription,
+        OperationDescription, ScalarOperationDescription, TensorDescription,
+        UnaryOperationDescription,
     },
     Element,
 };
@@ -46,6 +47,24 @@ impl<R: JitRuntime> OptimizationBuilder<JitOptimization<R>> for ElementWiseBuild
                     return;
                 }
             }
+            OperationDescription::BaseBool(ops) => {
+                if !self.register_base(ops) {
+                    self.status = OptimizationStatus::Closed;
+                    return;
+                }
+            }
+            OperationDescription::Bool(ops) => {
+                if !self.register_bool(ops) {
+                    self.status = OptimizationStatus::Closed;
+                    return;
+                }
+            }
+            OperationDescription::Int(ops) => {
+                if !self.register_int(ops) {
+                    self.status = OptimizationStatus::Closed;
+                    return;
+                }
+            }
             OperationDescription::Float(ops) => {
                 if !self.register_float(ops) {
                     self.status = OptimizationStatus::Closed;
@@ -134,6 +153,51 @@ impl<R: JitRuntime> ElementWiseBuilder<R> {
         }
     }
 
+    fn register_bool(&mut self, ops: &BoolOperationDescription) -> bool {
+        match ops {
+            BoolOperationDescription::IntoFloat(desc) => self
+                .register_unary_ops(desc, |input, out| {
+                    Operator::Assign(UnaryOperator { input, out })
+                }),
+            BoolOperationDescription::IntoInt(desc) => self
+                .register_unary_ops(desc, |input, out| {
+                    Operator::Assign(UnaryOperator { input, out })
+                }),
+            BoolOperationDescription::Not(desc) => self.register_unary_ops(desc, |input, out| {
+                Operator::Not(UnaryOperator { input, out })
+            }),
+            BoolOperationDescription::ArgwherePadded(_) => false,
+        }
+    }
+
+    fn register_int(&mut self, ops: &IntOperationDescription) -> bool {
+        match ops {
+            IntOperationDescription::IntoFloat(desc) => self
+                .register_unary_ops(desc, |input, out| {
+                    Operator::Assign(UnaryOperator { input, out })
+                }),
+            IntOperationDescription::BitwiseAnd(desc) => self
+                .register_binary_ops(desc, |lhs, rhs, out| {
+                    Operator::BitwiseAnd(BinaryOperator { lhs, rhs, out })
+                }),
+            IntOperationDescription::BitwiseAndScalar(desc) => self
+                .register_scalar_ops(desc, |lhs, rhs, out| {
+                    Operator::BitwiseAnd(BinaryOperator { lhs, rhs, out })
+                }),
+            IntOperationDescription::BitwiseXor(desc) => self
+                .register_binary_ops(desc, |lhs, rhs, out| {
+                    Operator::BitwiseXor(BinaryOperator { lhs, rhs, out })
+                }),
+            IntOperationDescription::BitwiseXorScalar(desc) => self
+                .register_scalar_ops(desc, |lhs, rhs, out| {
+                    Operator::BitwiseXor(BinaryOperator { lhs, rhs, out })
+                }),
+            // The shifts mask their amounts before the shift kernel, so they run unfused like the
+            // operations without a traced operator.
+            _ => false,
+        }
+    }
+
     fn register_float(&mut self, ops: &FloatOperationDescription) -> bool {
         match ops {
             FloatOperationDescription::Exp(desc) => self.register_unary_ops(desc, |input, out| {
@@ -166,6 +230,10 @@ impl<R: JitRuntime> ElementWiseBuilder<R> {
                 .register_unary_ops(desc, |input, out| {
                     Operator::Recip(UnaryOperator { input, out })
                 }),
+            FloatOperationDescription::IntoInt(desc) => self
+                .register_unary_ops(desc, |input, out| {
+                    Operator::Assign(UnaryOperator { input, out })
+                }),
             _ => false,
         }
     }
@@ -6,6 +6,7 @@ use super::{
 };
 use crate::{
     fusion::{kernel::FusionKernel, tracing::Trace, JitFusionHandle},
+    tune_cache,
     tune_key::JitAutotuneKey,
     JitRuntime, JitTuneId,
 };
@@ -68,6 +69,20 @@ impl<R: JitRuntime> ElementWise<R, CompilationPhase> {
 impl<R: JitRuntime> ElementWise<R, ExecutionPhase<R>> {
     pub(crate) fn execute(&mut self, context: &mut Context<'_, JitFusionHandle<R>>) {
         let client = R::client(&self.device);
+        let info = self.trace.running();
+
+        // Empty outputs only need their handles registered, so the kernel is never launched.
+        if info.outputs.iter().all(|output| output.shape.contains(&0)) {
+            FusionKernel::create(
+                &self.phase.kernel_factory_1,
+                &info,
+                context,
+                self.device.clone(),
+                client,
+                true,
+            );
+            return;
+        }
 
         let key = JitAutotuneKey::FusionElemWise(FusionElemWiseAutotuneKey::new(
             self.num_operations,
@@ -80,8 +95,15 @@ impl<R: JitRuntime> ElementWise<R, ExecutionPhase<R>> {
 
         if let Some(index) = TUNER.autotune_result(&id, &key) {
             self.run_kernel(context, client, index)
+        } else if let Some(index) = tune_cache::persisted_result::<R>(&self.device, &key, 2) {
+            self.run_kernel(context, client, index)
         } else {
-            self.run_autotune(context, client, id, key, &TUNER)
+            tune_cache::record_tuning();
+            self.run_autotune(context, client, id.clone(), key.clone(), &TUNER);
+
+            if let Some(index) = TUNER.autotune_result(&id, &key) {
+                tune_cache::persist_result::<R>(&self.device, &key, index, 2);
+            }
         }
     }
 
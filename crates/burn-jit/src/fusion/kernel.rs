@@ -70,6 +70,8 @@ pub enum OutputRuntimeInfo {
 impl<R: JitRuntime> ExecutableKernel<R> {
     /// Execute the kernel.
     pub fn execute(self) {
+        crate::perf::record_kernel();
+
         unsafe {
             self.client
                 .execute_unchecked(self.kernel, self.cube_count, self.bindings)
@@ -79,6 +81,7 @@ impl<R: JitRuntime> ExecutableKernel<R> {
 
 impl<R: JitRuntime> AutotuneOperation for AutotunableKernel<R> {
     fn execute(self: Box<Self>) {
+        crate::perf::record_kernel();
         self.client
             .execute(Box::new(self.kernel), self.count, self.bindings)
     }
@@ -185,6 +188,7 @@ impl<R: JitRuntime> FusionKernel<R> {
                 }
                 // Create a new buffer for this output.
                 OutputRuntimeInfo::Array { size } => {
+                    crate::perf::record_allocation(*size);
                     let handle_fusion = JitFusionHandle {
                         client: client.clone(),
                         device: device.clone(),
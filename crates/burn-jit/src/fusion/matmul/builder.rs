@@ -0,0 +1,535 @@
+use super::{MatmulEpilogue, MatmulEpilogueDescription};
+use crate::{fusion::JitOptimization, kernel::matmul::EpilogueActivation, JitRuntime};
+use burn_fusion::{OptimizationBuilder, OptimizationProperties, OptimizationStatus};
+use burn_tensor::repr::{
+    BaseOperationDescription, BinaryOperationDescription, FloatOperationDescription,
+    NumericOperationDescription, OperationDescription, ReshapeDescription, TensorDescription,
+    TensorStatus,
+};
+
+/// Fuse a matmul with the elementwise operations directly applied on its output: a bias
+/// broadcasted over the last dimension followed by one [activation](EpilogueActivation).
+pub(crate) struct MatmulEpilogueBuilder<R: JitRuntime> {
+    pattern: MatmulEpiloguePattern,
+    device: R::Device,
+}
+
+/// Match the operations of a matmul epilogue as they are registered.
+///
+/// Activations are captured from the operations they are composed of, so the patterns must match
+/// the default implementations of [ActivationOps](burn_tensor::ops::ActivationOps). The scalars
+/// of those patterns are only known when executing, so their positions in the context are
+/// recorded instead of being matched here.
+#[derive(Default)]
+struct MatmulEpiloguePattern {
+    matmul: Option<BinaryOperationDescription>,
+    bias_reshape: Option<ReshapeDescription>,
+    bias: Option<TensorDescription>,
+    activation: Option<EpilogueActivation>,
+    /// Operations of the activation currently being matched.
+    pending: Vec<OperationDescription>,
+    /// The positions in the context of the float scalars of the pending operations.
+    pending_scalars: Vec<usize>,
+    /// The number of float scalars of the registered operations, listed by the context in the
+    /// order of the operations.
+    num_scalars: usize,
+    /// The output of the last matched stage, which the next stage must consume.
+    head: Option<TensorDescription>,
+    num_registered: usize,
+    /// The fused operations up to the last complete stage.
+    complete: Option<MatmulEpilogueDescription>,
+    closed: bool,
+}
+
+/// The result of matching the operations of an activation.
+enum ActivationMatch {
+    /// The operations match the start of the pattern.
+    Partial,
+    /// The operations match the whole pattern, producing the given tensor.
+    Complete(EpilogueActivation, TensorDescription),
+    /// The operations don't match any pattern.
+    Invalid,
+}
+
+impl<R: JitRuntime> OptimizationBuilder<JitOptimization<R>> for MatmulEpilogueBuilder<R> {
+    fn register(&mut self, operation: &OperationDescription) {
+        self.pattern.register(operation);
+    }
+
+    fn build(&self) -> JitOptimization<R> {
+        let description = self
+            .pattern
+            .complete
+            .clone()
+            .expect("The matmul epilogue should be complete when built.");
+
+        JitOptimization::MatmulEpilogue(MatmulEpilogue::new(description, self.device.clone()))
+    }
+
+    fn len(&self) -> usize {
+        self.pattern.len()
+    }
+
+    fn reset(&mut self) {
+        self.pattern = MatmulEpiloguePattern::default();
+    }
+
+    fn status(&self) -> OptimizationStatus {
+        match self.pattern.closed {
+            true => OptimizationStatus::Closed,
+            false => OptimizationStatus::Open,
+        }
+    }
+
+    fn properties(&self) -> OptimizationProperties {
+        OptimizationProperties {
+            ready: self.pattern.is_ready(),
+            score: self.len() as u64,
+        }
+    }
+}
+
+impl<R: JitRuntime> MatmulEpilogueBuilder<R> {
+    pub fn new(device: R::Device) -> Self {
+        Self {
+            pattern: MatmulEpiloguePattern::default(),
+            device,
+        }
+    }
+}
+
+impl MatmulEpiloguePattern {
+    fn register(&mut self, operation: &OperationDescription) {
+        if self.closed {
+            return;
+        }
+
+        let accepted = match self.matmul {
+            None => self.register_matmul(operation),
+            Some(_) => self.register_epilogue(operation),
+        };
+
+        if !accepted {
+            self.closed = true;
+            return;
+        }
+
+        self.num_registered += 1;
+    }
+
+    fn len(&self) -> usize {
+        self.complete
+            .as_ref()
+            .map(|description| description.num_operations)
+            .unwrap_or(0)
+    }
+
+    fn is_ready(&self) -> bool {
+        // A matmul without epilogue is better executed by the matmul kernels of the backend.
+        self.complete
+            .as_ref()
+            .map(|description| description.bias.is_some() || description.activation.is_some())
+            .unwrap_or(false)
+    }
+
+    fn register_matmul(&mut self, operation: &OperationDescription) -> bool {
+        let OperationDescription::Float(FloatOperationDescription::Matmul(desc)) = operation else {
+            return false;
+        };
+
+        if desc.out.shape.len() < 2 {
+            return false;
+        }
+
+        self.matmul = Some(desc.clone());
+        self.head = Some(desc.out.clone());
+        self.complete(Vec::new(), 1);
+
+        true
+    }
+
+    fn register_epilogue(&mut self, operation: &OperationDescription) -> bool {
+        let head = self.head.clone().expect("Registered after the matmul");
+
+        if !self.pending.is_empty() || self.bias.is_some() || !self.register_bias(operation, &head)
+        {
+            return self.register_activation(operation, &head);
+        }
+
+        true
+    }
+
+    fn register_bias(
+        &mut self,
+        operation: &OperationDescription,
+        head: &TensorDescription,
+    ) -> bool {
+        if self.activation.is_some() {
+            return false;
+        }
+
+        match operation {
+            // The bias is often reshaped to the rank of the output right before being added.
+            OperationDescription::BaseFloat(BaseOperationDescription::Reshape(desc)) => {
+                if self.bias_reshape.is_some() || desc.input.id == head.id {
+                    return false;
+                }
+
+                let num_cols = desc.input.shape.last().copied().unwrap_or(0);
+                let is_vector = desc.input.shape.iter().product::<usize>() == num_cols;
+
+                if !is_vector || !is_bias(&desc.out, head) {
+                    return false;
+                }
+
+                self.bias_reshape = Some(desc.clone());
+                true
+            }
+            OperationDescription::NumericFloat(NumericOperationDescription::Add(desc)) => {
+                let bias = if is_consumed(&desc.lhs, head) {
+                    &desc.rhs
+                } else if is_consumed(&desc.rhs, head) {
+                    &desc.lhs
+                } else {
+                    return false;
+                };
+
+                if !is_bias(bias, head) || desc.out.shape != head.shape {
+                    return false;
+                }
+
+                if let Some(reshape) = &self.bias_reshape {
+                    if reshape.out.id != bias.id {
+                        return false;
+                    }
+                }
+
+                self.bias = Some(bias.clone());
+                self.head = Some(desc.out.clone());
+                self.complete(Vec::new(), self.num_registered + 1);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn register_activation(
+        &mut self,
+        operation: &OperationDescription,
+        head: &TensorDescription,
+    ) -> bool {
+        if self.activation.is_some() || self.bias_reshape.is_some() && self.bias.is_none() {
+            return false;
+        }
+
+        self.pending.push(operation.clone());
+        if has_float_scalar(operation) {
+            self.pending_scalars.push(self.num_scalars);
+            self.num_scalars += 1;
+        }
+
+        match match_activation(head, &self.pending) {
+            ActivationMatch::Partial => true,
+            ActivationMatch::Complete(activation, out) => {
+                self.pending.clear();
+                let scalar_positions = core::mem::take(&mut self.pending_scalars);
+                self.activation = Some(activation);
+                self.head = Some(out);
+                self.complete(scalar_positions, self.num_registered + 1);
+                true
+            }
+            ActivationMatch::Invalid => false,
+        }
+    }
+
+    fn complete(&mut self, scalar_positions: Vec<usize>, num_operations: usize) {
+        self.complete = Some(MatmulEpilogueDescription {
+            matmul: self.matmul.clone().expect("Registered after the matmul"),
+            bias_reshape: self.bias_reshape.clone(),
+            bias: self.bias.clone(),
+            activation: self.activation,
+            scalar_positions,
+            out: self.head.clone().expect("Registered after the matmul"),
+            num_operations,
+        });
+    }
+}
+
+/// If the tensor can be broadcasted over the last dimension of the output.
+fn is_bias(tensor: &TensorDescription, output: &TensorDescription) -> bool {
+    let rank = output.shape.len();
+
+    tensor.dtype == output.dtype
+        && tensor.shape.len() == rank
+        && tensor.shape[rank - 1] == output.shape[rank - 1]
+        && tensor.shape[0..rank - 1].iter().all(|dim| *dim == 1)
+}
+
+/// If the operation has a float scalar, which the context lists with the scalars of the other
+/// operations of the graph.
+fn has_float_scalar(operation: &OperationDescription) -> bool {
+    use NumericOperationDescription as Numeric;
+
+    matches!(
+        operation,
+        OperationDescription::NumericFloat(
+            Numeric::AddScalar(_)
+                | Numeric::SubScalar(_)
+                | Numeric::MulScalar(_)
+                | Numeric::DivScalar(_)
+                | Numeric::LowerEqualElem(_)
+                | Numeric::MaskFill(_)
+        )
+    )
+}
+
+/// If the tensor is the given intermediate and isn't used by any later operation.
+pub(crate) fn is_consumed(tensor: &TensorDescription, intermediate: &TensorDescription) -> bool {
+    tensor.id == intermediate.id && tensor.status == TensorStatus::ReadWrite
+}
+
+/// If the tensor is the given intermediate, which is used again by a later operation.
+pub(crate) fn is_read(tensor: &TensorDescription, intermediate: &TensorDescription) -> bool {
+    tensor.id == intermediate.id && tensor.status == TensorStatus::ReadOnly
+}
+
+fn match_activation(
+    head: &TensorDescription,
+    operations: &[OperationDescription],
+) -> ActivationMatch {
+    match operations.first() {
+        Some(OperationDescription::Float(FloatOperationDescription::Tanh(desc))) => {
+            if is_consumed(&desc.input, head) {
+                ActivationMatch::Complete(EpilogueActivation::Tanh, desc.out.clone())
+            } else {
+                ActivationMatch::Invalid
+            }
+        }
+        Some(OperationDescription::NumericFloat(NumericOperationDescription::LowerEqualElem(
+            _,
+        ))) => match_relu(head, operations),
+        Some(OperationDescription::NumericFloat(NumericOperationDescription::DivScalar(_))) => {
+            match_gelu(head, operations)
+        }
+        _ => ActivationMatch::Invalid,
+    }
+}
+
+/// `mask_fill(x, lower_equal_elem(x, s0), s1)`
+fn match_relu(head: &TensorDescription, operations: &[OperationDescription]) -> ActivationMatch {
+    use NumericOperationDescription as Numeric;
+
+    let mask = match &operations[0] {
+        OperationDescription::NumericFloat(Numeric::LowerEqualElem(desc))
+            if is_read(&desc.lhs, head) =>
+        {
+            &desc.out
+        }
+        _ => return ActivationMatch::Invalid,
+    };
+
+    match operations.get(1) {
+        None => ActivationMatch::Partial,
+        Some(OperationDescription::NumericFloat(Numeric::MaskFill(desc)))
+            if is_consumed(&desc.tensor, head) && is_consumed(&desc.mask, mask) =>
+        {
+            ActivationMatch::Complete(EpilogueActivation::Relu, desc.out.clone())
+        }
+        Some(_) => ActivationMatch::Invalid,
+    }
+}
+
+/// `x * (erf(x / s0) + s1) / s2`
+fn match_gelu(head: &TensorDescription, operations: &[OperationDescription]) -> ActivationMatch {
+    use NumericOperationDescription as Numeric;
+
+    let mut current = head.clone();
+
+    for (index, operation) in operations.iter().enumerate() {
+        current = match (index, operation) {
+            (0, OperationDescription::NumericFloat(Numeric::DivScalar(desc)))
+                if is_read(&desc.lhs, head) =>
+            {
+                desc.out.clone()
+            }
+            (1, OperationDescription::Float(FloatOperationDescription::Erf(desc)))
+                if is_consumed(&desc.input, &current) =>
+            {
+                desc.out.clone()
+            }
+            (2, OperationDescription::NumericFloat(Numeric::AddScalar(desc)))
+                if is_consumed(&desc.lhs, &current) =>
+            {
+                desc.out.clone()
+            }
+            (3, OperationDescription::NumericFloat(Numeric::Mul(desc)))
+                if is_consumed(&desc.lhs, head) && is_consumed(&desc.rhs, &current)
+                    || is_consumed(&desc.rhs, head) && is_consumed(&desc.lhs, &current) =>
+            {
+                desc.out.clone()
+            }
+            (4, OperationDescription::NumericFloat(Numeric::DivScalar(desc)))
+                if is_consumed(&desc.lhs, &current) =>
+            {
+                return ActivationMatch::Complete(EpilogueActivation::Gelu, desc.out.clone());
+            }
+            _ => return ActivationMatch::Invalid,
+        };
+    }
+
+    ActivationMatch::Partial
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn_tensor::{
+        repr::{ScalarOperationDescription, TensorId, UnaryOperationDescription},
+        DType,
+    };
+
+    fn tensor(id: u64, shape: &[usize], status: TensorStatus) -> TensorDescription {
+        TensorDescription {
+            id: TensorId::new(id),
+            shape: shape.to_vec(),
+            status,
+            dtype: DType::F32,
+        }
+    }
+
+    fn read_write(id: u64, shape: &[usize]) -> TensorDescription {
+        tensor(id, shape, TensorStatus::ReadWrite)
+    }
+
+    fn read_only(id: u64, shape: &[usize]) -> TensorDescription {
+        tensor(id, shape, TensorStatus::ReadOnly)
+    }
+
+    fn not_init(id: u64, shape: &[usize]) -> TensorDescription {
+        tensor(id, shape, TensorStatus::NotInit)
+    }
+
+    /// The operations of `linear.forward(x)`, producing tensor `5`.
+    fn linear(with_bias: bool) -> Vec<OperationDescription> {
+        let mut operations = vec![OperationDescription::Float(
+            FloatOperationDescription::Matmul(BinaryOperationDescription {
+                lhs: read_write(0, &[2, 3, 4]),
+                rhs: read_write(1, &[1, 4, 8]),
+                out: not_init(if with_bias { 2 } else { 5 }, &[2, 3, 8]),
+            }),
+        )];
+
+        if with_bias {
+            operations.push(OperationDescription::BaseFloat(
+                BaseOperationDescription::Reshape(ReshapeDescription {
+                    input: read_write(3, &[8]),
+                    out: not_init(4, &[1, 1, 8]),
+                }),
+            ));
+            operations.push(OperationDescription::NumericFloat(
+                NumericOperationDescription::Add(BinaryOperationDescription {
+                    lhs: read_write(2, &[2, 3, 8]),
+                    rhs: read_write(4, &[1, 1, 8]),
+                    out: not_init(5, &[2, 3, 8]),
+                }),
+            ));
+        }
+
+        operations
+    }
+
+    /// The operations of the default gelu implementation applied on tensor `5`.
+    fn gelu(input_status: TensorStatus) -> Vec<OperationDescription> {
+        let shape = [2, 3, 8];
+        let scalar = |lhs, out| ScalarOperationDescription { lhs, rhs: 0.0, out };
+
+        vec![
+            OperationDescription::NumericFloat(NumericOperationDescription::DivScalar(scalar(
+                read_only(5, &shape),
+                not_init(6, &shape),
+            ))),
+            OperationDescription::Float(FloatOperationDescription::Erf(
+                UnaryOperationDescription {
+                    input: read_write(6, &shape),
+                    out: not_init(7, &shape),
+                },
+            )),
+            OperationDescription::NumericFloat(NumericOperationDescription::AddScalar(scalar(
+                read_write(7, &shape),
+                not_init(8, &shape),
+            ))),
+            OperationDescription::NumericFloat(NumericOperationDescription::Mul(
+                BinaryOperationDescription {
+                    lhs: tensor(5, &shape, input_status),
+                    rhs: read_write(8, &shape),
+                    out: not_init(9, &shape),
+                },
+            )),
+            OperationDescription::NumericFloat(NumericOperationDescription::DivScalar(scalar(
+                read_write(9, &shape),
+                not_init(10, &shape),
+            ))),
+        ]
+    }
+
+    fn register_all(operations: &[OperationDescription]) -> MatmulEpiloguePattern {
+        let mut pattern = MatmulEpiloguePattern::default();
+        operations
+            .iter()
+            .for_each(|operation| pattern.register(operation));
+        pattern
+    }
+
+    #[test]
+    fn linear_gelu_should_be_fused_into_a_single_operation() {
+        let mut operations = linear(true);
+        operations.extend(gelu(TensorStatus::ReadWrite));
+
+        let pattern = register_all(&operations);
+        let description = pattern.complete.as_ref().unwrap();
+
+        // The matmul, the bias reshape and add, and the 5 operations of gelu.
+        assert_eq!(pattern.len(), 8);
+        assert!(pattern.is_ready());
+        assert!(description.bias.is_some());
+        assert_eq!(description.activation, Some(EpilogueActivation::Gelu));
+        assert_eq!(description.scalar_positions, vec![0, 1, 2]);
+        assert_eq!(description.out.id, TensorId::new(10));
+    }
+
+    #[test]
+    fn linear_gelu_without_bias_should_be_fused_into_a_single_operation() {
+        let mut operations = linear(false);
+        operations.extend(gelu(TensorStatus::ReadWrite));
+
+        let pattern = register_all(&operations);
+        let description = pattern.complete.as_ref().unwrap();
+
+        assert_eq!(pattern.len(), 6);
+        assert!(pattern.is_ready());
+        assert!(description.bias.is_none());
+        assert_eq!(description.activation, Some(EpilogueActivation::Gelu));
+    }
+
+    #[test]
+    fn linear_should_only_fuse_the_bias_when_its_output_is_used_later() {
+        let mut operations = linear(true);
+        operations.extend(gelu(TensorStatus::ReadOnly));
+
+        let pattern = register_all(&operations);
+        let description = pattern.complete.as_ref().unwrap();
+
+        assert_eq!(pattern.len(), 3);
+        assert!(pattern.closed);
+        assert!(description.activation.is_none());
+        assert_eq!(description.out.id, TensorId::new(5));
+    }
+
+    #[test]
+    fn matmul_without_epilogue_should_not_be_ready() {
+        let pattern = register_all(&linear(false));
+
+        assert_eq!(pattern.len(), 1);
+        assert!(!pattern.is_ready());
+    }
+}
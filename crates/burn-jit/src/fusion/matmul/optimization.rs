@@ -0,0 +1,230 @@
+use crate::{
+    fusion::{strides_dyn_rank, JitFusionHandle},
+    kernel::matmul::{launch_matmul_epilogue, matmul_epilogue, EpilogueActivation},
+    tensor::JitTensor,
+    FloatElement, JitRuntime,
+};
+use burn_fusion::stream::Context;
+use burn_tensor::{
+    repr::{BinaryOperationDescription, ReshapeDescription, TensorDescription, TensorStatus},
+    DType, Shape,
+};
+use cubecl::prelude::TensorHandleRef;
+use half::{bf16, f16};
+use serde::{Deserialize, Serialize};
+
+/// A matmul with its fused epilogue, as captured by the
+/// [builder](super::MatmulEpilogueBuilder).
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MatmulEpilogueDescription {
+    /// The matmul operation.
+    pub matmul: BinaryOperationDescription,
+    /// The reshape applied on the bias before it is added, if any.
+    pub bias_reshape: Option<ReshapeDescription>,
+    /// The bias added to the matmul output, if any.
+    pub bias: Option<TensorDescription>,
+    /// The activation applied last, if any.
+    pub activation: Option<EpilogueActivation>,
+    /// The positions in the context of the float scalars of the activation, in the order of the
+    /// [default scalars](EpilogueActivation::default_scalars).
+    pub scalar_positions: Vec<usize>,
+    /// The output of the epilogue.
+    pub out: TensorDescription,
+    /// The number of operations fused.
+    pub num_operations: usize,
+}
+
+#[derive(new)]
+pub struct MatmulEpilogue<R: JitRuntime> {
+    description: MatmulEpilogueDescription,
+    device: R::Device,
+}
+
+#[derive(new, Serialize, Deserialize)]
+pub struct MatmulEpilogueState {
+    description: MatmulEpilogueDescription,
+}
+
+impl<R: JitRuntime> MatmulEpilogue<R> {
+    pub(crate) fn execute(&mut self, context: &mut Context<'_, JitFusionHandle<R>>) {
+        match self.description.out.dtype {
+            DType::F32 => self.execute_typed::<f32>(context),
+            DType::F16 => self.execute_typed::<f16>(context),
+            DType::BF16 => self.execute_typed::<bf16>(context),
+            dtype => panic!("Matmul epilogue doesn't support {dtype:?}"),
+        }
+    }
+
+    fn execute_typed<E: FloatElement>(&self, context: &mut Context<'_, JitFusionHandle<R>>) {
+        let client = R::client(&self.device);
+        let description = &self.description;
+
+        let (lhs, lhs_shape) = input(context, &description.matmul.lhs);
+        let (rhs, rhs_shape) = input(context, &description.matmul.rhs);
+        let bias = description
+            .bias
+            .as_ref()
+            .map(|bias| match &description.bias_reshape {
+                Some(reshape) => reshaped_bias(context, reshape, bias),
+                None => input(context, bias),
+            });
+
+        let out_shape = context
+            .tensors
+            .get(&description.out.id)
+            .unwrap()
+            .shape
+            .clone();
+        let out_id = context.tensors.get(&description.out.id).unwrap().id;
+
+        let mut scalars = EpilogueActivation::default_scalars(description.activation);
+        for (scalar, position) in scalars.iter_mut().zip(&description.scalar_positions) {
+            *scalar = context.scalar_floats[*position];
+        }
+
+        // The tiling 2D epilogue computes the activations with their default constants, the
+        // other graphs being computed by the kernel reading the scalars.
+        if scalars == EpilogueActivation::default_scalars(description.activation) {
+            let bias = bias.as_ref();
+            let activation = description.activation;
+            let out = match out_shape.len() {
+                2 => Some(tiled::<R, E, 2>(
+                    &lhs, &lhs_shape, &rhs, &rhs_shape, bias, activation,
+                )),
+                3 => Some(tiled::<R, E, 3>(
+                    &lhs, &lhs_shape, &rhs, &rhs_shape, bias, activation,
+                )),
+                4 => Some(tiled::<R, E, 4>(
+                    &lhs, &lhs_shape, &rhs, &rhs_shape, bias, activation,
+                )),
+                _ => None,
+            };
+
+            if let Some(out) = out {
+                context.handles.register_handle(out_id, out);
+                return;
+            }
+        }
+
+        let num_bytes = out_shape.iter().product::<usize>() * core::mem::size_of::<E>();
+        crate::perf::record_allocation(num_bytes);
+        let out = JitFusionHandle {
+            client: client.clone(),
+            device: self.device.clone(),
+            strides: strides_dyn_rank(&out_shape),
+            handle: client.empty(num_bytes),
+        };
+
+        launch_matmul_epilogue::<R, E>(
+            &client,
+            handle_ref(&lhs, &lhs_shape),
+            handle_ref(&rhs, &rhs_shape),
+            bias.as_ref()
+                .map(|(handle, shape)| handle_ref(handle, shape)),
+            handle_ref(&out, &out_shape),
+            description.activation,
+            scalars,
+        );
+
+        context.handles.register_handle(out_id, out);
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.description.num_operations
+    }
+
+    pub(crate) fn from_state(device: &R::Device, state: MatmulEpilogueState) -> Self {
+        Self {
+            description: state.description,
+            device: device.clone(),
+        }
+    }
+
+    pub(crate) fn to_state(&self) -> MatmulEpilogueState {
+        MatmulEpilogueState {
+            description: self.description.clone(),
+        }
+    }
+}
+
+/// Compute the epilogue with the [tiled kernels](matmul_epilogue) on inputs of rank `D`.
+fn tiled<R: JitRuntime, E: FloatElement, const D: usize>(
+    lhs: &JitFusionHandle<R>,
+    lhs_shape: &[usize],
+    rhs: &JitFusionHandle<R>,
+    rhs_shape: &[usize],
+    bias: Option<&(JitFusionHandle<R>, Vec<usize>)>,
+    activation: Option<EpilogueActivation>,
+) -> JitFusionHandle<R> {
+    let tensor = |handle: &JitFusionHandle<R>, shape: &[usize]| {
+        let dims: [usize; D] = shape.try_into().expect("Wrong dimension");
+        handle.clone().into_tensor::<D, E>(Shape::new(dims))
+    };
+
+    // The bias only has a single dimension that isn't of size one, so it is viewed as a vector.
+    let bias = bias.map(|(handle, shape)| JitTensor::<R, E, 1> {
+        client: handle.client.clone(),
+        handle: handle.handle.clone(),
+        device: handle.device.clone(),
+        shape: Shape::new([shape[shape.len() - 1]]),
+        strides: [*handle.strides.last().unwrap()],
+        elem: core::marker::PhantomData,
+    });
+
+    matmul_epilogue(
+        tensor(lhs, lhs_shape),
+        tensor(rhs, rhs_shape),
+        bias,
+        activation,
+    )
+    .into()
+}
+
+pub(crate) fn handle_ref<'a, R: JitRuntime>(
+    handle: &'a JitFusionHandle<R>,
+    shape: &'a [usize],
+) -> TensorHandleRef<'a, R> {
+    TensorHandleRef {
+        handle: &handle.handle,
+        strides: &handle.strides,
+        shape,
+    }
+}
+
+/// Fetch the handle of an input with the shape it has in the global graph.
+pub(crate) fn input<R: JitRuntime>(
+    context: &mut Context<'_, JitFusionHandle<R>>,
+    tensor: &TensorDescription,
+) -> (JitFusionHandle<R>, Vec<usize>) {
+    // The status of the relative graph must be used, since the status of the global graph
+    // might be of a later operation on the same tensor id.
+    let global = context.tensors.get(&tensor.id).unwrap();
+    let handle = context.handles.get_handle(&global.id, &tensor.status);
+
+    (handle, global.shape.clone())
+}
+
+/// Execute the reshape of the bias without copying it, which is possible since the bias only
+/// has a single dimension that isn't of size one.
+fn reshaped_bias<R: JitRuntime>(
+    context: &mut Context<'_, JitFusionHandle<R>>,
+    reshape: &ReshapeDescription,
+    bias: &TensorDescription,
+) -> (JitFusionHandle<R>, Vec<usize>) {
+    let (mut handle, _) = input(context, &reshape.input);
+    let global = context.tensors.get(&reshape.out.id).unwrap();
+    let shape = global.shape.clone();
+    let stride = *handle.strides.last().unwrap();
+
+    handle.strides = strides_dyn_rank(&shape)
+        .into_iter()
+        .map(|s| s * stride)
+        .collect();
+
+    // The reshaped bias is still used by later operations.
+    if let TensorStatus::ReadOnly = bias.status {
+        context.handles.register_handle(global.id, handle.clone());
+    }
+
+    (handle, shape)
+}
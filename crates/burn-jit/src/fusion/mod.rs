@@ -1,8 +1,12 @@
+mod attention;
 mod base;
 mod elemwise;
+mod matmul;
 
 pub(crate) mod kernel;
 pub(crate) mod tracing;
 
+pub(crate) use attention::*;
 pub use base::*;
 pub(crate) use elemwise::*;
+pub(crate) use matmul::*;
@@ -0,0 +1,3 @@
+mod score_softmax;
+
+pub use score_softmax::*;
@@ -0,0 +1,575 @@
+//! Matmul fused with a softmax over the columns of its output, like the attention probabilities
+//! `softmax(scale * q @ k^T)`.
+//!
+//! Each cube computes the scores of a block of rows, all of their columns being kept in shared
+//! memory instead of being written to the output. The units of a row split its columns, and the
+//! inner dimension in chunks of the lhs row block loaded in shared memory, the same way the
+//! [tiling 2D](crate::kernel::matmul::matmul_tiling_2d) kernel loads its blocks. The row-wise max
+//! and sum are then reduced in shared memory, and only the normalized probabilities are written.
+//!
+//! When the runtime supports the [subcube](is_subcube_available) operations, each cube computes a
+//! single row, whose max and sum are reduced by the subgroup of its units without any shared
+//! memory or synchronization. The units of a cube must then all be in the same subgroup, so the
+//! path is only taken when the subgroups of the device have a fixed size of at least
+//! [UNITS_PER_ROW] units, the rows being reduced in shared memory otherwise.
+use crate::{
+    kernel::{
+        is_subgroup_available,
+        matmul::{
+            init_matmul_output, launch_matmul_epilogue, AccumulatorPrecision, EpilogueActivation,
+        },
+        subgroup_max, subgroup_sum, SUBCUBE_DIM_APPROX,
+    },
+    tensor::JitTensor,
+    FloatElement, JitRuntime,
+};
+use burn_tensor::ElementConversion;
+use cubecl::frontend::F32;
+use cubecl::{client::ComputeClient, prelude::*};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// The number of rows of scores computed by each cube.
+const BLOCK_ROWS: usize = 4;
+/// The number of units computing each row of scores.
+const UNITS_PER_ROW: usize = SUBCUBE_DIM_APPROX;
+
+/// The maximum number of columns of the scores computed by the fused kernel, since the rows of a
+/// cube are kept in shared memory.
+pub const MAX_SCORE_COLUMNS: usize = 512;
+
+static SUBCUBE_ENABLED: AtomicBool = AtomicBool::new(true);
+
+/// Enable or disable the reductions of the rows with the subcube operations, the rows being
+/// reduced in shared memory when disabled.
+pub fn set_subcube_enabled(enabled: bool) {
+    SUBCUBE_ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// Whether the rows of scores are reduced with the subcube operations on the client of the
+/// device: the path is enabled and the subgroups of the device
+/// [hold the units of a row](is_subgroup_available).
+pub fn is_subcube_available<R: JitRuntime>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    device: &R::Device,
+) -> bool {
+    SUBCUBE_ENABLED.load(Ordering::Relaxed)
+        && is_subgroup_available::<R>(client, device, UNITS_PER_ROW as u32)
+}
+
+/// The comptime configuration of the score softmax kernel, whose bound checks are derived from
+/// the shapes it is launched on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ScoreSoftmaxConfig {
+    block_rows: UInt,
+    units_per_row: UInt,
+    /// The number of columns of each row of scores in shared memory, a multiple of the units per
+    /// row.
+    row_storage: UInt,
+    check_m_bounds: bool,
+    check_k_bounds: bool,
+    check_n_bounds: bool,
+    /// Whether the max and the sum of each row are reduced by a subcube, each cube computing a
+    /// single row.
+    subcube: bool,
+}
+
+impl ScoreSoftmaxConfig {
+    /// The config of the scores with `m` rows and `n` columns, summed over `k`, or `None` when
+    /// the rows don't fit in shared memory.
+    pub(crate) fn new(m: usize, k: usize, n: usize, subcube: bool) -> Option<Self> {
+        if n == 0 || n > MAX_SCORE_COLUMNS {
+            return None;
+        }
+
+        let row_storage = n.div_ceil(UNITS_PER_ROW) * UNITS_PER_ROW;
+        let block_rows = block_rows(subcube);
+
+        Some(Self {
+            block_rows: UInt::new(block_rows as u32),
+            units_per_row: UInt::new(UNITS_PER_ROW as u32),
+            row_storage: UInt::new(row_storage as u32),
+            check_m_bounds: m % block_rows != 0,
+            check_k_bounds: k % UNITS_PER_ROW != 0,
+            check_n_bounds: n % UNITS_PER_ROW != 0,
+            subcube,
+        })
+    }
+}
+
+/// The number of rows computed by each cube, a single one when the rows are reduced by subcubes.
+fn block_rows(subcube: bool) -> usize {
+    match subcube {
+        true => 1,
+        false => BLOCK_ROWS,
+    }
+}
+
+impl Init for ScoreSoftmaxConfig {
+    fn init(self, _context: &mut CubeContext) -> Self {
+        self
+    }
+}
+
+impl CubeType for ScoreSoftmaxConfig {
+    type ExpandType = Self;
+}
+
+#[cube(launch_unchecked)]
+fn score_softmax_kernel<F: Float, A: Float>(
+    lhs: &Tensor<F>,
+    rhs: &Tensor<F>,
+    out: &mut Tensor<F>,
+    scale: F,
+    // number of dimensions not involved in the matmul
+    num_batches: Comptime<Option<UInt>>,
+    config: Comptime<ScoreSoftmaxConfig>,
+) {
+    let rank = out.rank();
+    let end = Comptime::unwrap_or_else(num_batches, || rank - UInt::new(2));
+    let unroll = Comptime::is_some(num_batches);
+
+    let units_per_row = Comptime::map(config, |c: ScoreSoftmaxConfig| c.units_per_row);
+    let chunk_storage = Comptime::map(config, |c: ScoreSoftmaxConfig| {
+        c.block_rows * c.units_per_row
+    });
+    let score_storage = Comptime::map(config, |c: ScoreSoftmaxConfig| c.block_rows * c.row_storage);
+    let num_col_chunks = Comptime::map(config, |c: ScoreSoftmaxConfig| {
+        c.row_storage / c.units_per_row
+    });
+    let check_m_bounds = Comptime::map(config, |c: ScoreSoftmaxConfig| c.check_m_bounds);
+    let check_lhs_bounds = Comptime::map(config, |c: ScoreSoftmaxConfig| {
+        c.check_m_bounds || c.check_k_bounds
+    });
+    let check_rhs_bounds = Comptime::map(config, |c: ScoreSoftmaxConfig| {
+        c.check_k_bounds || c.check_n_bounds
+    });
+    let check_n_bounds = Comptime::map(config, |c: ScoreSoftmaxConfig| c.check_n_bounds);
+    let subcube = Comptime::map(config, |c: ScoreSoftmaxConfig| c.subcube);
+    let units = Comptime::runtime(units_per_row);
+    let block_rows = Comptime::runtime(Comptime::map(config, |c: ScoreSoftmaxConfig| c.block_rows));
+    let row_storage =
+        Comptime::runtime(Comptime::map(config, |c: ScoreSoftmaxConfig| c.row_storage));
+
+    let mut lhs_chunk = SharedMemory::<F>::new(Comptime::get(chunk_storage));
+    let mut scores = SharedMemory::<A>::new(Comptime::get(score_storage));
+    let mut partials = SharedMemory::<A>::new(Comptime::get(chunk_storage));
+
+    let mut offset_lhs = UInt::new(0);
+    let mut offset_rhs = UInt::new(0);
+    let mut offset_out = UInt::new(0);
+    let mut batch = CUBE_POS_Z;
+
+    for i in range(0u32, end, unroll) {
+        let dim = end - i - UInt::new(1);
+        let coordinate = batch % out.shape(dim);
+        batch /= out.shape(dim);
+
+        offset_lhs += coordinate % lhs.shape(dim) * lhs.stride(dim);
+        offset_rhs += coordinate % rhs.shape(dim) * rhs.stride(dim);
+        offset_out += coordinate * out.stride(dim);
+    }
+
+    let n_rows = lhs.shape(rank - UInt::new(2));
+    let n_cols = rhs.shape(rank - UInt::new(1));
+    let k = lhs.shape(rank - UInt::new(1));
+
+    // Each unit owns the columns `UNIT_POS_X + c * units` of its row, so the scores are
+    // accumulated in shared memory without races.
+    let row = CUBE_POS_X * block_rows + UNIT_POS_Y;
+    let row_offset = UNIT_POS_Y * row_storage;
+    let chunk_offset = UNIT_POS_Y * units;
+
+    for c in range(0u32, Comptime::get(num_col_chunks), Comptime::new(false)) {
+        scores[row_offset + c * units + UNIT_POS_X] = A::new(0.);
+    }
+
+    // The units past the last row don't return early, since every unit of the cube takes part in
+    // the synchronizations.
+    let num_k_chunks = (k + units - UInt::new(1)) / units;
+
+    for chunk in range(0u32, num_k_chunks, Comptime::new(false)) {
+        let k_lhs = chunk * units + UNIT_POS_X;
+        let lhs_index = offset_lhs
+            + row * lhs.stride(rank - UInt::new(2))
+            + k_lhs * lhs.stride(rank - UInt::new(1));
+
+        if Comptime::get(check_lhs_bounds) {
+            let mut value = F::new(0.);
+            if row < n_rows && k_lhs < k {
+                value = lhs[lhs_index];
+            }
+            lhs_chunk[chunk_offset + UNIT_POS_X] = value;
+        } else {
+            lhs_chunk[chunk_offset + UNIT_POS_X] = lhs[lhs_index];
+        }
+
+        sync_units();
+
+        for c in range(0u32, Comptime::get(num_col_chunks), Comptime::new(false)) {
+            let col = c * units + UNIT_POS_X;
+            let mut sum = A::new(0.);
+
+            for i in range(0u32, Comptime::get(units_per_row), Comptime::new(true)) {
+                let k_rhs = chunk * units + i;
+                let lhs_value = A::cast_from(lhs_chunk[chunk_offset + i]);
+                let rhs_index = offset_rhs
+                    + k_rhs * rhs.stride(rank - UInt::new(2))
+                    + col * rhs.stride(rank - UInt::new(1));
+
+                if Comptime::get(check_rhs_bounds) {
+                    if k_rhs < k && col < n_cols {
+                        sum += lhs_value * A::cast_from(rhs[rhs_index]);
+                    }
+                } else {
+                    sum += lhs_value * A::cast_from(rhs[rhs_index]);
+                }
+            }
+
+            scores[row_offset + col] = scores[row_offset + col] + sum;
+        }
+
+        sync_units();
+    }
+
+    let scale = A::cast_from(scale);
+
+    // The first column is always in bounds.
+    let mut max = scores[row_offset] * scale;
+    for c in range(0u32, Comptime::get(num_col_chunks), Comptime::new(false)) {
+        let col = c * units + UNIT_POS_X;
+        let value = scores[row_offset + col] * scale;
+
+        if Comptime::get(check_n_bounds) {
+            if col < n_cols && value > max {
+                max = value;
+            }
+        } else if value > max {
+            max = value;
+        }
+    }
+
+    let mut row_max = max;
+    if Comptime::get(subcube) {
+        row_max = subgroup_max(max);
+    } else {
+        partials[chunk_offset + UNIT_POS_X] = max;
+        sync_units();
+
+        row_max = partials[chunk_offset];
+        for i in range(0u32, Comptime::get(units_per_row), Comptime::new(true)) {
+            let value = partials[chunk_offset + i];
+            if value > row_max {
+                row_max = value;
+            }
+        }
+
+        // The partial maxima are all read before being overwritten by the partial sums.
+        sync_units();
+    }
+
+    let mut sum = A::new(0.);
+    for c in range(0u32, Comptime::get(num_col_chunks), Comptime::new(false)) {
+        let col = c * units + UNIT_POS_X;
+        let index = row_offset + col;
+
+        if Comptime::get(check_n_bounds) {
+            if col < n_cols {
+                let value = A::exp(scores[index] * scale - row_max);
+                scores[index] = value;
+                sum += value;
+            }
+        } else {
+            let value = A::exp(scores[index] * scale - row_max);
+            scores[index] = value;
+            sum += value;
+        }
+    }
+
+    let mut row_sum = A::new(0.);
+    if Comptime::get(subcube) {
+        row_sum = subgroup_sum(sum);
+    } else {
+        partials[chunk_offset + UNIT_POS_X] = sum;
+        sync_units();
+
+        for i in range(0u32, Comptime::get(units_per_row), Comptime::new(true)) {
+            row_sum += partials[chunk_offset + i];
+        }
+    }
+
+    if Comptime::get(check_m_bounds) {
+        if row < n_rows {
+            write_probabilities(
+                out, &scores, row, row_offset, offset_out, row_sum, n_cols, config,
+            );
+        }
+    } else {
+        write_probabilities(
+            out, &scores, row, row_offset, offset_out, row_sum, n_cols, config,
+        );
+    }
+}
+
+/// Write the exponentials of the row of the unit divided by their sum, following the strides of
+/// the output.
+#[cube]
+#[allow(clippy::too_many_arguments)]
+fn write_probabilities<F: Float, A: Float>(
+    out: &mut Tensor<F>,
+    scores: &SharedMemory<A>,
+    row: UInt,
+    row_offset: UInt,
+    offset_out: UInt,
+    row_sum: A,
+    n_cols: UInt,
+    config: Comptime<ScoreSoftmaxConfig>,
+) {
+    let num_col_chunks = Comptime::map(config, |c: ScoreSoftmaxConfig| {
+        c.row_storage / c.units_per_row
+    });
+    let check_n_bounds = Comptime::map(config, |c: ScoreSoftmaxConfig| c.check_n_bounds);
+    let units = Comptime::runtime(Comptime::map(config, |c: ScoreSoftmaxConfig| {
+        c.units_per_row
+    }));
+
+    let rank = out.rank();
+
+    for c in range(0u32, Comptime::get(num_col_chunks), Comptime::new(false)) {
+        let col = c * units + UNIT_POS_X;
+        let index = offset_out
+            + row * out.stride(rank - UInt::new(2))
+            + col * out.stride(rank - UInt::new(1));
+
+        if Comptime::get(check_n_bounds) {
+            if col < n_cols {
+                out[index] = F::cast_from(scores[row_offset + col] / row_sum);
+            }
+        } else {
+            out[index] = F::cast_from(scores[row_offset + col] / row_sum);
+        }
+    }
+}
+
+/// Normalize the contiguous rows of scores in place with `softmax(scale * scores)`, each cube
+/// reducing a row whose columns are split between its units.
+///
+/// The max and the sum of the row are reduced by the subgroup of the units when `subcube` is set,
+/// and in shared memory otherwise.
+#[cube(launch)]
+fn scaled_softmax_rows_kernel<F: Float>(
+    scores: &mut Tensor<F>,
+    scale: F,
+    units_per_row: Comptime<UInt>,
+    subcube: Comptime<bool>,
+) {
+    let rank = scores.rank();
+    let num_cols = scores.shape(rank - UInt::new(1));
+    let num_rows = scores.shape(rank - UInt::new(2));
+    let units = Comptime::runtime(units_per_row);
+
+    // The row is the same for all the units of the cube, which return together.
+    let row = CUBE_POS_Z * num_rows + CUBE_POS_X;
+    if CUBE_POS_X >= num_rows {
+        return;
+    }
+
+    let offset = row * num_cols;
+    let num_col_chunks = (num_cols + units - UInt::new(1)) / units;
+    let mut partials = SharedMemory::<F>::new(Comptime::get(units_per_row));
+
+    // The first column is always in bounds.
+    let mut max = scores[offset] * scale;
+    for c in range(0u32, num_col_chunks, Comptime::new(false)) {
+        let col = c * units + UNIT_POS_X;
+        if col < num_cols {
+            let value = scores[offset + col] * scale;
+            if value > max {
+                max = value;
+            }
+        }
+    }
+
+    let mut row_max = max;
+    if Comptime::get(subcube) {
+        row_max = subgroup_max(max);
+    } else {
+        partials[UNIT_POS_X] = max;
+        sync_units();
+
+        row_max = partials[0];
+        for i in range(0u32, Comptime::get(units_per_row), Comptime::new(true)) {
+            let value = partials[i];
+            if value > row_max {
+                row_max = value;
+            }
+        }
+
+        // The partial maxima are all read before being overwritten by the partial sums.
+        sync_units();
+    }
+
+    let mut sum = F::new(0.);
+    for c in range(0u32, num_col_chunks, Comptime::new(false)) {
+        let col = c * units + UNIT_POS_X;
+        if col < num_cols {
+            let value = F::exp(scores[offset + col] * scale - row_max);
+            scores[offset + col] = value;
+            sum += value;
+        }
+    }
+
+    let mut row_sum = F::new(0.);
+    if Comptime::get(subcube) {
+        row_sum = subgroup_sum(sum);
+    } else {
+        partials[UNIT_POS_X] = sum;
+        sync_units();
+
+        for i in range(0u32, Comptime::get(units_per_row), Comptime::new(true)) {
+            row_sum += partials[i];
+        }
+    }
+
+    // Each unit only normalizes the columns it wrote.
+    for c in range(0u32, num_col_chunks, Comptime::new(false)) {
+        let col = c * units + UNIT_POS_X;
+        if col < num_cols {
+            scores[offset + col] = scores[offset + col] / row_sum;
+        }
+    }
+}
+
+/// Launch the score softmax kernel on tensors of any rank, computing
+/// `softmax(scale * lhs @ rhs)` over the columns into `out`, which must be contiguous.
+///
+/// The rows of more than [MAX_SCORE_COLUMNS] columns don't fit in shared memory, so their scores
+/// are written to the output by the [matmul epilogue](crate::kernel::matmul::matmul_epilogue)
+/// kernel before being normalized in place by a second kernel, each of its cubes reducing a row.
+/// The rows are then reduced by subgroups under the same conditions as the fused kernel.
+pub(crate) fn launch_score_softmax<R: JitRuntime, E: FloatElement>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    device: &R::Device,
+    lhs: TensorHandleRef<'_, R>,
+    rhs: TensorHandleRef<'_, R>,
+    out: TensorHandleRef<'_, R>,
+    scale: f32,
+) {
+    let rank = out.shape.len();
+    let num_rows = out.shape[rank - 2];
+    let num_cols = out.shape[rank - 1];
+    let num_batches = out.shape[0..rank - 2].iter().product::<usize>();
+    let k = lhs.shape[rank - 1];
+
+    if num_rows * num_cols * num_batches == 0 {
+        return;
+    }
+
+    let subcube = is_subcube_available::<R>(client, device);
+    let Some(config) = ScoreSoftmaxConfig::new(num_rows, k, num_cols, subcube) else {
+        launch_unfused::<R, E>(client, lhs, rhs, out, scale, subcube);
+        return;
+    };
+
+    let block_rows = block_rows(subcube);
+    let cube_dim = CubeDim::new(UNITS_PER_ROW as u32, block_rows as u32, 1);
+    let cube_count = CubeCount::Static(num_rows.div_ceil(block_rows) as u32, 1, num_batches as u32);
+    let num_batch_dims = Some(UInt::new(rank as u32 - 2));
+    let arg = |tensor: &TensorHandleRef<'_, R>| {
+        TensorArg::from_raw_parts(tensor.handle, tensor.strides, tensor.shape, 1)
+    };
+
+    crate::perf::record_kernel();
+
+    unsafe {
+        match AccumulatorPrecision::of::<E>() {
+            AccumulatorPrecision::Input => {
+                score_softmax_kernel::launch_unchecked::<E::FloatPrimitive, E::FloatPrimitive, R>(
+                    client,
+                    cube_count,
+                    cube_dim,
+                    arg(&lhs),
+                    arg(&rhs),
+                    arg(&out),
+                    ScalarArg::new(scale.elem::<E>()),
+                    num_batch_dims,
+                    config,
+                )
+            }
+            AccumulatorPrecision::F32 => {
+                score_softmax_kernel::launch_unchecked::<E::FloatPrimitive, F32, R>(
+                    client,
+                    cube_count,
+                    cube_dim,
+                    arg(&lhs),
+                    arg(&rhs),
+                    arg(&out),
+                    ScalarArg::new(scale.elem::<E>()),
+                    num_batch_dims,
+                    config,
+                )
+            }
+        }
+    };
+}
+
+fn launch_unfused<R: JitRuntime, E: FloatElement>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    lhs: TensorHandleRef<'_, R>,
+    rhs: TensorHandleRef<'_, R>,
+    out: TensorHandleRef<'_, R>,
+    scale: f32,
+    subcube: bool,
+) {
+    let rank = out.shape.len();
+    let num_rows = out.shape[rank - 2];
+    let num_batches = out.shape[0..rank - 2].iter().product::<usize>();
+    let strides = out.strides;
+    let shape = out.shape;
+    let handle = out.handle;
+
+    launch_matmul_epilogue::<R, E>(
+        client,
+        lhs,
+        rhs,
+        None,
+        out,
+        None,
+        EpilogueActivation::default_scalars(None),
+    );
+
+    crate::perf::record_kernel();
+    scaled_softmax_rows_kernel::launch::<E::FloatPrimitive, R>(
+        client,
+        CubeCount::Static(num_rows as u32, 1, num_batches as u32),
+        CubeDim::new(UNITS_PER_ROW as u32, 1, 1),
+        TensorArg::from_raw_parts(handle, strides, shape, 1),
+        ScalarArg::new(scale.elem::<E>()),
+        UInt::new(UNITS_PER_ROW as u32),
+        subcube,
+    );
+}
+
+/// The attention probabilities `softmax(scale * lhs @ rhs)`, the softmax being applied over the
+/// last dimension, computed without writing the scores to global memory when the rows have at
+/// most [MAX_SCORE_COLUMNS] columns.
+///
+/// The batch dimensions are broadcasted like with [matmul](crate::kernel::matmul::matmul).
+pub fn score_softmax<R: JitRuntime, E: FloatElement, const D: usize>(
+    lhs: JitTensor<R, E, D>,
+    rhs: JitTensor<R, E, D>,
+    scale: f32,
+) -> JitTensor<R, E, D> {
+    lhs.assert_is_on_same_device(&rhs);
+    let out = init_matmul_output(&lhs, &rhs);
+
+    launch_score_softmax::<R, E>(
+        &lhs.client,
+        &lhs.device,
+        lhs.as_handle_ref(),
+        rhs.as_handle_ref(),
+        out.as_handle_ref(),
+        scale,
+    );
+
+    out
+}
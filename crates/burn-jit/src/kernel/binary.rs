@@ -1,6 +1,11 @@
-use super::Kernel;
-use crate::{element::JitElement, tensor::JitTensor, JitRuntime};
-use burn_tensor::Shape;
+use super::{into_contiguous, Kernel};
+use crate::{
+    element::JitElement,
+    ops::{broadcast_shape, numeric::empty_device},
+    tensor::JitTensor,
+    JitRuntime,
+};
+use burn_tensor::MemoryFormat;
 use cubecl::{
     calculate_cube_count_elemwise, linalg::tensor::index_offset_with_layout, prelude::*,
     tensor_vectorization_factor, Runtime,
@@ -17,7 +22,17 @@ pub(crate) struct SubOp;
 pub(crate) struct MulOp;
 pub(crate) struct DivOp;
 pub(crate) struct RemainderOp;
+pub(crate) struct EuclidRemainderOp;
+pub(crate) struct PowiOp;
 pub(crate) struct PowOp;
+pub(crate) struct BitwiseAndOp;
+pub(crate) struct BitwiseOrOp;
+pub(crate) struct BitwiseXorOp;
+pub(crate) struct ShiftLeftOp;
+pub(crate) struct ShiftRightOp;
+pub(crate) struct LogicalAndOp;
+pub(crate) struct LogicalOrOp;
+pub(crate) struct LogicalXorOp;
 
 #[cube]
 impl<N: Numeric> BinaryOp<N> for AddOp {
@@ -54,6 +69,44 @@ impl<N: Numeric> BinaryOp<N> for RemainderOp {
     }
 }
 
+// The integer operations below are branchless, since the values may be vectorized.
+
+#[cube]
+impl<N: Int> BinaryOp<N> for EuclidRemainderOp {
+    fn execute(lhs: N, rhs: N) -> N {
+        // Shifting into the positive range works whether the remainder has the sign of the
+        // dividend or of the divisor.
+        let divisor = N::abs(rhs);
+        N::rem(N::rem(lhs, divisor) + divisor, divisor)
+    }
+}
+
+#[cube]
+impl<N: Int> BinaryOp<N> for PowiOp {
+    fn execute(lhs: N, rhs: N) -> N {
+        let zero = N::from_int(0);
+        let one = N::from_int(1);
+        let two = N::from_int(2);
+
+        let mut output = one;
+        let mut base = lhs;
+        let mut exponent = N::abs(rhs);
+
+        // Exponentiation by squaring over the bits of the exponent.
+        for _ in range(0u32, 31u32, Comptime::new(false)) {
+            let bit = N::rem(exponent, two);
+            output *= bit * (base - one) + one;
+            base *= base;
+            exponent = exponent / two;
+        }
+
+        // Negative powers are rounded toward zero, only keeping the powers of 1 and -1.
+        let negative = N::clamp(zero - rhs, zero, one);
+        let unit = one - N::clamp(N::abs(lhs) - one, zero, one);
+        output * (one - negative * (one - unit))
+    }
+}
+
 #[cube]
 impl<N: Float> BinaryOp<N> for PowOp {
     fn execute(lhs: N, rhs: N) -> N {
@@ -61,6 +114,65 @@ impl<N: Float> BinaryOp<N> for PowOp {
     }
 }
 
+#[cube]
+impl<N: Int> BinaryOp<N> for BitwiseAndOp {
+    fn execute(lhs: N, rhs: N) -> N {
+        lhs & rhs
+    }
+}
+
+#[cube]
+impl<N: Int> BinaryOp<N> for BitwiseOrOp {
+    fn execute(lhs: N, rhs: N) -> N {
+        lhs | rhs
+    }
+}
+
+#[cube]
+impl<N: Int> BinaryOp<N> for BitwiseXorOp {
+    fn execute(lhs: N, rhs: N) -> N {
+        lhs ^ rhs
+    }
+}
+
+#[cube]
+impl<N: Int> BinaryOp<N> for ShiftLeftOp {
+    fn execute(lhs: N, rhs: N) -> N {
+        lhs << rhs
+    }
+}
+
+#[cube]
+impl<N: Int> BinaryOp<N> for ShiftRightOp {
+    fn execute(lhs: N, rhs: N) -> N {
+        lhs >> rhs
+    }
+}
+
+// The logical operations are only valid on booleans, stored as zeros and ones.
+
+#[cube]
+impl<N: Numeric> BinaryOp<N> for LogicalAndOp {
+    fn execute(lhs: N, rhs: N) -> N {
+        lhs * rhs
+    }
+}
+
+#[cube]
+impl<N: Numeric> BinaryOp<N> for LogicalOrOp {
+    fn execute(lhs: N, rhs: N) -> N {
+        lhs + rhs - lhs * rhs
+    }
+}
+
+#[cube]
+impl<N: Numeric> BinaryOp<N> for LogicalXorOp {
+    fn execute(lhs: N, rhs: N) -> N {
+        // Evaluated from left to right, so that unsigned values never underflow.
+        lhs + rhs - lhs * rhs - lhs * rhs
+    }
+}
+
 #[cube(launch)]
 pub(crate) fn kernel_scalar_binop<C: Numeric, O: BinaryOp<C>>(
     input: &Tensor<C>,
@@ -134,25 +246,20 @@ pub(crate) fn launch_binop<
 
     let vectorization_factor = u8::min(vectorization_factor_lhs, vectorization_factor_rhs);
 
-    let mut shape_out = [0; D];
-    lhs.shape
-        .dims
-        .iter()
-        .zip(rhs.shape.dims.iter())
-        .enumerate()
-        .for_each(|(index, (dim_lhs, dim_rhs))| {
-            shape_out[index] = usize::max(*dim_lhs, *dim_rhs);
-        });
-
-    let shape_out = Shape::new(shape_out);
+    let shape_out = broadcast_shape(&lhs.shape, &rhs.shape);
     let client = lhs.client.clone();
     let num_elems = shape_out.num_elements();
 
+    if num_elems == 0 {
+        return empty_device(client, lhs.device, shape_out);
+    }
+
     let cube_dim = CubeDim::default();
     let cube_count =
         calculate_cube_count_elemwise(num_elems / vectorization_factor as usize, cube_dim);
 
     if lhs.can_mut_broadcast(&rhs) {
+        crate::perf::record_kernel();
         kernel_binop::launch::<E::Primitive, O, R>(
             &client,
             cube_count,
@@ -167,6 +274,7 @@ pub(crate) fn launch_binop<
 
         lhs
     } else if rhs.can_mut_broadcast(&lhs) {
+        crate::perf::record_kernel();
         kernel_binop::launch::<E::Primitive, O, R>(
             &client,
             cube_count,
@@ -181,12 +289,28 @@ pub(crate) fn launch_binop<
 
         rhs
     } else {
-        let buffer = lhs.client.empty(num_elems * core::mem::size_of::<E>());
-        let output =
-            JitTensor::new_contiguous(lhs.client.clone(), lhs.device.clone(), shape_out, buffer);
+        // The output keeps the memory format of the operand with its shape.
+        let format = if lhs.shape == shape_out {
+            lhs.memory_format()
+        } else if rhs.shape == shape_out {
+            rhs.memory_format()
+        } else {
+            MemoryFormat::Contiguous
+        };
+        let num_bytes = num_elems * core::mem::size_of::<E>();
+        crate::perf::record_allocation(num_bytes);
+        let buffer = lhs.client.empty(num_bytes);
+        let output = JitTensor::new_with_format(
+            lhs.client.clone(),
+            lhs.device.clone(),
+            shape_out,
+            buffer,
+            format,
+        );
         let to_contiguous_lhs = lhs.strides != output.strides || lhs.shape != output.shape;
         let to_contiguous_rhs = rhs.strides != output.strides || rhs.shape != output.shape;
 
+        crate::perf::record_kernel();
         kernel_binop::launch::<E::Primitive, O, R>(
             &client,
             cube_count,
@@ -212,6 +336,17 @@ pub(crate) fn launch_scalar_binop<
     tensor: JitTensor<R, E, D>,
     scalar: E,
 ) -> JitTensor<R, E, D> {
+    // The kernel doesn't handle layouts, so broadcasted tensors are copied first, which also
+    // makes the copy safe to mutate.
+    let tensor = match tensor.is_broadcasted() {
+        true => into_contiguous(tensor),
+        false => tensor,
+    };
+
+    if tensor.is_empty() {
+        return tensor;
+    }
+
     // Vectorization is only enabled when the last dimension is contiguous.
     let vectorization_factor =
         tensor_vectorization_factor(&[4, 2], &tensor.shape.dims, &tensor.strides, D - 1);
@@ -223,6 +358,7 @@ pub(crate) fn launch_scalar_binop<
         calculate_cube_count_elemwise(num_elems / vectorization_factor as usize, cube_dim);
 
     if tensor.can_mut() {
+        crate::perf::record_kernel();
         kernel_scalar_binop::launch::<E::Primitive, O, R>(
             &client,
             cube_count,
@@ -234,7 +370,9 @@ pub(crate) fn launch_scalar_binop<
 
         tensor
     } else {
-        let buffer = tensor.client.empty(num_elems * core::mem::size_of::<E>());
+        let num_bytes = num_elems * core::mem::size_of::<E>();
+        crate::perf::record_allocation(num_bytes);
+        let buffer = tensor.client.empty(num_bytes);
         let output = JitTensor::new(
             tensor.client.clone(),
             buffer,
@@ -243,6 +381,7 @@ pub(crate) fn launch_scalar_binop<
             tensor.strides,
         );
 
+        crate::perf::record_kernel();
         kernel_scalar_binop::launch::<E::Primitive, O, R>(
             &client,
             cube_count,
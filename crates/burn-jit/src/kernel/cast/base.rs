@@ -1,4 +1,6 @@
-use crate::{kernel::Kernel, tensor::JitTensor, JitElement, JitRuntime};
+use crate::{
+    kernel::Kernel, ops::numeric::empty_device, tensor::JitTensor, JitElement, JitRuntime,
+};
 use cubecl::linalg::tensor::index_offset_with_layout;
 use cubecl::{calculate_cube_count_elemwise, prelude::*, tensor_vectorization_factor};
 use cubecl::{ir::KernelDefinition, KernelSettings};
@@ -38,6 +40,10 @@ pub fn cast<R: JitRuntime, EI: JitElement, EO: JitElement, const D: usize>(
         return JitTensor::new_contiguous(input.client, input.device, input.shape, input.handle);
     }
 
+    if input.is_empty() {
+        return empty_device(input.client, input.device, input.shape);
+    }
+
     // Vectorization is only enabled when the last dimension is contiguous.
     let rank = D;
     let vectorization_factor =
@@ -49,7 +55,9 @@ pub fn cast<R: JitRuntime, EI: JitElement, EO: JitElement, const D: usize>(
     let cube_count =
         calculate_cube_count_elemwise(num_elems / vectorization_factor as usize, cube_dim);
     let client = input.client.clone();
-    let handle = client.empty(num_elems * core::mem::size_of::<EO>());
+    let num_bytes = num_elems * core::mem::size_of::<EO>();
+    crate::perf::record_allocation(num_bytes);
+    let handle = client.empty(num_bytes);
     let output = JitTensor::new_contiguous(
         client.clone(),
         input.device.clone(),
@@ -57,6 +65,7 @@ pub fn cast<R: JitRuntime, EI: JitElement, EO: JitElement, const D: usize>(
         handle,
     );
 
+    crate::perf::record_kernel();
     cast_element::launch::<EI::Primitive, EO::Primitive, R>(
         &client,
         cube_count,
@@ -20,7 +20,9 @@ pub fn bool_cast<R: JitRuntime, EO: JitElement, const D: usize>(
     tensor: JitTensor<R, u32, D>,
 ) -> JitTensor<R, EO, D> {
     let num_elems = tensor.shape.num_elements();
-    let buffer = tensor.client.empty(num_elems * core::mem::size_of::<EO>());
+    let num_bytes = num_elems * core::mem::size_of::<EO>();
+    crate::perf::record_allocation(num_bytes);
+    let buffer = tensor.client.empty(num_bytes);
     let output = JitTensor::new_contiguous(
         tensor.client.clone(),
         tensor.device.clone(),
@@ -28,9 +30,14 @@ pub fn bool_cast<R: JitRuntime, EO: JitElement, const D: usize>(
         buffer,
     );
 
+    if output.is_empty() {
+        return output;
+    }
+
     let cube_dim = CubeDim::default();
     let cube_count = calculate_cube_count_elemwise(num_elems, cube_dim);
 
+    crate::perf::record_kernel();
     bool_cast_kernel::launch::<EO::Primitive, R>(
         &tensor.client,
         cube_count,
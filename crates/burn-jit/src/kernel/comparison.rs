@@ -1,6 +1,10 @@
-use super::Kernel;
-use crate::{element::JitElement, tensor::JitTensor, JitRuntime};
-use burn_tensor::Shape;
+use super::{into_contiguous, Kernel};
+use crate::{
+    element::JitElement,
+    ops::{broadcast_shape, numeric::empty_device},
+    tensor::JitTensor,
+    JitRuntime,
+};
 use cubecl::{
     calculate_cube_count_elemwise, linalg::tensor::index_offset_with_layout, prelude::*,
     tensor_vectorization_factor, Runtime,
@@ -126,26 +130,21 @@ pub(crate) fn launch_cmp<
 
     let vectorization_factor = u8::min(vectorization_factor_lhs, vectorization_factor_rhs);
 
-    let mut shape_out = [0; D];
-    lhs.shape
-        .dims
-        .iter()
-        .zip(rhs.shape.dims.iter())
-        .enumerate()
-        .for_each(|(index, (dim_lhs, dim_rhs))| {
-            shape_out[index] = usize::max(*dim_lhs, *dim_rhs);
-        });
-
-    let shape_out = Shape::new(shape_out);
+    let shape_out = broadcast_shape(&lhs.shape, &rhs.shape);
     let client = lhs.client.clone();
     let num_elems = shape_out.num_elements();
 
+    if num_elems == 0 {
+        return empty_device(client, lhs.device, shape_out);
+    }
+
     let cube_dim = CubeDim::default();
     let cube_count =
         calculate_cube_count_elemwise(num_elems / vectorization_factor as usize, cube_dim);
 
     let same_tensor_type = core::any::TypeId::of::<E>() == core::any::TypeId::of::<UInt>();
     if same_tensor_type && lhs.can_mut_broadcast(&rhs) {
+        crate::perf::record_kernel();
         kernel_cmp::launch::<E::Primitive, O, R>(
             &client,
             cube_count,
@@ -160,6 +159,7 @@ pub(crate) fn launch_cmp<
 
         JitTensor::new(lhs.client, lhs.handle, lhs.shape, lhs.device, lhs.strides)
     } else if same_tensor_type && rhs.can_mut_broadcast(&lhs) {
+        crate::perf::record_kernel();
         kernel_cmp::launch::<E::Primitive, O, R>(
             &client,
             cube_count,
@@ -174,12 +174,15 @@ pub(crate) fn launch_cmp<
 
         JitTensor::new(rhs.client, rhs.handle, rhs.shape, rhs.device, rhs.strides)
     } else {
-        let buffer = lhs.client.empty(num_elems * core::mem::size_of::<E>());
+        let num_bytes = num_elems * core::mem::size_of::<E>();
+        crate::perf::record_allocation(num_bytes);
+        let buffer = lhs.client.empty(num_bytes);
         let output =
             JitTensor::new_contiguous(lhs.client.clone(), lhs.device.clone(), shape_out, buffer);
         let to_contiguous_lhs = lhs.strides != output.strides || lhs.shape != output.shape;
         let to_contiguous_rhs = rhs.strides != output.strides || rhs.shape != output.shape;
 
+        crate::perf::record_kernel();
         kernel_cmp::launch::<E::Primitive, O, R>(
             &client,
             cube_count,
@@ -205,6 +208,16 @@ pub(crate) fn launch_scalar_cmp<
     tensor: JitTensor<R, E, D>,
     scalar: E,
 ) -> JitTensor<R, u32, D> {
+    // The kernel doesn't handle layouts, so broadcasted tensors are copied first.
+    let tensor = match tensor.is_broadcasted() {
+        true => into_contiguous(tensor),
+        false => tensor,
+    };
+
+    if tensor.is_empty() {
+        return empty_device(tensor.client, tensor.device, tensor.shape);
+    }
+
     // Vectorization is only enabled when the last dimension is contiguous.
     let vectorization_factor =
         tensor_vectorization_factor(&[4, 2], &tensor.shape.dims, &tensor.strides, D - 1);
@@ -217,6 +230,7 @@ pub(crate) fn launch_scalar_cmp<
 
     let same_tensor_type = core::any::TypeId::of::<E>() == core::any::TypeId::of::<UInt>();
     if same_tensor_type && tensor.can_mut() {
+        crate::perf::record_kernel();
         kernel_scalar_cmp::launch::<E::Primitive, O, R>(
             &client,
             cube_count,
@@ -234,7 +248,9 @@ pub(crate) fn launch_scalar_cmp<
             tensor.strides,
         )
     } else {
-        let buffer = tensor.client.empty(num_elems * core::mem::size_of::<E>());
+        let num_bytes = num_elems * core::mem::size_of::<E>();
+        crate::perf::record_allocation(num_bytes);
+        let buffer = tensor.client.empty(num_bytes);
         let output = JitTensor::new(
             tensor.client.clone(),
             buffer,
@@ -243,6 +259,7 @@ pub(crate) fn launch_scalar_cmp<
             tensor.strides,
         );
 
+        crate::perf::record_kernel();
         kernel_scalar_cmp::launch::<E::Primitive, O, R>(
             &client,
             cube_count,
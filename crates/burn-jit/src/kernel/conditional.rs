@@ -0,0 +1,42 @@
+use cubecl::{
+    ir::{ConditionalAssign, Procedure},
+    prelude::*,
+    unexpanded,
+};
+
+/// Select `then` when the condition holds and `or_else` otherwise, with a conditional assignment
+/// instead of a branch.
+///
+/// Both values are computed by every unit, which makes the selection cheaper than a branch for
+/// the values already at hand, like clamped indices or the zeros padding the edges of a tile. The
+/// values can be vectorized, the condition selecting all of their lanes.
+pub fn cube_select<C: CubePrimitive>(_condition: bool, _then: C, _or_else: C) -> C {
+    unexpanded!()
+}
+
+/// Expanded version of [cube_select].
+pub mod cube_select {
+    use super::*;
+
+    /// Expand the selection to a conditional assignment of a new variable.
+    pub fn __expand<C: CubePrimitive>(
+        context: &mut CubeContext,
+        condition: ExpandElementTyped<bool>,
+        then: ExpandElementTyped<C>,
+        or_else: ExpandElementTyped<C>,
+    ) -> ExpandElementTyped<C> {
+        let condition: ExpandElement = condition.into();
+        let then: ExpandElement = then.into();
+        let or_else: ExpandElement = or_else.into();
+        let out = context.create_local(then.item());
+
+        context.register(Procedure::ConditionalAssign(ConditionalAssign {
+            cond: *condition,
+            lhs: *then,
+            rhs: *or_else,
+            out: *out,
+        }));
+
+        out.into()
+    }
+}
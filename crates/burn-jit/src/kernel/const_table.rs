@@ -0,0 +1,78 @@
+use cubecl::{prelude::*, unexpanded};
+
+/// A table of constants embedded in the kernels using it, instead of being passed as a buffer.
+///
+/// The table is a comptime argument of the kernels, the values being stored as bits since the
+/// comptime values are hashed with the kernel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ConstTable<const N: usize> {
+    bits: [u32; N],
+}
+
+impl<const N: usize> ConstTable<N> {
+    /// Create a new table from its values.
+    pub fn new(values: [f32; N]) -> Self {
+        Self {
+            bits: values.map(f32::to_bits),
+        }
+    }
+
+    /// The value of the table at the given comptime index.
+    ///
+    /// # Panics
+    ///
+    /// When the index is out of bounds, failing the compilation of the kernel.
+    pub fn value(self, index: usize) -> f32 {
+        assert!(
+            index < N,
+            "The index {index} is out of bounds for the table of {N} values."
+        );
+
+        f32::from_bits(self.bits[index])
+    }
+
+    /// Materialize the table in a local array of the kernel, indexable with runtime indices.
+    pub fn materialize<F: Float>(_table: Comptime<Self>) -> Array<F> {
+        unexpanded!()
+    }
+
+    /// Expanded version of [materialize](Self::materialize), assigning each value of the table as
+    /// an immediate constant.
+    pub fn __expand_materialize<F: Float>(
+        context: &mut CubeContext,
+        table: Self,
+    ) -> <Array<F> as CubeType>::ExpandType {
+        let array = new_array::__expand::<F>(context, UInt::new(N as u32));
+
+        for index in 0..N {
+            assign_constant::__expand::<F>(
+                context,
+                array.clone(),
+                UInt::new(index as u32),
+                table.value(index),
+            );
+        }
+
+        array
+    }
+}
+
+impl<const N: usize> Init for ConstTable<N> {
+    fn init(self, _context: &mut CubeContext) -> Self {
+        self
+    }
+}
+
+impl<const N: usize> CubeType for ConstTable<N> {
+    type ExpandType = Self;
+}
+
+#[cube]
+fn new_array<F: Float>(len: Comptime<UInt>) -> Array<F> {
+    Array::<F>::new(Comptime::get(len))
+}
+
+#[cube]
+fn assign_constant<F: Float>(array: &mut Array<F>, index: Comptime<UInt>, value: Comptime<f32>) {
+    array[Comptime::runtime(index)] = F::new(Comptime::get(value));
+}
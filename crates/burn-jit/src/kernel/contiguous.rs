@@ -1,4 +1,9 @@
-use crate::{tensor::JitTensor, JitElement, JitRuntime};
+use crate::{
+    ops::permute,
+    tensor::{assert_u32_span, JitTensor},
+    JitElement, JitRuntime,
+};
+use burn_tensor::MemoryFormat;
 
 /// Make a jit tensor contiguous.
 pub fn into_contiguous<R: JitRuntime, E: JitElement, const D: usize>(
@@ -8,6 +13,23 @@ pub fn into_contiguous<R: JitRuntime, E: JitElement, const D: usize>(
         return tensor;
     }
 
+    if tensor.is_empty() {
+        return JitTensor::new_contiguous(
+            tensor.client,
+            tensor.device,
+            tensor.shape,
+            tensor.handle,
+        );
+    }
+
+    tensor.assert_u32_indexable("into_contiguous");
+    assert_u32_span(
+        "into_contiguous",
+        tensor.shape.num_elements(),
+        &tensor.shape.dims,
+    );
+
+    crate::perf::record_kernel();
     let output = cubecl::linalg::tensor::into_contiguous::<R, E::Primitive>(
         &tensor.client,
         tensor.as_handle_ref(),
@@ -21,3 +43,25 @@ pub fn into_contiguous<R: JitRuntime, E: JitElement, const D: usize>(
         output.strides.try_into().unwrap(),
     )
 }
+
+/// Lay out a jit tensor in the given [memory format](MemoryFormat).
+pub fn into_memory_format<R: JitRuntime, E: JitElement, const D: usize>(
+    tensor: JitTensor<R, E, D>,
+    format: MemoryFormat,
+) -> JitTensor<R, E, D> {
+    match format {
+        MemoryFormat::Contiguous => into_contiguous(tensor),
+        MemoryFormat::ChannelsLast if tensor.memory_format() == format => tensor,
+        MemoryFormat::ChannelsLast => {
+            // The tensor is made contiguous in the order of the format, then the dimensions
+            // are permuted back.
+            let axes = format.permutation::<D>();
+            let mut inverse = [0; D];
+            for (i, axis) in axes.iter().enumerate() {
+                inverse[*axis] = i;
+            }
+
+            permute(into_contiguous(permute(tensor, axes)), inverse)
+        }
+    }
+}
@@ -6,9 +6,9 @@ use burn_tensor::{
 };
 
 use crate::{
-    kernel::into_contiguous,
+    kernel::into_memory_format,
     ops::{
-        numeric::{empty_device, zeros_device},
+        numeric::{empty_device_with_format, zeros_device},
         reshape,
     },
     tensor::JitTensor,
@@ -121,8 +121,12 @@ pub(crate) fn conv2d<R: JitRuntime, E: FloatElement>(
     bias: Option<JitTensor<R, E, 1>>,
     options: ConvOptions<2>,
 ) -> JitTensor<R, E, 4> {
-    let input = into_contiguous(input);
-    let weight = into_contiguous(weight);
+    // The kernel reads the strides of its inputs, so the channels last tensors are used as is,
+    // and the output keeps the memory format of the input.
+    let format = input.memory_format();
+    let input = into_memory_format(input, format);
+    let weight_format = weight.memory_format();
+    let weight = into_memory_format(weight, weight_format);
     let [batch_size, _, in_height, in_width] = input.shape.dims;
     let [out_channels, _, kernel_0, kernel_1] = weight.shape.dims;
 
@@ -143,12 +147,17 @@ pub(crate) fn conv2d<R: JitRuntime, E: FloatElement>(
 
     let shape_out = Shape::new([batch_size, out_channels, out_0, out_1]);
 
-    let output = empty_device(
+    let output = empty_device_with_format(
         input.client.clone(),
         input.device.clone(),
         shape_out.clone(),
+        format,
     );
 
+    if output.is_empty() {
+        return output;
+    }
+
     let bias = match bias {
         Some(bias) => {
             let shape = Shape::from([bias.shape.dims[0], 1, 1, 1]);
@@ -164,6 +173,7 @@ pub(crate) fn conv2d<R: JitRuntime, E: FloatElement>(
     let cube_dim = CubeDim::default();
     let cube_count = calculate_cube_count_elemwise(num_elems_output, cube_dim);
 
+    crate::perf::record_kernel();
     conv2d_kernel::launch::<E::FloatPrimitive, R>(
         &input.client,
         cube_count,
@@ -177,6 +177,10 @@ pub(crate) fn conv3d<R: JitRuntime, E: FloatElement>(
         shape_out.clone(),
     );
 
+    if output.is_empty() {
+        return output;
+    }
+
     let bias = match bias {
         Some(bias) => {
             let shape = Shape::from([bias.shape.dims[0], 1, 1, 1, 1]);
@@ -191,6 +195,7 @@ pub(crate) fn conv3d<R: JitRuntime, E: FloatElement>(
     let cube_dim = CubeDim::default();
     let cube_count = calculate_cube_count_elemwise(output.shape.num_elements(), cube_dim);
 
+    crate::perf::record_kernel();
     conv3d_kernel::launch::<E::FloatPrimitive, R>(
         &input.client,
         cube_count,
@@ -6,8 +6,11 @@ use cubecl::{
 };
 use std::marker::PhantomData;
 
+#[cfg(feature = "autotune")]
+use super::conv_transpose2d_autotune;
+use super::conv_transpose2d_implicit_gemm;
 use crate::{
-    element::JitElement,
+    element::{FloatElement, JitElement},
     kernel::{self, Kernel},
     ops::{
         numeric::{empty_device, zeros_device},
@@ -16,7 +19,7 @@ use crate::{
     tensor::JitTensor,
     JitRuntime,
 };
-use burn_tensor::{ops::ConvTransposeOptions, Element, Shape};
+use burn_tensor::{ops::ConvTransposeOptions, Shape};
 
 #[derive(new)]
 struct Conv2dTransposeEagerKernel<R, E> {
@@ -364,14 +367,77 @@ impl<R: JitRuntime, E: JitElement> Kernel for Conv2dTransposeEagerKernel<R, E> {
     }
 }
 
-pub(crate) fn conv_transpose2d<R: JitRuntime, E: JitElement + Element>(
+/// The strategy to be used when launching a transposed convolution kernel.
+#[derive(Copy, Clone, Debug)]
+pub enum ConvTranspose2dStrategy {
+    /// A direct kernel where each unit computes one element of the output.
+    Direct,
+    /// An implicit GEMM kernel, computing the convolution of the input dilated by the stride with
+    /// the transposed filters.
+    ImplicitGemm,
+    #[cfg(feature = "autotune")]
+    /// Using autotune to chose the best kernel based on runtime information.
+    Autotune,
+}
+
+impl Default for ConvTranspose2dStrategy {
+    fn default() -> Self {
+        // if autotune is enabled, default to autotune
+        #[cfg(feature = "autotune")]
+        return ConvTranspose2dStrategy::Autotune;
+
+        #[cfg(not(feature = "autotune"))]
+        ConvTranspose2dStrategy::ImplicitGemm
+    }
+}
+
+/// Perform a 2D transposed convolution using the given strategy.
+pub fn conv_transpose2d<R: JitRuntime, E: FloatElement>(
     input: JitTensor<R, E, 4>,
     weight: JitTensor<R, E, 4>,
     bias: Option<JitTensor<R, E, 1>>,
     options: ConvTransposeOptions<2>,
+    strategy: ConvTranspose2dStrategy,
 ) -> JitTensor<R, E, 4> {
     let input = kernel::into_contiguous(input);
     let weight = kernel::into_contiguous(weight);
+    let output = init_conv_transpose2d_output(&input, &weight, &options);
+
+    if output.is_empty() {
+        return output;
+    }
+
+    let bias = match bias {
+        Some(bias) => {
+            let shape = Shape::from([bias.shape.dims[0], 1, 1, 1]);
+            reshape(bias, shape)
+        }
+        None => {
+            let shape = Shape::from([output.shape.dims[1], 1, 1, 1]);
+            zeros_device(input.client.clone(), input.device.clone(), shape)
+        }
+    };
+
+    match strategy {
+        ConvTranspose2dStrategy::Direct => {
+            conv_transpose2d_direct(input, weight, bias, output, options)
+        }
+        ConvTranspose2dStrategy::ImplicitGemm => {
+            conv_transpose2d_implicit_gemm(input, weight, bias, output, options)
+        }
+        #[cfg(feature = "autotune")]
+        ConvTranspose2dStrategy::Autotune => {
+            conv_transpose2d_autotune(input, weight, bias, output, options)
+        }
+    }
+}
+
+/// Creates an empty output tensor with the transposed convolution output shape.
+pub(crate) fn init_conv_transpose2d_output<R: JitRuntime, E: JitElement>(
+    input: &JitTensor<R, E, 4>,
+    weight: &JitTensor<R, E, 4>,
+    options: &ConvTransposeOptions<2>,
+) -> JitTensor<R, E, 4> {
     let [batch_size, _, in_height, in_width] = input.shape.dims;
     let [_, out_channels, kernel_0, kernel_1] = weight.shape.dims;
 
@@ -388,25 +454,21 @@ pub(crate) fn conv_transpose2d<R: JitRuntime, E: JitElement + Element>(
 
     let shape_out = Shape::new([batch_size, out_channels * options.groups, out_0, out_1]);
 
-    let output = empty_device(
-        input.client.clone(),
-        input.device.clone(),
-        shape_out.clone(),
-    );
-
-    let bias = match bias {
-        Some(bias) => {
-            let shape = Shape::from([bias.shape.dims[0], 1, 1, 1]);
-            reshape(bias, shape)
-        }
-        None => {
-            let shape = Shape::from([output.shape.dims[0], 1, 1, 1]);
-            zeros_device(input.client.clone(), input.device.clone(), shape)
-        }
-    };
+    empty_device(input.client.clone(), input.device.clone(), shape_out)
+}
 
+/// Launch the direct transposed convolution kernel on contiguous tensors, with the bias of shape
+/// `[channels_out, 1, 1, 1]`.
+pub(crate) fn conv_transpose2d_direct<R: JitRuntime, E: JitElement>(
+    input: JitTensor<R, E, 4>,
+    weight: JitTensor<R, E, 4>,
+    bias: JitTensor<R, E, 4>,
+    output: JitTensor<R, E, 4>,
+    options: ConvTransposeOptions<2>,
+) -> JitTensor<R, E, 4> {
     let kernel = Conv2dTransposeEagerKernel::<R, E>::new();
 
+    crate::perf::record_kernel();
     Execution::start(kernel, input.client.clone())
         .inputs(&[
             input.as_handle_ref(),
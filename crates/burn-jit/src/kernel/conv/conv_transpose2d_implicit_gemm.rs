@@ -0,0 +1,199 @@
+//! Transposed convolution as an implicit GEMM.
+//!
+//! The transposed convolution is the convolution of the input dilated by the stride, with the
+//! filters rotated by 180 degrees and with the input and output channels swapped. Seen as a
+//! matrix multiplication, the lhs is the im2col matrix of the dilated input, of shape
+//! `[batch_size * out_height * out_width, channels_in_per_group * kernel_0 * kernel_1]`, and the
+//! rhs is the matrix of the filters, of shape
+//! `[channels_in_per_group * kernel_0 * kernel_1, channels_out_per_group]`, for each group.
+//!
+//! Neither matrix is materialized: the tiles are loaded in shared memory straight from the input
+//! and the filters. The zeros inserted by the dilation are never read, since an output position
+//! only gathers the input positions aligned with the stride, and the rotation of the filters is
+//! folded in the index arithmetic.
+use crate::{kernel::SUBCUBE_DIM_APPROX, tensor::JitTensor, FloatElement, JitRuntime};
+use burn_tensor::ops::ConvTransposeOptions;
+use cubecl::prelude::*;
+
+#[derive(CubeLaunch)]
+struct ConvTransposeArgs {
+    stride_0: UInt,
+    stride_1: UInt,
+    dilation_0: UInt,
+    dilation_1: UInt,
+    padding_0: UInt,
+    padding_1: UInt,
+    groups: UInt,
+}
+
+#[cube(launch)]
+fn conv_transpose2d_implicit_gemm_kernel<F: Float>(
+    input: &Tensor<F>,
+    weight: &Tensor<F>,
+    bias: &Tensor<F>,
+    output: &mut Tensor<F>,
+    args: &ConvTransposeArgs,
+    tile_size: Comptime<UInt>,
+) {
+    let tile = Comptime::runtime(tile_size);
+    let tile_area = Comptime::map(tile_size, |tile: UInt| tile * tile);
+    let mut lhs_tile = SharedMemory::<F>::new(Comptime::get(tile_area));
+    let mut rhs_tile = SharedMemory::<F>::new(Comptime::get(tile_area));
+
+    let channels_in_per_group = weight.shape(0) / args.groups;
+    let channels_out_per_group = weight.shape(1);
+    let kernel_0 = weight.shape(2);
+    let kernel_1 = weight.shape(3);
+    let out_height = output.shape(2);
+    let out_width = output.shape(3);
+
+    let size_m = output.shape(0) * out_height * out_width;
+    let size_k = channels_in_per_group * kernel_0 * kernel_1;
+
+    let row = CUBE_POS_X * tile + UNIT_POS_X;
+    let col = CUBE_POS_Y * tile + UNIT_POS_Y;
+    let group = CUBE_POS_Z;
+
+    let b = row / (out_height * out_width);
+    let oh = (row / out_width) % out_height;
+    let ow = row % out_width;
+
+    let mut sum = F::new(0.);
+    let num_tiles = (size_k + tile - UInt::new(1)) / tile;
+
+    for t in range(0u32, num_tiles, Comptime::new(false)) {
+        // Every unit loads one element of each tile, the out of bound elements being zeros so
+        // that they don't contribute to the sum.
+        let k_lhs = t * tile + UNIT_POS_Y;
+        let mut lhs_value = F::new(0.);
+        if row < size_m && k_lhs < size_k {
+            let ic = k_lhs / (kernel_0 * kernel_1);
+            let kh = (k_lhs / kernel_1) % kernel_0;
+            let kw = k_lhs % kernel_1;
+            let channel = group * channels_in_per_group + ic;
+
+            lhs_value = dilated_input(input, args, b, channel, oh, ow, kh, kw);
+        }
+        lhs_tile[UNIT_POS_X * tile + UNIT_POS_Y] = lhs_value;
+
+        let k_rhs = t * tile + UNIT_POS_X;
+        let mut rhs_value = F::new(0.);
+        if k_rhs < size_k && col < channels_out_per_group {
+            let ic = k_rhs / (kernel_0 * kernel_1);
+            let kh = (k_rhs / kernel_1) % kernel_0;
+            let kw = k_rhs % kernel_1;
+            let channel = group * channels_in_per_group + ic;
+
+            rhs_value = weight[channel * weight.stride(0)
+                + col * weight.stride(1)
+                + kh * weight.stride(2)
+                + kw * weight.stride(3)];
+        }
+        rhs_tile[UNIT_POS_X * tile + UNIT_POS_Y] = rhs_value;
+
+        sync_units();
+
+        for i in range(0u32, Comptime::get(tile_size), Comptime::new(false)) {
+            sum += lhs_tile[UNIT_POS_X * tile + i] * rhs_tile[i * tile + UNIT_POS_Y];
+        }
+
+        sync_units();
+    }
+
+    if row < size_m && col < channels_out_per_group {
+        let oc = group * channels_out_per_group + col;
+
+        output[b * output.stride(0)
+            + oc * output.stride(1)
+            + oh * output.stride(2)
+            + ow * output.stride(3)] = sum + bias[oc];
+    }
+}
+
+/// Read the input dilated by the stride at the position of the output tap `(kh, kw)`.
+///
+/// The output position `oh` gathers the input position `ih` through the tap `kh` when
+/// `oh + padding = ih * stride + kh * dilation`, any other position of the dilated input being
+/// zero.
+#[cube]
+#[allow(clippy::too_many_arguments)]
+fn dilated_input<F: Float>(
+    input: &Tensor<F>,
+    args: &ConvTransposeArgs,
+    b: UInt,
+    channel: UInt,
+    oh: UInt,
+    ow: UInt,
+    kh: UInt,
+    kw: UInt,
+) -> F {
+    let mut value = F::new(0.);
+
+    let padded_h = oh + args.padding_0;
+    let padded_w = ow + args.padding_1;
+    let offset_h = kh * args.dilation_0;
+    let offset_w = kw * args.dilation_1;
+
+    if padded_h >= offset_h && padded_w >= offset_w {
+        let dilated_h = padded_h - offset_h;
+        let dilated_w = padded_w - offset_w;
+
+        if dilated_h % args.stride_0 == UInt::new(0) && dilated_w % args.stride_1 == UInt::new(0) {
+            let ih = dilated_h / args.stride_0;
+            let iw = dilated_w / args.stride_1;
+
+            if ih < input.shape(2) && iw < input.shape(3) {
+                value = input[b * input.stride(0)
+                    + channel * input.stride(1)
+                    + ih * input.stride(2)
+                    + iw * input.stride(3)];
+            }
+        }
+    }
+
+    value
+}
+
+/// Launch the implicit GEMM transposed convolution kernel on contiguous tensors, with the bias
+/// of shape `[channels_out, 1, 1, 1]`.
+pub(crate) fn conv_transpose2d_implicit_gemm<R: JitRuntime, E: FloatElement>(
+    input: JitTensor<R, E, 4>,
+    weight: JitTensor<R, E, 4>,
+    bias: JitTensor<R, E, 4>,
+    output: JitTensor<R, E, 4>,
+    options: ConvTransposeOptions<2>,
+) -> JitTensor<R, E, 4> {
+    let tile_size = SUBCUBE_DIM_APPROX;
+    let [batch_size, _, out_height, out_width] = output.shape.dims;
+    let channels_out_per_group = weight.shape.dims[1];
+
+    let size_m = batch_size * out_height * out_width;
+    let cube_count = CubeCount::Static(
+        f32::ceil(size_m as f32 / tile_size as f32) as u32,
+        f32::ceil(channels_out_per_group as f32 / tile_size as f32) as u32,
+        options.groups as u32,
+    );
+
+    crate::perf::record_kernel();
+    conv_transpose2d_implicit_gemm_kernel::launch::<E::FloatPrimitive, R>(
+        &input.client,
+        cube_count,
+        CubeDim::new(tile_size as u32, tile_size as u32, 1),
+        input.as_tensor_arg(1),
+        weight.as_tensor_arg(1),
+        bias.as_tensor_arg(1),
+        output.as_tensor_arg(1),
+        ConvTransposeArgsLaunch::new(
+            ScalarArg::new(options.stride[0] as u32),
+            ScalarArg::new(options.stride[1] as u32),
+            ScalarArg::new(options.dilation[0] as u32),
+            ScalarArg::new(options.dilation[1] as u32),
+            ScalarArg::new(options.padding[0] as u32),
+            ScalarArg::new(options.padding[1] as u32),
+            ScalarArg::new(options.groups as u32),
+        ),
+        UInt::new(tile_size as u32),
+    );
+
+    output
+}
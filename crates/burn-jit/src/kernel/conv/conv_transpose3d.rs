@@ -482,6 +482,10 @@ pub(crate) fn conv_transpose3d<R: JitRuntime, E: JitElement + Element>(
         shape_out.clone(),
     );
 
+    if output.is_empty() {
+        return output;
+    }
+
     let bias = match bias {
         Some(bias) => {
             let shape = Shape::from([bias.shape.dims[0], 1, 1, 1, 1]);
@@ -495,6 +499,7 @@ pub(crate) fn conv_transpose3d<R: JitRuntime, E: JitElement + Element>(
 
     let kernel = Conv3dTransposeEagerKernel::<R, E>::new();
 
+    crate::perf::record_kernel();
     Execution::start(kernel, input.client.clone())
         .inputs(&[
             input.as_handle_ref(),
@@ -1,9 +1,13 @@
 mod conv2d;
 mod conv3d;
 mod conv_transpose2d;
+mod conv_transpose2d_implicit_gemm;
 mod conv_transpose3d;
+mod tune;
 
 pub(crate) use conv2d::*;
 pub(crate) use conv3d::*;
-pub(crate) use conv_transpose2d::*;
+pub use conv_transpose2d::*;
+pub(crate) use conv_transpose2d_implicit_gemm::*;
 pub(crate) use conv_transpose3d::*;
+pub use tune::*;
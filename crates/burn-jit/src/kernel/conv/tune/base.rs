@@ -0,0 +1,179 @@
+use burn_tensor::{ops::ConvTransposeOptions, ElementConversion};
+use cubecl::tune::{local_tuner, AutotuneOperation, AutotuneOperationSet, LocalTuner};
+
+use crate::{
+    element::FloatElement,
+    kernel::{
+        conv::{conv_transpose2d_direct, conv_transpose2d_implicit_gemm},
+        prng::random_like_uniform,
+    },
+    ops::numeric::empty_device,
+    tensor::JitTensor,
+    tune_cache::execute_tuned,
+    tune_key::JitAutotuneKey,
+    JitRuntime, JitTuneId,
+};
+
+use super::ConvTranspose2dAutotuneKey;
+
+/// Set of transposed convolution implementations available for autotune
+pub(crate) struct ConvTranspose2dAutotuneOperationSet<R: JitRuntime, E: FloatElement> {
+    key: JitAutotuneKey,
+    input: JitTensor<R, E, 4>,
+    weight: JitTensor<R, E, 4>,
+    bias: JitTensor<R, E, 4>,
+    output: JitTensor<R, E, 4>,
+    options: ConvTransposeOptions<2>,
+}
+
+impl<R: JitRuntime, E: FloatElement> ConvTranspose2dAutotuneOperationSet<R, E> {
+    fn new(
+        input: JitTensor<R, E, 4>,
+        weight: JitTensor<R, E, 4>,
+        bias: JitTensor<R, E, 4>,
+        output: JitTensor<R, E, 4>,
+        options: ConvTransposeOptions<2>,
+    ) -> Self {
+        Self {
+            key: JitAutotuneKey::ConvTranspose2d(ConvTranspose2dAutotuneKey::new(
+                &input.shape,
+                &weight.shape,
+                &options,
+            )),
+            input,
+            weight,
+            bias,
+            output,
+            options,
+        }
+    }
+}
+
+impl<R: JitRuntime, E: FloatElement> AutotuneOperationSet<JitAutotuneKey>
+    for ConvTranspose2dAutotuneOperationSet<R, E>
+{
+    fn key(&self) -> JitAutotuneKey {
+        self.key.clone()
+    }
+
+    fn autotunables(&self) -> Vec<Box<dyn AutotuneOperation>> {
+        let random_bounds: (E, E) = ((-10.0).elem::<E>(), (10.0).elem::<E>());
+        let input = random_like_uniform(&self.input, random_bounds.0, random_bounds.1);
+        let weight = random_like_uniform(&self.weight, random_bounds.0, random_bounds.1);
+        let bias = random_like_uniform(&self.bias, random_bounds.0, random_bounds.1);
+
+        let output = empty_device(
+            self.output.client.clone(),
+            self.output.device.clone(),
+            self.output.shape.clone(),
+        );
+
+        vec![
+            Box::new(ConvTranspose2dDirect::new(
+                input.clone(),
+                weight.clone(),
+                bias.clone(),
+                output.clone(),
+                self.options.clone(),
+            )),
+            Box::new(ConvTranspose2dImplicitGemm::new(
+                input,
+                weight,
+                bias,
+                output,
+                self.options.clone(),
+            )),
+        ]
+    }
+
+    fn fastest(self: Box<Self>, fastest_index: usize) -> Box<dyn AutotuneOperation> {
+        match fastest_index {
+            0 => Box::new(ConvTranspose2dDirect::new(
+                self.input,
+                self.weight,
+                self.bias,
+                self.output,
+                self.options,
+            )),
+            1 => Box::new(ConvTranspose2dImplicitGemm::new(
+                self.input,
+                self.weight,
+                self.bias,
+                self.output,
+                self.options,
+            )),
+            _ => panic!("Fastest index is out of bound"),
+        }
+    }
+}
+
+/// Executes autotune on transposed convolution operations
+pub(crate) fn conv_transpose2d_autotune<R: JitRuntime, E: FloatElement>(
+    input: JitTensor<R, E, 4>,
+    weight: JitTensor<R, E, 4>,
+    bias: JitTensor<R, E, 4>,
+    output: JitTensor<R, E, 4>,
+    options: ConvTransposeOptions<2>,
+) -> JitTensor<R, E, 4> {
+    let client = input.client.clone();
+    let device = input.device.clone();
+
+    static TUNER: LocalTuner<JitAutotuneKey, JitTuneId> = local_tuner!();
+
+    execute_tuned::<R>(
+        &TUNER,
+        &device,
+        &client,
+        Box::new(ConvTranspose2dAutotuneOperationSet::new(
+            input,
+            weight,
+            bias,
+            output.clone(),
+            options,
+        )),
+        2,
+    );
+
+    output
+}
+
+macro_rules! conv_transpose2d_tune_ops {
+    ($name:ident, $func:expr) => {
+        #[derive(new)]
+        pub(crate) struct $name<R: JitRuntime, E: FloatElement> {
+            input: JitTensor<R, E, 4>,
+            weight: JitTensor<R, E, 4>,
+            bias: JitTensor<R, E, 4>,
+            output: JitTensor<R, E, 4>,
+            options: ConvTransposeOptions<2>,
+        }
+
+        impl<R: JitRuntime, E: FloatElement> AutotuneOperation for $name<R, E> {
+            fn execute(self: Box<Self>) {
+                $func(
+                    self.input,
+                    self.weight,
+                    self.bias,
+                    self.output,
+                    self.options,
+                );
+            }
+
+            fn clone(&self) -> Box<dyn AutotuneOperation> {
+                Box::new(Self {
+                    input: self.input.clone(),
+                    weight: self.weight.clone(),
+                    bias: self.bias.clone(),
+                    output: self.output.clone(),
+                    options: self.options.clone(),
+                })
+            }
+        }
+    };
+}
+
+// Probably better when the filters are large compared to the channels.
+conv_transpose2d_tune_ops!(ConvTranspose2dDirect, conv_transpose2d_direct);
+
+// Probably the fastest in the general case, since the tiles are shared between units.
+conv_transpose2d_tune_ops!(ConvTranspose2dImplicitGemm, conv_transpose2d_implicit_gemm);
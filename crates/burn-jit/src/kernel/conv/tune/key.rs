@@ -0,0 +1,91 @@
+use crate::tune::anchor;
+use burn_tensor::{ops::ConvTransposeOptions, Shape};
+use core::fmt::Debug;
+use serde::{Deserialize, Serialize};
+use std::{fmt::Display, hash::Hash};
+
+#[derive(Hash, Eq, PartialEq, Debug, Clone, Serialize, Deserialize)]
+/// Autotune key representative of transposed convolution versions
+pub struct ConvTranspose2dAutotuneKey {
+    kernel_size: [usize; 2],
+    stride: [usize; 2],
+    padding: [usize; 2],
+    dilation: [usize; 2],
+    groups: usize,
+    anchored_channels_in: usize,
+    anchored_channels_out: usize,
+    anchored_height: usize,
+    anchored_width: usize,
+    anchored_batch: usize,
+}
+
+impl Display for ConvTranspose2dAutotuneKey {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(
+            format!(
+                "ConvTranspose2d - Kernel:{:?} Stride:{:?} Padding:{:?} Dilation:{:?} Groups:{:?} \
+                 channels_in:{:?} channels_out:{:?} height:{:?} width:{:?} batch:{:?}",
+                self.kernel_size,
+                self.stride,
+                self.padding,
+                self.dilation,
+                self.groups,
+                self.anchored_channels_in,
+                self.anchored_channels_out,
+                self.anchored_height,
+                self.anchored_width,
+                self.anchored_batch
+            )
+            .as_str(),
+        )
+    }
+}
+
+impl ConvTranspose2dAutotuneKey {
+    /// Create a transposed convolution autotune key from the input and weight shapes
+    pub fn new(
+        input_shape: &Shape<4>,
+        weight_shape: &Shape<4>,
+        options: &ConvTransposeOptions<2>,
+    ) -> Self {
+        let [batch_size, channels_in, height, width] = input_shape.dims;
+        let [_, channels_out_per_group, kernel_0, kernel_1] = weight_shape.dims;
+
+        Self {
+            kernel_size: [kernel_0, kernel_1],
+            stride: options.stride,
+            padding: options.padding,
+            dilation: options.dilation,
+            groups: options.groups,
+            anchored_channels_in: anchor(channels_in, None),
+            anchored_channels_out: anchor(channels_out_per_group * options.groups, None),
+            anchored_height: anchor(height, None),
+            anchored_width: anchor(width, None),
+            anchored_batch: anchor(batch_size, Some(128)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conv_transpose2d_autotune_key_anchors_sizes() {
+        let options = ConvTransposeOptions::new([2, 2], [1, 1], [1, 1], [1, 1], 2);
+        let key = ConvTranspose2dAutotuneKey::new(
+            &[512, 30, 17, 32].into(),
+            &[30, 8, 3, 3].into(),
+            &options,
+        );
+
+        assert_eq!(key.kernel_size, [3, 3]);
+        assert_eq!(key.stride, [2, 2]);
+        assert_eq!(key.groups, 2);
+        assert_eq!(key.anchored_channels_in, 32);
+        assert_eq!(key.anchored_channels_out, 16);
+        assert_eq!(key.anchored_height, 32);
+        assert_eq!(key.anchored_width, 32);
+        assert_eq!(key.anchored_batch, 128);
+    }
+}
@@ -0,0 +1,7 @@
+#[cfg(feature = "autotune")]
+mod base;
+mod key;
+
+#[cfg(feature = "autotune")]
+pub(crate) use base::*;
+pub use key::*;
@@ -0,0 +1,459 @@
+//! Runtime assertions and debug prints of the kernels.
+//!
+//! A failing [cube_assert] doesn't stop the kernel, which has no way to trap on most runtimes.
+//! It instead flags the failure in a small buffer bound as an argument of the kernel, along with
+//! the absolute position of a failing unit, and the host [checks](KernelAssertions::check) the
+//! buffer after the launch. When several units fail, the position is the one of any of them.
+//!
+//! The assertions are enabled by a comptime flag, set from the debug assertions of the host
+//! build, so they are compiled out of the release kernels.
+//!
+//! Since WGSL has no `printf`, the [debug prints](debug_print) of the units are recorded in a
+//! buffer too, each unit owning a region of it where its prints are written in order, then
+//! [decoded](DebugPrints::decode) on the host with their formats after the launch. The formats
+//! are identified by their [hash](format_id), so the prints declared with
+//! [debug_print!](crate::debug_print) don't need a shared table of formats.
+use crate::{ops::numeric::zeros_device, tensor::JitTensor, JitElement, JitRuntime};
+use burn_tensor::Shape;
+use cubecl::{client::ComputeClient, prelude::*, CubeElement};
+
+/// Flag the failure of the condition for the unit in the assertion buffer of the kernel, when the
+/// assertions are enabled.
+#[cube]
+pub fn cube_assert(condition: bool, assertions: &mut Tensor<UInt>, enabled: Comptime<bool>) {
+    if Comptime::get(enabled) {
+        if !condition {
+            assertions[0] = UInt::new(1);
+            assertions[1] = ABSOLUTE_POS;
+        }
+    }
+}
+
+/// The failure of a [kernel assertion](cube_assert).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KernelAssertionError {
+    /// The absolute position of a failing unit.
+    pub absolute_pos: u32,
+}
+
+impl core::fmt::Display for KernelAssertionError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "A kernel assertion failed for the unit at the absolute position {}.",
+            self.absolute_pos
+        )
+    }
+}
+
+impl std::error::Error for KernelAssertionError {}
+
+/// The assertion buffer of a kernel launch, holding a failure flag and the position of a failing
+/// unit.
+pub struct KernelAssertions<R: JitRuntime> {
+    buffer: JitTensor<R, u32, 1>,
+    /// Whether the buffer is the one of the launch, instead of the tensor bound in its place.
+    allocated: bool,
+}
+
+impl<R: JitRuntime> KernelAssertions<R> {
+    /// Create the assertion buffer of a launch, without any failure.
+    pub fn new(client: ComputeClient<R::Server, R::Channel>, device: R::Device) -> Self {
+        Self {
+            buffer: zeros_device(client, device, Shape::new([2])),
+            allocated: true,
+        }
+    }
+
+    /// The assertion buffer of a launch writing to the tensor, only allocated when the
+    /// assertions are [enabled](Self::enabled).
+    ///
+    /// Otherwise, the argument of the kernel binds the tensor again instead of a buffer of its
+    /// own, which the kernel never accesses since its assertions are compiled out.
+    pub fn of<E: JitElement, const D: usize>(tensor: &JitTensor<R, E, D>) -> Self {
+        if Self::enabled() {
+            return Self::new(tensor.client.clone(), tensor.device.clone());
+        }
+
+        Self {
+            buffer: JitTensor::new_contiguous(
+                tensor.client.clone(),
+                tensor.device.clone(),
+                Shape::new([1]),
+                tensor.handle.clone(),
+            ),
+            allocated: false,
+        }
+    }
+
+    /// Whether the assertions are compiled in the kernels, which is the case in debug builds.
+    pub fn enabled() -> bool {
+        cfg!(debug_assertions)
+    }
+
+    /// The argument of the kernel binding the buffer.
+    pub fn as_tensor_arg(&self) -> TensorArg<'_, R> {
+        self.buffer.as_tensor_arg(1)
+    }
+
+    /// Read the buffer after the launch, returning the failure of an assertion if any.
+    ///
+    /// Nothing is read when the buffer wasn't allocated, the assertions being compiled out.
+    pub fn check(&self) -> Result<(), KernelAssertionError> {
+        if !self.allocated {
+            return Ok(());
+        }
+
+        let bytes = self.buffer.client.read(self.buffer.handle.binding());
+
+        match u32::from_bytes(&bytes) {
+            [0, ..] => Ok(()),
+            [_, absolute_pos, ..] => Err(KernelAssertionError {
+                absolute_pos: *absolute_pos,
+            }),
+            _ => unreachable!("The assertion buffer holds a flag and a position"),
+        }
+    }
+}
+
+/// The number of elements of a record of a [debug print](debug_print): its format and its two
+/// values.
+const PRINT_RECORD_LEN: u32 = 3;
+
+/// The comptime configuration of the [debug prints](debug_print) of a kernel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DebugPrintConfig {
+    /// The number of prints recorded for each unit.
+    max_prints: UInt,
+    /// Whether the prints are compiled in the kernel.
+    enabled: bool,
+}
+
+impl Init for DebugPrintConfig {
+    fn init(self, _context: &mut CubeContext) -> Self {
+        self
+    }
+}
+
+impl CubeType for DebugPrintConfig {
+    type ExpandType = Self;
+}
+
+/// Record the print of two values with the format of the given [id](format_id), in the region of
+/// the unit.
+///
+/// The prints past the maximum number of prints of a unit are counted but not recorded, and the
+/// units without a region in the buffer don't print anything.
+#[cube]
+pub fn debug_print(
+    buffer: &mut Tensor<UInt>,
+    format: UInt,
+    first: UInt,
+    second: UInt,
+    config: Comptime<DebugPrintConfig>,
+) {
+    let enabled = Comptime::map(config, |c: DebugPrintConfig| c.enabled);
+
+    if Comptime::get(enabled) {
+        let max_prints =
+            Comptime::runtime(Comptime::map(config, |c: DebugPrintConfig| c.max_prints));
+        let record_len = UInt::new(PRINT_RECORD_LEN);
+        let region_len = UInt::new(1) + max_prints * record_len;
+        let region = ABSOLUTE_POS * region_len;
+
+        if region + region_len <= buffer.len() {
+            let count = buffer[region];
+
+            if count < max_prints {
+                let record = region + UInt::new(1) + count * record_len;
+                buffer[record] = format;
+                buffer[record + UInt::new(1)] = first;
+                buffer[record + UInt::new(2)] = second;
+            }
+
+            buffer[region] = count + UInt::new(1);
+        }
+    }
+}
+
+/// The [debug print](debug_print) of the values with a comptime format id, called by the prints
+/// declared with [debug_print!](crate::debug_print).
+#[doc(hidden)]
+#[cube]
+pub fn debug_print_format(
+    buffer: &mut Tensor<UInt>,
+    format: Comptime<UInt>,
+    first: UInt,
+    second: UInt,
+    config: Comptime<DebugPrintConfig>,
+) {
+    debug_print(buffer, Comptime::runtime(format), first, second, config);
+}
+
+/// The [debug print](debug_print) of a single value with a comptime format id, called by the
+/// prints declared with [debug_print!](crate::debug_print).
+#[doc(hidden)]
+#[cube]
+pub fn debug_print_format_single(
+    buffer: &mut Tensor<UInt>,
+    format: Comptime<UInt>,
+    value: UInt,
+    config: Comptime<DebugPrintConfig>,
+) {
+    debug_print(
+        buffer,
+        Comptime::runtime(format),
+        value,
+        UInt::new(0),
+        config,
+    );
+}
+
+/// The id of a format recorded by the [debug prints](debug_print), its 32 bit FNV-1a hash.
+pub const fn format_id(format: &str) -> u32 {
+    let bytes = format.as_bytes();
+    let mut hash = 0x811c_9dc5u32;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        hash ^= bytes[i] as u32;
+        hash = hash.wrapping_mul(0x0100_0193);
+        i += 1;
+    }
+
+    hash
+}
+
+/// The number of values of a format, each being written in place of a `{}`.
+pub const fn format_arity(format: &str) -> usize {
+    let bytes = format.as_bytes();
+    let mut arity = 0;
+    let mut i = 0;
+
+    while i + 1 < bytes.len() {
+        if bytes[i] == b'{' && bytes[i + 1] == b'}' {
+            arity += 1;
+            i += 1;
+        }
+        i += 1;
+    }
+
+    arity
+}
+
+/// Declare a [debug print](debug_print) of one or two values with a format, as a function called
+/// by the kernels with the print buffer and the [configuration](DebugPrints::config) of the
+/// launch.
+///
+/// ```ignore
+/// burn_jit::debug_print!(fn print_row_col("row={} col={}", row, col));
+///
+/// #[cube(launch)]
+/// fn kernel(buffer: &mut Tensor<UInt>, config: Comptime<DebugPrintConfig>) {
+///     print_row_col(buffer, ABSOLUTE_POS / CUBE_DIM_X, ABSOLUTE_POS % CUBE_DIM_X, config);
+/// }
+/// ```
+///
+/// The number of values is checked against the format at compile time. The kernels can't expand
+/// macros in their body, which is why the print is a function declared outside of them.
+#[macro_export]
+macro_rules! debug_print {
+    ($(#[$meta:meta])* $vis:vis fn $name:ident($format:literal, $value:ident $(,)?)) => {
+        $(#[$meta])*
+        #[allow(unused_variables)]
+        $vis fn $name(
+            buffer: &mut $crate::cubecl::prelude::Tensor<$crate::cubecl::prelude::UInt>,
+            $value: $crate::cubecl::prelude::UInt,
+            config: $crate::cubecl::prelude::Comptime<$crate::kernel::debug::DebugPrintConfig>,
+        ) {
+            $crate::cubecl::unexpanded!()
+        }
+
+        $vis mod $name {
+            use $crate::cubecl::prelude::{CubeContext, CubeType, Tensor, UInt};
+
+            const _: () = assert!(
+                $crate::kernel::debug::format_arity($format) == 1,
+                "The format of the print doesn't have a single value",
+            );
+
+            pub fn __expand(
+                context: &mut CubeContext,
+                buffer: <Tensor<UInt> as CubeType>::ExpandType,
+                $value: <UInt as CubeType>::ExpandType,
+                config: $crate::kernel::debug::DebugPrintConfig,
+            ) {
+                $crate::kernel::debug::debug_print_format_single::__expand(
+                    context,
+                    buffer,
+                    UInt::new($crate::kernel::debug::format_id($format)),
+                    $value,
+                    config,
+                )
+            }
+        }
+    };
+    ($(#[$meta:meta])* $vis:vis fn $name:ident(
+        $format:literal, $first:ident, $second:ident $(,)?
+    )) => {
+        $(#[$meta])*
+        #[allow(unused_variables)]
+        $vis fn $name(
+            buffer: &mut $crate::cubecl::prelude::Tensor<$crate::cubecl::prelude::UInt>,
+            $first: $crate::cubecl::prelude::UInt,
+            $second: $crate::cubecl::prelude::UInt,
+            config: $crate::cubecl::prelude::Comptime<$crate::kernel::debug::DebugPrintConfig>,
+        ) {
+            $crate::cubecl::unexpanded!()
+        }
+
+        $vis mod $name {
+            use $crate::cubecl::prelude::{CubeContext, CubeType, Tensor, UInt};
+
+            const _: () = assert!(
+                $crate::kernel::debug::format_arity($format) == 2,
+                "The format of the print doesn't have two values",
+            );
+
+            pub fn __expand(
+                context: &mut CubeContext,
+                buffer: <Tensor<UInt> as CubeType>::ExpandType,
+                $first: <UInt as CubeType>::ExpandType,
+                $second: <UInt as CubeType>::ExpandType,
+                config: $crate::kernel::debug::DebugPrintConfig,
+            ) {
+                $crate::kernel::debug::debug_print_format::__expand(
+                    context,
+                    buffer,
+                    UInt::new($crate::kernel::debug::format_id($format)),
+                    $first,
+                    $second,
+                    config,
+                )
+            }
+        }
+    };
+}
+
+/// A [debug print](debug_print) decoded on the host.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DebugPrint {
+    /// The absolute position of the unit.
+    pub unit: u32,
+    /// The format of the print with its values.
+    pub message: String,
+}
+
+/// The buffer of the [debug prints](debug_print) of a kernel launch, with their formats, each
+/// `{}` of a format being replaced by a value.
+pub struct DebugPrints<R: JitRuntime> {
+    buffer: JitTensor<R, u32, 1>,
+    formats: Vec<&'static str>,
+    max_prints: usize,
+    enabled: bool,
+}
+
+impl<R: JitRuntime> DebugPrints<R> {
+    /// Create the buffer of the prints of `num_units` units, recording up to `max_prints` prints
+    /// for each of them.
+    ///
+    /// The prints are enabled in debug builds, like the [assertions](KernelAssertions::enabled).
+    ///
+    /// # Panics
+    ///
+    /// When two of the formats have the same [id](format_id).
+    pub fn new(
+        client: ComputeClient<R::Server, R::Channel>,
+        device: R::Device,
+        formats: &[&'static str],
+        num_units: usize,
+        max_prints: usize,
+    ) -> Self {
+        for (i, format) in formats.iter().enumerate() {
+            for other in formats[..i].iter().filter(|other| *other != format) {
+                assert_ne!(
+                    format_id(format),
+                    format_id(other),
+                    "The formats {format:?} and {other:?} have the same id"
+                );
+            }
+        }
+
+        let region_len = 1 + max_prints * PRINT_RECORD_LEN as usize;
+
+        Self {
+            buffer: zeros_device(client, device, Shape::new([num_units * region_len])),
+            formats: formats.to_vec(),
+            max_prints,
+            enabled: cfg!(debug_assertions),
+        }
+    }
+
+    /// Whether the prints are compiled in the kernel, like the `debug_print` setting of the
+    /// kernels written in the source language of the runtime.
+    pub fn debug_print(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// The comptime configuration of the prints, disabled ones being compiled out of the kernel.
+    pub fn config(&self) -> DebugPrintConfig {
+        DebugPrintConfig {
+            max_prints: UInt::new(self.max_prints as u32),
+            enabled: self.enabled,
+        }
+    }
+
+    /// The argument of the kernel binding the buffer.
+    pub fn as_tensor_arg(&self) -> TensorArg<'_, R> {
+        self.buffer.as_tensor_arg(1)
+    }
+
+    /// Read the buffer after the launch, returning the recorded prints of each unit in the order
+    /// of the units, and of the prints for each unit.
+    pub fn decode(&self) -> Vec<DebugPrint> {
+        let bytes = self.buffer.client.read(self.buffer.handle.binding());
+        let region_len = 1 + self.max_prints * PRINT_RECORD_LEN as usize;
+
+        u32::from_bytes(&bytes)
+            .chunks(region_len)
+            .enumerate()
+            .flat_map(|(unit, region)| {
+                let count = usize::min(region[0] as usize, self.max_prints);
+
+                region[1..]
+                    .chunks(PRINT_RECORD_LEN as usize)
+                    .take(count)
+                    .map(move |record| DebugPrint {
+                        unit: unit as u32,
+                        message: self.format(record[0], &record[1..]),
+                    })
+            })
+            .collect()
+    }
+
+    /// Print the recorded prints to the standard output, prefixed by their unit.
+    pub fn print(&self) {
+        for print in self.decode() {
+            println!("[unit {}] {}", print.unit, print.message);
+        }
+    }
+
+    fn format(&self, id: u32, values: &[u32]) -> String {
+        let Some(format) = self.formats.iter().find(|format| format_id(format) == id) else {
+            return format!("unknown format {id} with the values {values:?}");
+        };
+
+        let mut parts = format.split("{}");
+        let mut message = parts.next().unwrap_or_default().to_string();
+        for (i, part) in parts.enumerate() {
+            match values.get(i) {
+                Some(value) => message += &value.to_string(),
+                None => message += "{}",
+            }
+            message += part;
+        }
+
+        message
+    }
+}
@@ -0,0 +1,100 @@
+use cubecl::{calculate_cube_count_elemwise, linalg::tensor::index_offset_with_layout, prelude::*};
+
+use crate::{ops::numeric::empty_device, tensor::JitTensor, FloatElement, JitRuntime};
+
+#[cube(launch)]
+fn fake_quantize_kernel<F: Float>(
+    input: &Tensor<F>,
+    scale: &Tensor<F>,
+    zero_point: &Tensor<F>,
+    output: &mut Tensor<F>,
+    qmin: F,
+    qmax: F,
+    rank: Comptime<UInt>,
+) {
+    if ABSOLUTE_POS >= output.len() {
+        return;
+    }
+
+    let index_input = index_offset_with_layout(
+        input,
+        output,
+        ABSOLUTE_POS,
+        UInt::new(0),
+        Comptime::runtime(rank),
+        Comptime::new(true),
+    );
+
+    let index_scale = index_offset_with_layout(
+        scale,
+        output,
+        ABSOLUTE_POS,
+        UInt::new(0),
+        Comptime::runtime(rank),
+        Comptime::new(true),
+    );
+
+    let index_zero_point = index_offset_with_layout(
+        zero_point,
+        output,
+        ABSOLUTE_POS,
+        UInt::new(0),
+        Comptime::runtime(rank),
+        Comptime::new(true),
+    );
+
+    let scale = scale[index_scale];
+    let zero_point = zero_point[index_zero_point];
+    let value = F::clamp(input[index_input] / scale + zero_point, qmin, qmax);
+
+    // Round half to even, like the cast used by the quantization of the other backends.
+    let mut quantized = F::floor(value);
+    let fraction = value - quantized;
+    let half = F::new(0.5);
+    let is_odd = quantized - F::floor(quantized * half) * F::new(2.0) > half;
+
+    if fraction > half || (fraction == half && is_odd) {
+        quantized = quantized + F::new(1.0);
+    }
+
+    output[ABSOLUTE_POS] = (quantized - zero_point) * scale;
+}
+
+/// Quantize and dequantize the input with the given scale and zero-point, which are broadcasted
+/// to the shape of the input.
+pub(crate) fn fake_quantize<R: JitRuntime, E: FloatElement, const D: usize>(
+    input: JitTensor<R, E, D>,
+    scale: JitTensor<R, E, D>,
+    zero_point: JitTensor<R, E, D>,
+    qmin: E,
+    qmax: E,
+) -> JitTensor<R, E, D> {
+    let output = empty_device(
+        input.client.clone(),
+        input.device.clone(),
+        input.shape.clone(),
+    );
+
+    if output.is_empty() {
+        return output;
+    }
+
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(output.shape.num_elements(), cube_dim);
+
+    crate::perf::record_kernel();
+    fake_quantize_kernel::launch::<E::FloatPrimitive, R>(
+        &input.client,
+        cube_count,
+        cube_dim,
+        input.as_tensor_arg(1),
+        scale.as_tensor_arg(1),
+        zero_point.as_tensor_arg(1),
+        output.as_tensor_arg(1),
+        ScalarArg::new(qmin),
+        ScalarArg::new(qmax),
+        UInt::new(D as u32),
+    );
+
+    output
+}
@@ -0,0 +1,124 @@
+//! Bounded stream compaction of the true values of a mask.
+//!
+//! The mask is split in chunks, each counted by a unit. The exclusive prefix sum of the counts
+//! gives the first output row of each chunk, computed on the device with the total count by
+//! [exclusive_scan]. Each unit then writes the coordinates of the true values of its chunk from
+//! that row, stopping at the capacity of the output, while the total count is kept as is.
+use crate::{
+    kernel::{cast, exclusive_scan, into_contiguous},
+    ops::numeric::{empty_device, full_device, zeros_device},
+    tensor::JitTensor,
+    IntElement, JitRuntime,
+};
+use burn_tensor::{ElementConversion, Shape};
+use cubecl::{calculate_cube_count_elemwise, prelude::*};
+
+/// The number of values of the mask handled by each unit.
+const NUM_ELEMS_PER_UNIT: usize = 256;
+
+#[cube(launch)]
+fn argwhere_count_kernel(mask: &Tensor<UInt>, counts: &mut Tensor<UInt>, num_elems: UInt) {
+    if ABSOLUTE_POS >= counts.len() {
+        return;
+    }
+
+    let offset = ABSOLUTE_POS * num_elems;
+    let mut count = UInt::new(0);
+
+    for i in range(0u32, num_elems, Comptime::new(false)) {
+        let index = offset + i;
+
+        if index < mask.len() {
+            if mask[index] != UInt::new(0) {
+                count += UInt::new(1);
+            }
+        }
+    }
+
+    counts[ABSOLUTE_POS] = count;
+}
+
+#[cube(launch)]
+fn argwhere_write_kernel<I: Int>(
+    mask: &Tensor<UInt>,
+    offsets: &Tensor<UInt>,
+    output: &mut Tensor<I>,
+    num_elems: UInt,
+) {
+    if ABSOLUTE_POS >= offsets.len() {
+        return;
+    }
+
+    let rank = mask.rank();
+    let max_count = output.shape(0);
+    let offset = ABSOLUTE_POS * num_elems;
+    let mut row = offsets[ABSOLUTE_POS];
+
+    for i in range(0u32, num_elems, Comptime::new(false)) {
+        let index = offset + i;
+
+        if index < mask.len() && row < max_count {
+            if mask[index] != UInt::new(0) {
+                for d in range(0u32, rank, Comptime::new(false)) {
+                    let coordinate = index / mask.stride(d) % mask.shape(d);
+                    output[row * rank + d] = I::cast_from(coordinate);
+                }
+
+                row += UInt::new(1);
+            }
+        }
+    }
+}
+
+/// Computes the coordinates of the first `max_count` true values of the mask, the unused rows
+/// being filled with `-1`, and the number of true values, without reading the mask back.
+pub(crate) fn argwhere_padded<R: JitRuntime, I: IntElement, const D: usize>(
+    mask: JitTensor<R, u32, D>,
+    max_count: usize,
+) -> (JitTensor<R, I, 2>, JitTensor<R, I, 1>) {
+    let client = mask.client.clone();
+    let device = mask.device.clone();
+    let indices = full_device::<R, I, 2>(
+        client.clone(),
+        Shape::new([max_count, D]),
+        device.clone(),
+        (-1).elem(),
+    );
+
+    if mask.is_empty() {
+        let count = zeros_device::<R, I, 1>(client, device, Shape::new([1]));
+        return (indices, count);
+    }
+
+    let mask = into_contiguous(mask);
+    let num_chunks = mask.shape.num_elements().div_ceil(NUM_ELEMS_PER_UNIT);
+    let offsets =
+        empty_device::<R, u32, 1>(client.clone(), device.clone(), Shape::new([num_chunks]));
+    let cube_dim = CubeDim::default();
+
+    crate::perf::record_kernel();
+    argwhere_count_kernel::launch::<R>(
+        &client,
+        calculate_cube_count_elemwise(num_chunks, cube_dim),
+        cube_dim,
+        mask.as_tensor_arg(1),
+        offsets.as_tensor_arg(1),
+        ScalarArg::new(NUM_ELEMS_PER_UNIT as u32),
+    );
+    let count = cast::<R, u32, I, 1>(exclusive_scan(&offsets));
+
+    if max_count > 0 {
+        crate::perf::record_kernel();
+        argwhere_write_kernel::launch::<I::Primitive, R>(
+            &client,
+            calculate_cube_count_elemwise(num_chunks, cube_dim),
+            cube_dim,
+            mask.as_tensor_arg(1),
+            offsets.as_tensor_arg(1),
+            indices.as_tensor_arg(1),
+            ScalarArg::new(NUM_ELEMS_PER_UNIT as u32),
+        );
+    }
+
+    (indices, count)
+}
@@ -126,6 +126,10 @@ pub(crate) fn flip_on_output<R: JitRuntime, E: JitElement, const D: usize>(
     output: JitTensor<R, E, D>,
     indices: &[usize],
 ) -> JitTensor<R, E, D> {
+    if output.is_empty() {
+        return output;
+    }
+
     let mut scalars: Vec<u32> = Vec::with_capacity(D);
 
     for i in 0..D {
@@ -134,6 +138,7 @@ pub(crate) fn flip_on_output<R: JitRuntime, E: JitElement, const D: usize>(
 
     let kernel = FlipEagerKernel::<R, E>::new(D);
 
+    crate::perf::record_kernel();
     Execution::start(kernel, tensor.client.clone())
         .inputs(&[tensor.as_handle_ref()])
         .outputs(&[output.as_handle_ref()])
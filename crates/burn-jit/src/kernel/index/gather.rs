@@ -17,7 +17,17 @@ fn gather_kernel<T: Numeric, I: Numeric>(
         return;
     }
 
-    let index = indices[ABSOLUTE_POS];
+    // The indices can be broadcast over the dimensions other than `dim`, so they are read with
+    // their own strides.
+    let index_offset = index_offset_with_layout(
+        indices,
+        output,
+        ABSOLUTE_POS,
+        UInt::new(0),
+        indices.rank(),
+        Comptime::new(false),
+    );
+    let index = indices[index_offset];
 
     let stride = input.stride(*dim);
     let mut offset = UInt::cast_from(index);
@@ -56,8 +66,14 @@ pub(crate) fn gather<R: JitRuntime, E: JitElement, I: JitElement, const D: usize
     let total_elem = shape_output.num_elements();
     let output = empty_device(tensor.client.clone(), tensor.device.clone(), shape_output);
 
+    if total_elem == 0 {
+        return output;
+    }
+
     let cube_dim = CubeDim::default();
     let cube_count = calculate_cube_count_elemwise(total_elem, cube_dim);
+    crate::perf::record_kernel();
+
     unsafe {
         gather_kernel::launch_unchecked::<E::Primitive, I::Primitive, R>(
             &tensor.client,
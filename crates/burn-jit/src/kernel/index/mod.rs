@@ -1,5 +1,8 @@
+mod argwhere;
 mod flip;
 mod gather;
+mod index_put;
+mod pad;
 mod repeat_dim;
 mod scatter;
 mod select;
@@ -14,5 +17,8 @@ pub(crate) use select_assign::*;
 pub use slice::*;
 pub use slice_assign::*;
 
+pub(crate) use argwhere::*;
 pub(crate) use gather::*;
+pub(crate) use index_put::*;
+pub(crate) use pad::*;
 pub(crate) use scatter::*;
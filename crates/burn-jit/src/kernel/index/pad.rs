@@ -0,0 +1,229 @@
+use crate::{
+    element::JitElement, kernel::Kernel, ops::numeric::empty_device, tensor::JitTensor, JitRuntime,
+};
+use burn_tensor::{PadDim, PadMode, Shape};
+use cubecl::{
+    cpa,
+    ir::{Elem, KernelDefinition, Scope, Variable, Visibility},
+    CubeCountSettings, Execution, InputInfo, KernelExpansion, KernelIntegrator, KernelSettings,
+    OutputInfo,
+};
+use std::marker::PhantomData;
+
+/// How the padded elements are filled, without the constant value which is given as a scalar.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+enum PadStrategy {
+    Constant,
+    Reflect,
+    Edge,
+}
+
+#[derive(new)]
+struct PadEagerKernel<R: JitRuntime, E: JitElement> {
+    rank: usize,
+    strategy: PadStrategy,
+    _runtime: PhantomData<R>,
+    _elem: PhantomData<E>,
+}
+
+pub struct PadComputeShader {
+    input: Variable,
+    output: Variable,
+    value: Variable,
+    rank: usize,
+    strategy: PadStrategy,
+}
+
+impl PadComputeShader {
+    pub fn expand(self, scope: &mut Scope) {
+        let input = self.input;
+        let output = self.output;
+        let value = self.value;
+        let id = Variable::AbsolutePos;
+
+        let offset_input = scope.zero(Elem::UInt);
+        let offset_local = scope.create_local(Elem::UInt);
+        let num_padded = scope.zero(Elem::UInt);
+
+        let stride_input = scope.create_local(Elem::UInt);
+        let stride_output = scope.create_local(Elem::UInt);
+        let shape_output = scope.create_local(Elem::UInt);
+        let last = scope.create_local(Elem::UInt);
+        let is_before = scope.create_local(Elem::Bool);
+        let is_after = scope.create_local(Elem::Bool);
+
+        for i in 0..self.rank {
+            let scalar = |index: usize| Variable::GlobalScalar {
+                id: (3 * i + index) as u16,
+                elem: Elem::UInt,
+            };
+            let pad_before = scalar(0);
+            let crop_before = scalar(1);
+            let length = scalar(2);
+
+            cpa!(scope, stride_input = stride(input, i));
+            cpa!(scope, stride_output = stride(output, i));
+            cpa!(scope, shape_output = shape(output, i));
+
+            cpa!(scope, offset_local = id / stride_output);
+            cpa!(scope, offset_local = offset_local % shape_output);
+
+            // Map the output coordinate to the cropped input, reflecting or clamping the
+            // coordinates of the padded elements.
+            cpa!(scope, is_before = offset_local < pad_before);
+            cpa!(scope, if(is_before).then(|scope| {
+                match self.strategy {
+                    PadStrategy::Constant => {
+                        cpa!(scope, num_padded = num_padded + 1u32);
+                        cpa!(scope, offset_local = pad_before);
+                    }
+                    PadStrategy::Reflect => {
+                        cpa!(scope, offset_local = pad_before - offset_local);
+                        cpa!(scope, offset_local = offset_local + pad_before);
+                    }
+                    PadStrategy::Edge => {
+                        cpa!(scope, offset_local = pad_before);
+                    }
+                }
+            }));
+            cpa!(scope, offset_local = offset_local - pad_before);
+
+            cpa!(scope, is_after = offset_local >= length);
+            cpa!(scope, if(is_after).then(|scope| {
+                cpa!(scope, last = length - 1u32);
+                match self.strategy {
+                    PadStrategy::Constant => {
+                        cpa!(scope, num_padded = num_padded + 1u32);
+                        cpa!(scope, offset_local = last);
+                    }
+                    PadStrategy::Reflect => {
+                        cpa!(scope, last = last * 2u32);
+                        cpa!(scope, offset_local = last - offset_local);
+                    }
+                    PadStrategy::Edge => {
+                        cpa!(scope, offset_local = last);
+                    }
+                }
+            }));
+
+            cpa!(scope, offset_local = offset_local + crop_before);
+            cpa!(scope, offset_local = offset_local * stride_input);
+            cpa!(scope, offset_input += offset_local);
+        }
+
+        let result = scope.create_local(input.item());
+
+        match self.strategy {
+            PadStrategy::Constant => {
+                let is_padded = scope.create_local(Elem::Bool);
+                cpa!(scope, is_padded = num_padded > 0u32);
+                cpa!(scope, if(is_padded).then(|scope| {
+                    cpa!(scope, result = value);
+                }).else(|scope| {
+                    cpa!(scope, result = input[offset_input]);
+                }));
+            }
+            PadStrategy::Reflect | PadStrategy::Edge => {
+                cpa!(scope, result = input[offset_input]);
+            }
+        }
+
+        cpa!(scope, output[id] = result);
+    }
+}
+
+impl<R: JitRuntime, E: JitElement> Kernel for PadEagerKernel<R, E> {
+    fn define(&self) -> KernelDefinition {
+        let mut scope = Scope::root();
+        let item = E::cube_elem().into();
+
+        let input = Variable::GlobalInputArray { id: 0, item };
+        let output = Variable::GlobalOutputArray { id: 0, item };
+        let value = Variable::GlobalScalar {
+            id: 0,
+            elem: item.elem(),
+        };
+
+        scope.write_global_custom(output);
+
+        PadComputeShader {
+            input,
+            output,
+            value,
+            rank: self.rank,
+            strategy: self.strategy,
+        }
+        .expand(&mut scope);
+
+        let input = InputInfo::Array {
+            item,
+            visibility: Visibility::Read,
+        };
+        let value = InputInfo::Scalar {
+            elem: E::cube_elem(),
+            size: 1,
+        };
+        let dims = InputInfo::Scalar {
+            elem: Elem::UInt,
+            size: 3 * self.rank,
+        };
+        let output = OutputInfo::Array { item };
+
+        let info = KernelExpansion {
+            inputs: vec![input, value, dims],
+            outputs: vec![output],
+            scope,
+        };
+
+        let settings = KernelSettings::default();
+        KernelIntegrator::new(info).integrate(settings)
+    }
+
+    fn id(&self) -> cubecl::KernelId {
+        cubecl::KernelId::new::<Self>().info((self.rank, self.strategy))
+    }
+}
+
+/// Pad the tensor with a single kernel, each output element either reads its source element or
+/// is filled with the constant value.
+pub(crate) fn pad<R: JitRuntime, E: JitElement, const D: usize>(
+    tensor: JitTensor<R, E, D>,
+    padding: [(isize, isize); D],
+    mode: PadMode<E>,
+) -> JitTensor<R, E, D> {
+    let dims = padding.map(PadDim::new);
+    let shape_output = Shape::new(core::array::from_fn(|i| {
+        dims[i].padded_size(tensor.shape.dims[i])
+    }));
+    let output = empty_device(tensor.client.clone(), tensor.device.clone(), shape_output);
+
+    if output.is_empty() {
+        return output;
+    }
+
+    let (strategy, value) = match mode {
+        PadMode::Constant(value) => (PadStrategy::Constant, value),
+        PadMode::Reflect => (PadStrategy::Reflect, E::default()),
+        PadMode::Edge => (PadStrategy::Edge, E::default()),
+    };
+
+    let mut scalars: Vec<u32> = Vec::with_capacity(3 * D);
+    for (dim, size) in dims.iter().zip(tensor.shape.dims.iter()) {
+        let cropped = dim.cropped_range(*size);
+        scalars.push(dim.pad_before as u32);
+        scalars.push(cropped.start as u32);
+        scalars.push(cropped.len() as u32);
+    }
+
+    let kernel = PadEagerKernel::<R, E>::new(D, strategy);
+
+    crate::perf::record_kernel();
+    Execution::start(kernel, tensor.client.clone())
+        .inputs(&[tensor.as_handle_ref()])
+        .outputs(&[output.as_handle_ref()])
+        .with_scalars(&scalars)
+        .with_scalars(&[value])
+        .execute(CubeCountSettings::Output { pos: 0 });
+
+    output
+}
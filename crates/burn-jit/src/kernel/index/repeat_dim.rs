@@ -104,9 +104,9 @@ pub(crate) fn repeat_dim<R: JitRuntime, E: JitElement, const D1: usize>(
     // Create output handle
     shape.dims[dim] *= times;
     let num_elems_output = shape.num_elements();
-    let handle = input
-        .client
-        .empty(num_elems_output * core::mem::size_of::<E>());
+    let num_bytes = num_elems_output * core::mem::size_of::<E>();
+    crate::perf::record_allocation(num_bytes);
+    let handle = input.client.empty(num_bytes);
     let output = JitTensor::new_contiguous(
         input.client.clone(),
         input.device.clone(),
@@ -114,8 +114,13 @@ pub(crate) fn repeat_dim<R: JitRuntime, E: JitElement, const D1: usize>(
         handle,
     );
 
+    if output.is_empty() {
+        return output;
+    }
+
     let kernel = RepeatEagerKernel::<R, E>::new(dim, D1);
 
+    crate::perf::record_kernel();
     Execution::start(kernel, input.client.clone())
         .inputs(&[input.as_handle_ref()])
         .outputs(&[output.as_handle_ref()])
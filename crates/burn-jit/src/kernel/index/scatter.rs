@@ -71,6 +71,10 @@ pub(crate) fn scatter<R: JitRuntime, E: JitElement, I: JitElement, const D: usiz
     indices: JitTensor<R, I, D>,
     value: JitTensor<R, E, D>,
 ) -> JitTensor<R, E, D> {
+    if tensor.is_empty() || indices.is_empty() {
+        return tensor;
+    }
+
     let mut indices = kernel::into_contiguous(indices);
     let tensor = kernel::into_contiguous(tensor);
     let value = kernel::into_contiguous(value);
@@ -103,6 +107,8 @@ pub(crate) fn scatter<R: JitRuntime, E: JitElement, I: JitElement, const D: usiz
     let cube_dim = CubeDim::default();
     let cube_count = calculate_cube_count_elemwise(num_elems, cube_dim);
 
+    crate::perf::record_kernel();
+
     unsafe {
         scatter_kernel::launch_unchecked::<E::Primitive, R>(
             &indices.client.clone(),
@@ -41,10 +41,16 @@ pub(crate) fn select<R: JitRuntime, E: JitElement, I: JitElement, const D: usize
 
     let output = empty_device(tensor.client.clone(), tensor.device.clone(), shape_output);
 
+    if total_elem == 0 {
+        return output;
+    }
+
     let dummy_array = [1; D];
     let cube_dim = CubeDim::default();
     let cube_count = calculate_cube_count_elemwise(total_elem, cube_dim);
 
+    crate::perf::record_kernel();
+
     unsafe {
         select_kernel::launch_unchecked::<E::Primitive, I::Primitive, R>(
             &tensor.client,
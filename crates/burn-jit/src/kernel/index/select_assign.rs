@@ -50,6 +50,10 @@ pub(crate) fn select_assign<R: JitRuntime, E: JitElement, I: JitElement, const D
     indices: JitTensor<R, I, 1>,
     value: JitTensor<R, E, D>,
 ) -> JitTensor<R, E, D> {
+    if tensor.is_empty() || value.is_empty() {
+        return tensor;
+    }
+
     let tensor = match tensor.can_mut() {
         true => tensor,
         false => tensor.copy(),
@@ -74,6 +78,8 @@ pub(crate) fn select_assign<R: JitRuntime, E: JitElement, I: JitElement, const D
     let cube_dim = CubeDim::default();
     let cube_count = calculate_cube_count_elemwise(num_elems, cube_dim);
 
+    crate::perf::record_kernel();
+
     unsafe {
         select_assign_kernel::launch_unchecked::<E::Primitive, I::Primitive, R>(
             &tensor.client,
@@ -123,6 +123,10 @@ pub(crate) fn slice_on_output<R: JitRuntime, E: JitElement, const D1: usize, con
     output: JitTensor<R, E, D1>,
     indices: [Range<usize>; D2],
 ) -> JitTensor<R, E, D1> {
+    if output.is_empty() {
+        return output;
+    }
+
     let mut scalars: Vec<i32> = Vec::with_capacity(D1);
 
     for i in 0..D1 {
@@ -132,6 +136,7 @@ pub(crate) fn slice_on_output<R: JitRuntime, E: JitElement, const D1: usize, con
 
     let kernel = SliceEagerKernel::<R, E>::new(D1);
 
+    crate::perf::record_kernel();
     Execution::start(kernel, tensor.client.clone())
         .inputs(&[tensor.as_handle_ref()])
         .outputs(&[output.as_handle_ref()])
@@ -122,6 +122,10 @@ pub(crate) fn slice_assign<R: JitRuntime, E: JitElement, const D1: usize, const
     indices: [Range<usize>; D2],
     value: JitTensor<R, E, D1>,
 ) -> JitTensor<R, E, D1> {
+    if value.is_empty() {
+        return tensor;
+    }
+
     let tensor = match tensor.can_mut() {
         true => tensor,
         false => tensor.copy(),
@@ -135,6 +139,7 @@ pub(crate) fn slice_assign<R: JitRuntime, E: JitElement, const D1: usize, const
 
     let kernel = SliceAssignEagerKernel::<R, E>::new(D1);
 
+    crate::perf::record_kernel();
     Execution::start(kernel, value.client.clone())
         .inputs(&[tensor.as_handle_ref(), value.as_handle_ref()])
         .with_scalars(&scalars)
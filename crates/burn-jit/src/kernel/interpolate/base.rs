@@ -27,6 +27,10 @@ pub fn interpolate<R: JitRuntime, E: JitElement + Element>(
     let shape_out = Shape::new([batch_size, channels, out_height, out_width]);
     let output = empty_device(input.client.clone(), input.device.clone(), shape_out);
 
+    if output.is_empty() {
+        return output;
+    }
+
     match options.mode {
         InterpolateMode::Nearest => interpolate_nearest_launch(input, output),
         InterpolateMode::Bilinear => interpolate_bilinear_launch(input, output),
@@ -46,7 +50,9 @@ pub fn interpolate_backward<R: JitRuntime, E: JitElement + Element>(
     let out_grad = into_contiguous(out_grad);
     let output_shape = input.shape.clone();
     let num_elems = input.shape.num_elements();
-    let buffer = input.client.empty(num_elems * core::mem::size_of::<E>());
+    let num_bytes = num_elems * core::mem::size_of::<E>();
+    crate::perf::record_allocation(num_bytes);
+    let buffer = input.client.empty(num_bytes);
     let output = JitTensor::new_contiguous(
         input.client.clone(),
         input.device.clone(),
@@ -54,6 +60,10 @@ pub fn interpolate_backward<R: JitRuntime, E: JitElement + Element>(
         buffer,
     );
 
+    if output.is_empty() {
+        return output;
+    }
+
     match options.mode {
         InterpolateMode::Nearest => interpolate_nearest_backward_launch(out_grad, output),
         InterpolateMode::Bilinear => {
@@ -412,6 +412,7 @@ pub(crate) fn interpolate_bicubic_launch<R: JitRuntime, E: JitElement>(
 ) -> JitTensor<R, E, 4> {
     let kernel = InterpolateBicubicEagerKernel::<R, E>::new();
 
+    crate::perf::record_kernel();
     Execution::start(kernel, input.client.clone())
         .inputs(&[input.as_handle_ref()])
         .outputs(&[output.as_handle_ref()])
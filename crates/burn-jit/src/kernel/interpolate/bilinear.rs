@@ -225,6 +225,7 @@ pub(crate) fn interpolate_bilinear_launch<R: JitRuntime, E: JitElement>(
 ) -> JitTensor<R, E, 4> {
     let kernel = InterpolateBilinearEagerKernel::<R, E>::new();
 
+    crate::perf::record_kernel();
     Execution::start(kernel, input.client.clone())
         .inputs(&[input.as_handle_ref()])
         .outputs(&[output.as_handle_ref()])
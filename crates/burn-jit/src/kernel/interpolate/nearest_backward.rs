@@ -224,6 +224,7 @@ pub(crate) fn interpolate_nearest_backward_launch<R: JitRuntime, E: JitElement>(
 ) -> JitTensor<R, E, 4> {
     let kernel = InterpolateNearestBackwardEagerKernel::<R, E>::new();
 
+    crate::perf::record_kernel();
     Execution::start(kernel, out_grad.client.clone())
         .inputs(&[out_grad.as_handle_ref()])
         .outputs(&[output.as_handle_ref()])
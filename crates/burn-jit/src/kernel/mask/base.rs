@@ -22,9 +22,11 @@ pub(crate) fn mask_where_auto<R: JitRuntime, E: JitElement, const D: usize>(
     mask: JitTensor<R, u32, D>,
     value: JitTensor<R, E, D>,
 ) -> JitTensor<R, E, D> {
-    let strategy = if tensor.can_mut_broadcast(&value) {
+    let shape = super::mask_where_shape(&tensor, &mask, &value);
+
+    let strategy = if tensor.can_mut() && tensor.shape == shape {
         MaskWhereStrategy::InplaceLhs
-    } else if value.can_mut_broadcast(&tensor) {
+    } else if value.can_mut() && value.shape == shape {
         MaskWhereStrategy::InplaceRhs
     } else {
         MaskWhereStrategy::Readonly
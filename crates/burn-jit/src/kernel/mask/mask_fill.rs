@@ -84,6 +84,10 @@ pub fn mask_fill<R: JitRuntime, E: JitElement, const D: usize>(
     value: E,
     strategy: MaskFillStrategy,
 ) -> JitTensor<R, E, D> {
+    if input.is_empty() {
+        return input;
+    }
+
     match strategy {
         MaskFillStrategy::Readonly => mask_fill_readonly(input, mask, value),
         MaskFillStrategy::Inplace => mask_fill_inplace(input, mask, value),
@@ -104,6 +108,7 @@ fn mask_fill_readonly<R: JitRuntime, EI: JitElement, EM: JitElement, const D: us
     let cube_dim = CubeDim::default();
     let cube_count = calculate_cube_count_elemwise(input.shape.num_elements(), cube_dim);
 
+    crate::perf::record_kernel();
     mask_fill_readonly_kernel::launch::<EI::Primitive, R>(
         &input.client,
         cube_count,
@@ -126,6 +131,7 @@ fn mask_fill_inplace<R: JitRuntime, EI: JitElement, EM: JitElement, const D: usi
     let cube_dim = CubeDim::default();
     let cube_count = calculate_cube_count_elemwise(input.shape.num_elements(), cube_dim);
 
+    crate::perf::record_kernel();
     mask_fill_inplace_kernel::launch::<EI::Primitive, R>(
         &input.client,
         cube_count,
@@ -1,6 +1,12 @@
+use burn_tensor::Shape;
 use cubecl::{calculate_cube_count_elemwise, linalg::tensor::index_offset_with_layout, prelude::*};
 
-use crate::{element::JitElement, ops::numeric::empty_device, tensor::JitTensor, JitRuntime};
+use crate::{
+    element::JitElement,
+    ops::{broadcast_shape, numeric::empty_device},
+    tensor::JitTensor,
+    JitRuntime,
+};
 
 #[cube(launch)]
 fn mask_where_readonly_kernel<T: CubePrimitive>(
@@ -88,7 +94,8 @@ fn mask_where_inplace_kernel<T: CubePrimitive>(
 ///
 /// # Notes
 ///
-/// All assertions should be done before choosing the strategy.
+/// All assertions should be done before choosing the strategy. A tensor can only be reused
+/// inplace when it already has the broadcasted [output shape](mask_where_shape).
 pub enum MaskWhereStrategy {
     /// Don't mutate any input.
     Readonly,
@@ -98,6 +105,17 @@ pub enum MaskWhereStrategy {
     InplaceRhs,
 }
 
+/// The output shape of the mask where kernel, broadcasting the input, the mask and the value.
+pub fn mask_where_shape<R: JitRuntime, E: JitElement, const D: usize>(
+    input: &JitTensor<R, E, D>,
+    mask: &JitTensor<R, u32, D>,
+    value: &JitTensor<R, E, D>,
+) -> Shape<D> {
+    let shape_out = broadcast_shape(&input.shape, &mask.shape);
+
+    broadcast_shape(&shape_out, &value.shape)
+}
+
 /// Execute the mask where kernel with the given strategy.
 pub fn mask_where<R: JitRuntime, E: JitElement, const D: usize>(
     input: JitTensor<R, E, D>,
@@ -105,8 +123,14 @@ pub fn mask_where<R: JitRuntime, E: JitElement, const D: usize>(
     value: JitTensor<R, E, D>,
     strategy: MaskWhereStrategy,
 ) -> JitTensor<R, E, D> {
+    let shape = mask_where_shape(&input, &mask, &value);
+
+    if shape.num_elements() == 0 {
+        return empty_device(input.client.clone(), input.device.clone(), shape);
+    }
+
     match strategy {
-        MaskWhereStrategy::Readonly => mask_where_readonly(input, mask, value),
+        MaskWhereStrategy::Readonly => mask_where_readonly(input, mask, value, shape),
         MaskWhereStrategy::InplaceLhs => mask_where_inplace(input, mask, value, false),
         MaskWhereStrategy::InplaceRhs => mask_where_inplace(value, mask, input, true),
     }
@@ -116,16 +140,14 @@ fn mask_where_readonly<R: JitRuntime, EI: JitElement, EM: JitElement, const D: u
     input: JitTensor<R, EI, D>,
     mask: JitTensor<R, EM, D>,
     value: JitTensor<R, EI, D>,
+    shape: Shape<D>,
 ) -> JitTensor<R, EI, D> {
-    let output = empty_device(
-        input.client.clone(),
-        input.device.clone(),
-        input.shape.clone(),
-    );
+    let output = empty_device(input.client.clone(), input.device.clone(), shape);
 
     let cube_dim = CubeDim::default();
-    let cube_count = calculate_cube_count_elemwise(input.shape.num_elements(), cube_dim);
+    let cube_count = calculate_cube_count_elemwise(output.shape.num_elements(), cube_dim);
 
+    crate::perf::record_kernel();
     mask_where_readonly_kernel::launch::<EI::Primitive, R>(
         &input.client,
         cube_count,
@@ -149,6 +171,7 @@ fn mask_where_inplace<R: JitRuntime, EI: JitElement, EM: JitElement, const D: us
     let cube_dim = CubeDim::default();
     let cube_count = calculate_cube_count_elemwise(input.shape.num_elements(), cube_dim);
 
+    crate::perf::record_kernel();
     mask_where_inplace_kernel::launch::<EI::Primitive, R>(
         &input.client,
         cube_count,
@@ -1,10 +1,17 @@
-use super::{init_matmul_output, matmul_simple};
-use crate::{tensor::JitTensor, FloatElement, JitRuntime};
-use burn_tensor::Shape;
+use super::{
+    broadcast_batches, init_matmul_output, is_cmma_available, matmul_cmma, matmul_simple,
+    matmul_tiling_2d, shape_out, AccumulatorPrecision, Tiling2dConfig,
+};
+use crate::{
+    kernel::SUBCUBE_DIM_APPROX,
+    ops::numeric::zeros_device,
+    tensor::{assert_u32_span, JitTensor},
+    FloatElement, JitRuntime,
+};
 use cubecl::prelude::*;
 
 #[cfg(feature = "autotune")]
-use super::matmul_autotune;
+use super::{matmul_autotune, matmul_tiling_2d_autotune};
 
 /// The strategy to be used when launching a matmul kernel.
 pub enum MatmulStrategy {
@@ -18,8 +25,18 @@ pub enum MatmulStrategy {
     #[cfg(feature = "autotune")]
     /// Using autotune to chose the best kernel based on runtime information.
     Autotune,
-    /// Cube implementation of matmul.
+    /// Cube implementation of matmul, using the [cooperative matrices](matmul_cmma) when they
+    /// are available.
     Cube,
+    /// A kernel multiplying the tiles with the cooperative matrices of the tensor cores, falling
+    /// back to the tiling 2D kernel when they aren't [available](is_cmma_available).
+    Cmma,
+    /// A kernel loading the blocks of the inputs in shared memory tiles.
+    Tiling2d(Tiling2dConfig),
+    #[cfg(feature = "autotune")]
+    /// The tiling 2D kernel with the fastest of its [candidate configs](super::tiling_2d_candidates),
+    /// tuned for each class of shapes on the device.
+    Tiling2dAutotune,
 }
 
 impl Default for MatmulStrategy {
@@ -39,6 +56,21 @@ pub fn matmul<R: JitRuntime, E: FloatElement, const D: usize>(
     rhs: JitTensor<R, E, D>,
     strategy: MatmulStrategy,
 ) -> JitTensor<R, E, D> {
+    let shape_out = shape_out(&lhs, &rhs);
+
+    if shape_out.num_elements() == 0 {
+        return init_matmul_output(&lhs, &rhs);
+    }
+
+    // Without any value to accumulate, each output is an empty sum.
+    if lhs.shape.dims[D - 1] == 0 {
+        return zeros_device(lhs.client.clone(), lhs.device.clone(), shape_out);
+    }
+
+    lhs.assert_u32_indexable("matmul");
+    rhs.assert_u32_indexable("matmul");
+    assert_u32_span("matmul", shape_out.num_elements(), &shape_out.dims);
+
     match strategy {
         MatmulStrategy::Simple { grid_x, grid_y } => {
             let out = init_matmul_output(&lhs, &rhs);
@@ -46,36 +78,52 @@ pub fn matmul<R: JitRuntime, E: FloatElement, const D: usize>(
         }
         MatmulStrategy::Cube => {
             let out = init_matmul_output::<R, E, D>(&lhs, &rhs);
-            let client = &lhs.client;
-            cubecl::linalg::matmul::launch_ref::<R, E::Primitive>(
-                client,
-                lhs.as_handle_ref(),
-                rhs.as_handle_ref(),
-                out.as_handle_ref(),
-            );
-            out
+            match is_cmma_available::<R, E>(&lhs.client, &lhs.device) {
+                true => matmul_cmma(lhs, rhs, out),
+                false => matmul_cube(lhs, rhs, out),
+            }
+        }
+        MatmulStrategy::Cmma => {
+            let out = init_matmul_output(&lhs, &rhs);
+            matmul_cmma(lhs, rhs, out)
+        }
+        MatmulStrategy::Tiling2d(config) => {
+            let out = init_matmul_output(&lhs, &rhs);
+            matmul_tiling_2d(lhs, rhs, out, config)
         }
         #[cfg(feature = "autotune")]
         MatmulStrategy::Autotune => matmul_autotune(lhs, rhs),
+        #[cfg(feature = "autotune")]
+        MatmulStrategy::Tiling2dAutotune => matmul_tiling_2d_autotune(lhs, rhs),
     }
 }
 
-pub(crate) fn simple_cube_count<R: JitRuntime, const D: usize>(
-    lhs_shape: &Shape<D>,
-    rhs_shape: &Shape<D>,
-    output_shape: &Shape<D>,
-    cube_dim_x: usize,
-    cube_dim_y: usize,
-) -> CubeCount<R::Server> {
-    let num_rows = lhs_shape.dims[D - 2];
-    let num_cols = rhs_shape.dims[D - 1];
-
-    let cubes_x = f32::ceil(num_rows as f32 / cube_dim_x as f32) as u32;
-    let cubes_y = f32::ceil(num_cols as f32 / cube_dim_y as f32) as u32;
-    let mut num_iter = 1;
-    for i in 0..D - 2 {
-        num_iter *= output_shape.dims[i];
+/// Matrix multiplication using the cube kernel, supporting broadcasted batch dimensions.
+///
+/// The cube kernel writes contiguous outputs, so the other layouts, like a transposed view, are
+/// written by the [simple kernel](matmul_simple) instead, whose writes follow the output strides.
+/// The cube kernel also accumulates in the element type, so the half precision floats are
+/// multiplied by the simple kernel, which [accumulates](AccumulatorPrecision) them in `f32`.
+pub fn matmul_cube<R: JitRuntime, E: FloatElement, const D: usize>(
+    lhs: JitTensor<R, E, D>,
+    rhs: JitTensor<R, E, D>,
+    out: JitTensor<R, E, D>,
+) -> JitTensor<R, E, D> {
+    if !out.is_contiguous() || AccumulatorPrecision::of::<E>() != AccumulatorPrecision::Input {
+        return matmul_simple(lhs, rhs, out, SUBCUBE_DIM_APPROX, SUBCUBE_DIM_APPROX);
     }
 
-    CubeCount::Static(cubes_x, cubes_y, num_iter as u32)
+    // The kernel derives the batch offsets of the inputs from the ones of the output, which is
+    // only valid when all the tensors have the same batch dimensions.
+    let (lhs, rhs) = broadcast_batches(lhs, rhs);
+
+    crate::perf::record_kernel();
+    cubecl::linalg::matmul::launch_ref::<R, E::Primitive>(
+        &lhs.client,
+        lhs.as_handle_ref(),
+        rhs.as_handle_ref(),
+        out.as_handle_ref(),
+    );
+
+    out
 }
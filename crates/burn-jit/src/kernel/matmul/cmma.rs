@@ -0,0 +1,276 @@
+//! Cooperative matrix (CMMA) matmul kernel implementation
+//!
+//! Each cube computes a block of `32 x 32` elements of the output with four planes, each plane
+//! computing a `16 x 16` tile of it with the tensor cores. The inner dimension is split in steps
+//! of 16, the blocks of the inputs of each step being loaded in shared memory by all the units of
+//! the cube as `16 x 16` tiles in half precision, before being loaded in the cooperative matrices
+//! of the planes. The out of bound elements of the tiles are zeros, so the shapes don't have to be
+//! multiples of the tiles.
+//!
+//! The products are accumulated in `f32`, then stored in shared memory to be written to the output
+//! following its strides. Since the inputs are multiplied in `f16`, the kernel is only launched on
+//! `f16` tensors. The other element types are multiplied by the [cube](super::matmul_cube) kernel
+//! with the [cube strategy](super::MatmulStrategy::Cube), and by the
+//! [tiling 2D](super::matmul_tiling_2d) kernel when [matmul_cmma] is called directly.
+//!
+//! The cube has `32 x 4` units, the planes being made of the consecutive units of a cube, so each
+//! row of units is a plane and `UNIT_POS_Y` is its index only when the planes of the device have
+//! exactly 32 units. The kernel therefore needs the [subgroup size](DeviceProperties::subgroup_size)
+//! of the device to be 32, and the runtime to support the `16 x 16 x 16` cooperative matrices of
+//! `f16` inputs with `f32` accumulators, which is queried on the client. The path can also be
+//! disabled with [set_cmma_enabled], or by setting the `BURN_CMMA` environment variable to `0`,
+//! `false` or `off` before the first matmul, for instance to compare the results of both paths.
+use super::{matmul_tiling_2d, Tiling2dConfig};
+use crate::{tensor::JitTensor, FloatElement, JitRuntime};
+use burn_tensor::{backend::DeviceProperties, DType};
+use cubecl::{
+    client::ComputeClient,
+    frontend::{F16, F32},
+    ir::{Elem, FloatKind},
+    prelude::*,
+    Feature,
+};
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    OnceLock,
+};
+
+/// The environment variable disabling the cooperative matrix path when set to `0`, `false` or
+/// `off`.
+pub const CMMA_ENV: &str = "BURN_CMMA";
+
+/// The number of rows, columns and inner elements of the tiles multiplied by the planes.
+const TILE_SIZE: u32 = 16;
+/// The number of units of a plane, all of them taking part in each cooperative matrix operation,
+/// which must be the [subgroup size](DeviceProperties::subgroup_size) of the device.
+const PLANE_SIZE: u32 = 32;
+/// The number of planes along each dimension of the block of a cube.
+const PLANES_PER_DIM: u32 = 2;
+
+static ENABLED: AtomicBool = AtomicBool::new(true);
+/// Whether the path is disabled by the [environment variable](CMMA_ENV), read once.
+static DISABLED_BY_ENV: OnceLock<bool> = OnceLock::new();
+
+/// Enable or disable the cooperative matrix path, the matmuls falling back to the
+/// [tiling 2D](super::matmul_tiling_2d) kernel when disabled.
+pub fn set_cmma_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// Whether the matmuls of tensors of the element type `E` run on the cooperative matrix path with
+/// the client of the device: the path is enabled, the element type is `f16`, the subgroups of the
+/// device have 32 units and the runtime supports the `16 x 16 x 16` cooperative matrices of `f16`
+/// inputs with `f32` accumulators.
+pub fn is_cmma_available<R: JitRuntime, E: FloatElement>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    device: &R::Device,
+) -> bool {
+    let disabled = *DISABLED_BY_ENV.get_or_init(|| {
+        std::env::var(CMMA_ENV)
+            .map(|value| matches!(value.to_lowercase().as_str(), "0" | "false" | "off"))
+            .unwrap_or(false)
+    });
+
+    if disabled || !ENABLED.load(Ordering::Relaxed) || E::dtype() != DType::F16 {
+        return false;
+    }
+
+    if crate::device_properties::<R>(device).subgroup_size != Some(PLANE_SIZE) {
+        return false;
+    }
+
+    client.features().enabled(Feature::Cmma {
+        a: Elem::Float(FloatKind::F16),
+        b: Elem::Float(FloatKind::F16),
+        c: Elem::Float(FloatKind::F32),
+        m: TILE_SIZE as u8,
+        k: TILE_SIZE as u8,
+        n: TILE_SIZE as u8,
+    })
+}
+
+#[cube(launch_unchecked)]
+fn matmul_cmma_kernel<F: Float>(
+    lhs: &Tensor<F>,
+    rhs: &Tensor<F>,
+    out: &mut Tensor<F>,
+    // number of dimensions not involved in the matmul
+    num_batches: Comptime<UInt>,
+) {
+    let rank = out.rank();
+    let tile = UInt::new(TILE_SIZE);
+    let tile_area = UInt::new(TILE_SIZE * TILE_SIZE);
+    let block = UInt::new(TILE_SIZE * PLANES_PER_DIM);
+
+    // Two tiles of each input in half precision, and the four output tiles of the planes.
+    let mut lhs_tiles = SharedMemory::<F16>::new(512);
+    let mut rhs_tiles = SharedMemory::<F16>::new(512);
+    let mut out_tiles = SharedMemory::<F32>::new(1024);
+
+    let mut offset_lhs = UInt::new(0);
+    let mut offset_rhs = UInt::new(0);
+    let mut offset_out = UInt::new(0);
+    let mut batch = CUBE_POS_Z;
+
+    for i in range(0u32, Comptime::get(num_batches), Comptime::new(true)) {
+        let dim = Comptime::runtime(num_batches) - i - UInt::new(1);
+        let coordinate = batch % out.shape(dim);
+        batch /= out.shape(dim);
+
+        offset_lhs += coordinate % lhs.shape(dim) * lhs.stride(dim);
+        offset_rhs += coordinate % rhs.shape(dim) * rhs.stride(dim);
+        offset_out += coordinate * out.stride(dim);
+    }
+
+    let n_rows = lhs.shape(rank - UInt::new(2));
+    let n_cols = rhs.shape(rank - UInt::new(1));
+    let k = lhs.shape(rank - UInt::new(1));
+
+    // Each plane computes the output tile at its position in the block.
+    let tile_row = UNIT_POS_Y / UInt::new(PLANES_PER_DIM);
+    let tile_col = UNIT_POS_Y % UInt::new(PLANES_PER_DIM);
+
+    let matrix_lhs = cmma::Matrix::<F16>::new(
+        cmma::MatrixIdent::A,
+        16,
+        16,
+        16,
+        cmma::MatrixLayout::RowMajor,
+    );
+    let matrix_rhs = cmma::Matrix::<F16>::new(
+        cmma::MatrixIdent::B,
+        16,
+        16,
+        16,
+        cmma::MatrixLayout::RowMajor,
+    );
+    let matrix_out = cmma::Matrix::<F32>::new(
+        cmma::MatrixIdent::Accumulator,
+        16,
+        16,
+        16,
+        cmma::MatrixLayout::Undefined,
+    );
+    cmma::fill::<F32>(&matrix_out, F32::new(0.0));
+
+    // The units of the planes past the edges of the output don't return early, since every unit
+    // of the cube loads the tiles and takes part in the synchronizations.
+    let num_steps = (k + tile - UInt::new(1)) / tile;
+
+    for step in range(0u32, num_steps, Comptime::new(false)) {
+        let k_start = step * tile;
+
+        // The 128 units load the 512 elements of the two tiles of each input.
+        for i in range(0u32, 4u32, Comptime::new(true)) {
+            let element = UNIT_POS * UInt::new(4) + i;
+            let stacked = element / tile_area;
+            let row = (element % tile_area) / tile;
+            let col = element % tile;
+
+            // The lhs tiles are stacked along the rows of the block, the rhs ones along its
+            // columns.
+            let lhs_row = CUBE_POS_X * block + stacked * tile + row;
+            let lhs_k = k_start + col;
+            let mut lhs_value = F::new(0.);
+            if lhs_row < n_rows && lhs_k < k {
+                lhs_value = lhs[offset_lhs
+                    + lhs_row * lhs.stride(rank - UInt::new(2))
+                    + lhs_k * lhs.stride(rank - UInt::new(1))];
+            }
+            lhs_tiles[element] = F16::cast_from(lhs_value);
+
+            let rhs_k = k_start + row;
+            let rhs_col = CUBE_POS_Y * block + stacked * tile + col;
+            let mut rhs_value = F::new(0.);
+            if rhs_k < k && rhs_col < n_cols {
+                rhs_value = rhs[offset_rhs
+                    + rhs_k * rhs.stride(rank - UInt::new(2))
+                    + rhs_col * rhs.stride(rank - UInt::new(1))];
+            }
+            rhs_tiles[element] = F16::cast_from(rhs_value);
+        }
+        sync_units();
+
+        let lhs_start = tile_row * tile_area;
+        let rhs_start = tile_col * tile_area;
+        cmma::load::<F16>(
+            &matrix_lhs,
+            lhs_tiles.slice(lhs_start, lhs_start + tile_area),
+            tile,
+        );
+        cmma::load::<F16>(
+            &matrix_rhs,
+            rhs_tiles.slice(rhs_start, rhs_start + tile_area),
+            tile,
+        );
+        cmma::execute::<F16, F16, F32, F32>(&matrix_lhs, &matrix_rhs, &matrix_out, &matrix_out);
+        sync_units();
+    }
+
+    let out_start = UNIT_POS_Y * tile_area;
+    cmma::store::<F32>(
+        out_tiles.slice_mut(out_start, out_start + tile_area),
+        &matrix_out,
+        tile,
+        cmma::MatrixLayout::RowMajor,
+    );
+    sync_units();
+
+    // Each unit of the plane writes 8 of the 256 elements of its tile.
+    for i in range(0u32, 8u32, Comptime::new(true)) {
+        let position = UNIT_POS_X * UInt::new(8) + i;
+        let row = CUBE_POS_X * block + tile_row * tile + position / tile;
+        let col = CUBE_POS_Y * block + tile_col * tile + position % tile;
+
+        if row < n_rows && col < n_cols {
+            out[offset_out
+                + row * out.stride(rank - UInt::new(2))
+                + col * out.stride(rank - UInt::new(1))] =
+                F::cast_from(out_tiles[out_start + position]);
+        }
+    }
+}
+
+/// Matrix multiplication using the cooperative matrices of the tensor cores, supporting
+/// broadcasted batch dimensions and any layout of the inputs and of the output.
+///
+/// When the path isn't [available](is_cmma_available) for the client and the element type, the
+/// matmul is done by the [tiling 2D](matmul_tiling_2d) kernel with its default config.
+pub fn matmul_cmma<R: JitRuntime, E: FloatElement, const D: usize>(
+    lhs: JitTensor<R, E, D>,
+    rhs: JitTensor<R, E, D>,
+    out: JitTensor<R, E, D>,
+) -> JitTensor<R, E, D> {
+    if !is_cmma_available::<R, E>(&lhs.client, &lhs.device) {
+        return matmul_tiling_2d(lhs, rhs, out, Tiling2dConfig::default());
+    }
+
+    lhs.assert_is_on_same_device(&rhs);
+
+    let block_size = TILE_SIZE * PLANES_PER_DIM;
+    let num_rows = out.shape.dims[D - 2] as u32;
+    let num_cols = out.shape.dims[D - 1] as u32;
+    let num_batches = out.shape.dims[0..D - 2].iter().product::<usize>();
+
+    let cube_dim = CubeDim::new(PLANE_SIZE, PLANES_PER_DIM * PLANES_PER_DIM, 1);
+    let cube_count = CubeCount::Static(
+        num_rows.div_ceil(block_size),
+        num_cols.div_ceil(block_size),
+        num_batches as u32,
+    );
+
+    crate::perf::record_kernel();
+    unsafe {
+        matmul_cmma_kernel::launch_unchecked::<E::FloatPrimitive, R>(
+            &lhs.client,
+            cube_count,
+            cube_dim,
+            lhs.as_tensor_arg(1),
+            rhs.as_tensor_arg(1),
+            out.as_tensor_arg(1),
+            UInt::new(D as u32 - 2),
+        );
+    }
+
+    out
+}
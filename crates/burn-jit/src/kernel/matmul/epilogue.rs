@@ -0,0 +1,270 @@
+//! Matmul with a fused elementwise epilogue, `activation(lhs @ rhs + bias)` in a single launch.
+//!
+//! The [tiling 2D](super::matmul_tiling_2d_epilogue) kernel is used whenever the device supports
+//! it, with its fastest config when autotune is enabled. The kernel of this module is the fallback:
+//! each local unit computes a single element of the output matrix, then adds the bias and applies
+//! the activation before writing it. Unlike the tiling 2D epilogue, its activation is parametrized
+//! by [scalars](EpilogueActivation::default_scalars), so it also computes the fused graphs whose
+//! activations use other constants.
+use crate::{
+    kernel::{into_contiguous, SUBCUBE_DIM_APPROX},
+    tensor::JitTensor,
+    FloatElement, JitRuntime,
+};
+use burn_tensor::{ElementConversion, Shape};
+use cubecl::{client::ComputeClient, prelude::*};
+use serde::{Deserialize, Serialize};
+
+use super::{
+    init_matmul_output, is_tiling_2d_supported, matmul_tiling_2d_epilogue, Tiling2dConfig,
+};
+
+/// The activation applied by the epilogue of [matmul_epilogue].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EpilogueActivation {
+    /// [Relu](burn_tensor::activation::relu) activation.
+    Relu,
+    /// [Gelu](burn_tensor::activation::gelu) activation.
+    Gelu,
+    /// [Tanh](burn_tensor::activation::tanh) activation.
+    Tanh,
+}
+
+impl EpilogueActivation {
+    /// The scalars used by the activation when it isn't created from a fused graph.
+    ///
+    /// - Relu: `x <= s0 ? s1 : x`
+    /// - Gelu: `x * (erf(x / s0) + s1) / s2`
+    pub(crate) fn default_scalars(activation: Option<Self>) -> [f32; 3] {
+        match activation {
+            Some(Self::Gelu) => [core::f32::consts::SQRT_2, 1.0, 2.0],
+            _ => [0.0, 0.0, 0.0],
+        }
+    }
+}
+
+/// The comptime configuration of the epilogue, only the code of the selected activation being
+/// compiled in the kernel.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+struct EpilogueConfig {
+    has_bias: bool,
+    activation: Option<EpilogueActivation>,
+}
+
+impl Init for EpilogueConfig {
+    fn init(self, _context: &mut CubeContext) -> Self {
+        self
+    }
+}
+
+impl CubeType for EpilogueConfig {
+    type ExpandType = Self;
+}
+
+#[cube(launch_unchecked)]
+#[allow(clippy::too_many_arguments)]
+fn matmul_epilogue_kernel<F: Float>(
+    lhs: &Tensor<F>,
+    rhs: &Tensor<F>,
+    bias: &Tensor<F>,
+    out: &mut Tensor<F>,
+    scalar_0: F,
+    scalar_1: F,
+    scalar_2: F,
+    config: Comptime<EpilogueConfig>,
+) {
+    let rank = out.rank();
+
+    let n_rows = lhs.shape(rank - UInt::new(2));
+    let n_cols = rhs.shape(rank - UInt::new(1));
+    let k = rhs.shape(rank - UInt::new(2));
+
+    let batch_pos = ABSOLUTE_POS_Z;
+    let row = CUBE_DIM_X * CUBE_POS_X + UNIT_POS_X;
+    let col = CUBE_DIM_Y * CUBE_POS_Y + UNIT_POS_Y;
+
+    if row >= n_rows || col >= n_cols {
+        return;
+    }
+
+    let mut offset_lhs = UInt::new(0);
+    let mut offset_rhs = UInt::new(0);
+    let offset_out = n_rows * n_cols * batch_pos;
+
+    for i in range(0u32, rank - UInt::new(2), Comptime::new(false)) {
+        let ogwl = offset_out / out.stride(i);
+
+        offset_lhs += ogwl % lhs.shape(i) * lhs.stride(i);
+        offset_rhs += ogwl % rhs.shape(i) * rhs.stride(i);
+    }
+
+    // Both inputs are read with their strides, so transposed inputs don't need a copy.
+    offset_lhs += row * lhs.stride(rank - UInt::new(2));
+    offset_rhs += col * rhs.stride(rank - UInt::new(1));
+    let stride_lhs = lhs.stride(rank - UInt::new(1));
+    let stride_rhs = rhs.stride(rank - UInt::new(2));
+
+    let mut value = F::new(0.);
+
+    for i in range(0u32, k, Comptime::new(false)) {
+        value += lhs[offset_lhs + i * stride_lhs] * rhs[offset_rhs + i * stride_rhs];
+    }
+
+    if Comptime::get(Comptime::map(config, |c: EpilogueConfig| c.has_bias)) {
+        value += bias[col * bias.stride(rank - UInt::new(1))];
+    }
+
+    let is_relu = Comptime::map(config, |c: EpilogueConfig| {
+        c.activation == Some(EpilogueActivation::Relu)
+    });
+    let is_gelu = Comptime::map(config, |c: EpilogueConfig| {
+        c.activation == Some(EpilogueActivation::Gelu)
+    });
+    let is_tanh = Comptime::map(config, |c: EpilogueConfig| {
+        c.activation == Some(EpilogueActivation::Tanh)
+    });
+
+    if Comptime::get(is_relu) {
+        if value <= scalar_0 {
+            value = scalar_1;
+        }
+    }
+
+    if Comptime::get(is_gelu) {
+        value = value * (F::erf(value / scalar_0) + scalar_1) / scalar_2;
+    }
+
+    if Comptime::get(is_tanh) {
+        value = F::tanh(value);
+    }
+
+    out[offset_out + row * n_cols + col] = value;
+}
+
+/// Launch the matmul epilogue kernel on tensors of any rank.
+///
+/// The output must be contiguous and the bias, when provided, must have a size of one in every
+/// dimension except the last one, which must be the number of columns of the output. The
+/// `scalars` parametrize the activation, see [EpilogueActivation::default_scalars].
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn launch_matmul_epilogue<R: JitRuntime, E: FloatElement>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    lhs: TensorHandleRef<'_, R>,
+    rhs: TensorHandleRef<'_, R>,
+    bias: Option<TensorHandleRef<'_, R>>,
+    out: TensorHandleRef<'_, R>,
+    activation: Option<EpilogueActivation>,
+    scalars: [f32; 3],
+) {
+    let rank = out.shape.len();
+    let num_rows = out.shape[rank - 2];
+    let num_cols = out.shape[rank - 1];
+    let num_batches = out.shape[0..rank - 2].iter().product::<usize>();
+
+    let cube_dim = CubeDim::new(SUBCUBE_DIM_APPROX as u32, SUBCUBE_DIM_APPROX as u32, 1);
+    let cube_count = CubeCount::Static(
+        f32::ceil(num_rows as f32 / SUBCUBE_DIM_APPROX as f32) as u32,
+        f32::ceil(num_cols as f32 / SUBCUBE_DIM_APPROX as f32) as u32,
+        num_batches as u32,
+    );
+
+    let has_bias = bias.is_some();
+    // The bias binding is never read when there is no bias.
+    let bias = bias.unwrap_or(TensorHandleRef {
+        handle: lhs.handle,
+        strides: lhs.strides,
+        shape: lhs.shape,
+    });
+
+    crate::perf::record_kernel();
+
+    unsafe {
+        matmul_epilogue_kernel::launch_unchecked::<E::FloatPrimitive, R>(
+            client,
+            cube_count,
+            cube_dim,
+            TensorArg::from_raw_parts(lhs.handle, lhs.strides, lhs.shape, 1),
+            TensorArg::from_raw_parts(rhs.handle, rhs.strides, rhs.shape, 1),
+            TensorArg::from_raw_parts(bias.handle, bias.strides, bias.shape, 1),
+            TensorArg::from_raw_parts(out.handle, out.strides, out.shape, 1),
+            ScalarArg::new(scalars[0].elem::<E>()),
+            ScalarArg::new(scalars[1].elem::<E>()),
+            ScalarArg::new(scalars[2].elem::<E>()),
+            EpilogueConfig {
+                has_bias,
+                activation,
+            },
+        );
+    };
+}
+
+/// Matrix multiplication followed by a bias addition and an activation, computed in a single
+/// kernel.
+///
+/// The bias is broadcasted over all dimensions except the last one. The tiling 2D kernel is
+/// launched when the device supports it, tuned when autotune is enabled, and the
+/// [naive kernel](matmul_epilogue_naive) otherwise.
+pub fn matmul_epilogue<R: JitRuntime, E: FloatElement, const D: usize>(
+    lhs: JitTensor<R, E, D>,
+    rhs: JitTensor<R, E, D>,
+    bias: Option<JitTensor<R, E, 1>>,
+    activation: Option<EpilogueActivation>,
+) -> JitTensor<R, E, D> {
+    lhs.assert_is_on_same_device(&rhs);
+
+    #[cfg(feature = "autotune")]
+    if !super::tiling_2d_candidates::<R, E>(&lhs.device).is_empty() {
+        return super::matmul_epilogue_tiling_2d_autotune(lhs, rhs, bias, activation);
+    }
+
+    let config = Tiling2dConfig::default();
+    if is_tiling_2d_supported::<R, E>(&config, &lhs.device) {
+        let out = init_matmul_output(&lhs, &rhs);
+        return matmul_tiling_2d_epilogue(lhs, rhs, out, bias, activation, config);
+    }
+
+    matmul_epilogue_naive(lhs, rhs, bias, activation)
+}
+
+/// Matrix multiplication followed by a bias addition and an activation, each local unit computing
+/// a single element of the output.
+pub fn matmul_epilogue_naive<R: JitRuntime, E: FloatElement, const D: usize>(
+    lhs: JitTensor<R, E, D>,
+    rhs: JitTensor<R, E, D>,
+    bias: Option<JitTensor<R, E, 1>>,
+    activation: Option<EpilogueActivation>,
+) -> JitTensor<R, E, D> {
+    lhs.assert_is_on_same_device(&rhs);
+    let out = init_matmul_output(&lhs, &rhs);
+
+    let bias = bias.map(|bias| {
+        let num_cols = out.shape.dims[D - 1];
+        assert_eq!(
+            bias.shape.dims[0], num_cols,
+            "The bias must have the same size as the last dimension of the output"
+        );
+
+        let mut dims = [1; D];
+        dims[D - 1] = num_cols;
+        let bias = into_contiguous(bias);
+
+        JitTensor::<R, E, D>::new_contiguous(
+            bias.client,
+            bias.device,
+            Shape::new(dims),
+            bias.handle,
+        )
+    });
+
+    launch_matmul_epilogue::<R, E>(
+        &lhs.client,
+        lhs.as_handle_ref(),
+        rhs.as_handle_ref(),
+        bias.as_ref().map(|bias| bias.as_handle_ref()),
+        out.as_handle_ref(),
+        activation,
+        EpilogueActivation::default_scalars(activation),
+    );
+
+    out
+}
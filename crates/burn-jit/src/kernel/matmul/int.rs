@@ -0,0 +1,150 @@
+//! Integer matmul kernel implementation
+//!
+//! Each local unit computes a single element of the output matrix like the simple kernel, the
+//! products being accumulated in `i32` so that the result is exact as long as it doesn't
+//! overflow, which is what the quantized operations need.
+use crate::{
+    kernel::{into_contiguous, SUBCUBE_DIM_APPROX},
+    ops::swap_dims,
+    tensor::JitTensor,
+    IntElement, JitRuntime,
+};
+use cubecl::frontend::I32;
+use cubecl::prelude::*;
+
+use super::init_matmul_output;
+
+#[cube(launch_unchecked)]
+fn matmul_int_kernel<I: Int, A: Int>(
+    lhs: &Tensor<I>,
+    rhs: &Tensor<I>,
+    out: &mut Tensor<I>,
+    // number of dimensions not involved in the matmul
+    num_batches: Comptime<Option<UInt>>,
+) {
+    let rank = out.rank();
+    let end = Comptime::unwrap_or_else(num_batches, || rank - UInt::new(2));
+    let unroll = Comptime::is_some(num_batches);
+
+    let n_rows = lhs.shape(rank - UInt::new(2));
+    let n_cols = rhs.shape(rank - UInt::new(1));
+    let mut k = rhs.shape(rank - UInt::new(2));
+
+    let batch_pos = ABSOLUTE_POS_Z;
+    let row = CUBE_DIM_X * CUBE_POS_X + UNIT_POS_X;
+    let col = CUBE_DIM_Y * CUBE_POS_Y + UNIT_POS_Y;
+
+    // The cubes overflow the output when its shape isn't a multiple of the cube dimensions.
+    if row >= n_rows || col >= n_cols {
+        return;
+    }
+
+    let vectorization_factor = Comptime::vectorization(lhs);
+
+    let mut offset_lhs = UInt::new(0);
+    let mut offset_rhs = UInt::new(0);
+    let mut offset_out = UInt::new(0);
+    let mut batch = batch_pos;
+
+    for i in range(0u32, end, unroll) {
+        let dim = end - i - UInt::new(1);
+        let coordinate = batch % out.shape(dim);
+        batch /= out.shape(dim);
+
+        offset_lhs += coordinate % lhs.shape(dim) * lhs.stride(dim);
+        offset_rhs += coordinate % rhs.shape(dim) * rhs.stride(dim);
+        offset_out += coordinate * out.stride(dim);
+    }
+
+    offset_lhs /= Comptime::runtime(vectorization_factor);
+    offset_rhs /= Comptime::runtime(vectorization_factor);
+
+    let mut sum = A::vectorized(0, Comptime::get(vectorization_factor));
+
+    k /= Comptime::runtime(vectorization_factor);
+
+    for i in range(0u32, k, Comptime::new(false)) {
+        let lhs_index = row * k + i + offset_lhs;
+        let rhs_index = col * k + i + offset_rhs;
+
+        sum += A::cast_from(lhs[lhs_index]) * A::cast_from(rhs[rhs_index]);
+    }
+
+    let out_index =
+        row * out.stride(rank - UInt::new(2)) + col * out.stride(rank - UInt::new(1)) + offset_out;
+
+    let unroll_sum = Comptime::map(vectorization_factor, |w: UInt| w != UInt::new(1));
+    if Comptime::get(unroll_sum) {
+        let mut accum = A::new(0);
+        for v in range(
+            0u32,
+            Comptime::get(vectorization_factor),
+            Comptime::new(true),
+        ) {
+            accum += sum[v];
+        }
+
+        write_results_int(out, out_index, accum);
+    } else {
+        write_results_int(out, out_index, sum);
+    }
+}
+
+/// Write the accumulated value, cast to the output type.
+#[cube]
+fn write_results_int<I: Int, A: Int>(out: &mut Tensor<I>, index: UInt, value: A) {
+    out[index] = I::cast_from(value);
+}
+
+/// Integer matrix multiplication, the products being accumulated in `i32`.
+///
+/// The inputs are read with the layouts of the [simple](super::matmul_simple) kernel, four
+/// values at a time when the inner dimension is a multiple of four.
+pub fn matmul_int<R: JitRuntime, I: IntElement, const D: usize>(
+    lhs: JitTensor<R, I, D>,
+    rhs: JitTensor<R, I, D>,
+) -> JitTensor<R, I, D> {
+    lhs.assert_is_on_same_device(&rhs);
+    let out = init_matmul_output(&lhs, &rhs);
+    let lhs = into_contiguous(lhs);
+    let rhs_original_shape = rhs.shape.clone();
+    // The columns of the rhs are stored contiguously, like in the simple kernel.
+    let rhs = into_contiguous(swap_dims(rhs, D - 1, D - 2));
+
+    let num_rows = out.shape.dims[D - 2];
+    let num_cols = out.shape.dims[D - 1];
+    let num_batches = out.shape.dims[0..D - 2].iter().product::<usize>();
+
+    let cube_dim = CubeDim::new(SUBCUBE_DIM_APPROX as u32, SUBCUBE_DIM_APPROX as u32, 1);
+    let cube_count = CubeCount::Static(
+        f32::ceil(num_rows as f32 / SUBCUBE_DIM_APPROX as f32) as u32,
+        f32::ceil(num_cols as f32 / SUBCUBE_DIM_APPROX as f32) as u32,
+        num_batches as u32,
+    );
+
+    let vectorization_factor = match lhs.shape.dims[D - 1] % 4 == 0 {
+        true => 4,
+        false => 1,
+    };
+
+    crate::perf::record_kernel();
+
+    unsafe {
+        matmul_int_kernel::launch_unchecked::<I::IntPrimitive, I32, R>(
+            &lhs.client,
+            cube_count,
+            cube_dim,
+            lhs.as_tensor_arg(vectorization_factor),
+            TensorArg::from_raw_parts(
+                &rhs.handle,
+                &rhs.strides,
+                &rhs_original_shape.dims, // We need the original shape.
+                vectorization_factor,
+            ),
+            out.as_tensor_arg(1),
+            Some(UInt::new(D as u32 - 2)),
+        );
+    };
+
+    out
+}
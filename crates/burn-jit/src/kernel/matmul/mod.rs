@@ -1,11 +1,19 @@
 mod base;
+mod cmma;
+mod epilogue;
+mod int;
 mod simple;
+mod tiling2d;
 mod tune;
 
 /// Contains utilitary for matmul operation
 pub mod utils;
 
 pub use base::*;
+pub use cmma::*;
+pub use epilogue::*;
+pub use int::*;
 pub use simple::*;
+pub use tiling2d::*;
 pub use tune::*;
 pub use utils::*;
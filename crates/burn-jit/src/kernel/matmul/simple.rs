@@ -7,19 +7,45 @@ use crate::{
     tensor::JitTensor,
     FloatElement, JitRuntime,
 };
+use cubecl::client::ComputeClient;
 use cubecl::ir::KernelDefinition;
 use cubecl::KernelSettings;
 
-use super::simple_cube_count;
+use super::shape_out;
+use burn_tensor::{DType, Element, ElementConversion};
 use cubecl::prelude::*;
 
+/// The precision of the partial sums of a matmul.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccumulatorPrecision {
+    /// The sums are accumulated in the element type of the tensors.
+    Input,
+    /// The sums are accumulated in `f32`, the inputs and the output keeping their element type.
+    F32,
+}
+
+impl AccumulatorPrecision {
+    /// The precision used for the element type, which is `f32` for the half precision floats
+    /// since their sums lose most of their precision when the inner dimension is large.
+    pub fn of<E: Element>() -> Self {
+        match E::dtype() {
+            DType::F16 | DType::BF16 => Self::F32,
+            _ => Self::Input,
+        }
+    }
+}
+
 #[cube(launch_unchecked)]
-fn matmul_kernel<F: Float>(
+fn matmul_kernel<F: Float, A: Float>(
     lhs: &Tensor<F>,
     rhs: &Tensor<F>,
     out: &mut Tensor<F>,
+    alpha: A,
+    beta: A,
     // number of dimensions not involved in the matmul
     num_batches: Comptime<Option<UInt>>,
+    // whether the previous output is blended with a scale of `beta`
+    read_output: Comptime<bool>,
 ) {
     let rank = out.rank();
     let end = Comptime::unwrap_or_else(num_batches, || rank - UInt::new(2));
@@ -41,19 +67,26 @@ fn matmul_kernel<F: Float>(
 
     let mut offset_lhs = UInt::new(0);
     let mut offset_rhs = UInt::new(0);
-    let offset_out = n_rows * n_cols * batch_pos;
+    let mut offset_out = UInt::new(0);
+    let mut batch = batch_pos;
 
+    // The batch coordinates are decomposed from the last batch dimension rather than derived from
+    // the output strides, so that the output can have any layout, like a transposed view.
     for i in range(0u32, end, unroll) {
-        let ogwl = offset_out / out.stride(i);
+        let dim = end - i - UInt::new(1);
+        let coordinate = batch % out.shape(dim);
+        batch /= out.shape(dim);
 
-        offset_lhs += ogwl % lhs.shape(i) * lhs.stride(i);
-        offset_rhs += ogwl % rhs.shape(i) * rhs.stride(i);
+        offset_lhs += coordinate % lhs.shape(dim) * lhs.stride(dim);
+        offset_rhs += coordinate % rhs.shape(dim) * rhs.stride(dim);
+        offset_out += coordinate * out.stride(dim);
     }
 
     offset_lhs /= Comptime::runtime(vectorization_factor);
     offset_rhs /= Comptime::runtime(vectorization_factor);
 
-    let mut sum = F::vectorized(0., Comptime::get(vectorization_factor));
+    // The partial sums are kept in the accumulator type, the inputs being cast when read.
+    let mut sum = A::vectorized(0., Comptime::get(vectorization_factor));
 
     k /= Comptime::runtime(vectorization_factor);
 
@@ -61,15 +94,15 @@ fn matmul_kernel<F: Float>(
         let lhs_index = row * k + i + offset_lhs;
         let rhs_index = col * k + i + offset_rhs;
 
-        sum += lhs[lhs_index] * rhs[rhs_index];
+        sum += A::cast_from(lhs[lhs_index]) * A::cast_from(rhs[rhs_index]);
     }
 
-    let mut out_index = row * n_cols + col;
-    out_index += offset_out;
+    let out_index =
+        row * out.stride(rank - UInt::new(2)) + col * out.stride(rank - UInt::new(1)) + offset_out;
 
     let unroll_sum = Comptime::map(vectorization_factor, |w: UInt| w != UInt::new(1));
     if Comptime::get(unroll_sum) {
-        let mut accum = F::new(0.);
+        let mut accum = A::new(0.);
         // we unroll the loop to sum `vectorization_factor` elements at once, which lets us
         // use SIMD instructions to speed up the computation
         for v in range(
@@ -80,9 +113,26 @@ fn matmul_kernel<F: Float>(
             accum += sum[v];
         }
 
-        out[out_index] = accum;
+        write_output(out, out_index, accum, alpha, beta, read_output);
+    } else {
+        write_output(out, out_index, sum, alpha, beta, read_output);
+    }
+}
+
+/// Write the accumulated value, cast to the output type only once it is scaled and blended.
+#[cube]
+fn write_output<F: Float, A: Float>(
+    out: &mut Tensor<F>,
+    index: UInt,
+    value: A,
+    alpha: A,
+    beta: A,
+    read_output: Comptime<bool>,
+) {
+    if Comptime::get(read_output) {
+        out[index] = F::cast_from(alpha * value + beta * A::cast_from(out[index]));
     } else {
-        out[out_index] = sum;
+        out[index] = F::cast_from(alpha * value);
     }
 }
 
@@ -96,51 +146,384 @@ pub fn matmul_mem_coalescing_default<R: JitRuntime, E: FloatElement, const D: us
 }
 
 /// Matrix multiplication using memory coalescing algorithm with custom cube dimensions
+///
+/// The sums are accumulated with the [precision](AccumulatorPrecision::of) of the element type.
 pub fn matmul_simple<R: JitRuntime, E: FloatElement, const D: usize>(
     lhs: JitTensor<R, E, D>,
     rhs: JitTensor<R, E, D>,
     out: JitTensor<R, E, D>,
     cube_dim_x: usize,
     cube_dim_y: usize,
+) -> JitTensor<R, E, D> {
+    matmul_simple_with_accumulator(
+        lhs,
+        rhs,
+        out,
+        cube_dim_x,
+        cube_dim_y,
+        AccumulatorPrecision::of::<E>(),
+    )
+}
+
+/// Matrix multiplication using memory coalescing algorithm with custom cube dimensions, the sums
+/// being accumulated with the given precision.
+pub fn matmul_simple_with_accumulator<R: JitRuntime, E: FloatElement, const D: usize>(
+    lhs: JitTensor<R, E, D>,
+    rhs: JitTensor<R, E, D>,
+    out: JitTensor<R, E, D>,
+    cube_dim_x: usize,
+    cube_dim_y: usize,
+    accumulator: AccumulatorPrecision,
+) -> JitTensor<R, E, D> {
+    launch_simple(
+        lhs,
+        rhs,
+        out,
+        1.elem(),
+        0.elem(),
+        cube_dim_x,
+        cube_dim_y,
+        accumulator,
+    )
+}
+
+/// General matrix multiplication `alpha * lhs @ rhs + beta * out`, written to the output.
+///
+/// The output must have the shape of the matmul output, with any strides. Its previous values are
+/// only read when `beta` isn't zero, the kernel skipping the read otherwise, and it is copied
+/// first when it can't be mutated.
+pub fn matmul_gemm<R: JitRuntime, E: FloatElement, const D: usize>(
+    lhs: JitTensor<R, E, D>,
+    rhs: JitTensor<R, E, D>,
+    out: JitTensor<R, E, D>,
+    alpha: E,
+    beta: E,
+) -> JitTensor<R, E, D> {
+    assert_eq!(
+        out.shape,
+        shape_out(&lhs, &rhs),
+        "The output must have the shape of the matmul output"
+    );
+
+    let out = match out.can_mut() {
+        true => out,
+        false => out.copy(),
+    };
+
+    launch_simple(
+        lhs,
+        rhs,
+        out,
+        alpha,
+        beta,
+        SUBCUBE_DIM_APPROX,
+        SUBCUBE_DIM_APPROX,
+        AccumulatorPrecision::of::<E>(),
+    )
+}
+
+/// The configuration of a [GEMM launch](launch_gemm).
+#[derive(Debug, Clone, Copy)]
+pub struct GemmConfig<E> {
+    /// The scale of the product.
+    pub alpha: E,
+    /// The scale of the previous output, whose values are only read when it isn't zero.
+    pub beta: E,
+    /// The number of units of each cube along the rows of the output.
+    pub cube_dim_x: usize,
+    /// The number of units of each cube along the columns of the output.
+    pub cube_dim_y: usize,
+    /// The precision of the partial sums.
+    pub accumulator: AccumulatorPrecision,
+}
+
+impl<E: FloatElement> Default for GemmConfig<E> {
+    fn default() -> Self {
+        Self {
+            alpha: 1.elem(),
+            beta: 0.elem(),
+            cube_dim_x: SUBCUBE_DIM_APPROX,
+            cube_dim_y: SUBCUBE_DIM_APPROX,
+            accumulator: AccumulatorPrecision::of::<E>(),
+        }
+    }
+}
+
+/// Launch the general matrix multiplication `out = alpha * lhs @ rhs + beta * out` on raw
+/// handles, which is the extension point for the custom operations needing the kernel of
+/// [matmul_simple] on their own buffers.
+///
+/// The handles hold elements of type `E` and have the same rank of at least 2:
+///
+/// - `lhs` has the shape `[..., m, k]` and is row major, with the strides `[k, 1]` for its last
+///   two dimensions.
+/// - `rhs` has the shape `[..., k, n]` and is column major, with the strides `[1, k]` for its
+///   last two dimensions, which is the layout of a contiguous `[..., n, k]` tensor.
+/// - `out` has the shape `[..., m, n]` with any strides, as long as its elements don't overlap.
+///
+/// The batch dimensions of the inputs can have a size of one, in which case they are broadcasted
+/// to the ones of the output. Unlike the kernels of the other launch functions, the layout
+/// expected here is kept across releases.
+///
+/// # Panics
+///
+/// If the shapes or the strides of the handles don't follow the layout above.
+///
+/// # Example
+///
+/// ```rust
+/// use burn_jit::{
+///     cubecl::prelude::TensorHandleRef,
+///     kernel::{
+///         into_contiguous,
+///         matmul::{launch_gemm, GemmConfig},
+///     },
+///     tensor::JitTensor,
+///     FloatElement, JitRuntime,
+/// };
+/// use burn_tensor::Shape;
+///
+/// /// Multiplies the `[m, k]` lhs by the transpose of the `[n, k]` rhs.
+/// fn matmul_transposed<R: JitRuntime, E: FloatElement>(
+///     lhs: JitTensor<R, E, 2>,
+///     rhs: JitTensor<R, E, 2>,
+/// ) -> JitTensor<R, E, 2> {
+///     let lhs = into_contiguous(lhs);
+///     let rhs = into_contiguous(rhs);
+///     let [m, k] = lhs.shape.dims;
+///     let [n, _] = rhs.shape.dims;
+///
+///     let handle = lhs.client.empty(m * n * core::mem::size_of::<E>());
+///     let out = JitTensor::new_contiguous(
+///         lhs.client.clone(),
+///         lhs.device.clone(),
+///         Shape::new([m, n]),
+///         handle,
+///     );
+///
+///     // The transpose of the contiguous rhs is a column major `[k, n]` matrix.
+///     let rhs_transposed = TensorHandleRef {
+///         handle: &rhs.handle,
+///         strides: &[1, k],
+///         shape: &[k, n],
+///     };
+///
+///     launch_gemm::<R, E>(
+///         &lhs.client,
+///         lhs.as_handle_ref(),
+///         rhs_transposed,
+///         out.as_handle_ref(),
+///         GemmConfig::default(),
+///     );
+///
+///     out
+/// }
+/// ```
+pub fn launch_gemm<R: JitRuntime, E: FloatElement>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    lhs: TensorHandleRef<'_, R>,
+    rhs: TensorHandleRef<'_, R>,
+    out: TensorHandleRef<'_, R>,
+    config: GemmConfig<E>,
+) {
+    check_gemm_layout(&lhs, &rhs, &out);
+
+    launch_gemm_unchecked(client, lhs, rhs, out, config)
+}
+
+/// Panics with the violated rule when the handles don't follow the layout of [launch_gemm].
+fn check_gemm_layout<R: JitRuntime>(
+    lhs: &TensorHandleRef<'_, R>,
+    rhs: &TensorHandleRef<'_, R>,
+    out: &TensorHandleRef<'_, R>,
+) {
+    let rank = out.shape.len();
+
+    assert!(
+        rank >= 2 && lhs.shape.len() == rank && rhs.shape.len() == rank,
+        "GEMM: the lhs shape {:?}, the rhs shape {:?} and the out shape {:?} should have the same \
+         rank of at least 2.",
+        lhs.shape,
+        rhs.shape,
+        out.shape,
+    );
+
+    for (name, handle) in [("lhs", lhs), ("rhs", rhs), ("out", out)] {
+        assert_eq!(
+            handle.strides.len(),
+            rank,
+            "GEMM: the {name} strides {:?} should have the rank of its shape {:?}.",
+            handle.strides,
+            handle.shape,
+        );
+    }
+
+    let (m, k) = (lhs.shape[rank - 2], lhs.shape[rank - 1]);
+    let n = rhs.shape[rank - 1];
+
+    assert_eq!(
+        rhs.shape[rank - 2],
+        k,
+        "GEMM: the lhs shape {:?} and the rhs shape {:?} should have the same inner dimension.",
+        lhs.shape,
+        rhs.shape,
+    );
+    assert!(
+        out.shape[rank - 2] == m && out.shape[rank - 1] == n,
+        "GEMM: the out shape {:?} should end with [{m}, {n}], the rows of the lhs shape {:?} and \
+         the columns of the rhs shape {:?}.",
+        out.shape,
+        lhs.shape,
+        rhs.shape,
+    );
+
+    for dim in 0..rank - 2 {
+        for (name, handle) in [("lhs", lhs), ("rhs", rhs)] {
+            assert!(
+                handle.shape[dim] == out.shape[dim] || handle.shape[dim] == 1,
+                "GEMM: the batch dimension {dim} of the {name} shape {:?} should be 1 or the one \
+                 of the out shape {:?}.",
+                handle.shape,
+                out.shape,
+            );
+        }
+    }
+
+    // The stride of a dimension of size one is never used.
+    let has_stride = |size: usize, stride: usize, expected: usize| size == 1 || stride == expected;
+
+    assert!(
+        has_stride(m, lhs.strides[rank - 2], k) && has_stride(k, lhs.strides[rank - 1], 1),
+        "GEMM: the lhs should be row major, with the strides [{k}, 1] for its last two dimensions, \
+         got the strides {:?}.",
+        lhs.strides,
+    );
+    assert!(
+        has_stride(k, rhs.strides[rank - 2], 1) && has_stride(n, rhs.strides[rank - 1], k),
+        "GEMM: the rhs should be column major, with the strides [1, {k}] for its last two \
+         dimensions, got the strides {:?}.",
+        rhs.strides,
+    );
+}
+
+/// Launch the kernel on handles following the layout of [launch_gemm].
+fn launch_gemm_unchecked<R: JitRuntime, E: FloatElement>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    lhs: TensorHandleRef<'_, R>,
+    rhs: TensorHandleRef<'_, R>,
+    out: TensorHandleRef<'_, R>,
+    config: GemmConfig<E>,
+) {
+    let rank = out.shape.len();
+    let num_rows = out.shape[rank - 2];
+    let num_cols = out.shape[rank - 1];
+    let num_batches = out.shape[0..rank - 2].iter().product::<usize>();
+
+    // The vectorized offsets of the inputs are divided by the factor, so their batch strides
+    // should be multiples of it.
+    let mut batch_strides = lhs.strides[0..rank - 2]
+        .iter()
+        .chain(&rhs.strides[0..rank - 2]);
+    let vectorization_factor =
+        match lhs.shape[rank - 1] % 4 == 0 && batch_strides.all(|stride| stride % 4 == 0) {
+            true => 4,
+            false => 1,
+        };
+
+    let cube_dim = CubeDim::new(config.cube_dim_x as u32, config.cube_dim_y as u32, 1);
+    let cube_count = CubeCount::Static(
+        f32::ceil(num_rows as f32 / config.cube_dim_x as f32) as u32,
+        f32::ceil(num_cols as f32 / config.cube_dim_y as f32) as u32,
+        num_batches as u32,
+    );
+
+    let (lhs, rhs, out) = unsafe {
+        (
+            TensorArg::from_raw_parts(lhs.handle, lhs.strides, lhs.shape, vectorization_factor),
+            TensorArg::from_raw_parts(rhs.handle, rhs.strides, rhs.shape, vectorization_factor),
+            TensorArg::from_raw_parts(out.handle, out.strides, out.shape, 1),
+        )
+    };
+    let num_batch_dims = Some(UInt::new(rank as u32 - 2));
+    let read_output = config.beta != 0.elem::<E>();
+
+    crate::perf::record_kernel();
+
+    match config.accumulator {
+        AccumulatorPrecision::Input => unsafe {
+            matmul_kernel::launch_unchecked::<E::FloatPrimitive, E::FloatPrimitive, R>(
+                client,
+                cube_count,
+                cube_dim,
+                lhs,
+                rhs,
+                out,
+                ScalarArg::new(config.alpha),
+                ScalarArg::new(config.beta),
+                num_batch_dims,
+                read_output,
+            );
+        },
+        AccumulatorPrecision::F32 => unsafe {
+            matmul_kernel::launch_unchecked::<E::FloatPrimitive, F32, R>(
+                client,
+                cube_count,
+                cube_dim,
+                lhs,
+                rhs,
+                out,
+                ScalarArg::new(config.alpha.elem::<f32>()),
+                ScalarArg::new(config.beta.elem::<f32>()),
+                num_batch_dims,
+                read_output,
+            );
+        },
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn launch_simple<R: JitRuntime, E: FloatElement, const D: usize>(
+    lhs: JitTensor<R, E, D>,
+    rhs: JitTensor<R, E, D>,
+    out: JitTensor<R, E, D>,
+    alpha: E,
+    beta: E,
+    cube_dim_x: usize,
+    cube_dim_y: usize,
+    accumulator: AccumulatorPrecision,
 ) -> JitTensor<R, E, D> {
     lhs.assert_is_on_same_device(&rhs);
     let lhs = into_contiguous(lhs);
 
-    let rhs_original_shape = rhs.shape.clone();
     // we swap the dimensions to achieve memory-coalescing:
     // consecutive elements of a column in the original rhs tensor will now be stored
     // consecutively in memory, which allows to fetch them with fewer memory instructions
     let rhs = into_contiguous(swap_dims(rhs, D - 1, D - 2));
 
-    let cube_count = simple_cube_count::<R, D>(
-        &lhs.shape,
-        &rhs_original_shape,
-        &out.shape,
-        cube_dim_x,
-        cube_dim_y,
-    );
-
-    let vectorization_factor = match lhs.shape.dims[D - 1] % 4 == 0 {
-        true => 4,
-        false => 1,
+    // Swapped back, the contiguous tensor is the column major layout of the original rhs.
+    let mut rhs_shape = rhs.shape.dims;
+    let mut rhs_strides = rhs.strides;
+    rhs_shape.swap(D - 1, D - 2);
+    rhs_strides.swap(D - 1, D - 2);
+    let rhs_ref = TensorHandleRef {
+        handle: &rhs.handle,
+        strides: &rhs_strides,
+        shape: &rhs_shape,
     };
 
-    unsafe {
-        matmul_kernel::launch_unchecked::<E::FloatPrimitive, R>(
-            &lhs.client,
-            cube_count,
-            CubeDim::new(cube_dim_x as u32, cube_dim_y as u32, 1),
-            lhs.as_tensor_arg(vectorization_factor),
-            TensorArg::from_raw_parts(
-                &rhs.handle,
-                &rhs.strides,
-                &rhs_original_shape.dims, // We need the original shape.
-                vectorization_factor,
-            ),
-            out.as_tensor_arg(1),
-            Some(UInt::new(D as u32 - 2)),
-        );
-    };
+    launch_gemm_unchecked(
+        &lhs.client,
+        lhs.as_handle_ref(),
+        rhs_ref,
+        out.as_handle_ref(),
+        GemmConfig {
+            alpha,
+            beta,
+            cube_dim_x,
+            cube_dim_y,
+            accumulator,
+        },
+    );
 
     out
 }
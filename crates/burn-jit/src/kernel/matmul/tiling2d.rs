@@ -0,0 +1,1429 @@
+//! Tiling 2D matmul kernel implementation
+//!
+//! Each cube computes a square block of the output, each unit computing a square tile of
+//! `tile_size * tile_size` elements of it. The inner dimension is split in blocks, each block of
+//! the inputs being loaded in shared memory by all the units of the cube before being read by the
+//! units of the same rows or columns. The out of bound elements of the blocks are zeros, so the
+//! shapes don't have to be multiples of the block size, the bound checks being only compiled when
+//! they aren't.
+//!
+//! With double buffering, each input has two tiles in shared memory: the block `k + 1` is loaded
+//! in one while the block `k` is read from the other. The loads of the next block are then issued
+//! before the products of the current one, and a single synchronization per block is needed
+//! instead of two.
+//!
+//! The rows of the tiles are written to the output in vectors when its columns are contiguous and
+//! aligned, each row being written in `tile_size / vectorization` chunks. The output columns are
+//! then a multiple of the vectorization, so the bound checks are done per chunk.
+//!
+//! When every dimension is a multiple of the block size and the rows are written in vectors of 4
+//! elements, the [aligned](Tiling2dPath::Aligned) specialization is launched instead, without any
+//! bound check and with the loop over the inner dimension of the blocks unrolled.
+//!
+//! The [accumulating](matmul_accumulate) variant adds the tiles to the previous values of the
+//! output instead of overwriting them, each chunk being read before it is written.
+//!
+//! The [epilogue](matmul_tiling_2d_epilogue) variant adds a bias to the rows of the output and
+//! applies an activation to each chunk before it is stored, instead of launching elementwise
+//! kernels on the written output.
+//!
+//! Each product is accumulated with a fused multiply-add, rounded once instead of twice, so the
+//! results don't depend on whether the shader compiler contracts the multiplication and the
+//! addition, and match a CPU reference accumulating in the same order with `mul_add`.
+//!
+//! The inputs are read with their strides, without any copy. The blocks are loaded along the
+//! contiguous dimension of each input: the neighboring units of a cube read neighboring elements
+//! of the rows of a row major input, and of the columns of a [transposed](is_transposed) one, like
+//! the transposed weights of the backward pass of a linear layer.
+//!
+//! The matmuls with an inner dimension much larger than the output, like the tall and skinny
+//! products of a matrix by a vector, are [split](Tiling2dConfig::split_k_ratio) along the inner
+//! dimension: the cubes of each slice of it write their partial sums to a temporary buffer, which
+//! is then reduced into the output by a second kernel. The cubes along the rows and the columns
+//! of such an output would otherwise be too few to occupy the device.
+//!
+//! The vectorization of the writes is counted in elements of the output, whatever their size, so a
+//! vector of 4 `f16` is 8 bytes wide. The half precision floats need the device to support them,
+//! the launch failing with a [capability error](crate::assert_float_supported) otherwise.
+use super::{shape_out, AccumulatorPrecision, EpilogueActivation};
+use crate::{
+    assert_float_supported, is_float_supported,
+    kernel::{
+        cube_select,
+        debug::{cube_assert, KernelAssertions},
+        into_contiguous, SUBCUBE_DIM_APPROX,
+    },
+    tensor::JitTensor,
+    FloatElement, JitRuntime,
+};
+use burn_tensor::{backend::DeviceOps, Shape};
+use cubecl::frontend::F32;
+use cubecl::linalg::tensor::MatrixLayout;
+use cubecl::{calculate_cube_count_elemwise, prelude::*, KernelId};
+
+/// The maximum number of units of a cube.
+pub(crate) const MAX_UNITS_PER_CUBE: usize = 1024;
+/// The maximum number of slices of the inner dimension chosen by
+/// [num_k_splits](Tiling2dConfig::num_k_splits), which bounds the size of the partial sums.
+const MAX_K_SPLITS: usize = 16;
+/// The vectorization of the output writes of the [aligned](Tiling2dPath::Aligned) path.
+const ALIGNED_VECTORIZATION: usize = 4;
+/// The inverse of the square root of two, scaling the inputs of the error function of the
+/// [gelu](EpilogueActivation::Gelu) epilogue.
+const FRAC_1_SQRT_2: f32 = core::f32::consts::FRAC_1_SQRT_2;
+
+/// The configuration of the [tiling 2D](matmul_tiling_2d) matmul kernel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Tiling2dConfig {
+    /// The number of rows and columns of the output block computed by a cube, which is also the
+    /// size of the blocks along the inner dimension.
+    pub block_size: usize,
+    /// The number of rows and columns of the output tile computed by each unit, by default the
+    /// largest of 4, 2 and 1 dividing the block size. Larger tiles reuse each loaded value more,
+    /// which pays off on large inner dimensions.
+    pub tile_size: Option<usize>,
+    /// Whether the next block is loaded in a second shared memory tile while the current one is
+    /// computed, which trades twice the shared memory for fewer stalls on the loads. Small
+    /// problems, with few blocks along the inner dimension, gain little from it.
+    pub double_buffering: bool,
+    /// The ratio of the inner dimension to the largest of the rows and the columns of the output
+    /// from which the inner dimension is split between several cubes, by default 4. The inner
+    /// dimension is never split when `None`.
+    pub split_k_ratio: Option<usize>,
+}
+
+impl Default for Tiling2dConfig {
+    fn default() -> Self {
+        Self {
+            block_size: SUBCUBE_DIM_APPROX,
+            tile_size: None,
+            double_buffering: true,
+            split_k_ratio: Some(4),
+        }
+    }
+}
+
+impl Tiling2dConfig {
+    /// The number of slices of the inner dimension computed by different cubes for the matmul of
+    /// `m x k` by `k x n`, one when the inner dimension is below the
+    /// [split ratio](Self::split_k_ratio) times the largest of `m` and `n`.
+    ///
+    /// The inner dimension is split in about `k / max(m, n)` slices, up to 16, each one spanning
+    /// the same number of blocks except the last.
+    pub fn num_k_splits(&self, [m, k, n]: [usize; 3]) -> usize {
+        let size = usize::max(m, n).max(1);
+
+        match self.split_k_ratio {
+            Some(ratio) if self.block_size > 0 && k >= ratio.max(1) * size => {
+                let num_blocks = k.div_ceil(self.block_size);
+                let num_splits = (k / size).clamp(1, MAX_K_SPLITS);
+
+                split_blocks(num_blocks, num_splits).0
+            }
+            _ => 1,
+        }
+    }
+
+    /// The number of bytes of shared memory used by a cube, holding the blocks of both inputs in
+    /// one or two buffers.
+    pub fn shared_memory_size<E: FloatElement>(&self) -> usize {
+        let num_buffers = match self.double_buffering {
+            true => 2,
+            false => 1,
+        };
+
+        2 * num_buffers * self.block_size * self.block_size * core::mem::size_of::<E>()
+    }
+
+    /// Check that the [shared memory](Self::shared_memory_size) of a cube fits in the shared
+    /// memory of a device, whose size is unknown when `None`.
+    pub fn check_shared_memory_size<E: FloatElement>(
+        &self,
+        max_size: Option<u32>,
+    ) -> Result<(), Tiling2dConfigError> {
+        let size = self.shared_memory_size::<E>();
+
+        match max_size {
+            Some(max_size) if size > max_size as usize => {
+                Err(Tiling2dConfigError::SharedMemoryTooLarge {
+                    block_size: self.block_size,
+                    size,
+                    max_size: max_size as usize,
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Split the blocks along the inner dimension in at most `num_splits` slices of the same number of
+/// blocks except the last, returning the number of slices and of blocks per slice.
+fn split_blocks(num_blocks: usize, num_splits: usize) -> (usize, usize) {
+    let blocks_per_split = num_blocks.div_ceil(num_splits.max(1)).max(1);
+
+    (num_blocks.div_ceil(blocks_per_split), blocks_per_split)
+}
+
+/// The specialization of the [tiling 2D](matmul_tiling_2d) kernel launched for the shapes of a
+/// matmul.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tiling2dPath {
+    /// Every dimension is a multiple of the block size and the rows of the tiles are written in
+    /// vectors of 4 elements, so the kernel is compiled without bound checks and with the loop
+    /// over the inner dimension of the blocks unrolled.
+    Aligned,
+    /// The dimensions that aren't multiples of the block size are bound checked.
+    Checked,
+}
+
+impl Tiling2dPath {
+    /// The path launched for the matmul of `m x k` by `k x n` with the block size, the rows of
+    /// the tiles being written `vectorization` elements at a time.
+    fn of(block_size: usize, vectorization: usize, [m, k, n]: [usize; 3]) -> Self {
+        let aligned = block_size > 0
+            && vectorization == ALIGNED_VECTORIZATION
+            && [m, k, n].iter().all(|dim| dim % block_size == 0);
+
+        match aligned {
+            true => Self::Aligned,
+            false => Self::Checked,
+        }
+    }
+}
+
+/// The reason why a [CubeTiling2dConfig] can't be built.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Tiling2dConfigError {
+    /// The block size or the tile size is zero, or the tile size doesn't divide the block size.
+    TileSize {
+        /// The block size of the config.
+        block_size: usize,
+        /// The tile size computed by each unit.
+        tile_size: usize,
+    },
+    /// The cube would have more units than a cube can hold.
+    CubeTooLarge {
+        /// The block size of the config.
+        block_size: usize,
+        /// The tile size computed by each unit.
+        tile_size: usize,
+    },
+    /// The blocks of the inputs don't fit in the shared memory of the device.
+    SharedMemoryTooLarge {
+        /// The block size of the config.
+        block_size: usize,
+        /// The number of bytes of shared memory used by a cube.
+        size: usize,
+        /// The number of bytes of shared memory of the device.
+        max_size: usize,
+    },
+    /// The vectorization of the output doesn't divide the tile size or the output columns.
+    Vectorization {
+        /// The tile size computed by each unit.
+        tile_size: usize,
+        /// The number of elements written at once.
+        vectorization: usize,
+        /// The number of columns of the output.
+        num_cols: usize,
+    },
+    /// The shapes aren't the ones of a matmul.
+    Shapes {
+        /// The shape of the lhs.
+        lhs: Vec<usize>,
+        /// The shape of the rhs.
+        rhs: Vec<usize>,
+        /// The shape of the output.
+        out: Vec<usize>,
+    },
+}
+
+impl core::fmt::Display for Tiling2dConfigError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::TileSize {
+                block_size,
+                tile_size,
+            } => write!(
+                f,
+                "Tiling 2D: the tile size {tile_size} should divide the block size {block_size}, \
+                 both being positive."
+            ),
+            Self::CubeTooLarge {
+                block_size,
+                tile_size,
+            } => {
+                let units = block_size / tile_size;
+                write!(
+                    f,
+                    "Tiling 2D: the block size {block_size} with the tile size {tile_size} needs \
+                     {units}x{units} units per cube, more than the maximum of \
+                     {MAX_UNITS_PER_CUBE}."
+                )
+            }
+            Self::SharedMemoryTooLarge {
+                block_size,
+                size,
+                max_size,
+            } => write!(
+                f,
+                "Tiling 2D: the blocks of the block size {block_size} need {size} bytes of shared \
+                 memory per cube, more than the {max_size} bytes of the device."
+            ),
+            Self::Vectorization {
+                tile_size,
+                vectorization,
+                num_cols,
+            } => write!(
+                f,
+                "Tiling 2D: the vectorization {vectorization} should divide the tile size \
+                 {tile_size} and the {num_cols} columns of the output."
+            ),
+            Self::Shapes { lhs, rhs, out } => write!(
+                f,
+                "Tiling 2D: the lhs shape {lhs:?} and the rhs shape {rhs:?} should have the same \
+                 inner dimension and batch dimensions broadcastable to the ones of the out shape \
+                 {out:?}, which should end with the rows of the lhs and the columns of the rhs."
+            ),
+        }
+    }
+}
+
+/// The comptime configuration of the [tiling 2D](matmul_tiling_2d) kernel, whose bound checks are
+/// derived from the shapes it is launched on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CubeTiling2dConfig {
+    block_size: UInt,
+    tile_size: UInt,
+    /// The number of elements of a row of a tile written at once to the output.
+    vectorization: UInt,
+    /// The size of the shared memory of each input, which holds one block per buffer.
+    block_storage: UInt,
+    double_buffering: bool,
+    check_m_bounds: bool,
+    check_k_bounds: bool,
+    check_n_bounds: bool,
+    /// Whether the loop over the inner dimension of the blocks is unrolled.
+    unroll: bool,
+    /// Whether the tiles are added to the previous values of the output.
+    accumulate: bool,
+    /// Whether the columns of the lhs are contiguous instead of its rows.
+    lhs_transposed: bool,
+    /// Whether the columns of the rhs are contiguous instead of its rows.
+    rhs_transposed: bool,
+    /// Whether each cube only sums a slice of the inner dimension, writing its partial sums after
+    /// the ones of the previous slices.
+    split_k: bool,
+    /// Whether the writes to the output are checked by [kernel assertions](cube_assert).
+    assertions: bool,
+    /// The operations applied to each element before it is stored.
+    epilogue: Tiling2dEpilogue,
+}
+
+/// The comptime epilogue of the [tiling 2D](matmul_tiling_2d) kernel, applied to each element of
+/// the output before it is stored, only the code of the selected operations being compiled.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Tiling2dEpilogue {
+    /// Whether a bias is added to each row of the output.
+    pub has_bias: bool,
+    /// The activation applied after the bias.
+    pub activation: Option<EpilogueActivation>,
+}
+
+impl CubeTiling2dConfig {
+    /// Create the kernel config for the matmul of `lhs_shape` by `rhs_shape` into `out_shape`,
+    /// each unit computing `tile_size * tile_size` elements of the output and writing them
+    /// `vectorization` at a time.
+    ///
+    /// The dimensions that aren't multiples of the block size are bound checked.
+    pub fn from_shapes<const D: usize>(
+        config: &Tiling2dConfig,
+        lhs_shape: &Shape<D>,
+        rhs_shape: &Shape<D>,
+        out_shape: &Shape<D>,
+        tile_size: usize,
+        vectorization: usize,
+    ) -> Result<Self, Tiling2dConfigError> {
+        let block_size = config.block_size;
+
+        if block_size == 0 || tile_size == 0 || block_size % tile_size != 0 {
+            return Err(Tiling2dConfigError::TileSize {
+                block_size,
+                tile_size,
+            });
+        }
+
+        let units = block_size / tile_size;
+        if units * units > MAX_UNITS_PER_CUBE {
+            return Err(Tiling2dConfigError::CubeTooLarge {
+                block_size,
+                tile_size,
+            });
+        }
+
+        let [lhs, rhs, out] = [lhs_shape, rhs_shape, out_shape].map(|shape| &shape.dims);
+        let broadcastable = (0..D - 2).all(|dim| {
+            [lhs[dim], rhs[dim]]
+                .iter()
+                .all(|&size| size == out[dim] || size == 1)
+        });
+
+        if lhs[D - 1] != rhs[D - 2]
+            || out[D - 2] != lhs[D - 2]
+            || out[D - 1] != rhs[D - 1]
+            || !broadcastable
+        {
+            return Err(Tiling2dConfigError::Shapes {
+                lhs: lhs.to_vec(),
+                rhs: rhs.to_vec(),
+                out: out.to_vec(),
+            });
+        }
+
+        let (m, k, n) = (out[D - 2], lhs[D - 1], out[D - 1]);
+
+        if vectorization == 0 || tile_size % vectorization != 0 || n % vectorization != 0 {
+            return Err(Tiling2dConfigError::Vectorization {
+                tile_size,
+                vectorization,
+                num_cols: n,
+            });
+        }
+
+        let num_buffers = match config.double_buffering {
+            true => 2,
+            false => 1,
+        };
+
+        Ok(Self {
+            block_size: UInt::new(block_size as u32),
+            tile_size: UInt::new(tile_size as u32),
+            vectorization: UInt::new(vectorization as u32),
+            block_storage: UInt::new((num_buffers * block_size * block_size) as u32),
+            double_buffering: config.double_buffering,
+            check_m_bounds: m % block_size != 0,
+            check_k_bounds: k % block_size != 0,
+            check_n_bounds: n % block_size != 0,
+            unroll: false,
+            accumulate: false,
+            lhs_transposed: false,
+            rhs_transposed: false,
+            split_k: false,
+            assertions: false,
+            epilogue: Tiling2dEpilogue::default(),
+        })
+    }
+
+    /// Create the kernel config of the [aligned](Tiling2dPath::Aligned) path, writing the rows of
+    /// the tiles in vectors of 4 elements.
+    ///
+    /// No bound check is compiled, so every dimension must be a multiple of the block size. This
+    /// is only validated with the debug assertions, the kernel reading and writing out of bounds
+    /// otherwise.
+    pub fn aligned<const D: usize>(
+        config: &Tiling2dConfig,
+        lhs_shape: &Shape<D>,
+        rhs_shape: &Shape<D>,
+        out_shape: &Shape<D>,
+        tile_size: usize,
+    ) -> Result<Self, Tiling2dConfigError> {
+        let kernel_config = Self::from_shapes(
+            config,
+            lhs_shape,
+            rhs_shape,
+            out_shape,
+            tile_size,
+            ALIGNED_VECTORIZATION,
+        )?;
+
+        debug_assert!(
+            !(kernel_config.check_m_bounds
+                || kernel_config.check_k_bounds
+                || kernel_config.check_n_bounds),
+            "Tiling 2D: the aligned path needs the {} rows, the inner dimension {} and the {} \
+             columns to be multiples of the block size {}.",
+            out_shape.dims[D - 2],
+            lhs_shape.dims[D - 1],
+            out_shape.dims[D - 1],
+            config.block_size,
+        );
+
+        Ok(Self {
+            check_m_bounds: false,
+            check_k_bounds: false,
+            check_n_bounds: false,
+            unroll: true,
+            ..kernel_config
+        })
+    }
+
+    /// The specialization of the kernel compiled with the config, the
+    /// [aligned](Tiling2dPath::Aligned) one having no bound check and an unrolled inner loop.
+    pub fn path(&self) -> Tiling2dPath {
+        let checked = self.check_m_bounds || self.check_k_bounds || self.check_n_bounds;
+
+        match self.unroll && !checked {
+            true => Tiling2dPath::Aligned,
+            false => Tiling2dPath::Checked,
+        }
+    }
+
+    /// Load the blocks of the inputs along their contiguous dimension, the columns of a
+    /// transposed input being contiguous instead of its rows.
+    ///
+    /// Any layout is read correctly either way, only the order of the loads changes.
+    pub fn with_transposed(self, lhs_transposed: bool, rhs_transposed: bool) -> Self {
+        Self {
+            lhs_transposed,
+            rhs_transposed,
+            ..self
+        }
+    }
+}
+
+impl Init for CubeTiling2dConfig {
+    fn init(self, _context: &mut CubeContext) -> Self {
+        self
+    }
+}
+
+impl CubeType for CubeTiling2dConfig {
+    type ExpandType = Self;
+}
+
+#[cube(launch)]
+fn matmul_tiling_2d_kernel<F: Float, A: Float>(
+    lhs: &Tensor<F>,
+    rhs: &Tensor<F>,
+    // added to each row of the output, only accessed when the epilogue has a bias
+    bias: &Tensor<F>,
+    out: &mut Tensor<F>,
+    // flags the writes out of the output, only accessed when the assertions are compiled
+    assertions: &mut Tensor<UInt>,
+    // number of elements of the inner dimension summed by each cube when it is split
+    k_split_size: UInt,
+    // number of dimensions not involved in the matmul
+    num_batches: Comptime<Option<UInt>>,
+    config: Comptime<CubeTiling2dConfig>,
+) {
+    let rank = out.rank();
+    let end = Comptime::unwrap_or_else(num_batches, || rank - UInt::new(2));
+    let unroll = Comptime::is_some(num_batches);
+    let split_k = Comptime::map(config, |c: CubeTiling2dConfig| c.split_k);
+
+    let block_storage = Comptime::map(config, |c: CubeTiling2dConfig| c.block_storage);
+    let tile_area = Comptime::map(config, |c: CubeTiling2dConfig| c.tile_size * c.tile_size);
+    let double_buffering = Comptime::map(config, |c: CubeTiling2dConfig| c.double_buffering);
+    let block = Comptime::runtime(Comptime::map(config, |c: CubeTiling2dConfig| c.block_size));
+
+    let mut lhs_blocks = SharedMemory::<F>::new(Comptime::get(block_storage));
+    let mut rhs_blocks = SharedMemory::<F>::new(Comptime::get(block_storage));
+    let mut results = Array::<A>::new(Comptime::get(tile_area));
+
+    for i in range(0u32, Comptime::get(tile_area), Comptime::new(true)) {
+        results[i] = A::new(0.);
+    }
+
+    let k = lhs.shape(rank - UInt::new(1));
+    let mut num_blocks = (k + block - UInt::new(1)) / block;
+
+    let mut offset_out = UInt::new(0);
+    let mut batch = CUBE_POS_Z;
+    let mut k_begin = UInt::new(0);
+
+    // The cubes of the slices of a batch follow each other, the partial sums of each slice being
+    // written after the ones of the previous slices, each as large as the contiguous output.
+    if Comptime::get(split_k) {
+        let num_splits = (k + k_split_size - UInt::new(1)) / k_split_size;
+        let split = CUBE_POS_Z % num_splits;
+        let blocks_per_split = k_split_size / block;
+        batch = CUBE_POS_Z / num_splits;
+        k_begin = split * k_split_size;
+        offset_out = split * out.stride(0) * out.shape(0);
+
+        num_blocks -= split * blocks_per_split;
+        if num_blocks > blocks_per_split {
+            num_blocks = blocks_per_split;
+        }
+    }
+
+    let offsets = batch_offsets(lhs, rhs, out, batch, end, unroll);
+    let offset_lhs = offsets.lhs;
+    let offset_rhs = offsets.rhs;
+    offset_out += offsets.out;
+
+    // The units past the edges of the output don't return early, since every unit of the cube
+    // loads the blocks and takes part in the synchronizations.
+    if Comptime::get(double_buffering) {
+        let block_area = block * block;
+
+        load_blocks(
+            lhs,
+            rhs,
+            &mut lhs_blocks,
+            &mut rhs_blocks,
+            offset_lhs,
+            offset_rhs,
+            k_begin,
+            UInt::new(0),
+            config,
+        );
+        sync_units();
+
+        for b in range(0u32, num_blocks, Comptime::new(false)) {
+            let current = (b % UInt::new(2)) * block_area;
+
+            // The other buffer was last read during the previous block, before its
+            // synchronization, so it can be overwritten.
+            if b + UInt::new(1) < num_blocks {
+                load_blocks(
+                    lhs,
+                    rhs,
+                    &mut lhs_blocks,
+                    &mut rhs_blocks,
+                    offset_lhs,
+                    offset_rhs,
+                    k_begin + (b + UInt::new(1)) * block,
+                    block_area - current,
+                    config,
+                );
+            }
+
+            compute_blocks(&lhs_blocks, &rhs_blocks, &mut results, current, config);
+            sync_units();
+        }
+    } else {
+        for b in range(0u32, num_blocks, Comptime::new(false)) {
+            load_blocks(
+                lhs,
+                rhs,
+                &mut lhs_blocks,
+                &mut rhs_blocks,
+                offset_lhs,
+                offset_rhs,
+                k_begin + b * block,
+                UInt::new(0),
+                config,
+            );
+            sync_units();
+
+            compute_blocks(&lhs_blocks, &rhs_blocks, &mut results, UInt::new(0), config);
+            sync_units();
+        }
+    }
+
+    write_results(out, &results, bias, assertions, offset_out, config);
+}
+
+/// The offsets of a batch in the inputs and in the output.
+#[derive(CubeType)]
+struct BatchOffsets {
+    lhs: UInt,
+    rhs: UInt,
+    out: UInt,
+}
+
+/// The offsets of the batch in each tensor, from its coordinates along the `end` first
+/// dimensions of the output, the inputs being broadcasted along the dimensions of size 1.
+#[cube]
+fn batch_offsets<F: Float>(
+    lhs: &Tensor<F>,
+    rhs: &Tensor<F>,
+    out: &Tensor<F>,
+    batch: UInt,
+    end: UInt,
+    unroll: Comptime<bool>,
+) -> BatchOffsets {
+    let mut remaining = batch;
+    let mut offset_lhs = UInt::new(0);
+    let mut offset_rhs = UInt::new(0);
+    let mut offset_out = UInt::new(0);
+
+    for i in range(0u32, end, unroll) {
+        let dim = end - i - UInt::new(1);
+        let coordinate = remaining % out.shape(dim);
+        remaining /= out.shape(dim);
+
+        offset_lhs += coordinate % lhs.shape(dim) * lhs.stride(dim);
+        offset_rhs += coordinate % rhs.shape(dim) * rhs.stride(dim);
+        offset_out += coordinate * out.stride(dim);
+    }
+
+    BatchOffsets {
+        lhs: offset_lhs,
+        rhs: offset_rhs,
+        out: offset_out,
+    }
+}
+
+/// Load the blocks of the inputs starting at `k_start` in the buffers at `buffer_offset`, the out
+/// of bound elements being zeros.
+///
+/// Along the contiguous dimension of each input, the neighboring units load neighboring elements,
+/// each unit loading an element every `block_size / tile_size` of them. Along the other one, each
+/// unit loads the elements of its tile.
+#[cube]
+#[allow(clippy::too_many_arguments)]
+fn load_blocks<F: Float>(
+    lhs: &Tensor<F>,
+    rhs: &Tensor<F>,
+    lhs_blocks: &mut SharedMemory<F>,
+    rhs_blocks: &mut SharedMemory<F>,
+    offset_lhs: UInt,
+    offset_rhs: UInt,
+    k_start: UInt,
+    buffer_offset: UInt,
+    config: Comptime<CubeTiling2dConfig>,
+) {
+    let check_lhs_bounds = Comptime::map(config, |c: CubeTiling2dConfig| {
+        c.check_m_bounds || c.check_k_bounds
+    });
+    let check_rhs_bounds = Comptime::map(config, |c: CubeTiling2dConfig| {
+        c.check_k_bounds || c.check_n_bounds
+    });
+    let lhs_row_major = Comptime::map(config, |c: CubeTiling2dConfig| !c.lhs_transposed);
+    let rhs_row_major = Comptime::map(config, |c: CubeTiling2dConfig| !c.rhs_transposed);
+    let tile_size = Comptime::map(config, |c: CubeTiling2dConfig| c.tile_size);
+    let tile = Comptime::runtime(tile_size);
+    let units = Comptime::runtime(Comptime::map(config, |c: CubeTiling2dConfig| {
+        c.block_size / c.tile_size
+    }));
+    let block = Comptime::runtime(Comptime::map(config, |c: CubeTiling2dConfig| c.block_size));
+
+    let rank = lhs.rank();
+    let n_rows = lhs.shape(rank - UInt::new(2));
+    let n_cols = rhs.shape(rank - UInt::new(1));
+    let k = lhs.shape(rank - UInt::new(1));
+
+    for r in range(0u32, Comptime::get(tile_size), Comptime::new(true)) {
+        for c in range(0u32, Comptime::get(tile_size), Comptime::new(true)) {
+            // The position along the contiguous dimension, shared by the neighboring units, and
+            // the one along the other dimension, within the tile of the unit.
+            let strided = r * units + UNIT_POS_X;
+            let tiled = UNIT_POS_Y * tile + c;
+
+            let mut lhs_row = strided;
+            let mut lhs_col = tiled;
+            if Comptime::get(lhs_row_major) {
+                lhs_row = tiled;
+                lhs_col = strided;
+            }
+
+            let mut rhs_row = strided;
+            let mut rhs_col = tiled;
+            if Comptime::get(rhs_row_major) {
+                rhs_row = tiled;
+                rhs_col = strided;
+            }
+
+            // The lhs block holds the rows of the cube, the rhs block its columns.
+            let lhs_block_index = buffer_offset + lhs_row * block + lhs_col;
+            let row = CUBE_POS_X * block + lhs_row;
+            let k_lhs = k_start + lhs_col;
+            let lhs_index = offset_lhs
+                + row * lhs.stride(rank - UInt::new(2))
+                + k_lhs * lhs.stride(rank - UInt::new(1));
+
+            // The elements out of the inputs read their first element instead, replaced by zeros
+            // without branching.
+            if Comptime::get(check_lhs_bounds) {
+                let in_bounds = row < n_rows && k_lhs < k;
+                let value = lhs[cube_select(in_bounds, lhs_index, UInt::new(0))];
+                lhs_blocks[lhs_block_index] = cube_select(in_bounds, value, F::new(0.));
+            } else {
+                lhs_blocks[lhs_block_index] = lhs[lhs_index];
+            }
+
+            let rhs_block_index = buffer_offset + rhs_row * block + rhs_col;
+            let k_rhs = k_start + rhs_row;
+            let col = CUBE_POS_Y * block + rhs_col;
+            let rhs_index = offset_rhs
+                + k_rhs * rhs.stride(rank - UInt::new(2))
+                + col * rhs.stride(rank - UInt::new(1));
+
+            if Comptime::get(check_rhs_bounds) {
+                let in_bounds = k_rhs < k && col < n_cols;
+                let value = rhs[cube_select(in_bounds, rhs_index, UInt::new(0))];
+                rhs_blocks[rhs_block_index] = cube_select(in_bounds, value, F::new(0.));
+            } else {
+                rhs_blocks[rhs_block_index] = rhs[rhs_index];
+            }
+        }
+    }
+}
+
+/// Accumulate the products of the rows of the tile of the unit in the lhs block and of its
+/// columns in the rhs block, both at `buffer_offset`.
+#[cube]
+fn compute_blocks<F: Float, A: Float>(
+    lhs_blocks: &SharedMemory<F>,
+    rhs_blocks: &SharedMemory<F>,
+    results: &mut Array<A>,
+    buffer_offset: UInt,
+    config: Comptime<CubeTiling2dConfig>,
+) {
+    let block_size = Comptime::map(config, |c: CubeTiling2dConfig| c.block_size);
+    let tile_size = Comptime::map(config, |c: CubeTiling2dConfig| c.tile_size);
+    let unroll = Comptime::map(config, |c: CubeTiling2dConfig| c.unroll);
+    let block = Comptime::runtime(block_size);
+    let tile = Comptime::runtime(tile_size);
+
+    for i in range(0u32, Comptime::get(block_size), unroll) {
+        for r in range(0u32, Comptime::get(tile_size), Comptime::new(true)) {
+            let lhs_value =
+                A::cast_from(lhs_blocks[buffer_offset + (UNIT_POS_X * tile + r) * block + i]);
+
+            for c in range(0u32, Comptime::get(tile_size), Comptime::new(true)) {
+                let rhs_value =
+                    A::cast_from(rhs_blocks[buffer_offset + i * block + UNIT_POS_Y * tile + c]);
+
+                results[r * tile + c] = fma(lhs_value, rhs_value, results[r * tile + c]);
+            }
+        }
+    }
+}
+
+/// Write the tile of the unit to the output, following its strides, each chunk only being
+/// written when it is within the output.
+#[cube]
+fn write_results<F: Float, A: Float>(
+    out: &mut Tensor<F>,
+    results: &Array<A>,
+    bias: &Tensor<F>,
+    assertions: &mut Tensor<UInt>,
+    offset_out: UInt,
+    config: Comptime<CubeTiling2dConfig>,
+) {
+    let tile_size = Comptime::map(config, |c: CubeTiling2dConfig| c.tile_size);
+    let vectorization = Comptime::map(config, |c: CubeTiling2dConfig| c.vectorization);
+    let num_chunks = Comptime::map(config, |c: CubeTiling2dConfig| {
+        c.tile_size / c.vectorization
+    });
+    let tile = Comptime::runtime(tile_size);
+    let vec = Comptime::runtime(vectorization);
+    let block = Comptime::runtime(Comptime::map(config, |c: CubeTiling2dConfig| c.block_size));
+
+    let rank = out.rank();
+    let n_rows = out.shape(rank - UInt::new(2));
+    let n_cols = out.shape(rank - UInt::new(1));
+
+    for r in range(0u32, Comptime::get(tile_size), Comptime::new(true)) {
+        let row = CUBE_POS_X * block + UNIT_POS_X * tile + r;
+
+        // The chunks start at multiples of the vectorization, which divides the number of
+        // columns, so each chunk is either fully in the output or fully out of it.
+        for chunk in range(0u32, Comptime::get(num_chunks), Comptime::new(true)) {
+            let col = CUBE_POS_Y * block + UNIT_POS_Y * tile + chunk * vec;
+            let index = offset_out
+                + row * out.stride(rank - UInt::new(2))
+                + col * out.stride(rank - UInt::new(1));
+            let position = r * tile + chunk * vec;
+            let in_bounds = row < n_rows && col < n_cols;
+
+            write_chunk(
+                out, results, bias, assertions, index, position, col, in_bounds, config,
+            );
+        }
+    }
+}
+
+/// Write the chunk of the tile starting at `position` to the output at `index`, in a vector when
+/// the output is vectorized. With accumulation, the chunk is added to the previous values of the
+/// output, read with the same vectorization. The [epilogue](apply_epilogue) is then applied to
+/// each element, with the bias of the column `col`, read with the same vectorization too.
+///
+/// When the bounds of the output are checked, the chunks out of it read the first element of the
+/// output and of the bias instead, [selected](cube_select) without branching, so only their store
+/// is masked. With the assertions enabled, writing out of the output flags the failure of the unit
+/// instead.
+#[cube]
+#[allow(clippy::too_many_arguments)]
+fn write_chunk<F: Float, A: Float>(
+    out: &mut Tensor<F>,
+    results: &Array<A>,
+    bias: &Tensor<F>,
+    assertions: &mut Tensor<UInt>,
+    index: UInt,
+    position: UInt,
+    col: UInt,
+    in_bounds: bool,
+    config: Comptime<CubeTiling2dConfig>,
+) {
+    let check_out_bounds = Comptime::map(config, |c: CubeTiling2dConfig| {
+        c.check_m_bounds || c.check_n_bounds
+    });
+    let accumulate = Comptime::map(config, |c: CubeTiling2dConfig| c.accumulate);
+    let has_bias = Comptime::map(config, |c: CubeTiling2dConfig| c.epilogue.has_bias);
+    let vectorization = Comptime::map(config, |c: CubeTiling2dConfig| c.vectorization);
+    let vectorized = Comptime::map(config, |c: CubeTiling2dConfig| {
+        c.vectorization != UInt::new(1)
+    });
+    let vec = Comptime::runtime(vectorization);
+
+    // The chunks start at multiples of the vectorization, so the vector index is exact.
+    let mut out_index = index / vec;
+    let mut bias_index = col / vec;
+    if Comptime::get(check_out_bounds) {
+        out_index = cube_select(in_bounds, out_index, UInt::new(0));
+        bias_index = cube_select(in_bounds, bias_index, UInt::new(0));
+    }
+
+    if Comptime::get(vectorized) {
+        let mut value = F::vectorized(0., Comptime::get(vectorization));
+        if Comptime::get(accumulate) {
+            value = out[out_index];
+        }
+
+        let mut bias_value = F::vectorized(0., Comptime::get(vectorization));
+        if Comptime::get(has_bias) {
+            bias_value = bias[bias_index];
+        }
+
+        for j in range(0u32, Comptime::get(vectorization), Comptime::new(true)) {
+            value[j] = apply_epilogue(
+                value[j] + F::cast_from(results[position + j]),
+                bias_value[j],
+                config,
+            );
+        }
+
+        store_chunk(out, assertions, out_index, value, in_bounds, config);
+    } else {
+        let mut value = F::cast_from(results[position]);
+        if Comptime::get(accumulate) {
+            value += out[out_index];
+        }
+
+        let mut bias_value = F::new(0.);
+        if Comptime::get(has_bias) {
+            bias_value = bias[bias_index];
+        }
+        value = apply_epilogue(value, bias_value, config);
+
+        store_chunk(out, assertions, out_index, value, in_bounds, config);
+    }
+}
+
+/// Add the bias to the element and apply the activation of the
+/// [epilogue](Tiling2dEpilogue), the element being returned as is without any.
+#[cube]
+fn apply_epilogue<F: Float>(value: F, bias: F, config: Comptime<CubeTiling2dConfig>) -> F {
+    let has_bias = Comptime::map(config, |c: CubeTiling2dConfig| c.epilogue.has_bias);
+    let is_relu = Comptime::map(config, |c: CubeTiling2dConfig| {
+        c.epilogue.activation == Some(EpilogueActivation::Relu)
+    });
+    let is_gelu = Comptime::map(config, |c: CubeTiling2dConfig| {
+        c.epilogue.activation == Some(EpilogueActivation::Gelu)
+    });
+    let is_tanh = Comptime::map(config, |c: CubeTiling2dConfig| {
+        c.epilogue.activation == Some(EpilogueActivation::Tanh)
+    });
+
+    let mut output = value;
+
+    if Comptime::get(has_bias) {
+        output += bias;
+    }
+
+    if Comptime::get(is_relu) {
+        output = cube_select(output < F::new(0.), F::new(0.), output);
+    }
+
+    if Comptime::get(is_gelu) {
+        output = output * (F::erf(output * F::new(FRAC_1_SQRT_2)) + F::new(1.)) / F::new(2.);
+    }
+
+    if Comptime::get(is_tanh) {
+        output = F::tanh(output);
+    }
+
+    output
+}
+
+/// Store the value of a chunk at the index of the output, masked by the bounds of the output
+/// when they are checked.
+#[cube]
+fn store_chunk<F: Float>(
+    out: &mut Tensor<F>,
+    assertions: &mut Tensor<UInt>,
+    out_index: UInt,
+    value: F,
+    in_bounds: bool,
+    config: Comptime<CubeTiling2dConfig>,
+) {
+    let check_out_bounds = Comptime::map(config, |c: CubeTiling2dConfig| {
+        c.check_m_bounds || c.check_n_bounds
+    });
+    let assertions_enabled = Comptime::map(config, |c: CubeTiling2dConfig| c.assertions);
+
+    if Comptime::get(check_out_bounds) {
+        if in_bounds {
+            cube_assert(out_index < out.len(), assertions, assertions_enabled);
+            out[out_index] = value;
+        }
+    } else {
+        cube_assert(out_index < out.len(), assertions, assertions_enabled);
+        out[out_index] = value;
+    }
+}
+
+/// Sum the partial sums of the slices of the inner dimension into the output, following its
+/// strides. With accumulation, the sum is added to the previous value of the output.
+#[cube(launch)]
+fn reduce_k_splits_kernel<F: Float, A: Float>(
+    partials: &Tensor<F>,
+    out: &mut Tensor<F>,
+    num_splits: UInt,
+    accumulate: Comptime<bool>,
+) {
+    // The partial sums of each slice are laid out like a contiguous output.
+    let num_elements = partials.stride(0) * partials.shape(0);
+    if ABSOLUTE_POS >= num_elements {
+        return;
+    }
+
+    let mut index_out = UInt::new(0);
+    for dim in range(0u32, out.rank(), Comptime::new(false)) {
+        index_out += ABSOLUTE_POS / partials.stride(dim) % partials.shape(dim) * out.stride(dim);
+    }
+
+    let mut sum = A::new(0.);
+    for split in range(0u32, num_splits, Comptime::new(false)) {
+        sum += A::cast_from(partials[split * num_elements + ABSOLUTE_POS]);
+    }
+
+    if Comptime::get(accumulate) {
+        sum += A::cast_from(out[index_out]);
+    }
+
+    out[index_out] = F::cast_from(sum);
+}
+
+/// The tile size of the config, by default the largest dividing the block size up to 4 elements
+/// along each dimension.
+fn tile_size(config: &Tiling2dConfig) -> usize {
+    config.tile_size.unwrap_or_else(|| {
+        [4, 2]
+            .into_iter()
+            .find(|tile_size| config.block_size % tile_size == 0)
+            .unwrap_or(1)
+    })
+}
+
+/// The largest number of elements of a row of a tile, up to 4, that can be written at once to
+/// the output, its columns being contiguous and every row starting at a multiple of it.
+fn out_vectorization<R: JitRuntime, E: FloatElement, const D: usize>(
+    out: &JitTensor<R, E, D>,
+    tile_size: usize,
+) -> usize {
+    if out.strides[D - 1] != 1 {
+        return 1;
+    }
+
+    [4, 2]
+        .into_iter()
+        .find(|vectorization| {
+            tile_size % vectorization == 0
+                && out.shape.dims[D - 1] % vectorization == 0
+                && out.strides[0..D - 1]
+                    .iter()
+                    .all(|stride| stride % vectorization == 0)
+        })
+        .unwrap_or(1)
+}
+
+/// Whether the columns of the matrices of the tensor are contiguous instead of their rows, like
+/// the ones of a row major tensor with its last two dimensions swapped.
+///
+/// The blocks of a transposed input are loaded along its columns by the
+/// [tiling 2D](matmul_tiling_2d) kernel, which reads it with its strides instead of copying it to
+/// a row major layout.
+pub fn is_transposed<R: JitRuntime, E: FloatElement, const D: usize>(
+    tensor: &JitTensor<R, E, D>,
+) -> bool {
+    matches!(
+        tensor.matrix_layout(),
+        MatrixLayout::MildlyPermuted {
+            transposed: true,
+            ..
+        }
+    )
+}
+
+/// Matrix multiplication using shared memory blocks, supporting broadcasted batch dimensions and
+/// any layout of the inputs and of the output.
+///
+/// Each unit computes a [tile](Tiling2dConfig::tile_size) of the output, written in vectors of up
+/// to 4 elements when the layout of the output allows it. The sums are accumulated with the
+/// [precision](AccumulatorPrecision::of) of the element type.
+///
+/// The [aligned](Tiling2dPath::Aligned) specialization is launched when the shapes and the layout
+/// of the output allow it. The [transposed](is_transposed) inputs are read along their columns. The
+/// inner dimension is [split](Tiling2dConfig::num_k_splits) when it is much larger than the output.
+///
+/// # Panics
+///
+/// If a [kernel config](CubeTiling2dConfig::from_shapes) can't be built for the shapes.
+pub fn matmul_tiling_2d<R: JitRuntime, E: FloatElement, const D: usize>(
+    lhs: JitTensor<R, E, D>,
+    rhs: JitTensor<R, E, D>,
+    out: JitTensor<R, E, D>,
+    config: Tiling2dConfig,
+) -> JitTensor<R, E, D> {
+    launch_tiling_2d(lhs, rhs, out, config, false)
+}
+
+/// Matrix multiplication accumulated into the output, `out += lhs @ rhs`, with the
+/// [tiling 2D](matmul_tiling_2d) kernel.
+///
+/// The output must have the shape of the matmul output, with any strides, and it is copied first
+/// when it can't be mutated. Each chunk of the tiles is read from the output and written back with
+/// the same vectorization and bound checks as the overwriting kernel, so the accumulation is done
+/// in the element type of the output.
+///
+/// # Panics
+///
+/// If the output doesn't have the shape of the matmul output, or if a
+/// [kernel config](CubeTiling2dConfig::from_shapes) can't be built for the shapes.
+pub fn matmul_accumulate<R: JitRuntime, E: FloatElement, const D: usize>(
+    lhs: JitTensor<R, E, D>,
+    rhs: JitTensor<R, E, D>,
+    out: JitTensor<R, E, D>,
+    config: Tiling2dConfig,
+) -> JitTensor<R, E, D> {
+    assert_eq!(
+        out.shape,
+        shape_out(&lhs, &rhs),
+        "The output must have the shape of the matmul output"
+    );
+
+    let out = match out.can_mut() {
+        true => out,
+        false => out.copy(),
+    };
+
+    launch_tiling_2d(lhs, rhs, out, config, true)
+}
+
+/// Matrix multiplication followed by a bias addition and an activation,
+/// `activation(lhs @ rhs + bias)`, with the [tiling 2D](matmul_tiling_2d) kernel.
+///
+/// The [epilogue](Tiling2dEpilogue) is applied by each unit to its chunks before they are stored,
+/// so no elementwise kernel is launched on the output. The bias, when provided, is added to each
+/// row and must have the number of columns of the output. The inner dimension is never split,
+/// since the activation can't be applied to the partial sums of its slices.
+///
+/// # Panics
+///
+/// If the bias doesn't have the number of columns of the output, or if a
+/// [kernel config](CubeTiling2dConfig::from_shapes) can't be built for the shapes.
+pub fn matmul_tiling_2d_epilogue<R: JitRuntime, E: FloatElement, const D: usize>(
+    lhs: JitTensor<R, E, D>,
+    rhs: JitTensor<R, E, D>,
+    out: JitTensor<R, E, D>,
+    bias: Option<JitTensor<R, E, 1>>,
+    activation: Option<EpilogueActivation>,
+    config: Tiling2dConfig,
+) -> JitTensor<R, E, D> {
+    assert_launchable(&lhs, &rhs, &config);
+
+    let bias = bias.map(|bias| {
+        assert_eq!(
+            bias.shape.dims[0],
+            out.shape.dims[D - 1],
+            "The bias must have the same size as the last dimension of the output"
+        );
+
+        into_contiguous(bias)
+    });
+
+    let epilogue = Epilogue {
+        bias: bias.as_ref(),
+        activation,
+    };
+    launch_kernel(&lhs, &rhs, &out, config, false, None, epilogue);
+
+    out
+}
+
+/// Matrix multiplication with the [tiling 2D](matmul_tiling_2d) kernel, the inner dimension being
+/// split in `num_splits` slices of whole blocks, whatever the
+/// [split ratio](Tiling2dConfig::split_k_ratio) of the config.
+///
+/// The cubes of each slice write their partial sums to a temporary buffer, reduced into the output
+/// by a second kernel with the [precision](AccumulatorPrecision::of) of the element type. The
+/// slices have the same number of blocks except the last, so there may be fewer of them than
+/// requested, a single slice being computed in one pass.
+///
+/// # Panics
+///
+/// If a [kernel config](CubeTiling2dConfig::from_shapes) can't be built for the shapes.
+pub fn matmul_tiling_2d_split_k<R: JitRuntime, E: FloatElement, const D: usize>(
+    lhs: JitTensor<R, E, D>,
+    rhs: JitTensor<R, E, D>,
+    out: JitTensor<R, E, D>,
+    config: Tiling2dConfig,
+    num_splits: usize,
+) -> JitTensor<R, E, D> {
+    launch_tiling_2d_split_k(lhs, rhs, out, config, false, num_splits)
+}
+
+fn launch_tiling_2d<R: JitRuntime, E: FloatElement, const D: usize>(
+    lhs: JitTensor<R, E, D>,
+    rhs: JitTensor<R, E, D>,
+    out: JitTensor<R, E, D>,
+    config: Tiling2dConfig,
+    accumulate: bool,
+) -> JitTensor<R, E, D> {
+    let num_splits = config.num_k_splits([
+        out.shape.dims[D - 2],
+        lhs.shape.dims[D - 1],
+        out.shape.dims[D - 1],
+    ]);
+
+    launch_tiling_2d_split_k(lhs, rhs, out, config, accumulate, num_splits)
+}
+
+fn launch_tiling_2d_split_k<R: JitRuntime, E: FloatElement, const D: usize>(
+    lhs: JitTensor<R, E, D>,
+    rhs: JitTensor<R, E, D>,
+    out: JitTensor<R, E, D>,
+    config: Tiling2dConfig,
+    accumulate: bool,
+    num_splits: usize,
+) -> JitTensor<R, E, D> {
+    assert_launchable(&lhs, &rhs, &config);
+
+    let num_blocks = lhs.shape.dims[D - 1].div_ceil(config.block_size.max(1));
+    let (num_splits, blocks_per_split) = split_blocks(num_blocks, num_splits);
+    let num_elements = out.shape.num_elements();
+
+    // The partial sums of all the slices are indexed with 32 bit integers too.
+    if num_splits <= 1 || num_splits * num_elements > u32::MAX as usize {
+        launch_kernel(&lhs, &rhs, &out, config, accumulate, None, Epilogue::none());
+        return out;
+    }
+
+    let num_bytes = num_splits * num_elements * core::mem::size_of::<E>();
+    crate::perf::record_allocation(num_bytes);
+    let partials = JitTensor::new_contiguous(
+        out.client.clone(),
+        out.device.clone(),
+        out.shape.clone(),
+        out.client.empty(num_bytes),
+    );
+
+    let split = KSplit {
+        num_splits,
+        size: blocks_per_split * config.block_size,
+    };
+    launch_kernel(
+        &lhs,
+        &rhs,
+        &partials,
+        config,
+        false,
+        Some(split),
+        Epilogue::none(),
+    );
+
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(num_elements, cube_dim);
+
+    crate::perf::record_kernel();
+
+    match AccumulatorPrecision::of::<E>() {
+        AccumulatorPrecision::Input => {
+            reduce_k_splits_kernel::launch::<E::FloatPrimitive, E::FloatPrimitive, R>(
+                &out.client,
+                cube_count,
+                cube_dim,
+                partials.as_tensor_arg(1),
+                out.as_tensor_arg(1),
+                ScalarArg::new(num_splits as u32),
+                accumulate,
+            )
+        }
+        AccumulatorPrecision::F32 => reduce_k_splits_kernel::launch::<E::FloatPrimitive, F32, R>(
+            &out.client,
+            cube_count,
+            cube_dim,
+            partials.as_tensor_arg(1),
+            out.as_tensor_arg(1),
+            ScalarArg::new(num_splits as u32),
+            accumulate,
+        ),
+    }
+
+    out
+}
+
+/// Whether the [tiling 2D](matmul_tiling_2d) kernel can be launched with the config on the
+/// device: the element type is supported and the blocks fit in the shared memory of the device.
+pub fn is_tiling_2d_supported<R: JitRuntime, E: FloatElement>(
+    config: &Tiling2dConfig,
+    device: &R::Device,
+) -> bool {
+    is_float_supported::<R, E>(device)
+        && config
+            .check_shared_memory_size::<E>(
+                crate::device_properties::<R>(device).max_shared_memory_size,
+            )
+            .is_ok()
+}
+
+/// Check that the inputs are on the same device, which supports their element type and the
+/// shared memory of the config.
+fn assert_launchable<R: JitRuntime, E: FloatElement, const D: usize>(
+    lhs: &JitTensor<R, E, D>,
+    rhs: &JitTensor<R, E, D>,
+    config: &Tiling2dConfig,
+) {
+    lhs.assert_is_on_same_device(rhs);
+    assert_float_supported::<R, E>(&lhs.device);
+    config
+        .check_shared_memory_size::<E>(
+            crate::device_properties::<R>(&lhs.device).max_shared_memory_size,
+        )
+        .unwrap_or_else(|err| panic!("{err}"));
+}
+
+/// The bias and the activation of the [epilogue](Tiling2dEpilogue) of a launch.
+struct Epilogue<'a, R: JitRuntime, E: FloatElement> {
+    bias: Option<&'a JitTensor<R, E, 1>>,
+    activation: Option<EpilogueActivation>,
+}
+
+impl<R: JitRuntime, E: FloatElement> Epilogue<'_, R, E> {
+    fn none() -> Self {
+        Self {
+            bias: None,
+            activation: None,
+        }
+    }
+}
+
+/// The slices of the inner dimension summed by different cubes.
+#[derive(Debug, Clone, Copy)]
+struct KSplit {
+    num_splits: usize,
+    /// The number of elements of the inner dimension of each slice, a multiple of the block size.
+    size: usize,
+}
+
+/// Launch the tiling 2D kernel writing into `out`, which holds the partial sums of every slice one
+/// after the other when the inner dimension is split.
+fn launch_kernel<R: JitRuntime, E: FloatElement, const D: usize>(
+    lhs: &JitTensor<R, E, D>,
+    rhs: &JitTensor<R, E, D>,
+    out: &JitTensor<R, E, D>,
+    config: Tiling2dConfig,
+    accumulate: bool,
+    split: Option<KSplit>,
+    epilogue: Epilogue<'_, R, E>,
+) {
+    let block_size = config.block_size;
+    let tile_size = tile_size(&config);
+    let vectorization = out_vectorization(out, tile_size);
+    let num_rows = out.shape.dims[D - 2];
+    let num_cols = out.shape.dims[D - 1];
+    let path = Tiling2dPath::of(
+        block_size,
+        vectorization,
+        [num_rows, lhs.shape.dims[D - 1], num_cols],
+    );
+
+    let kernel_config = match path {
+        Tiling2dPath::Aligned => {
+            CubeTiling2dConfig::aligned(&config, &lhs.shape, &rhs.shape, &out.shape, tile_size)
+        }
+        Tiling2dPath::Checked => CubeTiling2dConfig::from_shapes(
+            &config,
+            &lhs.shape,
+            &rhs.shape,
+            &out.shape,
+            tile_size,
+            vectorization,
+        ),
+    }
+    .map(|kernel_config| {
+        CubeTiling2dConfig {
+            accumulate,
+            split_k: split.is_some(),
+            assertions: KernelAssertions::<R>::enabled(),
+            epilogue: Tiling2dEpilogue {
+                has_bias: epilogue.bias.is_some(),
+                activation: epilogue.activation,
+            },
+            ..kernel_config
+        }
+        .with_transposed(is_transposed(lhs), is_transposed(rhs))
+    })
+    .unwrap_or_else(|err| panic!("{err}"));
+
+    let num_batches = out.shape.dims[0..D - 2].iter().product::<usize>();
+    let (num_splits, k_split_size) = split.map_or((1, 0), |split| (split.num_splits, split.size));
+
+    let units = (block_size / tile_size) as u32;
+    let cube_dim = CubeDim::new(units, units, 1);
+    let cube_count = CubeCount::Static(
+        f32::ceil(num_rows as f32 / block_size as f32) as u32,
+        f32::ceil(num_cols as f32 / block_size as f32) as u32,
+        (num_batches * num_splits) as u32,
+    );
+    let num_batch_dims = Some(UInt::new(D as u32 - 2));
+    let assertions = KernelAssertions::<R>::of(out);
+    // The bias binding is never read when there is no bias.
+    let bias = match epilogue.bias {
+        Some(bias) => bias.as_tensor_arg(vectorization as u8),
+        None => lhs.as_tensor_arg(1),
+    };
+
+    crate::perf::record_kernel();
+    // The comptime arguments of the launch, from which the cube macro derives the id of the kernel.
+    crate::perf::record_kernel_id(|| {
+        KernelId::new::<(E, R)>().info((
+            kernel_config,
+            num_batch_dims,
+            vectorization,
+            cube_dim.x,
+            cube_dim.y,
+        ))
+    });
+    crate::perf::record_tiling_2d_path(kernel_config.path());
+
+    match AccumulatorPrecision::of::<E>() {
+        AccumulatorPrecision::Input => {
+            matmul_tiling_2d_kernel::launch::<E::FloatPrimitive, E::FloatPrimitive, R>(
+                &lhs.client,
+                cube_count,
+                cube_dim,
+                lhs.as_tensor_arg(1),
+                rhs.as_tensor_arg(1),
+                bias,
+                out.as_tensor_arg(vectorization as u8),
+                assertions.as_tensor_arg(),
+                ScalarArg::new(k_split_size as u32),
+                num_batch_dims,
+                kernel_config,
+            )
+        }
+        AccumulatorPrecision::F32 => matmul_tiling_2d_kernel::launch::<E::FloatPrimitive, F32, R>(
+            &lhs.client,
+            cube_count,
+            cube_dim,
+            lhs.as_tensor_arg(1),
+            rhs.as_tensor_arg(1),
+            bias,
+            out.as_tensor_arg(vectorization as u8),
+            assertions.as_tensor_arg(),
+            ScalarArg::new(k_split_size as u32),
+            num_batch_dims,
+            kernel_config,
+        ),
+    }
+
+    // Only read in debug builds, the buffer isn't allocated otherwise.
+    assertions
+        .check()
+        .unwrap_or_else(|err| panic!("Tiling 2D: {err}"));
+}
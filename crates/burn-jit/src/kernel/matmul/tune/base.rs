@@ -1,11 +1,15 @@
-use burn_tensor::{Element, ElementConversion};
+use burn_tensor::{backend::DeviceProperties, Element, ElementConversion};
 use cubecl::tune::{local_tuner, AutotuneOperation, AutotuneOperationSet, LocalTuner};
 
 use crate::{
     element::FloatElement,
-    kernel::{matmul::utils::init_matmul_output, prng::random_like_uniform},
+    kernel::{
+        matmul::{is_cmma_available, utils::init_matmul_output},
+        prng::random_like_uniform,
+    },
     ops::numeric::empty_device,
     tensor::JitTensor,
+    tune_cache::execute_tuned,
     tune_key::JitAutotuneKey,
     JitRuntime, JitTuneId,
 };
@@ -29,6 +33,12 @@ impl<R: JitRuntime, E: FloatElement, const D: usize> MatmulAutotuneOperationSet<
             out,
         }
     }
+
+    /// The number of [autotunables](AutotuneOperationSet::autotunables), without creating them.
+    fn num_candidates(&self) -> usize {
+        5 + is_apple_gpu::<R>(&self.lhs.device) as usize
+            + is_cmma_available::<R, E>(&self.lhs.client, &self.lhs.device) as usize
+    }
 }
 
 impl<R: JitRuntime, E: FloatElement, const D: usize> AutotuneOperationSet<JitAutotuneKey>
@@ -49,7 +59,7 @@ impl<R: JitRuntime, E: FloatElement, const D: usize> AutotuneOperationSet<JitAut
             self.out.shape.clone(),
         );
 
-        vec![
+        let mut autotunables: Vec<Box<dyn AutotuneOperation>> = vec![
             Box::new(SimpleMatmul::new(lhs.clone(), rhs.clone(), out.clone())),
             Box::new(SimpleMatmul16x16::new(
                 lhs.clone(),
@@ -57,34 +67,83 @@ impl<R: JitRuntime, E: FloatElement, const D: usize> AutotuneOperationSet<JitAut
                 out.clone(),
             )),
             Box::new(MatmulCube::new(lhs.clone(), rhs.clone(), out.clone())),
-        ]
+            Box::new(MatmulTiling2d::new(lhs.clone(), rhs.clone(), out.clone())),
+            Box::new(MatmulTiling2dDoubleBuffered::new(
+                lhs.clone(),
+                rhs.clone(),
+                out.clone(),
+            )),
+        ];
+
+        if is_apple_gpu::<R>(&self.lhs.device) {
+            autotunables.push(Box::new(SimpleMatmul8x8::new(
+                lhs.clone(),
+                rhs.clone(),
+                out.clone(),
+            )));
+        }
+
+        if is_cmma_available::<R, E>(&self.lhs.client, &self.lhs.device) {
+            autotunables.push(Box::new(MatmulCmma::new(lhs, rhs, out)));
+        }
+
+        autotunables
     }
 
     fn fastest(self: Box<Self>, fastest_index: usize) -> Box<dyn AutotuneOperation> {
+        let is_apple_gpu = is_apple_gpu::<R>(&self.lhs.device);
+
         match fastest_index {
             0 => Box::new(SimpleMatmul::new(self.lhs, self.rhs, self.out)),
             1 => Box::new(SimpleMatmul16x16::new(self.lhs, self.rhs, self.out)),
             2 => Box::new(MatmulCube::new(self.lhs, self.rhs, self.out)),
+            3 => Box::new(MatmulTiling2d::new(self.lhs, self.rhs, self.out)),
+            4 => Box::new(MatmulTiling2dDoubleBuffered::new(
+                self.lhs, self.rhs, self.out,
+            )),
+            5 if is_apple_gpu => Box::new(SimpleMatmul8x8::new(self.lhs, self.rhs, self.out)),
+            // The cooperative matrix kernel is the last candidate, after the Apple one. A result
+            // tuned before the path was disabled still runs, on the fallback of the kernel.
+            index if index == 5 + is_apple_gpu as usize => {
+                Box::new(MatmulCmma::new(self.lhs, self.rhs, self.out))
+            }
             _ => panic!("Fastest index is out of bound"),
         }
     }
 }
 
+/// Whether the device is an Apple GPU, where smaller cube dimensions are also tried since the other
+/// candidates were tuned for desktop GPUs. Metal doesn't report the PCI vendor of its adapters, but
+/// names the Apple ones after their chip.
+fn is_apple_gpu<R: JitRuntime>(device: &R::Device) -> bool {
+    is_apple_adapter(&crate::device_properties::<R>(device))
+}
+
+fn is_apple_adapter(properties: &DeviceProperties) -> bool {
+    properties.vendor == "Apple" || properties.name.starts_with("Apple")
+}
+
 /// Executes autotune on matmul operations
 pub fn matmul_autotune<R: JitRuntime, E: FloatElement + Element, const D: usize>(
     lhs: JitTensor<R, E, D>,
     rhs: JitTensor<R, E, D>,
 ) -> JitTensor<R, E, D> {
     let client = lhs.client.clone();
+    let device = lhs.device.clone();
 
     let output = init_matmul_output(&lhs, &rhs);
 
     static TUNER: LocalTuner<JitAutotuneKey, JitTuneId> = local_tuner!();
 
-    TUNER.execute(
-        &JitTuneId::new::<R>(&lhs.device),
+    let operation_set = MatmulAutotuneOperationSet::new(lhs, rhs, output.clone());
+    let num_candidates = operation_set.num_candidates();
+
+    execute_tuned::<R>(
+        &TUNER,
+        &device,
         &client,
-        Box::new(MatmulAutotuneOperationSet::new(lhs, rhs, output.clone())),
+        Box::new(operation_set),
+        num_candidates,
     );
 
     output
@@ -127,15 +186,62 @@ matmul_tune_ops!(SimpleMatmul16x16, |lhs, rhs, out| {
     crate::kernel::matmul::matmul_simple(lhs, rhs, out, 16, 16)
 });
 
+// Potentially better for small matrices on Apple GPUs.
+matmul_tune_ops!(SimpleMatmul8x8, |lhs, rhs, out| {
+    crate::kernel::matmul::matmul_simple(lhs, rhs, out, 8, 8)
+});
+
 // Probably the fastest in the general case, without loop unrolling
-matmul_tune_ops!(
-    MatmulCube,
-    |lhs: JitTensor<R, E, D>, rhs: JitTensor<R, E, D>, out: JitTensor<R, E, D>| {
-        cubecl::linalg::matmul::launch_ref::<R, E::Primitive>(
-            &lhs.client,
-            lhs.as_handle_ref(),
-            rhs.as_handle_ref(),
-            out.as_handle_ref(),
-        );
+matmul_tune_ops!(MatmulCube, crate::kernel::matmul::matmul_cube);
+
+// Potentially better for small matrices, with few blocks along the inner dimension.
+matmul_tune_ops!(MatmulTiling2d, |lhs, rhs, out| {
+    let config = crate::kernel::matmul::Tiling2dConfig {
+        double_buffering: false,
+        ..Default::default()
+    };
+    crate::kernel::matmul::matmul_tiling_2d(lhs, rhs, out, config)
+});
+
+// Much faster on the tensor cores, only a candidate when they are available for the element type.
+matmul_tune_ops!(MatmulCmma, crate::kernel::matmul::matmul_cmma);
+
+// Hides the latency of the loads when the inner dimension spans many blocks.
+matmul_tune_ops!(MatmulTiling2dDoubleBuffered, |lhs, rhs, out| {
+    crate::kernel::matmul::matmul_tiling_2d(lhs, rhs, out, Default::default())
+});
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn properties(vendor: &str, name: &str) -> DeviceProperties {
+        DeviceProperties {
+            name: name.to_string(),
+            vendor: vendor.to_string(),
+            ..Default::default()
+        }
     }
-);
+
+    #[test]
+    fn apple_adapters_should_be_detected_by_vendor_or_chip_name() {
+        assert!(is_apple_adapter(&properties("Apple", "Apple M1 Pro")));
+        // Metal doesn't report the PCI vendor of the Apple adapters.
+        assert!(is_apple_adapter(&properties("0x0000", "Apple M2")));
+        assert!(is_apple_adapter(&properties("Apple", "")));
+    }
+
+    #[test]
+    fn other_adapters_of_macs_should_not_be_apple_gpus() {
+        // The discrete and integrated GPUs of the Intel Macs.
+        assert!(!is_apple_adapter(&properties(
+            "AMD",
+            "AMD Radeon Pro 5500M"
+        )));
+        assert!(!is_apple_adapter(&properties(
+            "Intel",
+            "Intel(R) UHD Graphics 630"
+        )));
+        assert!(!is_apple_adapter(&properties("0x0000", "")));
+    }
+}
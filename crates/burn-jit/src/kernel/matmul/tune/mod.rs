@@ -1,7 +1,11 @@
 #[cfg(feature = "autotune")]
 mod base;
 mod key;
+#[cfg(feature = "autotune")]
+mod tiling2d;
 
 #[cfg(feature = "autotune")]
 pub use base::*;
 pub use key::*;
+#[cfg(feature = "autotune")]
+pub use tiling2d::*;
@@ -0,0 +1,345 @@
+use burn_tensor::{Element, ElementConversion};
+use cubecl::tune::{local_tuner, AutotuneOperation, AutotuneOperationSet, LocalTuner};
+
+use crate::{
+    element::FloatElement,
+    kernel::{
+        matmul::{
+            matmul_accumulate, matmul_tiling_2d, matmul_tiling_2d_epilogue,
+            utils::init_matmul_output, EpilogueActivation, Tiling2dConfig, MAX_UNITS_PER_CUBE,
+        },
+        prng::random_like_uniform,
+    },
+    ops::numeric::empty_device,
+    tensor::JitTensor,
+    tune_cache::execute_tuned,
+    tune_key::JitAutotuneKey,
+    JitRuntime, JitTuneId,
+};
+
+use super::key::MatmulAutotuneKey;
+
+/// The block sizes of the [candidates](tiling_2d_candidates).
+const CANDIDATE_BLOCK_SIZES: [usize; 3] = [32, 64, 128];
+/// The tile sizes of the [candidates](tiling_2d_candidates).
+const CANDIDATE_TILE_SIZES: [usize; 3] = [1, 2, 4];
+/// The shared memory assumed for the devices that don't report it, the minimum a WebGPU device
+/// provides to a workgroup.
+const DEFAULT_SHARED_MEMORY_SIZE: u32 = 16384;
+
+/// The [tiling 2D](matmul_tiling_2d) configs tuned on the device, the block sizes 32, 64 and 128
+/// each with the tile sizes 1, 2 and 4 whose cubes have a valid number of units and whose blocks
+/// fit in the shared memory of the device.
+///
+/// The candidates only depend on the device, so the index of the fastest one is the same in every
+/// process using it.
+pub fn tiling_2d_candidates<R: JitRuntime, E: FloatElement>(
+    device: &R::Device,
+) -> Vec<Tiling2dConfig> {
+    let max_shared_memory_size = crate::device_properties::<R>(device)
+        .max_shared_memory_size
+        .unwrap_or(DEFAULT_SHARED_MEMORY_SIZE);
+
+    CANDIDATE_BLOCK_SIZES
+        .into_iter()
+        .flat_map(|block_size| {
+            CANDIDATE_TILE_SIZES
+                .into_iter()
+                .map(move |tile_size| Tiling2dConfig {
+                    block_size,
+                    tile_size: Some(tile_size),
+                    ..Default::default()
+                })
+        })
+        .filter(|config| {
+            let units = config.block_size / config.tile_size.unwrap();
+
+            units * units <= MAX_UNITS_PER_CUBE
+                && config
+                    .check_shared_memory_size::<E>(Some(max_shared_memory_size))
+                    .is_ok()
+        })
+        .collect()
+}
+
+/// Set of [tiling 2D](matmul_tiling_2d) configs available for autotune, the
+/// [candidates](tiling_2d_candidates) of the device.
+/// Autotune key is given by concatenating the closest upper power of 2 of m, k and n
+///
+/// The [accumulating](matmul_accumulate) matmuls share the key of the overwriting ones, since
+/// reading the output back doesn't change which blocks are the fastest. The
+/// [epilogue](matmul_tiling_2d_epilogue) matmuls have their own key, since they never split the
+/// inner dimension.
+pub struct Tiling2dAutotuneOperationSet<R: JitRuntime, E: FloatElement, const D: usize> {
+    key: JitAutotuneKey,
+    lhs: JitTensor<R, E, D>,
+    rhs: JitTensor<R, E, D>,
+    out: JitTensor<R, E, D>,
+    candidates: Vec<Tiling2dConfig>,
+    mode: Tiling2dMode<R, E>,
+}
+
+/// How the tuned matmul writes its output.
+enum Tiling2dMode<R: JitRuntime, E: FloatElement> {
+    Overwrite,
+    Accumulate,
+    Epilogue {
+        bias: Option<JitTensor<R, E, 1>>,
+        activation: Option<EpilogueActivation>,
+    },
+}
+
+impl<R: JitRuntime, E: FloatElement> Clone for Tiling2dMode<R, E> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Overwrite => Self::Overwrite,
+            Self::Accumulate => Self::Accumulate,
+            Self::Epilogue { bias, activation } => Self::Epilogue {
+                bias: bias.clone(),
+                activation: *activation,
+            },
+        }
+    }
+}
+
+impl<R: JitRuntime, E: FloatElement, const D: usize> Tiling2dAutotuneOperationSet<R, E, D> {
+    fn new(
+        lhs: JitTensor<R, E, D>,
+        rhs: JitTensor<R, E, D>,
+        out: JitTensor<R, E, D>,
+        candidates: Vec<Tiling2dConfig>,
+        mode: Tiling2dMode<R, E>,
+    ) -> Self {
+        let key = MatmulAutotuneKey::new(&lhs.shape, &rhs.shape);
+        let key = match mode {
+            Tiling2dMode::Epilogue { .. } => JitAutotuneKey::Tiling2dEpilogue(key),
+            _ => JitAutotuneKey::Tiling2d(key),
+        };
+
+        Self {
+            key,
+            candidates,
+            lhs,
+            rhs,
+            out,
+            mode,
+        }
+    }
+}
+
+impl<R: JitRuntime, E: FloatElement, const D: usize> AutotuneOperationSet<JitAutotuneKey>
+    for Tiling2dAutotuneOperationSet<R, E, D>
+{
+    fn key(&self) -> JitAutotuneKey {
+        self.key.clone()
+    }
+
+    fn autotunables(&self) -> Vec<Box<dyn AutotuneOperation>> {
+        let random_bounds: (E, E) = ((-10.0).elem::<E>(), (10.0).elem::<E>());
+        let lhs = random_like_uniform(&self.lhs, random_bounds.0, random_bounds.1);
+        let rhs = random_like_uniform(&self.rhs, random_bounds.0, random_bounds.1);
+
+        let out = empty_device(
+            self.out.client.clone(),
+            self.out.device.clone(),
+            self.out.shape.clone(),
+        );
+
+        self.candidates
+            .iter()
+            .map(|config| {
+                Box::new(MatmulTiling2dConfig::new(
+                    lhs.clone(),
+                    rhs.clone(),
+                    out.clone(),
+                    *config,
+                    self.mode.clone(),
+                )) as Box<dyn AutotuneOperation>
+            })
+            .collect()
+    }
+
+    fn fastest(self: Box<Self>, fastest_index: usize) -> Box<dyn AutotuneOperation> {
+        let config = self
+            .candidates
+            .get(fastest_index)
+            .copied()
+            .expect("Fastest index is out of bound");
+
+        Box::new(MatmulTiling2dConfig::new(
+            self.lhs, self.rhs, self.out, config, self.mode,
+        ))
+    }
+}
+
+/// Executes autotune on the configs of the tiling 2D matmul
+pub fn matmul_tiling_2d_autotune<R: JitRuntime, E: FloatElement + Element, const D: usize>(
+    lhs: JitTensor<R, E, D>,
+    rhs: JitTensor<R, E, D>,
+) -> JitTensor<R, E, D> {
+    let client = lhs.client.clone();
+    let device = lhs.device.clone();
+
+    let output = init_matmul_output(&lhs, &rhs);
+    let candidates = tiling_2d_candidates::<R, E>(&device);
+
+    execute_tiling_2d_tuned(
+        Tiling2dAutotuneOperationSet::new(
+            lhs,
+            rhs,
+            output.clone(),
+            candidates,
+            Tiling2dMode::Overwrite,
+        ),
+        &device,
+        &client,
+    );
+
+    output
+}
+
+/// Executes autotune on the configs of the tiling 2D matmul accumulated into the output,
+/// `out += lhs @ rhs`.
+///
+/// The output is copied first when it can't be mutated, like with [matmul_accumulate].
+///
+/// # Panics
+///
+/// If the device has no [candidate](tiling_2d_candidates).
+pub fn matmul_accumulate_tiling_2d_autotune<
+    R: JitRuntime,
+    E: FloatElement + Element,
+    const D: usize,
+>(
+    lhs: JitTensor<R, E, D>,
+    rhs: JitTensor<R, E, D>,
+    out: JitTensor<R, E, D>,
+) -> JitTensor<R, E, D> {
+    let client = lhs.client.clone();
+    let device = lhs.device.clone();
+
+    let candidates = tiling_2d_candidates::<R, E>(&device);
+    assert!(
+        !candidates.is_empty(),
+        "No tiling 2D config fits in the shared memory of the device"
+    );
+
+    let out = match out.can_mut() {
+        true => out,
+        false => out.copy(),
+    };
+
+    execute_tiling_2d_tuned(
+        Tiling2dAutotuneOperationSet::new(
+            lhs,
+            rhs,
+            out.clone(),
+            candidates,
+            Tiling2dMode::Accumulate,
+        ),
+        &device,
+        &client,
+    );
+
+    out
+}
+
+/// Executes autotune on the configs of the tiling 2D matmul followed by a bias addition and an
+/// activation, `activation(lhs @ rhs + bias)`.
+///
+/// # Panics
+///
+/// If the device has no [candidate](tiling_2d_candidates).
+pub fn matmul_epilogue_tiling_2d_autotune<
+    R: JitRuntime,
+    E: FloatElement + Element,
+    const D: usize,
+>(
+    lhs: JitTensor<R, E, D>,
+    rhs: JitTensor<R, E, D>,
+    bias: Option<JitTensor<R, E, 1>>,
+    activation: Option<EpilogueActivation>,
+) -> JitTensor<R, E, D> {
+    let client = lhs.client.clone();
+    let device = lhs.device.clone();
+
+    let candidates = tiling_2d_candidates::<R, E>(&device);
+    assert!(
+        !candidates.is_empty(),
+        "No tiling 2D config fits in the shared memory of the device"
+    );
+
+    let output = init_matmul_output(&lhs, &rhs);
+
+    execute_tiling_2d_tuned(
+        Tiling2dAutotuneOperationSet::new(
+            lhs,
+            rhs,
+            output.clone(),
+            candidates,
+            Tiling2dMode::Epilogue { bias, activation },
+        ),
+        &device,
+        &client,
+    );
+
+    output
+}
+
+fn execute_tiling_2d_tuned<R: JitRuntime, E: FloatElement, const D: usize>(
+    operation_set: Tiling2dAutotuneOperationSet<R, E, D>,
+    device: &R::Device,
+    client: &cubecl::client::ComputeClient<R::Server, R::Channel>,
+) {
+    static TUNER: LocalTuner<JitAutotuneKey, JitTuneId> = local_tuner!();
+
+    let num_candidates = operation_set.candidates.len();
+
+    execute_tuned::<R>(
+        &TUNER,
+        device,
+        client,
+        Box::new(operation_set),
+        num_candidates,
+    );
+}
+
+#[derive(new)]
+struct MatmulTiling2dConfig<R: JitRuntime, E: FloatElement, const D: usize> {
+    lhs: JitTensor<R, E, D>,
+    rhs: JitTensor<R, E, D>,
+    out: JitTensor<R, E, D>,
+    config: Tiling2dConfig,
+    mode: Tiling2dMode<R, E>,
+}
+
+impl<R: JitRuntime, E: FloatElement, const D: usize> AutotuneOperation
+    for MatmulTiling2dConfig<R, E, D>
+{
+    fn execute(self: Box<Self>) {
+        match self.mode {
+            Tiling2dMode::Overwrite => matmul_tiling_2d(self.lhs, self.rhs, self.out, self.config),
+            Tiling2dMode::Accumulate => {
+                matmul_accumulate(self.lhs, self.rhs, self.out, self.config)
+            }
+            Tiling2dMode::Epilogue { bias, activation } => matmul_tiling_2d_epilogue(
+                self.lhs,
+                self.rhs,
+                self.out,
+                bias,
+                activation,
+                self.config,
+            ),
+        };
+    }
+
+    fn clone(&self) -> Box<dyn AutotuneOperation> {
+        Box::new(Self {
+            lhs: self.lhs.clone(),
+            rhs: self.rhs.clone(),
+            out: self.out.clone(),
+            config: self.config,
+            mode: self.mode.clone(),
+        })
+    }
+}
@@ -1,4 +1,9 @@
-use crate::{element::JitElement, ops::numeric::empty_device, tensor::JitTensor, JitRuntime};
+use crate::{
+    element::JitElement,
+    ops::{broadcast_shape, expand, numeric::empty_device},
+    tensor::JitTensor,
+    JitRuntime,
+};
 use burn_tensor::Shape;
 
 /// Creates an empty output tensor with matmul output shape
@@ -9,20 +14,35 @@ pub fn init_matmul_output<R: JitRuntime, E: JitElement, const D: usize>(
     empty_device(lhs.client.clone(), lhs.device.clone(), shape_out(lhs, rhs))
 }
 
+/// Broadcasts the batch dimensions of the inputs to the ones of the output.
+///
+/// The broadcasted dimensions get a stride of zero, so each input is indexed with the batch
+/// position of the output, without copying the broadcasted matrices.
+pub(crate) fn broadcast_batches<R: JitRuntime, E: JitElement, const D: usize>(
+    lhs: JitTensor<R, E, D>,
+    rhs: JitTensor<R, E, D>,
+) -> (JitTensor<R, E, D>, JitTensor<R, E, D>) {
+    let shape_out = shape_out(&lhs, &rhs);
+    let expand_batches = |tensor: JitTensor<R, E, D>| {
+        let mut shape = shape_out.clone();
+        shape.dims[D - 2] = tensor.shape.dims[D - 2];
+        shape.dims[D - 1] = tensor.shape.dims[D - 1];
+
+        match shape == tensor.shape {
+            true => tensor,
+            false => expand(tensor, shape),
+        }
+    };
+
+    (expand_batches(lhs), expand_batches(rhs))
+}
+
 pub(crate) fn shape_out<R: JitRuntime, E: JitElement, const D: usize>(
     lhs: &JitTensor<R, E, D>,
     rhs: &JitTensor<R, E, D>,
 ) -> Shape<D> {
-    let mut shape_out = [0; D];
-    lhs.shape
-        .dims
-        .iter()
-        .zip(rhs.shape.dims.iter())
-        .enumerate()
-        .for_each(|(index, (dim_lhs, dim_rhs))| {
-            shape_out[index] = usize::max(*dim_lhs, *dim_rhs);
-        });
-    shape_out[D - 2] = lhs.shape.dims[D - 2];
-    shape_out[D - 1] = rhs.shape.dims[D - 1];
-    Shape::new(shape_out)
+    let mut shape_out = broadcast_shape(&lhs.shape, &rhs.shape);
+    shape_out.dims[D - 2] = lhs.shape.dims[D - 2];
+    shape_out.dims[D - 1] = rhs.shape.dims[D - 1];
+    shape_out
 }
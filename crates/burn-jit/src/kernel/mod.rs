@@ -2,32 +2,54 @@ mod binary;
 mod cast;
 mod clamp;
 mod comparison;
+mod conditional;
+mod const_table;
 mod contiguous;
+mod fake_quantize;
 mod index;
 mod mask;
+mod pointwise;
+mod prefix_sum;
+mod scan;
+mod subgroup;
 mod unary;
 
 pub(crate) use binary::*;
 pub use cast::*;
+pub use conditional::*;
+pub use const_table::*;
 pub use contiguous::*;
 pub use mask::*;
+pub use subgroup::*;
 pub(crate) use unary::*;
 
 pub use cubecl::{Kernel, SUBCUBE_DIM_APPROX};
 
+/// Attention kernels
+pub mod attention;
 /// Convolution kernels
 pub mod conv;
+/// Runtime assertions of the kernels
+pub mod debug;
 /// Interpolation kernels
 pub mod interpolate;
 /// Matmul kernels
 pub mod matmul;
+/// Optimizer kernels
+pub mod optim;
 /// Pooling kernels
 pub mod pool;
 /// Pseudo-random number generator kernels
 pub mod prng;
 /// Reduction algorithms
 pub mod reduce;
+/// Sparse matrix kernels
+pub mod sparse;
 
 pub(crate) use clamp::*;
 pub(crate) use comparison::*;
+pub(crate) use fake_quantize::*;
 pub(crate) use index::*;
+pub(crate) use pointwise::*;
+pub(crate) use prefix_sum::*;
+pub(crate) use scan::*;
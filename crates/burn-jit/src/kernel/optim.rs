@@ -0,0 +1,383 @@
+//! Fused optimizer steps.
+//!
+//! Each unit updates a single element of the parameters, reading the parameter, its gradient and
+//! its moments once and writing the three updated values, with the hyperparameters given as
+//! uniforms. The operations are applied in the same order as the default implementation of
+//! [OptimizerOps](burn_tensor::ops::OptimizerOps), so the results are identical in full precision.
+//!
+//! The multi-tensor step updates many parameter tensors flattened into the same tensors, each
+//! unit finding the tensor of its element with a binary search in the indirection table of the
+//! offsets of the tensors, which gives the bias corrections of their time.
+use crate::{
+    kernel::into_contiguous,
+    ops::{from_data, numeric::empty_device},
+    tensor::JitTensor,
+    FloatElement, JitRuntime,
+};
+use burn_tensor::{
+    ops::{bias_corrections, check_segments, AdamStepOptions, AdamStepSegment},
+    ElementConversion, TensorData,
+};
+use cubecl::{calculate_cube_count_elemwise, prelude::*, CubeDim};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static NUM_LAUNCHES: AtomicUsize = AtomicUsize::new(0);
+
+/// The number of launches of the fused optimizer kernels since the start of the program.
+///
+/// This is useful to check that an optimizer step isn't split into multiple launches.
+pub fn num_launches() -> usize {
+    NUM_LAUNCHES.load(Ordering::Relaxed)
+}
+
+#[derive(CubeLaunch)]
+struct AdamArgs<F: Float> {
+    lr: F,
+    beta_1: F,
+    beta_2: F,
+    factor_1: F,
+    factor_2: F,
+    epsilon: F,
+    penalty: F,
+    weight_decay: F,
+}
+
+#[allow(clippy::too_many_arguments)]
+#[cube]
+fn adam_element<F: Float>(
+    tensor: &Tensor<F>,
+    grad: &Tensor<F>,
+    moment_1: &Tensor<F>,
+    moment_2: &Tensor<F>,
+    tensor_out: &mut Tensor<F>,
+    moment_1_out: &mut Tensor<F>,
+    moment_2_out: &mut Tensor<F>,
+    index: UInt,
+    correction_1: F,
+    correction_2: F,
+    args: &AdamArgs<F>,
+    moments: Comptime<bool>,
+    penalty: Comptime<bool>,
+    weight_decay: Comptime<bool>,
+) {
+    let param = tensor[index];
+    let mut gradient = grad[index];
+    if Comptime::get(penalty) {
+        gradient = param * args.penalty + gradient;
+    }
+
+    let mut m_1 = gradient * args.factor_1;
+    let mut m_2 = F::powf(gradient, F::new(2.0)) * args.factor_2;
+    if Comptime::get(moments) {
+        m_1 = moment_1[index] * args.beta_1 + m_1;
+        m_2 = moment_2[index] * args.beta_2 + m_2;
+    }
+
+    let update = (m_1 / correction_1) / (F::sqrt(m_2 / correction_2) + args.epsilon);
+
+    let mut decayed = param;
+    if Comptime::get(weight_decay) {
+        decayed = param - param * args.lr * args.weight_decay;
+    }
+
+    tensor_out[index] = decayed - update * args.lr;
+    moment_1_out[index] = m_1;
+    moment_2_out[index] = m_2;
+}
+
+#[allow(clippy::too_many_arguments)]
+#[cube(launch_unchecked)]
+fn adam_step_kernel<F: Float>(
+    tensor: &Tensor<F>,
+    grad: &Tensor<F>,
+    moment_1: &Tensor<F>,
+    moment_2: &Tensor<F>,
+    tensor_out: &mut Tensor<F>,
+    moment_1_out: &mut Tensor<F>,
+    moment_2_out: &mut Tensor<F>,
+    correction_1: F,
+    correction_2: F,
+    args: &AdamArgs<F>,
+    moments: Comptime<bool>,
+    penalty: Comptime<bool>,
+    weight_decay: Comptime<bool>,
+) {
+    if ABSOLUTE_POS >= tensor_out.len() {
+        return;
+    }
+
+    adam_element::<F>(
+        tensor,
+        grad,
+        moment_1,
+        moment_2,
+        tensor_out,
+        moment_1_out,
+        moment_2_out,
+        ABSOLUTE_POS,
+        correction_1,
+        correction_2,
+        args,
+        moments,
+        penalty,
+        weight_decay,
+    );
+}
+
+#[allow(clippy::too_many_arguments)]
+#[cube(launch_unchecked)]
+fn adam_step_multi_kernel<F: Float>(
+    tensor: &Tensor<F>,
+    grad: &Tensor<F>,
+    moment_1: &Tensor<F>,
+    moment_2: &Tensor<F>,
+    tensor_out: &mut Tensor<F>,
+    moment_1_out: &mut Tensor<F>,
+    moment_2_out: &mut Tensor<F>,
+    offsets: &Tensor<UInt>,
+    corrections: &Tensor<F>,
+    args: &AdamArgs<F>,
+    moments: Comptime<bool>,
+    penalty: Comptime<bool>,
+    weight_decay: Comptime<bool>,
+) {
+    if ABSOLUTE_POS >= tensor_out.len() {
+        return;
+    }
+
+    // Invariant: offsets[low] <= ABSOLUTE_POS < offsets[high].
+    let mut low = UInt::new(0);
+    let mut high = offsets.len() - UInt::new(1);
+    while high - low > UInt::new(1) {
+        let middle = (low + high) / UInt::new(2);
+        if offsets[middle] <= ABSOLUTE_POS {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+
+    adam_element::<F>(
+        tensor,
+        grad,
+        moment_1,
+        moment_2,
+        tensor_out,
+        moment_1_out,
+        moment_2_out,
+        ABSOLUTE_POS,
+        corrections[low * UInt::new(2)],
+        corrections[low * UInt::new(2) + UInt::new(1)],
+        args,
+        moments,
+        penalty,
+        weight_decay,
+    );
+}
+
+fn adam_args<'a, R: JitRuntime, E: FloatElement>(
+    options: &AdamStepOptions,
+) -> AdamArgsLaunch<'a, E::FloatPrimitive, R> {
+    AdamArgsLaunch::new(
+        ScalarArg::new(options.lr.elem::<E>()),
+        ScalarArg::new(options.beta_1.elem::<E>()),
+        ScalarArg::new(options.beta_2.elem::<E>()),
+        ScalarArg::new((1.0 - options.beta_1).elem::<E>()),
+        ScalarArg::new((1.0 - options.beta_2).elem::<E>()),
+        ScalarArg::new(options.epsilon.elem::<E>()),
+        ScalarArg::new(options.penalty.unwrap_or(0.0).elem::<E>()),
+        ScalarArg::new(options.weight_decay.unwrap_or(0.0).elem::<E>()),
+    )
+}
+
+/// The parameters, their gradient and their moments, contiguous, and the allocated outputs.
+struct AdamTensors<R: JitRuntime, E: FloatElement, const D: usize> {
+    tensor: JitTensor<R, E, D>,
+    grad: JitTensor<R, E, D>,
+    moments: Option<(JitTensor<R, E, D>, JitTensor<R, E, D>)>,
+    tensor_out: JitTensor<R, E, D>,
+    moment_1_out: JitTensor<R, E, D>,
+    moment_2_out: JitTensor<R, E, D>,
+}
+
+impl<R: JitRuntime, E: FloatElement, const D: usize> AdamTensors<R, E, D> {
+    fn new(
+        tensor: JitTensor<R, E, D>,
+        grad: JitTensor<R, E, D>,
+        moments: Option<(JitTensor<R, E, D>, JitTensor<R, E, D>)>,
+    ) -> Self {
+        tensor.assert_is_on_same_device(&grad);
+        assert_eq!(
+            tensor.shape, grad.shape,
+            "The gradient must have the shape of the parameters."
+        );
+        if let Some((moment_1, moment_2)) = &moments {
+            assert_eq!(
+                tensor.shape, moment_1.shape,
+                "The moments must have the shape of the parameters."
+            );
+            assert_eq!(
+                tensor.shape, moment_2.shape,
+                "The moments must have the shape of the parameters."
+            );
+        }
+
+        let tensor = into_contiguous(tensor);
+        let grad = into_contiguous(grad);
+        let moments = moments
+            .map(|(moment_1, moment_2)| (into_contiguous(moment_1), into_contiguous(moment_2)));
+
+        let empty = || {
+            empty_device::<R, E, D>(
+                tensor.client.clone(),
+                tensor.device.clone(),
+                tensor.shape.clone(),
+            )
+        };
+        let tensor_out = empty();
+        let moment_1_out = empty();
+        let moment_2_out = empty();
+
+        Self {
+            tensor,
+            grad,
+            moments,
+            tensor_out,
+            moment_1_out,
+            moment_2_out,
+        }
+    }
+
+    /// The moments to read, which are never read without a previous step.
+    fn moments_or_grad(&self) -> (&JitTensor<R, E, D>, &JitTensor<R, E, D>) {
+        match &self.moments {
+            Some((moment_1, moment_2)) => (moment_1, moment_2),
+            None => (&self.grad, &self.grad),
+        }
+    }
+}
+
+/// Apply an Adam step to a parameter tensor in a single kernel.
+pub fn adam_step<R: JitRuntime, E: FloatElement, const D: usize>(
+    tensor: JitTensor<R, E, D>,
+    grad: JitTensor<R, E, D>,
+    moments: Option<(JitTensor<R, E, D>, JitTensor<R, E, D>)>,
+    time: usize,
+    options: &AdamStepOptions,
+) -> (JitTensor<R, E, D>, JitTensor<R, E, D>, JitTensor<R, E, D>) {
+    let tensors = AdamTensors::new(tensor, grad, moments);
+    let num_elems = tensors.tensor.shape.num_elements();
+    if num_elems == 0 {
+        return (
+            tensors.tensor_out,
+            tensors.moment_1_out,
+            tensors.moment_2_out,
+        );
+    }
+    let (moment_1, moment_2) = tensors.moments_or_grad();
+    let (correction_1, correction_2) = bias_corrections(time, options);
+
+    let cube_dim = CubeDim::default();
+
+    NUM_LAUNCHES.fetch_add(1, Ordering::Relaxed);
+    crate::perf::record_kernel();
+
+    unsafe {
+        adam_step_kernel::launch_unchecked::<E::FloatPrimitive, R>(
+            &tensors.tensor.client,
+            calculate_cube_count_elemwise(num_elems, cube_dim),
+            cube_dim,
+            tensors.tensor.as_tensor_arg(1),
+            tensors.grad.as_tensor_arg(1),
+            moment_1.as_tensor_arg(1),
+            moment_2.as_tensor_arg(1),
+            tensors.tensor_out.as_tensor_arg(1),
+            tensors.moment_1_out.as_tensor_arg(1),
+            tensors.moment_2_out.as_tensor_arg(1),
+            ScalarArg::new(correction_1.elem::<E>()),
+            ScalarArg::new(correction_2.elem::<E>()),
+            adam_args::<R, E>(options),
+            tensors.moments.is_some(),
+            options.penalty.is_some(),
+            options.weight_decay.is_some(),
+        );
+    }
+
+    (
+        tensors.tensor_out,
+        tensors.moment_1_out,
+        tensors.moment_2_out,
+    )
+}
+
+/// Apply an Adam step to many parameter tensors flattened into the same tensors, in a single
+/// kernel.
+pub fn adam_step_multi<R: JitRuntime, E: FloatElement>(
+    tensor: JitTensor<R, E, 1>,
+    grad: JitTensor<R, E, 1>,
+    moments: Option<(JitTensor<R, E, 1>, JitTensor<R, E, 1>)>,
+    segments: &[AdamStepSegment],
+    options: &AdamStepOptions,
+) -> (JitTensor<R, E, 1>, JitTensor<R, E, 1>, JitTensor<R, E, 1>) {
+    let num_elems = tensor.shape.num_elements();
+    check_segments(segments, num_elems);
+
+    let tensors = AdamTensors::new(tensor, grad, moments);
+    if num_elems == 0 {
+        return (
+            tensors.tensor_out,
+            tensors.moment_1_out,
+            tensors.moment_2_out,
+        );
+    }
+    let (moment_1, moment_2) = tensors.moments_or_grad();
+
+    let device = &tensors.tensor.device;
+    let offsets = segments
+        .iter()
+        .map(|segment| segment.offset as u32)
+        .chain([num_elems as u32])
+        .collect::<Vec<_>>();
+    let corrections = segments
+        .iter()
+        .flat_map(|segment| {
+            let (correction_1, correction_2) = bias_corrections(segment.time, options);
+            [correction_1, correction_2]
+        })
+        .collect::<Vec<_>>();
+    let offsets = from_data::<R, u32, 1>(TensorData::new(offsets, [segments.len() + 1]), device);
+    let corrections =
+        from_data::<R, E, 1>(TensorData::new(corrections, [segments.len() * 2]), device);
+
+    let cube_dim = CubeDim::default();
+
+    NUM_LAUNCHES.fetch_add(1, Ordering::Relaxed);
+    crate::perf::record_kernel();
+
+    unsafe {
+        adam_step_multi_kernel::launch_unchecked::<E::FloatPrimitive, R>(
+            &tensors.tensor.client,
+            calculate_cube_count_elemwise(num_elems, cube_dim),
+            cube_dim,
+            tensors.tensor.as_tensor_arg(1),
+            tensors.grad.as_tensor_arg(1),
+            moment_1.as_tensor_arg(1),
+            moment_2.as_tensor_arg(1),
+            tensors.tensor_out.as_tensor_arg(1),
+            tensors.moment_1_out.as_tensor_arg(1),
+            tensors.moment_2_out.as_tensor_arg(1),
+            offsets.as_tensor_arg(1),
+            corrections.as_tensor_arg(1),
+            adam_args::<R, E>(options),
+            tensors.moments.is_some(),
+            options.penalty.is_some(),
+            options.weight_decay.is_some(),
+        );
+    }
+
+    (
+        tensors.tensor_out,
+        tensors.moment_1_out,
+        tensors.moment_2_out,
+    )
+}
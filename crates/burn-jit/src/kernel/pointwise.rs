@@ -0,0 +1,244 @@
+//! Fused pointwise combinations of tensors, such as the interpolations and the accumulations of
+//! the optimizer updates.
+//!
+//! Each one reads its inputs once and writes the output in a single launch, instead of the two or
+//! three launches of the composed operations. The inputs are broadcasted to the shape of the
+//! output with their strides.
+use cubecl::{calculate_cube_count_elemwise, linalg::tensor::index_offset_with_layout, prelude::*};
+
+use crate::{
+    ops::{broadcast_shape, numeric::empty_device},
+    tensor::JitTensor,
+    FloatElement, JitRuntime,
+};
+
+#[cube]
+fn index_of<F: Float>(input: &Tensor<F>, output: &Tensor<F>, rank: Comptime<UInt>) -> UInt {
+    index_offset_with_layout(
+        input,
+        output,
+        ABSOLUTE_POS,
+        UInt::new(0),
+        Comptime::runtime(rank),
+        Comptime::new(true),
+    )
+}
+
+#[cube(launch)]
+fn lerp_kernel<F: Float>(
+    input: &Tensor<F>,
+    end: &Tensor<F>,
+    weight: &Tensor<F>,
+    output: &mut Tensor<F>,
+    rank: Comptime<UInt>,
+) {
+    if ABSOLUTE_POS >= output.len() {
+        return;
+    }
+
+    let start = input[index_of::<F>(input, output, rank)];
+    let stop = end[index_of::<F>(end, output, rank)];
+    let factor = weight[index_of::<F>(weight, output, rank)];
+
+    output[ABSOLUTE_POS] = start + factor * (stop - start);
+}
+
+#[cube(launch)]
+fn lerp_scalar_kernel<F: Float>(
+    input: &Tensor<F>,
+    end: &Tensor<F>,
+    output: &mut Tensor<F>,
+    weight: F,
+    rank: Comptime<UInt>,
+) {
+    if ABSOLUTE_POS >= output.len() {
+        return;
+    }
+
+    let start = input[index_of::<F>(input, output, rank)];
+    let stop = end[index_of::<F>(end, output, rank)];
+
+    output[ABSOLUTE_POS] = start + (stop - start) * weight;
+}
+
+#[cube(launch)]
+fn addcmul_kernel<F: Float>(
+    input: &Tensor<F>,
+    tensor1: &Tensor<F>,
+    tensor2: &Tensor<F>,
+    output: &mut Tensor<F>,
+    value: F,
+    rank: Comptime<UInt>,
+    divide: Comptime<bool>,
+) {
+    if ABSOLUTE_POS >= output.len() {
+        return;
+    }
+
+    let lhs = tensor1[index_of::<F>(tensor1, output, rank)] * value;
+    let rhs = tensor2[index_of::<F>(tensor2, output, rank)];
+    let mut term = lhs * rhs;
+    if Comptime::get(divide) {
+        term = lhs / rhs;
+    }
+
+    output[ABSOLUTE_POS] = input[index_of::<F>(input, output, rank)] + term;
+}
+
+#[cube(launch)]
+fn axpby_kernel<F: Float>(
+    input: &Tensor<F>,
+    other: &Tensor<F>,
+    output: &mut Tensor<F>,
+    alpha: F,
+    beta: F,
+    rank: Comptime<UInt>,
+) {
+    if ABSOLUTE_POS >= output.len() {
+        return;
+    }
+
+    let lhs = input[index_of::<F>(input, output, rank)];
+    let rhs = other[index_of::<F>(other, output, rank)];
+
+    output[ABSOLUTE_POS] = lhs * alpha + rhs * beta;
+}
+
+fn output_of<R: JitRuntime, E: FloatElement, const D: usize>(
+    inputs: &[&JitTensor<R, E, D>],
+) -> JitTensor<R, E, D> {
+    let shape = inputs
+        .iter()
+        .skip(1)
+        .fold(inputs[0].shape.clone(), |shape, input| {
+            broadcast_shape(&shape, &input.shape)
+        });
+
+    empty_device(inputs[0].client.clone(), inputs[0].device.clone(), shape)
+}
+
+fn cube_count<R: JitRuntime, E: FloatElement, const D: usize>(
+    output: &JitTensor<R, E, D>,
+    cube_dim: CubeDim,
+) -> CubeCount<R::Server> {
+    calculate_cube_count_elemwise(output.shape.num_elements(), cube_dim)
+}
+
+/// Linearly interpolate between the input and `end` with the weights of the `weight` tensor.
+pub(crate) fn lerp<R: JitRuntime, E: FloatElement, const D: usize>(
+    input: JitTensor<R, E, D>,
+    end: JitTensor<R, E, D>,
+    weight: JitTensor<R, E, D>,
+) -> JitTensor<R, E, D> {
+    let output = output_of(&[&input, &end, &weight]);
+    if output.is_empty() {
+        return output;
+    }
+
+    let cube_dim = CubeDim::default();
+
+    crate::perf::record_kernel();
+    lerp_kernel::launch::<E::FloatPrimitive, R>(
+        &input.client,
+        cube_count(&output, cube_dim),
+        cube_dim,
+        input.as_tensor_arg(1),
+        end.as_tensor_arg(1),
+        weight.as_tensor_arg(1),
+        output.as_tensor_arg(1),
+        UInt::new(D as u32),
+    );
+
+    output
+}
+
+/// Linearly interpolate between the input and `end` with the same weight for all elements.
+pub(crate) fn lerp_scalar<R: JitRuntime, E: FloatElement, const D: usize>(
+    input: JitTensor<R, E, D>,
+    end: JitTensor<R, E, D>,
+    weight: E,
+) -> JitTensor<R, E, D> {
+    let output = output_of(&[&input, &end]);
+    if output.is_empty() {
+        return output;
+    }
+
+    let cube_dim = CubeDim::default();
+
+    crate::perf::record_kernel();
+    lerp_scalar_kernel::launch::<E::FloatPrimitive, R>(
+        &input.client,
+        cube_count(&output, cube_dim),
+        cube_dim,
+        input.as_tensor_arg(1),
+        end.as_tensor_arg(1),
+        output.as_tensor_arg(1),
+        ScalarArg::new(weight),
+        UInt::new(D as u32),
+    );
+
+    output
+}
+
+/// Add the product, or the quotient when `divide` is set, of `tensor1` and `tensor2` scaled by
+/// `value` to the input.
+pub(crate) fn addcmul<R: JitRuntime, E: FloatElement, const D: usize>(
+    input: JitTensor<R, E, D>,
+    tensor1: JitTensor<R, E, D>,
+    tensor2: JitTensor<R, E, D>,
+    value: E,
+    divide: bool,
+) -> JitTensor<R, E, D> {
+    let output = output_of(&[&input, &tensor1, &tensor2]);
+    if output.is_empty() {
+        return output;
+    }
+
+    let cube_dim = CubeDim::default();
+
+    crate::perf::record_kernel();
+    addcmul_kernel::launch::<E::FloatPrimitive, R>(
+        &input.client,
+        cube_count(&output, cube_dim),
+        cube_dim,
+        input.as_tensor_arg(1),
+        tensor1.as_tensor_arg(1),
+        tensor2.as_tensor_arg(1),
+        output.as_tensor_arg(1),
+        ScalarArg::new(value),
+        UInt::new(D as u32),
+        divide,
+    );
+
+    output
+}
+
+/// Compute the linear combination `alpha * input + beta * other`.
+pub(crate) fn axpby<R: JitRuntime, E: FloatElement, const D: usize>(
+    input: JitTensor<R, E, D>,
+    alpha: E,
+    other: JitTensor<R, E, D>,
+    beta: E,
+) -> JitTensor<R, E, D> {
+    let output = output_of(&[&input, &other]);
+    if output.is_empty() {
+        return output;
+    }
+
+    let cube_dim = CubeDim::default();
+
+    crate::perf::record_kernel();
+    axpby_kernel::launch::<E::FloatPrimitive, R>(
+        &input.client,
+        cube_count(&output, cube_dim),
+        cube_dim,
+        input.as_tensor_arg(1),
+        other.as_tensor_arg(1),
+        output.as_tensor_arg(1),
+        ScalarArg::new(alpha),
+        ScalarArg::new(beta),
+        UInt::new(D as u32),
+    );
+
+    output
+}
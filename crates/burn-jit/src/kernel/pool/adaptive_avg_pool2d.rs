@@ -13,8 +13,13 @@ pub(crate) fn adaptive_avg_pool2d<R: JitRuntime, E: JitElement>(
     let output_shape = Shape::new([batch_size, channels, output_size[0], output_size[1]]);
     let output = empty_device(input.client.clone(), input.device.clone(), output_shape);
 
+    if output.is_empty() {
+        return output;
+    }
+
     let kernel = AdaptivePool2dEagerKernel::<R, E>::new();
 
+    crate::perf::record_kernel();
     Execution::start(kernel, input.client.clone())
         .inputs(&[input.as_handle_ref()])
         .outputs(&[output.as_handle_ref()])
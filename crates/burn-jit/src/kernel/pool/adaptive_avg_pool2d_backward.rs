@@ -249,7 +249,9 @@ pub(crate) fn adaptive_avg_pool2d_backward<R: JitRuntime, E: JitElement>(
 ) -> JitTensor<R, E, 4> {
     let output_shape = x.shape.clone();
     let num_elems = output_shape.num_elements();
-    let output_buffer = x.client.empty(num_elems * core::mem::size_of::<E>());
+    let num_bytes = num_elems * core::mem::size_of::<E>();
+    crate::perf::record_allocation(num_bytes);
+    let output_buffer = x.client.empty(num_bytes);
     let output = JitTensor::new_contiguous(
         x.client.clone(),
         x.device.clone(),
@@ -259,6 +261,7 @@ pub(crate) fn adaptive_avg_pool2d_backward<R: JitRuntime, E: JitElement>(
 
     let kernel = AdaptiveAvgPool2dBackwardEagerKernel::<R, E>::new();
 
+    crate::perf::record_kernel();
     Execution::start(kernel, x.client)
         .inputs(&[out_grad.as_handle_ref()])
         .outputs(&[output.as_handle_ref()])
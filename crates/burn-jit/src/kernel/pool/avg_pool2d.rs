@@ -1,4 +1,6 @@
-use crate::{element::JitElement, ops::numeric::empty_device, tensor::JitTensor, JitRuntime};
+use crate::{
+    element::JitElement, ops::numeric::empty_device_with_format, tensor::JitTensor, JitRuntime,
+};
 use burn_tensor::{ops::conv::calculate_pool_output_size, Shape};
 use cubecl::{
     cpa,
@@ -11,19 +13,24 @@ use super::{Pool2dEagerKernel, PoolStrategy};
 
 #[derive(new, Debug, Clone, Hash, PartialEq, Eq)]
 struct AvgPool {
-    kernel_size: [usize; 2],
     count_include_pad: bool,
 }
 
 impl PoolStrategy for AvgPool {
     type Accumulator = (Variable, Variable);
 
-    fn initialize(&self, scope: &mut Scope, item: Item) -> Self::Accumulator {
+    fn initialize(
+        &self,
+        scope: &mut Scope,
+        item: Item,
+        [window_size_0, window_size_1]: [Variable; 2],
+    ) -> Self::Accumulator {
         let sum = scope.create_local(item);
         let count = scope.create_local(Elem::UInt);
         if self.count_include_pad {
-            let kernel_size: Variable = (self.kernel_size[0] * self.kernel_size[1]).into();
-            cpa!(scope, count = kernel_size);
+            // The padding is counted, but not the positions after the padding of the partial
+            // windows added with `ceil_mode`.
+            cpa!(scope, count = window_size_0 * window_size_1);
         } else {
             let zero: Variable = 0u32.into();
             cpa!(scope, count = zero);
@@ -74,6 +81,7 @@ pub(crate) fn avg_pool2d<R: JitRuntime, E: JitElement>(
     stride: [usize; 2],
     padding: [usize; 2],
     count_include_pad: bool,
+    ceil_mode: bool,
 ) -> JitTensor<R, E, 4> {
     let [batch_size, channels, _, _] = x.shape.dims;
     let dilation = 1;
@@ -83,6 +91,7 @@ pub(crate) fn avg_pool2d<R: JitRuntime, E: JitElement>(
         stride[0],
         padding[0],
         dilation,
+        ceil_mode,
         x.shape.dims[2],
     );
     let size_1 = calculate_pool_output_size(
@@ -90,15 +99,27 @@ pub(crate) fn avg_pool2d<R: JitRuntime, E: JitElement>(
         stride[1],
         padding[1],
         dilation,
+        ceil_mode,
         x.shape.dims[3],
     );
 
     let shape_out = Shape::new([batch_size, channels, size_0, size_1]);
-    let output = empty_device(x.client.clone(), x.device.clone(), shape_out);
+    // The kernel reads the strides of the input, so the output keeps its memory format.
+    let output = empty_device_with_format(
+        x.client.clone(),
+        x.device.clone(),
+        shape_out,
+        x.memory_format(),
+    );
+
+    if output.is_empty() {
+        return output;
+    }
 
-    let pool_strategy = AvgPool::new(kernel_size, count_include_pad);
+    let pool_strategy = AvgPool::new(count_include_pad);
     let kernel = Pool2dEagerKernel::<AvgPool, R, E>::new(kernel_size, pool_strategy);
 
+    crate::perf::record_kernel();
     Execution::start(kernel, x.client.clone())
         .inputs(&[x.as_handle_ref()])
         .outputs(&[output.as_handle_ref()])
@@ -17,6 +17,7 @@ use std::marker::PhantomData;
 struct AvgPool2dBackwardEagerKernel<R: JitRuntime, E: JitElement> {
     kernel_size: [usize; 2],
     count_include_pad: bool,
+    ceil_mode: bool,
     _runtime: PhantomData<R>,
     _elem: PhantomData<E>,
 }
@@ -26,6 +27,7 @@ struct AvgPool2dBackwardComputeShader {
     output: Variable,
     kernel_size: [usize; 2],
     count_include_pad: bool,
+    ceil_mode: bool,
 }
 
 impl AvgPool2dBackwardComputeShader {
@@ -121,7 +123,9 @@ impl AvgPool2dBackwardComputeShader {
         let count = scope.create_local(grad.item());
 
         let count_include_pad = self.count_include_pad;
-        if count_include_pad {
+        // The partial windows added with `ceil_mode` don't count the positions after the padding.
+        let count_partial_windows = count_include_pad && self.ceil_mode;
+        if count_include_pad && !count_partial_windows {
             let kernel_size: Variable = (self.kernel_size[0] * self.kernel_size[1]).into();
             cpa!(scope, count = kernel_size);
         }
@@ -152,8 +156,12 @@ impl AvgPool2dBackwardComputeShader {
         let before_end = scope.create_local(Elem::Bool);
         let contributed_h = scope.create_local(Elem::Bool);
         let contributed_w = scope.create_local(Elem::Bool);
+        let padded_bottom = scope.create_local(Elem::UInt);
+        let padded_right = scope.create_local(Elem::UInt);
         cpa!(scope, border_bottom = output_shape_2 + padding_0);
         cpa!(scope, border_right = output_shape_3 + padding_1);
+        cpa!(scope, padded_bottom = border_bottom + padding_0);
+        cpa!(scope, padded_right = border_right + padding_1);
         cpa!(scope, begin_h = ih + padding_0);
         cpa!(scope, begin_w = iw + padding_1);
 
@@ -167,6 +175,10 @@ impl AvgPool2dBackwardComputeShader {
                 // Contributed h
                 cpa!(scope, ih_start = oh * pool_stride_0);
                 cpa!(scope, ih_end = ih_start + kernel_size_0);
+                if count_partial_windows {
+                    cpa!(scope, ih_diff = min(ih_end, padded_bottom));
+                    cpa!(scope, ih_diff = ih_diff - ih_start);
+                }
                 cpa!(scope, ih_start = max(ih_start, padding_0));
                 cpa!(scope, ih_end = min(ih_end, border_bottom));
                 cpa!(scope, after_start = begin_h >= ih_start);
@@ -190,6 +202,10 @@ impl AvgPool2dBackwardComputeShader {
                         // Contributed w
                         cpa!(scope, iw_start = ow * pool_stride_1);
                         cpa!(scope, iw_end = iw_start + kernel_size_1);
+                        if count_partial_windows {
+                            cpa!(scope, iw_diff = min(iw_end, padded_right));
+                            cpa!(scope, iw_diff = iw_diff - iw_start);
+                        }
                         cpa!(scope, iw_start = max(iw_start, padding_1));
                         cpa!(scope, iw_end = min(iw_end, border_right));
                         cpa!(scope, after_start = begin_w >= iw_start);
@@ -199,6 +215,8 @@ impl AvgPool2dBackwardComputeShader {
                         cpa!(scope, if(contributed_w).then(|scope|{
                             if !count_include_pad {
                                 cpa!(scope, iw_diff = iw_end - iw_start);
+                            }
+                            if !count_include_pad || count_partial_windows {
                                 cpa!(scope, count_int = ih_diff * iw_diff);
                                 cpa!(scope, count = cast(count_int));
                             }
@@ -360,6 +378,7 @@ impl<R: JitRuntime, E: JitElement> Kernel for AvgPool2dBackwardEagerKernel<R, E>
             output,
             kernel_size: self.kernel_size,
             count_include_pad: self.count_include_pad,
+            ceil_mode: self.ceil_mode,
         }
         .expand(&mut scope);
 
@@ -384,7 +403,11 @@ impl<R: JitRuntime, E: JitElement> Kernel for AvgPool2dBackwardEagerKernel<R, E>
     }
 
     fn id(&self) -> cubecl::KernelId {
-        cubecl::KernelId::new::<Self>().info((self.kernel_size, self.count_include_pad))
+        cubecl::KernelId::new::<Self>().info((
+            self.kernel_size,
+            self.count_include_pad,
+            self.ceil_mode,
+        ))
     }
 }
 
@@ -395,13 +418,16 @@ pub(crate) fn avg_pool2d_backward<R: JitRuntime, E: JitElement>(
     stride: [usize; 2],
     padding: [usize; 2],
     count_include_pad: bool,
+    ceil_mode: bool,
 ) -> JitTensor<R, E, 4> {
     let grad = kernel::into_contiguous(grad);
     let dilation = 1;
 
     let output = empty_device(x.client.clone(), x.device.clone(), x.shape.clone());
-    let kernel = AvgPool2dBackwardEagerKernel::<R, E>::new(kernel_size, count_include_pad);
+    let kernel =
+        AvgPool2dBackwardEagerKernel::<R, E>::new(kernel_size, count_include_pad, ceil_mode);
 
+    crate::perf::record_kernel();
     Execution::start(kernel, x.client)
         .inputs(&[grad.as_handle_ref()])
         .outputs(&[output.as_handle_ref()])
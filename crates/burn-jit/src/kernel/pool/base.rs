@@ -7,7 +7,15 @@ pub(crate) trait PoolStrategy:
 {
     type Accumulator: Copy;
 
-    fn initialize(&self, scope: &mut Scope, item: Item) -> Self::Accumulator;
+    /// Initialize the accumulator of an output element, where `window_size` is the number of
+    /// positions of its window in the padded input along each dimension, which is smaller than the
+    /// kernel size for the partial windows added with `ceil_mode`.
+    fn initialize(
+        &self,
+        scope: &mut Scope,
+        item: Item,
+        window_size: [Variable; 2],
+    ) -> Self::Accumulator;
 
     fn process_result(
         &self,
@@ -5,7 +5,9 @@ use cubecl::{
 };
 use std::{fmt::Debug, marker::PhantomData};
 
-use crate::{element::JitElement, ops::numeric::empty_device, tensor::JitTensor, JitRuntime};
+use crate::{
+    element::JitElement, ops::numeric::empty_device_with_format, tensor::JitTensor, JitRuntime,
+};
 use burn_tensor::{ops::conv::calculate_pool_output_size, Shape};
 
 use super::{Pool2dEagerKernel, PoolStrategy};
@@ -26,7 +28,12 @@ impl<E: JitElement> core::hash::Hash for MaxPool<E> {
 impl<E: JitElement> PoolStrategy for MaxPool<E> {
     type Accumulator = Variable;
 
-    fn initialize(&self, scope: &mut Scope, item: Item) -> Self::Accumulator {
+    fn initialize(
+        &self,
+        scope: &mut Scope,
+        item: Item,
+        _window_size: [Variable; 2],
+    ) -> Self::Accumulator {
         let max_val = scope.create_local(item);
         let max_initial = item.elem().constant_from_f64(E::minimum_value().to_f64());
         cpa!(scope, max_val = max_initial);
@@ -80,7 +87,12 @@ impl<E: JitElement> core::hash::Hash for MaxPoolWithIndices<E> {
 impl<E: JitElement> PoolStrategy for MaxPoolWithIndices<E> {
     type Accumulator = (Variable, Variable);
 
-    fn initialize(&self, scope: &mut Scope, item: Item) -> Self::Accumulator {
+    fn initialize(
+        &self,
+        scope: &mut Scope,
+        item: Item,
+        _window_size: [Variable; 2],
+    ) -> Self::Accumulator {
         let max_val = scope.create_local(item);
         let max_initial = item.elem().constant_from_f64(E::minimum_value().to_f64());
         cpa!(scope, max_val = max_initial);
@@ -128,6 +140,7 @@ pub(crate) fn max_pool2d<R: JitRuntime, E: JitElement>(
     stride: [usize; 2],
     padding: [usize; 2],
     dilation: [usize; 2],
+    ceil_mode: bool,
 ) -> JitTensor<R, E, 4> {
     let [batch_size, channels, _, _] = x.shape.dims;
 
@@ -136,6 +149,7 @@ pub(crate) fn max_pool2d<R: JitRuntime, E: JitElement>(
         stride[0],
         padding[0],
         dilation[0],
+        ceil_mode,
         x.shape.dims[2],
     );
     let size_1 = calculate_pool_output_size(
@@ -143,14 +157,26 @@ pub(crate) fn max_pool2d<R: JitRuntime, E: JitElement>(
         stride[1],
         padding[1],
         dilation[1],
+        ceil_mode,
         x.shape.dims[3],
     );
 
     let shape_out = Shape::new([batch_size, channels, size_0, size_1]);
-    let output = empty_device(x.client.clone(), x.device.clone(), shape_out);
+    // The kernel reads the strides of the input, so the output keeps its memory format.
+    let output = empty_device_with_format(
+        x.client.clone(),
+        x.device.clone(),
+        shape_out,
+        x.memory_format(),
+    );
+
+    if output.is_empty() {
+        return output;
+    }
 
     let kernel = Pool2dEagerKernel::<MaxPool<E>, R, E>::new(kernel_size, MaxPool::default());
 
+    crate::perf::record_kernel();
     Execution::start(kernel, x.client.clone())
         .inputs(&[x.as_handle_ref()])
         .outputs(&[output.as_handle_ref()])
@@ -173,6 +199,7 @@ pub(crate) fn max_pool2d_with_indices<R: JitRuntime, E: JitElement, I: JitElemen
     stride: [usize; 2],
     padding: [usize; 2],
     dilation: [usize; 2],
+    ceil_mode: bool,
 ) -> (JitTensor<R, E, 4>, JitTensor<R, I, 4>) {
     let [batch_size, channels, _, _] = x.shape.dims;
 
@@ -181,6 +208,7 @@ pub(crate) fn max_pool2d_with_indices<R: JitRuntime, E: JitElement, I: JitElemen
         stride[0],
         padding[0],
         dilation[0],
+        ceil_mode,
         x.shape.dims[2],
     );
     let size_1 = calculate_pool_output_size(
@@ -188,18 +216,35 @@ pub(crate) fn max_pool2d_with_indices<R: JitRuntime, E: JitElement, I: JitElemen
         stride[1],
         padding[1],
         dilation[1],
+        ceil_mode,
         x.shape.dims[3],
     );
 
     let shape_out = Shape::new([batch_size, channels, size_0, size_1]);
-    let output = empty_device(x.client.clone(), x.device.clone(), shape_out.clone());
-    let indices = empty_device(x.client.clone(), x.device.clone(), shape_out);
+    // The kernel reads the strides of the input, so the output keeps its memory format.
+    let output = empty_device_with_format(
+        x.client.clone(),
+        x.device.clone(),
+        shape_out.clone(),
+        x.memory_format(),
+    );
+    let indices = empty_device_with_format(
+        x.client.clone(),
+        x.device.clone(),
+        shape_out,
+        x.memory_format(),
+    );
+
+    if output.is_empty() {
+        return (output, indices);
+    }
 
     let kernel = Pool2dEagerKernel::<MaxPoolWithIndices<E>, R, E>::new(
         kernel_size,
         MaxPoolWithIndices::default(),
     );
 
+    crate::perf::record_kernel();
     Execution::start(kernel, x.client.clone())
         .inputs(&[x.as_handle_ref()])
         .outputs(&[output.as_handle_ref(), indices.as_handle_ref()])
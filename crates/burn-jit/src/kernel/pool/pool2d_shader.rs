@@ -134,7 +134,35 @@ impl<P: PoolStrategy, R: JitRuntime, E: JitElement> Pool2dComputeShader<P, R, E>
         cpa!(scope, index_input_0 = b * input_stride_0);
         cpa!(scope, index_input_1 = c * input_stride_1);
 
-        let accumulator = self.pool_strategy.initialize(scope, input.item());
+        let window_start = scope.create_local(Elem::UInt);
+        let padded_end = scope.create_local(Elem::UInt);
+        let window_size_0 = scope.create_local(Elem::UInt);
+        let window_size_1 = scope.create_local(Elem::UInt);
+
+        cpa!(scope, window_start = oh * pool_stride_0);
+        cpa!(scope, padded_end = border_bottom + padding_0);
+        Self::window_size(
+            scope,
+            window_size_0,
+            window_start,
+            padded_end,
+            dilation_0,
+            self.kernel_size[0],
+        );
+        cpa!(scope, window_start = ow * pool_stride_1);
+        cpa!(scope, padded_end = border_right + padding_1);
+        Self::window_size(
+            scope,
+            window_size_1,
+            window_start,
+            padded_end,
+            dilation_1,
+            self.kernel_size[1],
+        );
+
+        let accumulator =
+            self.pool_strategy
+                .initialize(scope, input.item(), [window_size_0, window_size_1]);
 
         (0..self.kernel_size[0]).for_each(|kh| {
             cpa!(scope, ih = oh * pool_stride_0);
@@ -180,6 +208,25 @@ impl<P: PoolStrategy, R: JitRuntime, E: JitElement> Pool2dComputeShader<P, R, E>
         self.pool_strategy
             .assign(scope, id, output, self.indices, accumulator);
     }
+
+    /// The number of positions of a window in the padded input, which ends before the end of the
+    /// window for the partial windows added with `ceil_mode`.
+    fn window_size(
+        scope: &mut Scope,
+        window_size: Variable,
+        window_start: Variable,
+        padded_end: Variable,
+        dilation: Variable,
+        kernel_size: usize,
+    ) {
+        let kernel_size: Variable = kernel_size.into();
+
+        cpa!(scope, window_size = padded_end - window_start);
+        cpa!(scope, window_size = window_size + dilation);
+        cpa!(scope, window_size = window_size - 1u32);
+        cpa!(scope, window_size = window_size / dilation);
+        cpa!(scope, window_size = min(window_size, kernel_size));
+    }
 }
 
 #[derive(new)]
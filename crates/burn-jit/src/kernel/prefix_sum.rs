@@ -0,0 +1,99 @@
+//! Exclusive prefix sum of unsigned integers on the device.
+//!
+//! The values are split in blocks scanned by a cube each in shared memory. The sums of the blocks
+//! are scanned the same way, recursively, and added back to the values of their blocks, so the
+//! total stays on the device.
+use crate::{ops::numeric::empty_device, tensor::JitTensor, JitRuntime};
+use burn_tensor::Shape;
+use cubecl::{calculate_cube_count_elemwise, prelude::*};
+
+/// The number of values scanned by each cube, one per unit.
+const SCAN_BLOCK_SIZE: u32 = 256;
+
+/// Replace the values of each block with their exclusive prefix sum in the block, writing the sum
+/// of the block.
+///
+/// The units past the values scan zeros instead of returning, since they take part in the
+/// synchronizations of the cube.
+#[cube(launch)]
+fn scan_block_kernel(values: &mut Tensor<UInt>, block_sums: &mut Tensor<UInt>) {
+    let sums = SharedMemory::<UInt>::new(SCAN_BLOCK_SIZE);
+    let position = CUBE_POS_X * CUBE_DIM_X + UNIT_POS_X;
+
+    let mut value = UInt::new(0);
+    if position < values.len() {
+        value = values[position];
+    }
+    sums[UNIT_POS_X] = value;
+    sync_units();
+
+    // Inclusive Hillis-Steele scan, each step adding the sum of the previous `offset` values.
+    let mut offset = UInt::new(1);
+    while offset < CUBE_DIM_X {
+        let mut previous = UInt::new(0);
+        if UNIT_POS_X >= offset {
+            previous = sums[UNIT_POS_X - offset];
+        }
+        sync_units();
+
+        sums[UNIT_POS_X] = sums[UNIT_POS_X] + previous;
+        sync_units();
+
+        offset = offset * UInt::new(2);
+    }
+
+    if position < values.len() {
+        values[position] = sums[UNIT_POS_X] - value;
+    }
+    if UNIT_POS_X == CUBE_DIM_X - UInt::new(1) {
+        block_sums[CUBE_POS_X] = sums[UNIT_POS_X];
+    }
+}
+
+/// Add the exclusive prefix sum of the blocks to their values.
+#[cube(launch)]
+fn scan_add_kernel(values: &mut Tensor<UInt>, block_offsets: &Tensor<UInt>) {
+    if ABSOLUTE_POS >= values.len() {
+        return;
+    }
+
+    values[ABSOLUTE_POS] += block_offsets[ABSOLUTE_POS / UInt::new(SCAN_BLOCK_SIZE)];
+}
+
+/// Replace the values with their exclusive prefix sum, returning their total of shape `[1]`.
+pub(crate) fn exclusive_scan<R: JitRuntime>(values: &JitTensor<R, u32, 1>) -> JitTensor<R, u32, 1> {
+    let client = values.client.clone();
+    let num_blocks = values.shape.dims[0].div_ceil(SCAN_BLOCK_SIZE as usize);
+    let block_sums = empty_device::<R, u32, 1>(
+        client.clone(),
+        values.device.clone(),
+        Shape::new([num_blocks]),
+    );
+
+    crate::perf::record_kernel();
+    scan_block_kernel::launch::<R>(
+        &client,
+        CubeCount::Static(num_blocks as u32, 1, 1),
+        CubeDim::new(SCAN_BLOCK_SIZE, 1, 1),
+        values.as_tensor_arg(1),
+        block_sums.as_tensor_arg(1),
+    );
+
+    if num_blocks == 1 {
+        return block_sums;
+    }
+
+    let total = exclusive_scan(&block_sums);
+    let cube_dim = CubeDim::default();
+
+    crate::perf::record_kernel();
+    scan_add_kernel::launch::<R>(
+        &client,
+        calculate_cube_count_elemwise(values.shape.dims[0], cube_dim),
+        cube_dim,
+        values.as_tensor_arg(1),
+        block_sums.as_tensor_arg(1),
+    );
+
+    total
+}
@@ -11,8 +11,10 @@ use crate::{
     tensor::JitTensor,
     JitElement, JitRuntime, SEED,
 };
-use burn_common::rand::get_seeded_rng;
-use burn_tensor::Shape;
+use burn_tensor::{
+    backend::{DeviceId, DeviceOps},
+    Shape,
+};
 use rand::Rng;
 
 pub(crate) const N_VALUES_PER_THREAD: usize = 128;
@@ -26,10 +28,18 @@ pub(crate) fn random<P: Prng<E>, R: JitRuntime, E: JitElement, const D: usize>(
     let client = R::client(device);
     let kernel: PrngEagerKernel<P, R, E> = PrngEagerKernel::new();
     let num_elems = shape.num_elements();
-    let buffer = client.empty(num_elems * core::mem::size_of::<E>());
+    let num_bytes = num_elems * core::mem::size_of::<E>();
+    crate::perf::record_allocation(num_bytes);
+    let buffer = client.empty(num_bytes);
     let output = JitTensor::new_contiguous(client.clone(), device.clone(), shape.clone(), buffer);
-    let seeds = get_seeds();
 
+    if num_elems == 0 {
+        return output;
+    }
+
+    let seeds = get_seeds(device.id());
+
+    crate::perf::record_kernel();
     Execution::start(kernel, client)
         .outputs(&[output.as_handle_ref()])
         .with_scalars(&seeds)
@@ -126,19 +136,8 @@ pub(crate) struct PrngEagerKernel<P: Prng<E>, R: JitRuntime, E: JitElement> {
     _elem: PhantomData<E>,
 }
 
-pub(crate) fn get_seeds() -> [u32; 4] {
-    let mut seed = SEED.lock().unwrap();
-    let mut rng = match seed.as_ref() {
-        Some(rng_seeded) => rng_seeded.clone(),
-        None => get_seeded_rng(),
-    };
-    let mut seeds: Vec<u32> = Vec::with_capacity(4);
-    for _ in 0..4 {
-        seeds.push(rng.gen());
-    }
-    *seed = Some(rng);
-
-    seeds.try_into().unwrap()
+pub(crate) fn get_seeds(device: DeviceId) -> [u32; 4] {
+    SEED.with(device, |rng| [rng.gen(), rng.gen(), rng.gen(), rng.gen()])
 }
 
 pub(crate) trait Prng<E>: Send + Sync + 'static {
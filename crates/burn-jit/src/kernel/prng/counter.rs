@@ -0,0 +1,67 @@
+//! Counter-based random numbers, computed from a seed and the index of each value.
+//!
+//! Unlike the generators of the [random](super::random_uniform) kernels, which carry a state from
+//! one value to the next, a value only depends on the seed and its index, so any unit of any
+//! kernel can draw the values it needs without a buffer of random numbers or a shared state.
+use burn_tensor::backend::DeviceOps;
+use cubecl::{frontend::F32, prelude::*};
+use rand::Rng;
+
+use crate::{JitRuntime, SEED};
+
+/// Mix the bits of the value, a bijection of the integers of 32 bits whose outputs are hard to
+/// tell apart from random ones even for consecutive inputs.
+#[cube]
+fn hash(value: UInt) -> UInt {
+    let mut x = value;
+    x = x ^ (x >> UInt::new(16));
+    x = x * UInt::new(0x7feb_352d);
+    x = x ^ (x >> UInt::new(15));
+    x = x * UInt::new(0x846c_a68b);
+    x ^ (x >> UInt::new(16))
+}
+
+/// The random bits of the value at the index of the stream of the seed.
+#[cube]
+pub fn random_bits(seed: UInt, index: UInt) -> UInt {
+    hash(hash(index + hash(seed)) ^ seed)
+}
+
+/// The uniform value over `[0, 1)` of the 24 high bits, which fill the mantissa of a 32 bit float
+/// exactly.
+///
+/// It is always computed with 32 bit floats, since the bits overflow the range of a half
+/// precision float before being scaled.
+#[cube]
+fn uniform_f32(bits: UInt) -> F32 {
+    F32::cast_from(bits >> UInt::new(8)) * F32::new(1.0 / 16_777_216.0)
+}
+
+/// A random value of the uniform distribution over `[0, 1)` at the index of the stream of the seed.
+///
+/// Casting to a float of lower precision may round the values close to 1 up to 1.
+#[cube]
+pub fn random_uniform<F: Float>(seed: UInt, index: UInt) -> F {
+    F::cast_from(uniform_f32(random_bits(seed, index)))
+}
+
+/// A random value of the standard normal distribution at the index of the stream of the seed,
+/// computed with the Box-Muller transform of the uniform values at the indices `2 * index` and
+/// `2 * index + 1`.
+#[cube]
+pub fn random_normal<F: Float>(seed: UInt, index: UInt) -> F {
+    // Shifted to `(0, 1]` so that the logarithm is finite.
+    let u1 = uniform_f32(random_bits(seed, index * UInt::new(2))) + F32::new(1.0 / 16_777_216.0);
+    let u2 = uniform_f32(random_bits(seed, index * UInt::new(2) + UInt::new(1)));
+
+    F::cast_from(
+        F32::sqrt(F32::new(-2.0) * F32::log(u1))
+            * F32::cos(F32::new(2.0 * core::f32::consts::PI) * u2),
+    )
+}
+
+/// Draw a seed of the [counter-based](random_bits) generators from the seeded generator of the
+/// device, to be passed to a kernel as a scalar argument.
+pub fn random_seed<R: JitRuntime>(device: &R::Device) -> u32 {
+    SEED.with(device.id(), |rng| rng.gen())
+}
@@ -0,0 +1,59 @@
+use cubecl::{calculate_cube_count_elemwise, frontend::F32, prelude::*};
+
+use crate::{
+    kernel::{into_contiguous, prng::counter},
+    ops::numeric::empty_device,
+    tensor::JitTensor,
+    FloatElement, JitRuntime,
+};
+
+#[cube(launch)]
+fn dropout_kernel<F: Float>(input: &Tensor<F>, output: &mut Tensor<F>, prob: F, seed: UInt) {
+    if ABSOLUTE_POS >= input.len() {
+        return;
+    }
+
+    // Compared with 32 bit floats, the uniform values rounding up to 1 with half precision.
+    if counter::random_uniform::<F32>(seed, ABSOLUTE_POS) < F32::cast_from(prob) {
+        output[ABSOLUTE_POS] = F::new(0.0);
+    } else {
+        output[ABSOLUTE_POS] = input[ABSOLUTE_POS] / (F::new(1.0) - prob);
+    }
+}
+
+/// Zero each element of the tensor with the probability `prob`, scaling the other ones by
+/// `1 / (1 - prob)`.
+///
+/// The mask is drawn inside the kernel with the [counter-based](counter) generator, instead
+/// of being written to a random tensor by another kernel and read back.
+pub fn dropout<R: JitRuntime, E: FloatElement, const D: usize>(
+    input: JitTensor<R, E, D>,
+    prob: f64,
+    seed: u32,
+) -> JitTensor<R, E, D> {
+    let input = into_contiguous(input);
+    let output = empty_device(
+        input.client.clone(),
+        input.device.clone(),
+        input.shape.clone(),
+    );
+
+    if input.is_empty() {
+        return output;
+    }
+
+    let num_elems = input.shape.num_elements();
+    let cube_dim = CubeDim::default();
+    crate::perf::record_kernel();
+    dropout_kernel::launch::<E::FloatPrimitive, R>(
+        &input.client,
+        calculate_cube_count_elemwise(num_elems, cube_dim),
+        cube_dim,
+        input.as_tensor_arg(1),
+        output.as_tensor_arg(1),
+        ScalarArg::new(E::from_elem(prob)),
+        ScalarArg::new(seed),
+    );
+
+    output
+}
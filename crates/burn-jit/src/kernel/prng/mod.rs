@@ -1,9 +1,13 @@
 mod base;
 mod bernoulli;
+/// Counter-based random numbers, usable inside any kernel
+pub mod counter;
+mod dropout;
 mod normal;
 mod uniform;
 
 pub use base::*;
 pub use bernoulli::*;
+pub use dropout::*;
 pub use normal::*;
 pub use uniform::*;
@@ -23,9 +23,9 @@ pub fn init_reduce_output<R: JitRuntime, EI: JitElement, EO: JitElement, const D
 
     // Create output handle
     let num_elems_output = shape_out.num_elements();
-    let handle = input
-        .client
-        .empty(num_elems_output * core::mem::size_of::<EO>());
+    let num_bytes = num_elems_output * core::mem::size_of::<EO>();
+    crate::perf::record_allocation(num_bytes);
+    let handle = input.client.empty(num_bytes);
     JitTensor::new_contiguous(
         input.client.clone(),
         input.device.clone(),
@@ -73,6 +73,17 @@ macro_rules! reduce_operation {
             dim: usize,
             strategy: ReduceStrategy,
         ) -> JitTensor<R, EO, D> {
+            if tensor.shape.dims[dim] == 0 {
+                let output = init_reduce_output(&tensor, dim);
+
+                if output.is_empty() {
+                    return output;
+                }
+
+                // Only the naive kernel handles empty reductions, which never read the input.
+                return reduce_dim_naive::<$ops, R, EI, EO, D>(tensor, output, dim);
+            }
+
             match strategy {
                 ReduceStrategy::Naive => {
                     let output = init_reduce_output(&tensor, dim);
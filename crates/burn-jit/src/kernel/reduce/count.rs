@@ -0,0 +1,245 @@
+use cubecl::{calculate_cube_count_elemwise, prelude::*};
+
+use crate::{
+    element::JitElement,
+    kernel::{equal_elem, into_contiguous},
+    ops::{broadcast_shape, expand, numeric::empty_device, permute, reshape},
+    tensor::JitTensor,
+    FloatElement, IntElement, JitRuntime,
+};
+use burn_tensor::{ElementConversion, Shape};
+
+use super::sum;
+
+/// The number of elements compared by each unit when testing the closeness of two tensors.
+const NUM_ELEMS_PER_UNIT: usize = 256;
+/// The maximum number of units counting a row.
+const MAX_UNITS_PER_ROW: u32 = 256;
+/// The maximum number of cubes along a dimension of the dispatch.
+const MAX_CUBE_COUNT: usize = u16::MAX as usize;
+
+/// Counts the non-zero values of each row of contiguous values, a cube counting each row.
+///
+/// Each unit counts the values at its position modulo the cube size, the counts of the units
+/// being summed in shared memory.
+#[cube(launch)]
+fn count_nonzero_kernel<N: Numeric, I: Int>(
+    input: &Tensor<N>,
+    output: &mut Tensor<I>,
+    num_elems: UInt,
+    cubes_x: UInt,
+) {
+    // The row is the same for all the units of the cube, which return together.
+    let row = CUBE_POS_Y * cubes_x + CUBE_POS_X;
+    if row >= output.len() {
+        return;
+    }
+
+    let offset = row * num_elems;
+    let mut count = UInt::new(0);
+    let mut index = UNIT_POS_X;
+
+    while index < num_elems {
+        if input[offset + index] != N::from_int(0) {
+            count += UInt::new(1);
+        }
+        index += CUBE_DIM_X;
+    }
+
+    let mut counts = SharedMemory::<UInt>::new(MAX_UNITS_PER_ROW);
+    counts[UNIT_POS_X] = count;
+    sync_units();
+
+    let mut stride = CUBE_DIM_X / UInt::new(2);
+    while stride > UInt::new(0) {
+        if UNIT_POS_X < stride {
+            counts[UNIT_POS_X] = counts[UNIT_POS_X] + counts[UNIT_POS_X + stride];
+        }
+        sync_units();
+
+        stride = stride / UInt::new(2);
+    }
+
+    if UNIT_POS_X == UInt::new(0) {
+        output[row] = I::cast_from(counts[UInt::new(0)]);
+    }
+}
+
+/// If `a` is close to `b`, NaN failing both comparisons while equal infinities are close.
+#[cube]
+fn is_close<F: Float>(a: F, b: F, rtol: F, atol: F) -> bool {
+    a == b || F::abs(a - b) <= atol + rtol * F::abs(b)
+}
+
+#[cube(launch)]
+fn is_close_kernel<F: Float>(
+    lhs: &Tensor<F>,
+    rhs: &Tensor<F>,
+    output: &mut Tensor<UInt>,
+    rtol: F,
+    atol: F,
+) {
+    if ABSOLUTE_POS >= output.len() {
+        return;
+    }
+
+    output[ABSOLUTE_POS] =
+        UInt::cast_from(is_close(lhs[ABSOLUTE_POS], rhs[ABSOLUTE_POS], rtol, atol));
+}
+
+/// Counts the values of each chunk of contiguous values that are not close, so that the
+/// comparison and the reduction of the chunk happen in a single pass.
+#[cube(launch)]
+fn count_not_close_kernel<F: Float, I: Int>(
+    lhs: &Tensor<F>,
+    rhs: &Tensor<F>,
+    output: &mut Tensor<I>,
+    num_elems: UInt,
+    rtol: F,
+    atol: F,
+) {
+    if ABSOLUTE_POS >= output.len() {
+        return;
+    }
+
+    let offset = ABSOLUTE_POS * num_elems;
+    let mut count = I::from_int(0);
+
+    for i in range(0u32, num_elems, Comptime::new(false)) {
+        let index = offset + i;
+
+        if index < lhs.len() {
+            if !is_close(lhs[index], rhs[index], rtol, atol) {
+                count += I::from_int(1);
+            }
+        }
+    }
+
+    output[ABSOLUTE_POS] = count;
+}
+
+/// Counts the non-zero values along the given dimension.
+pub fn count_nonzero<R: JitRuntime, E: JitElement, I: IntElement, const D: usize>(
+    tensor: JitTensor<R, E, D>,
+    dim: usize,
+) -> JitTensor<R, I, D> {
+    // Move the counted dimension last, so that each output counts contiguous values.
+    let mut axes = [0; D];
+    for (axis, d) in axes
+        .iter_mut()
+        .zip((0..D).filter(|d| *d != dim).chain([dim]))
+    {
+        *axis = d;
+    }
+
+    let mut shape_out = tensor.shape.clone();
+    shape_out.dims[dim] = 1;
+    let num_elems = tensor.shape.dims[dim];
+    let num_outputs = shape_out.num_elements();
+
+    let input = into_contiguous(permute(tensor, axes));
+    let output = empty_device::<R, I, 1>(
+        input.client.clone(),
+        input.device.clone(),
+        Shape::new([num_outputs]),
+    );
+
+    if num_outputs == 0 {
+        return reshape(output, shape_out);
+    }
+
+    // The units of a cube sum their counts in halves, so their number is a power of two.
+    let units = num_elems
+        .next_power_of_two()
+        .clamp(32, MAX_UNITS_PER_ROW as usize);
+    let cubes_x = num_outputs.min(MAX_CUBE_COUNT);
+    let cubes_y = num_outputs.div_ceil(cubes_x);
+
+    crate::perf::record_kernel();
+    count_nonzero_kernel::launch::<E::Primitive, I::Primitive, R>(
+        &input.client,
+        CubeCount::Static(cubes_x as u32, cubes_y as u32, 1),
+        CubeDim::new(units as u32, 1, 1),
+        input.as_tensor_arg(1),
+        output.as_tensor_arg(1),
+        ScalarArg::new(num_elems as u32),
+        ScalarArg::new(cubes_x as u32),
+    );
+
+    // The kept dimensions are in order, so the output only needs to be reshaped.
+    reshape(output, shape_out)
+}
+
+/// Tests element wise if the values of the tensors are close, in a single kernel.
+pub fn is_close<R: JitRuntime, E: FloatElement, const D: usize>(
+    lhs: JitTensor<R, E, D>,
+    rhs: JitTensor<R, E, D>,
+    rtol: f64,
+    atol: f64,
+) -> JitTensor<R, u32, D> {
+    // Broadcast the tensors to the same shape.
+    let shape = broadcast_shape(&lhs.shape, &rhs.shape);
+    let lhs = into_contiguous(expand(lhs, shape.clone()));
+    let rhs = into_contiguous(expand(rhs, shape.clone()));
+    let output = empty_device::<R, u32, D>(lhs.client.clone(), lhs.device.clone(), shape);
+
+    let num_elems = output.shape.num_elements();
+    if num_elems == 0 {
+        return output;
+    }
+
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(num_elems, cube_dim);
+
+    crate::perf::record_kernel();
+    is_close_kernel::launch::<E::FloatPrimitive, R>(
+        &lhs.client,
+        cube_count,
+        cube_dim,
+        lhs.as_tensor_arg(1),
+        rhs.as_tensor_arg(1),
+        output.as_tensor_arg(1),
+        ScalarArg::new(rtol.elem::<E>()),
+        ScalarArg::new(atol.elem::<E>()),
+    );
+
+    output
+}
+
+/// Tests if all the values of the tensors are close, reading back a single boolean.
+pub fn all_close<R: JitRuntime, E: FloatElement, I: IntElement, const D: usize>(
+    lhs: JitTensor<R, E, D>,
+    rhs: JitTensor<R, E, D>,
+    rtol: f64,
+    atol: f64,
+) -> JitTensor<R, u32, 1> {
+    // Broadcast the tensors to the same shape.
+    let shape = broadcast_shape(&lhs.shape, &rhs.shape);
+    let lhs = into_contiguous(expand(lhs, shape.clone()));
+    let rhs = into_contiguous(expand(rhs, shape.clone()));
+
+    let num_outputs = usize::max(shape.num_elements().div_ceil(NUM_ELEMS_PER_UNIT), 1);
+    let counts = empty_device::<R, I, 1>(
+        lhs.client.clone(),
+        lhs.device.clone(),
+        Shape::new([num_outputs]),
+    );
+
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(num_outputs, cube_dim);
+
+    crate::perf::record_kernel();
+    count_not_close_kernel::launch::<E::FloatPrimitive, I::Primitive, R>(
+        &lhs.client,
+        cube_count,
+        cube_dim,
+        lhs.as_tensor_arg(1),
+        rhs.as_tensor_arg(1),
+        counts.as_tensor_arg(1),
+        ScalarArg::new(NUM_ELEMS_PER_UNIT as u32),
+        ScalarArg::new(rtol.elem::<E>()),
+        ScalarArg::new(atol.elem::<E>()),
+    );
+
+    equal_elem(sum(counts, Default::default()), 0.elem())
+}
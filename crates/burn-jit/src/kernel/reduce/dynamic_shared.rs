@@ -0,0 +1,233 @@
+use core::fmt::Display;
+
+use cubecl::{
+    prelude::{CompiledKernel, CubeCount, CubeDim, CubeTask},
+    ExecutionMode, KernelId,
+};
+
+use crate::{
+    device_properties, kernel::into_contiguous, ops::numeric::empty_device, tensor::JitTensor,
+    JitRuntime,
+};
+
+/// The error of a launch whose dynamic shared memory can't be allocated.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DynamicSharedMemoryError {
+    /// The shared memory of a cube is larger than the limit of the device.
+    TooLarge {
+        /// The number of bytes requested by the launch.
+        size: usize,
+        /// The number of bytes a cube of the device can allocate.
+        max_size: usize,
+    },
+    /// The number of units of a cube isn't a power of two, which the tree reduction requires.
+    InvalidCubeDim(u32),
+    /// The runtime has no source for the kernel.
+    UnsupportedRuntime(&'static str),
+}
+
+impl Display for DynamicSharedMemoryError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::TooLarge { size, max_size } => write!(
+                f,
+                "The dynamic shared memory of {size} bytes is larger than the {max_size} bytes \
+                 available to a cube of the device."
+            ),
+            Self::InvalidCubeDim(dim) => {
+                write!(f, "The cube of {dim} units isn't a power of two.")
+            }
+            Self::UnsupportedRuntime(name) => {
+                write!(f, "The runtime {name} has no dynamic shared memory kernel.")
+            }
+        }
+    }
+}
+
+/// Sum the last dimension of the tensor with cubes of `cube_dim_x` units, each cube reducing a
+/// row in shared memory sized at launch time.
+///
+/// On CUDA the size of the shared memory is a launch parameter, so a single compiled kernel is
+/// reused for every cube size. WGSL has no dynamically sized workgroup memory, so the source is
+/// specialized with the size instead, compiling one kernel per cube size.
+pub fn sum_last_dim_dynamic_shared<R: JitRuntime, const D: usize>(
+    input: JitTensor<R, f32, D>,
+    cube_dim_x: u32,
+) -> Result<JitTensor<R, f32, D>, DynamicSharedMemoryError> {
+    if !cube_dim_x.is_power_of_two() {
+        return Err(DynamicSharedMemoryError::InvalidCubeDim(cube_dim_x));
+    }
+
+    let language = match R::name() {
+        "cuda" => SourceLanguage::Cuda,
+        "wgpu" => SourceLanguage::Wgsl,
+        name => return Err(DynamicSharedMemoryError::UnsupportedRuntime(name)),
+    };
+
+    let shared_mem_bytes = cube_dim_x as usize * core::mem::size_of::<f32>();
+    if let Some(max_size) = device_properties::<R>(&input.device).max_shared_memory_size {
+        if shared_mem_bytes > max_size as usize {
+            return Err(DynamicSharedMemoryError::TooLarge {
+                size: shared_mem_bytes,
+                max_size: max_size as usize,
+            });
+        }
+    }
+
+    let input = into_contiguous(input);
+    let row_len = input.shape.dims[D - 1];
+    let num_rows = input.shape.num_elements() / row_len.max(1);
+
+    let mut shape = input.shape.clone();
+    shape.dims[D - 1] = 1;
+    let output = empty_device::<R, f32, D>(input.client.clone(), input.device.clone(), shape);
+
+    let info = [num_rows as u32, row_len as u32];
+    let info = input.client.create(bytemuck::cast_slice(&info));
+
+    let kernel = DynamicSharedSumKernel {
+        language,
+        cube_dim: CubeDim::new(cube_dim_x, 1, 1),
+        shared_mem_bytes,
+    };
+    let cubes_x = num_rows.clamp(1, u16::MAX as usize);
+    let cubes_y = num_rows.div_ceil(cubes_x).max(1);
+
+    crate::perf::record_kernel();
+    input.client.execute(
+        Box::new(kernel),
+        CubeCount::Static(cubes_x as u32, cubes_y as u32, 1),
+        vec![
+            input.handle.clone().binding(),
+            output.handle.clone().binding(),
+            info.binding(),
+        ],
+    );
+
+    Ok(output)
+}
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+enum SourceLanguage {
+    Cuda,
+    Wgsl,
+}
+
+struct DynamicSharedSumKernel {
+    language: SourceLanguage,
+    cube_dim: CubeDim,
+    shared_mem_bytes: usize,
+}
+
+impl CubeTask for DynamicSharedSumKernel {
+    fn compile(&self, _mode: ExecutionMode) -> CompiledKernel {
+        let (source, shared_mem_bytes) = match self.language {
+            SourceLanguage::Cuda => (CUDA_SOURCE.to_string(), self.shared_mem_bytes),
+            // The workgroup memory is declared in the source, so none is allocated at launch.
+            SourceLanguage::Wgsl => (
+                WGSL_SOURCE
+                    .replace("{{ workgroup_size }}", &self.cube_dim.x.to_string())
+                    .replace(
+                        "{{ shared_memory_len }}",
+                        &(self.shared_mem_bytes / core::mem::size_of::<f32>()).to_string(),
+                    ),
+                0,
+            ),
+        };
+
+        CompiledKernel {
+            name: Some(core::any::type_name::<Self>()),
+            source,
+            cube_dim: self.cube_dim,
+            shared_mem_bytes,
+            debug_info: None,
+        }
+    }
+
+    fn id(&self) -> KernelId {
+        match self.language {
+            // The same module is launched with any shared memory size.
+            SourceLanguage::Cuda => KernelId::new::<Self>().info(self.language),
+            SourceLanguage::Wgsl => KernelId::new::<Self>().info((self.language, self.cube_dim.x)),
+        }
+    }
+}
+
+const CUDA_SOURCE: &str = r#"
+extern "C" __global__ void kernel(float input[], float output[], unsigned int info[]) {
+    extern __shared__ float shared_memory[];
+
+    const unsigned int num_rows = info[0];
+    const unsigned int row_len = info[1];
+    const unsigned int row = blockIdx.x + blockIdx.y * gridDim.x;
+    const unsigned int unit = threadIdx.x;
+
+    float partial = 0.0f;
+    if (row < num_rows) {
+        for (unsigned int i = unit; i < row_len; i += blockDim.x) {
+            partial += input[row * row_len + i];
+        }
+    }
+    shared_memory[unit] = partial;
+    __syncthreads();
+
+    for (unsigned int stride = blockDim.x / 2; stride > 0; stride /= 2) {
+        if (unit < stride) {
+            shared_memory[unit] += shared_memory[unit + stride];
+        }
+        __syncthreads();
+    }
+
+    if (unit == 0 && row < num_rows) {
+        output[row] = shared_memory[0];
+    }
+}
+"#;
+
+const WGSL_SOURCE: &str = r#"
+@group(0) @binding(0)
+var<storage, read_write> input: array<f32>;
+
+@group(0) @binding(1)
+var<storage, read_write> output: array<f32>;
+
+@group(0) @binding(2)
+var<storage, read_write> info: array<u32>;
+
+var<workgroup> shared_memory: array<f32, {{ shared_memory_len }}>;
+
+const WORKGROUP_SIZE = {{ workgroup_size }}u;
+
+@compute
+@workgroup_size({{ workgroup_size }}, 1, 1)
+fn main(
+    @builtin(local_invocation_index) unit: u32,
+    @builtin(workgroup_id) workgroup_id: vec3<u32>,
+    @builtin(num_workgroups) num_workgroups: vec3<u32>,
+) {
+    let num_rows = info[0];
+    let row_len = info[1];
+    let row = workgroup_id.x + workgroup_id.y * num_workgroups.x;
+
+    var partial = 0.0;
+    if row < num_rows {
+        for (var i = unit; i < row_len; i += WORKGROUP_SIZE) {
+            partial += input[row * row_len + i];
+        }
+    }
+    shared_memory[unit] = partial;
+    workgroupBarrier();
+
+    // The bounds of the loop are constant, keeping the barriers in uniform control flow.
+    for (var stride = WORKGROUP_SIZE / 2u; stride > 0u; stride /= 2u) {
+        if unit < stride {
+            shared_memory[unit] += shared_memory[unit + stride];
+        }
+        workgroupBarrier();
+    }
+
+    if unit == 0u && row < num_rows {
+        output[row] = shared_memory[0];
+    }
+}
+"#;
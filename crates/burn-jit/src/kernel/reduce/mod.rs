@@ -1,11 +1,17 @@
 mod base;
+mod count;
+mod dynamic_shared;
 mod naive;
 mod prod;
 mod shared;
 mod sum;
 mod tune;
+mod var_mean;
 
 pub use base::*;
+pub use count::*;
+pub use dynamic_shared::*;
 pub use prod::*;
 pub use sum::*;
 pub use tune::*;
+pub use var_mean::*;
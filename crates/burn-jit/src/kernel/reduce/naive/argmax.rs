@@ -1,33 +1,34 @@
 use super::base::ReduceDimNaive;
 use crate::kernel::reduce::Argmax;
 use cubecl::cube;
-use cubecl::frontend::{Float, Tensor, UInt, ABSOLUTE_POS, F32};
+use cubecl::frontend::{Tensor, UInt, ABSOLUTE_POS};
 use cubecl::prelude::{Cast, Numeric};
 
 #[allow(clippy::extra_unused_type_parameters)]
 #[cube]
 impl<EI: Numeric> ReduceDimNaive<EI> for Argmax {
-    type Accumulator = (F32, UInt);
+    // The values are compared with the input element, casting to f32 would merge distinct
+    // values of wider elements.
+    type Accumulator = (EI, UInt);
 
-    fn initialize_naive() -> (F32, UInt) {
-        // TODO: switch to using f32::NEG_INFINITY when it's supported: https://github.com/tracel-ai/cubecl/issues/68
-        let a = F32::new(0.0);
-        let b = F32::new(100000000.0);
+    fn initialize_naive() -> (EI, UInt) {
+        // TODO: switch to using the minimum value when it's supported: https://github.com/tracel-ai/cubecl/issues/68
+        let a = EI::from_int(0);
+        let b = EI::from_int(100000000);
         (a - b, UInt::new(0))
     }
 
-    fn inner_loop_naive(accumulator: &mut (F32, UInt), current_value: EI, i: UInt) {
+    fn inner_loop_naive(accumulator: &mut (EI, UInt), current_value: EI, i: UInt) {
         let (max, index) = accumulator;
-        let val = F32::cast_from(current_value);
-        if val > *max {
-            *max = val;
+        if current_value > *max {
+            *max = current_value;
             *index = i;
         }
     }
 
     fn assign_naive<EO: Numeric>(
         output: &mut Tensor<EO>,
-        accumulator: (F32, UInt),
+        accumulator: (EI, UInt),
         _shape_reduce_dim: UInt,
     ) {
         let (_, index) = accumulator;
@@ -50,6 +50,8 @@ pub fn reduce_dim_naive<
     let cube_count =
         calculate_cube_count_elemwise::<R::Server>(output.shape.num_elements(), cube_dim);
 
+    crate::perf::record_kernel();
+
     unsafe {
         naive_reduce_dim_compute_shader::launch_unchecked::<RD, EI::Primitive, EO::Primitive, R>(
             &input.client,
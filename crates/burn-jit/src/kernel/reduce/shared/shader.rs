@@ -266,6 +266,7 @@ pub fn reduce_dim_shared<
         divisible_shape,
     );
 
+    crate::perf::record_kernel();
     Execution::start(kernel, input.client.clone())
         .inputs(&[input.as_handle_ref()])
         .outputs(&[output.as_handle_ref()])
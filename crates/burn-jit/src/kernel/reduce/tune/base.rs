@@ -14,6 +14,7 @@ use crate::{
     },
     ops::numeric::empty_device,
     tensor::JitTensor,
+    tune_cache::execute_tuned,
     tune_key::JitAutotuneKey,
     JitRuntime, JitTuneId,
 };
@@ -120,7 +121,7 @@ pub(crate) fn reduce_dim_autotune<
     let client = input.client.clone();
 
     let output = init_reduce_output(&input, reduce_dim);
-    let id = JitTuneId::new::<R>(&input.device);
+    let device = input.device.clone();
 
     let operation_set = Box::new(ReduceDimAutotuneOperationSet::<RD, R, EI, EO, D>::new(
         input,
@@ -130,7 +131,7 @@ pub(crate) fn reduce_dim_autotune<
 
     static TUNER: LocalTuner<JitAutotuneKey, JitTuneId> = local_tuner!();
 
-    TUNER.execute(&id, &client, operation_set);
+    execute_tuned::<R>(&TUNER, &device, &client, operation_set, 2);
 
     output
 }
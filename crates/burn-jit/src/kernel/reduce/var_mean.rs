@@ -0,0 +1,148 @@
+use cubecl::prelude::*;
+
+use crate::{
+    kernel::into_contiguous,
+    ops::{numeric::empty_device, permute, reshape},
+    tensor::JitTensor,
+    FloatElement, JitRuntime,
+};
+use burn_tensor::{ElementConversion, Shape};
+
+/// The maximum number of units reducing a row.
+const MAX_UNITS_PER_ROW: u32 = 256;
+/// The maximum number of cubes along a dimension of the dispatch.
+const MAX_CUBE_COUNT: usize = u16::MAX as usize;
+
+/// Computes the variance and the mean of each row of contiguous values, a cube reducing each row.
+///
+/// Each unit computes the count, the mean and the sum of the squared differences to the mean of
+/// the values at its position modulo the cube size with the Welford's algorithm. The statistics of
+/// the units are then merged in shared memory with the Chan's formula, so the variance is never
+/// negative and the variance of equal values is exactly zero.
+#[cube(launch)]
+fn var_mean_kernel<F: Float>(
+    input: &Tensor<F>,
+    var: &mut Tensor<F>,
+    mean: &mut Tensor<F>,
+    num_elems: UInt,
+    divisor: F,
+    cubes_x: UInt,
+) {
+    // The row is the same for all the units of the cube, which return together.
+    let row = CUBE_POS_Y * cubes_x + CUBE_POS_X;
+    if row >= mean.len() {
+        return;
+    }
+
+    let offset = row * num_elems;
+    let mut count = UInt::new(0);
+    let mut average = F::new(0.0);
+    let mut squares = F::new(0.0);
+    let mut index = UNIT_POS_X;
+
+    while index < num_elems {
+        let value = input[offset + index];
+        count += UInt::new(1);
+        let delta = value - average;
+        average += delta / F::cast_from(count);
+        squares += delta * (value - average);
+        index += CUBE_DIM_X;
+    }
+
+    let mut counts = SharedMemory::<UInt>::new(MAX_UNITS_PER_ROW);
+    let mut averages = SharedMemory::<F>::new(MAX_UNITS_PER_ROW);
+    let mut sums = SharedMemory::<F>::new(MAX_UNITS_PER_ROW);
+    counts[UNIT_POS_X] = count;
+    averages[UNIT_POS_X] = average;
+    sums[UNIT_POS_X] = squares;
+    sync_units();
+
+    let mut stride = CUBE_DIM_X / UInt::new(2);
+    while stride > UInt::new(0) {
+        if UNIT_POS_X < stride {
+            let other = UNIT_POS_X + stride;
+            let count_other = counts[other];
+
+            // The units past the end of a short row have no value to merge.
+            if count_other > UInt::new(0) {
+                let count_self = F::cast_from(counts[UNIT_POS_X]);
+                let total = counts[UNIT_POS_X] + count_other;
+                let weight = F::cast_from(count_other) / F::cast_from(total);
+                let delta = averages[other] - averages[UNIT_POS_X];
+
+                averages[UNIT_POS_X] = averages[UNIT_POS_X] + delta * weight;
+                sums[UNIT_POS_X] =
+                    sums[UNIT_POS_X] + sums[other] + delta * delta * count_self * weight;
+                counts[UNIT_POS_X] = total;
+            }
+        }
+        sync_units();
+
+        stride = stride / UInt::new(2);
+    }
+
+    if UNIT_POS_X == UInt::new(0) {
+        mean[row] = averages[UInt::new(0)];
+        var[row] = sums[UInt::new(0)] / divisor;
+    }
+}
+
+/// Computes the variance and the mean along the given dimensions in a single pass.
+pub fn var_mean<R: JitRuntime, E: FloatElement, const D: usize>(
+    tensor: JitTensor<R, E, D>,
+    dims: &[usize],
+    correction: usize,
+) -> (JitTensor<R, E, D>, JitTensor<R, E, D>) {
+    // Move the reduced dimensions last, so that each output reduces contiguous values.
+    let mut axes = [0; D];
+    let kept = (0..D).filter(|dim| !dims.contains(dim));
+    for (axis, dim) in axes.iter_mut().zip(kept.chain(dims.iter().copied())) {
+        *axis = dim;
+    }
+
+    let mut shape_out = tensor.shape.clone();
+    for dim in dims {
+        shape_out.dims[*dim] = 1;
+    }
+    let num_elems: usize = dims.iter().map(|dim| tensor.shape.dims[*dim]).product();
+    let num_outputs = shape_out.num_elements();
+
+    let input = into_contiguous(permute(tensor, axes));
+    let empty = || {
+        empty_device::<R, E, 1>(
+            input.client.clone(),
+            input.device.clone(),
+            Shape::new([num_outputs]),
+        )
+    };
+    let var = empty();
+    let mean = empty();
+    let divisor = num_elems.saturating_sub(correction) as f64;
+
+    if num_outputs == 0 {
+        return (reshape(var, shape_out.clone()), reshape(mean, shape_out));
+    }
+
+    // The units of a cube merge their statistics in halves, so their number is a power of two.
+    let units = num_elems
+        .next_power_of_two()
+        .clamp(32, MAX_UNITS_PER_ROW as usize);
+    let cubes_x = num_outputs.min(MAX_CUBE_COUNT);
+    let cubes_y = num_outputs.div_ceil(cubes_x);
+
+    crate::perf::record_kernel();
+    var_mean_kernel::launch::<E::FloatPrimitive, R>(
+        &input.client,
+        CubeCount::Static(cubes_x as u32, cubes_y as u32, 1),
+        CubeDim::new(units as u32, 1, 1),
+        input.as_tensor_arg(1),
+        var.as_tensor_arg(1),
+        mean.as_tensor_arg(1),
+        ScalarArg::new(num_elems as u32),
+        ScalarArg::new(divisor.elem::<E>()),
+        ScalarArg::new(cubes_x as u32),
+    );
+
+    // The kept dimensions are in order, so the outputs only need to be reshaped.
+    (reshape(var, shape_out.clone()), reshape(mean, shape_out))
+}
@@ -0,0 +1,216 @@
+//! Associative scan of the linear recurrence `y[t] = a[t] * y[t - 1] + b[t]`.
+//!
+//! An element `(a, b)` is the affine map `y -> a * y + b`, and the composition of the maps is
+//! associative, so the prefixes are computed with a work-efficient Blelloch scan. The scanned
+//! dimension is moved last and padded to a power of two with identity maps, and each line is
+//! scanned in its own segment of the work buffers. The up-sweep reduces the maps in a balanced
+//! tree, the down-sweep turns the tree into the exclusive prefixes, and the output applies each
+//! exclusive prefix to its element.
+use crate::{
+    kernel::into_contiguous,
+    ops::{numeric::empty_device, swap_dims},
+    tensor::JitTensor,
+    FloatElement, JitRuntime,
+};
+use burn_tensor::Shape;
+use cubecl::{calculate_cube_count_elemwise, prelude::*, CubeDim};
+
+#[cube(launch_unchecked)]
+fn scan_init_kernel<F: Float>(
+    a: &Tensor<F>,
+    b: &Tensor<F>,
+    work_a: &mut Tensor<F>,
+    work_b: &mut Tensor<F>,
+    length: UInt,
+) {
+    if ABSOLUTE_POS >= work_a.len() {
+        return;
+    }
+
+    let length_padded = work_a.shape(1);
+    let line = ABSOLUTE_POS / length_padded;
+    let t = ABSOLUTE_POS % length_padded;
+
+    if t < length {
+        let index = line * length + t;
+        work_a[ABSOLUTE_POS] = a[index];
+        work_b[ABSOLUTE_POS] = b[index];
+    } else {
+        work_a[ABSOLUTE_POS] = F::new(1.);
+        work_b[ABSOLUTE_POS] = F::new(0.);
+    }
+}
+
+#[cube(launch_unchecked)]
+fn scan_up_sweep_kernel<F: Float>(work_a: &mut Tensor<F>, work_b: &mut Tensor<F>, stride: UInt) {
+    let length_padded = work_a.shape(1);
+    let num_pairs = length_padded / (stride * UInt::new(2));
+
+    if ABSOLUTE_POS >= work_a.shape(0) * num_pairs {
+        return;
+    }
+
+    let line = ABSOLUTE_POS / num_pairs;
+    let pair = ABSOLUTE_POS % num_pairs;
+    let left = line * length_padded + pair * stride * UInt::new(2) + stride - UInt::new(1);
+    let right = left + stride;
+
+    work_b[right] = work_a[right] * work_b[left] + work_b[right];
+    work_a[right] = work_a[left] * work_a[right];
+}
+
+#[cube(launch_unchecked)]
+fn scan_down_sweep_kernel<F: Float>(
+    work_a: &mut Tensor<F>,
+    work_b: &mut Tensor<F>,
+    stride: UInt,
+    first: Comptime<bool>,
+) {
+    let length_padded = work_a.shape(1);
+    let num_pairs = length_padded / (stride * UInt::new(2));
+
+    if ABSOLUTE_POS >= work_a.shape(0) * num_pairs {
+        return;
+    }
+
+    let line = ABSOLUTE_POS / num_pairs;
+    let pair = ABSOLUTE_POS % num_pairs;
+    let left = line * length_padded + pair * stride * UInt::new(2) + stride - UInt::new(1);
+    let right = left + stride;
+
+    // The root of the tree is the exclusive prefix of the first element, the identity map.
+    let mut prefix_a = F::new(1.);
+    let mut prefix_b = F::new(0.);
+    if !Comptime::get(first) {
+        prefix_a = work_a[right];
+        prefix_b = work_b[right];
+    }
+
+    let left_a = work_a[left];
+    let left_b = work_b[left];
+
+    work_a[left] = prefix_a;
+    work_b[left] = prefix_b;
+    work_a[right] = left_a * prefix_a;
+    work_b[right] = left_a * prefix_b + left_b;
+}
+
+#[cube(launch_unchecked)]
+fn scan_output_kernel<F: Float>(
+    a: &Tensor<F>,
+    b: &Tensor<F>,
+    work_b: &Tensor<F>,
+    output: &mut Tensor<F>,
+    length: UInt,
+) {
+    if ABSOLUTE_POS >= output.len() {
+        return;
+    }
+
+    let line = ABSOLUTE_POS / length;
+    let t = ABSOLUTE_POS % length;
+    let previous = work_b[line * work_b.shape(1) + t];
+
+    output[ABSOLUTE_POS] = a[ABSOLUTE_POS] * previous + b[ABSOLUTE_POS];
+}
+
+/// Compute the linear recurrence `y[t] = a[t] * y[t - 1] + b[t]` along the dimension, with
+/// `y[-1] = 0`.
+pub(crate) fn associative_scan<R: JitRuntime, E: FloatElement, const D: usize>(
+    a: JitTensor<R, E, D>,
+    b: JitTensor<R, E, D>,
+    dim: usize,
+) -> JitTensor<R, E, D> {
+    let length = a.shape.dims[dim];
+    if length <= 1 || a.is_empty() {
+        return b;
+    }
+
+    let a = into_contiguous(swap_dims(a, dim, D - 1));
+    let b = into_contiguous(swap_dims(b, dim, D - 1));
+
+    let num_elems = a.shape.num_elements();
+    let num_lines = num_elems / length;
+    let length_padded = length.next_power_of_two();
+
+    let client = a.client.clone();
+    let device = a.device.clone();
+    let work_shape = Shape::new([num_lines, length_padded]);
+    let work_a = empty_device::<R, E, 2>(client.clone(), device.clone(), work_shape.clone());
+    let work_b = empty_device::<R, E, 2>(client.clone(), device.clone(), work_shape);
+    let output = empty_device(client.clone(), device, a.shape.clone());
+
+    let cube_dim = CubeDim::default();
+
+    crate::perf::record_kernel();
+
+    unsafe {
+        scan_init_kernel::launch_unchecked::<E::FloatPrimitive, R>(
+            &client,
+            calculate_cube_count_elemwise(num_lines * length_padded, cube_dim),
+            cube_dim,
+            a.as_tensor_arg(1),
+            b.as_tensor_arg(1),
+            work_a.as_tensor_arg(1),
+            work_b.as_tensor_arg(1),
+            ScalarArg::new(length as u32),
+        );
+    }
+
+    // The last level of the up-sweep is skipped, the root being replaced by the identity.
+    let mut stride = 1;
+    while stride * 2 < length_padded {
+        let num_units = num_lines * (length_padded / (stride * 2));
+        crate::perf::record_kernel();
+
+        unsafe {
+            scan_up_sweep_kernel::launch_unchecked::<E::FloatPrimitive, R>(
+                &client,
+                calculate_cube_count_elemwise(num_units, cube_dim),
+                cube_dim,
+                work_a.as_tensor_arg(1),
+                work_b.as_tensor_arg(1),
+                ScalarArg::new(stride as u32),
+            );
+        }
+        stride *= 2;
+    }
+
+    let mut stride = length_padded / 2;
+    let mut first = true;
+    while stride >= 1 {
+        let num_units = num_lines * (length_padded / (stride * 2));
+        crate::perf::record_kernel();
+
+        unsafe {
+            scan_down_sweep_kernel::launch_unchecked::<E::FloatPrimitive, R>(
+                &client,
+                calculate_cube_count_elemwise(num_units, cube_dim),
+                cube_dim,
+                work_a.as_tensor_arg(1),
+                work_b.as_tensor_arg(1),
+                ScalarArg::new(stride as u32),
+                first,
+            );
+        }
+        stride /= 2;
+        first = false;
+    }
+
+    crate::perf::record_kernel();
+
+    unsafe {
+        scan_output_kernel::launch_unchecked::<E::FloatPrimitive, R>(
+            &client,
+            calculate_cube_count_elemwise(num_elems, cube_dim),
+            cube_dim,
+            a.as_tensor_arg(1),
+            b.as_tensor_arg(1),
+            work_b.as_tensor_arg(1),
+            output.as_tensor_arg(1),
+            ScalarArg::new(length as u32),
+        );
+    }
+
+    swap_dims(output, dim, D - 1)
+}
@@ -0,0 +1,187 @@
+//! Sparse-dense matrix multiplication in the compressed sparse row (CSR) format.
+//!
+//! The entries are sorted by row on the device with a counting sort: the entries of each row are
+//! counted with atomics, the exclusive prefix sum of the counts gives the offset of the first entry
+//! of each row, and each entry is written at the offset of its row plus its rank, taken from an
+//! atomic cursor of the row. A cube then computes a tile of columns of an output row, the entries
+//! of the row being split among the units of each column and their partial sums reduced in shared
+//! memory, so that a row with many entries isn't summed by a single unit.
+//!
+//! The rank of an entry in its row depends on the order of the atomic increments, so the order of
+//! the sums of a row, and their last bits, may differ between runs.
+use crate::{
+    kernel::{exclusive_scan, into_contiguous},
+    ops::numeric::{empty_device, zeros_device},
+    tensor::JitTensor,
+    FloatElement, IntElement, JitRuntime,
+};
+use burn_tensor::Shape;
+use cubecl::{calculate_cube_count_elemwise, prelude::*};
+
+/// The number of output columns computed by each cube.
+const TILE_COLS: u32 = 16;
+/// The number of units splitting the entries of a row for each column.
+const NUM_SPLITS: u32 = 16;
+/// The maximum number of cubes along a dimension of the dispatch.
+const MAX_CUBE_COUNT: usize = u16::MAX as usize;
+
+#[cube(launch)]
+fn csr_count_kernel<I: Int>(rows: &Tensor<I>, counts: &Tensor<AtomicUInt>) {
+    if ABSOLUTE_POS >= rows.len() {
+        return;
+    }
+
+    AtomicUInt::add(&counts[UInt::cast_from(rows[ABSOLUTE_POS])], UInt::new(1));
+}
+
+#[cube(launch)]
+fn csr_scatter_kernel<F: Float, I: Int>(
+    rows: &Tensor<I>,
+    cols: &Tensor<I>,
+    values: &Tensor<F>,
+    offsets: &Tensor<UInt>,
+    cursors: &Tensor<AtomicUInt>,
+    sorted_cols: &mut Tensor<I>,
+    sorted_values: &mut Tensor<F>,
+) {
+    if ABSOLUTE_POS >= rows.len() {
+        return;
+    }
+
+    let row = UInt::cast_from(rows[ABSOLUTE_POS]);
+    let entry = offsets[row] + AtomicUInt::add(&cursors[row], UInt::new(1));
+
+    sorted_cols[entry] = cols[ABSOLUTE_POS];
+    sorted_values[entry] = values[ABSOLUTE_POS];
+}
+
+/// Compute the columns of the tile of the cube for its output row, each unit summing the entries
+/// of the row at its position modulo [NUM_SPLITS] for its column.
+#[cube(launch)]
+fn spmm_csr_kernel<F: Float, I: Int>(
+    offsets: &Tensor<UInt>,
+    cols: &Tensor<I>,
+    values: &Tensor<F>,
+    dense: &Tensor<F>,
+    output: &mut Tensor<F>,
+    cubes_x: UInt,
+) {
+    let num_cols = output.shape(1);
+
+    // The row is the same for all the units of the cube, which return together.
+    let row = CUBE_POS_Z * cubes_x + CUBE_POS_X;
+    if row >= output.shape(0) {
+        return;
+    }
+
+    let col = CUBE_POS_Y * CUBE_DIM_X + UNIT_POS_X;
+    let end = offsets[row + UInt::new(1)];
+    let mut sum = F::new(0.);
+
+    if col < num_cols {
+        let offset_col = col * dense.stride(1);
+        let mut entry = offsets[row] + UNIT_POS_Y;
+
+        while entry < end {
+            let dense_row = UInt::cast_from(cols[entry]);
+            sum += values[entry] * dense[dense_row * dense.stride(0) + offset_col];
+            entry += CUBE_DIM_Y;
+        }
+    }
+
+    let mut partials = SharedMemory::<F>::new(TILE_COLS * NUM_SPLITS);
+    let position = UNIT_POS_Y * CUBE_DIM_X + UNIT_POS_X;
+    partials[position] = sum;
+    sync_units();
+
+    let mut stride = CUBE_DIM_Y / UInt::new(2);
+    while stride > UInt::new(0) {
+        if UNIT_POS_Y < stride {
+            partials[position] = partials[position] + partials[position + stride * CUBE_DIM_X];
+        }
+        sync_units();
+
+        stride = stride / UInt::new(2);
+    }
+
+    if UNIT_POS_Y == UInt::new(0) && col < num_cols {
+        output[row * num_cols + col] = partials[UNIT_POS_X];
+    }
+}
+
+/// Multiply the sparse matrix of `num_rows` rows by the dense matrix, the entries of the sparse
+/// matrix being in any order.
+pub(crate) fn spmm_csr<R: JitRuntime, F: FloatElement, I: IntElement>(
+    rows: JitTensor<R, I, 1>,
+    cols: JitTensor<R, I, 1>,
+    values: JitTensor<R, F, 1>,
+    dense: JitTensor<R, F, 2>,
+    num_rows: usize,
+) -> JitTensor<R, F, 2> {
+    let client = dense.client.clone();
+    let device = dense.device.clone();
+    let num_cols = dense.shape.dims[1];
+    let shape_out = Shape::new([num_rows, num_cols]);
+
+    if shape_out.num_elements() == 0 || rows.shape.num_elements() == 0 {
+        return zeros_device(client, device, shape_out);
+    }
+
+    let rows = into_contiguous(rows);
+    let cols = into_contiguous(cols);
+    let values = into_contiguous(values);
+    let num_entries = rows.shape.num_elements();
+    let cube_dim = CubeDim::default();
+
+    // The count after the last row stays zero, so that its offset is the number of entries.
+    let offsets =
+        zeros_device::<R, u32, 1>(client.clone(), device.clone(), Shape::new([num_rows + 1]));
+    crate::perf::record_kernel();
+    csr_count_kernel::launch::<I::Primitive, R>(
+        &client,
+        calculate_cube_count_elemwise(num_entries, cube_dim),
+        cube_dim,
+        rows.as_tensor_arg(1),
+        offsets.as_tensor_arg(1),
+    );
+    exclusive_scan(&offsets);
+
+    let cursors = zeros_device::<R, u32, 1>(client.clone(), device.clone(), Shape::new([num_rows]));
+    let sorted_cols =
+        empty_device::<R, I, 1>(client.clone(), device.clone(), Shape::new([num_entries]));
+    let sorted_values =
+        empty_device::<R, F, 1>(client.clone(), device.clone(), Shape::new([num_entries]));
+    crate::perf::record_kernel();
+    csr_scatter_kernel::launch::<F::FloatPrimitive, I::Primitive, R>(
+        &client,
+        calculate_cube_count_elemwise(num_entries, cube_dim),
+        cube_dim,
+        rows.as_tensor_arg(1),
+        cols.as_tensor_arg(1),
+        values.as_tensor_arg(1),
+        offsets.as_tensor_arg(1),
+        cursors.as_tensor_arg(1),
+        sorted_cols.as_tensor_arg(1),
+        sorted_values.as_tensor_arg(1),
+    );
+
+    let output = empty_device::<R, F, 2>(client.clone(), device, shape_out);
+    let cubes_x = num_rows.min(MAX_CUBE_COUNT);
+    let cubes_z = num_rows.div_ceil(cubes_x);
+    let num_col_tiles = num_cols.div_ceil(TILE_COLS as usize);
+
+    crate::perf::record_kernel();
+    spmm_csr_kernel::launch::<F::FloatPrimitive, I::Primitive, R>(
+        &client,
+        CubeCount::Static(cubes_x as u32, num_col_tiles as u32, cubes_z as u32),
+        CubeDim::new(TILE_COLS, NUM_SPLITS, 1),
+        offsets.as_tensor_arg(1),
+        sorted_cols.as_tensor_arg(1),
+        sorted_values.as_tensor_arg(1),
+        dense.as_tensor_arg(1),
+        output.as_tensor_arg(1),
+        ScalarArg::new(cubes_x as u32),
+    );
+
+    output
+}
@@ -0,0 +1,57 @@
+use super::cube_select;
+use crate::JitRuntime;
+use cubecl::{client::ComputeClient, prelude::*, Feature};
+
+/// The number of units whose votes are gathered by [subgroup_ballot], one per bit of the result.
+pub const BALLOT_UNITS: u32 = 32;
+
+/// Whether the kernels launched with the client on the device can reduce the values of cubes of
+/// `num_units` units with the subgroup operations: the runtime supports them, and the subgroups of
+/// the device have a fixed size holding all the units of such a cube.
+///
+/// The kernels compile a fallback through shared memory otherwise, like the reductions of the
+/// [score softmax](crate::kernel::attention::score_softmax).
+pub fn is_subgroup_available<R: JitRuntime>(
+    client: &ComputeClient<R::Server, R::Channel>,
+    device: &R::Device,
+    num_units: u32,
+) -> bool {
+    client.features().enabled(Feature::Subcube)
+        && crate::device_properties::<R>(device)
+            .subgroup_size
+            .is_some_and(|size| size >= num_units)
+}
+
+/// The sum of the values of the units of the subgroup, returned to all of them.
+#[cube]
+pub fn subgroup_sum<N: Numeric>(value: N) -> N {
+    subcube_sum(value)
+}
+
+/// The maximum of the values of the units of the subgroup, returned to all of them.
+#[cube]
+pub fn subgroup_max<N: Numeric>(value: N) -> N {
+    subcube_max(value)
+}
+
+/// The value of the unit `lane` of the subgroup, returned to all of its units.
+#[cube]
+pub fn subgroup_broadcast<N: Numeric>(value: N, lane: UInt) -> N {
+    subcube_broadcast(value, lane)
+}
+
+/// The votes of the units of the subgroup, the bit `i` of the result being set when the predicate
+/// holds for its unit `i`, returned to all of them.
+///
+/// The units of a subgroup are the consecutive ones of the cube, so the lane of a unit is its
+/// position modulo the size of the subgroup. Only the first [BALLOT_UNITS] lanes vote, the units
+/// past them never setting any bit. The votes are distinct powers of two, so their sum is the
+/// ballot.
+#[cube]
+pub fn subgroup_ballot(predicate: bool) -> UInt {
+    let lane = UNIT_POS % SUBCUBE_DIM;
+    let bit = UInt::new(1) << (lane % UInt::new(BALLOT_UNITS));
+    let votes = predicate && lane < UInt::new(BALLOT_UNITS);
+
+    subcube_sum(cube_select(votes, bit, UInt::new(0)))
+}
@@ -65,6 +65,10 @@ where
     // argument.
     for<'a> F: FnOnce(&'a ()) -> RuntimeArg<'a, O::Options, R>,
 {
+    if tensor.is_empty() {
+        return tensor;
+    }
+
     // Vectorization is only enabled when the last dimension is contiguous.
     let vectorization_factor =
         tensor_vectorization_factor(&[4, 2], &tensor.shape.dims, &tensor.strides, D - 1);
@@ -75,9 +79,11 @@ where
     let cube_dim = CubeDim::default();
     let cube_count =
         calculate_cube_count_elemwise(num_elems / vectorization_factor as usize, cube_dim);
-    let is_contiguous = tensor.is_contiguous();
 
-    if tensor.can_mut() && is_contiguous {
+    // The output reuses the layout of the input when written in place, so any layout works as
+    // long as each element has its own memory location.
+    if tensor.can_mut() {
+        crate::perf::record_kernel();
         unary_kernel::launch::<E::Primitive, O, R>(
             &client,
             cube_count,
@@ -91,14 +97,18 @@ where
 
         tensor
     } else {
-        let buffer = tensor.client.empty(num_elems * core::mem::size_of::<E>());
-        let output = JitTensor::new_contiguous(
+        let num_bytes = num_elems * core::mem::size_of::<E>();
+        crate::perf::record_allocation(num_bytes);
+        let buffer = tensor.client.empty(num_bytes);
+        let output = JitTensor::new_with_format(
             tensor.client.clone(),
             tensor.device.clone(),
             tensor.shape.clone(),
             buffer,
+            tensor.memory_format(),
         );
 
+        crate::perf::record_kernel();
         unary_kernel::launch::<E::Primitive, O, R>(
             &client,
             cube_count,
@@ -107,7 +117,7 @@ where
             output.as_tensor_arg(vectorization_factor),
             options(&()),
             Some(UInt::new(D as u32)),
-            !is_contiguous,
+            tensor.strides != output.strides,
         );
         output
     }
@@ -18,12 +18,18 @@ pub(crate) mod tune;
 /// Elements for JIT backend
 pub mod element;
 
+mod device;
+pub use device::device_properties;
+
 use burn_tensor::backend::{DeviceId, DeviceOps};
 use cubecl::{
     compute::{CubeCount, CubeTask},
     Runtime,
 };
-pub use element::{FloatElement, IntElement, JitElement};
+pub use element::{
+    assert_atomic_supported, assert_float_supported, is_atomic_supported, is_float_supported,
+    AtomicElement, FloatElement, IntElement, JitElement,
+};
 
 mod backend;
 mod bridge;
@@ -37,6 +43,12 @@ pub use cubecl;
 mod tune_key;
 pub use tune_key::JitAutotuneKey;
 
+/// Persistent cache of the autotune results.
+pub mod tune_cache;
+
+/// Counters of the kernels and allocations for the performance regression tests.
+pub mod perf;
+
 #[cfg(any(feature = "fusion", test))]
 mod fusion;
 
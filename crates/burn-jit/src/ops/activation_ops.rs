@@ -1,5 +1,7 @@
+use crate::kernel::{launch_unary, unary_op, UnaryOp};
 use crate::{FloatElement, IntElement, JitBackend, JitRuntime};
-use burn_tensor::ops::ActivationOps;
+use burn_tensor::ops::{ActivationOps, FloatTensor};
+use cubecl::prelude::*;
 
 impl<R, F, I> ActivationOps<Self> for JitBackend<R, F, I>
 where
@@ -7,4 +9,19 @@ where
     F: FloatElement,
     I: IntElement,
 {
+    fn relu<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        // A single unary kernel can be executed in place, unlike the default implementation
+        // that allocates a mask.
+        unary_op!(float(tensor) => |context, input| {
+            #[cube]
+            fn execute<C: Float>(input: C) -> C {
+                let mut output = input;
+                if input <= C::new(0.0) {
+                    output = C::new(0.0);
+                }
+                output
+            }
+            execute::__expand::<C>(context, input)
+        })
+    }
 }
@@ -3,13 +3,28 @@ use burn_tensor::{Shape, TensorData};
 use cubecl::CubeElement;
 use std::marker::PhantomData;
 
+/// The shape of the result of an element-wise operation between tensors of the given shapes,
+/// where a dimension of size one is broadcast to the size of the other dimension, even zero.
+pub(crate) fn broadcast_shape<const D: usize>(lhs: &Shape<D>, rhs: &Shape<D>) -> Shape<D> {
+    let mut shape = lhs.clone();
+    for (dim, size) in shape.dims.iter_mut().zip(rhs.dims) {
+        if *dim == 1 {
+            *dim = size;
+        }
+    }
+
+    shape
+}
+
 pub(crate) fn from_data<R: JitRuntime, E: JitElement, const D: usize>(
     data: TensorData,
     device: &R::Device,
 ) -> JitTensor<R, E, D> {
     let shape: Shape<D> = (&data.shape).into();
     let client = R::client(device);
-    let buffer = client.create(data.convert::<E>().as_bytes());
+    let data = data.convert::<E>();
+    crate::perf::record_allocation(data.as_bytes().len());
+    let buffer = client.create(data.as_bytes());
 
     JitTensor::new_contiguous(client, device.clone(), shape, buffer)
 }
@@ -51,7 +66,9 @@ pub(crate) fn empty<R: JitRuntime, E: JitElement, const D: usize>(
     device: &R::Device,
 ) -> JitTensor<R, E, D> {
     let client = R::client(device);
-    let buffer = client.empty(shape.num_elements() * core::mem::size_of::<E>());
+    let num_bytes = shape.num_elements() * core::mem::size_of::<E>();
+    crate::perf::record_allocation(num_bytes);
+    let buffer = client.empty(num_bytes);
 
     JitTensor::new_contiguous(client, device.clone(), shape, buffer)
 }
@@ -3,7 +3,7 @@ use burn_tensor::ops::{BoolTensor, Device, FloatTensor, IntTensor};
 use burn_tensor::{ops::BoolTensorOps, Shape, TensorData};
 use std::ops::Range;
 
-use super::{expand, permute};
+use super::{expand, numeric, permute};
 
 impl<R, F, I> BoolTensorOps<Self> for JitBackend<R, F, I>
 where
@@ -79,6 +79,27 @@ where
         kernel::equal_elem(tensor, 0)
     }
 
+    fn bool_and<const D: usize>(
+        lhs: BoolTensor<Self, D>,
+        rhs: BoolTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        numeric::logical_and(lhs, rhs)
+    }
+
+    fn bool_or<const D: usize>(
+        lhs: BoolTensor<Self, D>,
+        rhs: BoolTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        numeric::logical_or(lhs, rhs)
+    }
+
+    fn bool_xor<const D: usize>(
+        lhs: BoolTensor<Self, D>,
+        rhs: BoolTensor<Self, D>,
+    ) -> BoolTensor<Self, D> {
+        numeric::logical_xor(lhs, rhs)
+    }
+
     fn bool_into_float<const D: usize>(tensor: BoolTensor<Self, D>) -> FloatTensor<Self, D> {
         kernel::bool_cast(tensor)
     }
@@ -122,4 +143,18 @@ where
     ) -> BoolTensor<Self, D> {
         kernel::flip(tensor, axes)
     }
+
+    fn bool_count_nonzero<const D: usize>(
+        tensor: BoolTensor<Self, D>,
+        dim: usize,
+    ) -> IntTensor<Self, D> {
+        kernel::reduce::count_nonzero(tensor, dim)
+    }
+
+    fn bool_argwhere_padded<const D: usize>(
+        tensor: BoolTensor<Self, D>,
+        max_count: usize,
+    ) -> (IntTensor<Self, 2>, IntTensor<Self, 1>) {
+        kernel::argwhere_padded(tensor, max_count)
+    }
 }
@@ -1,12 +1,16 @@
 use super::{expand, numeric, permute};
+#[cfg(not(feature = "autotune"))]
+use crate::kernel::matmul::{is_tiling_2d_supported, matmul_accumulate, Tiling2dConfig};
 use crate::kernel::matmul::{matmul, MatmulStrategy};
+#[cfg(feature = "autotune")]
+use crate::kernel::matmul::{matmul_accumulate_tiling_2d_autotune, tiling_2d_candidates};
 use crate::kernel::prng::{random_bernoulli, random_normal, random_uniform};
 use crate::kernel::{self, launch_unary, reduce, unary_op, UnaryOp};
 use crate::JitBackend;
 use crate::{FloatElement, IntElement, JitRuntime};
-use burn_tensor::ops::{BoolTensor, Device, FloatElem, FloatTensor, IntTensor};
+use burn_tensor::ops::{BoolTensor, Device, FloatElem, FloatTensor, IntTensor, IntTensorOps};
 use burn_tensor::ElementConversion;
-use burn_tensor::{ops::FloatTensorOps, Distribution, Shape, TensorData};
+use burn_tensor::{ops::FloatTensorOps, Distribution, MemoryFormat, PadMode, Shape, TensorData};
 use cubecl::prelude::*;
 use std::ops::Range;
 
@@ -149,6 +153,36 @@ where
         matmul(lhs, rhs, MatmulStrategy::default())
     }
 
+    fn float_matmul_accumulate<const D: usize>(
+        out: FloatTensor<Self, D>,
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        // The product is added by a separate kernel when no config of the tiling 2D kernel can
+        // be launched on the device.
+        #[cfg(feature = "autotune")]
+        if !tiling_2d_candidates::<R, F>(&lhs.device).is_empty() {
+            return matmul_accumulate_tiling_2d_autotune(lhs, rhs, out);
+        }
+
+        #[cfg(not(feature = "autotune"))]
+        if is_tiling_2d_supported::<R, F>(&Tiling2dConfig::default(), &lhs.device) {
+            return matmul_accumulate(lhs, rhs, out, Tiling2dConfig::default());
+        }
+
+        numeric::add(out, matmul(lhs, rhs, MatmulStrategy::default()))
+    }
+
+    fn float_spmm(
+        rows: IntTensor<Self, 1>,
+        cols: IntTensor<Self, 1>,
+        values: FloatTensor<Self, 1>,
+        dense: FloatTensor<Self, 2>,
+        num_rows: usize,
+    ) -> FloatTensor<Self, 2> {
+        kernel::sparse::spmm_csr(rows, cols, values, dense, num_rows)
+    }
+
     fn float_swap_dims<const D: usize>(
         tensor: FloatTensor<Self, D>,
         dim1: usize,
@@ -198,6 +232,16 @@ where
         kernel::select_assign(tensor, dim, indices, value)
     }
 
+    fn float_index_put<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+        indices: IntTensor<Self, 1>,
+        values: FloatTensor<Self, D>,
+        accumulate: bool,
+    ) -> FloatTensor<Self, D> {
+        kernel::index_put(tensor, dim, indices, values, accumulate)
+    }
+
     fn float_slice<const D1: usize, const D2: usize>(
         tensor: FloatTensor<Self, D1>,
         ranges: [Range<usize>; D2],
@@ -317,6 +361,39 @@ where
         reduce::mean_dim(tensor, dim, Default::default())
     }
 
+    fn float_var_mean<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dims: &[usize],
+        correction: usize,
+    ) -> (FloatTensor<Self, D>, FloatTensor<Self, D>) {
+        reduce::var_mean(tensor, dims, correction)
+    }
+
+    fn float_count_nonzero<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> IntTensor<Self, D> {
+        reduce::count_nonzero(tensor, dim)
+    }
+
+    fn float_is_close<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+        rtol: f64,
+        atol: f64,
+    ) -> BoolTensor<Self, D> {
+        reduce::is_close(lhs, rhs, rtol, atol)
+    }
+
+    fn float_all_close<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+        rtol: f64,
+        atol: f64,
+    ) -> BoolTensor<Self, 1> {
+        reduce::all_close::<R, F, I, D>(lhs, rhs, rtol, atol)
+    }
+
     fn float_prod<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, 1> {
         reduce::prod(tensor, Default::default())
     }
@@ -431,6 +508,46 @@ where
         })
     }
 
+    fn float_erfinv<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        // Single precision rational approximation from M. Giles, "Approximating the erfinv
+        // function".
+        unary_op!(float(tensor) => |context, tensor| {
+            #[cube]
+            fn execute<C: Float>(input: C) -> C {
+                let one = C::new(1.0);
+                let mut w = C::new(0.0) - C::log((one - input) * (one + input));
+                let mut p = C::new(0.0);
+
+                if w < C::new(5.0) {
+                    w = w - C::new(2.5);
+                    p = C::new(2.810_226_4e-8);
+                    p = C::new(3.432_739_4e-7) + p * w;
+                    p = C::new(-3.523_387_7e-6) + p * w;
+                    p = C::new(-4.391_506_5e-6) + p * w;
+                    p = C::new(0.000_218_580_87) + p * w;
+                    p = C::new(-0.001_253_725) + p * w;
+                    p = C::new(-0.004_177_681_6) + p * w;
+                    p = C::new(0.246_640_73) + p * w;
+                    p = C::new(1.501_409_4) + p * w;
+                } else {
+                    w = C::sqrt(w) - C::new(3.0);
+                    p = C::new(-0.000_200_214_26);
+                    p = C::new(0.000_100_950_56) + p * w;
+                    p = C::new(0.001_349_343_2) + p * w;
+                    p = C::new(-0.003_673_428_4) + p * w;
+                    p = C::new(0.005_739_507_7) + p * w;
+                    p = C::new(-0.007_622_461_3) + p * w;
+                    p = C::new(0.009_438_870_5) + p * w;
+                    p = C::new(1.001_674_1) + p * w;
+                    p = C::new(2.832_976_8) + p * w;
+                }
+
+                p * input
+            }
+            execute::__expand::<C>(context, tensor)
+        })
+    }
+
     fn float_argmax<const D: usize>(
         tensor: FloatTensor<Self, D>,
         dim: usize,
@@ -489,6 +606,17 @@ where
         permute(tensor, axes)
     }
 
+    fn float_to_memory_format<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        format: MemoryFormat,
+    ) -> FloatTensor<Self, D> {
+        kernel::into_memory_format(tensor, format)
+    }
+
+    fn float_memory_format<const D: usize>(tensor: &FloatTensor<Self, D>) -> MemoryFormat {
+        tensor.memory_format()
+    }
+
     fn float_expand<const D1: usize, const D2: usize>(
         tensor: FloatTensor<Self, D1>,
         shape: Shape<D2>,
@@ -502,4 +630,73 @@ where
     ) -> FloatTensor<Self, D> {
         kernel::flip(tensor, axes)
     }
+
+    fn float_pad<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        padding: [(isize, isize); D],
+        mode: PadMode<FloatElem<Self>>,
+    ) -> FloatTensor<Self, D> {
+        kernel::pad(tensor, padding, mode)
+    }
+
+    fn float_associative_scan<const D: usize>(
+        a: FloatTensor<Self, D>,
+        b: FloatTensor<Self, D>,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        kernel::associative_scan(a, b, dim)
+    }
+
+    fn float_fake_quantize<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        scale: FloatTensor<Self, D>,
+        zero_point: FloatTensor<Self, D>,
+        qmin: FloatElem<Self>,
+        qmax: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        kernel::fake_quantize(tensor, scale, zero_point, qmin, qmax)
+    }
+
+    fn float_lerp<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        end: FloatTensor<Self, D>,
+        weight: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        kernel::lerp(tensor, end, weight)
+    }
+
+    fn float_lerp_scalar<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        end: FloatTensor<Self, D>,
+        weight: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        kernel::lerp_scalar(tensor, end, weight)
+    }
+
+    fn float_addcmul<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        tensor1: FloatTensor<Self, D>,
+        tensor2: FloatTensor<Self, D>,
+        value: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        kernel::addcmul(tensor, tensor1, tensor2, value, false)
+    }
+
+    fn float_addcdiv<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        tensor1: FloatTensor<Self, D>,
+        tensor2: FloatTensor<Self, D>,
+        value: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        kernel::addcmul(tensor, tensor1, tensor2, value, true)
+    }
+
+    fn float_axpby<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        alpha: FloatElem<Self>,
+        other: FloatTensor<Self, D>,
+        beta: FloatElem<Self>,
+    ) -> FloatTensor<Self, D> {
+        kernel::axpby(tensor, alpha, other, beta)
+    }
 }
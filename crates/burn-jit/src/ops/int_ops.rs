@@ -3,7 +3,7 @@ use crate::kernel::prng::{random_bernoulli, random_normal, random_uniform};
 use crate::kernel::{launch_unary, unary_op, UnaryOp};
 use crate::{kernel, FloatElement, IntElement, JitBackend, JitRuntime};
 use burn_tensor::ops::{BoolTensor, Device, FloatTensor, IntElem, IntTensor};
-use burn_tensor::{ops::IntTensorOps, Distribution, ElementConversion, Shape, TensorData};
+use burn_tensor::{ops::IntTensorOps, Distribution, ElementConversion, PadMode, Shape, TensorData};
 use cubecl::frontend::Numeric;
 use cubecl::prelude::*;
 use std::ops::Range;
@@ -116,6 +116,16 @@ where
         kernel::select_assign(tensor, dim, indices, value)
     }
 
+    fn int_index_put<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim: usize,
+        indices: IntTensor<Self, 1>,
+        values: IntTensor<Self, D>,
+        accumulate: bool,
+    ) -> IntTensor<Self, D> {
+        kernel::index_put(tensor, dim, indices, values, accumulate)
+    }
+
     fn int_equal<const D: usize>(
         lhs: IntTensor<Self, D>,
         rhs: IntTensor<Self, D>,
@@ -228,6 +238,13 @@ where
         numeric::mul_scalar(lhs, rhs)
     }
 
+    fn int_matmul<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        kernel::matmul::matmul_int(lhs, rhs)
+    }
+
     fn int_div<const D: usize>(
         lhs: IntTensor<Self, D>,
         rhs: IntTensor<Self, D>,
@@ -242,11 +259,32 @@ where
         numeric::div_scalar(lhs, rhs)
     }
 
+    fn int_remainder<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        numeric::remainder_euclid(lhs, rhs)
+    }
+
     fn int_remainder_scalar<const D: usize>(
         lhs: IntTensor<Self, D>,
         rhs: IntElem<Self>,
     ) -> IntTensor<Self, D> {
-        numeric::remainder_scalar(lhs, rhs)
+        numeric::remainder_euclid_scalar(lhs, rhs)
+    }
+
+    fn int_powi<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        numeric::powi(lhs, rhs)
+    }
+
+    fn int_powi_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        numeric::powi_scalar(lhs, rhs)
     }
 
     fn int_zeros<const D: usize>(shape: Shape<D>, device: &Device<Self>) -> IntTensor<Self, D> {
@@ -265,6 +303,13 @@ where
         kernel::reduce::sum_dim(tensor, dim, Default::default())
     }
 
+    fn int_count_nonzero<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        dim: usize,
+    ) -> IntTensor<Self, D> {
+        kernel::reduce::count_nonzero(tensor, dim)
+    }
+
     fn int_prod<const D: usize>(tensor: IntTensor<Self, D>) -> IntTensor<Self, 1> {
         kernel::reduce::prod(tensor, Default::default())
     }
@@ -303,6 +348,18 @@ where
         })
     }
 
+    fn int_sign<const D: usize>(tensor: IntTensor<Self, D>) -> IntTensor<Self, D> {
+        unary_op!(int(tensor) => |context, tensor| {
+            #[cube]
+            fn execute<C: Numeric>(input: C) -> C {
+                // The sign of an integer is the integer clamped to a unit.
+                let one = C::from_int(1);
+                C::clamp(input, C::from_int(0) - one, one)
+            }
+            execute::__expand::<C>(context, tensor)
+        })
+    }
+
     fn int_into_float<const D: usize>(tensor: IntTensor<Self, D>) -> FloatTensor<Self, D> {
         kernel::cast(tensor)
     }
@@ -362,4 +419,86 @@ where
     fn int_flip<const D: usize>(tensor: IntTensor<Self, D>, axes: &[usize]) -> IntTensor<Self, D> {
         kernel::flip(tensor, axes)
     }
+
+    fn int_pad<const D: usize>(
+        tensor: IntTensor<Self, D>,
+        padding: [(isize, isize); D],
+        mode: PadMode<IntElem<Self>>,
+    ) -> IntTensor<Self, D> {
+        kernel::pad(tensor, padding, mode)
+    }
+
+    fn int_bitwise_and<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        numeric::bitwise_and(lhs, rhs)
+    }
+
+    fn int_bitwise_and_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        numeric::bitwise_and_scalar(lhs, rhs)
+    }
+
+    fn int_bitwise_or<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        numeric::bitwise_or(lhs, rhs)
+    }
+
+    fn int_bitwise_or_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        numeric::bitwise_or_scalar(lhs, rhs)
+    }
+
+    fn int_bitwise_xor<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        numeric::bitwise_xor(lhs, rhs)
+    }
+
+    fn int_bitwise_xor_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        numeric::bitwise_xor_scalar(lhs, rhs)
+    }
+
+    fn int_bitwise_left_shift<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        numeric::bitwise_left_shift(lhs, rhs)
+    }
+
+    fn int_bitwise_left_shift_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        numeric::bitwise_left_shift_scalar(lhs, rhs)
+    }
+
+    fn int_bitwise_right_shift<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        numeric::bitwise_right_shift(lhs, rhs)
+    }
+
+    fn int_bitwise_right_shift_scalar<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntElem<Self>,
+    ) -> IntTensor<Self, D> {
+        numeric::bitwise_right_shift_scalar(lhs, rhs)
+    }
+
+    fn int_bitwise_not<const D: usize>(tensor: IntTensor<Self, D>) -> IntTensor<Self, D> {
+        numeric::bitwise_xor_scalar(tensor, (-1).elem())
+    }
 }
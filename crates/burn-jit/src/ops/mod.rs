@@ -3,6 +3,7 @@ mod bool_ops;
 mod float_ops;
 mod int_ops;
 mod module_ops;
+mod optim_ops;
 mod qtensor;
 
 mod base;
@@ -11,6 +11,12 @@ where
     F: FloatElement,
     I: IntElement,
 {
+    fn dropout<const D: usize>(x: FloatTensor<Self, D>, prob: f64) -> FloatTensor<Self, D> {
+        let seed = kernel::prng::counter::random_seed::<R>(&x.device);
+
+        kernel::prng::dropout(x, prob, seed)
+    }
+
     fn conv2d(
         x: FloatTensor<Self, 4>,
         weight: FloatTensor<Self, 4>,
@@ -35,7 +41,7 @@ where
         bias: Option<FloatTensor<Self, 1>>,
         options: ConvTransposeOptions<2>,
     ) -> FloatTensor<Self, 4> {
-        kernel::conv::conv_transpose2d(x, weight, bias, options)
+        kernel::conv::conv_transpose2d(x, weight, bias, options, Default::default())
     }
 
     fn conv_transpose3d(
@@ -53,8 +59,16 @@ where
         stride: [usize; 2],
         padding: [usize; 2],
         count_include_pad: bool,
+        ceil_mode: bool,
     ) -> FloatTensor<Self, 4> {
-        kernel::pool::avg_pool2d(x, kernel_size, stride, padding, count_include_pad)
+        kernel::pool::avg_pool2d(
+            x,
+            kernel_size,
+            stride,
+            padding,
+            count_include_pad,
+            ceil_mode,
+        )
     }
 
     fn avg_pool2d_backward(
@@ -64,8 +78,17 @@ where
         stride: [usize; 2],
         padding: [usize; 2],
         count_include_pad: bool,
+        ceil_mode: bool,
     ) -> FloatTensor<Self, 4> {
-        kernel::pool::avg_pool2d_backward(x, grad, kernel_size, stride, padding, count_include_pad)
+        kernel::pool::avg_pool2d_backward(
+            x,
+            grad,
+            kernel_size,
+            stride,
+            padding,
+            count_include_pad,
+            ceil_mode,
+        )
     }
 
     fn max_pool2d(
@@ -74,8 +97,9 @@ where
         stride: [usize; 2],
         padding: [usize; 2],
         dilation: [usize; 2],
+        ceil_mode: bool,
     ) -> FloatTensor<Self, 4> {
-        kernel::pool::max_pool2d(x, kernel_size, stride, padding, dilation)
+        kernel::pool::max_pool2d(x, kernel_size, stride, padding, dilation, ceil_mode)
     }
 
     fn max_pool2d_with_indices(
@@ -84,9 +108,16 @@ where
         stride: [usize; 2],
         padding: [usize; 2],
         dilation: [usize; 2],
+        ceil_mode: bool,
     ) -> MaxPool2dWithIndices<Self> {
-        let (output, indices) =
-            kernel::pool::max_pool2d_with_indices(x, kernel_size, stride, padding, dilation);
+        let (output, indices) = kernel::pool::max_pool2d_with_indices(
+            x,
+            kernel_size,
+            stride,
+            padding,
+            dilation,
+            ceil_mode,
+        );
 
         MaxPool2dWithIndices::new(output, indices)
     }
@@ -97,6 +128,7 @@ where
         stride: [usize; 2],
         padding: [usize; 2],
         dilation: [usize; 2],
+        _ceil_mode: bool,
         output_grad: FloatTensor<Self, 4>,
         indices: IntTensor<Self, 4>,
     ) -> MaxPool2dBackward<Self> {
@@ -1,9 +1,11 @@
 use crate::kernel::{
-    launch_binop, launch_scalar_binop, AddOp, DivOp, MulOp, PowOp, RemainderOp, SubOp,
+    launch_binop, launch_scalar_binop, AddOp, BitwiseAndOp, BitwiseOrOp, BitwiseXorOp, DivOp,
+    EuclidRemainderOp, LogicalAndOp, LogicalOrOp, LogicalXorOp, MulOp, PowOp, PowiOp, RemainderOp,
+    ShiftLeftOp, ShiftRightOp, SubOp,
 };
 use crate::{element::JitElement, tensor::JitTensor};
-use crate::{FloatElement, JitRuntime};
-use burn_tensor::{ElementConversion, Shape};
+use crate::{FloatElement, IntElement, JitRuntime};
+use burn_tensor::{shift_mask, ElementConversion, MemoryFormat, Shape};
 use cubecl::client::ComputeClient;
 use cubecl::{calculate_cube_count_elemwise, prelude::*};
 use cubecl::{tensor_vectorization_factor, Runtime};
@@ -26,6 +28,10 @@ pub fn full_device<R: JitRuntime, E: JitElement, const D: usize>(
 ) -> JitTensor<R, E, D> {
     let empty = empty_device(client, device, shape);
 
+    if empty.is_empty() {
+        return empty;
+    }
+
     #[cube(launch)]
     pub fn full_kernel<C: Numeric + Vectorized>(tensor: &mut Tensor<C>, value: C) {
         if ABSOLUTE_POS >= tensor.len() {
@@ -43,6 +49,7 @@ pub fn full_device<R: JitRuntime, E: JitElement, const D: usize>(
     let cube_count =
         calculate_cube_count_elemwise(num_elems / vectorization_factor as usize, cube_dim);
 
+    crate::perf::record_kernel();
     full_kernel::launch::<E::Primitive, R>(
         &empty.client,
         cube_count,
@@ -93,11 +100,27 @@ pub fn empty_device<R: JitRuntime, E: JitElement, const D: usize>(
     device: R::Device,
     shape: Shape<D>,
 ) -> JitTensor<R, E, D> {
-    let buffer = client.empty(shape.num_elements() * core::mem::size_of::<E>());
+    let num_bytes = shape.num_elements() * core::mem::size_of::<E>();
+    crate::perf::record_allocation(num_bytes);
+    let buffer = client.empty(num_bytes);
 
     JitTensor::new_contiguous(client, device, shape, buffer)
 }
 
+/// Create an empty tensor laid out in the given [memory format](MemoryFormat).
+pub fn empty_device_with_format<R: JitRuntime, E: JitElement, const D: usize>(
+    client: ComputeClient<R::Server, R::Channel>,
+    device: R::Device,
+    shape: Shape<D>,
+    format: MemoryFormat,
+) -> JitTensor<R, E, D> {
+    let num_bytes = shape.num_elements() * core::mem::size_of::<E>();
+    crate::perf::record_allocation(num_bytes);
+    let buffer = client.empty(num_bytes);
+
+    JitTensor::new_with_format(client, device, shape, buffer, format)
+}
+
 pub fn add<R: JitRuntime, E: JitElement, const D: usize>(
     lhs: JitTensor<R, E, D>,
     rhs: JitTensor<R, E, D>,
@@ -161,9 +184,136 @@ pub fn remainder_scalar<R: JitRuntime, E: JitElement, const D: usize>(
     launch_scalar_binop::<D, R, E, RemainderOp>(lhs, rhs)
 }
 
+/// The Euclidean remainder of integers, which is never negative.
+pub fn remainder_euclid<R: JitRuntime, E: IntElement, const D: usize>(
+    lhs: JitTensor<R, E, D>,
+    rhs: JitTensor<R, E, D>,
+) -> JitTensor<R, E, D> {
+    launch_binop::<D, R, E, EuclidRemainderOp>(lhs, rhs)
+}
+
+/// The Euclidean remainder of integers by a scalar, which is never negative.
+pub fn remainder_euclid_scalar<R: JitRuntime, E: IntElement, const D: usize>(
+    lhs: JitTensor<R, E, D>,
+    rhs: E,
+) -> JitTensor<R, E, D> {
+    launch_scalar_binop::<D, R, E, EuclidRemainderOp>(lhs, rhs)
+}
+
+/// The power of integers, negative powers being rounded toward zero.
+pub fn powi<R: JitRuntime, E: IntElement, const D: usize>(
+    lhs: JitTensor<R, E, D>,
+    rhs: JitTensor<R, E, D>,
+) -> JitTensor<R, E, D> {
+    launch_binop::<D, R, E, PowiOp>(lhs, rhs)
+}
+
+/// The power of integers by a scalar, a negative power being rounded toward zero.
+pub fn powi_scalar<R: JitRuntime, E: IntElement, const D: usize>(
+    lhs: JitTensor<R, E, D>,
+    rhs: E,
+) -> JitTensor<R, E, D> {
+    launch_scalar_binop::<D, R, E, PowiOp>(lhs, rhs)
+}
+
 pub fn pow<R: JitRuntime, E: FloatElement, const D: usize>(
     lhs: JitTensor<R, E, D>,
     rhs: JitTensor<R, E, D>,
 ) -> JitTensor<R, E, D> {
     launch_binop::<D, R, E, PowOp>(lhs, rhs)
 }
+
+pub fn bitwise_and<R: JitRuntime, E: IntElement, const D: usize>(
+    lhs: JitTensor<R, E, D>,
+    rhs: JitTensor<R, E, D>,
+) -> JitTensor<R, E, D> {
+    launch_binop::<D, R, E, BitwiseAndOp>(lhs, rhs)
+}
+
+pub fn bitwise_and_scalar<R: JitRuntime, E: IntElement, const D: usize>(
+    lhs: JitTensor<R, E, D>,
+    rhs: E,
+) -> JitTensor<R, E, D> {
+    launch_scalar_binop::<D, R, E, BitwiseAndOp>(lhs, rhs)
+}
+
+pub fn bitwise_or<R: JitRuntime, E: IntElement, const D: usize>(
+    lhs: JitTensor<R, E, D>,
+    rhs: JitTensor<R, E, D>,
+) -> JitTensor<R, E, D> {
+    launch_binop::<D, R, E, BitwiseOrOp>(lhs, rhs)
+}
+
+pub fn bitwise_or_scalar<R: JitRuntime, E: IntElement, const D: usize>(
+    lhs: JitTensor<R, E, D>,
+    rhs: E,
+) -> JitTensor<R, E, D> {
+    launch_scalar_binop::<D, R, E, BitwiseOrOp>(lhs, rhs)
+}
+
+pub fn bitwise_xor<R: JitRuntime, E: IntElement, const D: usize>(
+    lhs: JitTensor<R, E, D>,
+    rhs: JitTensor<R, E, D>,
+) -> JitTensor<R, E, D> {
+    launch_binop::<D, R, E, BitwiseXorOp>(lhs, rhs)
+}
+
+pub fn bitwise_xor_scalar<R: JitRuntime, E: IntElement, const D: usize>(
+    lhs: JitTensor<R, E, D>,
+    rhs: E,
+) -> JitTensor<R, E, D> {
+    launch_scalar_binop::<D, R, E, BitwiseXorOp>(lhs, rhs)
+}
+
+pub fn bitwise_left_shift<R: JitRuntime, E: IntElement, const D: usize>(
+    lhs: JitTensor<R, E, D>,
+    rhs: JitTensor<R, E, D>,
+) -> JitTensor<R, E, D> {
+    let rhs = bitwise_and_scalar(rhs, shift_mask::<E>().elem());
+    launch_binop::<D, R, E, ShiftLeftOp>(lhs, rhs)
+}
+
+pub fn bitwise_left_shift_scalar<R: JitRuntime, E: IntElement, const D: usize>(
+    lhs: JitTensor<R, E, D>,
+    rhs: E,
+) -> JitTensor<R, E, D> {
+    let rhs = (rhs.elem::<i64>() & shift_mask::<E>()).elem();
+    launch_scalar_binop::<D, R, E, ShiftLeftOp>(lhs, rhs)
+}
+
+pub fn bitwise_right_shift<R: JitRuntime, E: IntElement, const D: usize>(
+    lhs: JitTensor<R, E, D>,
+    rhs: JitTensor<R, E, D>,
+) -> JitTensor<R, E, D> {
+    let rhs = bitwise_and_scalar(rhs, shift_mask::<E>().elem());
+    launch_binop::<D, R, E, ShiftRightOp>(lhs, rhs)
+}
+
+pub fn bitwise_right_shift_scalar<R: JitRuntime, E: IntElement, const D: usize>(
+    lhs: JitTensor<R, E, D>,
+    rhs: E,
+) -> JitTensor<R, E, D> {
+    let rhs = (rhs.elem::<i64>() & shift_mask::<E>()).elem();
+    launch_scalar_binop::<D, R, E, ShiftRightOp>(lhs, rhs)
+}
+
+pub fn logical_and<R: JitRuntime, const D: usize>(
+    lhs: JitTensor<R, u32, D>,
+    rhs: JitTensor<R, u32, D>,
+) -> JitTensor<R, u32, D> {
+    launch_binop::<D, R, u32, LogicalAndOp>(lhs, rhs)
+}
+
+pub fn logical_or<R: JitRuntime, const D: usize>(
+    lhs: JitTensor<R, u32, D>,
+    rhs: JitTensor<R, u32, D>,
+) -> JitTensor<R, u32, D> {
+    launch_binop::<D, R, u32, LogicalOrOp>(lhs, rhs)
+}
+
+pub fn logical_xor<R: JitRuntime, const D: usize>(
+    lhs: JitTensor<R, u32, D>,
+    rhs: JitTensor<R, u32, D>,
+) -> JitTensor<R, u32, D> {
+    launch_binop::<D, R, u32, LogicalXorOp>(lhs, rhs)
+}
@@ -0,0 +1,46 @@
+use crate::{kernel, FloatElement, IntElement, JitBackend, JitRuntime};
+use burn_tensor::ops::{
+    AdamStepInput, AdamStepOptions, AdamStepOutput, AdamStepSegment, OptimizerOps,
+};
+
+impl<R, F, I> OptimizerOps<Self> for JitBackend<R, F, I>
+where
+    R: JitRuntime,
+    F: FloatElement,
+    I: IntElement,
+{
+    fn adam_step<const D: usize>(
+        input: AdamStepInput<Self, D>,
+        time: usize,
+        options: &AdamStepOptions,
+    ) -> AdamStepOutput<Self, D> {
+        let (tensor, moment_1, moment_2) =
+            kernel::optim::adam_step(input.tensor, input.grad, input.moments, time, options);
+
+        AdamStepOutput {
+            tensor,
+            moment_1,
+            moment_2,
+        }
+    }
+
+    fn adam_step_multi(
+        input: AdamStepInput<Self, 1>,
+        segments: &[AdamStepSegment],
+        options: &AdamStepOptions,
+    ) -> AdamStepOutput<Self, 1> {
+        let (tensor, moment_1, moment_2) = kernel::optim::adam_step_multi(
+            input.tensor,
+            input.grad,
+            input.moments,
+            segments,
+            options,
+        );
+
+        AdamStepOutput {
+            tensor,
+            moment_1,
+            moment_2,
+        }
+    }
+}
@@ -0,0 +1,359 @@
+//! Counters of the kernels launched and the bytes allocated by the operations, used by the
+//! performance regression tests.
+//!
+//! Unlike the wall time, the counters don't depend on the machine, so they are the primary
+//! assertion of a [baseline](PerfBaseline) check: an extra copy sneaking into an operation adds a
+//! kernel and an allocation on every device. The wall time is only compared with a tolerance, and
+//! a slower run logs a warning instead of failing.
+//!
+//! The counters are only recorded with the `perf-test` feature, and are kept per thread so that
+//! the tests running concurrently don't affect each other.
+//!
+//! The ids of the kernels are recorded as well by the launches building them, the id of a kernel
+//! being the key of the cache of the compiled kernels of the runtimes: each distinct id is a
+//! compilation on a fresh device.
+//!
+//! The launches of the tiling 2D kernel also record the [specialization](Tiling2dPath) they
+//! compiled, which the benchmarks report. Only the last one is kept, so it is recorded without the
+//! `perf-test` feature as well.
+#[cfg(feature = "perf-test")]
+pub use harness::*;
+
+use crate::kernel::matmul::Tiling2dPath;
+use core::cell::Cell;
+use cubecl::KernelId;
+
+std::thread_local! {
+    static LAST_TILING_2D_PATH: Cell<Option<Tiling2dPath>> = const { Cell::new(None) };
+}
+
+/// Count a kernel launch.
+#[inline(always)]
+pub(crate) fn record_kernel() {
+    #[cfg(feature = "perf-test")]
+    harness::COUNTERS.with(|counters| {
+        let mut current = counters.get();
+        current.num_kernels += 1;
+        counters.set(current);
+    });
+}
+
+/// Record the id of a launched kernel, only built when the counters are recorded.
+#[inline(always)]
+pub(crate) fn record_kernel_id(_id: impl FnOnce() -> KernelId) {
+    #[cfg(feature = "perf-test")]
+    harness::KERNEL_IDS.with(|ids| {
+        ids.borrow_mut().insert(_id());
+    });
+}
+
+/// Record the specialization of a launched [tiling 2D](crate::kernel::matmul::matmul_tiling_2d)
+/// kernel, replacing the previous one.
+#[inline(always)]
+pub(crate) fn record_tiling_2d_path(path: Tiling2dPath) {
+    LAST_TILING_2D_PATH.with(|last| last.set(Some(path)));
+}
+
+/// The specialization of the last tiling 2D kernel launched on the thread, unless none was
+/// launched since the last call.
+pub fn take_last_tiling_2d_path() -> Option<Tiling2dPath> {
+    LAST_TILING_2D_PATH.with(Cell::take)
+}
+
+/// Count an allocation of the given number of bytes.
+#[inline(always)]
+pub(crate) fn record_allocation(_num_bytes: usize) {
+    #[cfg(feature = "perf-test")]
+    harness::COUNTERS.with(|counters| {
+        let mut current = counters.get();
+        current.num_bytes_allocated += _num_bytes;
+        counters.set(current);
+    });
+}
+
+#[cfg(feature = "perf-test")]
+mod harness {
+    use burn_tensor::backend::{Backend, DeviceOps, SyncType};
+    use cubecl::KernelId;
+    use serde::{Deserialize, Serialize};
+    use std::{
+        cell::{Cell, RefCell},
+        collections::{BTreeMap, HashSet},
+        path::{Path, PathBuf},
+        time::{Duration, Instant},
+    };
+
+    /// The environment variable overriding the directory of the baselines.
+    pub const PERF_BASELINE_DIR_ENV: &str = "BURN_PERF_BASELINE_DIR";
+    /// The environment variable regenerating the baselines when set to `1`, `true` or `on`.
+    pub const PERF_REGENERATE_ENV: &str = "BURN_PERF_REGENERATE";
+
+    thread_local! {
+        pub(super) static COUNTERS: Cell<PerfCounters> = Cell::new(PerfCounters::default());
+        pub(super) static KERNEL_IDS: RefCell<HashSet<KernelId>> = RefCell::new(HashSet::new());
+    }
+
+    /// The number of distinct [kernel ids](KernelId) launched on the thread, by the operations
+    /// recording them.
+    pub fn num_distinct_kernels() -> usize {
+        KERNEL_IDS.with(|ids| ids.borrow().len())
+    }
+
+    /// The work done by the operations measured on a thread.
+    #[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+    pub struct PerfCounters {
+        /// The number of kernels launched.
+        pub num_kernels: usize,
+        /// The number of bytes of the tensor buffers allocated.
+        pub num_bytes_allocated: usize,
+    }
+
+    /// The [counters](PerfCounters) and the wall time of a measured function.
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub struct PerfMeasure {
+        /// The counters of the operations.
+        pub counters: PerfCounters,
+        /// The time elapsed until the device finished the work.
+        pub wall_time: Duration,
+    }
+
+    /// Measure the function on the current thread, waiting for the device to finish the work
+    /// queued before and during the function.
+    ///
+    /// The backend is synchronized so that the operations queued by the fusion backend are
+    /// executed, and counted, before the end of the measure.
+    pub fn measure<B: Backend, T>(
+        device: &B::Device,
+        func: impl FnOnce() -> T,
+    ) -> (T, PerfMeasure) {
+        B::sync(device, SyncType::Wait);
+
+        let start = COUNTERS.with(Cell::get);
+        let instant = Instant::now();
+        let output = func();
+        B::sync(device, SyncType::Wait);
+        let wall_time = instant.elapsed();
+        let end = COUNTERS.with(Cell::get);
+
+        let counters = PerfCounters {
+            num_kernels: end.num_kernels - start.num_kernels,
+            num_bytes_allocated: end.num_bytes_allocated - start.num_bytes_allocated,
+        };
+
+        (
+            output,
+            PerfMeasure {
+                counters,
+                wall_time,
+            },
+        )
+    }
+
+    /// A measure exceeding its baseline.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct PerfRegression {
+        /// The name of the measure.
+        pub name: String,
+        /// The counters of the baseline.
+        pub expected: PerfCounters,
+        /// The counters measured.
+        pub actual: PerfCounters,
+    }
+
+    impl core::fmt::Display for PerfRegression {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            write!(
+                f,
+                "{} regressed: {} kernels and {} bytes allocated, expected at most {} kernels and \
+                 {} bytes, regenerate the baseline with {PERF_REGENERATE_ENV}=1 if it is intended",
+                self.name,
+                self.actual.num_kernels,
+                self.actual.num_bytes_allocated,
+                self.expected.num_kernels,
+                self.expected.num_bytes_allocated,
+            )
+        }
+    }
+
+    #[derive(Serialize, Deserialize, Debug, Clone, Copy)]
+    struct PerfBaselineEntry {
+        counters: PerfCounters,
+        wall_time_us: u128,
+    }
+
+    /// The measures of a machine, saved to a file where each entry is recorded on its first
+    /// check, or on every check when regenerating.
+    ///
+    /// The file defaults to the `burn/perf` directory of the user cache, named after the runtime
+    /// and the adapter of the device. The directory can be changed with the `BURN_PERF_BASELINE_DIR`
+    /// environment variable, and the baselines are regenerated when `BURN_PERF_REGENERATE` is set.
+    pub struct PerfBaseline {
+        path: PathBuf,
+        entries: BTreeMap<String, PerfBaselineEntry>,
+        regenerate: bool,
+        tolerance: f64,
+    }
+
+    impl PerfBaseline {
+        /// Load the baseline of the backend on the device.
+        pub fn load<B: Backend>(device: &B::Device) -> Self {
+            let properties = device.properties();
+            let name: String = format!("{}-{}", B::name(), properties.name)
+                .chars()
+                .map(|c| if c.is_alphanumeric() { c } else { '_' })
+                .collect();
+            let dir = std::env::var_os(PERF_BASELINE_DIR_ENV)
+                .map(PathBuf::from)
+                .or_else(|| dirs::cache_dir().map(|dir| dir.join("burn").join("perf")))
+                .unwrap_or_else(|| std::env::temp_dir().join("burn-perf"));
+
+            Self::from_file(dir.join(format!("{name}.json")))
+        }
+
+        /// Load the baseline saved to the file, a missing or invalid file having no entry.
+        pub fn from_file<P: Into<PathBuf>>(path: P) -> Self {
+            let path = path.into();
+            let regenerate = std::env::var(PERF_REGENERATE_ENV)
+                .map(|value| matches!(value.to_lowercase().as_str(), "1" | "true" | "on"))
+                .unwrap_or(false);
+
+            Self {
+                entries: read_entries(&path),
+                path,
+                regenerate,
+                tolerance: 0.25,
+            }
+        }
+
+        /// Set the tolerance of the wall time as a fraction of the baseline, `0.25` by default.
+        pub fn with_tolerance(mut self, tolerance: f64) -> Self {
+            self.tolerance = tolerance;
+            self
+        }
+
+        /// Regenerate the entries instead of checking them.
+        pub fn with_regenerate(mut self, regenerate: bool) -> Self {
+            self.regenerate = regenerate;
+            self
+        }
+
+        /// Check the measure against its entry, recording it when there is none.
+        ///
+        /// The check fails when more kernels are launched or more bytes are allocated than in the
+        /// baseline, while a wall time above the tolerance only logs a warning.
+        pub fn check(&mut self, name: &str, measure: PerfMeasure) -> Result<(), PerfRegression> {
+            let entry = PerfBaselineEntry {
+                counters: measure.counters,
+                wall_time_us: measure.wall_time.as_micros(),
+            };
+
+            let expected = match self.entries.get(name) {
+                Some(expected) if !self.regenerate => *expected,
+                _ => {
+                    self.entries.insert(name.to_string(), entry);
+                    if let Err(err) = write_entries(&self.path, &self.entries) {
+                        log::warn!("Unable to save the perf baseline to {:?}: {err}", self.path);
+                    }
+                    return Ok(());
+                }
+            };
+
+            let max_wall_time_us = expected.wall_time_us as f64 * (1.0 + self.tolerance);
+            if entry.wall_time_us as f64 > max_wall_time_us {
+                log::warn!(
+                    "{name} took {}us, expected at most {max_wall_time_us:.0}us",
+                    entry.wall_time_us
+                );
+            }
+
+            if entry.counters.num_kernels > expected.counters.num_kernels
+                || entry.counters.num_bytes_allocated > expected.counters.num_bytes_allocated
+            {
+                return Err(PerfRegression {
+                    name: name.to_string(),
+                    expected: expected.counters,
+                    actual: entry.counters,
+                });
+            }
+
+            Ok(())
+        }
+    }
+
+    fn read_entries(path: &Path) -> BTreeMap<String, PerfBaselineEntry> {
+        let Ok(content) = std::fs::read_to_string(path) else {
+            return BTreeMap::new();
+        };
+
+        serde_json::from_str(&content).unwrap_or_else(|err| {
+            log::warn!("Ignoring the invalid perf baseline {path:?}: {err}");
+            BTreeMap::new()
+        })
+    }
+
+    fn write_entries(
+        path: &Path,
+        entries: &BTreeMap<String, PerfBaselineEntry>,
+    ) -> std::io::Result<()> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+
+        std::fs::write(path, serde_json::to_string_pretty(entries)?)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        fn measure(
+            num_kernels: usize,
+            num_bytes_allocated: usize,
+            wall_time_us: u64,
+        ) -> PerfMeasure {
+            PerfMeasure {
+                counters: PerfCounters {
+                    num_kernels,
+                    num_bytes_allocated,
+                },
+                wall_time: Duration::from_micros(wall_time_us),
+            }
+        }
+
+        #[test]
+        fn check_should_fail_when_counters_exceed_the_baseline() {
+            let dir = std::env::temp_dir().join(format!("burn-perf-{}", std::process::id()));
+            let path = dir.join("baseline.json");
+            let mut baseline = PerfBaseline::from_file(&path).with_regenerate(false);
+
+            assert!(baseline.check("matmul", measure(1, 64, 100)).is_ok());
+
+            // Loaded again, the recorded entry is checked instead of being replaced.
+            let mut baseline = PerfBaseline::from_file(&path).with_regenerate(false);
+            let slower = baseline.check("matmul", measure(1, 64, 1000));
+            let more_kernels = baseline.check("matmul", measure(2, 64, 100));
+            let more_bytes = baseline.check("matmul", measure(1, 128, 100));
+            std::fs::remove_dir_all(&dir).unwrap();
+
+            assert!(slower.is_ok());
+            assert_eq!(more_kernels.unwrap_err().actual.num_kernels, 2);
+            assert_eq!(more_bytes.unwrap_err().expected.num_bytes_allocated, 64);
+        }
+
+        #[test]
+        fn regenerate_should_replace_the_entries() {
+            let dir = std::env::temp_dir().join(format!("burn-perf-regen-{}", std::process::id()));
+            let path = dir.join("baseline.json");
+
+            let mut baseline = PerfBaseline::from_file(&path).with_regenerate(false);
+            baseline.check("conv", measure(3, 64, 100)).unwrap();
+            let mut baseline = PerfBaseline::from_file(&path).with_regenerate(true);
+            baseline.check("conv", measure(4, 64, 100)).unwrap();
+            let mut baseline = PerfBaseline::from_file(&path).with_regenerate(false);
+            let result = baseline.check("conv", measure(4, 64, 100));
+            std::fs::remove_dir_all(&dir).unwrap();
+
+            assert!(result.is_ok());
+        }
+    }
+}
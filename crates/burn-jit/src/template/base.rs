@@ -16,6 +16,19 @@ pub trait KernelSource: Send + 'static + Sync {
 pub struct SourceKernel<K> {
     kernel_source: K,
     cube_dim: CubeDim,
+    #[new(default)]
+    shared_mem_bytes: usize,
+}
+
+impl<K> SourceKernel<K> {
+    /// Allocate the given number of bytes of dynamic shared memory for each cube at launch.
+    ///
+    /// Only the runtimes with dynamically sized shared memory, like CUDA, use it; the size must be
+    /// part of the source of the others.
+    pub fn with_shared_memory_bytes(mut self, shared_mem_bytes: usize) -> Self {
+        self.shared_mem_bytes = shared_mem_bytes;
+        self
+    }
 }
 
 impl<K: KernelSource> CubeTask for SourceKernel<K> {
@@ -27,7 +40,7 @@ impl<K: KernelSource> CubeTask for SourceKernel<K> {
             name: Some(core::any::type_name::<K>()),
             source,
             cube_dim: self.cube_dim,
-            shared_mem_bytes: 0,
+            shared_mem_bytes: self.shared_mem_bytes,
             debug_info: None,
         }
     }
@@ -1,7 +1,7 @@
 use crate::element::JitElement;
 use crate::kernel::{launch_unary, unary_op, UnaryOp};
 use crate::JitRuntime;
-use burn_tensor::Shape;
+use burn_tensor::{MemoryFormat, Shape};
 use cubecl::client::ComputeClient;
 use cubecl::frontend::Numeric;
 use cubecl::linalg::tensor::{matrix_layout, MatrixLayout, TensorHandle};
@@ -9,6 +9,17 @@ use cubecl::prelude::{TensorHandleRef, *};
 use cubecl::server::Handle;
 use std::marker::PhantomData;
 
+/// Assert that the elements spanned by a tensor of the shape can be indexed with 32 bit integers
+/// by the kernel.
+pub(crate) fn assert_u32_span(kernel: &str, span: usize, dims: &[usize]) {
+    if span > u32::MAX as usize {
+        panic!(
+            "The {kernel} kernel indexes tensors with 32 bit integers, which can't address the \
+             {span} elements spanned by a tensor of shape {dims:?}."
+        );
+    }
+}
+
 /// The basic tensor primitive struct.
 #[derive(new)]
 pub struct JitTensor<R, E, const D: usize>
@@ -83,18 +94,18 @@ where
         shape: Shape<D>,
         handle: Handle<R::Server>,
     ) -> Self {
-        let mut strides = [0; D];
+        Self::new_with_format(client, device, shape, handle, MemoryFormat::Contiguous)
+    }
 
-        let mut current = 1;
-        shape
-            .dims
-            .iter()
-            .enumerate()
-            .rev()
-            .for_each(|(index, val)| {
-                strides[index] = current;
-                current *= val;
-            });
+    /// Create a new tensor laid out in the given [memory format](MemoryFormat).
+    pub fn new_with_format(
+        client: ComputeClient<R::Server, R::Channel>,
+        device: R::Device,
+        shape: Shape<D>,
+        handle: Handle<R::Server>,
+        format: MemoryFormat,
+    ) -> Self {
+        let strides = format_strides(&shape, format);
 
         Self {
             client,
@@ -116,6 +127,7 @@ where
             self.client.read_async(self.handle.clone().binding()),
         )
         .expect("Can only change client synchronously");
+        crate::perf::record_allocation(bytes.len());
         let handle = client.create(&bytes);
 
         Self {
@@ -147,7 +159,7 @@ where
     }
 
     pub(crate) fn can_mut_broadcast(&self, rhs: &Self) -> bool {
-        if !self.handle.can_mut() {
+        if !self.can_mut() {
             return false;
         }
 
@@ -176,8 +188,21 @@ where
     }
 
     /// Check if the tensor is safe to mutate.
+    ///
+    /// The buffer must be uniquely referenced and each element must have its own memory location,
+    /// which isn't the case for broadcasted tensors.
     pub fn can_mut(&self) -> bool {
-        self.handle.can_mut()
+        self.handle.can_mut() && !self.is_broadcasted()
+    }
+
+    /// Check if the tensor has dimensions broadcasted with a stride of zero, so that many elements
+    /// share the same memory location.
+    pub(crate) fn is_broadcasted(&self) -> bool {
+        self.shape
+            .dims
+            .iter()
+            .zip(self.strides.iter())
+            .any(|(dim, stride)| *stride == 0 && *dim > 1)
     }
 
     /// Assert that both tensors are on the same device.
@@ -190,6 +215,37 @@ where
         }
     }
 
+    /// The number of elements of the buffer spanned by the tensor, from its first element to its
+    /// last one following its strides.
+    pub fn span(&self) -> usize {
+        if self.is_empty() {
+            return 0;
+        }
+
+        self.shape
+            .dims
+            .iter()
+            .zip(self.strides.iter())
+            .map(|(dim, stride)| (dim - 1) * stride)
+            .sum::<usize>()
+            + 1
+    }
+
+    /// Assert that the elements of the tensor can be indexed with 32 bit integers, the index type
+    /// of the kernels.
+    ///
+    /// Without this check, the positions computed by the kernels on larger tensors silently
+    /// overflow, reading and writing the wrong elements.
+    pub fn assert_u32_indexable(&self, kernel: &str) {
+        assert_u32_span(kernel, self.span(), &self.shape.dims);
+    }
+
+    /// Check if the current tensor has no elements, in which case no kernel should be launched
+    /// on it, since there is nothing to compute and the cube count would be empty.
+    pub fn is_empty(&self) -> bool {
+        self.shape.num_elements() == 0
+    }
+
     /// Check if the current tensor is contiguous.
     pub fn is_contiguous(&self) -> bool {
         self.matrix_layout() == MatrixLayout::Contiguous
@@ -198,4 +254,32 @@ where
     pub(crate) fn matrix_layout(&self) -> MatrixLayout {
         matrix_layout(&self.strides)
     }
+
+    /// The [memory format](MemoryFormat) of the tensor, any layout other than channels last
+    /// being reported as contiguous.
+    pub fn memory_format(&self) -> MemoryFormat {
+        let channels_last = format_strides(&self.shape, MemoryFormat::ChannelsLast);
+
+        if D > 2
+            && self.strides == channels_last
+            && self.strides != format_strides(&self.shape, MemoryFormat::Contiguous)
+        {
+            MemoryFormat::ChannelsLast
+        } else {
+            MemoryFormat::Contiguous
+        }
+    }
+}
+
+/// The strides of a dense tensor laid out in the given memory format.
+fn format_strides<const D: usize>(shape: &Shape<D>, format: MemoryFormat) -> [usize; D] {
+    let mut strides = [0; D];
+    let mut current = 1;
+
+    for &index in format.permutation::<D>().iter().rev() {
+        strides[index] = current;
+        current *= shape.dims[index];
+    }
+
+    strides
 }
@@ -0,0 +1,152 @@
+#[burn_tensor_testgen::testgen(adam_step)]
+mod tests {
+    use super::*;
+
+    // Use the reexported test dependency.
+    use burn_jit::tests::serial_test;
+    use serial_test::serial;
+
+    use burn_jit::kernel::optim::num_launches;
+    use burn_tensor::ops::{AdamStepInput, AdamStepOptions, AdamStepSegment, OptimizerOps};
+    use burn_tensor::{backend::Backend, Distribution, Tensor, TensorData, TensorPrimitive};
+
+    const OPTIONS: AdamStepOptions = AdamStepOptions {
+        lr: 0.001,
+        beta_1: 0.9,
+        beta_2: 0.999,
+        epsilon: 1e-5,
+        penalty: Some(0.01),
+        weight_decay: None,
+    };
+
+    #[test]
+    #[serial]
+    fn adam_step_should_launch_a_single_kernel() {
+        let input = input([16, 32], true);
+
+        let launches = num_launches();
+        let output = TestBackend::adam_step(input, 3, &OPTIONS);
+
+        assert_eq!(num_launches() - launches, 1);
+        assert_eq!(output.tensor.shape.dims, [16, 32]);
+    }
+
+    #[test]
+    #[serial]
+    fn adam_step_multi_should_launch_a_single_kernel_for_all_tensors() {
+        let lengths = (1..=200).map(|length| length % 17 + 1).collect::<Vec<_>>();
+        let mut offset = 0;
+        let segments = lengths
+            .iter()
+            .map(|&length| {
+                let segment = AdamStepSegment {
+                    offset,
+                    length,
+                    time: length,
+                };
+                offset += length;
+                segment
+            })
+            .collect::<Vec<_>>();
+        let input = input([offset], false);
+
+        let launches = num_launches();
+        let output = TestBackend::adam_step_multi(input, &segments, &OPTIONS);
+
+        assert_eq!(num_launches() - launches, 1);
+        assert_eq!(output.tensor.shape.dims, [offset]);
+    }
+
+    #[test]
+    #[serial]
+    fn adam_step_multi_should_find_the_segment_of_each_element() {
+        let lengths = [1, 7, 2, 33, 1, 1, 64, 5, 12, 3];
+        let mut offset = 0;
+        let segments = lengths
+            .iter()
+            .enumerate()
+            .map(|(i, &length)| {
+                let segment = AdamStepSegment {
+                    offset,
+                    length,
+                    time: i + 1,
+                };
+                offset += length;
+                segment
+            })
+            .collect::<Vec<_>>();
+        let random = || {
+            TestTensor::<1>::random([offset], Distribution::Default, &Default::default())
+                .into_data()
+        };
+        let data = [random(), random(), random(), random()];
+
+        let output = TestBackend::adam_step_multi(input_of(&data), &segments, &OPTIONS);
+        let expected = ReferenceBackend::adam_step_multi(input_of(&data), &segments, &OPTIONS);
+
+        for (actual, expected) in [
+            (output.tensor, expected.tensor),
+            (output.moment_1, expected.moment_1),
+            (output.moment_2, expected.moment_2),
+        ] {
+            Tensor::<TestBackend, 1>::from_primitive(TensorPrimitive::Float(actual))
+                .into_data()
+                .assert_approx_eq(
+                    &Tensor::<ReferenceBackend, 1>::from_primitive(TensorPrimitive::Float(
+                        expected,
+                    ))
+                    .into_data(),
+                    5,
+                );
+        }
+    }
+
+    #[cfg(feature = "perf-test")]
+    #[test]
+    #[serial]
+    fn adam_step_should_not_launch_other_kernels() {
+        use burn_jit::perf::measure;
+
+        for with_moments in [false, true] {
+            let input = input([64, 64], with_moments);
+
+            let (_, perf) = measure::<TestBackend, _>(&Default::default(), || {
+                TestBackend::adam_step(input, 2, &OPTIONS)
+            });
+
+            // The parameters, the gradient and the moments are updated without any copy.
+            assert_eq!(perf.counters.num_kernels, 1, "{perf:?}");
+        }
+    }
+
+    fn input_of<B: Backend>(data: &[TensorData; 4]) -> AdamStepInput<B, 1> {
+        let tensor = |data: &TensorData| {
+            Tensor::<B, 1>::from_data(data.clone(), &Default::default())
+                .into_primitive()
+                .tensor()
+        };
+
+        AdamStepInput {
+            tensor: tensor(&data[0]),
+            grad: tensor(&data[1]),
+            moments: Some((tensor(&data[2]), tensor(&data[3]))),
+        }
+    }
+
+    fn input<const D: usize>(
+        shape: [usize; D],
+        with_moments: bool,
+    ) -> AdamStepInput<TestBackend, D> {
+        let random = || {
+            TestTensor::<D>::random(shape, Distribution::Default, &Default::default())
+                .into_primitive()
+                .tensor()
+        };
+
+        AdamStepInput {
+            tensor: random(),
+            grad: random(),
+            moments: with_moments.then(|| (random(), random())),
+        }
+    }
+}
@@ -0,0 +1,43 @@
+#[burn_tensor_testgen::testgen(argwhere)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Distribution, TensorData};
+
+    #[test]
+    fn argwhere_padded_should_scan_the_counts_of_many_blocks() {
+        // More than 256 chunks of 256 values, so the sums of the scanned blocks are scanned too.
+        let num_values = 300_000;
+        let device = Default::default();
+        let mask =
+            TestTensor::<2>::random([300, 1000], Distribution::Default, &device).greater_elem(0.5);
+        let expected = mask.clone().argwhere();
+        let num_rows = expected.dims()[0];
+
+        let (indices, count) = mask.argwhere_padded(num_values);
+
+        count
+            .into_data()
+            .assert_eq(&TensorData::from([num_rows as i32]), false);
+        indices
+            .slice([0..num_rows, 0..2])
+            .into_data()
+            .assert_eq(&expected.into_data(), false);
+    }
+
+    #[test]
+    fn argwhere_padded_should_count_the_values_past_the_capacity() {
+        let device = Default::default();
+        let mask = TestTensorInt::<1>::arange(0..200_000, &device)
+            .remainder_scalar(3)
+            .equal_elem(0);
+
+        let (indices, count) = mask.argwhere_padded(4);
+
+        count
+            .into_data()
+            .assert_eq(&TensorData::from([66_667]), false);
+        indices
+            .into_data()
+            .assert_eq(&TensorData::from([[0], [3], [6], [9]]), false);
+    }
+}
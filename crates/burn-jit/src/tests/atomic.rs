@@ -0,0 +1,130 @@
+#[burn_tensor_testgen::testgen(atomic)]
+mod tests {
+    use super::*;
+    use burn_jit::{
+        assert_atomic_supported, is_atomic_supported, tensor::JitTensor, AtomicElement,
+    };
+    use cubecl::{calculate_cube_count_elemwise, prelude::*, CubeElement, Runtime};
+
+    /// The number of counters of the [histogram](histogram_kernel), lower than the number of
+    /// units of a cube.
+    const NUM_BINS: u32 = 13;
+
+    /// Count the values of the input in each bin, the units of a cube incrementing the counters
+    /// in shared memory before the first units of each cube add them to the global counters.
+    #[cube(launch)]
+    fn histogram_kernel(input: &Tensor<UInt>, output: &Tensor<AtomicUInt>) {
+        let num_bins = UInt::new(NUM_BINS);
+        let counters = SharedMemory::<AtomicUInt>::new(NUM_BINS);
+
+        if UNIT_POS < num_bins {
+            AtomicUInt::store(&counters[UNIT_POS], UInt::new(0));
+        }
+        sync_units();
+
+        if ABSOLUTE_POS < input.len() {
+            AtomicUInt::add(&counters[input[ABSOLUTE_POS] % num_bins], UInt::new(1));
+        }
+        sync_units();
+
+        if UNIT_POS < num_bins {
+            AtomicUInt::add(&output[UNIT_POS], AtomicUInt::load(&counters[UNIT_POS]));
+        }
+    }
+
+    /// Reduce the input to its maximum and minimum, and to the first of its values swapped in
+    /// place of the initial zero.
+    #[cube(launch)]
+    fn extrema_kernel<A: Atomic>(input: &Tensor<A::Primitive>, output: &Tensor<A>) {
+        if ABSOLUTE_POS < input.len() {
+            let value = input[ABSOLUTE_POS];
+            A::max(&output[0], value);
+            A::min(&output[1], value);
+            A::compare_and_swap(&output[2], A::Primitive::from_int(0), value);
+        }
+    }
+
+    fn tensor<E: AtomicElement>(values: &[E]) -> JitTensor<TestRuntime, E, 1> {
+        let device = Default::default();
+        let client = TestRuntime::client(&device);
+        let handle = client.create(E::as_bytes(values));
+
+        JitTensor::new_contiguous(client, device, [values.len()].into(), handle)
+    }
+
+    fn read<E: AtomicElement>(tensor: JitTensor<TestRuntime, E, 1>) -> Vec<E> {
+        let bytes = tensor.client.read(tensor.handle.binding());
+
+        E::from_bytes(&bytes)[0..tensor.shape.num_elements()].to_vec()
+    }
+
+    #[test]
+    fn atomic_counters_should_have_deterministic_totals() {
+        let num_values = 10_000;
+        let values = (0..num_values as u32)
+            .map(|i| i.wrapping_mul(2_654_435_761) >> 7)
+            .collect::<Vec<_>>();
+
+        let mut expected = vec![0u32; NUM_BINS as usize];
+        for value in values.iter() {
+            expected[(value % NUM_BINS) as usize] += 1;
+        }
+
+        let input = tensor(&values);
+
+        for run in 0..3 {
+            let output = tensor(&[0u32; NUM_BINS as usize]);
+
+            let cube_dim = CubeDim::default();
+            histogram_kernel::launch::<TestRuntime>(
+                &input.client,
+                calculate_cube_count_elemwise(num_values, cube_dim),
+                cube_dim,
+                input.as_tensor_arg(1),
+                output.as_tensor_arg(1),
+            );
+
+            assert_eq!(read(output), expected, "Atomic histogram run {run}");
+        }
+    }
+
+    #[test]
+    fn atomic_extrema_should_match_the_cpu_reduction() {
+        type Atomic32 = <i32 as AtomicElement>::Atomic;
+
+        let device = Default::default();
+        assert_atomic_supported::<TestRuntime, i32>(&device);
+
+        let num_values = 5_000;
+        let values = (0..num_values as i32)
+            .map(|i| i.wrapping_mul(1_103_515_245) >> 9)
+            .filter(|value| *value != 0)
+            .collect::<Vec<_>>();
+
+        let input = tensor(&values);
+        let output = tensor(&[i32::MIN, i32::MAX, 0]);
+
+        let cube_dim = CubeDim::default();
+        extrema_kernel::launch::<Atomic32, TestRuntime>(
+            &input.client,
+            calculate_cube_count_elemwise(values.len(), cube_dim),
+            cube_dim,
+            input.as_tensor_arg(1),
+            output.as_tensor_arg(1),
+        );
+
+        let output = read(output);
+        assert_eq!(output[0], *values.iter().max().unwrap());
+        assert_eq!(output[1], *values.iter().min().unwrap());
+        // A single unit wins the swap, whichever it is.
+        assert!(values.contains(&output[2]), "Swapped {}", output[2]);
+    }
+
+    #[test]
+    fn atomic_32_bit_elements_should_be_supported_by_every_device() {
+        let device = Default::default();
+
+        assert!(is_atomic_supported::<TestRuntime, u32>(&device));
+        assert!(is_atomic_supported::<TestRuntime, i32>(&device));
+    }
+}
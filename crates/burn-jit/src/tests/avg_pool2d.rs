@@ -18,16 +18,69 @@ mod tests {
         let stride = [1, 2];
         let padding = [1, 2];
         let count_include_pad = true;
+        let ceil_mode = false;
 
-        let pooled = module::avg_pool2d(tensor, kernel_size, stride, padding, count_include_pad);
-        let pooled_ref =
-            module::avg_pool2d(tensor_ref, kernel_size, stride, padding, count_include_pad);
+        let pooled = module::avg_pool2d(
+            tensor,
+            kernel_size,
+            stride,
+            padding,
+            count_include_pad,
+            ceil_mode,
+        );
+        let pooled_ref = module::avg_pool2d(
+            tensor_ref,
+            kernel_size,
+            stride,
+            padding,
+            count_include_pad,
+            ceil_mode,
+        );
 
         pooled
             .into_data()
             .assert_approx_eq(&pooled_ref.into_data(), 3);
     }
 
+    #[test]
+    fn avg_pool2d_should_match_reference_with_ceil_mode() {
+        let tensor = Tensor::<TestBackend, 4>::random(
+            [2, 3, 32, 31],
+            Distribution::Default,
+            &Default::default(),
+        );
+        let tensor_ref =
+            Tensor::<ReferenceBackend, 4>::from_data(tensor.to_data(), &Default::default());
+        let kernel_size = [3, 2];
+        let stride = [2, 2];
+        let padding = [1, 0];
+        let ceil_mode = true;
+
+        for count_include_pad in [true, false] {
+            let pooled = module::avg_pool2d(
+                tensor.clone(),
+                kernel_size,
+                stride,
+                padding,
+                count_include_pad,
+                ceil_mode,
+            );
+            let pooled_ref = module::avg_pool2d(
+                tensor_ref.clone(),
+                kernel_size,
+                stride,
+                padding,
+                count_include_pad,
+                ceil_mode,
+            );
+
+            assert_eq!(pooled.dims(), [2, 3, 17, 16]);
+            pooled
+                .into_data()
+                .assert_approx_eq(&pooled_ref.into_data(), 3);
+        }
+    }
+
     #[test]
     fn avg_pool2d_backward_should_work_with_multiple_invocations() {
         TestBackend::seed(0);
@@ -43,6 +96,7 @@ mod tests {
         let stride = [1, 1];
         let padding = [1, 1];
         let count_include_pad = true;
+        let ceil_mode = false;
 
         let shape_out = module::avg_pool2d(
             tensor.clone(),
@@ -50,6 +104,7 @@ mod tests {
             stride,
             padding,
             count_include_pad,
+            ceil_mode,
         )
         .shape();
         let grad_output =
@@ -65,6 +120,7 @@ mod tests {
                 stride,
                 padding,
                 count_include_pad,
+                ceil_mode,
             )));
         let grad_ref: Tensor<ReferenceBackend, 4> = Tensor::from_primitive(TensorPrimitive::Float(
             ReferenceBackend::avg_pool2d_backward(
@@ -74,9 +130,62 @@ mod tests {
                 stride,
                 padding,
                 count_include_pad,
+                ceil_mode,
             ),
         ));
 
         grad.into_data().assert_approx_eq(&grad_ref.into_data(), 3);
     }
+
+    #[test]
+    fn avg_pool2d_backward_should_match_reference_with_ceil_mode() {
+        TestBackend::seed(0);
+        let tensor = Tensor::<TestBackend, 4>::random(
+            [2, 3, 32, 31],
+            Distribution::Default,
+            &Default::default(),
+        );
+        let tensor_ref =
+            Tensor::<ReferenceBackend, 4>::from_data(tensor.to_data(), &Default::default());
+        let kernel_size = [3, 2];
+        let stride = [2, 2];
+        let padding = [1, 0];
+        let ceil_mode = true;
+
+        for count_include_pad in [true, false] {
+            let grad_output = Tensor::<TestBackend, 4>::random(
+                [2, 3, 17, 16],
+                Distribution::Default,
+                &Default::default(),
+            );
+            let grad_output_ref = Tensor::<ReferenceBackend, 4>::from_data(
+                grad_output.to_data(),
+                &Default::default(),
+            );
+
+            let grad: Tensor<TestBackend, 4> =
+                Tensor::from_primitive(TensorPrimitive::Float(TestBackend::avg_pool2d_backward(
+                    tensor.clone().into_primitive().tensor(),
+                    grad_output.into_primitive().tensor(),
+                    kernel_size,
+                    stride,
+                    padding,
+                    count_include_pad,
+                    ceil_mode,
+                )));
+            let grad_ref: Tensor<ReferenceBackend, 4> = Tensor::from_primitive(
+                TensorPrimitive::Float(ReferenceBackend::avg_pool2d_backward(
+                    tensor_ref.clone().into_primitive().tensor(),
+                    grad_output_ref.into_primitive().tensor(),
+                    kernel_size,
+                    stride,
+                    padding,
+                    count_include_pad,
+                    ceil_mode,
+                )),
+            );
+
+            grad.into_data().assert_approx_eq(&grad_ref.into_data(), 3);
+        }
+    }
 }
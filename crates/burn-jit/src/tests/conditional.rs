@@ -0,0 +1,55 @@
+#[burn_tensor_testgen::testgen(conditional)]
+mod tests {
+    use super::*;
+    use burn_jit::kernel::cube_select;
+    use burn_tensor::{Distribution, TensorData};
+    use cubecl::{calculate_cube_count_elemwise, frontend::F32, prelude::*};
+
+    /// Copy the values at the even positions and double the other ones, selecting between both
+    /// without branching, each unit handling a vector of the input when it is vectorized.
+    #[cube(launch)]
+    fn double_odd_kernel<F: Float>(input: &Tensor<F>, output: &mut Tensor<F>) {
+        if ABSOLUTE_POS >= input.len() {
+            return;
+        }
+
+        let value = input[ABSOLUTE_POS];
+        let even = ABSOLUTE_POS % UInt::new(2) == UInt::new(0);
+        output[ABSOLUTE_POS] = cube_select(even, value, value * F::new(2.));
+    }
+
+    #[test]
+    fn select_should_apply_to_every_lane_of_the_vectors() {
+        let num_values = 64;
+        let device = Default::default();
+        let input = TestTensor::<1>::random([num_values], Distribution::Default, &device);
+        let values = input.to_data().to_vec::<f32>().unwrap();
+
+        for vectorization in [1, 4] {
+            let output = TestTensor::<1>::empty([num_values], &device);
+            let input_primitive = input.clone().into_primitive().tensor();
+            let output_primitive = output.clone().into_primitive().tensor();
+
+            let cube_dim = CubeDim::default();
+            double_odd_kernel::launch::<F32, TestRuntime>(
+                &input_primitive.client,
+                calculate_cube_count_elemwise(num_values / vectorization, cube_dim),
+                cube_dim,
+                input_primitive.as_tensor_arg(vectorization as u8),
+                output_primitive.as_tensor_arg(vectorization as u8),
+            );
+
+            let expected = values
+                .iter()
+                .enumerate()
+                .map(|(i, value)| match (i / vectorization) % 2 == 0 {
+                    true => *value,
+                    false => value * 2.,
+                })
+                .collect::<Vec<_>>();
+            output
+                .into_data()
+                .assert_approx_eq(&TensorData::new(expected, [num_values]), 5);
+        }
+    }
+}
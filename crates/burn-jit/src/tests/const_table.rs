@@ -0,0 +1,94 @@
+#[burn_tensor_testgen::testgen(const_table)]
+mod tests {
+    use super::*;
+    use burn_jit::kernel::ConstTable;
+    use burn_tensor::{Distribution, TensorData};
+    use cubecl::{calculate_cube_count_elemwise, frontend::F32, prelude::*};
+
+    /// The number of coefficients of the polynomial table.
+    const NUM_COEFFICIENTS: usize = 7;
+
+    /// Evaluate the polynomial of the table on each input with Horner's method, the coefficients
+    /// being materialized as constants in a local array indexed at runtime.
+    #[cube(launch)]
+    fn polynomial_kernel<F: Float>(
+        input: &Tensor<F>,
+        output: &mut Tensor<F>,
+        table: Comptime<ConstTable<NUM_COEFFICIENTS>>,
+    ) {
+        if ABSOLUTE_POS >= input.len() {
+            return;
+        }
+
+        let coefficients = ConstTable::<NUM_COEFFICIENTS>::materialize::<F>(table);
+        let degree = UInt::new(NUM_COEFFICIENTS as u32 - 1);
+
+        let x = input[ABSOLUTE_POS];
+        let mut value = F::new(0.);
+        for i in range(0u32, NUM_COEFFICIENTS as u32, Comptime::new(false)) {
+            value = value * x + coefficients[degree - i];
+        }
+
+        output[ABSOLUTE_POS] = value;
+    }
+
+    #[test]
+    fn polynomial_table_should_match_the_cpu_evaluation() {
+        // The Taylor polynomial of the exponential of degree 6.
+        let coefficients = [
+            1.0,
+            1.0,
+            0.5,
+            1.0 / 6.0,
+            1.0 / 24.0,
+            1.0 / 120.0,
+            1.0 / 720.0,
+        ];
+        let device = Default::default();
+        let input = TestTensor::<1>::random([1000], Distribution::Uniform(-2.0, 2.0), &device);
+        let output = TestTensor::<1>::empty([1000], &device);
+
+        let expected = input
+            .to_data()
+            .to_vec::<f32>()
+            .unwrap()
+            .into_iter()
+            .map(|x| {
+                coefficients
+                    .iter()
+                    .rev()
+                    .fold(0.0, |value, c| value * x + c)
+            })
+            .collect::<Vec<f32>>();
+
+        let input_primitive = input.into_primitive().tensor();
+        let output_primitive = output.clone().into_primitive().tensor();
+        let cube_dim = CubeDim::default();
+        polynomial_kernel::launch::<F32, TestRuntime>(
+            &input_primitive.client,
+            calculate_cube_count_elemwise(1000, cube_dim),
+            cube_dim,
+            input_primitive.as_tensor_arg(1),
+            output_primitive.as_tensor_arg(1),
+            ConstTable::new(coefficients),
+        );
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::new(expected, [1000]), 5);
+    }
+
+    #[test]
+    fn const_table_should_read_its_values() {
+        let table = ConstTable::new([0.5, -1.0, 3.0]);
+
+        assert_eq!(table.value(0), 0.5);
+        assert_eq!(table.value(2), 3.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn const_table_should_reject_an_out_of_bounds_comptime_index() {
+        ConstTable::new([0.5, -1.0, 3.0]).value(3);
+    }
+}
@@ -1,7 +1,13 @@
 #[burn_tensor_testgen::testgen(conv_transpose2d)]
 mod tests {
     use super::*;
-    use burn_tensor::{backend::Backend, module, Distribution, Tensor};
+    use burn_jit::kernel::conv::{conv_transpose2d, ConvTranspose2dStrategy};
+    use burn_tensor::{
+        backend::Backend,
+        module,
+        ops::{ConvOptions, ConvTransposeOptions},
+        Distribution, Tensor, TensorData, TensorPrimitive,
+    };
 
     #[test]
     fn conv_transpose2d_should_work_with_multiple_invocations() {
@@ -47,4 +53,123 @@ mod tests {
             .into_data()
             .assert_approx_eq(&output_ref.into_data(), 3);
     }
+
+    #[test]
+    fn strategies_should_match_with_stride_dilation_and_groups() {
+        TestBackend::seed(0);
+        let options = ConvTransposeOptions::new([2, 3], [1, 2], [1, 0], [2, 1], 2);
+        let device = Default::default();
+        let input = Tensor::<TestBackend, 4>::random([2, 4, 5, 6], Distribution::Default, &device);
+        let weight = Tensor::<TestBackend, 4>::random([4, 3, 3, 2], Distribution::Default, &device);
+        let bias = Tensor::<TestBackend, 1>::random([6], Distribution::Default, &device);
+        let ref_device = Default::default();
+        let input_ref = Tensor::<ReferenceBackend, 4>::from_data(input.to_data(), &ref_device);
+        let weight_ref = Tensor::<ReferenceBackend, 4>::from_data(weight.to_data(), &ref_device);
+        let bias_ref = Tensor::<ReferenceBackend, 1>::from_data(bias.to_data(), &ref_device);
+
+        let output_ref =
+            module::conv_transpose2d(input_ref, weight_ref, Some(bias_ref), options.clone());
+        let direct = transposed(
+            input.clone(),
+            weight.clone(),
+            Some(bias.clone()),
+            options.clone(),
+            ConvTranspose2dStrategy::Direct,
+        );
+        let gemm = transposed(
+            input,
+            weight,
+            Some(bias),
+            options,
+            ConvTranspose2dStrategy::ImplicitGemm,
+        );
+
+        assert_eq!(gemm.dims(), [2, 6, 12, 13]);
+        gemm.clone()
+            .into_data()
+            .assert_approx_eq(&direct.into_data(), 3);
+        gemm.into_data()
+            .assert_approx_eq(&output_ref.into_data(), 3);
+    }
+
+    #[test]
+    fn strided_backward_input_should_match_finite_differences() {
+        TestBackend::seed(0);
+        let device = Default::default();
+        let [channels_in, height, width] = [4, 7, 8];
+        let num_elems = channels_in * height * width;
+        let options = ConvOptions::new([2, 2], [1, 1], [1, 1], 2);
+        let x = Tensor::<TestBackend, 4>::random(
+            [1, channels_in, height, width],
+            Distribution::Default,
+            &device,
+        );
+        let weight = Tensor::<TestBackend, 4>::random([4, 2, 3, 3], Distribution::Default, &device);
+        let output_grad =
+            Tensor::<TestBackend, 4>::random([1, 4, 4, 4], Distribution::Default, &device);
+
+        // The loss `sum(conv2d(x) * output_grad)` is evaluated on a batch of inputs, each one with
+        // a single element perturbed, to get all the central differences at once.
+        let step = 0.5;
+        let perturbed = |sign: f32| {
+            let x = x.to_data().to_vec::<f32>().unwrap();
+            let values = (0..num_elems)
+                .flat_map(|i| {
+                    x.iter().enumerate().map(move |(j, value)| match i == j {
+                        true => value + sign * step,
+                        false => *value,
+                    })
+                })
+                .collect::<Vec<_>>();
+            let batch = Tensor::<TestBackend, 4>::from_data(
+                TensorData::new(values, [num_elems, channels_in, height, width]),
+                &device,
+            );
+
+            (module::conv2d(batch, weight.clone(), None, options.clone()) * output_grad.clone())
+                .sum_dim(3)
+                .sum_dim(2)
+                .sum_dim(1)
+                .reshape([1, channels_in, height, width])
+        };
+        let finite_differences = (perturbed(1.0) - perturbed(-1.0)) / (2.0 * step);
+
+        // The output width of the convolution is rounded down, so the output padding is needed to
+        // recover the input width.
+        let backward_options = ConvTransposeOptions::new([2, 2], [1, 1], [0, 1], [1, 1], 2);
+        for strategy in [
+            ConvTranspose2dStrategy::Direct,
+            ConvTranspose2dStrategy::ImplicitGemm,
+        ] {
+            let x_grad = transposed(
+                output_grad.clone(),
+                weight.clone(),
+                None,
+                backward_options.clone(),
+                strategy,
+            );
+
+            x_grad
+                .into_data()
+                .assert_approx_eq(&finite_differences.to_data(), 2);
+        }
+    }
+
+    fn transposed(
+        input: Tensor<TestBackend, 4>,
+        weight: Tensor<TestBackend, 4>,
+        bias: Option<Tensor<TestBackend, 1>>,
+        options: ConvTransposeOptions<2>,
+        strategy: ConvTranspose2dStrategy,
+    ) -> Tensor<TestBackend, 4> {
+        let output = conv_transpose2d::<TestRuntime, f32>(
+            input.into_primitive().tensor(),
+            weight.into_primitive().tensor(),
+            bias.map(|bias| bias.into_primitive().tensor()),
+            options,
+            strategy,
+        );
+
+        Tensor::from_primitive(TensorPrimitive::Float(output))
+    }
 }
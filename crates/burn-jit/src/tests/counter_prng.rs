@@ -0,0 +1,119 @@
+#[burn_tensor_testgen::testgen(counter_prng)]
+mod tests {
+    use super::*;
+    use burn_jit::kernel::prng::counter;
+    use burn_tensor::module::dropout;
+    use cubecl::{calculate_cube_count_elemwise, frontend::F32, prelude::*};
+
+    /// The number of samples drawn by the tests.
+    const NUM_SAMPLES: usize = 1_000_000;
+
+    /// Write the value of each index of the stream of the seed, of the normal distribution or of
+    /// the uniform one.
+    #[cube(launch)]
+    fn sample_kernel<F: Float>(output: &mut Tensor<F>, seed: UInt, normal: Comptime<bool>) {
+        if ABSOLUTE_POS >= output.len() {
+            return;
+        }
+
+        if Comptime::get(normal) {
+            output[ABSOLUTE_POS] = counter::random_normal::<F>(seed, ABSOLUTE_POS);
+        } else {
+            output[ABSOLUTE_POS] = counter::random_uniform::<F>(seed, ABSOLUTE_POS);
+        }
+    }
+
+    fn samples(seed: u32, normal: bool) -> Vec<f32> {
+        let output = TestTensor::<1>::empty([NUM_SAMPLES], &Default::default());
+        let primitive = output.clone().into_primitive().tensor();
+
+        let cube_dim = CubeDim::default();
+        sample_kernel::launch::<F32, TestRuntime>(
+            &primitive.client,
+            calculate_cube_count_elemwise(NUM_SAMPLES, cube_dim),
+            cube_dim,
+            primitive.as_tensor_arg(1),
+            ScalarArg::new(seed),
+            normal,
+        );
+
+        output.into_data().to_vec::<f32>().unwrap()
+    }
+
+    fn mean_variance(values: &[f32]) -> (f64, f64) {
+        let n = values.len() as f64;
+        let mean = values.iter().map(|v| *v as f64).sum::<f64>() / n;
+        let variance = values
+            .iter()
+            .map(|v| (*v as f64 - mean).powi(2))
+            .sum::<f64>()
+            / n;
+
+        (mean, variance)
+    }
+
+    #[test]
+    fn counter_prng_should_follow_its_distributions() {
+        let uniform = samples(42, false);
+        assert!(
+            uniform.iter().all(|v| (0.0..1.0).contains(v)),
+            "Uniform values out of [0, 1)"
+        );
+        let (mean, variance) = mean_variance(&uniform);
+        assert!((mean - 0.5).abs() < 2e-3, "Uniform mean {mean}");
+        assert!(
+            (variance - 1.0 / 12.0).abs() < 2e-3,
+            "Uniform variance {variance}"
+        );
+
+        let normal = samples(42, true);
+        assert!(
+            normal.iter().all(|v| v.is_finite()),
+            "Normal values not finite"
+        );
+        let (mean, variance) = mean_variance(&normal);
+        assert!(mean.abs() < 5e-3, "Normal mean {mean}");
+        assert!((variance - 1.0).abs() < 1e-2, "Normal variance {variance}");
+    }
+
+    #[test]
+    fn counter_prng_should_be_deterministic_given_the_seed() {
+        for normal in [false, true] {
+            let first = samples(7, normal);
+            let second = samples(7, normal);
+            let other = samples(8, normal);
+
+            assert_eq!(first, second, "Different samples with the same seed");
+            let num_equal = first
+                .iter()
+                .zip(other.iter())
+                .filter(|(a, b)| a == b)
+                .count();
+            assert!(
+                num_equal < NUM_SAMPLES / 100,
+                "{num_equal} equal samples with different seeds"
+            );
+        }
+    }
+
+    #[test]
+    fn dropout_should_zero_and_scale_the_elements() {
+        let prob = 0.3;
+        let input = TestTensor::<1>::ones([NUM_SAMPLES], &Default::default());
+
+        let output = dropout(input, prob).into_data().to_vec::<f32>().unwrap();
+        let num_zeros = output.iter().filter(|v| **v == 0.0).count();
+        let fraction = num_zeros as f64 / NUM_SAMPLES as f64;
+
+        assert!(
+            (fraction - prob).abs() < 2e-3,
+            "Fraction of zeros {fraction}"
+        );
+        assert!(
+            output
+                .iter()
+                .all(|v| *v == 0.0 || (*v - 1.0 / (1.0 - prob as f32)).abs() < 1e-6),
+            "Kept elements not scaled by 1 / (1 - prob)"
+        );
+    }
+}
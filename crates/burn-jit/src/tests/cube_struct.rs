@@ -0,0 +1,220 @@
+#[burn_tensor_testgen::testgen(cube_struct)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Distribution, TensorData};
+    use cubecl::{calculate_cube_count_elemwise, frontend::F32, prelude::*, unexpanded};
+
+    /// An affine map passed as an argument of the kernel, its expand and launch types being
+    /// derived.
+    #[derive(CubeLaunch)]
+    struct Affine<F: Float> {
+        scale: F,
+        bias: F,
+    }
+
+    /// A range of positions built inside the kernel, its expand type being derived.
+    #[derive(CubeType)]
+    struct Bounds {
+        start: UInt,
+        end: UInt,
+    }
+
+    impl<F: Float> Affine<F> {
+        /// Apply the map to the value.
+        fn apply(_affine: &Self, _value: F) -> F {
+            unexpanded!()
+        }
+
+        fn __expand_apply(
+            context: &mut CubeContext,
+            affine: AffineExpand<F>,
+            value: F::ExpandType,
+        ) -> F::ExpandType {
+            #[cube]
+            fn apply<F: Float>(affine: &Affine<F>, value: F) -> F {
+                value * affine.scale + affine.bias
+            }
+
+            apply::__expand::<F>(context, affine, value)
+        }
+    }
+
+    impl Bounds {
+        /// Create the bounds excluding as many positions at each end of the range.
+        fn centered(_margin: UInt, _len: UInt) -> Self {
+            unexpanded!()
+        }
+
+        fn __expand_centered(
+            context: &mut CubeContext,
+            margin: <UInt as CubeType>::ExpandType,
+            len: <UInt as CubeType>::ExpandType,
+        ) -> BoundsExpand {
+            #[cube]
+            fn centered(margin: UInt, len: UInt) -> Bounds {
+                Bounds {
+                    start: margin,
+                    end: len - margin,
+                }
+            }
+
+            centered::__expand(context, margin, len)
+        }
+
+        /// Whether the position is in the bounds.
+        fn contains(_bounds: &Self, _pos: UInt) -> bool {
+            unexpanded!()
+        }
+
+        fn __expand_contains(
+            context: &mut CubeContext,
+            bounds: BoundsExpand,
+            pos: <UInt as CubeType>::ExpandType,
+        ) -> <bool as CubeType>::ExpandType {
+            #[cube]
+            fn contains(bounds: &Bounds, pos: UInt) -> bool {
+                pos >= bounds.start && pos < bounds.end
+            }
+
+            contains::__expand(context, bounds, pos)
+        }
+    }
+
+    /// Two values returned together by a function of the kernels, destructured by the caller.
+    #[derive(CubeType)]
+    struct SumDiff<F: Float> {
+        sum: F,
+        diff: F,
+    }
+
+    #[cube]
+    fn sum_diff<F: Float>(lhs: F, rhs: F) -> SumDiff<F> {
+        SumDiff::<F> {
+            sum: lhs + rhs,
+            diff: lhs - rhs,
+        }
+    }
+
+    /// The sum and the difference of the sum and the difference, `2 * lhs` and `2 * rhs`, from
+    /// the values returned by the nested calls.
+    #[cube]
+    fn nested_sum_diff<F: Float>(lhs: F, rhs: F) -> SumDiff<F> {
+        let inner = sum_diff::<F>(lhs, rhs);
+        sum_diff::<F>(inner.sum, inner.diff)
+    }
+
+    /// Write both values returned by the nested calls, each unit handling a vector of the inputs
+    /// when they are vectorized.
+    #[cube(launch)]
+    fn sum_diff_kernel<F: Float>(
+        lhs: &Tensor<F>,
+        rhs: &Tensor<F>,
+        doubled_lhs: &mut Tensor<F>,
+        doubled_rhs: &mut Tensor<F>,
+    ) {
+        if ABSOLUTE_POS >= lhs.len() {
+            return;
+        }
+
+        let values = nested_sum_diff::<F>(lhs[ABSOLUTE_POS], rhs[ABSOLUTE_POS]);
+        doubled_lhs[ABSOLUTE_POS] = values.sum;
+        doubled_rhs[ABSOLUTE_POS] = values.diff;
+    }
+
+    /// Apply the affine map to the inputs in the bounds built by the kernel, copying the other
+    /// ones.
+    #[cube(launch)]
+    fn affine_kernel<F: Float>(
+        input: &Tensor<F>,
+        output: &mut Tensor<F>,
+        affine: &Affine<F>,
+        margin: UInt,
+    ) {
+        if ABSOLUTE_POS >= input.len() {
+            return;
+        }
+
+        let bounds = Bounds::centered(margin, input.len());
+
+        if Bounds::contains(&bounds, ABSOLUTE_POS) {
+            output[ABSOLUTE_POS] = Affine::<F>::apply(affine, input[ABSOLUTE_POS]);
+        } else {
+            output[ABSOLUTE_POS] = input[ABSOLUTE_POS];
+        }
+    }
+
+    #[test]
+    fn derived_structs_and_their_methods_should_be_usable_in_kernels() {
+        let num_values = 100;
+        let margin = 10;
+        let (scale, bias) = (2.0, -1.0);
+        let device = Default::default();
+        let input =
+            TestTensor::<1>::random([num_values], Distribution::Uniform(-1.0, 1.0), &device);
+        let output = TestTensor::<1>::empty([num_values], &device);
+
+        let expected = input
+            .to_data()
+            .to_vec::<f32>()
+            .unwrap()
+            .into_iter()
+            .enumerate()
+            .map(|(i, value)| match i >= margin && i < num_values - margin {
+                true => value * scale + bias,
+                false => value,
+            })
+            .collect::<Vec<_>>();
+
+        let input = input.into_primitive().tensor();
+        let output_primitive = output.clone().into_primitive().tensor();
+        let cube_dim = CubeDim::default();
+        affine_kernel::launch::<F32, TestRuntime>(
+            &input.client,
+            calculate_cube_count_elemwise(num_values, cube_dim),
+            cube_dim,
+            input.as_tensor_arg(1),
+            output_primitive.as_tensor_arg(1),
+            AffineLaunch::new(ScalarArg::new(scale), ScalarArg::new(bias)),
+            ScalarArg::new(margin as u32),
+        );
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::new(expected, [num_values]), 5);
+    }
+
+    #[test]
+    fn structs_returned_by_nested_calls_should_hold_vectorized_values() {
+        let num_values = 64;
+        let device = Default::default();
+        let lhs = TestTensor::<1>::random([num_values], Distribution::Default, &device);
+        let rhs = TestTensor::<1>::random([num_values], Distribution::Default, &device);
+
+        for vectorization in [1, 4] {
+            let doubled_lhs = TestTensor::<1>::empty([num_values], &device);
+            let doubled_rhs = TestTensor::<1>::empty([num_values], &device);
+
+            let lhs_primitive = lhs.clone().into_primitive().tensor();
+            let rhs_primitive = rhs.clone().into_primitive().tensor();
+            let doubled_lhs_primitive = doubled_lhs.clone().into_primitive().tensor();
+            let doubled_rhs_primitive = doubled_rhs.clone().into_primitive().tensor();
+            let cube_dim = CubeDim::default();
+            sum_diff_kernel::launch::<F32, TestRuntime>(
+                &lhs_primitive.client,
+                calculate_cube_count_elemwise(num_values / vectorization, cube_dim),
+                cube_dim,
+                lhs_primitive.as_tensor_arg(vectorization as u8),
+                rhs_primitive.as_tensor_arg(vectorization as u8),
+                doubled_lhs_primitive.as_tensor_arg(vectorization as u8),
+                doubled_rhs_primitive.as_tensor_arg(vectorization as u8),
+            );
+
+            doubled_lhs
+                .into_data()
+                .assert_approx_eq(&(lhs.clone() * 2).into_data(), 5);
+            doubled_rhs
+                .into_data()
+                .assert_approx_eq(&(rhs.clone() * 2).into_data(), 5);
+        }
+    }
+}
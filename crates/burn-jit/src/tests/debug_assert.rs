@@ -0,0 +1,65 @@
+#[burn_tensor_testgen::testgen(debug_assert)]
+mod tests {
+    use super::*;
+    use burn_jit::kernel::debug::{cube_assert, KernelAssertionError, KernelAssertions};
+    use burn_tensor::TensorData;
+    use cubecl::{calculate_cube_count_elemwise, frontend::F32, prelude::*};
+
+    /// Copy the input, asserting that each of its values isn't negative.
+    #[cube(launch)]
+    fn copy_non_negative_kernel<F: Float>(
+        input: &Tensor<F>,
+        output: &mut Tensor<F>,
+        assertions: &mut Tensor<UInt>,
+        debug: Comptime<bool>,
+    ) {
+        if ABSOLUTE_POS >= input.len() {
+            return;
+        }
+
+        let value = input[ABSOLUTE_POS];
+        cube_assert(value >= F::new(0.), assertions, debug);
+        output[ABSOLUTE_POS] = value;
+    }
+
+    #[test]
+    fn failing_kernel_assertion_should_report_the_unit() {
+        let num_values = 1000;
+        let failing = 737;
+        let values = (0..num_values)
+            .map(|i| match i == failing {
+                true => -1.0,
+                false => i as f32,
+            })
+            .collect::<Vec<_>>();
+
+        let device = Default::default();
+        let input = TestTensor::<1>::from_data(TensorData::new(values, [num_values]), &device)
+            .into_primitive()
+            .tensor();
+        let output = TestTensor::<1>::zeros([num_values], &device)
+            .into_primitive()
+            .tensor();
+        let assertions = KernelAssertions::of(&output);
+
+        let cube_dim = CubeDim::default();
+        copy_non_negative_kernel::launch::<F32, TestRuntime>(
+            &input.client,
+            calculate_cube_count_elemwise(num_values, cube_dim),
+            cube_dim,
+            input.as_tensor_arg(1),
+            output.as_tensor_arg(1),
+            assertions.as_tensor_arg(),
+            KernelAssertions::<TestRuntime>::enabled(),
+        );
+
+        // Release builds compile the assertion out, without binding a buffer of its own.
+        let expected = match KernelAssertions::<TestRuntime>::enabled() {
+            true => Err(KernelAssertionError {
+                absolute_pos: failing as u32,
+            }),
+            false => Ok(()),
+        };
+        assert_eq!(assertions.check(), expected);
+    }
+}
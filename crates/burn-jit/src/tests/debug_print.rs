@@ -0,0 +1,93 @@
+#[burn_tensor_testgen::testgen(debug_print)]
+mod tests {
+    use super::*;
+    use burn_jit::kernel::debug::{DebugPrint, DebugPrintConfig, DebugPrints};
+    use cubecl::{prelude::*, Runtime};
+
+    burn_jit::debug_print!(fn print_row_col("row={} col={}", row, col));
+    burn_jit::debug_print!(fn print_square("square={}", square));
+    burn_jit::debug_print!(fn print_second_half("second half {}", col));
+
+    /// Print the row and the column of each unit, then the square of its position, the units of
+    /// the second half of each cube printing a third time.
+    #[cube(launch)]
+    fn print_positions_kernel(buffer: &mut Tensor<UInt>, config: Comptime<DebugPrintConfig>) {
+        let row = ABSOLUTE_POS / CUBE_DIM_X;
+        let col = ABSOLUTE_POS % CUBE_DIM_X;
+
+        print_row_col(buffer, row, col, config);
+        print_square(buffer, ABSOLUTE_POS * ABSOLUTE_POS, config);
+
+        if col >= CUBE_DIM_X / UInt::new(2) {
+            print_second_half(buffer, col, config);
+        }
+    }
+
+    const FORMATS: [&str; 3] = ["row={} col={}", "square={}", "second half {}"];
+
+    fn launch(prints: &DebugPrints<TestRuntime>, num_cubes: u32) {
+        print_positions_kernel::launch::<TestRuntime>(
+            &TestRuntime::client(&Default::default()),
+            CubeCount::Static(num_cubes, 1, 1),
+            CubeDim::new(8, 1, 1),
+            prints.as_tensor_arg(),
+            prints.config(),
+        );
+    }
+
+    fn expected_prints(num_units: u32) -> Vec<DebugPrint> {
+        (0..num_units)
+            .flat_map(|unit| {
+                let (row, col) = (unit / 8, unit % 8);
+                let mut prints = vec![
+                    format!("row={row} col={col}"),
+                    format!("square={}", unit * unit),
+                ];
+                if col >= 4 {
+                    prints.push(format!("second half {col}"));
+                }
+
+                prints
+                    .into_iter()
+                    .map(move |message| DebugPrint { unit, message })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn debug_prints_should_be_decoded_in_order_for_each_unit() {
+        let device = Default::default();
+        let client = TestRuntime::client(&device);
+        let prints =
+            DebugPrints::<TestRuntime>::new(client, device, &FORMATS, 3 * 8, 4).debug_print(true);
+
+        launch(&prints, 3);
+
+        assert_eq!(prints.decode(), expected_prints(3 * 8));
+    }
+
+    #[test]
+    fn disabled_debug_prints_should_be_compiled_out() {
+        let device = Default::default();
+        let client = TestRuntime::client(&device);
+        let prints =
+            DebugPrints::<TestRuntime>::new(client, device, &FORMATS, 3 * 8, 4).debug_print(false);
+
+        launch(&prints, 3);
+
+        assert_eq!(prints.decode(), Vec::new());
+    }
+
+    #[test]
+    fn debug_prints_of_units_without_a_region_should_be_dropped() {
+        let device = Default::default();
+        let client = TestRuntime::client(&device);
+        // The buffer only has the regions of the units of the first two cubes.
+        let prints =
+            DebugPrints::<TestRuntime>::new(client, device, &FORMATS, 2 * 8, 4).debug_print(true);
+
+        launch(&prints, 3);
+
+        assert_eq!(prints.decode(), expected_prints(2 * 8));
+    }
+}
@@ -0,0 +1,27 @@
+#[burn_tensor_testgen::testgen(device)]
+mod tests {
+    use super::*;
+    use burn_tensor::backend::{Backend, DeviceOps};
+
+    #[test]
+    fn device_properties_should_be_consistent_across_calls() {
+        let device = Default::default();
+
+        let first = TestBackend::device_properties(&device);
+        let second = TestBackend::device_properties(&device);
+
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn enumerated_devices_should_have_distinct_ids() {
+        let devices = TestBackend::devices();
+
+        assert_eq!(devices, TestBackend::devices());
+        for (i, device) in devices.iter().enumerate() {
+            for other in devices.iter().skip(i + 1) {
+                assert_ne!(device.id(), other.id());
+            }
+        }
+    }
+}
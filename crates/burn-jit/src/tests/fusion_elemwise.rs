@@ -0,0 +1,102 @@
+#[burn_tensor_testgen::testgen(fusion_elemwise)]
+mod tests {
+    use super::*;
+    use burn_fusion::{FusionBackend, FusionRuntime, OptimizationStatus};
+    use burn_tensor::{
+        repr::{
+            BaseOperationDescription, BinaryOperationDescription, BoolOperationDescription,
+            NumericOperationDescription, OperationDescription, ScalarOperationDescription,
+            TensorDescription, TensorId, TensorStatus, UnaryOperationDescription,
+        },
+        DType, Distribution, Int, Tensor, TensorData,
+    };
+
+    type Runtime = <JitBackend<TestRuntime, f32, i32> as FusionBackend>::FusionRuntime;
+
+    #[test]
+    fn padding_mask_should_fuse_into_a_single_kernel_before_the_reduction() {
+        let shape = [2, 8];
+        let tensor = |id: u64, status: TensorStatus, dtype: DType| TensorDescription {
+            id: TensorId::new(id),
+            shape: shape.to_vec(),
+            status,
+            dtype,
+        };
+
+        // The operations of `(ids.not_equal(pad).float() * scores).sum_dim(1)`.
+        let operations = [
+            OperationDescription::BaseInt(BaseOperationDescription::Equal(
+                BinaryOperationDescription {
+                    lhs: tensor(0, TensorStatus::ReadOnly, DType::I32),
+                    rhs: tensor(1, TensorStatus::ReadOnly, DType::I32),
+                    out: tensor(2, TensorStatus::NotInit, DType::Bool),
+                },
+            )),
+            OperationDescription::Bool(BoolOperationDescription::Not(UnaryOperationDescription {
+                input: tensor(2, TensorStatus::ReadWrite, DType::Bool),
+                out: tensor(3, TensorStatus::NotInit, DType::Bool),
+            })),
+            OperationDescription::Bool(BoolOperationDescription::IntoFloat(
+                UnaryOperationDescription {
+                    input: tensor(3, TensorStatus::ReadWrite, DType::Bool),
+                    out: tensor(4, TensorStatus::NotInit, DType::F32),
+                },
+            )),
+            OperationDescription::NumericFloat(NumericOperationDescription::Mul(
+                BinaryOperationDescription {
+                    lhs: tensor(4, TensorStatus::ReadWrite, DType::F32),
+                    rhs: tensor(5, TensorStatus::ReadOnly, DType::F32),
+                    out: tensor(6, TensorStatus::NotInit, DType::F32),
+                },
+            )),
+            OperationDescription::NumericFloat(NumericOperationDescription::SumDim(
+                ScalarOperationDescription {
+                    lhs: tensor(6, TensorStatus::ReadWrite, DType::F32),
+                    rhs: 1,
+                    out: TensorDescription {
+                        id: TensorId::new(7),
+                        shape: vec![2, 1],
+                        status: TensorStatus::NotInit,
+                        dtype: DType::F32,
+                    },
+                },
+            )),
+        ];
+
+        let mut builders = Runtime::optimizations(Default::default());
+        // The first builder is the element wise one.
+        let elemwise = &mut builders[0];
+
+        for operation in operations.iter() {
+            elemwise.register(operation);
+        }
+
+        // Every element wise operation is fused, only the reduction is left for another kernel.
+        assert_eq!(elemwise.len(), operations.len() - 1);
+        assert!(matches!(elemwise.status(), OptimizationStatus::Closed));
+        assert!(elemwise.properties().ready);
+    }
+
+    #[test]
+    fn padding_mask_should_match_reference() {
+        let device = Default::default();
+        let ids = Tensor::<TestBackend, 2, Int>::from_data(
+            TensorData::from([[4, 7, 1, 0, 0], [3, 0, 0, 0, 0]]),
+            &device,
+        );
+        let pad = Tensor::<TestBackend, 2, Int>::zeros([2, 5], &device);
+        let scores = TestTensor::<2>::random([2, 5], Distribution::Default, &device);
+
+        let device_ref = Default::default();
+        let ids_ref = Tensor::<ReferenceBackend, 2, Int>::from_data(ids.to_data(), &device_ref);
+        let pad_ref = Tensor::<ReferenceBackend, 2, Int>::zeros([2, 5], &device_ref);
+        let scores_ref = ReferenceTensor::<2>::from_data(scores.to_data(), &device_ref);
+
+        let actual = (ids.not_equal(pad).float() * scores).sum_dim(1);
+        let expected = (ids_ref.not_equal(pad_ref).float() * scores_ref).sum_dim(1);
+
+        expected
+            .into_data()
+            .assert_approx_eq(&actual.into_data(), 3);
+    }
+}
@@ -0,0 +1,124 @@
+#[burn_tensor_testgen::testgen(fusion_matmul_epilogue)]
+mod tests {
+    use super::*;
+    use burn_tensor::{activation, Distribution, Tensor};
+
+    // Use the reexported test dependency.
+    #[cfg(feature = "perf-test")]
+    use burn_jit::tests::serial_test;
+    #[cfg(feature = "perf-test")]
+    use serial_test::serial;
+
+    #[test]
+    fn linear_gelu_should_match_reference() {
+        same_as_reference(true, activation::gelu, activation::gelu);
+    }
+
+    #[test]
+    fn linear_relu_should_match_reference() {
+        same_as_reference(true, activation::relu, activation::relu);
+    }
+
+    #[test]
+    fn linear_tanh_should_match_reference() {
+        same_as_reference(true, activation::tanh, activation::tanh);
+    }
+
+    #[test]
+    fn linear_gelu_without_bias_should_match_reference() {
+        same_as_reference(false, activation::gelu, activation::gelu);
+    }
+
+    #[test]
+    fn linear_without_activation_should_match_reference() {
+        same_as_reference(true, |x| x, |x| x);
+    }
+
+    #[test]
+    fn linear_gelu_with_intermediate_reused_should_match_reference() {
+        let device = Default::default();
+        let (x, weight, bias) = inputs(&device);
+        let (x_ref, weight_ref, bias_ref) = reference_inputs(&x, &weight, &bias);
+
+        // The output of the bias addition is read after the activation, so the activation can't
+        // be fused in the epilogue.
+        let hidden = x.matmul(weight.unsqueeze()) + bias.unsqueeze();
+        let actual = activation::gelu(hidden.clone()) + hidden;
+        let hidden_ref = x_ref.matmul(weight_ref.unsqueeze()) + bias_ref.unsqueeze();
+        let expected = activation::gelu(hidden_ref.clone()) + hidden_ref;
+
+        expected
+            .into_data()
+            .assert_approx_eq(&actual.into_data(), 3);
+    }
+
+    #[cfg(feature = "perf-test")]
+    #[test]
+    #[serial]
+    fn linear_relu_should_launch_a_single_kernel() {
+        use burn_jit::perf::measure;
+
+        let device = Default::default();
+        let (x, weight, bias) = inputs(&device);
+        let forward = || {
+            activation::relu(
+                x.clone().matmul(weight.clone().unsqueeze()) + bias.clone().unsqueeze(),
+            )
+        };
+
+        // The first execution tunes the tiling 2D kernel when autotune is enabled.
+        forward().into_data();
+        let (_, perf) = measure::<TestBackend, _>(&device, forward);
+
+        // The matmul, the bias and the activation are computed by the tiling 2D epilogue.
+        assert_eq!(perf.counters.num_kernels, 1, "{perf:?}");
+    }
+
+    fn same_as_reference(
+        with_bias: bool,
+        func: impl Fn(TestTensor<3>) -> TestTensor<3>,
+        func_ref: impl Fn(ReferenceTensor<3>) -> ReferenceTensor<3>,
+    ) {
+        let device = Default::default();
+        let (x, weight, bias) = inputs(&device);
+        let (x_ref, weight_ref, bias_ref) = reference_inputs(&x, &weight, &bias);
+
+        // Same operations as `Linear::forward`.
+        let mut hidden = x.matmul(weight.unsqueeze());
+        let mut hidden_ref = x_ref.matmul(weight_ref.unsqueeze());
+        if with_bias {
+            hidden = hidden + bias.unsqueeze();
+            hidden_ref = hidden_ref + bias_ref.unsqueeze();
+        }
+
+        func_ref(hidden_ref)
+            .into_data()
+            .assert_approx_eq(&func(hidden).into_data(), 3);
+    }
+
+    fn inputs(
+        device: &<TestBackend as burn_tensor::backend::Backend>::Device,
+    ) -> (TestTensor<3>, TestTensor<2>, TestTensor<1>) {
+        let distribution = Distribution::Uniform(-1.0, 1.0);
+
+        (
+            Tensor::random([2, 13, 24], distribution, device),
+            Tensor::random([24, 18], distribution, device),
+            Tensor::random([18], distribution, device),
+        )
+    }
+
+    fn reference_inputs(
+        x: &TestTensor<3>,
+        weight: &TestTensor<2>,
+        bias: &TestTensor<1>,
+    ) -> (ReferenceTensor<3>, ReferenceTensor<2>, ReferenceTensor<1>) {
+        let device = Default::default();
+
+        (
+            Tensor::from_data(x.to_data(), &device),
+            Tensor::from_data(weight.to_data(), &device),
+            Tensor::from_data(bias.to_data(), &device),
+        )
+    }
+}
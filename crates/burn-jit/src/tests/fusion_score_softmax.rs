@@ -0,0 +1,90 @@
+#[burn_tensor_testgen::testgen(fusion_score_softmax)]
+mod tests {
+    use super::*;
+    use burn_tensor::{activation, Distribution, Tensor};
+
+    #[test]
+    fn scaled_attention_scores_should_match_reference() {
+        same_as_reference(
+            [2, 13, 8],
+            [2, 37, 8],
+            |scores| scores / 8f32.sqrt(),
+            |scores| scores / 8f32.sqrt(),
+        );
+    }
+
+    #[test]
+    fn attention_scores_multiplied_by_the_scale_should_match_reference() {
+        same_as_reference(
+            [2, 16, 16],
+            [2, 32, 16],
+            |scores| scores * 0.25,
+            |scores| scores * 0.25,
+        );
+    }
+
+    #[test]
+    fn attention_scores_without_scale_should_match_reference() {
+        same_as_reference([1, 5, 12], [1, 19, 12], |scores| scores, |scores| scores);
+    }
+
+    #[test]
+    fn attention_scores_reused_after_the_softmax_should_match_reference() {
+        let device = Default::default();
+        let (query, key) = inputs([2, 7, 8], [2, 9, 8], &device);
+        let (query_ref, key_ref) = reference_inputs(&query, &key);
+
+        // The scores are read after the softmax, so they can't be kept in shared memory.
+        let scores = query.matmul(key.swap_dims(1, 2));
+        let actual = activation::softmax(scores.clone(), 2) + scores;
+        let scores_ref = query_ref.matmul(key_ref.swap_dims(1, 2));
+        let expected = activation::softmax(scores_ref.clone(), 2) + scores_ref;
+
+        expected
+            .into_data()
+            .assert_approx_eq_diff(&actual.into_data(), 1e-5);
+    }
+
+    fn same_as_reference(
+        shape_query: [usize; 3],
+        shape_key: [usize; 3],
+        scale: impl Fn(TestTensor<3>) -> TestTensor<3>,
+        scale_ref: impl Fn(ReferenceTensor<3>) -> ReferenceTensor<3>,
+    ) {
+        let device = Default::default();
+        let (query, key) = inputs(shape_query, shape_key, &device);
+        let (query_ref, key_ref) = reference_inputs(&query, &key);
+
+        let actual = activation::softmax(scale(query.matmul(key.swap_dims(1, 2))), 2);
+        let expected = activation::softmax(scale_ref(query_ref.matmul(key_ref.swap_dims(1, 2))), 2);
+
+        expected
+            .into_data()
+            .assert_approx_eq_diff(&actual.into_data(), 1e-5);
+    }
+
+    fn inputs(
+        shape_query: [usize; 3],
+        shape_key: [usize; 3],
+        device: &<TestBackend as burn_tensor::backend::Backend>::Device,
+    ) -> (TestTensor<3>, TestTensor<3>) {
+        let distribution = Distribution::Uniform(-1.0, 1.0);
+
+        (
+            Tensor::random(shape_query, distribution, device),
+            Tensor::random(shape_key, distribution, device),
+        )
+    }
+
+    fn reference_inputs(
+        query: &TestTensor<3>,
+        key: &TestTensor<3>,
+    ) -> (ReferenceTensor<3>, ReferenceTensor<3>) {
+        let device = Default::default();
+
+        (
+            Tensor::from_data(query.to_data(), &device),
+            Tensor::from_data(key.to_data(), &device),
+        )
+    }
+}
@@ -0,0 +1,131 @@
+#[burn_tensor_testgen::testgen(gemm)]
+mod tests {
+    use super::*;
+    use burn_jit::{
+        cubecl::prelude::TensorHandleRef,
+        kernel::matmul::{launch_gemm, GemmConfig},
+    };
+    use burn_tensor::{Distribution, Tensor, TensorPrimitive};
+
+    #[test]
+    fn gemm_should_broadcast_the_batches() {
+        let device = Default::default();
+        let lhs = TestTensor::<4>::random([2, 1, 6, 8], Distribution::Uniform(-1.0, 1.0), &device);
+        let rhs = TestTensor::<4>::random([1, 3, 8, 5], Distribution::Uniform(-1.0, 1.0), &device);
+        let out = TestTensor::<4>::zeros([2, 3, 6, 5], &device);
+        let lhs_ref = ReferenceTensor::<4>::from_data(lhs.to_data(), &device);
+        let rhs_ref = ReferenceTensor::<4>::from_data(rhs.to_data(), &device);
+
+        let actual = gemm(
+            lhs,
+            rhs,
+            out,
+            GemmConfig {
+                alpha: 2.0,
+                ..Default::default()
+            },
+        );
+
+        let expected = lhs_ref.matmul(rhs_ref).mul_scalar(2.0);
+        expected
+            .into_data()
+            .assert_approx_eq(&actual.into_data(), 3);
+    }
+
+    #[test]
+    fn gemm_should_blend_the_previous_output() {
+        let device = Default::default();
+        let lhs = TestTensor::<3>::random([2, 7, 12], Distribution::Uniform(-1.0, 1.0), &device);
+        let rhs = TestTensor::<3>::random([2, 12, 9], Distribution::Uniform(-1.0, 1.0), &device);
+        let out = TestTensor::<3>::random([2, 7, 9], Distribution::Uniform(-1.0, 1.0), &device);
+        let lhs_ref = ReferenceTensor::<3>::from_data(lhs.to_data(), &device);
+        let rhs_ref = ReferenceTensor::<3>::from_data(rhs.to_data(), &device);
+        let out_ref = ReferenceTensor::<3>::from_data(out.to_data(), &device);
+
+        let actual = gemm(
+            lhs,
+            rhs,
+            out,
+            GemmConfig {
+                beta: 0.5,
+                ..Default::default()
+            },
+        );
+
+        let expected = lhs_ref.matmul(rhs_ref) + out_ref.mul_scalar(0.5);
+        expected
+            .into_data()
+            .assert_approx_eq(&actual.into_data(), 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "should have the same inner dimension")]
+    fn gemm_should_panic_when_the_inner_dimensions_differ() {
+        let device = Default::default();
+        let lhs = TestTensor::<2>::zeros([4, 6], &device);
+        let rhs = TestTensor::<2>::zeros([5, 3], &device);
+        let out = TestTensor::<2>::zeros([4, 3], &device);
+
+        gemm(lhs, rhs, out, GemmConfig::default());
+    }
+
+    #[test]
+    #[should_panic(expected = "the rhs should be column major")]
+    fn gemm_should_panic_when_the_rhs_is_row_major() {
+        let device = Default::default();
+        let lhs = TestTensor::<2>::zeros([4, 6], &device)
+            .into_primitive()
+            .tensor();
+        let rhs = TestTensor::<2>::zeros([6, 3], &device)
+            .into_primitive()
+            .tensor();
+        let out = TestTensor::<2>::zeros([4, 3], &device)
+            .into_primitive()
+            .tensor();
+
+        launch_gemm::<TestRuntime, f32>(
+            &lhs.client,
+            lhs.as_handle_ref(),
+            rhs.as_handle_ref(),
+            out.as_handle_ref(),
+            GemmConfig::default(),
+        );
+    }
+
+    /// Launches the GEMM with the column major layout of the rhs, read from its contiguous
+    /// transpose.
+    fn gemm<const D: usize>(
+        lhs: TestTensor<D>,
+        rhs: TestTensor<D>,
+        out: TestTensor<D>,
+        config: GemmConfig<f32>,
+    ) -> TestTensor<D> {
+        let device = Default::default();
+        let rhs_transposed =
+            TestTensor::<D>::from_data(rhs.swap_dims(D - 1, D - 2).into_data(), &device);
+
+        let lhs = lhs.into_primitive().tensor();
+        let rhs_transposed = rhs_transposed.into_primitive().tensor();
+        let out = out.into_primitive().tensor();
+
+        let mut shape = rhs_transposed.shape.dims;
+        let mut strides = rhs_transposed.strides;
+        shape.swap(D - 1, D - 2);
+        strides.swap(D - 1, D - 2);
+        let rhs = TensorHandleRef {
+            handle: &rhs_transposed.handle,
+            strides: &strides,
+            shape: &shape,
+        };
+
+        launch_gemm::<TestRuntime, f32>(
+            &lhs.client,
+            lhs.as_handle_ref(),
+            rhs,
+            out.as_handle_ref(),
+            config,
+        );
+
+        Tensor::from_primitive(TensorPrimitive::Float(out))
+    }
+}
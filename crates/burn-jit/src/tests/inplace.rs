@@ -0,0 +1,78 @@
+#[burn_tensor_testgen::testgen(inplace)]
+mod tests {
+    use super::*;
+    use burn_tensor::{activation::relu, Distribution, Tensor, TensorData, TensorPrimitive};
+
+    #[test]
+    fn elementwise_chain_should_reuse_the_input_buffer() {
+        let input =
+            Tensor::<TestBackend, 2>::random([32, 64], Distribution::Default, &Default::default());
+        let input_ref =
+            Tensor::<ReferenceBackend, 2>::from_data(input.to_data(), &Default::default());
+
+        let mut output = input;
+        let mut output_ref = input_ref;
+        for _ in 0..5 {
+            output = relu(output.add_scalar(-0.25));
+            output_ref = relu(output_ref.add_scalar(-0.25));
+        }
+
+        // Every op of the chain consumed a uniquely referenced buffer, so the result is written in
+        // the buffer of the input, which is still uniquely referenced.
+        let primitive = output.into_primitive().tensor();
+        assert!(primitive.can_mut());
+
+        Tensor::<TestBackend, 2>::from_primitive(TensorPrimitive::Float(primitive))
+            .into_data()
+            .assert_approx_eq(&output_ref.into_data(), 3);
+    }
+
+    #[test]
+    fn same_tensor_as_both_operands_should_not_be_mutated() {
+        let tensor =
+            Tensor::<TestBackend, 2>::random([8, 16], Distribution::Default, &Default::default());
+        let tensor_ref =
+            Tensor::<ReferenceBackend, 2>::from_data(tensor.to_data(), &Default::default());
+
+        let added = tensor.clone() + tensor.clone();
+        let multiplied = tensor.clone() * tensor.clone();
+        let assigned = tensor
+            .clone()
+            .slice_assign([0..4, 0..16], tensor.clone().slice([4..8, 0..16]));
+
+        added
+            .into_data()
+            .assert_approx_eq(&(tensor_ref.clone() + tensor_ref.clone()).into_data(), 3);
+        multiplied
+            .into_data()
+            .assert_approx_eq(&(tensor_ref.clone() * tensor_ref.clone()).into_data(), 3);
+        assigned.into_data().assert_approx_eq(
+            &tensor_ref
+                .clone()
+                .slice_assign([0..4, 0..16], tensor_ref.clone().slice([4..8, 0..16]))
+                .into_data(),
+            3,
+        );
+        tensor
+            .into_data()
+            .assert_approx_eq(&tensor_ref.into_data(), 3);
+    }
+
+    #[test]
+    fn broadcasted_tensor_should_not_be_mutated_in_place() {
+        let tensor = Tensor::<TestBackend, 2>::from_floats([[1.0, -2.0, 3.0]], &Default::default())
+            .expand([4, 3]);
+
+        let primitive = tensor.into_primitive().tensor();
+        assert!(!primitive.can_mut());
+
+        let output = relu(
+            Tensor::<TestBackend, 2>::from_primitive(TensorPrimitive::Float(primitive))
+                .add_scalar(1.0),
+        );
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[2.0, 0.0, 4.0]; 4]), false);
+    }
+}
@@ -0,0 +1,64 @@
+#[burn_tensor_testgen::testgen(large_index)]
+mod tests {
+    use super::*;
+    use burn_jit::{
+        kernel::{into_contiguous, matmul::matmul},
+        tensor::JitTensor,
+    };
+
+    /// A view of the tensor whose rows are `2^32` elements apart, without the memory to back
+    /// them, so it must be rejected before any kernel reads it.
+    fn large_view<const D: usize>(
+        mut tensor: JitTensor<TestRuntime, f32, D>,
+    ) -> JitTensor<TestRuntime, f32, D> {
+        tensor.strides[0] = 1 << 32;
+        tensor
+    }
+
+    #[test]
+    fn span_should_follow_the_strides() {
+        let device = Default::default();
+        let tensor = TestTensor::<3>::ones([2, 3, 4], &device)
+            .into_primitive()
+            .tensor();
+        assert_eq!(tensor.span(), 24);
+        assert_eq!(large_view(tensor).span(), (1 << 32) + 12);
+
+        let empty = TestTensor::<3>::ones([2, 0, 4], &device)
+            .into_primitive()
+            .tensor();
+        assert_eq!(empty.span(), 0);
+
+        // Broadcasted tensors span the elements they read, not the elements of their shape.
+        let broadcasted = TestTensor::<2>::ones([1, 1], &device)
+            .expand([1 << 20, 1 << 13])
+            .into_primitive()
+            .tensor();
+        assert_eq!(broadcasted.span(), 1);
+        broadcasted.assert_u32_indexable("test");
+    }
+
+    #[test]
+    #[should_panic = "32 bit integers"]
+    fn into_contiguous_should_reject_tensors_larger_than_u32() {
+        let tensor = TestTensor::<2>::ones([2, 2], &Default::default())
+            .into_primitive()
+            .tensor();
+
+        into_contiguous(large_view(tensor));
+    }
+
+    #[test]
+    #[should_panic = "32 bit integers"]
+    fn matmul_should_reject_tensors_larger_than_u32() {
+        let device = Default::default();
+        let lhs = TestTensor::<2>::ones([2, 2], &device)
+            .into_primitive()
+            .tensor();
+        let rhs = TestTensor::<2>::ones([2, 2], &device)
+            .into_primitive()
+            .tensor();
+
+        matmul(large_view(lhs), rhs, Default::default());
+    }
+}
@@ -1,7 +1,10 @@
 #[burn_tensor_testgen::testgen(matmul)]
 mod tests {
     use super::*;
-    use burn_jit::kernel::matmul::{matmul, MatmulStrategy};
+    use burn_jit::kernel::matmul::{
+        is_transposed, matmul, matmul_accumulate, matmul_cube, matmul_gemm, matmul_simple,
+        matmul_tiling_2d, CubeTiling2dConfig, MatmulStrategy, Tiling2dConfig,
+    };
     use burn_tensor::{Shape, Tensor, TensorPrimitive};
 
     mod simple {
@@ -52,6 +55,11 @@ mod tests {
             test_with_params::<3, 3>(7, 7, 7, 1, 1);
         }
 
+        #[test]
+        pub fn small_cube_dims_divide_shapes_unevenly() {
+            test_with_params::<8, 8>(37, 19, 45, 2, 1);
+        }
+
         #[test]
         fn swapped_batches_no_padding() {
             let strategy = MatmulStrategy::Simple {
@@ -110,294 +118,1285 @@ mod tests {
         }
     }
 
-    mod padding {
+    mod tiling2d {
         use super::*;
-        use burn_jit::kernel::matmul::padding::{crop, pad_round};
-        use burn_tensor::backend::Backend;
-
-        fn padding_already_round_should_have_same_shape() {
-            let row = 10;
-            let row_divisor = 5;
-            let col = 12;
-            let col_divisor = 3;
-            let tensor = TestTensor::random(
-                [row, col],
-                burn_tensor::Distribution::Default,
-                &Default::default(),
-            );
-            let expected_shape = [row, col].into();
 
-            let padded =
-                pad_round(tensor.into_primitive().tensor(), row_divisor, col_divisor).into_tensor();
+        #[test]
+        fn k_multiple_of_block_size() {
+            test_both_buffering(8, [2, 24, 32], [2, 32, 17]);
+        }
 
-            assert!(padded.shape == expected_shape);
+        #[test]
+        fn k_not_multiple_of_block_size() {
+            test_both_buffering(8, [2, 19, 29], [2, 29, 21]);
         }
 
         #[test]
-        fn padding_already_round_should_have_same_values() {
-            let row = 10;
-            let row_divisor = 5;
-            let col = 12;
-            let col_divisor = 3;
-            let tensor = TestTensor::random(
-                [row, col],
-                burn_tensor::Distribution::Default,
-                &Default::default(),
+        fn k_smaller_than_block_size() {
+            test_both_buffering(16, [3, 20, 5], [3, 5, 33]);
+        }
+
+        #[test]
+        fn tile_of_two_elements() {
+            test_both_buffering(6, [2, 13, 14], [2, 14, 7]);
+        }
+
+        #[test]
+        fn tile_of_one_element() {
+            test_both_buffering(5, [1, 11, 9], [1, 9, 12]);
+        }
+
+        #[test]
+        fn tile_of_eight_elements_vectorized_by_four() {
+            test_both_buffering_with_tile(16, Some(8), [2, 20, 13], [2, 13, 12]);
+        }
+
+        #[test]
+        fn tile_of_eight_elements_vectorized_by_two() {
+            test_both_buffering_with_tile(16, Some(8), [1, 6, 7], [1, 7, 6]);
+        }
+
+        #[test]
+        fn tile_of_eight_elements_without_bound_checks() {
+            test_both_buffering_with_tile(16, Some(8), [2, 32, 32], [2, 32, 16]);
+        }
+
+        #[test]
+        fn tile_of_eight_elements_not_vectorized() {
+            test_both_buffering_with_tile(8, Some(8), [1, 9, 5], [1, 5, 7]);
+        }
+
+        #[test]
+        fn shapes_multiple_of_block_size_without_bound_checks() {
+            test_both_buffering(8, [2, 16, 24], [2, 24, 8]);
+        }
+
+        /// The specialization compiled by each launch, as recorded by the launch itself.
+        mod launched_path {
+            use super::*;
+            use burn_jit::{kernel::matmul::Tiling2dPath, perf::take_last_tiling_2d_path};
+
+            #[test]
+            fn aligned_shapes_should_launch_the_aligned_path() {
+                let path = |lhs: [usize; 3], rhs: [usize; 3]| {
+                    launched_path(Tiling2dConfig::default(), lhs, rhs, false)
+                };
+
+                assert_eq!(
+                    path([1, 1024, 1024], [1, 1024, 1024]),
+                    Tiling2dPath::Aligned
+                );
+                assert_eq!(path([8, 64, 32], [1, 32, 48]), Tiling2dPath::Aligned);
+                assert_eq!(
+                    path([1, 1000, 1024], [1, 1024, 1024]),
+                    Tiling2dPath::Checked
+                );
+                assert_eq!(
+                    path([1, 1024, 1000], [1, 1000, 1024]),
+                    Tiling2dPath::Checked
+                );
+                assert_eq!(
+                    path([1, 1024, 1024], [1, 1024, 1000]),
+                    Tiling2dPath::Checked
+                );
+            }
+
+            #[test]
+            fn aligned_shapes_written_in_smaller_vectors_should_launch_the_checked_path() {
+                let config = Tiling2dConfig {
+                    block_size: 16,
+                    tile_size: Some(2),
+                    double_buffering: true,
+                    split_k_ratio: None,
+                };
+
+                let path = launched_path(config, [1, 32, 32], [1, 32, 32], false);
+
+                assert_eq!(path, Tiling2dPath::Checked);
+            }
+
+            #[test]
+            fn aligned_shapes_with_a_transposed_output_should_launch_the_checked_path() {
+                let path = launched_path(Tiling2dConfig::default(), [1, 64, 64], [1, 64, 64], true);
+
+                assert_eq!(path, Tiling2dPath::Checked);
+            }
+
+            /// The specialization compiled by the launch of the tiling 2D kernel for the shapes,
+            /// into a contiguous output or one whose last two dimensions are swapped.
+            fn launched_path(
+                config: Tiling2dConfig,
+                shape_lhs: [usize; 3],
+                shape_rhs: [usize; 3],
+                transposed_out: bool,
+            ) -> Tiling2dPath {
+                let device = Default::default();
+                let lhs = TestTensor::<3>::zeros(shape_lhs, &device)
+                    .into_primitive()
+                    .tensor();
+                let rhs = TestTensor::<3>::zeros(shape_rhs, &device)
+                    .into_primitive()
+                    .tensor();
+                let out = match transposed_out {
+                    true => {
+                        let batches = usize::max(shape_lhs[0], shape_rhs[0]);
+                        TestTensor::<3>::zeros([batches, shape_rhs[2], shape_lhs[1]], &device)
+                            .swap_dims(1, 2)
+                            .into_primitive()
+                            .tensor()
+                    }
+                    false => burn_jit::kernel::matmul::init_matmul_output(&lhs, &rhs),
+                };
+
+                // The path is recorded per thread, each test running on its own.
+                take_last_tiling_2d_path();
+                matmul_tiling_2d(lhs, rhs, out, config);
+
+                take_last_tiling_2d_path().expect("The tiling 2D kernel should be launched")
+            }
+        }
+
+        #[test]
+        fn aligned_path_should_match_reference() {
+            test_both_buffering(16, [2, 64, 32], [1, 32, 48]);
+        }
+
+        #[test]
+        fn aligned_path_with_tile_of_eight_elements_should_match_reference() {
+            test_both_buffering_with_tile(32, Some(8), [1, 64, 96], [1, 96, 32]);
+        }
+
+        #[cfg(debug_assertions)]
+        #[test]
+        #[should_panic = "the aligned path needs the 20 rows"]
+        fn aligned_config_should_panic_on_shapes_not_multiple_of_the_block_size() {
+            let config = Tiling2dConfig::default();
+
+            let _ = CubeTiling2dConfig::aligned(
+                &config,
+                &Shape::new([1, 20, 16]),
+                &Shape::new([1, 16, 32]),
+                &Shape::new([1, 20, 32]),
+                4,
             );
+        }
 
-            let padded = pad_round(
-                tensor.clone().into_primitive().tensor(),
-                row_divisor,
-                col_divisor,
+        #[test]
+        fn aligned_config_should_reject_columns_not_multiple_of_four() {
+            let config = Tiling2dConfig::default();
+
+            let result = CubeTiling2dConfig::aligned(
+                &config,
+                &Shape::new([1, 16, 16]),
+                &Shape::new([1, 16, 18]),
+                &Shape::new([1, 16, 18]),
+                4,
             );
 
-            let padded = TestTensor::from_primitive(TensorPrimitive::Float((padded.into_tensor())));
-            padded.into_data().assert_approx_eq(&tensor.into_data(), 3);
+            assert!(result.is_err());
         }
 
         #[test]
-        fn padding_not_round_should_have_rounded_shape() {
-            let row = 10;
-            let row_divisor = 6;
-            let col = 12;
-            let col_divisor = 5;
-            let tensor = TestTensor::random(
-                [row, col],
-                burn_tensor::Distribution::Default,
-                &Default::default(),
+        fn products_should_be_fused_like_the_cpu() {
+            let [m, k, n] = [16, 48, 16];
+            let distribution = burn_tensor::Distribution::Uniform(-1.0, 1.0);
+            let x = ReferenceTensor::<3>::random([1, m, k], distribution, &Default::default());
+            let y = ReferenceTensor::<3>::random([1, k, n], distribution, &Default::default());
+            let x_values = x.to_data().to_vec::<f32>().unwrap();
+            let y_values = y.to_data().to_vec::<f32>().unwrap();
+
+            // Accumulated in the order of the inner dimension, like each unit of the kernel.
+            let expected = (0..m * n)
+                .map(|index| {
+                    let (row, col) = (index / n, index % n);
+                    (0..k).fold(0.0f32, |sum, i| {
+                        x_values[row * k + i].mul_add(y_values[i * n + col], sum)
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            let config = Tiling2dConfig {
+                block_size: 16,
+                tile_size: None,
+                double_buffering: true,
+                split_k_ratio: None,
+            };
+            let device = Default::default();
+            let z = Tensor::<TestBackend, 3>::from_primitive(TensorPrimitive::Float(matmul(
+                TestTensor::from_data(x.to_data(), &device)
+                    .into_primitive()
+                    .tensor(),
+                TestTensor::from_data(y.to_data(), &device)
+                    .into_primitive()
+                    .tensor(),
+                MatmulStrategy::Tiling2d(config),
+            )));
+            let actual = z.into_data().to_vec::<f32>().unwrap();
+
+            // The distance between two floats in units in the last place.
+            let ordered = |value: f32| {
+                let bits = value.to_bits();
+                match bits >> 31 {
+                    0 => bits as i64,
+                    _ => -((bits & 0x7fff_ffff) as i64),
+                }
+            };
+            for (index, (actual, expected)) in actual.iter().zip(expected).enumerate() {
+                let ulps = (ordered(*actual) - ordered(expected)).abs();
+                assert!(
+                    ulps <= 1,
+                    "Element {index}: {actual} is {ulps} ulps away from {expected}"
+                );
+            }
+        }
+
+        #[test]
+        fn config_should_count_the_shared_memory_of_both_inputs() {
+            let config = Tiling2dConfig {
+                block_size: 32,
+                tile_size: Some(4),
+                double_buffering: true,
+                split_k_ratio: None,
+            };
+
+            assert_eq!(config.shared_memory_size::<f32>(), 2 * 2 * 32 * 32 * 4);
+            assert_eq!(config.check_shared_memory_size::<f32>(None), Ok(()));
+            assert_eq!(
+                config.check_shared_memory_size::<f32>(Some(16 * 1024)),
+                Ok(())
             );
-            let expected_shape = [12, 15].into();
+            assert!(config
+                .check_shared_memory_size::<f32>(Some(16 * 1024 - 1))
+                .is_err());
+        }
 
-            let padded =
-                pad_round(tensor.into_primitive().tensor(), row_divisor, col_divisor).into_tensor();
+        #[test]
+        #[should_panic = "bytes of shared memory per cube"]
+        fn blocks_larger_than_the_shared_memory_should_panic() {
+            // 256 KiB of shared memory, more than any device has.
+            let strategy = MatmulStrategy::Tiling2d(Tiling2dConfig {
+                block_size: 128,
+                tile_size: Some(8),
+                double_buffering: true,
+                split_k_ratio: None,
+            });
 
-            assert!(padded.shape == expected_shape);
+            same_as_reference(strategy, [1, 128, 128], [1, 128, 128]);
         }
 
         #[test]
-        fn padding_not_round_should_have_same_values() {
-            let row = 10;
-            let row_divisor = 6;
-            let col = 12;
-            let col_divisor = 5;
-            let tensor = TestTensor::random(
-                [row, col],
-                burn_tensor::Distribution::Default,
-                &Default::default(),
+        fn single_block() {
+            test_both_buffering(16, [1, 16, 16], [1, 16, 16]);
+        }
+
+        #[test]
+        fn mixed_broadcast() {
+            test_both_buffering(8, [1, 3, 17, 12], [4, 1, 12, 9]);
+        }
+
+        #[test]
+        fn swapped_row_col() {
+            let strategy = MatmulStrategy::Tiling2d(Tiling2dConfig {
+                block_size: 4,
+                tile_size: None,
+                double_buffering: true,
+                split_k_ratio: None,
+            });
+            same_as_reference_swapped_dims(strategy, [0, 0], [2, 3], [3, 2, 9, 9], [3, 2, 9, 9]);
+        }
+
+        #[test]
+        fn config_should_reject_a_tile_size_not_dividing_the_block_size() {
+            let err = from_shapes(16, [8, 4], [4, 8], [8, 8], 3, 1).unwrap_err();
+
+            assert_eq!(
+                err.to_string(),
+                "Tiling 2D: the tile size 3 should divide the block size 16, both being positive."
             );
+        }
+
+        #[test]
+        fn config_should_reject_a_cube_too_large() {
+            let err = from_shapes(64, [8, 4], [4, 8], [8, 8], 1, 1).unwrap_err();
+
+            assert_eq!(
+                err.to_string(),
+                "Tiling 2D: the block size 64 with the tile size 1 needs 64x64 units per cube, \
+                 more than the maximum of 1024."
+            );
+        }
 
-            let padded = pad_round(
-                tensor.clone().into_primitive().tensor(),
-                row_divisor,
-                col_divisor,
+        #[test]
+        fn config_should_reject_a_vectorization_not_dividing_the_columns() {
+            let err = from_shapes(16, [6, 4], [4, 6], [6, 6], 8, 4).unwrap_err();
+
+            assert_eq!(
+                err.to_string(),
+                "Tiling 2D: the vectorization 4 should divide the tile size 8 and the 6 columns \
+                 of the output."
+            );
+            assert!(from_shapes(16, [6, 4], [4, 6], [6, 6], 8, 2).is_ok());
+            assert!(from_shapes(16, [6, 4], [4, 8], [6, 8], 2, 4).is_err());
+        }
+
+        #[test]
+        fn config_should_reject_stale_output_shapes() {
+            let err = from_shapes(16, [8, 4], [4, 8], [6, 8], 4, 1).unwrap_err();
+
+            assert_eq!(
+                err.to_string(),
+                "Tiling 2D: the lhs shape [8, 4] and the rhs shape [4, 8] should have the same \
+                 inner dimension and batch dimensions broadcastable to the ones of the out shape \
+                 [6, 8], which should end with the rows of the lhs and the columns of the rhs."
+            );
+        }
+
+        #[test]
+        fn config_should_reject_batches_not_broadcastable() {
+            assert!(from_shapes(16, [2, 8, 4], [3, 4, 8], [3, 8, 8], 4, 1).is_err());
+            assert!(from_shapes(16, [1, 8, 4], [3, 4, 8], [3, 8, 8], 4, 1).is_ok());
+        }
+
+        fn from_shapes<const D: usize>(
+            block_size: usize,
+            shape_lhs: [usize; D],
+            shape_rhs: [usize; D],
+            shape_out: [usize; D],
+            tile_size: usize,
+            vectorization: usize,
+        ) -> Result<CubeTiling2dConfig, burn_jit::kernel::matmul::Tiling2dConfigError> {
+            let config = Tiling2dConfig {
+                block_size,
+                tile_size: Some(tile_size),
+                double_buffering: false,
+                split_k_ratio: None,
+            };
+
+            CubeTiling2dConfig::from_shapes(
+                &config,
+                &shape_lhs.into(),
+                &shape_rhs.into(),
+                &shape_out.into(),
+                tile_size,
+                vectorization,
             )
-            .into_tensor();
-
-            let padded = TestTensor::from_primitive(TensorPrimitive::Float(padded)).to_data();
-            let padded = padded
-                .as_slice::<<TestBackend as Backend>::FloatElem>()
-                .unwrap();
-            let tensor = tensor.into_data();
-            let tensor = tensor
-                .as_slice::<<TestBackend as Backend>::FloatElem>()
-                .unwrap();
-            for i in 0..row {
-                for j in 0..col {
-                    assert!(padded[i * 15 + j] == tensor[i * col + j]);
-                }
+        }
+
+        fn test_both_buffering<const D: usize>(
+            block_size: usize,
+            shape_lhs: [usize; D],
+            shape_rhs: [usize; D],
+        ) {
+            test_both_buffering_with_tile(block_size, None, shape_lhs, shape_rhs);
+        }
+
+        fn test_both_buffering_with_tile<const D: usize>(
+            block_size: usize,
+            tile_size: Option<usize>,
+            shape_lhs: [usize; D],
+            shape_rhs: [usize; D],
+        ) {
+            for double_buffering in [false, true] {
+                let strategy = MatmulStrategy::Tiling2d(Tiling2dConfig {
+                    block_size,
+                    tile_size,
+                    double_buffering,
+                    split_k_ratio: None,
+                });
+                same_as_reference(strategy, shape_lhs, shape_rhs);
             }
         }
+    }
+
+    mod broadcast {
+        use super::*;
 
         #[test]
-        fn padding_not_round_should_have_zero_padding() {
-            let row = 10;
-            let row_divisor = 6;
-            let col = 12;
-            let col_divisor = 5;
-            let tensor = TestTensor::random(
-                [row, col],
-                burn_tensor::Distribution::Default,
-                &Default::default(),
+        fn cube_lhs_broadcast() {
+            same_as_reference(MatmulStrategy::Cube, [1, 3, 16, 8], [4, 3, 8, 12]);
+        }
+
+        #[test]
+        fn cube_rhs_broadcast() {
+            same_as_reference(MatmulStrategy::Cube, [4, 3, 16, 8], [4, 1, 8, 12]);
+        }
+
+        #[test]
+        fn cube_mixed_broadcast() {
+            same_as_reference(MatmulStrategy::Cube, [1, 3, 16, 8], [4, 1, 8, 12]);
+        }
+
+        #[test]
+        fn simple_mixed_broadcast() {
+            let strategy = MatmulStrategy::Simple {
+                grid_x: 8,
+                grid_y: 8,
+            };
+            same_as_reference(strategy, [4, 1, 16, 8], [1, 3, 8, 12]);
+        }
+    }
+
+    mod transposed_output {
+        use super::*;
+        use burn_jit::tensor::JitTensor;
+
+        #[test]
+        fn simple_should_follow_the_output_strides() {
+            same_as_reference_transposed_output([19, 7], [7, 21], |x, y, out| {
+                matmul_simple(x, y, out, 16, 16)
+            });
+        }
+
+        #[test]
+        fn simple_batched_should_follow_the_output_strides() {
+            same_as_reference_transposed_output([3, 2, 17, 8], [3, 2, 8, 9], |x, y, out| {
+                matmul_simple(x, y, out, 8, 8)
+            });
+        }
+
+        #[test]
+        fn cube_should_follow_the_output_strides() {
+            same_as_reference_transposed_output([2, 33, 16], [2, 16, 20], matmul_cube);
+        }
+
+        #[test]
+        fn tiling_2d_should_follow_the_output_strides() {
+            same_as_reference_transposed_output([2, 21, 13], [2, 13, 10], |x, y, out| {
+                matmul_tiling_2d(x, y, out, Tiling2dConfig::default())
+            });
+        }
+
+        #[test]
+        fn gemm_should_follow_the_output_strides() {
+            same_as_reference_transposed_output([12, 8], [8, 5], |x, y, out| {
+                matmul_gemm(x, y, out, 1.0, 0.0)
+            });
+        }
+
+        /// Writes the matmul into an output whose last two dimensions are swapped, with strides
+        /// `[1, rows]` for a single matrix.
+        fn same_as_reference_transposed_output<const D: usize, F>(
+            shape_lhs: [usize; D],
+            shape_rhs: [usize; D],
+            func: F,
+        ) where
+            F: Fn(
+                JitTensor<TestRuntime, f32, D>,
+                JitTensor<TestRuntime, f32, D>,
+                JitTensor<TestRuntime, f32, D>,
+            ) -> JitTensor<TestRuntime, f32, D>,
+        {
+            let device = Default::default();
+            let distribution = burn_tensor::Distribution::Uniform(-1.0, 1.0);
+            let x = ReferenceTensor::<D>::random(shape_lhs, distribution, &device);
+            let y = ReferenceTensor::<D>::random(shape_rhs, distribution, &device);
+            let mut shape_transposed = shape_lhs;
+            shape_transposed[D - 2] = shape_rhs[D - 1];
+            shape_transposed[D - 1] = shape_lhs[D - 2];
+
+            let x_jit = TestTensor::<D>::from_data(x.to_data(), &device);
+            let y_jit = TestTensor::<D>::from_data(y.to_data(), &device);
+            let out = TestTensor::<D>::zeros(shape_transposed, &device)
+                .swap_dims(D - 2, D - 1)
+                .into_primitive()
+                .tensor();
+            assert_eq!(out.strides[D - 2], 1);
+
+            let z = Tensor::<TestBackend, D>::from_primitive(TensorPrimitive::Float(func(
+                x_jit.into_primitive().tensor(),
+                y_jit.into_primitive().tensor(),
+                out,
+            )));
+
+            x.matmul(y).into_data().assert_approx_eq(&z.into_data(), 3);
+        }
+    }
+
+    mod gemm {
+        use super::*;
+
+        #[test]
+        fn vectorized_with_beta_on_partial_tiles() {
+            same_as_reference_gemm([2, 37, 8], [2, 8, 45], 0.5, 2.0);
+        }
+
+        #[test]
+        fn scalar_with_beta_on_partial_tiles() {
+            same_as_reference_gemm([3, 19, 7], [3, 7, 21], -1.5, 0.25);
+        }
+
+        #[test]
+        fn zero_beta_should_not_read_output() {
+            let device = Default::default();
+            let x = TestTensor::<2>::ones([5, 4], &device);
+            let y = TestTensor::<2>::ones([4, 3], &device);
+            let c = TestTensor::<2>::full([5, 3], f32::NAN, &device);
+
+            let z = Tensor::<TestBackend, 2>::from_primitive(TensorPrimitive::Float(matmul_gemm(
+                x.into_primitive().tensor(),
+                y.into_primitive().tensor(),
+                c.into_primitive().tensor(),
+                2.0,
+                0.0,
+            )));
+
+            z.into_data()
+                .assert_approx_eq(&TestTensor::<2>::full([5, 3], 8.0, &device).into_data(), 3);
+        }
+
+        fn same_as_reference_gemm<const D: usize>(
+            shape_lhs: [usize; D],
+            shape_rhs: [usize; D],
+            alpha: f32,
+            beta: f32,
+        ) {
+            let device = Default::default();
+            let distribution = burn_tensor::Distribution::Uniform(-1.0, 1.0);
+            let mut shape_out = shape_lhs;
+            shape_out[D - 1] = shape_rhs[D - 1];
+            let x = ReferenceTensor::<D>::random(shape_lhs, distribution, &device);
+            let y = ReferenceTensor::<D>::random(shape_rhs, distribution, &device);
+            let c = ReferenceTensor::<D>::random(shape_out, distribution, &device);
+
+            let x_jit = TestTensor::<D>::from_data(x.to_data(), &device);
+            let y_jit = TestTensor::<D>::from_data(y.to_data(), &device);
+            let c_jit = TestTensor::<D>::from_data(c.to_data(), &device);
+
+            let z_reference = x.matmul(y).mul_scalar(alpha) + c.mul_scalar(beta);
+            let z = Tensor::<TestBackend, D>::from_primitive(TensorPrimitive::Float(matmul_gemm(
+                x_jit.into_primitive().tensor(),
+                y_jit.into_primitive().tensor(),
+                c_jit.into_primitive().tensor(),
+                alpha,
+                beta,
+            )));
+
+            z_reference.into_data().assert_approx_eq(&z.into_data(), 3);
+        }
+    }
+
+    mod accumulate {
+        use super::*;
+
+        #[test]
+        fn vectorized_on_partial_tiles() {
+            same_as_reference_accumulate(16, None, [2, 20, 13], [2, 13, 12]);
+        }
+
+        #[test]
+        fn scalar_out_of_bounds_5x1() {
+            same_as_reference_accumulate(4, None, [5, 3], [3, 1]);
+        }
+
+        #[test]
+        fn tile_of_eight_elements_not_vectorized() {
+            same_as_reference_accumulate(8, Some(8), [1, 9, 5], [1, 5, 7]);
+        }
+
+        #[test]
+        fn aligned_path() {
+            same_as_reference_accumulate(16, None, [2, 32, 16], [2, 16, 32]);
+        }
+
+        #[test]
+        fn broadcasted_batches() {
+            same_as_reference_accumulate(8, None, [1, 3, 17, 12], [4, 1, 12, 9]);
+        }
+
+        #[test]
+        fn backend_op_should_match_reference() {
+            use burn_tensor::ops::FloatTensorOps;
+
+            let device = Default::default();
+            let distribution = burn_tensor::Distribution::Uniform(-1.0, 1.0);
+            let x = TestTensor::<3>::random([2, 37, 21], distribution, &device);
+            let y = TestTensor::<3>::random([2, 21, 45], distribution, &device);
+            let c = TestTensor::<3>::random([2, 37, 45], distribution, &device);
+            let z_reference = x.clone().matmul(y.clone()) + c.clone();
+
+            let z = Tensor::<TestBackend, 3>::from_primitive(TensorPrimitive::Float(
+                TestBackend::float_matmul_accumulate(
+                    c.into_primitive().tensor(),
+                    x.into_primitive().tensor(),
+                    y.into_primitive().tensor(),
+                ),
+            ));
+
+            z_reference.into_data().assert_approx_eq(&z.into_data(), 3);
+        }
+
+        #[test]
+        fn shared_output_should_be_copied() {
+            let device = Default::default();
+            let x = TestTensor::<2>::ones([5, 4], &device);
+            let y = TestTensor::<2>::ones([4, 3], &device);
+            let c = TestTensor::<2>::full([5, 3], 2.0, &device);
+
+            let z = Tensor::<TestBackend, 2>::from_primitive(TensorPrimitive::Float(
+                matmul_accumulate(
+                    x.into_primitive().tensor(),
+                    y.into_primitive().tensor(),
+                    c.clone().into_primitive().tensor(),
+                    Tiling2dConfig::default(),
+                ),
+            ));
+
+            z.into_data()
+                .assert_approx_eq(&TestTensor::<2>::full([5, 3], 6.0, &device).into_data(), 3);
+            c.into_data()
+                .assert_approx_eq(&TestTensor::<2>::full([5, 3], 2.0, &device).into_data(), 3);
+        }
+
+        fn same_as_reference_accumulate<const D: usize>(
+            block_size: usize,
+            tile_size: Option<usize>,
+            shape_lhs: [usize; D],
+            shape_rhs: [usize; D],
+        ) {
+            let device = Default::default();
+            let distribution = burn_tensor::Distribution::Uniform(-1.0, 1.0);
+            let mut shape_out = shape_lhs;
+            for (i, dim) in shape_out.iter_mut().enumerate().take(D - 2) {
+                *dim = usize::max(shape_lhs[i], shape_rhs[i]);
+            }
+            shape_out[D - 1] = shape_rhs[D - 1];
+            let x = ReferenceTensor::<D>::random(shape_lhs, distribution, &device);
+            let y = ReferenceTensor::<D>::random(shape_rhs, distribution, &device);
+            let c = ReferenceTensor::<D>::random(shape_out, distribution, &device);
+
+            let z_reference = x.clone().matmul(y.clone()) + c.clone();
+
+            for double_buffering in [false, true] {
+                let config = Tiling2dConfig {
+                    block_size,
+                    tile_size,
+                    double_buffering,
+                    split_k_ratio: None,
+                };
+                let x_jit = TestTensor::<D>::from_data(x.to_data(), &device);
+                let y_jit = TestTensor::<D>::from_data(y.to_data(), &device);
+                let c_jit = TestTensor::<D>::from_data(c.to_data(), &device);
+
+                let z = Tensor::<TestBackend, D>::from_primitive(TensorPrimitive::Float(
+                    matmul_accumulate(
+                        x_jit.into_primitive().tensor(),
+                        y_jit.into_primitive().tensor(),
+                        c_jit.into_primitive().tensor(),
+                        config,
+                    ),
+                ));
+
+                z_reference.to_data().assert_approx_eq(&z.into_data(), 3);
+            }
+        }
+    }
+
+    mod split_k {
+        use super::*;
+        use burn_jit::kernel::matmul::matmul_tiling_2d_split_k;
+
+        #[test]
+        fn inner_dimension_not_divisible_by_the_splits() {
+            // 7 blocks of 16 elements, the last one partial, in slices of 3, 3 and 1 blocks.
+            same_as_single_pass(16, None, 3, [2, 9, 100], [2, 100, 7]);
+        }
+
+        #[test]
+        fn more_splits_than_blocks() {
+            same_as_single_pass(16, None, 8, [1, 5, 40], [1, 40, 3]);
+        }
+
+        #[test]
+        fn vector_output() {
+            same_as_single_pass(16, None, 4, [1, 37, 515], [1, 515, 1]);
+        }
+
+        #[test]
+        fn aligned_path() {
+            same_as_single_pass(16, None, 4, [1, 32, 256], [1, 256, 32]);
+        }
+
+        #[test]
+        fn broadcasted_batches() {
+            same_as_single_pass(8, Some(2), 5, [1, 3, 6, 90], [4, 1, 90, 8]);
+        }
+
+        #[test]
+        fn transposed_inputs() {
+            let device = Default::default();
+            let distribution = burn_tensor::Distribution::Uniform(-1.0, 1.0);
+            let x = ReferenceTensor::<3>::random([2, 70, 6], distribution, &device);
+            let y = ReferenceTensor::<3>::random([2, 5, 70], distribution, &device);
+            let z_reference = x.clone().swap_dims(1, 2).matmul(y.clone().swap_dims(1, 2));
+
+            let x_jit = TestTensor::<3>::from_data(x.into_data(), &device).swap_dims(1, 2);
+            let y_jit = TestTensor::<3>::from_data(y.into_data(), &device).swap_dims(1, 2);
+            let out = burn_jit::kernel::matmul::init_matmul_output(
+                &x_jit.clone().into_primitive().tensor(),
+                &y_jit.clone().into_primitive().tensor(),
             );
 
-            let padded =
-                pad_round(tensor.into_primitive().tensor(), row_divisor, col_divisor).into_tensor();
-            let padded = TestTensor::from_primitive(TensorPrimitive::Float(padded)).to_data();
-            let padded = padded
-                .as_slice::<<TestBackend as Backend>::FloatElem>()
-                .unwrap();
-
-            // check right of matrix
-            for i in 0..row {
-                for j in col..15 {
-                    assert!(padded[i * 15 + j] == 0.0);
-                }
+            let z = Tensor::<TestBackend, 3>::from_primitive(TensorPrimitive::Float(
+                matmul_tiling_2d_split_k(
+                    x_jit.into_primitive().tensor(),
+                    y_jit.into_primitive().tensor(),
+                    out,
+                    single_pass_config(8, None),
+                    3,
+                ),
+            ));
+
+            z_reference.into_data().assert_approx_eq(&z.into_data(), 3);
+        }
+
+        #[test]
+        fn tall_and_skinny_shapes_should_be_split() {
+            let config = Tiling2dConfig::default();
+
+            assert_eq!(config.num_k_splits([4096, 16384, 1]), 4);
+            assert_eq!(config.num_k_splits([1, 4096, 1]), 16);
+            assert_eq!(config.num_k_splits([64, 64, 64]), 1);
+            assert_eq!(config.num_k_splits([64, 255, 64]), 1);
+            assert_eq!(single_pass_config(32, None).num_k_splits([1, 16384, 1]), 1);
+        }
+
+        #[test]
+        fn splits_should_span_whole_blocks() {
+            let config = Tiling2dConfig {
+                block_size: 16,
+                split_k_ratio: Some(1),
+                ..Default::default()
+            };
+
+            // 7 blocks in 4 slices of 2 blocks except the last, instead of 5 slices.
+            assert_eq!(config.num_k_splits([1, 100, 20]), 4);
+            assert_eq!(config.num_k_splits([1, 15, 1]), 1);
+        }
+
+        #[test]
+        fn dispatch_should_split_tall_and_skinny_shapes() {
+            let strategy = MatmulStrategy::Tiling2d(Tiling2dConfig {
+                block_size: 16,
+                ..Default::default()
+            });
+            same_as_reference(strategy, [3, 300], [300, 2]);
+        }
+
+        #[test]
+        fn accumulate_should_add_the_sum_of_the_splits() {
+            let device = Default::default();
+            let distribution = burn_tensor::Distribution::Uniform(-1.0, 1.0);
+            let x = ReferenceTensor::<2>::random([3, 300], distribution, &device);
+            let y = ReferenceTensor::<2>::random([300, 5], distribution, &device);
+            let c = ReferenceTensor::<2>::random([3, 5], distribution, &device);
+            let z_reference = x.clone().matmul(y.clone()) + c.clone();
+
+            let config = Tiling2dConfig {
+                block_size: 16,
+                ..Default::default()
+            };
+            assert!(config.num_k_splits([3, 300, 5]) > 1);
+
+            let z = Tensor::<TestBackend, 2>::from_primitive(TensorPrimitive::Float(
+                matmul_accumulate(
+                    TestTensor::<2>::from_data(x.into_data(), &device)
+                        .into_primitive()
+                        .tensor(),
+                    TestTensor::<2>::from_data(y.into_data(), &device)
+                        .into_primitive()
+                        .tensor(),
+                    TestTensor::<2>::from_data(c.into_data(), &device)
+                        .into_primitive()
+                        .tensor(),
+                    config,
+                ),
+            ));
+
+            z_reference.into_data().assert_approx_eq(&z.into_data(), 3);
+        }
+
+        fn single_pass_config(block_size: usize, tile_size: Option<usize>) -> Tiling2dConfig {
+            Tiling2dConfig {
+                block_size,
+                tile_size,
+                double_buffering: true,
+                split_k_ratio: None,
+            }
+        }
+
+        /// Compare the split matmul with the single pass one, with and without double buffering.
+        fn same_as_single_pass<const D: usize>(
+            block_size: usize,
+            tile_size: Option<usize>,
+            num_splits: usize,
+            shape_lhs: [usize; D],
+            shape_rhs: [usize; D],
+        ) {
+            let device = Default::default();
+            let distribution = burn_tensor::Distribution::Uniform(-1.0, 1.0);
+            let x = TestTensor::<D>::random(shape_lhs, distribution, &device);
+            let y = TestTensor::<D>::random(shape_rhs, distribution, &device);
+
+            for double_buffering in [false, true] {
+                let config = Tiling2dConfig {
+                    double_buffering,
+                    ..single_pass_config(block_size, tile_size)
+                };
+                let lhs = x.clone().into_primitive().tensor();
+                let rhs = y.clone().into_primitive().tensor();
+                let out = burn_jit::kernel::matmul::init_matmul_output(&lhs, &rhs);
+                let z_single_pass = matmul_tiling_2d(lhs.clone(), rhs.clone(), out, config);
+
+                let out = burn_jit::kernel::matmul::init_matmul_output(&lhs, &rhs);
+                let z = matmul_tiling_2d_split_k(lhs, rhs, out, config, num_splits);
+
+                let [z, z_single_pass] = [z, z_single_pass].map(|tensor| {
+                    Tensor::<TestBackend, D>::from_primitive(TensorPrimitive::Float(tensor))
+                });
+                z_single_pass
+                    .into_data()
+                    .assert_approx_eq(&z.into_data(), 4);
+            }
+        }
+    }
+
+    mod transposed_inputs {
+        use super::*;
+
+        #[test]
+        fn row_major_lhs_row_major_rhs() {
+            same_as_reference_transposed_inputs([false, false], [2, 19, 13], [2, 13, 21]);
+        }
+
+        #[test]
+        fn transposed_lhs_row_major_rhs() {
+            same_as_reference_transposed_inputs([true, false], [2, 19, 13], [2, 13, 21]);
+        }
+
+        #[test]
+        fn row_major_lhs_transposed_rhs() {
+            same_as_reference_transposed_inputs([false, true], [2, 19, 13], [2, 13, 21]);
+        }
+
+        #[test]
+        fn transposed_lhs_transposed_rhs() {
+            same_as_reference_transposed_inputs([true, true], [2, 19, 13], [2, 13, 21]);
+        }
+
+        #[test]
+        fn transposed_inputs_on_the_aligned_path() {
+            for transposed in [[true, false], [false, true], [true, true]] {
+                same_as_reference_transposed_inputs(transposed, [1, 32, 64], [1, 64, 32]);
             }
-            // check below matrix, including bottom right
-            for i in row..12 {
-                for j in 0..15 {
-                    assert!(padded[i * 15 + j] == 0.0);
+        }
+
+        #[test]
+        fn transposed_broadcasted_rhs() {
+            same_as_reference_transposed_inputs([true, true], [3, 9, 17], [1, 17, 6]);
+        }
+
+        #[test]
+        fn swapped_batches_should_not_be_transposed() {
+            let tensor = TestTensor::<3>::zeros([2, 4, 8], &Default::default())
+                .swap_dims(0, 1)
+                .into_primitive()
+                .tensor();
+
+            assert!(!is_transposed(&tensor));
+        }
+
+        /// Multiply inputs whose matrices are transposed views of row major tensors, as given by
+        /// `transposed` for the lhs and the rhs, with both buffering modes and tile sizes.
+        fn same_as_reference_transposed_inputs<const D: usize>(
+            transposed: [bool; 2],
+            shape_lhs: [usize; D],
+            shape_rhs: [usize; D],
+        ) {
+            let device = Default::default();
+            let distribution = burn_tensor::Distribution::Uniform(-1.0, 1.0);
+            let x = ReferenceTensor::<D>::random(shape_lhs, distribution, &device);
+            let y = ReferenceTensor::<D>::random(shape_rhs, distribution, &device);
+            let z_reference = x.clone().matmul(y.clone());
+
+            // The transposed views hold the same values as the reference inputs.
+            let layout = |tensor: ReferenceTensor<D>, transposed: bool| match transposed {
+                true => {
+                    TestTensor::<D>::from_data(tensor.swap_dims(D - 2, D - 1).into_data(), &device)
+                        .swap_dims(D - 2, D - 1)
                 }
+                false => TestTensor::<D>::from_data(tensor.into_data(), &device),
+            };
+
+            for (block_size, tile_size, double_buffering) in [
+                (16, None, false),
+                (16, None, true),
+                (16, Some(8), true),
+                (8, Some(1), false),
+            ] {
+                let config = Tiling2dConfig {
+                    block_size,
+                    tile_size,
+                    double_buffering,
+                    split_k_ratio: None,
+                };
+                let x_jit = layout(x.clone(), transposed[0]).into_primitive().tensor();
+                let y_jit = layout(y.clone(), transposed[1]).into_primitive().tensor();
+                assert_eq!([is_transposed(&x_jit), is_transposed(&y_jit)], transposed);
+
+                let out = burn_jit::kernel::matmul::init_matmul_output(&x_jit, &y_jit);
+                let z = Tensor::<TestBackend, D>::from_primitive(TensorPrimitive::Float(
+                    matmul_tiling_2d(x_jit, y_jit, out, config),
+                ));
+
+                z_reference.to_data().assert_approx_eq(&z.into_data(), 3);
             }
         }
+    }
+
+    mod half_precision {
+        use super::*;
+        use burn_jit::is_float_supported;
+        use burn_tensor::f16;
+
+        type HalfBackend = JitBackend<TestRuntime, f16, i32>;
 
         #[test]
-        fn padding_works_with_batch() {
-            let row = 10;
-            let row_divisor = 4;
-            let col = 12;
-            let col_divisor = 5;
-            let tensor = TestTensor::random(
-                [2, 3, row, col],
-                burn_tensor::Distribution::Default,
+        fn large_k_should_accumulate_in_f32() {
+            if !is_float_supported::<TestRuntime, f16>(&Default::default()) {
+                return;
+            }
+
+            let distribution = burn_tensor::Distribution::Uniform(0.0, 1.0);
+            let x = ReferenceTensor::<2>::random([4, 2048], distribution, &Default::default());
+            let y = ReferenceTensor::<2>::random([2048, 4], distribution, &Default::default());
+
+            let x_half = Tensor::<HalfBackend, 2>::from_data(
+                x.into_data().convert::<f16>(),
+                &Default::default(),
+            );
+            let y_half = Tensor::<HalfBackend, 2>::from_data(
+                y.into_data().convert::<f16>(),
+                &Default::default(),
+            );
+
+            // The reference multiplies the rounded inputs, so that only the error of the
+            // accumulation is measured.
+            let x = ReferenceTensor::<2>::from_data(
+                x_half.to_data().convert::<f32>(),
+                &Default::default(),
+            );
+            let y = ReferenceTensor::<2>::from_data(
+                y_half.to_data().convert::<f32>(),
+                &Default::default(),
+            );
+            let z_reference = x.matmul(y);
+            let z = ReferenceTensor::<2>::from_data(
+                x_half.matmul(y_half).into_data().convert::<f32>(),
                 &Default::default(),
             );
-            let expected_shape = [2, 3, 12, 15].into();
 
-            let padded =
-                pad_round(tensor.into_primitive().tensor(), row_divisor, col_divisor).into_tensor();
+            let relative_error = (z - z_reference.clone())
+                .abs()
+                .div(z_reference)
+                .max()
+                .into_scalar();
+            assert!(relative_error < 1e-3, "relative error {relative_error}");
+        }
+    }
 
-            assert!(padded.shape == expected_shape);
+    mod tiling2d_half_precision {
+        use super::*;
+        use burn_jit::{is_float_supported, FloatElement};
+        use burn_tensor::{bf16, f16, Element};
+
+        type HalfTensor<F, const D: usize> = Tensor<JitBackend<TestRuntime, F, i32>, D>;
+
+        #[test]
+        fn f16_vectorized_by_four() {
+            same_as_reference_half::<f16, 3>(2, 16, None, [2, 20, 13], [2, 13, 12]);
         }
 
         #[test]
-        fn padding_with_row_divisor_larger_than_row() {
-            let row = 10;
-            let row_divisor = 32;
-            let col = 4;
-            let col_divisor = 3;
-            let tensor = TestTensor::random(
-                [row, col],
-                burn_tensor::Distribution::Default,
-                &Default::default(),
-            );
-            let expected_shape = [row_divisor, 2 * col_divisor].into();
+        fn f16_vectorized_by_two() {
+            same_as_reference_half::<f16, 3>(2, 16, Some(2), [1, 17, 9], [1, 9, 6]);
+        }
 
-            let padded =
-                pad_round(tensor.into_primitive().tensor(), row_divisor, col_divisor).into_tensor();
+        #[test]
+        fn f16_not_vectorized() {
+            same_as_reference_half::<f16, 3>(2, 8, None, [3, 5, 7], [3, 7, 5]);
+        }
 
-            assert!(padded.shape == expected_shape);
+        #[test]
+        fn f16_aligned_path() {
+            same_as_reference_half::<f16, 3>(2, 16, None, [1, 32, 48], [1, 48, 64]);
         }
 
         #[test]
-        fn padding_with_row_divisor_equal_to_row_but_col_must_be_padded() {
-            let row = 32;
-            let row_divisor = 32;
-            let col = 4;
-            let col_divisor = 64;
-            let tensor = TestTensor::random(
-                [row, col],
-                burn_tensor::Distribution::Default,
-                &Default::default(),
-            );
-            let expected_shape = [32, 64].into();
+        fn bf16_vectorized_by_four() {
+            same_as_reference_half::<bf16, 3>(1, 16, None, [2, 20, 13], [2, 13, 12]);
+        }
 
-            let padded =
-                pad_round(tensor.into_primitive().tensor(), row_divisor, col_divisor).into_tensor();
+        #[test]
+        fn bf16_not_vectorized() {
+            same_as_reference_half::<bf16, 3>(1, 8, None, [3, 5, 7], [3, 7, 5]);
+        }
 
-            assert!(padded.shape == expected_shape);
+        #[test]
+        fn bf16_aligned_path() {
+            same_as_reference_half::<bf16, 3>(1, 16, None, [1, 32, 48], [1, 48, 64]);
         }
 
         #[test]
-        fn crop_same_shape_should_be_unchanged_shape() {
-            let row = 10;
-            let col = 12;
+        fn unsupported_float_should_fail_with_a_capability_error() {
+            for (dtype, supported) in [
+                (
+                    f16::dtype(),
+                    is_float_supported::<TestRuntime, f16>(&Default::default()),
+                ),
+                (
+                    bf16::dtype(),
+                    is_float_supported::<TestRuntime, bf16>(&Default::default()),
+                ),
+            ] {
+                if supported {
+                    continue;
+                }
+
+                let result = std::panic::catch_unwind(|| match dtype {
+                    burn_tensor::DType::F16 => multiply_half::<f16, 2>([4, 4], [4, 4]),
+                    _ => multiply_half::<bf16, 2>([4, 4], [4, 4]),
+                });
+                let message = result.unwrap_err();
+                let message = message
+                    .downcast_ref::<String>()
+                    .expect("A formatted panic message");
+
+                assert!(
+                    message.starts_with(&format!("The float type {dtype:?} isn't supported")),
+                    "{message}"
+                );
+            }
+        }
+
+        fn multiply_half<F: FloatElement, const D: usize>(
+            shape_lhs: [usize; D],
+            shape_rhs: [usize; D],
+        ) {
+            // Created from data, since filling them on the device would launch a kernel of the
+            // unsupported float type.
             let device = Default::default();
-            let tensor =
-                TestTensor::random([row, col], burn_tensor::Distribution::Default, &device);
-            let expected_shape = [row, col].into();
+            let zeros = |shape: [usize; D]| {
+                HalfTensor::<F, D>::from_data(
+                    burn_tensor::TensorData::zeros::<F, _>(shape),
+                    &device,
+                )
+            };
+            let x = zeros(shape_lhs);
+            let y = zeros(shape_rhs);
 
-            let unpadded = crop(
-                tensor.clone().into_primitive().tensor(),
-                TestTensor::empty([row, col], &device)
-                    .into_primitive()
-                    .tensor(),
+            let _ = matmul(
+                x.into_primitive().tensor(),
+                y.into_primitive().tensor(),
+                MatmulStrategy::Tiling2d(Tiling2dConfig::default()),
             );
+        }
 
-            assert!(unpadded.shape == expected_shape);
+        /// Multiply the inputs rounded to the half precision float type with the tiling 2D kernel,
+        /// the reference multiplying the rounded inputs in `f32`. The cases are skipped on the
+        /// devices without the float type.
+        fn same_as_reference_half<F: FloatElement, const D: usize>(
+            precision: usize,
+            block_size: usize,
+            tile_size: Option<usize>,
+            shape_lhs: [usize; D],
+            shape_rhs: [usize; D],
+        ) {
+            let device = Default::default();
+            if !is_float_supported::<TestRuntime, F>(&device) {
+                return;
+            }
+
+            let distribution = burn_tensor::Distribution::Uniform(-1.0, 1.0);
+            let x = ReferenceTensor::<D>::random(shape_lhs, distribution, &Default::default());
+            let y = ReferenceTensor::<D>::random(shape_rhs, distribution, &Default::default());
+            let x_half = HalfTensor::<F, D>::from_data(x.into_data().convert::<F>(), &device);
+            let y_half = HalfTensor::<F, D>::from_data(y.into_data().convert::<F>(), &device);
+
+            let reference = |data: burn_tensor::TensorData| {
+                ReferenceTensor::<D>::from_data(data.convert::<f32>(), &Default::default())
+            };
+            let z_reference = reference(x_half.to_data()).matmul(reference(y_half.to_data()));
+
+            for double_buffering in [false, true] {
+                let config = Tiling2dConfig {
+                    block_size,
+                    tile_size,
+                    double_buffering,
+                    split_k_ratio: None,
+                };
+                let z = HalfTensor::<F, D>::from_primitive(TensorPrimitive::Float(matmul(
+                    x_half.clone().into_primitive().tensor(),
+                    y_half.clone().into_primitive().tensor(),
+                    MatmulStrategy::Tiling2d(config),
+                )));
+                let z = z.into_data();
+
+                assert_eq!(z.dtype, F::dtype());
+                reference(z)
+                    .into_data()
+                    .assert_approx_eq(&z_reference.to_data(), precision);
+            }
         }
+    }
+
+    #[cfg(feature = "perf-test")]
+    mod kernel_cache {
+        use super::*;
+        use burn_jit::{kernel::matmul::init_matmul_output, perf::num_distinct_kernels};
 
         #[test]
-        fn crop_same_shape_should_have_unchanged_values() {
-            let row = 10;
-            let col = 12;
+        fn launches_of_the_same_configs_should_compile_each_kernel_once() {
             let device = Default::default();
-            let tensor =
-                TestTensor::random([row, col], burn_tensor::Distribution::Default, &device);
+            let x = TestTensor::<2>::random([64, 64], burn_tensor::Distribution::Default, &device);
+            let y = TestTensor::<2>::random([64, 64], burn_tensor::Distribution::Default, &device);
+            let configs = [
+                Tiling2dConfig::default(),
+                Tiling2dConfig {
+                    double_buffering: false,
+                    ..Default::default()
+                },
+                Tiling2dConfig {
+                    tile_size: Some(2),
+                    ..Default::default()
+                },
+            ];
 
-            let unpadded = crop(
-                tensor.clone().into_primitive().tensor(),
-                TestTensor::empty([row, col], &device)
-                    .into_primitive()
-                    .tensor(),
-            );
+            // The ids are recorded per thread, each test running on its own.
+            let start = num_distinct_kernels();
+            for i in 0..100 {
+                let lhs = x.clone().into_primitive().tensor();
+                let rhs = y.clone().into_primitive().tensor();
+                let out = init_matmul_output(&lhs, &rhs);
 
-            let unpadded = TestTensor::from_primitive(TensorPrimitive::Float(unpadded)).to_data();
-            let unpadded = unpadded
-                .as_slice::<<TestBackend as Backend>::FloatElem>()
-                .unwrap();
-            let tensor = tensor.into_data();
-            let tensor = tensor
-                .as_slice::<<TestBackend as Backend>::FloatElem>()
-                .unwrap();
-            for i in 0..row {
-                for j in 0..col {
-                    assert!(unpadded[i * col + j] == tensor[i * col + j]);
-                }
+                matmul_tiling_2d(lhs, rhs, out, configs[i % configs.len()]);
             }
+
+            assert_eq!(num_distinct_kernels() - start, configs.len());
         }
+    }
+
+    mod cmma {
+        use super::*;
+        use burn_jit::cubecl::Runtime;
+        use burn_jit::is_float_supported;
+        use burn_jit::kernel::matmul::{is_cmma_available, set_cmma_enabled};
+        use burn_jit::tests::serial_test;
+        use burn_tensor::{f16, Distribution};
+        use serial_test::serial;
+
+        type HalfBackend = JitBackend<TestRuntime, f16, i32>;
 
         #[test]
-        fn crop_should_decrease_shape() {
-            let row = 10;
-            let keep_rows = 8;
-            let col = 12;
-            let keep_cols = 10;
+        #[serial]
+        fn should_not_be_available_when_disabled() {
             let device = Default::default();
-            let tensor =
-                TestTensor::random([row, col], burn_tensor::Distribution::Default, &device);
-            let expected_shape = [keep_rows, keep_cols].into();
+            let client = TestRuntime::client(&device);
 
-            let unpadded = crop(
-                tensor.clone().into_primitive().tensor(),
-                TestTensor::empty([keep_rows, keep_cols], &device)
-                    .into_primitive()
-                    .tensor(),
-            );
+            set_cmma_enabled(false);
+            let available = is_cmma_available::<TestRuntime, f16>(&client, &device);
+            set_cmma_enabled(true);
 
-            assert!(unpadded.shape == expected_shape);
+            assert!(!available);
         }
 
         #[test]
-        fn crop_should_keep_same_values() {
-            let row = 4;
-            let keep_rows = 3;
-            let col = 4;
-            let keep_cols = 3;
+        fn should_not_be_available_for_f32() {
             let device = Default::default();
-            let tensor =
-                TestTensor::random([row, col], burn_tensor::Distribution::Default, &device);
+            let client = TestRuntime::client(&device);
 
-            let unpadded = crop(
-                tensor.clone().into_primitive().tensor(),
-                TestTensor::empty([keep_rows, keep_cols], &device)
-                    .into_primitive()
-                    .tensor(),
+            assert!(!is_cmma_available::<TestRuntime, f32>(&client, &device));
+        }
+
+        #[test]
+        fn should_not_be_available_without_planes_of_32_units() {
+            let device = Default::default();
+            let client = TestRuntime::client(&device);
+            let subgroup_size = burn_jit::device_properties::<TestRuntime>(&device).subgroup_size;
+
+            if subgroup_size != Some(32) {
+                assert!(
+                    !is_cmma_available::<TestRuntime, f16>(&client, &device),
+                    "Available with subgroups of {subgroup_size:?} units"
+                );
+            }
+        }
+
+        #[test]
+        #[serial]
+        fn both_paths_should_match_on_aligned_shapes() {
+            same_with_and_without_cmma([2, 64, 32], [2, 32, 48]);
+        }
+
+        #[test]
+        #[serial]
+        fn both_paths_should_match_on_unaligned_shapes() {
+            same_with_and_without_cmma([3, 17, 29], [3, 29, 33]);
+        }
+
+        #[test]
+        #[serial]
+        fn both_paths_should_match_with_broadcasted_batches() {
+            same_with_and_without_cmma([2, 1, 40, 24], [1, 3, 24, 16]);
+        }
+
+        /// Multiply with the cooperative matrices when they are available, then with the path
+        /// forced off, both results being compared to the reference on the rounded inputs. The
+        /// cases are skipped on the devices without `f16`.
+        fn same_with_and_without_cmma<const D: usize>(
+            shape_lhs: [usize; D],
+            shape_rhs: [usize; D],
+        ) {
+            if !is_float_supported::<TestRuntime, f16>(&Default::default()) {
+                return;
+            }
+
+            let distribution = Distribution::Uniform(-1.0, 1.0);
+            let x = ReferenceTensor::<D>::random(shape_lhs, distribution, &Default::default());
+            let y = ReferenceTensor::<D>::random(shape_rhs, distribution, &Default::default());
+
+            let x_half = Tensor::<HalfBackend, D>::from_data(
+                x.into_data().convert::<f16>(),
+                &Default::default(),
+            );
+            let y_half = Tensor::<HalfBackend, D>::from_data(
+                y.into_data().convert::<f16>(),
+                &Default::default(),
             );
+            let reference = |tensor: &Tensor<HalfBackend, D>| {
+                ReferenceTensor::<D>::from_data(
+                    tensor.to_data().convert::<f32>(),
+                    &Default::default(),
+                )
+            };
+            let z_reference = reference(&x_half).matmul(reference(&y_half));
 
-            let unpadded = TestTensor::from_primitive(TensorPrimitive::Float(unpadded)).to_data();
-            let unpadded = unpadded
-                .as_slice::<<TestBackend as Backend>::FloatElem>()
-                .unwrap();
-            let tensor = tensor.into_data();
-            let tensor = tensor
-                .as_slice::<<TestBackend as Backend>::FloatElem>()
-                .unwrap();
-
-            for i in 0..keep_rows {
-                for j in 0..keep_cols {
-                    assert!(unpadded[i * keep_cols + j] == tensor[i * col + j]);
-                }
+            let multiply = || {
+                Tensor::<HalfBackend, D>::from_primitive(TensorPrimitive::Float(matmul(
+                    x_half.clone().into_primitive().tensor(),
+                    y_half.clone().into_primitive().tensor(),
+                    MatmulStrategy::Cmma,
+                )))
+            };
+
+            let z_cmma = multiply();
+            set_cmma_enabled(false);
+            let z_tiling2d = multiply();
+            set_cmma_enabled(true);
+
+            for z in [z_cmma, z_tiling2d] {
+                reference(&z)
+                    .into_data()
+                    .assert_approx_eq(&z_reference.to_data(), 2);
             }
         }
     }
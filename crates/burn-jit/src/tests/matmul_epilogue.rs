@@ -0,0 +1,233 @@
+#[burn_tensor_testgen::testgen(matmul_epilogue)]
+mod tests {
+    use super::*;
+    use burn_jit::kernel::matmul::{
+        init_matmul_output, matmul_epilogue, matmul_epilogue_naive, matmul_tiling_2d_epilogue,
+        EpilogueActivation, Tiling2dConfig,
+    };
+    use burn_tensor::{activation, Distribution, Tensor, TensorPrimitive};
+
+    #[test]
+    fn matmul_bias_gelu_should_match_reference() {
+        same_as_reference(
+            [2, 3, 7, 5],
+            [2, 3, 5, 9],
+            true,
+            Some(EpilogueActivation::Gelu),
+        );
+    }
+
+    #[test]
+    fn matmul_bias_relu_should_match_reference() {
+        same_as_reference(
+            [4, 17, 12],
+            [1, 12, 33],
+            true,
+            Some(EpilogueActivation::Relu),
+        );
+    }
+
+    #[test]
+    fn matmul_bias_tanh_should_match_reference() {
+        same_as_reference([1, 8, 8], [1, 8, 8], true, Some(EpilogueActivation::Tanh));
+    }
+
+    #[test]
+    fn matmul_gelu_without_bias_should_match_reference() {
+        same_as_reference([2, 7, 5], [2, 5, 9], false, Some(EpilogueActivation::Gelu));
+    }
+
+    #[test]
+    fn matmul_bias_without_activation_should_match_reference() {
+        same_as_reference([3, 19, 6], [3, 6, 21], true, None);
+    }
+
+    #[test]
+    fn matmul_bias_relu_should_match_unfused_kernels() {
+        same_as_unfused([2, 16, 24], [2, 24, 20], EpilogueActivation::Relu);
+    }
+
+    #[test]
+    fn matmul_bias_gelu_should_match_unfused_kernels() {
+        same_as_unfused([1, 3, 9, 31], [2, 1, 31, 13], EpilogueActivation::Gelu);
+    }
+
+    #[test]
+    fn tiling_2d_bias_relu_should_match_unfused_kernels_on_the_aligned_path() {
+        // Every dimension is a multiple of the block size, the rows being written in vectors of 4.
+        tiling_2d_same_as_unfused(
+            [2, 64, 32],
+            [2, 32, 64],
+            true,
+            Some(EpilogueActivation::Relu),
+        );
+    }
+
+    #[test]
+    fn tiling_2d_bias_gelu_should_match_unfused_kernels_with_bound_checks() {
+        // The 36 columns are written in vectors of 4, the last chunks being out of the output.
+        tiling_2d_same_as_unfused(
+            [3, 30, 20],
+            [1, 20, 36],
+            true,
+            Some(EpilogueActivation::Gelu),
+        );
+    }
+
+    #[test]
+    fn tiling_2d_bias_tanh_should_match_unfused_kernels_without_vectorization() {
+        tiling_2d_same_as_unfused([2, 17, 9], [2, 9, 13], true, Some(EpilogueActivation::Tanh));
+    }
+
+    #[test]
+    fn tiling_2d_activation_without_bias_should_match_unfused_kernels() {
+        tiling_2d_same_as_unfused(
+            [1, 40, 24],
+            [1, 24, 22],
+            false,
+            Some(EpilogueActivation::Relu),
+        );
+    }
+
+    #[test]
+    fn tiling_2d_bias_without_activation_should_match_unfused_kernels() {
+        tiling_2d_same_as_unfused([2, 5, 7], [2, 7, 6], true, None);
+    }
+
+    /// Compares the epilogue of the tiling 2D kernel with the same kernel followed by the
+    /// elementwise kernels, with small blocks so that the output spans several cubes.
+    fn tiling_2d_same_as_unfused<const D: usize>(
+        shape_lhs: [usize; D],
+        shape_rhs: [usize; D],
+        with_bias: bool,
+        activation: Option<EpilogueActivation>,
+    ) {
+        let device = Default::default();
+        let lhs = TestTensor::<D>::random(shape_lhs, Distribution::Uniform(-1.0, 1.0), &device);
+        let rhs = TestTensor::<D>::random(shape_rhs, Distribution::Uniform(-1.0, 1.0), &device);
+        let bias = TestTensor::<1>::random(
+            [shape_rhs[D - 1]],
+            Distribution::Uniform(-1.0, 1.0),
+            &device,
+        );
+        let config = Tiling2dConfig {
+            block_size: 16,
+            ..Default::default()
+        };
+
+        let mut expected = Tensor::<TestBackend, D>::from_primitive(TensorPrimitive::Float(
+            burn_jit::kernel::matmul::matmul(
+                lhs.clone().into_primitive().tensor(),
+                rhs.clone().into_primitive().tensor(),
+                burn_jit::kernel::matmul::MatmulStrategy::Tiling2d(config),
+            ),
+        ));
+        if with_bias {
+            expected = expected + bias.clone().unsqueeze();
+        }
+        let expected = match activation {
+            Some(EpilogueActivation::Relu) => activation::relu(expected),
+            Some(EpilogueActivation::Gelu) => activation::gelu(expected),
+            Some(EpilogueActivation::Tanh) => activation::tanh(expected),
+            None => expected,
+        };
+
+        let lhs = lhs.into_primitive().tensor();
+        let rhs = rhs.into_primitive().tensor();
+        let out = init_matmul_output(&lhs, &rhs);
+        let actual = Tensor::<TestBackend, D>::from_primitive(TensorPrimitive::Float(
+            matmul_tiling_2d_epilogue(
+                lhs,
+                rhs,
+                out,
+                with_bias.then(|| bias.into_primitive().tensor()),
+                activation,
+                config,
+            ),
+        ));
+
+        expected
+            .into_data()
+            .assert_approx_eq(&actual.into_data(), 3);
+    }
+
+    /// Compares the epilogue with the matmul kernel followed by the elementwise kernels.
+    fn same_as_unfused<const D: usize>(
+        shape_lhs: [usize; D],
+        shape_rhs: [usize; D],
+        activation: EpilogueActivation,
+    ) {
+        let device = Default::default();
+        let lhs = TestTensor::<D>::random(shape_lhs, Distribution::Uniform(-1.0, 1.0), &device);
+        let rhs = TestTensor::<D>::random(shape_rhs, Distribution::Uniform(-1.0, 1.0), &device);
+        let bias = TestTensor::<1>::random(
+            [shape_rhs[D - 1]],
+            Distribution::Uniform(-1.0, 1.0),
+            &device,
+        );
+
+        let expected = lhs.clone().matmul(rhs.clone()) + bias.clone().unsqueeze();
+        let expected = match activation {
+            EpilogueActivation::Relu => activation::relu(expected),
+            EpilogueActivation::Gelu => activation::gelu(expected),
+            EpilogueActivation::Tanh => activation::tanh(expected),
+        };
+
+        let actual =
+            Tensor::<TestBackend, D>::from_primitive(TensorPrimitive::Float(matmul_epilogue(
+                lhs.into_primitive().tensor(),
+                rhs.into_primitive().tensor(),
+                Some(bias.into_primitive().tensor()),
+                Some(activation),
+            )));
+
+        expected
+            .into_data()
+            .assert_approx_eq(&actual.into_data(), 3);
+    }
+
+    fn same_as_reference<const D: usize>(
+        shape_lhs: [usize; D],
+        shape_rhs: [usize; D],
+        with_bias: bool,
+        activation: Option<EpilogueActivation>,
+    ) {
+        let device = Default::default();
+        let lhs = TestTensor::<D>::random(shape_lhs, Distribution::Uniform(-1.0, 1.0), &device);
+        let rhs = TestTensor::<D>::random(shape_rhs, Distribution::Uniform(-1.0, 1.0), &device);
+        let bias = TestTensor::<1>::random(
+            [shape_rhs[D - 1]],
+            Distribution::Uniform(-1.0, 1.0),
+            &device,
+        );
+        let lhs_ref = ReferenceTensor::<D>::from_data(lhs.to_data(), &device);
+        let rhs_ref = ReferenceTensor::<D>::from_data(rhs.to_data(), &device);
+        let bias_ref = ReferenceTensor::<1>::from_data(bias.to_data(), &device);
+
+        let mut expected = lhs_ref.matmul(rhs_ref);
+        if with_bias {
+            expected = expected + bias_ref.unsqueeze();
+        }
+        let expected = match activation {
+            Some(EpilogueActivation::Relu) => activation::relu(expected),
+            Some(EpilogueActivation::Gelu) => activation::gelu(expected),
+            Some(EpilogueActivation::Tanh) => activation::tanh(expected),
+            None => expected,
+        };
+        let bias = with_bias.then(|| bias.into_primitive().tensor());
+        let lhs = lhs.into_primitive().tensor();
+        let rhs = rhs.into_primitive().tensor();
+
+        // The entry point launches the tiling 2D kernel, the naive kernel being its fallback.
+        let actual = Tensor::<TestBackend, D>::from_primitive(TensorPrimitive::Float(
+            matmul_epilogue(lhs.clone(), rhs.clone(), bias.clone(), activation),
+        ));
+        let actual_naive = Tensor::<TestBackend, D>::from_primitive(TensorPrimitive::Float(
+            matmul_epilogue_naive(lhs, rhs, bias, activation),
+        ));
+
+        let expected = expected.into_data();
+        expected.assert_approx_eq(&actual.into_data(), 3);
+        expected.assert_approx_eq(&actual_naive.into_data(), 3);
+    }
+}
@@ -0,0 +1,159 @@
+#[burn_tensor_testgen::testgen(matmul_fuzz)]
+mod tests {
+    use super::*;
+    use burn_jit::{
+        kernel::matmul::{matmul, MatmulStrategy, Tiling2dConfig},
+        tests::rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng},
+    };
+    use burn_tensor::{backend::Backend, Distribution, Tensor, TensorPrimitive};
+
+    #[test]
+    fn matmul_should_match_the_reference_on_random_shapes() {
+        fuzz(0);
+    }
+
+    /// The number of random cases of a [fuzz test](fuzz).
+    const NUM_CASES: usize = 50;
+
+    /// The kernels multiplying the inputs of a fuzz case.
+    #[derive(Debug, Clone, Copy)]
+    enum FuzzStrategy {
+        /// The default cube matmul.
+        Cube,
+        /// The tiling 2D kernel, whose tile size also bounds the vectorization of its writes.
+        Tiling2d(Tiling2dConfig),
+    }
+
+    /// A random matmul of `[batches..., m, k]` by `[batches..., k, n]`, whose inputs may be
+    /// broadcasted along each batch dimension and transposed views of row major tensors.
+    #[derive(Debug, Clone)]
+    struct FuzzCase {
+        shape_lhs: [usize; 4],
+        shape_rhs: [usize; 4],
+        transposed: [bool; 2],
+        strategy: FuzzStrategy,
+    }
+
+    impl FuzzCase {
+        fn random(rng: &mut StdRng) -> Self {
+            let [m, k, n] = [(); 3].map(|_| random_dim(rng));
+            let batches = [(); 2].map(|_| rng.gen_range(1..=3));
+            let mut shape_lhs = [batches[0], batches[1], m, k];
+            let mut shape_rhs = [batches[0], batches[1], k, n];
+
+            for dim in 0..2 {
+                match rng.gen_range(0..4) {
+                    0 => shape_lhs[dim] = 1,
+                    1 => shape_rhs[dim] = 1,
+                    _ => {}
+                }
+            }
+
+            let strategy = match rng.gen_bool(0.5) {
+                true => FuzzStrategy::Cube,
+                false => {
+                    let block_size = *[8, 16, 32].choose(rng).unwrap();
+                    let tile_size = *[None, Some(1), Some(2), Some(4), Some(8)]
+                        .iter()
+                        .filter(|tile_size| tile_size.unwrap_or(1) <= block_size / 2)
+                        .collect::<Vec<_>>()
+                        .choose(rng)
+                        .unwrap();
+
+                    FuzzStrategy::Tiling2d(Tiling2dConfig {
+                        block_size,
+                        tile_size: *tile_size,
+                        double_buffering: rng.gen_bool(0.5),
+                        split_k_ratio: *[None, Some(1), Some(4)].choose(rng).unwrap(),
+                    })
+                }
+            };
+
+            Self {
+                shape_lhs,
+                shape_rhs,
+                transposed: [rng.gen_bool(0.25), rng.gen_bool(0.25)],
+                strategy,
+            }
+        }
+
+        /// The maximum difference with the reference allowed for the case, growing with the number of
+        /// accumulated products.
+        fn tolerance(&self) -> f64 {
+            1e-5 * self.shape_lhs[3] as f64 + 1e-5
+        }
+    }
+
+    /// A dimension of a fuzz case, either degenerate, around a multiple of the usual block sizes or
+    /// any small size.
+    fn random_dim(rng: &mut StdRng) -> usize {
+        match rng.gen_range(0..4) {
+            0 => 1,
+            1 => *[15, 16, 17, 31, 32, 33, 63, 64, 65].choose(rng).unwrap(),
+            _ => rng.gen_range(2..=70),
+        }
+    }
+
+    /// Multiply the inputs of random shapes, layouts and kernel configs with the matmul of the runtime
+    /// and compare the results with the ones of the ndarray backend.
+    ///
+    /// The cases and the values of the inputs are derived from the seed, the failing case being
+    /// printed with its index so that it can be reproduced.
+    ///
+    /// # Panics
+    ///
+    /// When the result of a case is farther from the reference than its tolerance.
+    fn fuzz(seed: u64) {
+        let mut rng = StdRng::seed_from_u64(seed);
+        ReferenceBackend::seed(seed);
+
+        for index in 0..NUM_CASES {
+            let case = FuzzCase::random(&mut rng);
+            let difference = run_case(&case);
+
+            assert!(
+                difference <= case.tolerance(),
+                "Matmul fuzz case {index} of seed {seed} differs from the reference by {difference}, \
+                 more than the tolerance {}: {case:?}",
+                case.tolerance()
+            );
+        }
+    }
+
+    /// Run the case, returning the largest difference with the reference.
+    fn run_case(case: &FuzzCase) -> f64 {
+        type ReferenceTensor = Tensor<ReferenceBackend, 4>;
+
+        let device = Default::default();
+        let distribution = Distribution::Uniform(-1.0, 1.0);
+        let x = ReferenceTensor::random(case.shape_lhs, distribution, &device);
+        let y = ReferenceTensor::random(case.shape_rhs, distribution, &device);
+        let z_reference = x.clone().matmul(y.clone());
+
+        let layout = |tensor: ReferenceTensor, transposed: bool| {
+            let device = Default::default();
+            match transposed {
+                true => TestTensor::<4>::from_data(tensor.swap_dims(2, 3).into_data(), &device)
+                    .swap_dims(2, 3),
+                false => TestTensor::<4>::from_data(tensor.into_data(), &device),
+            }
+        };
+        let lhs = layout(x, case.transposed[0]).into_primitive().tensor();
+        let rhs = layout(y, case.transposed[1]).into_primitive().tensor();
+
+        let strategy = match case.strategy {
+            FuzzStrategy::Cube => MatmulStrategy::Cube,
+            FuzzStrategy::Tiling2d(config) => MatmulStrategy::Tiling2d(config),
+        };
+        let z = TestTensor::<4>::from_primitive(TensorPrimitive::Float(matmul(lhs, rhs, strategy)));
+        let z = ReferenceTensor::from_data(z.into_data(), &device);
+
+        assert_eq!(
+            z.dims(),
+            z_reference.dims(),
+            "Matmul fuzz case with the wrong output shape: {case:?}"
+        );
+
+        (z - z_reference).abs().max().into_scalar() as f64
+    }
+}
@@ -0,0 +1,58 @@
+#[burn_tensor_testgen::testgen(matmul_int)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Distribution, Int, Tensor};
+
+    #[test]
+    fn int_matmul_vectorized_should_match_reference() {
+        same_as_reference([2, 6, 16], [2, 16, 5]);
+    }
+
+    #[test]
+    fn int_matmul_unvectorized_should_match_reference() {
+        same_as_reference([3, 5, 7], [3, 7, 9]);
+    }
+
+    #[test]
+    fn int_matmul_partial_cubes_should_match_reference() {
+        same_as_reference([1, 17, 12], [1, 12, 33]);
+    }
+
+    #[test]
+    fn int_matmul_broadcast_should_match_reference() {
+        same_as_reference([2, 1, 9, 8], [1, 3, 8, 6]);
+    }
+
+    #[test]
+    fn int_matmul_should_not_round_large_sums() {
+        let device = Default::default();
+        // The sum 16_781_315 is odd and above 2^24, so a float matmul would round it.
+        let lhs = Tensor::<TestBackend, 2, Int>::from_ints([[4_097, 4_097, 1, 0]], &device);
+        let rhs = Tensor::<TestBackend, 2, Int>::from_ints([[4_095], [1], [3], [5]], &device);
+        let lhs_ref =
+            Tensor::<ReferenceBackend, 2, Int>::from_data(lhs.to_data(), &Default::default());
+        let rhs_ref =
+            Tensor::<ReferenceBackend, 2, Int>::from_data(rhs.to_data(), &Default::default());
+
+        let expected = lhs_ref.matmul(rhs_ref);
+        let actual = lhs.matmul(rhs);
+
+        expected.into_data().assert_eq(&actual.into_data(), false);
+    }
+
+    fn same_as_reference<const D: usize>(shape_lhs: [usize; D], shape_rhs: [usize; D]) {
+        let device = Default::default();
+        let distribution = Distribution::Uniform(-128.0, 128.0);
+        let lhs = Tensor::<TestBackend, D, Int>::random(shape_lhs, distribution, &device);
+        let rhs = Tensor::<TestBackend, D, Int>::random(shape_rhs, distribution, &device);
+        let lhs_ref =
+            Tensor::<ReferenceBackend, D, Int>::from_data(lhs.to_data(), &Default::default());
+        let rhs_ref =
+            Tensor::<ReferenceBackend, D, Int>::from_data(rhs.to_data(), &Default::default());
+
+        let expected = lhs_ref.matmul(rhs_ref);
+        let actual = lhs.matmul(rhs);
+
+        expected.into_data().assert_eq(&actual.into_data(), false);
+    }
+}
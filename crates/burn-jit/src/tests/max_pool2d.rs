@@ -16,9 +16,17 @@ mod tests {
         let stride = [2, 2];
         let padding = [1, 1];
         let dilation = [1, 1];
+        let ceil_mode = false;
 
-        let pooled = module::max_pool2d(tensor, kernel_size, stride, padding, dilation);
-        let pooled_ref = module::max_pool2d(tensor_ref, kernel_size, stride, padding, dilation);
+        let pooled = module::max_pool2d(tensor, kernel_size, stride, padding, dilation, ceil_mode);
+        let pooled_ref = module::max_pool2d(
+            tensor_ref,
+            kernel_size,
+            stride,
+            padding,
+            dilation,
+            ceil_mode,
+        );
 
         pooled
             .into_data()
@@ -38,12 +46,66 @@ mod tests {
         let stride = [2, 2];
         let padding = [1, 1];
         let dilation = [1, 1];
+        let ceil_mode = false;
+
+        let (pooled, indices) = module::max_pool2d_with_indices(
+            tensor,
+            kernel_size,
+            stride,
+            padding,
+            dilation,
+            ceil_mode,
+        );
+        let (pooled_ref, indices_ref) = module::max_pool2d_with_indices(
+            tensor_ref,
+            kernel_size,
+            stride,
+            padding,
+            dilation,
+            ceil_mode,
+        );
+
+        pooled
+            .into_data()
+            .assert_approx_eq(&pooled_ref.into_data(), 3);
+        indices
+            .into_data()
+            .assert_eq(&indices_ref.into_data(), false);
+    }
+
+    #[test]
+    pub fn max_pool2d_with_indices_should_match_reference_with_ceil_mode() {
+        let tensor = Tensor::<TestBackend, 4>::random(
+            [2, 3, 32, 31],
+            Distribution::Default,
+            &Default::default(),
+        );
+        let tensor_ref =
+            Tensor::<ReferenceBackend, 4>::from_data(tensor.to_data(), &Default::default());
+        let kernel_size = [3, 2];
+        let stride = [2, 2];
+        let padding = [0, 0];
+        let dilation = [1, 1];
+        let ceil_mode = true;
 
-        let (pooled, indices) =
-            module::max_pool2d_with_indices(tensor, kernel_size, stride, padding, dilation);
-        let (pooled_ref, indices_ref) =
-            module::max_pool2d_with_indices(tensor_ref, kernel_size, stride, padding, dilation);
+        let (pooled, indices) = module::max_pool2d_with_indices(
+            tensor,
+            kernel_size,
+            stride,
+            padding,
+            dilation,
+            ceil_mode,
+        );
+        let (pooled_ref, indices_ref) = module::max_pool2d_with_indices(
+            tensor_ref,
+            kernel_size,
+            stride,
+            padding,
+            dilation,
+            ceil_mode,
+        );
 
+        assert_eq!(pooled.dims(), [2, 3, 16, 16]);
         pooled
             .into_data()
             .assert_approx_eq(&pooled_ref.into_data(), 3);
@@ -18,15 +18,23 @@ mod tests {
         let stride = [2, 2];
         let padding = [1, 1];
         let dilation = [1, 1];
+        let ceil_mode = false;
 
-        let (_, indices) =
-            module::max_pool2d_with_indices(tensor.clone(), kernel_size, stride, padding, dilation);
+        let (_, indices) = module::max_pool2d_with_indices(
+            tensor.clone(),
+            kernel_size,
+            stride,
+            padding,
+            dilation,
+            ceil_mode,
+        );
         let (_, indices_ref) = module::max_pool2d_with_indices(
             tensor_ref.clone(),
             kernel_size,
             stride,
             padding,
             dilation,
+            ceil_mode,
         );
         let grad = TestBackend::max_pool2d_with_indices_backward(
             tensor.into_primitive().tensor(),
@@ -34,6 +42,7 @@ mod tests {
             stride,
             padding,
             dilation,
+            ceil_mode,
             grad_output.into_primitive().tensor(),
             indices.into_primitive(),
         )
@@ -44,6 +53,7 @@ mod tests {
             stride,
             padding,
             dilation,
+            ceil_mode,
             grad_output_ref.into_primitive().tensor(),
             indices_ref.into_primitive(),
         )
@@ -0,0 +1,92 @@
+#[burn_tensor_testgen::testgen(memory_format)]
+mod tests {
+    use super::*;
+    use burn_tensor::{module, ops::ConvOptions, Distribution, MemoryFormat, Tensor};
+
+    fn random(shape: [usize; 4]) -> Tensor<TestBackend, 4> {
+        Tensor::random(shape, Distribution::Default, &Default::default())
+    }
+
+    #[test]
+    fn channels_last_should_keep_the_shape_and_values() {
+        let tensor = random([2, 3, 4, 5]);
+
+        let channels_last = tensor.clone().to_memory_format(MemoryFormat::ChannelsLast);
+        assert_eq!(channels_last.memory_format(), MemoryFormat::ChannelsLast);
+        assert_eq!(channels_last.dims(), [2, 3, 4, 5]);
+        assert_eq!(
+            channels_last.clone().into_primitive().tensor().strides,
+            [60, 1, 15, 3]
+        );
+        channels_last
+            .clone()
+            .into_data()
+            .assert_eq(&tensor.to_data(), true);
+
+        let contiguous = channels_last.to_memory_format(MemoryFormat::Contiguous);
+        assert_eq!(contiguous.memory_format(), MemoryFormat::Contiguous);
+        contiguous.into_data().assert_eq(&tensor.into_data(), true);
+    }
+
+    #[test]
+    fn channels_last_conv2d_should_match_contiguous_conv2d() {
+        let input = random([2, 6, 9, 11]);
+        let weight = random([8, 3, 3, 2]);
+        let bias =
+            Tensor::<TestBackend, 1>::random([8], Distribution::Default, &Default::default());
+        let options = ConvOptions::new([2, 1], [1, 2], [1, 2], 2);
+
+        let expected = module::conv2d(
+            input.clone(),
+            weight.clone(),
+            Some(bias.clone()),
+            options.clone(),
+        );
+        let output = module::conv2d(
+            input.to_memory_format(MemoryFormat::ChannelsLast),
+            weight.to_memory_format(MemoryFormat::ChannelsLast),
+            Some(bias),
+            options,
+        );
+
+        assert_eq!(output.memory_format(), MemoryFormat::ChannelsLast);
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+    }
+
+    #[test]
+    fn channels_last_pooling_should_match_contiguous_pooling() {
+        let input = random([2, 4, 8, 7]);
+        let channels_last = input.clone().to_memory_format(MemoryFormat::ChannelsLast);
+
+        let expected = module::max_pool2d(input.clone(), [3, 2], [2, 1], [1, 0], [1, 1], false);
+        let output =
+            module::max_pool2d(channels_last.clone(), [3, 2], [2, 1], [1, 0], [1, 1], false);
+        assert_eq!(output.memory_format(), MemoryFormat::ChannelsLast);
+        output.into_data().assert_eq(&expected.into_data(), true);
+
+        let expected = module::avg_pool2d(input, [2, 2], [2, 2], [0, 1], true, false);
+        let output = module::avg_pool2d(channels_last, [2, 2], [2, 2], [0, 1], true, false);
+        assert_eq!(output.memory_format(), MemoryFormat::ChannelsLast);
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 5);
+    }
+
+    #[test]
+    fn elementwise_ops_should_keep_the_channels_last_format() {
+        let input = random([2, 4, 3, 5]);
+        let channels_last = input.clone().to_memory_format(MemoryFormat::ChannelsLast);
+        let bias = random([1, 4, 1, 1]);
+
+        // The input is still referenced, so the ops can't be executed in place.
+        let output = (channels_last.clone().exp() + bias.clone()) * channels_last.clone();
+        let expected = (input.clone().exp() + bias) * input;
+
+        assert_eq!(output.memory_format(), MemoryFormat::ChannelsLast);
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 4);
+    }
+}
@@ -1,28 +1,56 @@
 #![allow(missing_docs)]
 
+mod adam_step;
+mod argwhere;
+mod atomic;
 mod avg_pool2d;
 mod bernoulli;
 mod cast;
 mod cat;
 mod clamp;
+mod conditional;
+mod const_table;
 mod conv2d;
 mod conv3d;
 mod conv_transpose2d;
 mod conv_transpose3d;
+mod counter_prng;
+mod cube_struct;
+mod debug_assert;
+mod debug_print;
+mod device;
+mod fusion_elemwise;
+mod fusion_matmul_epilogue;
+mod fusion_score_softmax;
 mod gather;
+mod gemm;
+mod inplace;
+mod large_index;
 mod mask_fill;
 mod mask_where;
 mod matmul;
+mod matmul_epilogue;
+mod matmul_fuzz;
+mod matmul_int;
 mod max_pool2d;
 mod max_pool2d_backward;
+mod memory_format;
 mod normal;
+mod pad;
+mod perf;
+mod pointwise;
 mod reduce;
 mod repeat_dim;
 mod scatter;
+mod score_softmax;
 mod select;
 mod select_assign;
 mod slice;
 mod slice_assign;
+mod spmm;
+mod subgroup;
+mod sync;
+mod tune_cache;
 mod unary;
 mod uniform;
 
@@ -31,6 +59,8 @@ pub use burn_autodiff;
 pub use burn_fusion;
 pub use burn_ndarray;
 pub use burn_tensor;
+pub use half;
+pub use rand;
 pub use serial_test;
 
 #[macro_export]
@@ -48,6 +78,7 @@ macro_rules! testgen_all {
                 burn_jit::testgen_conv_transpose2d!();
                 burn_jit::testgen_conv_transpose3d!();
 
+                burn_jit::testgen_pad!();
                 burn_jit::testgen_repeat_dim!();
                 burn_jit::testgen_gather!();
                 burn_jit::testgen_scatter!();
@@ -72,11 +103,45 @@ macro_rules! testgen_all {
                 burn_jit::testgen_cast!();
                 burn_jit::testgen_cat!();
                 burn_jit::testgen_clamp!();
+                burn_jit::testgen_conditional!();
+                burn_jit::testgen_subgroup!();
+                burn_jit::testgen_pointwise!();
                 burn_jit::testgen_unary!();
+                burn_jit::testgen_inplace!();
+                burn_jit::testgen_memory_format!();
+                burn_jit::testgen_device!();
+                burn_jit::testgen_matmul!();
+                burn_jit::testgen_matmul_epilogue!();
+                burn_jit::testgen_score_softmax!();
+                burn_jit::testgen_gemm!();
+                burn_jit::testgen_matmul_int!();
+                burn_jit::testgen_matmul_fuzz!();
+                burn_jit::testgen_spmm!();
+                burn_jit::testgen_sync!();
+                burn_jit::testgen_adam_step!();
+                burn_jit::testgen_argwhere!();
+                burn_jit::testgen_atomic!();
+                burn_jit::testgen_const_table!();
+                burn_jit::testgen_debug_assert!();
+                burn_jit::testgen_debug_print!();
+                burn_jit::testgen_large_index!();
+                burn_jit::testgen_cube_struct!();
+                burn_jit::testgen_counter_prng!();
+                burn_jit::testgen_tune_cache!();
+                burn_jit::testgen_perf!();
             }
         }
         mod jit_fusion {
             burn_jit::testgen_jit_fusion!();
+
+            mod kernel {
+                use super::*;
+
+                burn_jit::testgen_fusion_elemwise!();
+                burn_jit::testgen_fusion_matmul_epilogue!();
+                burn_jit::testgen_fusion_score_softmax!();
+                burn_jit::testgen_perf!();
+            }
         }
     };
 }
@@ -84,10 +149,19 @@ macro_rules! testgen_all {
 #[macro_export]
 macro_rules! testgen_jit {
     () => {
+        burn_jit::testgen_jit_float!(f32);
+    };
+}
+
+/// Run the exported suites of burn-tensor and burn-autodiff with the float element type, like
+/// `burn_jit::tests::half::f16` on the runtimes supporting it.
+#[macro_export]
+macro_rules! testgen_jit_float {
+    ($float:ty) => {
         use super::*;
         use burn_jit::tests::{burn_autodiff, burn_ndarray, burn_tensor, serial_test};
 
-        pub type TestBackend = JitBackend<TestRuntime, f32, i32>;
+        pub type TestBackend = JitBackend<TestRuntime, $float, i32>;
         pub type ReferenceBackend = burn_ndarray::NdArray<f32>;
 
         pub type TestTensor<const D: usize> = burn_tensor::Tensor<TestBackend, D>;
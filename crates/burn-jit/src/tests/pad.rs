@@ -0,0 +1,45 @@
+#[burn_tensor_testgen::testgen(pad)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Distribution, PadMode, Tensor};
+
+    #[test]
+    fn pad_constant_should_match_reference() {
+        same_as_reference([(1, -1), (-3, 4), (1, 1)], PadMode::Constant(-2.0), false);
+    }
+
+    #[test]
+    fn pad_reflect_should_match_reference() {
+        same_as_reference([(1, -1), (-3, 4), (1, 1)], PadMode::Reflect, false);
+    }
+
+    #[test]
+    fn pad_edge_should_match_reference() {
+        same_as_reference([(1, -1), (-3, 4), (1, 1)], PadMode::Edge, false);
+    }
+
+    #[test]
+    fn pad_should_support_non_contiguous_input() {
+        same_as_reference([(-1, 3), (2, 0), (0, -2)], PadMode::Reflect, true);
+    }
+
+    fn same_as_reference(padding: [(isize, isize); 3], mode: PadMode<f32>, transposed: bool) {
+        let mut tensor = Tensor::<TestBackend, 3>::random(
+            [3, 64, 17],
+            Distribution::Default,
+            &Default::default(),
+        );
+        if transposed {
+            tensor = tensor.swap_dims(0, 2);
+        }
+        let tensor_ref =
+            Tensor::<ReferenceBackend, 3>::from_data(tensor.to_data(), &Default::default());
+
+        let actual = tensor.pad(&padding, mode);
+        let expected = tensor_ref.pad(&padding, mode);
+
+        expected
+            .into_data()
+            .assert_approx_eq(&actual.into_data(), 3);
+    }
+}
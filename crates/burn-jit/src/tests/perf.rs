@@ -0,0 +1,88 @@
+#[burn_tensor_testgen::testgen(perf)]
+mod tests {
+    use super::*;
+
+    // Use the reexported test dependency.
+    #[cfg(feature = "perf-test")]
+    use burn_jit::tests::serial_test;
+    #[cfg(feature = "perf-test")]
+    use serial_test::serial;
+
+    #[cfg(feature = "perf-test")]
+    use burn_jit::perf::{measure, PerfBaseline, PerfMeasure};
+    #[cfg(feature = "perf-test")]
+    use burn_tensor::{module::conv2d, ops::ConvOptions, Distribution};
+
+    #[cfg(feature = "perf-test")]
+    #[test]
+    #[serial]
+    fn matmul_1024_should_launch_a_single_kernel() {
+        let device = Default::default();
+        let lhs = TestTensor::<2>::random([1024, 1024], Distribution::Default, &device);
+        let rhs = TestTensor::<2>::random([1024, 1024], Distribution::Default, &device);
+
+        // The first matmul tunes the kernels, which isn't part of the measure.
+        let _ = lhs.clone().matmul(rhs.clone()).into_data();
+        let (_, perf) = measure::<TestBackend, _>(&device, || lhs.matmul(rhs));
+
+        assert!(perf.counters.num_kernels <= 1, "{perf:?}");
+        assert!(
+            perf.counters.num_bytes_allocated <= 1024 * 1024 * core::mem::size_of::<f32>(),
+            "{perf:?}"
+        );
+        check_baseline("matmul_1024x1024x1024", perf);
+    }
+
+    #[cfg(feature = "perf-test")]
+    #[test]
+    #[serial]
+    fn conv2d_should_match_the_baseline() {
+        let device = Default::default();
+        let x = TestTensor::<4>::random([8, 16, 32, 32], Distribution::Default, &device);
+        let weight = TestTensor::<4>::random([32, 16, 3, 3], Distribution::Default, &device);
+        let bias = TestTensor::<1>::random([32], Distribution::Default, &device);
+        let options = ConvOptions::new([1, 1], [1, 1], [1, 1], 1);
+
+        let _ = conv2d(
+            x.clone(),
+            weight.clone(),
+            Some(bias.clone()),
+            options.clone(),
+        )
+        .into_data();
+        let (_, perf) =
+            measure::<TestBackend, _>(&device, || conv2d(x, weight, Some(bias), options));
+
+        check_baseline("conv2d_8x16x32x32_k3", perf);
+    }
+
+    #[cfg(feature = "perf-test")]
+    #[test]
+    #[serial]
+    fn elementwise_chain_should_match_the_baseline() {
+        let device = Default::default();
+        let x = TestTensor::<2>::random([512, 512], Distribution::Default, &device);
+        let y = TestTensor::<2>::random([512, 512], Distribution::Default, &device);
+
+        let chain = |x: TestTensor<2>, y: TestTensor<2>| x.exp().add_scalar(1.0).mul(y);
+        let _ = chain(x.clone(), y.clone()).into_data();
+        let (_, perf) = measure::<TestBackend, _>(&device, || chain(x, y));
+
+        // The operations write in place when they can, so at most the output is allocated.
+        assert!(
+            perf.counters.num_bytes_allocated <= 512 * 512 * core::mem::size_of::<f32>(),
+            "{perf:?}"
+        );
+        check_baseline("elementwise_exp_add_mul_512x512", perf);
+    }
+
+    #[cfg(feature = "perf-test")]
+    fn check_baseline(name: &str, perf: PerfMeasure) {
+        let device = Default::default();
+        let mut baseline = PerfBaseline::load::<TestBackend>(&device);
+
+        if let Err(regression) = baseline.check(name, perf) {
+            panic!("{regression}");
+        }
+    }
+}
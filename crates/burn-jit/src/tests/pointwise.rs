@@ -0,0 +1,121 @@
+#[burn_tensor_testgen::testgen(pointwise)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Distribution, Tensor};
+
+    // Use the reexported test dependency.
+    #[cfg(feature = "perf-test")]
+    use burn_jit::tests::serial_test;
+    #[cfg(feature = "perf-test")]
+    use serial_test::serial;
+
+    #[cfg(feature = "perf-test")]
+    type Ternary = fn(TestTensor<2>, TestTensor<2>, TestTensor<2>) -> TestTensor<2>;
+
+    #[test]
+    fn lerp_should_match_reference() {
+        let [tensor, end, weight] = inputs([[2, 8, 33], [2, 8, 33], [1, 8, 1]]);
+        let [tensor_ref, end_ref, weight_ref] = [&tensor, &end, &weight].map(reference);
+
+        let output = tensor.lerp(end, weight);
+        let expected = tensor_ref.clone() + weight_ref * (end_ref - tensor_ref);
+
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+    }
+
+    #[test]
+    fn lerp_scalar_should_match_reference() {
+        let [tensor, end] = inputs([[2, 8, 33], [2, 8, 33]]);
+        let [tensor_ref, end_ref] = [&tensor, &end].map(reference);
+
+        let output = tensor.lerp_scalar(end, 0.01);
+        let expected = tensor_ref.clone().lerp_scalar(end_ref, 0.01);
+
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+    }
+
+    #[test]
+    fn addcmul_should_match_reference() {
+        let [tensor, tensor1, tensor2] = inputs([[2, 8, 33], [2, 1, 33], [2, 8, 33]]);
+        let [tensor_ref, tensor1_ref, tensor2_ref] = [&tensor, &tensor1, &tensor2].map(reference);
+
+        let output = tensor.addcmul(tensor1, tensor2, 0.2);
+        let expected = tensor_ref.addcmul(tensor1_ref, tensor2_ref, 0.2);
+
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+    }
+
+    #[test]
+    fn addcdiv_should_match_reference() {
+        let [tensor, tensor1] = inputs([[2, 8, 33], [2, 8, 33]]);
+        let tensor2 = Tensor::<TestBackend, 3>::random(
+            [2, 8, 33],
+            Distribution::Uniform(0.5, 2.0),
+            &Default::default(),
+        );
+        let [tensor_ref, tensor1_ref, tensor2_ref] = [&tensor, &tensor1, &tensor2].map(reference);
+
+        let output = tensor.addcdiv(tensor1, tensor2, -0.5);
+        let expected = tensor_ref.addcdiv(tensor1_ref, tensor2_ref, -0.5);
+
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+    }
+
+    #[test]
+    fn axpby_should_match_reference_with_a_transposed_input() {
+        let [tensor, other] = inputs([[2, 33, 8], [2, 8, 33]]);
+        let tensor = tensor.swap_dims(1, 2);
+        let [tensor_ref, other_ref] = [&tensor, &other].map(reference);
+
+        let output = tensor.axpby(0.99, other, 0.01);
+        let expected = tensor_ref.axpby(0.99, other_ref, 0.01);
+
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+    }
+
+    #[cfg(feature = "perf-test")]
+    #[test]
+    #[serial]
+    fn pointwise_ops_should_launch_a_single_kernel() {
+        use burn_jit::perf::measure;
+
+        let [tensor, tensor1, tensor2] = inputs([[64, 64], [64, 64], [64, 64]]);
+        let ops: [(&str, Ternary); 5] = [
+            ("lerp", |x, y, w| x.lerp(y, w)),
+            ("lerp_scalar", |x, y, _| x.lerp_scalar(y, 0.1)),
+            ("addcmul", |x, y, z| x.addcmul(y, z, 0.1)),
+            ("addcdiv", |x, y, z| x.addcdiv(y, z, 0.1)),
+            ("axpby", |x, y, _| x.axpby(0.9, y, 0.1)),
+        ];
+
+        for (name, op) in ops {
+            let (_, perf) = measure::<TestBackend, _>(&Default::default(), || {
+                op(tensor.clone(), tensor1.clone(), tensor2.clone())
+            });
+
+            assert_eq!(perf.counters.num_kernels, 1, "{name}: {perf:?}");
+        }
+    }
+
+    fn inputs<const D: usize, const N: usize>(
+        shapes: [[usize; D]; N],
+    ) -> [Tensor<TestBackend, D>; N] {
+        shapes.map(|shape| {
+            Tensor::<TestBackend, D>::random(shape, Distribution::Default, &Default::default())
+        })
+    }
+
+    fn reference<const D: usize>(tensor: &Tensor<TestBackend, D>) -> Tensor<ReferenceBackend, D> {
+        Tensor::<ReferenceBackend, D>::from_data(tensor.to_data(), &Default::default())
+    }
+}
@@ -2,7 +2,8 @@
 mod reduction {
     use super::*;
     use burn_jit::kernel::reduce::{
-        argmax, argmin, mean_dim, prod, prod_dim, sum, sum_dim, ReduceStrategy,
+        argmax, argmin, mean_dim, prod, prod_dim, sum, sum_dim, sum_last_dim_dynamic_shared,
+        DynamicSharedMemoryError, ReduceStrategy,
     };
     use burn_tensor::{
         backend::Backend, ops::IntTensorOps, Distribution, Int, Shape, Tensor, TensorData,
@@ -93,6 +94,25 @@ mod reduction {
         val_ref.into_data().assert_eq(&val.into_data(), false);
     }
 
+    #[test]
+    fn reduction_argmax_argmin_naive_should_not_round_int_values() {
+        // 2^24 + 1 isn't representable as f32, comparing as f32 would merge both values.
+        let tensor = TestBackend::int_from_data(
+            TensorData::from([[16_777_216, 16_777_217, 3]]),
+            &Default::default(),
+        );
+
+        let max = Tensor::<TestBackend, 2, Int>::from_primitive(
+            argmax::<TestRuntime, i32, i32, 2>(tensor.clone(), 1, ReduceStrategy::Naive),
+        );
+        let min = Tensor::<TestBackend, 2, Int>::from_primitive(
+            argmin::<TestRuntime, i32, i32, 2>(tensor, 1, ReduceStrategy::Naive),
+        );
+
+        max.into_data().assert_eq(&TensorData::from([[1]]), false);
+        min.into_data().assert_eq(&TensorData::from([[2]]), false);
+    }
+
     #[test]
     fn sum_dim_should_work_with_int() {
         let summed_shape = Shape::new([1]);
@@ -404,4 +424,111 @@ mod reduction {
                 .unwrap()[0]
         );
     }
+
+    #[test]
+    fn reduction_sum_last_dim_dynamic_shared_should_match_reference_for_every_cube_size() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 3>::random([3, 5, 1000], Distribution::Default, &device);
+        let tensor_ref =
+            Tensor::<ReferenceBackend, 3>::from_data(tensor.to_data(), &Default::default());
+        let expected = tensor_ref.sum_dim(2).into_data();
+
+        for cube_dim_x in [1, 32, 64, 256] {
+            let val = sum_last_dim_dynamic_shared::<TestRuntime, 3>(
+                tensor.clone().into_primitive().tensor(),
+                cube_dim_x,
+            )
+            .unwrap();
+            let val = Tensor::<TestBackend, 3>::from_primitive(TensorPrimitive::Float(val));
+
+            expected.assert_approx_eq(&val.into_data(), 2);
+        }
+    }
+
+    #[test]
+    fn reduction_sum_last_dim_dynamic_shared_should_reject_invalid_launches() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 2>::random([2, 8], Distribution::Default, &device);
+
+        assert_eq!(
+            sum_last_dim_dynamic_shared::<TestRuntime, 2>(
+                tensor.clone().into_primitive().tensor(),
+                48,
+            )
+            .err(),
+            Some(DynamicSharedMemoryError::InvalidCubeDim(48)),
+        );
+
+        let Some(max_size) = TestBackend::device_properties(&device).max_shared_memory_size else {
+            return;
+        };
+        let cube_dim_x = (max_size as usize / core::mem::size_of::<f32>() + 1).next_power_of_two();
+        assert_eq!(
+            sum_last_dim_dynamic_shared::<TestRuntime, 2>(
+                tensor.into_primitive().tensor(),
+                cube_dim_x as u32,
+            )
+            .err(),
+            Some(DynamicSharedMemoryError::TooLarge {
+                size: cube_dim_x * core::mem::size_of::<f32>(),
+                max_size: max_size as usize,
+            }),
+        );
+    }
+
+    #[test]
+    fn var_mean_dims_should_match_reference_for_long_and_short_rows() {
+        let device = Default::default();
+
+        // The rows of 3000 values are split among the units of a cube, the rows of 3 values
+        // leave most of its units without any value.
+        for (shape, dims) in [([4, 3000, 2], [1]), ([9, 3, 5], [1])] {
+            let tensor =
+                Tensor::<TestBackend, 3>::random(shape, Distribution::Uniform(-1.0, 3.0), &device);
+            let tensor_ref =
+                Tensor::<ReferenceBackend, 3>::from_data(tensor.to_data(), &Default::default());
+
+            let (var, mean) = tensor.var_mean_dims(&dims, 1);
+            let (var_ref, mean_ref) = tensor_ref.var_mean_dims(&dims, 1);
+
+            var_ref.into_data().assert_approx_eq(&var.into_data(), 3);
+            mean_ref.into_data().assert_approx_eq(&mean.into_data(), 3);
+        }
+    }
+
+    #[test]
+    fn count_nonzero_should_match_reference_for_long_and_short_rows() {
+        let device = Default::default();
+
+        // Rows of 3000 values are split among the units of a cube, rows of 3 values leave most
+        // of its units without any value.
+        for (shape, dim) in [([4, 3000, 2], 1), ([9, 3, 5], 1), ([2, 5, 3000], 2)] {
+            let tensor =
+                Tensor::<TestBackend, 3>::random(shape, Distribution::Uniform(-1.0, 1.0), &device)
+                    .lower_elem(0.0)
+                    .float();
+            let tensor_ref =
+                Tensor::<ReferenceBackend, 3>::from_data(tensor.to_data(), &Default::default());
+
+            let count = tensor.count_nonzero(dim);
+            let count_ref = tensor_ref.count_nonzero(dim);
+
+            count_ref.into_data().assert_eq(&count.into_data(), false);
+        }
+    }
+
+    #[test]
+    fn is_close_should_match_reference_when_broadcasting() {
+        let device = Default::default();
+        let lhs =
+            Tensor::<TestBackend, 2>::random([7, 300], Distribution::Uniform(-1.0, 1.0), &device);
+        let rhs = lhs.clone().slice([0..1, 0..300]) + 1e-3;
+        let lhs_ref = Tensor::<ReferenceBackend, 2>::from_data(lhs.to_data(), &Default::default());
+        let rhs_ref = Tensor::<ReferenceBackend, 2>::from_data(rhs.to_data(), &Default::default());
+
+        let close = lhs.is_close(rhs, Some(0.0), Some(0.01));
+        let close_ref = lhs_ref.is_close(rhs_ref, Some(0.0), Some(0.01));
+
+        close_ref.into_data().assert_eq(&close.into_data(), false);
+    }
 }
@@ -0,0 +1,155 @@
+#[burn_tensor_testgen::testgen(score_softmax)]
+mod tests {
+    use super::*;
+    use burn_jit::kernel::attention::{score_softmax, MAX_SCORE_COLUMNS};
+    use burn_tensor::{activation, Distribution, Tensor, TensorPrimitive};
+
+    #[test]
+    fn score_softmax_should_match_reference_when_the_blocks_divide_the_shapes() {
+        same_as_reference([2, 16, 32], [2, 32, 64], 1.0);
+    }
+
+    #[test]
+    fn score_softmax_should_match_reference_with_columns_out_of_bounds() {
+        same_as_reference([2, 8, 16], [2, 16, 37], 1.0);
+    }
+
+    #[test]
+    fn score_softmax_should_match_reference_with_rows_out_of_bounds() {
+        same_as_reference([3, 13, 16], [3, 16, 32], 1.0);
+    }
+
+    #[test]
+    fn score_softmax_should_match_reference_with_inner_dimension_out_of_bounds() {
+        same_as_reference([1, 8, 21], [1, 21, 16], 1.0);
+    }
+
+    #[test]
+    fn score_softmax_should_match_reference_with_all_bounds_checked() {
+        same_as_reference([2, 3, 7, 9], [2, 3, 9, 45], 0.5);
+    }
+
+    #[test]
+    fn score_softmax_should_match_reference_with_broadcasted_batches() {
+        same_as_reference([2, 1, 5, 8], [1, 4, 8, 19], 1.0 / 8f32.sqrt());
+    }
+
+    #[test]
+    fn score_softmax_should_match_reference_with_the_widest_rows() {
+        same_as_reference([1, 6, 16], [1, 16, MAX_SCORE_COLUMNS], 0.25);
+    }
+
+    #[test]
+    fn score_softmax_should_match_reference_with_rows_too_wide_for_shared_memory() {
+        same_as_reference([2, 5, 12], [2, 12, MAX_SCORE_COLUMNS + 3], 0.25);
+    }
+
+    #[test]
+    fn score_softmax_should_match_reference_with_many_rows_too_wide_for_shared_memory() {
+        same_as_reference([2, 3, 67, 8], [2, 3, 8, 2 * MAX_SCORE_COLUMNS + 5], 0.5);
+    }
+
+    #[test]
+    fn score_softmax_should_match_reference_with_transposed_keys() {
+        let device = Default::default();
+        let distribution = Distribution::Uniform(-1.0, 1.0);
+        let query = TestTensor::<3>::random([2, 11, 8], distribution, &device);
+        let key = TestTensor::<3>::random([2, 23, 8], distribution, &device);
+        let query_ref = ReferenceTensor::<3>::from_data(query.to_data(), &device);
+        let key_ref = ReferenceTensor::<3>::from_data(key.to_data(), &device);
+
+        let expected = activation::softmax(query_ref.matmul(key_ref.swap_dims(1, 2)) * 0.5, 2);
+        let actual =
+            Tensor::<TestBackend, 3>::from_primitive(TensorPrimitive::Float(score_softmax(
+                query.into_primitive().tensor(),
+                key.swap_dims(1, 2).into_primitive().tensor(),
+                0.5,
+            )));
+
+        expected
+            .into_data()
+            .assert_approx_eq_diff(&actual.into_data(), 1e-5);
+    }
+
+    mod subcube {
+        use super::*;
+        use burn_jit::cubecl::Runtime;
+        use burn_jit::kernel::attention::{is_subcube_available, set_subcube_enabled};
+        use burn_jit::tests::serial_test;
+        use serial_test::serial;
+
+        #[test]
+        #[serial]
+        fn subcube_reductions_should_match_shared_memory_reductions() {
+            same_as_shared_memory([2, 16, 32], [2, 32, 64]);
+            same_as_shared_memory([3, 13, 21], [3, 21, 37]);
+            same_as_shared_memory([2, 1, 5, 8], [1, 4, 8, MAX_SCORE_COLUMNS]);
+        }
+
+        #[test]
+        #[serial]
+        fn subcube_reductions_of_rows_too_wide_for_shared_memory_should_match_shared_memory_reductions(
+        ) {
+            same_as_shared_memory([2, 5, 12], [2, 12, MAX_SCORE_COLUMNS + 3]);
+            same_as_shared_memory([2, 3, 7, 9], [2, 3, 9, 4 * MAX_SCORE_COLUMNS + 1]);
+        }
+
+        #[test]
+        #[serial]
+        fn disabled_subcube_reductions_should_not_be_available() {
+            let device = Default::default();
+            set_subcube_enabled(false);
+            let available =
+                is_subcube_available::<TestRuntime>(&TestRuntime::client(&device), &device);
+            set_subcube_enabled(true);
+
+            assert!(!available);
+        }
+
+        fn same_as_shared_memory<const D: usize>(shape_lhs: [usize; D], shape_rhs: [usize; D]) {
+            let device = Default::default();
+            let distribution = Distribution::Uniform(-1.0, 1.0);
+            let lhs = TestTensor::<D>::random(shape_lhs, distribution, &device);
+            let rhs = TestTensor::<D>::random(shape_rhs, distribution, &device);
+
+            let run = |subcube: bool| {
+                set_subcube_enabled(subcube);
+                let probabilities = Tensor::<TestBackend, D>::from_primitive(
+                    TensorPrimitive::Float(score_softmax(
+                        lhs.clone().into_primitive().tensor(),
+                        rhs.clone().into_primitive().tensor(),
+                        0.5,
+                    )),
+                );
+                set_subcube_enabled(true);
+
+                probabilities.into_data()
+            };
+            let shared_memory = run(false);
+            let subcube = run(true);
+
+            shared_memory.assert_approx_eq_diff(&subcube, 1e-6);
+        }
+    }
+
+    fn same_as_reference<const D: usize>(shape_lhs: [usize; D], shape_rhs: [usize; D], scale: f32) {
+        let device = Default::default();
+        let distribution = Distribution::Uniform(-1.0, 1.0);
+        let lhs = TestTensor::<D>::random(shape_lhs, distribution, &device);
+        let rhs = TestTensor::<D>::random(shape_rhs, distribution, &device);
+        let lhs_ref = ReferenceTensor::<D>::from_data(lhs.to_data(), &device);
+        let rhs_ref = ReferenceTensor::<D>::from_data(rhs.to_data(), &device);
+
+        let expected = activation::softmax(lhs_ref.matmul(rhs_ref) * scale, D - 1);
+        let actual =
+            Tensor::<TestBackend, D>::from_primitive(TensorPrimitive::Float(score_softmax(
+                lhs.into_primitive().tensor(),
+                rhs.into_primitive().tensor(),
+                scale,
+            )));
+
+        expected
+            .into_data()
+            .assert_approx_eq_diff(&actual.into_data(), 1e-5);
+    }
+}
@@ -0,0 +1,81 @@
+#[burn_tensor_testgen::testgen(spmm)]
+mod tests {
+    use super::*;
+    use burn_tensor::{sparse::SparseTensor, Bool, Distribution, Int, Tensor, TensorData};
+
+    #[test]
+    fn spmm_should_match_reference() {
+        same_as_reference([33, 17], [17, 9], 0.3);
+    }
+
+    #[test]
+    fn spmm_very_sparse_should_match_reference() {
+        same_as_reference([64, 8], [8, 3], 0.02);
+    }
+
+    #[test]
+    fn spmm_with_a_long_row_should_match_reference() {
+        let device = Default::default();
+        let sparse_cols = 1500;
+        let mut mask = vec![false; 3 * sparse_cols];
+        // The middle row has all its entries, split among the units of each column.
+        mask[sparse_cols..2 * sparse_cols].fill(true);
+        mask[7] = true;
+        let mask = Tensor::<TestBackend, 2, Bool>::from_bool(
+            TensorData::new(mask, [3, sparse_cols]),
+            &device,
+        );
+        let values =
+            TestTensor::<2>::random([3, sparse_cols], Distribution::Uniform(-1.0, 1.0), &device);
+        let dense =
+            TestTensor::<2>::random([sparse_cols, 21], Distribution::Uniform(-1.0, 1.0), &device);
+
+        assert_same_as_reference(SparseTensor::from_mask(values, mask), dense);
+    }
+
+    #[test]
+    fn spmm_without_entries_should_be_zeros() {
+        same_as_reference([5, 4], [4, 6], 0.0);
+    }
+
+    #[test]
+    fn spmm_unsorted_duplicated_entries_should_match_reference() {
+        let device = Default::default();
+        let indices = Tensor::<TestBackend, 2, Int>::from_ints(
+            [[4, 0, 2, 4, 0, 2], [1, 2, 0, 1, 3, 3]],
+            &device,
+        );
+        let values = TestTensor::<1>::from_floats([2.0, -1.0, 4.0, 3.0, 0.5, 1.5], &device);
+        let dense = TestTensor::<2>::random([4, 7], Distribution::Default, &device);
+
+        assert_same_as_reference(SparseTensor::new(indices, values, [6, 4]), dense);
+    }
+
+    fn same_as_reference(shape_sparse: [usize; 2], shape_dense: [usize; 2], density: f64) {
+        let device = Default::default();
+        let values =
+            TestTensor::<2>::random(shape_sparse, Distribution::Uniform(1.0, 2.0), &device);
+        let mask = TestTensor::<2>::random(shape_sparse, Distribution::Uniform(0.0, 1.0), &device)
+            .lower_elem(density);
+        let dense = TestTensor::<2>::random(shape_dense, Distribution::Default, &device);
+
+        assert_same_as_reference(SparseTensor::from_mask(values, mask), dense);
+    }
+
+    fn assert_same_as_reference(sparse: SparseTensor<TestBackend>, dense: TestTensor<2>) {
+        let device = Default::default();
+        let sparse_ref = SparseTensor::<ReferenceBackend>::new(
+            Tensor::from_data(sparse.indices().into_data(), &device),
+            Tensor::from_data(sparse.values().into_data(), &device),
+            sparse.shape(),
+        );
+        let dense_ref = ReferenceTensor::from_data(dense.to_data(), &device);
+
+        let expected = sparse_ref.spmm(dense_ref);
+        let actual = sparse.spmm(dense);
+
+        expected
+            .into_data()
+            .assert_approx_eq(&actual.into_data(), 3);
+    }
+}
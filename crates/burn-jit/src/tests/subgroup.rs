@@ -0,0 +1,93 @@
+#[burn_tensor_testgen::testgen(subgroup)]
+mod tests {
+    use super::*;
+    use burn_jit::kernel::{
+        is_subgroup_available, subgroup_ballot, subgroup_broadcast, subgroup_max, subgroup_sum,
+        BALLOT_UNITS,
+    };
+    use burn_tensor::TensorData;
+    use cubecl::{frontend::F32, prelude::*, Runtime};
+
+    /// The lane whose value is broadcasted to the units of the subgroup.
+    const SOURCE_LANE: u32 = 3;
+
+    /// Write the sum, the max, the value of the source lane and the ballot of the positive values
+    /// of the subgroup to each unit of a single cube holding a subgroup.
+    #[cube(launch)]
+    fn subgroup_kernel<F: Float>(
+        input: &Tensor<F>,
+        sums: &mut Tensor<F>,
+        maxima: &mut Tensor<F>,
+        broadcasts: &mut Tensor<F>,
+        ballots: &mut Tensor<UInt>,
+    ) {
+        let value = input[UNIT_POS];
+
+        sums[UNIT_POS] = subgroup_sum(value);
+        maxima[UNIT_POS] = subgroup_max(value);
+        broadcasts[UNIT_POS] = subgroup_broadcast(value, UInt::new(SOURCE_LANE));
+        ballots[UNIT_POS] = subgroup_ballot(value > F::new(0.));
+    }
+
+    #[test]
+    fn subgroup_operations_should_match_the_cpu() {
+        let device = Default::default();
+        let client = TestRuntime::client(&device);
+        let Some(num_units) = burn_jit::device_properties::<TestRuntime>(&device).subgroup_size
+        else {
+            return;
+        };
+        if !is_subgroup_available::<TestRuntime>(&client, &device, num_units) {
+            return;
+        }
+
+        let values = (0..num_units as usize)
+            .map(|i| ((i * 7) % 11) as f32 - 5.0)
+            .collect::<Vec<_>>();
+        let sum = values.iter().sum::<f32>();
+        let max = values.iter().copied().fold(f32::MIN, f32::max);
+        let ballot = values
+            .iter()
+            .take(BALLOT_UNITS as usize)
+            .enumerate()
+            .filter(|(_, value)| **value > 0.0)
+            .map(|(lane, _)| 1u32 << lane)
+            .sum::<u32>();
+
+        let input = TestTensor::<1>::from_floats(values.as_slice(), &device);
+        let [sums, maxima, broadcasts] =
+            [0; 3].map(|_| TestTensor::<1>::zeros([num_units as usize], &device));
+        let ballots = TestTensorInt::<1>::zeros([num_units as usize], &device);
+
+        let input_primitive = input.into_primitive().tensor();
+        let [sums_primitive, maxima_primitive, broadcasts_primitive] =
+            [&sums, &maxima, &broadcasts].map(|tensor| tensor.clone().into_primitive().tensor());
+        let ballots_primitive = ballots.clone().into_primitive();
+
+        subgroup_kernel::launch::<F32, TestRuntime>(
+            &client,
+            CubeCount::Static(1, 1, 1),
+            CubeDim::new(num_units, 1, 1),
+            input_primitive.as_tensor_arg(1),
+            sums_primitive.as_tensor_arg(1),
+            maxima_primitive.as_tensor_arg(1),
+            broadcasts_primitive.as_tensor_arg(1),
+            ballots_primitive.as_tensor_arg(1),
+        );
+
+        let num_units = num_units as usize;
+        sums.into_data()
+            .assert_approx_eq(&TensorData::new(vec![sum; num_units], [num_units]), 4);
+        maxima
+            .into_data()
+            .assert_eq(&TensorData::new(vec![max; num_units], [num_units]), false);
+        broadcasts.into_data().assert_eq(
+            &TensorData::new(vec![values[SOURCE_LANE as usize]; num_units], [num_units]),
+            false,
+        );
+        ballots.into_data().assert_eq(
+            &TensorData::new(vec![ballot as i32; num_units], [num_units]),
+            false,
+        );
+    }
+}
@@ -0,0 +1,86 @@
+#[burn_tensor_testgen::testgen(sync)]
+mod tests {
+    use super::*;
+    use burn_tensor::{
+        backend::{Backend, SyncType},
+        Distribution, TensorData,
+    };
+
+    // The launches are asynchronous, so reading a tensor has to wait for the ones queued before
+    // it, including the ones of the tensors it depends on, whose handles may be all that keeps
+    // their inputs alive.
+
+    #[test]
+    fn read_should_flush_many_chained_launches() {
+        let device = Default::default();
+        let mut tensor = TestTensor::<1>::zeros([4], &device);
+
+        for _ in 0..1000 {
+            tensor = tensor.add_scalar(1.0);
+        }
+
+        tensor
+            .into_data()
+            .assert_eq(&TensorData::from([1000.0, 1000.0, 1000.0, 1000.0]), false);
+    }
+
+    #[test]
+    fn read_should_see_the_intermediates_of_pending_launches() {
+        let device = Default::default();
+        let mut tensor = TestTensor::<1>::ones([3], &device);
+        let mut intermediates = Vec::new();
+
+        // The intermediates are only kept alive by the handles of the tensors, while the
+        // launches reading them are still pending.
+        for i in 0..100 {
+            tensor = tensor.add_scalar(1.0);
+            if i % 10 == 0 {
+                intermediates.push(tensor.clone());
+            }
+        }
+
+        for (i, intermediate) in intermediates.into_iter().enumerate().rev() {
+            let expected = (10 * i + 2) as f32;
+            intermediate
+                .into_data()
+                .assert_eq(&TensorData::from([expected; 3]), false);
+        }
+        tensor
+            .into_data()
+            .assert_eq(&TensorData::from([101.0; 3]), false);
+    }
+
+    #[test]
+    fn read_should_match_the_reference_after_many_launches() {
+        let device = Default::default();
+        let reference_device = Default::default();
+        let data = TestTensor::<2>::random([16, 8], Distribution::Default, &device).into_data();
+        let mut tensor = TestTensor::<2>::from_data(data.clone(), &device);
+        let mut reference = ReferenceTensor::<2>::from_data(data, &reference_device);
+
+        for i in 0..500 {
+            let scalar = (i % 7) as f32 * 0.01;
+            tensor = tensor.add_scalar(scalar).tanh();
+            reference = reference.add_scalar(scalar).tanh();
+        }
+
+        reference
+            .into_data()
+            .assert_approx_eq(&tensor.into_data(), 3);
+    }
+
+    #[test]
+    fn sync_should_flush_the_pending_launches() {
+        let device = Default::default();
+        let mut tensor = TestTensor::<1>::zeros([4], &device);
+
+        for _ in 0..256 {
+            tensor = tensor.add_scalar(0.5);
+        }
+        TestBackend::sync(&device, SyncType::Wait);
+
+        tensor
+            .into_data()
+            .assert_eq(&TensorData::from([128.0, 128.0, 128.0, 128.0]), false);
+    }
+}
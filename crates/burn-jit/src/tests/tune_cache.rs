@@ -0,0 +1,224 @@
+#[burn_tensor_testgen::testgen(tune_cache)]
+mod tests {
+    use super::*;
+
+    // Use the reexported test dependency.
+    use burn_jit::tests::serial_test;
+    use serial_test::serial;
+
+    use burn_jit::tune_cache::{
+        num_tunings, set_tune_cache_dir, set_tune_cache_enabled, TUNE_CACHE_DIR_ENV, TUNE_CACHE_ENV,
+    };
+    use burn_tensor::{Distribution, Tensor, TensorPrimitive};
+
+    /// Set for the child process, which checks that the results saved by its parent are used.
+    const CHILD_ENV: &str = "BURN_TUNE_CACHE_TEST_CHILD";
+    // Shape classes no other test tunes, so the parent process always tunes them.
+    const SHAPE_LHS: [usize; 3] = [2, 300, 200];
+    const SHAPE_RHS: [usize; 3] = [2, 200, 3];
+    const INVALID_SHAPE_LHS: [usize; 3] = [2, 100, 700];
+    const INVALID_SHAPE_RHS: [usize; 3] = [2, 700, 3];
+
+    #[cfg(feature = "autotune")]
+    #[test]
+    #[serial]
+    fn persisted_results_should_skip_the_tuning_of_a_new_process() {
+        let Some(dir) = cache_dir("skip") else {
+            return;
+        };
+        set_tune_cache_dir::<TestRuntime, _>(&dir);
+
+        let tunings = num_tunings();
+        autotuned_matmul(SHAPE_LHS, SHAPE_RHS);
+        assert_eq!(num_tunings() - tunings, 1);
+
+        run_child(
+            "persisted_results_should_be_used_by_the_child_process",
+            &dir,
+        );
+    }
+
+    #[cfg(feature = "autotune")]
+    #[test]
+    #[ignore = "run by the parent process of the tune cache test"]
+    fn persisted_results_should_be_used_by_the_child_process() {
+        if std::env::var_os(CHILD_ENV).is_none() {
+            return;
+        }
+
+        autotuned_matmul(SHAPE_LHS, SHAPE_RHS);
+        // The result used by the first matmul is found again in memory.
+        autotuned_matmul(SHAPE_LHS, SHAPE_RHS);
+
+        assert_eq!(num_tunings(), 0);
+    }
+
+    #[cfg(feature = "autotune")]
+    #[test]
+    #[serial]
+    fn invalid_persisted_results_should_be_tuned_again() {
+        let Some(dir) = cache_dir("invalid") else {
+            return;
+        };
+        set_tune_cache_dir::<TestRuntime, _>(&dir);
+        autotuned_matmul(INVALID_SHAPE_LHS, INVALID_SHAPE_RHS);
+
+        // Point every result to an operation the sets don't have.
+        let path = dir.join("autotune.json");
+        let content: String = std::fs::read_to_string(&path)
+            .unwrap()
+            .lines()
+            .map(|line| match line.split_once("\"fastest_index\": ") {
+                Some((start, _)) => format!("{start}\"fastest_index\": 999,\n"),
+                None => format!("{line}\n"),
+            })
+            .collect();
+        std::fs::write(&path, content).unwrap();
+
+        run_child(
+            "invalid_persisted_results_should_be_tuned_again_by_the_child_process",
+            &dir,
+        );
+    }
+
+    #[cfg(feature = "autotune")]
+    #[test]
+    #[ignore = "run by the parent process of the tune cache test"]
+    fn invalid_persisted_results_should_be_tuned_again_by_the_child_process() {
+        if std::env::var_os(CHILD_ENV).is_none() {
+            return;
+        }
+
+        // The invalid result is ignored instead of running.
+        autotuned_matmul(INVALID_SHAPE_LHS, INVALID_SHAPE_RHS);
+
+        assert_eq!(num_tunings(), 1);
+    }
+
+    /// A new cache directory, unless the environment variables would apply to the parent process
+    /// too.
+    fn cache_dir(name: &str) -> Option<std::path::PathBuf> {
+        if std::env::var_os(TUNE_CACHE_DIR_ENV).is_some()
+            || std::env::var_os(TUNE_CACHE_ENV).is_some()
+        {
+            return None;
+        }
+
+        Some(std::env::temp_dir().join(format!("burn-tune-cache-{name}-{}", std::process::id())))
+    }
+
+    /// Run the test in a new process, which creates its own client and loads the cache from the
+    /// directory.
+    fn run_child(test: &str, dir: &std::path::Path) {
+        let output = std::process::Command::new(std::env::current_exe().unwrap())
+            .args([test, "--ignored", "--test-threads=1"])
+            .env(TUNE_CACHE_DIR_ENV, dir)
+            .env(CHILD_ENV, "1")
+            .output()
+            .unwrap();
+        std::fs::remove_dir_all(dir).unwrap();
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert!(
+            output.status.success(),
+            "The child process failed:\n{stdout}"
+        );
+        assert!(
+            stdout.contains("1 passed"),
+            "The child test didn't run:\n{stdout}"
+        );
+    }
+
+    #[cfg(feature = "autotune")]
+    #[test]
+    #[serial]
+    fn matmul_should_tune_once_per_shape_class() {
+        // Only keep the results in memory, so that the ones saved by previous runs aren't used.
+        set_tune_cache_enabled(false);
+
+        let tunings = num_tunings();
+        autotuned_matmul([5, 37, 70], [5, 70, 19]);
+        assert_eq!(num_tunings() - tunings, 1);
+
+        // The batch, m, k and n are rounded up to the same powers of two.
+        let tunings = num_tunings();
+        autotuned_matmul([6, 40, 100], [6, 100, 20]);
+        assert_eq!(num_tunings(), tunings);
+
+        set_tune_cache_enabled(true);
+    }
+
+    #[cfg(feature = "autotune")]
+    #[test]
+    #[serial]
+    fn tiling_2d_configs_should_be_tuned_once_per_shape_class() {
+        set_tune_cache_enabled(false);
+
+        let tunings = num_tunings();
+        autotuned_tiling_2d_matmul([3, 45, 90], [3, 90, 33]);
+        assert_eq!(num_tunings() - tunings, 1);
+
+        // The cached config of the shape class is used, without tuning the candidates again.
+        let tunings = num_tunings();
+        autotuned_tiling_2d_matmul([4, 50, 120], [4, 120, 40]);
+        assert_eq!(num_tunings(), tunings);
+
+        set_tune_cache_enabled(true);
+    }
+
+    #[cfg(feature = "autotune")]
+    #[test]
+    fn tiling_2d_candidates_should_fit_in_a_cube() {
+        let device = Default::default();
+        let candidates =
+            burn_jit::kernel::matmul::tiling_2d_candidates::<TestRuntime, f32>(&device);
+
+        // The smallest blocks fit in the shared memory of every device.
+        assert!(candidates
+            .iter()
+            .any(|config| config.block_size == 32 && config.tile_size == Some(1)));
+
+        for config in candidates {
+            let units = config.block_size / config.tile_size.unwrap();
+            assert!(
+                units * units <= 1024,
+                "{config:?} has {units}x{units} units"
+            );
+        }
+    }
+
+    fn autotuned_tiling_2d_matmul(shape_lhs: [usize; 3], shape_rhs: [usize; 3]) {
+        let device = Default::default();
+        let x = ReferenceTensor::<3>::random(shape_lhs, Distribution::Default, &device);
+        let y = ReferenceTensor::<3>::random(shape_rhs, Distribution::Default, &device);
+        let x_jit = TestTensor::<3>::from_data(x.to_data(), &device);
+        let y_jit = TestTensor::<3>::from_data(y.to_data(), &device);
+
+        let z = Tensor::<TestBackend, 3>::from_primitive(TensorPrimitive::Float(
+            burn_jit::kernel::matmul::matmul(
+                x_jit.into_primitive().tensor(),
+                y_jit.into_primitive().tensor(),
+                burn_jit::kernel::matmul::MatmulStrategy::Tiling2dAutotune,
+            ),
+        ));
+
+        x.matmul(y).into_data().assert_approx_eq(&z.into_data(), 3);
+    }
+
+    fn autotuned_matmul(shape_lhs: [usize; 3], shape_rhs: [usize; 3]) {
+        let device = Default::default();
+        let x = ReferenceTensor::<3>::random(shape_lhs, Distribution::Default, &device);
+        let y = ReferenceTensor::<3>::random(shape_rhs, Distribution::Default, &device);
+        let x_jit = TestTensor::<3>::from_data(x.to_data(), &device);
+        let y_jit = TestTensor::<3>::from_data(y.to_data(), &device);
+
+        let z = Tensor::<TestBackend, 3>::from_primitive(TensorPrimitive::Float(
+            burn_jit::kernel::matmul::matmul_autotune(
+                x_jit.into_primitive().tensor(),
+                y_jit.into_primitive().tensor(),
+            ),
+        ));
+
+        x.matmul(y).into_data().assert_approx_eq(&z.into_data(), 3);
+    }
+}
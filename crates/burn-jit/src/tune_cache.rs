@@ -0,0 +1,556 @@
+//! Persistent cache of the autotune results.
+//!
+//! The fastest operation found for each [autotune key](JitAutotuneKey) is saved to a file in the
+//! cache directory, so that the next processes running on the same device skip the benchmarks.
+//! The results are keyed by a fingerprint of the device made of its runtime, its adapter name and
+//! its driver version, so updating the driver invalidates them.
+//!
+//! The cache is opt-in: the results of a runtime are only persisted once its directory is set with
+//! [set_tune_cache_dir], e.g. to the [default directory](default_tune_cache_dir), or for all the
+//! runtimes with the `BURN_TUNE_CACHE_DIR` environment variable which takes precedence. The cache
+//! can be disabled with [set_tune_cache_enabled], or by setting the `BURN_TUNE_CACHE` environment
+//! variable to `0`, `false` or `off`.
+//!
+//! The file is written to a temporary file then renamed, after merging the results saved by
+//! other processes in the meantime, so concurrent processes never leave a partial file behind. The
+//! read, merge and write of a process are serialized with the other processes by a lock file.
+//!
+//! A persisted result is only used when its operation set had the same number of candidates and
+//! its index is one of them, so changing the candidates of a set retunes it instead of running
+//! another operation. Reordering the candidates must bump [TUNE_CACHE_VERSION].
+use crate::{JitAutotuneKey, JitRuntime, JitTuneId};
+use cubecl::{
+    client::ComputeClient,
+    tune::{AutotuneOperationSet, LocalTuner},
+};
+use hashbrown::HashMap;
+use serde::{Deserialize, Serialize};
+use std::{
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    time::Duration,
+};
+
+/// The environment variable overriding the cache directory.
+pub const TUNE_CACHE_DIR_ENV: &str = "BURN_TUNE_CACHE_DIR";
+/// The environment variable disabling the cache when set to `0`, `false` or `off`.
+pub const TUNE_CACHE_ENV: &str = "BURN_TUNE_CACHE";
+
+/// The version of the candidates of the operation sets, part of the fingerprint of the results.
+pub const TUNE_CACHE_VERSION: u32 = 1;
+
+const TUNE_CACHE_FILE: &str = "autotune.json";
+const TUNE_CACHE_LOCK_FILE: &str = "autotune.json.lock";
+/// A lock file older than this was left behind by a process that stopped while holding it.
+const STALE_LOCK: Duration = Duration::from_secs(10);
+
+static STATE: Mutex<Option<TuneCacheState>> = Mutex::new(None);
+static NUM_TUNINGS: AtomicUsize = AtomicUsize::new(0);
+
+/// Set the directory of the cache of the runtime, which enables the cache for it. The results are
+/// loaded again from the new directory, before the first operation of the runtime is tuned.
+pub fn set_tune_cache_dir<R: JitRuntime, P: Into<PathBuf>>(dir: P) {
+    with_state(|state| {
+        state.dirs.insert(R::name(), dir.into());
+        state.results.remove(R::name());
+        state.hits.clear();
+        state.results(R::name());
+    })
+}
+
+/// The `burn/autotune` directory of the user cache, when the platform has one.
+pub fn default_tune_cache_dir() -> Option<PathBuf> {
+    dirs::cache_dir().map(|dir| dir.join("burn").join("autotune"))
+}
+
+/// Enable or disable the cache, the autotune results are only kept in memory when disabled.
+pub fn set_tune_cache_enabled(enabled: bool) {
+    with_state(|state| {
+        state.enabled = enabled;
+        state.results.clear();
+        state.hits.clear();
+    })
+}
+
+/// The number of tunings since the start of the program, each running the benchmarks of an
+/// operation set.
+///
+/// This is useful to check that the persisted results are used instead of tuning again.
+pub fn num_tunings() -> usize {
+    NUM_TUNINGS.load(Ordering::Relaxed)
+}
+
+/// Execute the fastest operation of the set on the device, using the persisted result when there
+/// is one, or tuning the operations with the tuner and persisting the fastest otherwise.
+///
+/// The set has `num_candidates` [autotunables](AutotuneOperationSet::autotunables), counted
+/// without creating them, which validates the persisted results.
+pub fn execute_tuned<R: JitRuntime>(
+    tuner: &LocalTuner<JitAutotuneKey, JitTuneId>,
+    device: &R::Device,
+    client: &ComputeClient<R::Server, R::Channel>,
+    operation_set: Box<dyn AutotuneOperationSet<JitAutotuneKey>>,
+    num_candidates: usize,
+) {
+    let id = JitTuneId::new::<R>(device);
+    let key = operation_set.key();
+
+    // The tuner already knows the results found by this process.
+    if tuner.autotune_result(&id, &key).is_some() {
+        tuner.execute(&id, client, operation_set);
+        return;
+    }
+
+    if let Some(index) = persisted_result::<R>(device, &key, num_candidates) {
+        operation_set.fastest(index).execute();
+        return;
+    }
+
+    record_tuning();
+    tuner.execute(&id, client, operation_set);
+
+    if let Some(index) = tuner.autotune_result(&id, &key) {
+        persist_result::<R>(device, &key, index, num_candidates);
+    }
+}
+
+/// The persisted index of the fastest operation for the key on the device, when it was found
+/// among the same number of candidates.
+pub(crate) fn persisted_result<R: JitRuntime>(
+    device: &R::Device,
+    key: &JitAutotuneKey,
+    num_candidates: usize,
+) -> Option<usize> {
+    let id = JitTuneId::new::<R>(device);
+
+    // The results already used by the process are found without serializing the key again.
+    let hit = with_state(|state| state.hits.get(&id).and_then(|hits| hits.get(key)).copied());
+    if hit.is_some() {
+        return hit;
+    }
+
+    let entry_key = entry_key(key)?;
+    let fingerprint = fingerprint::<R>(device);
+
+    let result = with_state(|state| {
+        state
+            .results(R::name())
+            .get(&(fingerprint, entry_key))
+            .copied()
+    })?;
+    let index = result.index_of(num_candidates)?;
+
+    with_state(|state| state.hits.entry(id).or_default().insert(key.clone(), index));
+
+    Some(index)
+}
+
+/// Persist the index of the fastest operation for the key on the device, found among
+/// `num_candidates` operations.
+pub(crate) fn persist_result<R: JitRuntime>(
+    device: &R::Device,
+    key: &JitAutotuneKey,
+    index: usize,
+    num_candidates: usize,
+) {
+    let Some(entry_key) = entry_key(key) else {
+        return;
+    };
+    let entry = TuneCacheEntry {
+        device: JitTuneId::new::<R>(device).to_string(),
+        fingerprint: fingerprint::<R>(device),
+        key: entry_key,
+        fastest_index: index,
+        num_candidates,
+    };
+
+    with_state(|state| {
+        state.results(R::name()).insert(
+            (entry.fingerprint.clone(), entry.key.clone()),
+            entry.result(),
+        );
+
+        if let Some(path) = state.path(R::name()) {
+            let saved = with_file_lock(&path, || {
+                let entries = merge_entries(read_entries(&path), entry);
+                write_entries(&path, &entries)
+            });
+
+            if let Err(err) = saved {
+                log::warn!("Unable to save the autotune cache to {path:?}: {err}");
+            }
+        }
+    })
+}
+
+/// Count a tuning for [num_tunings].
+pub(crate) fn record_tuning() {
+    NUM_TUNINGS.fetch_add(1, Ordering::Relaxed);
+}
+
+#[derive(Default)]
+struct TuneCacheState {
+    /// The cache directory of each runtime, by name.
+    dirs: HashMap<&'static str, PathBuf>,
+    enabled: bool,
+    /// The persisted results of each runtime by fingerprint and key, loaded on first use.
+    results: HashMap<&'static str, HashMap<(String, String), PersistedResult>>,
+    /// The fingerprint of each device, since querying the device properties is slow.
+    fingerprints: HashMap<String, String>,
+    /// The persisted results already used on each device, by key.
+    hits: HashMap<JitTuneId, HashMap<JitAutotuneKey, usize>>,
+}
+
+impl TuneCacheState {
+    fn new() -> Self {
+        Self {
+            enabled: true,
+            ..Default::default()
+        }
+    }
+
+    /// The path of the cache file of the runtime, or `None` when the cache is disabled or has no
+    /// directory for it.
+    fn path(&self, runtime: &str) -> Option<PathBuf> {
+        let disabled = std::env::var(TUNE_CACHE_ENV)
+            .map(|value| matches!(value.to_lowercase().as_str(), "0" | "false" | "off"))
+            .unwrap_or(false);
+
+        if disabled || !self.enabled {
+            return None;
+        }
+
+        let dir = std::env::var_os(TUNE_CACHE_DIR_ENV)
+            .map(PathBuf::from)
+            .or_else(|| self.dirs.get(runtime).cloned())?;
+
+        Some(dir.join(TUNE_CACHE_FILE))
+    }
+
+    fn results(
+        &mut self,
+        runtime: &'static str,
+    ) -> &mut HashMap<(String, String), PersistedResult> {
+        if !self.results.contains_key(runtime) {
+            let entries = self
+                .path(runtime)
+                .map(|path| read_entries(&path))
+                .unwrap_or_default();
+            let results = entries
+                .into_iter()
+                .map(|entry| {
+                    let result = entry.result();
+                    ((entry.fingerprint, entry.key), result)
+                })
+                .collect();
+
+            self.results.insert(runtime, results);
+        }
+
+        self.results.get_mut(runtime).unwrap()
+    }
+}
+
+fn with_state<T>(func: impl FnOnce(&mut TuneCacheState) -> T) -> T {
+    let mut state = STATE.lock().unwrap();
+
+    func(state.get_or_insert_with(TuneCacheState::new))
+}
+
+/// The fingerprint of the device, which changes when the adapter, its driver or the candidate
+/// operations of the tuners, tied to the version of burn, change.
+fn fingerprint<R: JitRuntime>(device: &R::Device) -> String {
+    let id = JitTuneId::new::<R>(device).to_string();
+
+    if let Some(fingerprint) = with_state(|state| state.fingerprints.get(&id).cloned()) {
+        return fingerprint;
+    }
+
+    let properties = crate::device_properties::<R>(device);
+    let fingerprint = format!(
+        "{} {} {} {} burn-{} v{TUNE_CACHE_VERSION}",
+        R::name(),
+        properties.backend_kind,
+        properties.name,
+        properties.driver_version,
+        env!("CARGO_PKG_VERSION"),
+    );
+
+    with_state(|state| state.fingerprints.insert(id, fingerprint.clone()));
+
+    fingerprint
+}
+
+fn entry_key(key: &JitAutotuneKey) -> Option<String> {
+    serde_json::to_string(key).ok()
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+struct TuneCacheEntry {
+    /// The device the result was found on.
+    device: String,
+    fingerprint: String,
+    key: String,
+    fastest_index: usize,
+    /// The number of candidates of the operation set, unknown for the results saved before it
+    /// was added to the file.
+    #[serde(default)]
+    num_candidates: usize,
+}
+
+impl TuneCacheEntry {
+    fn result(&self) -> PersistedResult {
+        PersistedResult {
+            fastest_index: self.fastest_index,
+            num_candidates: self.num_candidates,
+        }
+    }
+}
+
+/// The index of the fastest operation of a set, with the number of candidates it was found among.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct PersistedResult {
+    fastest_index: usize,
+    num_candidates: usize,
+}
+
+impl PersistedResult {
+    /// The index of the fastest operation among the current candidates, unless the candidates
+    /// changed since it was found or the index is invalid.
+    fn index_of(self, num_candidates: usize) -> Option<usize> {
+        (self.num_candidates == num_candidates && self.fastest_index < num_candidates)
+            .then_some(self.fastest_index)
+    }
+}
+
+#[derive(Serialize, Deserialize, Default)]
+struct TuneCacheFile {
+    entries: Vec<TuneCacheEntry>,
+}
+
+/// Read the entries of the cache file, a missing or invalid file having no entry.
+fn read_entries(path: &Path) -> Vec<TuneCacheEntry> {
+    let Ok(content) = std::fs::read_to_string(path) else {
+        return Vec::new();
+    };
+
+    match serde_json::from_str::<TuneCacheFile>(&content) {
+        Ok(file) => file.entries,
+        Err(err) => {
+            log::warn!("Ignoring the invalid autotune cache {path:?}: {err}");
+            Vec::new()
+        }
+    }
+}
+
+/// Write the entries to a temporary file renamed to the cache file, which replaces it atomically.
+fn write_entries(path: &Path, entries: &[TuneCacheEntry]) -> std::io::Result<()> {
+    let file = TuneCacheFile {
+        entries: entries.to_vec(),
+    };
+    let content = serde_json::to_string_pretty(&file)?;
+
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|duration| duration.subsec_nanos())
+        .unwrap_or(0);
+    let temp = path.with_extension(format!("{}-{nanos}.tmp", std::process::id()));
+
+    std::fs::write(&temp, content)?;
+    std::fs::rename(&temp, path).map_err(|err| {
+        let _ = std::fs::remove_file(&temp);
+        err
+    })
+}
+
+/// Run the function while holding the lock file next to the cache file, so the read, merge and
+/// write of the processes sharing the cache don't interleave.
+fn with_file_lock<T>(path: &Path, func: impl FnOnce() -> std::io::Result<T>) -> std::io::Result<T> {
+    let lock = path.with_file_name(TUNE_CACHE_LOCK_FILE);
+    if let Some(dir) = lock.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+
+    let mut attempts = 0;
+    loop {
+        match std::fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&lock)
+        {
+            Ok(_) => break,
+            Err(err) if err.kind() == std::io::ErrorKind::AlreadyExists => {
+                let stale = std::fs::metadata(&lock)
+                    .and_then(|metadata| metadata.modified())
+                    .ok()
+                    .and_then(|modified| modified.elapsed().ok())
+                    .is_some_and(|elapsed| elapsed > STALE_LOCK);
+
+                if stale {
+                    let _ = std::fs::remove_file(&lock);
+                } else if attempts == 100 {
+                    return Err(err);
+                } else {
+                    attempts += 1;
+                    std::thread::sleep(Duration::from_millis(20));
+                }
+            }
+            Err(err) => return Err(err),
+        }
+    }
+
+    let result = func();
+    let _ = std::fs::remove_file(&lock);
+
+    result
+}
+
+/// Add the entry to the entries read from the file, replacing the previous result for its key and
+/// dropping the stale results of its device found with another fingerprint.
+fn merge_entries(entries: Vec<TuneCacheEntry>, entry: TuneCacheEntry) -> Vec<TuneCacheEntry> {
+    let mut entries: Vec<_> = entries
+        .into_iter()
+        .filter(|other| {
+            other.device != entry.device
+                || (other.fingerprint == entry.fingerprint && other.key != entry.key)
+        })
+        .collect();
+
+    entries.push(entry);
+    entries
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(device: &str, fingerprint: &str, key: &str, fastest_index: usize) -> TuneCacheEntry {
+        TuneCacheEntry {
+            device: device.to_string(),
+            fingerprint: fingerprint.to_string(),
+            key: key.to_string(),
+            fastest_index,
+            num_candidates: 3,
+        }
+    }
+
+    #[test]
+    fn persisted_result_should_only_be_used_with_the_same_candidates() {
+        let result = entry("a", "v1", "matmul", 2).result();
+
+        assert_eq!(result.index_of(3), Some(2));
+        // A candidate was added or removed since the result was found.
+        assert_eq!(result.index_of(4), None);
+        assert_eq!(result.index_of(2), None);
+    }
+
+    #[test]
+    fn persisted_result_should_reject_an_invalid_index() {
+        let mut entry = entry("a", "v1", "matmul", 2);
+        entry.fastest_index = 7;
+
+        assert_eq!(entry.result().index_of(3), None);
+    }
+
+    #[test]
+    fn results_saved_without_the_number_of_candidates_should_be_ignored() {
+        let content = r#"{ "entries": [
+            { "device": "a", "fingerprint": "v1", "key": "matmul", "fastest_index": 0 }
+        ] }"#;
+        let file: TuneCacheFile = serde_json::from_str(content).unwrap();
+
+        assert_eq!(file.entries[0].result().index_of(1), None);
+    }
+
+    #[test]
+    fn file_lock_should_be_released_after_use() {
+        let dir = std::env::temp_dir().join(format!("burn-tune-cache-lock-{}", std::process::id()));
+        let path = dir.join(TUNE_CACHE_FILE);
+
+        with_file_lock(&path, || Ok(())).unwrap();
+        // The lock is free again, so it can be taken a second time without waiting.
+        with_file_lock(&path, || Ok(())).unwrap();
+        let locked = dir.join(TUNE_CACHE_LOCK_FILE).exists();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert!(!locked);
+    }
+
+    #[test]
+    fn merge_should_replace_the_result_of_the_same_key() {
+        let entries = vec![entry("a", "v1", "matmul", 0), entry("a", "v1", "reduce", 1)];
+
+        let merged = merge_entries(entries, entry("a", "v1", "matmul", 2));
+
+        assert_eq!(
+            merged,
+            vec![entry("a", "v1", "reduce", 1), entry("a", "v1", "matmul", 2)]
+        );
+    }
+
+    #[test]
+    fn merge_should_drop_stale_results_of_the_device() {
+        let entries = vec![entry("a", "v1", "reduce", 1), entry("b", "v1", "reduce", 0)];
+
+        let merged = merge_entries(entries, entry("a", "v2", "matmul", 2));
+
+        assert_eq!(
+            merged,
+            vec![entry("b", "v1", "reduce", 0), entry("a", "v2", "matmul", 2)]
+        );
+    }
+
+    #[test]
+    fn path_should_only_be_set_for_the_runtimes_with_a_directory() {
+        // The environment variables apply to all the runtimes.
+        if std::env::var_os(TUNE_CACHE_DIR_ENV).is_some()
+            || std::env::var_os(TUNE_CACHE_ENV).is_some()
+        {
+            return;
+        }
+        let mut state = TuneCacheState::new();
+
+        assert_eq!(state.path("wgpu"), None);
+
+        state.dirs.insert("wgpu", PathBuf::from("cache"));
+
+        assert_eq!(
+            state.path("wgpu"),
+            Some(PathBuf::from("cache").join(TUNE_CACHE_FILE))
+        );
+        assert_eq!(state.path("cuda"), None);
+    }
+
+    #[test]
+    fn entries_should_round_trip_through_the_file() {
+        let dir = std::env::temp_dir().join(format!("burn-tune-cache-{}", std::process::id()));
+        let path = dir.join(TUNE_CACHE_FILE);
+        let entries = vec![entry("a", "v1", "matmul", 2), entry("b", "v1", "reduce", 0)];
+
+        write_entries(&path, &entries).unwrap();
+        let read = read_entries(&path);
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(read, entries);
+    }
+
+    #[test]
+    fn invalid_file_should_have_no_entry() {
+        let dir = std::env::temp_dir().join(format!("burn-tune-cache-bad-{}", std::process::id()));
+        let path = dir.join(TUNE_CACHE_FILE);
+
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(&path, "{ \"entries\": [").unwrap();
+        let read = read_entries(&path);
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert!(read.is_empty());
+    }
+}
@@ -1,4 +1,6 @@
-use crate::kernel::{matmul::MatmulAutotuneKey, reduce::ReduceAutotuneKey};
+use crate::kernel::{
+    conv::ConvTranspose2dAutotuneKey, matmul::MatmulAutotuneKey, reduce::ReduceAutotuneKey,
+};
 use cubecl::tune::AutotuneKey;
 use serde::{Deserialize, Serialize};
 use std::fmt::Display;
@@ -11,8 +13,14 @@ use crate::fusion::FusionElemWiseAutotuneKey;
 pub enum JitAutotuneKey {
     /// Key for matmul operation
     Matmul(MatmulAutotuneKey),
+    /// Key for the configs of the tiling 2D matmul, tuned apart from the other matmul kernels
+    Tiling2d(MatmulAutotuneKey),
+    /// Key for the configs of the tiling 2D matmul with a fused epilogue
+    Tiling2dEpilogue(MatmulAutotuneKey),
     /// Key for reduce dim operations
     ReduceDim(ReduceAutotuneKey),
+    /// Key for transposed convolution operations
+    ConvTranspose2d(ConvTranspose2dAutotuneKey),
     #[cfg(any(feature = "fusion", test))]
     /// Key for fused element wise operations.
     FusionElemWise(FusionElemWiseAutotuneKey),
@@ -22,7 +30,16 @@ impl Display for JitAutotuneKey {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             JitAutotuneKey::Matmul(matmul_key) => std::fmt::Display::fmt(&matmul_key, f),
+            JitAutotuneKey::Tiling2d(matmul_key) => {
+                write!(f, "Tiling 2D ")?;
+                std::fmt::Display::fmt(&matmul_key, f)
+            }
+            JitAutotuneKey::Tiling2dEpilogue(matmul_key) => {
+                write!(f, "Tiling 2D epilogue ")?;
+                std::fmt::Display::fmt(&matmul_key, f)
+            }
             JitAutotuneKey::ReduceDim(reduce_key) => std::fmt::Display::fmt(&reduce_key, f),
+            JitAutotuneKey::ConvTranspose2d(conv_key) => std::fmt::Display::fmt(&conv_key, f),
             #[cfg(any(feature = "fusion", test))]
             JitAutotuneKey::FusionElemWise(reduce_key) => std::fmt::Display::fmt(&reduce_key, f),
         }
@@ -2,12 +2,12 @@ use crate::element::{FloatNdArrayElement, QuantElement};
 use crate::PrecisionBridge;
 use crate::{NdArrayQTensor, NdArrayTensor};
 use alloc::string::String;
-use burn_common::stub::Mutex;
-use burn_tensor::backend::{Backend, DeviceId, DeviceOps};
+use burn_tensor::backend::{
+    Backend, DeviceId, DeviceOps, DeviceProperties, DeviceRngs, DeviceType, RngState,
+};
 use core::marker::PhantomData;
-use rand::{rngs::StdRng, SeedableRng};
 
-pub(crate) static SEED: Mutex<Option<StdRng>> = Mutex::new(None);
+pub(crate) static SEED: DeviceRngs = DeviceRngs::new();
 
 /// The device type for the ndarray backend.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -22,6 +22,25 @@ impl DeviceOps for NdArrayDevice {
             NdArrayDevice::Cpu => DeviceId::new(0, 0),
         }
     }
+
+    fn properties(&self) -> DeviceProperties {
+        DeviceProperties {
+            name: String::from("CPU"),
+            vendor: String::new(),
+            driver_version: String::new(),
+            backend_kind: String::from("cpu"),
+            device_type: DeviceType::Cpu,
+            total_memory: None,
+            supports_f16: false,
+            supports_f64: true,
+            supports_bf16: false,
+            supports_atomic_64: false,
+            max_workgroup_size: None,
+            max_shared_memory_size: None,
+            subgroup_support: false,
+            subgroup_size: None,
+        }
+    }
 }
 
 impl Default for NdArrayDevice {
@@ -63,8 +82,37 @@ impl<E: FloatNdArrayElement, Q: QuantElement> Backend for NdArray<E, Q> {
     }
 
     fn seed(seed: u64) {
-        let rng = StdRng::seed_from_u64(seed);
-        let mut seed = SEED.lock().unwrap();
-        *seed = Some(rng);
+        SEED.seed(seed);
+    }
+
+    fn seed_device(device: &Self::Device, seed: u64) {
+        SEED.seed_device(device.id(), seed);
+    }
+
+    fn rng_state() -> Option<RngState> {
+        Some(SEED.state())
+    }
+
+    fn restore_rng_state(state: RngState) {
+        SEED.restore(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type B = NdArray<f32>;
+
+    #[test]
+    fn should_describe_the_cpu() {
+        let devices = B::devices();
+        assert_eq!(devices, [NdArrayDevice::Cpu]);
+
+        let properties = B::device_properties(&devices[0]);
+        assert_eq!(properties.device_type, DeviceType::Cpu);
+        assert_eq!(properties.backend_kind, "cpu");
+        assert!(properties.supports_f64);
+        assert_eq!(B::preferred_device(), NdArrayDevice::Cpu);
     }
 }
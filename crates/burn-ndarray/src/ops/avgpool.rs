@@ -1,7 +1,7 @@
 use crate::{element::FloatNdArrayElement, sharing::UnsafeSharedRef, tensor::NdArrayTensor};
 use burn_common::{iter_range_par, run_par};
 
-use burn_tensor::ElementConversion;
+use burn_tensor::{ops::conv::calculate_pool_output_size, ElementConversion};
 use ndarray::Array4;
 
 pub(crate) fn avg_pool2d<E: FloatNdArrayElement>(
@@ -10,14 +10,29 @@ pub(crate) fn avg_pool2d<E: FloatNdArrayElement>(
     stride: [usize; 2],
     padding: [usize; 2],
     count_include_pad: bool,
+    ceil_mode: bool,
 ) -> NdArrayTensor<E, 4> {
     let [kernel_height, kernel_width] = kernel_size;
     let [padding_height, padding_width] = padding;
     let [stride_height, stride_width] = stride;
     let [batch_size, channels, x_height, x_width] = x.shape().dims;
 
-    let out_height = ((x_height + 2 * padding_height - kernel_height) / stride_height) + 1;
-    let out_width = ((x_width + 2 * padding_width - kernel_width) / stride_width) + 1;
+    let out_height = calculate_pool_output_size(
+        kernel_height,
+        stride_height,
+        padding_height,
+        1,
+        ceil_mode,
+        x_height,
+    );
+    let out_width = calculate_pool_output_size(
+        kernel_width,
+        stride_width,
+        padding_width,
+        1,
+        ceil_mode,
+        x_width,
+    );
 
     let x = x.array;
 
@@ -33,35 +48,23 @@ pub(crate) fn avg_pool2d<E: FloatNdArrayElement>(
 
             for oh in 0..out_height {
                 for ow in 0..out_width {
+                    let window = PoolWindow::new(
+                        [oh, ow],
+                        kernel_size,
+                        stride,
+                        padding,
+                        [x_height, x_width],
+                    );
                     let mut sum_val: E = 0.elem();
-                    let mut count: E = 0.elem();
 
-                    for kh in 0..kernel_height {
-                        for kw in 0..kernel_width {
-                            let ih = oh * stride_height + kh;
-                            let iw = ow * stride_width + kw;
-
-                            if ih >= x_height + padding_height
-                                || iw >= x_width + padding_width
-                                || ih < padding_height
-                                || iw < padding_width
-                            {
-                                continue;
-                            }
-
-                            let ih = ih - padding_height;
-                            let iw = iw - padding_width;
-
-                            count += 1.elem();
-                            sum_val += x[[b, c, ih, iw]];
+                    for ih in window.ih_start..window.ih_end {
+                        for iw in window.iw_start..window.iw_end {
+                            sum_val += x[[b, c, ih - padding_height, iw - padding_width]];
                         }
                     }
 
-                    if count_include_pad {
-                        count = ((kernel_height * kernel_width) as i32).elem();
-                    }
-
-                    output[[b, c, oh, ow]] = sum_val / count;
+                    output[[b, c, oh, ow]] =
+                        sum_val / (window.count(count_include_pad) as i32).elem();
                 }
             }
         })
@@ -77,9 +80,8 @@ pub(crate) fn avg_pool2d_backward<E: FloatNdArrayElement>(
     stride: [usize; 2],
     padding: [usize; 2],
     count_include_pad: bool,
+    _ceil_mode: bool,
 ) -> NdArrayTensor<E, 4> {
-    let [kernel_height, kernel_width] = kernel_size;
-    let [stride_height, stride_width] = stride;
     let [padding_height, padding_width] = padding;
     let [batch_size, channels, x_height, x_width] = x.shape().dims;
     let [_batch_size, _channels, out_height, out_width] = grad.shape().dims;
@@ -98,25 +100,17 @@ pub(crate) fn avg_pool2d_backward<E: FloatNdArrayElement>(
 
             for oh in 0..out_height {
                 for ow in 0..out_width {
-                    let ih_start = oh * stride_height;
-                    let iw_start = ow * stride_width;
-
-                    let ih_end = ih_start + kernel_height;
-                    let iw_end = iw_start + kernel_width;
-
-                    let ih_start = usize::max(ih_start, padding_height);
-                    let iw_start = usize::max(iw_start, padding_width);
-
-                    let ih_end = usize::min(ih_end, x_height + padding_height);
-                    let iw_end = usize::min(iw_end, x_width + padding_width);
-
-                    let count = match count_include_pad {
-                        true => kernel_width * kernel_height,
-                        false => (ih_end - ih_start) * (iw_end - iw_start),
-                    };
-
-                    for ih in ih_start..ih_end {
-                        for iw in iw_start..iw_end {
+                    let window = PoolWindow::new(
+                        [oh, ow],
+                        kernel_size,
+                        stride,
+                        padding,
+                        [x_height, x_width],
+                    );
+                    let count = window.count(count_include_pad);
+
+                    for ih in window.ih_start..window.ih_end {
+                        for iw in window.iw_start..window.iw_end {
                             let ih = ih - padding_height;
                             let iw = iw - padding_width;
 
@@ -131,3 +125,53 @@ pub(crate) fn avg_pool2d_backward<E: FloatNdArrayElement>(
 
     NdArrayTensor::new(output_grad.into_dyn().into_shared())
 }
+
+/// A pooling window, in the coordinates of the padded input.
+struct PoolWindow {
+    ih_start: usize,
+    ih_end: usize,
+    iw_start: usize,
+    iw_end: usize,
+    /// The number of elements of the window in the padded input, which is smaller than the kernel
+    /// for the partial windows added with `ceil_mode`.
+    count_padded: usize,
+}
+
+impl PoolWindow {
+    fn new(
+        [oh, ow]: [usize; 2],
+        [kernel_height, kernel_width]: [usize; 2],
+        [stride_height, stride_width]: [usize; 2],
+        [padding_height, padding_width]: [usize; 2],
+        [x_height, x_width]: [usize; 2],
+    ) -> Self {
+        let ih_start = oh * stride_height;
+        let iw_start = ow * stride_width;
+
+        let ih_end = usize::min(ih_start + kernel_height, x_height + 2 * padding_height);
+        let iw_end = usize::min(iw_start + kernel_width, x_width + 2 * padding_width);
+        let count_padded = (ih_end - ih_start) * (iw_end - iw_start);
+
+        let ih_start = usize::max(ih_start, padding_height);
+        let iw_start = usize::max(iw_start, padding_width);
+
+        let ih_end = usize::max(usize::min(ih_end, x_height + padding_height), ih_start);
+        let iw_end = usize::max(usize::min(iw_end, x_width + padding_width), iw_start);
+
+        Self {
+            ih_start,
+            ih_end,
+            iw_start,
+            iw_end,
+            count_padded,
+        }
+    }
+
+    /// The divisor of the sum of the window.
+    fn count(&self, count_include_pad: bool) -> usize {
+        match count_include_pad {
+            true => self.count_padded,
+            false => (self.ih_end - self.ih_start) * (self.iw_end - self.iw_start),
+        }
+    }
+}
@@ -17,7 +17,7 @@ use ndarray::IxDyn;
 use ndarray::SliceInfoElem;
 
 use crate::element::NdArrayElement;
-use crate::ops::macros::{keepdim, mean_dim, prod_dim, sum_dim};
+use crate::ops::macros::{empty_mean, keepdim, mean_dim, prod_dim, sum_dim};
 use crate::{reshape, tensor::NdArrayTensor};
 
 pub struct NdArrayOps<E> {
@@ -250,7 +250,8 @@ where
     }
 
     pub fn mean<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, 1> {
-        let data = TensorData::from([tensor.array.mean().unwrap()]);
+        let mean = tensor.array.mean().unwrap_or_else(empty_mean);
+        let data = TensorData::from([mean]);
         NdArrayTensor::from_data(data)
     }
 
@@ -400,17 +401,29 @@ where
         mask: NdArrayTensor<bool, D>,
         source: NdArrayTensor<E, D>,
     ) -> NdArrayTensor<E, D> {
-        let mask_mul_4tensor = mask.array.mapv(|x| match x {
-            true => 0.elem(),
-            false => 1.elem(),
-        });
-        let mask_mul_4source = mask.array.mapv(|x| match x {
-            true => 1.elem(),
-            false => 0.elem(),
-        });
-        let array = (tensor.array * mask_mul_4tensor) + (source.array * mask_mul_4source);
+        // Select instead of multiplying by the mask, so NaN and Inf don't leak from the unselected
+        // values.
+        let broadcast = |lhs: usize, rhs: usize| if lhs == 1 { rhs } else { lhs };
+        let shape: Vec<usize> = tensor
+            .array
+            .shape()
+            .iter()
+            .zip(mask.array.shape())
+            .zip(source.array.shape())
+            .map(|((&tensor, &mask), &source)| broadcast(broadcast(tensor, mask), source))
+            .collect();
+        let shape = IxDyn(&shape);
 
-        NdArrayTensor::new(array)
+        let tensor = tensor.array.broadcast(shape.clone()).unwrap();
+        let mask = mask.array.broadcast(shape.clone()).unwrap();
+        let source = source.array.broadcast(shape).unwrap();
+
+        let array = Zip::from(tensor)
+            .and(mask)
+            .and(source)
+            .map_collect(|&tensor, &mask, &source| if mask { source } else { tensor });
+
+        NdArrayTensor::new(array.into_shared())
     }
 
     pub fn mask_fill<const D: usize>(
@@ -484,6 +497,29 @@ where
 
         NdArrayTensor::new(output_array.into_shared())
     }
+
+    pub fn index_put<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        dim: usize,
+        indices: NdArrayTensor<i64, 1>,
+        value: NdArrayTensor<E, D>,
+        accumulate: bool,
+    ) -> NdArrayTensor<E, D> {
+        let mut output_array = tensor.array.into_owned();
+
+        // The values are written in index order, so the last one wins for repeated indices.
+        for (index_value, index) in indices.array.into_iter().enumerate() {
+            let mut view = output_array.index_axis_mut(Axis(dim), index as usize);
+            let value = value.array.index_axis(Axis(dim), index_value);
+
+            match accumulate {
+                true => view.zip_mut_with(&value, |a, b| *a += *b),
+                false => view.assign(&value),
+            }
+        }
+
+        NdArrayTensor::new(output_array.into_shared())
+    }
     pub fn argmax<const D: usize>(
         tensor: NdArrayTensor<E, D>,
         dim: usize,
@@ -117,6 +117,30 @@ impl<E: FloatNdArrayElement, Q: QuantElement> BoolTensorOps<Self> for NdArray<E,
         NdArrayTensor { array }
     }
 
+    fn bool_and<const D: usize>(
+        lhs: <NdArray<E> as Backend>::BoolTensorPrimitive<D>,
+        rhs: <NdArray<E> as Backend>::BoolTensorPrimitive<D>,
+    ) -> <NdArray<E> as Backend>::BoolTensorPrimitive<D> {
+        let array = (&lhs.array & &rhs.array).into_shared();
+        NdArrayTensor { array }
+    }
+
+    fn bool_or<const D: usize>(
+        lhs: <NdArray<E> as Backend>::BoolTensorPrimitive<D>,
+        rhs: <NdArray<E> as Backend>::BoolTensorPrimitive<D>,
+    ) -> <NdArray<E> as Backend>::BoolTensorPrimitive<D> {
+        let array = (&lhs.array | &rhs.array).into_shared();
+        NdArrayTensor { array }
+    }
+
+    fn bool_xor<const D: usize>(
+        lhs: <NdArray<E> as Backend>::BoolTensorPrimitive<D>,
+        rhs: <NdArray<E> as Backend>::BoolTensorPrimitive<D>,
+    ) -> <NdArray<E> as Backend>::BoolTensorPrimitive<D> {
+        let array = (&lhs.array ^ &rhs.array).into_shared();
+        NdArrayTensor { array }
+    }
+
     fn bool_into_float<const D: usize>(
         tensor: <NdArray<E> as Backend>::BoolTensorPrimitive<D>,
     ) -> <NdArray<E> as Backend>::FloatTensorPrimitive<D> {
@@ -1,10 +1,10 @@
 // Language
 use alloc::vec;
 use alloc::vec::Vec;
-use burn_common::rand::get_seeded_rng;
 use burn_tensor::ops::IntTensorOps;
 use burn_tensor::Distribution;
 
+use burn_tensor::shift_mask;
 use burn_tensor::ElementConversion;
 use core::ops::Range;
 use ndarray::IntoDimension;
@@ -18,9 +18,12 @@ use crate::{tensor::NdArrayTensor, NdArray};
 use crate::{NdArrayDevice, SEED};
 
 // Workspace crates
-use burn_tensor::{backend::Backend, Shape, TensorData};
+use burn_tensor::{
+    backend::{Backend, DeviceOps},
+    Shape, TensorData,
+};
 
-use super::{NdArrayMathOps, NdArrayOps};
+use super::{matmul::matmul, NdArrayMathOps, NdArrayOps};
 
 impl<E: FloatNdArrayElement, Q: QuantElement> IntTensorOps<Self> for NdArray<E, Q> {
     fn int_from_data<const D: usize>(
@@ -231,6 +234,13 @@ impl<E: FloatNdArrayElement, Q: QuantElement> IntTensorOps<Self> for NdArray<E,
         NdArrayMathOps::mul_scalar(lhs, rhs)
     }
 
+    fn int_matmul<const D: usize>(
+        lhs: NdArrayTensor<i64, D>,
+        rhs: NdArrayTensor<i64, D>,
+    ) -> NdArrayTensor<i64, D> {
+        matmul(lhs, rhs)
+    }
+
     fn int_div<const D: usize>(
         lhs: NdArrayTensor<i64, D>,
         rhs: NdArrayTensor<i64, D>,
@@ -245,11 +255,21 @@ impl<E: FloatNdArrayElement, Q: QuantElement> IntTensorOps<Self> for NdArray<E,
         NdArrayMathOps::div_scalar(lhs, rhs)
     }
 
+    fn int_remainder<const D: usize>(
+        lhs: NdArrayTensor<i64, D>,
+        rhs: NdArrayTensor<i64, D>,
+    ) -> NdArrayTensor<i64, D> {
+        let divisor = rhs.array.mapv(i64::abs);
+        let array = ((&lhs.array % &divisor) + &divisor) % &divisor;
+
+        NdArrayTensor::new(array.into_shared())
+    }
+
     fn int_remainder_scalar<const D: usize>(
         lhs: NdArrayTensor<i64, D>,
         rhs: i64,
     ) -> NdArrayTensor<i64, D> {
-        NdArrayMathOps::remainder_scalar(lhs, rhs)
+        NdArrayMathOps::elementwise_op_scalar(lhs, |a: i64| a.rem_euclid(rhs))
     }
 
     fn int_neg<const D: usize>(tensor: NdArrayTensor<i64, D>) -> NdArrayTensor<i64, D> {
@@ -344,6 +364,17 @@ impl<E: FloatNdArrayElement, Q: QuantElement> IntTensorOps<Self> for NdArray<E,
     ) -> NdArrayTensor<i64, D> {
         NdArrayMathOps::select_assign(tensor, dim, indices, value)
     }
+
+    fn int_index_put<const D: usize>(
+        tensor: NdArrayTensor<i64, D>,
+        dim: usize,
+        indices: NdArrayTensor<i64, 1>,
+        values: NdArrayTensor<i64, D>,
+        accumulate: bool,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayMathOps::index_put(tensor, dim, indices, values, accumulate)
+    }
+
     fn int_argmax<const D: usize>(
         tensor: NdArrayTensor<i64, D>,
         dim: usize,
@@ -406,32 +437,31 @@ impl<E: FloatNdArrayElement, Q: QuantElement> IntTensorOps<Self> for NdArray<E,
         distribution: Distribution,
         device: &NdArrayDevice,
     ) -> NdArrayTensor<i64, D> {
-        let mut seed = SEED.lock().unwrap();
-        let mut rng = if let Some(rng_seeded) = seed.as_ref() {
-            rng_seeded.clone()
-        } else {
-            get_seeded_rng()
-        };
-
         let effective_distribution = if distribution == Distribution::Default {
             Distribution::Uniform(0.0, 255.0) // Assuming UniformInt is the integer variant
         } else {
             distribution
         };
 
-        let tensor = Self::int_from_data(
-            TensorData::random::<i64, _, _>(shape, effective_distribution, &mut rng),
-            device,
-        );
-        *seed = Some(rng);
-        tensor
+        let data = SEED.with(device.id(), |rng| {
+            TensorData::random::<i64, _, _>(shape, effective_distribution, rng)
+        });
+
+        Self::int_from_data(data, device)
     }
 
     fn int_powi<const D: usize>(
         lhs: NdArrayTensor<i64, D>,
         rhs: NdArrayTensor<i64, D>,
     ) -> NdArrayTensor<i64, D> {
-        NdArrayMathOps::elementwise_op(lhs, rhs, |a: &i64, b: &i64| a.pow(*b as u32))
+        NdArrayMathOps::elementwise_op(lhs, rhs, |a: &i64, b: &i64| powi_elem(*a, *b))
+    }
+
+    fn int_powi_scalar<const D: usize>(
+        lhs: NdArrayTensor<i64, D>,
+        rhs: i64,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayMathOps::elementwise_op_scalar(lhs, |a: i64| powi_elem(a, rhs))
     }
 
     fn int_powf<const D: usize>(
@@ -473,4 +503,99 @@ impl<E: FloatNdArrayElement, Q: QuantElement> IntTensorOps<Self> for NdArray<E,
     ) -> burn_tensor::ops::IntTensor<Self, D2> {
         NdArrayOps::expand(tensor, shape)
     }
+
+    fn int_bitwise_and<const D: usize>(
+        lhs: NdArrayTensor<i64, D>,
+        rhs: NdArrayTensor<i64, D>,
+    ) -> NdArrayTensor<i64, D> {
+        let array = (&lhs.array & &rhs.array).into_shared();
+        NdArrayTensor { array }
+    }
+
+    fn int_bitwise_and_scalar<const D: usize>(
+        lhs: NdArrayTensor<i64, D>,
+        rhs: i64,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayMathOps::elementwise_op_scalar(lhs, |a: i64| a & rhs)
+    }
+
+    fn int_bitwise_or<const D: usize>(
+        lhs: NdArrayTensor<i64, D>,
+        rhs: NdArrayTensor<i64, D>,
+    ) -> NdArrayTensor<i64, D> {
+        let array = (&lhs.array | &rhs.array).into_shared();
+        NdArrayTensor { array }
+    }
+
+    fn int_bitwise_or_scalar<const D: usize>(
+        lhs: NdArrayTensor<i64, D>,
+        rhs: i64,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayMathOps::elementwise_op_scalar(lhs, |a: i64| a | rhs)
+    }
+
+    fn int_bitwise_xor<const D: usize>(
+        lhs: NdArrayTensor<i64, D>,
+        rhs: NdArrayTensor<i64, D>,
+    ) -> NdArrayTensor<i64, D> {
+        let array = (&lhs.array ^ &rhs.array).into_shared();
+        NdArrayTensor { array }
+    }
+
+    fn int_bitwise_xor_scalar<const D: usize>(
+        lhs: NdArrayTensor<i64, D>,
+        rhs: i64,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayMathOps::elementwise_op_scalar(lhs, |a: i64| a ^ rhs)
+    }
+
+    fn int_bitwise_left_shift<const D: usize>(
+        lhs: NdArrayTensor<i64, D>,
+        rhs: NdArrayTensor<i64, D>,
+    ) -> NdArrayTensor<i64, D> {
+        let amounts = rhs.array.mapv(|amount| amount & shift_mask::<i64>());
+        let array = (&lhs.array << &amounts).into_shared();
+        NdArrayTensor { array }
+    }
+
+    fn int_bitwise_left_shift_scalar<const D: usize>(
+        lhs: NdArrayTensor<i64, D>,
+        rhs: i64,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayMathOps::elementwise_op_scalar(lhs, |a: i64| a.wrapping_shl(rhs as u32))
+    }
+
+    fn int_bitwise_right_shift<const D: usize>(
+        lhs: NdArrayTensor<i64, D>,
+        rhs: NdArrayTensor<i64, D>,
+    ) -> NdArrayTensor<i64, D> {
+        let amounts = rhs.array.mapv(|amount| amount & shift_mask::<i64>());
+        let array = (&lhs.array >> &amounts).into_shared();
+        NdArrayTensor { array }
+    }
+
+    fn int_bitwise_right_shift_scalar<const D: usize>(
+        lhs: NdArrayTensor<i64, D>,
+        rhs: i64,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayMathOps::elementwise_op_scalar(lhs, |a: i64| a.wrapping_shr(rhs as u32))
+    }
+
+    fn int_bitwise_not<const D: usize>(tensor: NdArrayTensor<i64, D>) -> NdArrayTensor<i64, D> {
+        NdArrayMathOps::elementwise_op_scalar(tensor, |a: i64| !a)
+    }
+}
+
+/// Raise the base to the power, a negative power being rounded toward zero like the division.
+fn powi_elem(base: i64, exponent: i64) -> i64 {
+    if exponent >= 0 {
+        return base.wrapping_pow(u32::try_from(exponent).unwrap_or(u32::MAX));
+    }
+
+    match base {
+        1 => 1,
+        -1 if exponent % 2 == 0 => 1,
+        -1 => -1,
+        _ => 0,
+    }
 }
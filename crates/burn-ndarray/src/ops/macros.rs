@@ -36,7 +36,7 @@ macro_rules! keepdim {
 
 use burn_tensor::ElementConversion;
 pub(crate) use keepdim;
-use ndarray::Axis;
+use ndarray::{ArrayD, Axis};
 
 use crate::{element::NdArrayElement, tensor::NdArrayTensor};
 
@@ -44,11 +44,24 @@ pub(crate) fn mean_dim<E: NdArrayElement, const D1: usize, const D2: usize>(
     tensor: NdArrayTensor<E, D1>,
     dim: usize,
 ) -> NdArrayTensor<E, D2> {
-    let array = tensor.array.mean_axis(Axis(dim)).unwrap().into_shared();
+    let array = match tensor.array.mean_axis(Axis(dim)) {
+        Some(array) => array.into_shared(),
+        // The mean over an empty dimension is NaN, or zero for integers.
+        None => {
+            let mut shape = tensor.array.shape().to_vec();
+            shape.remove(dim);
+            ArrayD::from_elem(shape, empty_mean()).into_shared()
+        }
+    };
 
     NdArrayTensor { array }
 }
 
+/// The mean of no values, NaN for floats and zero for integers, which can't represent NaN.
+pub(crate) fn empty_mean<E: NdArrayElement>() -> E {
+    E::from_f64(f64::NAN).unwrap_or(0.elem())
+}
+
 pub(crate) fn sum_dim<E: NdArrayElement, const D1: usize, const D2: usize>(
     tensor: NdArrayTensor<E, D1>,
     dim: usize,
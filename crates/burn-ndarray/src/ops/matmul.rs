@@ -1,9 +1,9 @@
-use crate::{element::FloatNdArrayElement, tensor::NdArrayTensor, NdArray, UnsafeSharedRef};
+use crate::{element::NdArrayElement, ops::NdArrayOps, tensor::NdArrayTensor, UnsafeSharedRef};
 
 use alloc::vec::Vec;
 use burn_common::{iter_range_par, run_par};
 use burn_tensor::ElementConversion;
-use burn_tensor::{ops::FloatTensorOps, Shape};
+use burn_tensor::Shape;
 use ndarray::s;
 
 pub(crate) fn matmul<E, const D: usize>(
@@ -11,7 +11,7 @@ pub(crate) fn matmul<E, const D: usize>(
     rhs: NdArrayTensor<E, D>,
 ) -> NdArrayTensor<E, D>
 where
-    E: FloatNdArrayElement,
+    E: NdArrayElement,
 {
     let shape_lhs = lhs.shape();
     let shape_rhs = rhs.shape();
@@ -20,13 +20,12 @@ where
     let n = shape_rhs.dims[D - 1]; // # of right cols
 
     let (out_shape, strides_lhs, strides_rhs, strides_out) = output_shape(&shape_lhs, &shape_rhs);
-    let l_mat_size = m * k; // size of matrix component of left array
-    let r_mat_size = k * n; // size of matrix component of right array
-    let out_mat_size = m * n; // size of matrix component of output array
 
-    let num_l_batches = shape_lhs.num_elements() / l_mat_size;
-    let num_r_batches = shape_rhs.num_elements() / r_mat_size;
-    let num_out_batches = out_shape.num_elements() / out_mat_size;
+    // The batches are counted from the batch dimensions, since the matrices can be empty.
+    let num_batches = |shape: &Shape<D>| shape.dims[..D - 2].iter().product::<usize>();
+    let num_l_batches = num_batches(&shape_lhs);
+    let num_r_batches = num_batches(&shape_rhs);
+    let num_out_batches = num_batches(&out_shape);
 
     let alpha: E = 1.0.elem();
     let beta: E = 0.0.elem();
@@ -35,8 +34,8 @@ where
         let mut out_array = ndarray::Array3::<E>::zeros((num_out_batches, m, n));
         let unsafe_shared_out_array = UnsafeSharedRef::new(&mut out_array);
 
-        let lhs_array = NdArray::<E>::float_reshape(lhs, Shape::new([num_l_batches, m, k])).array;
-        let rhs_array = NdArray::<E>::float_reshape(rhs, Shape::new([num_r_batches, k, n])).array;
+        let lhs_array = NdArrayOps::reshape(lhs, Shape::new([num_l_batches, m, k])).array;
+        let rhs_array = NdArrayOps::reshape(rhs, Shape::new([num_r_batches, k, n])).array;
 
         iter_range_par!(0, num_out_batches).for_each(|out_batch| {
             // Here, we:
@@ -68,7 +67,7 @@ where
         NdArrayTensor::new(out_array.into_shared().into_dyn())
     });
 
-    NdArray::<E>::float_reshape(out, out_shape)
+    NdArrayOps::reshape(out, out_shape)
 }
 
 #[derive(Debug, PartialEq)]
@@ -6,7 +6,7 @@ use crate::{
 };
 
 use burn_common::{iter_range_par, run_par};
-use burn_tensor::ElementConversion;
+use burn_tensor::{ops::conv::calculate_pool_output_size, ElementConversion};
 use ndarray::Array4;
 
 pub(crate) fn max_pool2d<E: FloatNdArrayElement, Q: QuantElement>(
@@ -15,6 +15,7 @@ pub(crate) fn max_pool2d<E: FloatNdArrayElement, Q: QuantElement>(
     stride: [usize; 2],
     padding: [usize; 2],
     dilation: [usize; 2],
+    ceil_mode: bool,
 ) -> NdArrayTensor<E, 4> {
     let [kernel_height, kernel_width] = kernel_size;
     let [padding_height, padding_width] = padding;
@@ -23,12 +24,25 @@ pub(crate) fn max_pool2d<E: FloatNdArrayElement, Q: QuantElement>(
     let [batch_size, channels, x_height, x_width] = x.shape().dims;
     let inf = (-f32::INFINITY).elem::<E>();
 
-    let out_height = ((x_height + 2 * padding_height - dilation_height * (kernel_height - 1) - 1)
-        / stride_height)
-        + 1;
-    let out_width = ((x_width + 2 * padding_width - dilation_width * (kernel_width - 1) - 1)
-        / stride_width)
-        + 1;
+    let out_height = calculate_pool_output_size(
+        kernel_height,
+        stride_height,
+        padding_height,
+        dilation_height,
+        ceil_mode,
+        x_height,
+    );
+    let out_width = calculate_pool_output_size(
+        kernel_width,
+        stride_width,
+        padding_width,
+        dilation_width,
+        ceil_mode,
+        x_width,
+    );
+    // The partial windows added with `ceil_mode` can overflow the padded input.
+    let padded_height = x_height + 2 * padding_height;
+    let padded_width = x_width + 2 * padding_width;
 
     let x = apply_padding_4d::<E, Q>(x, padding, inf).array;
 
@@ -48,9 +62,15 @@ pub(crate) fn max_pool2d<E: FloatNdArrayElement, Q: QuantElement>(
 
                     for kh in 0..kernel_height {
                         let ih = oh * stride_height + kh * dilation_height;
+                        if ih >= padded_height {
+                            break;
+                        }
 
                         for kw in 0..kernel_width {
                             let iw = ow * stride_width + kw * dilation_width;
+                            if iw >= padded_width {
+                                break;
+                            }
 
                             let val = x[[b, c, ih, iw]];
 
@@ -75,6 +95,7 @@ pub(crate) fn max_pool2d_with_indices<E: FloatNdArrayElement, Q: QuantElement>(
     stride: [usize; 2],
     padding: [usize; 2],
     dilation: [usize; 2],
+    ceil_mode: bool,
 ) -> (NdArrayTensor<E, 4>, NdArrayTensor<i64, 4>) {
     let [kernel_height, kernel_width] = kernel_size;
     let [padding_height, padding_width] = padding;
@@ -83,12 +104,25 @@ pub(crate) fn max_pool2d_with_indices<E: FloatNdArrayElement, Q: QuantElement>(
     let [batch_size, channels, x_height, x_width] = x.shape().dims;
     let inf = (-f32::INFINITY).elem::<E>();
 
-    let out_height = ((x_height + 2 * padding_height - dilation_height * (kernel_height - 1) - 1)
-        / stride_height)
-        + 1;
-    let out_width = ((x_width + 2 * padding_width - dilation_width * (kernel_width - 1) - 1)
-        / stride_width)
-        + 1;
+    let out_height = calculate_pool_output_size(
+        kernel_height,
+        stride_height,
+        padding_height,
+        dilation_height,
+        ceil_mode,
+        x_height,
+    );
+    let out_width = calculate_pool_output_size(
+        kernel_width,
+        stride_width,
+        padding_width,
+        dilation_width,
+        ceil_mode,
+        x_width,
+    );
+    // The partial windows added with `ceil_mode` can overflow the padded input.
+    let padded_height = x_height + 2 * padding_height;
+    let padded_width = x_width + 2 * padding_width;
 
     let x = apply_padding_4d::<E, Q>(x, padding, inf).array;
 
@@ -113,9 +147,16 @@ pub(crate) fn max_pool2d_with_indices<E: FloatNdArrayElement, Q: QuantElement>(
 
                     for kh in 0..kernel_height {
                         let ih = oh * stride_height + kh * dilation_height;
+                        if ih >= padded_height {
+                            break;
+                        }
 
                         for kw in 0..kernel_width {
                             let iw = ow * stride_width + kw * dilation_width;
+                            if iw >= padded_width {
+                                break;
+                            }
+
                             let val = x[[b, c, ih, iw]];
 
                             if val > max_val {
@@ -124,7 +165,7 @@ pub(crate) fn max_pool2d_with_indices<E: FloatNdArrayElement, Q: QuantElement>(
                                 let ih = ih as i64 - padding_height as i64;
                                 let iw = iw as i64 - padding_width as i64;
 
-                                index = ih * x_height as i64 + iw;
+                                index = ih * x_width as i64 + iw;
                             }
                         }
                     }
@@ -148,6 +189,7 @@ pub(crate) fn max_pool2d_backward<E: FloatNdArrayElement>(
     _stride: [usize; 2],
     _padding: [usize; 2],
     _dilation: [usize; 2],
+    _ceil_mode: bool,
     output_grad: NdArrayTensor<E, 4>,
     indices: NdArrayTensor<i64, 4>,
 ) -> NdArrayTensor<E, 4> {
@@ -34,8 +34,16 @@ impl<E: FloatNdArrayElement, Q: QuantElement> ModuleOps<Self> for NdArray<E, Q>
         stride: [usize; 2],
         padding: [usize; 2],
         count_include_pad: bool,
+        ceil_mode: bool,
     ) -> NdArrayTensor<E, 4> {
-        avg_pool2d(x, kernel_size, stride, padding, count_include_pad)
+        avg_pool2d(
+            x,
+            kernel_size,
+            stride,
+            padding,
+            count_include_pad,
+            ceil_mode,
+        )
     }
 
     fn avg_pool2d_backward(
@@ -45,8 +53,17 @@ impl<E: FloatNdArrayElement, Q: QuantElement> ModuleOps<Self> for NdArray<E, Q>
         stride: [usize; 2],
         padding: [usize; 2],
         count_include_pad: bool,
+        ceil_mode: bool,
     ) -> NdArrayTensor<E, 4> {
-        avg_pool2d_backward(x, grad, kernel_size, stride, padding, count_include_pad)
+        avg_pool2d_backward(
+            x,
+            grad,
+            kernel_size,
+            stride,
+            padding,
+            count_include_pad,
+            ceil_mode,
+        )
     }
 
     fn max_pool2d(
@@ -55,8 +72,9 @@ impl<E: FloatNdArrayElement, Q: QuantElement> ModuleOps<Self> for NdArray<E, Q>
         stride: [usize; 2],
         padding: [usize; 2],
         dilation: [usize; 2],
+        ceil_mode: bool,
     ) -> NdArrayTensor<E, 4> {
-        max_pool2d::<E, Q>(x, kernel_size, stride, padding, dilation)
+        max_pool2d::<E, Q>(x, kernel_size, stride, padding, dilation, ceil_mode)
     }
 
     fn max_pool2d_with_indices(
@@ -65,9 +83,10 @@ impl<E: FloatNdArrayElement, Q: QuantElement> ModuleOps<Self> for NdArray<E, Q>
         stride: [usize; 2],
         padding: [usize; 2],
         dilation: [usize; 2],
+        ceil_mode: bool,
     ) -> MaxPool2dWithIndices<NdArray<E, Q>> {
         let (output, indices) =
-            max_pool2d_with_indices::<E, Q>(x, kernel_size, stride, padding, dilation);
+            max_pool2d_with_indices::<E, Q>(x, kernel_size, stride, padding, dilation, ceil_mode);
 
         MaxPool2dWithIndices::new(output, indices)
     }
@@ -78,6 +97,7 @@ impl<E: FloatNdArrayElement, Q: QuantElement> ModuleOps<Self> for NdArray<E, Q>
         stride: [usize; 2],
         padding: [usize; 2],
         dilation: [usize; 2],
+        ceil_mode: bool,
         output_grad: NdArrayTensor<E, 4>,
         indices: NdArrayTensor<i64, 4>,
     ) -> MaxPool2dBackward<NdArray<E, Q>> {
@@ -87,6 +107,7 @@ impl<E: FloatNdArrayElement, Q: QuantElement> ModuleOps<Self> for NdArray<E, Q>
             stride,
             padding,
             dilation,
+            ceil_mode,
             output_grad,
             indices,
         ))
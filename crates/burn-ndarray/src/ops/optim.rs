@@ -0,0 +1,7 @@
+use crate::{
+    element::{FloatNdArrayElement, QuantElement},
+    NdArray,
+};
+use burn_tensor::ops::OptimizerOps;
+
+impl<E: FloatNdArrayElement, Q: QuantElement> OptimizerOps<Self> for NdArray<E, Q> {}
@@ -10,15 +10,18 @@ use crate::{tensor::NdArrayTensor, NdArray};
 use crate::{NdArrayDevice, SEED};
 
 // Workspace crates
-use burn_common::rand::get_seeded_rng;
 use burn_tensor::Distribution;
-use burn_tensor::{backend::Backend, ops::FloatTensorOps, ElementConversion, Shape, TensorData};
+use burn_tensor::{
+    backend::{Backend, DeviceOps},
+    ops::FloatTensorOps,
+    ElementConversion, Shape, TensorData,
+};
 
 #[cfg(not(feature = "std"))]
 #[allow(unused_imports)]
 use num_traits::Float;
 
-use libm::erf;
+use libm::{erf, exp, log, sqrt};
 
 impl<E: FloatNdArrayElement, Q: QuantElement> FloatTensorOps<Self> for NdArray<E, Q> {
     fn float_from_data<const D: usize>(
@@ -33,18 +36,11 @@ impl<E: FloatNdArrayElement, Q: QuantElement> FloatTensorOps<Self> for NdArray<E
         distribution: Distribution,
         device: &NdArrayDevice,
     ) -> NdArrayTensor<E, D> {
-        let mut seed = SEED.lock().unwrap();
-        let mut rng = if let Some(rng_seeded) = seed.as_ref() {
-            rng_seeded.clone()
-        } else {
-            get_seeded_rng()
-        };
-        let tensor = Self::float_from_data(
-            TensorData::random::<E, _, _>(shape, distribution, &mut rng),
-            device,
-        );
-        *seed = Some(rng);
-        tensor
+        let data = SEED.with(device.id(), |rng| {
+            TensorData::random::<E, _, _>(shape, distribution, rng)
+        });
+
+        Self::float_from_data(data, device)
     }
 
     fn float_shape<const D: usize>(tensor: &NdArrayTensor<E, D>) -> Shape<D> {
@@ -190,6 +186,16 @@ impl<E: FloatNdArrayElement, Q: QuantElement> FloatTensorOps<Self> for NdArray<E
         NdArrayMathOps::select_assign(tensor, dim, indices, value)
     }
 
+    fn float_index_put<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        dim: usize,
+        indices: NdArrayTensor<i64, 1>,
+        values: NdArrayTensor<E, D>,
+        accumulate: bool,
+    ) -> NdArrayTensor<E, D> {
+        NdArrayMathOps::index_put(tensor, dim, indices, values, accumulate)
+    }
+
     fn float_slice<const D1: usize, const D2: usize>(
         tensor: NdArrayTensor<E, D1>,
         ranges: [Range<usize>; D2],
@@ -440,6 +446,15 @@ impl<E: FloatNdArrayElement, Q: QuantElement> FloatTensorOps<Self> for NdArray<E
         NdArrayTensor::new(array)
     }
 
+    fn float_erfinv<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
+        let array = tensor
+            .array
+            .mapv_into(|a| erfinv(a.to_f64()).elem())
+            .into_shared();
+
+        NdArrayTensor::new(array)
+    }
+
     fn float_cat<const D: usize>(
         tensors: Vec<NdArrayTensor<E, D>>,
         dim: usize,
@@ -503,3 +518,52 @@ impl<E: FloatNdArrayElement, Q: QuantElement> FloatTensorOps<Self> for NdArray<E
         NdArrayOps::expand(tensor, shape)
     }
 }
+
+/// Inverse error function, computed with the single precision approximation from M. Giles,
+/// "Approximating the erfinv function", then refined to double precision with Newton's method.
+fn erfinv(x: f64) -> f64 {
+    if x.abs() >= 1.0 {
+        return if x.abs() == 1.0 {
+            x * f64::INFINITY
+        } else {
+            f64::NAN
+        };
+    }
+
+    let mut w = -log((1.0 - x) * (1.0 + x));
+    let mut p;
+
+    if w < 5.0 {
+        w -= 2.5;
+        p = 2.810_226_36e-08;
+        p = 3.432_739_39e-07 + p * w;
+        p = -3.523_387_7e-06 + p * w;
+        p = -4.391_506_54e-06 + p * w;
+        p = 0.000_218_580_87 + p * w;
+        p = -0.001_253_725_03 + p * w;
+        p = -0.004_177_681_64 + p * w;
+        p = 0.246_640_727 + p * w;
+        p = 1.501_409_41 + p * w;
+    } else {
+        w = sqrt(w) - 3.0;
+        p = -0.000_200_214_257;
+        p = 0.000_100_950_558 + p * w;
+        p = 0.001_349_343_22 + p * w;
+        p = -0.003_673_428_44 + p * w;
+        p = 0.005_739_507_73 + p * w;
+        p = -0.007_622_461_3 + p * w;
+        p = 0.009_438_870_47 + p * w;
+        p = 1.001_674_06 + p * w;
+        p = 2.832_976_82 + p * w;
+    }
+
+    let mut y = p * x;
+
+    // d/dy erf(y) = 2 / sqrt(pi) * exp(-y^2)
+    for _ in 0..2 {
+        let derivative = core::f64::consts::FRAC_2_SQRT_PI * exp(-y * y);
+        y -= (erf(y) - x) / derivative;
+    }
+
+    y
+}
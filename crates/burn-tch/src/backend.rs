@@ -2,7 +2,7 @@ use crate::{PrecisionBridge, QuantElement, TchQTensor};
 
 use super::element::TchElement;
 use super::TchTensor;
-use burn_tensor::backend::{Backend, DeviceId, DeviceOps, SyncType};
+use burn_tensor::backend::{Backend, DeviceId, DeviceOps, DeviceProperties, DeviceType, SyncType};
 use burn_tensor::ops::IntTensorOps;
 use burn_tensor::{Int, Tensor};
 
@@ -68,6 +68,62 @@ impl DeviceOps for LibTorchDevice {
             LibTorchDevice::Vulkan => DeviceId::new(3, 0),
         }
     }
+
+    fn enumerate() -> Vec<Self> {
+        let mut devices = vec![LibTorchDevice::Cpu];
+        devices.extend((0..tch::Cuda::device_count() as usize).map(LibTorchDevice::Cuda));
+
+        if tch::utils::has_mps() {
+            devices.push(LibTorchDevice::Mps);
+        }
+        if tch::utils::has_vulkan() {
+            devices.push(LibTorchDevice::Vulkan);
+        }
+
+        devices
+    }
+
+    fn properties(&self) -> DeviceProperties {
+        // LibTorch doesn't expose the name nor the memory of the devices.
+        match self {
+            LibTorchDevice::Cpu => DeviceProperties {
+                name: "CPU".to_string(),
+                backend_kind: "cpu".to_string(),
+                device_type: DeviceType::Cpu,
+                supports_f16: true,
+                supports_f64: true,
+                supports_bf16: true,
+                ..Default::default()
+            },
+            LibTorchDevice::Cuda(index) => DeviceProperties {
+                name: format!("CUDA device {index}"),
+                vendor: "NVIDIA".to_string(),
+                backend_kind: "cuda".to_string(),
+                device_type: DeviceType::DiscreteGpu,
+                supports_f16: true,
+                supports_f64: true,
+                supports_bf16: true,
+                max_workgroup_size: Some(1024),
+                max_shared_memory_size: Some(48 * 1024),
+                subgroup_support: true,
+                subgroup_size: Some(32),
+                ..Default::default()
+            },
+            LibTorchDevice::Mps => DeviceProperties {
+                name: "Metal Performance Shaders".to_string(),
+                vendor: "Apple".to_string(),
+                backend_kind: "mps".to_string(),
+                device_type: DeviceType::IntegratedGpu,
+                supports_f16: true,
+                ..Default::default()
+            },
+            LibTorchDevice::Vulkan => DeviceProperties {
+                name: "Vulkan".to_string(),
+                backend_kind: "vulkan".to_string(),
+                ..Default::default()
+            },
+        }
+    }
 }
 
 impl Default for LibTorchDevice {
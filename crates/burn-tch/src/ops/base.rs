@@ -134,6 +134,41 @@ impl<E: tch::kind::Element + Copy + Default> TchOps<E> {
         )
     }
 
+    pub fn index_put<const D: usize>(
+        tensor: TchTensor<E, D>,
+        dim: usize,
+        indices: TchTensor<i64, 1>,
+        value: TchTensor<E, D>,
+        accumulate: bool,
+    ) -> TchTensor<E, D> {
+        if accumulate {
+            return Self::select_assign(tensor, dim, indices, value);
+        }
+
+        // LibTorch doesn't define which value is written for repeated indices, so only the last
+        // occurrence of each index is kept, and the values are written in index order.
+        let device = indices.tensor.device();
+        let size = tensor.tensor.size()[dim];
+        let positions = tch::Tensor::arange(indices.tensor.size()[0], (tch::Kind::Int64, device));
+        let last = tch::Tensor::full([size], -1, (tch::Kind::Int64, device)).scatter_reduce(
+            0,
+            &indices.tensor,
+            &positions,
+            "amax",
+            true,
+        );
+        let positions =
+            positions.masked_select(&last.index_select(0, &indices.tensor).eq_tensor(&positions));
+
+        let indices = indices.tensor.index_select(0, &positions);
+        let value = value.tensor.index_select(dim as i64, &positions);
+
+        tensor.unary_ops(
+            |mut tensor| tensor.index_copy_(dim as i64, &indices, &value),
+            |tensor| tensor.index_copy(dim as i64, &indices, &value),
+        )
+    }
+
     pub fn cat<const D: usize>(tensors: Vec<TchTensor<E, D>>, dim: usize) -> TchTensor<E, D> {
         let tensors: Vec<tch::Tensor> = tensors
             .into_iter()
@@ -96,6 +96,45 @@ impl<E: TchElement, Q: QuantElement> BoolTensorOps<Self> for LibTorch<E, Q> {
         )
     }
 
+    fn bool_and<const D: usize>(
+        lhs: TchTensor<bool, D>,
+        rhs: TchTensor<bool, D>,
+    ) -> TchTensor<bool, D> {
+        TchTensor::binary_ops_tensor(
+            lhs,
+            rhs,
+            |lhs, rhs| lhs.f_logical_and_(rhs).unwrap(),
+            |lhs, rhs| rhs.f_logical_and_(lhs).unwrap(),
+            |lhs, rhs| lhs.f_logical_and(rhs).unwrap(),
+        )
+    }
+
+    fn bool_or<const D: usize>(
+        lhs: TchTensor<bool, D>,
+        rhs: TchTensor<bool, D>,
+    ) -> TchTensor<bool, D> {
+        TchTensor::binary_ops_tensor(
+            lhs,
+            rhs,
+            |lhs, rhs| lhs.f_logical_or_(rhs).unwrap(),
+            |lhs, rhs| rhs.f_logical_or_(lhs).unwrap(),
+            |lhs, rhs| lhs.f_logical_or(rhs).unwrap(),
+        )
+    }
+
+    fn bool_xor<const D: usize>(
+        lhs: TchTensor<bool, D>,
+        rhs: TchTensor<bool, D>,
+    ) -> TchTensor<bool, D> {
+        TchTensor::binary_ops_tensor(
+            lhs,
+            rhs,
+            |lhs, rhs| lhs.f_logical_xor_(rhs).unwrap(),
+            |lhs, rhs| rhs.f_logical_xor_(lhs).unwrap(),
+            |lhs, rhs| lhs.f_logical_xor(rhs).unwrap(),
+        )
+    }
+
     fn bool_into_int<const D: usize>(tensor: TchTensor<bool, D>) -> TchTensor<i64, D> {
         let tensor = tensor.tensor.to_kind(tch::Kind::Int64);
         TchTensor::new(tensor)
@@ -1,6 +1,8 @@
 use std::ops::Range;
 
-use burn_tensor::{backend::Backend, ops::IntTensorOps, Distribution, Shape, TensorData};
+use burn_tensor::{
+    backend::Backend, ops::IntTensorOps, shift_mask, Distribution, Shape, TensorData,
+};
 
 use crate::{element::TchElement, LibTorch, LibTorchDevice, QuantElement, TchShape, TchTensor};
 
@@ -189,39 +191,71 @@ impl<E: TchElement, Q: QuantElement> IntTensorOps<Self> for LibTorch<E, Q> {
         lhs: TchTensor<i64, D>,
         rhs: TchTensor<i64, D>,
     ) -> TchTensor<i64, D> {
-        let copy = false;
-        let non_blocking = true;
-        let lhs: TchTensor<f64, D> =
-            TchTensor::new(lhs.tensor.to_dtype(tch::Kind::Float, non_blocking, copy));
-        let rhs: TchTensor<f64, D> =
-            TchTensor::new(rhs.tensor.to_dtype(tch::Kind::Float, non_blocking, copy));
-
-        let out = TchOps::div(lhs, rhs);
-
-        TchTensor::<i64, D>::new(out.tensor.to_dtype(tch::Kind::Int64, non_blocking, copy))
+        // The true division returns floats, which lose the precision of large integers.
+        TchTensor::binary_ops_tensor(
+            lhs,
+            rhs,
+            |lhs, rhs| lhs.f_div_tensor_mode_(rhs, "trunc").unwrap(),
+            |lhs, rhs| lhs.f_div_tensor_mode(rhs, "trunc").unwrap(),
+            |lhs, rhs| lhs.f_div_tensor_mode(rhs, "trunc").unwrap(),
+        )
     }
 
     fn int_div_scalar<const D: usize>(lhs: TchTensor<i64, D>, rhs: i64) -> TchTensor<i64, D> {
-        let copy = false;
-        let non_blocking = true;
-        let lhs: TchTensor<f64, D> =
-            TchTensor::new(lhs.tensor.to_dtype(tch::Kind::Float, non_blocking, copy));
-
-        let out: TchTensor<f64, D> = lhs.unary_ops(
-            |mut tensor| tensor.f_div_scalar_(rhs).unwrap(),
-            |tensor| tensor.f_div_scalar(rhs).unwrap(),
-        );
+        lhs.unary_ops(
+            |mut tensor| tensor.f_div_scalar_mode_(rhs, "trunc").unwrap(),
+            |tensor| tensor.f_div_scalar_mode(rhs, "trunc").unwrap(),
+        )
+    }
 
-        TchTensor::<i64, D>::new(out.tensor.to_dtype(tch::Kind::Int64, non_blocking, copy))
+    fn int_remainder<const D: usize>(
+        lhs: TchTensor<i64, D>,
+        rhs: TchTensor<i64, D>,
+    ) -> TchTensor<i64, D> {
+        // The remainder has the sign of the divisor, so it is Euclidean for a positive divisor.
+        let divisor = TchTensor::<i64, D>::new(rhs.tensor.abs());
+
+        TchTensor::binary_ops_tensor(
+            lhs,
+            divisor,
+            |lhs, rhs| lhs.f_remainder_tensor_(rhs).unwrap(),
+            |lhs, rhs| lhs.f_remainder_tensor(rhs).unwrap(),
+            |lhs, rhs| lhs.f_remainder_tensor(rhs).unwrap(),
+        )
     }
 
     fn int_remainder_scalar<const D: usize>(lhs: TchTensor<i64, D>, rhs: i64) -> TchTensor<i64, D> {
+        let divisor = rhs.abs();
+
         lhs.unary_ops(
-            |tensor| tensor.f_remainder(rhs).unwrap(),
-            |tensor| tensor.f_remainder(rhs).unwrap(),
+            |tensor| tensor.f_remainder(divisor).unwrap(),
+            |tensor| tensor.f_remainder(divisor).unwrap(),
         )
     }
 
+    fn int_powi<const D: usize>(
+        lhs: TchTensor<i64, D>,
+        rhs: TchTensor<i64, D>,
+    ) -> TchTensor<i64, D> {
+        // LibTorch refuses negative integer powers, which are rounded toward zero instead, so that
+        // only the powers of 1 and -1 are kept.
+        let power = lhs.tensor.pow(&rhs.tensor.abs());
+        let keep = rhs.tensor.ge(0).logical_or(&lhs.tensor.abs().eq(1));
+
+        TchTensor::new(power.where_self(&keep, &power.zeros_like()))
+    }
+
+    fn int_powi_scalar<const D: usize>(lhs: TchTensor<i64, D>, rhs: i64) -> TchTensor<i64, D> {
+        let power = lhs.tensor.pow_tensor_scalar(rhs.abs());
+
+        if rhs >= 0 {
+            return TchTensor::new(power);
+        }
+
+        let keep = lhs.tensor.abs().eq(1);
+        TchTensor::new(power.where_self(&keep, &power.zeros_like()))
+    }
+
     fn int_neg<const D: usize>(tensor: TchTensor<i64, D>) -> TchTensor<i64, D> {
         Self::int_mul_scalar(tensor, -1)
     }
@@ -328,6 +362,16 @@ impl<E: TchElement, Q: QuantElement> IntTensorOps<Self> for LibTorch<E, Q> {
         TchOps::select_assign(tensor, dim, indices, value)
     }
 
+    fn int_index_put<const D: usize>(
+        tensor: TchTensor<i64, D>,
+        dim: usize,
+        indices: TchTensor<i64, 1>,
+        values: TchTensor<i64, D>,
+        accumulate: bool,
+    ) -> TchTensor<i64, D> {
+        TchOps::index_put(tensor, dim, indices, values, accumulate)
+    }
+
     fn int_mask_where<const D: usize>(
         tensor: TchTensor<i64, D>,
         mask: TchTensor<bool, D>,
@@ -515,4 +559,130 @@ impl<E: TchElement, Q: QuantElement> IntTensorOps<Self> for LibTorch<E, Q> {
     ) -> <LibTorch<E> as Backend>::IntTensorPrimitive<D> {
         TchOps::argsort(tensor, dim, descending)
     }
+
+    fn int_bitwise_and<const D: usize>(
+        lhs: TchTensor<i64, D>,
+        rhs: TchTensor<i64, D>,
+    ) -> TchTensor<i64, D> {
+        TchTensor::binary_ops_tensor(
+            lhs,
+            rhs,
+            |lhs, rhs| lhs.f_bitwise_and_tensor_(rhs).unwrap(),
+            |lhs, rhs| rhs.f_bitwise_and_tensor_(lhs).unwrap(),
+            |lhs, rhs| lhs.f_bitwise_and_tensor(rhs).unwrap(),
+        )
+    }
+
+    fn int_bitwise_and_scalar<const D: usize>(
+        lhs: TchTensor<i64, D>,
+        rhs: i64,
+    ) -> TchTensor<i64, D> {
+        lhs.unary_ops(
+            |mut tensor| tensor.f_bitwise_and_(rhs).unwrap(),
+            |tensor| tensor.f_bitwise_and(rhs).unwrap(),
+        )
+    }
+
+    fn int_bitwise_or<const D: usize>(
+        lhs: TchTensor<i64, D>,
+        rhs: TchTensor<i64, D>,
+    ) -> TchTensor<i64, D> {
+        TchTensor::binary_ops_tensor(
+            lhs,
+            rhs,
+            |lhs, rhs| lhs.f_bitwise_or_tensor_(rhs).unwrap(),
+            |lhs, rhs| rhs.f_bitwise_or_tensor_(lhs).unwrap(),
+            |lhs, rhs| lhs.f_bitwise_or_tensor(rhs).unwrap(),
+        )
+    }
+
+    fn int_bitwise_or_scalar<const D: usize>(
+        lhs: TchTensor<i64, D>,
+        rhs: i64,
+    ) -> TchTensor<i64, D> {
+        lhs.unary_ops(
+            |mut tensor| tensor.f_bitwise_or_(rhs).unwrap(),
+            |tensor| tensor.f_bitwise_or(rhs).unwrap(),
+        )
+    }
+
+    fn int_bitwise_xor<const D: usize>(
+        lhs: TchTensor<i64, D>,
+        rhs: TchTensor<i64, D>,
+    ) -> TchTensor<i64, D> {
+        TchTensor::binary_ops_tensor(
+            lhs,
+            rhs,
+            |lhs, rhs| lhs.f_bitwise_xor_tensor_(rhs).unwrap(),
+            |lhs, rhs| rhs.f_bitwise_xor_tensor_(lhs).unwrap(),
+            |lhs, rhs| lhs.f_bitwise_xor_tensor(rhs).unwrap(),
+        )
+    }
+
+    fn int_bitwise_xor_scalar<const D: usize>(
+        lhs: TchTensor<i64, D>,
+        rhs: i64,
+    ) -> TchTensor<i64, D> {
+        lhs.unary_ops(
+            |mut tensor| tensor.f_bitwise_xor_(rhs).unwrap(),
+            |tensor| tensor.f_bitwise_xor(rhs).unwrap(),
+        )
+    }
+
+    fn int_bitwise_left_shift<const D: usize>(
+        lhs: TchTensor<i64, D>,
+        rhs: TchTensor<i64, D>,
+    ) -> TchTensor<i64, D> {
+        let rhs = Self::int_bitwise_and_scalar(rhs, shift_mask::<i64>());
+        TchTensor::binary_ops_tensor(
+            lhs,
+            rhs,
+            |lhs, rhs| lhs.f_bitwise_left_shift_(rhs).unwrap(),
+            |lhs, rhs| lhs.f_bitwise_left_shift(rhs).unwrap(),
+            |lhs, rhs| lhs.f_bitwise_left_shift(rhs).unwrap(),
+        )
+    }
+
+    fn int_bitwise_left_shift_scalar<const D: usize>(
+        lhs: TchTensor<i64, D>,
+        rhs: i64,
+    ) -> TchTensor<i64, D> {
+        let rhs = rhs & shift_mask::<i64>();
+        lhs.unary_ops(
+            |mut tensor| tensor.f_bitwise_left_shift_tensor_scalar_(rhs).unwrap(),
+            |tensor| tensor.f_bitwise_left_shift_tensor_scalar(rhs).unwrap(),
+        )
+    }
+
+    fn int_bitwise_right_shift<const D: usize>(
+        lhs: TchTensor<i64, D>,
+        rhs: TchTensor<i64, D>,
+    ) -> TchTensor<i64, D> {
+        let rhs = Self::int_bitwise_and_scalar(rhs, shift_mask::<i64>());
+        TchTensor::binary_ops_tensor(
+            lhs,
+            rhs,
+            |lhs, rhs| lhs.f_bitwise_right_shift_(rhs).unwrap(),
+            |lhs, rhs| lhs.f_bitwise_right_shift(rhs).unwrap(),
+            |lhs, rhs| lhs.f_bitwise_right_shift(rhs).unwrap(),
+        )
+    }
+
+    fn int_bitwise_right_shift_scalar<const D: usize>(
+        lhs: TchTensor<i64, D>,
+        rhs: i64,
+    ) -> TchTensor<i64, D> {
+        let rhs = rhs & shift_mask::<i64>();
+        lhs.unary_ops(
+            |mut tensor| tensor.f_bitwise_right_shift_tensor_scalar_(rhs).unwrap(),
+            |tensor| tensor.f_bitwise_right_shift_tensor_scalar(rhs).unwrap(),
+        )
+    }
+
+    fn int_bitwise_not<const D: usize>(tensor: TchTensor<i64, D>) -> TchTensor<i64, D> {
+        tensor.unary_ops(
+            |mut tensor| tensor.f_bitwise_not_().unwrap(),
+            |tensor| tensor.f_bitwise_not().unwrap(),
+        )
+    }
 }
@@ -152,13 +152,14 @@ impl<E: TchElement, Q: QuantElement> ModuleOps<Self> for LibTorch<E, Q> {
         stride: usize,
         padding: usize,
         count_include_pad: bool,
+        ceil_mode: bool,
     ) -> TchTensor<E, 3> {
         let tensor = tch::Tensor::avg_pool1d(
             &x.tensor,
             [kernel_size as i64],
             [stride as i64],
             [padding as i64],
-            false,
+            ceil_mode,
             count_include_pad,
         );
 
@@ -170,13 +171,14 @@ impl<E: TchElement, Q: QuantElement> ModuleOps<Self> for LibTorch<E, Q> {
         stride: [usize; 2],
         padding: [usize; 2],
         count_include_pad: bool,
+        ceil_mode: bool,
     ) -> TchTensor<E, 4> {
         let tensor = tch::Tensor::avg_pool2d(
             &x.tensor,
             [kernel_size[0] as i64, kernel_size[1] as i64],
             [stride[0] as i64, stride[1] as i64],
             [padding[0] as i64, padding[1] as i64],
-            false,
+            ceil_mode,
             count_include_pad,
             None,
         );
@@ -191,6 +193,7 @@ impl<E: TchElement, Q: QuantElement> ModuleOps<Self> for LibTorch<E, Q> {
         stride: [usize; 2],
         padding: [usize; 2],
         count_include_pad: bool,
+        ceil_mode: bool,
     ) -> TchTensor<E, 4> {
         let tensor = tch::Tensor::avg_pool2d_backward(
             &x.tensor,
@@ -198,7 +201,7 @@ impl<E: TchElement, Q: QuantElement> ModuleOps<Self> for LibTorch<E, Q> {
             [kernel_size[0] as i64, kernel_size[1] as i64],
             [stride[0] as i64, stride[1] as i64],
             [padding[0] as i64, padding[1] as i64],
-            false,
+            ceil_mode,
             count_include_pad,
             None,
         );
@@ -212,6 +215,7 @@ impl<E: TchElement, Q: QuantElement> ModuleOps<Self> for LibTorch<E, Q> {
         stride: usize,
         padding: usize,
         dilation: usize,
+        ceil_mode: bool,
     ) -> TchTensor<E, 3> {
         let tensor = tch::Tensor::max_pool1d(
             &x.tensor,
@@ -219,7 +223,7 @@ impl<E: TchElement, Q: QuantElement> ModuleOps<Self> for LibTorch<E, Q> {
             stride as i64,
             padding as i64,
             dilation as i64,
-            false,
+            ceil_mode,
         );
 
         TchTensor::new(tensor)
@@ -231,6 +235,7 @@ impl<E: TchElement, Q: QuantElement> ModuleOps<Self> for LibTorch<E, Q> {
         stride: usize,
         padding: usize,
         dilation: usize,
+        ceil_mode: bool,
     ) -> MaxPool1dWithIndices<LibTorch<E, Q>> {
         let (tensor, indices) = tch::Tensor::max_pool1d_with_indices(
             &x.tensor,
@@ -238,7 +243,7 @@ impl<E: TchElement, Q: QuantElement> ModuleOps<Self> for LibTorch<E, Q> {
             stride as i64,
             padding as i64,
             dilation as i64,
-            false,
+            ceil_mode,
         );
 
         MaxPool1dWithIndices::new(TchTensor::new(tensor), TchTensor::new(indices))
@@ -250,6 +255,7 @@ impl<E: TchElement, Q: QuantElement> ModuleOps<Self> for LibTorch<E, Q> {
         stride: [usize; 2],
         padding: [usize; 2],
         dilation: [usize; 2],
+        ceil_mode: bool,
     ) -> TchTensor<E, 4> {
         let tensor = tch::Tensor::max_pool2d(
             &x.tensor,
@@ -257,7 +263,7 @@ impl<E: TchElement, Q: QuantElement> ModuleOps<Self> for LibTorch<E, Q> {
             [stride[0] as i64, stride[1] as i64],
             [padding[0] as i64, padding[1] as i64],
             [dilation[0] as i64, dilation[1] as i64],
-            false,
+            ceil_mode,
         );
 
         TchTensor::new(tensor)
@@ -269,6 +275,7 @@ impl<E: TchElement, Q: QuantElement> ModuleOps<Self> for LibTorch<E, Q> {
         stride: [usize; 2],
         padding: [usize; 2],
         dilation: [usize; 2],
+        ceil_mode: bool,
     ) -> MaxPool2dWithIndices<LibTorch<E, Q>> {
         let (tensor, indices) = tch::Tensor::max_pool2d_with_indices(
             &x.tensor,
@@ -276,7 +283,7 @@ impl<E: TchElement, Q: QuantElement> ModuleOps<Self> for LibTorch<E, Q> {
             [stride[0] as i64, stride[1] as i64],
             [padding[0] as i64, padding[1] as i64],
             [dilation[0] as i64, dilation[1] as i64],
-            false,
+            ceil_mode,
         );
 
         MaxPool2dWithIndices::new(TchTensor::new(tensor), TchTensor::new(indices))
@@ -288,6 +295,7 @@ impl<E: TchElement, Q: QuantElement> ModuleOps<Self> for LibTorch<E, Q> {
         stride: [usize; 2],
         padding: [usize; 2],
         dilation: [usize; 2],
+        ceil_mode: bool,
         output_grad: TchTensor<E, 4>,
         indices: TchTensor<i64, 4>,
     ) -> MaxPool2dBackward<LibTorch<E, Q>> {
@@ -298,7 +306,7 @@ impl<E: TchElement, Q: QuantElement> ModuleOps<Self> for LibTorch<E, Q> {
             [stride[0] as i64, stride[1] as i64],
             [padding[0] as i64, padding[1] as i64],
             [dilation[0] as i64, dilation[1] as i64],
-            false,
+            ceil_mode,
             &indices.tensor,
         );
 
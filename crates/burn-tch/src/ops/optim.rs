@@ -0,0 +1,4 @@
+use crate::{element::TchElement, LibTorch, QuantElement};
+use burn_tensor::ops::OptimizerOps;
+
+impl<E: TchElement, Q: QuantElement> OptimizerOps<Self> for LibTorch<E, Q> {}
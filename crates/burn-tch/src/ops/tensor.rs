@@ -223,6 +223,16 @@ impl<E: TchElement, Q: QuantElement> FloatTensorOps<Self> for LibTorch<E, Q> {
         TchOps::select_assign(tensor, dim, indices, value)
     }
 
+    fn float_index_put<const D: usize>(
+        tensor: TchTensor<E, D>,
+        dim: usize,
+        indices: TchTensor<i64, 1>,
+        values: TchTensor<E, D>,
+        accumulate: bool,
+    ) -> TchTensor<E, D> {
+        TchOps::index_put(tensor, dim, indices, values, accumulate)
+    }
+
     fn float_slice<const D1: usize, const D2: usize>(
         tensor: TchTensor<E, D1>,
         ranges: [Range<usize>; D2],
@@ -416,6 +426,10 @@ impl<E: TchElement, Q: QuantElement> FloatTensorOps<Self> for LibTorch<E, Q> {
         tensor.unary_ops(|mut tensor| tensor.erf_(), |tensor| tensor.erf())
     }
 
+    fn float_erfinv<const D: usize>(tensor: TchTensor<E, D>) -> TchTensor<E, D> {
+        tensor.unary_ops(|mut tensor| tensor.erfinv_(), |tensor| tensor.erfinv())
+    }
+
     fn float_cat<const D: usize>(tensors: Vec<TchTensor<E, D>>, dim: usize) -> TchTensor<E, D> {
         TchOps::cat(tensors, dim)
     }
@@ -498,6 +512,21 @@ impl<E: TchElement, Q: QuantElement> FloatTensorOps<Self> for LibTorch<E, Q> {
         TchOps::expand(tensor, shape)
     }
 
+    fn float_multinomial(
+        tensor: TchTensor<E, 2>,
+        num_samples: usize,
+        replacement: bool,
+    ) -> TchTensor<i64, 2> {
+        // LibTorch rejects rows without any weight, which are sampled uniformly instead.
+        let empty = tensor
+            .tensor
+            .sum_dim_intlist(Some([1i64].as_slice()), true, E::KIND)
+            .eq(0);
+        let weights = tensor.tensor.ones_like().where_self(&empty, &tensor.tensor);
+
+        TchTensor::new(weights.multinomial(num_samples as i64, replacement))
+    }
+
     fn float_sort<const D: usize>(
         tensor: <LibTorch<E> as Backend>::FloatTensorPrimitive<D>,
         dim: usize,
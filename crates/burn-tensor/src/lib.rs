@@ -55,7 +55,21 @@ mod cube {
 #[cfg(feature = "cubecl-wgpu")]
 mod cube_wgpu {
     use crate::backend::{DeviceId, DeviceOps};
+    #[cfg(not(target_family = "wasm"))]
+    use crate::backend::{DeviceProperties, DeviceType};
+    #[cfg(not(target_family = "wasm"))]
+    use alloc::{
+        format,
+        string::{String, ToString},
+        vec::Vec,
+    };
     use cubecl::wgpu::WgpuDevice;
+    #[cfg(not(target_family = "wasm"))]
+    use cubecl::wgpu::{AutoGraphicsApi, GraphicsApi};
+    #[cfg(not(target_family = "wasm"))]
+    extern crate std;
+    #[cfg(not(target_family = "wasm"))]
+    use std::sync::OnceLock;
 
     impl DeviceOps for WgpuDevice {
         fn id(&self) -> DeviceId {
@@ -74,17 +88,240 @@ mod cube_wgpu {
                 }
             }
         }
+
+        #[cfg(not(target_family = "wasm"))]
+        fn enumerate() -> Vec<Self> {
+            let mut counts = [0; 5];
+            let mut devices = Vec::new();
+
+            for adapter in adapters() {
+                let device_type = adapter.device_type;
+                let count = &mut counts[device_type as usize];
+                let index = *count;
+                *count += 1;
+
+                let device = match device_type {
+                    wgpu::DeviceType::DiscreteGpu => WgpuDevice::DiscreteGpu(index),
+                    wgpu::DeviceType::IntegratedGpu => WgpuDevice::IntegratedGpu(index),
+                    wgpu::DeviceType::VirtualGpu => WgpuDevice::VirtualGpu(index),
+                    // Only the first CPU adapter can be selected.
+                    wgpu::DeviceType::Cpu if index == 0 => WgpuDevice::Cpu,
+                    wgpu::DeviceType::Cpu | wgpu::DeviceType::Other => continue,
+                };
+                devices.push(device);
+            }
+
+            devices
+        }
+
+        #[cfg(not(target_family = "wasm"))]
+        fn properties(&self) -> DeviceProperties {
+            let adapters = adapters();
+            let of_type = |device_type: wgpu::DeviceType| {
+                adapters
+                    .iter()
+                    .filter(move |adapter| adapter.device_type == device_type)
+            };
+
+            // Mirrors the adapter selection of the runtime initialized with its default graphics
+            // API.
+            let adapter = match self {
+                WgpuDevice::DiscreteGpu(index) => {
+                    of_type(wgpu::DeviceType::DiscreteGpu).nth(*index)
+                }
+                WgpuDevice::IntegratedGpu(index) => {
+                    of_type(wgpu::DeviceType::IntegratedGpu).nth(*index)
+                }
+                WgpuDevice::VirtualGpu(index) => of_type(wgpu::DeviceType::VirtualGpu).nth(*index),
+                WgpuDevice::Cpu => of_type(wgpu::DeviceType::Cpu).next(),
+                // The first adapter with the best score, the other adapters being preferred to
+                // the integrated GPUs.
+                WgpuDevice::BestAvailable => adapters.iter().fold(None, |best, adapter| {
+                    let score = |adapter: &Adapter| match adapter.device_type {
+                        wgpu::DeviceType::DiscreteGpu => 5,
+                        wgpu::DeviceType::Other => 4,
+                        wgpu::DeviceType::IntegratedGpu => 3,
+                        wgpu::DeviceType::VirtualGpu => 2,
+                        wgpu::DeviceType::Cpu => 1,
+                    };
+                    match best {
+                        Some(best) if score(best) >= score(adapter) => Some(best),
+                        _ => Some(adapter),
+                    }
+                }),
+                // The adapter of an existing device isn't known.
+                WgpuDevice::Existing(_) => None,
+            };
+
+            adapter
+                .map(|adapter| adapter.properties.clone())
+                .unwrap_or_default()
+        }
+    }
+
+    /// An adapter the runtime selects from.
+    #[cfg(not(target_family = "wasm"))]
+    struct Adapter {
+        device_type: wgpu::DeviceType,
+        properties: DeviceProperties,
+    }
+
+    /// The adapters the runtime selects from, which are those of its default graphics API only.
+    ///
+    /// Creating an instance and querying its adapters is costly, so it is only done once.
+    #[cfg(not(target_family = "wasm"))]
+    fn adapters() -> &'static [Adapter] {
+        static ADAPTERS: OnceLock<Vec<Adapter>> = OnceLock::new();
+
+        ADAPTERS.get_or_init(|| {
+            wgpu::Instance::default()
+                .enumerate_adapters(AutoGraphicsApi::backend().into())
+                .iter()
+                .map(|adapter| Adapter {
+                    device_type: adapter.get_info().device_type,
+                    properties: properties(adapter),
+                })
+                .collect()
+        })
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn properties(adapter: &wgpu::Adapter) -> DeviceProperties {
+        let info = adapter.get_info();
+        let features = adapter.features();
+        let limits = adapter.limits();
+
+        DeviceProperties {
+            name: info.name,
+            vendor: vendor(info.vendor),
+            driver_version: format!("{} {}", info.driver, info.driver_info)
+                .trim()
+                .to_string(),
+            backend_kind: format!("{:?}", info.backend).to_lowercase(),
+            device_type: match info.device_type {
+                wgpu::DeviceType::DiscreteGpu => DeviceType::DiscreteGpu,
+                wgpu::DeviceType::IntegratedGpu => DeviceType::IntegratedGpu,
+                wgpu::DeviceType::VirtualGpu => DeviceType::VirtualGpu,
+                wgpu::DeviceType::Cpu => DeviceType::Cpu,
+                wgpu::DeviceType::Other => DeviceType::Other,
+            },
+            // Not exposed by wgpu, whose memory limits are per buffer.
+            total_memory: None,
+            supports_f16: features.contains(wgpu::Features::SHADER_F16),
+            supports_f64: features.contains(wgpu::Features::SHADER_F64),
+            // WGSL has no brain float type.
+            supports_bf16: false,
+            supports_atomic_64: features.contains(wgpu::Features::SHADER_INT64_ATOMIC_ALL_OPS),
+            max_workgroup_size: Some(limits.max_compute_invocations_per_workgroup),
+            max_shared_memory_size: Some(limits.max_compute_workgroup_storage_size),
+            subgroup_support: features.contains(wgpu::Features::SUBGROUP),
+            // The size of the subgroups can vary between the kernels of some devices, in which
+            // case the kernels can't rely on it.
+            subgroup_size: Some(limits.min_subgroup_size)
+                .filter(|size| *size > 0 && *size == limits.max_subgroup_size),
+        }
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn vendor(id: u32) -> String {
+        match id {
+            0x1002 => "AMD".to_string(),
+            0x106B => "Apple".to_string(),
+            0x10DE => "NVIDIA".to_string(),
+            0x13B5 => "ARM".to_string(),
+            0x5143 => "Qualcomm".to_string(),
+            0x8086 => "Intel".to_string(),
+            id => format!("{id:#06x}"),
+        }
     }
 }
 
 #[cfg(feature = "cubecl-cuda")]
 mod cube_cuda {
-    use crate::backend::{DeviceId, DeviceOps};
+    use crate::backend::{DeviceId, DeviceOps, DeviceProperties, DeviceType};
+    use alloc::{
+        format,
+        string::{String, ToString},
+    };
     use cubecl::cuda::CudaDevice;
+    use cudarc::driver::{
+        result,
+        sys::{self, CUdevice, CUdevice_attribute},
+    };
 
     impl DeviceOps for CudaDevice {
         fn id(&self) -> DeviceId {
             DeviceId::new(0, self.index as u32)
         }
+
+        fn properties(&self) -> DeviceProperties {
+            // Without a driver the runtime can't launch anything, so the defaults are reported.
+            let device = device(self.index);
+            let attribute =
+                |attribute| device.and_then(|device| self::attribute(device, attribute));
+            let compute_capability =
+                attribute(CUdevice_attribute::CU_DEVICE_ATTRIBUTE_COMPUTE_CAPABILITY_MAJOR).zip(
+                    attribute(CUdevice_attribute::CU_DEVICE_ATTRIBUTE_COMPUTE_CAPABILITY_MINOR),
+                );
+
+            DeviceProperties {
+                name: device
+                    .and_then(|device| result::device::get_name(device).ok())
+                    .unwrap_or_else(|| format!("CUDA device {}", self.index)),
+                vendor: "NVIDIA".to_string(),
+                driver_version: driver_version().unwrap_or_default(),
+                backend_kind: "cuda".to_string(),
+                device_type: match attribute(CUdevice_attribute::CU_DEVICE_ATTRIBUTE_INTEGRATED) {
+                    Some(1) => DeviceType::IntegratedGpu,
+                    _ => DeviceType::DiscreteGpu,
+                },
+                // Safety: the device handle was returned by the initialized driver.
+                total_memory: device
+                    .and_then(|device| unsafe { result::device::total_mem(device) }.ok())
+                    .map(|bytes| bytes as u64),
+                // The half precision arithmetic needs the compute capability 5.3, the brain
+                // floats 8.0.
+                supports_f16: compute_capability >= Some((5, 3)),
+                supports_f64: true,
+                supports_bf16: compute_capability >= Some((8, 0)),
+                // The 64 bit `atomicMin` and `atomicMax` need the compute capability 3.5.
+                supports_atomic_64: compute_capability >= Some((3, 5)),
+                max_workgroup_size: attribute(
+                    CUdevice_attribute::CU_DEVICE_ATTRIBUTE_MAX_THREADS_PER_BLOCK,
+                ),
+                // The limit of a launch without opting in to the larger carveout of the device,
+                // which the runtime doesn't do.
+                max_shared_memory_size: attribute(
+                    CUdevice_attribute::CU_DEVICE_ATTRIBUTE_MAX_SHARED_MEMORY_PER_BLOCK,
+                ),
+                subgroup_support: true,
+                subgroup_size: attribute(CUdevice_attribute::CU_DEVICE_ATTRIBUTE_WARP_SIZE),
+            }
+        }
+    }
+
+    /// The handle of the device from the driver, [None] when the driver can't be loaded.
+    fn device(index: usize) -> Option<CUdevice> {
+        result::init().ok()?;
+        result::device::get(index as i32).ok()
+    }
+
+    fn attribute(device: CUdevice, attribute: CUdevice_attribute) -> Option<u32> {
+        // Safety: the device handle was returned by the initialized driver.
+        let value = unsafe { result::device::get_attribute(device, attribute) }.ok()?;
+
+        u32::try_from(value).ok()
+    }
+
+    /// The version of the driver, formatted as `major.minor`.
+    fn driver_version() -> Option<String> {
+        result::init().ok()?;
+        let mut version = 0;
+        // Safety: the driver is initialized and only writes the version to the pointer.
+        unsafe { sys::lib().cuDriverGetVersion(&mut version) }
+            .result()
+            .ok()?;
+
+        Some(format!("{}.{}", version / 1000, (version % 1000) / 10))
     }
 }
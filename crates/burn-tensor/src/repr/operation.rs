@@ -4,7 +4,7 @@ use std::ops::Range;
 use crate::{
     ops::{ConvOptions, ConvTransposeOptions, InterpolateMode, InterpolateOptions},
     repr::tensor::TensorDescription,
-    Distribution, Element,
+    Distribution, Element, PadMode,
 };
 
 /// Describe all tensor operations possible.
@@ -41,6 +41,8 @@ pub enum FloatOperationDescription {
     Log1p(UnaryOperationDescription),
     /// Operation corresponding to [erf](crate::ops::FloatTensorOps::float_erf).
     Erf(UnaryOperationDescription),
+    /// Operation corresponding to [erfinv](crate::ops::FloatTensorOps::float_erfinv).
+    Erfinv(UnaryOperationDescription),
     /// Operation corresponding to [powf_scalar](crate::ops::FloatTensorOps::float_powf_scalar).
     PowfScalar(ScalarOperationDescription<f32>),
     /// Operation corresponding to [sqrt](crate::ops::FloatTensorOps::float_sqrt).
@@ -59,6 +61,25 @@ pub enum FloatOperationDescription {
     Random(RandomOperationDescription),
     /// Operation corresponding to [recip](crate::ops::FloatTensorOps::float_recip).
     Recip(UnaryOperationDescription),
+    /// Operation corresponding to [spmm](crate::ops::FloatTensorOps::float_spmm).
+    Spmm(SpmmDescription),
+}
+
+/// Sparse-dense matrix multiplication operation description.
+#[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
+pub struct SpmmDescription {
+    /// The rows of the values of the sparse matrix.
+    pub rows: TensorDescription,
+    /// The columns of the values of the sparse matrix.
+    pub cols: TensorDescription,
+    /// The values of the sparse matrix.
+    pub values: TensorDescription,
+    /// The dense matrix.
+    pub dense: TensorDescription,
+    /// The number of rows of the sparse matrix.
+    pub num_rows: usize,
+    /// Output tensor description.
+    pub out: TensorDescription,
 }
 
 /// Operation description specific to module.
@@ -68,6 +89,8 @@ pub enum ModuleOperationDescription {
     Embedding(EmbeddingDescription),
     /// Operation corresponding to [embedding_backward](crate::ops::ModuleOps::embedding_backward).
     EmbeddingBackward(EmbeddingBackwardDescription),
+    /// Operation corresponding to [dropout](crate::ops::ModuleOps::dropout).
+    Dropout(DropoutDescription),
     /// Operation corresponding to [conv1d](crate::ops::ModuleOps::conv1d).
     Conv1d(Conv1dDescription),
     /// Operation corresponding to [conv2d](crate::ops::ModuleOps::conv2d).
@@ -433,6 +456,26 @@ pub enum NumericOperationDescription<E> {
     /// Float => [powf](crate::ops::FloatTensorOps::float_powf).
     /// Int => [powf](crate::ops::IntTensorOps::int_powf).
     Powf(BinaryOperationDescription),
+    /// Operation corresponding to:
+    ///
+    /// Float => [pad](crate::ops::FloatTensorOps::float_pad).
+    /// Int => [pad](crate::ops::IntTensorOps::int_pad).
+    Pad(PadOperationDescription<E>),
+    /// Operation corresponding to:
+    ///
+    /// Float => [count nonzero](crate::ops::FloatTensorOps::float_count_nonzero).
+    /// Int => [count nonzero](crate::ops::IntTensorOps::int_count_nonzero).
+    CountNonzero(ScalarOperationDescription<usize>),
+    /// Operation corresponding to:
+    ///
+    /// Float => [is close](crate::ops::FloatTensorOps::float_is_close).
+    /// Int => [is close](crate::ops::IntTensorOps::int_is_close).
+    IsClose(CloseOperationDescription),
+    /// Operation corresponding to:
+    ///
+    /// Float => [all close](crate::ops::FloatTensorOps::float_all_close).
+    /// Int => [all close](crate::ops::IntTensorOps::int_all_close).
+    AllClose(CloseOperationDescription),
 }
 
 /// Operation description specific to an int tensor.
@@ -440,6 +483,28 @@ pub enum NumericOperationDescription<E> {
 pub enum IntOperationDescription {
     /// Operation corresponding to [into float](crate::ops::IntTensorOps::int_into_float).
     IntoFloat(UnaryOperationDescription),
+    /// Operation corresponding to [bitwise and](crate::ops::IntTensorOps::int_bitwise_and).
+    BitwiseAnd(BinaryOperationDescription),
+    /// Operation corresponding to [bitwise and scalar](crate::ops::IntTensorOps::int_bitwise_and_scalar).
+    BitwiseAndScalar(ScalarOperationDescription<i32>),
+    /// Operation corresponding to [bitwise or](crate::ops::IntTensorOps::int_bitwise_or).
+    BitwiseOr(BinaryOperationDescription),
+    /// Operation corresponding to [bitwise or scalar](crate::ops::IntTensorOps::int_bitwise_or_scalar).
+    BitwiseOrScalar(ScalarOperationDescription<i32>),
+    /// Operation corresponding to [bitwise xor](crate::ops::IntTensorOps::int_bitwise_xor).
+    BitwiseXor(BinaryOperationDescription),
+    /// Operation corresponding to [bitwise xor scalar](crate::ops::IntTensorOps::int_bitwise_xor_scalar).
+    BitwiseXorScalar(ScalarOperationDescription<i32>),
+    /// Operation corresponding to [bitwise not](crate::ops::IntTensorOps::int_bitwise_not).
+    BitwiseNot(UnaryOperationDescription),
+    /// Operation corresponding to [bitwise left shift](crate::ops::IntTensorOps::int_bitwise_left_shift).
+    BitwiseLeftShift(BinaryOperationDescription),
+    /// Operation corresponding to [bitwise left shift scalar](crate::ops::IntTensorOps::int_bitwise_left_shift_scalar).
+    BitwiseLeftShiftScalar(ScalarOperationDescription<i32>),
+    /// Operation corresponding to [bitwise right shift](crate::ops::IntTensorOps::int_bitwise_right_shift).
+    BitwiseRightShift(BinaryOperationDescription),
+    /// Operation corresponding to [bitwise right shift scalar](crate::ops::IntTensorOps::int_bitwise_right_shift_scalar).
+    BitwiseRightShiftScalar(ScalarOperationDescription<i32>),
 }
 
 /// Operation description specific to a bool tensor.
@@ -451,6 +516,23 @@ pub enum BoolOperationDescription {
     IntoInt(UnaryOperationDescription),
     /// Operation corresponding to [not](crate::ops::BoolTensorOps::bool_not).
     Not(UnaryOperationDescription),
+    /// Operation corresponding to [argwhere padded](crate::ops::BoolTensorOps::bool_argwhere_padded).
+    ArgwherePadded(ArgwherePaddedDescription),
+    /// Operation corresponding to [count nonzero](crate::ops::BoolTensorOps::bool_count_nonzero).
+    CountNonzero(ScalarOperationDescription<usize>),
+}
+
+/// Argwhere padded operation description.
+#[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
+pub struct ArgwherePaddedDescription {
+    /// Input tensor description.
+    pub input: TensorDescription,
+    /// The number of rows of the indices.
+    pub max_count: usize,
+    /// Output indices description.
+    pub out_indices: TensorDescription,
+    /// Output count description.
+    pub out_count: TensorDescription,
 }
 
 /// Swap dim operation description.
@@ -616,6 +698,25 @@ pub struct MaskFillOperationDescription<E> {
     pub out: TensorDescription,
 }
 
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[allow(missing_docs)]
+pub struct PadOperationDescription<E> {
+    pub tensor: TensorDescription,
+    pub padding: Vec<(isize, isize)>,
+    pub mode: PadMode<E>,
+    pub out: TensorDescription,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[allow(missing_docs)]
+pub struct CloseOperationDescription {
+    pub lhs: TensorDescription,
+    pub rhs: TensorDescription,
+    pub rtol: f64,
+    pub atol: f64,
+    pub out: TensorDescription,
+}
+
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 #[allow(missing_docs)]
 pub struct ClampOperationDescription<E> {
@@ -668,6 +769,14 @@ pub struct EmbeddingBackwardDescription {
     pub out: TensorDescription,
 }
 
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[allow(missing_docs)]
+pub struct DropoutDescription {
+    pub x: TensorDescription,
+    pub prob: f64,
+    pub out: TensorDescription,
+}
+
 #[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
 #[allow(missing_docs)]
 pub struct Conv1dDescription {
@@ -931,6 +1040,7 @@ pub struct AvgPool1dDescription {
     pub stride: usize,
     pub padding: usize,
     pub count_include_pad: bool,
+    pub ceil_mode: bool,
     pub out: TensorDescription,
 }
 
@@ -942,6 +1052,7 @@ pub struct AvgPool2dDescription {
     pub stride: [usize; 2],
     pub padding: [usize; 2],
     pub count_include_pad: bool,
+    pub ceil_mode: bool,
     pub out: TensorDescription,
 }
 
@@ -954,6 +1065,7 @@ pub struct AvgPool1dBackwardDescription {
     pub stride: usize,
     pub padding: usize,
     pub count_include_pad: bool,
+    pub ceil_mode: bool,
     pub out: TensorDescription,
 }
 
@@ -966,6 +1078,7 @@ pub struct AvgPool2dBackwardDescription {
     pub stride: [usize; 2],
     pub padding: [usize; 2],
     pub count_include_pad: bool,
+    pub ceil_mode: bool,
     pub out: TensorDescription,
 }
 
@@ -1009,6 +1122,7 @@ pub struct MaxPool1dDescription {
     pub stride: usize,
     pub padding: usize,
     pub dilation: usize,
+    pub ceil_mode: bool,
     pub out: TensorDescription,
 }
 
@@ -1020,6 +1134,7 @@ pub struct MaxPool1dWithIndicesDescription {
     pub stride: usize,
     pub padding: usize,
     pub dilation: usize,
+    pub ceil_mode: bool,
     pub out: TensorDescription,
     pub out_indices: TensorDescription,
 }
@@ -1034,6 +1149,7 @@ pub struct MaxPool1dWithIndicesBackwardDescription {
     pub stride: usize,
     pub padding: usize,
     pub dilation: usize,
+    pub ceil_mode: bool,
     pub out: TensorDescription,
 }
 
@@ -1045,6 +1161,7 @@ pub struct MaxPool2dDescription {
     pub stride: [usize; 2],
     pub padding: [usize; 2],
     pub dilation: [usize; 2],
+    pub ceil_mode: bool,
     pub out: TensorDescription,
 }
 
@@ -1056,6 +1173,7 @@ pub struct MaxPool2dWithIndicesDescription {
     pub stride: [usize; 2],
     pub padding: [usize; 2],
     pub dilation: [usize; 2],
+    pub ceil_mode: bool,
     pub out: TensorDescription,
     pub out_indices: TensorDescription,
 }
@@ -1070,6 +1188,7 @@ pub struct MaxPool2dWithIndicesBackwardDescription {
     pub stride: [usize; 2],
     pub padding: [usize; 2],
     pub dilation: [usize; 2],
+    pub ceil_mode: bool,
     pub out: TensorDescription,
 }
 
@@ -1280,6 +1399,15 @@ impl<E: Element> NumericOperationDescription<E> {
             NumericOperationDescription::ArgMin(desc) => {
                 vec![&desc.lhs, &desc.out]
             }
+            NumericOperationDescription::CountNonzero(desc) => {
+                vec![&desc.lhs, &desc.out]
+            }
+            NumericOperationDescription::IsClose(desc) => {
+                vec![&desc.lhs, &desc.rhs, &desc.out]
+            }
+            NumericOperationDescription::AllClose(desc) => {
+                vec![&desc.lhs, &desc.rhs, &desc.out]
+            }
             NumericOperationDescription::Clamp(desc) => {
                 vec![&desc.tensor, &desc.out]
             }
@@ -1330,6 +1458,9 @@ impl<E: Element> NumericOperationDescription<E> {
             NumericOperationDescription::Powf(desc) => {
                 vec![&desc.lhs, &desc.rhs, &desc.out]
             }
+            NumericOperationDescription::Pad(desc) => {
+                vec![&desc.tensor, &desc.out]
+            }
         }
     }
 }
@@ -1341,10 +1472,14 @@ impl FloatOperationDescription {
                 vec![&desc.lhs, &desc.rhs, &desc.out]
             }
             FloatOperationDescription::Random(desc) => vec![&desc.out],
+            FloatOperationDescription::Spmm(desc) => {
+                vec![&desc.rows, &desc.cols, &desc.values, &desc.dense, &desc.out]
+            }
             FloatOperationDescription::Exp(desc) => vec![&desc.input, &desc.out],
             FloatOperationDescription::Log(desc) => vec![&desc.input, &desc.out],
             FloatOperationDescription::Log1p(desc) => vec![&desc.input, &desc.out],
             FloatOperationDescription::Erf(desc) => vec![&desc.input, &desc.out],
+            FloatOperationDescription::Erfinv(desc) => vec![&desc.input, &desc.out],
             FloatOperationDescription::Recip(desc) => vec![&desc.input, &desc.out],
             FloatOperationDescription::PowfScalar(desc) => vec![&desc.lhs, &desc.out],
             FloatOperationDescription::Sqrt(desc) => vec![&desc.input, &desc.out],
@@ -1360,6 +1495,21 @@ impl IntOperationDescription {
     fn nodes(&self) -> Vec<&TensorDescription> {
         match self {
             IntOperationDescription::IntoFloat(desc) => vec![&desc.input, &desc.out],
+            IntOperationDescription::BitwiseAnd(desc)
+            | IntOperationDescription::BitwiseOr(desc)
+            | IntOperationDescription::BitwiseXor(desc)
+            | IntOperationDescription::BitwiseLeftShift(desc)
+            | IntOperationDescription::BitwiseRightShift(desc) => {
+                vec![&desc.lhs, &desc.rhs, &desc.out]
+            }
+            IntOperationDescription::BitwiseAndScalar(desc)
+            | IntOperationDescription::BitwiseOrScalar(desc)
+            | IntOperationDescription::BitwiseXorScalar(desc)
+            | IntOperationDescription::BitwiseLeftShiftScalar(desc)
+            | IntOperationDescription::BitwiseRightShiftScalar(desc) => {
+                vec![&desc.lhs, &desc.out]
+            }
+            IntOperationDescription::BitwiseNot(desc) => vec![&desc.input, &desc.out],
         }
     }
 }
@@ -1370,6 +1520,10 @@ impl BoolOperationDescription {
             BoolOperationDescription::IntoFloat(desc) => vec![&desc.input, &desc.out],
             BoolOperationDescription::IntoInt(desc) => vec![&desc.input, &desc.out],
             BoolOperationDescription::Not(desc) => vec![&desc.input, &desc.out],
+            BoolOperationDescription::ArgwherePadded(desc) => {
+                vec![&desc.input, &desc.out_indices, &desc.out_count]
+            }
+            BoolOperationDescription::CountNonzero(desc) => vec![&desc.lhs, &desc.out],
         }
     }
 }
@@ -1380,6 +1534,9 @@ impl ModuleOperationDescription {
             ModuleOperationDescription::Embedding(desc) => {
                 vec![&desc.weights, &desc.indices, &desc.out]
             }
+            ModuleOperationDescription::Dropout(desc) => {
+                vec![&desc.x, &desc.out]
+            }
             ModuleOperationDescription::EmbeddingBackward(desc) => {
                 vec![&desc.weights, &desc.out_grad, &desc.indices, &desc.out]
             }
@@ -1490,6 +1647,13 @@ impl core::hash::Hash for RandomOperationDescription {
     }
 }
 
+impl core::hash::Hash for DropoutDescription {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.x.hash(state);
+        self.out.hash(state);
+    }
+}
+
 impl<E> core::hash::Hash for ScalarOperationDescription<E> {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
         self.lhs.hash(state);
@@ -1505,6 +1669,23 @@ impl<E> core::hash::Hash for MaskFillOperationDescription<E> {
     }
 }
 
+impl<E> core::hash::Hash for PadOperationDescription<E> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.tensor.hash(state);
+        self.padding.hash(state);
+        core::mem::discriminant(&self.mode).hash(state);
+        self.out.hash(state);
+    }
+}
+
+impl core::hash::Hash for CloseOperationDescription {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.lhs.hash(state);
+        self.rhs.hash(state);
+        self.out.hash(state);
+    }
+}
+
 impl<E> core::hash::Hash for ClampOperationDescription<E> {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
         self.tensor.hash(state);
@@ -1560,6 +1741,10 @@ impl<E> core::hash::Hash for NumericOperationDescription<E> {
             NumericOperationDescription::Clamp(desc) => desc.hash(state),
             NumericOperationDescription::IntRandom(desc) => desc.hash(state),
             NumericOperationDescription::Powf(desc) => desc.hash(state),
+            NumericOperationDescription::Pad(desc) => desc.hash(state),
+            NumericOperationDescription::CountNonzero(desc) => desc.hash(state),
+            NumericOperationDescription::IsClose(desc) => desc.hash(state),
+            NumericOperationDescription::AllClose(desc) => desc.hash(state),
         }
     }
 }
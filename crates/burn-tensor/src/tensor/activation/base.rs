@@ -81,9 +81,20 @@ pub fn softmax<const D: usize, B: Backend>(tensor: Tensor<B, D>, dim: usize) ->
 /// Applies the softplus function
 ///
 /// `softplus(x_i) = log(1 + exp(\beta x_i)) / \beta`
+///
+/// # Notes
+///
+/// The function reverts to the identity when `\beta x_i > 20`, where `exp(\beta x_i)` might
+/// overflow and `softplus(x_i)` is equal to `x_i` up to the float precision.
 pub fn softplus<const D: usize, B: Backend>(tensor: Tensor<B, D>, beta: f64) -> Tensor<B, D> {
-    let tensor = (tensor.mul_scalar(beta).exp() + 1).log();
-    tensor.div_scalar(beta)
+    const THRESHOLD: f64 = 20.0;
+
+    let scaled = tensor.clone().mul_scalar(beta);
+    let is_linear = scaled.clone().greater_elem(THRESHOLD);
+
+    // The input is clamped so that the gradient of the masked branch never becomes `NaN`.
+    let output = scaled.clamp_max(THRESHOLD).exp().log1p().div_scalar(beta);
+    output.mask_where(is_linear, tensor)
 }
 
 /// Applies the "quiet softmax" function on the input tensor along the given dimension.
@@ -1,4 +1,5 @@
 use crate::{backend::Backend, ops::IntTensor, Device, ElementConversion, Shape, TensorData};
+use alloc::vec;
 use alloc::vec::Vec;
 
 /// Compute the indices of the elements that are non-zero, grouped by element.
@@ -25,22 +26,6 @@ pub fn argwhere_data<B: Backend, const D: usize>(
     let dims = &data.shape;
     let count_nonzero = data.iter::<bool>().filter(|&v| v).count();
 
-    /// Converts a flat index into a vector of indices for the specified tensor shape
-    fn unravel_index<B: Backend>(index: usize, shape: &[usize]) -> Vec<B::IntElem> {
-        shape
-            .iter()
-            .rev()
-            .scan(index, |i, size| {
-                let dim_idx = *i % size;
-                *i /= size;
-                Some((dim_idx as i64).elem())
-            })
-            .collect::<Vec<_>>()
-            .into_iter()
-            .rev()
-            .collect()
-    }
-
     let indices = data
         .iter::<bool>()
         .enumerate()
@@ -54,3 +39,68 @@ pub fn argwhere_data<B: Backend, const D: usize>(
         device,
     )
 }
+
+/// Compute the indices of at most `max_count` elements that are non-zero, grouped by element, and
+/// the number of non-zero elements.
+///
+/// # Arguments
+///
+/// * `data` - The input tensor data.
+/// * `max_count` - The number of rows of the indices.
+///
+/// # Returns
+///
+/// The indices of shape `[max_count, D]`, the rows after the number of non-zero elements being
+/// filled with `-1`, and the number of non-zero elements of shape `[1]`, which can be greater
+/// than `max_count`.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+pub fn argwhere_padded_data<B: Backend, const D: usize>(
+    data: TensorData,
+    max_count: usize,
+    device: &Device<B>,
+) -> (IntTensor<B, 2>, IntTensor<B, 1>) {
+    let dims = &data.shape;
+    let count_nonzero = data.iter::<bool>().filter(|&v| v).count();
+
+    let mut indices = data
+        .iter::<bool>()
+        .enumerate()
+        .filter_map(|(index, v)| if v { Some(index) } else { None })
+        .take(max_count)
+        .flat_map(|index| unravel_index::<B>(index, dims))
+        .collect::<Vec<_>>();
+    indices.resize(max_count * D, (-1).elem());
+
+    let indices = B::int_from_data(TensorData::new(indices, Shape::new([max_count, D])), device);
+    let count = B::int_from_data(
+        TensorData::new(
+            vec![(count_nonzero as i64).elem::<B::IntElem>()],
+            Shape::new([1]),
+        ),
+        device,
+    );
+
+    (indices, count)
+}
+
+/// Converts a flat index into a vector of indices for the specified tensor shape
+fn unravel_index<B: Backend>(index: usize, shape: &[usize]) -> Vec<B::IntElem> {
+    shape
+        .iter()
+        .rev()
+        .scan(index, |i, size| {
+            let dim_idx = *i % size;
+            *i /= size;
+            Some((dim_idx as i64).elem())
+        })
+        .collect::<Vec<_>>()
+        .into_iter()
+        .rev()
+        .collect()
+}
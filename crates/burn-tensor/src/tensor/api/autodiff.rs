@@ -8,6 +8,15 @@ impl<const D: usize, B: AutodiffBackend> Tensor<B, D> {
         B::backward::<D>(self.primitive.clone().tensor())
     }
 
+    /// Backward pass of the tensor, retaining the computational graph.
+    ///
+    /// Unlike [backward](Tensor::backward), the graph isn't consumed, so the gradients of other
+    /// tensors sharing parts of it, e.g. several losses computed from the same forward pass, can
+    /// be computed afterward without computing the forward pass again.
+    pub fn backward_retained(&self) -> B::Gradients {
+        B::backward_retained::<D>(self.primitive.clone().tensor())
+    }
+
     /// Get the gradients of a tensor if it exist.
     ///
     /// Returns a new reference to the same tensor. Therefore the same grad tensor can
@@ -640,7 +640,7 @@ where
     pub fn slice<const D2: usize, R: RangesArg<D2>>(self, ranges: R) -> Self {
         let ranges = ranges.into_ranges(self.shape());
 
-        check!(TensorCheck::slice(&self.shape(), &ranges));
+        check!(TensorCheck::slice(&self, &ranges));
         Self::new(K::slice(self.primitive, ranges))
     }
 
@@ -671,11 +671,7 @@ where
         ranges: [core::ops::Range<usize>; D2],
         values: Self,
     ) -> Self {
-        check!(TensorCheck::slice_assign(
-            &self.shape(),
-            &values.shape(),
-            &ranges
-        ));
+        check!(TensorCheck::slice_assign(&self, &values, &ranges));
         Self::new(K::slice_assign(self.primitive, ranges, values.primitive))
     }
 
@@ -769,9 +765,21 @@ where
     /// # Panics
     ///
     /// If all tensors don't have the same shape.
-    pub fn cat(tensors: Vec<Self>, dim: usize) -> Self {
+    pub fn cat(mut tensors: Vec<Self>, dim: usize) -> Self {
         check!(TensorCheck::cat(&tensors, dim));
 
+        // Empty tensors don't contribute any value, so the backends never see them.
+        if tensors.iter().any(|tensor| tensor.dims()[dim] == 0) {
+            let first = tensors[0].clone();
+            tensors.retain(|tensor| tensor.dims()[dim] != 0);
+
+            match tensors.len() {
+                0 => return first,
+                1 => return tensors.remove(0),
+                _ => (),
+            }
+        }
+
         Self::new(K::cat(
             tensors.into_iter().map(|vector| vector.primitive).collect(),
             dim,
@@ -900,6 +908,23 @@ where
         K::all_dim(self.primitive, dim)
     }
 
+    /// Counts the elements of the `tensor` that are non-zero (or True) along a given dimension `dim`.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to count. All input tensor types (Float, Int, Bool) are supported.
+    /// * `dim` - The axis along which to count.
+    ///
+    /// # Returns
+    ///
+    /// An integer tensor `Tensor<B, D, Int>` with the same size as input `tensor`, except in the `dim`
+    /// axis where the size is 1. The elem in the `dim` axis is the number of non-zero elements along
+    /// this dim in the input.
+    pub fn count_nonzero(self, dim: usize) -> Tensor<B, D, Int> {
+        check!(TensorCheck::aggregate_dim::<D>("CountNonzero", dim));
+        K::count_nonzero(self.primitive, dim)
+    }
+
     /// Convert the tensor into a scalar.
     ///
     /// # Panics
@@ -942,7 +967,7 @@ where
     /// A new tensor with the given shape.
     pub fn expand<const D2: usize, S: BroadcastArgs<D, D2>>(self, shape: S) -> Tensor<B, D2, K> {
         let shape = shape.into_shape(&self.shape());
-        check!(TensorCheck::expand("expand", &self.shape(), &shape,));
+        check!(TensorCheck::expand("Expand", &self, &shape));
 
         Tensor::<B, D2, K>::new(K::expand(self.primitive, shape))
     }
@@ -1716,6 +1741,26 @@ pub trait BasicOps<B: Backend>: TensorKind<B> {
     /// which is more high-level and designed for public use.
     fn all_dim<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Tensor<B, D, Bool>;
 
+    /// Counts the non-zero (or True) elements of the tensor along a given dimension dim.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to count.
+    /// * `dim` - The axis along which to count.
+    ///
+    /// # Returns
+    ///
+    /// An integer tensor with the same size as input `tensor`, except in the `dim` axis where the size
+    /// is 1, holding the number of non-zero elements along the given dimension.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly. Users should prefer the [Tensor::count_nonzero](Tensor::count_nonzero)
+    /// function, which is more high-level and designed for public use.
+    fn count_nonzero<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Tensor<B, D, Int>;
+
     /// Broadcasts the given tensor to the specified shape.
     ///
     /// # Arguments
@@ -1863,6 +1908,10 @@ impl<B: Backend> BasicOps<B> for Float {
         Tensor::new(B::float_all_dim(tensor.tensor(), dim))
     }
 
+    fn count_nonzero<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Tensor<B, D, Int> {
+        Tensor::new(B::float_count_nonzero(tensor.tensor(), dim))
+    }
+
     fn permute<const D: usize>(tensor: Self::Primitive<D>, axes: [usize; D]) -> Self::Primitive<D> {
         TensorPrimitive::Float(B::float_permute(tensor.tensor(), axes))
     }
@@ -1985,6 +2034,10 @@ impl<B: Backend> BasicOps<B> for Int {
         Tensor::new(B::int_all_dim(tensor, dim))
     }
 
+    fn count_nonzero<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Tensor<B, D, Int> {
+        Tensor::new(B::int_count_nonzero(tensor, dim))
+    }
+
     fn permute<const D: usize>(tensor: Self::Primitive<D>, axes: [usize; D]) -> Self::Primitive<D> {
         B::int_permute(tensor, axes)
     }
@@ -2107,6 +2160,10 @@ impl<B: Backend> BasicOps<B> for Bool {
         Tensor::new(B::bool_all_dim(tensor, dim))
     }
 
+    fn count_nonzero<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Tensor<B, D, Int> {
+        Tensor::new(B::bool_count_nonzero(tensor, dim))
+    }
+
     fn permute<const D: usize>(tensor: Self::Primitive<D>, axes: [usize; D]) -> Self::Primitive<D> {
         B::bool_permute(tensor, axes)
     }
@@ -2276,7 +2333,7 @@ impl<const D2: usize> ReshapeArgs<D2> for Shape<D2> {
         self,
         tensor: &Tensor<B, D, K>,
     ) -> Shape<D2> {
-        check!(TensorCheck::reshape_args_usize(&tensor.shape(), &self));
+        check!(TensorCheck::reshape_args_usize(tensor, &self));
 
         self
     }
@@ -2288,7 +2345,7 @@ impl<const D2: usize> ReshapeArgs<D2> for [usize; D2] {
     ) -> Shape<D2> {
         let shape = Shape::from(self);
 
-        check!(TensorCheck::reshape_args_usize(&tensor.shape(), &shape));
+        check!(TensorCheck::reshape_args_usize(tensor, &shape));
 
         shape
     }
@@ -2330,21 +2387,15 @@ impl<const D2: usize> ReshapeArgs<D2> for [i32; D2] {
             let product_current = tensor.shape().num_elements() as i32;
 
             new_shape[index] = product_current / product;
-
-            // Check if the reshape is valid
-            if product_current % product != 0 {
-                panic!(
-                    "Cannot reshape tensor of shape {:?} to shape {:?}",
-                    tensor.shape(),
-                    new_shape
-                );
-            }
         };
 
         // Convert each element to usize
-        let new_shape: [usize; D2] = new_shape.map(|x| x as usize);
+        let new_shape = Shape::from(new_shape.map(|x| x as usize));
 
-        Shape::from(new_shape)
+        // An inferred dimension that doesn't divide the number of elements is rejected here.
+        check!(TensorCheck::reshape_args_usize(tensor, &new_shape));
+
+        new_shape
     }
 }
 
@@ -0,0 +1,124 @@
+use crate::{
+    backend::Backend,
+    ops::{IntElem, IntTensor},
+    DType, Element, ElementConversion, Shape, TensorData,
+};
+use alloc::vec::Vec;
+use burn_common::reader::try_read_sync;
+
+/// Apply a bitwise operation element-wise to two integer tensors, broadcasting their shapes.
+///
+/// # Arguments
+///
+/// * `lhs` - The left hand side tensor.
+/// * `rhs` - The right hand side tensor.
+/// * `op` - The operation, applied to the values of the elements sign extended to 64 bits.
+///
+/// # Returns
+///
+/// A tensor with the values returned by the operation, wrapped to the integer type of the backend.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+pub fn bitwise<B: Backend, const D: usize>(
+    lhs: IntTensor<B, D>,
+    rhs: IntTensor<B, D>,
+    op: fn(i64, i64) -> i64,
+) -> IntTensor<B, D> {
+    let lhs_shape = B::int_shape(&lhs);
+    let rhs_shape = B::int_shape(&rhs);
+    let shape = Shape::new(core::array::from_fn(|i| {
+        usize::max(lhs_shape.dims[i], rhs_shape.dims[i])
+    }));
+
+    let device = B::int_device(&lhs);
+    let lhs = read::<B, D>(B::int_expand(lhs, shape.clone()));
+    let rhs = read::<B, D>(B::int_expand(rhs, shape.clone()));
+
+    let values = lhs
+        .iter::<i64>()
+        .zip(rhs.iter::<i64>())
+        .map(|(lhs, rhs)| wrap::<IntElem<B>>(op(lhs, rhs)))
+        .collect::<Vec<_>>();
+
+    from_values::<B, D>(values, shape, &device)
+}
+
+/// Apply a bitwise operation element-wise to an integer tensor and a scalar.
+///
+/// # Arguments
+///
+/// * `lhs` - The left hand side tensor.
+/// * `rhs` - The right hand side scalar.
+/// * `op` - The operation, applied to the values sign extended to 64 bits.
+///
+/// # Returns
+///
+/// A tensor with the values returned by the operation, wrapped to the integer type of the backend.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+pub fn bitwise_scalar<B: Backend, const D: usize>(
+    lhs: IntTensor<B, D>,
+    rhs: IntElem<B>,
+    op: fn(i64, i64) -> i64,
+) -> IntTensor<B, D> {
+    let shape = B::int_shape(&lhs);
+    let device = B::int_device(&lhs);
+    let rhs = rhs.elem::<i64>();
+
+    let values = read::<B, D>(lhs)
+        .iter::<i64>()
+        .map(|lhs| wrap::<IntElem<B>>(op(lhs, rhs)))
+        .collect::<Vec<_>>();
+
+    from_values::<B, D>(values, shape, &device)
+}
+
+fn read<B: Backend, const D: usize>(tensor: IntTensor<B, D>) -> TensorData {
+    try_read_sync(B::int_into_data(tensor)).expect("Failed to synchronously read tensor data. This operation is not supported until this backend has a GPU bitwise implementation.")
+}
+
+fn from_values<B: Backend, const D: usize>(
+    values: Vec<i64>,
+    shape: Shape<D>,
+    device: &B::Device,
+) -> IntTensor<B, D> {
+    let data = TensorData::new(values, shape).convert::<IntElem<B>>();
+    B::int_from_data(data, device)
+}
+
+/// The mask applied to the shift amounts of the integer type, one less than its bit width.
+///
+/// The shifts only use the lowest bits of their amounts, the amount being taken modulo the bit
+/// width, so shifting by a negative amount or by at least the bit width never fails.
+pub fn shift_mask<E: Element>() -> i64 {
+    match E::dtype() {
+        DType::I64 | DType::U64 => 63,
+        DType::I32 | DType::U32 => 31,
+        DType::I16 => 15,
+        DType::I8 | DType::U8 => 7,
+        dtype => panic!("{dtype:?} is not an integer type"),
+    }
+}
+
+/// Wrap a value to the integer type, keeping its lowest bits like a two's complement cast.
+fn wrap<E: Element>(value: i64) -> i64 {
+    match E::dtype() {
+        DType::I64 | DType::U64 => value,
+        DType::I32 => value as i32 as i64,
+        DType::I16 => value as i16 as i64,
+        DType::I8 => value as i8 as i64,
+        DType::U32 => value as u32 as i64,
+        DType::U8 => value as u8 as i64,
+        dtype => panic!("{dtype:?} is not an integer type"),
+    }
+}
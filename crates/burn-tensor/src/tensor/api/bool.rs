@@ -1,4 +1,8 @@
-use crate::{backend::Backend, Bool, Int, Shape, Tensor, TensorData, TensorPrimitive};
+use crate::{
+    backend::Backend, check, check::TensorCheck, Bool, Int, Shape, Tensor, TensorData,
+    TensorPrimitive,
+};
+use alloc::vec;
 use alloc::vec::Vec;
 
 use crate::try_read_sync;
@@ -39,6 +43,93 @@ where
         Tensor::new(B::bool_not(self.primitive))
     }
 
+    /// Applies element wise logical and operation.
+    pub fn bool_and(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("BoolAnd", &self, &other));
+        Tensor::new(B::bool_and(self.primitive, other.primitive))
+    }
+
+    /// Applies element wise logical or operation.
+    pub fn bool_or(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("BoolOr", &self, &other));
+        Tensor::new(B::bool_or(self.primitive, other.primitive))
+    }
+
+    /// Applies element wise logical exclusive or operation.
+    pub fn bool_xor(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("BoolXor", &self, &other));
+        Tensor::new(B::bool_xor(self.primitive, other.primitive))
+    }
+
+    /// Pack the boolean values in words of 32 bits, storing 32 values per integer instead of one.
+    ///
+    /// The values are taken in the row-major order of the tensor: the value `i` is the bit
+    /// `i % 32` of the word `i / 32`, where the bit `0` is the least significant one. The last
+    /// word is padded with zeros.
+    ///
+    /// The words are stored in the integers of the backend, so the bit `31` is the sign bit
+    /// when the backend uses 32-bit integers.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Bool, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let mask = Tensor::<B, 2, Bool>::from_bool([[true, false], [true, true]].into(), &device);
+    ///     // [13]
+    ///     let packed = mask.clone().to_packed_u32();
+    ///     let unpacked = Tensor::<B, 2, Bool>::from_packed(packed, mask.dims());
+    /// }
+    /// ```
+    pub fn to_packed_u32(self) -> Tensor<B, 1, Int> {
+        let num_elements = self.shape().num_elements();
+        let num_words = num_elements.div_ceil(32);
+        let device = self.device();
+
+        let mut bits = self.int().reshape([num_elements]);
+        if num_elements % 32 != 0 {
+            let padding = Tensor::zeros([num_words * 32 - num_elements], &device);
+            bits = Tensor::cat(vec![bits, padding], 0);
+        }
+        let bits = bits.reshape([num_words, 32]);
+
+        // The bits are distinct, so their sum is their bitwise or.
+        bits.bitwise_left_shift(bit_positions(&device))
+            .sum_dim(1)
+            .reshape([num_words])
+    }
+
+    /// Unpack the boolean values packed by [to_packed_u32](Tensor::to_packed_u32).
+    ///
+    /// # Arguments
+    ///
+    /// * `packed` - The words holding the values.
+    /// * `shape` - The shape of the unpacked tensor.
+    ///
+    /// # Panics
+    ///
+    /// If the number of words isn't the number needed for the values of the shape.
+    pub fn from_packed<S: Into<Shape<D>>>(packed: Tensor<B, 1, Int>, shape: S) -> Self {
+        let shape = shape.into();
+        let num_elements = shape.num_elements();
+        check!(TensorCheck::from_packed(&packed, &shape));
+
+        let [num_words] = packed.dims();
+        let device = packed.device();
+
+        packed
+            .reshape([num_words, 1])
+            .bitwise_right_shift(bit_positions(&device))
+            .bitwise_and_scalar(1)
+            .equal_elem(1)
+            .reshape([num_words * 32])
+            .slice([0..num_elements])
+            .reshape(shape)
+    }
+
     /// Compute the indices of the elements that are non-zero.
     ///
     /// # Returns
@@ -85,6 +176,29 @@ where
         Tensor::new(B::bool_argwhere(self.primitive).await)
     }
 
+    /// Compute the indices of at most `max_count` elements that are true, grouped by element, with
+    /// a fixed output shape.
+    ///
+    /// Unlike [argwhere](Tensor::argwhere), the shapes of the outputs don't depend on the values, so
+    /// backends supporting it don't need to read the tensor back.
+    ///
+    /// # Returns
+    ///
+    /// - The indices of the first `max_count` true elements, in row-major order, of shape
+    ///   `[max_count, D]`. The rows after the number of true elements are filled with `-1`.
+    /// - The number of true elements of shape `[1]`. It is the true count even when it is greater
+    ///   than `max_count`, so the omitted elements can be detected.
+    ///
+    /// # Autodiff
+    ///
+    /// The outputs are integer tensors, which are never tracked: using them to index a float
+    /// tensor propagates the gradients to the indexed values, not to the mask.
+    pub fn argwhere_padded(self, max_count: usize) -> (Tensor<B, 2, Int>, Tensor<B, 1, Int>) {
+        let (indices, count) = B::bool_argwhere_padded(self.primitive, max_count);
+
+        (Tensor::new(indices), Tensor::new(count))
+    }
+
     /// Creates a mask for the upper, lower triangle, or diagonal of a matrix, which can be used to
     /// fill the specified area with a value.
     fn tri_mask<S: Into<Shape<D>>>(
@@ -183,3 +297,40 @@ where
         Self::tri_mask(shape, TriPart::Diagonal, offset, device)
     }
 }
+
+/// The positions of the bits of a packed word, of shape `[1, 32]`.
+fn bit_positions<B: Backend>(device: &B::Device) -> Tensor<B, 2, Int> {
+    Tensor::arange(0..32, device).reshape([1, 32])
+}
+
+impl<B: Backend, const D: usize> core::ops::BitAnd<Self> for Tensor<B, D, Bool> {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self {
+        self.bool_and(rhs)
+    }
+}
+
+impl<B: Backend, const D: usize> core::ops::BitOr<Self> for Tensor<B, D, Bool> {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        self.bool_or(rhs)
+    }
+}
+
+impl<B: Backend, const D: usize> core::ops::BitXor<Self> for Tensor<B, D, Bool> {
+    type Output = Self;
+
+    fn bitxor(self, rhs: Self) -> Self {
+        self.bool_xor(rhs)
+    }
+}
+
+impl<B: Backend, const D: usize> core::ops::Not for Tensor<B, D, Bool> {
+    type Output = Self;
+
+    fn not(self) -> Self {
+        self.bool_not()
+    }
+}
@@ -0,0 +1,137 @@
+use crate::{backend::Backend, DType, Element, Int, Tensor};
+
+/// The rounding applied when casting floats to integers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Rounding {
+    /// Round to the nearest integer, with ties rounded to the nearest even integer.
+    #[default]
+    Nearest,
+    /// Round toward zero.
+    Trunc,
+    /// Round toward negative infinity.
+    Floor,
+    /// Round toward positive infinity.
+    Ceil,
+}
+
+/// Options of an explicit cast between element types.
+///
+/// The default rounds to the nearest integer, with ties to even, and saturates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CastOptions {
+    /// The rounding applied when casting floats to integers.
+    pub rounding: Rounding,
+    /// Clamp the values that overflow the target type to its bounds, and map NaN to zero when
+    /// casting to integers.
+    ///
+    /// Without saturation, the result of overflowing values is backend specific for tensors,
+    /// and the conversion of [tensor data](crate::TensorData) panics.
+    pub saturate: bool,
+}
+
+impl Default for CastOptions {
+    fn default() -> Self {
+        Self {
+            rounding: Rounding::Nearest,
+            saturate: true,
+        }
+    }
+}
+
+impl CastOptions {
+    /// Create new cast options.
+    pub fn new(rounding: Rounding, saturate: bool) -> Self {
+        Self { rounding, saturate }
+    }
+}
+
+impl<const D: usize, B> Tensor<B, D>
+where
+    B: Backend,
+{
+    /// Cast the tensor to an integer tensor with the given [options](CastOptions).
+    ///
+    /// Unlike [int](Tensor::int), which relies on the conversion of the backend, the result
+    /// is the same on every backend.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{CastOptions, Rounding, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<B, 1>::from_floats([-1.5, -0.5, 0.5, 1.5, f32::NAN], &device);
+    ///     // [-2, 0, 0, 2, 0]
+    ///     let nearest = tensor.clone().cast_with(CastOptions::default());
+    ///     // [-2, -1, 0, 1, 0]
+    ///     let floor = tensor.cast_with(CastOptions::new(Rounding::Floor, true));
+    /// }
+    /// ```
+    pub fn cast_with(self, options: CastOptions) -> Tensor<B, D, Int> {
+        let (min, max) = int_bounds::<B::IntElem>();
+
+        if !options.saturate {
+            return round(self, options.rounding);
+        }
+
+        // The first float above the integer range is a power of two, which is exact in every
+        // float type, while the lower bound might round to an infinity.
+        let above = self.clone().greater_equal_elem(max as f64 + 1.0);
+        let below = self.clone().lower_equal_elem(min as f64);
+        let tensor = self
+            .clone()
+            .mask_fill(self.is_nan(), 0)
+            .mask_fill(above.clone(), 0)
+            .mask_fill(below.clone(), 0)
+            .clamp(min as f64, max as f64);
+
+        round(tensor, options.rounding)
+            .mask_fill(above, max)
+            .mask_fill(below, min)
+    }
+}
+
+/// Round the values, which must be in the integer range.
+///
+/// The conversion of the backend either truncates or rounds, so its result is corrected
+/// using the fact that it is always less than one away from the value.
+fn round<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+    rounding: Rounding,
+) -> Tensor<B, D, Int> {
+    let converted = tensor.clone().int();
+    let approx = converted.clone().float();
+
+    let floor = || converted.clone() - approx.clone().greater(tensor.clone()).int();
+    let ceil = || converted.clone() + approx.clone().lower(tensor.clone()).int();
+
+    match rounding {
+        Rounding::Floor => floor(),
+        Rounding::Ceil => ceil(),
+        Rounding::Trunc => floor().mask_where(tensor.clone().lower_elem(0.0), ceil()),
+        Rounding::Nearest => {
+            let floor = floor();
+            let fraction = tensor - floor.clone().float();
+            let is_odd = floor.clone().remainder_scalar(2).not_equal_elem(0);
+            let is_tie = fraction.clone().equal_elem(0.5);
+
+            floor + fraction.greater_elem(0.5).int() + (is_tie.int() * is_odd.int())
+        }
+    }
+}
+
+/// The bounds of an integer type.
+pub(crate) fn int_bounds<E: Element>() -> (i64, u64) {
+    match E::dtype() {
+        DType::I64 => (i64::MIN, i64::MAX as u64),
+        DType::I32 => (i32::MIN as i64, i32::MAX as u64),
+        DType::I16 => (i16::MIN as i64, i16::MAX as u64),
+        DType::I8 => (i8::MIN as i64, i8::MAX as u64),
+        DType::U64 => (0, u64::MAX),
+        DType::U32 => (0, u32::MAX as u64),
+        DType::U8 => (0, u8::MAX as u64),
+        dtype => panic!("{dtype:?} is not an integer type"),
+    }
+}
@@ -1,10 +1,52 @@
-use crate::{backend::Backend, BasicOps, Shape, Tensor};
+use crate::{backend::Backend, BasicOps, DType, Element, PadDim, PadMode, Shape, Tensor};
 use alloc::format;
 use alloc::string::{String, ToString};
 use alloc::vec;
 use alloc::vec::Vec;
+use core::fmt::{Debug, Display};
 use core::ops::Range;
 
+/// An operand of a checked operation, described in the error messages by its shape, its element
+/// type and its device, e.g. `lhs shape [2, 3] (f32, Cpu)`.
+pub(crate) trait CheckOperand<const D: usize> {
+    /// The device type of the operand.
+    type Device: PartialEq + Debug;
+
+    /// The dimensions of the operand.
+    fn dims(&self) -> [usize; D];
+    /// The device of the operand.
+    fn device(&self) -> Self::Device;
+    /// The element type of the operand.
+    fn dtype(&self) -> DType;
+}
+
+impl<B: Backend, const D: usize, K: BasicOps<B>> CheckOperand<D> for Tensor<B, D, K> {
+    type Device = B::Device;
+
+    fn dims(&self) -> [usize; D] {
+        Tensor::dims(self)
+    }
+
+    fn device(&self) -> B::Device {
+        Tensor::device(self)
+    }
+
+    fn dtype(&self) -> DType {
+        K::Elem::dtype()
+    }
+}
+
+/// Describes the operand with its name, which is only done once a check failed.
+fn describe<const D: usize, T: CheckOperand<D>>(name: &str, operand: &T) -> String {
+    let dtype = format!("{:?}", operand.dtype()).to_lowercase();
+
+    format!(
+        "{name} shape {:?} ({dtype}, {:?})",
+        operand.dims(),
+        operand.device()
+    )
+}
+
 /// The struct should always be used with the [check](crate::check) macro.
 ///
 /// This is a simple pub(crate) data structure that efficiently checks tensor operations and
@@ -39,14 +81,46 @@ pub(crate) enum TensorCheck {
 
 impl TensorCheck {
     /// Checks device and shape compatibility for element wise binary operations.
-    pub(crate) fn binary_ops_ew<B: Backend, const D: usize, K: BasicOps<B>>(
+    pub(crate) fn binary_ops_ew<const D: usize, L, R>(ops: &str, lhs: &L, rhs: &R) -> Self
+    where
+        L: CheckOperand<D>,
+        R: CheckOperand<D, Device = L::Device>,
+    {
+        Self::Ok
+            .same_device(ops, ("lhs", lhs), ("rhs", rhs))
+            .broadcast_shapes(ops, ("lhs", lhs), ("rhs", rhs))
+    }
+
+    pub(crate) fn ternary_ops_ew<const D: usize, T: CheckOperand<D>>(
         ops: &str,
-        lhs: &Tensor<B, D, K>,
-        rhs: &Tensor<B, D, K>,
+        tensor: &T,
+        lhs: &T,
+        rhs: &T,
     ) -> Self {
+        let (tensor, lhs, rhs) = (("tensor", tensor), ("lhs", lhs), ("rhs", rhs));
+
+        Self::Ok
+            .same_device(ops, tensor, lhs)
+            .same_device(ops, tensor, rhs)
+            .broadcast_shapes(ops, tensor, lhs)
+            .broadcast_shapes(ops, tensor, rhs)
+            .broadcast_shapes(ops, lhs, rhs)
+    }
+
+    pub(crate) fn mask_where<const D: usize, T, M>(tensor: &T, mask: &M, value: &T) -> Self
+    where
+        T: CheckOperand<D>,
+        M: CheckOperand<D, Device = T::Device>,
+    {
+        let ops = "Mask Where";
+        let (tensor, mask, value) = (("tensor", tensor), ("mask", mask), ("value", value));
+
         Self::Ok
-            .binary_ops_device(ops, &lhs.device(), &rhs.device())
-            .binary_ops_ew_shape(ops, &lhs.shape(), &rhs.shape())
+            .same_device(ops, tensor, mask)
+            .same_device(ops, tensor, value)
+            .broadcast_shapes(ops, tensor, mask)
+            .broadcast_shapes(ops, tensor, value)
+            .broadcast_shapes(ops, mask, value)
     }
 
     pub(crate) fn into_scalar<const D: usize>(shape: &Shape<D>) -> Self {
@@ -80,6 +154,61 @@ impl TensorCheck {
         check
     }
 
+    pub(crate) fn dims_ops<const D: usize>(ops: &str, dims: &[usize]) -> Self {
+        let mut check = Self::Ok;
+
+        for (i, &dim) in dims.iter().enumerate() {
+            if dim >= D {
+                check = check.register(
+                    ops,
+                    TensorError::new("Given dimension is higher than the tensor rank.")
+                        .details(format!("Tensor rank: '{D}', given dimension: '{dim}'.")),
+                );
+            }
+
+            if dims[..i].contains(&dim) {
+                check = check.register(
+                    ops,
+                    TensorError::new("The given dimensions contain duplicates.").details(format!(
+                        "The dimension {dim} is duplicated in the given dimensions {dims:?}."
+                    )),
+                );
+            }
+        }
+
+        check
+    }
+
+    /// Checks that the variance along the dimensions has a positive divisor, the number of reduced
+    /// elements minus the correction.
+    pub(crate) fn var_correction<const D: usize>(
+        ops: &str,
+        shape: &Shape<D>,
+        dims: &[usize],
+        correction: usize,
+    ) -> Self {
+        let mut check = Self::Ok;
+        let num_elems: usize = dims
+            .iter()
+            .filter(|dim| **dim < D)
+            .map(|dim| shape.dims[*dim])
+            .product();
+
+        if correction >= num_elems {
+            check = check.register(
+                ops,
+                TensorError::new(
+                    "The correction must be smaller than the number of reduced elements.",
+                )
+                .details(format!(
+                    "Number of reduced elements: '{num_elems}', correction: '{correction}'."
+                )),
+            );
+        }
+
+        check
+    }
+
     pub(crate) fn creation_ops<const D: usize>(ops: &str, dims: &[usize]) -> Self {
         let mut check = Self::Ok;
 
@@ -147,23 +276,25 @@ impl TensorCheck {
         check
     }
 
-    pub(crate) fn reshape_args_usize<const D1: usize, const D2: usize>(
-        original: &Shape<D1>,
+    pub(crate) fn reshape_args_usize<const D1: usize, const D2: usize, T: CheckOperand<D1>>(
+        tensor: &T,
         target: &Shape<D2>,
     ) -> Self {
         let mut check = Self::Ok;
+        let num_elements: usize = tensor.dims().iter().product();
 
-        if original.num_elements() != target.num_elements() {
+        if num_elements != target.num_elements() {
             check = check.register(
                 "Reshape",
-                TensorError::new(
-                    "The given shape doesn't have the same number of elements as the current \
-                     tensor.",
-                )
-                .details(format!(
-                    "Current shape: {:?}, target shape: {:?}.",
-                    original.dims, target.dims
-                )),
+                TensorError::invalid(
+                    describe("tensor", tensor),
+                    format!(
+                        "the {num_elements} elements can't be reshaped to {:?}, which has {} \
+                         elements.",
+                        target.dims,
+                        target.num_elements()
+                    ),
+                ),
             );
         }
 
@@ -521,50 +652,205 @@ impl TensorCheck {
         check
     }
 
-    pub(crate) fn matmul<B: Backend, const D: usize>(
-        lhs: &Tensor<B, D>,
-        rhs: &Tensor<B, D>,
+    pub(crate) fn pad<const D: usize, E>(
+        shape: &Shape<D>,
+        padding: &[(isize, isize); D],
+        mode: &PadMode<E>,
+    ) -> Self {
+        let mut check = Self::Ok;
+
+        for (i, (size, padding)) in shape.dims.iter().zip(padding.iter()).enumerate() {
+            let dim = PadDim::new(*padding);
+
+            if dim.crop_before + dim.crop_after > *size {
+                check = check.register(
+                    "Pad",
+                    TensorError::new("Can't crop more elements than the dimension size.").details(
+                        format!(
+                            "Dimension {i} has {size} elements, but the padding {padding:?} \
+                             crops {} elements.",
+                            dim.crop_before + dim.crop_after
+                        ),
+                    ),
+                );
+                continue;
+            }
+
+            if dim.pad_before == 0 && dim.pad_after == 0 {
+                continue;
+            }
+
+            let cropped = size - dim.crop_before - dim.crop_after;
+            let too_large = match mode {
+                PadMode::Constant(_) => false,
+                PadMode::Reflect => dim.pad_before >= cropped || dim.pad_after >= cropped,
+                PadMode::Edge => cropped == 0,
+            };
+
+            if too_large {
+                check = check.register(
+                    "Pad",
+                    TensorError::new(
+                        "The padding must be smaller than the cropped dimension size with the \
+                         reflect mode, and the dimension can't be empty with the edge mode.",
+                    )
+                    .details(format!(
+                        "Dimension {i} has {cropped} elements after cropping, but is padded with \
+                         {padding:?}."
+                    )),
+                );
+            }
+        }
+
+        check
+    }
+
+    pub(crate) fn multinomial<const D: usize>(
+        num_categories: usize,
+        num_samples: usize,
+        replacement: bool,
+    ) -> Self {
+        let mut check = Self::Ok;
+
+        if D > 2 {
+            check = check.register(
+                "Multinomial",
+                TensorError::new("The tensor must have one or two dimensions.")
+                    .details(format!("Got a tensor with {D} dimensions.")),
+            );
+        }
+
+        if !replacement && num_samples > num_categories {
+            check = check.register(
+                "Multinomial",
+                TensorError::new("Can't draw more samples than categories without replacement.")
+                    .details(format!(
+                        "Got {num_samples} samples and {num_categories} categories."
+                    )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn fake_quantize<const D: usize>(
+        shape: &Shape<D>,
+        shape_scale: &Shape<1>,
+        shape_zero_point: &Shape<1>,
+        qmin: i32,
+        qmax: i32,
+        axis: Option<usize>,
     ) -> Self {
         let mut check = Self::Ok;
 
-        check = check.binary_ops_device("Matmul", &lhs.device(), &rhs.device());
+        let num_channels = match axis {
+            Some(axis) if axis >= D => {
+                return check.register(
+                    "Fake Quantize",
+                    TensorError::new("The channel axis must be smaller than the tensor rank.")
+                        .details(format!("Got axis {axis} with a tensor of rank {D}.")),
+                );
+            }
+            Some(axis) => shape.dims[axis],
+            None => 1,
+        };
+
+        if shape_scale.dims[0] != num_channels || shape_zero_point.dims[0] != num_channels {
+            check = check.register(
+                "Fake Quantize",
+                TensorError::new(
+                    "The scale and the zero-point must have one value per channel, or a single \
+                     value without channel axis.",
+                )
+                .details(format!(
+                    "Expected {num_channels} values, got {} scales and {} zero-points.",
+                    shape_scale.dims[0], shape_zero_point.dims[0]
+                )),
+            );
+        }
+
+        if qmin >= qmax {
+            check = check.register(
+                "Fake Quantize",
+                TensorError::new("The quantized range must contain more than one value.")
+                    .details(format!("Got qmin {qmin} and qmax {qmax}.")),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn associative_scan<const D: usize>(a: &Shape<D>, b: &Shape<D>, dim: usize) -> Self {
+        let mut check = Self::dim_ops::<D>("Associative Scan", dim);
+
+        if a != b {
+            check = check.register(
+                "Associative Scan",
+                TensorError::new("The coefficients and the values must have the same shape.")
+                    .details(format!(
+                        "Got coefficients of shape {:?} and values of shape {:?}.",
+                        a.dims, b.dims
+                    )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn matmul<const D: usize, T: CheckOperand<D>>(lhs: &T, rhs: &T) -> Self {
+        let ops = "Matmul";
+        let mut check = Self::Ok.same_device(ops, ("lhs", lhs), ("rhs", rhs));
 
         if D < 2 {
             return check;
         }
 
-        let shape_lhs = lhs.shape();
-        let shape_rhs = rhs.shape();
+        let dims_lhs = lhs.dims();
+        let dims_rhs = rhs.dims();
 
-        let dim_lhs = shape_lhs.dims[D - 1];
-        let dim_rhs = shape_rhs.dims[D - 2];
+        let dim_lhs = dims_lhs[D - 1];
+        let dim_rhs = dims_rhs[D - 2];
 
         if dim_lhs != dim_rhs {
             check = check.register(
-                "Matmul",
-                TensorError::new(format!(
-                    "The inner dimension of matmul should be the same, but got {dim_lhs} and \
-                     {dim_rhs}."
-                ))
-                .details(format!(
-                    "Lhs shape {:?}, rhs shape {:?}.",
-                    shape_lhs.dims, shape_rhs.dims
-                )),
+                ops,
+                TensorError::incompatible(
+                    describe("lhs", lhs),
+                    describe("rhs", rhs),
+                    format!("the inner dimensions {dim_lhs} and {dim_rhs} should be equal."),
+                ),
             );
         }
 
+        for i in 0..D - 2 {
+            let d_lhs = dims_lhs[i];
+            let d_rhs = dims_rhs[i];
+
+            if d_lhs != d_rhs && d_lhs != 1 && d_rhs != 1 {
+                check = check.register(
+                    ops,
+                    TensorError::incompatible(
+                        describe("lhs", lhs),
+                        describe("rhs", rhs),
+                        format!(
+                            "the batch sizes {d_lhs} and {d_rhs} of dimension {i} should be \
+                             equal, or one of them 1 to be broadcasted."
+                        ),
+                    ),
+                );
+            }
+        }
+
         check
     }
 
-    pub(crate) fn stack<B: Backend, const D: usize, K: BasicOps<B>>(
-        tensors: &[Tensor<B, D, K>],
-        dim: usize,
-    ) -> Self {
+    pub(crate) fn stack<const D: usize, T: CheckOperand<D>>(tensors: &[T], dim: usize) -> Self {
+        let ops = "Stack";
         let mut check = Self::Ok;
 
         if dim > D {
             check = check.register(
-                "Stack",
+                ops,
                 TensorError::new(
                     "Can't stack tensors on a dim that exceeds the tensors dimension (inclusive)",
                 )
@@ -574,26 +860,24 @@ impl TensorCheck {
             );
         }
 
-        if tensors.is_empty() {
+        let Some(reference) = tensors.first() else {
             return check.register(
-                "Stack",
+                ops,
                 TensorError::new("Can't stack an empty list of tensors."),
             );
-        }
-
-        let shape_reference = tensors.first().unwrap().shape();
+        };
 
-        for tensor in tensors {
-            let shape = tensor.shape();
+        for (i, tensor) in tensors.iter().enumerate().skip(1) {
+            check = check.same_device_at(ops, reference, tensor, i);
 
-            if shape_reference != shape {
+            if tensor.dims() != reference.dims() {
                 return check.register(
-                    "Stack",
-                    TensorError::new("Can't stack tensors with different shapes").details(format!(
-                        "Provided dimension ({}), tensors shapes: {:?}",
-                        dim,
-                        tensors.iter().map(Tensor::shape).collect::<Vec<_>>()
-                    )),
+                    ops,
+                    TensorError::incompatible(
+                        describe("tensors[0]", reference),
+                        describe(&format!("tensors[{i}]"), tensor),
+                        "the shapes should be equal.",
+                    ),
                 );
             }
         }
@@ -601,15 +885,13 @@ impl TensorCheck {
         check
     }
 
-    pub(crate) fn cat<B: Backend, const D: usize, K: BasicOps<B>>(
-        tensors: &[Tensor<B, D, K>],
-        dim: usize,
-    ) -> Self {
+    pub(crate) fn cat<const D: usize, T: CheckOperand<D>>(tensors: &[T], dim: usize) -> Self {
+        let ops = "Cat";
         let mut check = Self::Ok;
 
         if dim >= D {
             check = check.register(
-                "Cat",
+                ops,
                 TensorError::new(
                     "Can't concatenate tensors on a dim that exceeds the tensors dimension",
                 )
@@ -619,33 +901,33 @@ impl TensorCheck {
             );
         }
 
-        if tensors.is_empty() {
+        let Some(reference) = tensors.first() else {
             return check.register(
-                "Cat",
+                ops,
                 TensorError::new("Can't concatenate an empty list of tensors."),
             );
-        }
+        };
+        let dims_reference = reference.dims();
 
-        let mut shape_reference = tensors.first().unwrap().shape();
-        shape_reference.dims[dim] = 1; // We want to check every dims except the one where the
-                                       // concatenation happens.
+        for (i, tensor) in tensors.iter().enumerate().skip(1) {
+            check = check.same_device_at(ops, reference, tensor, i);
 
-        for tensor in tensors {
-            let mut shape = tensor.shape();
-            shape.dims[dim] = 1; // Ignore the concatenate dim.
+            // Every dimension should match except the one where the concatenation happens.
+            let dims = tensor.dims();
+            let mismatch = (0..D).find(|&d| d != dim && dims[d] != dims_reference[d]);
 
-            if shape_reference != shape {
+            if let Some(d) = mismatch {
                 return check.register(
-                    "Cat",
-                    TensorError::new(
-                        "Can't concatenate tensors with different shapes, except for the provided \
-                         dimension",
-                    )
-                    .details(format!(
-                        "Provided dimension ({}), tensors shapes: {:?}",
-                        dim,
-                        tensors.iter().map(Tensor::shape).collect::<Vec<_>>()
-                    )),
+                    ops,
+                    TensorError::incompatible(
+                        describe("tensors[0]", reference),
+                        describe(&format!("tensors[{i}]"), tensor),
+                        format!(
+                            "the sizes {} and {} of dimension {d} should be equal, except for the \
+                             concatenated dimension {dim}.",
+                            dims_reference[d], dims[d]
+                        ),
+                    ),
                 );
             }
         }
@@ -653,60 +935,40 @@ impl TensorCheck {
         check
     }
 
-    pub(crate) fn slice<const D1: usize, const D2: usize>(
-        shape: &Shape<D1>,
+    pub(crate) fn slice<const D1: usize, const D2: usize, T: CheckOperand<D1>>(
+        tensor: &T,
         ranges: &[Range<usize>; D2],
     ) -> Self {
-        let mut check = Self::Ok;
-        let n_dims_tensor = D1;
-        let n_dims_ranges = D2;
-
-        if n_dims_tensor < n_dims_ranges {
-            check = check.register(
-                "Slice",
-                TensorError::new(
-                    "The provided ranges array has a higher number of dimensions than the current \
-                     tensor.",
-                )
-                .details(format!(
-                    "The ranges array must be smaller or equal to the tensor number of \
-                     dimensions. Tensor number of dimensions: {n_dims_tensor}, ranges array \
-                     length {n_dims_ranges}."
-                )),
-            );
-        }
+        let ops = "Slice";
+        let mut check = Self::Ok.ranges_rank::<D1, D2, T>(ops, tensor);
+        let dims = tensor.dims();
 
         for i in 0..usize::min(D1, D2) {
-            let d_tensor = shape.dims[i];
-            let range = ranges.get(i).unwrap();
+            let d_tensor = dims[i];
+            let range = &ranges[i];
 
             if range.end > d_tensor {
                 check = check.register(
-                    "Slice",
-                    TensorError::new(
-                        "The provided ranges array has a range that exceeds the current tensor \
-                         size.",
-                    )
-                    .details(format!(
-                        "The range ({}..{}) exceeds the size of the tensor ({}) at dimension {}. \
-                         Tensor shape {:?}, provided ranges {:?}.",
-                        range.start, range.end, d_tensor, i, shape.dims, ranges,
-                    )),
+                    ops,
+                    TensorError::invalid(
+                        describe("tensor", tensor),
+                        format!(
+                            "the range {range:?} of dimension {i} exceeds its size {d_tensor}."
+                        ),
+                    ),
                 );
             }
 
-            if range.start >= range.end {
+            // An empty range can only slice a dimension that is already empty.
+            if range.start > range.end || (range.start == range.end && d_tensor != 0) {
                 check = check.register(
-                    "Slice",
-                    TensorError::new(
-                        "The provided range array has a range where the start index is bigger or \
-                         equal to its end.",
-                    )
-                    .details(format!(
-                        "The range at dimension '{}' starts at '{}' and is greater or equal to \
-                         its end '{}'. Tensor shape {:?}, provided ranges {:?}.",
-                        i, range.start, range.end, shape.dims, ranges,
-                    )),
+                    ops,
+                    TensorError::invalid(
+                        describe("tensor", tensor),
+                        format!(
+                            "the range {range:?} of dimension {i} should start before its end."
+                        ),
+                    ),
                 );
             }
         }
@@ -714,82 +976,59 @@ impl TensorCheck {
         check
     }
 
-    pub(crate) fn slice_assign<const D1: usize, const D2: usize>(
-        shape: &Shape<D1>,
-        shape_value: &Shape<D1>,
+    pub(crate) fn slice_assign<const D1: usize, const D2: usize, T: CheckOperand<D1>>(
+        tensor: &T,
+        value: &T,
         ranges: &[Range<usize>; D2],
     ) -> Self {
-        let mut check = Self::Ok;
-
-        if D1 < D2 {
-            check = check.register(
-                "Slice Assign",
-                TensorError::new(
-                    "The provided ranges array has a higher number of dimensions than the current \
-                     tensor.",
-                )
-                .details(format!(
-                    "The ranges array must be smaller or equal to the tensor number of \
-                     dimensions. Tensor number of dimensions: {D1}, ranges array length {D2}."
-                )),
-            );
-        }
+        let ops = "Slice Assign";
+        let mut check = Self::Ok.ranges_rank::<D1, D2, T>(ops, tensor).same_device(
+            ops,
+            ("tensor", tensor),
+            ("value", value),
+        );
+        let dims = tensor.dims();
+        let dims_value = value.dims();
 
         for i in 0..usize::min(D1, D2) {
-            let d_tensor = shape.dims[i];
-            let d_tensor_value = shape_value.dims[i];
-            let range = ranges.get(i).unwrap();
+            let d_tensor = dims[i];
+            let d_value = dims_value[i];
+            let range = &ranges[i];
 
             if range.end > d_tensor {
                 check = check.register(
-                    "Range Assign",
-                    TensorError::new(
-                        "The provided ranges array has a range that exceeds the current tensor \
-                         size.",
-                    )
-                    .details(format!(
-                        "The range ({}..{}) exceeds the size of the tensor ({}) at dimension {}. \
-                         Current tensor shape {:?}, value tensor shape {:?}, provided ranges {:?}.",
-                        range.start, range.end, d_tensor, i, shape.dims, shape_value.dims, ranges,
-                    )),
+                    ops,
+                    TensorError::invalid(
+                        describe("tensor", tensor),
+                        format!(
+                            "the range {range:?} of dimension {i} exceeds its size {d_tensor}."
+                        ),
+                    ),
                 );
             }
 
-            if range.end - range.start != d_tensor_value {
+            if range.start >= range.end {
                 check = check.register(
-                    "Slice Assign",
-                    TensorError::new(
-                        "The value tensor must match the amount of elements selected with the \
-                         ranges array",
-                    )
-                    .details(format!(
-                        "The range ({}..{}) doesn't match the number of elements of the value \
-                         tensor ({}) at dimension {}. Current tensor shape {:?}, value tensor \
-                         shape {:?}, provided ranges {:?}.",
-                        range.start,
-                        range.end,
-                        d_tensor_value,
-                        i,
-                        shape.dims,
-                        shape_value.dims,
-                        ranges,
-                    )),
+                    ops,
+                    TensorError::invalid(
+                        describe("tensor", tensor),
+                        format!(
+                            "the range {range:?} of dimension {i} should start before its end."
+                        ),
+                    ),
                 );
-            }
-
-            if range.start >= range.end {
+            } else if range.end - range.start != d_value {
                 check = check.register(
-                    "Slice Assign",
-                    TensorError::new(
-                        "The provided ranges array has a range where the start index is bigger or \
-                         equal to its end.",
-                    )
-                    .details(format!(
-                        "The range at dimension '{}' starts at '{}' and is greater or equal to \
-                         its end '{}'. Current tensor shape {:?}, value tensor shape {:?}, \
-                         provided ranges {:?}.",
-                        i, range.start, range.end, shape.dims, shape_value.dims, ranges,
-                    )),
+                    ops,
+                    TensorError::incompatible(
+                        describe("tensor", tensor),
+                        describe("value", value),
+                        format!(
+                            "the range {range:?} of dimension {i} selects {} elements, while the \
+                             value has {d_value}.",
+                            range.end - range.start
+                        ),
+                    ),
                 );
             }
         }
@@ -797,41 +1036,141 @@ impl TensorCheck {
         check
     }
 
-    pub(crate) fn gather<const D: usize>(
-        dim: usize,
-        shape: &Shape<D>,
-        shape_indices: &Shape<D>,
+    /// Checks that the ranges don't have more dimensions than the tensor.
+    fn ranges_rank<const D1: usize, const D2: usize, T: CheckOperand<D1>>(
+        self,
+        ops: &str,
+        tensor: &T,
     ) -> Self {
-        Self::check_gather_scatter_indices(Self::Ok, "Gather", dim, shape, shape_indices)
+        if D1 >= D2 {
+            return self;
+        }
+
+        self.register(
+            ops,
+            TensorError::invalid(
+                describe("tensor", tensor),
+                format!("the {D2} ranges exceed the {D1} dimensions of the tensor."),
+            ),
+        )
+    }
+
+    pub(crate) fn gather<const D: usize, T, I>(dim: usize, tensor: &T, indices: &I) -> Self
+    where
+        T: CheckOperand<D>,
+        I: CheckOperand<D, Device = T::Device>,
+    {
+        Self::check_gather_scatter_indices(Self::Ok, "Gather", dim, tensor, indices)
     }
 
-    pub(crate) fn scatter<const D: usize>(
+    pub(crate) fn take_along_dim<const D: usize, const DI: usize>(
         dim: usize,
         shape: &Shape<D>,
-        shape_indices: &Shape<D>,
-        shape_value: &Shape<D>,
+        shape_indices: &Shape<DI>,
     ) -> Self {
-        let ops = "Scatter";
-        let mut check =
-            Self::check_gather_scatter_indices(Self::Ok, ops, dim, shape, shape_indices);
+        let ops = "TakeAlongDim";
+        let mut check = Self::Ok;
 
-        if shape_indices != shape_value {
+        if DI > D {
             check = check.register(
                 ops,
                 TensorError::new(
-                    "Indices tensor shape should be the same as the value tensor shape."
-                        .to_string(),
+                    "The index tensor can't have more dimensions than the tensor.".to_string(),
                 )
                 .details(format!(
-                    "The shape differs: {:?} != {:?}",
-                    shape_indices.dims, shape_value.dims
+                    "The index tensor has ({DI}) dimensions, while the tensor has ({D})."
                 )),
             );
         }
 
-        check
-    }
-
+        if dim >= D {
+            check = check.register(
+                ops,
+                TensorError::new(format!(
+                    "Can't index a tensor with ({D}) dimensions on axis ({dim})"
+                )),
+            );
+        }
+
+        if let Self::Failed(_) = check {
+            return check;
+        }
+
+        // The index tensor is aligned with the trailing dimensions of the tensor.
+        let num_missing = D - DI;
+
+        if dim < num_missing {
+            check = check.register(
+                ops,
+                TensorError::new(
+                    "The index tensor should have the dimension along which the elements are taken."
+                        .to_string(),
+                )
+                .details(format!(
+                    "The index tensor shape {:?} is aligned with the last ({DI}) dimensions of the \
+                     tensor shape {:?}, which don't include the axis ({dim}).",
+                    shape_indices.dims, shape.dims
+                )),
+            );
+        }
+
+        for i in num_missing..D {
+            if i == dim {
+                continue;
+            }
+
+            let tensor_dim_i = shape.dims[i];
+            let indices_dim_i = shape_indices.dims[i - num_missing];
+
+            if indices_dim_i != tensor_dim_i && indices_dim_i != 1 {
+                check = check.register(
+                    ops,
+                    TensorError::new(
+                        "The index tensor shape should be the same as the tensor shape, or be \
+                         broadcastable to it, except for the dimension along which the elements \
+                         are taken."
+                            .to_string(),
+                    )
+                    .details(format!(
+                        "Incompatible size at dimension '{i}' => '{indices_dim_i} != \
+                         {tensor_dim_i}'. Tensor shape {:?}, Index tensor shape {:?}.",
+                        shape.dims, shape_indices.dims
+                    )),
+                );
+            }
+        }
+
+        check
+    }
+
+    pub(crate) fn scatter<const D: usize, T, I>(
+        dim: usize,
+        tensor: &T,
+        indices: &I,
+        values: &T,
+    ) -> Self
+    where
+        T: CheckOperand<D>,
+        I: CheckOperand<D, Device = T::Device>,
+    {
+        let ops = "Scatter";
+        let mut check = Self::check_gather_scatter_indices(Self::Ok, ops, dim, tensor, indices)
+            .same_device(ops, ("tensor", tensor), ("values", values));
+
+        if indices.dims() != values.dims() {
+            check = check.register(
+                ops,
+                TensorError::incompatible(
+                    describe("indices", indices),
+                    describe("values", values),
+                    "the shapes should be equal.",
+                ),
+            );
+        }
+
+        check
+    }
+
     pub(crate) fn select<const D: usize>(dim: usize) -> Self {
         Self::check_select_basic::<D>(Self::Ok, "select", dim)
     }
@@ -840,6 +1179,66 @@ impl TensorCheck {
         Self::check_select_basic::<D>(Self::Ok, "select_assign", dim)
     }
 
+    pub(crate) fn index_put<const D: usize>(
+        dim: usize,
+        shape: &Shape<D>,
+        num_indices: usize,
+        shape_values: &Shape<D>,
+    ) -> Self {
+        let ops = "IndexPut";
+        let mut check = Self::check_select_basic::<D>(Self::Ok, ops, dim);
+
+        if dim >= D {
+            return check;
+        }
+
+        let mut shape_expected = shape.clone();
+        shape_expected.dims[dim] = num_indices;
+
+        if &shape_expected != shape_values {
+            check = check.register(
+                ops,
+                TensorError::new(
+                    "Values tensor shape should be the same as the tensor shape, except for the \
+                     dimension whose size should be the number of indices."
+                        .to_string(),
+                )
+                .details(format!(
+                    "The shape differs: {:?} != {:?}",
+                    shape_values.dims, shape_expected.dims
+                )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn index_put_nd<const D: usize>(
+        shape_indices: &[Shape<1>; D],
+        shape_values: &Shape<1>,
+    ) -> Self {
+        let ops = "IndexPutNd";
+        let mut check = Self::Ok;
+
+        for (dim, shape_index) in shape_indices.iter().enumerate() {
+            if shape_index != shape_values {
+                check = check.register(
+                    ops,
+                    TensorError::new(
+                        "Each indices tensor should have as many elements as the values tensor."
+                            .to_string(),
+                    )
+                    .details(format!(
+                        "The indices of dimension {dim} have {} elements, while the values have {}.",
+                        shape_index.dims[0], shape_values.dims[0]
+                    )),
+                );
+            }
+        }
+
+        check
+    }
+
     fn check_select_basic<const D: usize>(mut check: Self, ops: &str, dim: usize) -> Self {
         if dim > D {
             check = check.register(
@@ -852,14 +1251,18 @@ impl TensorCheck {
 
         check
     }
-    fn check_gather_scatter_indices<const D: usize>(
+    fn check_gather_scatter_indices<const D: usize, T, I>(
         mut check: Self,
         ops: &str,
         dim: usize,
-        shape: &Shape<D>,
-        shape_indices: &Shape<D>,
-    ) -> Self {
-        if dim > D {
+        tensor: &T,
+        indices: &I,
+    ) -> Self
+    where
+        T: CheckOperand<D>,
+        I: CheckOperand<D, Device = T::Device>,
+    {
+        if dim >= D {
             check = check.register(
                 ops,
                 TensorError::new(format!(
@@ -868,24 +1271,30 @@ impl TensorCheck {
             );
         }
 
+        check = check.same_device(ops, ("tensor", tensor), ("indices", indices));
+
+        let dims = tensor.dims();
+        let dims_indices = indices.dims();
+
         for i in 0..D {
             if i == dim {
                 continue;
             }
 
-            let tensor_dim_i = shape.dims[i];
-            let indices_dim_i = shape_indices.dims[i];
+            let tensor_dim_i = dims[i];
+            let indices_dim_i = dims_indices[i];
 
             if tensor_dim_i != indices_dim_i {
                 check = check.register(
                     ops,
-                    TensorError::new(
-                        "The tensor shape should be the same as the index tensor shape."
-                            .to_string(),
-                    )
-                    .details(format!(
-                        "The shape differs at dimension {i}: {tensor_dim_i} != {indices_dim_i}"
-                    )),
+                    TensorError::incompatible(
+                        describe("tensor", tensor),
+                        describe("indices", indices),
+                        format!(
+                            "the sizes {tensor_dim_i} and {indices_dim_i} of dimension {i} should \
+                             be equal, except for the indexed dimension {dim}."
+                        ),
+                    ),
                 );
             }
         }
@@ -981,33 +1390,76 @@ impl TensorCheck {
         })
     }
 
-    /// Checks if shapes are compatible for element wise operations supporting broadcasting.
-    pub(crate) fn binary_ops_ew_shape<const D: usize>(
+    /// Checks that the operands are on the same device.
+    fn same_device<const D1: usize, const D2: usize, L, R>(
         self,
         ops: &str,
-        lhs: &Shape<D>,
-        rhs: &Shape<D>,
+        (name_lhs, lhs): (&str, &L),
+        (name_rhs, rhs): (&str, &R),
+    ) -> Self
+    where
+        L: CheckOperand<D1>,
+        R: CheckOperand<D2, Device = L::Device>,
+    {
+        if lhs.device() == rhs.device() {
+            return self;
+        }
+
+        self.register(
+            ops,
+            TensorError::incompatible(
+                describe(name_lhs, lhs),
+                describe(name_rhs, rhs),
+                "the tensors should be on the same device.",
+            ),
+        )
+    }
+
+    /// Checks that the tensor at the index of a list is on the same device as the first one,
+    /// naming the operands only when they aren't.
+    fn same_device_at<const D: usize, T: CheckOperand<D>>(
+        self,
+        ops: &str,
+        reference: &T,
+        tensor: &T,
+        index: usize,
     ) -> Self {
-        let mut check = self;
+        if tensor.device() == reference.device() {
+            return self;
+        }
 
-        for i in 0..D {
-            let d_lhs = lhs.dims[i];
-            let d_rhs = rhs.dims[i];
+        self.same_device(
+            ops,
+            ("tensors[0]", reference),
+            (&format!("tensors[{index}]"), tensor),
+        )
+    }
 
-            if d_lhs != d_rhs {
-                let is_broadcast = d_lhs == 1 || d_rhs == 1;
+    /// Checks if the shapes of the operands are compatible for element wise operations
+    /// supporting broadcasting.
+    fn broadcast_shapes<const D: usize, L: CheckOperand<D>, R: CheckOperand<D>>(
+        self,
+        ops: &str,
+        (name_lhs, lhs): (&str, &L),
+        (name_rhs, rhs): (&str, &R),
+    ) -> Self {
+        let mut check = self;
+        let dims_lhs = lhs.dims();
+        let dims_rhs = rhs.dims();
 
-                if is_broadcast {
-                    continue;
-                }
+        for i in 0..D {
+            let d_lhs = dims_lhs[i];
+            let d_rhs = dims_rhs[i];
 
+            if d_lhs != d_rhs && d_lhs != 1 && d_rhs != 1 {
                 check = check.register(
                     ops,
-                    TensorError::new("The provided tensors have incompatible shapes.").details(
+                    TensorError::incompatible(
+                        describe(name_lhs, lhs),
+                        describe(name_rhs, rhs),
                         format!(
-                            "Incompatible size at dimension '{}' => '{} != {}', which can't be \
-                             broadcasted. Lhs tensor shape {:?}, Rhs tensor shape {:?}.",
-                            i, d_lhs, d_rhs, lhs.dims, rhs.dims,
+                            "the sizes {d_lhs} and {d_rhs} of dimension {i} should be equal, or \
+                             one of them 1 to be broadcasted."
                         ),
                     ),
                 );
@@ -1017,31 +1469,14 @@ impl TensorCheck {
         check
     }
 
-    /// Checks if tensor devices are equal.
-    fn binary_ops_device<Device: PartialEq + core::fmt::Debug>(
-        self,
-        ops: &str,
-        lhs: &Device,
-        rhs: &Device,
-    ) -> Self {
-        match lhs != rhs {
-            true => self.register(
-                ops,
-                TensorError::new("The provided tensors are not on the same device.").details(
-                    format!("Lhs tensor device {lhs:?}, Rhs tensor device {rhs:?}.",),
-                ),
-            ),
-            false => self,
-        }
-    }
-
     /// Checks if expand operation is possible for the given shapes.
-    pub fn expand<const D1: usize, const D2: usize>(
+    pub(crate) fn expand<const D1: usize, const D2: usize, T: CheckOperand<D1>>(
         ops: &str,
-        shape: &Shape<D1>,
+        tensor: &T,
         to: &Shape<D2>,
     ) -> Self {
         let mut check = TensorCheck::Ok;
+        let dims = tensor.dims();
         let max_dims = core::cmp::max(D1, D2);
 
         // Calculate the starting indices for each shape array, ensuring alignment from the right.
@@ -1051,7 +1486,7 @@ impl TensorCheck {
         for i in 0..max_dims {
             // Use 1 as the default dimension size for dimensions beyond the tensor's rank.
             let d_shape = if i >= start_index_shape {
-                shape.dims[i - start_index_shape]
+                dims[i - start_index_shape]
             } else {
                 1
             };
@@ -1065,18 +1500,15 @@ impl TensorCheck {
                 // Register an incompatibility error.
                 check = check.register(
                     ops,
-                    TensorError::new(
-                        "The provided tensor can't be broadcasted to the target shape.",
-                    )
-                    .details(format!(
-                        "Incompatible size at dimension '{}' => '{} != {}', which can't be \
-                         broadcasted. Tensor shape {:?}, Target shape {:?}.",
-                        max_dims - i - 1,
-                        d_shape,
-                        d_to,
-                        shape.dims,
-                        to.dims,
-                    )),
+                    TensorError::invalid(
+                        describe("tensor", tensor),
+                        format!(
+                            "the size {d_shape} can't be broadcasted to the size {d_to} of \
+                             dimension {} of the target shape {:?}.",
+                            i.saturating_sub(start_index_to),
+                            to.dims,
+                        ),
+                    ),
                 );
                 break; // Incompatibility found, no need to check further.
             }
@@ -1084,6 +1516,272 @@ impl TensorCheck {
 
         check
     }
+
+    /// Checks the channels of the input, the weight and the bias of a convolution, whose weight
+    /// has the shape `[channels_out, channels_in / groups, kernel_size...]`.
+    pub(crate) fn conv<const D: usize, T, TB>(
+        ops: &str,
+        x: &T,
+        weight: &T,
+        bias: Option<&TB>,
+        groups: usize,
+    ) -> Self
+    where
+        T: CheckOperand<D>,
+        TB: CheckOperand<1, Device = T::Device>,
+    {
+        let mut check = Self::Ok.same_device(ops, ("x", x), ("weight", weight));
+        let channels_in = x.dims()[1];
+        let dims_weight = weight.dims();
+        let channels_out = dims_weight[0];
+        let channels_per_group = dims_weight[1];
+
+        if channels_in != channels_per_group * groups {
+            check = check.register(
+                ops,
+                TensorError::incompatible(
+                    describe("x", x),
+                    describe("weight", weight),
+                    format!(
+                        "the {channels_in} input channels should be the {channels_per_group} \
+                         channels of the weight times the {groups} groups."
+                    ),
+                ),
+            );
+        }
+
+        if channels_out % groups != 0 {
+            check = check.register(
+                ops,
+                TensorError::invalid(
+                    describe("weight", weight),
+                    format!(
+                        "the {channels_out} output channels should be divisible by the {groups} \
+                         groups."
+                    ),
+                ),
+            );
+        }
+
+        match bias {
+            Some(bias) => check.conv_bias(ops, weight, bias, channels_out),
+            None => check,
+        }
+    }
+
+    /// Checks the channels of the input, the weight and the bias of a transposed convolution,
+    /// whose weight has the shape `[channels_in, channels_out / groups, kernel_size...]`.
+    pub(crate) fn conv_transpose<const D: usize, T, TB>(
+        ops: &str,
+        x: &T,
+        weight: &T,
+        bias: Option<&TB>,
+        groups: usize,
+    ) -> Self
+    where
+        T: CheckOperand<D>,
+        TB: CheckOperand<1, Device = T::Device>,
+    {
+        let mut check = Self::Ok.same_device(ops, ("x", x), ("weight", weight));
+        let channels_in = x.dims()[1];
+        let dims_weight = weight.dims();
+        let channels_in_weight = dims_weight[0];
+        let channels_per_group = dims_weight[1];
+
+        if channels_in != channels_in_weight {
+            check = check.register(
+                ops,
+                TensorError::incompatible(
+                    describe("x", x),
+                    describe("weight", weight),
+                    format!(
+                        "the {channels_in} input channels should be the {channels_in_weight} \
+                         input channels of the weight."
+                    ),
+                ),
+            );
+        }
+
+        if channels_in_weight % groups != 0 {
+            check = check.register(
+                ops,
+                TensorError::invalid(
+                    describe("weight", weight),
+                    format!(
+                        "the {channels_in_weight} input channels should be divisible by the \
+                         {groups} groups."
+                    ),
+                ),
+            );
+        }
+
+        match bias {
+            Some(bias) => check.conv_bias(ops, weight, bias, channels_per_group * groups),
+            None => check,
+        }
+    }
+
+    fn conv_bias<const D: usize, T, TB>(
+        self,
+        ops: &str,
+        weight: &T,
+        bias: &TB,
+        channels_out: usize,
+    ) -> Self
+    where
+        T: CheckOperand<D>,
+        TB: CheckOperand<1, Device = T::Device>,
+    {
+        let [size] = bias.dims();
+        let check = self.same_device(ops, ("weight", weight), ("bias", bias));
+
+        if size == channels_out {
+            return check;
+        }
+
+        check.register(
+            ops,
+            TensorError::incompatible(
+                describe("weight", weight),
+                describe("bias", bias),
+                format!("the bias size should be the {channels_out} output channels."),
+            ),
+        )
+    }
+
+    /// Checks that the packed tensor has the number of words needed to unpack the shape.
+    pub(crate) fn from_packed<const D: usize, T: CheckOperand<1>>(
+        packed: &T,
+        shape: &Shape<D>,
+    ) -> Self {
+        let [num_words] = packed.dims();
+        let num_words_expected = shape.num_elements().div_ceil(32);
+
+        if num_words == num_words_expected {
+            return Self::Ok;
+        }
+
+        Self::Ok.register(
+            "From Packed",
+            TensorError::invalid(
+                describe("packed", packed),
+                format!(
+                    "{num_words_expected} words are needed to unpack a tensor of shape {:?}, got \
+                     {num_words}.",
+                    shape.dims
+                ),
+            ),
+        )
+    }
+
+    /// Checks the coordinates and the values of a sparse matrix.
+    pub(crate) fn sparse_coo<I, V>(indices: &I, values: &V) -> Self
+    where
+        I: CheckOperand<2>,
+        V: CheckOperand<1, Device = I::Device>,
+    {
+        let ops = "Sparse COO";
+        let mut check = Self::Ok.same_device(ops, ("indices", indices), ("values", values));
+        let [num_coordinates, num_entries] = indices.dims();
+        let [num_values] = values.dims();
+
+        if num_coordinates != 2 {
+            check = check.register(
+                ops,
+                TensorError::invalid(
+                    describe("indices", indices),
+                    "the indices should have 2 rows, the row and the column of each value.",
+                ),
+            );
+        }
+
+        if num_entries != num_values {
+            check = check.register(
+                ops,
+                TensorError::incompatible(
+                    describe("indices", indices),
+                    describe("values", values),
+                    format!(
+                        "each of the {num_entries} coordinates should have a value, got \
+                         {num_values} values."
+                    ),
+                ),
+            );
+        }
+
+        check
+    }
+
+    /// Checks that the dense matrix has the shape of the sparse matrix it scales.
+    pub(crate) fn sparse_mul<T: CheckOperand<2>>(shape: &Shape<2>, dense: &T) -> Self {
+        if dense.dims() == shape.dims {
+            return Self::Ok;
+        }
+
+        Self::Ok.register(
+            "Sparse Mul",
+            TensorError::incompatible(
+                format!("sparse shape {:?}", shape.dims),
+                describe("dense", dense),
+                "the shapes should be equal.",
+            ),
+        )
+    }
+
+    /// Checks that the sparse matrix of the shape can be multiplied by the dense matrix.
+    pub(crate) fn spmm<T: CheckOperand<2>>(shape: &Shape<2>, dense: &T) -> Self {
+        let [dim_sparse, dim_dense] = [shape.dims[1], dense.dims()[0]];
+
+        if dim_sparse == dim_dense {
+            return Self::Ok;
+        }
+
+        Self::Ok.register(
+            "Sparse Matmul",
+            TensorError::incompatible(
+                format!("sparse shape {:?}", shape.dims),
+                describe("dense", dense),
+                format!("the inner dimensions {dim_sparse} and {dim_dense} should be equal."),
+            ),
+        )
+    }
+
+    /// Checks that the product of the dense matrices can be sampled at the coordinates of the
+    /// sparse matrix of the shape.
+    pub(crate) fn sddmm<T: CheckOperand<2>>(shape: &Shape<2>, lhs: &T, rhs: &T) -> Self {
+        let ops = "Sampled Dense Matmul";
+        let mut check = Self::Ok.same_device(ops, ("lhs", lhs), ("rhs", rhs));
+        let [rows, dim_lhs] = lhs.dims();
+        let [dim_rhs, cols] = rhs.dims();
+
+        if dim_lhs != dim_rhs {
+            check = check.register(
+                ops,
+                TensorError::incompatible(
+                    describe("lhs", lhs),
+                    describe("rhs", rhs),
+                    format!("the inner dimensions {dim_lhs} and {dim_rhs} should be equal."),
+                ),
+            );
+        }
+
+        if [rows, cols] != shape.dims {
+            check = check.register(
+                ops,
+                TensorError::incompatible(
+                    describe("lhs", lhs),
+                    describe("rhs", rhs),
+                    format!(
+                        "the product shape {:?} should be the sparse shape {:?}.",
+                        [rows, cols],
+                        shape.dims
+                    ),
+                ),
+            );
+        }
+
+        check
+    }
 }
 
 pub(crate) struct FailedTensorCheck {
@@ -1117,6 +1815,17 @@ impl TensorError {
         }
     }
 
+    /// An error of two operands violating the rule, such as `lhs shape [2, 3] (f32, Cpu)
+    /// incompatible with rhs shape [4, 5] (f32, Cpu): ...`.
+    pub(crate) fn incompatible(lhs: String, rhs: String, rule: impl Display) -> Self {
+        Self::new(format!("{lhs} incompatible with {rhs}: {rule}"))
+    }
+
+    /// An error of an operand violating the rule.
+    pub(crate) fn invalid(operand: String, rule: impl Display) -> Self {
+        Self::new(format!("{operand}: {rule}"))
+    }
+
     pub(crate) fn details<S: Into<String>>(mut self, details: S) -> Self {
         self.details = Some(details.into());
         self
@@ -1125,11 +1834,10 @@ impl TensorError {
     fn format(self, number: usize) -> String {
         let mut message = format!("\n    {number}. ");
         message += self.description.as_str();
-        message += " ";
 
         if let Some(details) = self.details {
-            message += details.as_str();
             message += " ";
+            message += details.as_str();
         }
 
         message
@@ -1156,11 +1864,68 @@ mod tests {
     use super::*;
     use macros::check;
 
+    #[derive(Debug, Clone, PartialEq)]
+    enum TestDevice {
+        Cpu,
+        Gpu(usize),
+    }
+
+    /// An operand described without a backend.
+    #[derive(Clone)]
+    struct TestOperand<const D: usize> {
+        dims: [usize; D],
+        dtype: DType,
+        device: TestDevice,
+    }
+
+    impl<const D: usize> TestOperand<D> {
+        fn with_dtype(mut self, dtype: DType) -> Self {
+            self.dtype = dtype;
+            self
+        }
+
+        fn on(mut self, device: TestDevice) -> Self {
+            self.device = device;
+            self
+        }
+    }
+
+    impl<const D: usize> CheckOperand<D> for TestOperand<D> {
+        type Device = TestDevice;
+
+        fn dims(&self) -> [usize; D] {
+            self.dims
+        }
+
+        fn device(&self) -> TestDevice {
+            self.device.clone()
+        }
+
+        fn dtype(&self) -> DType {
+            self.dtype
+        }
+    }
+
+    fn operand<const D: usize>(dims: [usize; D]) -> TestOperand<D> {
+        TestOperand {
+            dims,
+            dtype: DType::F32,
+            device: TestDevice::Cpu,
+        }
+    }
+
+    fn message(check: TensorCheck) -> String {
+        match check {
+            TensorCheck::Ok => panic!("The check should fail."),
+            TensorCheck::Failed(failed) => failed.format(),
+        }
+    }
+
     #[test]
     #[should_panic]
     fn reshape_invalid_shape() {
         check!(TensorCheck::reshape_args_usize(
-            &Shape::new([2, 2]),
+            &operand([2, 2]),
             &Shape::new([1, 3])
         ));
     }
@@ -1168,7 +1933,7 @@ mod tests {
     #[test]
     fn reshape_valid_shape() {
         check!(TensorCheck::reshape_args_usize(
-            &Shape::new([2, 2]),
+            &operand([2, 2]),
             &Shape::new([1, 4])
         ));
     }
@@ -1176,50 +1941,358 @@ mod tests {
     #[test]
     #[should_panic]
     fn index_range_exceed_dimension() {
-        check!(TensorCheck::slice(
-            &Shape::new([3, 5, 7]),
-            &[0..2, 0..4, 1..8]
-        ));
+        check!(TensorCheck::slice(&operand([3, 5, 7]), &[0..2, 0..4, 1..8]));
     }
 
     #[test]
     #[should_panic]
     fn index_range_exceed_number_of_dimensions() {
-        check!(TensorCheck::slice(&Shape::new([3, 5]), &[0..1, 0..1, 0..1]));
+        check!(TensorCheck::slice(&operand([3, 5]), &[0..1, 0..1, 0..1]));
     }
 
     #[test]
     #[should_panic]
     fn binary_ops_shapes_no_broadcast() {
-        check!(TensorCheck::binary_ops_ew_shape(
-            TensorCheck::Ok,
+        check!(TensorCheck::binary_ops_ew(
             "TestOps",
-            &Shape::new([3, 5]),
-            &Shape::new([3, 6])
+            &operand([3, 5]),
+            &operand([3, 6])
         ));
     }
 
     #[test]
     fn binary_ops_shapes_with_broadcast() {
-        check!(TensorCheck::binary_ops_ew_shape(
-            TensorCheck::Ok,
+        check!(TensorCheck::binary_ops_ew(
             "Test",
-            &Shape::new([3, 5]),
-            &Shape::new([1, 5])
+            &operand([3, 5]),
+            &operand([1, 5])
         ));
     }
 
     #[test]
     #[should_panic]
     fn binary_ops_devices() {
-        check!(TensorCheck::binary_ops_device(
-            TensorCheck::Ok,
+        check!(TensorCheck::binary_ops_ew(
             "Test",
-            &5, // We can pass anything that implements PartialEq as device
-            &8
+            &operand([3, 5]),
+            &operand([3, 5]).on(TestDevice::Gpu(0))
         ));
     }
 
+    #[test]
+    fn binary_ops_shapes_message() {
+        let check = TensorCheck::binary_ops_ew("Add", &operand([3, 5]), &operand([3, 6]));
+
+        assert_eq!(
+            message(check),
+            "=== Tensor Operation Error ===
+  Operation: 'Add'
+  Reason:
+    1. lhs shape [3, 5] (f32, Cpu) incompatible with rhs shape [3, 6] (f32, Cpu): the sizes 5 and 6 of dimension 1 should be equal, or one of them 1 to be broadcasted.
+"
+        );
+    }
+
+    #[test]
+    fn binary_ops_devices_message() {
+        let rhs = operand([2]).on(TestDevice::Gpu(1));
+        let check = TensorCheck::binary_ops_ew("Mul", &operand([2]), &rhs);
+
+        assert_eq!(
+            message(check),
+            "=== Tensor Operation Error ===
+  Operation: 'Mul'
+  Reason:
+    1. lhs shape [2] (f32, Cpu) incompatible with rhs shape [2] (f32, Gpu(1)): the tensors should be on the same device.
+"
+        );
+    }
+
+    #[test]
+    fn matmul_inner_dimensions_message() {
+        let check = TensorCheck::matmul(&operand([2, 3, 5]), &operand([2, 4, 7]));
+
+        assert_eq!(
+            message(check),
+            "=== Tensor Operation Error ===
+  Operation: 'Matmul'
+  Reason:
+    1. lhs shape [2, 3, 5] (f32, Cpu) incompatible with rhs shape [2, 4, 7] (f32, Cpu): the inner dimensions 5 and 4 should be equal.
+"
+        );
+    }
+
+    #[test]
+    fn matmul_batch_dimensions_message() {
+        let check = TensorCheck::matmul(&operand([2, 3, 5]), &operand([4, 5, 7]));
+
+        assert_eq!(
+            message(check),
+            "=== Tensor Operation Error ===
+  Operation: 'Matmul'
+  Reason:
+    1. lhs shape [2, 3, 5] (f32, Cpu) incompatible with rhs shape [4, 5, 7] (f32, Cpu): the batch sizes 2 and 4 of dimension 0 should be equal, or one of them 1 to be broadcasted.
+"
+        );
+    }
+
+    #[test]
+    fn cat_shapes_message() {
+        let tensors = [operand([2, 3]), operand([2, 3]), operand([3, 4])];
+        let check = TensorCheck::cat(&tensors, 0);
+
+        assert_eq!(
+            message(check),
+            "=== Tensor Operation Error ===
+  Operation: 'Cat'
+  Reason:
+    1. tensors[0] shape [2, 3] (f32, Cpu) incompatible with tensors[2] shape [3, 4] (f32, Cpu): the sizes 3 and 4 of dimension 1 should be equal, except for the concatenated dimension 0.
+"
+        );
+    }
+
+    #[test]
+    fn cat_devices_message() {
+        let tensors = [operand([2]), operand([2]).on(TestDevice::Gpu(0))];
+        let check = TensorCheck::cat(&tensors, 0);
+
+        assert_eq!(
+            message(check),
+            "=== Tensor Operation Error ===
+  Operation: 'Cat'
+  Reason:
+    1. tensors[0] shape [2] (f32, Cpu) incompatible with tensors[1] shape [2] (f32, Gpu(0)): the tensors should be on the same device.
+"
+        );
+    }
+
+    #[test]
+    fn stack_shapes_message() {
+        let check = TensorCheck::stack(&[operand([2, 3]), operand([3, 2])], 0);
+
+        assert_eq!(
+            message(check),
+            "=== Tensor Operation Error ===
+  Operation: 'Stack'
+  Reason:
+    1. tensors[0] shape [2, 3] (f32, Cpu) incompatible with tensors[1] shape [3, 2] (f32, Cpu): the shapes should be equal.
+"
+        );
+    }
+
+    #[test]
+    fn slice_range_message() {
+        let check = TensorCheck::slice(&operand([3, 5, 7]), &[0..2, 0..4, 1..8]);
+
+        assert_eq!(
+            message(check),
+            "=== Tensor Operation Error ===
+  Operation: 'Slice'
+  Reason:
+    1. tensor shape [3, 5, 7] (f32, Cpu): the range 1..8 of dimension 2 exceeds its size 7.
+"
+        );
+    }
+
+    #[test]
+    fn slice_assign_value_message() {
+        let check = TensorCheck::slice_assign(&operand([4, 4]), &operand([2, 3]), &[0..2, 1..3]);
+
+        assert_eq!(
+            message(check),
+            "=== Tensor Operation Error ===
+  Operation: 'Slice Assign'
+  Reason:
+    1. tensor shape [4, 4] (f32, Cpu) incompatible with value shape [2, 3] (f32, Cpu): the range 1..3 of dimension 1 selects 2 elements, while the value has 3.
+"
+        );
+    }
+
+    #[test]
+    fn gather_indices_message() {
+        let indices = operand([2, 2]).with_dtype(DType::I64);
+        let check = TensorCheck::gather(1, &operand([3, 4]), &indices);
+
+        assert_eq!(
+            message(check),
+            "=== Tensor Operation Error ===
+  Operation: 'Gather'
+  Reason:
+    1. tensor shape [3, 4] (f32, Cpu) incompatible with indices shape [2, 2] (i64, Cpu): the sizes 3 and 2 of dimension 0 should be equal, except for the indexed dimension 1.
+"
+        );
+    }
+
+    #[test]
+    fn scatter_values_message() {
+        let indices = operand([2, 4]).with_dtype(DType::I64);
+        let check = TensorCheck::scatter(0, &operand([3, 4]), &indices, &operand([2, 3]));
+
+        assert_eq!(
+            message(check),
+            "=== Tensor Operation Error ===
+  Operation: 'Scatter'
+  Reason:
+    1. indices shape [2, 4] (i64, Cpu) incompatible with values shape [2, 3] (f32, Cpu): the shapes should be equal.
+"
+        );
+    }
+
+    #[test]
+    fn mask_where_shapes_message() {
+        let mask = operand([2, 4]).with_dtype(DType::Bool);
+        let check = TensorCheck::mask_where(&operand([2, 3]), &mask, &operand([2, 3]));
+
+        assert_eq!(
+            message(check),
+            "=== Tensor Operation Error ===
+  Operation: 'Mask Where'
+  Reason:
+    1. tensor shape [2, 3] (f32, Cpu) incompatible with mask shape [2, 4] (bool, Cpu): the sizes 3 and 4 of dimension 1 should be equal, or one of them 1 to be broadcasted.
+    2. mask shape [2, 4] (bool, Cpu) incompatible with value shape [2, 3] (f32, Cpu): the sizes 4 and 3 of dimension 1 should be equal, or one of them 1 to be broadcasted.
+"
+        );
+    }
+
+    #[test]
+    fn expand_message() {
+        let check = TensorCheck::expand("Expand", &operand([2, 3]), &Shape::new([4, 2, 4]));
+
+        assert_eq!(
+            message(check),
+            "=== Tensor Operation Error ===
+  Operation: 'Expand'
+  Reason:
+    1. tensor shape [2, 3] (f32, Cpu): the size 3 can't be broadcasted to the size 4 of dimension 2 of the target shape [4, 2, 4].
+"
+        );
+    }
+
+    #[test]
+    fn reshape_message() {
+        let check = TensorCheck::reshape_args_usize(&operand([2, 2]), &Shape::new([1, 3]));
+
+        assert_eq!(
+            message(check),
+            "=== Tensor Operation Error ===
+  Operation: 'Reshape'
+  Reason:
+    1. tensor shape [2, 2] (f32, Cpu): the 4 elements can't be reshaped to [1, 3], which has 3 elements.
+"
+        );
+    }
+
+    #[test]
+    fn conv_channels_message() {
+        let check = TensorCheck::conv(
+            "Conv2d",
+            &operand([1, 3, 8, 8]),
+            &operand([4, 2, 3, 3]),
+            None::<&TestOperand<1>>,
+            1,
+        );
+
+        assert_eq!(
+            message(check),
+            "=== Tensor Operation Error ===
+  Operation: 'Conv2d'
+  Reason:
+    1. x shape [1, 3, 8, 8] (f32, Cpu) incompatible with weight shape [4, 2, 3, 3] (f32, Cpu): the 3 input channels should be the 2 channels of the weight times the 1 groups.
+"
+        );
+    }
+
+    #[test]
+    fn conv_grouped_channels_should_pass() {
+        check!(TensorCheck::conv(
+            "Conv2d",
+            &operand([1, 4, 8, 8]),
+            &operand([6, 2, 3, 3]),
+            Some(&operand([6])),
+            2,
+        ));
+    }
+
+    #[test]
+    fn conv_transpose_bias_message() {
+        let check = TensorCheck::conv_transpose(
+            "ConvTranspose1d",
+            &operand([1, 4, 8]),
+            &operand([4, 3, 3]),
+            Some(&operand([5])),
+            2,
+        );
+
+        assert_eq!(
+            message(check),
+            "=== Tensor Operation Error ===
+  Operation: 'ConvTranspose1d'
+  Reason:
+    1. weight shape [4, 3, 3] (f32, Cpu) incompatible with bias shape [5] (f32, Cpu): the bias size should be the 6 output channels.
+"
+        );
+    }
+
+    #[test]
+    fn from_packed_message() {
+        let packed = operand([1]).with_dtype(DType::I32);
+        let check = TensorCheck::from_packed(&packed, &Shape::new([40]));
+
+        assert_eq!(
+            message(check),
+            "=== Tensor Operation Error ===
+  Operation: 'From Packed'
+  Reason:
+    1. packed shape [1] (i32, Cpu): 2 words are needed to unpack a tensor of shape [40], got 1.
+"
+        );
+    }
+
+    #[test]
+    fn sparse_coo_message() {
+        let indices = operand([3, 4]).with_dtype(DType::I64);
+        let values = operand([5]);
+        let check = TensorCheck::sparse_coo(&indices, &values);
+
+        assert_eq!(
+            message(check),
+            "=== Tensor Operation Error ===
+  Operation: 'Sparse COO'
+  Reason:
+    1. indices shape [3, 4] (i64, Cpu): the indices should have 2 rows, the row and the column of each value.
+    2. indices shape [3, 4] (i64, Cpu) incompatible with values shape [5] (f32, Cpu): each of the 4 coordinates should have a value, got 5 values.
+"
+        );
+    }
+
+    #[test]
+    fn spmm_message() {
+        let check = TensorCheck::spmm(&Shape::new([4, 6]), &operand([5, 3]));
+
+        assert_eq!(
+            message(check),
+            "=== Tensor Operation Error ===
+  Operation: 'Sparse Matmul'
+  Reason:
+    1. sparse shape [4, 6] incompatible with dense shape [5, 3] (f32, Cpu): the inner dimensions 6 and 5 should be equal.
+"
+        );
+    }
+
+    #[test]
+    fn sddmm_message() {
+        let check = TensorCheck::sddmm(&Shape::new([4, 6]), &operand([4, 8]), &operand([8, 5]));
+
+        assert_eq!(
+            message(check),
+            "=== Tensor Operation Error ===
+  Operation: 'Sampled Dense Matmul'
+  Reason:
+    1. lhs shape [4, 8] (f32, Cpu) incompatible with rhs shape [8, 5] (f32, Cpu): the product shape [4, 5] should be the sparse shape [4, 6].
+"
+        );
+    }
+
     #[test]
     #[should_panic]
     fn movedim_args_out_of_bounds() {
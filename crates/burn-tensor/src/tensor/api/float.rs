@@ -9,7 +9,7 @@ use crate::tensor::backend::Backend;
 use crate::tensor::stats;
 use crate::tensor::{Distribution, Shape, TensorData};
 use crate::Tensor;
-use crate::{Int, TensorPrimitive};
+use crate::{ElementConversion, Int, TensorPrimitive};
 
 impl<const D: usize, B> Tensor<B, D>
 where
@@ -69,6 +69,16 @@ where
         )))
     }
 
+    /// Applies the inverse of the [error function](https://en.wikipedia.org/wiki/Error_function)
+    /// element wise.
+    ///
+    /// `y = erfinv(x)`
+    pub fn erfinv(self) -> Self {
+        Self::new(TensorPrimitive::Float(B::float_erfinv(
+            self.primitive.tensor(),
+        )))
+    }
+
     /// Applies element wise reciprocal operation.
     pub fn recip(self) -> Self {
         Self::new(TensorPrimitive::Float(B::float_recip(
@@ -200,9 +210,14 @@ where
     ///
     /// `C = AB`
     ///
+    /// The batch dimensions, all but the last two, are broadcast like the element-wise
+    /// operations, so a dimension of size 1 is shared by all the matrices of the other tensor
+    /// without being repeated, e.g. a query of shape `[batch, 1, 1, k]` with the keys of shape
+    /// `[batch, heads, k, n]`.
+    ///
     /// # Panics
     ///
-    /// If the two tensors dont' have a compatible shape.
+    /// If the two tensors don't have a compatible shape.
     pub fn matmul(self, other: Self) -> Self {
         check!(TensorCheck::matmul(&self, &other));
         Self::new(TensorPrimitive::Float(B::float_matmul(
@@ -235,6 +250,48 @@ where
         (var, mean)
     }
 
+    /// Calculate the variance along the given dimensions, which are kept with a size of 1.
+    ///
+    /// The sum of the squared differences to the mean is divided by the number of reduced elements
+    /// minus the `correction`, so a correction of `0` gives the biased variance and a correction of
+    /// `1` applies the Bessel’s correction.
+    ///
+    /// # Panics
+    ///
+    /// If a dimension is higher than the tensor rank or is given twice, or if the correction isn't
+    /// smaller than the number of reduced elements, which would divide by zero.
+    pub fn var_dims(self, dims: &[usize], correction: usize) -> Self {
+        self.var_mean_dims(dims, correction).0
+    }
+
+    /// Calculate the standard deviation along the given dimensions, which are kept with a size
+    /// of 1.
+    ///
+    /// See [var_dims](Tensor::var_dims) for the meaning of the `correction`.
+    pub fn std_dims(self, dims: &[usize], correction: usize) -> Self {
+        self.var_dims(dims, correction).sqrt()
+    }
+
+    /// Calculate the variance along the given dimensions and also returns the mean, in a single
+    /// pass when supported by the backend.
+    ///
+    /// See [var_dims](Tensor::var_dims) for the meaning of the `correction`.
+    pub fn var_mean_dims(self, dims: &[usize], correction: usize) -> (Self, Self) {
+        check!(TensorCheck::dims_ops::<D>("Var", dims));
+        check!(TensorCheck::var_correction::<D>(
+            "Var",
+            &self.shape(),
+            dims,
+            correction
+        ));
+
+        let (var, mean) = B::float_var_mean(self.primitive.tensor(), dims, correction);
+        (
+            Self::new(TensorPrimitive::Float(var)),
+            Self::new(TensorPrimitive::Float(mean)),
+        )
+    }
+
     /// Returns a tensor with full precision based on the selected backend.
     pub fn into_full_precision(self) -> Tensor<FullPrecisionBackend<B>, D> {
         Tensor::new(TensorPrimitive::Float(B::float_into_full_precision(
@@ -296,6 +353,187 @@ where
         Self::new(TensorPrimitive::Float(B::relu(self.primitive.tensor())))
     }
 
+    /// Sample category indices from the distributions given by the last dimension of the tensor.
+    ///
+    /// The tensor holds the weights of a single distribution when it has one dimension, and
+    /// one distribution per row when it has two. The weights must be non-negative but don't
+    /// need to be normalized, and a row without any weight is sampled uniformly. Without
+    /// replacement, each row must have at least `num_samples` non-zero weights, the indices
+    /// drawn past them being unspecified.
+    ///
+    /// # Arguments
+    ///
+    /// * `num_samples` - The number of samples drawn from each distribution.
+    /// * `replacement` - Whether a category can be drawn more than once in a distribution.
+    ///
+    /// # Returns
+    ///
+    /// The drawn indices, with the last dimension of size `num_samples`.
+    ///
+    /// # Panics
+    ///
+    /// If the tensor has more than two dimensions, or if more samples than categories are drawn
+    /// without replacement.
+    pub fn multinomial(self, num_samples: usize, replacement: bool) -> Tensor<B, D, Int> {
+        check!(TensorCheck::multinomial::<D>(
+            self.dims()[D - 1],
+            num_samples,
+            replacement
+        ));
+
+        let mut dims = self.dims();
+        let num_categories = dims[D - 1];
+        let rows = self.shape().num_elements() / num_categories;
+        dims[D - 1] = num_samples;
+
+        let samples = B::float_multinomial(
+            self.reshape([rows, num_categories]).primitive.tensor(),
+            num_samples,
+            replacement,
+        );
+
+        Tensor::<B, 2, Int>::new(samples).reshape(dims)
+    }
+
+    /// Computes the linear recurrence `y[t] = a[t] * y[t - 1] + b[t]` along a dimension, with
+    /// `y[-1] = 0`, such as the state of a linear state space model over time.
+    ///
+    /// The steps of the recurrence are affine maps, whose composition is associative: the pair
+    /// `(a1, b1)` followed by `(a2, b2)` is `(a1 * a2, a2 * b1 + b2)`. Backends can thus compute
+    /// the recurrence in parallel over the dimension, with a parallel prefix scan.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The coefficients multiplying the previous values.
+    /// * `b` - The values added at each step, of the shape of `a`.
+    /// * `dim` - The dimension of the recurrence.
+    ///
+    /// # Returns
+    ///
+    /// The values of the recurrence, of the shape of `a`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let a = Tensor::<B, 1>::from_floats([0.5, 0.5, 2.0], &device);
+    ///     let b = Tensor::<B, 1>::from_floats([1.0, 2.0, 3.0], &device);
+    ///
+    ///     let y = Tensor::associative_scan(a, b, 0);
+    ///     println!("{y}");
+    ///     // [1.0, 2.5, 8.0]
+    /// }
+    /// ```
+    pub fn associative_scan(a: Self, b: Self, dim: usize) -> Self {
+        check!(TensorCheck::associative_scan::<D>(
+            &a.shape(),
+            &b.shape(),
+            dim
+        ));
+
+        Self::new(TensorPrimitive::Float(B::float_associative_scan(
+            a.primitive.tensor(),
+            b.primitive.tensor(),
+            dim,
+        )))
+    }
+
+    /// Linearly interpolates between the tensor and `end` with the weights of the `weight` tensor.
+    ///
+    /// `y = x + weight * (end - x)`
+    ///
+    /// The interpolation launches a single kernel on the backends that fuse it, for instance to
+    /// update an exponential moving average.
+    pub fn lerp(self, end: Self, weight: Self) -> Self {
+        check!(TensorCheck::ternary_ops_ew("Lerp", &self, &end, &weight));
+
+        Self::new(TensorPrimitive::Float(B::float_lerp(
+            self.primitive.tensor(),
+            end.primitive.tensor(),
+            weight.primitive.tensor(),
+        )))
+    }
+
+    /// Linearly interpolates between the tensor and `end` with the same weight for all elements.
+    ///
+    /// `y = x + weight * (end - x)`
+    pub fn lerp_scalar<E: ElementConversion>(self, end: Self, weight: E) -> Self {
+        check!(TensorCheck::binary_ops_ew("Lerp", &self, &end));
+
+        Self::new(TensorPrimitive::Float(B::float_lerp_scalar(
+            self.primitive.tensor(),
+            end.primitive.tensor(),
+            weight.elem(),
+        )))
+    }
+
+    /// Adds the product of `tensor1` and `tensor2`, scaled by `value`, to the tensor.
+    ///
+    /// `y = x + value * tensor1 * tensor2`
+    pub fn addcmul<E: ElementConversion>(self, tensor1: Self, tensor2: Self, value: E) -> Self {
+        check!(TensorCheck::ternary_ops_ew(
+            "Addcmul", &self, &tensor1, &tensor2
+        ));
+
+        Self::new(TensorPrimitive::Float(B::float_addcmul(
+            self.primitive.tensor(),
+            tensor1.primitive.tensor(),
+            tensor2.primitive.tensor(),
+            value.elem(),
+        )))
+    }
+
+    /// Adds the quotient of `tensor1` by `tensor2`, scaled by `value`, to the tensor.
+    ///
+    /// `y = x + value * tensor1 / tensor2`
+    pub fn addcdiv<E: ElementConversion>(self, tensor1: Self, tensor2: Self, value: E) -> Self {
+        check!(TensorCheck::ternary_ops_ew(
+            "Addcdiv", &self, &tensor1, &tensor2
+        ));
+
+        Self::new(TensorPrimitive::Float(B::float_addcdiv(
+            self.primitive.tensor(),
+            tensor1.primitive.tensor(),
+            tensor2.primitive.tensor(),
+            value.elem(),
+        )))
+    }
+
+    /// Computes the linear combination of the tensor and `other`.
+    ///
+    /// `y = alpha * x + beta * other`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let x = Tensor::<B, 1>::from_floats([1.0, 2.0, 3.0], &device);
+    ///     let y = Tensor::<B, 1>::from_floats([4.0, 4.0, 4.0], &device);
+    ///
+    ///     let z = x.axpby(0.5, y, 2.0);
+    ///     println!("{z}");
+    ///     // [8.5, 9.0, 9.5]
+    /// }
+    /// ```
+    pub fn axpby<E: ElementConversion>(self, alpha: E, other: Self, beta: E) -> Self {
+        check!(TensorCheck::binary_ops_ew("Axpby", &self, &other));
+
+        Self::new(TensorPrimitive::Float(B::float_axpby(
+            self.primitive.tensor(),
+            alpha.elem(),
+            other.primitive.tensor(),
+            beta.elem(),
+        )))
+    }
+
     /// Calculate covaraince matrix between different entries alongside a given dimension.
     ///
     /// # Arguments
@@ -344,4 +582,54 @@ where
     pub fn dequantize(self) -> Tensor<B, D> {
         Tensor::new(TensorPrimitive::Float(self.primitive.tensor()))
     }
+
+    /// Fake quantize the tensor, rounding its values to the quantization grid while keeping them
+    /// as floats, as done by quantization-aware training.
+    ///
+    /// `output = (clamp(round(tensor / scale + zero_point), qmin, qmax) - zero_point) * scale`
+    ///
+    /// The values are rounded to the nearest integer, with ties rounded to the nearest even
+    /// integer. The gradient of the tensor is the straight-through estimator: it is passed where
+    /// `tensor / scale + zero_point` is within `[qmin, qmax]` and is zero elsewhere. The scale
+    /// receives the gradient of [LSQ](https://arxiv.org/abs/1902.08153) and the zero-point the
+    /// gradient of [LSQ+](https://arxiv.org/abs/2004.09576), so that both can be learned.
+    ///
+    /// # Arguments
+    ///
+    /// * `scale` - The scaling factors, a single one or one per channel.
+    /// * `zero_point` - The zero-points, holding integer values, as many as scaling factors.
+    /// * `qmin` - The smallest quantized value.
+    /// * `qmax` - The largest quantized value.
+    /// * `axis` - The dimension of the channels, each quantized with its own parameters, or
+    ///   `None` to quantize the whole tensor with the same parameters.
+    pub fn fake_quantize(
+        self,
+        scale: Tensor<B, 1>,
+        zero_point: Tensor<B, 1>,
+        qmin: i32,
+        qmax: i32,
+        axis: Option<usize>,
+    ) -> Tensor<B, D> {
+        check!(TensorCheck::fake_quantize::<D>(
+            &self.shape(),
+            &scale.shape(),
+            &zero_point.shape(),
+            qmin,
+            qmax,
+            axis
+        ));
+
+        let mut shape = [1; D];
+        if let Some(axis) = axis {
+            shape[axis] = self.dims()[axis];
+        }
+
+        Tensor::new(TensorPrimitive::Float(B::float_fake_quantize(
+            self.primitive.tensor(),
+            scale.reshape(shape).primitive.tensor(),
+            zero_point.reshape(shape).primitive.tensor(),
+            qmin.elem(),
+            qmax.elem(),
+        )))
+    }
 }
@@ -0,0 +1,71 @@
+use crate::{backend::Backend, ops::IntTensor, BasicOps, Element, Int, TensorData, TensorKind};
+use burn_common::reader::try_read_sync;
+
+/// Put the `values` in the `tensor` at the given indices along a dimension, overwriting the
+/// previous elements.
+///
+/// # Arguments
+///
+/// * `tensor` - The tensor to put the values in.
+/// * `dim` - The dimension along which the values are put.
+/// * `indices` - The positions along the dimension, one for each slice of the values.
+/// * `values` - The values, with the same shape as the tensor except for the dimension, whose
+///   size is the number of indices.
+///
+/// # Returns
+///
+/// The tensor with the values put at the indices. When an index is repeated, the values are
+/// written in index order, so the last one wins.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+pub fn index_put<B: Backend, const D: usize, K: TensorKind<B> + BasicOps<B>>(
+    tensor: K::Primitive<D>,
+    dim: usize,
+    indices: IntTensor<B, 1>,
+    values: K::Primitive<D>,
+) -> K::Primitive<D>
+where
+    <K as BasicOps<B>>::Elem: Element,
+{
+    let device = K::device(&tensor);
+    let mut data = read::<B, D, K>(tensor);
+    let values = read::<B, D, K>(values);
+    let indices = read::<B, 1, Int>(indices);
+
+    let size = data.shape[dim];
+    let num_outer = data.shape[..dim].iter().product::<usize>();
+    let num_inner = data.shape[dim + 1..].iter().product::<usize>();
+    let num_indices = values.shape[dim];
+
+    let values = values.as_slice::<K::Elem>().unwrap();
+    let output = data.as_mut_slice::<K::Elem>().unwrap();
+
+    for (i, index) in indices.iter::<i64>().enumerate() {
+        let index = index as usize;
+        assert!(
+            index < size,
+            "Index {index} is out of bounds for dimension {dim} of size {size}"
+        );
+
+        for outer in 0..num_outer {
+            let offset_output = (outer * size + index) * num_inner;
+            let offset_values = (outer * num_indices + i) * num_inner;
+
+            output[offset_output..offset_output + num_inner]
+                .copy_from_slice(&values[offset_values..offset_values + num_inner]);
+        }
+    }
+
+    K::from_data(data, &device)
+}
+
+fn read<B: Backend, const D: usize, K: TensorKind<B> + BasicOps<B>>(
+    tensor: K::Primitive<D>,
+) -> TensorData {
+    try_read_sync(K::into_data_async(tensor)).expect("Failed to synchronously read tensor data. This operation is not supported until this backend has a GPU index put implementation.")
+}
@@ -1,4 +1,7 @@
-use crate::{backend::Backend, Float, Int, Shape, Tensor, TensorData, TensorPrimitive};
+use crate::{
+    backend::Backend, check, check::TensorCheck, ElementConversion, Float, Int, Shape, Tensor,
+    TensorData, TensorPrimitive,
+};
 
 use core::ops::Range;
 
@@ -97,4 +100,208 @@ where
     ) -> Tensor<B, D2, Int> {
         Tensor::new(B::int_cartesian_grid::<S, D, D2>(shape, device))
     }
+
+    /// Applies the matrix multiplication operation.
+    ///
+    /// `C = AB`
+    ///
+    /// The products are summed as integers, so the result is exact unless it overflows. The batch
+    /// dimensions are broadcast like with the float matmul.
+    ///
+    /// # Panics
+    ///
+    /// If the two tensors don't have a compatible shape.
+    pub fn matmul(self, other: Self) -> Self {
+        check!(TensorCheck::matmul(&self, &other));
+        Self::new(B::int_matmul(self.primitive, other.primitive))
+    }
+
+    /// Applies element wise the Euclidean remainder operation.
+    ///
+    /// The remainder is never negative, whatever the signs of the operands: `-7 % 3 = 2` and
+    /// `7 % -3 = 1`, so it can be used to wrap indices into range.
+    pub fn remainder(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("Remainder", &self, &other));
+        Self::new(B::int_remainder(self.primitive, other.primitive))
+    }
+
+    /// Applies element wise bitwise and operation.
+    pub fn bitwise_and(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("BitwiseAnd", &self, &other));
+        Self::new(B::int_bitwise_and(self.primitive, other.primitive))
+    }
+
+    /// Applies element wise bitwise and operation with a scalar.
+    pub fn bitwise_and_scalar<E: ElementConversion>(self, other: E) -> Self {
+        Self::new(B::int_bitwise_and_scalar(self.primitive, other.elem()))
+    }
+
+    /// Applies element wise bitwise or operation.
+    pub fn bitwise_or(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("BitwiseOr", &self, &other));
+        Self::new(B::int_bitwise_or(self.primitive, other.primitive))
+    }
+
+    /// Applies element wise bitwise or operation with a scalar.
+    pub fn bitwise_or_scalar<E: ElementConversion>(self, other: E) -> Self {
+        Self::new(B::int_bitwise_or_scalar(self.primitive, other.elem()))
+    }
+
+    /// Applies element wise bitwise exclusive or operation.
+    pub fn bitwise_xor(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("BitwiseXor", &self, &other));
+        Self::new(B::int_bitwise_xor(self.primitive, other.primitive))
+    }
+
+    /// Applies element wise bitwise exclusive or operation with a scalar.
+    pub fn bitwise_xor_scalar<E: ElementConversion>(self, other: E) -> Self {
+        Self::new(B::int_bitwise_xor_scalar(self.primitive, other.elem()))
+    }
+
+    /// Flips all the bits of the elements.
+    pub fn bitwise_not(self) -> Self {
+        Self::new(B::int_bitwise_not(self.primitive))
+    }
+
+    /// Shifts the bits of the elements to the left by the amounts of the `other` tensor.
+    ///
+    /// Only the lowest bits of the amounts are used, each amount being taken modulo the bit width
+    /// of the integer type of the backend, so negative amounts and amounts of at least the bit
+    /// width are wrapped instead of failing.
+    pub fn bitwise_left_shift(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew(
+            "BitwiseLeftShift",
+            &self,
+            &other
+        ));
+        Self::new(B::int_bitwise_left_shift(self.primitive, other.primitive))
+    }
+
+    /// Shifts the bits of the elements to the left by a scalar amount.
+    ///
+    /// Only the lowest bits of the amount are used, the amount being taken modulo the bit width of
+    /// the integer type of the backend, so a negative amount or an amount of at least the bit
+    /// width is wrapped instead of failing.
+    pub fn bitwise_left_shift_scalar<E: ElementConversion>(self, other: E) -> Self {
+        Self::new(B::int_bitwise_left_shift_scalar(
+            self.primitive,
+            other.elem(),
+        ))
+    }
+
+    /// Shifts the bits of the elements to the right by the amounts of the `other` tensor, the
+    /// shift being arithmetic for signed integer types.
+    ///
+    /// Only the lowest bits of the amounts are used, each amount being taken modulo the bit width
+    /// of the integer type of the backend, so negative amounts and amounts of at least the bit
+    /// width are wrapped instead of failing.
+    pub fn bitwise_right_shift(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew(
+            "BitwiseRightShift",
+            &self,
+            &other
+        ));
+        Self::new(B::int_bitwise_right_shift(self.primitive, other.primitive))
+    }
+
+    /// Shifts the bits of the elements to the right by a scalar amount, the shift being
+    /// arithmetic for signed integer types.
+    ///
+    /// Only the lowest bits of the amount are used, the amount being taken modulo the bit width of
+    /// the integer type of the backend, so a negative amount or an amount of at least the bit
+    /// width is wrapped instead of failing.
+    pub fn bitwise_right_shift_scalar<E: ElementConversion>(self, other: E) -> Self {
+        Self::new(B::int_bitwise_right_shift_scalar(
+            self.primitive,
+            other.elem(),
+        ))
+    }
+}
+
+impl<B: Backend, const D: usize> core::ops::BitAnd<Self> for Tensor<B, D, Int> {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self {
+        self.bitwise_and(rhs)
+    }
+}
+
+impl<B: Backend, const D: usize, E: ElementConversion> core::ops::BitAnd<E> for Tensor<B, D, Int> {
+    type Output = Self;
+
+    fn bitand(self, rhs: E) -> Self {
+        self.bitwise_and_scalar(rhs)
+    }
+}
+
+impl<B: Backend, const D: usize> core::ops::BitOr<Self> for Tensor<B, D, Int> {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        self.bitwise_or(rhs)
+    }
+}
+
+impl<B: Backend, const D: usize, E: ElementConversion> core::ops::BitOr<E> for Tensor<B, D, Int> {
+    type Output = Self;
+
+    fn bitor(self, rhs: E) -> Self {
+        self.bitwise_or_scalar(rhs)
+    }
+}
+
+impl<B: Backend, const D: usize> core::ops::BitXor<Self> for Tensor<B, D, Int> {
+    type Output = Self;
+
+    fn bitxor(self, rhs: Self) -> Self {
+        self.bitwise_xor(rhs)
+    }
+}
+
+impl<B: Backend, const D: usize, E: ElementConversion> core::ops::BitXor<E> for Tensor<B, D, Int> {
+    type Output = Self;
+
+    fn bitxor(self, rhs: E) -> Self {
+        self.bitwise_xor_scalar(rhs)
+    }
+}
+
+impl<B: Backend, const D: usize> core::ops::Shl<Self> for Tensor<B, D, Int> {
+    type Output = Self;
+
+    fn shl(self, rhs: Self) -> Self {
+        self.bitwise_left_shift(rhs)
+    }
+}
+
+impl<B: Backend, const D: usize, E: ElementConversion> core::ops::Shl<E> for Tensor<B, D, Int> {
+    type Output = Self;
+
+    fn shl(self, rhs: E) -> Self {
+        self.bitwise_left_shift_scalar(rhs)
+    }
+}
+
+impl<B: Backend, const D: usize> core::ops::Shr<Self> for Tensor<B, D, Int> {
+    type Output = Self;
+
+    fn shr(self, rhs: Self) -> Self {
+        self.bitwise_right_shift(rhs)
+    }
+}
+
+impl<B: Backend, const D: usize, E: ElementConversion> core::ops::Shr<E> for Tensor<B, D, Int> {
+    type Output = Self;
+
+    fn shr(self, rhs: E) -> Self {
+        self.bitwise_right_shift_scalar(rhs)
+    }
+}
+
+impl<B: Backend, const D: usize> core::ops::Not for Tensor<B, D, Int> {
+    type Output = Self;
+
+    fn not(self) -> Self {
+        self.bitwise_not()
+    }
 }
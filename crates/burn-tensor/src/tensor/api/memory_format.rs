@@ -0,0 +1,65 @@
+use crate::{backend::Backend, Tensor, TensorPrimitive};
+
+/// The order in which the elements of a tensor are laid out in memory.
+///
+/// The memory format never changes the logical shape of a tensor, which stays
+/// `[batch_size, channels, ...]`, only its strides.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum MemoryFormat {
+    /// The elements are laid out in the order of the dimensions, the last dimension being
+    /// contiguous.
+    #[default]
+    Contiguous,
+    /// The channels, the second dimension, are laid out last, so that the channels of a
+    /// position are contiguous. For 4-D tensors, this is the `NHWC` layout of the activations
+    /// and the `OHWI` layout of the convolution weights.
+    ChannelsLast,
+}
+
+impl MemoryFormat {
+    /// The permutation of the dimensions of a tensor with `D` dimensions, from the slowest to
+    /// the fastest varying in memory.
+    pub fn permutation<const D: usize>(&self) -> [usize; D] {
+        let mut axes = [0; D];
+        for (i, axis) in axes.iter_mut().enumerate() {
+            *axis = i;
+        }
+
+        if let MemoryFormat::ChannelsLast = self {
+            if D > 2 {
+                axes[1..].rotate_left(1);
+            }
+        }
+
+        axes
+    }
+}
+
+impl<const D: usize, B> Tensor<B, D>
+where
+    B: Backend,
+{
+    /// Lay out the elements of the tensor in the given [memory format](MemoryFormat), keeping
+    /// its shape and values.
+    ///
+    /// The format is a hint: the operations of the backends that can exploit it, such as the
+    /// convolutions and the pooling of the JIT backends, keep the format of their inputs, and
+    /// the backends that don't control the layout of their tensors ignore it.
+    pub fn to_memory_format(self, format: MemoryFormat) -> Self {
+        Self::new(TensorPrimitive::Float(B::float_to_memory_format(
+            self.primitive.tensor(),
+            format,
+        )))
+    }
+
+    /// The [memory format](MemoryFormat) of the tensor.
+    ///
+    /// Layouts that are neither strictly contiguous nor channels last, such as transposed
+    /// views, are reported as [contiguous](MemoryFormat::Contiguous).
+    pub fn memory_format(&self) -> MemoryFormat {
+        match &self.primitive {
+            TensorPrimitive::Float(tensor) => B::float_memory_format(tensor),
+            TensorPrimitive::QFloat(_) => MemoryFormat::Contiguous,
+        }
+    }
+}
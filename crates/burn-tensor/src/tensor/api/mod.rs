@@ -3,22 +3,36 @@ pub(crate) mod check;
 mod argwhere;
 mod autodiff;
 mod base;
+mod bitwise;
 mod bool;
 mod cartesian_grid;
+mod cast;
 mod chunk;
 mod float;
+mod index_put;
 mod int;
 mod kind;
+mod memory_format;
+mod multinomial;
 mod narrow;
 mod numeric;
+mod pad;
+mod scan;
 mod sort;
 
-pub use argwhere::argwhere_data;
+pub use argwhere::{argwhere_data, argwhere_padded_data};
 pub use autodiff::*;
 pub use base::*;
+pub use bitwise::{bitwise, bitwise_scalar, shift_mask};
 pub use cartesian_grid::cartesian_grid;
+pub use cast::*;
 pub use chunk::chunk;
+pub use index_put::index_put;
 pub use kind::*;
+pub use memory_format::*;
+pub use multinomial::multinomial;
 pub use narrow::narrow;
 pub use numeric::*;
+pub use pad::{pad, PadDim, PadMode};
+pub use scan::associative_scan;
 pub use sort::{argsort, sort, sort_with_indices};
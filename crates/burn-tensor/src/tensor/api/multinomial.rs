@@ -0,0 +1,140 @@
+use crate::{
+    backend::Backend,
+    ops::{FloatTensor, IntTensor},
+    Distribution, Int, Tensor, TensorPrimitive,
+};
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Sample indices from the categorical distributions given by the rows of the `tensor`.
+///
+/// # Arguments
+///
+/// * `tensor` - The non-negative weights of the categories, of shape `[rows, categories]`.
+/// * `num_samples` - The number of samples drawn from each row.
+/// * `replacement` - Whether a category can be drawn more than once in a row.
+///
+/// # Returns
+///
+/// The indices of the drawn categories, of shape `[rows, num_samples]`.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+///
+/// Every operation runs on the device of the tensor. Without replacement, the categories are
+/// drawn with the Gumbel-max trick, each draw masking the previous ones, and with replacement
+/// they are drawn by inverse transform sampling, with a binary search in the cumulative sums.
+pub fn multinomial<B: Backend>(
+    tensor: FloatTensor<B, 2>,
+    num_samples: usize,
+    replacement: bool,
+) -> IntTensor<B, 2> {
+    let weights = Tensor::<B, 2>::from_primitive(TensorPrimitive::Float(tensor));
+    let [rows, num_categories] = weights.dims();
+
+    // Rows without any weight are sampled uniformly.
+    let empty = weights
+        .clone()
+        .sum_dim(1)
+        .equal_elem(0.0)
+        .expand([rows, num_categories]);
+    let weights = weights.clone().mask_where(empty, weights.ones_like());
+
+    let samples = if replacement && num_samples > 1 {
+        inverse_cdf(weights, num_samples)
+    } else {
+        gumbel_max(weights, num_samples)
+    };
+
+    samples.into_primitive()
+}
+
+/// Draw without replacement, the largest perturbed log weight of a row being distributed as
+/// the normalized weights. The weights don't need to be normalized, since the normalization
+/// only shifts the log weights of a row.
+fn gumbel_max<B: Backend>(weights: Tensor<B, 2>, num_samples: usize) -> Tensor<B, 2, Int> {
+    let [rows, _] = weights.dims();
+    let device = weights.device();
+
+    // The uniform values are in [0, 1), and a zero leads to a key of negative infinity.
+    let gumbel = weights
+        .random_like(Distribution::Default)
+        .log()
+        .neg()
+        .log()
+        .neg();
+    let mut keys = weights.log() + gumbel;
+    let drawn = Tensor::full([rows, 1], f32::NEG_INFINITY, &device);
+
+    let mut samples = Vec::with_capacity(num_samples);
+    for i in 0..num_samples {
+        let sample = keys.clone().argmax(1);
+        if i + 1 < num_samples {
+            keys = keys.scatter(1, sample.clone(), drawn.clone());
+        }
+        samples.push(sample);
+    }
+
+    Tensor::cat(samples, 1)
+}
+
+/// Draw with replacement, the category of a sample being the first one whose cumulative weight
+/// exceeds a uniform value scaled by the total weight of the row.
+fn inverse_cdf<B: Backend>(weights: Tensor<B, 2>, num_samples: usize) -> Tensor<B, 2, Int> {
+    let [rows, num_categories] = weights.dims();
+    let device = weights.device();
+
+    let cdf = cumsum(weights);
+    let total = cdf
+        .clone()
+        .slice([0..rows, num_categories - 1..num_categories]);
+    let values = Tensor::<B, 2>::random([rows, num_samples], Distribution::Default, &device)
+        * total.expand([rows, num_samples]);
+
+    // Invariant: the category is in [low, high].
+    let mut low = Tensor::<B, 2, Int>::zeros([rows, num_samples], &device);
+    let mut high =
+        Tensor::<B, 2, Int>::full([rows, num_samples], num_categories as i64 - 1, &device);
+    let mut size = num_categories;
+
+    while size > 1 {
+        let mid = (low.clone() + high.clone()).div_scalar(2);
+        let above = cdf
+            .clone()
+            .gather(1, mid.clone())
+            .lower_equal(values.clone());
+
+        low = low.mask_where(above.clone(), mid.clone().add_scalar(1));
+        high = mid.mask_where(above, high);
+        size = size.div_ceil(2);
+    }
+
+    low
+}
+
+/// The cumulative sums of the rows, computed in a logarithmic number of steps.
+fn cumsum<B: Backend>(tensor: Tensor<B, 2>) -> Tensor<B, 2> {
+    let [rows, num_categories] = tensor.dims();
+    let device = tensor.device();
+
+    let mut output = tensor;
+    let mut offset = 1;
+
+    while offset < num_categories {
+        let shifted = Tensor::cat(
+            vec![
+                Tensor::zeros([rows, offset], &device),
+                output.clone().slice([0..rows, 0..num_categories - offset]),
+            ],
+            1,
+        );
+        output = output + shifted;
+        offset *= 2;
+    }
+
+    output
+}
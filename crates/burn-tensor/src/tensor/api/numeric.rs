@@ -5,7 +5,7 @@ use crate::alloc::borrow::ToOwned;
 use crate::TensorPrimitive;
 use crate::{
     backend::Backend, check, check::TensorCheck, BasicOps, Bool, Distribution, Element,
-    ElementConversion, Float, Int, Shape, Tensor, TensorKind,
+    ElementConversion, Float, Int, PadMode, Shape, Tensor, TensorKind,
 };
 
 impl<B, const D: usize, K> Tensor<B, D, K>
@@ -49,6 +49,8 @@ where
     /// Applies element wise division operation.
     ///
     /// `y = x2 / x1`
+    ///
+    /// The division of int tensors rounds toward zero.
     #[allow(clippy::should_implement_trait)]
     pub fn div(self, other: Self) -> Self {
         check!(TensorCheck::binary_ops_ew("Div", &self, &other));
@@ -65,6 +67,10 @@ where
     /// Applies element wise the remainder operation with a scalar.
     ///
     /// `y = x2 % x1`
+    ///
+    /// The remainder of float tensors has the sign of the divisor, like PyTorch, while the
+    /// remainder of int tensors is Euclidean and never negative: `-7 % 3 = 2` and `-7 % -3 = 2`.
+    /// Both agree for a positive divisor, which wraps indices into `[0, x1)`.
     #[allow(clippy::should_implement_trait)]
     pub fn remainder_scalar<E: ElementConversion>(self, other: E) -> Self {
         Self::new(K::remainder_scalar(self.primitive, other))
@@ -235,7 +241,13 @@ where
     ///
     /// This is similar to [mask_fill](Tensor::mask_fill), however the value is a tensor instead of
     /// a scalar.
+    ///
+    /// The tensor, the mask and the value are broadcasted together, so the output has the
+    /// broadcasted shape of the three. Each element is selected from one of the tensors, so NaN or
+    /// infinite values of the unselected tensor don't propagate to the output.
     pub fn mask_where(self, mask: Tensor<B, D, Bool>, value: Self) -> Self {
+        check!(TensorCheck::mask_where(&self, &mask, &value));
+
         Self::new(K::mask_where(self.primitive, mask, value.primitive))
     }
 
@@ -260,13 +272,47 @@ where
     /// The index tensor should have the same shape as the original tensor except for the dim
     /// specified.
     pub fn gather(self, dim: usize, indices: Tensor<B, D, Int>) -> Self {
-        check!(TensorCheck::gather::<D>(
+        check!(TensorCheck::gather(dim, &self, &indices));
+
+        Self::new(K::gather(dim, self.primitive, indices))
+    }
+
+    /// Take the tensor elements corresponding to the given indices along the specified dim,
+    /// broadcasting the indices over the other dimensions.
+    ///
+    /// This is the same as [gather](Tensor::gather), except that the index tensor can have fewer
+    /// dimensions, which are aligned with the last dimensions of the tensor, and can have a size
+    /// of one in any dimension other than `dim`, in which case it is repeated.
+    ///
+    /// Example using a 3D tensor and a 2D index tensor of shape `[1, n]`:
+    ///
+    /// `output[i, j, k] = input[i, j, indices[0, k]]; // dim = 2`
+    ///
+    /// # Notes
+    ///
+    /// The output tensor has the same shape as the original tensor except for the dim specified,
+    /// whose size is the one of the index tensor.
+    ///
+    /// # Panics
+    ///
+    /// If the index tensor can't be broadcast to the tensor shape, or if it doesn't have the
+    /// specified dim. In debug builds, the indices are also read back to reject negative values.
+    pub fn take_along_dim<const DI: usize>(self, dim: usize, indices: Tensor<B, DI, Int>) -> Self {
+        check!(TensorCheck::take_along_dim::<D, DI>(
             dim,
             &self.shape(),
             &indices.shape()
         ));
 
-        Self::new(K::gather(dim, self.primitive, indices))
+        #[cfg(debug_assertions)]
+        if indices.clone().lower_elem(0).any().into_scalar() {
+            panic!("TakeAlongDim: the indices along axis ({dim}) can't be negative.");
+        }
+
+        let mut shape = self.shape();
+        shape.dims[dim] = indices.dims()[dim - (D - DI)];
+
+        self.gather(dim, indices.expand(shape))
     }
 
     /// Assign the gathered elements corresponding to the given indices along the specified dimension
@@ -285,12 +331,7 @@ where
     ///
     /// Other references to the input tensor will not be modified by this operation.
     pub fn scatter(self, dim: usize, indices: Tensor<B, D, Int>, values: Self) -> Self {
-        check!(TensorCheck::scatter::<D>(
-            dim,
-            &self.shape(),
-            &indices.shape(),
-            &values.shape()
-        ));
+        check!(TensorCheck::scatter(dim, &self, &indices, &values));
 
         Self::new(K::scatter(dim, self.primitive, indices, values.primitive))
     }
@@ -331,6 +372,93 @@ where
         ))
     }
 
+    /// Put the values at the given indices along the given dimension, overwriting the elements or
+    /// adding the values to them.
+    ///
+    /// Example using a 3D tensor:
+    ///
+    /// `input[indices[i], j, k] = values[i, j, k]; // dim = 0`
+    /// `input[i, indices[j], k] = values[i, j, k]; // dim = 1`
+    /// `input[i, j, indices[k]] = values[i, j, k]; // dim = 2`
+    ///
+    /// With `accumulate`, the values are added to the elements instead, like
+    /// [select_assign](Tensor::select_assign).
+    ///
+    /// # Notes
+    ///
+    /// The values tensor should have the same shape as the original tensor except for the
+    /// specified dimension, whose size should be the number of indices. When an index is repeated
+    /// without accumulation, the values are written in index order, so the last one wins.
+    ///
+    /// Other references to the input tensor will not be modified by this operation.
+    pub fn index_put(
+        self,
+        dim: usize,
+        indices: Tensor<B, 1, Int>,
+        values: Self,
+        accumulate: bool,
+    ) -> Self {
+        check!(TensorCheck::index_put::<D>(
+            dim,
+            &self.shape(),
+            indices.dims()[0],
+            &values.shape()
+        ));
+
+        Self::new(K::index_put(
+            self.primitive,
+            dim,
+            indices,
+            values.primitive,
+            accumulate,
+        ))
+    }
+
+    /// Put the values at the positions given by one indices tensor for each dimension,
+    /// overwriting the elements or adding the values to them.
+    ///
+    /// Example using a 3D tensor:
+    ///
+    /// `input[indices[0][i], indices[1][i], indices[2][i]] = values[i];`
+    ///
+    /// # Notes
+    ///
+    /// Every indices tensor should have as many elements as the values tensor. When a position is
+    /// repeated without accumulation, the values are written in index order, so the last one wins.
+    pub fn index_put_nd(
+        self,
+        indices: [Tensor<B, 1, Int>; D],
+        values: Tensor<B, 1, K>,
+        accumulate: bool,
+    ) -> Self {
+        check!(TensorCheck::index_put_nd::<D>(
+            &indices.each_ref().map(|indices| indices.shape()),
+            &values.shape()
+        ));
+
+        let shape = self.shape();
+        let mut stride = 1;
+        let mut positions: Option<Tensor<B, 1, Int>> = None;
+
+        // The positions in the flattened tensor, in row-major order.
+        for (indices, size) in indices.into_iter().zip(shape.dims).rev() {
+            let offsets = indices.mul_scalar(stride as i64);
+            positions = Some(match positions {
+                Some(positions) => positions + offsets,
+                None => offsets,
+            });
+            stride *= size;
+        }
+
+        match positions {
+            Some(positions) => self
+                .reshape([shape.num_elements()])
+                .index_put(0, positions, values, accumulate)
+                .reshape(shape),
+            None => self,
+        }
+    }
+
     /// Applies the argmax function along the given dimension and returns an integer tensor.
     ///
     /// # Example
@@ -495,6 +623,48 @@ where
         Self::new(K::clamp_max(self.primitive, max.elem()))
     }
 
+    /// Clamp the tensor between the values of the min and max tensors, element wise.
+    ///
+    /// # Arguments
+    ///
+    /// * `min` - The tensor of the minimum values, with the same shape as the tensor.
+    /// * `max` - The tensor of the maximum values, with the same shape as the tensor.
+    ///
+    /// # Returns
+    ///
+    /// A new tensor with each value clamped between the corresponding min and max values.
+    pub fn clamp_tensor(self, min: Self, max: Self) -> Self {
+        self.clamp_min_tensor(min).clamp_max_tensor(max)
+    }
+
+    /// Clamps a tensor under the values of a minimum tensor, element wise.
+    ///
+    /// # Arguments
+    ///
+    /// * `min` - The tensor of the minimum values, with the same shape as the tensor.
+    ///
+    /// # Returns
+    ///
+    /// A new tensor with each value clamped under the corresponding min value.
+    pub fn clamp_min_tensor(self, min: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("ClampMin", &self, &min));
+        self.max_pair(min)
+    }
+
+    /// Clamps a tensor over the values of a maximum tensor, element wise.
+    ///
+    /// # Arguments
+    ///
+    /// * `max` - The tensor of the maximum values, with the same shape as the tensor.
+    ///
+    /// # Returns
+    ///
+    /// A new tensor with each value clamped over the corresponding max value.
+    pub fn clamp_max_tensor(self, max: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("ClampMax", &self, &max));
+        self.min_pair(max)
+    }
+
     /// Apply element wise absolute value operation
     pub fn abs(self) -> Self {
         Self::new(K::abs(self.primitive))
@@ -606,6 +776,8 @@ where
     /// and `atol` is the absolute tolerance.
     /// ```
     ///
+    /// Equal elements are always close, including infinities, while NaN is never close.
+    ///
     /// # Arguments
     ///
     /// * `other` - The tensor to compare with.
@@ -616,13 +788,12 @@ where
     ///
     /// A boolean tensor with the same shape as the input tensors.
     pub fn is_close(self, other: Self, rtol: Option<f64>, atol: Option<f64>) -> Tensor<B, D, Bool> {
+        check!(TensorCheck::binary_ops_ew("IsClose", &self, &other));
+
         let rtol = rtol.unwrap_or(1e-5);
         let atol = atol.unwrap_or(1e-8);
 
-        K::lower_equal(
-            K::abs(K::sub(self.primitive, other.primitive.clone())),
-            K::add_scalar(K::mul_scalar(K::abs(other.primitive), rtol), atol),
-        )
+        K::is_close(self.primitive, other.primitive, rtol, atol)
     }
 
     /// Checks if all elements are close to another tensor.
@@ -649,8 +820,15 @@ where
     /// A boolean scalar.
     ///
     /// # Remarks
+    ///
+    /// The comparison is reduced on the device, so only a single boolean is read back.
     pub fn all_close(self, other: Self, rtol: Option<f64>, atol: Option<f64>) -> bool {
-        self.is_close(other, rtol, atol).all().into_scalar()
+        check!(TensorCheck::binary_ops_ew("AllClose", &self, &other));
+
+        let rtol = rtol.unwrap_or(1e-5);
+        let atol = atol.unwrap_or(1e-8);
+
+        K::all_close(self.primitive, other.primitive, rtol, atol).into_scalar()
     }
 
     /// Converts the tensor to a boolean tensor by checking if the elements are non-zero.
@@ -742,47 +920,36 @@ where
         )
     }
 
-    /// Pad the tensor of rank two or higher with the given value on the last two dimensions.
+    /// Pad the tensor with the given `(before, after)` amounts on each dimension.
+    ///
+    /// Negative amounts crop the tensor instead, cropping is applied before padding.
     ///
     /// # Arguments
     ///
-    /// * `padding` - A tuple of four integers representing the padding on the left, right, top, and bottom.
-    /// * `value` - The value to pad the tensor with.
+    /// * `padding` - The `(before, after)` padding amounts of each dimension.
+    /// * `mode` - How the padded elements are filled.
     ///
     /// # Returns
     ///
     /// A new tensor with the given padding.
-    pub fn pad(self, padding: (usize, usize, usize, usize), value: K::Elem) -> Tensor<B, D, K> {
-        let (left, right, top, bottom) = padding;
-
-        let mut padded_dims: [usize; D] = self.dims();
-
-        // Update the last two dimensions with padding
-        padded_dims[D - 2] += top + bottom;
-        padded_dims[D - 1] += left + right;
-
-        // Create the ranges for the padded tensor
-        let ranges: [core::ops::Range<usize>; D] = padded_dims
-            .iter()
-            .enumerate()
-            .map(|(i, &dim)| {
-                if i == D - 2 {
-                    top..dim - bottom
-                } else if i == D - 1 {
-                    left..dim - right
-                } else {
-                    0..dim
-                }
-            })
-            .collect::<Vec<core::ops::Range<usize>>>()
-            .try_into()
-            .unwrap();
-
-        // Create the padded tensor
-        let padded_tensor = Tensor::full(padded_dims, value, &self.device());
-
-        // Assign the original tensor data to the appropriate slice of the padded tensor
-        padded_tensor.slice_assign(ranges, self)
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{ElementConversion, PadMode, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = B::Device::default();
+    ///    let tensor = Tensor::<B, 2>::from_data([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device);
+    ///    let padded = tensor.pad(&[(1, 0), (-1, 2)], PadMode::Constant(0.0.elem()));
+    ///    println!("{padded}");
+    ///    // [[0.0, 0.0, 0.0, 0.0], [2.0, 3.0, 0.0, 0.0], [5.0, 6.0, 0.0, 0.0]]
+    /// }
+    /// ```
+    pub fn pad(self, padding: &[(isize, isize); D], mode: PadMode<K::Elem>) -> Tensor<B, D, K> {
+        check!(TensorCheck::pad(&self.shape(), padding, &mode));
+        Tensor::new(K::pad(self.primitive, *padding, mode))
     }
 
     /// Returns a new tensor with boolean elements indicating whether each element of the input is NaN.
@@ -1540,9 +1707,10 @@ where
     ///
     /// # Returns
     ///
-    /// A tensor with the same shape as the input tensors, where each element is taken from the
-    /// corresponding element of the left hand side tensor if the corresponding element of the mask
-    /// is true, and from the corresponding element of the right hand side tensor otherwise.
+    /// A tensor with the broadcasted shape of the input tensors and the mask, where each element is
+    /// taken from the corresponding element of the left hand side tensor if the corresponding
+    /// element of the mask is true, and from the corresponding element of the right hand side
+    /// tensor otherwise.
     ///
     /// # Remarks
     ///
@@ -1704,6 +1872,36 @@ where
         values: Self::Primitive<D>,
     ) -> Self::Primitive<D>;
 
+    /// Puts the values at the given indices along an axis, overwriting or accumulating.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to put the values in.
+    /// * `dim` - The axis along which to put the values.
+    /// * `indices` - The positions along the axis, one for each slice of the values.
+    /// * `values` - The values to put.
+    /// * `accumulate` - Whether the values are added to the elements instead of overwriting them.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor, with the values put at the indices.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For putting values in a tensor along an axis, users should prefer the
+    /// [Tensor::index_put](Tensor::index_put) function, which is more high-level and designed for public use.
+    fn index_put<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+        indices: Tensor<B, 1, Int>,
+        values: Self::Primitive<D>,
+        accumulate: bool,
+    ) -> Self::Primitive<D>;
+
     /// Gets the indices of the maximum elements of a tensor along an axis.
     ///
     /// # Arguments
@@ -1968,6 +2166,62 @@ where
     /// which is more high-level and designed for public use.
     fn abs<const D: usize>(tensor: Self::Primitive<D>) -> Self::Primitive<D>;
 
+    /// Tests element wise if a tensor is close to another tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The tensor to compare.
+    /// * `rhs` - The reference tensor.
+    /// * `rtol` - The relative tolerance.
+    /// * `atol` - The absolute tolerance.
+    ///
+    /// # Returns
+    ///
+    /// A boolean tensor with the shape of the inputs, True where the elements are close.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For comparing tensors, users should prefer the [Tensor::is_close](Tensor::is_close) function,
+    /// which is more high-level and designed for public use.
+    fn is_close<const D: usize>(
+        lhs: Self::Primitive<D>,
+        rhs: Self::Primitive<D>,
+        rtol: f64,
+        atol: f64,
+    ) -> Tensor<B, D, Bool>;
+
+    /// Tests if all the elements of a tensor are close to the ones of another tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The tensor to compare.
+    /// * `rhs` - The reference tensor.
+    /// * `rtol` - The relative tolerance.
+    /// * `atol` - The absolute tolerance.
+    ///
+    /// # Returns
+    ///
+    /// A boolean tensor with a single element, True if all the elements are close.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For comparing tensors, users should prefer the [Tensor::all_close](Tensor::all_close) function,
+    /// which is more high-level and designed for public use.
+    fn all_close<const D: usize>(
+        lhs: Self::Primitive<D>,
+        rhs: Self::Primitive<D>,
+        rtol: f64,
+        atol: f64,
+    ) -> Tensor<B, 1, Bool>;
+
     /// Element-wise power of a tensor to a float tensor
     ///
     /// # Arguments
@@ -2112,6 +2366,32 @@ where
         dim: usize,
         descending: bool,
     ) -> <Int as TensorKind<B>>::Primitive<D>;
+
+    /// Pad the input `tensor` with the given `(before, after)` amounts on each dimension, where
+    /// negative amounts crop the tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `padding` - The `(before, after)` padding amounts of each dimension.
+    /// * `mode` - How the padded elements are filled.
+    ///
+    /// # Returns
+    ///
+    /// The padded tensor.
+    ///
+    /// # Remarks
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// Users should prefer the [Tensor::pad](Tensor::pad) function,
+    /// which is more high-level and designed for public use.
+    fn pad<const D: usize>(
+        tensor: Self::Primitive<D>,
+        padding: [(isize, isize); D],
+        mode: PadMode<Self::Elem>,
+    ) -> Self::Primitive<D>;
 }
 
 impl<B: Backend> Numeric<B> for Int {
@@ -2303,6 +2583,17 @@ impl<B: Backend> Numeric<B> for Int {
     ) -> Self::Primitive<D> {
         B::int_select_assign(tensor, dim, indices.primitive, values)
     }
+
+    fn index_put<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+        indices: Tensor<B, 1, Int>,
+        values: Self::Primitive<D>,
+        accumulate: bool,
+    ) -> Self::Primitive<D> {
+        B::int_index_put(tensor, dim, indices.primitive, values, accumulate)
+    }
+
     fn gather<const D: usize>(
         dim: usize,
         tensor: Self::Primitive<D>,
@@ -2390,6 +2681,24 @@ impl<B: Backend> Numeric<B> for Int {
         B::int_abs(tensor)
     }
 
+    fn is_close<const D: usize>(
+        lhs: Self::Primitive<D>,
+        rhs: Self::Primitive<D>,
+        rtol: f64,
+        atol: f64,
+    ) -> Tensor<B, D, Bool> {
+        Tensor::new(B::int_is_close(lhs, rhs, rtol, atol))
+    }
+
+    fn all_close<const D: usize>(
+        lhs: Self::Primitive<D>,
+        rhs: Self::Primitive<D>,
+        rtol: f64,
+        atol: f64,
+    ) -> Tensor<B, 1, Bool> {
+        Tensor::new(B::int_all_close(lhs, rhs, rtol, atol))
+    }
+
     fn powf<const D: usize>(
         lhs: Self::Primitive<D>,
         rhs: Self::Primitive<D>,
@@ -2453,6 +2762,14 @@ impl<B: Backend> Numeric<B> for Int {
     ) -> <Int as TensorKind<B>>::Primitive<D> {
         B::int_argsort(tensor, dim, descending)
     }
+
+    fn pad<const D: usize>(
+        tensor: Self::Primitive<D>,
+        padding: [(isize, isize); D],
+        mode: PadMode<Self::Elem>,
+    ) -> Self::Primitive<D> {
+        B::int_pad(tensor, padding, mode)
+    }
 }
 
 impl<B: Backend> Numeric<B> for Float {
@@ -2656,6 +2973,22 @@ impl<B: Backend> Numeric<B> for Float {
         ))
     }
 
+    fn index_put<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+        indices: Tensor<B, 1, Int>,
+        values: Self::Primitive<D>,
+        accumulate: bool,
+    ) -> Self::Primitive<D> {
+        TensorPrimitive::Float(B::float_index_put(
+            tensor.tensor(),
+            dim,
+            indices.primitive,
+            values.tensor(),
+            accumulate,
+        ))
+    }
+
     fn gather<const D: usize>(
         dim: usize,
         tensor: Self::Primitive<D>,
@@ -2750,6 +3083,24 @@ impl<B: Backend> Numeric<B> for Float {
         TensorPrimitive::Float(B::float_abs(tensor.tensor()))
     }
 
+    fn is_close<const D: usize>(
+        lhs: Self::Primitive<D>,
+        rhs: Self::Primitive<D>,
+        rtol: f64,
+        atol: f64,
+    ) -> Tensor<B, D, Bool> {
+        Tensor::new(B::float_is_close(lhs.tensor(), rhs.tensor(), rtol, atol))
+    }
+
+    fn all_close<const D: usize>(
+        lhs: Self::Primitive<D>,
+        rhs: Self::Primitive<D>,
+        rtol: f64,
+        atol: f64,
+    ) -> Tensor<B, 1, Bool> {
+        Tensor::new(B::float_all_close(lhs.tensor(), rhs.tensor(), rtol, atol))
+    }
+
     fn powf<const D: usize>(
         lhs: Self::Primitive<D>,
         rhs: Self::Primitive<D>,
@@ -2814,6 +3165,14 @@ impl<B: Backend> Numeric<B> for Float {
     ) -> <Int as TensorKind<B>>::Primitive<D> {
         B::float_argsort(tensor.tensor(), dim, descending)
     }
+
+    fn pad<const D: usize>(
+        tensor: Self::Primitive<D>,
+        padding: [(isize, isize); D],
+        mode: PadMode<Self::Elem>,
+    ) -> Self::Primitive<D> {
+        TensorPrimitive::Float(B::float_pad(tensor.tensor(), padding, mode))
+    }
 }
 
 impl<B, const D: usize, K> core::ops::Add<Self> for Tensor<B, D, K>
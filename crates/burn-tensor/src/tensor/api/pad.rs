@@ -0,0 +1,175 @@
+use crate::{
+    backend::Backend, ops::IntElem, Element, ElementConversion, Int, Numeric, Shape, Tensor,
+    TensorData, TensorKind,
+};
+use alloc::vec::Vec;
+use core::ops::Range;
+use serde::{Deserialize, Serialize};
+
+/// How the padded elements of a tensor are filled.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum PadMode<E> {
+    /// Fill the padded elements with the given value.
+    Constant(E),
+    /// Mirror the tensor at its borders, without repeating the border elements.
+    ///
+    /// `[1, 2, 3]` padded with `(2, 2)` becomes `[3, 2, 1, 2, 3, 2, 1]`.
+    Reflect,
+    /// Repeat the border elements.
+    ///
+    /// `[1, 2, 3]` padded with `(2, 2)` becomes `[1, 1, 1, 2, 3, 3, 3]`.
+    Edge,
+}
+
+/// The padding of a single dimension, split between the cropped and the padded amounts.
+///
+/// Negative padding amounts crop the tensor first, then positive amounts pad the cropped tensor.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PadDim {
+    /// The number of elements cropped at the start of the dimension.
+    pub crop_before: usize,
+    /// The number of elements cropped at the end of the dimension.
+    pub crop_after: usize,
+    /// The number of elements padded at the start of the dimension.
+    pub pad_before: usize,
+    /// The number of elements padded at the end of the dimension.
+    pub pad_after: usize,
+}
+
+impl PadDim {
+    /// Split the signed `(before, after)` padding amounts of a dimension.
+    pub fn new(padding: (isize, isize)) -> Self {
+        let (before, after) = padding;
+
+        Self {
+            crop_before: before.min(0).unsigned_abs(),
+            crop_after: after.min(0).unsigned_abs(),
+            pad_before: before.max(0) as usize,
+            pad_after: after.max(0) as usize,
+        }
+    }
+
+    /// The range of the input dimension of the given `size` that is kept after cropping.
+    pub fn cropped_range(&self, size: usize) -> Range<usize> {
+        self.crop_before..size - self.crop_after
+    }
+
+    /// The size of the dimension of the given `size` once padded.
+    pub fn padded_size(&self, size: usize) -> usize {
+        size - self.crop_before - self.crop_after + self.pad_before + self.pad_after
+    }
+
+    /// The index in the cropped dimension read to fill the element at `index` of the padded
+    /// dimension, or `None` when it is filled with the constant value.
+    pub fn source_index<E>(
+        &self,
+        index: usize,
+        cropped_size: usize,
+        mode: &PadMode<E>,
+    ) -> Option<usize> {
+        if index < self.pad_before {
+            return match mode {
+                PadMode::Constant(_) => None,
+                PadMode::Reflect => Some(self.pad_before - index),
+                PadMode::Edge => Some(0),
+            };
+        }
+
+        let index = index - self.pad_before;
+
+        if index >= cropped_size {
+            return match mode {
+                PadMode::Constant(_) => None,
+                PadMode::Reflect => Some(2 * (cropped_size - 1) - index),
+                PadMode::Edge => Some(cropped_size - 1),
+            };
+        }
+
+        Some(index)
+    }
+}
+
+/// Pad the input `tensor` with the given per-dimension `(before, after)` amounts, where negative
+/// amounts crop the tensor.
+///
+/// # Arguments
+///
+/// * `tensor` - The input tensor.
+/// * `padding` - The `(before, after)` padding amounts of each dimension.
+/// * `mode` - How the padded elements are filled.
+///
+/// # Returns
+///
+/// The padded tensor.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+pub fn pad<B: Backend, const D: usize, K: TensorKind<B> + Numeric<B>>(
+    tensor: K::Primitive<D>,
+    padding: [(isize, isize); D],
+    mode: PadMode<K::Elem>,
+) -> K::Primitive<D>
+where
+    K::Elem: Element,
+{
+    let dims = padding.map(PadDim::new);
+    let shape = K::shape(&tensor);
+
+    let mut tensor = tensor;
+    if dims
+        .iter()
+        .any(|dim| dim.crop_before > 0 || dim.crop_after > 0)
+    {
+        let ranges: [Range<usize>; D] =
+            core::array::from_fn(|i| dims[i].cropped_range(shape.dims[i]));
+        tensor = K::slice(tensor, ranges);
+    }
+
+    if dims
+        .iter()
+        .all(|dim| dim.pad_before == 0 && dim.pad_after == 0)
+    {
+        return tensor;
+    }
+
+    let cropped = K::shape(&tensor);
+
+    match mode {
+        PadMode::Constant(value) => {
+            let device = K::device(&tensor);
+            let padded = Shape::new(core::array::from_fn(|i| dims[i].padded_size(shape.dims[i])));
+            let ranges: [Range<usize>; D] =
+                core::array::from_fn(|i| dims[i].pad_before..dims[i].pad_before + cropped.dims[i]);
+
+            K::slice_assign(K::full(padded, value, &device), ranges, tensor)
+        }
+        PadMode::Reflect | PadMode::Edge => {
+            for (i, dim) in dims.iter().enumerate() {
+                if dim.pad_before == 0 && dim.pad_after == 0 {
+                    continue;
+                }
+
+                let size = cropped.dims[i];
+                let indices = (0..size + dim.pad_before + dim.pad_after)
+                    .map(|index| {
+                        let index = dim.source_index(index, size, &mode).unwrap();
+                        IntElem::<B>::from_elem(index as i64)
+                    })
+                    .collect::<Vec<_>>();
+                let num_indices = indices.len();
+                let indices = Tensor::<B, 1, Int>::from_data(
+                    TensorData::new(indices, [num_indices]),
+                    &K::device(&tensor),
+                );
+
+                tensor = K::select(tensor, i, indices);
+            }
+
+            tensor
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use crate::{backend::Backend, ops::FloatTensor, TensorData};
+use alloc::vec;
+use alloc::vec::Vec;
+use burn_common::reader::try_read_sync;
+
+/// Compute the linear recurrence `y[t] = a[t] * y[t - 1] + b[t]` along a dimension, with
+/// `y[-1] = 0`.
+///
+/// # Arguments
+///
+/// * `a` - The coefficients multiplying the previous values.
+/// * `b` - The values added at each step, of the shape of `a`.
+/// * `dim` - The dimension of the recurrence.
+///
+/// # Returns
+///
+/// The values of the recurrence, of the shape of `a`.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+///
+/// The recurrence is computed sequentially on the host, in double precision.
+pub fn associative_scan<B: Backend, const D: usize>(
+    a: FloatTensor<B, D>,
+    b: FloatTensor<B, D>,
+    dim: usize,
+) -> FloatTensor<B, D> {
+    let shape = B::float_shape(&a);
+    let device = B::float_device(&a);
+
+    let a = read::<B, D>(a);
+    let b = read::<B, D>(b);
+
+    let length = shape.dims[dim];
+    let stride = shape.dims[dim + 1..].iter().product::<usize>();
+    let num_elements = shape.num_elements();
+    let mut output = vec![0.0; num_elements];
+
+    // The elements of a line are `stride` apart, and the lines of a block of `length * stride`
+    // elements start at consecutive offsets.
+    for block in (0..num_elements).step_by(usize::max(length * stride, 1)) {
+        for start in block..block + stride {
+            let mut value = 0.0;
+
+            for index in (start..start + length * stride).step_by(stride) {
+                value = a[index] * value + b[index];
+                output[index] = value;
+            }
+        }
+    }
+
+    let data = TensorData::new(output, shape).convert::<B::FloatElem>();
+    B::float_from_data(data, &device)
+}
+
+fn read<B: Backend, const D: usize>(tensor: FloatTensor<B, D>) -> Vec<f64> {
+    try_read_sync(B::float_into_data(tensor))
+        .expect("Failed to synchronously read tensor data. This operation is not supported until this backend has a GPU associative scan implementation.")
+        .iter::<f64>()
+        .collect()
+}
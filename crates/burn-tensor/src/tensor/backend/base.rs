@@ -1,10 +1,11 @@
 use alloc::string::String;
+use alloc::vec::Vec;
 pub use burn_common::sync_type::SyncType;
 
 use crate::tensor::Element;
 use crate::{ops::*, quantization::QTensorPrimitive};
 
-use super::{BackendBridge, DeviceOps};
+use super::{BackendBridge, DeviceOps, DeviceProperties, DeviceType, RngState};
 
 /// This trait defines all types and functions needed for a backend to be used with burn.
 ///
@@ -58,6 +59,7 @@ pub trait Backend:
     + IntTensorOps<Self>
     + ModuleOps<Self>
     + ActivationOps<Self>
+    + OptimizerOps<Self>
     + QTensorOps<Self>
     + Clone
     + Sized
@@ -104,8 +106,58 @@ pub trait Backend:
     /// Seed the backend.
     fn seed(seed: u64);
 
+    /// Seed the random number generator of a single device, leaving the other devices
+    /// untouched.
+    ///
+    /// Defaults to seeding the whole backend for backends without per device generators.
+    fn seed_device(_device: &Self::Device, seed: u64) {
+        Self::seed(seed);
+    }
+
+    /// Returns the state of the random number generators, if the backend can save it.
+    fn rng_state() -> Option<RngState> {
+        None
+    }
+
+    /// Restore a state returned by [rng_state](Backend::rng_state).
+    fn restore_rng_state(_state: RngState) {}
+
     /// Sync the backend, ensure that all computation are finished.
     fn sync(_device: &Self::Device, _sync_type: SyncType) {}
+
+    /// Returns all the devices available to the backend.
+    fn devices() -> Vec<Self::Device> {
+        Self::Device::enumerate()
+    }
+
+    /// Returns the [properties](DeviceProperties) of the device.
+    fn device_properties(device: &Self::Device) -> DeviceProperties {
+        device.properties()
+    }
+
+    /// Returns the discrete GPU with the most memory, falling back to the integrated, virtual
+    /// and CPU devices in that order, and to the default device when none is reported.
+    fn preferred_device() -> Self::Device {
+        let rank = |device_type| match device_type {
+            DeviceType::DiscreteGpu => 4,
+            DeviceType::IntegratedGpu => 3,
+            DeviceType::VirtualGpu => 2,
+            DeviceType::Cpu => 1,
+            DeviceType::Other => 0,
+        };
+
+        Self::devices()
+            .into_iter()
+            .map(|device| (Self::device_properties(&device), device))
+            .max_by_key(|(properties, _)| {
+                (
+                    rank(properties.device_type),
+                    properties.total_memory.unwrap_or(0),
+                )
+            })
+            .map(|(_, device)| device)
+            .unwrap_or_default()
+    }
 }
 
 /// Trait that allows a backend to support autodiff.
@@ -131,6 +183,28 @@ pub trait AutodiffBackend: Backend {
     /// The gradients.
     fn backward<const D: usize>(tensor: FloatTensor<Self, D>) -> Self::Gradients;
 
+    /// Backward pass retaining the computational graph, so that other backward passes can be
+    /// called on tensors sharing parts of it.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor is the last node of computational graph where the gradients are computed.
+    ///
+    /// # Returns
+    ///
+    /// The gradients.
+    ///
+    /// # Panics
+    ///
+    /// By default, since the backend doesn't support retaining the graph.
+    fn backward_retained<const D: usize>(tensor: FloatTensor<Self, D>) -> Self::Gradients {
+        let _ = tensor;
+        panic!(
+            "The backend {} doesn't support retaining the graph of a backward pass.",
+            Self::name()
+        )
+    }
+
     /// Returns the gradients of a tensor.
     ///
     /// # Arguments
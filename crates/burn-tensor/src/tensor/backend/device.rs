@@ -1,3 +1,7 @@
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
 /// The device id.
 #[derive(Debug, Hash, PartialEq, Eq, Clone, Copy, new)]
 pub struct DeviceId {
@@ -11,6 +15,70 @@ pub struct DeviceId {
 pub trait DeviceOps: Clone + Default + PartialEq + Send + Sync + core::fmt::Debug {
     /// Return the [device id](DeviceId).
     fn id(&self) -> DeviceId;
+
+    /// Return all the devices of this type available on the system.
+    ///
+    /// Defaults to the default device only.
+    fn enumerate() -> Vec<Self> {
+        vec![Self::default()]
+    }
+
+    /// Return the [properties](DeviceProperties) of the device.
+    fn properties(&self) -> DeviceProperties {
+        DeviceProperties::default()
+    }
+}
+
+/// The kind of hardware of a device.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum DeviceType {
+    /// A GPU with its own memory.
+    DiscreteGpu,
+    /// A GPU sharing its memory with the CPU.
+    IntegratedGpu,
+    /// A GPU exposed by a virtualization layer.
+    VirtualGpu,
+    /// The CPU.
+    Cpu,
+    /// Any other or unknown kind of device.
+    #[default]
+    Other,
+}
+
+/// The properties of a device, as reported by its backend.
+///
+/// The values that the backend or the driver can't report are left to their default, so
+/// `None` for the optional values and `false` for the capabilities.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct DeviceProperties {
+    /// The name of the device.
+    pub name: String,
+    /// The vendor of the device.
+    pub vendor: String,
+    /// The name and version of the driver of the device.
+    pub driver_version: String,
+    /// The API used to execute on the device, such as `vulkan`, `cuda` or `cpu`.
+    pub backend_kind: String,
+    /// The kind of hardware of the device.
+    pub device_type: DeviceType,
+    /// The total memory of the device, in bytes.
+    pub total_memory: Option<u64>,
+    /// If the device supports 16 bit floats.
+    pub supports_f16: bool,
+    /// If the device supports 64 bit floats.
+    pub supports_f64: bool,
+    /// If the device supports 16 bit brain floats.
+    pub supports_bf16: bool,
+    /// If the device supports the atomic operations on 64 bit integers.
+    pub supports_atomic_64: bool,
+    /// The maximum number of invocations in a workgroup.
+    pub max_workgroup_size: Option<u32>,
+    /// The maximum size of the shared memory of a workgroup, in bytes.
+    pub max_shared_memory_size: Option<u32>,
+    /// If the device supports subgroup (warp) operations.
+    pub subgroup_support: bool,
+    /// The number of units of a subgroup, when every subgroup of the device has the same size.
+    pub subgroup_size: Option<u32>,
 }
 
 impl core::fmt::Display for DeviceId {
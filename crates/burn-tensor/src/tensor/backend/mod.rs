@@ -1,10 +1,12 @@
 mod base;
 mod bridge;
 mod device;
+mod rng;
 
 pub use base::*;
 pub use bridge::*;
 pub use device::*;
+pub use rng::*;
 
 // Not needed for now, useful for different tensor memory layout
 // pub mod conversion;
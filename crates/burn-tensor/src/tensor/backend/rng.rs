@@ -0,0 +1,246 @@
+use burn_common::rand::get_seeded_rng;
+use burn_common::stub::Mutex;
+use hashbrown::HashMap;
+use rand::{Error, Rng, RngCore};
+
+use super::DeviceId;
+
+/// The increment of the counter, the golden ratio.
+const GOLDEN_GAMMA: u64 = 0x9E37_79B9_7F4A_7C15;
+/// The constant mixed in the key of the forked streams.
+const FORK_GAMMA: u64 = 0xD1B5_4A32_D192_ED03;
+
+/// A counter-based random number generator that can be forked into independent streams.
+///
+/// The `i`-th value of a stream is a hash of the key of the stream and of `i`, so a stream
+/// doesn't share any state with the streams forked from it, and saving or restoring a stream
+/// is only a matter of cloning it.
+///
+/// # Example
+///
+/// ```rust
+/// use burn_tensor::backend::RngContext;
+/// use rand::Rng;
+///
+/// let mut context = RngContext::new(42);
+/// // One independent stream per worker.
+/// let mut workers = (0..4).map(|_| context.fork()).collect::<Vec<_>>();
+/// let value: f32 = workers[0].gen();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RngContext {
+    key: u64,
+    counter: u64,
+    forks: u64,
+}
+
+impl RngContext {
+    /// Create the root stream of the given seed.
+    pub fn new(seed: u64) -> Self {
+        Self {
+            key: mix(seed),
+            counter: 0,
+            forks: 0,
+        }
+    }
+
+    /// Create a root stream with a random seed.
+    pub fn from_entropy() -> Self {
+        Self::new(get_seeded_rng().gen())
+    }
+
+    /// Fork a new independent stream.
+    ///
+    /// The forked streams only depend on the key of the parent and on how many streams were
+    /// forked before them, not on how many values were generated.
+    pub fn fork(&mut self) -> Self {
+        self.forks += 1;
+        self.substream(self.forks)
+    }
+
+    /// The independent stream with the given id, which is the same every time it is requested.
+    pub fn substream(&self, id: u64) -> Self {
+        Self {
+            key: mix(self.key ^ mix(id.wrapping_mul(FORK_GAMMA))),
+            counter: 0,
+            forks: 0,
+        }
+    }
+}
+
+impl RngCore for RngContext {
+    fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let value = mix(self.key ^ mix(self.counter.wrapping_mul(GOLDEN_GAMMA)));
+        self.counter = self.counter.wrapping_add(1);
+        value
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for chunk in dest.chunks_mut(8) {
+            let bytes = self.next_u64().to_le_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+/// The finalizer of SplitMix64.
+fn mix(value: u64) -> u64 {
+    let mut z = value.wrapping_add(GOLDEN_GAMMA);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// The state of the random number generators of a backend, as returned by
+/// [rng_state](crate::backend::Backend::rng_state).
+#[derive(Debug, Clone, Default)]
+pub struct RngState {
+    root: Option<RngContext>,
+    devices: HashMap<DeviceId, RngContext>,
+}
+
+/// The random number generators of a backend, one stream per device.
+///
+/// The stream of a device is taken from the seed of the device when one was given, else it is
+/// derived from the seed of the backend, so that seeding the backend still makes every device
+/// reproducible, and random otherwise.
+pub struct DeviceRngs {
+    state: Mutex<Option<RngState>>,
+}
+
+impl DeviceRngs {
+    /// Create the generators, without any seed.
+    pub const fn new() -> Self {
+        Self {
+            state: Mutex::new(None),
+        }
+    }
+
+    /// Seed the generators of all devices.
+    pub fn seed(&self, seed: u64) {
+        *self.state.lock().unwrap() = Some(RngState {
+            root: Some(RngContext::new(seed)),
+            devices: HashMap::new(),
+        });
+    }
+
+    /// Seed the generator of one device, leaving the other devices untouched.
+    pub fn seed_device(&self, device: DeviceId, seed: u64) {
+        let mut state = self.state.lock().unwrap();
+        state
+            .get_or_insert_with(Default::default)
+            .devices
+            .insert(device, RngContext::new(seed));
+    }
+
+    /// Run the function with the generator of the device.
+    pub fn with<T>(&self, device: DeviceId, func: impl FnOnce(&mut RngContext) -> T) -> T {
+        let mut state = self.state.lock().unwrap();
+        let state = state.get_or_insert_with(Default::default);
+        let root = &state.root;
+        let rng = state.devices.entry(device).or_insert_with(|| match root {
+            Some(root) => root.substream(((device.type_id as u64) << 32) | device.index_id as u64),
+            None => RngContext::from_entropy(),
+        });
+
+        func(rng)
+    }
+
+    /// The current state of the generators.
+    pub fn state(&self) -> RngState {
+        self.state.lock().unwrap().clone().unwrap_or_default()
+    }
+
+    /// Restore a state returned by [state](DeviceRngs::state).
+    pub fn restore(&self, state: RngState) {
+        *self.state.lock().unwrap() = Some(state);
+    }
+}
+
+impl Default for DeviceRngs {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    fn sequence(rng: &mut RngContext) -> Vec<u64> {
+        (0..256).map(|_| rng.next_u64()).collect()
+    }
+
+    #[test]
+    fn forked_streams_should_be_decorrelated() {
+        let mut root = RngContext::new(0);
+        let mut first = root.fork();
+        let mut second = root.fork();
+
+        let first = sequence(&mut first);
+        let second = sequence(&mut second);
+        let root = sequence(&mut root);
+
+        for (a, b) in [(&first, &second), (&first, &root), (&second, &root)] {
+            assert!(a.iter().all(|value| !b.contains(value)));
+
+            // The fraction of matching bits of decorrelated streams is close to one half.
+            let matching: u32 = a
+                .iter()
+                .zip(b.iter())
+                .map(|(a, b)| (!(a ^ b)).count_ones())
+                .sum();
+            let fraction = matching as f64 / (64 * a.len()) as f64;
+            assert!((fraction - 0.5).abs() < 0.02, "{fraction}");
+        }
+    }
+
+    #[test]
+    fn forks_should_not_depend_on_the_generated_values() {
+        let mut rng = RngContext::new(7);
+        let expected = rng.clone().fork();
+
+        sequence(&mut rng);
+
+        assert_eq!(rng.fork(), expected);
+    }
+
+    #[test]
+    fn device_seed_should_not_affect_other_devices() {
+        let rngs = DeviceRngs::new();
+        let device_0 = DeviceId::new(0, 0);
+        let device_1 = DeviceId::new(0, 1);
+
+        rngs.seed(1);
+        let expected = rngs.with(device_1, sequence);
+
+        rngs.seed(1);
+        rngs.seed_device(device_0, 2);
+        rngs.with(device_0, sequence);
+
+        assert_eq!(rngs.with(device_1, sequence), expected);
+    }
+
+    #[test]
+    fn restored_state_should_reproduce_the_sequence() {
+        let rngs = DeviceRngs::new();
+        let device = DeviceId::new(0, 0);
+        rngs.seed(3);
+
+        let state = rngs.state();
+        let expected = rngs.with(device, sequence);
+        rngs.restore(state);
+
+        assert_eq!(rngs.with(device, sequence), expected);
+    }
+}
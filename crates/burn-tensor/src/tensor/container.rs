@@ -73,6 +73,11 @@ where
             .map(|primitive| Tensor::from_primitive(*primitive))
     }
 
+    /// If a tensor is registered for the given ID.
+    pub fn contains(&self, id: &ID) -> bool {
+        self.tensors.contains_key(id)
+    }
+
     /// The number of tensors registered.
     pub fn len(&self) -> usize {
         self.tensors.len()
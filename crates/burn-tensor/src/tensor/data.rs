@@ -7,9 +7,10 @@ use alloc::vec::Vec;
 use half::{bf16, f16};
 
 use crate::{
+    int_bounds,
     quantization::{Quantization, QuantizationStrategy},
     tensor::Shape,
-    DType, Distribution, Element, ElementConversion,
+    CastOptions, DType, Distribution, Element, ElementConversion, Rounding,
 };
 
 use num_traits::pow::Pow;
@@ -277,6 +278,51 @@ impl TensorData {
         }
     }
 
+    /// Converts the data to a different element type with the given [options](CastOptions).
+    ///
+    /// Floats are rounded when converted to integers. With saturation, the values overflowing
+    /// the target type are clamped to its bounds, infinities and NaN are kept when converted to
+    /// floats and NaN is mapped to zero when converted to integers. Without saturation, the
+    /// conversion panics when a value can't be represented by the target integer type, while
+    /// overflowing floats become infinite.
+    pub fn convert_with<E: Element>(self, options: CastOptions) -> Self {
+        let shape = self.shape.clone();
+        let to_float = is_float(E::dtype());
+
+        match self.dtype {
+            DType::QFloat(_) => panic!("Quantized data can't be converted"),
+            DType::F64 | DType::F32 | DType::F16 | DType::BF16 if to_float => {
+                floats_into::<E>(self.iter::<f64>(), shape, options.saturate)
+            }
+            DType::F64 | DType::F32 | DType::F16 | DType::BF16 => {
+                let values = self.iter::<f64>().map(|value| {
+                    let value = round(value, options.rounding);
+                    assert!(
+                        options.saturate || value.is_finite(),
+                        "Element cannot be represented in the target type"
+                    );
+                    // Float to int casts saturate and map NaN to zero.
+                    value as i128
+                });
+                ints_into::<E>(values, shape, options.saturate)
+            }
+            DType::U64 => {
+                let values = self.iter::<u64>().map(i128::from);
+                match to_float {
+                    true => floats_into::<E>(values.map(|v| v as f64), shape, options.saturate),
+                    false => ints_into::<E>(values, shape, options.saturate),
+                }
+            }
+            _ => {
+                let values = self.iter::<i64>().map(i128::from);
+                match to_float {
+                    true => floats_into::<E>(values.map(|v| v as f64), shape, options.saturate),
+                    false => ints_into::<E>(values, shape, options.saturate),
+                }
+            }
+        }
+    }
+
     /// Returns the data as a slice of bytes.
     pub fn as_bytes(&self) -> &[u8] {
         self.bytes.as_slice()
@@ -418,7 +464,8 @@ impl TensorData {
     /// # Arguments
     ///
     /// * `other` - The other data.
-    /// * `tolerance` - The tolerance of the comparison.
+    /// * `tolerance` - The tolerance of the comparison, widened to a few units of the precision of
+    ///   the half precision data, relative to the magnitude of the values.
     ///
     /// # Panics
     ///
@@ -435,6 +482,16 @@ impl TensorData {
         }
 
         let iter = self.iter::<f64>().zip(other.iter::<f64>());
+        // The half precision values are only compared within a few units of their precision,
+        // relative to their magnitude.
+        let half_epsilon = [self.dtype, other.dtype]
+            .into_iter()
+            .filter_map(|dtype| match dtype {
+                DType::F16 => Some(f16::EPSILON.to_f64()),
+                DType::BF16 => Some(bf16::EPSILON.to_f64()),
+                _ => None,
+            })
+            .reduce(f64::max);
 
         let mut num_diff = 0;
         let max_num_diff = 5;
@@ -450,6 +507,10 @@ impl TensorData {
             }
 
             let err = ((a - b).pow(2.0f64)).sqrt();
+            let tolerance = match half_epsilon {
+                Some(epsilon) => tolerance.max(4.0 * epsilon * a.abs().max(b.abs()).max(1.0)),
+                None => tolerance,
+            };
 
             if err > tolerance || err.is_nan() {
                 // Only print the first 5 different values.
@@ -830,7 +891,8 @@ impl<E: Into<f64> + Clone + core::fmt::Debug + PartialEq, const D: usize> Data<E
     /// # Arguments
     ///
     /// * `other` - The other data.
-    /// * `tolerance` - The tolerance of the comparison.
+    /// * `tolerance` - The tolerance of the comparison, widened to a few units of the precision of
+    ///   the half precision data, relative to the magnitude of the values.
     ///
     /// # Panics
     ///
@@ -865,6 +927,10 @@ impl<E: Into<f64> + Clone + core::fmt::Debug + PartialEq, const D: usize> Data<E
             }
 
             let err = ((a - b).pow(2.0f64)).sqrt();
+            let tolerance = match half_epsilon {
+                Some(epsilon) => tolerance.max(4.0 * epsilon * a.abs().max(b.abs()).max(1.0)),
+                None => tolerance,
+            };
 
             if err > tolerance || err.is_nan() {
                 // Only print the first 5 different values.
@@ -1014,6 +1080,87 @@ impl<E: core::fmt::Debug, const D: usize> core::fmt::Display for Data<E, D> {
     }
 }
 
+fn is_float(dtype: DType) -> bool {
+    matches!(
+        dtype,
+        DType::F64 | DType::F32 | DType::F16 | DType::BF16 | DType::QFloat(_)
+    )
+}
+
+fn round(value: f64, rounding: Rounding) -> f64 {
+    match rounding {
+        Rounding::Trunc => value.trunc(),
+        Rounding::Floor => value.floor(),
+        Rounding::Ceil => value.ceil(),
+        // Ties are rounded away from zero by `round`, so they are handled separately.
+        Rounding::Nearest => match (value - value.trunc()).abs() == 0.5 {
+            true => 2.0 * (value / 2.0).round(),
+            false => value.round(),
+        },
+    }
+}
+
+fn floats_into<E: Element>(
+    values: impl Iterator<Item = f64>,
+    shape: Vec<usize>,
+    saturate: bool,
+) -> TensorData {
+    let saturated = |value: f64, max: f64| match saturate && value.is_finite() {
+        true => value.clamp(-max, max),
+        false => value,
+    };
+
+    match E::dtype() {
+        DType::F64 => TensorData::new(values.collect::<Vec<_>>(), shape),
+        DType::F32 => TensorData::new(
+            values
+                .map(|v| saturated(v, f32::MAX as f64) as f32)
+                .collect(),
+            shape,
+        ),
+        DType::F16 => TensorData::new(
+            values
+                .map(|v| f16::from_f64(saturated(v, f16::MAX.to_f64())))
+                .collect(),
+            shape,
+        ),
+        DType::BF16 => TensorData::new(
+            values
+                .map(|v| bf16::from_f64(saturated(v, bf16::MAX.to_f64())))
+                .collect(),
+            shape,
+        ),
+        dtype => panic!("{dtype:?} is not a float type"),
+    }
+}
+
+fn ints_into<E: Element>(
+    values: impl Iterator<Item = i128>,
+    shape: Vec<usize>,
+    saturate: bool,
+) -> TensorData {
+    let (min, max) = int_bounds::<E>();
+    let (min, max) = (min as i128, max as i128);
+    let values = values.map(|value| {
+        assert!(
+            saturate || (min <= value && value <= max),
+            "Element cannot be represented in the target type"
+        );
+        value.clamp(min, max)
+    });
+
+    match E::dtype() {
+        DType::I64 => TensorData::new(values.map(|v| v as i64).collect(), shape),
+        DType::I32 => TensorData::new(values.map(|v| v as i32).collect(), shape),
+        DType::I16 => TensorData::new(values.map(|v| v as i16).collect(), shape),
+        DType::I8 => TensorData::new(values.map(|v| v as i8).collect(), shape),
+        DType::U64 => TensorData::new(values.map(|v| v as u64).collect(), shape),
+        DType::U32 => TensorData::new(values.map(|v| v as u32).collect(), shape),
+        DType::U8 => TensorData::new(values.map(|v| v as u8).collect(), shape),
+        dtype => panic!("{dtype:?} is not an integer type"),
+    }
+}
+
 #[cfg(test)]
 #[allow(deprecated)]
 mod tests {
@@ -1021,6 +1168,82 @@ mod tests {
     use alloc::vec;
     use rand::{rngs::StdRng, SeedableRng};
 
+    #[test]
+    fn convert_with_should_round_floats_to_ints() {
+        let data = TensorData::from([-2.5f32, -1.5, -0.5, 0.5, 1.5, 2.5, 2.6]);
+
+        let cases = [
+            (Rounding::Nearest, [-2, -2, 0, 0, 2, 2, 3]),
+            (Rounding::Trunc, [-2, -1, 0, 0, 1, 2, 2]),
+            (Rounding::Floor, [-3, -2, -1, 0, 1, 2, 2]),
+            (Rounding::Ceil, [-2, -1, 0, 1, 2, 3, 3]),
+        ];
+
+        for (rounding, expected) in cases {
+            let output = data
+                .clone()
+                .convert_with::<i32>(CastOptions::new(rounding, true));
+            assert_eq!(output, TensorData::from(expected));
+        }
+    }
+
+    #[test]
+    fn convert_with_should_saturate_floats_to_ints() {
+        let data = TensorData::from([
+            i32::MAX as f32,
+            i32::MIN as f32,
+            f32::INFINITY,
+            f32::NEG_INFINITY,
+            f32::NAN,
+        ]);
+
+        let output = data.convert_with::<i32>(CastOptions::default());
+
+        assert_eq!(
+            output,
+            TensorData::from([i32::MAX, i32::MIN, i32::MAX, i32::MIN, 0])
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn convert_with_should_panic_on_overflow_without_saturation() {
+        let data = TensorData::from([i32::MAX as f32]);
+
+        data.convert_with::<i32>(CastOptions::new(Rounding::Nearest, false));
+    }
+
+    #[test]
+    fn convert_with_should_saturate_ints_to_smaller_ints() {
+        let data = TensorData::from([-1000i64, -129, -128, 0, 127, 128, 1000]);
+
+        assert_eq!(
+            data.clone().convert_with::<i8>(CastOptions::default()),
+            TensorData::from([-128i8, -128, -128, 0, 127, 127, 127])
+        );
+        assert_eq!(
+            data.convert_with::<u8>(CastOptions::default()),
+            TensorData::from([0u8, 0, 0, 0, 127, 128, 255])
+        );
+    }
+
+    #[test]
+    fn convert_with_should_saturate_f32_to_f16() {
+        let data = TensorData::from([1e6f32, -1e6, 1.5, f32::INFINITY, f32::NAN]);
+
+        let saturated = data.clone().convert_with::<f16>(CastOptions::default());
+        let values = saturated.iter::<f16>().collect::<Vec<_>>();
+        assert_eq!(
+            &values[0..4],
+            &[f16::MAX, f16::MIN, f16::from_f32(1.5), f16::INFINITY]
+        );
+        assert!(values[4].is_nan());
+
+        let overflowed = data.convert_with::<f16>(CastOptions::new(Rounding::Nearest, false));
+        let values = overflowed.iter::<f16>().collect::<Vec<_>>();
+        assert_eq!(&values[0..2], &[f16::INFINITY, f16::NEG_INFINITY]);
+    }
+
     #[test]
     fn should_have_right_num_elements() {
         let shape = Shape::new([3, 5, 6]);
@@ -33,6 +33,9 @@ pub mod ops;
 /// Tensor quantization module.
 pub mod quantization;
 
+/// The sparse tensor module.
+pub mod sparse;
+
 #[cfg(feature = "std")]
 pub use report::*;
 
@@ -1,5 +1,7 @@
 use crate::{
     backend::Backend,
+    check,
+    check::TensorCheck,
     ops::{ConvOptions, ConvTransposeOptions, InterpolateOptions, UnfoldOptions},
     Int, Tensor, TensorPrimitive,
 };
@@ -15,6 +17,17 @@ where
     )))
 }
 
+/// Applies the [dropout](crate::ops::ModuleOps::dropout) to the tensor.
+pub fn dropout<B, const D: usize>(x: Tensor<B, D>, prob: f64) -> Tensor<B, D>
+where
+    B: Backend,
+{
+    Tensor::new(TensorPrimitive::Float(B::dropout(
+        x.primitive.tensor(),
+        prob,
+    )))
+}
+
 /// Applies a [1D convolution](crate::ops::ModuleOps::conv2d).
 pub fn conv1d<B>(
     x: Tensor<B, 3>,
@@ -25,6 +38,14 @@ pub fn conv1d<B>(
 where
     B: Backend,
 {
+    check!(TensorCheck::conv(
+        "Conv1d",
+        &x,
+        &weight,
+        bias.as_ref(),
+        options.groups
+    ));
+
     Tensor::new(TensorPrimitive::Float(B::conv1d(
         x.primitive.tensor(),
         weight.primitive.tensor(),
@@ -43,6 +64,14 @@ pub fn conv2d<B>(
 where
     B: Backend,
 {
+    check!(TensorCheck::conv(
+        "Conv2d",
+        &x,
+        &weight,
+        bias.as_ref(),
+        options.groups
+    ));
+
     Tensor::new(TensorPrimitive::Float(B::conv2d(
         x.primitive.tensor(),
         weight.primitive.tensor(),
@@ -61,6 +90,14 @@ pub fn conv3d<B>(
 where
     B: Backend,
 {
+    check!(TensorCheck::conv(
+        "Conv3d",
+        &x,
+        &weight,
+        bias.as_ref(),
+        options.groups
+    ));
+
     Tensor::new(TensorPrimitive::Float(B::conv3d(
         x.primitive.tensor(),
         weight.primitive.tensor(),
@@ -79,6 +116,14 @@ pub fn conv_transpose1d<B>(
 where
     B: Backend,
 {
+    check!(TensorCheck::conv_transpose(
+        "ConvTranspose1d",
+        &x,
+        &weight,
+        bias.as_ref(),
+        options.groups
+    ));
+
     Tensor::new(TensorPrimitive::Float(B::conv_transpose1d(
         x.primitive.tensor(),
         weight.primitive.tensor(),
@@ -97,6 +142,14 @@ pub fn conv_transpose2d<B>(
 where
     B: Backend,
 {
+    check!(TensorCheck::conv_transpose(
+        "ConvTranspose2d",
+        &x,
+        &weight,
+        bias.as_ref(),
+        options.groups
+    ));
+
     Tensor::new(TensorPrimitive::Float(B::conv_transpose2d(
         x.primitive.tensor(),
         weight.primitive.tensor(),
@@ -115,6 +168,14 @@ pub fn conv_transpose3d<B>(
 where
     B: Backend,
 {
+    check!(TensorCheck::conv_transpose(
+        "ConvTranspose3d",
+        &x,
+        &weight,
+        bias.as_ref(),
+        options.groups
+    ));
+
     Tensor::new(TensorPrimitive::Float(B::conv_transpose3d(
         x.primitive.tensor(),
         weight.primitive.tensor(),
@@ -142,6 +203,7 @@ pub fn max_pool1d<B>(
     stride: usize,
     padding: usize,
     dilation: usize,
+    ceil_mode: bool,
 ) -> Tensor<B, 3>
 where
     B: Backend,
@@ -152,6 +214,7 @@ where
         stride,
         padding,
         dilation,
+        ceil_mode,
     )))
 }
 
@@ -162,6 +225,7 @@ pub fn max_pool2d<B>(
     stride: [usize; 2],
     padding: [usize; 2],
     dilation: [usize; 2],
+    ceil_mode: bool,
 ) -> Tensor<B, 4>
 where
     B: Backend,
@@ -172,6 +236,7 @@ where
         stride,
         padding,
         dilation,
+        ceil_mode,
     )))
 }
 
@@ -182,6 +247,7 @@ pub fn avg_pool2d<B>(
     stride: [usize; 2],
     padding: [usize; 2],
     count_include_pad: bool,
+    ceil_mode: bool,
 ) -> Tensor<B, 4>
 where
     B: Backend,
@@ -192,6 +258,7 @@ where
         stride,
         padding,
         count_include_pad,
+        ceil_mode,
     )))
 }
 
@@ -202,6 +269,7 @@ pub fn avg_pool1d<B>(
     stride: usize,
     padding: usize,
     count_include_pad: bool,
+    ceil_mode: bool,
 ) -> Tensor<B, 3>
 where
     B: Backend,
@@ -212,6 +280,7 @@ where
         stride,
         padding,
         count_include_pad,
+        ceil_mode,
     )))
 }
 
@@ -222,12 +291,19 @@ pub fn max_pool1d_with_indices<B>(
     stride: usize,
     padding: usize,
     dilation: usize,
+    ceil_mode: bool,
 ) -> (Tensor<B, 3>, Tensor<B, 3, Int>)
 where
     B: Backend,
 {
-    let output =
-        B::max_pool1d_with_indices(x.primitive.tensor(), kernel_size, stride, padding, dilation);
+    let output = B::max_pool1d_with_indices(
+        x.primitive.tensor(),
+        kernel_size,
+        stride,
+        padding,
+        dilation,
+        ceil_mode,
+    );
 
     (
         Tensor::new(TensorPrimitive::Float(output.output)),
@@ -242,12 +318,19 @@ pub fn max_pool2d_with_indices<B>(
     stride: [usize; 2],
     padding: [usize; 2],
     dilation: [usize; 2],
+    ceil_mode: bool,
 ) -> (Tensor<B, 4>, Tensor<B, 4, Int>)
 where
     B: Backend,
 {
-    let output =
-        B::max_pool2d_with_indices(x.primitive.tensor(), kernel_size, stride, padding, dilation);
+    let output = B::max_pool2d_with_indices(
+        x.primitive.tensor(),
+        kernel_size,
+        stride,
+        padding,
+        dilation,
+        ceil_mode,
+    );
 
     (
         Tensor::new(TensorPrimitive::Float(output.output)),
@@ -3,8 +3,8 @@ use super::{
     FloatTensor, IntTensor,
 };
 use crate::{
-    argwhere_data, backend::Backend, chunk, narrow, tensor::Shape, Bool, ElementConversion, Tensor,
-    TensorData,
+    argwhere_data, argwhere_padded_data, backend::Backend, chunk, narrow, tensor::Shape,
+    try_read_sync, Bool, ElementConversion, Tensor, TensorData,
 };
 use alloc::{vec, vec::Vec};
 use core::{future::Future, ops::Range};
@@ -233,6 +233,51 @@ pub trait BoolTensorOps<B: Backend> {
     /// The tensor with the result of the negation.
     fn bool_not<const D: usize>(tensor: BoolTensor<B, D>) -> BoolTensor<B, D>;
 
+    /// Element-wise logical and.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side tensor.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the result of the logical and.
+    fn bool_and<const D: usize>(lhs: BoolTensor<B, D>, rhs: BoolTensor<B, D>) -> BoolTensor<B, D> {
+        let count = B::int_add(B::bool_into_int(lhs), B::bool_into_int(rhs));
+        B::int_equal_elem(count, 2.elem())
+    }
+
+    /// Element-wise logical or.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side tensor.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the result of the logical or.
+    fn bool_or<const D: usize>(lhs: BoolTensor<B, D>, rhs: BoolTensor<B, D>) -> BoolTensor<B, D> {
+        let count = B::int_add(B::bool_into_int(lhs), B::bool_into_int(rhs));
+        B::int_greater_elem(count, 0.elem())
+    }
+
+    /// Element-wise logical exclusive or.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side tensor.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the result of the logical exclusive or.
+    fn bool_xor<const D: usize>(lhs: BoolTensor<B, D>, rhs: BoolTensor<B, D>) -> BoolTensor<B, D> {
+        let count = B::int_add(B::bool_into_int(lhs), B::bool_into_int(rhs));
+        B::int_equal_elem(count, 1.elem())
+    }
+
     /// Transposes a bool tensor.
     ///
     /// # Arguments
@@ -395,6 +440,21 @@ pub trait BoolTensorOps<B: Backend> {
         B::int_equal_elem(sum, (num_elems as i32).elem())
     }
 
+    /// Counts the elements of the boolean `tensor` that are True along a given dimension `dim`.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to count.
+    /// * `dim` - The axis along which to count.
+    ///
+    /// # Returns
+    ///
+    /// An int tensor with the same size as input `tensor`, except in the `dim` axis where the size
+    /// is 1, holding the number of True elements along this dim in the input.
+    fn bool_count_nonzero<const D: usize>(tensor: BoolTensor<B, D>, dim: usize) -> IntTensor<B, D> {
+        B::int_sum_dim(B::bool_into_int(tensor), dim)
+    }
+
     /// Compute the indices of the elements that are non-zero, grouped by element.
     ///
     /// # Arguments
@@ -417,6 +477,32 @@ pub trait BoolTensorOps<B: Backend> {
         }
     }
 
+    /// Compute the indices of at most `max_count` elements that are non-zero, grouped by element,
+    /// with a fixed output shape.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `max_count` - The number of rows of the indices.
+    ///
+    /// # Returns
+    ///
+    /// The indices of the first `max_count` non-zero elements of shape `[max_count, D]`, the rows
+    /// after the number of non-zero elements being filled with `-1`, and the number of non-zero
+    /// elements of shape `[1]`, which is greater than `max_count` when some are omitted.
+    fn bool_argwhere_padded<const D: usize>(
+        tensor: BoolTensor<B, D>,
+        max_count: usize,
+    ) -> (IntTensor<B, 2>, IntTensor<B, 1>) {
+        // The output shapes are static, but the default implementation computes the outputs
+        // from the data, which causes a sync.
+        let device = B::bool_device(&tensor);
+        let data = try_read_sync(B::bool_into_data(tensor)).expect(
+            "Failed to synchronously read tensor data. This operation is not supported until this backend has a GPU argwhere padded implementation.",
+        );
+        argwhere_padded_data::<B, D>(data, max_count, &device)
+    }
+
     /// Compute the indices of the elements that are non-zero.
     ///
     /// # Arguments
@@ -9,7 +9,9 @@ use alloc::vec::Vec;
 use core::future::Future;
 use core::ops::Range;
 
-use crate::{argsort, sort, sort_with_indices};
+use crate::{
+    argsort, bitwise, bitwise_scalar, index_put, pad, shift_mask, sort, sort_with_indices, PadMode,
+};
 
 /// Int Tensor API for basic and numeric operations, see [tensor](crate::Tensor)
 /// for documentation on each function.
@@ -147,7 +149,9 @@ pub trait IntTensorOps<B: Backend> {
     ///
     /// # Returns
     ///
-    /// The tensor with the values filled.
+    /// The tensor with the values filled, with the broadcasted shape of the tensor, the mask and
+    /// the source. The values must be selected, not computed from the mask, so that NaN or
+    /// infinite values of the unselected tensor don't propagate.
     fn int_mask_where<const D: usize>(
         tensor: IntTensor<B, D>,
         mask: BoolTensor<B, D>,
@@ -240,6 +244,35 @@ pub trait IntTensorOps<B: Backend> {
         value: IntTensor<B, D>,
     ) -> IntTensor<B, D>;
 
+    /// Put the values at the given indices along the given dimension, overwriting the elements or
+    /// adding the values to them.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `dim` - The dimension along which the values are put.
+    /// * `indices` - The positions along the dimension, one for each slice of the values.
+    /// * `values` - The values, with the same shape as the tensor except for the dimension, whose
+    ///   size is the number of indices.
+    /// * `accumulate` - Whether the values are added to the elements instead of overwriting them.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the values put at the indices. When an index is repeated without
+    /// accumulation, the values are written in index order, so the last one wins.
+    fn int_index_put<const D: usize>(
+        tensor: IntTensor<B, D>,
+        dim: usize,
+        indices: IntTensor<B, 1>,
+        values: IntTensor<B, D>,
+        accumulate: bool,
+    ) -> IntTensor<B, D> {
+        match accumulate {
+            true => B::int_select_assign(tensor, dim, indices, values),
+            false => index_put::<B, D, Int>(tensor, dim, indices, values),
+        }
+    }
+
     /// Repeats the tensor along the given dimension the given number of times.
     ///
     /// # Arguments
@@ -488,12 +521,18 @@ pub trait IntTensorOps<B: Backend> {
     ///
     /// # Returns
     ///
-    /// The elements of `lhs` raised to the power of the elements of `rhs`.
+    /// The elements of `lhs` raised to the power of the elements of `rhs`. Negative exponents
+    /// round the result toward zero, so only `1` and `-1` keep a non-zero power.
     fn int_powi<const D: usize>(lhs: IntTensor<B, D>, rhs: IntTensor<B, D>) -> IntTensor<B, D> {
-        B::float_into_int(B::float_powf(
+        // Raising to the absolute exponent avoids the infinite negative powers of zero.
+        let negative = B::int_lower_elem(rhs.clone(), 0.elem());
+        let non_unit = B::bool_not(B::int_equal_elem(B::int_abs(lhs.clone()), 1.elem()));
+        let power = B::float_into_int(B::float_powf(
             B::int_into_float(lhs),
-            B::int_into_float(rhs),
-        ))
+            B::int_into_float(B::int_abs(rhs)),
+        ));
+
+        B::int_mask_fill(power, B::bool_and(negative, non_unit), 0.elem())
     }
 
     /// Element-wise power with a floatTensor.
@@ -519,9 +558,21 @@ pub trait IntTensorOps<B: Backend> {
     ///
     /// # Returns
     ///
-    /// The elements of `lhs` raised to the value of `rhs`.
+    /// The elements of `lhs` raised to the value of `rhs`. A negative exponent rounds the result
+    /// toward zero, so only `1` and `-1` keep a non-zero power.
     fn int_powi_scalar<const D: usize>(lhs: IntTensor<B, D>, rhs: IntElem<B>) -> IntTensor<B, D> {
-        B::float_into_int(B::float_powf_scalar(B::int_into_float(lhs), rhs.to_f32()))
+        let exponent = rhs.to_f32();
+        let power = B::float_into_int(B::float_powf_scalar(
+            B::int_into_float(lhs.clone()),
+            if exponent < 0.0 { -exponent } else { exponent },
+        ));
+
+        if exponent >= 0.0 {
+            return power;
+        }
+
+        let non_unit = B::bool_not(B::int_equal_elem(B::int_abs(lhs), 1.elem()));
+        B::int_mask_fill(power, non_unit, 0.elem())
     }
 
     /// Element-wise power with a floatTensor.
@@ -635,6 +686,29 @@ pub trait IntTensorOps<B: Backend> {
     /// The result of the multiplication.
     fn int_mul_scalar<const D: usize>(lhs: IntTensor<B, D>, rhs: IntElem<B>) -> IntTensor<B, D>;
 
+    /// Multiplies two tensors together using matrix multiplication.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side tensor.
+    ///
+    /// # Returns
+    ///
+    /// The result of multiplying the two tensors together using matrix multiplication.
+    ///
+    /// # Remarks
+    ///
+    /// The default implementation multiplies the tensors as floats, so the result is only exact
+    /// when the sums fit in the mantissa of the float element. The backends should override it
+    /// with an exact integer matmul.
+    fn int_matmul<const D: usize>(lhs: IntTensor<B, D>, rhs: IntTensor<B, D>) -> IntTensor<B, D> {
+        B::float_into_int(B::float_matmul(
+            B::int_into_float(lhs),
+            B::int_into_float(rhs),
+        ))
+    }
+
     /// Element-wise division.
     ///
     /// # Arguments
@@ -644,7 +718,7 @@ pub trait IntTensorOps<B: Backend> {
     ///
     /// # Returns
     ///
-    /// The result of the division.
+    /// The result of the division, rounded toward zero.
     fn int_div<const D: usize>(lhs: IntTensor<B, D>, rhs: IntTensor<B, D>) -> IntTensor<B, D>;
 
     /// Element-wise division with a scalar.
@@ -656,10 +730,35 @@ pub trait IntTensorOps<B: Backend> {
     ///
     /// # Returns
     ///
-    /// The result of the division.
+    /// The result of the division, rounded toward zero.
     fn int_div_scalar<const D: usize>(lhs: IntTensor<B, D>, rhs: IntElem<B>) -> IntTensor<B, D>;
 
-    /// Element-wise modulus with a scalar.
+    /// Element-wise Euclidean remainder.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side tensor.
+    ///
+    /// # Returns
+    ///
+    /// The Euclidean remainder of the division, which is never negative: `r` in `[0, |rhs|)`
+    /// such that `lhs = q * rhs + r`.
+    fn int_remainder<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntTensor<B, D>,
+    ) -> IntTensor<B, D> {
+        let divisor = B::int_abs(rhs);
+        let quotient = B::int_div(lhs.clone(), divisor.clone());
+        let remainder = B::int_sub(lhs, B::int_mul(quotient, divisor.clone()));
+
+        // The division rounds toward zero, so the remainder has the sign of the dividend.
+        let negative = B::int_lower_elem(remainder.clone(), 0.elem());
+        let shifted = B::int_add(remainder.clone(), divisor);
+        B::int_mask_where(remainder, negative, shifted)
+    }
+
+    /// Element-wise Euclidean remainder with a scalar.
     ///
     /// # Arguments
     /// * `lhs` - The left hand side tensor.
@@ -667,7 +766,7 @@ pub trait IntTensorOps<B: Backend> {
     ///
     /// # Returns
     ///
-    /// The result of applying the modulus of the scalar to the tensor.
+    /// The Euclidean remainder of the division by the scalar, in `[0, |rhs|)`.
     fn int_remainder_scalar<const D: usize>(
         lhs: IntTensor<B, D>,
         rhs: IntElem<B>,
@@ -786,6 +885,12 @@ pub trait IntTensorOps<B: Backend> {
     /// The mean of all elements in the tensor.
     fn int_mean<const D: usize>(tensor: IntTensor<B, D>) -> IntTensor<B, 1> {
         let num_elems = B::int_shape(&tensor).num_elements();
+
+        // The mean of an empty tensor isn't defined for integers, it is zero like its sum.
+        if num_elems == 0 {
+            return B::int_sum(tensor);
+        }
+
         B::int_div_scalar(B::int_sum(tensor), (num_elems as i64).elem())
     }
 
@@ -1191,6 +1296,67 @@ pub trait IntTensorOps<B: Backend> {
         B::int_equal_elem(sum, (num_elems as i32).elem())
     }
 
+    /// Counts the elements of the int `tensor` that are non-zero along a given dimension `dim`.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to count.
+    /// * `dim` - The axis along which to count.
+    ///
+    /// # Returns
+    ///
+    /// An int tensor with the same size as input `tensor`, except in the `dim` axis where the size
+    /// is 1, holding the number of non-zero elements along this dim in the input.
+    fn int_count_nonzero<const D: usize>(tensor: IntTensor<B, D>, dim: usize) -> IntTensor<B, D> {
+        B::bool_count_nonzero(B::int_not_equal_elem(tensor, 0.elem()), dim)
+    }
+
+    /// Tests element wise if the int `lhs` tensor is close to `rhs`, that is if
+    /// `abs(lhs - rhs) <= atol + rtol * abs(rhs)` or `lhs == rhs`.
+    ///
+    /// The elements are compared as floats, so the tolerances aren't truncated to integers.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The tensor to compare.
+    /// * `rhs` - The reference tensor, with the same shape.
+    /// * `rtol` - The relative tolerance.
+    /// * `atol` - The absolute tolerance.
+    ///
+    /// # Returns
+    ///
+    /// A boolean tensor with the shape of the inputs, True where the elements are close.
+    fn int_is_close<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntTensor<B, D>,
+        rtol: f64,
+        atol: f64,
+    ) -> BoolTensor<B, D> {
+        B::float_is_close(B::int_into_float(lhs), B::int_into_float(rhs), rtol, atol)
+    }
+
+    /// Tests if all the elements of the int `lhs` tensor are [close](Self::int_is_close) to the
+    /// ones of `rhs`.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The tensor to compare.
+    /// * `rhs` - The reference tensor, with the same shape.
+    /// * `rtol` - The relative tolerance.
+    /// * `atol` - The absolute tolerance.
+    ///
+    /// # Returns
+    ///
+    /// A boolean tensor with a single element, True if all the elements are close.
+    fn int_all_close<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntTensor<B, D>,
+        rtol: f64,
+        atol: f64,
+    ) -> BoolTensor<B, 1> {
+        B::float_all_close(B::int_into_float(lhs), B::int_into_float(rhs), rtol, atol)
+    }
+
     /// Returns the signs of the int `tensor`.
     ///
     /// # Arguments
@@ -1279,4 +1445,223 @@ pub trait IntTensorOps<B: Backend> {
     ) -> IntTensor<B, D> {
         argsort::<B, D, Int>(tensor, dim, descending)
     }
+
+    /// Pads the tensor with the given `(before, after)` amounts on each dimension, where negative
+    /// amounts crop the tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `padding` - The `(before, after)` padding amounts of each dimension.
+    /// * `mode` - How the padded elements are filled.
+    ///
+    /// # Returns
+    ///
+    /// The padded tensor.
+    fn int_pad<const D: usize>(
+        tensor: IntTensor<B, D>,
+        padding: [(isize, isize); D],
+        mode: PadMode<IntElem<B>>,
+    ) -> IntTensor<B, D> {
+        pad::<B, D, Int>(tensor, padding, mode)
+    }
+
+    /// Element-wise bitwise and.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side tensor.
+    ///
+    /// # Returns
+    ///
+    /// The result of the bitwise and.
+    fn int_bitwise_and<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntTensor<B, D>,
+    ) -> IntTensor<B, D> {
+        bitwise::<B, D>(lhs, rhs, |lhs, rhs| lhs & rhs)
+    }
+
+    /// Element-wise bitwise and with a scalar.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side scalar.
+    ///
+    /// # Returns
+    ///
+    /// The result of the bitwise and.
+    fn int_bitwise_and_scalar<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntElem<B>,
+    ) -> IntTensor<B, D> {
+        bitwise_scalar::<B, D>(lhs, rhs, |lhs, rhs| lhs & rhs)
+    }
+
+    /// Element-wise bitwise or.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side tensor.
+    ///
+    /// # Returns
+    ///
+    /// The result of the bitwise or.
+    fn int_bitwise_or<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntTensor<B, D>,
+    ) -> IntTensor<B, D> {
+        bitwise::<B, D>(lhs, rhs, |lhs, rhs| lhs | rhs)
+    }
+
+    /// Element-wise bitwise or with a scalar.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side scalar.
+    ///
+    /// # Returns
+    ///
+    /// The result of the bitwise or.
+    fn int_bitwise_or_scalar<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntElem<B>,
+    ) -> IntTensor<B, D> {
+        bitwise_scalar::<B, D>(lhs, rhs, |lhs, rhs| lhs | rhs)
+    }
+
+    /// Element-wise bitwise exclusive or.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side tensor.
+    ///
+    /// # Returns
+    ///
+    /// The result of the bitwise exclusive or.
+    fn int_bitwise_xor<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntTensor<B, D>,
+    ) -> IntTensor<B, D> {
+        bitwise::<B, D>(lhs, rhs, |lhs, rhs| lhs ^ rhs)
+    }
+
+    /// Element-wise bitwise exclusive or with a scalar.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side scalar.
+    ///
+    /// # Returns
+    ///
+    /// The result of the bitwise exclusive or.
+    fn int_bitwise_xor_scalar<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntElem<B>,
+    ) -> IntTensor<B, D> {
+        bitwise_scalar::<B, D>(lhs, rhs, |lhs, rhs| lhs ^ rhs)
+    }
+
+    /// Element-wise bitwise not.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with all the bits flipped.
+    fn int_bitwise_not<const D: usize>(tensor: IntTensor<B, D>) -> IntTensor<B, D> {
+        B::int_bitwise_xor_scalar(tensor, (-1).elem())
+    }
+
+    /// Element-wise left shift.
+    ///
+    /// Only the lowest bits of the shift amounts are used, each amount being taken modulo the bit
+    /// width of the integer type, like with [shift_mask](crate::shift_mask).
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The tensor to shift.
+    /// * `rhs` - The shift amounts.
+    ///
+    /// # Returns
+    ///
+    /// The shifted tensor, the bits shifted out being discarded.
+    fn int_bitwise_left_shift<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntTensor<B, D>,
+    ) -> IntTensor<B, D> {
+        let rhs = B::int_bitwise_and_scalar(rhs, shift_mask::<IntElem<B>>().elem());
+        bitwise::<B, D>(lhs, rhs, |lhs, rhs| lhs << rhs)
+    }
+
+    /// Element-wise left shift by a scalar.
+    ///
+    /// Only the lowest bits of the shift amount are used, the amount being taken modulo the bit
+    /// width of the integer type, like with [shift_mask](crate::shift_mask).
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The tensor to shift.
+    /// * `rhs` - The shift amount.
+    ///
+    /// # Returns
+    ///
+    /// The shifted tensor, the bits shifted out being discarded.
+    fn int_bitwise_left_shift_scalar<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntElem<B>,
+    ) -> IntTensor<B, D> {
+        let rhs = (rhs.elem::<i64>() & shift_mask::<IntElem<B>>()).elem();
+        bitwise_scalar::<B, D>(lhs, rhs, |lhs, rhs| lhs << rhs)
+    }
+
+    /// Element-wise right shift, which is arithmetic for signed integer types.
+    ///
+    /// Only the lowest bits of the shift amounts are used, each amount being taken modulo the bit
+    /// width of the integer type, like with [shift_mask](crate::shift_mask).
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The tensor to shift.
+    /// * `rhs` - The shift amounts.
+    ///
+    /// # Returns
+    ///
+    /// The shifted tensor.
+    fn int_bitwise_right_shift<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntTensor<B, D>,
+    ) -> IntTensor<B, D> {
+        let rhs = B::int_bitwise_and_scalar(rhs, shift_mask::<IntElem<B>>().elem());
+        bitwise::<B, D>(lhs, rhs, |lhs, rhs| lhs >> rhs)
+    }
+
+    /// Element-wise right shift by a scalar, which is arithmetic for signed integer types.
+    ///
+    /// Only the lowest bits of the shift amount are used, the amount being taken modulo the bit
+    /// width of the integer type, like with [shift_mask](crate::shift_mask).
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The tensor to shift.
+    /// * `rhs` - The shift amount.
+    ///
+    /// # Returns
+    ///
+    /// The shifted tensor.
+    fn int_bitwise_right_shift_scalar<const D: usize>(
+        lhs: IntTensor<B, D>,
+        rhs: IntElem<B>,
+    ) -> IntTensor<B, D> {
+        let rhs = (rhs.elem::<i64>() & shift_mask::<IntElem<B>>()).elem();
+        bitwise_scalar::<B, D>(lhs, rhs, |lhs, rhs| lhs >> rhs)
+    }
 }
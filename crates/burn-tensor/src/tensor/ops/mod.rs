@@ -3,6 +3,7 @@ mod alias;
 mod bool_tensor;
 mod int_tensor;
 mod modules;
+mod optim;
 mod qtensor;
 mod tensor;
 
@@ -11,5 +12,6 @@ pub use alias::*;
 pub use bool_tensor::*;
 pub use int_tensor::*;
 pub use modules::*;
+pub use optim::*;
 pub use qtensor::*;
 pub use tensor::*;
@@ -2,7 +2,7 @@ use super::{conv, pool, unfold::unfold4d_using_conv2d};
 use crate::{
     backend::Backend,
     ops::{FloatTensor, IntTensor},
-    Shape,
+    Distribution, ElementConversion, Shape,
 };
 
 /// Gradient computed during the backward pass for each tensor used by [conv2d](ModuleOps::conv2d).
@@ -206,6 +206,26 @@ pub trait ModuleOps<B: Backend> {
 
         B::float_select_assign(grad, 0, indices, output_grad)
     }
+
+    /// Dropout operation, zeroing each element of the tensor with the probability `prob` and
+    /// scaling the other ones by `1 / (1 - prob)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The tensor.
+    /// * `prob` - The probability of zeroing an element.
+    ///
+    /// # Returns
+    ///
+    /// The output tensor.
+    fn dropout<const D: usize>(x: FloatTensor<B, D>, prob: f64) -> FloatTensor<B, D> {
+        let prob_keep = 1.0 - prob;
+        let shape = B::float_shape(&x);
+        let device = B::float_device(&x);
+        let mask = B::float_random(shape, Distribution::Bernoulli(prob_keep), &device);
+
+        B::float_mul_scalar(B::float_mul(x, mask), (1.0 / prob_keep).elem())
+    }
     /// One dimensional convolution.
     ///
     /// # Shapes
@@ -213,13 +233,22 @@ pub trait ModuleOps<B: Backend> {
     /// x:      `[batch_size, channels_in, length]`,
     /// weight: `[channels_out, channels_in, kernel_size]`,
     /// bias:   `[channels_out]`,
+    ///
+    /// When `kernel_size * dilation` exceeds [the threshold](conv::set_conv1d_fft_threshold),
+    /// if one is set, the default implementation computes the convolution in the frequency
+    /// domain.
     fn conv1d(
         x: FloatTensor<B, 3>,
         weight: FloatTensor<B, 3>,
         bias: Option<FloatTensor<B, 1>>,
         options: ConvOptions<1>,
     ) -> FloatTensor<B, 3> {
-        conv::conv1d_from_conv2d::<B>(x, weight, bias, options)
+        let [_, _, kernel_size] = B::float_shape(&weight).dims;
+
+        match conv::conv1d_use_fft(kernel_size, &options) {
+            true => conv::conv1d_fft::<B>(x, weight, bias, options),
+            false => conv::conv1d_from_conv2d::<B>(x, weight, bias, options),
+        }
     }
     /// Backward pass for the [conv1d](ModuleOps::conv1d) operation.
     fn conv1d_backward(
@@ -376,8 +405,16 @@ pub trait ModuleOps<B: Backend> {
         stride: usize,
         padding: usize,
         count_include_pad: bool,
+        ceil_mode: bool,
     ) -> FloatTensor<B, 3> {
-        pool::avg_pool1d_from_2d::<B>(x, kernel_size, stride, padding, count_include_pad)
+        pool::avg_pool1d_from_2d::<B>(
+            x,
+            kernel_size,
+            stride,
+            padding,
+            count_include_pad,
+            ceil_mode,
+        )
     }
     /// Backward pass for the [avg pooling 1d](ModuleOps::avg_pool1d) operation.
     fn avg_pool1d_backward(
@@ -387,6 +424,7 @@ pub trait ModuleOps<B: Backend> {
         stride: usize,
         padding: usize,
         count_include_pad: bool,
+        ceil_mode: bool,
     ) -> FloatTensor<B, 3> {
         pool::avg_pool1d_backward_from_2d::<B>(
             x,
@@ -395,6 +433,7 @@ pub trait ModuleOps<B: Backend> {
             stride,
             padding,
             count_include_pad,
+            ceil_mode,
         )
     }
     /// Two dimensional avg pooling.
@@ -408,6 +447,7 @@ pub trait ModuleOps<B: Backend> {
         stride: [usize; 2],
         padding: [usize; 2],
         count_include_pad: bool,
+        ceil_mode: bool,
     ) -> FloatTensor<B, 4>;
     /// Backward pass for the [avg pooling 2d](ModuleOps::avg_pool2d) operation.
     fn avg_pool2d_backward(
@@ -417,6 +457,7 @@ pub trait ModuleOps<B: Backend> {
         stride: [usize; 2],
         padding: [usize; 2],
         count_include_pad: bool,
+        ceil_mode: bool,
     ) -> FloatTensor<B, 4>;
     /// Two dimensional adaptive avg pooling.
     ///
@@ -455,8 +496,9 @@ pub trait ModuleOps<B: Backend> {
         stride: usize,
         padding: usize,
         dilation: usize,
+        ceil_mode: bool,
     ) -> FloatTensor<B, 3> {
-        pool::max_pool1d_from_2d::<B>(x, kernel_size, stride, padding, dilation)
+        pool::max_pool1d_from_2d::<B>(x, kernel_size, stride, padding, dilation, ceil_mode)
     }
 
     /// One dimensional max pooling with indices.
@@ -470,8 +512,16 @@ pub trait ModuleOps<B: Backend> {
         stride: usize,
         padding: usize,
         dilation: usize,
+        ceil_mode: bool,
     ) -> MaxPool1dWithIndices<B> {
-        pool::max_pool1d_with_indices_from_2d::<B>(x, kernel_size, stride, padding, dilation)
+        pool::max_pool1d_with_indices_from_2d::<B>(
+            x,
+            kernel_size,
+            stride,
+            padding,
+            dilation,
+            ceil_mode,
+        )
     }
     /// Backward pass for the [max pooling 1d](ModuleOps::max_pool1d_with_indices) operation.
     fn max_pool1d_with_indices_backward(
@@ -480,6 +530,7 @@ pub trait ModuleOps<B: Backend> {
         stride: usize,
         padding: usize,
         dilation: usize,
+        ceil_mode: bool,
         output_grad: FloatTensor<B, 3>,
         indices: IntTensor<B, 3>,
     ) -> MaxPool1dBackward<B> {
@@ -489,6 +540,7 @@ pub trait ModuleOps<B: Backend> {
             stride,
             padding,
             dilation,
+            ceil_mode,
             output_grad,
             indices,
         )
@@ -505,6 +557,7 @@ pub trait ModuleOps<B: Backend> {
         stride: [usize; 2],
         padding: [usize; 2],
         dilation: [usize; 2],
+        ceil_mode: bool,
     ) -> FloatTensor<B, 4>;
 
     /// Two dimensional max pooling with indices.
@@ -518,6 +571,7 @@ pub trait ModuleOps<B: Backend> {
         stride: [usize; 2],
         padding: [usize; 2],
         dilation: [usize; 2],
+        ceil_mode: bool,
     ) -> MaxPool2dWithIndices<B>;
     /// Backward pass for the [max pooling 2d](ModuleOps::max_pool2d_with_indices) operation.
     fn max_pool2d_with_indices_backward(
@@ -526,6 +580,7 @@ pub trait ModuleOps<B: Backend> {
         stride: [usize; 2],
         padding: [usize; 2],
         dilation: [usize; 2],
+        ceil_mode: bool,
         output_grad: FloatTensor<B, 4>,
         indices: IntTensor<B, 4>,
     ) -> MaxPool2dBackward<B>;
@@ -1,6 +1,38 @@
 #![allow(clippy::single_range_in_vec_init)]
-use super::{Conv1dBackward, Conv2dBackward, Conv3dBackward, ConvOptions, ConvTransposeOptions};
-use crate::{backend::Backend, ops::FloatTensor, Shape};
+use super::{
+    fft::fft, Conv1dBackward, Conv2dBackward, Conv3dBackward, ConvOptions, ConvTransposeOptions,
+};
+use crate::{backend::Backend, ops::FloatTensor, ElementConversion, Shape, TensorData};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+/// The span of the kernel above which the 1D convolution is computed in the frequency domain,
+/// zero when the path is disabled.
+static CONV1D_FFT_THRESHOLD: AtomicUsize = AtomicUsize::new(0);
+
+/// Set the span of the kernel, `kernel_size * dilation`, above which the
+/// [1D convolution](crate::ops::ModuleOps::conv1d) is computed in the frequency domain with
+/// [conv1d_fft], or disable the path with `None`.
+///
+/// The path is disabled by default, since the span where the frequency domain becomes faster
+/// depends on the backend and on the device. The `conv1d` benchmark compares both paths for a
+/// range of spans, which gives the threshold to use.
+pub fn set_conv1d_fft_threshold(threshold: Option<usize>) {
+    CONV1D_FFT_THRESHOLD.store(threshold.map(|t| t.max(1)).unwrap_or(0), Ordering::Relaxed);
+}
+
+/// The span of the kernel above which the [1D convolution](crate::ops::ModuleOps::conv1d) is
+/// computed in the frequency domain, if the path is [enabled](set_conv1d_fft_threshold).
+pub fn conv1d_fft_threshold() -> Option<usize> {
+    match CONV1D_FFT_THRESHOLD.load(Ordering::Relaxed) {
+        0 => None,
+        threshold => Some(threshold),
+    }
+}
+
+/// The smallest block length of the overlap-save method used by [conv1d_fft].
+const CONV1D_FFT_MIN_BLOCK: usize = 1024;
 
 #[cfg(not(feature = "std"))]
 use num_traits::Float;
@@ -47,14 +79,29 @@ pub fn calculate_conv_transpose_output_size(
 }
 
 /// Calculate the expected output size when doing a pooling operation.
+///
+/// With `ceil_mode`, the output size is rounded up so that a partial window covers the end of the
+/// input, as long as that window starts in the input or its left padding.
 pub fn calculate_pool_output_size(
     kernel_size: usize,
     stride: usize,
     padding: usize,
     dilation: usize,
+    ceil_mode: bool,
     size_in: usize,
 ) -> usize {
-    ((size_in + 2 * padding - dilation * (kernel_size - 1) - 1) / stride) + 1
+    let size = size_in + 2 * padding - dilation * (kernel_size - 1) - 1;
+
+    if !ceil_mode {
+        return size / stride + 1;
+    }
+
+    let size_out = size.div_ceil(stride) + 1;
+    if (size_out - 1) * stride >= size_in + padding {
+        size_out - 1
+    } else {
+        size_out
+    }
 }
 
 /// Calculate the [1D convolution](crate::ops::ModuleOps::conv1d) backward pass using convolutions.
@@ -72,27 +119,32 @@ pub(crate) fn conv1d_backward<B: Backend>(
     let [_batch_size, channels_out, length_out] = B::float_shape(&output_grad).dims;
     let [_, _, kernel_size] = weight_shape.dims;
 
-    let padding_out = calculate_padding_out(
-        kernel_size,
-        options.stride[0],
-        options.padding[0],
-        options.dilation[0],
-        length_in,
-        length_out,
-    );
+    let x_grad = match conv1d_use_fft(kernel_size, &options) {
+        true => conv1d_x_grad_fft::<B>(output_grad.clone(), weight, length_in, options.clone()),
+        false => {
+            let padding_out = calculate_padding_out(
+                kernel_size,
+                options.stride[0],
+                options.padding[0],
+                options.dilation[0],
+                length_in,
+                length_out,
+            );
 
-    let x_grad = B::conv_transpose1d(
-        output_grad.clone(),
-        weight,
-        None,
-        ConvTransposeOptions::new(
-            options.stride,
-            options.padding,
-            [padding_out],
-            options.dilation,
-            options.groups,
-        ),
-    );
+            B::conv_transpose1d(
+                output_grad.clone(),
+                weight,
+                None,
+                ConvTransposeOptions::new(
+                    options.stride,
+                    options.padding,
+                    [padding_out],
+                    options.dilation,
+                    options.groups,
+                ),
+            )
+        }
+    };
 
     let weight_grad = match options.groups == 1 {
         true => conv1d_weight_grad_no_groups::<B>(x, output_grad.clone(), weight_shape, options),
@@ -472,6 +524,282 @@ pub(crate) fn conv1d_from_conv2d<B: Backend>(
     B::float_reshape(tensor, Shape::from([batch_size, channels_out, height_out]))
 }
 
+/// Whether the [1D convolution](crate::ops::ModuleOps::conv1d) with the given kernel size and
+/// options is computed in the frequency domain with [conv1d_fft], its span exceeding the
+/// [threshold](set_conv1d_fft_threshold).
+pub fn conv1d_use_fft(kernel_size: usize, options: &ConvOptions<1>) -> bool {
+    conv1d_fft_threshold().is_some_and(|threshold| kernel_size * options.dilation[0] > threshold)
+}
+
+/// Execute a 1D convolution in the frequency domain, with the same semantics as the direct
+/// [1D convolution](crate::ops::ModuleOps::conv1d).
+///
+/// The dilation is applied by inserting zeros in the kernel, so the cost doesn't grow with the
+/// span of the kernel. When the input is much longer than the kernel, it is split into
+/// overlapping blocks that are transformed independently (overlap-save), otherwise the whole
+/// sequence is transformed at once.
+pub fn conv1d_fft<B: Backend>(
+    x: FloatTensor<B, 3>,
+    weight: FloatTensor<B, 3>,
+    bias: Option<FloatTensor<B, 1>>,
+    options: ConvOptions<1>,
+) -> FloatTensor<B, 3> {
+    let [batch_size, _, length_in] = B::float_shape(&x).dims;
+    let [channels_out, channels_per_group, kernel_size] = B::float_shape(&weight).dims;
+    let [stride] = options.stride;
+    let [padding] = options.padding;
+    let [dilation] = options.dilation;
+    let groups = options.groups;
+
+    let x = pad_last::<B>(x, padding, padding);
+    let weight = dilate_last::<B>(weight, dilation);
+    let length_valid = length_in + 2 * padding - dilation * (kernel_size - 1);
+
+    let mut output = match groups == 1 {
+        true => correlate_fft::<B>(x, weight),
+        false => {
+            let channels_out_per_group = channels_out / groups;
+            let outputs = (0..groups)
+                .map(|g| {
+                    let x = B::float_slice(
+                        x.clone(),
+                        [
+                            0..batch_size,
+                            g * channels_per_group..(g + 1) * channels_per_group,
+                        ],
+                    );
+                    let weight = B::float_slice(
+                        weight.clone(),
+                        [g * channels_out_per_group..(g + 1) * channels_out_per_group],
+                    );
+                    correlate_fft::<B>(x, weight)
+                })
+                .collect();
+            B::float_cat(outputs, 1)
+        }
+    };
+
+    if stride > 1 {
+        let indices = B::int_arange_step(0..length_valid as i64, stride, &B::float_device(&output));
+        output = B::float_select(output, 2, indices);
+    }
+
+    match bias {
+        Some(bias) => {
+            let bias = B::float_reshape(bias, Shape::new([1, channels_out, 1]));
+            B::float_add(output, bias)
+        }
+        None => output,
+    }
+}
+
+/// Computes the valid cross-correlation of `x` of shape `[batch_size, channels_in, length]` with
+/// `weight` of shape `[channels_out, channels_in, kernel_size]`, summed over the input channels.
+fn correlate_fft<B: Backend>(x: FloatTensor<B, 3>, weight: FloatTensor<B, 3>) -> FloatTensor<B, 3> {
+    let [batch_size, channels_in, length] = B::float_shape(&x).dims;
+    let [channels_out, _, kernel_size] = B::float_shape(&weight).dims;
+    let length_valid = length - kernel_size + 1;
+
+    let length_full = length.next_power_of_two();
+    let length_block = usize::max((2 * kernel_size).next_power_of_two(), CONV1D_FFT_MIN_BLOCK);
+
+    // The whole sequence fits in a couple of blocks, so there is no gain in splitting it.
+    if length_full <= 2 * length_block {
+        let output = correlate_blocks::<B>(x, weight, length_full);
+        return B::float_slice(output, [0..batch_size, 0..channels_out, 0..length_valid]);
+    }
+
+    // Each block of the input gives as many valid outputs as its length minus the kernel span.
+    let step = length_block - kernel_size + 1;
+    let num_blocks = length_valid.div_ceil(step);
+    let x = pad_last::<B>(x, 0, (num_blocks - 1) * step + length_block - length);
+
+    let device = B::float_device(&x);
+    let indices = (0..num_blocks)
+        .flat_map(|i| (0..length_block).map(move |j| ((i * step + j) as i64).elem()))
+        .collect::<Vec<B::IntElem>>();
+    let indices = B::int_from_data(
+        TensorData::new(indices, Shape::new([num_blocks * length_block])),
+        &device,
+    );
+    let blocks = B::float_select(x, 2, indices);
+    let blocks = B::float_reshape(
+        blocks,
+        Shape::new([batch_size, channels_in, num_blocks, length_block]),
+    );
+    let blocks = B::float_permute(blocks, [0, 2, 1, 3]);
+    let blocks = B::float_reshape(
+        blocks,
+        Shape::new([batch_size * num_blocks, channels_in, length_block]),
+    );
+
+    let output = correlate_blocks::<B>(blocks, weight, length_block);
+    let output = B::float_slice(
+        output,
+        [0..batch_size * num_blocks, 0..channels_out, 0..step],
+    );
+    let output = B::float_reshape(
+        output,
+        Shape::new([batch_size, num_blocks, channels_out, step]),
+    );
+    let output = B::float_permute(output, [0, 2, 1, 3]);
+    let output = B::float_reshape(
+        output,
+        Shape::new([batch_size, channels_out, num_blocks * step]),
+    );
+
+    B::float_slice(output, [0..batch_size, 0..channels_out, 0..length_valid])
+}
+
+/// Computes the circular cross-correlation of each block of `x` of shape
+/// `[batch_size, channels_in, length]` with `weight`, zero-padded to the power of two `length`.
+fn correlate_blocks<B: Backend>(
+    x: FloatTensor<B, 3>,
+    weight: FloatTensor<B, 3>,
+    length: usize,
+) -> FloatTensor<B, 3> {
+    let [batch_size, _, length_x] = B::float_shape(&x).dims;
+    let [channels_out, _, kernel_size] = B::float_shape(&weight).dims;
+
+    // Transforms the real rows of the tensor, given with the frequencies as the batch dimension.
+    let transform = |tensor: FloatTensor<B, 3>, size: usize| {
+        let [dim_0, dim_1, _] = B::float_shape(&tensor).dims;
+        let tensor = pad_last::<B>(tensor, 0, length - size);
+        let re = B::float_reshape(tensor, Shape::new([dim_0 * dim_1, length]));
+        let im = B::float_zeros(B::float_shape(&re), &B::float_device(&re));
+        let (re, im) = fft::<B>(re, im, false);
+        let reorder = |tensor: FloatTensor<B, 2>| {
+            let tensor = B::float_reshape(tensor, Shape::new([dim_0, dim_1, length]));
+            B::float_permute(tensor, [2, 0, 1])
+        };
+
+        (reorder(re), reorder(im))
+    };
+
+    // [length, batch_size, channels_in] and [length, channels_out, channels_in]
+    let (x_re, x_im) = transform(x, length_x);
+    let (weight_re, weight_im) = transform(weight, kernel_size);
+    let weight_re = B::float_swap_dims(weight_re, 1, 2);
+    let weight_im = B::float_swap_dims(weight_im, 1, 2);
+
+    // The correlation multiplies by the conjugate of the kernel spectrum, summing over the input
+    // channels with a matrix multiplication for each frequency.
+    let output_re = B::float_add(
+        B::float_matmul(x_re.clone(), weight_re.clone()),
+        B::float_matmul(x_im.clone(), weight_im.clone()),
+    );
+    let output_im = B::float_sub(
+        B::float_matmul(x_im, weight_re),
+        B::float_matmul(x_re, weight_im),
+    );
+
+    let output_re = B::float_reshape(
+        B::float_permute(output_re, [1, 2, 0]),
+        Shape::new([batch_size * channels_out, length]),
+    );
+    let output_im = B::float_reshape(
+        B::float_permute(output_im, [1, 2, 0]),
+        Shape::new([batch_size * channels_out, length]),
+    );
+    let (output, _) = fft::<B>(output_re, output_im, true);
+
+    B::float_reshape(output, Shape::new([batch_size, channels_out, length]))
+}
+
+/// Computes the input gradient of a [1D convolution](crate::ops::ModuleOps::conv1d) in the
+/// frequency domain, as the convolution of the output gradient with the flipped kernel.
+fn conv1d_x_grad_fft<B: Backend>(
+    output_grad: FloatTensor<B, 3>,
+    weight: FloatTensor<B, 3>,
+    length_in: usize,
+    options: ConvOptions<1>,
+) -> FloatTensor<B, 3> {
+    let [batch_size, _, length_out] = B::float_shape(&output_grad).dims;
+    let [channels_out, channels_per_group, kernel_size] = B::float_shape(&weight).dims;
+    let [stride] = options.stride;
+    let [padding] = options.padding;
+    let [dilation] = options.dilation;
+    let groups = options.groups;
+    let channels_in = channels_per_group * groups;
+
+    // Spread the gradient to the positions of the strided outputs, and pad it so that every
+    // position of the padded input is reached by the whole kernel.
+    let span = dilation * (kernel_size - 1);
+    let remainder = length_in + 2 * padding - ((length_out - 1) * stride + 1 + span);
+    let output_grad = dilate_last::<B>(output_grad, stride);
+    let output_grad = pad_last::<B>(output_grad, span, span + remainder);
+
+    // Swap the input and output channels of each group, and flip the kernel.
+    let weight = B::float_reshape(
+        weight,
+        Shape::new([
+            groups,
+            channels_out / groups,
+            channels_per_group,
+            kernel_size,
+        ]),
+    );
+    let weight = B::float_swap_dims(weight, 1, 2);
+    let weight = B::float_reshape(
+        weight,
+        Shape::new([channels_in, channels_out / groups, kernel_size]),
+    );
+    let weight = B::float_flip(weight, &[2]);
+
+    let x_grad = conv1d_fft::<B>(
+        output_grad,
+        weight,
+        None,
+        ConvOptions::new([1], [0], [dilation], groups),
+    );
+
+    B::float_slice(
+        x_grad,
+        [0..batch_size, 0..channels_in, padding..padding + length_in],
+    )
+}
+
+/// Pads the last dimension of the tensor with zeros.
+fn pad_last<B: Backend>(
+    tensor: FloatTensor<B, 3>,
+    before: usize,
+    after: usize,
+) -> FloatTensor<B, 3> {
+    if before == 0 && after == 0 {
+        return tensor;
+    }
+
+    let [dim_0, dim_1, _] = B::float_shape(&tensor).dims;
+    let device = B::float_device(&tensor);
+    let mut tensors = Vec::with_capacity(3);
+
+    if before > 0 {
+        tensors.push(B::float_zeros(Shape::new([dim_0, dim_1, before]), &device));
+    }
+    tensors.push(tensor);
+    if after > 0 {
+        tensors.push(B::float_zeros(Shape::new([dim_0, dim_1, after]), &device));
+    }
+
+    B::float_cat(tensors, 2)
+}
+
+/// Inserts `factor - 1` zeros between the consecutive values of the last dimension of the tensor.
+fn dilate_last<B: Backend>(tensor: FloatTensor<B, 3>, factor: usize) -> FloatTensor<B, 3> {
+    if factor == 1 {
+        return tensor;
+    }
+
+    let [dim_0, dim_1, size] = B::float_shape(&tensor).dims;
+    let device = B::float_device(&tensor);
+    let tensor = B::float_reshape(tensor, Shape::new([dim_0, dim_1, size, 1]));
+    let zeros = B::float_zeros(Shape::new([dim_0, dim_1, size, factor - 1]), &device);
+    let tensor = B::float_cat(vec![tensor, zeros], 3);
+    let tensor = B::float_reshape(tensor, Shape::new([dim_0, dim_1, size * factor]));
+
+    B::float_slice(tensor, [0..dim_0, 0..dim_1, 0..(size - 1) * factor + 1])
+}
+
 /// Execute a 1D transposed convolution using a 2D transposed convolution.
 pub(crate) fn conv_transpose1d_from_conv_transpose2d<B: Backend>(
     x: FloatTensor<B, 3>,
@@ -621,6 +949,15 @@ fn conv1d_weight_grad_groups<B: Backend>(
             ConvOptions::new(options.dilation, options.padding, options.stride, 1),
         );
         weight_grad_tmp = B::float_swap_dims(weight_grad_tmp, 0, 1);
+        let [_, _, kernel_size_tmp] = B::float_shape(&weight_grad_tmp).dims;
+
+        if kernel_size_tmp != kernel_size {
+            weight_grad_tmp = B::float_slice(
+                weight_grad_tmp,
+                [0..increment_co, 0..increment_ci, 0..kernel_size],
+            );
+        }
+
         weight_grad = B::float_slice_assign(
             weight_grad,
             [start_idx_co..end_idx_co, 0..increment_ci, 0..kernel_size],
@@ -665,8 +1002,8 @@ fn conv2d_weight_grad_groups<B: Backend>(
             weight_grad_tmp = B::float_slice(
                 weight_grad_tmp,
                 [
-                    0..increment_ci,
                     0..increment_co,
+                    0..increment_ci,
                     0..kernel_size_1,
                     0..kernel_size_2,
                 ],
@@ -726,8 +1063,8 @@ fn conv3d_weight_grad_groups<B: Backend>(
             weight_grad_tmp = B::float_slice(
                 weight_grad_tmp,
                 [
-                    0..increment_ci,
                     0..increment_co,
+                    0..increment_ci,
                     0..kernel_size_1,
                     0..kernel_size_2,
                     0..kernel_size_3,
@@ -1111,4 +1448,23 @@ mod tests {
 
         assert_eq!(size_out, size_out_expected, "Expected size");
     }
+
+    #[test]
+    fn test_calculate_pool_output_size_ceil_mode_adds_partial_window() {
+        let size_floor = calculate_pool_output_size(3, 2, 0, 1, false, 6);
+        let size_ceil = calculate_pool_output_size(3, 2, 0, 1, true, 6);
+
+        assert_eq!(size_floor, 2);
+        assert_eq!(size_ceil, 3);
+    }
+
+    #[test]
+    fn test_calculate_pool_output_size_ceil_mode_skips_window_in_padding() {
+        // The extra window would start in the right padding.
+        let size_floor = calculate_pool_output_size(2, 2, 1, 1, false, 5);
+        let size_ceil = calculate_pool_output_size(2, 2, 1, 1, true, 5);
+
+        assert_eq!(size_floor, 3);
+        assert_eq!(size_ceil, 3);
+    }
 }
@@ -0,0 +1,182 @@
+use crate::backend::Backend;
+use crate::ops::FloatTensor;
+use crate::{ElementConversion, Shape, TensorData};
+use alloc::vec;
+use alloc::vec::Vec;
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+/// Computes the discrete Fourier transform of each row of a complex tensor, given as its real and
+/// imaginary parts of shape `[batch_size, length]`, with the radix-2 Cooley-Tukey algorithm.
+///
+/// With `inverse`, the inverse transform is computed instead, normalized by the length.
+///
+/// # Panics
+///
+/// If the length isn't a power of two.
+pub(crate) fn fft<B: Backend>(
+    re: FloatTensor<B, 2>,
+    im: FloatTensor<B, 2>,
+    inverse: bool,
+) -> (FloatTensor<B, 2>, FloatTensor<B, 2>) {
+    let [batch_size, length] = B::float_shape(&re).dims;
+    assert!(
+        length.is_power_of_two(),
+        "The FFT length should be a power of two, got {length}"
+    );
+
+    let mut re = B::float_reshape(re, Shape::new([batch_size, 1, length]));
+    let mut im = B::float_reshape(im, Shape::new([batch_size, 1, length]));
+
+    // Split the values by parity until each group holds a single value, which leaves the groups
+    // in bit-reversed order.
+    let mut groups = 1;
+    while groups < length {
+        re = split_parity::<B>(re, batch_size, groups, length / groups);
+        im = split_parity::<B>(im, batch_size, groups, length / groups);
+        groups *= 2;
+    }
+
+    // Merge the transforms of the even and odd values of each pair of groups, the twiddle factors
+    // of every stage being read from the same table.
+    if length > 1 {
+        let twiddles = Twiddles::<B>::new(length, inverse, &B::float_device(&re));
+        let mut size = 1;
+        while size < length {
+            (re, im) = merge_halves::<B>(re, im, batch_size, length / size, size, &twiddles);
+            size *= 2;
+        }
+    }
+
+    let re = B::float_reshape(re, Shape::new([batch_size, length]));
+    let im = B::float_reshape(im, Shape::new([batch_size, length]));
+
+    match inverse {
+        true => {
+            let scale = (1.0 / length as f64).elem();
+            (
+                B::float_mul_scalar(re, scale),
+                B::float_mul_scalar(im, scale),
+            )
+        }
+        false => (re, im),
+    }
+}
+
+/// Splits each group of size `size` into its even and odd values, doubling the number of groups.
+fn split_parity<B: Backend>(
+    tensor: FloatTensor<B, 3>,
+    batch_size: usize,
+    groups: usize,
+    size: usize,
+) -> FloatTensor<B, 3> {
+    let tensor = B::float_reshape(tensor, Shape::new([batch_size, groups, size / 2, 2]));
+    let tensor = B::float_swap_dims(tensor, 2, 3);
+
+    B::float_reshape(tensor, Shape::new([batch_size, groups * 2, size / 2]))
+}
+
+/// The twiddle factors `exp(-2πik / length)` of a transform, for `k` below half its length,
+/// conjugated for the inverse transform.
+///
+/// The table is created on the device once per transform: the factors `exp(-2πik / 2size)` of
+/// the stage merging the transforms of size `size` are its values at the multiples of
+/// `length / 2size`.
+struct Twiddles<B: Backend> {
+    re: FloatTensor<B, 1>,
+    im: FloatTensor<B, 1>,
+    length: usize,
+}
+
+impl<B: Backend> Twiddles<B> {
+    fn new(length: usize, inverse: bool, device: &B::Device) -> Self {
+        let sign = if inverse { 1.0 } else { -1.0 };
+        let (re, im): (Vec<_>, Vec<_>) = (0..length / 2)
+            .map(|k| {
+                let angle = sign * 2.0 * core::f64::consts::PI * k as f64 / length as f64;
+                (
+                    angle.cos().elem::<B::FloatElem>(),
+                    angle.sin().elem::<B::FloatElem>(),
+                )
+            })
+            .unzip();
+        let table = |values: Vec<B::FloatElem>| {
+            B::float_from_data(TensorData::new(values, Shape::new([length / 2])), device)
+        };
+
+        Self {
+            re: table(re),
+            im: table(im),
+            length,
+        }
+    }
+
+    /// The real and imaginary parts of the factors of the stage merging the transforms of size
+    /// `size`, of shape `[1, 1, size]`.
+    fn stage(&self, size: usize) -> (FloatTensor<B, 3>, FloatTensor<B, 3>) {
+        let stride = self.length / (2 * size);
+        let strided = |table: FloatTensor<B, 1>| {
+            let table = B::float_reshape(table, Shape::new([size, stride]));
+            let table = B::float_slice(table, [0..size, 0..1]);
+
+            B::float_reshape(table, Shape::new([1, 1, size]))
+        };
+
+        (strided(self.re.clone()), strided(self.im.clone()))
+    }
+}
+
+/// Merges each pair of consecutive groups of `size` values, the transform of the even values
+/// followed by the transform of the odd values, into the transform of their `2 * size` values.
+///
+/// The tensor holds `groups` groups before the merge and half as many after it.
+fn merge_halves<B: Backend>(
+    re: FloatTensor<B, 3>,
+    im: FloatTensor<B, 3>,
+    batch_size: usize,
+    groups: usize,
+    size: usize,
+    twiddles: &Twiddles<B>,
+) -> (FloatTensor<B, 3>, FloatTensor<B, 3>) {
+    let shape = Shape::new([batch_size, groups / 2, 2, size]);
+    let halves = |tensor: FloatTensor<B, 3>| {
+        let tensor = B::float_reshape(tensor, shape.clone());
+        let shape = Shape::new([batch_size, groups / 2, size]);
+        let even = B::float_slice(tensor.clone(), [0..batch_size, 0..groups / 2, 0..1]);
+        let odd = B::float_slice(tensor, [0..batch_size, 0..groups / 2, 1..2]);
+
+        (
+            B::float_reshape(even, shape.clone()),
+            B::float_reshape(odd, shape),
+        )
+    };
+    let (even_re, odd_re) = halves(re);
+    let (even_im, odd_im) = halves(im);
+
+    let (twiddle_re, twiddle_im) = twiddles.stage(size);
+
+    let odd_re_twiddled = B::float_sub(
+        B::float_mul(odd_re.clone(), twiddle_re.clone()),
+        B::float_mul(odd_im.clone(), twiddle_im.clone()),
+    );
+    let odd_im_twiddled = B::float_add(
+        B::float_mul(odd_re, twiddle_im),
+        B::float_mul(odd_im, twiddle_re),
+    );
+
+    let merge = |even: FloatTensor<B, 3>, odd: FloatTensor<B, 3>| {
+        B::float_cat(
+            vec![
+                B::float_add(even.clone(), odd.clone()),
+                B::float_sub(even, odd),
+            ],
+            2,
+        )
+    };
+
+    (
+        merge(even_re, odd_re_twiddled),
+        merge(even_im, odd_im_twiddled),
+    )
+}
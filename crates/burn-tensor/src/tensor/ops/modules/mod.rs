@@ -3,6 +3,8 @@ pub mod conv;
 
 /// Module with cat operation
 pub(crate) mod cat;
+/// Module with the fast Fourier transform.
+pub(crate) mod fft;
 /// Module with repeat operation
 pub(crate) mod repeat_dim;
 /// Module with unfold operations.
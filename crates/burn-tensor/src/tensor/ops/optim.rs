@@ -0,0 +1,240 @@
+use crate::{backend::Backend, ElementConversion};
+use alloc::vec::Vec;
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+use super::FloatTensor;
+
+/// The hyperparameters of an [Adam step](OptimizerOps::adam_step).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AdamStepOptions {
+    /// The learning rate.
+    pub lr: f64,
+    /// The decay rate of the first moment.
+    pub beta_1: f32,
+    /// The decay rate of the second moment.
+    pub beta_2: f32,
+    /// A value required for numerical stability.
+    pub epsilon: f32,
+    /// The L2 penalty added to the gradient, as done by Adam.
+    pub penalty: Option<f64>,
+    /// The weight decay applied to the parameters independently of the gradient, as done by
+    /// AdamW.
+    pub weight_decay: Option<f32>,
+}
+
+/// The tensors read by an [Adam step](OptimizerOps::adam_step).
+pub struct AdamStepInput<B: Backend, const D: usize> {
+    /// The parameters.
+    pub tensor: FloatTensor<B, D>,
+    /// The gradient of the parameters.
+    pub grad: FloatTensor<B, D>,
+    /// The first and second moments, if a step was done before.
+    pub moments: Option<(FloatTensor<B, D>, FloatTensor<B, D>)>,
+}
+
+/// A parameter tensor in the flattened tensors of a
+/// [multi-tensor Adam step](OptimizerOps::adam_step_multi).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AdamStepSegment {
+    /// The position of the first element of the tensor.
+    pub offset: usize,
+    /// The number of elements of the tensor.
+    pub length: usize,
+    /// The number of steps done with the moments of the tensor, including this one.
+    pub time: usize,
+}
+
+/// The tensors written by an [Adam step](OptimizerOps::adam_step).
+pub struct AdamStepOutput<B: Backend, const D: usize> {
+    /// The updated parameters.
+    pub tensor: FloatTensor<B, D>,
+    /// The updated first moment.
+    pub moment_1: FloatTensor<B, D>,
+    /// The updated second moment.
+    pub moment_2: FloatTensor<B, D>,
+}
+
+/// Optimizer operations.
+///
+/// This trait let backend implementations override the update steps of optimizers, which are
+/// chains of element-wise operations on every parameter, with fused kernels.
+pub trait OptimizerOps<B: Backend> {
+    /// Applies an Adam step to a parameter tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The parameters, their gradient and the moments of the previous step.
+    /// * `time` - The number of steps done with the moments, including this one.
+    /// * `options` - The hyperparameters of the step.
+    ///
+    /// # Returns
+    ///
+    /// The updated parameters and moments.
+    ///
+    /// # Remarks
+    ///
+    /// The default implementation composes the element-wise operations, in the same order as a
+    /// fused implementation is expected to apply them in full precision:
+    ///
+    /// ```text
+    /// grad = tensor * penalty + grad
+    /// moment_1 = moment_1 * beta_1 + grad * (1 - beta_1)
+    /// moment_2 = moment_2 * beta_2 + grad^2 * (1 - beta_2)
+    /// update = (moment_1 / (1 - beta_1^time)) / (sqrt(moment_2 / (1 - beta_2^time)) + epsilon)
+    /// tensor = tensor - tensor * lr * weight_decay - update * lr
+    /// ```
+    fn adam_step<const D: usize>(
+        input: AdamStepInput<B, D>,
+        time: usize,
+        options: &AdamStepOptions,
+    ) -> AdamStepOutput<B, D> {
+        let AdamStepInput {
+            tensor,
+            mut grad,
+            moments,
+        } = input;
+
+        if let Some(penalty) = options.penalty {
+            grad = B::float_add(B::float_mul_scalar(tensor.clone(), penalty.elem()), grad);
+        }
+
+        let factor_1 = 1.0 - options.beta_1;
+        let factor_2 = 1.0 - options.beta_2;
+        let grad_squared = B::float_powf_scalar(grad.clone(), 2.0);
+
+        let (moment_1, moment_2) = match moments {
+            Some((moment_1, moment_2)) => (
+                B::float_add(
+                    B::float_mul_scalar(moment_1, options.beta_1.elem()),
+                    B::float_mul_scalar(grad, factor_1.elem()),
+                ),
+                B::float_add(
+                    B::float_mul_scalar(moment_2, options.beta_2.elem()),
+                    B::float_mul_scalar(grad_squared, factor_2.elem()),
+                ),
+            ),
+            None => (
+                B::float_mul_scalar(grad, factor_1.elem()),
+                B::float_mul_scalar(grad_squared, factor_2.elem()),
+            ),
+        };
+
+        let (correction_1, correction_2) = bias_corrections(time, options);
+        let moment_1_corrected = B::float_div_scalar(moment_1.clone(), correction_1.elem());
+        let moment_2_corrected = B::float_div_scalar(moment_2.clone(), correction_2.elem());
+        let update = B::float_div(
+            moment_1_corrected,
+            B::float_add_scalar(B::float_sqrt(moment_2_corrected), options.epsilon.elem()),
+        );
+
+        let tensor = match options.weight_decay {
+            Some(weight_decay) => B::float_sub(
+                tensor.clone(),
+                B::float_mul_scalar(
+                    B::float_mul_scalar(tensor, options.lr.elem()),
+                    weight_decay.elem(),
+                ),
+            ),
+            None => tensor,
+        };
+        let tensor = B::float_sub(tensor, B::float_mul_scalar(update, options.lr.elem()));
+
+        AdamStepOutput {
+            tensor,
+            moment_1,
+            moment_2,
+        }
+    }
+
+    /// Applies an Adam step to many parameter tensors flattened into the same tensors.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The flattened parameters, their gradient and the moments of the previous step,
+    ///   the moments of the tensors without a previous step being zeros.
+    /// * `segments` - The position and the time of every tensor, in order, covering the
+    ///   flattened tensors.
+    /// * `options` - The hyperparameters of the step.
+    ///
+    /// # Returns
+    ///
+    /// The flattened updated parameters and moments.
+    ///
+    /// # Remarks
+    ///
+    /// The default implementation applies [adam_step](OptimizerOps::adam_step) to the slice of
+    /// each tensor, backends can override it to update all of them at once, which avoids a launch
+    /// per tensor when there are many small parameters.
+    fn adam_step_multi(
+        input: AdamStepInput<B, 1>,
+        segments: &[AdamStepSegment],
+        options: &AdamStepOptions,
+    ) -> AdamStepOutput<B, 1> {
+        check_segments(segments, B::float_shape(&input.tensor).num_elements());
+
+        let slice = |tensor: &FloatTensor<B, 1>, segment: &AdamStepSegment| {
+            B::float_slice(
+                tensor.clone(),
+                [segment.offset..segment.offset + segment.length],
+            )
+        };
+
+        let mut tensors = Vec::with_capacity(segments.len());
+        let mut moments_1 = Vec::with_capacity(segments.len());
+        let mut moments_2 = Vec::with_capacity(segments.len());
+
+        for segment in segments {
+            let output = B::adam_step(
+                AdamStepInput {
+                    tensor: slice(&input.tensor, segment),
+                    grad: slice(&input.grad, segment),
+                    moments: input.moments.as_ref().map(|(moment_1, moment_2)| {
+                        (slice(moment_1, segment), slice(moment_2, segment))
+                    }),
+                },
+                segment.time,
+                options,
+            );
+
+            tensors.push(output.tensor);
+            moments_1.push(output.moment_1);
+            moments_2.push(output.moment_2);
+        }
+
+        AdamStepOutput {
+            tensor: B::float_cat(tensors, 0),
+            moment_1: B::float_cat(moments_1, 0),
+            moment_2: B::float_cat(moments_2, 0),
+        }
+    }
+}
+
+/// Check that the segments of a [multi-tensor Adam step](OptimizerOps::adam_step_multi) cover
+/// the flattened tensors in order.
+pub fn check_segments(segments: &[AdamStepSegment], num_elements: usize) {
+    let mut offset = 0;
+    for segment in segments {
+        assert_eq!(
+            segment.offset, offset,
+            "The segments must cover the flattened tensors in order."
+        );
+        offset += segment.length;
+    }
+    assert_eq!(
+        offset, num_elements,
+        "The segments must cover the flattened tensors in order."
+    );
+}
+
+/// The divisors of the moments correcting their bias toward zero, computed like Adam in single
+/// precision.
+pub fn bias_corrections(time: usize, options: &AdamStepOptions) -> (f32, f32) {
+    let time = time as i32;
+
+    (
+        1f32 - options.beta_1.powi(time),
+        1f32 - options.beta_2.powi(time),
+    )
+}
@@ -3,14 +3,19 @@ use super::repeat_dim::repeat_with_slice_assign;
 use super::{BoolTensor, Device, FloatElem, FloatTensor, FullPrecisionBackend, IntElem, IntTensor};
 use crate::backend::BackendBridge;
 use crate::tensor::cast::ToElement;
-use crate::{backend::Backend, tensor::Shape, Distribution, ElementConversion, Float, TensorData};
+use crate::{
+    backend::Backend, tensor::Shape, Distribution, ElementConversion, Float, MemoryFormat,
+    TensorData,
+};
 use crate::{tensor::api::chunk, tensor::api::narrow};
 use crate::{Tensor, TensorPrimitive};
 use alloc::vec::Vec;
 use core::future::Future;
 use core::ops::Range;
 
-use crate::{argsort, sort, sort_with_indices};
+use crate::{
+    argsort, associative_scan, index_put, multinomial, pad, sort, sort_with_indices, PadMode,
+};
 
 /// Operations on float tensors.
 pub trait FloatTensorOps<B: Backend> {
@@ -386,6 +391,66 @@ pub trait FloatTensorOps<B: Backend> {
         rhs: FloatTensor<B, D>,
     ) -> FloatTensor<B, D>;
 
+    /// Adds the matrix multiplication of two tensors to an output tensor.
+    ///
+    /// `output = out + lhs @ rhs`
+    ///
+    /// Backends can accumulate the product directly into the output, without a separate addition,
+    /// for instance to accumulate gradients.
+    ///
+    /// # Arguments
+    ///
+    /// * `out` - The tensor the product is added to, of the shape of the matmul output.
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side tensor.
+    ///
+    /// # Returns
+    ///
+    /// The sum of the output tensor and of the matrix multiplication.
+    fn float_matmul_accumulate<const D: usize>(
+        out: FloatTensor<B, D>,
+        lhs: FloatTensor<B, D>,
+        rhs: FloatTensor<B, D>,
+    ) -> FloatTensor<B, D> {
+        B::float_add(out, B::float_matmul(lhs, rhs))
+    }
+
+    /// Multiplies a sparse matrix by a dense matrix.
+    ///
+    /// # Arguments
+    ///
+    /// * `rows` - The row of each value of the sparse matrix.
+    /// * `cols` - The column of each value of the sparse matrix.
+    /// * `values` - The values of the sparse matrix, the values of the duplicated coordinates
+    ///   being summed.
+    /// * `dense` - The dense matrix of shape `[k, n]`.
+    /// * `num_rows` - The number of rows `m` of the sparse matrix.
+    ///
+    /// # Returns
+    ///
+    /// The dense product of shape `[m, n]`.
+    fn float_spmm(
+        rows: IntTensor<B, 1>,
+        cols: IntTensor<B, 1>,
+        values: FloatTensor<B, 1>,
+        dense: FloatTensor<B, 2>,
+        num_rows: usize,
+    ) -> FloatTensor<B, 2> {
+        let [num_entries] = B::float_shape(&values).dims;
+        let num_cols = B::float_shape(&dense).dims[1];
+        let device = B::float_device(&dense);
+
+        // Each value scales the row of the dense matrix at its column, added to the output at
+        // its row.
+        let products = B::float_mul(
+            B::float_select(dense, 0, cols),
+            B::float_reshape(values, Shape::new([num_entries, 1])),
+        );
+        let output = B::float_zeros(Shape::new([num_rows, num_cols]), &device);
+
+        B::float_select_assign(output, 0, rows, products)
+    }
+
     /// Negates a tensor element-wise.
     fn float_neg<const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D> {
         Self::float_mul_scalar(tensor, (-1.0_f32).elem::<FloatElem<B>>())
@@ -536,6 +601,41 @@ pub trait FloatTensorOps<B: Backend> {
         value: FloatTensor<B, D>,
     ) -> FloatTensor<B, D>;
 
+    /// Put the values at the given indices along the given dimension, overwriting the elements or
+    /// adding the values to them.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `dim` - The dimension along which the values are put.
+    /// * `indices` - The positions along the dimension, one for each slice of the values.
+    /// * `values` - The values, with the same shape as the tensor except for the dimension, whose
+    ///   size is the number of indices.
+    /// * `accumulate` - Whether the values are added to the elements instead of overwriting them.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the values put at the indices. When an index is repeated without
+    /// accumulation, the values are written in index order, so the last one wins.
+    fn float_index_put<const D: usize>(
+        tensor: FloatTensor<B, D>,
+        dim: usize,
+        indices: IntTensor<B, 1>,
+        values: FloatTensor<B, D>,
+        accumulate: bool,
+    ) -> FloatTensor<B, D> {
+        match accumulate {
+            true => B::float_select_assign(tensor, dim, indices, values),
+            false => index_put::<B, D, Float>(
+                TensorPrimitive::Float(tensor),
+                dim,
+                indices,
+                TensorPrimitive::Float(values),
+            )
+            .tensor(),
+        }
+    }
+
     /// Select tensor elements corresponding for the given ranges.
     ///
     /// # Arguments
@@ -578,7 +678,9 @@ pub trait FloatTensorOps<B: Backend> {
     ///
     /// # Returns
     ///
-    /// The tensor with the selected elements assigned to the given value.
+    /// The tensor with the selected elements assigned to the given value, with the broadcasted
+    /// shape of the tensor, the mask and the value. The values must be selected, not computed from
+    /// the mask, so that NaN or infinite values of the unselected tensor don't propagate.
     fn float_mask_where<const D: usize>(
         tensor: FloatTensor<B, D>,
         mask: BoolTensor<B, D>,
@@ -886,6 +988,65 @@ pub trait FloatTensorOps<B: Backend> {
     /// A tensor with the mean of all elements in `tensor` along `dim`.
     fn float_mean_dim<const D: usize>(tensor: FloatTensor<B, D>, dim: usize) -> FloatTensor<B, D>;
 
+    /// Variance and mean of all elements in a tensor along the given dimensions.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to compute the variance of.
+    /// * `dims` - The dimensions along which to compute the variance.
+    /// * `correction` - The difference between the number of reduced elements and the divisor
+    ///   of the variance, `1` applying the Bessel's correction. It is smaller than the number of
+    ///   reduced elements, which is checked by the tensor API.
+    ///
+    /// # Returns
+    ///
+    /// The variance and the mean of all elements in `tensor` along `dims`, with a size of 1 for
+    /// each of the reduced dimensions.
+    fn float_var_mean<const D: usize>(
+        tensor: FloatTensor<B, D>,
+        dims: &[usize],
+        correction: usize,
+    ) -> (FloatTensor<B, D>, FloatTensor<B, D>) {
+        let shape = B::float_shape(&tensor);
+        let num_elems: usize = dims.iter().map(|dim| shape.dims[*dim]).product();
+        let divisor = num_elems.saturating_sub(correction) as i64;
+
+        // The values are shifted by the first reduced value, which makes the variance of equal
+        // values exactly zero and the sums smaller.
+        let shift = match num_elems {
+            0 => None,
+            _ => {
+                let ranges: [Range<usize>; D] =
+                    core::array::from_fn(|dim| match dims.contains(&dim) {
+                        true => 0..1,
+                        false => 0..shape.dims[dim],
+                    });
+                Some(B::float_slice(tensor.clone(), ranges))
+            }
+        };
+        let tensor = match &shift {
+            Some(shift) => B::float_sub(tensor, shift.clone()),
+            None => tensor,
+        };
+
+        let mean = dims
+            .iter()
+            .fold(tensor.clone(), |mean, dim| B::float_mean_dim(mean, *dim));
+        let diff = B::float_sub(tensor, mean.clone());
+        let squares = B::float_mul(diff.clone(), diff);
+        let sum = dims
+            .iter()
+            .fold(squares, |sum, dim| B::float_sum_dim(sum, *dim));
+
+        let var = B::float_div_scalar(sum, divisor.elem());
+        let mean = match shift {
+            Some(shift) => B::float_add(mean, shift),
+            None => mean,
+        };
+
+        (var, mean)
+    }
+
     /// Converts a tensor to full precision.
     ///
     /// # Arguments
@@ -1079,6 +1240,69 @@ pub trait FloatTensorOps<B: Backend> {
     /// A tensor with the same shape as `tensor` with error function values.
     fn float_erf<const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D>;
 
+    /// Returns a new tensor with the inverse error function values.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to take the inverse error function of.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with inverse error function values.
+    fn float_erfinv<const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D> {
+        // Single precision rational approximation from M. Giles, "Approximating the erfinv
+        // function", where both branches are computed and the tail one is selected when
+        // `w >= 5`.
+        let shape = B::float_shape(&tensor);
+        let device = B::float_device(&tensor);
+
+        let polynomial = |w: FloatTensor<B, D>, coefficients: &[f64]| {
+            let mut output = B::float_full(shape.clone(), coefficients[0].elem(), &device);
+            for coefficient in &coefficients[1..] {
+                output = B::float_add_scalar(B::float_mul(output, w.clone()), coefficient.elem());
+            }
+            output
+        };
+
+        // w = -log((1 - x) * (1 + x))
+        let w = B::float_neg(B::float_log(B::float_mul(
+            B::float_neg(B::float_sub_scalar(tensor.clone(), 1.elem())),
+            B::float_add_scalar(tensor.clone(), 1.elem()),
+        )));
+        let is_tail = B::float_greater_equal_elem(w.clone(), 5.elem());
+
+        let central = polynomial(
+            B::float_sub_scalar(w.clone(), 2.5.elem()),
+            &[
+                2.810_226_36e-08,
+                3.432_739_39e-07,
+                -3.523_387_7e-06,
+                -4.391_506_54e-06,
+                0.000_218_580_87,
+                -0.001_253_725_03,
+                -0.004_177_681_64,
+                0.246_640_727,
+                1.501_409_41,
+            ],
+        );
+        let tail = polynomial(
+            B::float_sub_scalar(B::float_sqrt(w), 3.elem()),
+            &[
+                -0.000_200_214_257,
+                0.000_100_950_558,
+                0.001_349_343_22,
+                -0.003_673_428_44,
+                0.005_739_507_73,
+                -0.007_622_461_3,
+                0.009_438_870_47,
+                1.001_674_06,
+                2.832_976_82,
+            ],
+        );
+
+        B::float_mul(B::float_mask_where(central, is_tail, tail), tensor)
+    }
+
     /// Concatenates tensors along a dimension.
     ///
     /// # Arguments
@@ -1349,6 +1573,78 @@ pub trait FloatTensorOps<B: Backend> {
         B::float_equal_elem(sum, (num_elems as f32).elem())
     }
 
+    /// Counts the elements of the float `tensor` that are non-zero along a given dimension `dim`.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to count.
+    /// * `dim` - The axis along which to count.
+    ///
+    /// # Returns
+    ///
+    /// An int tensor with the same size as input `tensor`, except in the `dim` axis where the size
+    /// is 1, holding the number of non-zero elements along this dim in the input.
+    fn float_count_nonzero<const D: usize>(
+        tensor: FloatTensor<B, D>,
+        dim: usize,
+    ) -> IntTensor<B, D> {
+        B::bool_count_nonzero(B::float_not_equal_elem(tensor, 0.0f32.elem()), dim)
+    }
+
+    /// Tests element wise if the float `lhs` tensor is close to `rhs`, that is if
+    /// `abs(lhs - rhs) <= atol + rtol * abs(rhs)` or `lhs == rhs`.
+    ///
+    /// Equal infinities are close, while NaN is never close to any value.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The tensor to compare.
+    /// * `rhs` - The reference tensor, with the same shape.
+    /// * `rtol` - The relative tolerance.
+    /// * `atol` - The absolute tolerance.
+    ///
+    /// # Returns
+    ///
+    /// A boolean tensor with the shape of the inputs, True where the elements are close.
+    fn float_is_close<const D: usize>(
+        lhs: FloatTensor<B, D>,
+        rhs: FloatTensor<B, D>,
+        rtol: f64,
+        atol: f64,
+    ) -> BoolTensor<B, D> {
+        let equal = B::float_equal(lhs.clone(), rhs.clone());
+        let diff = B::float_abs(B::float_sub(lhs, rhs.clone()));
+        let tolerance = B::float_add_scalar(
+            B::float_mul_scalar(B::float_abs(rhs), rtol.elem()),
+            atol.elem(),
+        );
+        let close = B::float_lower_equal(diff, tolerance);
+
+        B::bool_or(close, equal)
+    }
+
+    /// Tests if all the elements of the float `lhs` tensor are [close](Self::float_is_close) to
+    /// the ones of `rhs`.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The tensor to compare.
+    /// * `rhs` - The reference tensor, with the same shape.
+    /// * `rtol` - The relative tolerance.
+    /// * `atol` - The absolute tolerance.
+    ///
+    /// # Returns
+    ///
+    /// A boolean tensor with a single element, True if all the elements are close.
+    fn float_all_close<const D: usize>(
+        lhs: FloatTensor<B, D>,
+        rhs: FloatTensor<B, D>,
+        rtol: f64,
+        atol: f64,
+    ) -> BoolTensor<B, 1> {
+        B::bool_all(B::float_is_close(lhs, rhs, rtol, atol))
+    }
+
     /// Returns the signs of the float `tensor`.
     ///
     /// # Arguments
@@ -1374,6 +1670,70 @@ pub trait FloatTensorOps<B: Backend> {
         shape: Shape<D2>,
     ) -> FloatTensor<B, D2>;
 
+    /// Lay out the elements of the `tensor` in the given memory `format`.
+    ///
+    /// The default implementation keeps the layout of the tensor, for the backends that don't
+    /// control it.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `format` - The memory format.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape and values.
+    fn float_to_memory_format<const D: usize>(
+        tensor: FloatTensor<B, D>,
+        _format: MemoryFormat,
+    ) -> FloatTensor<B, D> {
+        tensor
+    }
+
+    /// Returns the memory format of the `tensor`.
+    fn float_memory_format<const D: usize>(_tensor: &FloatTensor<B, D>) -> MemoryFormat {
+        MemoryFormat::Contiguous
+    }
+
+    /// Sample indices from the categorical distributions given by the rows of the `tensor`.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The non-negative weights of the categories, of shape `[rows, categories]`.
+    /// * `num_samples` - The number of samples drawn from each row.
+    /// * `replacement` - Whether a category can be drawn more than once in a row.
+    ///
+    /// # Returns
+    ///
+    /// The indices of the drawn categories, of shape `[rows, num_samples]`.
+    fn float_multinomial(
+        tensor: FloatTensor<B, 2>,
+        num_samples: usize,
+        replacement: bool,
+    ) -> IntTensor<B, 2> {
+        multinomial::<B>(tensor, num_samples, replacement)
+    }
+
+    /// Compute the linear recurrence `y[t] = a[t] * y[t - 1] + b[t]` along a dimension, with
+    /// `y[-1] = 0`.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The coefficients multiplying the previous values.
+    /// * `b` - The values added at each step, of the shape of `a`.
+    /// * `dim` - The dimension of the recurrence.
+    ///
+    /// # Returns
+    ///
+    /// The values of the recurrence, of the shape of `a`.
+    fn float_associative_scan<const D: usize>(
+        a: FloatTensor<B, D>,
+        b: FloatTensor<B, D>,
+        dim: usize,
+    ) -> FloatTensor<B, D> {
+        associative_scan::<B, D>(a, b, dim)
+    }
+
     /// Sort the elements of the input `tensor` by value in along a given dimension.
     ///
     /// This sort is unstable (i.e., may reorder equal elements).
@@ -1439,4 +1799,180 @@ pub trait FloatTensorOps<B: Backend> {
     ) -> IntTensor<B, D> {
         argsort::<B, D, Float>(TensorPrimitive::Float(tensor), dim, descending)
     }
+
+    /// Pads the tensor with the given `(before, after)` amounts on each dimension, where negative
+    /// amounts crop the tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `padding` - The `(before, after)` padding amounts of each dimension.
+    /// * `mode` - How the padded elements are filled.
+    ///
+    /// # Returns
+    ///
+    /// The padded tensor.
+    fn float_pad<const D: usize>(
+        tensor: FloatTensor<B, D>,
+        padding: [(isize, isize); D],
+        mode: PadMode<FloatElem<B>>,
+    ) -> FloatTensor<B, D> {
+        pad::<B, D, Float>(TensorPrimitive::Float(tensor), padding, mode).tensor()
+    }
+
+    /// Fake quantizes the tensor, rounding its values to the quantization grid while keeping
+    /// them as floats.
+    ///
+    /// `output = (clamp(round(tensor / scale + zero_point), qmin, qmax) - zero_point) * scale`
+    ///
+    /// The values are rounded to the nearest integer, with ties rounded to the nearest even
+    /// integer.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `scale` - The scaling factors, broadcast to the shape of the tensor.
+    /// * `zero_point` - The zero-points holding integer values, broadcast to the shape of the
+    ///   tensor.
+    /// * `qmin` - The smallest quantized value.
+    /// * `qmax` - The largest quantized value.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with the fake quantized values.
+    fn float_fake_quantize<const D: usize>(
+        tensor: FloatTensor<B, D>,
+        scale: FloatTensor<B, D>,
+        zero_point: FloatTensor<B, D>,
+        qmin: FloatElem<B>,
+        qmax: FloatElem<B>,
+    ) -> FloatTensor<B, D> {
+        crate::quantization::fake_quantize::<B, D>(
+            Tensor::from_primitive(TensorPrimitive::Float(tensor)),
+            Tensor::from_primitive(TensorPrimitive::Float(scale)),
+            Tensor::from_primitive(TensorPrimitive::Float(zero_point)),
+            qmin.to_f64(),
+            qmax.to_f64(),
+        )
+        .into_primitive()
+        .tensor()
+    }
+
+    /// Linearly interpolates between `tensor` and `end` with the weights of the `weight` tensor.
+    ///
+    /// `output = tensor + weight * (end - tensor)`
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The start tensor, returned where the weight is zero.
+    /// * `end` - The end tensor, returned where the weight is one.
+    /// * `weight` - The interpolation weights.
+    ///
+    /// # Returns
+    ///
+    /// The interpolated tensor, of the broadcasted shape of the inputs.
+    fn float_lerp<const D: usize>(
+        tensor: FloatTensor<B, D>,
+        end: FloatTensor<B, D>,
+        weight: FloatTensor<B, D>,
+    ) -> FloatTensor<B, D> {
+        let delta = B::float_sub(end, tensor.clone());
+        B::float_add(tensor, B::float_mul(weight, delta))
+    }
+
+    /// Linearly interpolates between `tensor` and `end` with the same `weight` for all elements.
+    ///
+    /// `output = tensor + weight * (end - tensor)`
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The start tensor, returned when the weight is zero.
+    /// * `end` - The end tensor, returned when the weight is one.
+    /// * `weight` - The interpolation weight.
+    ///
+    /// # Returns
+    ///
+    /// The interpolated tensor, of the broadcasted shape of the inputs.
+    fn float_lerp_scalar<const D: usize>(
+        tensor: FloatTensor<B, D>,
+        end: FloatTensor<B, D>,
+        weight: FloatElem<B>,
+    ) -> FloatTensor<B, D> {
+        let delta = B::float_sub(end, tensor.clone());
+        B::float_add(tensor, B::float_mul_scalar(delta, weight))
+    }
+
+    /// Adds the product of `tensor1` and `tensor2`, scaled by `value`, to `tensor`.
+    ///
+    /// `output = tensor + value * tensor1 * tensor2`
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to add to.
+    /// * `tensor1` - The first factor of the product.
+    /// * `tensor2` - The second factor of the product.
+    /// * `value` - The scale of the product.
+    ///
+    /// # Returns
+    ///
+    /// The sum, of the broadcasted shape of the inputs.
+    fn float_addcmul<const D: usize>(
+        tensor: FloatTensor<B, D>,
+        tensor1: FloatTensor<B, D>,
+        tensor2: FloatTensor<B, D>,
+        value: FloatElem<B>,
+    ) -> FloatTensor<B, D> {
+        let product = B::float_mul(B::float_mul_scalar(tensor1, value), tensor2);
+        B::float_add(tensor, product)
+    }
+
+    /// Adds the quotient of `tensor1` by `tensor2`, scaled by `value`, to `tensor`.
+    ///
+    /// `output = tensor + value * tensor1 / tensor2`
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to add to.
+    /// * `tensor1` - The numerator of the quotient.
+    /// * `tensor2` - The denominator of the quotient.
+    /// * `value` - The scale of the quotient.
+    ///
+    /// # Returns
+    ///
+    /// The sum, of the broadcasted shape of the inputs.
+    fn float_addcdiv<const D: usize>(
+        tensor: FloatTensor<B, D>,
+        tensor1: FloatTensor<B, D>,
+        tensor2: FloatTensor<B, D>,
+        value: FloatElem<B>,
+    ) -> FloatTensor<B, D> {
+        let quotient = B::float_div(B::float_mul_scalar(tensor1, value), tensor2);
+        B::float_add(tensor, quotient)
+    }
+
+    /// Computes the linear combination of `tensor` and `other`.
+    ///
+    /// `output = alpha * tensor + beta * other`
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The first tensor.
+    /// * `alpha` - The scale of the first tensor.
+    /// * `other` - The second tensor.
+    /// * `beta` - The scale of the second tensor.
+    ///
+    /// # Returns
+    ///
+    /// The linear combination, of the broadcasted shape of the inputs.
+    fn float_axpby<const D: usize>(
+        tensor: FloatTensor<B, D>,
+        alpha: FloatElem<B>,
+        other: FloatTensor<B, D>,
+        beta: FloatElem<B>,
+    ) -> FloatTensor<B, D> {
+        B::float_add(
+            B::float_mul_scalar(tensor, alpha),
+            B::float_mul_scalar(other, beta),
+        )
+    }
 }
@@ -32,3 +32,28 @@ impl Calibration for MinMaxCalibration {
         CalibrationRange { min, max }
     }
 }
+
+/// Computes the per-channel quantization range mapping based on the min and max values of each
+/// channel, the range holding one value per channel.
+pub struct PerChannelMinMaxCalibration {
+    /// The dimension of the channels.
+    pub axis: usize,
+}
+
+impl Calibration for PerChannelMinMaxCalibration {
+    fn compute_range<B: Backend, const D: usize>(
+        &self,
+        tensor: &Tensor<B, D>,
+    ) -> CalibrationRange<B> {
+        let num_channels = tensor.dims()[self.axis];
+        let channels = tensor
+            .clone()
+            .swap_dims(0, self.axis)
+            .reshape([num_channels as i32, -1]);
+
+        let min = channels.clone().min_dim(1).reshape([num_channels]);
+        let max = channels.max_dim(1).reshape([num_channels]);
+
+        CalibrationRange { min, max }
+    }
+}
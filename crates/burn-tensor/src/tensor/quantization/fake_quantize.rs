@@ -0,0 +1,22 @@
+use crate::{backend::Backend, CastOptions, Rounding, Tensor};
+
+/// The default implementation of the [fake quantization](crate::ops::FloatTensorOps::float_fake_quantize).
+///
+/// The values are clamped before being rounded, which gives the same result since the bounds
+/// are integers, so that they can be rounded through a cast without overflowing.
+pub(crate) fn fake_quantize<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+    scale: Tensor<B, D>,
+    zero_point: Tensor<B, D>,
+    qmin: f64,
+    qmax: f64,
+) -> Tensor<B, D> {
+    let quantized = tensor
+        .div(scale.clone())
+        .add(zero_point.clone())
+        .clamp(qmin, qmax)
+        .cast_with(CastOptions::new(Rounding::Nearest, false))
+        .float();
+
+    quantized.sub(zero_point).mul(scale)
+}
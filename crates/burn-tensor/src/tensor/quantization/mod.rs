@@ -1,10 +1,12 @@
 mod calibration;
+mod fake_quantize;
 mod parameters;
 mod primitive;
 mod scheme;
 mod strategy;
 
 pub use calibration::*;
+pub(crate) use fake_quantize::*;
 pub use parameters::*;
 pub use primitive::*;
 pub use scheme::*;
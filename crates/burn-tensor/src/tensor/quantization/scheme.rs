@@ -24,6 +24,10 @@ pub enum QuantizationScheme {
 
 impl QuantizationScheme {
     /// Compute the quantization parameters.
+    ///
+    /// The parameters are computed for each value of the range, so a per-channel range, such as
+    /// computed by [PerChannelMinMaxCalibration](super::PerChannelMinMaxCalibration), gives
+    /// per-channel parameters.
     pub fn compute_q_params<B: Backend>(
         &self,
         range: CalibrationRange<B>,
@@ -1,4 +1,4 @@
-use super::{backend::Backend, Tensor};
+use super::{backend::Backend, ElementConversion, Tensor};
 
 use colored::*;
 
@@ -79,14 +79,24 @@ pub fn check_closeness<B: Backend, const D: usize>(output: &Tensor<B, D>, expect
     for epsilon in [1e-1, 1e-2, 1e-3, 1e-4, 1e-5, 1e-6, 1e-7, 1e-8].iter() {
         println!("{} {:.e}", "Epsilon:".bold(), epsilon);
 
-        let close = output
-            .clone()
-            .is_close(expected.clone(), Some(*epsilon), Some(*epsilon));
-        let data = close.clone().into_data();
-        let num_elements = data.num_elements();
+        let num_elements = output.shape().num_elements();
 
-        // Count the number of elements that are close (true)
-        let count = data.iter::<bool>().filter(|x| *x).count();
+        // Only a single value is read back from the device, whether all the elements are close
+        // or the number of elements that are close.
+        let count = if output
+            .clone()
+            .all_close(expected.clone(), Some(*epsilon), Some(*epsilon))
+        {
+            num_elements
+        } else {
+            output
+                .clone()
+                .is_close(expected.clone(), Some(*epsilon), Some(*epsilon))
+                .int()
+                .sum()
+                .into_scalar()
+                .elem::<i64>() as usize
+        };
 
         let percentage = (count as f64 / num_elements as f64) * 100.0;
 
@@ -0,0 +1,211 @@
+use crate::{
+    backend::Backend, check, check::TensorCheck, Bool, ElementConversion, Int, Shape, Tensor,
+    TensorPrimitive,
+};
+
+/// A sparse matrix in the coordinate (COO) format, storing the row and the column of each of its
+/// values.
+///
+/// The entries can be in any order, and the values of duplicated coordinates are summed. The
+/// operations are built on tensor operations, so the gradients flow to the values and to the
+/// dense operands, the gradient of the values being the one of the dense matrix at their
+/// coordinates.
+#[derive(Clone, Debug)]
+pub struct SparseTensor<B: Backend> {
+    /// The coordinates of shape `[2, nnz]`, the rows followed by the columns.
+    indices: Tensor<B, 2, Int>,
+    /// The values of shape `[nnz]`.
+    values: Tensor<B, 1>,
+    shape: Shape<2>,
+}
+
+impl<B: Backend> SparseTensor<B> {
+    /// Create a sparse matrix from the coordinates of shape `[2, nnz]`, the rows followed by the
+    /// columns, and the values of shape `[nnz]`.
+    ///
+    /// # Panics
+    ///
+    /// If the indices and the values don't have the same number of entries.
+    pub fn new<S: Into<Shape<2>>>(
+        indices: Tensor<B, 2, Int>,
+        values: Tensor<B, 1>,
+        shape: S,
+    ) -> Self {
+        check!(TensorCheck::sparse_coo(&indices, &values));
+
+        Self {
+            indices,
+            values,
+            shape: shape.into(),
+        }
+    }
+
+    /// Create a sparse matrix from the non-zero values of the dense matrix.
+    pub fn from_dense(tensor: Tensor<B, 2>) -> Self {
+        let mask = tensor.clone().not_equal_elem(0);
+
+        Self::from_mask(tensor, mask)
+    }
+
+    /// Create a sparse matrix from the values of the dense matrix where the mask is true, which
+    /// keeps the zeros of the mask pattern.
+    pub fn from_mask(tensor: Tensor<B, 2>, mask: Tensor<B, 2, Bool>) -> Self {
+        let shape = tensor.shape();
+        let indices = mask.argwhere().transpose();
+        let values = tensor
+            .reshape([shape.num_elements()])
+            .select(0, flat_indices(&indices, &shape));
+
+        Self::new(indices, values, shape)
+    }
+
+    /// Convert the sparse matrix to a dense one, the missing values being zeros.
+    pub fn to_dense(self) -> Tensor<B, 2> {
+        let device = self.values.device();
+        let dense = Tensor::zeros([self.shape.num_elements()], &device);
+
+        dense
+            .select_assign(0, flat_indices(&self.indices, &self.shape), self.values)
+            .reshape(self.shape)
+    }
+
+    /// The coordinates of shape `[2, nnz]`, the rows followed by the columns.
+    pub fn indices(&self) -> Tensor<B, 2, Int> {
+        self.indices.clone()
+    }
+
+    /// The row of each value.
+    pub fn rows(&self) -> Tensor<B, 1, Int> {
+        self.coordinates(0)
+    }
+
+    /// The column of each value.
+    pub fn cols(&self) -> Tensor<B, 1, Int> {
+        self.coordinates(1)
+    }
+
+    /// The values of shape `[nnz]`.
+    pub fn values(&self) -> Tensor<B, 1> {
+        self.values.clone()
+    }
+
+    /// The shape of the matrix.
+    pub fn shape(&self) -> Shape<2> {
+        self.shape.clone()
+    }
+
+    /// The number of values stored, counting the duplicated coordinates.
+    pub fn num_entries(&self) -> usize {
+        self.values.dims()[0]
+    }
+
+    /// The device of the matrix.
+    pub fn device(&self) -> B::Device {
+        self.values.device()
+    }
+
+    /// Replace the values, keeping the coordinates.
+    ///
+    /// # Panics
+    ///
+    /// If the number of values changes.
+    pub fn with_values(self, values: Tensor<B, 1>) -> Self {
+        Self::new(self.indices, values, self.shape)
+    }
+
+    /// Transpose the matrix by swapping the rows and the columns of the coordinates.
+    pub fn transpose(self) -> Self {
+        let [rows, cols] = self.shape.dims;
+
+        Self {
+            indices: self.indices.flip([0]),
+            values: self.values,
+            shape: Shape::new([cols, rows]),
+        }
+    }
+
+    /// Multiply the values by the scalar.
+    pub fn mul_scalar<E: ElementConversion>(self, other: E) -> Self {
+        Self {
+            values: self.values.mul_scalar(other),
+            ..self
+        }
+    }
+
+    /// Multiply the values by the ones of the dense matrix at their coordinates, which keeps the
+    /// sparsity pattern.
+    pub fn mul_dense(self, other: Tensor<B, 2>) -> Self {
+        check!(TensorCheck::sparse_mul(&self.shape, &other));
+
+        let sampled = other
+            .reshape([self.shape.num_elements()])
+            .select(0, flat_indices(&self.indices, &self.shape));
+
+        Self {
+            values: self.values.mul(sampled),
+            ..self
+        }
+    }
+
+    /// Multiply the sparse matrix of shape `[m, k]` by the dense matrix of shape `[k, n]`,
+    /// returning the dense product of shape `[m, n]`.
+    ///
+    /// # Panics
+    ///
+    /// If the inner dimensions aren't equal.
+    pub fn spmm(self, dense: Tensor<B, 2>) -> Tensor<B, 2> {
+        check!(TensorCheck::spmm(&self.shape, &dense));
+
+        Tensor::new(TensorPrimitive::Float(B::float_spmm(
+            self.rows().primitive,
+            self.cols().primitive,
+            self.values.primitive.tensor(),
+            dense.primitive.tensor(),
+            self.shape.dims[0],
+        )))
+    }
+
+    /// Sampled dense-dense matrix multiplication, computing the product of the dense matrices of
+    /// shapes `[m, k]` and `[k, n]` only at the coordinates of the sparse matrix of shape
+    /// `[m, n]`, scaled by its values.
+    ///
+    /// This is the sparse equivalent of `self.to_dense() * lhs.matmul(rhs)`, like the attention
+    /// scores computed over the edges of a graph.
+    ///
+    /// # Panics
+    ///
+    /// If the inner dimensions aren't equal, or the product doesn't have the shape of the sparse
+    /// matrix.
+    pub fn sddmm(self, lhs: Tensor<B, 2>, rhs: Tensor<B, 2>) -> Self {
+        check!(TensorCheck::sddmm(&self.shape, &lhs, &rhs));
+
+        let lhs_rows = lhs.select(0, self.rows());
+        let rhs_cols = rhs.transpose().select(0, self.cols());
+        let products = lhs_rows.mul(rhs_cols).sum_dim(1).squeeze::<1>(1);
+
+        Self {
+            values: self.values.mul(products),
+            ..self
+        }
+    }
+
+    fn coordinates(&self, index: usize) -> Tensor<B, 1, Int> {
+        let num_entries = self.num_entries();
+
+        self.indices
+            .clone()
+            .slice([index..index + 1, 0..num_entries])
+            .reshape([num_entries])
+    }
+}
+
+/// The indices of the coordinates in the flattened matrix.
+fn flat_indices<B: Backend>(indices: &Tensor<B, 2, Int>, shape: &Shape<2>) -> Tensor<B, 1, Int> {
+    let [_, num_entries] = indices.dims();
+    let rows = indices.clone().slice([0..1, 0..num_entries]);
+    let cols = indices.clone().slice([1..2, 0..num_entries]);
+
+    rows.mul_scalar(shape.dims[1] as i64)
+        .add(cols)
+        .reshape([num_entries])
+}
@@ -20,4 +20,35 @@ mod tests {
 
         output.into_data().assert_approx_eq(&expected, 4);
     }
+
+    #[test]
+    fn test_softplus_extreme_inputs() {
+        let tensor = Tensor::<TestBackend, 2>::from([[-100.0, -20.0, -1.0, 0.0, 1.0, 20.0, 100.0]]);
+
+        let output = activation::softplus(tensor.clone(), 1.0);
+        let expected = TensorData::from([[
+            0.0,
+            2.0611536e-9,
+            0.31326169,
+            0.69314718,
+            1.31326169,
+            20.0,
+            100.0,
+        ]]);
+
+        output.into_data().assert_approx_eq(&expected, 4);
+
+        let output = activation::softplus(tensor, 2.0);
+        let expected = TensorData::from([[
+            0.0,
+            2.1241771e-18,
+            0.06346401,
+            0.34657359,
+            1.06346401,
+            20.0,
+            100.0,
+        ]]);
+
+        output.into_data().assert_approx_eq(&expected, 4);
+    }
 }
@@ -290,6 +290,10 @@ mod tests {
             unary: Erf,
             ops_float: |tensor: TestTensor<2>| tensor.erf()
         );
+        clone_invariance_test!(
+            unary: Erfinv,
+            ops_float: |tensor: TestTensor<2>| tensor.erfinv()
+        );
         clone_invariance_test!(
             unary: EqualElem,
             ops_float: |tensor: TestTensor<2>| tensor.equal_elem(0.5)
@@ -23,7 +23,9 @@ macro_rules! testgen_all {
 
         // test module
         burn_tensor::testgen_module_forward!();
+        burn_tensor::testgen_module_dropout!();
         burn_tensor::testgen_module_conv1d!();
+        burn_tensor::testgen_module_conv1d_fft!();
         burn_tensor::testgen_module_conv2d!();
         burn_tensor::testgen_module_conv3d!();
         burn_tensor::testgen_module_conv_transpose1d!();
@@ -42,25 +44,31 @@ macro_rules! testgen_all {
 
         // test ops
         burn_tensor::testgen_add!();
+        burn_tensor::testgen_addcmul!();
         burn_tensor::testgen_aggregation!();
         burn_tensor::testgen_arange!();
         burn_tensor::testgen_arange_step!();
         burn_tensor::testgen_arg!();
+        burn_tensor::testgen_axpby!();
         burn_tensor::testgen_cast!();
         burn_tensor::testgen_cat!();
         burn_tensor::testgen_chunk!();
         burn_tensor::testgen_clamp!();
         burn_tensor::testgen_close!();
         burn_tensor::testgen_cos!();
+        burn_tensor::testgen_count_nonzero!();
         burn_tensor::testgen_create_like!();
         burn_tensor::testgen_div!();
         burn_tensor::testgen_erf!();
+        burn_tensor::testgen_erfinv!();
         burn_tensor::testgen_exp!();
         burn_tensor::testgen_flatten!();
         burn_tensor::testgen_full!();
         burn_tensor::testgen_gather_scatter!();
+        burn_tensor::testgen_index_put!();
         burn_tensor::testgen_init!();
         burn_tensor::testgen_iter_dim!();
+        burn_tensor::testgen_lerp!();
         burn_tensor::testgen_log!();
         burn_tensor::testgen_log1p!();
         burn_tensor::testgen_map_comparison!();
@@ -85,22 +93,30 @@ macro_rules! testgen_all {
         burn_tensor::testgen_abs!();
         burn_tensor::testgen_squeeze!();
         burn_tensor::testgen_sub!();
+        burn_tensor::testgen_take_along_dim!();
         burn_tensor::testgen_tanh!();
         burn_tensor::testgen_transpose!();
         burn_tensor::testgen_tri!();
         burn_tensor::testgen_powf!();
+        burn_tensor::testgen_powi!();
         burn_tensor::testgen_any!();
         burn_tensor::testgen_all_op!();
         burn_tensor::testgen_permute!();
         burn_tensor::testgen_movedim!();
         burn_tensor::testgen_flip!();
         burn_tensor::testgen_bool!();
+        burn_tensor::testgen_bitwise!();
         burn_tensor::testgen_argwhere_nonzero!();
         burn_tensor::testgen_sign!();
         burn_tensor::testgen_expand!();
         burn_tensor::testgen_tri_mask!();
         burn_tensor::testgen_sort_argsort!();
+        burn_tensor::testgen_sparse!();
         burn_tensor::testgen_topk!();
+        burn_tensor::testgen_multinomial!();
+        burn_tensor::testgen_zero_size!();
+        burn_tensor::testgen_associative_scan!();
+        burn_tensor::testgen_adam_step!();
         burn_tensor::testgen_remainder!();
         burn_tensor::testgen_cartesian_grid!();
         burn_tensor::testgen_nan!();
@@ -119,6 +135,7 @@ macro_rules! testgen_all {
 
         // test quantization
         burn_tensor::testgen_calibration!();
+        burn_tensor::testgen_fake_quantize!();
         burn_tensor::testgen_scheme!();
     };
 }
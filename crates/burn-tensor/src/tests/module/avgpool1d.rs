@@ -14,6 +14,7 @@ mod tests {
             stride: 1,
             length: 6,
             count_include_pad: true,
+            ceil_mode: false,
         };
 
         test.assert_output(TestTensor::from([[[1., 2., 3., 4.]]]));
@@ -29,6 +30,7 @@ mod tests {
             stride: 2,
             length: 6,
             count_include_pad: true,
+            ceil_mode: false,
         };
 
         test.assert_output(TestTensor::from([[
@@ -47,6 +49,7 @@ mod tests {
             stride: 2,
             length: 6,
             count_include_pad: false,
+            ceil_mode: false,
         };
 
         test.assert_output(TestTensor::from([[
@@ -55,6 +58,38 @@ mod tests {
         ]]));
     }
 
+    #[test]
+    fn test_avg_pool1d_ceil_mode() {
+        let test = AvgPool1dTestCase {
+            batch_size: 1,
+            channels: 1,
+            kernel_size: 3,
+            padding: 1,
+            stride: 2,
+            length: 6,
+            count_include_pad: true,
+            ceil_mode: true,
+        };
+
+        test.assert_output(TestTensor::from([[[0.3333, 2.0000, 4.0000, 2.5000]]]));
+    }
+
+    #[test]
+    fn test_avg_pool1d_ceil_mode_dont_count_pad() {
+        let test = AvgPool1dTestCase {
+            batch_size: 1,
+            channels: 1,
+            kernel_size: 3,
+            padding: 1,
+            stride: 2,
+            length: 6,
+            count_include_pad: false,
+            ceil_mode: true,
+        };
+
+        test.assert_output(TestTensor::from([[[0.5000, 2.0000, 4.0000, 5.0000]]]));
+    }
+
     struct AvgPool1dTestCase {
         batch_size: usize,
         channels: usize,
@@ -63,6 +98,7 @@ mod tests {
         stride: usize,
         length: usize,
         count_include_pad: bool,
+        ceil_mode: bool,
     }
 
     impl AvgPool1dTestCase {
@@ -79,6 +115,7 @@ mod tests {
                 self.stride,
                 self.padding,
                 self.count_include_pad,
+                self.ceil_mode,
             );
 
             y.to_data().assert_approx_eq(&output.into_data(), 3);
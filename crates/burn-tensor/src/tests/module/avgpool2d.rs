@@ -18,6 +18,7 @@ mod tests {
             height: 6,
             width: 6,
             count_include_pad: true,
+            ceil_mode: false,
         };
 
         test.assert_output(TestTensor::from([[[
@@ -42,6 +43,7 @@ mod tests {
             height: 4,
             width: 6,
             count_include_pad: true,
+            ceil_mode: false,
         };
 
         test.assert_output(TestTensor::from([[[
@@ -66,6 +68,7 @@ mod tests {
             height: 4,
             width: 6,
             count_include_pad: false,
+            ceil_mode: false,
         };
 
         test.assert_output(TestTensor::from([[[
@@ -76,6 +79,54 @@ mod tests {
         ]]]));
     }
 
+    #[test]
+    fn test_avg_pool2d_ceil_mode() {
+        let test = AvgPool2dTestCase {
+            batch_size: 1,
+            channels: 1,
+            kernel_size_1: 3,
+            kernel_size_2: 2,
+            padding_1: 1,
+            padding_2: 0,
+            stride_1: 2,
+            stride_2: 2,
+            height: 5,
+            width: 5,
+            count_include_pad: true,
+            ceil_mode: true,
+        };
+
+        test.assert_output(TestTensor::from([[[
+            [2.0000, 3.3333, 4.3333],
+            [10.5000, 12.5000, 14.0000],
+            [12.0000, 13.3333, 14.3333],
+        ]]]));
+    }
+
+    #[test]
+    fn test_avg_pool2d_ceil_mode_dont_include_pad() {
+        let test = AvgPool2dTestCase {
+            batch_size: 1,
+            channels: 1,
+            kernel_size_1: 3,
+            kernel_size_2: 2,
+            padding_1: 1,
+            padding_2: 0,
+            stride_1: 2,
+            stride_2: 2,
+            height: 5,
+            width: 5,
+            count_include_pad: false,
+            ceil_mode: true,
+        };
+
+        test.assert_output(TestTensor::from([[[
+            [3.0000, 5.0000, 6.5000],
+            [10.5000, 12.5000, 14.0000],
+            [18.0000, 20.0000, 21.5000],
+        ]]]));
+    }
+
     struct AvgPool2dTestCase {
         batch_size: usize,
         channels: usize,
@@ -88,6 +139,7 @@ mod tests {
         height: usize,
         width: usize,
         count_include_pad: bool,
+        ceil_mode: bool,
     }
 
     impl AvgPool2dTestCase {
@@ -104,6 +156,7 @@ mod tests {
                 [self.stride_1, self.stride_2],
                 [self.padding_1, self.padding_2],
                 self.count_include_pad,
+                self.ceil_mode,
             );
 
             y.to_data().assert_approx_eq(&output.into_data(), 3);
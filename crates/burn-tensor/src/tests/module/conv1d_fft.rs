@@ -0,0 +1,145 @@
+#[burn_tensor_testgen::testgen(module_conv1d_fft)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::{conv1d, conv2d};
+    use burn_tensor::ops::{conv, ConvOptions};
+    use burn_tensor::{Distribution, Tensor, TensorPrimitive};
+
+    /// The span of the kernel above which the tests compute the convolution in the frequency
+    /// domain, the same in every test since the threshold is global.
+    const FFT_THRESHOLD: usize = 256;
+
+    #[test]
+    fn test_conv1d_fft_single_transform() {
+        let test = Conv1dFftTestCase {
+            batch_size: 2,
+            channels_in: 3,
+            channels_out: 2,
+            kernel_size: 17,
+            padding: 5,
+            stride: 1,
+            dilation: 17,
+            groups: 1,
+            length: 300,
+        };
+
+        test.assert_fft_matches_direct();
+    }
+
+    #[test]
+    fn test_conv1d_fft_overlap_save() {
+        let test = Conv1dFftTestCase {
+            batch_size: 1,
+            channels_in: 2,
+            channels_out: 3,
+            kernel_size: 33,
+            padding: 0,
+            stride: 1,
+            dilation: 9,
+            groups: 1,
+            length: 2500,
+        };
+
+        test.assert_fft_matches_direct();
+    }
+
+    #[test]
+    fn test_conv1d_fft_stride_groups() {
+        let test = Conv1dFftTestCase {
+            batch_size: 2,
+            channels_in: 4,
+            channels_out: 6,
+            kernel_size: 5,
+            padding: 3,
+            stride: 3,
+            dilation: 60,
+            groups: 2,
+            length: 700,
+        };
+
+        test.assert_fft_matches_direct();
+    }
+
+    #[test]
+    fn test_conv1d_fft_small_kernel() {
+        let test = Conv1dFftTestCase {
+            batch_size: 1,
+            channels_in: 2,
+            channels_out: 2,
+            kernel_size: 3,
+            padding: 1,
+            stride: 2,
+            dilation: 1,
+            groups: 1,
+            length: 9,
+        };
+
+        test.assert_fft_matches_direct();
+    }
+
+    struct Conv1dFftTestCase {
+        batch_size: usize,
+        channels_in: usize,
+        channels_out: usize,
+        kernel_size: usize,
+        padding: usize,
+        stride: usize,
+        dilation: usize,
+        groups: usize,
+        length: usize,
+    }
+
+    impl Conv1dFftTestCase {
+        fn assert_fft_matches_direct(self) {
+            let device = Default::default();
+            let x = TestTensor::<3>::random(
+                [self.batch_size, self.channels_in, self.length],
+                Distribution::Default,
+                &device,
+            );
+            let weight = TestTensor::<3>::random(
+                [
+                    self.channels_out,
+                    self.channels_in / self.groups,
+                    self.kernel_size,
+                ],
+                Distribution::Default,
+                &device,
+            );
+            let bias = TestTensor::<1>::random([self.channels_out], Distribution::Default, &device);
+            let options =
+                ConvOptions::new([self.stride], [self.padding], [self.dilation], self.groups);
+
+            // The direct path computes the 1D convolution as a 2D convolution.
+            let expected = conv2d(
+                x.clone().unsqueeze_dim::<4>(3),
+                weight.clone().unsqueeze_dim::<4>(3),
+                Some(bias.clone()),
+                ConvOptions::new(
+                    [self.stride, 1],
+                    [self.padding, 0],
+                    [self.dilation, 1],
+                    self.groups,
+                ),
+            )
+            .squeeze::<3>(3)
+            .into_data();
+
+            let output = Tensor::<TestBackend, 3>::from_primitive(TensorPrimitive::Float(
+                conv::conv1d_fft::<TestBackend>(
+                    x.clone().into_primitive().tensor(),
+                    weight.clone().into_primitive().tensor(),
+                    Some(bias.clone().into_primitive().tensor()),
+                    options.clone(),
+                ),
+            ));
+            output.into_data().assert_approx_eq(&expected, 2);
+
+            // The frequency domain is selected for the kernel spans above the threshold.
+            conv::set_conv1d_fft_threshold(Some(FFT_THRESHOLD));
+            conv1d(x, weight, Some(bias), options)
+                .into_data()
+                .assert_approx_eq(&expected, 2);
+        }
+    }
+}
@@ -0,0 +1,37 @@
+#[burn_tensor_testgen::testgen(module_dropout)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::dropout;
+
+    #[test]
+    fn test_dropout_should_zero_and_scale_the_elements() {
+        let num_elements = 10_000;
+        let prob = 0.4;
+        let tensor = TestTensor::<2>::ones([100, num_elements / 100], &Default::default());
+
+        let output = dropout(tensor, prob).into_data().to_vec::<f32>().unwrap();
+        let num_zeros = output.iter().filter(|value| **value == 0.0).count();
+        let fraction = num_zeros as f64 / num_elements as f64;
+        let scale = 1.0 / (1.0 - prob as f32);
+
+        assert!(
+            (fraction - prob).abs() < 0.03,
+            "Fraction of zeros {fraction}"
+        );
+        assert!(
+            output
+                .iter()
+                .all(|value| *value == 0.0 || (*value - scale).abs() < 1e-3),
+            "Kept elements not scaled by 1 / (1 - prob)"
+        );
+    }
+
+    #[test]
+    fn test_dropout_without_probability_should_keep_the_elements() {
+        let tensor = TestTensor::<2>::from([[1.0, -2.0], [3.0, 0.5]]);
+
+        let output = dropout(tensor.clone(), 0.0);
+
+        output.into_data().assert_eq(&tensor.into_data(), false);
+    }
+}
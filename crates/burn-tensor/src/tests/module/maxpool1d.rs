@@ -20,7 +20,7 @@ mod tests {
             [0.949, 0.949, 0.949, 0.789],
         ]]);
 
-        let output = max_pool1d(x, kernel_size, stride, padding, dilation);
+        let output = max_pool1d(x, kernel_size, stride, padding, dilation, false);
 
         y.to_data().assert_approx_eq(&output.into_data(), 3);
     }
@@ -35,7 +35,7 @@ mod tests {
         let x = TestTensor::from([[[0.6309, 0.6112, 0.6998, 0.4708]]]);
         let y = TestTensor::<3>::from([[[0.6309, 0.6998]]]);
 
-        let output = max_pool1d(x, kernel_size, stride, padding, dilation);
+        let output = max_pool1d(x, kernel_size, stride, padding, dilation, false);
 
         y.to_data().assert_approx_eq(&output.into_data(), 3);
     }
@@ -50,7 +50,7 @@ mod tests {
         let x = TestTensor::from([[[-0.6309, -0.6112, -0.6998, -0.4708]]]);
         let y = TestTensor::<3>::from([[[-0.6112, -0.6112, -0.4708, -0.4708]]]);
 
-        let output = max_pool1d(x, kernel_size, stride, padding, dilation);
+        let output = max_pool1d(x, kernel_size, stride, padding, dilation, false);
 
         y.to_data().assert_approx_eq(&output.into_data(), 3);
     }
@@ -71,7 +71,7 @@ mod tests {
             [0.5474, 0.9490, 0.7890, 0.9490, 0.7890, 0.5537],
         ]]);
 
-        let output = max_pool1d(x, kernel_size, stride, padding, dilation);
+        let output = max_pool1d(x, kernel_size, stride, padding, dilation, false);
 
         y.to_data().assert_approx_eq(&output.into_data(), 3);
     }
@@ -88,7 +88,7 @@ mod tests {
         let y = TestTensor::<3>::from([[[0.6386, 0.6386, 0.5742]]]);
 
         let (output, output_indices) =
-            max_pool1d_with_indices(x, kernel_size, stride, padding, dilation);
+            max_pool1d_with_indices(x, kernel_size, stride, padding, dilation, false);
 
         y.to_data().assert_approx_eq(&output.into_data(), 3);
         output_indices.into_data().assert_eq(&indices, false);
@@ -106,7 +106,25 @@ mod tests {
         let y = TestTensor::<3>::from([[[0.5388, 0.7122, 0.8316, 0.8316, 0.8316, 0.8316]]]);
 
         let (output, output_indices) =
-            max_pool1d_with_indices(x, kernel_size, stride, padding, dilation);
+            max_pool1d_with_indices(x, kernel_size, stride, padding, dilation, false);
+
+        y.to_data().assert_approx_eq(&output.into_data(), 3);
+        output_indices.into_data().assert_eq(&indices, false);
+    }
+
+    #[test]
+    fn test_max_pool1d_ceil_mode() {
+        let kernel_size = 2;
+        let padding = 0;
+        let stride = 2;
+        let dilation = 1;
+
+        let x = TestTensor::from([[[0.2479, 0.6386, 0.3166, 0.5742, 0.9154]]]);
+        let indices = TensorData::from([[[1, 3, 4]]]);
+        let y = TestTensor::<3>::from([[[0.6386, 0.5742, 0.9154]]]);
+
+        let (output, output_indices) =
+            max_pool1d_with_indices(x, kernel_size, stride, padding, dilation, true);
 
         y.to_data().assert_approx_eq(&output.into_data(), 3);
         output_indices.into_data().assert_eq(&indices, false);
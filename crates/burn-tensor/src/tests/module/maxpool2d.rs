@@ -100,6 +100,7 @@ mod tests {
             [stride_1, stride_2],
             [padding_1, padding_2],
             [dilation_1, dilation_2],
+            false,
         );
 
         y.to_data().assert_approx_eq(&output.into_data(), 3);
@@ -141,6 +142,7 @@ mod tests {
             [stride_1, stride_2],
             [padding_1, padding_2],
             [dilation_1, dilation_2],
+            false,
         );
 
         y.to_data().assert_approx_eq(&output.into_data(), 3);
@@ -183,6 +185,7 @@ mod tests {
             [stride_1, stride_2],
             [padding_1, padding_2],
             [dilation_1, dilation_2],
+            false,
         );
 
         y.to_data().assert_approx_eq(&output.into_data(), 3);
@@ -222,6 +225,7 @@ mod tests {
             [stride_1, stride_2],
             [padding_1, padding_2],
             [dilation_1, dilation_2],
+            false,
         );
 
         y.to_data().assert_approx_eq(&output.into_data(), 3);
@@ -266,6 +270,7 @@ mod tests {
             [stride_1, stride_2],
             [padding_1, padding_2],
             [dilation_1, dilation_2],
+            false,
         );
 
         y.to_data().assert_approx_eq(&output.into_data(), 3);
@@ -314,6 +319,44 @@ mod tests {
             [stride_1, stride_2],
             [padding_1, padding_2],
             [dilation_1, dilation_2],
+            false,
+        );
+
+        y.to_data().assert_approx_eq(&output.into_data(), 3);
+        output_indices.into_data().assert_eq(&indices, false);
+    }
+
+    #[test]
+    fn test_max_pool2d_ceil_mode() {
+        let kernel_size_1 = 2;
+        let kernel_size_2 = 2;
+        let padding_1 = 0;
+        let padding_2 = 0;
+        let stride_1 = 2;
+        let stride_2 = 2;
+        let dilation_1 = 1;
+        let dilation_2 = 1;
+
+        let x = TestTensor::from([[[
+            [0.5388, 0.0676, 0.7122, 0.8316, 0.0653],
+            [0.9154, 0.1536, 0.9089, 0.8016, 0.7518],
+            [0.2073, 0.0501, 0.8811, 0.5604, 0.5075],
+            [0.4384, 0.9963, 0.9698, 0.4988, 0.2609],
+            [0.3391, 0.2230, 0.4610, 0.5365, 0.6880],
+        ]]]);
+        let indices = TensorData::from([[[[5, 7, 9], [16, 17, 14], [20, 23, 24]]]]);
+        let y = TestTensor::<4>::from([[[
+            [0.9154, 0.9089, 0.7518],
+            [0.9963, 0.9698, 0.5075],
+            [0.3391, 0.5365, 0.688],
+        ]]]);
+        let (output, output_indices) = max_pool2d_with_indices(
+            x,
+            [kernel_size_1, kernel_size_2],
+            [stride_1, stride_2],
+            [padding_1, padding_2],
+            [dilation_1, dilation_2],
+            true,
         );
 
         y.to_data().assert_approx_eq(&output.into_data(), 3);
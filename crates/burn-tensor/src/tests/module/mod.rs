@@ -5,11 +5,13 @@ mod avgpool2d;
 mod bicubic_interpolate;
 mod bilinear_interpolate;
 mod conv1d;
+mod conv1d_fft;
 mod conv2d;
 mod conv3d;
 mod conv_transpose1d;
 mod conv_transpose2d;
 mod conv_transpose3d;
+mod dropout;
 mod forward;
 mod maxpool1d;
 mod maxpool2d;
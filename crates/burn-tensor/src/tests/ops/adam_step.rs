@@ -0,0 +1,281 @@
+#[burn_tensor_testgen::testgen(adam_step)]
+mod tests {
+    use super::*;
+    use burn_tensor::ops::{AdamStepInput, AdamStepOptions, AdamStepSegment, OptimizerOps};
+    use burn_tensor::{Distribution, Tensor, TensorPrimitive};
+
+    const OPTIONS: AdamStepOptions = AdamStepOptions {
+        lr: 0.01,
+        beta_1: 0.9,
+        beta_2: 0.999,
+        epsilon: 1e-8,
+        penalty: None,
+        weight_decay: None,
+    };
+
+    #[test]
+    fn adam_step_should_match_composed_without_moments() {
+        assert_step_matches_composed::<2>([7, 13], false, 1, OPTIONS);
+    }
+
+    #[test]
+    fn adam_step_should_match_composed_with_moments() {
+        assert_step_matches_composed::<3>([3, 5, 11], true, 4, OPTIONS);
+    }
+
+    #[test]
+    fn adam_step_should_match_composed_with_penalty() {
+        let options = AdamStepOptions {
+            penalty: Some(0.05),
+            ..OPTIONS
+        };
+        assert_step_matches_composed::<2>([9, 17], true, 2, options);
+    }
+
+    #[test]
+    fn adam_step_should_match_composed_with_weight_decay() {
+        let options = AdamStepOptions {
+            weight_decay: Some(0.01),
+            ..OPTIONS
+        };
+        assert_step_matches_composed::<1>([100], true, 7, options);
+        assert_step_matches_composed::<1>([100], false, 1, options);
+    }
+
+    #[test]
+    fn adam_step_multi_should_match_adam_step_per_tensor() {
+        let options = AdamStepOptions {
+            weight_decay: Some(0.02),
+            ..OPTIONS
+        };
+        let lengths = [5, 1, 64, 33, 2];
+        let segments = segments(&lengths, |index| index + 1);
+        let num_elements = lengths.iter().sum();
+
+        let tensor = random([num_elements]);
+        let grad = random([num_elements]);
+        let moment_1 = random([num_elements]);
+        let moment_2 = random([num_elements]).abs();
+
+        let (tensor_multi, moment_1_multi, moment_2_multi) = step_multi(
+            tensor.clone(),
+            grad.clone(),
+            Some((moment_1.clone(), moment_2.clone())),
+            &segments,
+            &options,
+        );
+
+        for segment in segments {
+            let range = segment.offset..segment.offset + segment.length;
+            let slice = |tensor: &TestTensor<1>| tensor.clone().slice([range.clone()]);
+
+            let (tensor_expected, moment_1_expected, moment_2_expected) = step(
+                slice(&tensor),
+                slice(&grad),
+                Some((slice(&moment_1), slice(&moment_2))),
+                segment.time,
+                &options,
+            );
+
+            slice(&tensor_multi)
+                .into_data()
+                .assert_eq(&tensor_expected.into_data(), false);
+            slice(&moment_1_multi)
+                .into_data()
+                .assert_eq(&moment_1_expected.into_data(), false);
+            slice(&moment_2_multi)
+                .into_data()
+                .assert_eq(&moment_2_expected.into_data(), false);
+        }
+    }
+
+    #[test]
+    fn adam_step_multi_without_moments_should_match_zero_moments() {
+        let lengths = [3, 8, 4];
+        let segments = segments(&lengths, |_| 1);
+
+        let tensor = random([15]);
+        let grad = random([15]);
+        let zeros = TestTensor::<1>::zeros([15], &Default::default());
+
+        let (tensor_actual, moment_1_actual, moment_2_actual) =
+            step_multi(tensor.clone(), grad.clone(), None, &segments, &OPTIONS);
+        let (tensor_expected, moment_1_expected, moment_2_expected) = step_multi(
+            tensor,
+            grad,
+            Some((zeros.clone(), zeros)),
+            &segments,
+            &OPTIONS,
+        );
+
+        tensor_actual
+            .into_data()
+            .assert_eq(&tensor_expected.into_data(), false);
+        moment_1_actual
+            .into_data()
+            .assert_eq(&moment_1_expected.into_data(), false);
+        moment_2_actual
+            .into_data()
+            .assert_eq(&moment_2_expected.into_data(), false);
+    }
+
+    #[test]
+    #[should_panic]
+    fn adam_step_multi_should_panic_when_segments_dont_cover_tensors() {
+        let segments = segments(&[3, 4], |_| 1);
+
+        step_multi(random([8]), random([8]), None, &segments, &OPTIONS);
+    }
+
+    type Tensors<const D: usize> = (TestTensor<D>, TestTensor<D>, TestTensor<D>);
+
+    fn assert_step_matches_composed<const D: usize>(
+        shape: [usize; D],
+        with_moments: bool,
+        time: usize,
+        options: AdamStepOptions,
+    ) {
+        let tensor = random(shape);
+        let grad = random(shape);
+        let moments = with_moments.then(|| (random(shape), random(shape).abs()));
+
+        let (tensor_actual, moment_1_actual, moment_2_actual) = step(
+            tensor.clone(),
+            grad.clone(),
+            moments.clone(),
+            time,
+            &options,
+        );
+        let (tensor_expected, moment_1_expected, moment_2_expected) =
+            composed(tensor, grad, moments, time, &options);
+
+        tensor_actual
+            .into_data()
+            .assert_eq(&tensor_expected.into_data(), false);
+        moment_1_actual
+            .into_data()
+            .assert_eq(&moment_1_expected.into_data(), false);
+        moment_2_actual
+            .into_data()
+            .assert_eq(&moment_2_expected.into_data(), false);
+    }
+
+    /// The step of the Adam optimizer, written with the tensor API.
+    fn composed<const D: usize>(
+        tensor: TestTensor<D>,
+        mut grad: TestTensor<D>,
+        moments: Option<(TestTensor<D>, TestTensor<D>)>,
+        time: usize,
+        options: &AdamStepOptions,
+    ) -> Tensors<D> {
+        if let Some(penalty) = options.penalty {
+            grad = tensor.clone().mul_scalar(penalty) + grad;
+        }
+
+        let factor_1 = 1.0 - options.beta_1;
+        let factor_2 = 1.0 - options.beta_2;
+        let grad_squared = grad.clone().powf_scalar(2.0);
+
+        let (moment_1, moment_2) = match moments {
+            Some((moment_1, moment_2)) => (
+                moment_1.mul_scalar(options.beta_1) + grad.mul_scalar(factor_1),
+                moment_2.mul_scalar(options.beta_2) + grad_squared.mul_scalar(factor_2),
+            ),
+            None => (grad.mul_scalar(factor_1), grad_squared.mul_scalar(factor_2)),
+        };
+
+        let correction_1 = 1.0 - options.beta_1.powi(time as i32);
+        let correction_2 = 1.0 - options.beta_2.powi(time as i32);
+        let update = moment_1.clone().div_scalar(correction_1)
+            / (moment_2
+                .clone()
+                .div_scalar(correction_2)
+                .sqrt()
+                .add_scalar(options.epsilon));
+
+        let tensor = match options.weight_decay {
+            Some(weight_decay) => {
+                tensor.clone() - tensor.mul_scalar(options.lr).mul_scalar(weight_decay)
+            }
+            None => tensor,
+        };
+
+        (tensor - update.mul_scalar(options.lr), moment_1, moment_2)
+    }
+
+    fn step<const D: usize>(
+        tensor: TestTensor<D>,
+        grad: TestTensor<D>,
+        moments: Option<(TestTensor<D>, TestTensor<D>)>,
+        time: usize,
+        options: &AdamStepOptions,
+    ) -> Tensors<D> {
+        let output = TestBackend::adam_step(input(tensor, grad, moments), time, options);
+
+        (
+            float(output.tensor),
+            float(output.moment_1),
+            float(output.moment_2),
+        )
+    }
+
+    fn step_multi(
+        tensor: TestTensor<1>,
+        grad: TestTensor<1>,
+        moments: Option<(TestTensor<1>, TestTensor<1>)>,
+        segments: &[AdamStepSegment],
+        options: &AdamStepOptions,
+    ) -> Tensors<1> {
+        let output = TestBackend::adam_step_multi(input(tensor, grad, moments), segments, options);
+
+        (
+            float(output.tensor),
+            float(output.moment_1),
+            float(output.moment_2),
+        )
+    }
+
+    fn input<const D: usize>(
+        tensor: TestTensor<D>,
+        grad: TestTensor<D>,
+        moments: Option<(TestTensor<D>, TestTensor<D>)>,
+    ) -> AdamStepInput<TestBackend, D> {
+        AdamStepInput {
+            tensor: tensor.into_primitive().tensor(),
+            grad: grad.into_primitive().tensor(),
+            moments: moments.map(|(moment_1, moment_2)| {
+                (
+                    moment_1.into_primitive().tensor(),
+                    moment_2.into_primitive().tensor(),
+                )
+            }),
+        }
+    }
+
+    fn float<const D: usize>(
+        tensor: <TestBackend as burn_tensor::backend::Backend>::FloatTensorPrimitive<D>,
+    ) -> TestTensor<D> {
+        Tensor::from_primitive(TensorPrimitive::Float(tensor))
+    }
+
+    fn segments(lengths: &[usize], time: impl Fn(usize) -> usize) -> Vec<AdamStepSegment> {
+        let mut offset = 0;
+        lengths
+            .iter()
+            .enumerate()
+            .map(|(index, &length)| {
+                let segment = AdamStepSegment {
+                    offset,
+                    length,
+                    time: time(index),
+                };
+                offset += length;
+                segment
+            })
+            .collect()
+    }
+
+    fn random<const D: usize>(shape: [usize; D]) -> TestTensor<D> {
+        TestTensor::random(shape, Distribution::Uniform(-1.0, 1.0), &Default::default())
+    }
+}
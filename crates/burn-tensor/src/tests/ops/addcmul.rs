@@ -0,0 +1,59 @@
+#[burn_tensor_testgen::testgen(addcmul)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Distribution, TensorData};
+
+    #[test]
+    fn should_support_addcmul_ops() {
+        let tensor = TestTensor::<2>::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+        let tensor1 = TestTensor::<2>::from([[1.0, 2.0, 3.0], [-1.0, 0.5, 0.0]]);
+        let tensor2 = TestTensor::<2>::from([[2.0, 2.0, 2.0], [4.0, 4.0, 4.0]]);
+
+        let output = tensor.addcmul(tensor1, tensor2, 0.5);
+        let expected = TensorData::from([[1.0, 3.0, 5.0], [1.0, 5.0, 5.0]]);
+
+        output.into_data().assert_approx_eq(&expected, 5);
+    }
+
+    #[test]
+    fn should_support_addcdiv_ops() {
+        let tensor = TestTensor::<2>::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+        let tensor1 = TestTensor::<2>::from([[1.0, 2.0, 3.0], [-1.0, 0.5, 0.0]]);
+        let tensor2 = TestTensor::<2>::from([[2.0, 2.0, 2.0], [4.0, 4.0, 4.0]]);
+
+        let output = tensor.addcdiv(tensor1, tensor2, 2.0);
+        let expected = TensorData::from([[1.0, 3.0, 5.0], [2.5, 4.25, 5.0]]);
+
+        output.into_data().assert_approx_eq(&expected, 5);
+    }
+
+    #[test]
+    fn addcmul_should_match_the_composed_ops() {
+        let device = Default::default();
+        let tensor = TestTensor::<3>::random([3, 4, 5], Distribution::Default, &device);
+        let tensor1 = TestTensor::<3>::random([3, 4, 5], Distribution::Default, &device);
+        let tensor2 = TestTensor::<3>::random([3, 1, 5], Distribution::Default, &device);
+
+        let expected = tensor.clone() + tensor1.clone().mul_scalar(-0.3) * tensor2.clone();
+        let output = tensor.addcmul(tensor1, tensor2, -0.3);
+
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 4);
+    }
+
+    #[test]
+    fn addcdiv_should_match_the_composed_ops() {
+        let device = Default::default();
+        let tensor = TestTensor::<3>::random([3, 4, 5], Distribution::Default, &device);
+        let tensor1 = TestTensor::<3>::random([3, 4, 5], Distribution::Default, &device);
+        let tensor2 = TestTensor::<3>::random([3, 4, 5], Distribution::Uniform(1.0, 2.0), &device);
+
+        let expected = tensor.clone() + tensor1.clone().mul_scalar(0.7) / tensor2.clone();
+        let output = tensor.addcdiv(tensor1, tensor2, 0.7);
+
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 4);
+    }
+}
@@ -99,4 +99,74 @@ mod tests {
 
         assert_eq!(output.len(), 0);
     }
+
+    #[test]
+    fn test_argwhere_padded_exact_capacity() {
+        let tensor = TestTensorBool::<2>::from([[false, false], [false, true], [true, true]]);
+        let (indices, count) = tensor.argwhere_padded(3);
+
+        indices
+            .into_data()
+            .assert_eq(&TensorData::from([[1, 1], [2, 0], [2, 1]]), false);
+        count.into_data().assert_eq(&TensorData::from([3]), false);
+    }
+
+    #[test]
+    fn test_argwhere_padded_fills_unused_rows() {
+        let tensor = TestTensorBool::<1>::from([false, true, false, true, false]);
+        let (indices, count) = tensor.argwhere_padded(4);
+
+        indices
+            .into_data()
+            .assert_eq(&TensorData::from([[1], [3], [-1], [-1]]), false);
+        count.into_data().assert_eq(&TensorData::from([2]), false);
+    }
+
+    #[test]
+    fn test_argwhere_padded_overflow_reports_true_count() {
+        let tensor = TestTensorBool::<2>::from([[true, false, true], [true, true, false]]);
+        let (indices, count) = tensor.argwhere_padded(2);
+
+        indices
+            .into_data()
+            .assert_eq(&TensorData::from([[0, 0], [0, 2]]), false);
+        count.into_data().assert_eq(&TensorData::from([4]), false);
+    }
+
+    #[test]
+    fn test_argwhere_padded_empty_mask() {
+        let tensor = TestTensorBool::<2>::from([[false, false], [false, false]]);
+        let (indices, count) = tensor.argwhere_padded(2);
+
+        indices
+            .into_data()
+            .assert_eq(&TensorData::from([[-1, -1], [-1, -1]]), false);
+        count.into_data().assert_eq(&TensorData::from([0]), false);
+    }
+
+    #[test]
+    fn test_argwhere_padded_should_match_argwhere() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<1>::arange(0..3000, &device)
+            .reshape([30, 100])
+            .remainder_scalar(7)
+            .equal_elem(3);
+        let expected = tensor.clone().argwhere();
+        let num_rows = expected.dims()[0];
+
+        let (indices, count) = tensor.argwhere_padded(num_rows + 5);
+
+        count
+            .into_data()
+            .assert_eq(&TensorData::from([num_rows as i64]), false);
+        indices
+            .clone()
+            .slice([0..num_rows, 0..2])
+            .into_data()
+            .assert_eq(&expected.into_data(), false);
+        indices
+            .slice([num_rows..num_rows + 5, 0..2])
+            .into_data()
+            .assert_eq(&TensorData::from([[-1, -1]; 5]), false);
+    }
 }
@@ -0,0 +1,95 @@
+#[burn_tensor_testgen::testgen(associative_scan)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Distribution, Tensor, TensorData};
+
+    /// The recurrence computed with a loop over the last dimension.
+    fn sequential(a: &TensorData, b: &TensorData, length: usize) -> Vec<f32> {
+        let a = a.to_vec::<f32>().unwrap();
+        let b = b.to_vec::<f32>().unwrap();
+        let mut output = Vec::with_capacity(a.len());
+
+        for (a, b) in a.chunks(length).zip(b.chunks(length)) {
+            let mut value = 0.0;
+            for (a, b) in a.iter().zip(b) {
+                value = a * value + b;
+                output.push(value);
+            }
+        }
+
+        output
+    }
+
+    #[test]
+    fn should_support_associative_scan() {
+        let a = TestTensor::<2>::from([[0.5, 0.5, 2.0], [1.0, -1.0, 0.0]]);
+        let b = TestTensor::<2>::from([[1.0, 2.0, 3.0], [1.0, 1.0, 4.0]]);
+
+        TestTensor::associative_scan(a, b, 1)
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[1.0, 2.5, 8.0], [1.0, 0.0, 4.0]]), 5);
+    }
+
+    #[test]
+    fn should_support_associative_scan_over_the_first_dimension() {
+        let a = TestTensor::<2>::from([[0.5, 2.0], [0.5, 3.0], [2.0, 0.5]]);
+        let b = TestTensor::<2>::from([[1.0, 1.0], [2.0, 0.0], [3.0, 1.0]]);
+
+        TestTensor::associative_scan(a, b, 0)
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[1.0, 1.0], [2.5, 3.0], [8.0, 2.5]]), 5);
+    }
+
+    #[test]
+    fn should_match_the_sequential_recurrence_with_any_length() {
+        let device = Default::default();
+
+        for length in [1, 2, 5, 16, 37, 100] {
+            let a = TestTensor::<2>::random([3, length], Distribution::Uniform(-1.0, 1.0), &device);
+            let b = TestTensor::<2>::random([3, length], Distribution::Default, &device);
+            let expected = sequential(&a.to_data(), &b.to_data(), length);
+
+            TestTensor::associative_scan(a, b, 1)
+                .into_data()
+                .assert_approx_eq(&TensorData::new(expected, [3, length]), 3);
+        }
+    }
+
+    #[test]
+    fn should_be_stable_with_coefficients_near_one() {
+        let device = Default::default();
+        let length = 300;
+        let a = TestTensor::<2>::random([2, length], Distribution::Uniform(0.99, 1.0), &device);
+        let b = TestTensor::<2>::random([2, length], Distribution::Uniform(-0.1, 0.1), &device);
+        let expected = sequential(&a.to_data(), &b.to_data(), length);
+
+        let output = TestTensor::associative_scan(a, b, 1).into_data();
+
+        output
+            .iter::<f32>()
+            .zip(expected)
+            .for_each(|(value, expected)| {
+                assert!(
+                    (value - expected).abs() <= 1e-3 * f32::max(1.0, expected.abs()),
+                    "{value} != {expected}"
+                )
+            });
+    }
+
+    #[test]
+    fn should_support_associative_scan_over_a_middle_dimension() {
+        let device = Default::default();
+        let a = TestTensor::<3>::random([2, 7, 3], Distribution::Uniform(-1.0, 1.0), &device);
+        let b = TestTensor::<3>::random([2, 7, 3], Distribution::Default, &device);
+        let expected = sequential(
+            &a.clone().swap_dims(1, 2).into_data(),
+            &b.clone().swap_dims(1, 2).into_data(),
+            7,
+        );
+
+        TestTensor::associative_scan(a, b, 1)
+            .swap_dims(1, 2)
+            .into_data()
+            .assert_approx_eq(&TensorData::new(expected, [2, 3, 7]), 3);
+    }
+}
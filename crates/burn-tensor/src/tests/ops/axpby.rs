@@ -0,0 +1,30 @@
+#[burn_tensor_testgen::testgen(axpby)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Distribution, TensorData};
+
+    #[test]
+    fn should_support_axpby_ops() {
+        let tensor = TestTensor::<2>::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+        let other = TestTensor::<2>::from([[1.0, 2.0, 3.0], [-1.0, 0.5, 0.0]]);
+
+        let output = tensor.axpby(2.0, other, -1.0);
+        let expected = TensorData::from([[-1.0, 0.0, 1.0], [7.0, 7.5, 10.0]]);
+
+        output.into_data().assert_approx_eq(&expected, 5);
+    }
+
+    #[test]
+    fn axpby_should_match_the_composed_ops() {
+        let device = Default::default();
+        let tensor = TestTensor::<3>::random([2, 3, 17], Distribution::Default, &device);
+        let other = TestTensor::<3>::random([2, 1, 17], Distribution::Default, &device);
+
+        let expected = tensor.clone().mul_scalar(0.9) + other.clone().mul_scalar(0.1);
+        let output = tensor.axpby(0.9, other, 0.1);
+
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 4);
+    }
+}
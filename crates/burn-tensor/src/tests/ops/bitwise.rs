@@ -0,0 +1,214 @@
+#[burn_tensor_testgen::testgen(bitwise)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Bool, Distribution, Tensor, TensorData};
+
+    fn random_ints(shape: [usize; 2]) -> TestTensorInt<2> {
+        TestTensorInt::random(
+            shape,
+            Distribution::Uniform(-1000.0, 1000.0),
+            &Default::default(),
+        )
+    }
+
+    fn random_bools(shape: [usize; 2]) -> Tensor<TestBackend, 2, Bool> {
+        TestTensor::<2>::random(shape, Distribution::Default, &Default::default()).greater_elem(0.5)
+    }
+
+    #[test]
+    fn should_support_int_bitwise_ops() {
+        let lhs = TestTensorInt::<2>::from([[3, -4, 12], [7, 0, -1]]);
+        let rhs = TestTensorInt::<2>::from([[5, 2, 10], [-8, 9, 6]]);
+
+        (lhs.clone() & rhs.clone())
+            .into_data()
+            .assert_eq(&TensorData::from([[1, 0, 8], [0, 0, 6]]), false);
+        (lhs.clone() | rhs.clone())
+            .into_data()
+            .assert_eq(&TensorData::from([[7, -2, 14], [-1, 9, -1]]), false);
+        (lhs ^ rhs)
+            .into_data()
+            .assert_eq(&TensorData::from([[6, -2, 6], [-1, 9, -7]]), false);
+    }
+
+    #[test]
+    fn should_support_int_bitwise_scalar_ops() {
+        let tensor = TestTensorInt::<1>::from([1, 6, -3]);
+
+        (tensor.clone() & 3)
+            .into_data()
+            .assert_eq(&TensorData::from([1, 2, 1]), false);
+        (tensor.clone() | 8)
+            .into_data()
+            .assert_eq(&TensorData::from([9, 14, -3]), false);
+        (tensor ^ 5)
+            .into_data()
+            .assert_eq(&TensorData::from([4, 3, -8]), false);
+    }
+
+    #[test]
+    fn should_support_int_bitwise_not() {
+        let tensor = TestTensorInt::<1>::from([0, 5, -6]);
+
+        (!tensor)
+            .into_data()
+            .assert_eq(&TensorData::from([-1, -6, 5]), false);
+    }
+
+    #[test]
+    fn should_support_int_shifts() {
+        let tensor = TestTensorInt::<1>::from([1, -8, 3]);
+        let shifts = TestTensorInt::<1>::from([2, 1, 4]);
+        (tensor << shifts)
+            .into_data()
+            .assert_eq(&TensorData::from([4, -16, 48]), false);
+
+        // The right shift of signed integers is arithmetic.
+        let tensor = TestTensorInt::<1>::from([16, -8, 7]);
+        let shifts = TestTensorInt::<1>::from([2, 1, 1]);
+        (tensor >> shifts)
+            .into_data()
+            .assert_eq(&TensorData::from([4, -4, 3]), false);
+
+        (TestTensorInt::<1>::from([1, 2, -1]) << 3)
+            .into_data()
+            .assert_eq(&TensorData::from([8, 16, -8]), false);
+        (TestTensorInt::<1>::from([5, -5]) >> 1)
+            .into_data()
+            .assert_eq(&TensorData::from([2, -3]), false);
+    }
+
+    #[test]
+    fn should_wrap_int_shift_amounts_to_the_bit_width() {
+        // The amounts are taken modulo the bit width, these ones being 0, 1 and 1 for both 32 and
+        // 64 bits integers.
+        let tensor = TestTensorInt::<1>::from([3, -8, 5]);
+        let shifts = TestTensorInt::<1>::from([64, 65, -63]);
+        (tensor.clone() << shifts.clone())
+            .into_data()
+            .assert_eq(&TensorData::from([3, -16, 10]), false);
+        (tensor.clone() >> shifts)
+            .into_data()
+            .assert_eq(&TensorData::from([3, -4, 2]), false);
+
+        (tensor.clone() << 65)
+            .into_data()
+            .assert_eq(&TensorData::from([6, -16, 10]), false);
+        (tensor.clone() >> 64)
+            .into_data()
+            .assert_eq(&TensorData::from([3, -8, 5]), false);
+        (tensor >> -63)
+            .into_data()
+            .assert_eq(&TensorData::from([1, -4, 2]), false);
+    }
+
+    #[test]
+    fn should_support_int_bitwise_broadcasting() {
+        let lhs = TestTensorInt::<2>::from([[12, 5], [3, 10]]);
+        let rhs = TestTensorInt::<2>::from([[6], [1]]);
+
+        lhs.bitwise_and(rhs)
+            .into_data()
+            .assert_eq(&TensorData::from([[4, 4], [1, 0]]), false);
+    }
+
+    #[test]
+    fn int_bitwise_ops_should_satisfy_de_morgan_laws() {
+        let lhs = random_ints([8, 13]);
+        let rhs = random_ints([8, 13]);
+
+        (!(lhs.clone() & rhs.clone()))
+            .into_data()
+            .assert_eq(&(!lhs.clone() | !rhs.clone()).into_data(), false);
+        (!(lhs.clone() | rhs.clone()))
+            .into_data()
+            .assert_eq(&(!lhs.clone() & !rhs.clone()).into_data(), false);
+        (lhs.clone() ^ rhs.clone()).into_data().assert_eq(
+            &((lhs.clone() | rhs.clone()) & !(lhs & rhs)).into_data(),
+            false,
+        );
+    }
+
+    #[test]
+    fn should_support_bool_logical_ops() {
+        let lhs = Tensor::<TestBackend, 2, Bool>::from([[true, true], [false, false]]);
+        let rhs = Tensor::<TestBackend, 2, Bool>::from([[true, false], [true, false]]);
+
+        (lhs.clone() & rhs.clone())
+            .into_data()
+            .assert_eq(&TensorData::from([[true, false], [false, false]]), false);
+        (lhs.clone() | rhs.clone())
+            .into_data()
+            .assert_eq(&TensorData::from([[true, true], [true, false]]), false);
+        (lhs.clone() ^ rhs)
+            .into_data()
+            .assert_eq(&TensorData::from([[false, true], [true, false]]), false);
+        (!lhs)
+            .into_data()
+            .assert_eq(&TensorData::from([[false, false], [true, true]]), false);
+    }
+
+    #[test]
+    fn should_support_bool_logical_broadcasting() {
+        let lhs = Tensor::<TestBackend, 2, Bool>::from([[true, false], [true, true]]);
+        let rhs = Tensor::<TestBackend, 2, Bool>::from([[true], [false]]);
+
+        lhs.bool_and(rhs)
+            .into_data()
+            .assert_eq(&TensorData::from([[true, false], [false, false]]), false);
+    }
+
+    #[test]
+    fn bool_logical_ops_should_satisfy_de_morgan_laws() {
+        let lhs = random_bools([8, 13]);
+        let rhs = random_bools([8, 13]);
+
+        (!(lhs.clone() & rhs.clone()))
+            .into_data()
+            .assert_eq(&(!lhs.clone() | !rhs.clone()).into_data(), false);
+        (!(lhs.clone() | rhs.clone()))
+            .into_data()
+            .assert_eq(&(!lhs.clone() & !rhs.clone()).into_data(), false);
+        (lhs.clone() ^ rhs.clone()).into_data().assert_eq(
+            &((lhs.clone() | rhs.clone()) & !(lhs & rhs)).into_data(),
+            false,
+        );
+    }
+
+    #[test]
+    fn should_pack_bools_from_the_least_significant_bit() {
+        let tensor = Tensor::<TestBackend, 2, Bool>::from([[true, false], [true, true]]);
+
+        tensor
+            .to_packed_u32()
+            .into_data()
+            .assert_eq(&TensorData::from([13]), false);
+    }
+
+    #[test]
+    fn packed_bools_should_round_trip() {
+        for length in [1, 31, 32, 33, 70] {
+            let tensor = random_bools([1, length]);
+
+            let packed = tensor.clone().to_packed_u32();
+            assert_eq!(packed.dims(), [length.div_ceil(32)]);
+
+            Tensor::<TestBackend, 2, Bool>::from_packed(packed, [1, length])
+                .into_data()
+                .assert_eq(&tensor.into_data(), false);
+        }
+    }
+
+    #[test]
+    fn packed_bools_should_round_trip_with_all_bits_set() {
+        let tensor = Tensor::<TestBackend, 2, Bool>::from_data(
+            TensorData::new(vec![true; 3 * 25], [3, 25]),
+            &Default::default(),
+        );
+
+        let packed = tensor.clone().to_packed_u32();
+        let unpacked = Tensor::<TestBackend, 2, Bool>::from_packed(packed, [3, 25]);
+
+        unpacked.into_data().assert_eq(&tensor.into_data(), false);
+    }
+}
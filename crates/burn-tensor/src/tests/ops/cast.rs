@@ -1,7 +1,8 @@
 #[burn_tensor_testgen::testgen(cast)]
 mod tests {
     use super::*;
-    use burn_tensor::{Bool, Tensor, TensorData};
+    use burn_tensor::backend::Backend;
+    use burn_tensor::{Bool, CastOptions, DType, Element, Rounding, Tensor, TensorData};
 
     #[test]
     fn cast_float_to_int() {
@@ -39,4 +40,51 @@ mod tests {
 
         tensor.into_data().assert_eq(&expected, false);
     }
+
+    #[test]
+    fn cast_with_should_round_negative_halves() {
+        let tensor = TestTensor::<1>::from([-2.5, -1.5, -0.5, 0.5, 1.5, 2.5, -2.6, 2.4]);
+
+        let cases = [
+            (Rounding::Nearest, [-2, -2, 0, 0, 2, 2, -3, 2]),
+            (Rounding::Trunc, [-2, -1, 0, 0, 1, 2, -2, 2]),
+            (Rounding::Floor, [-3, -2, -1, 0, 1, 2, -3, 2]),
+            (Rounding::Ceil, [-2, -1, 0, 1, 2, 3, -2, 3]),
+        ];
+
+        for (rounding, expected) in cases {
+            let output = tensor.clone().cast_with(CastOptions::new(rounding, true));
+
+            output
+                .into_data()
+                .assert_eq(&TensorData::from(expected), false);
+        }
+    }
+
+    #[test]
+    fn cast_with_should_saturate_at_the_int_bounds() {
+        let (min, max) = int_bounds();
+        let tensor = TestTensor::<1>::from([
+            i32::MAX as f32,
+            i32::MIN as f32,
+            f32::INFINITY,
+            f32::NEG_INFINITY,
+            f32::NAN,
+            -0.5,
+        ]);
+
+        let output = tensor.cast_with(CastOptions::default());
+
+        // `i32::MAX as f32` is 2^31, which only fits in 64-bit integers.
+        let expected = TensorData::from([(1i64 << 31).min(max), i32::MIN as i64, max, min, 0, 0]);
+        output.into_data().assert_eq(&expected, false);
+    }
+
+    fn int_bounds() -> (i64, i64) {
+        match <TestBackend as Backend>::IntElem::dtype() {
+            DType::I64 => (i64::MIN, i64::MAX),
+            DType::I32 => (i32::MIN as i64, i32::MAX as i64),
+            dtype => panic!("Unsupported int type {dtype:?}"),
+        }
+    }
 }
@@ -70,4 +70,58 @@ mod tests {
             .into_data()
             .assert_eq(&TensorData::from([[1, 1, 2], [3, 4, 4]]), false);
     }
+
+    #[test]
+    fn clamp_min_max_int_negative() {
+        let tensor = TestTensorInt::<2>::from([[-5, -1, 0], [1, 3, 6]]);
+
+        let output = tensor.clamp(-2, 3);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[-2, -1, 0], [1, 3, 3]]), false);
+    }
+
+    #[test]
+    fn clamp_tensor() {
+        let tensor = TestTensor::<2>::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+        let min = TestTensor::<2>::from([[1.0, 1.0, 3.0], [0.0, 0.0, 0.0]]);
+        let max = TestTensor::<2>::from([[2.0, 2.0, 4.0], [2.0, 5.0, 4.0]]);
+
+        let output = tensor.clamp_tensor(min, max);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[1.0, 1.0, 3.0], [2.0, 4.0, 4.0]]), false);
+    }
+
+    #[test]
+    fn clamp_tensor_int_indices_into_range() {
+        let indices = TestTensorInt::<2>::from([[-3, 0, 5], [2, 7, -1]]);
+        let min = TestTensorInt::<2>::zeros([2, 3], &Default::default());
+        let max = TestTensorInt::<2>::from([[2, 2, 2], [4, 4, 4]]);
+
+        let output = indices.clamp_tensor(min, max);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[0, 0, 2], [2, 4, 0]]), false);
+    }
+
+    #[test]
+    fn clamp_min_max_tensor_int_negative() {
+        let tensor = TestTensorInt::<2>::from([[-3, 0, 5]]);
+
+        let output_min = tensor
+            .clone()
+            .clamp_min_tensor(TestTensorInt::from([[-2, 1, 6]]));
+        let output_max = tensor.clamp_max_tensor(TestTensorInt::from([[-4, 0, 4]]));
+
+        output_min
+            .into_data()
+            .assert_eq(&TensorData::from([[-2, 1, 6]]), false);
+        output_max
+            .into_data()
+            .assert_eq(&TensorData::from([[-4, 0, 4]]), false);
+    }
 }
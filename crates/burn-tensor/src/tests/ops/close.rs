@@ -21,4 +21,83 @@ mod tests {
         let tensor2 = TestTensor::from([[0.0, 1.0, 0.0], [1.0, -1.0, 1.0]]) + 1e-9;
         assert!(tensor1.all_close(tensor2, None, None));
     }
+
+    #[test]
+    fn test_all_close_nan() {
+        let tensor1 = TestTensor::<1>::from([1.0, f32::NAN, 3.0]);
+        let tensor2 = TestTensor::<1>::from([1.0, f32::NAN, 3.0]);
+        assert!(!tensor1.clone().all_close(tensor2, None, None));
+        assert!(!tensor1.clone().all_close(tensor1, Some(1.0), Some(1.0)));
+    }
+
+    #[test]
+    fn test_all_close_inf() {
+        let tensor1 = TestTensor::<1>::from([1.0, f32::INFINITY, f32::NEG_INFINITY]);
+        let tensor2 = TestTensor::<1>::from([1.0, f32::INFINITY, f32::NEG_INFINITY]);
+        assert!(tensor1.clone().all_close(tensor2, None, None));
+
+        let tensor2 = TestTensor::<1>::from([1.0, f32::NEG_INFINITY, f32::NEG_INFINITY]);
+        assert!(!tensor1.clone().all_close(tensor2, None, None));
+
+        let tensor2 = TestTensor::<1>::from([1.0, 1e30, f32::NEG_INFINITY]);
+        assert!(!tensor1.all_close(tensor2, Some(1.0), Some(1.0)));
+    }
+
+    #[test]
+    fn test_all_close_zeros_rtol_only() {
+        let tensor1 = TestTensor::<1>::from([0.0, 0.0, 2.0]);
+        let tensor2 = TestTensor::<1>::from([0.0, -0.0, 2.1]);
+        assert!(tensor1.clone().all_close(tensor2, Some(0.1), Some(0.0)));
+
+        let tensor2 = TestTensor::<1>::from([1e-30, 0.0, 2.0]);
+        assert!(!tensor1.all_close(tensor2, Some(0.1), Some(0.0)));
+    }
+
+    #[test]
+    fn test_all_close_many_elements() {
+        let tensor1 = TestTensor::<2>::ones([40, 50], &Default::default());
+        assert!(tensor1.clone().all_close(tensor1.clone(), None, None));
+
+        let tensor2 = tensor1.clone().slice_assign(
+            [39..40, 49..50],
+            TestTensor::<2>::full([1, 1], 2.0, &Default::default()),
+        );
+        assert!(!tensor1.all_close(tensor2, None, None));
+    }
+
+    #[test]
+    fn test_all_close_int() {
+        let tensor1 = TestTensorInt::<1>::from([1, 2, 100]);
+        let tensor2 = TestTensorInt::<1>::from([1, 2, 101]);
+        assert!(!tensor1.clone().all_close(tensor2.clone(), None, None));
+        assert!(tensor1.all_close(tensor2, Some(0.01), Some(0.0)));
+    }
+
+    #[test]
+    fn test_is_close_nan_inf() {
+        let tensor1 = TestTensor::<1>::from([f32::NAN, f32::INFINITY, 0.0]);
+        let tensor2 = TestTensor::<1>::from([f32::NAN, f32::INFINITY, 0.0]);
+        let data_actual = tensor1.is_close(tensor2, Some(0.0), Some(0.0)).into_data();
+        let data_expected = TensorData::from([false, true, true]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn test_int_is_close_should_not_truncate_the_tolerances() {
+        let tensor1 = TestTensorInt::<1>::from([10, 10, 100]);
+        let tensor2 = TestTensorInt::<1>::from([11, 13, 95]);
+
+        // The relative tolerance of 0.1 allows a difference of 1.1 to 11 and of 9.5 to 95.
+        let data_actual = tensor1
+            .clone()
+            .is_close(tensor2.clone(), Some(0.1), Some(0.0))
+            .into_data();
+        let data_expected = TensorData::from([true, false, true]);
+        assert_eq!(data_expected, data_actual);
+
+        assert!(!tensor1
+            .clone()
+            .all_close(tensor2.clone(), Some(0.1), Some(0.0)));
+        assert!(tensor1.all_close(tensor2, Some(0.25), Some(0.0)));
+    }
 }
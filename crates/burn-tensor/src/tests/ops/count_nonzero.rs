@@ -0,0 +1,59 @@
+#[burn_tensor_testgen::testgen(count_nonzero)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn test_count_nonzero_float() {
+        let tensor = TestTensor::<2>::from([[0.0, 1.0, 0.0], [1.0, -1.0, f32::NAN]]);
+
+        tensor
+            .clone()
+            .count_nonzero(0)
+            .into_data()
+            .assert_eq(&TensorData::from([[1, 2, 1]]), false);
+
+        tensor
+            .count_nonzero(1)
+            .into_data()
+            .assert_eq(&TensorData::from([[1], [3]]), false);
+    }
+
+    #[test]
+    fn test_count_nonzero_int() {
+        let tensor = TestTensorInt::<3>::from([[[0, 1], [2, 0]], [[-3, 4], [0, 0]]]);
+
+        tensor
+            .clone()
+            .count_nonzero(0)
+            .into_data()
+            .assert_eq(&TensorData::from([[[1, 2], [1, 0]]]), false);
+
+        tensor
+            .clone()
+            .count_nonzero(1)
+            .into_data()
+            .assert_eq(&TensorData::from([[[1, 1]], [[1, 1]]]), false);
+
+        tensor
+            .count_nonzero(2)
+            .into_data()
+            .assert_eq(&TensorData::from([[[1], [1]], [[2], [0]]]), false);
+    }
+
+    #[test]
+    fn test_count_nonzero_bool() {
+        let tensor = TestTensorBool::<2>::from([[false, true, true], [false, false, true]]);
+
+        tensor
+            .clone()
+            .count_nonzero(0)
+            .into_data()
+            .assert_eq(&TensorData::from([[0, 1, 2]]), false);
+
+        tensor
+            .count_nonzero(1)
+            .into_data()
+            .assert_eq(&TensorData::from([[2], [1]]), false);
+    }
+}
@@ -89,4 +89,31 @@ mod tests {
             .into_data()
             .assert_eq(&TensorData::from([[0, 0, 1], [1, 2, 2]]), false);
     }
+
+    #[test]
+    fn should_round_div_ops_int_toward_zero() {
+        let tensor_1 = TestTensorInt::<2>::from([[-7, 7, -7], [7, -1, 0]]);
+        let tensor_2 = TestTensorInt::<2>::from([[2, -2, -2], [3, 2, -3]]);
+
+        let output = tensor_1 / tensor_2;
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[-3, -3, 3], [2, 0, 0]]), false);
+    }
+
+    #[test]
+    fn should_round_div_scalar_ops_int_toward_zero() {
+        let tensor = TestTensorInt::<1>::from([-7, -5, -1, 1, 5, 7]);
+
+        let output = tensor.clone() / 2;
+        let output_neg = tensor / -2;
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([-3, -2, 0, 0, 2, 3]), false);
+        output_neg
+            .into_data()
+            .assert_eq(&TensorData::from([3, 2, 0, 0, -2, -3]), false);
+    }
 }
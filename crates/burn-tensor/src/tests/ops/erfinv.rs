@@ -0,0 +1,41 @@
+#[burn_tensor_testgen::testgen(erfinv)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Tensor, TensorData};
+
+    #[test]
+    fn should_support_erfinv_ops() {
+        let data = TensorData::from([[-0.5, 0.0, 0.3], [0.9, 0.999, -0.9]]);
+        let tensor = Tensor::<TestBackend, 2>::from_data(data, &Default::default());
+
+        let output = tensor.erfinv();
+        let expected = TensorData::from([
+            [-0.47693628, 0.0, 0.27246273],
+            [1.16308707, 2.32675633, -1.16308707],
+        ]);
+
+        output.into_data().assert_approx_eq(&expected, 4);
+    }
+
+    #[test]
+    fn should_support_erfinv_ops_close_to_one() {
+        // `1 - 1e-7` rounded to the closest single precision float.
+        let data = TensorData::from([[-0.999_999_880_790_710_4, 0.999_999_880_790_710_4]]);
+        let tensor = Tensor::<TestBackend, 2>::from_data(data, &Default::default());
+
+        let output = tensor.erfinv();
+        let expected = TensorData::from([[-3.74392116, 3.74392116]]);
+
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn should_support_erf_of_erfinv() {
+        let data = TensorData::from([[-0.99, -0.25, 0.0], [0.1, 0.75, 0.9999]]);
+        let tensor = Tensor::<TestBackend, 2>::from_data(data.clone(), &Default::default());
+
+        let output = tensor.erfinv().erf();
+
+        output.into_data().assert_approx_eq(&data, 4);
+    }
+}
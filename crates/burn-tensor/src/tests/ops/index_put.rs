@@ -0,0 +1,158 @@
+#[burn_tensor_testgen::testgen(index_put)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn should_index_put_1d() {
+        let device = Default::default();
+        let tensor = TestTensor::<1>::from_data([0.0, 1.0, 2.0, 3.0], &device);
+        let indices = TestTensorInt::from_data([3, 1], &device);
+        let values = TestTensor::from_data([5.0, 6.0], &device);
+
+        let output = tensor.index_put(0, indices, values, false);
+        let expected = TensorData::from([0.0, 6.0, 2.0, 5.0]);
+
+        output.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    fn should_index_put_1d_accumulate() {
+        let device = Default::default();
+        let tensor = TestTensor::<1>::from_data([0.0, 1.0, 2.0, 3.0], &device);
+        let indices = TestTensorInt::from_data([3, 1, 3], &device);
+        let values = TestTensor::from_data([5.0, 6.0, 7.0], &device);
+
+        let output = tensor.index_put(0, indices, values, true);
+        let expected = TensorData::from([0.0, 7.0, 2.0, 15.0]);
+
+        output.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    fn should_index_put_2d_dim0() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::from_data([[0.0, 1.0], [2.0, 3.0], [4.0, 5.0]], &device);
+        let indices = TestTensorInt::from_data([2, 0], &device);
+        let values = TestTensor::from_data([[6.0, 7.0], [8.0, 9.0]], &device);
+
+        let output = tensor.index_put(0, indices, values, false);
+        let expected = TensorData::from([[8.0, 9.0], [2.0, 3.0], [6.0, 7.0]]);
+
+        output.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    fn should_index_put_2d_dim1() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::from_data([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]], &device);
+        let indices = TestTensorInt::from_data([2], &device);
+        let values = TestTensor::from_data([[6.0], [7.0]], &device);
+
+        let output = tensor.index_put(1, indices, values, false);
+        let expected = TensorData::from([[0.0, 1.0, 6.0], [3.0, 4.0, 7.0]]);
+
+        output.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    fn should_index_put_3d_dim1_accumulate() {
+        let device = Default::default();
+        let tensor = TestTensor::<3>::ones([2, 3, 2], &device);
+        let indices = TestTensorInt::from_data([0, 2, 0], &device);
+        let values = TestTensor::from_data(
+            [
+                [[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]],
+                [[7.0, 8.0], [9.0, 10.0], [11.0, 12.0]],
+            ],
+            &device,
+        );
+
+        let output = tensor.index_put(1, indices, values, true);
+        let expected = TensorData::from([
+            [[7.0, 9.0], [1.0, 1.0], [4.0, 5.0]],
+            [[19.0, 21.0], [1.0, 1.0], [10.0, 11.0]],
+        ]);
+
+        output.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    fn should_index_put_duplicates_with_last_write_wins() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::zeros([3, 2], &device);
+        let indices = TestTensorInt::from_data([1, 0, 1, 1, 0], &device);
+        let values = TestTensor::from_data(
+            [[1.0, 2.0], [3.0, 4.0], [5.0, 6.0], [7.0, 8.0], [9.0, 10.0]],
+            &device,
+        );
+
+        let output = tensor.index_put(0, indices, values, false);
+        let expected = TensorData::from([[9.0, 10.0], [7.0, 8.0], [0.0, 0.0]]);
+
+        output.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    fn should_index_put_int() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<2>::from_data([[0, 1, 2], [3, 4, 5]], &device);
+        let indices = TestTensorInt::from_data([0, 2, 0], &device);
+        let values = TestTensorInt::from_data([[6, 7, 8], [9, 10, 11]], &device);
+
+        let output = tensor
+            .clone()
+            .index_put(1, indices.clone(), values.clone(), false);
+        let expected = TensorData::from([[8, 1, 7], [11, 4, 10]]);
+        output.into_data().assert_eq(&expected, false);
+
+        let output = tensor.index_put(1, indices, values, true);
+        let expected = TensorData::from([[14, 1, 9], [23, 4, 15]]);
+        output.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    fn should_index_put_nd() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::zeros([2, 3], &device);
+        let rows = TestTensorInt::from_data([0, 1, 1, 0], &device);
+        let cols = TestTensorInt::from_data([2, 0, 0, 2], &device);
+        let values = TestTensor::from_data([1.0, 2.0, 3.0, 4.0], &device);
+
+        let output =
+            tensor
+                .clone()
+                .index_put_nd([rows.clone(), cols.clone()], values.clone(), false);
+        let expected = TensorData::from([[0.0, 0.0, 4.0], [3.0, 0.0, 0.0]]);
+        output.into_data().assert_eq(&expected, false);
+
+        let output = tensor.index_put_nd([rows, cols], values, true);
+        let expected = TensorData::from([[0.0, 0.0, 5.0], [5.0, 0.0, 0.0]]);
+        output.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    fn should_not_modify_other_references() {
+        let device = Default::default();
+        let tensor = TestTensor::<1>::from_data([0.0, 1.0, 2.0], &device);
+        let indices = TestTensorInt::from_data([1], &device);
+        let values = TestTensor::from_data([5.0], &device);
+
+        let _output = tensor.clone().index_put(0, indices, values, false);
+
+        tensor
+            .into_data()
+            .assert_eq(&TensorData::from([0.0, 1.0, 2.0]), false);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_values_shape_does_not_match() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::zeros([3, 2], &device);
+        let indices = TestTensorInt::from_data([1, 0], &device);
+        let values = TestTensor::<2>::zeros([2, 3], &device);
+
+        let _output = tensor.index_put(0, indices, values, false);
+    }
+}
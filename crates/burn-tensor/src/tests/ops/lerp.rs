@@ -0,0 +1,72 @@
+#[burn_tensor_testgen::testgen(lerp)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Distribution, TensorData};
+
+    #[test]
+    fn should_support_lerp_ops() {
+        let tensor = TestTensor::<2>::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+        let end = TestTensor::<2>::from([[2.0, 2.0, 2.0], [-3.0, 8.0, 5.0]]);
+        let weight = TestTensor::<2>::from([[0.5, 0.0, 1.0], [0.25, 0.75, 2.0]]);
+
+        let output = tensor.lerp(end, weight);
+        let expected = TensorData::from([[1.0, 1.0, 2.0], [1.5, 7.0, 5.0]]);
+
+        output.into_data().assert_approx_eq(&expected, 5);
+    }
+
+    #[test]
+    fn should_support_lerp_scalar_ops() {
+        let tensor = TestTensor::<2>::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+        let end = TestTensor::<2>::from([[2.0, 2.0, 2.0], [-3.0, 8.0, 5.0]]);
+
+        let output = tensor.lerp_scalar(end, 0.25);
+        let expected = TensorData::from([[0.5, 1.25, 2.0], [1.5, 5.0, 5.0]]);
+
+        output.into_data().assert_approx_eq(&expected, 5);
+    }
+
+    #[test]
+    fn lerp_should_match_the_composed_ops() {
+        let device = Default::default();
+        let tensor = TestTensor::<3>::random([2, 5, 7], Distribution::Default, &device);
+        let end = TestTensor::<3>::random([2, 5, 7], Distribution::Default, &device);
+        let weight = TestTensor::<3>::random([2, 5, 7], Distribution::Default, &device);
+
+        let expected = tensor.clone() + weight.clone() * (end.clone() - tensor.clone());
+        let output = tensor.lerp(end, weight);
+
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 4);
+    }
+
+    #[test]
+    fn lerp_should_broadcast_the_weight() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::random([4, 6], Distribution::Default, &device);
+        let end = TestTensor::<2>::random([4, 6], Distribution::Default, &device);
+        let weight = TestTensor::<2>::random([1, 6], Distribution::Default, &device);
+
+        let expected = tensor.clone() + weight.clone() * (end.clone() - tensor.clone());
+        let output = tensor.lerp(end, weight);
+
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 4);
+    }
+
+    #[test]
+    fn lerp_scalar_should_match_the_composed_ops() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::random([8, 9], Distribution::Default, &device);
+        let end = TestTensor::<2>::random([8, 9], Distribution::Default, &device);
+
+        let expected = tensor.clone() + (end.clone() - tensor.clone()).mul_scalar(0.1);
+        let output = tensor.lerp_scalar(end, 0.1);
+
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 4);
+    }
+}
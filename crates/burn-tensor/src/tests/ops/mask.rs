@@ -22,6 +22,84 @@ mod tests {
         output.into_data().assert_eq(&expected, false);
     }
 
+    #[test]
+    fn should_support_mask_where_broadcasting() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::from_data([[1.0], [2.0]], &device);
+        let mask = Tensor::<TestBackend, 2, Bool>::from_bool(
+            TensorData::from([[true, false, true]]),
+            &device,
+        );
+        let value = TestTensor::from_data([[10.0, 20.0, 30.0], [40.0, 50.0, 60.0]], &device);
+
+        let output = tensor.mask_where(mask, value);
+        let expected = TensorData::from([[10.0, 1.0, 30.0], [40.0, 2.0, 60.0]]);
+
+        output.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    fn should_support_mask_where_broadcasting_value_and_mask() {
+        let device = Default::default();
+        let tensor = TestTensor::from_data([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device);
+        let mask =
+            Tensor::<TestBackend, 2, Bool>::from_bool(TensorData::from([[true], [false]]), &device);
+        let value = TestTensor::<2>::from_data([[7.0, 8.0, 9.0]], &device);
+
+        let output = tensor.mask_where(mask, value);
+        let expected = TensorData::from([[7.0, 8.0, 9.0], [4.0, 5.0, 6.0]]);
+
+        output.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    fn mask_where_should_not_propagate_nan_from_unselected() {
+        let device = Default::default();
+        let tensor = TestTensor::from_data([[f32::NAN, 1.0], [f32::INFINITY, f32::NAN]], &device);
+        let mask = Tensor::<TestBackend, 2, Bool>::from_bool(
+            TensorData::from([[true, false], [true, true]]),
+            &device,
+        );
+        let value = TestTensor::from_data([[2.0, f32::NAN], [3.0, 4.0]], &device);
+
+        let output = tensor.mask_where(mask, value);
+
+        assert!(!output.clone().is_nan().any().into_scalar());
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[2.0, 1.0], [3.0, 4.0]]), false);
+    }
+
+    #[test]
+    fn mask_where_should_not_propagate_nan_with_broadcasting() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::from_data([[f32::NAN], [5.0]], &device);
+        let mask =
+            Tensor::<TestBackend, 2, Bool>::from_bool(TensorData::from([[true], [false]]), &device);
+        let value = TestTensor::<2>::from_data([[1.0, 2.0]], &device);
+
+        let output = tensor.mask_where(mask, value);
+
+        assert!(!output.clone().is_nan().any().into_scalar());
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[1.0, 2.0], [5.0, 5.0]]), false);
+    }
+
+    #[test]
+    #[should_panic]
+    fn mask_where_should_panic_when_shapes_cannot_broadcast() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::zeros([2, 3], &device);
+        let mask = Tensor::<TestBackend, 2, Bool>::from_bool(
+            TensorData::from([[true, false], [false, true]]),
+            &device,
+        );
+        let value = TestTensor::<2>::ones([2, 3], &device);
+
+        let _output = tensor.mask_where(mask, value);
+    }
+
     #[test]
     fn should_support_mask_fill_ops() {
         let device = Default::default();
@@ -54,6 +132,20 @@ mod tests {
         output.into_data().assert_eq(&expected, false);
     }
 
+    #[test]
+    fn should_support_int_mask_where_broadcasting() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 2, Int>::from_data([[1], [2]], &device);
+        let mask =
+            Tensor::<TestBackend, 2, Bool>::from_bool(TensorData::from([[false, true]]), &device);
+        let value = Tensor::<TestBackend, 2, Int>::from_data([[8, 9]], &device);
+
+        let output = tensor.mask_where(mask, value);
+        let expected = TensorData::from([[1, 9], [2, 9]]);
+
+        output.into_data().assert_eq(&expected, false);
+    }
+
     #[test]
     fn should_support_int_mask_fill_ops() {
         let device = Default::default();
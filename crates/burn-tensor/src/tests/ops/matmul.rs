@@ -1,7 +1,7 @@
 #[burn_tensor_testgen::testgen(matmul)]
 mod tests {
     use super::*;
-    use burn_tensor::{Int, Tensor, TensorData};
+    use burn_tensor::{Distribution, Int, Tensor, TensorData};
 
     #[test]
     fn test_matmul_d2() {
@@ -68,6 +68,70 @@ mod tests {
         tensor_3.into_data().assert_eq(&expected, false);
     }
 
+    #[test]
+    fn test_matmul_broadcast_rhs() {
+        let device = Default::default();
+        // [2, 2, 3]
+        let tensor_1 = TestTensor::<3>::from_floats(
+            [
+                [[1.0, 2.0, 0.0], [-1.0, 3.0, 2.0]],
+                [[4.0, 0.0, 1.0], [2.0, -2.0, 5.0]],
+            ],
+            &device,
+        );
+        // [1, 3, 2]
+        let tensor_2 = TestTensor::from_floats([[[2.0, 1.0], [0.0, -1.0], [3.0, 4.0]]], &device);
+
+        // [2, 2, 3] @ [1, 3, 2] -> [2, 2, 2]
+        let tensor_3 = tensor_1.matmul(tensor_2);
+        let expected = TensorData::from([[[2.0, -1.0], [4.0, 4.0]], [[11.0, 8.0], [19.0, 24.0]]]);
+
+        tensor_3.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    fn test_matmul_broadcast_attention() {
+        let device = Default::default();
+        // A single query shared by the heads, [batch, 1, 1, k].
+        let tensor_1 =
+            TestTensor::<4>::from_floats([[[[1.0, 0.0, 2.0]]], [[[-1.0, 3.0, 1.0]]]], &device);
+        // The transposed keys of each head, [batch, heads, k, n].
+        let tensor_2 = TestTensor::from_floats(
+            [
+                [
+                    [[1.0, 2.0], [0.0, 1.0], [3.0, -1.0]],
+                    [[2.0, 0.0], [1.0, 1.0], [-1.0, 2.0]],
+                ],
+                [
+                    [[0.0, 1.0], [2.0, 2.0], [1.0, 0.0]],
+                    [[3.0, -2.0], [1.0, 0.0], [0.0, 4.0]],
+                ],
+            ],
+            &device,
+        );
+
+        // [2, 1, 1, 3] @ [2, 2, 3, 2] -> [2, 2, 1, 2]
+        let tensor_3 = tensor_1.matmul(tensor_2);
+        let expected =
+            TensorData::from([[[[7.0, 0.0]], [[0.0, 4.0]]], [[[7.0, 5.0]], [[0.0, 6.0]]]]);
+
+        tensor_3.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    fn test_matmul_broadcast_like_repeat() {
+        let device = Default::default();
+        let tensor_1 = TestTensor::<4>::random([1, 3, 4, 5], Distribution::Default, &device);
+        let tensor_2 = TestTensor::<4>::random([2, 1, 5, 6], Distribution::Default, &device);
+
+        let tensor_3 = tensor_1.clone().matmul(tensor_2.clone());
+        let expected = tensor_1.repeat_dim(0, 2).matmul(tensor_2.repeat_dim(1, 3));
+
+        expected
+            .into_data()
+            .assert_approx_eq(&tensor_3.into_data(), 3);
+    }
+
     #[test]
     fn test_matmul_simple_1() {
         let device = Default::default();
@@ -196,6 +260,22 @@ mod tests {
         tensor_3.into_data().assert_eq(&expected, false);
     }
 
+    #[test]
+    fn test_int_matmul_broadcast() {
+        let device = Default::default();
+        let tensor_1 = TestTensorInt::<3>::from_ints([[[1, -7], [2, 3]]], &device);
+        let tensor_2 = TestTensorInt::<3>::from_ints(
+            [[[4, 7, 0], [2, -3, 1]], [[2, 5, -1], [6, 3, 4]]],
+            &device,
+        );
+
+        let tensor_3 = tensor_1.matmul(tensor_2);
+        let expected =
+            TensorData::from([[[-10, 28, -7], [14, 5, 3]], [[-40, -16, -29], [22, 19, 10]]]);
+
+        tensor_3.into_data().assert_eq(&expected, false);
+    }
+
     #[test]
     #[should_panic]
     fn should_panic_when_inner_dimensions_are_not_equal() {
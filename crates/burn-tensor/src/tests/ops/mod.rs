@@ -1,5 +1,7 @@
 mod abs;
+mod adam_step;
 mod add;
+mod addcmul;
 mod aggregation;
 mod all;
 mod any;
@@ -7,6 +9,9 @@ mod arange;
 mod arange_step;
 mod arg;
 mod argwhere_nonzero;
+mod associative_scan;
+mod axpby;
+mod bitwise;
 mod bool;
 mod cartesian_grid;
 mod cast;
@@ -15,17 +20,21 @@ mod chunk;
 mod clamp;
 mod close;
 mod cos;
+mod count_nonzero;
 mod create_like;
 mod div;
 mod erf;
+mod erfinv;
 mod exp;
 mod expand;
 mod flatten;
 mod flip;
 mod full;
 mod gather_scatter;
+mod index_put;
 mod init;
 mod iter_dim;
+mod lerp;
 mod log;
 mod log1p;
 mod map_comparison;
@@ -34,6 +43,7 @@ mod matmul;
 mod maxmin;
 mod movedim;
 mod mul;
+mod multinomial;
 mod nan;
 mod narrow;
 mod neg;
@@ -42,6 +52,7 @@ mod padding;
 mod permute;
 mod powf;
 mod powf_scalar;
+mod powi;
 mod random;
 mod recip;
 mod remainder;
@@ -53,12 +64,15 @@ mod sign;
 mod sin;
 mod slice;
 mod sort_argsort;
+mod sparse;
 mod sqrt;
 mod squeeze;
 mod stack;
 mod sub;
+mod take_along_dim;
 mod tanh;
 mod topk;
 mod transpose;
 mod tri;
 mod tri_mask;
+mod zero_size;
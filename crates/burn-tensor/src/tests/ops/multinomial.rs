@@ -0,0 +1,115 @@
+#[burn_tensor_testgen::testgen(multinomial)]
+mod tests {
+    use super::*;
+    use burn_tensor::{backend::Backend, Int, Tensor, TensorData};
+
+    fn frequencies(samples: Tensor<TestBackend, 2, Int>, num_categories: usize) -> Vec<f64> {
+        let samples = samples.into_data().iter::<i64>().collect::<Vec<_>>();
+        let mut counts = vec![0; num_categories];
+        for sample in samples.iter() {
+            counts[*sample as usize] += 1;
+        }
+
+        counts
+            .into_iter()
+            .map(|count| count as f64 / samples.len() as f64)
+            .collect()
+    }
+
+    fn assert_frequencies(actual: Vec<f64>, expected: &[f64]) {
+        for (actual, expected) in actual.iter().zip(expected) {
+            assert!(
+                (actual - expected).abs() < 0.04,
+                "Expected the frequencies {expected:?}, got {actual:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn should_sample_with_replacement_from_unnormalized_weights() {
+        let device = Default::default();
+        let weights = TestTensor::<2>::from_floats([[1.0, 2.0, 0.0, 7.0]], &device);
+
+        let samples = weights.multinomial(4000, true);
+
+        assert_eq!(samples.dims(), [1, 4000]);
+        assert_frequencies(frequencies(samples, 4), &[0.1, 0.2, 0.0, 0.7]);
+    }
+
+    #[test]
+    fn should_sample_a_single_category_per_row() {
+        let device = Default::default();
+        let weights = TestTensor::<1>::from_floats([0.5, 0.0, 1.5], &device)
+            .unsqueeze::<2>()
+            .repeat_dim(0, 4000);
+
+        let samples = weights.multinomial(1, false);
+
+        assert_eq!(samples.dims(), [4000, 1]);
+        assert_frequencies(frequencies(samples, 3), &[0.25, 0.0, 0.75]);
+    }
+
+    #[test]
+    fn should_sample_distinct_categories_without_replacement() {
+        let device = Default::default();
+        let weights = TestTensor::<2>::from_floats(
+            [[0.1, 0.2, 0.3, 0.4, 0.0], [5.0, 1.0, 0.0, 1.0, 1.0]],
+            &device,
+        );
+
+        let samples = weights.multinomial(4, false).into_data();
+
+        let samples = samples.iter::<i64>().collect::<Vec<_>>();
+        for (row, zero) in samples.chunks(4).zip([4, 2]) {
+            let mut sorted = row.to_vec();
+            sorted.sort();
+            sorted.dedup();
+            assert_eq!(sorted.len(), 4, "Duplicated categories in {row:?}");
+            assert!(!row.contains(&zero), "Zero weight category in {row:?}");
+        }
+    }
+
+    #[test]
+    fn should_sample_rows_without_weight_uniformly() {
+        let device = Default::default();
+        let weights = TestTensor::<2>::zeros([1, 4], &device);
+
+        let samples = weights.multinomial(4000, true);
+
+        assert_frequencies(frequencies(samples, 4), &[0.25, 0.25, 0.25, 0.25]);
+    }
+
+    #[test]
+    fn should_sample_from_a_single_distribution() {
+        let device = Default::default();
+        let weights = TestTensor::<1>::from_floats([0.0, 1.0, 0.0], &device);
+
+        let samples = weights.multinomial(3, true);
+
+        samples
+            .into_data()
+            .assert_eq(&TensorData::from([1, 1, 1]), false);
+    }
+
+    #[test]
+    fn same_seed_should_reproduce_the_samples() {
+        let device = Default::default();
+        let weights = TestTensor::<2>::from_floats([[1.0, 2.0, 3.0], [3.0, 2.0, 1.0]], &device);
+
+        TestBackend::seed(42);
+        let first = weights.clone().multinomial(64, true).into_data();
+        TestBackend::seed(42);
+        let second = weights.multinomial(64, true).into_data();
+
+        first.assert_eq(&second, true);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_drawing_more_samples_than_categories_without_replacement() {
+        let device = Default::default();
+        let weights = TestTensor::<1>::from_floats([1.0, 2.0], &device);
+
+        let _samples = weights.multinomial(3, false);
+    }
+}
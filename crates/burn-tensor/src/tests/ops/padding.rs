@@ -1,14 +1,14 @@
 #[burn_tensor_testgen::testgen(padding)]
 mod tests {
     use super::*;
-    use burn_tensor::{backend::Backend, Int, Numeric, Shape, Tensor, TensorData};
+    use burn_tensor::{backend::Backend, Int, Numeric, PadMode, Shape, Tensor, TensorData};
 
     #[test]
     fn padding_2d_test() {
         let unpadded_floats: [[f32; 3]; 2] = [[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]];
         let tensor = TestTensor::<2>::from(unpadded_floats);
 
-        let padded_tensor = tensor.pad((2, 2, 2, 2), 1.1);
+        let padded_tensor = tensor.pad(&[(2, 2), (2, 2)], PadMode::Constant(1.1));
 
         let expected = TensorData::from([
             [1.1, 1.1, 1.1, 1.1, 1.1, 1.1, 1.1],
@@ -26,7 +26,7 @@ mod tests {
         let unpadded_floats = [[[[0.0, 1.0], [2.0, 3.0], [4.0, 5.0]]]];
         let tensor = TestTensor::<4>::from(unpadded_floats);
 
-        let padded_tensor = tensor.pad((2, 2, 2, 2), 1.1);
+        let padded_tensor = tensor.pad(&[(0, 0), (0, 0), (2, 2), (2, 2)], PadMode::Constant(1.1));
 
         let expected = TensorData::from([[[
             [1.1, 1.1, 1.1, 1.1, 1.1, 1.1],
@@ -45,7 +45,7 @@ mod tests {
         let unpadded_floats = [[[[0.0, 1.0], [2.0, 3.0], [4.0, 5.0]]]];
         let tensor = TestTensor::<4>::from(unpadded_floats);
 
-        let padded_tensor = tensor.pad((2, 1, 4, 3), 1.1);
+        let padded_tensor = tensor.pad(&[(0, 0), (0, 0), (4, 3), (2, 1)], PadMode::Constant(1.1));
 
         let expected = TensorData::from([[[
             [1.1, 1.1, 1.1, 1.1, 1.1],
@@ -67,7 +67,7 @@ mod tests {
         let unpadded_ints = [[[[0, 1], [2, 3], [4, 5]]]];
 
         let tensor = TestTensorInt::<4>::from(unpadded_ints);
-        let padded_tensor = tensor.pad((2, 1, 4, 3), 6);
+        let padded_tensor = tensor.pad(&[(0, 0), (0, 0), (4, 3), (2, 1)], PadMode::Constant(6));
 
         let padded_primitive_data_expected = [[[
             [6, 6, 6, 6, 6],
@@ -95,4 +95,90 @@ mod tests {
         ]]]);
         padded_tensor.into_data().assert_eq(&expected, false);
     }
+
+    #[test]
+    fn padding_negative_amounts_should_crop() {
+        let tensor = TestTensor::<2>::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0], [6.0, 7.0, 8.0]]);
+
+        let cropped = tensor.pad(&[(-1, 0), (-1, -1)], PadMode::Constant(0.0));
+
+        cropped
+            .into_data()
+            .assert_eq(&TensorData::from([[4.0], [7.0]]), false);
+    }
+
+    #[test]
+    fn padding_mixed_amounts_constant() {
+        let tensor = TestTensor::<3>::from([
+            [[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]],
+            [[6.0, 7.0, 8.0], [9.0, 10.0, 11.0]],
+        ]);
+
+        let padded = tensor.pad(&[(0, -1), (1, -1), (-1, 2)], PadMode::Constant(-1.0));
+
+        let expected = TensorData::from([[[-1.0, -1.0, -1.0, -1.0], [1.0, 2.0, -1.0, -1.0]]]);
+        padded.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    fn padding_mixed_amounts_reflect() {
+        let tensor = TestTensor::<2>::from([[0.0, 1.0, 2.0, 3.0], [4.0, 5.0, 6.0, 7.0]]);
+
+        let padded = tensor.pad(&[(1, 0), (-1, 2)], PadMode::Reflect);
+
+        let expected = TensorData::from([
+            [5.0, 6.0, 7.0, 6.0, 5.0],
+            [1.0, 2.0, 3.0, 2.0, 1.0],
+            [5.0, 6.0, 7.0, 6.0, 5.0],
+        ]);
+        padded.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    fn padding_mixed_amounts_edge() {
+        let tensor = TestTensor::<2>::from([[0.0, 1.0, 2.0, 3.0], [4.0, 5.0, 6.0, 7.0]]);
+
+        let padded = tensor.pad(&[(0, 2), (2, -2)], PadMode::Edge);
+
+        let expected = TensorData::from([
+            [0.0, 0.0, 0.0, 1.0],
+            [4.0, 4.0, 4.0, 5.0],
+            [4.0, 4.0, 4.0, 5.0],
+            [4.0, 4.0, 4.0, 5.0],
+        ]);
+        padded.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    fn padding_mixed_amounts_integer() {
+        let tensor = TestTensorInt::<2>::from([[0, 1, 2], [3, 4, 5], [6, 7, 8]]);
+
+        let padded = tensor
+            .clone()
+            .pad(&[(-1, 1), (1, -1)], PadMode::Constant(9));
+        let reflected = tensor.pad(&[(1, -1), (-1, 1)], PadMode::Reflect);
+
+        padded
+            .into_data()
+            .assert_eq(&TensorData::from([[9, 3, 4], [9, 6, 7], [9, 9, 9]]), false);
+        reflected
+            .into_data()
+            .assert_eq(&TensorData::from([[4, 5, 4], [1, 2, 1], [4, 5, 4]]), false);
+    }
+
+    #[test]
+    #[should_panic]
+    fn padding_should_panic_when_cropping_more_than_the_dimension() {
+        let tensor = TestTensor::<2>::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+
+        let _ = tensor.pad(&[(0, 0), (-2, -2)], PadMode::Constant(0.0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn padding_reflect_should_panic_when_padding_is_too_large() {
+        let tensor = TestTensor::<2>::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+
+        let _ = tensor.pad(&[(2, 0), (0, 0)], PadMode::Reflect);
+    }
 }
@@ -0,0 +1,37 @@
+#[burn_tensor_testgen::testgen(powi)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn should_support_powi_ops_int() {
+        let tensor = TestTensorInt::<2>::from([[-2, -2, 3, -1], [-1, 1, 2, 0]]);
+        let pow = TestTensorInt::<2>::from([[3, 2, 2, -3], [-2, -5, -1, 4]]);
+
+        let output = tensor.powi(pow);
+
+        // Negative powers are rounded toward zero, so only 1 and -1 keep a non-zero power.
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[-8, 4, 9, -1], [1, 1, 0, 0]]), false);
+    }
+
+    #[test]
+    fn should_support_powi_scalar_ops_int() {
+        let tensor = TestTensorInt::<1>::from([-3, -2, -1, 0, 1, 2]);
+
+        let output = tensor.clone().powi_scalar(3);
+        let output_zero = tensor.clone().powi_scalar(0);
+        let output_neg = tensor.powi_scalar(-1);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([-27, -8, -1, 0, 1, 8]), false);
+        output_zero
+            .into_data()
+            .assert_eq(&TensorData::from([1, 1, 1, 1, 1, 1]), false);
+        output_neg
+            .into_data()
+            .assert_eq(&TensorData::from([0, 0, -1, 0, 1, 0]), false);
+    }
+}
@@ -100,4 +100,40 @@ mod tests {
 
         output.into_data().assert_approx_eq(&expected, 3);
     }
+
+    #[test]
+    fn should_support_euclidean_remainder_scalar_int() {
+        let tensor = TestTensorInt::<1>::from([-7, -3, -1, 0, 1, 3, 7]);
+
+        let output = tensor.clone().remainder_scalar(3);
+        let output_neg = tensor.remainder_scalar(-3);
+        let expected = TensorData::from([2, 0, 2, 0, 1, 0, 1]);
+
+        output.into_data().assert_eq(&expected, false);
+        output_neg.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    fn should_support_euclidean_remainder_int() {
+        let lhs = TestTensorInt::<2>::from([[-7, 7, -7, 7], [-6, 5, 0, -1]]);
+        let rhs = TestTensorInt::<2>::from([[3, 3, -3, -3], [4, -4, 5, 2]]);
+
+        let output = lhs.remainder(rhs);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[2, 1, 2, 1], [2, 1, 0, 1]]), false);
+    }
+
+    #[test]
+    fn should_wrap_indices_with_remainder_int_broadcast() {
+        let indices = TestTensorInt::<2>::from([[-5, -4, -3, -2, -1, 0, 1]]);
+        let length = TestTensorInt::<2>::from([[3]]);
+
+        let output = indices.remainder(length);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[1, 2, 0, 1, 2, 0, 1]]), false);
+    }
 }
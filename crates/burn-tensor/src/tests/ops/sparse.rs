@@ -0,0 +1,130 @@
+#[burn_tensor_testgen::testgen(sparse)]
+mod tests {
+    use super::*;
+    use burn_tensor::{sparse::SparseTensor, Distribution, TensorData};
+
+    /// A matrix of shape `[4, 3]` whose second row is empty, with unsorted entries and a
+    /// duplicated coordinate.
+    fn sparse() -> SparseTensor<TestBackend> {
+        let device = Default::default();
+        let indices = TestTensorInt::from_ints([[3, 0, 2, 0, 3], [1, 2, 0, 2, 0]], &device);
+        let values = TestTensor::from_floats([2.0, -1.0, 4.0, 3.0, 0.5], &device);
+
+        SparseTensor::new(indices, values, [4, 3])
+    }
+
+    #[test]
+    fn should_sum_the_duplicated_coordinates_to_dense() {
+        let dense = sparse().to_dense();
+
+        dense.into_data().assert_eq(
+            &TensorData::from([
+                [0.0, 0.0, 2.0],
+                [0.0, 0.0, 0.0],
+                [4.0, 0.0, 0.0],
+                [0.5, 2.0, 0.0],
+            ]),
+            false,
+        );
+    }
+
+    #[test]
+    fn should_spmm_like_the_dense_matmul() {
+        let device = Default::default();
+        let sparse = sparse();
+        let dense = TestTensor::from_floats([[1.0, 2.0], [3.0, -1.0], [0.5, 4.0]], &device);
+
+        let actual = sparse.clone().spmm(dense.clone());
+        let expected = sparse.to_dense().matmul(dense);
+
+        expected
+            .into_data()
+            .assert_approx_eq(&actual.into_data(), 3);
+    }
+
+    #[test]
+    fn should_spmm_random_patterns_like_the_dense_matmul() {
+        let device = Default::default();
+        let mask = TestTensor::<2>::random([17, 9], Distribution::Uniform(0.0, 1.0), &device)
+            .lower_elem(0.3);
+        // Empty a few rows, including the first and the last ones.
+        let rows = TestTensor::<1>::from_floats(
+            [
+                0.0, 1.0, 1.0, 0.0, 1.0, 1.0, 1.0, 1.0, 0.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 0.0,
+            ],
+            &device,
+        );
+        let values = TestTensor::<2>::random([17, 9], Distribution::Uniform(1.0, 2.0), &device)
+            * rows.reshape([17, 1]);
+        let mask = mask.bool_and(values.clone().not_equal_elem(0.0));
+        let sparse = SparseTensor::from_mask(values, mask);
+        let dense = TestTensor::<2>::random([9, 5], Distribution::Default, &device);
+
+        let actual = sparse.clone().spmm(dense.clone());
+        let expected = sparse.to_dense().matmul(dense);
+
+        expected
+            .into_data()
+            .assert_approx_eq(&actual.into_data(), 3);
+    }
+
+    #[test]
+    fn should_sddmm_like_the_sampled_dense_matmul() {
+        let device = Default::default();
+        let sparse = sparse();
+        let lhs = TestTensor::<2>::random([4, 6], Distribution::Default, &device);
+        let rhs = TestTensor::<2>::random([6, 3], Distribution::Default, &device);
+
+        let actual = sparse.clone().sddmm(lhs.clone(), rhs.clone()).to_dense();
+        let expected = sparse.to_dense() * lhs.matmul(rhs);
+
+        expected
+            .into_data()
+            .assert_approx_eq(&actual.into_data(), 3);
+    }
+
+    #[test]
+    fn should_transpose_and_scale_like_the_dense_matrix() {
+        let device = Default::default();
+        let sparse = sparse();
+        let scale = TestTensor::<2>::random([3, 4], Distribution::Default, &device);
+
+        let actual = sparse
+            .clone()
+            .transpose()
+            .mul_scalar(2.0)
+            .mul_dense(scale.clone())
+            .to_dense();
+        let expected = sparse.to_dense().transpose() * 2.0 * scale;
+
+        expected
+            .into_data()
+            .assert_approx_eq(&actual.into_data(), 3);
+    }
+
+    #[test]
+    fn should_round_trip_the_non_zero_values() {
+        let device = Default::default();
+        let tensor = TestTensor::from_floats(
+            [[0.0, 1.5, 0.0], [0.0, 0.0, 0.0], [-2.0, 0.0, 3.0]],
+            &device,
+        );
+
+        let sparse = SparseTensor::from_dense(tensor.clone());
+
+        assert_eq!(sparse.num_entries(), 3);
+        sparse
+            .to_dense()
+            .into_data()
+            .assert_eq(&tensor.into_data(), false);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_the_inner_dimensions_differ() {
+        let device = Default::default();
+        let dense = TestTensor::<2>::zeros([4, 2], &device);
+
+        sparse().spmm(dense);
+    }
+}
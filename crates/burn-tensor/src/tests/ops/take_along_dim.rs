@@ -0,0 +1,105 @@
+#[burn_tensor_testgen::testgen(take_along_dim)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Tensor, TensorData};
+
+    #[test]
+    fn should_take_along_dim_with_same_rank_indices() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::from_floats([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]], &device);
+        let indices = TestTensorInt::from_ints([[1, 2], [0, 0]], &device);
+
+        let output = tensor.take_along_dim(1, indices);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[1.0, 2.0], [3.0, 3.0]]), false);
+    }
+
+    #[test]
+    fn should_take_along_dim_with_lower_rank_indices() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::from_floats([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]], &device);
+        let indices = TestTensorInt::<1>::from_ints([2, 0], &device);
+
+        let output = tensor.take_along_dim(1, indices);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[2.0, 0.0], [5.0, 3.0]]), false);
+    }
+
+    #[test]
+    fn should_take_along_dim_with_broadcast_indices() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<1>::arange(0..12, &device)
+            .float()
+            .reshape([2, 2, 3]);
+        let indices = TestTensorInt::from_ints([[[2, 0]], [[1, 1]]], &device);
+
+        let output = tensor.take_along_dim(2, indices);
+
+        output.into_data().assert_eq(
+            &TensorData::from([[[2.0, 0.0], [5.0, 3.0]], [[7.0, 7.0], [10.0, 10.0]]]),
+            false,
+        );
+    }
+
+    #[test]
+    fn should_take_along_dim_0_int() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<2>::from_ints([[1, 2], [3, 4], [5, 6]], &device);
+        let indices = TestTensorInt::from_ints([[2], [0]], &device);
+
+        let output = tensor.take_along_dim(0, indices);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[5, 6], [1, 2]]), false);
+    }
+
+    #[test]
+    fn take_along_dim_should_match_gather_with_expanded_indices() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<1>::arange(0..24, &device)
+            .float()
+            .reshape([2, 3, 4]);
+        let indices = TestTensorInt::<2>::from_ints([[3, 1, 1, 0, 2]], &device);
+
+        let output = tensor.clone().take_along_dim(2, indices.clone());
+        let expected = tensor.gather(2, indices.expand([2, 3, 5]));
+
+        output.into_data().assert_eq(&expected.into_data(), false);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_indices_are_not_broadcastable() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::from_floats([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]], &device);
+        let indices = TestTensorInt::from_ints([[0], [1], [2]], &device);
+
+        tensor.take_along_dim(1, indices);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_indices_dont_have_the_dim() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::from_floats([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]], &device);
+        let indices = TestTensorInt::<1>::from_ints([0, 1, 2], &device);
+
+        tensor.take_along_dim(0, indices);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic = "can't be negative"]
+    fn should_panic_when_indices_are_negative() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::from_floats([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]], &device);
+        let indices = TestTensorInt::<1>::from_ints([1, -1], &device);
+
+        tensor.take_along_dim(1, indices);
+    }
+}
@@ -0,0 +1,175 @@
+#[burn_tensor_testgen::testgen(zero_size)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+    use burn_tensor::module::{conv1d, conv2d};
+    use burn_tensor::ops::ConvOptions;
+    use burn_tensor::{Int, Tensor, TensorData};
+
+    #[test]
+    fn should_create_zero_sized_tensors() {
+        let device = Default::default();
+
+        let zeros = TestTensor::<2>::zeros([0, 3], &device);
+        let empty = TestTensor::<3>::empty([2, 0, 4], &device);
+        let data = TestTensor::<2>::from_data(TensorData::new(Vec::<f32>::new(), [3, 0]), &device);
+
+        assert_eq!(zeros.dims(), [0, 3]);
+        assert_eq!(empty.dims(), [2, 0, 4]);
+        assert_eq!(data.dims(), [3, 0]);
+        assert_eq!(zeros.into_data().shape, vec![0, 3]);
+        assert_eq!(data.into_data().num_elements(), 0);
+    }
+
+    #[test]
+    fn should_support_elementwise_ops_on_zero_sized_tensors() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::zeros([0, 3], &device);
+
+        let output = tensor.clone().exp().add_scalar(1.0).mul(tensor.clone());
+        let mask = tensor.clone().equal_elem(0.0);
+
+        assert_eq!(output.dims(), [0, 3]);
+        assert_eq!(mask.into_data().num_elements(), 0);
+        assert_eq!(output.into_data().num_elements(), 0);
+    }
+
+    #[test]
+    fn should_broadcast_to_zero_sized_dims() {
+        let device = Default::default();
+        let lhs = TestTensor::<2>::zeros([0, 3], &device);
+        let rhs = TestTensor::<2>::from_data([[1.0, 2.0, 3.0]], &device);
+
+        let output = rhs.clone() + lhs.clone();
+        let mask = rhs.lower(lhs);
+
+        assert_eq!(output.dims(), [0, 3]);
+        assert_eq!(mask.dims(), [0, 3]);
+    }
+
+    #[test]
+    fn should_sum_zero_length_dim_to_zeros() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::zeros([0, 3], &device);
+
+        let output = tensor.clone().sum_dim(0);
+        let sum = tensor.sum();
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[0.0, 0.0, 0.0]]), false);
+        sum.into_data().assert_eq(&TensorData::from([0.0]), false);
+    }
+
+    #[test]
+    fn should_mean_zero_length_dim_to_nan() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::zeros([0, 3], &device);
+
+        let output = tensor.clone().mean_dim(0);
+        let mean = tensor.mean();
+
+        assert_eq!(output.dims(), [1, 3]);
+        assert!(output.is_nan().all().into_scalar());
+        assert!(mean.is_nan().into_scalar());
+    }
+
+    #[test]
+    fn should_reduce_int_zero_length_dim() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 2, Int>::zeros([2, 0], &device);
+
+        let output = tensor.clone().sum_dim(1);
+        let sum = tensor.sum();
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[0], [0]]), false);
+        sum.into_data().assert_eq(&TensorData::from([0]), false);
+    }
+
+    #[test]
+    fn should_matmul_zero_inner_dim_to_zeros() {
+        let device = Default::default();
+        let lhs = TestTensor::<2>::zeros([2, 0], &device);
+        let rhs = TestTensor::<2>::zeros([0, 3], &device);
+
+        let output = lhs.matmul(rhs);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[0.0, 0.0, 0.0], [0.0, 0.0, 0.0]]), false);
+    }
+
+    #[test]
+    fn should_matmul_zero_sized_output() {
+        let device = Default::default();
+        let lhs = TestTensor::<3>::zeros([2, 0, 4], &device);
+        let rhs = TestTensor::<3>::ones([2, 4, 3], &device);
+
+        let output = lhs.matmul(rhs);
+
+        assert_eq!(output.dims(), [2, 0, 3]);
+    }
+
+    #[test]
+    fn should_cat_with_zero_sized_member() {
+        let device = Default::default();
+        let tensor_1 = TestTensor::<2>::from_data([[1.0, 2.0]], &device);
+        let tensor_2 = TestTensor::<2>::zeros([0, 2], &device);
+        let tensor_3 = TestTensor::<2>::from_data([[3.0, 4.0]], &device);
+
+        let output = TestTensor::cat(vec![tensor_1, tensor_2, tensor_3], 0);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[1.0, 2.0], [3.0, 4.0]]), false);
+    }
+
+    #[test]
+    fn should_cat_zero_sized_tensors() {
+        let device = Default::default();
+        let tensor_1 = TestTensor::<2>::zeros([0, 2], &device);
+        let tensor_2 = TestTensor::<2>::zeros([0, 2], &device);
+
+        let output = TestTensor::cat(vec![tensor_1.clone(), tensor_2.clone()], 0);
+        let stacked = TestTensor::<2>::stack::<3>(vec![tensor_1, tensor_2], 0);
+
+        assert_eq!(output.dims(), [0, 2]);
+        assert_eq!(stacked.dims(), [2, 0, 2]);
+    }
+
+    #[test]
+    fn should_slice_zero_sized_tensors() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::zeros([0, 4], &device);
+
+        let output = tensor.slice([0..0, 1..3]);
+
+        assert_eq!(output.dims(), [0, 2]);
+    }
+
+    #[test]
+    fn should_conv_zero_sized_batch() {
+        let device = Default::default();
+        let weight = TestTensor::<4>::ones([3, 2, 3, 3], &device);
+        let bias = TestTensor::<1>::ones([3], &device);
+        let x = TestTensor::<4>::zeros([0, 2, 5, 5], &device);
+
+        let output = conv2d(
+            x,
+            weight,
+            Some(bias),
+            ConvOptions::new([1, 1], [1, 1], [1, 1], 1),
+        );
+
+        assert_eq!(output.dims(), [0, 3, 5, 5]);
+
+        let weight = TestTensor::<3>::ones([3, 2, 3], &device);
+        let x = TestTensor::<3>::zeros([0, 2, 5], &device);
+
+        let output = conv1d(x, weight, None, ConvOptions::new([1], [0], [1], 1));
+
+        assert_eq!(output.dims(), [0, 3, 3]);
+    }
+}
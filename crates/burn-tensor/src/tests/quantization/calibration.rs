@@ -2,7 +2,7 @@
 mod tests {
     use super::*;
     use burn_tensor::{
-        quantization::{Calibration, MinMaxCalibration, QuantizationType},
+        quantization::{Calibration, MinMaxCalibration, PerChannelMinMaxCalibration},
         Tensor, TensorData,
     };
 
@@ -23,4 +23,24 @@ mod tests {
             .into_data()
             .assert_eq(&TensorData::from([0.5]), false);
     }
+
+    #[test]
+    fn per_channel_min_max_calibration_range() {
+        let tensor = Tensor::<TestBackend, 3>::from_floats(
+            [[[-1.8, 0.5], [2.0, 1.0]], [[0.0, -3.0], [0.25, 4.0]]],
+            &Default::default(),
+        );
+        let calibration = PerChannelMinMaxCalibration { axis: 2 };
+
+        let range = calibration.compute_range(&tensor);
+
+        range
+            .min
+            .into_data()
+            .assert_eq(&TensorData::from([-1.8, -3.0]), false);
+        range
+            .max
+            .into_data()
+            .assert_eq(&TensorData::from([2.0, 4.0]), false);
+    }
 }
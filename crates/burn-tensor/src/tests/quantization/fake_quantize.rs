@@ -0,0 +1,52 @@
+#[burn_tensor_testgen::testgen(fake_quantize)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Tensor, TensorData};
+
+    #[test]
+    fn should_fake_quantize_per_tensor() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 1>::from_floats(
+            [-3.0, -2.5, -0.6, 0.0, 0.25, 0.75, 1.0, 5.0],
+            &device,
+        );
+        let scale = Tensor::from_floats([0.5], &device);
+        let zero_point = Tensor::from_floats([1.0], &device);
+
+        let output = tensor.fake_quantize(scale, zero_point, -4, 3, None);
+
+        // The values are clamped to the quantized range, and `1.5` and `2.5` are rounded to `2`.
+        let expected = TensorData::from([-2.5, -2.5, -0.5, 0.0, 0.5, 0.5, 1.0, 1.0]);
+        output.into_data().assert_approx_eq(&expected, 5);
+    }
+
+    #[test]
+    fn should_fake_quantize_per_channel() {
+        let device = Default::default();
+        let tensor =
+            Tensor::<TestBackend, 2>::from_floats([[0.14, -0.26, 0.96], [0.5, -7.0, 3.2]], &device);
+        let scale = Tensor::from_floats([0.1, 1.0], &device);
+        let zero_point = Tensor::from_floats([0.0, -2.0], &device);
+
+        let output = tensor.fake_quantize(scale, zero_point, -8, 7, Some(0));
+
+        let expected = TensorData::from([[0.1, -0.3, 0.7], [0.0, -6.0, 3.0]]);
+        output.into_data().assert_approx_eq(&expected, 5);
+    }
+
+    #[test]
+    fn should_fake_quantize_the_channels_of_the_last_dimension() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 3>::from_floats(
+            [[[0.3, 0.3], [-1.3, 2.6]], [[4.4, -0.4], [1.0, -9.0]]],
+            &device,
+        );
+        let scale = Tensor::from_floats([1.0, 2.0], &device);
+        let zero_point = Tensor::from_floats([0.0, 0.0], &device);
+
+        let output = tensor.fake_quantize(scale, zero_point, -2, 1, Some(2));
+
+        let expected = TensorData::from([[[0.0, 0.0], [-1.0, 2.0]], [[1.0, 0.0], [1.0, -4.0]]]);
+        output.into_data().assert_approx_eq(&expected, 5);
+    }
+}
@@ -1,2 +1,3 @@
 mod calibration;
+mod fake_quantize;
 mod scheme;
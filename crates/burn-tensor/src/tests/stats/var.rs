@@ -64,4 +64,64 @@ mod tests {
         var.into_data().assert_approx_eq(&var_expected, 3);
         mean.into_data().assert_approx_eq(&mean_expected, 3);
     }
+
+    #[test]
+    fn test_var_dims_with_correction() {
+        let tensor = TestTensor::<2>::from_data(
+            [[0.5, 1.8, 0.2, -2.0], [3.0, -4.0, 5.0, 0.0]],
+            &Default::default(),
+        );
+
+        let biased = tensor.clone().var_dims(&[1], 0);
+        let unbiased = tensor.clone().var_dims(&[1], 1);
+        let std = tensor.std_dims(&[1], 1);
+
+        let biased_expected = TensorData::from([[1.86688], [11.5]]).convert::<FloatElem>();
+        let unbiased_expected = TensorData::from([[2.4892], [15.3333]]).convert::<FloatElem>();
+        let std_expected = TensorData::from([[1.57771], [3.91578]]).convert::<FloatElem>();
+
+        biased.into_data().assert_approx_eq(&biased_expected, 3);
+        unbiased.into_data().assert_approx_eq(&unbiased_expected, 3);
+        std.into_data().assert_approx_eq(&std_expected, 3);
+    }
+
+    #[test]
+    fn test_var_mean_dims_two_dims() {
+        let tensor = TestTensor::<3>::from_data(
+            [[[0.5, 1.8], [0.2, -2.0]], [[3.0, -4.0], [5.0, 0.0]]],
+            &Default::default(),
+        );
+
+        let (var, mean) = tensor.clone().var_mean_dims(&[0, 2], 1);
+        let std = tensor.std_dims(&[2, 0], 0);
+
+        let var_expected = TensorData::from([[[9.35583], [8.82667]]]).convert::<FloatElem>();
+        let mean_expected = TensorData::from([[[0.325], [0.8]]]).convert::<FloatElem>();
+        let std_expected = TensorData::from([[[2.64894], [2.57294]]]).convert::<FloatElem>();
+
+        assert_eq!(var.dims(), [1, 2, 1]);
+        var.into_data().assert_approx_eq(&var_expected, 3);
+        mean.into_data().assert_approx_eq(&mean_expected, 3);
+        std.into_data().assert_approx_eq(&std_expected, 3);
+    }
+
+    #[test]
+    fn test_var_dims_equal_values_is_zero() {
+        let tensor = TestTensor::<2>::full([3, 7], 0.1, &Default::default());
+
+        let (var, mean) = tensor.var_mean_dims(&[0, 1], 1);
+
+        let expected = TensorData::from([[0.0]]).convert::<FloatElem>();
+        var.into_data().assert_eq(&expected, true);
+        mean.into_data()
+            .assert_approx_eq(&TensorData::from([[0.1]]).convert::<FloatElem>(), 5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_var_dims_should_panic_when_the_correction_leaves_no_divisor() {
+        let tensor = TestTensor::<2>::from_data([[0.5, 1.8]], &Default::default());
+
+        let _var = tensor.var_dims(&[0], 1);
+    }
 }
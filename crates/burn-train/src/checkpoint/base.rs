@@ -45,3 +45,18 @@ where
     /// The record.
     fn restore(&self, epoch: usize, device: &B::Device) -> Result<R, CheckpointerError>;
 }
+
+/// How the [checkpointers](Checkpointer) of a [learner](crate::learner::Learner) number the
+/// records they save.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CheckpointNaming {
+    /// Records are numbered with the epoch they are saved at, once per epoch.
+    #[default]
+    Epoch,
+    /// Records are numbered with the global step they are saved at, which is the number of
+    /// optimizer steps done since the start of the training.
+    ///
+    /// With [step validation](crate::learner::LearnerBuilder::validate_every_n_steps), records
+    /// are also saved after every validation run in the middle of an epoch.
+    GlobalStep,
+}
@@ -18,7 +18,8 @@ impl<FR> FileCheckpointer<FR> {
     ///
     /// # Arguments
     ///
-    /// * `recorder` - The file recorder.
+    /// * `recorder` - The file recorder, such as the
+    ///   [sharded file recorder](burn_core::record::ShardedFileRecorder) for large models.
     /// * `directory` - The directory to save the checkpoints.
     /// * `name` - The name of the checkpoint.
     pub fn new(recorder: FR, directory: impl AsRef<Path>, name: &str) -> Self {
@@ -70,15 +71,13 @@ where
     }
 
     fn delete(&self, epoch: usize) -> Result<(), CheckpointerError> {
-        let file_to_remove = format!(
-            "{}.{}",
-            self.path_for_epoch(epoch).display(),
-            FR::file_extension(),
-        );
+        let files_to_remove = self.recorder.record_files(self.path_for_epoch(epoch));
 
-        if std::path::Path::new(&file_to_remove).exists() {
-            log::info!("Removing checkpoint {}", file_to_remove);
-            std::fs::remove_file(file_to_remove).map_err(CheckpointerError::IOError)?;
+        for file_to_remove in files_to_remove {
+            if file_to_remove.exists() {
+                log::info!("Removing checkpoint {}", file_to_remove.display());
+                std::fs::remove_file(file_to_remove).map_err(CheckpointerError::IOError)?;
+            }
         }
 
         Ok(())
@@ -1,6 +1,8 @@
-use crate::checkpoint::{Checkpointer, CheckpointingAction, CheckpointingStrategy};
+use crate::checkpoint::{
+    CheckpointNaming, Checkpointer, CheckpointingAction, CheckpointingStrategy,
+};
 use crate::components::LearnerComponents;
-use crate::learner::EarlyStoppingStrategy;
+use crate::learner::{EarlyStoppingStrategy, UnfreezeSchedule};
 use crate::metric::store::EventStoreClient;
 use crate::LearnerSummaryConfig;
 use burn_core::lr_scheduler::LrScheduler;
@@ -8,6 +10,9 @@ use burn_core::module::Module;
 use burn_core::optim::Optimizer;
 use burn_core::tensor::backend::Backend;
 use burn_core::tensor::Device;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
 use std::rc::Rc;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
@@ -22,85 +27,178 @@ pub struct Learner<LC: LearnerComponents> {
     pub(crate) num_epochs: usize,
     pub(crate) checkpoint: Option<usize>,
     pub(crate) grad_accumulation: Option<usize>,
+    pub(crate) validation_steps: Option<usize>,
     pub(crate) checkpointer: Option<LearnerCheckpointer<LC>>,
     pub(crate) devices: Vec<<LC::Backend as Backend>::Device>,
     pub(crate) interrupter: TrainingInterrupter,
     pub(crate) early_stopping: Option<Box<dyn EarlyStoppingStrategy>>,
+    pub(crate) unfreeze_schedule: Option<UnfreezeSchedule>,
     pub(crate) event_processor: LC::EventProcessor,
     pub(crate) event_store: Rc<EventStoreClient>,
     pub(crate) summary: Option<LearnerSummaryConfig>,
 }
 
+/// Where the training was when the records of a checkpoint were saved.
+///
+/// The records numbered by [global step](CheckpointNaming::GlobalStep) are saved with their
+/// position, since the epoch and the validation index can't be recovered from the step.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct TrainingPosition {
+    /// The epoch the training resumes with.
+    pub epoch: usize,
+    /// The number of items of the epoch already trained on, which are skipped when resuming.
+    pub items: usize,
+    /// The index of the last validation.
+    pub index: usize,
+    /// The global step of the last validation, when it ran in the middle of the epoch.
+    pub last_step: Option<usize>,
+}
+
+impl TrainingPosition {
+    /// The start of the training.
+    pub(crate) fn start() -> Self {
+        Self {
+            epoch: 1,
+            items: 0,
+            index: 0,
+            last_step: None,
+        }
+    }
+
+    /// The start of the epoch following the one of the records numbered by epoch.
+    pub(crate) fn after_epoch(epoch: usize) -> Self {
+        Self {
+            epoch: epoch + 1,
+            items: 0,
+            index: epoch,
+            last_step: None,
+        }
+    }
+}
+
 #[derive(new)]
 pub(crate) struct LearnerCheckpointer<LC: LearnerComponents> {
     model: LC::CheckpointerModel,
     optim: LC::CheckpointerOptimizer,
     lr_scheduler: LC::CheckpointerLrScheduler,
     strategy: LC::CheckpointerStrategy,
+    naming: CheckpointNaming,
+    /// The directory of the positions of the records numbered by global step.
+    directory: PathBuf,
+    /// The number of the record saved for each index given to the strategy.
+    #[new(default)]
+    numbers: HashMap<usize, usize>,
 }
 
 impl<LC: LearnerComponents> LearnerCheckpointer<LC> {
+    /// Run the checkpointing strategy for the index of the position, which is the epoch or the
+    /// validation run it is called after, and save the records under the given number.
     pub(crate) fn checkpoint(
         &mut self,
         model: &LC::Model,
         optim: &LC::Optimizer,
         scheduler: &LC::LrScheduler,
-        epoch: usize,
+        position: TrainingPosition,
+        number: usize,
         store: &EventStoreClient,
     ) {
-        let actions = self.strategy.checkpointing(epoch, store);
+        let index = position.index;
+        let actions = self.strategy.checkpointing(index, store);
 
         for action in actions {
             match action {
-                CheckpointingAction::Delete(epoch) => {
+                CheckpointingAction::Delete(index) => {
+                    let number = match self.numbers.remove(&index) {
+                        Some(number) => number,
+                        // Records saved before resuming the training are numbered by epoch.
+                        None if self.naming == CheckpointNaming::Epoch => index,
+                        None => continue,
+                    };
+
                     self.model
-                        .delete(epoch)
+                        .delete(number)
                         .expect("Can delete model checkpoint.");
                     self.optim
-                        .delete(epoch)
+                        .delete(number)
                         .expect("Can delete optimizer checkpoint.");
                     self.lr_scheduler
-                        .delete(epoch)
+                        .delete(number)
                         .expect("Can delete learning rate scheduler checkpoint.");
+                    if self.naming == CheckpointNaming::GlobalStep {
+                        std::fs::remove_file(self.position_path(number)).ok();
+                    }
                 }
                 CheckpointingAction::Save => {
                     self.model
-                        .save(epoch, model.clone().into_record())
+                        .save(number, model.clone().into_record())
                         .expect("Can save model checkpoint.");
                     self.optim
-                        .save(epoch, optim.to_record())
+                        .save(number, optim.to_record())
                         .expect("Can save optimizer checkpoint.");
                     self.lr_scheduler
-                        .save(epoch, scheduler.to_record())
+                        .save(number, scheduler.to_record())
                         .expect("Can save learning rate scheduler checkpoint.");
+                    if self.naming == CheckpointNaming::GlobalStep {
+                        self.save_position(number, position)
+                            .expect("Can save training position checkpoint.");
+                    }
+                    self.numbers.insert(index, number);
                 }
             }
         }
     }
 
+    pub(crate) fn naming(&self) -> CheckpointNaming {
+        self.naming
+    }
+
+    /// The position of the training the records with the given number were saved at.
+    pub(crate) fn load_position(&self, number: usize) -> TrainingPosition {
+        match self.naming {
+            CheckpointNaming::Epoch => TrainingPosition::after_epoch(number),
+            CheckpointNaming::GlobalStep => {
+                let content = std::fs::read_to_string(self.position_path(number))
+                    .expect("Can load training position checkpoint.");
+
+                serde_json::from_str(&content).expect("Can decode training position checkpoint.")
+            }
+        }
+    }
+
+    fn save_position(&self, number: usize, position: TrainingPosition) -> std::io::Result<()> {
+        std::fs::create_dir_all(&self.directory)?;
+        let content = serde_json::to_string_pretty(&position)?;
+
+        std::fs::write(self.position_path(number), content)
+    }
+
+    fn position_path(&self, number: usize) -> PathBuf {
+        self.directory.join(format!("position-{number}.json"))
+    }
+
     pub(crate) fn load_checkpoint(
         &self,
         model: LC::Model,
         optim: LC::Optimizer,
         scheduler: LC::LrScheduler,
         device: &Device<LC::Backend>,
-        epoch: usize,
+        number: usize,
     ) -> (LC::Model, LC::Optimizer, LC::LrScheduler) {
         let record = self
             .model
-            .restore(epoch, device)
+            .restore(number, device)
             .expect("Can load model checkpoint.");
         let model = model.load_record(record);
 
         let record = self
             .optim
-            .restore(epoch, device)
+            .restore(number, device)
             .expect("Can load optimizer checkpoint.");
         let optim = optim.load_record(record);
 
         let record = self
             .lr_scheduler
-            .restore(epoch, device)
+            .restore(number, device)
             .expect("Can load learning rate scheduler checkpoint.");
         let scheduler = scheduler.load_record(record);
 
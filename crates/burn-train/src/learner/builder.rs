@@ -4,12 +4,12 @@ use std::rc::Rc;
 
 use super::Learner;
 use crate::checkpoint::{
-    AsyncCheckpointer, CheckpointingStrategy, ComposedCheckpointingStrategy, FileCheckpointer,
-    KeepLastNCheckpoints, MetricCheckpointingStrategy,
+    AsyncCheckpointer, CheckpointNaming, CheckpointingStrategy, ComposedCheckpointingStrategy,
+    FileCheckpointer, KeepLastNCheckpoints, MetricCheckpointingStrategy,
 };
 use crate::components::LearnerComponentsMarker;
 use crate::learner::base::TrainingInterrupter;
-use crate::learner::EarlyStoppingStrategy;
+use crate::learner::{EarlyStoppingStrategy, UnfreezeSchedule};
 use crate::logger::{FileMetricLogger, MetricLogger};
 use crate::metric::processor::{FullEventProcessor, Metrics};
 use crate::metric::store::{Aggregate, Direction, EventStoreClient, LogEventStore, Split};
@@ -48,6 +48,8 @@ where
     checkpoint: Option<usize>,
     directory: PathBuf,
     grad_accumulation: Option<usize>,
+    validation_steps: Option<usize>,
+    checkpoint_naming: CheckpointNaming,
     devices: Vec<B::Device>,
     renderer: Option<Box<dyn MetricsRenderer + 'static>>,
     metrics: Metrics<T, V>,
@@ -57,6 +59,7 @@ where
     num_loggers: usize,
     checkpointer_strategy: Box<dyn CheckpointingStrategy>,
     early_stopping: Option<Box<dyn EarlyStoppingStrategy>>,
+    unfreeze_schedule: Option<UnfreezeSchedule>,
     summary_metrics: HashSet<String>,
     summary: bool,
 }
@@ -84,6 +87,8 @@ where
             checkpointers: None,
             directory,
             grad_accumulation: None,
+            validation_steps: None,
+            checkpoint_naming: CheckpointNaming::Epoch,
             devices: vec![B::Device::default()],
             metrics: Metrics::default(),
             event_store: LogEventStore::default(),
@@ -104,6 +109,7 @@ where
                     .build(),
             ),
             early_stopping: None,
+            unfreeze_schedule: None,
             summary_metrics: HashSet::new(),
             summary: false,
         }
@@ -181,6 +187,42 @@ where
         self
     }
 
+    /// Run the validation loop every `n` optimizer steps, in addition to the end of each epoch.
+    ///
+    /// # Notes
+    ///
+    /// With [gradients accumulation](Self::grads_accumulation), only the backward passes that
+    /// update the model count as steps. The steps are counted from the start of the training,
+    /// across epochs.
+    ///
+    /// Each validation run is logged as its own validation epoch, numbered in order, and the
+    /// [early stopping strategy](EarlyStoppingStrategy) and the
+    /// [checkpointing strategy](CheckpointingStrategy) are updated after each of them, so their
+    /// epochs are validation runs. The records are only saved in the middle of an epoch with the
+    /// [global step naming](CheckpointNaming::GlobalStep).
+    pub fn validate_every_n_steps(mut self, n: usize) -> Self {
+        assert!(
+            n > 0,
+            "The number of steps between validations must be positive."
+        );
+        self.validation_steps = Some(n);
+        self
+    }
+
+    /// How the checkpointers number the records they save, which is the epoch by default.
+    ///
+    /// # Notes
+    ///
+    /// With the [global step naming](CheckpointNaming::GlobalStep), the
+    /// [checkpoint](Self::checkpoint) to resume from is a global step. The epoch and the index of
+    /// the last validation are saved next to the records, so the training resumes where it was,
+    /// skipping the items of the epoch already trained on. Those are only the same items when the
+    /// training dataloader iterates in the same order.
+    pub fn checkpoint_naming(mut self, naming: CheckpointNaming) -> Self {
+        self.checkpoint_naming = naming;
+        self
+    }
+
     /// Register a [numeric](crate::metric::Numeric) training [metric](Metric).
     pub fn metric_train_numeric<Me>(mut self, metric: Me) -> Self
     where
@@ -217,7 +259,8 @@ where
         self
     }
 
-    /// The epoch from which the training must resume.
+    /// The epoch from which the training must resume, or the global step with the
+    /// [global step naming](CheckpointNaming::GlobalStep).
     pub fn checkpoint(mut self, checkpoint: usize) -> Self {
         self.checkpoint = Some(checkpoint);
         self
@@ -238,6 +281,13 @@ where
         self
     }
 
+    /// Register an [unfreeze schedule](UnfreezeSchedule) freezing and unfreezing parameters of
+    /// the model at the beginning of each epoch.
+    pub fn unfreeze_schedule(mut self, schedule: UnfreezeSchedule) -> Self {
+        self.unfreeze_schedule = Some(schedule);
+        self
+    }
+
     /// By default, Rust logs are captured and written into
     /// `experiment.log`. If disabled, standard Rust log handling
     /// will apply.
@@ -317,7 +367,11 @@ where
             }
         }
         let renderer = self.renderer.unwrap_or_else(|| {
-            Box::new(default_renderer(self.interrupter.clone(), self.checkpoint))
+            Box::new(default_renderer(
+                self.interrupter.clone(),
+                self.checkpoint,
+                &self.directory,
+            ))
         });
 
         if self.num_loggers == 0 {
@@ -331,7 +385,14 @@ where
         let event_processor = FullEventProcessor::new(self.metrics, renderer, event_store.clone());
 
         let checkpointer = self.checkpointers.map(|(model, optim, scheduler)| {
-            LearnerCheckpointer::new(model, optim, scheduler, self.checkpointer_strategy)
+            LearnerCheckpointer::new(
+                model,
+                optim,
+                scheduler,
+                self.checkpointer_strategy,
+                self.checkpoint_naming,
+                self.directory.join("checkpoint"),
+            )
         });
 
         let summary = if self.summary {
@@ -353,9 +414,11 @@ where
             event_store,
             checkpoint: self.checkpoint,
             grad_accumulation: self.grad_accumulation,
+            validation_steps: self.validation_steps,
             devices: self.devices,
             interrupter: self.interrupter,
             early_stopping: self.early_stopping,
+            unfreeze_schedule: self.unfreeze_schedule,
             summary,
         }
     }
@@ -5,6 +5,7 @@ use burn_core::{
 use std::sync::Arc;
 
 use crate::metric::processor::{Event, EventProcessor, LearnerItem};
+use crate::metric::{AccumulatedGradientNorms, GradientNorms, GradientStats};
 use crate::{components::LearnerComponents, learner::base::TrainingInterrupter};
 use crate::{MultiDevicesTrainStep, TrainStep, ValidStep};
 
@@ -14,6 +15,10 @@ pub struct ValidEpoch<VI> {
     dataloader: Arc<dyn DataLoader<VI>>,
     epoch: usize,
     epoch_total: usize,
+    #[new(default)]
+    global_step: usize,
+    #[new(default)]
+    index: Option<usize>,
 }
 
 /// A training epoch.
@@ -23,9 +28,21 @@ pub struct TrainEpoch<TI> {
     epoch: usize,
     epoch_total: usize,
     grad_accumulation: Option<usize>,
+    #[new(default)]
+    global_step: usize,
+    #[new(default)]
+    skipped_items: usize,
 }
 
 impl<VI> ValidEpoch<VI> {
+    /// Run the validation at the given global step, logging its metrics under the given index
+    /// instead of the epoch.
+    pub(crate) fn at_step(mut self, global_step: usize, index: usize) -> Self {
+        self.global_step = global_step;
+        self.index = Some(index);
+        self
+    }
+
     /// Runs the validation epoch.
     ///
     /// # Arguments
@@ -41,7 +58,11 @@ impl<VI> ValidEpoch<VI> {
         LC::EventProcessor: EventProcessor<ItemValid = VO>,
         <LC::Model as AutodiffModule<LC::Backend>>::InnerModule: ValidStep<VI, VO>,
     {
-        log::info!("Executing validation step for epoch {}", self.epoch);
+        log::info!(
+            "Executing validation step for epoch {} at global step {}",
+            self.epoch,
+            self.global_step
+        );
         let model = model.valid();
 
         let mut iterator = self.dataloader.iter();
@@ -58,6 +79,7 @@ impl<VI> ValidEpoch<VI> {
                 self.epoch,
                 self.epoch_total,
                 iteration,
+                self.global_step,
                 None,
             );
 
@@ -68,11 +90,24 @@ impl<VI> ValidEpoch<VI> {
                 break;
             }
         }
-        processor.process_valid(Event::EndEpoch(self.epoch));
+        processor.process_valid(Event::EndEpoch(self.index.unwrap_or(self.epoch)));
     }
 }
 
 impl<TI> TrainEpoch<TI> {
+    /// Start counting the optimizer steps of the epoch from the given global step.
+    pub(crate) fn starting_at_step(mut self, global_step: usize) -> Self {
+        self.global_step = global_step;
+        self
+    }
+
+    /// Skip the given number of items at the start of the epoch, already trained on before the
+    /// checkpoint the training resumes from.
+    pub(crate) fn skipping(mut self, items: usize) -> Self {
+        self.skipped_items = items;
+        self
+    }
+
     /// Runs the training epoch.
     ///
     /// # Arguments
@@ -81,6 +116,8 @@ impl<TI> TrainEpoch<TI> {
     /// * `optim` - The optimizer to use.
     /// * `scheduler` - The learning rate scheduler to use.
     /// * `processor` - The event processor to use.
+    /// * `on_step` - Called after every optimizer step with the updated components and the
+    ///   global step, returns if the training should stop.
     ///
     /// # Returns
     ///
@@ -92,6 +129,13 @@ impl<TI> TrainEpoch<TI> {
         scheduler: &mut LC::LrScheduler,
         processor: &mut LC::EventProcessor,
         interrupter: &TrainingInterrupter,
+        on_step: &mut dyn FnMut(
+            &LC::Model,
+            &LC::Optimizer,
+            &LC::LrScheduler,
+            &mut LC::EventProcessor,
+            usize,
+        ) -> bool,
     ) -> (LC::Model, LC::Optimizer)
     where
         LC::EventProcessor: EventProcessor<ItemTrain = TO>,
@@ -101,8 +145,12 @@ impl<TI> TrainEpoch<TI> {
 
         let mut iterator = self.dataloader.iter();
         let mut iteration = 0;
+        while iteration < self.skipped_items && iterator.next().is_some() {
+            iteration += 1;
+        }
         let mut accumulator = GradientsAccumulator::new();
         let mut accumulation_current = 0;
+        let mut global_step = self.global_step;
 
         while let Some(item) = iterator.next() {
             iteration += 1;
@@ -111,32 +159,56 @@ impl<TI> TrainEpoch<TI> {
 
             let progress = iterator.progress();
             let item = model.step(item);
-
-            match self.grad_accumulation {
+            let grad_norms = item.grad_norms;
+            let mut accumulated = None;
+            let optimized = match self.grad_accumulation {
                 Some(accumulation) => {
                     accumulator.accumulate(&model, item.grads);
                     accumulation_current += 1;
 
-                    if accumulation <= accumulation_current {
+                    let optimized = accumulation <= accumulation_current;
+                    if optimized {
                         let grads = accumulator.grads();
+                        if grad_norms.is_some() {
+                            accumulated = Some(AccumulatedGradientNorms::new(
+                                Arc::new(GradientNorms::from_grads::<LC::Backend, _>(
+                                    &grads, &model,
+                                )),
+                                accumulation_current,
+                            ));
+                        }
                         model = model.optimize(&mut optim, lr, grads);
                         accumulation_current = 0;
                     }
+                    optimized
+                }
+                None => {
+                    model = model.optimize(&mut optim, lr, item.grads);
+                    true
                 }
-                None => model = model.optimize(&mut optim, lr, item.grads),
+            };
+
+            if optimized {
+                global_step += 1;
             }
 
-            let item = LearnerItem::new(
+            let mut item = LearnerItem::new(
                 item.item,
                 progress,
                 self.epoch,
                 self.epoch_total,
                 iteration,
+                global_step,
                 Some(lr),
             );
+            item.gradients = grad_norms.map(|norms| GradientStats { norms, accumulated });
 
             processor.process_train(Event::ProcessedItem(item));
 
+            if optimized && on_step(&model, &optim, scheduler, processor, global_step) {
+                break;
+            }
+
             if interrupter.should_stop() {
                 log::info!("Training interrupted.");
                 break;
@@ -158,6 +230,8 @@ impl<TI> TrainEpoch<TI> {
     /// * `lr_scheduler` - The learning rate scheduler to use.
     /// * `processor` - The event processor to use.
     /// * `devices` - The devices to use.
+    /// * `on_step` - Called after every optimizer step with the updated components and the
+    ///   global step, returns if the training should stop.
     ///
     /// # Returns
     ///
@@ -170,6 +244,13 @@ impl<TI> TrainEpoch<TI> {
         processor: &mut LC::EventProcessor,
         devices: Vec<<LC::Backend as Backend>::Device>,
         interrupter: &TrainingInterrupter,
+        on_step: &mut dyn FnMut(
+            &LC::Model,
+            &LC::Optimizer,
+            &LC::LrScheduler,
+            &mut LC::EventProcessor,
+            usize,
+        ) -> bool,
     ) -> (LC::Model, LC::Optimizer)
     where
         LC::EventProcessor: EventProcessor<ItemTrain = TO>,
@@ -185,6 +266,9 @@ impl<TI> TrainEpoch<TI> {
 
         let mut iterator = self.dataloader.iter();
         let mut iteration = 0;
+        while iteration < self.skipped_items && iterator.next().is_some() {
+            iteration += 1;
+        }
         let mut accumulator = GradientsAccumulator::new();
         let mut accumulation_current = 0;
 
@@ -194,6 +278,7 @@ impl<TI> TrainEpoch<TI> {
         // The main device is always the first in the list.
         let device_main = devices.first().expect("A minimum of one device.").clone();
         let mut interrupted = false;
+        let mut global_step = self.global_step;
 
         loop {
             let items = step.step(&mut iterator, &model);
@@ -207,27 +292,44 @@ impl<TI> TrainEpoch<TI> {
                 let progress = iterator.progress();
 
                 let grads = item.grads.to_device(&device_main, &model);
+                let grad_norms = item.grad_norms;
+                let mut accumulated = None;
 
                 accumulator.accumulate(&model, grads);
                 accumulation_current += 1;
+                let optimized = accumulation <= accumulation_current;
 
-                if accumulation <= accumulation_current {
+                if optimized {
                     let grads = accumulator.grads();
+                    if grad_norms.is_some() {
+                        accumulated = Some(AccumulatedGradientNorms::new(
+                            Arc::new(GradientNorms::from_grads::<LC::Backend, _>(&grads, &model)),
+                            accumulation_current,
+                        ));
+                    }
                     model = model.optimize(&mut optim, lr, grads);
                     accumulation_current = 0;
+                    global_step += 1;
                 }
 
-                let item = LearnerItem::new(
+                let mut item = LearnerItem::new(
                     item.item,
                     progress,
                     self.epoch,
                     self.epoch_total,
                     iteration,
+                    global_step,
                     Some(lr),
                 );
+                item.gradients = grad_norms.map(|norms| GradientStats { norms, accumulated });
 
                 processor.process_train(Event::ProcessedItem(item));
 
+                if optimized && on_step(&model, &optim, lr_scheduler, processor, global_step) {
+                    interrupted = true;
+                    break;
+                }
+
                 if interrupter.should_stop() {
                     log::info!("Training interrupted.");
                     interrupted = true;
@@ -7,7 +7,9 @@ mod epoch;
 mod regression;
 mod step;
 mod summary;
+mod task;
 mod train_val;
+mod unfreeze;
 
 pub use application_logger::*;
 pub use base::*;
@@ -18,5 +20,7 @@ pub use epoch::*;
 pub use regression::*;
 pub use step::*;
 pub use summary::*;
+pub use task::*;
 pub use train::*;
 pub use train_val::*;
+pub use unfreeze::*;
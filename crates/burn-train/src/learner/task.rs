@@ -0,0 +1,431 @@
+use crate::metric::{AccuracyMetric, LossMetric};
+use crate::{
+    ClassificationOutput, LearnerBuilder, RegressionOutput, TrainOutput, TrainStep, ValidStep,
+};
+use burn_core as burn;
+use burn_core::lr_scheduler::LrScheduler;
+use burn_core::module::{AutodiffModule, Module};
+use burn_core::nn::loss::{CrossEntropyLoss, HuberLoss, MseLoss, Reduction};
+use burn_core::optim::Optimizer;
+use burn_core::tensor::backend::{AutodiffBackend, Backend};
+use burn_core::tensor::{Int, Tensor};
+
+/// A model that can be trained by a [classification](ClassificationTask) or a
+/// [regression](RegressionTask) task.
+pub trait TaskModel<B: Backend, I> {
+    /// Compute the output of the model, of shape `[batch_size, num_outputs]`.
+    ///
+    /// For classification, the outputs are the logits of every class.
+    fn forward(&self, inputs: I) -> Tensor<B, 2>;
+}
+
+/// The loss of a [classification task](ClassificationTask).
+pub trait ClassificationLoss<B: Backend> {
+    /// Compute the loss from the logits of shape `[batch_size, num_classes]` and the target
+    /// classes of shape `[batch_size]`.
+    fn loss(&self, logits: Tensor<B, 2>, targets: Tensor<B, 1, Int>) -> Tensor<B, 1>;
+}
+
+/// The loss of a [regression task](RegressionTask).
+pub trait RegressionLoss<B: Backend> {
+    /// Compute the loss from the outputs and the targets of shape `[batch_size, num_outputs]`.
+    fn loss(&self, output: Tensor<B, 2>, targets: Tensor<B, 2>) -> Tensor<B, 1>;
+}
+
+impl<B: Backend> ClassificationLoss<B> for CrossEntropyLoss<B> {
+    fn loss(&self, logits: Tensor<B, 2>, targets: Tensor<B, 1, Int>) -> Tensor<B, 1> {
+        self.forward(logits, targets)
+    }
+}
+
+impl<B: Backend> RegressionLoss<B> for MseLoss {
+    fn loss(&self, output: Tensor<B, 2>, targets: Tensor<B, 2>) -> Tensor<B, 1> {
+        self.forward(output, targets, Reduction::Mean)
+    }
+}
+
+impl<B: Backend> RegressionLoss<B> for HuberLoss {
+    fn loss(&self, output: Tensor<B, 2>, targets: Tensor<B, 2>) -> Tensor<B, 1> {
+        self.forward(output, targets, Reduction::Mean)
+    }
+}
+
+/// A batch of a [classification task](ClassificationTask).
+#[derive(new, Clone, Debug)]
+pub struct ClassificationBatch<B: Backend, I> {
+    /// The inputs of the model.
+    pub inputs: I,
+    /// The target classes, of shape `[batch_size]`.
+    pub targets: Tensor<B, 1, Int>,
+}
+
+/// A batch of a [regression task](RegressionTask).
+#[derive(new, Clone, Debug)]
+pub struct RegressionBatch<B: Backend, I> {
+    /// The inputs of the model.
+    pub inputs: I,
+    /// The targets, of shape `[batch_size, num_outputs]`.
+    pub targets: Tensor<B, 2>,
+}
+
+/// Train a [model](TaskModel) to classify its inputs, producing a [ClassificationOutput].
+///
+/// The task implements [TrainStep] and [ValidStep] with the given loss, so it can directly be
+/// given to a [learner](crate::Learner). The default metrics can be registered with
+/// [LearnerBuilder::classification_metrics].
+#[derive(Module, Debug)]
+pub struct ClassificationTask<B: Backend, M, L> {
+    /// The wrapped model.
+    pub model: M,
+    /// The loss.
+    pub loss: L,
+    _backend: core::marker::PhantomData<B>,
+}
+
+/// Train a [model](TaskModel) to predict continuous values, producing a [RegressionOutput].
+///
+/// The task implements [TrainStep] and [ValidStep] with the given loss, so it can directly be
+/// given to a [learner](crate::Learner). The default metrics can be registered with
+/// [LearnerBuilder::regression_metrics].
+#[derive(Module, Debug)]
+pub struct RegressionTask<B: Backend, M, L> {
+    /// The wrapped model.
+    pub model: M,
+    /// The loss.
+    pub loss: L,
+    _backend: core::marker::PhantomData<B>,
+}
+
+impl<B: Backend, M> ClassificationTask<B, M, CrossEntropyLoss<B>> {
+    /// Create a classification task trained with the [cross-entropy loss](CrossEntropyLoss).
+    pub fn new(model: M, device: &B::Device) -> Self {
+        Self::with_loss(model, CrossEntropyLoss::new(None, device))
+    }
+}
+
+impl<B: Backend, M, L> ClassificationTask<B, M, L> {
+    /// Create a classification task trained with the given loss.
+    pub fn with_loss(model: M, loss: L) -> Self {
+        Self {
+            model,
+            loss,
+            _backend: core::marker::PhantomData,
+        }
+    }
+
+    /// Compute the output of the model and its loss on a batch.
+    pub fn forward<I>(&self, batch: ClassificationBatch<B, I>) -> ClassificationOutput<B>
+    where
+        M: TaskModel<B, I>,
+        L: ClassificationLoss<B>,
+    {
+        let output = self.model.forward(batch.inputs);
+        let loss = self.loss.loss(output.clone(), batch.targets.clone());
+
+        ClassificationOutput::new(loss, output, batch.targets)
+    }
+}
+
+impl<B: Backend, M> RegressionTask<B, M, MseLoss> {
+    /// Create a regression task trained with the [mean squared error](MseLoss).
+    pub fn new(model: M) -> Self {
+        Self::with_loss(model, MseLoss::new())
+    }
+}
+
+impl<B: Backend, M, L> RegressionTask<B, M, L> {
+    /// Create a regression task trained with the given loss.
+    pub fn with_loss(model: M, loss: L) -> Self {
+        Self {
+            model,
+            loss,
+            _backend: core::marker::PhantomData,
+        }
+    }
+
+    /// Compute the output of the model and its loss on a batch.
+    pub fn forward<I>(&self, batch: RegressionBatch<B, I>) -> RegressionOutput<B>
+    where
+        M: TaskModel<B, I>,
+        L: RegressionLoss<B>,
+    {
+        let output = self.model.forward(batch.inputs);
+        let loss = self.loss.loss(output.clone(), batch.targets.clone());
+
+        RegressionOutput::new(loss, output, batch.targets)
+    }
+}
+
+impl<B, M, L, I> TrainStep<ClassificationBatch<B, I>, ClassificationOutput<B>>
+    for ClassificationTask<B, M, L>
+where
+    B: AutodiffBackend,
+    M: TaskModel<B, I> + AutodiffModule<B>,
+    L: ClassificationLoss<B> + AutodiffModule<B>,
+{
+    fn step(&self, batch: ClassificationBatch<B, I>) -> TrainOutput<ClassificationOutput<B>> {
+        let item = self.forward(batch);
+
+        TrainOutput::new(self, item.loss.backward(), item)
+    }
+}
+
+impl<B, M, L, I> ValidStep<ClassificationBatch<B, I>, ClassificationOutput<B>>
+    for ClassificationTask<B, M, L>
+where
+    B: Backend,
+    M: TaskModel<B, I>,
+    L: ClassificationLoss<B>,
+{
+    fn step(&self, batch: ClassificationBatch<B, I>) -> ClassificationOutput<B> {
+        self.forward(batch)
+    }
+}
+
+impl<B, M, L, I> TrainStep<RegressionBatch<B, I>, RegressionOutput<B>> for RegressionTask<B, M, L>
+where
+    B: AutodiffBackend,
+    M: TaskModel<B, I> + AutodiffModule<B>,
+    L: RegressionLoss<B> + AutodiffModule<B>,
+{
+    fn step(&self, batch: RegressionBatch<B, I>) -> TrainOutput<RegressionOutput<B>> {
+        let item = self.forward(batch);
+
+        TrainOutput::new(self, item.loss.backward(), item)
+    }
+}
+
+impl<B, M, L, I> ValidStep<RegressionBatch<B, I>, RegressionOutput<B>> for RegressionTask<B, M, L>
+where
+    B: Backend,
+    M: TaskModel<B, I>,
+    L: RegressionLoss<B>,
+{
+    fn step(&self, batch: RegressionBatch<B, I>) -> RegressionOutput<B> {
+        self.forward(batch)
+    }
+}
+
+impl<B, M, O, S>
+    LearnerBuilder<B, ClassificationOutput<B>, ClassificationOutput<B::InnerBackend>, M, O, S>
+where
+    B: AutodiffBackend,
+    M: AutodiffModule<B> + core::fmt::Display + 'static,
+    O: Optimizer<M, B>,
+    S: LrScheduler<B>,
+{
+    /// Register the default metrics of a [classification task](ClassificationTask), which are
+    /// the loss and the accuracy for both training and validation.
+    pub fn classification_metrics(self) -> Self {
+        self.metric_train_numeric(AccuracyMetric::new())
+            .metric_valid_numeric(AccuracyMetric::new())
+            .metric_train_numeric(LossMetric::new())
+            .metric_valid_numeric(LossMetric::new())
+    }
+}
+
+impl<B, M, O, S> LearnerBuilder<B, RegressionOutput<B>, RegressionOutput<B::InnerBackend>, M, O, S>
+where
+    B: AutodiffBackend,
+    M: AutodiffModule<B> + core::fmt::Display + 'static,
+    O: Optimizer<M, B>,
+    S: LrScheduler<B>,
+{
+    /// Register the default metrics of a [regression task](RegressionTask), which is the loss for
+    /// both training and validation.
+    pub fn regression_metrics(self) -> Self {
+        self.metric_train_numeric(LossMetric::new())
+            .metric_valid_numeric(LossMetric::new())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::logger::InMemoryMetricLogger;
+    use crate::renderer::{MetricState, MetricsRenderer, TrainingProgress};
+    use crate::{TestAutodiffBackend, TestBackend};
+    use burn_core::data::dataloader::{batcher::Batcher, DataLoader, DataLoaderBuilder};
+    use burn_core::data::dataset::InMemDataset;
+    use burn_core::nn::{Linear, LinearConfig, Relu};
+    use burn_core::optim::AdamConfig;
+    use burn_core::tensor::{ElementConversion, TensorData};
+    use std::sync::Arc;
+
+    #[derive(Module, Debug)]
+    struct Mlp<B: Backend> {
+        hidden: Linear<B>,
+        activation: Relu,
+        output: Linear<B>,
+    }
+
+    impl<B: Backend> Mlp<B> {
+        fn new(num_outputs: usize, device: &B::Device) -> Self {
+            Self {
+                hidden: LinearConfig::new(2, 16).init(device),
+                activation: Relu::new(),
+                output: LinearConfig::new(16, num_outputs).init(device),
+            }
+        }
+    }
+
+    impl<B: Backend> TaskModel<B, Tensor<B, 2>> for Mlp<B> {
+        fn forward(&self, inputs: Tensor<B, 2>) -> Tensor<B, 2> {
+            let x = self.hidden.forward(inputs);
+            let x = self.activation.forward(x);
+
+            self.output.forward(x)
+        }
+    }
+
+    struct QuietRenderer;
+
+    impl MetricsRenderer for QuietRenderer {
+        fn update_train(&mut self, _state: MetricState) {}
+        fn update_valid(&mut self, _state: MetricState) {}
+        fn render_train(&mut self, _item: TrainingProgress) {}
+        fn render_valid(&mut self, _item: TrainingProgress) {}
+    }
+
+    #[derive(Clone)]
+    struct ClassificationBatcher;
+
+    #[derive(Clone)]
+    struct RegressionBatcher;
+
+    fn inputs<B: Backend>(items: &[[f32; 2]], device: &B::Device) -> Tensor<B, 2> {
+        let values = items.iter().flatten().copied().collect::<Vec<_>>();
+
+        Tensor::from_floats(TensorData::new(values, [items.len(), 2]), device)
+    }
+
+    impl<B: Backend> Batcher<B, [f32; 2], ClassificationBatch<B, Tensor<B, 2>>>
+        for ClassificationBatcher
+    {
+        fn batch(
+            &self,
+            items: Vec<[f32; 2]>,
+            device: &B::Device,
+        ) -> ClassificationBatch<B, Tensor<B, 2>> {
+            // The class is whether the point is above the diagonal.
+            let targets = items
+                .iter()
+                .map(|[x, y]| (y > x) as i64)
+                .collect::<Vec<_>>();
+            let targets = Tensor::from_ints(TensorData::new(targets, [items.len()]), device);
+
+            ClassificationBatch::new(inputs(&items, device), targets)
+        }
+    }
+
+    impl<B: Backend> Batcher<B, [f32; 2], RegressionBatch<B, Tensor<B, 2>>> for RegressionBatcher {
+        fn batch(
+            &self,
+            items: Vec<[f32; 2]>,
+            device: &B::Device,
+        ) -> RegressionBatch<B, Tensor<B, 2>> {
+            let targets = items.iter().map(|[x, y]| 2.0 * x - y).collect::<Vec<_>>();
+            let targets = Tensor::from_floats(TensorData::new(targets, [items.len(), 1]), device);
+
+            RegressionBatch::new(inputs(&items, device), targets)
+        }
+    }
+
+    /// Points on a grid of `[-1, 1]^2`, away from the diagonal.
+    fn points() -> Vec<[f32; 2]> {
+        let coordinates = (0..21).map(|i| i as f32 / 10.0 - 1.0).collect::<Vec<_>>();
+
+        coordinates
+            .iter()
+            .flat_map(|x| coordinates.iter().map(move |y| [*x, *y]))
+            .filter(|[x, y]| (x - y).abs() > 0.05)
+            .collect()
+    }
+
+    fn dataloader<B, I, T>(batcher: T, device: &B::Device, shuffle: bool) -> Arc<dyn DataLoader<I>>
+    where
+        B: Backend,
+        T: Batcher<B, [f32; 2], I> + Clone + 'static,
+        I: Send + Clone + core::fmt::Debug + 'static,
+    {
+        let builder = DataLoaderBuilder::<B, _, _>::new(batcher)
+            .batch_size(32)
+            .device(device.clone());
+        let builder = match shuffle {
+            true => builder.shuffle(42),
+            false => builder,
+        };
+
+        builder.build(InMemDataset::new(points()))
+    }
+
+    fn learner_builder<T, V, M, O>(
+        name: &str,
+    ) -> LearnerBuilder<TestAutodiffBackend, T, V, M, O, f64>
+    where
+        T: Send + 'static,
+        V: Send + 'static,
+        M: AutodiffModule<TestAutodiffBackend> + core::fmt::Display + 'static,
+        O: Optimizer<M, TestAutodiffBackend>,
+    {
+        LearnerBuilder::new(std::env::temp_dir().join(name))
+            .metric_loggers(InMemoryMetricLogger::new(), InMemoryMetricLogger::new())
+            .renderer(QuietRenderer)
+            .with_application_logger(None)
+            .num_epochs(10)
+    }
+
+    #[test]
+    fn classification_task_should_learn_synthetic_data() {
+        let device = Default::default();
+        TestAutodiffBackend::seed(0);
+        let task = ClassificationTask::new(Mlp::new(2, &device), &device);
+
+        let learner = learner_builder("burn-train-classification-task")
+            .classification_metrics()
+            .build(task, AdamConfig::new().init(), 1e-2);
+        let task = learner.fit(
+            dataloader::<TestAutodiffBackend, _, _>(ClassificationBatcher, &device, true),
+            dataloader::<TestBackend, _, _>(ClassificationBatcher, &device, false),
+        );
+
+        let batch = Batcher::<TestBackend, _, _>::batch(&ClassificationBatcher, points(), &device);
+        let output = ValidStep::step(&task.valid(), batch);
+        let accuracy = output
+            .output
+            .argmax(1)
+            .squeeze::<1>(1)
+            .equal(output.targets)
+            .int()
+            .float()
+            .mean()
+            .into_scalar()
+            .elem::<f32>();
+
+        assert!(accuracy > 0.95, "Accuracy {accuracy} is too low");
+    }
+
+    #[test]
+    fn regression_task_should_learn_synthetic_data() {
+        let device = Default::default();
+        TestAutodiffBackend::seed(0);
+        let task = RegressionTask::new(Mlp::new(1, &device));
+
+        let learner = learner_builder("burn-train-regression-task")
+            .regression_metrics()
+            .num_epochs(20)
+            .build(task, AdamConfig::new().init(), 1e-2);
+        let task = learner.fit(
+            dataloader::<TestAutodiffBackend, _, _>(RegressionBatcher, &device, true),
+            dataloader::<TestBackend, _, _>(RegressionBatcher, &device, false),
+        );
+
+        let batch = Batcher::<TestBackend, _, _>::batch(&RegressionBatcher, points(), &device);
+        let loss = ValidStep::step(&task.valid(), batch)
+            .loss
+            .into_scalar()
+            .elem::<f32>();
+
+        assert!(loss < 0.1, "Loss {loss} is too high");
+    }
+}
@@ -1,6 +1,12 @@
+use crate::checkpoint::CheckpointNaming;
 use crate::components::LearnerComponents;
+use crate::learner::EarlyStoppingStrategy;
 use crate::metric::processor::EventProcessor;
-use crate::{Learner, TrainEpoch, ValidEpoch};
+use crate::metric::store::EventStoreClient;
+use crate::metric::GradientNorms;
+use crate::{
+    Learner, LearnerCheckpointer, TrainEpoch, TrainingInterrupter, TrainingPosition, ValidEpoch,
+};
 use burn_core::data::dataloader::DataLoader;
 use burn_core::module::{AutodiffModule, Module};
 use burn_core::optim::{GradientsParams, Optimizer};
@@ -8,12 +14,19 @@ use burn_core::tensor::backend::AutodiffBackend;
 use std::sync::Arc;
 
 /// A training output.
+///
+/// The output is created with [new](TrainOutput::new) or [from_params](TrainOutput::from_params),
+/// so the statistics attached to it can be extended without breaking the training steps.
 pub struct TrainOutput<TO> {
     /// The gradients.
     pub grads: GradientsParams,
 
     /// The item.
     pub item: TO,
+
+    /// The norms of the gradients, given to the metrics when attached with
+    /// [with_gradient_norms](TrainOutput::with_gradient_norms).
+    pub(crate) grad_norms: Option<Arc<GradientNorms>>,
 }
 
 impl<TO> TrainOutput<TO> {
@@ -34,7 +47,46 @@ impl<TO> TrainOutput<TO> {
         item: TO,
     ) -> Self {
         let grads = GradientsParams::from_grads(grads, module);
-        Self { grads, item }
+        Self::from_params(grads, item)
+    }
+
+    /// Creates a new training output from the gradients already mapped to the parameters of the
+    /// module.
+    ///
+    /// # Arguments
+    ///
+    /// * `grads` - The gradients of the parameters.
+    /// * `item` - The item.
+    ///
+    /// # Returns
+    ///
+    /// A new training output.
+    pub fn from_params(grads: GradientsParams, item: TO) -> Self {
+        Self {
+            grads,
+            item,
+            grad_norms: None,
+        }
+    }
+
+    /// The norms of the gradients, if they were
+    /// [attached](TrainOutput::with_gradient_norms).
+    pub fn grad_norms(&self) -> Option<&GradientNorms> {
+        self.grad_norms.as_deref()
+    }
+
+    /// Attach the norms of the gradients to the output, so they can be tracked by the
+    /// [gradient statistics](crate::metric::GradientStatsMetric) metric.
+    ///
+    /// With gradients accumulation, the norms of the accumulated gradients are also computed at
+    /// each optimizer step, which gives an estimate of the gradient noise scale.
+    pub fn with_gradient_norms<B: AutodiffBackend, M: AutodiffModule<B>>(
+        mut self,
+        module: &M,
+    ) -> Self {
+        let norms = GradientNorms::from_grads::<B, M>(&self.grads, module);
+        self.grad_norms = Some(Arc::new(norms));
+        self
     }
 }
 
@@ -128,9 +180,9 @@ impl<LC: LearnerComponents> Learner<LC> {
             self.model = self.model.fork(device);
         }
 
-        let starting_epoch = match self.checkpoint {
-            Some(checkpoint) => {
-                if let Some(checkpointer) = &mut self.checkpointer {
+        let (position, mut global_step) = match self.checkpoint {
+            Some(checkpoint) => match &mut self.checkpointer {
+                Some(checkpointer) => {
                     (self.model, self.optim, self.lr_scheduler) = checkpointer.load_checkpoint(
                         self.model,
                         self.optim,
@@ -138,19 +190,68 @@ impl<LC: LearnerComponents> Learner<LC> {
                         &Default::default(), // Load the checkpoint on the default device.
                         checkpoint,
                     );
+                    let global_step = match checkpointer.naming() {
+                        CheckpointNaming::Epoch => 0,
+                        CheckpointNaming::GlobalStep => checkpoint,
+                    };
+
+                    (checkpointer.load_position(checkpoint), global_step)
                 }
-                checkpoint + 1
-            }
-            None => 1,
+                None => (TrainingPosition::after_epoch(checkpoint), 0),
+            },
+            None => (TrainingPosition::start(), 0),
         };
 
-        for epoch in starting_epoch..self.num_epochs + 1 {
+        // The number of items trained on between two optimizer steps.
+        let items_per_step = match self.devices.len() > 1 {
+            true => self.grad_accumulation.unwrap_or(1) * self.devices.len(),
+            false => self.grad_accumulation.unwrap_or(1),
+        };
+        let validation_steps = self.validation_steps;
+        let mut validation = Validation {
+            dataloader: dataloader_valid,
+            checkpointer: &mut self.checkpointer,
+            early_stopping: &mut self.early_stopping,
+            store: &self.event_store,
+            interrupter: &self.interrupter,
+            num_epochs: self.num_epochs,
+            index: position.index,
+            last_step: position.last_step,
+            epoch_step: global_step - position.items / items_per_step,
+            items_per_step,
+        };
+        let mut skipped_items = position.items;
+
+        for epoch in position.epoch..self.num_epochs + 1 {
+            if let Some(schedule) = &self.unfreeze_schedule {
+                self.model = schedule.apply(self.model, epoch);
+            }
+
             let epoch_train = TrainEpoch::new(
                 dataloader_train.clone(),
                 epoch,
                 self.num_epochs,
                 self.grad_accumulation,
-            );
+            )
+            .starting_at_step(global_step)
+            .skipping(skipped_items);
+            validation.epoch_step = global_step - skipped_items / items_per_step;
+            skipped_items = 0;
+
+            let mut stopped = false;
+            let mut on_step = |model: &LC::Model,
+                               optim: &LC::Optimizer,
+                               scheduler: &LC::LrScheduler,
+                               processor: &mut LC::EventProcessor,
+                               step: usize| {
+                global_step = step;
+                stopped = match validation_steps {
+                    Some(n) if step % n == 0 => validation
+                        .run::<OutputValid>(model, optim, scheduler, processor, epoch, step, false),
+                    _ => false,
+                };
+                stopped
+            };
 
             if self.devices.len() > 1 {
                 (self.model, self.optim) = epoch_train.run_multi_device::<LC, OutputTrain>(
@@ -160,6 +261,7 @@ impl<LC: LearnerComponents> Learner<LC> {
                     &mut self.event_processor,
                     self.devices.clone(),
                     &self.interrupter,
+                    &mut on_step,
                 )
             } else {
                 (self.model, self.optim) = epoch_train.run::<LC, OutputTrain>(
@@ -168,34 +270,24 @@ impl<LC: LearnerComponents> Learner<LC> {
                     &mut self.lr_scheduler,
                     &mut self.event_processor,
                     &self.interrupter,
+                    &mut on_step,
                 );
             }
 
-            if self.interrupter.should_stop() {
+            if stopped || self.interrupter.should_stop() {
                 break;
             }
 
-            let epoch_valid = ValidEpoch::new(dataloader_valid.clone(), epoch, self.num_epochs);
-            epoch_valid.run::<LC, OutputValid>(
+            if validation.run::<OutputValid>(
                 &self.model,
+                &self.optim,
+                &self.lr_scheduler,
                 &mut self.event_processor,
-                &self.interrupter,
-            );
-
-            if let Some(checkpointer) = &mut self.checkpointer {
-                checkpointer.checkpoint(
-                    &self.model,
-                    &self.optim,
-                    &self.lr_scheduler,
-                    epoch,
-                    &self.event_store,
-                );
-            }
-
-            if let Some(early_stopping) = &mut self.early_stopping {
-                if early_stopping.should_stop(epoch, &self.event_store) {
-                    break;
-                }
+                epoch,
+                global_step,
+                true,
+            ) {
+                break;
             }
         }
 
@@ -215,3 +307,333 @@ impl<LC: LearnerComponents> Learner<LC> {
         self.model
     }
 }
+
+/// The validations of a training, which feed the checkpointer and the early stopping strategy.
+struct Validation<'a, LC: LearnerComponents, VI> {
+    dataloader: Arc<dyn DataLoader<VI>>,
+    checkpointer: &'a mut Option<LearnerCheckpointer<LC>>,
+    early_stopping: &'a mut Option<Box<dyn EarlyStoppingStrategy>>,
+    store: &'a EventStoreClient,
+    interrupter: &'a TrainingInterrupter,
+    num_epochs: usize,
+    /// The index of the last validation, which is the epoch without step validation.
+    index: usize,
+    /// The global step of the last validation in the middle of an epoch.
+    last_step: Option<usize>,
+    /// The global step at the start of the current epoch.
+    epoch_step: usize,
+    /// The number of items trained on between two optimizer steps.
+    items_per_step: usize,
+}
+
+impl<LC: LearnerComponents, VI> Validation<'_, LC, VI> {
+    /// Validate the model at the given global step, returns if the training should stop.
+    ///
+    /// At the end of an epoch, the validation isn't run again if the last step of the epoch was
+    /// already validated, only the records numbered by epoch are saved.
+    #[allow(clippy::too_many_arguments)]
+    fn run<VO>(
+        &mut self,
+        model: &LC::Model,
+        optim: &LC::Optimizer,
+        scheduler: &LC::LrScheduler,
+        processor: &mut LC::EventProcessor,
+        epoch: usize,
+        global_step: usize,
+        end_of_epoch: bool,
+    ) -> bool
+    where
+        LC::EventProcessor: EventProcessor<ItemValid = VO>,
+        <LC::Model as AutodiffModule<LC::Backend>>::InnerModule: ValidStep<VI, VO>,
+    {
+        let validated = end_of_epoch && self.last_step == Some(global_step);
+
+        if !validated {
+            self.index += 1;
+            if !end_of_epoch {
+                self.last_step = Some(global_step);
+            }
+
+            ValidEpoch::new(self.dataloader.clone(), epoch, self.num_epochs)
+                .at_step(global_step, self.index)
+                .run::<LC, VO>(model, processor, self.interrupter);
+        }
+
+        if let Some(checkpointer) = self.checkpointer.as_mut() {
+            let number = match checkpointer.naming() {
+                CheckpointNaming::Epoch => end_of_epoch.then_some(epoch),
+                CheckpointNaming::GlobalStep => (!validated).then_some(global_step),
+            };
+
+            // The training resumes after the epoch, or after the items of the epoch trained on.
+            let position = match end_of_epoch {
+                true => TrainingPosition {
+                    epoch: epoch + 1,
+                    items: 0,
+                    index: self.index,
+                    last_step: None,
+                },
+                false => TrainingPosition {
+                    epoch,
+                    items: (global_step - self.epoch_step) * self.items_per_step,
+                    index: self.index,
+                    last_step: self.last_step,
+                },
+            };
+
+            if let Some(number) = number {
+                checkpointer.checkpoint(model, optim, scheduler, position, number, self.store);
+            }
+        }
+
+        match self.early_stopping.as_mut() {
+            Some(early_stopping) if !validated => {
+                early_stopping.should_stop(self.index, self.store)
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checkpoint::KeepLastNCheckpoints;
+    use crate::logger::{InMemoryMetricLogger, MetricLogger};
+    use crate::metric::{Adaptor, Metric, MetricEntry, MetricMetadata, NumericEntry};
+    use crate::renderer::{MetricState, MetricsRenderer, TrainingProgress};
+    use crate::{
+        LearnerBuilder, RegressionBatch, RegressionOutput, RegressionTask, TaskModel,
+        TestAutodiffBackend, TestBackend,
+    };
+    use burn_core::data::dataloader::{batcher::Batcher, DataLoaderBuilder};
+    use burn_core::data::dataset::InMemDataset;
+    use burn_core::nn::{Linear, LinearConfig};
+    use burn_core::optim::SgdConfig;
+    use burn_core::record::CompactRecorder;
+    use burn_core::tensor::backend::Backend;
+    use burn_core::tensor::{Tensor, TensorData};
+    use std::sync::{Arc, Mutex};
+
+    #[derive(burn_core::module::Module, Debug)]
+    struct LinearModel<B: Backend> {
+        linear: Linear<B>,
+    }
+
+    impl<B: Backend> TaskModel<B, Tensor<B, 2>> for LinearModel<B> {
+        fn forward(&self, inputs: Tensor<B, 2>) -> Tensor<B, 2> {
+            self.linear.forward(inputs)
+        }
+    }
+
+    #[derive(Clone)]
+    struct LineBatcher;
+
+    impl<B: Backend> Batcher<B, f32, RegressionBatch<B, Tensor<B, 2>>> for LineBatcher {
+        fn batch(&self, items: Vec<f32>, device: &B::Device) -> RegressionBatch<B, Tensor<B, 2>> {
+            let targets = items.iter().map(|x| 2.0 * x).collect::<Vec<_>>();
+            let inputs = TensorData::new(items.clone(), [items.len(), 1]);
+            let targets = TensorData::new(targets, [items.len(), 1]);
+
+            RegressionBatch::new(
+                Tensor::from_floats(inputs, device),
+                Tensor::from_floats(targets, device),
+            )
+        }
+    }
+
+    fn dataloader<B: Backend>(
+        num_items: usize,
+    ) -> Arc<dyn DataLoader<RegressionBatch<B, Tensor<B, 2>>>> {
+        let items = (0..num_items)
+            .map(|i| i as f32 / num_items as f32)
+            .collect();
+
+        DataLoaderBuilder::<B, _, _>::new(LineBatcher)
+            .batch_size(1)
+            .build(InMemDataset::new(items))
+    }
+
+    struct QuietRenderer;
+
+    impl MetricsRenderer for QuietRenderer {
+        fn update_train(&mut self, _state: MetricState) {}
+        fn update_valid(&mut self, _state: MetricState) {}
+        fn render_train(&mut self, _item: TrainingProgress) {}
+        fn render_valid(&mut self, _item: TrainingProgress) {}
+    }
+
+    /// Records the global step of every validated item.
+    #[derive(Clone, Default)]
+    struct GlobalStepMetric {
+        steps: Arc<Mutex<Vec<usize>>>,
+    }
+
+    impl Metric for GlobalStepMetric {
+        const NAME: &'static str = "Global Step";
+        type Input = ();
+
+        fn update(&mut self, _item: &(), metadata: &MetricMetadata) -> MetricEntry {
+            self.steps.lock().unwrap().push(metadata.global_step);
+            let step = metadata.global_step.to_string();
+
+            MetricEntry::new(Self::NAME.to_string(), step.clone(), step)
+        }
+
+        fn clear(&mut self) {}
+    }
+
+    impl<B: Backend> Adaptor<()> for RegressionOutput<B> {
+        fn adapt(&self) {}
+    }
+
+    /// Records the index of every validation epoch.
+    #[derive(Clone, Default)]
+    struct EpochLogger {
+        epochs: Arc<Mutex<Vec<usize>>>,
+    }
+
+    impl MetricLogger for EpochLogger {
+        fn log(&mut self, _item: &MetricEntry) {}
+
+        fn end_epoch(&mut self, epoch: usize) {
+            self.epochs.lock().unwrap().push(epoch);
+        }
+
+        fn read_numeric(
+            &mut self,
+            _name: &str,
+            _epoch: usize,
+        ) -> Result<Vec<NumericEntry>, String> {
+            Ok(Vec::new())
+        }
+    }
+
+    /// Fit a linear model for one epoch of 100 batches, returning the index and the global step
+    /// of every validation.
+    fn fit_with_validation_steps(
+        name: &str,
+        steps: usize,
+        grads_accumulation: Option<usize>,
+    ) -> (Vec<usize>, Vec<usize>) {
+        let device = Default::default();
+        let metric = GlobalStepMetric::default();
+        let logger = EpochLogger::default();
+        let model = LinearModel {
+            linear: LinearConfig::new(1, 1).init(&device),
+        };
+
+        let builder = LearnerBuilder::new(std::env::temp_dir().join(name))
+            .metric_loggers(InMemoryMetricLogger::new(), logger.clone())
+            .metric_valid(metric.clone())
+            .renderer(QuietRenderer)
+            .with_application_logger(None)
+            .validate_every_n_steps(steps);
+        let builder = match grads_accumulation {
+            Some(accumulation) => builder.grads_accumulation(accumulation),
+            None => builder,
+        };
+
+        let learner = builder.build(RegressionTask::new(model), SgdConfig::new().init(), 1e-2);
+        learner.fit(
+            dataloader::<TestAutodiffBackend>(100),
+            dataloader::<TestBackend>(2),
+        );
+
+        let mut steps = metric.steps.lock().unwrap().clone();
+        steps.dedup();
+        let epochs = logger.epochs.lock().unwrap().clone();
+
+        (epochs, steps)
+    }
+
+    /// Fit a linear model for two epochs of 100 batches, validating every 30 steps and saving the
+    /// records numbered by global step, returning the index and the global step of every
+    /// validation.
+    fn fit_with_global_step_checkpoints(
+        directory: &std::path::Path,
+        checkpoint: Option<usize>,
+    ) -> (Vec<usize>, Vec<usize>) {
+        let device = Default::default();
+        let metric = GlobalStepMetric::default();
+        let logger = EpochLogger::default();
+        let model = LinearModel {
+            linear: LinearConfig::new(1, 1).init(&device),
+        };
+
+        let builder = LearnerBuilder::new(directory)
+            .metric_loggers(InMemoryMetricLogger::new(), logger.clone())
+            .metric_valid(metric.clone())
+            .renderer(QuietRenderer)
+            .with_application_logger(None)
+            .with_file_checkpointer(CompactRecorder::new())
+            .with_checkpointing_strategy(KeepLastNCheckpoints::new(10))
+            .checkpoint_naming(CheckpointNaming::GlobalStep)
+            .validate_every_n_steps(30)
+            .num_epochs(2);
+        let builder = match checkpoint {
+            Some(checkpoint) => builder.checkpoint(checkpoint),
+            None => builder,
+        };
+
+        let learner = builder.build(RegressionTask::new(model), SgdConfig::new().init(), 1e-2);
+        learner.fit(
+            dataloader::<TestAutodiffBackend>(100),
+            dataloader::<TestBackend>(2),
+        );
+
+        let mut steps = metric.steps.lock().unwrap().clone();
+        steps.dedup();
+        let epochs = logger.epochs.lock().unwrap().clone();
+
+        (epochs, steps)
+    }
+
+    #[test]
+    fn should_resume_from_the_epoch_and_validation_of_a_global_step_checkpoint() {
+        let directory = std::env::temp_dir().join("burn-train-resume-global-step");
+        std::fs::remove_dir_all(&directory).ok();
+
+        let (epochs, steps) = fit_with_global_step_checkpoints(&directory, None);
+        assert_eq!(epochs, vec![1, 2, 3, 4, 5, 6, 7, 8]);
+        assert_eq!(steps, vec![30, 60, 90, 100, 120, 150, 180, 200]);
+
+        // In the middle of the second epoch, the 20 items trained on are skipped.
+        let (epochs, steps) = fit_with_global_step_checkpoints(&directory, Some(120));
+        assert_eq!(epochs, vec![6, 7, 8]);
+        assert_eq!(steps, vec![150, 180, 200]);
+
+        // At the end of the first epoch.
+        let (epochs, steps) = fit_with_global_step_checkpoints(&directory, Some(100));
+        assert_eq!(epochs, vec![5, 6, 7, 8]);
+        assert_eq!(steps, vec![120, 150, 180, 200]);
+
+        std::fs::remove_dir_all(&directory).ok();
+    }
+
+    #[test]
+    fn should_validate_every_n_steps_and_at_the_end_of_the_epoch() {
+        let (epochs, steps) = fit_with_validation_steps("burn-train-validation-steps", 30, None);
+
+        assert_eq!(epochs, vec![1, 2, 3, 4]);
+        assert_eq!(steps, vec![30, 60, 90, 100]);
+    }
+
+    #[test]
+    fn should_count_optimizer_steps_with_grads_accumulation() {
+        let (epochs, steps) =
+            fit_with_validation_steps("burn-train-validation-steps-accumulation", 30, Some(2));
+
+        assert_eq!(epochs, vec![1, 2]);
+        assert_eq!(steps, vec![30, 50]);
+    }
+
+    #[test]
+    fn should_not_validate_twice_at_the_end_of_the_epoch() {
+        let (epochs, steps) =
+            fit_with_validation_steps("burn-train-validation-steps-end", 25, None);
+
+        assert_eq!(epochs, vec![1, 2, 3, 4]);
+        assert_eq!(steps, vec![25, 50, 75, 100]);
+    }
+}
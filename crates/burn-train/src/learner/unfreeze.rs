@@ -0,0 +1,260 @@
+use std::collections::BTreeMap;
+
+use burn_core as burn;
+use burn_core::config::Config;
+use burn_core::module::{Module, ModuleMapper, ParamId};
+use burn_core::tensor::{backend::Backend, Tensor};
+
+/// Schedule unfreezing groups of parameters at the beginning of given epochs, also known as
+/// gradual unfreezing.
+///
+/// Parameters are selected with path patterns, such as `encoder.layers.3.*`, where the path of a
+/// parameter is made of the field names and collection indices leading to it, separated by dots.
+/// The `*` wildcard matches any sequence of characters, and a pattern naming a module selects
+/// every parameter inside it.
+///
+/// At the beginning of each epoch, the parameters selected by a later epoch are frozen while the
+/// ones selected by the current or an earlier epoch are trainable. The other parameters are left
+/// untouched. Since the state of the schedule only depends on the epoch, resuming from a
+/// checkpoint continues the schedule where it was stopped.
+///
+/// Frozen parameters don't receive gradients, so the optimizer only starts tracking their state
+/// once they are unfrozen, without affecting the state of the other parameters.
+#[derive(Config, Debug)]
+pub struct UnfreezeSchedule {
+    /// The path patterns of the parameters to unfreeze at the beginning of each epoch.
+    #[config(default = "BTreeMap::new()")]
+    pub stages: BTreeMap<usize, Vec<String>>,
+}
+
+impl UnfreezeSchedule {
+    /// Unfreeze the parameters matching the given path patterns at the beginning of an epoch.
+    pub fn unfreeze<P: Into<String>>(
+        mut self,
+        epoch: usize,
+        patterns: impl IntoIterator<Item = P>,
+    ) -> Self {
+        self.stages
+            .entry(epoch)
+            .or_default()
+            .extend(patterns.into_iter().map(Into::into));
+        self
+    }
+
+    /// Freeze or unfreeze the parameters of the module according to the given epoch.
+    pub fn apply<B: Backend, M: Module<B>>(&self, module: M, epoch: usize) -> M {
+        let mut mapper = UnfreezeMapper {
+            schedule: self,
+            epoch,
+            path: Vec::new(),
+        };
+
+        module.map(&mut mapper)
+    }
+
+    /// Returns if the parameter with the given path should be trainable at the given epoch, or
+    /// `None` if it isn't part of the schedule.
+    pub fn is_trainable(&self, path: &str, epoch: usize) -> Option<bool> {
+        self.stages
+            .iter()
+            .find(|(_, patterns)| patterns.iter().any(|pattern| matches(pattern, path)))
+            .map(|(stage, _)| *stage <= epoch)
+    }
+}
+
+struct UnfreezeMapper<'a> {
+    schedule: &'a UnfreezeSchedule,
+    epoch: usize,
+    path: Vec<String>,
+}
+
+impl<'a, B: Backend> ModuleMapper<B> for UnfreezeMapper<'a> {
+    fn enter_module(&mut self, name: &str) {
+        self.path.push(name.to_string());
+    }
+
+    fn exit_module(&mut self, _name: &str) {
+        self.path.pop();
+    }
+
+    fn map_float<const D: usize>(&mut self, _id: &ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        match self.schedule.is_trainable(&self.path.join("."), self.epoch) {
+            Some(trainable) => tensor.set_require_grad(trainable),
+            None => tensor,
+        }
+    }
+}
+
+/// Returns if the pattern matches the path, or a module containing it.
+fn matches(pattern: &str, path: &str) -> bool {
+    let mut prefix = path;
+
+    loop {
+        if glob(pattern.as_bytes(), prefix.as_bytes()) {
+            return true;
+        }
+
+        match prefix.rfind('.') {
+            Some(index) => prefix = &prefix[..index],
+            None => return false,
+        }
+    }
+}
+
+fn glob(pattern: &[u8], text: &[u8]) -> bool {
+    match pattern.split_first() {
+        None => text.is_empty(),
+        Some((b'*', rest)) => (0..=text.len()).any(|i| glob(rest, &text[i..])),
+        Some((c, rest)) => text.first() == Some(c) && glob(rest, &text[1..]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{TestAutodiffBackend, TestBackend};
+    use burn_core::module::Param;
+    use burn_core::nn::{Linear, LinearConfig};
+    use burn_core::optim::{GradientsParams, Optimizer, SgdConfig};
+
+    #[derive(Module, Debug)]
+    struct Model<B: Backend> {
+        embedding: Linear<B>,
+        layers: Vec<Linear<B>>,
+        head: Linear<B>,
+    }
+
+    impl<B: Backend> Model<B> {
+        fn new(device: &B::Device) -> Self {
+            Self {
+                embedding: LinearConfig::new(2, 4).init(device),
+                layers: vec![
+                    LinearConfig::new(4, 4).init(device),
+                    LinearConfig::new(4, 4).init(device),
+                ],
+                head: LinearConfig::new(4, 1).init(device),
+            }
+        }
+
+        fn forward(&self, inputs: Tensor<B, 2>) -> Tensor<B, 2> {
+            let x = self.embedding.forward(inputs);
+            let x = self
+                .layers
+                .iter()
+                .fold(x, |x, layer| layer.forward(x).tanh());
+
+            self.head.forward(x)
+        }
+    }
+
+    fn ulmfit() -> UnfreezeSchedule {
+        UnfreezeSchedule::new()
+            .unfreeze(1, ["head"])
+            .unfreeze(2, ["layers.1"])
+            .unfreeze(3, ["layers.0.*", "embedding"])
+    }
+
+    fn grads(model: &Model<TestAutodiffBackend>) -> GradientsParams {
+        let device = Default::default();
+        let inputs = Tensor::from_floats([[1.0, -2.0], [0.5, 3.0]], &device);
+        let grads = model.forward(inputs).sum().backward();
+
+        GradientsParams::from_grads(grads, model)
+    }
+
+    fn grad_sum(grads: &GradientsParams, param: &Param<Tensor<TestAutodiffBackend, 2>>) -> f32 {
+        grads
+            .get::<TestBackend, 2>(&param.id)
+            .map(|grad| grad.abs().sum().into_scalar())
+            .unwrap_or(0.0)
+    }
+
+    #[test]
+    fn patterns_should_match_paths_and_modules() {
+        assert!(matches("head.weight", "head.weight"));
+        assert!(matches("head", "head.bias"));
+        assert!(matches("layers.*.weight", "layers.10.weight"));
+        assert!(matches("*.bias", "layers.0.bias"));
+        assert!(!matches("head", "header.weight"));
+        assert!(!matches("layers.1", "layers.10.weight"));
+    }
+
+    #[test]
+    fn schedule_should_only_unfreeze_the_stages_reached() {
+        let schedule = ulmfit();
+
+        assert_eq!(schedule.is_trainable("head.weight", 1), Some(true));
+        assert_eq!(schedule.is_trainable("layers.1.bias", 1), Some(false));
+        assert_eq!(schedule.is_trainable("layers.1.bias", 2), Some(true));
+        assert_eq!(schedule.is_trainable("embedding.weight", 2), Some(false));
+        assert_eq!(schedule.is_trainable("embedding.weight", 3), Some(true));
+        assert_eq!(schedule.is_trainable("other.weight", 3), None);
+    }
+
+    #[test]
+    fn frozen_groups_should_have_zero_gradients_until_unfrozen() {
+        let device = Default::default();
+        let schedule = ulmfit();
+        let model = Model::<TestAutodiffBackend>::new(&device);
+
+        let model = schedule.apply(model, 1);
+        let grads_epoch_1 = grads(&model);
+
+        assert!(grad_sum(&grads_epoch_1, &model.head.weight) > 0.0);
+        assert_eq!(grad_sum(&grads_epoch_1, &model.layers[1].weight), 0.0);
+        assert_eq!(grad_sum(&grads_epoch_1, &model.layers[0].weight), 0.0);
+        assert_eq!(grad_sum(&grads_epoch_1, &model.embedding.weight), 0.0);
+
+        let model = schedule.apply(model, 2);
+        let grads_epoch_2 = grads(&model);
+
+        assert!(grad_sum(&grads_epoch_2, &model.head.weight) > 0.0);
+        assert!(grad_sum(&grads_epoch_2, &model.layers[1].weight) > 0.0);
+        assert_eq!(grad_sum(&grads_epoch_2, &model.layers[0].weight), 0.0);
+        assert_eq!(grad_sum(&grads_epoch_2, &model.embedding.weight), 0.0);
+    }
+
+    #[test]
+    fn optimizer_should_only_update_unfrozen_parameters() {
+        let device = Default::default();
+        let schedule = ulmfit();
+        let mut optim = SgdConfig::new().init();
+        let model = schedule.apply(Model::<TestAutodiffBackend>::new(&device), 1);
+        let initial = model.layers[1].weight.val().inner();
+
+        let model = optim.step(1.0, model.clone(), grads(&model));
+        let updated = model.layers[1].weight.val();
+        assert!(!updated.is_require_grad());
+        updated
+            .inner()
+            .into_data()
+            .assert_eq(&initial.to_data(), true);
+
+        // Unfreezing keeps the parameter values, which are then updated.
+        let model = schedule.apply(model, 2);
+        let updated = model.layers[1].weight.val();
+        assert!(updated.is_require_grad());
+        updated
+            .inner()
+            .into_data()
+            .assert_eq(&initial.to_data(), true);
+
+        let model = optim.step(1.0, model.clone(), grads(&model));
+        let updated = model.layers[1].weight.val();
+        let difference = (updated.clone().inner() - initial)
+            .abs()
+            .sum()
+            .into_scalar();
+        assert!(updated.is_require_grad());
+        assert!(difference > 0.0);
+    }
+
+    #[test]
+    fn schedule_should_be_serializable() {
+        let schedule = ulmfit();
+
+        let loaded = UnfreezeSchedule::load_binary(&schedule.to_string().into_bytes()).unwrap();
+
+        assert_eq!(loaded.stages, schedule.stages);
+    }
+}
@@ -0,0 +1,380 @@
+use super::metric::EPOCH_PREFIX;
+use crate::metric::NumericEntry;
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    fs,
+    path::{Path, PathBuf},
+};
+
+const TRAIN_DIRECTORY: &str = "train";
+const VALID_DIRECTORY: &str = "valid";
+const LOG_EXTENSION: &str = "log";
+
+/// Errors that can occur when loading the metric history of a run.
+#[derive(Debug)]
+pub enum MetricHistoryError {
+    /// The directory doesn't contain the logs of a run.
+    NotARun(PathBuf),
+    /// A log file couldn't be read.
+    Io(PathBuf, std::io::Error),
+    /// A log file contains an entry that isn't numeric.
+    Parse {
+        /// The log file.
+        path: PathBuf,
+        /// The line of the entry, starting at 1.
+        line: usize,
+        /// The parsing error.
+        message: String,
+    },
+}
+
+impl Display for MetricHistoryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotARun(directory) => write!(
+                f,
+                "The directory '{}' doesn't contain any metric logs.",
+                directory.display()
+            ),
+            Self::Io(path, err) => write!(f, "Unable to read '{}': {err}", path.display()),
+            Self::Parse {
+                path,
+                line,
+                message,
+            } => write!(
+                f,
+                "Invalid numeric entry at '{}' line {line}: {message}",
+                path.display()
+            ),
+        }
+    }
+}
+
+impl std::error::Error for MetricHistoryError {}
+
+/// The numeric entries logged for a metric during a run, grouped by epoch.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct MetricSeries {
+    epochs: Vec<Vec<NumericEntry>>,
+}
+
+impl MetricSeries {
+    /// The entries of every epoch, the first epoch being at index 0.
+    ///
+    /// An epoch without any logged entry is empty.
+    pub fn epochs(&self) -> &[Vec<NumericEntry>] {
+        &self.epochs
+    }
+
+    /// The values of all the entries, in logging order.
+    pub fn values(&self) -> impl Iterator<Item = f64> + '_ {
+        self.epochs.iter().flatten().map(entry_value)
+    }
+
+    /// The values of the entries of an epoch, the first epoch being at index 0.
+    pub fn epoch_values(&self, epoch: usize) -> impl Iterator<Item = f64> + '_ {
+        self.epochs
+            .get(epoch)
+            .into_iter()
+            .flatten()
+            .map(entry_value)
+    }
+
+    /// The mean value of each epoch, aggregated entries being weighted by their number of
+    /// elements, or `None` for an epoch without entries.
+    pub fn epoch_means(&self) -> Vec<Option<f64>> {
+        self.epochs
+            .iter()
+            .map(|entries| {
+                let (sum, count) = entries.iter().fold((0.0, 0), |(sum, count), entry| {
+                    let (value, numel) = match entry {
+                        NumericEntry::Value(value) => (*value, 1),
+                        NumericEntry::Aggregated(value, numel) => (*value, *numel),
+                    };
+                    (sum + value * numel as f64, count + numel)
+                });
+
+                (count > 0).then(|| sum / count as f64)
+            })
+            .collect()
+    }
+}
+
+/// The numeric metrics logged by the [file metric logger](super::FileMetricLogger) during a run.
+///
+/// A run directory is the directory given to the learner builder, with the training metrics in
+/// `train/epoch-<n>/<metric>.log` and the validation metrics in `valid/epoch-<n>/<metric>.log`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MetricHistory {
+    name: String,
+    directory: PathBuf,
+    train: BTreeMap<String, MetricSeries>,
+    valid: BTreeMap<String, MetricSeries>,
+}
+
+impl MetricHistory {
+    /// Load the metric history of the run saved in the given directory.
+    pub fn load(directory: impl AsRef<Path>) -> Result<Self, MetricHistoryError> {
+        let directory = directory.as_ref();
+        let train = load_split(&directory.join(TRAIN_DIRECTORY))?;
+        let valid = load_split(&directory.join(VALID_DIRECTORY))?;
+
+        if train.is_empty() && valid.is_empty() {
+            return Err(MetricHistoryError::NotARun(directory.to_path_buf()));
+        }
+
+        let name = directory
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_else(|| directory.display().to_string());
+
+        Ok(Self {
+            name,
+            directory: directory.to_path_buf(),
+            train,
+            valid,
+        })
+    }
+
+    /// Load the metric histories of the runs saved in the sibling directories of the given run
+    /// directory, sorted by name.
+    ///
+    /// The directories without metric logs are skipped, and so are the runs that can't be
+    /// loaded, with a warning.
+    pub fn load_siblings(directory: impl AsRef<Path>) -> Vec<Self> {
+        let directory = directory.as_ref();
+        let Some(parent) = directory.parent() else {
+            return Vec::new();
+        };
+        let Ok(entries) = fs::read_dir(parent) else {
+            return Vec::new();
+        };
+
+        let current = directory.file_name();
+        let mut siblings = entries
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| Some(entry.file_name().as_os_str()) != current)
+            .map(|entry| entry.path())
+            .filter(|path| path.is_dir())
+            .collect::<Vec<_>>();
+        siblings.sort();
+
+        siblings
+            .into_iter()
+            .filter_map(|path| match Self::load(&path) {
+                Ok(history) => Some(history),
+                Err(MetricHistoryError::NotARun(_)) => None,
+                Err(err) => {
+                    log::warn!("Skipping the metric history of '{}': {err}", path.display());
+                    None
+                }
+            })
+            .collect()
+    }
+
+    /// The name of the run, which is the name of its directory.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The directory of the run.
+    pub fn directory(&self) -> &Path {
+        &self.directory
+    }
+
+    /// The training series of the metric with the given name.
+    pub fn train(&self, metric: &str) -> Option<&MetricSeries> {
+        self.train.get(&file_stem(metric))
+    }
+
+    /// The validation series of the metric with the given name.
+    pub fn valid(&self, metric: &str) -> Option<&MetricSeries> {
+        self.valid.get(&file_stem(metric))
+    }
+
+    /// The names of the logged metrics, as written in the log files, where the spaces are
+    /// replaced by underscores.
+    pub fn metrics(&self) -> Vec<&str> {
+        let mut metrics = self
+            .train
+            .keys()
+            .chain(self.valid.keys())
+            .map(String::as_str)
+            .collect::<Vec<_>>();
+        metrics.sort();
+        metrics.dedup();
+        metrics
+    }
+}
+
+fn entry_value(entry: &NumericEntry) -> f64 {
+    match entry {
+        NumericEntry::Value(value) => *value,
+        NumericEntry::Aggregated(value, _) => *value,
+    }
+}
+
+/// The metric name used for the log files, see [FileMetricLogger](super::FileMetricLogger).
+fn file_stem(metric: &str) -> String {
+    metric.replace(' ', "_")
+}
+
+fn load_split(directory: &Path) -> Result<BTreeMap<String, MetricSeries>, MetricHistoryError> {
+    let mut series = BTreeMap::<String, MetricSeries>::new();
+
+    let Ok(entries) = fs::read_dir(directory) else {
+        return Ok(series);
+    };
+
+    let mut epochs = entries
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            let name = entry.file_name().into_string().ok()?;
+            let epoch = name.strip_prefix(EPOCH_PREFIX)?.parse::<usize>().ok()?;
+            (epoch > 0 && entry.path().is_dir()).then(|| (epoch, entry.path()))
+        })
+        .collect::<Vec<_>>();
+    epochs.sort();
+
+    let num_epochs = epochs.last().map(|(epoch, _)| *epoch).unwrap_or(0);
+
+    for (epoch, epoch_directory) in epochs {
+        let files = fs::read_dir(&epoch_directory)
+            .map_err(|err| MetricHistoryError::Io(epoch_directory.clone(), err))?;
+
+        for file in files.filter_map(|entry| entry.ok()) {
+            let path = file.path();
+            if path.extension().and_then(|ext| ext.to_str()) != Some(LOG_EXTENSION) {
+                continue;
+            }
+            let Some(metric) = path.file_stem().and_then(|stem| stem.to_str()) else {
+                continue;
+            };
+
+            let entries = read_entries(&path)?;
+            let metric = series.entry(metric.to_string()).or_default();
+            metric.epochs.resize_with(num_epochs, Vec::new);
+            metric.epochs[epoch - 1] = entries;
+        }
+    }
+
+    Ok(series)
+}
+
+fn read_entries(path: &Path) -> Result<Vec<NumericEntry>, MetricHistoryError> {
+    let content =
+        fs::read_to_string(path).map_err(|err| MetricHistoryError::Io(path.to_path_buf(), err))?;
+
+    content
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(i, line)| {
+            NumericEntry::deserialize(line).map_err(|message| MetricHistoryError::Parse {
+                path: path.to_path_buf(),
+                line: i + 1,
+                message,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_log(directory: &Path, split: &str, epoch: usize, metric: &str, content: &str) {
+        let directory = directory.join(split).join(format!("{EPOCH_PREFIX}{epoch}"));
+        fs::create_dir_all(&directory).unwrap();
+        fs::write(directory.join(format!("{metric}.log")), content).unwrap();
+    }
+
+    fn fixture(name: &str) -> PathBuf {
+        let directory = std::env::temp_dir().join("burn-train-history").join(name);
+        fs::remove_dir_all(&directory).ok();
+
+        let run = directory.join("run-b");
+        write_log(&run, TRAIN_DIRECTORY, 1, "Loss", "2.5\n2.0\n");
+        write_log(&run, TRAIN_DIRECTORY, 2, "Loss", "1.5\n1.0\n");
+        write_log(&run, TRAIN_DIRECTORY, 1, "Learning_Rate", "0.1\n0.1\n");
+        write_log(&run, VALID_DIRECTORY, 1, "Accuracy", "50,10\n80,30\n");
+        write_log(&run, VALID_DIRECTORY, 2, "Loss", "1.2\n");
+
+        let run = directory.join("run-a");
+        write_log(&run, TRAIN_DIRECTORY, 1, "Loss", "3.0\n");
+
+        fs::create_dir_all(directory.join("checkpoints")).unwrap();
+        fs::create_dir_all(directory.join("run-c")).unwrap();
+
+        directory
+    }
+
+    #[test]
+    fn should_load_the_history_of_a_run() {
+        let directory = fixture("load");
+        let history = MetricHistory::load(directory.join("run-b")).unwrap();
+
+        assert_eq!(history.name(), "run-b");
+        assert_eq!(history.metrics(), ["Accuracy", "Learning_Rate", "Loss"]);
+
+        let loss = history.train("Loss").unwrap();
+        assert_eq!(loss.values().collect::<Vec<_>>(), [2.5, 2.0, 1.5, 1.0]);
+        assert_eq!(loss.epoch_means(), [Some(2.25), Some(1.25)]);
+        assert_eq!(loss.epoch_values(1).collect::<Vec<_>>(), [1.5, 1.0]);
+
+        // The metric names are looked up with the spaces of the live metrics.
+        let lr = history.train("Learning Rate").unwrap();
+        assert_eq!(lr.epochs().len(), 2);
+        assert_eq!(lr.epoch_means(), [Some(0.1), None]);
+
+        let loss = history.valid("Loss").unwrap();
+        assert_eq!(loss.epochs(), [vec![], vec![NumericEntry::Value(1.2)]]);
+
+        let accuracy = history.valid("Accuracy").unwrap();
+        assert_eq!(accuracy.epoch_means(), [Some(72.5), None]);
+
+        assert!(history.valid("Learning Rate").is_none());
+    }
+
+    #[test]
+    fn should_load_the_sibling_runs_sorted_by_name() {
+        let directory = fixture("siblings");
+        let histories = MetricHistory::load_siblings(directory.join("run-c"));
+
+        let names = histories
+            .iter()
+            .map(|history| history.name())
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["run-a", "run-b"]);
+
+        let histories = MetricHistory::load_siblings(directory.join("run-b"));
+        assert_eq!(histories.len(), 1);
+        assert_eq!(histories[0].name(), "run-a");
+    }
+
+    #[test]
+    fn should_fail_to_load_a_directory_without_logs() {
+        let directory = fixture("empty");
+        let result = MetricHistory::load(directory.join("checkpoints"));
+
+        assert!(matches!(result, Err(MetricHistoryError::NotARun(_))));
+    }
+
+    #[test]
+    fn should_report_the_line_of_invalid_entries() {
+        let directory = fixture("invalid");
+        write_log(
+            &directory.join("run-a"),
+            TRAIN_DIRECTORY,
+            2,
+            "Loss",
+            "1.0\nnan?\n",
+        );
+
+        match MetricHistory::load(directory.join("run-a")) {
+            Err(MetricHistoryError::Parse { line, .. }) => assert_eq!(line, 2),
+            result => panic!("Expected a parsing error, got {result:?}"),
+        }
+    }
+}
@@ -6,7 +6,7 @@ use std::{
     path::{Path, PathBuf},
 };
 
-const EPOCH_PREFIX: &str = "epoch-";
+pub(crate) const EPOCH_PREFIX: &str = "epoch-";
 
 /// Metric logger.
 pub trait MetricLogger: Send {
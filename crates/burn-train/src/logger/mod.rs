@@ -1,11 +1,13 @@
 mod async_logger;
 mod base;
 mod file;
+mod history;
 mod in_memory;
 mod metric;
 
 pub use async_logger::*;
 pub use base::*;
 pub use file::*;
+pub use history::*;
 pub use in_memory::*;
 pub use metric::*;
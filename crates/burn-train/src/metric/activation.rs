@@ -0,0 +1,129 @@
+use super::{
+    state::{FormatOptions, NumericMetricState},
+    MetricMetadata, Numeric, NumericEntry,
+};
+use crate::metric::{Metric, MetricEntry};
+use burn_core::module::{ActivationStats, LayerActivationStats, Module};
+use burn_core::tensor::backend::Backend;
+
+/// The statistic of the outputs tracked by the [activation statistics metric](ActivationStatsMetric).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ActivationStatistic {
+    /// The mean of the outputs.
+    Mean,
+    /// The standard deviation of the outputs.
+    Std,
+    /// The proportion of the outputs equal to zero, e.g. the dead units after a ReLU.
+    ZerosRatio,
+}
+
+impl ActivationStatistic {
+    fn name(&self) -> &'static str {
+        match self {
+            ActivationStatistic::Mean => "Mean",
+            ActivationStatistic::Std => "Std",
+            ActivationStatistic::ZerosRatio => "Zeros Ratio",
+        }
+    }
+
+    fn value(&self, stats: &LayerActivationStats) -> f64 {
+        match self {
+            ActivationStatistic::Mean => stats.mean(),
+            ActivationStatistic::Std => stats.std(),
+            ActivationStatistic::ZerosRatio => stats.zeros_ratio(),
+        }
+    }
+}
+
+/// Track a statistic of the outputs of a layer, collected with [activation statistics](ActivationStats).
+///
+/// The statistics are collected during the forward passes of the layer between two updates, so the
+/// metric doesn't need any input. Since the hooks are specific to a backend, a metric created with
+/// the training model only tracks the training forward passes, and one created with the
+/// [valid](burn_core::module::AutodiffModule::valid) model only tracks the validation ones.
+pub struct ActivationStatsMetric {
+    stats: ActivationStats,
+    statistic: ActivationStatistic,
+    name: String,
+    state: NumericMetricState,
+}
+
+impl ActivationStatsMetric {
+    /// Creates a new activation statistics metric tracking the layer of `model` at the given path,
+    /// such as `encoder.layers.0.linear`.
+    pub fn new<B: Backend, M: Module<B>>(
+        model: &M,
+        layer: &str,
+        statistic: ActivationStatistic,
+    ) -> Self {
+        Self {
+            stats: ActivationStats::register(model, layer),
+            statistic,
+            name: format!("Activation {} ({layer})", statistic.name()),
+            state: NumericMetricState::new(),
+        }
+    }
+}
+
+impl Drop for ActivationStatsMetric {
+    fn drop(&mut self) {
+        self.stats.remove();
+    }
+}
+
+impl Metric for ActivationStatsMetric {
+    const NAME: &'static str = "Activation Statistics";
+
+    type Input = ();
+
+    fn update(&mut self, _item: &(), _metadata: &MetricMetadata) -> MetricEntry {
+        let layers = self.stats.take();
+
+        match layers.into_values().next() {
+            Some(stats) => self.state.update(
+                self.statistic.value(&stats),
+                stats.num_elements,
+                FormatOptions::new(&self.name).precision(4),
+            ),
+            // The layer wasn't called since the last update.
+            None => MetricEntry::new(
+                self.name.clone(),
+                String::from("no forward pass"),
+                NumericEntry::Value(self.state.value()).serialize(),
+            ),
+        }
+    }
+
+    fn clear(&mut self) {
+        self.stats.take();
+        self.state.reset()
+    }
+}
+
+impl Numeric for ActivationStatsMetric {
+    fn value(&self) -> f64 {
+        self.state.value()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_core::nn::{Linear, LinearConfig};
+    use burn_core::tensor::Tensor;
+
+    #[test]
+    fn test_activation_zeros_ratio() {
+        let device = Default::default();
+        let linear: Linear<TestBackend> = LinearConfig::new(2, 2).with_bias(false).init(&device);
+        let mut metric = ActivationStatsMetric::new(&linear, "", ActivationStatistic::ZerosRatio);
+
+        linear.forward(Tensor::<TestBackend, 2>::zeros([4, 2], &device));
+        let _entry = metric.update(&(), &MetricMetadata::fake());
+        assert_eq!(1.0, metric.value());
+
+        let entry = metric.update(&(), &MetricMetadata::fake());
+        assert_eq!(entry.formatted, "no forward pass");
+    }
+}
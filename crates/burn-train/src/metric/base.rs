@@ -1,6 +1,11 @@
 use burn_core::{data::dataloader::Progress, LearningRate};
 
+use super::GradientStats;
+
 /// Metric metadata that can be used when computing metrics.
+///
+/// The metadata is created with [new](MetricMetadata::new), the
+/// [gradient statistics](MetricMetadata::with_gradients) being optional.
 pub struct MetricMetadata {
     /// The current progress.
     pub progress: Progress,
@@ -14,23 +19,61 @@ pub struct MetricMetadata {
     /// The current iteration.
     pub iteration: usize,
 
+    /// The number of optimizer steps done since the start of the training.
+    pub global_step: usize,
+
     /// The current learning rate.
     pub lr: Option<LearningRate>,
+
+    /// The gradient statistics of the current training item, if any.
+    pub(crate) gradients: Option<GradientStats>,
 }
 
 impl MetricMetadata {
+    /// Create the metadata of an item without gradient statistics.
+    pub fn new(
+        progress: Progress,
+        epoch: usize,
+        epoch_total: usize,
+        iteration: usize,
+        global_step: usize,
+        lr: Option<LearningRate>,
+    ) -> Self {
+        Self {
+            progress,
+            epoch,
+            epoch_total,
+            iteration,
+            global_step,
+            lr,
+            gradients: None,
+        }
+    }
+
+    /// Attach the gradient statistics of the item.
+    pub fn with_gradients(mut self, gradients: GradientStats) -> Self {
+        self.gradients = Some(gradients);
+        self
+    }
+
+    /// The gradient statistics of the current training item, if any.
+    pub fn gradients(&self) -> Option<&GradientStats> {
+        self.gradients.as_ref()
+    }
+
     #[cfg(test)]
     pub fn fake() -> Self {
-        Self {
-            progress: Progress {
+        Self::new(
+            Progress {
                 items_processed: 1,
                 items_total: 1,
             },
-            epoch: 0,
-            epoch_total: 1,
-            iteration: 0,
-            lr: None,
-        }
+            0,
+            1,
+            0,
+            0,
+            None,
+        )
     }
 }
 
@@ -85,6 +128,7 @@ pub struct MetricEntry {
 }
 
 /// Numeric metric entry.
+#[derive(Debug, Clone, PartialEq)]
 pub enum NumericEntry {
     /// Single numeric value.
     Value(f64),
@@ -0,0 +1,529 @@
+use std::sync::Arc;
+
+use super::{format_float, MetricMetadata, Numeric, NumericEntry};
+use crate::metric::{Metric, MetricEntry};
+use burn_core::module::{AutodiffModule, ModuleVisitor, ParamId};
+use burn_core::optim::GradientsParams;
+use burn_core::tensor::backend::AutodiffBackend;
+use burn_core::tensor::Tensor;
+
+/// The norms of the gradients of a module, in total and for each of its top-level modules.
+///
+/// They are attached to the [training output](crate::TrainOutput) with
+/// [with_gradient_norms](crate::TrainOutput::with_gradient_norms), and shared by reference with
+/// the [gradient statistics](GradientStatsMetric) metric.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GradientNorms {
+    squared_modules: Vec<(String, f64)>,
+}
+
+impl GradientNorms {
+    /// Create the norms from the squared norms of the gradients of each top-level module.
+    pub fn new(squared_modules: Vec<(String, f64)>) -> Self {
+        Self { squared_modules }
+    }
+
+    /// Compute the norms of the gradients of the parameters of the module, grouped by the
+    /// top-level modules they belong to.
+    ///
+    /// The squared norms of all the parameters are summed on the device, so the values are read
+    /// back only once.
+    pub fn from_grads<B: AutodiffBackend, M: AutodiffModule<B>>(
+        grads: &GradientsParams,
+        module: &M,
+    ) -> Self {
+        let mut visitor = GradientNormsVisitor::<B> {
+            grads,
+            depth: 0,
+            modules: Vec::new(),
+        };
+        module.visit(&mut visitor);
+
+        let (names, sums): (Vec<_>, Vec<_>) = visitor
+            .modules
+            .into_iter()
+            .filter_map(|(name, sum)| sum.map(|sum| (name, sum)))
+            .unzip();
+
+        if sums.is_empty() {
+            return Self::new(Vec::new());
+        }
+
+        let values = Tensor::cat(sums, 0).into_data();
+        let squared_modules = names.into_iter().zip(values.iter::<f64>()).collect();
+
+        Self::new(squared_modules)
+    }
+
+    /// The norm of all the gradients.
+    pub fn total(&self) -> f64 {
+        self.squared_total().sqrt()
+    }
+
+    /// The squared norm of all the gradients.
+    pub fn squared_total(&self) -> f64 {
+        self.squared_modules
+            .iter()
+            .map(|(_, squared)| squared)
+            .sum()
+    }
+
+    /// The norms of the gradients of each top-level module with gradients, in the order of the
+    /// fields of the module.
+    pub fn modules(&self) -> impl Iterator<Item = (&str, f64)> {
+        self.squared_modules
+            .iter()
+            .map(|(name, squared)| (name.as_str(), squared.sqrt()))
+    }
+}
+
+struct GradientNormsVisitor<'a, B: AutodiffBackend> {
+    grads: &'a GradientsParams,
+    depth: usize,
+    modules: Vec<(String, Option<Tensor<B::InnerBackend, 1>>)>,
+}
+
+impl<B: AutodiffBackend> ModuleVisitor<B> for GradientNormsVisitor<'_, B> {
+    fn visit_float<const D: usize>(&mut self, id: &ParamId, _tensor: &Tensor<B, D>) {
+        let Some(grad) = self.grads.get::<B::InnerBackend, D>(id) else {
+            return;
+        };
+
+        if self.modules.is_empty() {
+            self.modules.push((String::new(), None));
+        }
+
+        let squared = grad.powf_scalar(2.0).sum();
+        let (_, sum) = self.modules.last_mut().unwrap();
+
+        *sum = Some(match sum.take() {
+            Some(sum) => sum + squared,
+            None => squared,
+        });
+    }
+
+    fn enter_module(&mut self, name: &str) {
+        if self.depth == 0 {
+            self.modules.push((name.to_string(), None));
+        }
+        self.depth += 1;
+    }
+
+    fn exit_module(&mut self, _name: &str) {
+        self.depth -= 1;
+    }
+}
+
+/// The gradient statistics of a training item, given to the metrics with its
+/// [metadata](MetricMetadata) when the norms are attached to the training output.
+#[derive(Debug, Clone)]
+pub struct GradientStats {
+    /// The norms of the gradients of the item.
+    pub norms: Arc<GradientNorms>,
+    /// The norms of the accumulated gradients, when the optimizer step is done after this item
+    /// with gradients accumulation.
+    pub accumulated: Option<AccumulatedGradientNorms>,
+}
+
+/// The norms of the gradients accumulated over several items before an optimizer step.
+#[derive(new, Debug, Clone)]
+pub struct AccumulatedGradientNorms {
+    /// The norms of the sum of the gradients of the items.
+    pub norms: Arc<GradientNorms>,
+    /// The number of items whose gradients are summed.
+    pub num_items: usize,
+}
+
+/// Online estimate of the gradient noise scale `B = tr(Σ) / |G|²`, from the gradients of small
+/// and large batches, following [An Empirical Model of Large-Batch Training](https://arxiv.org/abs/1812.06162).
+///
+/// With `k` micro-batches summed by the gradients accumulation, the squared norms of the
+/// gradients of the micro-batches and of their mean give an unbiased estimate of both `|G|²` and
+/// `tr(Σ)` at each optimizer step. Both are smoothed with bias-corrected exponential moving
+/// averages before their ratio is taken, the ratio of the noisy estimates themselves being
+/// biased. The noise scale is in units of micro-batches.
+#[derive(Debug, Clone)]
+pub struct NoiseScaleEstimator {
+    beta: f64,
+    signal: f64,
+    noise: f64,
+    weight: f64,
+}
+
+impl NoiseScaleEstimator {
+    /// Create an estimator smoothing the estimates with the given decay, between 0 and 1.
+    pub fn new(beta: f64) -> Self {
+        Self {
+            beta,
+            signal: 0.0,
+            noise: 0.0,
+            weight: 0.0,
+        }
+    }
+
+    /// Update the estimate with the squared norms of the gradients of the micro-batches and the
+    /// squared norm of their sum, returning the current noise scale.
+    ///
+    /// A single micro-batch doesn't give any information on the noise, the estimate being left
+    /// unchanged.
+    pub fn update(&mut self, micro_batches: &[f64], squared_sum: f64) -> Option<f64> {
+        let k = micro_batches.len() as f64;
+
+        if micro_batches.len() > 1 {
+            let small = micro_batches.iter().sum::<f64>() / k;
+            let large = squared_sum / (k * k);
+            let signal = (k * large - small) / (k - 1.0);
+            let noise = (small - large) / (1.0 - 1.0 / k);
+
+            self.signal = self.beta * self.signal + (1.0 - self.beta) * signal;
+            self.noise = self.beta * self.noise + (1.0 - self.beta) * noise;
+            self.weight = self.beta * self.weight + (1.0 - self.beta);
+        }
+
+        self.value()
+    }
+
+    /// The current noise scale, if any optimizer step with at least two micro-batches was seen.
+    pub fn value(&self) -> Option<f64> {
+        match self.weight > 0.0 {
+            // The bias corrections of both averages cancel out.
+            true => Some(self.noise / self.signal),
+            false => None,
+        }
+    }
+
+    /// Reset the estimate.
+    pub fn reset(&mut self) {
+        *self = Self::new(self.beta);
+    }
+}
+
+/// Track the norms of the gradients over training: in total, for each top-level module and, with
+/// gradients accumulation, the gradient noise scale estimated from the micro-batches.
+///
+/// The gradient norms have to be attached to the training output with
+/// [with_gradient_norms](crate::TrainOutput::with_gradient_norms). The numeric value of the
+/// metric is the total norm, the rendering summarizing the largest norms of the modules.
+pub struct GradientStatsMetric {
+    estimator: NoiseScaleEstimator,
+    micro_batches: Vec<f64>,
+    batch_size: Option<usize>,
+    num_modules: usize,
+    current: f64,
+}
+
+impl GradientStatsMetric {
+    /// Creates a new gradient statistics metric.
+    pub fn new() -> Self {
+        Self {
+            estimator: NoiseScaleEstimator::new(0.95),
+            micro_batches: Vec::new(),
+            batch_size: None,
+            num_modules: 3,
+            current: f64::NAN,
+        }
+    }
+
+    /// The number of items of each micro-batch, so the noise scale is given in items instead of
+    /// micro-batches.
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = Some(batch_size);
+        self
+    }
+
+    /// The decay of the moving averages of the noise scale estimator, `0.95` by default.
+    pub fn with_noise_scale_decay(mut self, beta: f64) -> Self {
+        self.estimator = NoiseScaleEstimator::new(beta);
+        self
+    }
+
+    /// The number of modules with the largest norms shown in the summary, `3` by default.
+    pub fn with_num_modules(mut self, num_modules: usize) -> Self {
+        self.num_modules = num_modules;
+        self
+    }
+
+    /// The current gradient noise scale, if it could be estimated.
+    pub fn noise_scale(&self) -> Option<f64> {
+        self.estimator
+            .value()
+            .map(|value| value * self.batch_size.unwrap_or(1) as f64)
+    }
+
+    fn summary(&self, norms: &GradientNorms) -> String {
+        let mut modules = norms.modules().collect::<Vec<_>>();
+        modules.sort_by(|(_, lhs), (_, rhs)| rhs.total_cmp(lhs));
+
+        let mut summary = format!("total {}", format_float(norms.total(), 2));
+
+        if let Some(noise_scale) = self.noise_scale() {
+            summary += &format!(" - noise scale {}", format_float(noise_scale, 2));
+        }
+
+        if modules.len() > 1 {
+            let shown = modules
+                .iter()
+                .take(self.num_modules)
+                .map(|(name, norm)| format!("{name} {}", format_float(*norm, 2)))
+                .collect::<Vec<_>>();
+            summary += &format!(" - {}", shown.join(", "));
+
+            if modules.len() > self.num_modules {
+                summary += &format!(", +{} more", modules.len() - self.num_modules);
+            }
+        }
+
+        summary
+    }
+}
+
+impl Default for GradientStatsMetric {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Metric for GradientStatsMetric {
+    const NAME: &'static str = "Gradient Stats";
+
+    type Input = ();
+
+    fn update(&mut self, _item: &(), metadata: &MetricMetadata) -> MetricEntry {
+        let Some(stats) = metadata.gradients() else {
+            self.current = f64::NAN;
+            let formatted = "no gradient norms attached to the training output".to_string();
+
+            return MetricEntry::new(
+                Self::NAME.to_string(),
+                formatted,
+                NumericEntry::Value(self.current).serialize(),
+            );
+        };
+
+        self.micro_batches.push(stats.norms.squared_total());
+
+        if let Some(accumulated) = stats.accumulated.as_ref() {
+            // The items of the window missing their norms are dropped from the estimate.
+            let start = self
+                .micro_batches
+                .len()
+                .saturating_sub(accumulated.num_items);
+
+            if self.micro_batches.len() - start == accumulated.num_items {
+                self.estimator.update(
+                    &self.micro_batches[start..],
+                    accumulated.norms.squared_total(),
+                );
+            }
+            self.micro_batches.clear();
+        }
+
+        self.current = stats.norms.total();
+
+        MetricEntry::new(
+            Self::NAME.to_string(),
+            self.summary(&stats.norms),
+            NumericEntry::Value(self.current).serialize(),
+        )
+    }
+
+    fn clear(&mut self) {
+        self.micro_batches.clear();
+        self.current = f64::NAN;
+    }
+}
+
+impl Numeric for GradientStatsMetric {
+    fn value(&self) -> f64 {
+        self.current
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{TestAutodiffBackend, TestBackend};
+    use burn_core as burn;
+    use burn_core::module::Module;
+    use burn_core::nn::{Linear, LinearConfig};
+    use burn_core::tensor::{backend::Backend, Distribution};
+
+    #[derive(Module, Debug)]
+    struct Model<B: Backend> {
+        embedding: Linear<B>,
+        layers: Vec<Linear<B>>,
+        head: Linear<B>,
+    }
+
+    fn model() -> Model<TestAutodiffBackend> {
+        let device = Default::default();
+
+        Model {
+            embedding: LinearConfig::new(2, 4).init(&device),
+            layers: vec![
+                LinearConfig::new(4, 4).with_bias(false).init(&device),
+                LinearConfig::new(4, 4).with_bias(false).init(&device),
+            ],
+            head: LinearConfig::new(4, 1).init(&device),
+        }
+    }
+
+    /// Register gradients filled with the given value for every parameter of the linear layer.
+    fn register(grads: &mut GradientsParams, linear: &Linear<TestAutodiffBackend>, value: f32) {
+        let device = Default::default();
+        let weight = Tensor::<TestBackend, 2>::full(linear.weight.shape(), value, &device);
+        grads.register(linear.weight.id.clone(), weight);
+
+        if let Some(bias) = linear.bias.as_ref() {
+            let bias = Tensor::<TestBackend, 1>::full(bias.shape(), value, &device);
+            grads.register(bias.id.clone(), bias);
+        }
+    }
+
+    fn metadata(gradients: Option<GradientStats>) -> MetricMetadata {
+        MetricMetadata {
+            gradients,
+            ..MetricMetadata::fake()
+        }
+    }
+
+    #[test]
+    fn norms_should_be_grouped_by_top_level_module() {
+        let model = model();
+        let mut grads = GradientsParams::new();
+        register(&mut grads, &model.embedding, 1.0);
+        register(&mut grads, &model.layers[0], 2.0);
+        register(&mut grads, &model.layers[1], -1.0);
+
+        let norms = GradientNorms::from_grads(&grads, &model);
+
+        // embedding: 8 + 4 ones, layers: 16 twos and 16 minus ones, head: no gradients.
+        assert_eq!(
+            norms.modules().collect::<Vec<_>>(),
+            vec![("embedding", 12.0f64.sqrt()), ("layers", 80.0f64.sqrt())]
+        );
+        assert_eq!(norms.squared_total(), 92.0);
+        assert_eq!(norms.total(), 92.0f64.sqrt());
+    }
+
+    #[test]
+    fn norms_of_empty_gradients_should_be_zero() {
+        let norms = GradientNorms::from_grads(&GradientsParams::new(), &model());
+
+        assert_eq!(norms.modules().count(), 0);
+        assert_eq!(norms.total(), 0.0);
+    }
+
+    #[test]
+    fn noise_scale_should_match_a_known_distribution() {
+        // Gradients `G + ε` of 64 dimensions with `|G|² = 16` and `tr(Σ) = 64`, so `B = 4`.
+        let device = Default::default();
+        let (num_steps, num_micro_batches, dims) = (400, 4, 64);
+        TestBackend::seed(0);
+        let noise = Tensor::<TestBackend, 3>::random(
+            [num_steps, num_micro_batches, dims],
+            Distribution::Normal(0.0, 1.0),
+            &device,
+        );
+        let grads = noise + 0.5;
+
+        let micro_batches = grads
+            .clone()
+            .powf_scalar(2.0)
+            .sum_dim(2)
+            .into_data()
+            .iter::<f64>()
+            .collect::<Vec<_>>();
+        let sums = grads
+            .sum_dim(1)
+            .powf_scalar(2.0)
+            .sum_dim(2)
+            .into_data()
+            .iter::<f64>()
+            .collect::<Vec<_>>();
+
+        let mut estimator = NoiseScaleEstimator::new(0.99);
+        for (step, squared_sum) in sums.into_iter().enumerate() {
+            let start = step * num_micro_batches;
+            estimator.update(
+                &micro_batches[start..start + num_micro_batches],
+                squared_sum,
+            );
+        }
+
+        let noise_scale = estimator.value().unwrap();
+        assert!(
+            (noise_scale - 4.0).abs() < 1.0,
+            "The noise scale {noise_scale} should be close to 4"
+        );
+    }
+
+    #[test]
+    fn noise_scale_should_need_several_micro_batches() {
+        let mut estimator = NoiseScaleEstimator::new(0.9);
+
+        assert_eq!(estimator.update(&[1.0], 1.0), None);
+        assert!(estimator.update(&[1.0, 3.0], 6.0).is_some());
+
+        estimator.reset();
+        assert_eq!(estimator.value(), None);
+    }
+
+    #[test]
+    fn metric_should_estimate_the_noise_scale_at_the_optimizer_steps() {
+        let mut metric = GradientStatsMetric::new()
+            .with_noise_scale_decay(0.0)
+            .with_batch_size(8);
+        let norms = |squared: f64| Arc::new(GradientNorms::new(vec![("model".into(), squared)]));
+
+        // Micro-batches of squared norms 2 and 4 with a sum of squared norm 10: the mean has a
+        // squared norm of 2.5, so `|G|² = 2` and `tr(Σ) = 1`.
+        let first = GradientStats {
+            norms: norms(2.0),
+            accumulated: None,
+        };
+        let second = GradientStats {
+            norms: norms(4.0),
+            accumulated: Some(AccumulatedGradientNorms::new(norms(10.0), 2)),
+        };
+
+        metric.update(&(), &metadata(Some(first)));
+        assert_eq!(metric.noise_scale(), None);
+
+        let entry = metric.update(&(), &metadata(Some(second)));
+        assert_eq!(metric.noise_scale(), Some(4.0));
+        assert_eq!(metric.value(), 2.0);
+        assert_eq!(entry.formatted, "total 2.00 - noise scale 4.00");
+    }
+
+    #[test]
+    fn summary_should_show_the_largest_modules() {
+        let mut metric = GradientStatsMetric::new().with_num_modules(2);
+        let norms = GradientNorms::new(vec![
+            ("embedding".into(), 0.04),
+            ("encoder".into(), 9.0),
+            ("decoder".into(), 1.0),
+            ("head".into(), 0.01),
+        ]);
+        let stats = GradientStats {
+            norms: Arc::new(norms),
+            accumulated: None,
+        };
+
+        let entry = metric.update(&(), &metadata(Some(stats)));
+
+        assert_eq!(
+            entry.formatted,
+            "total 3.17 - encoder 3.00, decoder 1.00, +2 more"
+        );
+    }
+
+    #[test]
+    fn metric_without_gradient_norms_should_be_nan() {
+        let mut metric = GradientStatsMetric::new();
+
+        metric.update(&(), &metadata(None));
+
+        assert!(metric.value().is_nan());
+    }
+}
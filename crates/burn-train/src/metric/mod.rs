@@ -2,6 +2,7 @@
 pub mod state;
 
 mod acc;
+mod activation;
 mod base;
 #[cfg(feature = "metrics")]
 mod cpu_temp;
@@ -9,6 +10,7 @@ mod cpu_temp;
 mod cpu_use;
 #[cfg(feature = "metrics")]
 mod cuda;
+mod gradient_stats;
 mod hamming;
 mod learning_rate;
 mod loss;
@@ -19,6 +21,7 @@ mod memory_use;
 mod top_k_acc;
 
 pub use acc::*;
+pub use activation::*;
 pub use base::*;
 #[cfg(feature = "metrics")]
 pub use cpu_temp::*;
@@ -26,6 +29,7 @@ pub use cpu_temp::*;
 pub use cpu_use::*;
 #[cfg(feature = "metrics")]
 pub use cuda::*;
+pub use gradient_stats::*;
 pub use hamming::*;
 pub use learning_rate::*;
 pub use loss::*;
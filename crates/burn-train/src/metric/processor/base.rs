@@ -1,6 +1,8 @@
 use burn_core::data::dataloader::Progress;
 use burn_core::LearningRate;
 
+use crate::metric::GradientStats;
+
 /// Event happening during the training/validation process.
 pub enum Event<T> {
     /// Signal that an item have been processed.
@@ -40,6 +42,13 @@ pub struct LearnerItem<T> {
     /// The iteration.
     pub iteration: usize,
 
+    /// The number of optimizer steps done since the start of the training.
+    pub global_step: usize,
+
     /// The learning rate.
     pub lr: Option<LearningRate>,
+
+    /// The gradient statistics, when the norms are attached to the training output.
+    #[new(default)]
+    pub gradients: Option<GradientStats>,
 }
@@ -141,12 +141,18 @@ impl<T> From<&LearnerItem<T>> for TrainingProgress {
 
 impl<T> From<&LearnerItem<T>> for MetricMetadata {
     fn from(item: &LearnerItem<T>) -> Self {
-        Self {
-            progress: item.progress.clone(),
-            epoch: item.epoch,
-            epoch_total: item.epoch_total,
-            iteration: item.iteration,
-            lr: item.lr,
+        let metadata = Self::new(
+            item.progress.clone(),
+            item.epoch,
+            item.epoch_total,
+            item.iteration,
+            item.global_step,
+            item.lr,
+        );
+
+        match &item.gradients {
+            Some(gradients) => metadata.with_gradients(gradients.clone()),
+            None => metadata,
         }
     }
 }
@@ -43,6 +43,7 @@ pub(crate) mod test_utils {
             epoch,
             num_epochs,
             dummy_iteration,
+            dummy_iteration,
             None,
         )));
     }
@@ -9,17 +9,23 @@ pub use cli::CliMetricsRenderer as SelectedMetricsRenderer;
 #[cfg(feature = "tui")]
 mod tui;
 use crate::TrainingInterrupter;
+use std::path::Path;
 #[cfg(feature = "tui")]
 pub use tui::TuiMetricsRenderer as SelectedMetricsRenderer;
 
 /// The TUI renderer, or a simple stub if the tui feature is not enabled.
+///
+/// The TUI renderer can compare the live plots with the runs saved in the sibling directories
+/// of the experiment directory.
 #[allow(unused_variables)]
 pub(crate) fn default_renderer(
     interuptor: TrainingInterrupter,
     checkpoint: Option<usize>,
+    directory: &Path,
 ) -> SelectedMetricsRenderer {
     #[cfg(feature = "tui")]
-    return SelectedMetricsRenderer::new(interuptor, checkpoint);
+    return SelectedMetricsRenderer::new(interuptor, checkpoint)
+        .with_reference_runs(crate::logger::MetricHistory::load_siblings(directory));
 
     #[cfg(not(feature = "tui"))]
     return SelectedMetricsRenderer::new();
@@ -28,6 +28,11 @@ impl ControlsView {
                 Span::from("⬆ ⬇").bold(),
                 Span::from("  Switch between types.").italic(),
             ],
+            vec![
+                Span::from(" Plots Compare : ").yellow().bold(),
+                Span::from("r  ").bold(),
+                Span::from("  Cycle through previous runs.").italic(),
+            ],
         ];
         let paragraph = Paragraph::new(lines.into_iter().map(Line::from).collect::<Vec<_>>())
             .alignment(Alignment::Left)
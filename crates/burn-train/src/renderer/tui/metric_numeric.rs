@@ -1,6 +1,7 @@
+use crate::logger::MetricHistory;
 use crate::renderer::TrainingProgress;
 
-use super::{FullHistoryPlot, RecentHistoryPlot, TerminalFrame};
+use super::{FullHistoryPlot, RecentHistoryPlot, ReferencePlot, TerminalFrame};
 use crossterm::event::{Event, KeyCode, KeyEventKind};
 use ratatui::{
     prelude::{Alignment, Constraint, Direction, Layout, Rect},
@@ -25,6 +26,14 @@ pub(crate) struct NumericMetricsState {
     kind: PlotKind,
     num_samples_train: Option<usize>,
     num_samples_valid: Option<usize>,
+    references: Vec<MetricHistory>,
+    reference: Option<ReferenceState>,
+}
+
+/// The previous run drawn behind the live plots, with the plots of its metrics.
+struct ReferenceState {
+    index: usize,
+    plots: HashMap<String, ReferencePlot>,
 }
 
 /// The kind of plot to display.
@@ -96,11 +105,25 @@ impl NumericMetricsState {
         self.num_samples_valid = Some(progress.progress.items_total);
     }
 
+    /// Set the previous runs that can be drawn behind the live plots for comparison.
+    pub(crate) fn set_references(&mut self, references: Vec<MetricHistory>) {
+        self.references = references;
+        self.reference = None;
+    }
+
     /// Create a view to display the numeric metrics.
     pub(crate) fn view(&self) -> NumericMetricView<'_> {
         match self.names.is_empty() {
             true => NumericMetricView::None,
-            false => NumericMetricView::Plots(&self.names, self.selected, self.chart(), self.kind),
+            false => NumericMetricView::Plots(
+                &self.names,
+                self.selected,
+                self.chart(),
+                self.kind,
+                self.reference
+                    .as_ref()
+                    .map(|reference| self.references[reference.index].name()),
+            ),
         }
     }
 
@@ -119,6 +142,7 @@ impl NumericMetricsState {
                 KeyCode::Left => self.previous_metric(),
                 KeyCode::Up => self.switch_kind(),
                 KeyCode::Down => self.switch_kind(),
+                KeyCode::Char('r') => self.next_reference(),
                 _ => {}
             }
         }
@@ -131,6 +155,27 @@ impl NumericMetricsState {
         };
     }
 
+    /// Cycle through the previous runs, and then back to the live plots alone.
+    fn next_reference(&mut self) {
+        let index = match &self.reference {
+            Some(reference) => reference.index + 1,
+            None => 0,
+        };
+
+        self.reference = self.references.get(index).map(|history| {
+            let plots = history
+                .metrics()
+                .into_iter()
+                .filter_map(|metric| {
+                    ReferencePlot::new(history, metric, MAX_NUM_SAMPLES_FULL)
+                        .map(|plot| (metric.to_string(), plot))
+                })
+                .collect();
+
+            ReferenceState { index, plots }
+        });
+    }
+
     fn next_metric(&mut self) {
         self.selected = (self.selected + 1) % {
             let this = &self;
@@ -153,11 +198,25 @@ impl NumericMetricsState {
         let name = self.names.get(self.selected).unwrap();
         let (recent, full) = self.data.get(name).unwrap();
 
-        let (datasets, axes) = match self.kind {
-            PlotKind::Full => (full.datasets(), &full.axes),
-            PlotKind::Recent => (recent.datasets(), &recent.axes),
+        let (mut datasets, mut axes) = match self.kind {
+            PlotKind::Full => (full.datasets(), full.axes.clone()),
+            PlotKind::Recent => (recent.datasets(), recent.axes.clone()),
         };
 
+        // The reference datasets come first to be drawn behind the live ones.
+        let reference = self
+            .reference
+            .as_ref()
+            .and_then(|reference| reference.plots.get(&name.replace(' ', "_")));
+        if let Some(reference) = reference {
+            if let Some(bounds_y) = reference.bounds_y(self.kind, axes.bounds_x) {
+                axes.extend_bounds_y(bounds_y);
+            }
+            let mut all = reference.datasets(self.kind, axes.bounds_x);
+            all.append(&mut datasets);
+            datasets = all;
+        }
+
         Chart::<'a>::new(datasets)
             .block(Block::default())
             .x_axis(
@@ -190,14 +249,14 @@ impl NumericMetricsState {
 
 #[derive(new)]
 pub(crate) enum NumericMetricView<'a> {
-    Plots(&'a [String], usize, Chart<'a>, PlotKind),
+    Plots(&'a [String], usize, Chart<'a>, PlotKind, Option<&'a str>),
     None,
 }
 
 impl<'a> NumericMetricView<'a> {
     pub(crate) fn render(self, frame: &mut TerminalFrame<'_>, size: Rect) {
         match self {
-            Self::Plots(titles, selected, chart, kind) => {
+            Self::Plots(titles, selected, chart, kind, reference) => {
                 let block = Block::default()
                     .borders(Borders::ALL)
                     .title("Plots")
@@ -233,8 +292,13 @@ impl<'a> NumericMetricView<'a> {
                     PlotKind::Recent => "Recent History",
                 };
 
-                let plot_type =
-                    Paragraph::new(Line::from(title.bold())).alignment(Alignment::Center);
+                let mut title = vec![title.bold()];
+                if let Some(reference) = reference {
+                    title.push(" compared with ".into());
+                    title.push(reference.to_string().bold().dim());
+                }
+
+                let plot_type = Paragraph::new(Line::from(title)).alignment(Alignment::Center);
 
                 frame.render_widget(tabs, chunks[0]);
                 frame.render_widget(plot_type, chunks[1]);
@@ -244,3 +308,79 @@ impl<'a> NumericMetricView<'a> {
         };
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::{backend::TestBackend, Terminal};
+    use std::fs;
+
+    fn reference_run() -> MetricHistory {
+        let directory = std::env::temp_dir()
+            .join("burn-train-tui-reference")
+            .join("run-a");
+        fs::remove_dir_all(&directory).ok();
+
+        for (split, content) in [("train", "3.0\n2.0\n1.5\n"), ("valid", "2.5\n")] {
+            let epoch = directory.join(split).join("epoch-1");
+            fs::create_dir_all(&epoch).unwrap();
+            fs::write(epoch.join("Loss.log"), content).unwrap();
+        }
+
+        MetricHistory::load(directory).unwrap()
+    }
+
+    fn render(state: &NumericMetricsState) -> String {
+        let mut terminal = Terminal::new(TestBackend::new(200, 40)).unwrap();
+        terminal
+            .draw(|frame| state.view().render(frame, frame.size()))
+            .unwrap();
+
+        let buffer = terminal.backend().buffer();
+        buffer
+            .content()
+            .chunks(buffer.area.width as usize)
+            .map(|line| line.iter().map(|cell| cell.symbol()).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn should_cycle_through_the_reference_runs() {
+        let mut state = NumericMetricsState::default();
+        for value in [4.0, 3.5, 3.0] {
+            state.push_train("Loss".to_string(), value);
+        }
+        state.set_references(vec![reference_run()]);
+
+        assert!(!render(&state).contains("run-a"));
+
+        state.next_reference();
+        let screen = render(&state);
+        assert!(screen.contains("compared with run-a"));
+        assert!(screen.contains("Train (run-a)"));
+
+        state.next_reference();
+        assert!(state.reference.is_none());
+        assert!(!render(&state).contains("run-a"));
+    }
+
+    #[test]
+    fn should_extend_the_y_bounds_with_the_reference() {
+        let mut state = NumericMetricsState::default();
+        for value in [1.0, 0.5, 0.25] {
+            state.push_train("Loss".to_string(), value);
+        }
+        state.set_references(vec![reference_run()]);
+        state.next_reference();
+
+        let (_, full) = state.data.get("Loss").unwrap();
+        let reference = &state.reference.as_ref().unwrap().plots["Loss"];
+
+        // Only the reference points within the live X bounds are considered.
+        assert_eq!(
+            reference.bounds_y(PlotKind::Full, full.axes.bounds_x),
+            Some([1.5, 3.0])
+        );
+    }
+}
@@ -7,6 +7,7 @@ mod plot_utils;
 mod popup;
 mod progress;
 mod recent_history;
+mod reference;
 mod renderer;
 mod status;
 
@@ -19,5 +20,6 @@ pub(crate) use plot_utils::*;
 pub(crate) use popup::*;
 pub(crate) use progress::*;
 pub(crate) use recent_history::*;
+pub(crate) use reference::*;
 pub use renderer::*;
 pub(crate) use status::*;
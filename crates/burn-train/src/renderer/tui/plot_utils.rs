@@ -3,6 +3,7 @@ use crate::metric::format_float;
 const AXIS_TITLE_PRECISION: usize = 2;
 
 /// The data describing both X and Y axes.
+#[derive(Clone)]
 pub(crate) struct PlotAxes {
     pub(crate) labels_x: Vec<String>,
     pub(crate) labels_y: Vec<String>,
@@ -40,9 +41,23 @@ impl PlotAxes {
 
         // We know x are integers.
         self.labels_x = vec![format!("{x_min}"), format!("{x_max}")];
+        self.update_labels_y();
+    }
+
+    /// Extend the Y bounds to include the given ones.
+    pub(crate) fn extend_bounds_y(&mut self, [y_min, y_max]: [f64; 2]) {
+        self.bounds_y = [
+            f64::min(self.bounds_y[0], y_min),
+            f64::max(self.bounds_y[1], y_max),
+        ];
+
+        self.update_labels_y();
+    }
+
+    fn update_labels_y(&mut self) {
         self.labels_y = vec![
-            format_float(y_min, AXIS_TITLE_PRECISION),
-            format_float(y_max, AXIS_TITLE_PRECISION),
+            format_float(self.bounds_y[0], AXIS_TITLE_PRECISION),
+            format_float(self.bounds_y[1], AXIS_TITLE_PRECISION),
         ];
     }
 }
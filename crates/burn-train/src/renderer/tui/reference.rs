@@ -0,0 +1,131 @@
+use super::PlotKind;
+use crate::logger::{MetricHistory, MetricSeries};
+use ratatui::{
+    style::{Color, Style, Stylize},
+    symbols,
+    widgets::{Dataset, GraphType},
+};
+
+/// The history of a metric in a previous run, drawn dimmed behind the live plot for comparison.
+///
+/// The points are placed like the live points: the training and validation entries of an epoch
+/// follow each other, with one step on the X axis per entry.
+pub(crate) struct ReferencePlot {
+    train: ReferencePoints,
+    valid: ReferencePoints,
+}
+
+struct ReferencePoints {
+    label: String,
+    points: Vec<(f64, f64)>,
+    points_full: Vec<(f64, f64)>,
+}
+
+impl ReferencePlot {
+    /// Create the plot of the metric with the given name, if the run has logged it.
+    pub(crate) fn new(
+        history: &MetricHistory,
+        metric: &str,
+        max_samples_full: usize,
+    ) -> Option<Self> {
+        let train = history.train(metric);
+        let valid = history.valid(metric);
+
+        if train.is_none() && valid.is_none() {
+            return None;
+        }
+
+        let num_epochs = usize::max(num_epochs(train), num_epochs(valid));
+        let mut points_train = Vec::new();
+        let mut points_valid = Vec::new();
+        let mut x = 0;
+
+        for epoch in 0..num_epochs {
+            for (series, points) in [(train, &mut points_train), (valid, &mut points_valid)] {
+                let Some(series) = series else {
+                    continue;
+                };
+
+                for y in series.epoch_values(epoch) {
+                    points.push((x as f64, y));
+                    x += 1;
+                }
+            }
+        }
+
+        // The same step is used for both lines, so they keep the same point density.
+        let step = usize::max(x.div_ceil(max_samples_full), 1);
+
+        Some(Self {
+            train: ReferencePoints::new(format!("Train ({})", history.name()), points_train, step),
+            valid: ReferencePoints::new(format!("Valid ({})", history.name()), points_valid, step),
+        })
+    }
+
+    /// Create the datasets of the points within the X bounds of the live plot.
+    pub(crate) fn datasets(&self, kind: PlotKind, bounds_x: [f64; 2]) -> Vec<Dataset<'_>> {
+        let mut datasets = Vec::with_capacity(2);
+
+        for (points, color) in [(&self.train, Color::Red), (&self.valid, Color::Blue)] {
+            let data = points.visible(kind, bounds_x);
+
+            if !data.is_empty() {
+                datasets.push(
+                    Dataset::default()
+                        .name(points.label.as_str())
+                        .marker(symbols::Marker::Braille)
+                        .style(Style::default().fg(color).dim())
+                        .graph_type(GraphType::Line)
+                        .data(data),
+                );
+            }
+        }
+
+        datasets
+    }
+
+    /// The Y bounds of the points within the X bounds of the live plot.
+    pub(crate) fn bounds_y(&self, kind: PlotKind, bounds_x: [f64; 2]) -> Option<[f64; 2]> {
+        self.train
+            .visible(kind, bounds_x)
+            .iter()
+            .chain(self.valid.visible(kind, bounds_x))
+            .fold(None, |bounds, (_, y)| match bounds {
+                Some([min, max]) => Some([f64::min(min, *y), f64::max(max, *y)]),
+                None => Some([*y, *y]),
+            })
+    }
+}
+
+impl ReferencePoints {
+    fn new(label: String, points: Vec<(f64, f64)>, step: usize) -> Self {
+        let points_full = points
+            .iter()
+            .filter(|(x, _)| *x as usize % step == 0)
+            .copied()
+            .collect();
+
+        Self {
+            label,
+            points,
+            points_full,
+        }
+    }
+
+    fn visible(&self, kind: PlotKind, [min_x, max_x]: [f64; 2]) -> &[(f64, f64)] {
+        let points = match kind {
+            PlotKind::Full => &self.points_full,
+            PlotKind::Recent => &self.points,
+        };
+
+        // The points are sorted by X.
+        let start = points.partition_point(|(x, _)| *x < min_x);
+        let end = points.partition_point(|(x, _)| *x <= max_x);
+
+        &points[start..usize::max(start, end)]
+    }
+}
+
+fn num_epochs(series: Option<&MetricSeries>) -> usize {
+    series.map(|series| series.epochs().len()).unwrap_or(0)
+}
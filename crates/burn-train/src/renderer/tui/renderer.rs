@@ -1,3 +1,4 @@
+use crate::logger::MetricHistory;
 use crate::renderer::{tui::NumericMetricsState, MetricsRenderer};
 use crate::renderer::{MetricState, TrainingProgress};
 use crate::TrainingInterrupter;
@@ -97,6 +98,13 @@ impl TuiMetricsRenderer {
         }
     }
 
+    /// Set the previous runs whose metrics can be drawn behind the live plots for comparison,
+    /// cycled through with the `r` key.
+    pub fn with_reference_runs(mut self, runs: Vec<MetricHistory>) -> Self {
+        self.metrics_numeric.set_references(runs);
+        self
+    }
+
     fn render(&mut self) -> Result<(), Box<dyn Error>> {
         let tick_rate = Duration::from_millis(MAX_REFRESH_RATE_MILLIS);
         if self.last_update.elapsed() < tick_rate {
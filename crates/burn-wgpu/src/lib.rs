@@ -86,4 +86,10 @@ mod tests {
     pub type TestRuntime = cubecl::wgpu::WgpuRuntime;
 
     burn_jit::testgen_all!();
+
+    // WGSL has no brain float type, and the half precision floats need the `shader-f16` feature.
+    #[cfg(feature = "half-test")]
+    mod jit_f16 {
+        burn_jit::testgen_jit_float!(burn_jit::tests::half::f16);
+    }
 }
@@ -26,6 +26,7 @@ pub fn dim_inference(node: &mut Node) {
         NodeType::Conv1d => conv1d_update_outputs(node),
         NodeType::Conv2d => conv2d_update_outputs(node),
         NodeType::Cos => same_as_input(node),
+        NodeType::DequantizeLinear => quantization_update_outputs(node, ElementType::Float32),
         NodeType::Div => same_as_input_broadcast(node),
         NodeType::Dropout => same_as_input(node),
         NodeType::Equal => elementwise_comparsion_outputs(node),
@@ -59,6 +60,7 @@ pub fn dim_inference(node: &mut Node) {
         NodeType::Pad => same_as_input(node),
         NodeType::PRelu => same_as_input_broadcast(node),
         NodeType::Pow => same_as_input_broadcast(node),
+        NodeType::QuantizeLinear => quantization_update_outputs(node, ElementType::Int32),
         NodeType::RandomNormal => random_update_output(node),
         NodeType::RandomUniform => random_update_output(node),
         NodeType::Range => range_update_outputs(node),
@@ -477,6 +479,19 @@ fn same_as_input(node: &mut Node) {
     node.outputs[0].ty = node.inputs[0].ty.clone();
 }
 
+/// Update the output of a quantization node, which has the shape of its input and the quantized
+/// (or dequantized) element type.
+fn quantization_update_outputs(node: &mut Node, elem_type: ElementType) {
+    node.outputs[0].ty = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => ArgType::Tensor(TensorType {
+            elem_type,
+            ..tensor.clone()
+        }),
+        ArgType::Scalar(_) => ArgType::Scalar(elem_type),
+        _ => panic!("Only tensor and scalar inputs can be quantized or dequantized"),
+    };
+}
+
 /// Temporary pass-through stub for dimension inference so that we can export the IR model.
 fn temporary_pass_through_stub(node: &mut Node) {
     log::warn!("Must implement dimension inference for {:?}", node);
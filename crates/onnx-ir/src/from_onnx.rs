@@ -5,6 +5,7 @@ use std::{
 };
 
 use crate::node_remap::remap_node_type;
+use crate::quantization::lower_quantized_nodes;
 
 use super::{
     coalesce::coalesce,
@@ -18,13 +19,15 @@ use super::ir::{ArgType, Argument, Node, NodeType};
 
 use protobuf::Message;
 
-const LIFT_CONSTANTS_FOR_NODE_TYPES: [NodeType; 12] = [
+const LIFT_CONSTANTS_FOR_NODE_TYPES: [NodeType; 14] = [
     NodeType::BatchNormalization,
     NodeType::Clip,
     NodeType::Conv1d,
     NodeType::Conv2d,
+    NodeType::DequantizeLinear,
     NodeType::Dropout,
     NodeType::Expand,
+    NodeType::QuantizeLinear,
     NodeType::Reshape,
     NodeType::Resize,
     NodeType::Unsqueeze,
@@ -208,7 +211,13 @@ impl OnnxGraphBuilder {
             &model_proto.graph.initializer,
         );
 
-        let mut node_iter = model_proto.graph.node.iter().peekable();
+        // Quantized operators are lowered to floating point ones before the conversion
+        let nodes = lower_quantized_nodes(
+            &model_proto.graph.node,
+            &model_proto.graph.initializer,
+            &mut graph_data,
+        );
+        let mut node_iter = nodes.iter().peekable();
 
         while let Some(node_proto) = node_iter.next() {
             let mut node = convert_node_proto(node_proto, &graph_data);
@@ -5,6 +5,7 @@ pub mod ir;
 mod node_remap;
 mod proto_conversion;
 mod protos;
+mod quantization;
 mod util;
 
 pub use from_onnx::convert_constant_value;
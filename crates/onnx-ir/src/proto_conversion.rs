@@ -35,6 +35,23 @@ impl TryFrom<TensorProto> for Tensor {
                     Data::Float32s(tensor.float_data)
                 },
             ),
+            // 8-bit integers are quantized values, widened to int32
+            DataType::INT8 => (
+                ElementType::Int32,
+                if !tensor.raw_data.is_empty() {
+                    Data::Int32s(tensor.raw_data.iter().map(|x| *x as i8 as i32).collect())
+                } else {
+                    Data::Int32s(tensor.int32_data)
+                },
+            ),
+            DataType::UINT8 => (
+                ElementType::Int32,
+                if !tensor.raw_data.is_empty() {
+                    Data::Int32s(tensor.raw_data.iter().map(|x| *x as i32).collect())
+                } else {
+                    Data::Int32s(tensor.int32_data)
+                },
+            ),
             DataType::INT16 => {
                 // TODO : Add support for int16 by converting to int32
                 todo!("Add support for int16");
@@ -107,7 +124,7 @@ impl TryFrom<&type_proto::Tensor> for Tensor {
     fn try_from(tensor: &type_proto::Tensor) -> Result<Tensor, Self::Error> {
         let elem_type = match DataType::from_i32(tensor.elem_type).unwrap() {
             DataType::FLOAT => ElementType::Float32,
-            DataType::INT32 => ElementType::Int32,
+            DataType::INT8 | DataType::UINT8 | DataType::INT32 => ElementType::Int32,
             DataType::INT64 => ElementType::Int64,
             DataType::DOUBLE => ElementType::Float64,
             DataType::BOOL => ElementType::Bool,
@@ -233,7 +250,7 @@ impl TryFrom<ValueInfoProto> for Argument {
 
         let elem_type = match DataType::from_i32(tensor_proto.elem_type).unwrap() {
             DataType::FLOAT => ElementType::Float32,
-            DataType::INT32 => ElementType::Int32,
+            DataType::INT8 | DataType::UINT8 | DataType::INT32 => ElementType::Int32,
             DataType::INT64 => ElementType::Int64,
             DataType::DOUBLE => ElementType::Float64,
             DataType::BOOL => ElementType::Bool,
@@ -0,0 +1,387 @@
+//! Lowering of the quantized ONNX operators to floating point operators.
+//!
+//! The quantized weights are dequantized at import time into f32 initializers, while the
+//! quantization of the activations is kept as explicit `QuantizeLinear` and `DequantizeLinear`
+//! nodes, so that the imported model computes the same values as the quantized one, up to the
+//! rounding of the operations done in floating point.
+use std::collections::HashMap;
+
+use protobuf::Enum;
+
+use crate::{
+    from_onnx::GraphData,
+    ir::{ArgType, Argument, Data, ElementType, Tensor, TensorType},
+    protos::{
+        attribute_proto::AttributeType, tensor_proto::DataType, AttributeProto, NodeProto,
+        TensorProto,
+    },
+};
+
+/// Lowers the quantized operators of the graph.
+///
+/// * `DequantizeLinear` nodes of initializers are folded into f32 initializers.
+/// * `QuantizeLinear` nodes get the `qmin` and `qmax` attributes of their quantized type.
+/// * `QLinearConv` and `QLinearMatMul` nodes are replaced by a floating point `Conv` or `MatMul`
+///   surrounded by the dequantization of their inputs and the quantization of their output.
+///
+/// The other nodes are returned unchanged.
+pub(crate) fn lower_quantized_nodes(
+    nodes: &[NodeProto],
+    initializers: &[TensorProto],
+    graph_data: &mut GraphData,
+) -> Vec<NodeProto> {
+    let mut lowering = QuantizationLowering {
+        initializers: initializers
+            .iter()
+            .map(|tensor| (tensor.name.clone(), tensor.clone()))
+            .collect(),
+        constants: nodes
+            .iter()
+            .filter(|node| node.op_type == "Constant")
+            .filter_map(|node| {
+                let value = node.attribute.iter().find(|attr| attr.name == "value")?;
+                let tensor = value.t.as_ref()?;
+                Some((node.output[0].clone(), tensor.data_type))
+            })
+            .collect(),
+        graph_data,
+        nodes: Vec::with_capacity(nodes.len()),
+    };
+
+    for node in nodes {
+        match node.op_type.as_str() {
+            "DequantizeLinear" => lowering.dequantize_linear(node),
+            "QuantizeLinear" => lowering.quantize_linear(node),
+            "QLinearConv" => lowering.qlinear_conv(node),
+            "QLinearMatMul" => lowering.qlinear_matmul(node),
+            _ => lowering.nodes.push(node.clone()),
+        }
+    }
+
+    lowering.nodes
+}
+
+struct QuantizationLowering<'a> {
+    /// The initializers of the graph, which can be dequantized at import time
+    initializers: HashMap<String, TensorProto>,
+    /// The data type of the constant node outputs, used to find the type of zero-points
+    constants: HashMap<String, i32>,
+    graph_data: &'a mut GraphData,
+    /// The lowered nodes
+    nodes: Vec<NodeProto>,
+}
+
+impl QuantizationLowering<'_> {
+    fn dequantize_linear(&mut self, node: &NodeProto) {
+        let axis = int_attr(node, "axis").unwrap_or(1);
+        let zero_point = optional_input(node, 2);
+
+        if let Some(node) = self.dequantize(
+            &node.input[0],
+            &node.input[1],
+            zero_point,
+            axis,
+            &node.output[0],
+        ) {
+            self.nodes.push(node);
+        }
+    }
+
+    fn quantize_linear(&mut self, node: &NodeProto) {
+        let mut node = node.clone();
+        let (qmin, qmax) = self.quantized_range(optional_input(&node, 2));
+
+        node.attribute.push(int_attribute("qmin", qmin));
+        node.attribute.push(int_attribute("qmax", qmax));
+        self.nodes.push(node);
+    }
+
+    /// Lowers `QLinearConv(x, x_scale, x_zero_point, w, w_scale, w_zero_point, y_scale,
+    /// y_zero_point, B)` to `QuantizeLinear(Conv(DequantizeLinear(x), w, B))`, with the weights and
+    /// the bias dequantized at import time.
+    fn qlinear_conv(&mut self, node: &NodeProto) {
+        let output = &node.output[0];
+        let weight = self.initializer(&node.input[3], "QLinearConv: weights");
+
+        // The weights are quantized per output channel
+        let x = format!("{output}_x_float");
+        let w = format!("{output}_w_float");
+        if let Some(dequantize) =
+            self.dequantize(&node.input[0], &node.input[1], Some(&node.input[2]), 1, &x)
+        {
+            self.nodes.push(dequantize);
+        }
+        if self
+            .dequantize(&node.input[3], &node.input[4], Some(&node.input[5]), 0, &w)
+            .is_some()
+        {
+            panic!("QLinearConv: weights must be initializers");
+        }
+
+        let mut inputs = vec![x, w];
+        if let Some(bias) = optional_input(node, 8) {
+            // The bias is quantized in int32 with a zero-point of 0 and the scale x_scale * w_scale
+            let b = format!("{output}_b_float");
+            let bias = self.initializer(bias, "QLinearConv: bias");
+            let x_scale = floats(self.initializer(&node.input[1], "QLinearConv: x_scale"));
+            let scale = floats(self.initializer(&node.input[4], "QLinearConv: w_scale"))
+                .into_iter()
+                .map(|w_scale| w_scale * x_scale[0])
+                .collect::<Vec<_>>();
+            let values = dequantize_values(&bias, &scale, &[], 0);
+
+            self.add_initializer(&b, values, &dims(&bias));
+            inputs.push(b);
+        }
+
+        let mut attribute = node.attribute.clone();
+        if int_attrs(node, "kernel_shape").is_none() {
+            // The kernel shape is needed to know the dimension of the convolution
+            let kernel_shape = dims(&weight)[2..].iter().map(|d| *d as i64).collect();
+            attribute.push(ints_attribute("kernel_shape", kernel_shape));
+        }
+
+        let y = format!("{output}_y_float");
+        self.nodes
+            .push(new_node("Conv", inputs, vec![y.clone()], attribute));
+        self.push_quantize(node, y, &node.input[6], &node.input[7]);
+    }
+
+    /// Lowers `QLinearMatMul(a, a_scale, a_zero_point, b, b_scale, b_zero_point, y_scale,
+    /// y_zero_point)` to `QuantizeLinear(MatMul(DequantizeLinear(a), DequantizeLinear(b)))`.
+    fn qlinear_matmul(&mut self, node: &NodeProto) {
+        let output = &node.output[0];
+        let a = format!("{output}_a_float");
+        let b = format!("{output}_b_float");
+
+        // The rhs is quantized per column, which is its last dimension
+        let b_axis = match self.initializers.get(&node.input[3]) {
+            Some(tensor) => tensor.dims.len() as i64 - 1,
+            None => -1,
+        };
+
+        for dequantize in [
+            self.dequantize(&node.input[0], &node.input[1], Some(&node.input[2]), 1, &a),
+            self.dequantize(
+                &node.input[3],
+                &node.input[4],
+                Some(&node.input[5]),
+                b_axis,
+                &b,
+            ),
+        ]
+        .into_iter()
+        .flatten()
+        {
+            self.nodes.push(dequantize);
+        }
+
+        let y = format!("{output}_y_float");
+        self.nodes
+            .push(new_node("MatMul", vec![a, b], vec![y.clone()], vec![]));
+        self.push_quantize(node, y, &node.input[6], &node.input[7]);
+    }
+
+    /// Quantizes the floating point result of a lowered node into the node output.
+    fn push_quantize(&mut self, node: &NodeProto, input: String, scale: &str, zero_point: &str) {
+        let (qmin, qmax) = self.quantized_range(Some(zero_point));
+        let attribute = vec![int_attribute("qmin", qmin), int_attribute("qmax", qmax)];
+        let inputs = vec![input, scale.to_string(), zero_point.to_string()];
+
+        self.nodes.push(new_node(
+            "QuantizeLinear",
+            inputs,
+            vec![node.output[0].clone()],
+            attribute,
+        ));
+    }
+
+    /// Dequantizes the `x` input into `output`.
+    ///
+    /// Initializers are dequantized at import time, in which case `None` is returned, otherwise the
+    /// `DequantizeLinear` node computing the output is returned.
+    fn dequantize(
+        &mut self,
+        x: &str,
+        scale: &str,
+        zero_point: Option<&str>,
+        axis: i64,
+        output: &str,
+    ) -> Option<NodeProto> {
+        let (Some(x_tensor), Some(scale_tensor)) =
+            (self.initializers.get(x), self.initializers.get(scale))
+        else {
+            let mut inputs = vec![x.to_string(), scale.to_string()];
+            inputs.extend(zero_point.map(str::to_string));
+
+            return Some(new_node(
+                "DequantizeLinear",
+                inputs,
+                vec![output.to_string()],
+                vec![int_attribute("axis", axis)],
+            ));
+        };
+
+        let shape = dims(x_tensor);
+        let scale = floats(scale_tensor);
+        let zero_point = match zero_point {
+            Some(name) => ints(self.initializer(name, "DequantizeLinear: zero_point")),
+            None => vec![],
+        };
+        let axis = if axis < 0 {
+            (axis + shape.len() as i64) as usize
+        } else {
+            axis as usize
+        };
+        let values = dequantize_values(x_tensor, &scale, &zero_point, axis);
+
+        self.add_initializer(output, values, &shape);
+        None
+    }
+
+    /// The range of the quantized type given by the zero-point, uint8 by default.
+    fn quantized_range(&self, zero_point: Option<&str>) -> (i64, i64) {
+        let data_type = match zero_point {
+            Some(name) => self
+                .initializers
+                .get(name)
+                .map(|tensor| tensor.data_type)
+                .or_else(|| self.constants.get(name).copied())
+                .unwrap_or_else(|| panic!("Zero-point {name} must be a constant")),
+            None => DataType::UINT8.value(),
+        };
+
+        match DataType::from_i32(data_type) {
+            Some(DataType::INT8) => (i8::MIN as i64, i8::MAX as i64),
+            Some(DataType::UINT8) => (u8::MIN as i64, u8::MAX as i64),
+            _ => panic!("Only int8 and uint8 quantization is supported"),
+        }
+    }
+
+    fn initializer(&self, name: &str, description: &str) -> TensorProto {
+        self.initializers
+            .get(name)
+            .cloned()
+            .unwrap_or_else(|| panic!("{description} must be an initializer"))
+    }
+
+    fn add_initializer(&mut self, name: &str, values: Vec<f32>, shape: &[usize]) {
+        let ty = if shape.is_empty() {
+            ArgType::Scalar(ElementType::Float32)
+        } else {
+            ArgType::Tensor(TensorType {
+                elem_type: ElementType::Float32,
+                dim: shape.len(),
+                shape: Some(shape.to_vec()),
+            })
+        };
+        let value = if shape.is_empty() {
+            Data::Float32(values[0])
+        } else {
+            Data::Float32s(values)
+        };
+
+        self.graph_data.initializers.insert(
+            name.to_string(),
+            Argument {
+                name: name.to_string(),
+                ty,
+                value: Some(value),
+                passed: false,
+            },
+        );
+    }
+}
+
+/// Computes `(x - zero_point) * scale`, where the scale and the zero-point are either a single
+/// value or one value per index along the `axis` dimension.
+fn dequantize_values(x: &TensorProto, scale: &[f32], zero_point: &[i64], axis: usize) -> Vec<f32> {
+    let shape = dims(x);
+    let per_axis = scale.len() > 1;
+    let (channels, inner) = if per_axis {
+        (shape[axis], shape[axis + 1..].iter().product::<usize>())
+    } else {
+        (1, 1)
+    };
+
+    ints(x.clone())
+        .into_iter()
+        .enumerate()
+        .map(|(i, value)| {
+            let channel = (i / inner) % channels;
+            let zero_point = zero_point.get(channel).copied().unwrap_or(0);
+
+            (value - zero_point) as f32 * scale[channel]
+        })
+        .collect()
+}
+
+fn tensor(tensor: TensorProto) -> Tensor {
+    let name = tensor.name.clone();
+    Tensor::try_from(tensor).unwrap_or_else(|_| panic!("invalid tensor {name}"))
+}
+
+fn floats(tensor_proto: TensorProto) -> Vec<f32> {
+    tensor(tensor_proto).data.unwrap().into_f32s()
+}
+
+fn ints(tensor_proto: TensorProto) -> Vec<i64> {
+    tensor(tensor_proto).data.unwrap().into_i64s()
+}
+
+fn dims(tensor: &TensorProto) -> Vec<usize> {
+    tensor.dims.iter().map(|d| *d as usize).collect()
+}
+
+/// The name of an optional input, which is either missing or empty when not provided.
+fn optional_input(node: &NodeProto, index: usize) -> Option<&str> {
+    node.input
+        .get(index)
+        .map(String::as_str)
+        .filter(|name| !name.is_empty())
+}
+
+fn int_attr(node: &NodeProto, name: &str) -> Option<i64> {
+    node.attribute
+        .iter()
+        .find(|attr| attr.name == name)
+        .map(|attr| attr.i)
+}
+
+fn int_attrs(node: &NodeProto, name: &str) -> Option<Vec<i64>> {
+    node.attribute
+        .iter()
+        .find(|attr| attr.name == name)
+        .map(|attr| attr.ints.clone())
+}
+
+fn int_attribute(name: &str, value: i64) -> AttributeProto {
+    let mut attr = AttributeProto::new();
+    attr.name = name.to_string();
+    attr.type_ = AttributeType::INT.into();
+    attr.i = value;
+    attr
+}
+
+fn ints_attribute(name: &str, values: Vec<i64>) -> AttributeProto {
+    let mut attr = AttributeProto::new();
+    attr.name = name.to_string();
+    attr.type_ = AttributeType::INTS.into();
+    attr.ints = values;
+    attr
+}
+
+fn new_node(
+    op_type: &str,
+    input: Vec<String>,
+    output: Vec<String>,
+    attribute: Vec<AttributeProto>,
+) -> NodeProto {
+    let mut node = NodeProto::new();
+    node.name = output[0].clone();
+    node.op_type = op_type.to_string();
+    node.input = input;
+    node.output = output;
+    node.attribute = attribute;
+    node
+}
@@ -81,18 +81,19 @@ pub fn train<B: AutodiffBackend>(config: TrainingConfig, device: B::Device) {
     B::seed(config.seed);
 
     // Dataloaders
-    let batcher_train = ClassificationBatcher::<B>::new(device.clone());
-    let batcher_valid = ClassificationBatcher::<B::InnerBackend>::new(device.clone());
+    let batcher = ClassificationBatcher::default();
 
-    let dataloader_train = DataLoaderBuilder::new(batcher_train)
+    let dataloader_train = DataLoaderBuilder::<B, _, _>::new(batcher.clone())
         .batch_size(config.batch_size)
+        .device(device.clone())
         .shuffle(config.seed)
         .num_workers(config.num_workers)
         .build(ImageFolderDataset::cifar10_train());
 
     // NOTE: we use the CIFAR-10 test set as validation for demonstration purposes
-    let dataloader_test = DataLoaderBuilder::new(batcher_valid)
+    let dataloader_test = DataLoaderBuilder::<B::InnerBackend, _, _>::new(batcher)
         .batch_size(config.batch_size)
+        .device(device.clone())
         .num_workers(config.num_workers)
         .build(ImageFolderDataset::cifar10_test());
 
@@ -55,18 +55,19 @@ pub fn run<B: AutodiffBackend>(device: B::Device) {
     let optim = config.optimizer.init();
 
     // Create the batcher.
-    let batcher_train = MnistBatcher::<B>::new(device.clone());
-    let batcher_valid = MnistBatcher::<B::InnerBackend>::new(device.clone());
+    let batcher = MnistBatcher::default();
 
     // Create the dataloaders.
-    let dataloader_train = DataLoaderBuilder::new(batcher_train)
+    let dataloader_train = DataLoaderBuilder::<B, _, _>::new(batcher.clone())
         .batch_size(config.batch_size)
+        .device(device.clone())
         .shuffle(config.seed)
         .num_workers(config.num_workers)
         .build(MnistDataset::train());
 
-    let dataloader_test = DataLoaderBuilder::new(batcher_valid)
+    let dataloader_test = DataLoaderBuilder::<B::InnerBackend, _, _>::new(batcher)
         .batch_size(config.batch_size)
+        .device(device.clone())
         .shuffle(config.seed)
         .num_workers(config.num_workers)
         .build(MnistDataset::test());
@@ -3,16 +3,8 @@ use burn::{
     prelude::*,
 };
 
-#[derive(Clone)]
-pub struct MnistBatcher<B: Backend> {
-    device: B::Device,
-}
-
-impl<B: Backend> MnistBatcher<B> {
-    pub fn new(device: B::Device) -> Self {
-        Self { device }
-    }
-}
+#[derive(Clone, Default)]
+pub struct MnistBatcher {}
 
 #[derive(Clone, Debug)]
 pub struct MnistBatch<B: Backend> {
@@ -20,12 +12,12 @@ pub struct MnistBatch<B: Backend> {
     pub targets: Tensor<B, 1, Int>,
 }
 
-impl<B: Backend> Batcher<MnistItem, MnistBatch<B>> for MnistBatcher<B> {
-    fn batch(&self, items: Vec<MnistItem>) -> MnistBatch<B> {
+impl<B: Backend> Batcher<B, MnistItem, MnistBatch<B>> for MnistBatcher {
+    fn batch(&self, items: Vec<MnistItem>, device: &B::Device) -> MnistBatch<B> {
         let images = items
             .iter()
             .map(|item| TensorData::from(item.image).convert::<B::FloatElem>())
-            .map(|data| Tensor::<B, 2>::from_data(data, &self.device))
+            .map(|data| Tensor::<B, 2>::from_data(data, device))
             .map(|tensor| tensor.reshape([1, 28, 28]))
             // normalize: make between [0,1] and make the mean =  0 and std = 1
             // values mean=0.1307,std=0.3081 were copied from Pytorch Mist Example
@@ -36,10 +28,7 @@ impl<B: Backend> Batcher<MnistItem, MnistBatch<B>> for MnistBatcher<B> {
         let targets = items
             .iter()
             .map(|item| {
-                Tensor::<B, 1, Int>::from_data(
-                    [(item.label as i64).elem::<B::IntElem>()],
-                    &self.device,
-                )
+                Tensor::<B, 1, Int>::from_data([(item.label as i64).elem::<B::IntElem>()], device)
             })
             .collect();
 
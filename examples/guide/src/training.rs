@@ -74,17 +74,18 @@ pub fn train<B: AutodiffBackend>(artifact_dir: &str, config: TrainingConfig, dev
 
     B::seed(config.seed);
 
-    let batcher_train = MnistBatcher::<B>::new(device.clone());
-    let batcher_valid = MnistBatcher::<B::InnerBackend>::new(device.clone());
+    let batcher = MnistBatcher::default();
 
-    let dataloader_train = DataLoaderBuilder::new(batcher_train)
+    let dataloader_train = DataLoaderBuilder::<B, _, _>::new(batcher.clone())
         .batch_size(config.batch_size)
+        .device(device.clone())
         .shuffle(config.seed)
         .num_workers(config.num_workers)
         .build(MnistDataset::train());
 
-    let dataloader_test = DataLoaderBuilder::new(batcher_valid)
+    let dataloader_test = DataLoaderBuilder::<B::InnerBackend, _, _>::new(batcher)
         .batch_size(config.batch_size)
+        .device(device.clone())
         .shuffle(config.seed)
         .num_workers(config.num_workers)
         .build(MnistDataset::test());
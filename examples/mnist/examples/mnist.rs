@@ -37,14 +37,13 @@ mod tch_gpu {
 
 #[cfg(feature = "wgpu")]
 mod wgpu {
-    use burn::backend::{
-        wgpu::{Wgpu, WgpuDevice},
-        Autodiff,
-    };
+    use burn::backend::{wgpu::Wgpu, Autodiff};
+    use burn::tensor::backend::Backend;
     use mnist::training;
 
     pub fn run() {
-        let device = WgpuDevice::default();
+        // Train on the discrete GPU with the most memory when there is one.
+        let device = <Wgpu as Backend>::preferred_device();
         training::run::<Autodiff<Wgpu>>(device);
     }
 }
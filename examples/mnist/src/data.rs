@@ -3,10 +3,8 @@ use burn::{
     prelude::*,
 };
 
-#[derive(Clone, Debug)]
-pub struct MnistBatcher<B: Backend> {
-    device: B::Device,
-}
+#[derive(Clone, Debug, Default)]
+pub struct MnistBatcher;
 
 #[derive(Clone, Debug)]
 pub struct MnistBatch<B: Backend> {
@@ -14,18 +12,12 @@ pub struct MnistBatch<B: Backend> {
     pub targets: Tensor<B, 1, Int>,
 }
 
-impl<B: Backend> MnistBatcher<B> {
-    pub fn new(device: B::Device) -> Self {
-        Self { device }
-    }
-}
-
-impl<B: Backend> Batcher<MnistItem, MnistBatch<B>> for MnistBatcher<B> {
-    fn batch(&self, items: Vec<MnistItem>) -> MnistBatch<B> {
+impl<B: Backend> Batcher<B, MnistItem, MnistBatch<B>> for MnistBatcher {
+    fn batch(&self, items: Vec<MnistItem>, device: &B::Device) -> MnistBatch<B> {
         let images = items
             .iter()
             .map(|item| TensorData::from(item.image))
-            .map(|data| Tensor::<B, 2>::from_data(data.convert::<B::FloatElem>(), &self.device))
+            .map(|data| Tensor::<B, 2>::from_data(data.convert::<B::FloatElem>(), device))
             .map(|tensor| tensor.reshape([1, 28, 28]))
             // normalize: make between [0,1] and make the mean =  0 and std = 1
             // values mean=0.1307,std=0.3081 were copied from Pytorch Mist Example
@@ -38,7 +30,7 @@ impl<B: Backend> Batcher<MnistItem, MnistBatch<B>> for MnistBatcher<B> {
             .map(|item| {
                 Tensor::<B, 1, Int>::from_data(
                     TensorData::from([(item.label as i64).elem::<B::IntElem>()]),
-                    &self.device,
+                    device,
                 )
             })
             .collect();
@@ -40,24 +40,39 @@ fn create_artifact_dir(artifact_dir: &str) {
     std::fs::create_dir_all(artifact_dir).ok();
 }
 
+/// Apply the command line arguments on the config in order: the TOML files are merged as partial
+/// configs and the other arguments are `path=value` overrides, such as `optimizer.beta_1=0.8`.
+fn apply_arguments(mut config: MnistTrainingConfig) -> MnistTrainingConfig {
+    for argument in std::env::args().skip(1) {
+        config = match argument.ends_with(".toml") {
+            true => config.merge_toml(&argument),
+            false => config.with_overrides([&argument]),
+        }
+        .unwrap_or_else(|err| panic!("Invalid argument `{argument}`: {err}"));
+    }
+
+    config
+}
+
 pub fn run<B: AutodiffBackend>(device: B::Device) {
     create_artifact_dir(ARTIFACT_DIR);
     // Config
     let config_optimizer = AdamConfig::new().with_weight_decay(Some(WeightDecayConfig::new(5e-5)));
-    let config = MnistTrainingConfig::new(config_optimizer);
+    let config = apply_arguments(MnistTrainingConfig::new(config_optimizer));
     B::seed(config.seed);
 
     // Data
-    let batcher_train = MnistBatcher::<B>::new(device.clone());
-    let batcher_valid = MnistBatcher::<B::InnerBackend>::new(device.clone());
+    let batcher = MnistBatcher::default();
 
-    let dataloader_train = DataLoaderBuilder::new(batcher_train)
+    let dataloader_train = DataLoaderBuilder::<B, _, _>::new(batcher.clone())
         .batch_size(config.batch_size)
+        .device(device.clone())
         .shuffle(config.seed)
         .num_workers(config.num_workers)
         .build(MnistDataset::train());
-    let dataloader_test = DataLoaderBuilder::new(batcher_valid)
+    let dataloader_test = DataLoaderBuilder::<B::InnerBackend, _, _>::new(batcher)
         .batch_size(config.batch_size)
+        .device(device.clone())
         .shuffle(config.seed)
         .num_workers(config.num_workers)
         .build(MnistDataset::test());
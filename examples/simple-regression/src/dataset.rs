@@ -109,10 +109,8 @@ impl DiabetesDataset {
     }
 }
 
-#[derive(Clone, Debug)]
-pub struct DiabetesBatcher<B: Backend> {
-    device: B::Device,
-}
+#[derive(Clone, Debug, Default)]
+pub struct DiabetesBatcher;
 
 #[derive(Clone, Debug)]
 pub struct DiabetesBatch<B: Backend> {
@@ -120,20 +118,16 @@ pub struct DiabetesBatch<B: Backend> {
     pub targets: Tensor<B, 1>,
 }
 
-impl<B: Backend> DiabetesBatcher<B> {
-    pub fn new(device: B::Device) -> Self {
-        Self { device }
-    }
-
-    pub fn min_max_norm<const D: usize>(&self, inp: Tensor<B, D>) -> Tensor<B, D> {
+impl DiabetesBatcher {
+    pub fn min_max_norm<B: Backend, const D: usize>(&self, inp: Tensor<B, D>) -> Tensor<B, D> {
         let min = inp.clone().min_dim(0);
         let max = inp.clone().max_dim(0);
         (inp.clone() - min.clone()).div(max - min)
     }
 }
 
-impl<B: Backend> Batcher<DiabetesItem, DiabetesBatch<B>> for DiabetesBatcher<B> {
-    fn batch(&self, items: Vec<DiabetesItem>) -> DiabetesBatch<B> {
+impl<B: Backend> Batcher<B, DiabetesItem, DiabetesBatch<B>> for DiabetesBatcher {
+    fn batch(&self, items: Vec<DiabetesItem>, device: &B::Device) -> DiabetesBatch<B> {
         let mut inputs: Vec<Tensor<B, 2>> = Vec::new();
 
         for item in items.iter() {
@@ -150,7 +144,7 @@ impl<B: Backend> Batcher<DiabetesItem, DiabetesBatch<B>> for DiabetesBatcher<B>
                     item.ltg,
                     item.glu as f32,
                 ],
-                &self.device,
+                device,
             );
 
             inputs.push(input_tensor.unsqueeze());
@@ -161,7 +155,7 @@ impl<B: Backend> Batcher<DiabetesItem, DiabetesBatch<B>> for DiabetesBatcher<B>
 
         let targets = items
             .iter()
-            .map(|item| Tensor::<B, 1>::from_floats([item.response as f32], &self.device))
+            .map(|item| Tensor::<B, 1>::from_floats([item.response as f32], device))
             .collect();
 
         let targets = Tensor::cat(targets, 0);
@@ -50,20 +50,20 @@ pub fn run<B: AutodiffBackend>(device: B::Device) {
     println!("Train Dataset Size: {}", train_dataset.len());
     println!("Test Dataset Size: {}", test_dataset.len());
 
-    let batcher_train = DiabetesBatcher::<B>::new(device.clone());
-
-    let batcher_test = DiabetesBatcher::<B::InnerBackend>::new(device.clone());
+    let batcher = DiabetesBatcher::default();
 
     // Since dataset size is small, we do full batch gradient descent and set batch size equivalent to size of dataset
 
-    let dataloader_train = DataLoaderBuilder::new(batcher_train)
+    let dataloader_train = DataLoaderBuilder::<B, _, _>::new(batcher.clone())
         .batch_size(train_dataset.len())
+        .device(device.clone())
         .shuffle(config.seed)
         .num_workers(config.num_workers)
         .build(train_dataset);
 
-    let dataloader_test = DataLoaderBuilder::new(batcher_test)
+    let dataloader_test = DataLoaderBuilder::<B::InnerBackend, _, _>::new(batcher)
         .batch_size(test_dataset.len())
+        .device(device.clone())
         .shuffle(config.seed)
         .num_workers(config.num_workers)
         .build(test_dataset);
@@ -33,9 +33,8 @@ pub fn infer<B: Backend, D: TextClassificationDataset + 'static>(
     let n_classes = D::num_classes();
 
     // Initialize batcher for batching samples
-    let batcher = Arc::new(TextClassificationBatcher::<B>::new(
+    let batcher = Arc::new(TextClassificationBatcher::new(
         tokenizer.clone(),
-        device.clone(),
         config.max_seq_length,
     ));
 
@@ -58,7 +57,7 @@ pub fn infer<B: Backend, D: TextClassificationDataset + 'static>(
 
     // Run inference on the given text samples
     println!("Running inference ...");
-    let item = batcher.batch(samples.clone()); // Batch samples using the batcher
+    let item = batcher.batch(samples.clone(), &device); // Batch samples using the batcher
     let predictions = model.infer(item); // Get model predictions
 
     // Print out predictions for each sample
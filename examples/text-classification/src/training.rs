@@ -48,17 +48,8 @@ pub fn train<B: AutodiffBackend, D: TextClassificationDataset + 'static>(
     // Initialize tokenizer
     let tokenizer = Arc::new(BertCasedTokenizer::default());
 
-    // Initialize batchers for training and testing data
-    let batcher_train = TextClassificationBatcher::<B>::new(
-        tokenizer.clone(),
-        devices[0].clone(),
-        config.max_seq_length,
-    );
-    let batcher_test = TextClassificationBatcher::<B::InnerBackend>::new(
-        tokenizer.clone(),
-        devices[0].clone(),
-        config.max_seq_length,
-    );
+    // Initialize batcher for training and testing data
+    let batcher = TextClassificationBatcher::new(tokenizer.clone(), config.max_seq_length);
 
     // Initialize model
     let model = TextClassificationModelConfig::new(
@@ -70,12 +61,14 @@ pub fn train<B: AutodiffBackend, D: TextClassificationDataset + 'static>(
     .init(&devices[0]);
 
     // Initialize data loaders for training and testing data
-    let dataloader_train = DataLoaderBuilder::new(batcher_train)
+    let dataloader_train = DataLoaderBuilder::<B, _, _>::new(batcher.clone())
         .batch_size(config.batch_size)
+        .device(devices[0].clone())
         .num_workers(1)
         .build(SamplerDataset::new(dataset_train, 50_000));
-    let dataloader_test = DataLoaderBuilder::new(batcher_test)
+    let dataloader_test = DataLoaderBuilder::<B::InnerBackend, _, _>::new(batcher)
         .batch_size(config.batch_size)
+        .device(devices[0].clone())
         .num_workers(1)
         .build(SamplerDataset::new(dataset_test, 5_000));
 
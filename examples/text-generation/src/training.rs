@@ -51,13 +51,15 @@ pub fn train<B: AutodiffBackend, D: Dataset<TextGenerationItem> + 'static>(
     )
     .init::<B>(&device);
 
-    let dataloader_train = DataLoaderBuilder::new(batcher_train)
+    let dataloader_train = DataLoaderBuilder::<B, _, _>::new(batcher_train)
         .batch_size(config.batch_size)
+        .device(device.clone())
         .num_workers(4)
         .build(SamplerDataset::new(dataset_train, 10_000));
 
-    let dataloader_test = DataLoaderBuilder::new(batcher_test)
+    let dataloader_test = DataLoaderBuilder::<B::InnerBackend, _, _>::new(batcher_test)
         .batch_size(config.batch_size)
+        .device(device.clone())
         .num_workers(4)
         .build(SamplerDataset::new(dataset_test, 1000));
 